@@ -27,6 +27,7 @@
 //!  * A new element can be inserted at a user-provided key with a max runtime of `O(1)`
 //!  * An element can be removed by providing the corresponding key with a max runtime of `O(1)`
 //!  * One can iterate over all elements of the SlotMap.
+//!  * Elements can be modified in place or bulk-removed during a single iteration.
 //!
 //! The SlotMap is the perfect container when elements shall be added, removed and accesses quickly
 //! but iteration is allowed to be slow.
@@ -44,6 +45,7 @@
 //! println!("value: {:?}", slotmap.get(key));
 //! ```
 
+use core::marker::PhantomData;
 use core::mem::MaybeUninit;
 
 use crate::queue::details::MetaQueue;
@@ -117,6 +119,62 @@ pub mod details {
         }
     }
 
+    /// The mutable iterator of a [`SlotMap`], [`RelocatableSlotMap`] or [`FixedSizeSlotMap`].
+    pub struct IterMut<'slotmap, T, Ptr: GenericPointer> {
+        slotmap: *mut MetaSlotMap<T, Ptr>,
+        key: SlotMapKey,
+        _lifetime: PhantomData<&'slotmap mut MetaSlotMap<T, Ptr>>,
+    }
+
+    pub type OwningIterMut<'slotmap, T> = IterMut<'slotmap, T, GenericOwningPointer>;
+    pub type RelocatableIterMut<'slotmap, T> = IterMut<'slotmap, T, GenericRelocatablePointer>;
+
+    impl<'slotmap, T, Ptr: GenericPointer> Iterator for IterMut<'slotmap, T, Ptr> {
+        type Item = (SlotMapKey, &'slotmap mut T);
+
+        fn next(&mut self) -> Option<Self::Item> {
+            // every key refers to a distinct data entry, therefore the returned mutable
+            // references never alias
+            let slotmap = unsafe { &mut *self.slotmap };
+            let next_key = slotmap.next_occupied_key_after(self.key)?;
+            self.key.0 = next_key.0 + 1;
+            let data_idx = slotmap.idx_to_data[next_key.0];
+            Some((
+                next_key,
+                slotmap.data[data_idx]
+                    .as_mut()
+                    .expect("By contract, data contains a value when idx_to_data contains a value"),
+            ))
+        }
+    }
+
+    /// The draining iterator of a [`SlotMap`], [`RelocatableSlotMap`] or [`FixedSizeSlotMap`].
+    /// It removes every entry it returns. When it is dropped, all remaining entries are removed
+    /// as well.
+    pub struct Drain<'slotmap, T, Ptr: GenericPointer> {
+        slotmap: &'slotmap mut MetaSlotMap<T, Ptr>,
+        key: SlotMapKey,
+    }
+
+    pub type OwningDrain<'slotmap, T> = Drain<'slotmap, T, GenericOwningPointer>;
+    pub type RelocatableDrain<'slotmap, T> = Drain<'slotmap, T, GenericRelocatablePointer>;
+
+    impl<T, Ptr: GenericPointer> Iterator for Drain<'_, T, Ptr> {
+        type Item = (SlotMapKey, T);
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let next_key = self.slotmap.next_occupied_key_after(self.key)?;
+            self.key.0 = next_key.0 + 1;
+            unsafe { self.slotmap.take_impl(next_key) }.map(|value| (next_key, value))
+        }
+    }
+
+    impl<T, Ptr: GenericPointer> Drop for Drain<'_, T, Ptr> {
+        fn drop(&mut self) {
+            for _ in self.by_ref() {}
+        }
+    }
+
     #[repr(C)]
     #[derive(Debug)]
     pub struct MetaSlotMap<T, Ptr: GenericPointer> {
@@ -158,6 +216,12 @@ pub mod details {
             None
         }
 
+        fn next_occupied_key_after(&self, start: SlotMapKey) -> Option<SlotMapKey> {
+            (start.0..self.idx_to_data.len())
+                .find(|n| self.idx_to_data[*n] != INVALID)
+                .map(SlotMapKey)
+        }
+
         pub(crate) unsafe fn initialize_data_structures(&mut self) {
             let capacity = self.capacity_impl();
             for n in 0..capacity {
@@ -180,6 +244,43 @@ pub mod details {
             }
         }
 
+        pub(crate) unsafe fn iter_mut_impl(&mut self) -> IterMut<T, Ptr> {
+            self.verify_init("iter_mut()");
+            IterMut {
+                slotmap: self,
+                key: SlotMapKey(0),
+                _lifetime: PhantomData,
+            }
+        }
+
+        pub(crate) unsafe fn drain_impl(&mut self) -> Drain<T, Ptr> {
+            self.verify_init("drain()");
+            Drain {
+                slotmap: self,
+                key: SlotMapKey(0),
+            }
+        }
+
+        pub(crate) unsafe fn retain_impl<F: FnMut(SlotMapKey, &mut T) -> bool>(
+            &mut self,
+            mut predicate: F,
+        ) {
+            self.verify_init("retain()");
+            for n in 0..self.idx_to_data.len() {
+                let data_idx = self.idx_to_data[n];
+                if data_idx == INVALID {
+                    continue;
+                }
+
+                let value = self.data[data_idx]
+                    .as_mut()
+                    .expect("By contract, data contains a value when idx_to_data contains a value");
+                if !predicate(SlotMapKey(n), value) {
+                    self.take_impl(SlotMapKey(n));
+                }
+            }
+        }
+
         pub(crate) unsafe fn contains_impl(&self, key: SlotMapKey) -> bool {
             self.verify_init("contains()");
             self.idx_to_data[key.0] != INVALID
@@ -289,17 +390,21 @@ pub mod details {
                 return false;
             }
 
+            self.take_impl(key).is_some()
+        }
+
+        unsafe fn take_impl(&mut self, key: SlotMapKey) -> Option<T> {
             let data_idx = self.idx_to_data[key.0];
             if data_idx != INVALID {
-                self.data[data_idx].take();
+                let value = self.data[data_idx].take();
                 let push_result = self.data_next_free_index.push_impl(data_idx);
                 debug_assert!(push_result);
                 self.release_free_index(key.0);
                 self.idx_to_data[key.0] = INVALID;
                 self.len -= 1;
-                true
+                value
             } else {
-                false
+                None
             }
         }
 
@@ -392,6 +497,23 @@ pub mod details {
             unsafe { self.iter_impl() }
         }
 
+        /// Returns the [`IterMut`]ator to iterate over all entries and modify them in place.
+        pub fn iter_mut(&mut self) -> OwningIterMut<T> {
+            unsafe { self.iter_mut_impl() }
+        }
+
+        /// Removes all entries and returns them with their [`SlotMapKey`] in a [`Drain`]
+        /// iterator. Entries that are not consumed are removed when the iterator is dropped.
+        pub fn drain(&mut self) -> OwningDrain<T> {
+            unsafe { self.drain_impl() }
+        }
+
+        /// Retains only the entries for which the predicate returns `true` and removes all
+        /// others in a single pass.
+        pub fn retain<F: FnMut(SlotMapKey, &mut T) -> bool>(&mut self, predicate: F) {
+            unsafe { self.retain_impl(predicate) }
+        }
+
         /// Returns `true` if the provided `key` is contained, otherwise `false`.
         pub fn contains(&self, key: SlotMapKey) -> bool {
             unsafe { self.contains_impl(key) }
@@ -475,6 +597,38 @@ pub mod details {
             self.iter_impl()
         }
 
+        /// Returns the [`IterMut`]ator to iterate over all entries and modify them in place.
+        ///
+        /// # Safety
+        ///
+        ///  * [`RelocatableSlotMap::init()`] must be called once before
+        ///
+        pub unsafe fn iter_mut(&mut self) -> RelocatableIterMut<T> {
+            self.iter_mut_impl()
+        }
+
+        /// Removes all entries and returns them with their [`SlotMapKey`] in a [`Drain`]
+        /// iterator. Entries that are not consumed are removed when the iterator is dropped.
+        ///
+        /// # Safety
+        ///
+        ///  * [`RelocatableSlotMap::init()`] must be called once before
+        ///
+        pub unsafe fn drain(&mut self) -> RelocatableDrain<T> {
+            self.drain_impl()
+        }
+
+        /// Retains only the entries for which the predicate returns `true` and removes all
+        /// others in a single pass.
+        ///
+        /// # Safety
+        ///
+        ///  * [`RelocatableSlotMap::init()`] must be called once before
+        ///
+        pub unsafe fn retain<F: FnMut(SlotMapKey, &mut T) -> bool>(&mut self, predicate: F) {
+            self.retain_impl(predicate)
+        }
+
         /// Returns `true` if the provided `key` is contained, otherwise `false`.
         ///
         /// # Safety
@@ -630,6 +784,25 @@ impl<T, const CAPACITY: usize> FixedSizeSlotMap<T, CAPACITY> {
         unsafe { self.state.iter_impl() }
     }
 
+    /// Returns the [`details::RelocatableIterMut`]ator to iterate over all entries and modify
+    /// them in place.
+    pub fn iter_mut(&mut self) -> details::RelocatableIterMut<T> {
+        unsafe { self.state.iter_mut_impl() }
+    }
+
+    /// Removes all entries and returns them with their [`SlotMapKey`] in a
+    /// [`details::RelocatableDrain`] iterator. Entries that are not consumed are removed when
+    /// the iterator is dropped.
+    pub fn drain(&mut self) -> details::RelocatableDrain<T> {
+        unsafe { self.state.drain_impl() }
+    }
+
+    /// Retains only the entries for which the predicate returns `true` and removes all others
+    /// in a single pass.
+    pub fn retain<F: FnMut(SlotMapKey, &mut T) -> bool>(&mut self, predicate: F) {
+        unsafe { self.state.retain_impl(predicate) }
+    }
+
     /// Returns `true` if the provided `key` is contained, otherwise `false`.
     pub fn contains(&self, key: SlotMapKey) -> bool {
         unsafe { self.state.contains_impl(key) }
@@ -226,4 +226,90 @@ mod slot_map {
         let next_key = sut.next_free_key();
         assert_that!(next_key, is_none);
     }
+
+    #[test]
+    fn iter_mut_modifies_values_in_place() {
+        let mut sut = FixedSizeSut::new();
+
+        for n in 0..SUT_CAPACITY {
+            assert_that!(sut.insert(n), is_some);
+        }
+
+        for n in 0..SUT_CAPACITY / 2 {
+            assert_that!(sut.remove(SlotMapKey::new(2 * n)), eq true);
+        }
+
+        for (_, value) in sut.iter_mut() {
+            *value *= 2;
+        }
+
+        assert_that!(sut.iter_mut().count(), eq SUT_CAPACITY / 2);
+        for (key, value) in sut.iter() {
+            assert_that!(key.value() % 2, eq 1);
+            assert_that!(*value, eq 2 * key.value());
+        }
+    }
+
+    #[test]
+    fn retain_removes_all_values_where_predicate_is_false() {
+        let mut sut = Sut::new(SUT_CAPACITY);
+
+        for n in 0..SUT_CAPACITY {
+            assert_that!(sut.insert(n), is_some);
+        }
+
+        sut.retain(|key, value| {
+            *value += 1;
+            key.value() % 3 == 0
+        });
+
+        assert_that!(sut, len SUT_CAPACITY.div_ceil(3));
+        for (key, value) in sut.iter() {
+            assert_that!(key.value() % 3, eq 0);
+            assert_that!(*value, eq key.value() + 1);
+        }
+
+        for _ in 0..SUT_CAPACITY - sut.len() {
+            assert_that!(sut.insert(0), is_some);
+        }
+        assert_that!(sut.is_full(), eq true);
+    }
+
+    #[test]
+    fn drain_returns_and_removes_all_values() {
+        let mut sut = FixedSizeSut::new();
+
+        for n in 0..SUT_CAPACITY {
+            assert_that!(sut.insert(3 * n), is_some);
+        }
+
+        let mut number_of_drained_values = 0;
+        for (key, value) in sut.drain() {
+            assert_that!(value, eq 3 * key.value());
+            number_of_drained_values += 1;
+        }
+
+        assert_that!(number_of_drained_values, eq SUT_CAPACITY);
+        assert_that!(sut, is_empty);
+        assert_that!(sut.iter().count(), eq 0);
+    }
+
+    #[test]
+    fn dropped_drain_removes_remaining_values() {
+        let mut sut = Sut::new(SUT_CAPACITY);
+
+        for n in 0..SUT_CAPACITY {
+            assert_that!(sut.insert(n), is_some);
+        }
+
+        let mut drain = sut.drain();
+        assert_that!(drain.next(), eq Some((SlotMapKey::new(0), 0)));
+        drop(drain);
+
+        assert_that!(sut, is_empty);
+        for _ in 0..SUT_CAPACITY {
+            assert_that!(sut.insert(0), is_some);
+        }
+        assert_that!(sut.is_full(), eq true);
+    }
 }
@@ -20,7 +20,10 @@ extern crate proc_macro;
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, Data, DeriveInput, Fields};
+use syn::{
+    parse_macro_input, punctuated::Punctuated, Attribute, Data, DeriveInput, Expr, ExprLit, Fields,
+    Lit, Meta, Token,
+};
 
 /// Implements the [`iceoryx2_bb_elementary::placement_default::PlacementDefault`] trait when all
 /// fields of the struct implement it.
@@ -103,3 +106,130 @@ pub fn placement_default_derive(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+/// Implements the [`iceoryx2_bb_elementary::versioned_user_header::VersionedUserHeader`] trait
+/// for a `#[repr(C)]` struct with named fields. The version is defined with
+/// `#[user_header(version = ...)]` and is `1` when it is not set. Every field that is missing
+/// in a header of an older writer is set to the value of its `#[user_header(default = ...)]`
+/// attribute or, when it is not set, to [`Default::default()`].
+///
+/// ```
+/// use iceoryx2_bb_derive_macros::UserHeader;
+/// use iceoryx2_bb_elementary::versioned_user_header::VersionedUserHeader;
+///
+/// #[repr(C)]
+/// #[derive(Debug, Clone, Copy, UserHeader)]
+/// #[user_header(version = 3)]
+/// struct MyHeader {
+///     timestamp: u64,
+///     // added in version 2
+///     frame_id: u32,
+///     // added in version 3
+///     #[user_header(default = 100)]
+///     quality: u32,
+/// }
+///
+/// assert_eq!(MyHeader::VERSION, 3);
+///
+/// let mut header = MyHeader { timestamp: 123, frame_id: 456, quality: 789 };
+/// // written by a writer with version 1 that knows only the timestamp
+/// header.fill_missing_fields(core::mem::size_of::<u64>());
+/// assert_eq!(header.frame_id, 0);
+/// assert_eq!(header.quality, 100);
+/// ```
+#[proc_macro_derive(UserHeader, attributes(user_header))]
+pub fn user_header_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    if !has_repr_c(&input.attrs) {
+        panic!(
+            "The 'repr(C)' attribute is missing from '{}'! The field order of a user header must be stable.",
+            name
+        );
+    }
+
+    let version = match user_header_attribute(&input.attrs, "version") {
+        Some(Expr::Lit(ExprLit {
+            lit: Lit::Int(value),
+            ..
+        })) => value
+            .base10_parse::<u16>()
+            .expect("The user header version must fit into an u16."),
+        Some(_) => panic!("The user header version must be an integer literal."),
+        None => 1,
+    };
+
+    let field_defaults = match input.data {
+        Data::Struct(ref data_struct) => match data_struct.fields {
+            Fields::Named(ref fields_named) => fields_named.named.iter().map(|f| {
+                let field_name = &f.ident;
+                let field_type = &f.ty;
+                let default_value = match user_header_attribute(&f.attrs, "default") {
+                    Some(value) => quote! { #value },
+                    None => quote! { <#field_type as Default>::default() },
+                };
+
+                quote! {
+                    if core::mem::offset_of!(Self, #field_name) + core::mem::size_of::<#field_type>()
+                        > number_of_valid_bytes
+                    {
+                        self.#field_name = #default_value;
+                    }
+                }
+            }),
+            _ => panic!("The UserHeader derive macro supports only structs with named fields."),
+        },
+        _ => panic!("The UserHeader derive macro supports only structs with named fields."),
+    };
+
+    let expanded = quote! {
+        impl #impl_generics VersionedUserHeader for #name #ty_generics #where_clause {
+            const VERSION: u16 = #version;
+
+            fn fill_missing_fields(&mut self, number_of_valid_bytes: usize) {
+                #(#field_defaults)*
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+fn has_repr_c(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .any(|nested| nested.iter().any(|meta| meta.path().is_ident("C")))
+}
+
+// returns the value of `key` in `#[user_header(key = value)]`
+fn user_header_attribute(attrs: &[Attribute], key: &str) -> Option<Expr> {
+    for attr in attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("user_header"))
+    {
+        let nested = attr
+            .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+            .expect("The 'user_header' attribute expects a list of 'key = value' pairs.");
+        for meta in nested {
+            match meta {
+                Meta::NameValue(name_value) if name_value.path.is_ident(key) => {
+                    return Some(name_value.value)
+                }
+                Meta::NameValue(name_value)
+                    if name_value.path.is_ident("version") || name_value.path.is_ident("default") => {}
+                _ => panic!(
+                    "Unsupported 'user_header' attribute! Only 'version = ...' on the struct and 'default = ...' on fields are supported."
+                ),
+            }
+        }
+    }
+
+    None
+}
@@ -0,0 +1,101 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod user_header {
+    use iceoryx2_bb_derive_macros::UserHeader;
+    use iceoryx2_bb_elementary::versioned_user_header::VersionedUserHeader;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy, UserHeader)]
+    struct WithoutVersion {
+        value: u64,
+    }
+
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy, UserHeader)]
+    #[user_header(version = 3)]
+    struct Versioned {
+        value_1: u64,
+        value_2: u32,
+        #[user_header(default = 8912)]
+        value_3: u32,
+        #[user_header(default = true)]
+        value_4: bool,
+    }
+
+    fn sut() -> Versioned {
+        Versioned {
+            value_1: 1,
+            value_2: 2,
+            value_3: 3,
+            value_4: false,
+        }
+    }
+
+    #[test]
+    fn user_header_without_version_attribute_has_version_one() {
+        let mut sut = WithoutVersion { value: 5 };
+        sut.fill_missing_fields(0);
+
+        assert_that!(WithoutVersion::VERSION, eq 1);
+        assert_that!(sut.value, eq 0);
+    }
+
+    #[test]
+    fn user_header_with_version_attribute_has_defined_version() {
+        assert_that!(Versioned::VERSION, eq 3);
+    }
+
+    #[test]
+    fn fill_missing_fields_keeps_complete_header() {
+        let mut sut = sut();
+        sut.fill_missing_fields(core::mem::size_of::<Versioned>());
+
+        assert_that!(sut.value_1, eq 1);
+        assert_that!(sut.value_2, eq 2);
+        assert_that!(sut.value_3, eq 3);
+        assert_that!(sut.value_4, eq false);
+    }
+
+    #[test]
+    fn fill_missing_fields_sets_trailing_fields_to_defaults() {
+        let mut sut = sut();
+        sut.fill_missing_fields(core::mem::size_of::<u64>() + core::mem::size_of::<u32>());
+
+        assert_that!(sut.value_1, eq 1);
+        assert_that!(sut.value_2, eq 2);
+        assert_that!(sut.value_3, eq 8912);
+        assert_that!(sut.value_4, eq true);
+    }
+
+    #[test]
+    fn fill_missing_fields_uses_default_trait_without_default_attribute() {
+        let mut sut = sut();
+        sut.fill_missing_fields(core::mem::size_of::<u64>());
+
+        assert_that!(sut.value_1, eq 1);
+        assert_that!(sut.value_2, eq 0);
+        assert_that!(sut.value_3, eq 8912);
+        assert_that!(sut.value_4, eq true);
+    }
+
+    #[test]
+    fn fill_missing_fields_treats_partially_written_field_as_missing() {
+        let mut sut = sut();
+        sut.fill_missing_fields(core::mem::size_of::<u64>() + 1);
+
+        assert_that!(sut.value_1, eq 1);
+        assert_that!(sut.value_2, eq 0);
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Computes the CRC-32C (Castagnoli) checksum of a byte slice. When the CPU supports it, the
+//! hardware instructions (SSE 4.2 on x86_64, the CRC extension on aarch64) are used, otherwise
+//! it falls back to a table based software implementation.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_elementary::crc32c::crc32c;
+//!
+//! assert_eq!(crc32c(b"123456789"), 0xe3069283);
+//! ```
+
+const POLYNOMIAL: u32 = 0x82f63b78;
+
+const TABLE: [u32; 256] = generate_table();
+
+const fn generate_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ POLYNOMIAL
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+fn software_update(mut crc: u32, data: &[u8]) -> u32 {
+    for byte in data {
+        crc = TABLE[((crc ^ *byte as u32) & 0xff) as usize] ^ (crc >> 8);
+    }
+    crc
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse4.2")]
+unsafe fn hardware_update(crc: u32, data: &[u8]) -> u32 {
+    use core::arch::x86_64::{_mm_crc32_u64, _mm_crc32_u8};
+
+    let mut crc = crc as u64;
+    let mut chunks = data.chunks_exact(8);
+    for chunk in &mut chunks {
+        let mut value = [0u8; 8];
+        value.copy_from_slice(chunk);
+        crc = _mm_crc32_u64(crc, u64::from_le_bytes(value));
+    }
+
+    let mut crc = crc as u32;
+    for byte in chunks.remainder() {
+        crc = _mm_crc32_u8(crc, *byte);
+    }
+    crc
+}
+
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "crc")]
+unsafe fn hardware_update(mut crc: u32, data: &[u8]) -> u32 {
+    use core::arch::aarch64::{__crc32cb, __crc32cd};
+
+    let mut chunks = data.chunks_exact(8);
+    for chunk in &mut chunks {
+        let mut value = [0u8; 8];
+        value.copy_from_slice(chunk);
+        crc = __crc32cd(crc, u64::from_le_bytes(value));
+    }
+
+    for byte in chunks.remainder() {
+        crc = __crc32cb(crc, *byte);
+    }
+    crc
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+fn has_hardware_support() -> bool {
+    #[cfg(target_arch = "x86_64")]
+    {
+        std::is_x86_feature_detected!("sse4.2")
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    {
+        std::arch::is_aarch64_feature_detected!("crc")
+    }
+}
+
+/// Returns the CRC-32C checksum of the provided data.
+pub fn crc32c(data: &[u8]) -> u32 {
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    if has_hardware_support() {
+        // SAFETY: the required cpu feature is available
+        return !unsafe { hardware_update(!0u32, data) };
+    }
+
+    crc32c_software(data)
+}
+
+/// Returns the CRC-32C checksum of the provided data and uses always the software
+/// implementation. Can be used to verify the hardware accelerated [`crc32c()`].
+pub fn crc32c_software(data: &[u8]) -> u32 {
+    !software_update(!0u32, data)
+}
@@ -29,6 +29,7 @@ pub mod alignment;
 pub mod allocator;
 /// A strong type that represents the alignment part of [`core::alloc::Layout`]
 pub mod bump_allocator;
+pub mod crc32c;
 pub mod generic_pointer;
 pub mod lazy_singleton;
 pub mod math;
@@ -41,6 +42,7 @@ pub mod relocatable_ptr;
 pub mod scope_guard;
 pub mod static_assert;
 pub mod unique_id;
+pub mod versioned_user_header;
 
 /// Defines how a callback based iteration shall progress after the calling the callback. Either
 /// stop the iteration with [`CallbackProgression::Stop`] or continue with
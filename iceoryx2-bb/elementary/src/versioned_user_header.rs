@@ -0,0 +1,59 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Trait for user headers whose definition evolves over time by appending fields.
+//! See [`VersionedUserHeader`] for example.
+
+/// A user header that is extended by appending new fields at the end of the `#[repr(C)]`
+/// struct. Every definition has a version that is increased whenever a field is appended.
+/// Readers with an older definition read only the beginning of a newer header and ignore the
+/// unknown trailing fields. Readers with a newer definition complete a header of an older
+/// writer with [`VersionedUserHeader::fill_missing_fields()`].
+///
+/// It is usually implemented with the `UserHeader` derive macro.
+///
+/// ```
+/// use iceoryx2_bb_elementary::versioned_user_header::VersionedUserHeader;
+///
+/// #[repr(C)]
+/// #[derive(Debug, Clone, Copy)]
+/// struct MyHeader {
+///     timestamp: u64,
+///     // added in version 2
+///     frame_id: u32,
+/// }
+///
+/// impl VersionedUserHeader for MyHeader {
+///     const VERSION: u16 = 2;
+///
+///     fn fill_missing_fields(&mut self, number_of_valid_bytes: usize) {
+///         if core::mem::offset_of!(Self, frame_id) + core::mem::size_of::<u32>()
+///             > number_of_valid_bytes
+///         {
+///             self.frame_id = 0;
+///         }
+///     }
+/// }
+///
+/// let mut header = MyHeader { timestamp: 123, frame_id: 456 };
+/// // written by a writer with version 1 that knows only the timestamp
+/// header.fill_missing_fields(core::mem::size_of::<u64>());
+/// assert_eq!(header.frame_id, 0);
+/// ```
+pub trait VersionedUserHeader: Sized {
+    /// The version of the header definition.
+    const VERSION: u16;
+
+    /// Sets every field that is not completely contained in the first `number_of_valid_bytes`
+    /// bytes of the header to its default value.
+    fn fill_missing_fields(&mut self, number_of_valid_bytes: usize);
+}
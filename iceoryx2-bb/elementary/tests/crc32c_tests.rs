@@ -0,0 +1,44 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_elementary::crc32c::*;
+use iceoryx2_bb_testing::assert_that;
+
+#[test]
+fn crc32c_of_empty_data_is_zero() {
+    assert_that!(crc32c(&[]), eq 0);
+    assert_that!(crc32c_software(&[]), eq 0);
+}
+
+#[test]
+fn crc32c_matches_reference_check_value() {
+    assert_that!(crc32c(b"123456789"), eq 0xe3069283);
+    assert_that!(crc32c_software(b"123456789"), eq 0xe3069283);
+}
+
+#[test]
+fn crc32c_hardware_and_software_implementation_are_equal() {
+    let data: Vec<u8> = (0..1031).map(|i| (i * 7 + 3) as u8).collect();
+
+    for len in [0, 1, 7, 8, 9, 63, 64, 1031] {
+        assert_that!(crc32c(&data[..len]), eq crc32c_software(&data[..len]));
+    }
+}
+
+#[test]
+fn crc32c_detects_single_bit_flip() {
+    let mut data = [0xa5u8; 128];
+    let checksum = crc32c(&data);
+    data[77] ^= 0x10;
+
+    assert_that!(crc32c(&data), ne checksum);
+}
@@ -52,6 +52,14 @@
 //!     });
 //! }
 //! ```
+//!
+//! # Validation
+//!
+//! Every [`ContainerHandle`] carries the generation of its slot. With the feature
+//! `container_validation`, [`Container::remove()`] and [`Container::update_state()`] verify
+//! the generations and detect concurrent mutation anomalies like releasing a handle twice.
+//! Instead of corrupting the [`Container`], the anomaly is reported as [`ContainerAnomaly`]
+//! and counted, see [`Container::number_of_detected_anomalies()`].
 
 pub use crate::mpmc::unique_index_set::ReleaseMode;
 use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
@@ -64,6 +72,8 @@ use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_elementary::relocatable_ptr::RelocatablePointer;
 use iceoryx2_bb_elementary::unique_id::UniqueId;
 use iceoryx2_bb_elementary::{allocator::BaseAllocator, math::align_to};
+#[cfg(feature = "container_validation")]
+use iceoryx2_bb_log::error;
 use iceoryx2_bb_log::{fail, fatal_panic};
 use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64};
 
@@ -92,12 +102,53 @@ impl From<UniqueIndexSetAcquireFailure> for ContainerAddFailure {
     }
 }
 
+/// Describes a concurrent mutation anomaly of a [`Container`] that was detected by comparing
+/// the generation of a slot with the expected one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContainerAnomaly {
+    /// The [`ContainerHandle`] was already released with [`Container::remove()`].
+    DoubleRelease {
+        /// The index of the slot
+        index: u32,
+        /// The generation of the slot that the handle refers to
+        generation: u64,
+    },
+    /// The [`ContainerHandle`] was released and its slot was acquired again by another element.
+    StaleHandle {
+        /// The index of the slot
+        index: u32,
+        /// The generation of the slot that the handle refers to
+        handle_generation: u64,
+        /// The current generation of the slot
+        current_generation: u64,
+    },
+    /// The generation of a slot went backwards, the underlying memory was modified without
+    /// using the [`Container`] API.
+    GenerationRegression {
+        /// The index of the slot
+        index: u32,
+        /// The generation that was observed
+        observed_generation: u64,
+        /// The generation that was expected at least
+        previous_generation: u64,
+    },
+}
+
+impl core::fmt::Display for ContainerAnomaly {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ContainerAnomaly::{:?}", self)
+    }
+}
+
+impl core::error::Error for ContainerAnomaly {}
+
 /// A handle that corresponds to an element inside the [`Container`]. Will be acquired when using
 /// [`Container::add()`] and can be released with [`Container::remove()`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ContainerHandle {
     index: u32,
     container_id: u64,
+    generation: u64,
 }
 
 impl ContainerHandle {
@@ -105,6 +156,12 @@ impl ContainerHandle {
     pub fn index(&self) -> u32 {
         self.index
     }
+
+    /// Returns the generation of the slot the handle refers to. It changes every time an
+    /// element is added to or removed from the slot.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
 }
 
 /// Contains a state of the [`Container`]. Can be created with [`Container::get_state()`] and
@@ -149,6 +206,7 @@ impl<T: Copy + Debug> ContainerState<T> {
                     ContainerHandle {
                         index: i as _,
                         container_id: self.container_id,
+                        generation: self.active_index[i],
                     },
                     unsafe { self.data[i].assume_init_ref() },
                 ) == CallbackProgression::Stop
@@ -171,6 +229,7 @@ pub struct Container<T: Copy + Debug> {
     data_ptr: RelocatablePointer<UnsafeCell<MaybeUninit<T>>>,
     capacity: usize,
     change_counter: IoxAtomicU64,
+    number_of_anomalies: IoxAtomicU64,
     is_initialized: IoxAtomicBool,
     container_id: UniqueId,
     // must be the last member, since it is a relocatable container as well and then the offset
@@ -194,6 +253,7 @@ impl<T: Copy + Debug> RelocatableContainer for Container<T> {
             ) as isize),
             capacity,
             change_counter: IoxAtomicU64::new(0),
+            number_of_anomalies: IoxAtomicU64::new(0),
             index_set: UniqueIndexSet::new_uninit(capacity),
             is_initialized: IoxAtomicBool::new(false),
         }
@@ -279,6 +339,55 @@ impl<T: Copy + Debug> Container<T> {
         self.len() == 0
     }
 
+    /// Returns the number of [`ContainerAnomaly`]s that were detected. Anomalies are only
+    /// detected when the feature `container_validation` is enabled.
+    pub fn number_of_detected_anomalies(&self) -> u64 {
+        self.number_of_anomalies.load(Ordering::Relaxed)
+    }
+
+    /// Verifies that the `handle` still refers to the element it was acquired for. Returns
+    /// the [`ContainerAnomaly`] when the `handle` was already released or its slot was
+    /// reused.
+    ///
+    /// # Safety
+    ///
+    ///  * Ensure that [`Container::init()`] was called before calling this method
+    ///  * Ensure that the `handle` was acquired by the same [`Container`]
+    ///
+    pub unsafe fn verify_handle(&self, handle: ContainerHandle) -> Result<(), ContainerAnomaly> {
+        self.verify_init("verify_handle()");
+        let current_generation = unsafe { &*self.active_index_ptr.as_ptr().add(handle.index as _) }
+            .load(Ordering::Acquire);
+
+        if current_generation == handle.generation {
+            Ok(())
+        } else if current_generation < handle.generation {
+            Err(ContainerAnomaly::GenerationRegression {
+                index: handle.index,
+                observed_generation: current_generation,
+                previous_generation: handle.generation,
+            })
+        } else if current_generation == handle.generation + 1 {
+            Err(ContainerAnomaly::DoubleRelease {
+                index: handle.index,
+                generation: handle.generation,
+            })
+        } else {
+            Err(ContainerAnomaly::StaleHandle {
+                index: handle.index,
+                handle_generation: handle.generation,
+                current_generation,
+            })
+        }
+    }
+
+    #[cfg(feature = "container_validation")]
+    fn report_anomaly(&self, anomaly: ContainerAnomaly) {
+        self.number_of_anomalies.fetch_add(1, Ordering::Relaxed);
+        error!(from self, "Concurrent mutation anomaly detected in Container<{}>: {}.",
+            core::any::type_name::<T>(), anomaly);
+    }
+
     /// Adds a new element to the [`Container`]. If there is no more space available it returns
     /// [`None`], otherwise [`Some`] containing the the index value to the underlying element.
     ///
@@ -301,13 +410,16 @@ impl<T: Copy + Debug> Container<T> {
         //////////////////////////////////////
         // SYNC POINT with reading data values
         //////////////////////////////////////
-        unsafe { &*self.active_index_ptr.as_ptr().add(index as _) }.fetch_add(1, Ordering::Release);
+        let generation = unsafe { &*self.active_index_ptr.as_ptr().add(index as _) }
+            .fetch_add(1, Ordering::Release)
+            + 1;
 
         // MUST HAPPEN AFTER all other operations
         self.change_counter.fetch_add(1, Ordering::Release);
         Ok(ContainerHandle {
             index,
             container_id: self.container_id.value(),
+            generation,
         })
     }
 
@@ -322,7 +434,8 @@ impl<T: Copy + Debug> Container<T> {
     ///     with [`Container::add()`], otherwise the method will panic.
     ///
     /// **Important:** If the UniqueIndex still exists it causes double frees or freeing an index
-    /// which was allocated afterwards
+    /// which was allocated afterwards. With the feature `container_validation` those cases are
+    /// detected, reported as [`ContainerAnomaly`] and the element is not released.
     ///
     pub unsafe fn remove(&self, handle: ContainerHandle, mode: ReleaseMode) -> ReleaseState {
         self.verify_init("remove()");
@@ -331,6 +444,15 @@ impl<T: Copy + Debug> Container<T> {
             "The ContainerHandle used as handle was not created by this Container instance."
         );
 
+        #[cfg(feature = "container_validation")]
+        if let Err(anomaly) = self.verify_handle(handle) {
+            self.report_anomaly(anomaly);
+            return match self.is_locked() {
+                true => ReleaseState::Locked,
+                false => ReleaseState::Unlocked,
+            };
+        }
+
         unsafe { &*self.active_index_ptr.as_ptr().add(handle.index as _) }
             .fetch_add(1, Ordering::Relaxed);
         let release_state = self.index_set.release_raw_index(handle.index, mode);
@@ -393,6 +515,15 @@ impl<T: Copy + Debug> Container<T> {
             let mut current_index_count =
                 unsafe { (*self.active_index_ptr.as_ptr().add(i)).load(Ordering::Acquire) };
 
+            #[cfg(feature = "container_validation")]
+            if current_index_count < previous_state.active_index[i] {
+                self.report_anomaly(ContainerAnomaly::GenerationRegression {
+                    index: i as _,
+                    observed_generation: current_index_count,
+                    previous_generation: previous_state.active_index[i],
+                });
+            }
+
             loop {
                 if current_index_count == previous_state.active_index[i] {
                     break;
@@ -486,6 +617,18 @@ impl<T: Copy + Debug, const CAPACITY: usize> FixedSizeContainer<T, CAPACITY> {
         self.container.is_empty()
     }
 
+    /// Returns the number of [`ContainerAnomaly`]s that were detected, see
+    /// [`Container::number_of_detected_anomalies()`].
+    pub fn number_of_detected_anomalies(&self) -> u64 {
+        self.container.number_of_detected_anomalies()
+    }
+
+    /// Verifies that the `handle` still refers to the element it was acquired for, see
+    /// [`Container::verify_handle()`].
+    pub fn verify_handle(&self, handle: ContainerHandle) -> Result<(), ContainerAnomaly> {
+        unsafe { self.container.verify_handle(handle) }
+    }
+
     /// Adds a new element to the [`FixedSizeContainer`]. If there is no more space available it returns
     /// [`None`], otherwise [`Some`] containing the the index value to the underlying element.
     ///
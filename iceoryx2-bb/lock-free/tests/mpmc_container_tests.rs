@@ -483,6 +483,78 @@ mod mpmc_container {
         mpmc_container_add_and_remove_elements_works::<T>();
     }
 
+    #[test]
+    fn mpmc_container_verify_handle_of_contained_element_works<
+        T: Debug + Copy + From<usize> + Into<usize>,
+    >() {
+        let sut = FixedSizeContainer::<T, CAPACITY>::new();
+        let handle = unsafe { sut.add(123.into()) }.unwrap();
+
+        assert_that!(sut.verify_handle(handle.clone()), is_ok);
+        assert_that!(sut.number_of_detected_anomalies(), eq 0);
+    }
+
+    #[test]
+    fn mpmc_container_verify_handle_detects_double_release<
+        T: Debug + Copy + From<usize> + Into<usize>,
+    >() {
+        let sut = FixedSizeContainer::<T, CAPACITY>::new();
+        let handle = unsafe { sut.add(456.into()) }.unwrap();
+        unsafe { sut.remove(handle.clone(), ReleaseMode::Default) };
+
+        let result = sut.verify_handle(handle.clone());
+        assert_that!(result, is_err);
+        assert_that!(
+            result.err().unwrap(), eq ContainerAnomaly::DoubleRelease {
+                index: handle.index(),
+                generation: handle.generation()
+            }
+        );
+    }
+
+    #[test]
+    fn mpmc_container_verify_handle_detects_stale_handle<
+        T: Debug + Copy + From<usize> + Into<usize>,
+    >() {
+        let sut = FixedSizeContainer::<T, CAPACITY>::new();
+        let mut handles = vec![];
+        for i in 0..CAPACITY {
+            handles.push(unsafe { sut.add(i.into()) }.unwrap());
+        }
+
+        let stale_handle = handles[CAPACITY / 2].clone();
+        unsafe { sut.remove(stale_handle.clone(), ReleaseMode::Default) };
+        let new_handle = unsafe { sut.add(789.into()) }.unwrap();
+        assert_that!(new_handle.index(), eq stale_handle.index());
+
+        assert_that!(sut.verify_handle(new_handle), is_ok);
+        let result = sut.verify_handle(stale_handle.clone());
+        assert_that!(
+            result.err().unwrap(), eq ContainerAnomaly::StaleHandle {
+                index: stale_handle.index(),
+                handle_generation: stale_handle.generation(),
+                current_generation: stale_handle.generation() + 2
+            }
+        );
+    }
+
+    #[cfg(feature = "container_validation")]
+    #[test]
+    fn mpmc_container_double_remove_is_reported_as_anomaly<
+        T: Debug + Copy + From<usize> + Into<usize>,
+    >() {
+        let sut = FixedSizeContainer::<T, CAPACITY>::new();
+        let handle = unsafe { sut.add(1011.into()) }.unwrap();
+        let other_handle = unsafe { sut.add(1213.into()) }.unwrap();
+
+        unsafe { sut.remove(handle.clone(), ReleaseMode::Default) };
+        assert_that!(sut.number_of_detected_anomalies(), eq 0);
+
+        unsafe { sut.remove(handle, ReleaseMode::Default) };
+        assert_that!(sut.number_of_detected_anomalies(), eq 1);
+        assert_that!(sut.verify_handle(other_handle), is_ok);
+    }
+
     #[instantiate_tests(<usize>)]
     mod usize {}
 
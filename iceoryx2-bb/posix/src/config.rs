@@ -55,6 +55,9 @@ pub fn shared_memory_directory() -> Path {
 pub const ACL_LIST_CAPACITY: u32 = 25;
 
 pub const UNIX_DOMAIN_SOCKET_PATH_LENGTH: usize = 108;
+/// Maximum size of a single FIFO message. POSIX guarantees that writes up to _POSIX_PIPE_BUF
+/// bytes are atomic.
+pub const FIFO_MAX_MESSAGE_SIZE: usize = 512;
 pub const DEFAULT_CLOCK_MODE: ClockMode = ClockMode::Performance;
 pub const PASSWD_BUFFER_SIZE: usize = 1024;
 pub const GROUP_BUFFER_SIZE: usize = 1024;
@@ -0,0 +1,591 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Abstraction of POSIX named pipes (FIFOs) to transfer tiny control messages. The
+//! [`FifoReceiver`] creates the FIFO in the file system and removes it when it goes out of
+//! scope. An arbitrary number of [`FifoSender`]s can open it and send messages with a size of
+//! at most [`FIFO_MAX_MESSAGE_SIZE`] bytes.
+//!
+//! A FIFO is a byte stream but every message up to [`FIFO_MAX_MESSAGE_SIZE`] bytes is written
+//! atomically. When all messages have the same size, a receive call with a buffer of exactly
+//! that size always acquires one complete message.
+//!
+//! The underlying file descriptors are always non-blocking. The timed and blocking calls
+//! wait on the file descriptor with a [`FileDescriptorSet`].
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_posix::fifo::*;
+//! use iceoryx2_bb_system_types::file_path::FilePath;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//!
+//! let fifo_name = FilePath::new(b"myFifo").unwrap();
+//! let receiver = FifoReceiverBuilder::new(&fifo_name)
+//!                         .creation_mode(CreationMode::PurgeAndCreate)
+//!                         .create().unwrap();
+//!
+//! let sender = FifoSenderBuilder::new(&fifo_name)
+//!                         .create().unwrap();
+//!
+//! // send some data
+//! sender.try_send(&[1u8, 2u8, 3u8, 4u8]).unwrap();
+//!
+//! // receive some data
+//! let mut buffer = [0u8; 4];
+//! receiver.try_receive(&mut buffer).unwrap();
+//! ```
+
+use core::time::Duration;
+
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_log::{fail, fatal_panic, trace};
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_pal_posix::posix::errno::Errno;
+use iceoryx2_pal_posix::*;
+
+pub use crate::config::FIFO_MAX_MESSAGE_SIZE;
+pub use crate::creation_mode::CreationMode;
+use crate::file::{File, FileAccessError, FileRemoveError, FileSetPermissionError, FileStatError};
+use crate::file_descriptor::{FileDescriptor, FileDescriptorBased, FileDescriptorManagement};
+use crate::file_descriptor_set::{
+    FileDescriptorSet, FileDescriptorSetAddError, FileDescriptorSetWaitError, FileEvent,
+    SynchronousMultiplexing,
+};
+use crate::file_type::FileType;
+use crate::permission::Permission;
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+enum FifoOpenError {
+    DoesNotExist,
+    NoReceiver,
+    InsufficientPermissions,
+    PerProcessFileHandleLimitReached,
+    SystemWideFileHandleLimitReached,
+    Interrupt,
+    UnknownError(i32),
+}
+
+enum_gen! {
+    /// Defines the errors that can occur when a [`FifoReceiver`] is created with
+    /// [`FifoReceiverBuilder::create()`].
+    FifoReceiverCreationError
+  entry:
+    FifoAlreadyExists,
+    NotAFifo,
+    InsufficientPermissions,
+    PathDoesNotExist,
+    ReadOnlyFileSystem,
+    NoSpaceLeft,
+    PerProcessFileHandleLimitReached,
+    SystemWideFileHandleLimitReached,
+    Interrupt,
+    NotSupported,
+    UnknownError(i32)
+  mapping:
+    FileAccessError,
+    FileRemoveError,
+    FileSetPermissionError,
+    FileStatError
+}
+
+impl From<FifoOpenError> for FifoReceiverCreationError {
+    fn from(value: FifoOpenError) -> Self {
+        match value {
+            FifoOpenError::DoesNotExist | FifoOpenError::NoReceiver => {
+                FifoReceiverCreationError::PathDoesNotExist
+            }
+            FifoOpenError::InsufficientPermissions => {
+                FifoReceiverCreationError::InsufficientPermissions
+            }
+            FifoOpenError::PerProcessFileHandleLimitReached => {
+                FifoReceiverCreationError::PerProcessFileHandleLimitReached
+            }
+            FifoOpenError::SystemWideFileHandleLimitReached => {
+                FifoReceiverCreationError::SystemWideFileHandleLimitReached
+            }
+            FifoOpenError::Interrupt => FifoReceiverCreationError::Interrupt,
+            FifoOpenError::UnknownError(v) => FifoReceiverCreationError::UnknownError(v),
+        }
+    }
+}
+
+enum_gen! {
+    /// Defines the errors that can occur when a [`FifoSender`] is created with
+    /// [`FifoSenderBuilder::create()`].
+    FifoSenderCreationError
+  entry:
+    DoesNotExist,
+    NotAFifo,
+    InsufficientPermissions,
+    PerProcessFileHandleLimitReached,
+    SystemWideFileHandleLimitReached,
+    Interrupt,
+    UnknownError(i32)
+  mapping:
+    FileStatError
+}
+
+impl From<FifoOpenError> for FifoSenderCreationError {
+    fn from(value: FifoOpenError) -> Self {
+        match value {
+            FifoOpenError::DoesNotExist | FifoOpenError::NoReceiver => {
+                FifoSenderCreationError::DoesNotExist
+            }
+            FifoOpenError::InsufficientPermissions => {
+                FifoSenderCreationError::InsufficientPermissions
+            }
+            FifoOpenError::PerProcessFileHandleLimitReached => {
+                FifoSenderCreationError::PerProcessFileHandleLimitReached
+            }
+            FifoOpenError::SystemWideFileHandleLimitReached => {
+                FifoSenderCreationError::SystemWideFileHandleLimitReached
+            }
+            FifoOpenError::Interrupt => FifoSenderCreationError::Interrupt,
+            FifoOpenError::UnknownError(v) => FifoSenderCreationError::UnknownError(v),
+        }
+    }
+}
+
+enum_gen! {
+    /// Defines the errors that can occur when a [`FifoSender`] sends data via
+    /// * [`FifoSender::try_send()`]
+    /// * [`FifoSender::timed_send()`]
+    /// * [`FifoSender::blocking_send()`]
+    FifoSendError
+  entry:
+    MessageTooLarge,
+    MessagePartiallySend(u64),
+    Interrupt,
+    IOerror,
+    InsufficientResources,
+    UnknownError(i32)
+  mapping:
+    FileDescriptorSetAddError,
+    FileDescriptorSetWaitError
+}
+
+enum_gen! {
+    /// Defines the errors that can occur when a [`FifoReceiver`] receives data via
+    /// * [`FifoReceiver::try_receive()`]
+    /// * [`FifoReceiver::timed_receive()`]
+    /// * [`FifoReceiver::blocking_receive()`]
+    FifoReceiveError
+  entry:
+    Interrupt,
+    IOerror,
+    UnknownError(i32)
+  mapping:
+    FileDescriptorSetAddError,
+    FileDescriptorSetWaitError
+}
+
+enum_gen! {
+    /// The FifoError enum is a generalization when one doesn't require the fine-grained error
+    /// handling enums. One can forward FifoError as more generic return value when a method
+    /// returns a Fifo***Error.
+    /// On a higher level it is again convertable to [`crate::Error`].
+    FifoError
+  generalization:
+    CreationFailed <= FifoSenderCreationError; FifoReceiverCreationError,
+    SendFailed <= FifoSendError,
+    ReceiveFailed <= FifoReceiveError
+}
+
+fn open_fifo(
+    name: &FilePath,
+    flags: posix::int,
+    msg: &str,
+) -> Result<FileDescriptor, FifoOpenError> {
+    let origin = "open_fifo()";
+    let raw_fd = unsafe { posix::open(name.as_c_str(), flags | posix::O_NONBLOCK) };
+
+    if let Some(fd) = FileDescriptor::new(raw_fd) {
+        return Ok(fd);
+    }
+
+    handle_errno!(FifoOpenError, from origin,
+        Errno::ENOENT => (DoesNotExist, "{} since the fifo \"{}\" does not exist.", msg, name),
+        Errno::ENXIO => (NoReceiver, "{} since the fifo \"{}\" has no receiver.", msg, name),
+        Errno::EACCES => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
+        Errno::EMFILE => (PerProcessFileHandleLimitReached, "{} since the per-process limit of file descriptors was reached.", msg),
+        Errno::ENFILE => (SystemWideFileHandleLimitReached, "{} since system-wide limit of file descriptors was reached.", msg),
+        Errno::EINTR => (Interrupt, "{} since an interrupt signal was received.", msg),
+        v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+    );
+}
+
+fn is_fifo<T: FileDescriptorManagement>(fd: &T) -> Result<bool, FileStatError> {
+    Ok(fd.metadata()?.file_type() == FileType::FiFo)
+}
+
+/// Creates a [`FifoReceiver`]. It must be created before the [`FifoSender`] since the sender
+/// opens the FIFO the receiver has created.
+#[derive(Debug)]
+pub struct FifoReceiverBuilder {
+    name: FilePath,
+    permission: Permission,
+    creation_mode: CreationMode,
+}
+
+impl FifoReceiverBuilder {
+    pub fn new(name: &FilePath) -> Self {
+        Self {
+            name: *name,
+            permission: Permission::OWNER_ALL,
+            creation_mode: CreationMode::CreateExclusive,
+        }
+    }
+
+    /// Sets the permission of the corresponding fifo file
+    pub fn permission(mut self, permission: Permission) -> Self {
+        self.permission = permission;
+        self
+    }
+
+    /// Defines the creation mode
+    pub fn creation_mode(mut self, value: CreationMode) -> Self {
+        self.creation_mode = value;
+        self
+    }
+
+    /// Creates a new [`FifoReceiver`].
+    pub fn create(self) -> Result<FifoReceiver, FifoReceiverCreationError> {
+        FifoReceiver::new(self)
+    }
+}
+
+/// Created by the [`FifoReceiverBuilder`]. Owns the FIFO in the file system and receives the
+/// messages of all [`FifoSender`]s with [`FifoReceiver::try_receive()`],
+/// [`FifoReceiver::timed_receive()`] or [`FifoReceiver::blocking_receive()`].
+#[derive(Debug)]
+pub struct FifoReceiver {
+    name: FilePath,
+    file_descriptor: FileDescriptor,
+    // keeps a writer attached so that the read end never signals end-of-file when the last
+    // sender goes out of scope
+    _write_end: FileDescriptor,
+}
+
+impl Drop for FifoReceiver {
+    fn drop(&mut self) {
+        fatal_panic!(from self, when File::remove(&self.name), "Failed to remove fifo file.");
+        trace!(from self, "stop receiving and remove");
+    }
+}
+
+impl FileDescriptorBased for FifoReceiver {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        &self.file_descriptor
+    }
+}
+
+impl FileDescriptorManagement for FifoReceiver {}
+
+impl SynchronousMultiplexing for FifoReceiver {}
+
+impl FifoReceiver {
+    fn create_fifo(config: &FifoReceiverBuilder) -> Result<(), FifoReceiverCreationError> {
+        let msg = "Unable to create fifo";
+        if unsafe { posix::mkfifo(config.name.as_c_str(), config.permission.as_mode()) } == 0 {
+            return Ok(());
+        }
+
+        handle_errno!(FifoReceiverCreationError, from config,
+            success_when config.creation_mode == CreationMode::OpenOrCreate, Errno::EEXIST => ((), FifoAlreadyExists, "{} since it already exists.", msg),
+            Errno::EACCES => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
+            Errno::ENOENT => (PathDoesNotExist, "{} since the path does not exist.", msg),
+            Errno::ENOTDIR => (PathDoesNotExist, "{} since the path does not exist.", msg),
+            Errno::ENOSPC => (NoSpaceLeft, "{} since there is no space left on the device.", msg),
+            Errno::EROFS => (ReadOnlyFileSystem, "{} since it would reside on an read-only file system.", msg),
+            Errno::ENOTSUP => (NotSupported, "{} since fifos are not supported by the platform.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+
+    fn new(config: FifoReceiverBuilder) -> Result<Self, FifoReceiverCreationError> {
+        let msg = "Unable to create fifo receiver";
+
+        let does_file_exist = fail!(from config, when File::does_exist(&config.name),
+            "{} since the fifo existance verification failed.", msg);
+
+        if does_file_exist {
+            match config.creation_mode {
+                CreationMode::PurgeAndCreate => {
+                    fail!(from config, when File::remove(&config.name),
+                        "{} since the already existing fifo could not be removed.", msg);
+                }
+                CreationMode::CreateExclusive => {
+                    fail!(from config, with FifoReceiverCreationError::FifoAlreadyExists,
+                        "{} since it already exists.", msg);
+                }
+                CreationMode::OpenOrCreate => (),
+            }
+        }
+
+        Self::create_fifo(&config)?;
+
+        let file_descriptor = fail!(from config, when open_fifo(&config.name, posix::O_RDONLY, msg),
+            "{} since the read end could not be opened.", msg);
+
+        if !fail!(from config, when is_fifo(&file_descriptor),
+            "{} since the file type could not be acquired.", msg)
+        {
+            fail!(from config, with FifoReceiverCreationError::NotAFifo,
+                "{} since the file \"{}\" exists but is not a fifo.", msg, config.name);
+        }
+
+        let write_end = fail!(from config, when open_fifo(&config.name, posix::O_WRONLY, msg),
+            "{} since the write end could not be opened.", msg);
+
+        let mut new_receiver = Self {
+            name: config.name,
+            file_descriptor,
+            _write_end: write_end,
+        };
+
+        fail!(from config, when new_receiver.set_permission(config.permission),
+            "{} since the permissions could not be applied.", msg);
+
+        trace!(from new_receiver, "created");
+        Ok(new_receiver)
+    }
+
+    /// Returns the name of the fifo
+    pub fn name(&self) -> &FilePath {
+        &self.name
+    }
+
+    fn receive_impl(&self, msg: &str, buffer: &mut [u8]) -> Result<usize, FifoReceiveError> {
+        let bytes_received = unsafe {
+            posix::read(
+                self.file_descriptor.native_handle(),
+                buffer.as_mut_ptr().cast(),
+                buffer.len(),
+            )
+        };
+
+        if bytes_received >= 0 {
+            return Ok(bytes_received as usize);
+        }
+
+        handle_errno!(FifoReceiveError, from self,
+            success Errno::EAGAIN => 0,
+            fatal Errno::EBADF => ("This should never happen! {} since the internal file descriptor was invalid.", msg);
+            fatal Errno::EINVAL => ("This should never happen! {} since an internal argument was invalid.", msg),
+            Errno::EINTR => (Interrupt, "{} since an interrupt signal was received.", msg),
+            Errno::EIO => (IOerror, "{} since an I/O error occurred while reading.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+
+    /// Tries to receive data. It does not block, when no data is present it returns `0`,
+    /// otherwise the number of bytes that were received.
+    pub fn try_receive(&self, buffer: &mut [u8]) -> Result<usize, FifoReceiveError> {
+        self.receive_impl("Unable to try receive data", buffer)
+    }
+
+    /// Blocks until either the timeout has passed or data was received. If the timeout passed
+    /// it returns `0`, otherwise the number of bytes that were received.
+    pub fn timed_receive(
+        &self,
+        buffer: &mut [u8],
+        timeout: Duration,
+    ) -> Result<usize, FifoReceiveError> {
+        let msg = "Unable to timed receive data";
+        let received_bytes = self.receive_impl(msg, buffer)?;
+        if received_bytes != 0 {
+            return Ok(received_bytes);
+        }
+
+        let fd_set = FileDescriptorSet::new();
+        let _guard = fail!(from self, when fd_set.add(self),
+            "{} since the fifo could not be attached to the file descriptor set.", msg);
+        fail!(from self, when fd_set.timed_wait(timeout, FileEvent::Read, |_| {}),
+            "{} since the wait on the fifo ({:?}) failed.", msg, timeout);
+
+        self.receive_impl(msg, buffer)
+    }
+
+    /// Blocks until data was received and returns the number of received bytes.
+    pub fn blocking_receive(&self, buffer: &mut [u8]) -> Result<usize, FifoReceiveError> {
+        let msg = "Unable to blocking receive data";
+        let fd_set = FileDescriptorSet::new();
+        let _guard = fail!(from self, when fd_set.add(self),
+            "{} since the fifo could not be attached to the file descriptor set.", msg);
+
+        loop {
+            let received_bytes = self.receive_impl(msg, buffer)?;
+            if received_bytes != 0 {
+                return Ok(received_bytes);
+            }
+
+            fail!(from self, when fd_set.blocking_wait(FileEvent::Read, |_| {}),
+                "{} since the wait on the fifo failed.", msg);
+        }
+    }
+}
+
+/// Creates a [`FifoSender`]. It requires that a [`FifoReceiver`] has already created the FIFO.
+#[derive(Debug)]
+pub struct FifoSenderBuilder {
+    name: FilePath,
+}
+
+impl FifoSenderBuilder {
+    pub fn new(name: &FilePath) -> Self {
+        Self { name: *name }
+    }
+
+    /// Creates a new [`FifoSender`].
+    pub fn create(self) -> Result<FifoSender, FifoSenderCreationError> {
+        FifoSender::new(self)
+    }
+}
+
+/// Created by the [`FifoSenderBuilder`]. Opens an existing FIFO and sends messages of at most
+/// [`FIFO_MAX_MESSAGE_SIZE`] bytes with [`FifoSender::try_send()`],
+/// [`FifoSender::timed_send()`] or [`FifoSender::blocking_send()`].
+///
+/// The sender keeps the read end of the FIFO open as well so that a vanished [`FifoReceiver`]
+/// never causes a `SIGPIPE`. Sending to a FIFO whose receiver is gone succeeds until the
+/// FIFO buffer is full.
+#[derive(Debug)]
+pub struct FifoSender {
+    name: FilePath,
+    file_descriptor: FileDescriptor,
+    _read_end: FileDescriptor,
+}
+
+impl Drop for FifoSender {
+    fn drop(&mut self) {
+        trace!(from self, "closed");
+    }
+}
+
+impl FileDescriptorBased for FifoSender {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        &self.file_descriptor
+    }
+}
+
+impl FileDescriptorManagement for FifoSender {}
+
+impl SynchronousMultiplexing for FifoSender {}
+
+impl FifoSender {
+    fn new(config: FifoSenderBuilder) -> Result<Self, FifoSenderCreationError> {
+        let msg = "Unable to create fifo sender";
+
+        let read_end = fail!(from config, when open_fifo(&config.name, posix::O_RDONLY, msg),
+            "{} since the fifo could not be opened.", msg);
+
+        if !fail!(from config, when is_fifo(&read_end),
+            "{} since the file type could not be acquired.", msg)
+        {
+            fail!(from config, with FifoSenderCreationError::NotAFifo,
+                "{} since the file \"{}\" is not a fifo.", msg, config.name);
+        }
+
+        let file_descriptor = fail!(from config, when open_fifo(&config.name, posix::O_WRONLY, msg),
+            "{} since the write end could not be opened.", msg);
+
+        let new_sender = Self {
+            name: config.name,
+            file_descriptor,
+            _read_end: read_end,
+        };
+
+        trace!(from new_sender, "opened");
+        Ok(new_sender)
+    }
+
+    /// Returns the name of the fifo
+    pub fn name(&self) -> &FilePath {
+        &self.name
+    }
+
+    fn send_impl(&self, msg: &str, data: &[u8]) -> Result<bool, FifoSendError> {
+        if data.len() > FIFO_MAX_MESSAGE_SIZE {
+            fail!(from self, with FifoSendError::MessageTooLarge,
+                "{} since the message size of {} bytes exceeds the maximum supported size of {} bytes.",
+                msg, data.len(), FIFO_MAX_MESSAGE_SIZE);
+        }
+
+        let bytes_sent = unsafe {
+            posix::write(
+                self.file_descriptor.native_handle(),
+                data.as_ptr().cast(),
+                data.len(),
+            )
+        };
+
+        if bytes_sent < 0 {
+            handle_errno!(FifoSendError, from self,
+                success Errno::EAGAIN => false,
+                fatal Errno::EBADF => ("This should never happen! {} since the internal file descriptor was invalid.", msg);
+                fatal Errno::EINVAL => ("This should never happen! {} since an internal argument was invalid.", msg),
+                Errno::EINTR => (Interrupt, "{} since an interrupt signal was received.", msg),
+                Errno::EIO => (IOerror, "{} since an I/O error occurred while writing.", msg),
+                Errno::ENOSPC => (InsufficientResources, "{} due to insufficient resources.", msg),
+                v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+            );
+        }
+
+        if data.len() != bytes_sent as usize {
+            fail!(from self, with FifoSendError::MessagePartiallySend(bytes_sent as u64),
+                "{} since only {} of {} bytes were sent.", msg, bytes_sent, data.len());
+        }
+
+        Ok(true)
+    }
+
+    /// Tries to send data in a non-blocking way. If the data was sent it returns true,
+    /// otherwise false.
+    pub fn try_send(&self, data: &[u8]) -> Result<bool, FifoSendError> {
+        self.send_impl("Unable to try send data", data)
+    }
+
+    /// Blocks until the data was sent or the timeout has passed. If the data was sent it
+    /// returns true, otherwise false.
+    pub fn timed_send(&self, data: &[u8], timeout: Duration) -> Result<bool, FifoSendError> {
+        let msg = "Unable to timed send data";
+        if self.send_impl(msg, data)? {
+            return Ok(true);
+        }
+
+        let fd_set = FileDescriptorSet::new();
+        let _guard = fail!(from self, when fd_set.add(self),
+            "{} since the fifo could not be attached to the file descriptor set.", msg);
+        fail!(from self, when fd_set.timed_wait(timeout, FileEvent::Write, |_| {}),
+            "{} since the wait on the fifo ({:?}) failed.", msg, timeout);
+
+        self.send_impl(msg, data)
+    }
+
+    /// Blocks until the data was sent.
+    pub fn blocking_send(&self, data: &[u8]) -> Result<(), FifoSendError> {
+        let msg = "Unable to blocking send data";
+        let fd_set = FileDescriptorSet::new();
+        let _guard = fail!(from self, when fd_set.add(self),
+            "{} since the fifo could not be attached to the file descriptor set.", msg);
+
+        loop {
+            if self.send_impl(msg, data)? {
+                return Ok(());
+            }
+
+            fail!(from self, when fd_set.blocking_wait(FileEvent::Write, |_| {}),
+                "{} since the wait on the fifo failed.", msg);
+        }
+    }
+}
@@ -157,6 +157,11 @@ impl FileDescriptor {
         })
     }
 
+    /// Returns true if the FileDescriptor refers to an open file descriptor, otherwise false.
+    pub fn is_valid(&self) -> bool {
+        unsafe { posix::fcntl2(self.value, posix::F_GETFD) >= 0 }
+    }
+
     /// Creates a new FileDescriptor.
     ///
     /// # Safety
@@ -111,6 +111,7 @@ pub struct FileDescriptorSet {
 struct Internals {
     fd_set: posix::fd_set,
     file_descriptors: Vec<i32>,
+    suspended_file_descriptors: Vec<i32>,
     max_fd: i32,
 }
 
@@ -131,6 +132,7 @@ impl Default for FileDescriptorSet {
             internals: UnsafeCell::new(Internals {
                 fd_set: posix::fd_set::new(),
                 file_descriptors: vec![],
+                suspended_file_descriptors: vec![],
                 max_fd: 0,
             }),
         };
@@ -209,6 +211,46 @@ impl FileDescriptorSet {
         self.internals_mut()
             .file_descriptors
             .retain(|&v| value != v);
+        self.internals_mut()
+            .suspended_file_descriptors
+            .retain(|&v| value != v);
+    }
+
+    /// Excludes an attached [`FileDescriptor`] from all wait calls until it is resumed with
+    /// [`FileDescriptorSet::resume()`]. Returns false when the [`FileDescriptor`] is not
+    /// attached or already suspended, otherwise true.
+    pub fn suspend(&self, fd: &FileDescriptor) -> bool {
+        let raw_fd = unsafe { fd.native_handle() };
+        if !self.internals().file_descriptors.contains(&raw_fd) || self.is_suspended(fd) {
+            return false;
+        }
+
+        unsafe { posix::FD_CLR(raw_fd, &mut self.internals_mut().fd_set) };
+        self.internals_mut().suspended_file_descriptors.push(raw_fd);
+        true
+    }
+
+    /// Includes a [`FileDescriptor`] that was suspended with [`FileDescriptorSet::suspend()`]
+    /// in all wait calls again. Returns false when the [`FileDescriptor`] was not suspended,
+    /// otherwise true.
+    pub fn resume(&self, fd: &FileDescriptor) -> bool {
+        let raw_fd = unsafe { fd.native_handle() };
+        if !self.is_suspended(fd) {
+            return false;
+        }
+
+        unsafe { posix::FD_SET(raw_fd, &mut self.internals_mut().fd_set) };
+        self.internals_mut()
+            .suspended_file_descriptors
+            .retain(|&v| raw_fd != v);
+        true
+    }
+
+    /// Returns true if the [`FileDescriptor`] is suspended, otherwise false.
+    pub fn is_suspended(&self, fd: &FileDescriptor) -> bool {
+        self.internals()
+            .suspended_file_descriptors
+            .contains(&unsafe { fd.native_handle() })
     }
 
     /// Returns the maximum capacity of the [`FileDescriptorSet`]
@@ -233,6 +275,7 @@ impl FileDescriptorSet {
 
     fn contains_impl(&self, fd: &FileDescriptor) -> bool {
         unsafe { posix::FD_ISSET(fd.native_handle(), &self.internals().fd_set) }
+        || self.is_suspended(fd)
     }
 
     /// Blocks until the specified event has occurred. It
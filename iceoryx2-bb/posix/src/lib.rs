@@ -19,6 +19,7 @@
 use barrier::BarrierCreationError;
 use clock::ClockError;
 use directory::DirectoryError;
+use fifo::FifoError;
 use file::FileError;
 use file_lock::FileLockError;
 use group::GroupError;
@@ -46,6 +47,7 @@ pub mod udp_socket;
 pub mod handle_errno;
 pub mod deadline_queue;
 pub mod directory;
+pub mod fifo;
 pub mod file;
 pub mod file_descriptor;
 pub mod file_descriptor_set;
@@ -55,6 +57,7 @@ pub mod group;
 pub mod ipc_capable;
 pub mod memory;
 pub mod memory_lock;
+pub mod memory_protection;
 pub mod metadata;
 pub mod mutex;
 pub mod ownership;
@@ -80,6 +83,7 @@ enum_gen! {Error
     Barrier <= BarrierCreationError,
     Clock <= ClockError,
     Directory <= DirectoryError,
+    Fifo <= FifoError,
     File <= FileError,
     FileLock <= FileLockError,
     Group <= GroupError,
@@ -0,0 +1,61 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Changes the [`AccessMode`] of a page aligned memory region of the process, for instance to
+//! map a region of a [`SharedMemory`](crate::shared_memory::SharedMemory) read-only so that
+//! a faulty write results in a segmentation fault instead of silently corrupting the data.
+
+use crate::access_mode::AccessMode;
+use crate::handle_errno;
+use crate::system_configuration::SystemInfo;
+use iceoryx2_pal_posix::posix::errno::Errno;
+use iceoryx2_pal_posix::*;
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum MemoryProtectionError {
+    InvalidAddressRange,
+    AddressNotAMultipleOfThePageSize,
+    AccessModeNotSupportedByMapping,
+    UnknownError(i32),
+}
+
+/// Changes the [`AccessMode`] of memory regions, see the [module](crate::memory_protection)
+/// documentation.
+#[derive(Debug)]
+pub struct MemoryProtection {}
+
+impl MemoryProtection {
+    /// Applies the [`AccessMode`] to all pages of the memory range [address, address + len].
+    ///
+    /// # Safety
+    ///   * the memory range [address, len] must be mapped into the process
+    ///   * no one shall access the memory range in a way that is forbidden by the new
+    ///     [`AccessMode`]
+    ///
+    pub unsafe fn set(
+        address: *const posix::void,
+        len: usize,
+        mode: AccessMode,
+    ) -> Result<(), MemoryProtectionError> {
+        if unsafe { posix::mprotect(address as *mut posix::void, len, mode.as_protflag()) } == 0 {
+            return Ok(());
+        }
+
+        let msg = "Unable to change the memory protection";
+        handle_errno!(MemoryProtectionError, from "MemoryProtection::set",
+            Errno::ENOMEM => (InvalidAddressRange, "{} since the specified range beginning from {:#16X} with a length of {} is not contained in the valid mapped pages in the address spaces of the current process.", msg, address as usize, len),
+            Errno::EINVAL => (AddressNotAMultipleOfThePageSize, "{} since the address {:#16X} is not a multiple of the page-size {}.", msg, address as usize, SystemInfo::PageSize.value()),
+            Errno::EACCES => (AccessModeNotSupportedByMapping, "{} to {:?} since the underlying mapping does not support it.", msg, mode),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+}
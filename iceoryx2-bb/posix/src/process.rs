@@ -40,6 +40,7 @@
 //!             process.get_priority().expect("failed to get priority"));
 //! ```
 use core::fmt::Display;
+use core::time::Duration;
 
 use crate::handle_errno;
 use iceoryx2_bb_elementary::enum_gen;
@@ -87,6 +88,11 @@ enum_gen! { ProcessSetSchedulerError
     SchedulerConversionError
 }
 
+enum_gen! { ProcessCpuTimeError
+  entry:
+    UnknownError(i32)
+}
+
 enum_gen! {
     /// The ProcessError enum is a generalization when one doesn't require the fine-grained error
     /// handling enums. One can forward ProcessError as more generic return value when a method
@@ -96,7 +102,8 @@ enum_gen! {
   generalization:
     FailedToSetSchedulerSettings <= ProcessSetSchedulerError,
     FailedToGetSchedulerSettings <= ProcessGetSchedulerError,
-    FailedToSendSignal <= ProcessSendSignalError
+    FailedToSendSignal <= ProcessSendSignalError,
+    FailedToAcquireCpuTime <= ProcessCpuTimeError
 }
 
 /// Trait to be able to convert integers into processes by interpreting their value as the
@@ -203,6 +210,24 @@ impl Process {
         );
     }
 
+    /// Returns the CPU time, the sum of the user and the system time, the calling process
+    /// consumed so far.
+    pub fn cpu_time_of_self() -> Result<Duration, ProcessCpuTimeError> {
+        let mut usage = posix::rusage::new();
+        if unsafe { posix::getrusage(posix::RUSAGE_SELF, &mut usage) } == 0 {
+            let as_duration = |value: &posix::timeval| {
+                Duration::from_secs(value.tv_sec as u64)
+                    + Duration::from_micros(value.tv_usec as u64)
+            };
+            return Ok(as_duration(&usage.ru_utime) + as_duration(&usage.ru_stime));
+        }
+
+        let msg = "Unable to acquire the CPU time of the process";
+        handle_errno!(ProcessCpuTimeError, from "Process::cpu_time_of_self()",
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+
     /// Returns the priority of the process. 0 is the lowest and 255 the highest priority.
     pub fn get_priority(&self) -> Result<u8, ProcessGetSchedulerError> {
         let msg = "Unable to acquire priority of process";
@@ -130,6 +130,7 @@
 //! ```
 
 use core::fmt::Debug;
+use core::time::Duration;
 
 pub use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_container::semantic_string::SemanticStringError;
@@ -141,7 +142,7 @@ use iceoryx2_pal_posix::posix::{self, Errno, Struct};
 use crate::{
     access_mode::AccessMode,
     directory::{Directory, DirectoryAccessError, DirectoryCreateError},
-    file::{File, FileBuilder, FileCreationError, FileOpenError, FileRemoveError},
+    file::{File, FileBuilder, FileCreationError, FileOpenError, FileRemoveError, FileWriteError},
     file_descriptor::{FileDescriptorBased, FileDescriptorManagement},
     file_lock::LockType,
     permission::Permission,
@@ -254,6 +255,7 @@ pub struct ProcessGuard {
 const INIT_PERMISSION: Permission = Permission::OWNER_WRITE;
 const FINAL_PERMISSION: Permission = Permission::OWNER_ALL;
 const OWNER_LOCK_SUFFIX: &[u8] = b"_owner_lock";
+const HEARTBEAT_SIZE: usize = 12;
 
 fn generate_owner_lock_path(path: &FilePath) -> Result<FilePath, SemanticStringError> {
     let mut owner_lock_path = *path;
@@ -469,6 +471,18 @@ impl ProcessGuard {
         }
     }
 
+    /// Writes the provided heartbeat into the underlying state file. It can be read by every
+    /// [`ProcessMonitor`] with [`ProcessMonitor::heartbeat()`].
+    pub fn write_heartbeat(&mut self, heartbeat: Duration) -> Result<(), FileWriteError> {
+        let mut buffer = [0u8; HEARTBEAT_SIZE];
+        buffer[..8].copy_from_slice(&heartbeat.as_secs().to_le_bytes());
+        buffer[8..].copy_from_slice(&heartbeat.subsec_nanos().to_le_bytes());
+
+        fail!(from self, when self.file.write(&buffer),
+            "Unable to write heartbeat {:?} into the process state file.", heartbeat);
+        Ok(())
+    }
+
     pub(crate) fn staged_death(mut self) {
         self.file.release_ownership();
         self.owner_lock_file.release_ownership();
@@ -628,6 +642,38 @@ impl ProcessMonitor {
         }
     }
 
+    /// Returns the last heartbeat that was written with [`ProcessGuard::write_heartbeat()`].
+    /// If no heartbeat was written yet or the process does not exist it returns [`None`].
+    pub fn heartbeat(&self) -> Result<Option<Duration>, ProcessMonitorStateError> {
+        let msg = "Unable to acquire heartbeat";
+        let file = match FileBuilder::new(&self.path).open_existing(AccessMode::Read) {
+            Ok(file) => file,
+            Err(FileOpenError::FileDoesNotExist) => return Ok(None),
+            Err(FileOpenError::Interrupt) => {
+                fail!(from self, with ProcessMonitorStateError::Interrupt,
+                    "{} since an interrupt signal was received while opening the state file.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with ProcessMonitorStateError::UnknownError(0),
+                    "{} since the state file could not be opened ({:?}).", msg, v);
+            }
+        };
+
+        let mut buffer = [0u8; HEARTBEAT_SIZE];
+        match file.read(&mut buffer) {
+            Ok(n) if n as usize == HEARTBEAT_SIZE => {
+                let seconds = u64::from_le_bytes(buffer[..8].try_into().unwrap());
+                let nanoseconds = u32::from_le_bytes(buffer[8..].try_into().unwrap());
+                Ok(Some(Duration::new(seconds, nanoseconds)))
+            }
+            Ok(_) => Ok(None),
+            Err(v) => {
+                fail!(from self, with ProcessMonitorStateError::UnknownError(0),
+                    "{} since the state file could not be read ({:?}).", msg, v);
+            }
+        }
+    }
+
     fn get_lock_state(file: &File) -> Result<i64, ProcessMonitorStateError> {
         let msg = format!("Unable to acquire lock on file {:?}", file);
         let mut current_state = posix::flock::new();
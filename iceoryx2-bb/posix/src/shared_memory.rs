@@ -71,19 +71,22 @@ use crate::signal::SignalHandler;
 use crate::system_configuration::Limit;
 use iceoryx2_bb_container::semantic_string::*;
 use iceoryx2_bb_elementary::enum_gen;
-use iceoryx2_bb_log::{error, fail, fatal_panic, trace};
+use iceoryx2_bb_elementary::math::align;
+use iceoryx2_bb_log::{debug, error, fail, fatal_panic, trace, warn};
 use iceoryx2_bb_system_types::file_name::*;
 use iceoryx2_bb_system_types::file_path::*;
 use iceoryx2_bb_system_types::path::*;
 use iceoryx2_pal_configuration::PATH_SEPARATOR;
 use iceoryx2_pal_posix::posix::errno::Errno;
 use iceoryx2_pal_posix::posix::POSIX_SUPPORT_ADVANCED_SIGNAL_HANDLING;
+use iceoryx2_pal_posix::posix::POSIX_SUPPORT_HUGE_PAGES;
 use iceoryx2_pal_posix::posix::POSIX_SUPPORT_PERSISTENT_SHARED_MEMORY;
 use iceoryx2_pal_posix::*;
 
 use core::ptr::NonNull;
 use core::sync::atomic::Ordering;
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+use serde::{Deserialize, Serialize};
 
 pub use crate::access_mode::AccessMode;
 pub use crate::creation_mode::CreationMode;
@@ -118,6 +121,26 @@ enum_gen! { SharedMemoryRemoveError
     UnknownError(i32)
 }
 
+/// The size of the huge pages that shall back a [`SharedMemory`], see
+/// [`SharedMemoryBuilder::huge_pages()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum HugePageSize {
+    /// Huge pages with a size of 2 MiB
+    Size2MiB,
+    /// Huge pages with a size of 1 GiB
+    Size1GiB,
+}
+
+impl HugePageSize {
+    /// Returns the size of a huge page in bytes.
+    pub const fn value(&self) -> usize {
+        match self {
+            HugePageSize::Size2MiB => 2 * 1024 * 1024,
+            HugePageSize::Size1GiB => 1024 * 1024 * 1024,
+        }
+    }
+}
+
 /// The builder for the [`SharedMemory`].
 #[derive(Debug)]
 pub struct SharedMemoryBuilder {
@@ -130,6 +153,7 @@ pub struct SharedMemoryBuilder {
     zero_memory: bool,
     access_mode: AccessMode,
     enforce_base_address: Option<u64>,
+    huge_pages: Option<HugePageSize>,
 }
 
 impl SharedMemoryBuilder {
@@ -144,6 +168,7 @@ impl SharedMemoryBuilder {
             creation_mode: None,
             zero_memory: true,
             enforce_base_address: None,
+            huge_pages: None,
         }
     }
 
@@ -161,6 +186,16 @@ impl SharedMemoryBuilder {
         self
     }
 
+    /// Requests that the shared memory is backed by huge pages to reduce the number of TLB
+    /// misses when large memory regions are accessed frequently. On creation, the size is
+    /// rounded up to a multiple of the huge page size. When the platform or the system
+    /// configuration, like the transparent huge page settings of `/dev/shm` on Linux, does not
+    /// support it, the shared memory gracefully falls back to regular pages.
+    pub fn huge_pages(mut self, value: Option<HugePageSize>) -> Self {
+        self.huge_pages = value;
+        self
+    }
+
     /// Opens an already existing shared memory.
     pub fn open_existing(
         mut self,
@@ -189,6 +224,8 @@ impl SharedMemoryBuilder {
                 "{} since the memory was mapped at {:X} which is not enforced base address.", msg, base_address as u64);
         }
 
+        SharedMemory::advise_huge_pages(base_address, self.size, &self);
+
         let shm = SharedMemory {
             name: self.name,
             base_address: base_address as *mut u8,
@@ -309,11 +346,20 @@ impl SharedMemoryCreationBuilder {
             shm.base_address = fail!(from self.config, when SharedMemory::mmap(&shm.file_descriptor, &self.config),
                                     "{} since the memory could not be mapped.", msg)
                 as *mut u8;
+            SharedMemory::advise_huge_pages(
+                shm.base_address.cast(),
+                self.config.size,
+                &self.config,
+            );
 
             trace!(from shm, "open");
             return Ok(shm);
         }
 
+        if let Some(huge_page_size) = self.config.huge_pages {
+            self.config.size = align(self.config.size, huge_page_size.value());
+        }
+
         fail!(from self.config, when shm.truncate(self.config.size), "{} since the shared memory truncation failed.", msg);
 
         shm.base_address = fail!(from self.config, when SharedMemory::mmap(&shm.file_descriptor, &self.config),
@@ -327,6 +373,9 @@ impl SharedMemoryCreationBuilder {
                 "{} since the memory was mapped at {:X} which is not enforced base address.", msg, shm.base_address as u64);
         }
 
+        // must be advised before the memory is touched the first time by zeroing or locking it
+        SharedMemory::advise_huge_pages(shm.base_address.cast(), self.config.size, &self.config);
+
         let actual_shm_size = fail!(from self.config, when shm.metadata(),
                 "{} since a failure occurred while acquiring the file attributes.", msg)
         .size();
@@ -591,6 +640,28 @@ impl SharedMemory {
         );
     }
 
+    fn advise_huge_pages(
+        base_address: *mut posix::void,
+        size: usize,
+        config: &SharedMemoryBuilder,
+    ) {
+        let huge_page_size = match config.huge_pages {
+            Some(v) => v,
+            None => return,
+        };
+
+        if !POSIX_SUPPORT_HUGE_PAGES {
+            debug!(from config, "The platform does not support huge pages, falling back to regular pages.");
+            return;
+        }
+
+        if unsafe { posix::madvise(base_address, size, posix::MADV_HUGEPAGE) } != 0 {
+            warn!(from config,
+                "Unable to back the shared memory with huge pages of size {:?}, falling back to regular pages ({}). Are transparent huge pages enabled for shared memory?",
+                huge_page_size, posix::Errno::get());
+        }
+    }
+
     fn shm_unlink(name: &FileName) -> Result<bool, SharedMemoryRemoveError> {
         let file_path =
             FilePath::from_path_and_file(&Path::new(&[PATH_SEPARATOR; 1]).unwrap(), name).unwrap();
@@ -0,0 +1,224 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_posix::config::*;
+use iceoryx2_bb_posix::fifo::*;
+use iceoryx2_bb_posix::file::*;
+use iceoryx2_bb_posix::testing::create_test_directory;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing::test_requires;
+use iceoryx2_pal_posix::posix::POSIX_SUPPORT_FIFO;
+use std::sync::Barrier;
+use std::thread;
+use std::time::Instant;
+
+const TIMEOUT: Duration = Duration::from_millis(100);
+
+fn generate_fifo_name() -> FilePath {
+    let mut file = FileName::new(b"fifo_tests").unwrap();
+    file.push_bytes(
+        UniqueSystemId::new()
+            .unwrap()
+            .value()
+            .to_string()
+            .as_bytes(),
+    )
+    .unwrap();
+
+    FilePath::from_path_and_file(&test_directory(), &file).unwrap()
+}
+
+#[test]
+fn fifo_send_receive_works() {
+    test_requires!(POSIX_SUPPORT_FIFO);
+
+    create_test_directory();
+    let fifo_name = generate_fifo_name();
+    let sut_receiver = FifoReceiverBuilder::new(&fifo_name).create().unwrap();
+    let sut_sender = FifoSenderBuilder::new(&fifo_name).create().unwrap();
+
+    let send_data = [1u8, 3u8, 3u8, 7u8, 13u8, 37u8];
+    assert_that!(sut_sender.try_send(&send_data), eq Ok(true));
+
+    let mut receive_data = [0u8; 6];
+    assert_that!(sut_receiver.try_receive(&mut receive_data), eq Ok(6));
+    assert_that!(receive_data, eq send_data);
+}
+
+#[test]
+fn fifo_try_receive_without_data_returns_zero() {
+    test_requires!(POSIX_SUPPORT_FIFO);
+
+    create_test_directory();
+    let fifo_name = generate_fifo_name();
+    let sut_receiver = FifoReceiverBuilder::new(&fifo_name).create().unwrap();
+
+    let mut receive_data = [0u8; 8];
+    assert_that!(sut_receiver.try_receive(&mut receive_data), eq Ok(0));
+
+    let sut_sender = FifoSenderBuilder::new(&fifo_name).create().unwrap();
+    drop(sut_sender);
+    assert_that!(sut_receiver.try_receive(&mut receive_data), eq Ok(0));
+}
+
+#[test]
+fn fifo_messages_of_multiple_senders_are_received() {
+    test_requires!(POSIX_SUPPORT_FIFO);
+
+    create_test_directory();
+    let fifo_name = generate_fifo_name();
+    let sut_receiver = FifoReceiverBuilder::new(&fifo_name).create().unwrap();
+    let sut_sender_1 = FifoSenderBuilder::new(&fifo_name).create().unwrap();
+    let sut_sender_2 = FifoSenderBuilder::new(&fifo_name).create().unwrap();
+
+    assert_that!(sut_sender_1.try_send(&1u64.to_ne_bytes()), eq Ok(true));
+    assert_that!(sut_sender_2.try_send(&2u64.to_ne_bytes()), eq Ok(true));
+
+    let mut receive_data = [0u8; 8];
+    assert_that!(sut_receiver.try_receive(&mut receive_data), eq Ok(8));
+    assert_that!(u64::from_ne_bytes(receive_data), eq 1);
+    assert_that!(sut_receiver.try_receive(&mut receive_data), eq Ok(8));
+    assert_that!(u64::from_ne_bytes(receive_data), eq 2);
+    assert_that!(sut_receiver.try_receive(&mut receive_data), eq Ok(0));
+}
+
+#[test]
+fn fifo_sending_too_large_message_fails() {
+    test_requires!(POSIX_SUPPORT_FIFO);
+
+    create_test_directory();
+    let fifo_name = generate_fifo_name();
+    let _sut_receiver = FifoReceiverBuilder::new(&fifo_name).create().unwrap();
+    let sut_sender = FifoSenderBuilder::new(&fifo_name).create().unwrap();
+
+    let send_data = [0u8; FIFO_MAX_MESSAGE_SIZE + 1];
+    assert_that!(sut_sender.try_send(&send_data), eq Err(FifoSendError::MessageTooLarge));
+}
+
+#[test]
+fn fifo_sender_cannot_be_created_without_receiver() {
+    test_requires!(POSIX_SUPPORT_FIFO);
+
+    create_test_directory();
+    let fifo_name = generate_fifo_name();
+    let sut = FifoSenderBuilder::new(&fifo_name).create();
+
+    assert_that!(sut.err(), eq Some(FifoSenderCreationError::DoesNotExist));
+}
+
+#[test]
+fn fifo_sender_cannot_be_created_for_regular_file() {
+    test_requires!(POSIX_SUPPORT_FIFO);
+
+    create_test_directory();
+    let fifo_name = generate_fifo_name();
+    let _file = FileBuilder::new(&fifo_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+        .unwrap();
+
+    let sut = FifoSenderBuilder::new(&fifo_name).create();
+    assert_that!(sut.err(), eq Some(FifoSenderCreationError::NotAFifo));
+
+    File::remove(&fifo_name).unwrap();
+}
+
+#[test]
+fn fifo_receiver_creation_modes_work() {
+    test_requires!(POSIX_SUPPORT_FIFO);
+
+    create_test_directory();
+    let fifo_name = generate_fifo_name();
+    let sut = FifoReceiverBuilder::new(&fifo_name).create().unwrap();
+
+    let sut_2 = FifoReceiverBuilder::new(&fifo_name)
+        .creation_mode(CreationMode::CreateExclusive)
+        .create();
+    assert_that!(sut_2.err(), eq Some(FifoReceiverCreationError::FifoAlreadyExists));
+
+    core::mem::forget(sut);
+    let sut_3 = FifoReceiverBuilder::new(&fifo_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create();
+    assert_that!(sut_3, is_ok);
+}
+
+#[test]
+fn fifo_is_removed_when_receiver_goes_out_of_scope() {
+    test_requires!(POSIX_SUPPORT_FIFO);
+
+    create_test_directory();
+    let fifo_name = generate_fifo_name();
+    let sut = FifoReceiverBuilder::new(&fifo_name).create().unwrap();
+    assert_that!(File::does_exist(&fifo_name), eq Ok(true));
+
+    drop(sut);
+    assert_that!(File::does_exist(&fifo_name), eq Ok(false));
+}
+
+#[test]
+fn fifo_send_to_dropped_receiver_does_not_raise_sigpipe() {
+    test_requires!(POSIX_SUPPORT_FIFO);
+
+    create_test_directory();
+    let fifo_name = generate_fifo_name();
+    let sut_receiver = FifoReceiverBuilder::new(&fifo_name).create().unwrap();
+    let sut_sender = FifoSenderBuilder::new(&fifo_name).create().unwrap();
+    drop(sut_receiver);
+
+    assert_that!(sut_sender.try_send(&[1u8]), is_ok);
+}
+
+#[test]
+fn fifo_timed_receive_blocks_for_at_least_timeout() {
+    test_requires!(POSIX_SUPPORT_FIFO);
+
+    create_test_directory();
+    let fifo_name = generate_fifo_name();
+    let sut_receiver = FifoReceiverBuilder::new(&fifo_name).create().unwrap();
+
+    let mut receive_data = [0u8; 8];
+    let start = Instant::now();
+    assert_that!(sut_receiver.timed_receive(&mut receive_data, TIMEOUT), eq Ok(0));
+    assert_that!(start.elapsed(), time_at_least TIMEOUT);
+}
+
+#[test]
+fn fifo_blocking_receive_blocks_until_data_was_sent() {
+    test_requires!(POSIX_SUPPORT_FIFO);
+
+    create_test_directory();
+    let fifo_name = generate_fifo_name();
+    let sut_receiver = FifoReceiverBuilder::new(&fifo_name).create().unwrap();
+    let barrier = Barrier::new(2);
+
+    thread::scope(|s| {
+        s.spawn(|| {
+            let sut_sender = FifoSenderBuilder::new(&fifo_name).create().unwrap();
+            barrier.wait();
+            thread::sleep(TIMEOUT);
+            sut_sender.blocking_send(&7u64.to_ne_bytes()).unwrap();
+        });
+
+        barrier.wait();
+        let start = Instant::now();
+        let mut receive_data = [0u8; 8];
+        assert_that!(sut_receiver.blocking_receive(&mut receive_data), eq Ok(8));
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+        assert_that!(u64::from_ne_bytes(receive_data), eq 7);
+    });
+}
@@ -341,3 +341,21 @@ pub fn process_state_owner_lock_cannot_be_acquired_twice() {
 }
 
 // END: OS with IPC only lock detection
+
+#[test]
+pub fn process_state_monitor_reads_heartbeat_written_by_guard() {
+    create_test_directory();
+    let path = generate_file_path();
+
+    let mut guard = ProcessGuard::new(&path).unwrap();
+    let monitor = ProcessMonitor::new(&path).unwrap();
+
+    assert_that!(monitor.heartbeat().unwrap(), is_none);
+
+    let heartbeat = Duration::new(1234, 5678);
+    guard.write_heartbeat(heartbeat).unwrap();
+    assert_that!(monitor.heartbeat().unwrap(), eq Some(heartbeat));
+
+    drop(guard);
+    assert_that!(monitor.heartbeat().unwrap(), is_none);
+}
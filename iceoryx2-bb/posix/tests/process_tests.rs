@@ -37,6 +37,21 @@ pub fn process_can_acquire_scheduler_information() {
     assert_that!(process.get_scheduler(), is_ok);
 }
 
+#[test]
+pub fn process_cpu_time_of_self_increases() {
+    let cpu_time = Process::cpu_time_of_self();
+    assert_that!(cpu_time, is_ok);
+
+    let start = std::time::Instant::now();
+    while start.elapsed() < core::time::Duration::from_millis(20) {
+        core::hint::black_box(start.elapsed());
+    }
+
+    let later_cpu_time = Process::cpu_time_of_self();
+    assert_that!(later_cpu_time, is_ok);
+    assert_that!(later_cpu_time.unwrap(), gt cpu_time.unwrap());
+}
+
 #[test]
 pub fn process_is_alive_works() {
     let process = Process::from_self();
@@ -57,6 +57,34 @@ fn shared_memory_create_and_open_works() {
     }
 }
 
+#[test]
+fn shared_memory_with_huge_pages_is_rounded_up_to_huge_page_size_and_works() {
+    let shm_name = generate_shm_name();
+    let mut sut_create = SharedMemoryBuilder::new(&shm_name)
+        .huge_pages(Some(HugePageSize::Size2MiB))
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(1024)
+        .permission(Permission::OWNER_ALL)
+        .zero_memory(true)
+        .create()
+        .unwrap();
+
+    let sut_open = SharedMemoryBuilder::new(&shm_name)
+        .huge_pages(Some(HugePageSize::Size2MiB))
+        .open_existing(AccessMode::Read)
+        .unwrap();
+
+    assert_that!(sut_create.size() % HugePageSize::Size2MiB.value(), eq 0);
+    assert_that!(sut_create.size(), eq sut_open.size());
+
+    sut_create.as_mut_slice()[0] = 123;
+    let last = sut_create.size() - 1;
+    sut_create.as_mut_slice()[last] = 45;
+
+    assert_that!(sut_open.as_slice()[0], eq 123);
+    assert_that!(sut_open.as_slice()[last], eq 45);
+}
+
 #[test]
 fn shared_memory_create_and_modify_open_works() {
     let shm_name = generate_shm_name();
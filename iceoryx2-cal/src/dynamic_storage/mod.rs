@@ -54,10 +54,11 @@
 //! }
 //! ```
 
-use core::{fmt::Debug, time::Duration};
+use core::{fmt::Debug, ptr::NonNull, time::Duration};
 
 use iceoryx2_bb_elementary::enum_gen;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+pub use iceoryx2_bb_posix::shared_memory::HugePageSize;
 use iceoryx2_bb_system_types::file_name::*;
 use tiny_fn::tiny_fn;
 
@@ -95,6 +96,54 @@ pub enum DynamicStorageOpenError {
     InternalError,
 }
 
+/// The maximum number of [`Arena`]s that can be appended to a [`DynamicStorage`] with
+/// [`DynamicStorage::append_arena()`].
+pub const MAX_NUMBER_OF_ARENAS: usize = 8;
+
+/// Describes failures when appending or acquiring an [`Arena`] with
+/// [`DynamicStorage::append_arena()`] or [`DynamicStorage::arena()`]
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum DynamicStorageArenaError {
+    /// [`MAX_NUMBER_OF_ARENAS`] were already appended to the [`DynamicStorage`].
+    ExceedsMaxNumberOfArenas,
+    /// Another instance of the [`DynamicStorage`] is appending an [`Arena`] at the same time.
+    /// The call can be retried.
+    AlreadyExists,
+    /// The [`Arena`] with the provided index was not appended or its underlying resource was
+    /// removed.
+    DoesNotExist,
+    /// The process is not allowed to create or open the underlying resource of the [`Arena`].
+    InsufficientPermissions,
+    /// An unexpected failure of the underlying resource occurred.
+    InternalError,
+}
+
+/// An additional memory region of a [`DynamicStorage`] that was appended with
+/// [`DynamicStorage::append_arena()`]. It is placed in a separate memory segment, therefore
+/// the memory of the [`DynamicStorage`] and of all previously appended [`Arena`]s does not
+/// move and all existing handles and references into it stay valid.
+#[derive(Debug, Clone, Copy)]
+pub struct Arena {
+    base_address: NonNull<u8>,
+    size: usize,
+}
+
+impl Arena {
+    pub(crate) fn new(base_address: NonNull<u8>, size: usize) -> Self {
+        Self { base_address, size }
+    }
+
+    /// Returns the start address of the [`Arena`] in the process local address space
+    pub fn base_address(&self) -> NonNull<u8> {
+        self.base_address
+    }
+
+    /// Returns the size of the [`Arena`] in bytes
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
 enum_gen! {
     DynamicStorageOpenOrCreateError
   mapping:
@@ -120,6 +169,18 @@ pub trait DynamicStorageBuilder<'builder, T: Send + Sync, D: DynamicStorage<T>>:
     /// By default it is set to [`Duration::ZERO`] for no timeout.
     fn timeout(self, value: Duration) -> Self;
 
+    /// Defines if [`DynamicStorageBuilder::open()`] fails with
+    /// [`DynamicStorageOpenError::VersionMismatch`] when the [`DynamicStorage`] was created by
+    /// a different iceoryx2 package version. It shall only be disabled when the stored type
+    /// carries its own protocol version and verifies it after opening.
+    /// By default it is enabled.
+    fn verify_package_version(self, value: bool) -> Self;
+
+    /// Requests that the [`DynamicStorage`] is backed by huge pages of the provided size.
+    /// Implementations that do not support huge pages fall back to regular pages.
+    /// By default it is [`None`].
+    fn huge_pages(self, value: Option<HugePageSize>) -> Self;
+
     /// Before the construction is finalized the initializer is called
     /// with a mutable reference to the new value and a mutable reference to a bump allocator
     /// which provides access to the supplementary memory. If the initialization failed it
@@ -152,6 +213,10 @@ pub trait DynamicStorage<T: Send + Sync>:
     /// resource remain even when every [`DynamicStorage`] instance in every process was removed.
     fn does_support_persistency() -> bool;
 
+    /// Returns true if every instance maps the [`DynamicStorage`] separately into the process
+    /// so that changing the memory protection of one instance does not affect the others.
+    fn does_support_memory_protection() -> bool;
+
     /// Returns true if the storage holds the ownership, otherwise false.
     fn has_ownership(&self) -> bool;
 
@@ -168,6 +233,26 @@ pub trait DynamicStorage<T: Send + Sync>:
     /// thread-safe.
     fn get(&self) -> &T;
 
+    /// Appends an additional zeroed [`Arena`] with at least `size` bytes to the
+    /// [`DynamicStorage`] and returns its index. The [`Arena`] becomes visible to all instances
+    /// of the [`DynamicStorage`], also in other processes. It can be used to grow containers
+    /// beyond the supplementary memory that was reserved at creation. The [`Arena`]s are removed
+    /// together with the [`DynamicStorage`], stale [`Arena`]s of a previous [`DynamicStorage`]
+    /// with the same name are removed when it is created.
+    ///
+    /// The memory of an [`Arena`] is managed by the user of the [`DynamicStorage`], the
+    /// dynamic configs of the iceoryx2 services do not use [`Arena`]s and stay bounded by the
+    /// limits that were defined when the service was created.
+    fn append_arena(&self, size: usize) -> Result<usize, DynamicStorageArenaError>;
+
+    /// Returns the [`Arena`] with the provided index. When the [`Arena`] was appended by
+    /// another instance, it is mapped into the process on first access.
+    fn arena(&self, index: usize) -> Result<Arena, DynamicStorageArenaError>;
+
+    /// Returns the number of [`Arena`]s that were appended with
+    /// [`DynamicStorage::append_arena()`] by any instance of the [`DynamicStorage`].
+    fn number_of_arenas(&self) -> usize;
+
     /// The default suffix of every dynamic storage
     fn default_suffix() -> FileName {
         unsafe { FileName::new_unchecked(b".dyn") }
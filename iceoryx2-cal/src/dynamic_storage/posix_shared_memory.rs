@@ -47,19 +47,23 @@ use core::marker::PhantomData;
 pub use core::ops::Deref;
 use core::ptr::NonNull;
 use core::sync::atomic::Ordering;
+use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_elementary::package_version::PackageVersion;
 use iceoryx2_bb_log::fail;
+use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_log::warn;
 use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 use iceoryx2_bb_posix::directory::*;
 use iceoryx2_bb_posix::file_descriptor::FileDescriptorManagement;
 use iceoryx2_bb_posix::shared_memory::*;
 use iceoryx2_bb_system_types::path::Path;
-use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicUsize};
+use std::sync::Mutex;
 
 use self::dynamic_storage_configuration::DynamicStorageConfiguration;
 
 const INIT_PERMISSIONS: Permission = Permission::OWNER_WRITE;
+const ARENA_SEPARATOR: &[u8] = b"__arena";
 
 #[cfg(not(feature = "dev_permissions"))]
 const FINAL_PERMISSIONS: Permission = Permission::OWNER_ALL;
@@ -75,6 +79,8 @@ pub struct Builder<'builder, T: Send + Sync + Debug> {
     has_ownership: bool,
     config: Configuration<T>,
     timeout: Duration,
+    verify_package_version: bool,
+    huge_pages: Option<HugePageSize>,
     initializer: Initializer<'builder, T>,
     _phantom_data: PhantomData<T>,
 }
@@ -101,6 +107,8 @@ impl<T: Send + Sync + Debug> Clone for Configuration<T> {
 #[repr(C)]
 struct Data<T: Send + Sync + Debug> {
     version: IoxAtomicU64,
+    number_of_arenas: IoxAtomicUsize,
+    arena_sizes: [IoxAtomicUsize; MAX_NUMBER_OF_ARENAS],
     data: T,
 }
 
@@ -162,6 +170,8 @@ impl<T: Send + Sync + Debug> NamedConceptBuilder<Storage<T>> for Builder<'_, T>
             supplementary_size: 0,
             config: Configuration::default(),
             timeout: Duration::ZERO,
+            verify_package_version: true,
+            huge_pages: None,
             initializer: Initializer::new(|_, _| true),
             _phantom_data: PhantomData,
         }
@@ -173,6 +183,27 @@ impl<T: Send + Sync + Debug> NamedConceptBuilder<Storage<T>> for Builder<'_, T>
     }
 }
 
+fn arena_name(storage_name: &FileName, index: usize) -> FileName {
+    let origin = "dynamic_storage::posix_shared_memory::arena_name()";
+    let msg = "This should never happen! Unable to construct the arena name since it would result in an invalid file name.";
+    let mut name = *storage_name;
+    fatal_panic!(from origin, when name.push_bytes(ARENA_SEPARATOR), "{msg}");
+    fatal_panic!(from origin, when name.push_bytes(index.to_string().as_bytes()), "{msg}");
+    name
+}
+
+// Removes all arenas of the storage including stale ones that were left behind by a crashed
+// process, therefore it iterates over all possible indices and not only the published ones.
+fn remove_arenas(storage_name: &FileName) {
+    let origin = "dynamic_storage::posix_shared_memory::remove_arenas()";
+    for index in 0..MAX_NUMBER_OF_ARENAS {
+        if let Err(e) = SharedMemory::remove(&arena_name(storage_name, index)) {
+            warn!(from origin, "Unable to remove arena {} of the dynamic storage \"{}\" ({:?}).",
+                index, storage_name, e);
+        }
+    }
+}
+
 impl<T: Send + Sync + Debug> Builder<'_, T> {
     fn open_impl(&self) -> Result<Storage<T>, DynamicStorageOpenError> {
         let msg = "Failed to open posix_shared_memory::DynamicStorage";
@@ -184,7 +215,10 @@ impl<T: Send + Sync + Debug> Builder<'_, T> {
 
         let mut elapsed_time = Duration::ZERO;
         let shm = loop {
-            match SharedMemoryBuilder::new(&full_name).open_existing(AccessMode::ReadWrite) {
+            match SharedMemoryBuilder::new(&full_name)
+                .huge_pages(self.huge_pages)
+                .open_existing(AccessMode::ReadWrite)
+            {
                 Ok(v) => break v,
                 Err(SharedMemoryCreationError::DoesNotExist) => {
                     fail!(from self, with DynamicStorageOpenError::DoesNotExist,
@@ -228,7 +262,7 @@ impl<T: Send + Sync + Debug> Builder<'_, T> {
                         "{} since the version number was not set - (it is not initialized after {:?}).",
                         msg, self.timeout);
                 }
-            } else if package_version != PackageVersion::get() {
+            } else if self.verify_package_version && package_version != PackageVersion::get() {
                 fail!(from self, with DynamicStorageOpenError::VersionMismatch,
                        "{} since the dynamic storage was created with version {} but this process requires version {}.",
                         msg, package_version, PackageVersion::get());
@@ -244,6 +278,7 @@ impl<T: Send + Sync + Debug> Builder<'_, T> {
         Ok(Storage {
             shm,
             name: self.storage_name,
+            arenas: Mutex::new(Vec::new()),
             _phantom_data: PhantomData,
         })
     }
@@ -253,6 +288,7 @@ impl<T: Send + Sync + Debug> Builder<'_, T> {
 
         let full_name = self.config.path_for(&self.storage_name).file_name();
         let shm = match SharedMemoryBuilder::new(&full_name)
+            .huge_pages(self.huge_pages)
             .creation_mode(CreationMode::CreateExclusive)
             // posix shared memory is always aligned to the greatest possible value (PAGE_SIZE)
             // therefore we do not have to add additional alignment space for T
@@ -277,6 +313,10 @@ impl<T: Send + Sync + Debug> Builder<'_, T> {
             }
         };
 
+        // the storage was created exclusively, therefore existing arenas with the same name
+        // are stale and were left behind by a previous storage
+        remove_arenas(shm.name());
+
         Ok(shm)
     }
 
@@ -289,6 +329,11 @@ impl<T: Send + Sync + Debug> Builder<'_, T> {
         let value = shm.base_address().as_ptr() as *mut Data<T>;
         let version_ptr = unsafe { core::ptr::addr_of_mut!((*value).version) };
         unsafe { version_ptr.write(IoxAtomicU64::new(0)) };
+        unsafe { core::ptr::addr_of_mut!((*value).number_of_arenas).write(IoxAtomicUsize::new(0)) };
+        unsafe {
+            core::ptr::addr_of_mut!((*value).arena_sizes)
+                .write(core::array::from_fn(|_| IoxAtomicUsize::new(0)))
+        };
 
         unsafe { core::ptr::addr_of_mut!((*value).data).write(initial_value) };
 
@@ -328,6 +373,7 @@ impl<T: Send + Sync + Debug> Builder<'_, T> {
         Ok(Storage {
             shm,
             name: self.storage_name,
+            arenas: Mutex::new(Vec::new()),
             _phantom_data: PhantomData,
         })
     }
@@ -354,6 +400,16 @@ impl<'builder, T: Send + Sync + Debug> DynamicStorageBuilder<'builder, T, Storag
         self
     }
 
+    fn verify_package_version(mut self, value: bool) -> Self {
+        self.verify_package_version = value;
+        self
+    }
+
+    fn huge_pages(mut self, value: Option<HugePageSize>) -> Self {
+        self.huge_pages = value;
+        self
+    }
+
     fn supplementary_size(mut self, value: usize) -> Self {
         self.supplementary_size = value;
         self
@@ -394,6 +450,7 @@ impl<'builder, T: Send + Sync + Debug> DynamicStorageBuilder<'builder, T, Storag
 pub struct Storage<T: Debug + Send + Sync> {
     shm: SharedMemory,
     name: FileName,
+    arenas: Mutex<Vec<Option<SharedMemory>>>,
     _phantom_data: PhantomData<T>,
 }
 
@@ -405,10 +462,22 @@ impl<T: Debug + Send + Sync> Drop for Storage<T> {
         if self.shm.has_ownership() {
             let data = unsafe { &mut (*(self.shm.base_address().as_ptr() as *mut Data<T>)).data };
             unsafe { core::ptr::drop_in_place(data) };
+
+            remove_arenas(self.shm.name());
         }
     }
 }
 
+impl<T: Debug + Send + Sync> Storage<T> {
+    fn management(&self) -> &Data<T> {
+        unsafe { &*(self.shm.base_address().as_ptr() as *const Data<T>) }
+    }
+
+    fn arena_name(&self, index: usize) -> FileName {
+        arena_name(self.shm.name(), index)
+    }
+}
+
 impl<T: Send + Sync + Debug> NamedConcept for Storage<T> {
     fn name(&self) -> &FileName {
         &self.name
@@ -463,6 +532,7 @@ impl<T: Send + Sync + Debug> NamedConceptMgmt for Storage<T> {
                     "Removing DynamicStorage in broken state ({:?}) will not call drop of the underlying data type {:?}.",
                     e, core::any::type_name::<T>());
 
+                remove_arenas(&full_name);
                 match iceoryx2_bb_posix::shared_memory::SharedMemory::remove(&full_name) {
                     Ok(v) => Ok(v),
                     Err(
@@ -494,6 +564,10 @@ impl<T: Send + Sync + Debug> DynamicStorage<T> for Storage<T> {
         SharedMemory::does_support_persistency()
     }
 
+    fn does_support_memory_protection() -> bool {
+        true
+    }
+
     fn acquire_ownership(&self) {
         self.shm.acquire_ownership()
     }
@@ -509,4 +583,115 @@ impl<T: Send + Sync + Debug> DynamicStorage<T> for Storage<T> {
     fn release_ownership(&self) {
         self.shm.release_ownership()
     }
+
+    fn append_arena(&self, size: usize) -> Result<usize, DynamicStorageArenaError> {
+        let msg = "Unable to append arena";
+        let management = self.management();
+        let mut index = management.number_of_arenas.load(Ordering::Acquire);
+
+        let shm = loop {
+            if MAX_NUMBER_OF_ARENAS <= index {
+                fail!(from self, with DynamicStorageArenaError::ExceedsMaxNumberOfArenas,
+                    "{} since the maximum number of {} arenas is already reached.",
+                    msg, MAX_NUMBER_OF_ARENAS);
+            }
+
+            // the exclusive creation of the arena guarantees that concurrent appends of the
+            // same index by other instances fail
+            match SharedMemoryBuilder::new(&self.arena_name(index))
+                .creation_mode(CreationMode::CreateExclusive)
+                .size(size)
+                .permission(FINAL_PERMISSIONS)
+                .zero_memory(true)
+                .has_ownership(false)
+                .create()
+            {
+                Ok(shm) => break shm,
+                Err(SharedMemoryCreationError::AlreadyExist) => {
+                    // another instance appended the arena in the meantime, continue with the
+                    // next index
+                    let number_of_arenas = management.number_of_arenas.load(Ordering::Acquire);
+                    if index < number_of_arenas {
+                        index = number_of_arenas;
+                        continue;
+                    }
+
+                    fail!(from self, with DynamicStorageArenaError::AlreadyExists,
+                        "{} since the arena {} is appended concurrently by another instance.", msg, index);
+                }
+                Err(SharedMemoryCreationError::InsufficientPermissions) => {
+                    fail!(from self, with DynamicStorageArenaError::InsufficientPermissions,
+                        "{} due to insufficient permissions.", msg);
+                }
+                Err(e) => {
+                    fail!(from self, with DynamicStorageArenaError::InternalError,
+                        "{} since the underlying shared memory could not be created ({:?}).", msg, e);
+                }
+            }
+        };
+
+        management.arena_sizes[index].store(shm.size(), Ordering::Relaxed);
+        //////////////////////////////////////////
+        // SYNC POINT: publish Data<T>::arena_sizes
+        //////////////////////////////////////////
+        management
+            .number_of_arenas
+            .store(index + 1, Ordering::Release);
+
+        let mut arenas = fatal_panic!(from self, when self.arenas.lock(),
+            "This should never happen! {} since the arena lock is poisoned.", msg);
+        if arenas.len() <= index {
+            arenas.resize_with(index + 1, || None);
+        }
+        arenas[index] = Some(shm);
+
+        Ok(index)
+    }
+
+    fn arena(&self, index: usize) -> Result<Arena, DynamicStorageArenaError> {
+        let msg = "Unable to acquire arena";
+        if self.number_of_arenas() <= index {
+            fail!(from self, with DynamicStorageArenaError::DoesNotExist,
+                "{} {} since only {} arenas exist.", msg, index, self.number_of_arenas());
+        }
+
+        let size = self.management().arena_sizes[index].load(Ordering::Relaxed);
+        let mut arenas = fatal_panic!(from self, when self.arenas.lock(),
+            "This should never happen! {} {} since the arena lock is poisoned.", msg, index);
+        if arenas.len() <= index {
+            arenas.resize_with(index + 1, || None);
+        }
+
+        if let Some(shm) = &arenas[index] {
+            return Ok(Arena::new(shm.base_address(), size));
+        }
+
+        match SharedMemoryBuilder::new(&self.arena_name(index)).open_existing(AccessMode::ReadWrite)
+        {
+            Ok(shm) => {
+                let arena = Arena::new(shm.base_address(), size);
+                arenas[index] = Some(shm);
+                Ok(arena)
+            }
+            Err(SharedMemoryCreationError::DoesNotExist) => {
+                fail!(from self, with DynamicStorageArenaError::DoesNotExist,
+                    "{} {} since the underlying shared memory does not exist.", msg, index);
+            }
+            Err(SharedMemoryCreationError::InsufficientPermissions) => {
+                fail!(from self, with DynamicStorageArenaError::InsufficientPermissions,
+                    "{} {} due to insufficient permissions.", msg, index);
+            }
+            Err(e) => {
+                fail!(from self, with DynamicStorageArenaError::InternalError,
+                    "{} {} since the underlying shared memory could not be opened ({:?}).", msg, index, e);
+            }
+        }
+    }
+
+    fn number_of_arenas(&self) -> usize {
+        //////////////////////////////////////////
+        // SYNC POINT: read Data<T>::arena_sizes
+        //////////////////////////////////////////
+        self.management().number_of_arenas.load(Ordering::Acquire)
+    }
 }
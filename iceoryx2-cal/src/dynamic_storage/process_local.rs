@@ -74,6 +74,7 @@ struct StorageEntry {
 struct StorageDetails<T> {
     data_ptr: *mut T,
     layout: Layout,
+    arenas: std::sync::Mutex<Vec<(NonNull<u8>, Layout)>>,
 }
 
 #[derive(PartialEq, Eq, Copy, Debug)]
@@ -155,6 +156,7 @@ impl<T> StorageDetails<T> {
                 .allocate(layout), "Failed to allocate {} bytes for dynamic global storage.", size)
             .as_ptr() as *mut T,
             layout,
+            arenas: std::sync::Mutex::new(Vec::new()),
         };
         unsafe { new_self.data_ptr.write(value) };
         new_self
@@ -163,6 +165,12 @@ impl<T> StorageDetails<T> {
 
 impl<T> Drop for StorageDetails<T> {
     fn drop(&mut self) {
+        if let Ok(arenas) = self.arenas.get_mut() {
+            for (ptr, layout) in arenas.drain(..) {
+                unsafe { HeapAllocator::new().deallocate(ptr, layout) };
+            }
+        }
+
         unsafe {
             HeapAllocator::new().deallocate(
                 NonNull::new_unchecked(self.data_ptr as *mut u8),
@@ -287,6 +295,10 @@ impl<T: Send + Sync + Debug + 'static> DynamicStorage<T> for Storage<T> {
         true
     }
 
+    fn does_support_memory_protection() -> bool {
+        false
+    }
+
     fn acquire_ownership(&self) {
         self.has_ownership.store(true, Ordering::Relaxed);
     }
@@ -302,6 +314,51 @@ impl<T: Send + Sync + Debug + 'static> DynamicStorage<T> for Storage<T> {
     fn release_ownership(&self) {
         self.has_ownership.store(false, Ordering::Relaxed)
     }
+
+    fn append_arena(&self, size: usize) -> Result<usize, DynamicStorageArenaError> {
+        let msg = "Unable to append arena";
+        let mut arenas = fatal_panic!(from self, when self.data.arenas.lock(),
+            "This should never happen! {} since the arena lock is poisoned.", msg);
+
+        if MAX_NUMBER_OF_ARENAS <= arenas.len() {
+            fail!(from self, with DynamicStorageArenaError::ExceedsMaxNumberOfArenas,
+                "{} since the maximum number of {} arenas is already reached.",
+                msg, MAX_NUMBER_OF_ARENAS);
+        }
+
+        let layout = fail!(from self, when Layout::from_size_align(size.max(1), core::mem::align_of::<u64>()),
+            with DynamicStorageArenaError::InternalError,
+            "{} since the arena size of {} bytes results in an invalid layout.", msg, size);
+        let memory = fail!(from self, when HeapAllocator::new().allocate_zeroed(layout),
+            with DynamicStorageArenaError::InternalError,
+            "{} since {} bytes could not be allocated.", msg, size);
+
+        arenas.push((memory.cast(), layout));
+        Ok(arenas.len() - 1)
+    }
+
+    fn arena(&self, index: usize) -> Result<Arena, DynamicStorageArenaError> {
+        let msg = "Unable to acquire arena";
+        let arenas = fatal_panic!(from self, when self.data.arenas.lock(),
+            "This should never happen! {} {} since the arena lock is poisoned.", msg, index);
+
+        match arenas.get(index) {
+            Some((ptr, layout)) => Ok(Arena::new(*ptr, layout.size())),
+            None => {
+                fail!(from self, with DynamicStorageArenaError::DoesNotExist,
+                    "{} {} since only {} arenas exist.", msg, index, arenas.len());
+            }
+        }
+    }
+
+    fn number_of_arenas(&self) -> usize {
+        match self.data.arenas.lock() {
+            Ok(arenas) => arenas.len(),
+            Err(_) => {
+                fatal_panic!(from self, "This should never happen! Unable to acquire the number of arenas since the arena lock is poisoned.");
+            }
+        }
+    }
 }
 
 impl<T: Send + Sync + Debug + 'static> Drop for Storage<T> {
@@ -452,6 +509,14 @@ impl<'builder, T: Send + Sync + Debug + 'static> DynamicStorageBuilder<'builder,
         self
     }
 
+    fn verify_package_version(self, _value: bool) -> Self {
+        self
+    }
+
+    fn huge_pages(self, _value: Option<HugePageSize>) -> Self {
+        self
+    }
+
     fn supplementary_size(mut self, value: usize) -> Self {
         self.supplementary_size = value;
         self
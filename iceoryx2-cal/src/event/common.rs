@@ -298,6 +298,32 @@ pub mod details {
             unsafe { self.storage.get().signal_mechanism.notify()? };
             Ok(())
         }
+
+        fn notify_batch(&self, ids: &[crate::event::TriggerId]) -> Result<(), NotifierNotifyError> {
+            let msg = "Failed to notify listener with a batch of trigger ids";
+            if !self.storage.get().has_listener.load(Ordering::Relaxed) {
+                fail!(from self, with NotifierNotifyError::Disconnected,
+                    "{} since the listener is no longer connected.", msg);
+            }
+
+            let trigger_id_max = self.storage.get().id_tracker.trigger_id_max();
+            if let Some(id) = ids.iter().find(|id| trigger_id_max < **id) {
+                fail!(from self, with NotifierNotifyError::TriggerIdOutOfBounds,
+                    "{} since the TriggerId {:?} is greater than the max supported TriggerId {:?}.",
+                    msg, id, trigger_id_max);
+            }
+
+            if ids.is_empty() {
+                return Ok(());
+            }
+
+            // the ids are merged in the id tracker, the listener is woken up only once
+            for id in ids {
+                unsafe { self.storage.get().id_tracker.add(*id)? };
+            }
+            unsafe { self.storage.get().signal_mechanism.notify()? };
+            Ok(())
+        }
     }
 
     #[derive(Debug)]
@@ -533,6 +559,38 @@ pub mod details {
             unsafe { self.storage.get().signal_mechanism.blocking_wait()? };
             self.try_wait_all(callback)
         }
+
+        fn try_wait_all_with_count<F: FnMut(TriggerId, u64)>(
+            &self,
+            callback: F,
+        ) -> Result<(), crate::event::ListenerWaitError> {
+            // see try_wait_all
+            while unsafe { self.storage.get().signal_mechanism.try_wait()? } {}
+            unsafe {
+                self.storage
+                    .get()
+                    .id_tracker
+                    .acquire_all_with_count(callback)
+            };
+            Ok(())
+        }
+
+        fn timed_wait_all_with_count<F: FnMut(TriggerId, u64)>(
+            &self,
+            callback: F,
+            timeout: Duration,
+        ) -> Result<(), crate::event::ListenerWaitError> {
+            unsafe { self.storage.get().signal_mechanism.timed_wait(timeout)? };
+            self.try_wait_all_with_count(callback)
+        }
+
+        fn blocking_wait_all_with_count<F: FnMut(TriggerId, u64)>(
+            &self,
+            callback: F,
+        ) -> Result<(), crate::event::ListenerWaitError> {
+            unsafe { self.storage.get().signal_mechanism.blocking_wait()? };
+            self.try_wait_all_with_count(callback)
+        }
     }
 
     #[derive(Debug)]
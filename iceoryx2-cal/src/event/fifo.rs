@@ -0,0 +1,424 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! [`Event`] implementation based on POSIX named pipes (FIFOs). Every [`TriggerId`] is written
+//! as one atomic message into the FIFO that is owned by the [`Listener`]. An arbitrary number
+//! of [`Notifier`]s can be opened for the same [`Listener`].
+
+use core::mem::MaybeUninit;
+
+pub use crate::event::*;
+use crate::static_storage::file::{
+    NamedConceptConfiguration, NamedConceptDoesExistError, NamedConceptListError,
+    NamedConceptRemoveError,
+};
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::{
+    directory::*,
+    fifo::*,
+    file::{File, FileRemoveError},
+    file_descriptor::FileDescriptorBased,
+    file_descriptor_set::SynchronousMultiplexing,
+};
+pub use iceoryx2_bb_system_types::file_name::FileName;
+
+const MAX_BATCH_SIZE: usize = 512;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Configuration {
+    suffix: FileName,
+    prefix: FileName,
+    path: Path,
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Self {
+            path: EventImpl::default_path_hint(),
+            suffix: EventImpl::default_suffix(),
+            prefix: EventImpl::default_prefix(),
+        }
+    }
+}
+
+impl NamedConceptConfiguration for Configuration {
+    fn prefix(mut self, value: &FileName) -> Self {
+        self.prefix = *value;
+        self
+    }
+
+    fn get_prefix(&self) -> &FileName {
+        &self.prefix
+    }
+
+    fn suffix(mut self, value: &FileName) -> Self {
+        self.suffix = *value;
+        self
+    }
+
+    fn path_hint(mut self, value: &Path) -> Self {
+        self.path = *value;
+        self
+    }
+
+    fn get_suffix(&self) -> &FileName {
+        &self.suffix
+    }
+
+    fn get_path_hint(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[derive(Debug)]
+pub struct EventImpl {}
+
+impl NamedConceptMgmt for EventImpl {
+    type Configuration = Configuration;
+
+    fn does_exist_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, NamedConceptDoesExistError> {
+        let msg = format!("Unable to check if event::fifo \"{}\" exists", name);
+
+        match File::does_exist(&cfg.path_for(name)) {
+            Ok(v) => Ok(v),
+            Err(v) => {
+                fail!(from "event::fifo::EventImpl::does_exist_cfg()",
+                        with NamedConceptDoesExistError::UnderlyingResourcesCorrupted,
+                    "{} due to an internal failure ({:?}), is the event in a corrupted state?", msg, v);
+            }
+        }
+    }
+
+    fn list_cfg(cfg: &Self::Configuration) -> Result<Vec<FileName>, NamedConceptListError> {
+        let msg = "Unable to list all event::fifo";
+        let origin = "event::fifo::EventImpl::list_cfg()";
+
+        let directory = fail!(from origin, when Directory::new(&cfg.path),
+            map DirectoryOpenError::InsufficientPermissions => NamedConceptListError::InsufficientPermissions,
+            unmatched NamedConceptListError::InternalError,
+            "{} due to a failure while reading the directory (\"{}\").", msg, cfg.path);
+
+        let entries = fail!(from origin,
+                            when directory.contents(),
+                            map DirectoryReadError::InsufficientPermissions => NamedConceptListError::InsufficientPermissions,
+                            unmatched NamedConceptListError::InternalError,
+                            "{} due to a failure while reading the directory (\"{}\") contents.", msg, cfg.path);
+
+        let mut result = vec![];
+        for entry in &entries {
+            if let Some(entry_name) = cfg.extract_name_from_file(entry.name()) {
+                result.push(entry_name);
+            }
+        }
+
+        Ok(result)
+    }
+
+    unsafe fn remove_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, NamedConceptRemoveError> {
+        let msg = format!("Unable to remove event::fifo \"{}\"", name);
+        let origin = "event::fifo::EventImpl::remove_cfg()";
+
+        match File::remove(&cfg.path_for(name)) {
+            Ok(v) => Ok(v),
+            Err(FileRemoveError::InsufficientPermissions)
+            | Err(FileRemoveError::PartOfReadOnlyFileSystem) => {
+                fail!(from origin, with NamedConceptRemoveError::InsufficientPermissions,
+                        "{} due to insufficient permissions.", msg);
+            }
+            Err(v) => {
+                fail!(from origin, with NamedConceptRemoveError::InternalError,
+                        "{} due to unknown failure ({:?}).", msg, v);
+            }
+        }
+    }
+
+    fn remove_path_hint(
+        value: &Path,
+    ) -> Result<(), crate::named_concept::NamedConceptPathHintRemoveError> {
+        crate::named_concept::remove_path_hint(value)
+    }
+}
+
+impl crate::event::Event for EventImpl {
+    type Notifier = Notifier;
+    type Listener = Listener;
+    type NotifierBuilder = NotifierBuilder;
+    type ListenerBuilder = ListenerBuilder;
+}
+
+#[derive(Debug)]
+pub struct Notifier {
+    sender: FifoSender,
+    name: FileName,
+}
+
+impl NamedConcept for Notifier {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl crate::event::Notifier for Notifier {
+    fn notify(&self, id: TriggerId) -> Result<(), NotifierNotifyError> {
+        let msg = "Failed to notify event::fifo::Listener";
+        match self.sender.try_send(unsafe {
+            core::slice::from_raw_parts(
+                (&id as *const TriggerId).cast(),
+                core::mem::size_of::<TriggerId>(),
+            )
+        }) {
+            Ok(true) => Ok(()),
+            Ok(false) | Err(FifoSendError::MessagePartiallySend(_)) => {
+                fail!(from self, with NotifierNotifyError::FailedToDeliverSignal,
+                        "{} since the signal could not be delivered.", msg);
+            }
+            Err(FifoSendError::Interrupt) => {
+                fail!(from self, with NotifierNotifyError::Interrupt,
+                        "{} since an interrupt signal was received.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with NotifierNotifyError::InternalFailure,
+                        "{} due to an unknown failure ({:?}).", msg, v);
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct NotifierBuilder {
+    name: FileName,
+    config: Configuration,
+}
+
+impl NamedConceptBuilder<EventImpl> for NotifierBuilder {
+    fn new(name: &FileName) -> Self {
+        Self {
+            name: *name,
+            config: Configuration::default(),
+        }
+    }
+
+    fn config(mut self, config: &Configuration) -> Self {
+        self.config = *config;
+        self
+    }
+}
+
+impl crate::event::NotifierBuilder<EventImpl> for NotifierBuilder {
+    fn timeout(self, _timeout: Duration) -> Self {
+        self
+    }
+
+    fn open(self) -> Result<Notifier, NotifierCreateError> {
+        let msg = "Failed to open event::fifo::Notifier";
+
+        let full_name = self.config.path_for(&self.name);
+        match FifoSenderBuilder::new(&full_name).create() {
+            Ok(sender) => Ok(Notifier {
+                sender,
+                name: self.name,
+            }),
+            Err(FifoSenderCreationError::DoesNotExist) => {
+                fail!(from self, with NotifierCreateError::DoesNotExist,
+                    "{} since the corresponding listener does not exist.", msg);
+            }
+            Err(FifoSenderCreationError::InsufficientPermissions) => {
+                fail!(from self, with NotifierCreateError::InsufficientPermissions,
+                    "{} due to insufficient permissions.", msg);
+            }
+            Err(FifoSenderCreationError::Interrupt) => {
+                fail!(from self, with NotifierCreateError::Interrupt,
+                    "{} since an interrupt signal was received.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with NotifierCreateError::InternalFailure,
+                    "{} due to an unknown failure ({:?}).", msg, v);
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Listener {
+    receiver: FifoReceiver,
+    name: FileName,
+}
+
+impl FileDescriptorBased for Listener {
+    fn file_descriptor(&self) -> &iceoryx2_bb_posix::file_descriptor::FileDescriptor {
+        self.receiver.file_descriptor()
+    }
+}
+
+impl SynchronousMultiplexing for Listener {}
+
+impl NamedConcept for Listener {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl Listener {
+    fn wait<F: FnMut(&Self, &mut [u8]) -> Result<usize, FifoReceiveError>>(
+        &self,
+        error_msg: &str,
+        mut wait_call: F,
+    ) -> Result<Option<TriggerId>, ListenerWaitError> {
+        let mut id_buffer = MaybeUninit::uninit();
+        match wait_call(self, unsafe {
+            core::slice::from_raw_parts_mut(
+                id_buffer.as_mut_ptr() as *mut u8,
+                core::mem::size_of::<TriggerId>(),
+            )
+        }) {
+            Ok(0) => Ok(None),
+            Ok(v) => {
+                if v != core::mem::size_of::<TriggerId>() {
+                    fail!(from self, with ListenerWaitError::ContractViolation,
+                        "{} since the expected amount of received bytes {} does not match the expected amount of bytes {}.",
+                        error_msg, v, core::mem::size_of::<TriggerId>());
+                }
+                Ok(Some(unsafe { id_buffer.assume_init() }))
+            }
+            Err(FifoReceiveError::Interrupt) => {
+                fail!(from self, with ListenerWaitError::InterruptSignal,
+                    "{} since an interrupt signal was received.", error_msg);
+            }
+            Err(v) => {
+                fail!(from self, with ListenerWaitError::InternalFailure,
+                    "{} due to an unknown failure ({:?}).", error_msg, v);
+            }
+        }
+    }
+}
+
+impl crate::event::Listener for Listener {
+    fn try_wait_one(&self) -> Result<Option<TriggerId>, ListenerWaitError> {
+        self.wait(
+            "Unable to try wait for signal on event::fifo::Listener",
+            |this, buffer| this.receiver.try_receive(buffer),
+        )
+    }
+
+    fn timed_wait_one(
+        &self,
+        timeout: core::time::Duration,
+    ) -> Result<Option<TriggerId>, ListenerWaitError> {
+        self.wait(
+            &format!(
+                "Unable to wait for signal with timeout {:?} on event::fifo::Listener",
+                timeout
+            ),
+            |this, buffer| this.receiver.timed_receive(buffer, timeout),
+        )
+    }
+
+    fn blocking_wait_one(&self) -> Result<Option<TriggerId>, ListenerWaitError> {
+        self.wait(
+            "Unable to blocking wait for signal on event::fifo::Listener",
+            |this, buffer| this.receiver.blocking_receive(buffer),
+        )
+    }
+
+    fn try_wait_all<F: FnMut(TriggerId)>(&self, mut callback: F) -> Result<(), ListenerWaitError> {
+        let mut counter = 0;
+        while let Some(id) = self.try_wait_one()? {
+            callback(id);
+
+            counter += 1;
+            if counter == MAX_BATCH_SIZE {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn timed_wait_all<F: FnMut(TriggerId)>(
+        &self,
+        mut callback: F,
+        timeout: Duration,
+    ) -> Result<(), ListenerWaitError> {
+        if let Some(id) = self.timed_wait_one(timeout)? {
+            callback(id);
+        }
+        self.try_wait_all(callback)
+    }
+
+    fn blocking_wait_all<F: FnMut(TriggerId)>(
+        &self,
+        mut callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        if let Some(id) = self.blocking_wait_one()? {
+            callback(id);
+        }
+        self.try_wait_all(callback)
+    }
+}
+
+#[derive(Debug)]
+pub struct ListenerBuilder {
+    name: FileName,
+    config: Configuration,
+}
+
+impl NamedConceptBuilder<EventImpl> for ListenerBuilder {
+    fn new(name: &FileName) -> Self {
+        Self {
+            name: *name,
+            config: Configuration::default(),
+        }
+    }
+
+    fn config(mut self, config: &Configuration) -> Self {
+        self.config = *config;
+        self
+    }
+}
+
+impl crate::event::ListenerBuilder<EventImpl> for ListenerBuilder {
+    fn trigger_id_max(self, _id: TriggerId) -> Self {
+        self
+    }
+
+    fn create(self) -> Result<Listener, ListenerCreateError> {
+        let msg = "Failed to create event::fifo::Listener";
+        let full_name = self.config.path_for(&self.name);
+        match FifoReceiverBuilder::new(&full_name)
+            .creation_mode(CreationMode::CreateExclusive)
+            .create()
+        {
+            Ok(r) => Ok(Listener {
+                receiver: r,
+                name: self.name,
+            }),
+            Err(FifoReceiverCreationError::FifoAlreadyExists) => {
+                fail!(from self, with ListenerCreateError::AlreadyExists,
+                            "{} since the underlying fifo already exists.", msg);
+            }
+            Err(FifoReceiverCreationError::InsufficientPermissions) => {
+                fail!(from self, with ListenerCreateError::InsufficientPermissions,
+                            "{} due insufficient permissions.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with ListenerCreateError::InternalFailure,
+                            "{} due to an unknown failure ({:?}).", msg, v);
+            }
+        }
+    }
+}
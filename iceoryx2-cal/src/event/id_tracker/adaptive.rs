@@ -0,0 +1,112 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! An [`IdTracker`] that selects the tracking mode depending on the [`TriggerId`] range.
+//! Small ranges are tracked with a [`RelocatableBitSet`], huge ranges with a
+//! [`RelocatableSparseIdTracker`] so that the memory consumption does not grow with the max
+//! [`TriggerId`].
+
+use iceoryx2_bb_elementary::{
+    allocator::{AllocationError, BaseAllocator},
+    relocatable_container::RelocatableContainer,
+};
+use iceoryx2_bb_lock_free::mpmc::bit_set::RelocatableBitSet;
+
+use super::{
+    sparse::{RelocatableSparseIdTracker, SPARSE_ID_TRACKER_MAX_NUMBER_OF_SLOTS},
+    IdTracker,
+};
+use crate::event::{NotifierNotifyError, TriggerId};
+
+/// Capacity above which the [`RelocatableAdaptiveIdTracker`] switches to the sparse tracking
+/// mode. It is the capacity where a bitset starts to consume more memory than the sparse
+/// tracker.
+pub const SPARSE_ID_TRACKING_THRESHOLD: usize = SPARSE_ID_TRACKER_MAX_NUMBER_OF_SLOTS
+    * (core::mem::size_of::<usize>() + core::mem::size_of::<u64>())
+    * 8;
+
+/// Tracks [`TriggerId`]s with a [`RelocatableBitSet`] when the capacity is less or equal
+/// [`SPARSE_ID_TRACKING_THRESHOLD`] and with a [`RelocatableSparseIdTracker`] otherwise.
+#[derive(Debug)]
+#[repr(C)]
+pub enum RelocatableAdaptiveIdTracker {
+    Dense(RelocatableBitSet),
+    Sparse(RelocatableSparseIdTracker),
+}
+
+impl RelocatableAdaptiveIdTracker {
+    fn is_sparse(capacity: usize) -> bool {
+        SPARSE_ID_TRACKING_THRESHOLD < capacity
+    }
+}
+
+impl RelocatableContainer for RelocatableAdaptiveIdTracker {
+    unsafe fn new_uninit(capacity: usize) -> Self {
+        if Self::is_sparse(capacity) {
+            Self::Sparse(RelocatableSparseIdTracker::new_uninit(capacity))
+        } else {
+            Self::Dense(RelocatableBitSet::new_uninit(capacity))
+        }
+    }
+
+    unsafe fn init<T: BaseAllocator>(&mut self, allocator: &T) -> Result<(), AllocationError> {
+        match self {
+            Self::Dense(v) => v.init(allocator),
+            Self::Sparse(v) => v.init(allocator),
+        }
+    }
+
+    fn memory_size(capacity: usize) -> usize {
+        if Self::is_sparse(capacity) {
+            RelocatableSparseIdTracker::memory_size(capacity)
+        } else {
+            RelocatableBitSet::memory_size(capacity)
+        }
+    }
+}
+
+impl IdTracker for RelocatableAdaptiveIdTracker {
+    fn trigger_id_max(&self) -> TriggerId {
+        match self {
+            Self::Dense(v) => v.trigger_id_max(),
+            Self::Sparse(v) => v.trigger_id_max(),
+        }
+    }
+
+    unsafe fn add(&self, id: TriggerId) -> Result<(), NotifierNotifyError> {
+        match self {
+            Self::Dense(v) => v.add(id),
+            Self::Sparse(v) => v.add(id),
+        }
+    }
+
+    unsafe fn acquire(&self) -> Option<TriggerId> {
+        match self {
+            Self::Dense(v) => v.acquire(),
+            Self::Sparse(v) => v.acquire(),
+        }
+    }
+
+    unsafe fn acquire_all<F: FnMut(TriggerId)>(&self, callback: F) {
+        match self {
+            Self::Dense(v) => v.acquire_all(callback),
+            Self::Sparse(v) => v.acquire_all(callback),
+        }
+    }
+
+    unsafe fn acquire_all_with_count<F: FnMut(TriggerId, u64)>(&self, callback: F) {
+        match self {
+            Self::Dense(v) => v.acquire_all_with_count(callback),
+            Self::Sparse(v) => v.acquire_all_with_count(callback),
+        }
+    }
+}
@@ -10,7 +10,9 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+pub mod adaptive;
 pub mod bit_set;
+pub mod sparse;
 
 use core::fmt::Debug;
 
@@ -48,4 +50,16 @@ pub trait IdTracker: RelocatableContainer + Send + Sync + Debug {
     ///  * underlying container must be initialized with [`RelocatableContainer::init()`]
     ///
     unsafe fn acquire_all<F: FnMut(TriggerId)>(&self, callback: F);
+
+    /// Acquires all tracked [`TriggerId`]s and calls for everyone the user
+    /// provided callback with the [`TriggerId`] and the number of times it was added since
+    /// it was acquired the last time. [`IdTracker`]s that store only a flag per [`TriggerId`]
+    /// report a count of 1.
+    ///
+    /// # Safety
+    ///  * underlying container must be initialized with [`RelocatableContainer::init()`]
+    ///
+    unsafe fn acquire_all_with_count<F: FnMut(TriggerId, u64)>(&self, mut callback: F) {
+        self.acquire_all(|id| callback(id, 1))
+    }
 }
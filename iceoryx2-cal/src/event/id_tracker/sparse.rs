@@ -0,0 +1,209 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! An [`IdTracker`] that stores the tracked [`TriggerId`]s in a fixed size, lock-free hash set.
+//! Its memory consumption depends only on the number of ids that can be pending at the same
+//! time and not on the max value of a [`TriggerId`]. It is therefore used for huge
+//! [`TriggerId`] ranges where a bitset would consume a lot of memory.
+//!
+//! A [`TriggerId`] that is added while it is concurrently acquired can be reported twice.
+//! Every slot counts how often its [`TriggerId`] was added, a notification that races with
+//! the acquisition of the slot can be attributed to the next acquisition.
+
+use core::{alloc::Layout, sync::atomic::Ordering};
+
+use iceoryx2_bb_elementary::{
+    allocator::{AllocationError, BaseAllocator},
+    math::unaligned_mem_size,
+    relocatable_container::RelocatableContainer,
+    relocatable_ptr::{PointerTrait, RelocatablePointer},
+};
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64, IoxAtomicUsize};
+
+use super::IdTracker;
+use crate::event::{NotifierNotifyError, TriggerId};
+
+/// The maximum number of [`TriggerId`]s that can be pending in a [`RelocatableSparseIdTracker`]
+/// at the same time.
+pub const SPARSE_ID_TRACKER_MAX_NUMBER_OF_SLOTS: usize = 8192;
+
+const EMPTY_SLOT: usize = 0;
+const FIBONACCI_HASH_MULTIPLIER: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// The [`RelocatableSparseIdTracker`] can be stored in shared memory and tracks at most
+/// [`SPARSE_ID_TRACKER_MAX_NUMBER_OF_SLOTS`] different pending [`TriggerId`]s.
+#[derive(Debug)]
+#[repr(C)]
+pub struct RelocatableSparseIdTracker {
+    data_ptr: RelocatablePointer<IoxAtomicUsize>,
+    counter_ptr: RelocatablePointer<IoxAtomicU64>,
+    capacity: usize,
+    number_of_slots: usize,
+    acquire_position: IoxAtomicUsize,
+    is_memory_initialized: IoxAtomicBool,
+}
+
+unsafe impl Send for RelocatableSparseIdTracker {}
+unsafe impl Sync for RelocatableSparseIdTracker {}
+
+impl RelocatableContainer for RelocatableSparseIdTracker {
+    unsafe fn new_uninit(capacity: usize) -> Self {
+        Self {
+            data_ptr: RelocatablePointer::new_uninit(),
+            counter_ptr: RelocatablePointer::new_uninit(),
+            capacity,
+            number_of_slots: Self::number_of_slots(capacity),
+            acquire_position: IoxAtomicUsize::new(0),
+            is_memory_initialized: IoxAtomicBool::new(false),
+        }
+    }
+
+    unsafe fn init<T: BaseAllocator>(&mut self, allocator: &T) -> Result<(), AllocationError> {
+        if self.is_memory_initialized.load(Ordering::Relaxed) {
+            fatal_panic!(from self,
+                "Memory already initialized. Initializing it twice may lead to undefined behavior.");
+        }
+
+        let memory = fail!(from self, when allocator
+            .allocate(Layout::from_size_align_unchecked(
+                    core::mem::size_of::<IoxAtomicUsize>() * self.number_of_slots,
+                    core::mem::align_of::<IoxAtomicUsize>())),
+            "Failed to initialize since the allocation of the data memory failed.");
+
+        self.data_ptr.init(memory);
+
+        let counter_memory = fail!(from self, when allocator
+            .allocate(Layout::from_size_align_unchecked(
+                    core::mem::size_of::<IoxAtomicU64>() * self.number_of_slots,
+                    core::mem::align_of::<IoxAtomicU64>())),
+            "Failed to initialize since the allocation of the counter memory failed.");
+
+        self.counter_ptr.init(counter_memory);
+
+        for i in 0..self.number_of_slots {
+            (self.data_ptr.as_ptr() as *mut IoxAtomicUsize)
+                .add(i)
+                .write(IoxAtomicUsize::new(EMPTY_SLOT));
+            (self.counter_ptr.as_ptr() as *mut IoxAtomicU64)
+                .add(i)
+                .write(IoxAtomicU64::new(0));
+        }
+
+        self.is_memory_initialized.store(true, Ordering::Relaxed);
+
+        Ok(())
+    }
+
+    fn memory_size(capacity: usize) -> usize {
+        unaligned_mem_size::<IoxAtomicUsize>(Self::number_of_slots(capacity))
+            + unaligned_mem_size::<IoxAtomicU64>(Self::number_of_slots(capacity))
+    }
+}
+
+impl RelocatableSparseIdTracker {
+    fn number_of_slots(capacity: usize) -> usize {
+        capacity.clamp(1, SPARSE_ID_TRACKER_MAX_NUMBER_OF_SLOTS)
+    }
+
+    fn slot(&self, index: usize) -> &IoxAtomicUsize {
+        debug_assert!(
+            self.is_memory_initialized.load(Ordering::Relaxed),
+            "Undefined behavior when using the RelocatableSparseIdTracker before it is initialized."
+        );
+        unsafe { &*self.data_ptr.as_ptr().add(index) }
+    }
+
+    fn counter(&self, index: usize) -> &IoxAtomicU64 {
+        unsafe { &*self.counter_ptr.as_ptr().add(index) }
+    }
+
+    fn start_index(&self, id: TriggerId) -> usize {
+        let hash = (id.as_value() as u64).wrapping_mul(FIBONACCI_HASH_MULTIPLIER) >> 32;
+        hash as usize % self.number_of_slots
+    }
+
+    fn take(&self, index: usize) -> Option<(TriggerId, u64)> {
+        let slot = self.slot(index);
+        if slot.load(Ordering::Relaxed) == EMPTY_SLOT {
+            return None;
+        }
+
+        let count = self.counter(index).swap(0, Ordering::Relaxed);
+        match slot.swap(EMPTY_SLOT, Ordering::Relaxed) {
+            EMPTY_SLOT => None,
+            value => Some((TriggerId::new(value - 1), count.max(1))),
+        }
+    }
+}
+
+impl IdTracker for RelocatableSparseIdTracker {
+    fn trigger_id_max(&self) -> TriggerId {
+        TriggerId::new(self.capacity - 1)
+    }
+
+    unsafe fn add(&self, id: TriggerId) -> Result<(), NotifierNotifyError> {
+        if self.trigger_id_max() < id {
+            fail!(from self, with NotifierNotifyError::TriggerIdOutOfBounds,
+                "Unable to track {:?} since it is out of bounds (max = {:?}).",
+                id, self.trigger_id_max());
+        }
+
+        let value = id.as_value() + 1;
+        let start_index = self.start_index(id);
+        for n in 0..self.number_of_slots {
+            let index = (start_index + n) % self.number_of_slots;
+            let slot = self.slot(index);
+            match slot.compare_exchange(EMPTY_SLOT, value, Ordering::Relaxed, Ordering::Relaxed) {
+                Ok(_) => {
+                    self.counter(index).fetch_add(1, Ordering::Relaxed);
+                    return Ok(());
+                }
+                Err(current) if current == value => {
+                    self.counter(index).fetch_add(1, Ordering::Relaxed);
+                    return Ok(());
+                }
+                Err(_) => (),
+            }
+        }
+
+        fail!(from self, with NotifierNotifyError::TooManyPendingTriggerIds,
+            "Unable to track {:?} since already {} trigger ids are pending.",
+            id, self.number_of_slots);
+    }
+
+    unsafe fn acquire(&self) -> Option<TriggerId> {
+        let start_index = self.acquire_position.load(Ordering::Relaxed);
+        for n in 0..self.number_of_slots {
+            let index = (start_index + n) % self.number_of_slots;
+            if let Some((id, _)) = self.take(index) {
+                self.acquire_position
+                    .store((index + 1) % self.number_of_slots, Ordering::Relaxed);
+                return Some(id);
+            }
+        }
+
+        None
+    }
+
+    unsafe fn acquire_all<F: FnMut(TriggerId)>(&self, mut callback: F) {
+        self.acquire_all_with_count(|id, _| callback(id))
+    }
+
+    unsafe fn acquire_all_with_count<F: FnMut(TriggerId, u64)>(&self, mut callback: F) {
+        for index in 0..self.number_of_slots {
+            if let Some((id, count)) = self.take(index) {
+                callback(id, count);
+            }
+        }
+    }
+}
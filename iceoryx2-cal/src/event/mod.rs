@@ -11,6 +11,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 pub mod common;
+pub mod fifo;
 pub mod id_tracker;
 pub mod process_local_socketpair;
 pub mod sem_bitset_posix_shared_memory;
@@ -29,6 +30,7 @@ pub enum NotifierNotifyError {
     Interrupt,
     FailedToDeliverSignal,
     TriggerIdOutOfBounds,
+    TooManyPendingTriggerIds,
     Disconnected,
     InternalFailure,
 }
@@ -107,6 +109,15 @@ pub trait Notifier: NamedConcept + Debug {
         TriggerId::new(usize::MAX)
     }
     fn notify(&self, id: TriggerId) -> Result<(), NotifierNotifyError>;
+
+    /// Notifies the [`Listener`] with all provided [`TriggerId`]s. Implementations that track
+    /// the pending [`TriggerId`]s merge them and wake up the [`Listener`] only once.
+    fn notify_batch(&self, ids: &[TriggerId]) -> Result<(), NotifierNotifyError> {
+        for id in ids {
+            self.notify(*id)?;
+        }
+        Ok(())
+    }
 }
 
 pub trait NotifierBuilder<T: Event>: NamedConceptBuilder<T> + Debug {
@@ -126,6 +137,56 @@ pub trait Listener: NamedConcept + Debug {
         timeout: Duration,
     ) -> Result<(), ListenerWaitError>;
     fn blocking_wait_all<F: FnMut(TriggerId)>(&self, callback: F) -> Result<(), ListenerWaitError>;
+
+    /// Like [`Listener::try_wait_all()`] but calls the callback once per [`TriggerId`] with the
+    /// number of times it was notified since the last wait.
+    fn try_wait_all_with_count<F: FnMut(TriggerId, u64)>(
+        &self,
+        callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        merge_duplicates(|c| self.try_wait_all(c), callback)
+    }
+
+    /// Like [`Listener::timed_wait_all()`] but calls the callback once per [`TriggerId`] with
+    /// the number of times it was notified since the last wait.
+    fn timed_wait_all_with_count<F: FnMut(TriggerId, u64)>(
+        &self,
+        callback: F,
+        timeout: Duration,
+    ) -> Result<(), ListenerWaitError> {
+        merge_duplicates(|c| self.timed_wait_all(c, timeout), callback)
+    }
+
+    /// Like [`Listener::blocking_wait_all()`] but calls the callback once per [`TriggerId`] with
+    /// the number of times it was notified since the last wait.
+    fn blocking_wait_all_with_count<F: FnMut(TriggerId, u64)>(
+        &self,
+        callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        merge_duplicates(|c| self.blocking_wait_all(c), callback)
+    }
+}
+
+// implementations that deliver every notification separately report a TriggerId once per
+// notification, the duplicates are merged into a count
+fn merge_duplicates<
+    Wait: FnOnce(&mut dyn FnMut(TriggerId)) -> Result<(), ListenerWaitError>,
+    F: FnMut(TriggerId, u64),
+>(
+    wait: Wait,
+    mut callback: F,
+) -> Result<(), ListenerWaitError> {
+    let mut counts: Vec<(TriggerId, u64)> = vec![];
+    wait(&mut |id| match counts.iter_mut().find(|(v, _)| *v == id) {
+        Some((_, count)) => *count += 1,
+        None => counts.push((id, 1)),
+    })?;
+
+    for (id, count) in counts {
+        callback(id, count);
+    }
+
+    Ok(())
 }
 
 pub trait ListenerBuilder<T: Event>: NamedConceptBuilder<T> + Debug {
@@ -13,8 +13,11 @@
 use crate::dynamic_storage::posix_shared_memory::Storage;
 use crate::event::common::details::EventImpl;
 use crate::event::common::details::Management;
+use crate::event::id_tracker::adaptive::RelocatableAdaptiveIdTracker;
 use crate::event::signal_mechanism::semaphore::Semaphore;
-use iceoryx2_bb_lock_free::mpmc::bit_set::RelocatableBitSet;
 
-pub type Event =
-    EventImpl<RelocatableBitSet, Semaphore, Storage<Management<RelocatableBitSet, Semaphore>>>;
+pub type Event = EventImpl<
+    RelocatableAdaptiveIdTracker,
+    Semaphore,
+    Storage<Management<RelocatableAdaptiveIdTracker, Semaphore>>,
+>;
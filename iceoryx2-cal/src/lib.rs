@@ -20,6 +20,7 @@ pub mod event;
 pub mod hash;
 pub mod monitoring;
 pub mod named_concept;
+pub mod named_concept_owner;
 pub mod reactor;
 pub mod resizable_shared_memory;
 pub mod serialize;
@@ -27,6 +28,7 @@ pub mod shared_memory;
 pub mod shared_memory_directory;
 pub mod shm_allocator;
 pub mod static_storage;
+pub mod zero_copy_broadcast;
 pub mod zero_copy_connection;
 
 #[doc(hidden)]
@@ -10,8 +10,11 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::time::Duration;
+
 use iceoryx2_bb_log::fail;
 use iceoryx2_bb_posix::{
+    clock::{ClockType, Time},
     directory::{Directory, DirectoryOpenError, DirectoryReadError},
     file::{File, FileRemoveError},
     file_type::FileType,
@@ -33,7 +36,8 @@ use crate::{
 
 use super::{
     testing::__InternalMonitoringTokenTestable, Monitoring, MonitoringBuilder, MonitoringCleaner,
-    MonitoringCreateTokenError, MonitoringMonitor, MonitoringStateError, MonitoringToken,
+    MonitoringCreateTokenError, MonitoringMonitor, MonitoringRefreshHeartbeatError,
+    MonitoringStateError, MonitoringToken,
 };
 
 #[derive(Debug)]
@@ -162,7 +166,20 @@ impl NamedConcept for Token {
     }
 }
 
-impl MonitoringToken for Token {}
+impl MonitoringToken for Token {
+    fn refresh_heartbeat(&mut self) -> Result<(), MonitoringRefreshHeartbeatError> {
+        let msg = "Unable to refresh heartbeat";
+        let now = fail!(from self, when Time::now_with_clock(ClockType::Monotonic),
+            with MonitoringRefreshHeartbeatError::InternalError,
+            "{} since the current time could not be acquired.", msg);
+
+        fail!(from self, when self.guard.write_heartbeat(now.as_duration()),
+            with MonitoringRefreshHeartbeatError::InternalError,
+            "{} since the heartbeat could not be written.", msg);
+
+        Ok(())
+    }
+}
 
 impl __InternalMonitoringTokenTestable for Token {
     fn staged_death(self) {
@@ -202,6 +219,29 @@ impl MonitoringMonitor for Monitor {
             }
         }
     }
+
+    fn time_since_last_heartbeat(&self) -> Result<Option<Duration>, MonitoringStateError> {
+        let msg = "Unable to acquire time since last heartbeat";
+
+        let heartbeat = match self.monitor.heartbeat() {
+            Ok(Some(heartbeat)) => heartbeat,
+            Ok(None) => return Ok(None),
+            Err(ProcessMonitorStateError::Interrupt) => {
+                fail!(from self, with MonitoringStateError::Interrupt,
+                    "{} since an interrupt signal was received.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with MonitoringStateError::InternalError,
+                    "{} since the heartbeat could not be read ({:?}).", msg, v);
+            }
+        };
+
+        let now = fail!(from self, when Time::now_with_clock(ClockType::Monotonic),
+            with MonitoringStateError::InternalError,
+            "{} since the current time could not be acquired.", msg);
+
+        Ok(Some(now.as_duration().saturating_sub(heartbeat)))
+    }
 }
 
 #[derive(Debug)]
@@ -70,6 +70,7 @@
 //! ```
 
 use core::fmt::Debug;
+use core::time::Duration;
 
 pub use iceoryx2_bb_container::semantic_string::SemanticString;
 pub use iceoryx2_bb_system_types::file_name::FileName;
@@ -129,8 +130,20 @@ pub enum MonitoringStateError {
     InternalError,
 }
 
+/// Represents the possible errors that can occur when the heartbeat is refreshed via
+/// [`MonitoringToken::refresh_heartbeat()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonitoringRefreshHeartbeatError {
+    InternalError,
+}
+
 /// The token enables a process to be monitored by another process.
-pub trait MonitoringToken: NamedConcept {}
+pub trait MonitoringToken: NamedConcept {
+    /// Stores the current time as heartbeat so that a [`MonitoringMonitor`] can detect with
+    /// [`MonitoringMonitor::time_since_last_heartbeat()`] whether the process still makes
+    /// progress.
+    fn refresh_heartbeat(&mut self) -> Result<(), MonitoringRefreshHeartbeatError>;
+}
 
 /// The cleaner owns the remains of a dead process and is the only one that is allowed to clean up
 /// those resources.
@@ -146,6 +159,11 @@ pub trait MonitoringMonitor: NamedConcept {
     /// Returns the current [`State`] of the monitored process. On failure it returns
     /// [`MonitoringStateError`].
     fn state(&self) -> Result<State, MonitoringStateError>;
+
+    /// Returns the time that has passed since the monitored process called
+    /// [`MonitoringToken::refresh_heartbeat()`] the last time. If the heartbeat was never
+    /// refreshed or the process does not exist it returns [`None`].
+    fn time_since_last_heartbeat(&self) -> Result<Option<Duration>, MonitoringStateError>;
 }
 
 /// Creates either a [`MonitoringToken`] or instantiates a [`MonitoringMonitor`] that can monitor
@@ -10,15 +10,20 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use std::collections::HashSet;
+use core::time::Duration;
+use std::collections::HashMap;
 
 use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_bb_posix::clock::{ClockType, Time};
 use iceoryx2_bb_posix::mutex::*;
 use iceoryx2_bb_system_types::{file_name::FileName, file_path::FilePath, path::Path};
 use once_cell::sync::Lazy;
 
 use crate::{
-    monitoring::{MonitoringCreateCleanerError, MonitoringCreateTokenError, MonitoringStateError},
+    monitoring::{
+        MonitoringCreateCleanerError, MonitoringCreateTokenError, MonitoringRefreshHeartbeatError,
+        MonitoringStateError,
+    },
     named_concept::NamedConceptConfiguration,
 };
 
@@ -27,11 +32,13 @@ use super::{
     NamedConcept, NamedConceptBuilder, NamedConceptMgmt, State,
 };
 
-static PROCESS_LOCAL_MTX_HANDLE: Lazy<MutexHandle<HashSet<FilePath>>> = Lazy::new(MutexHandle::new);
-static PROCESS_LOCAL_STORAGE: Lazy<Mutex<HashSet<FilePath>>> = Lazy::new(|| {
+type HeartbeatMap = HashMap<FilePath, Option<Duration>>;
+
+static PROCESS_LOCAL_MTX_HANDLE: Lazy<MutexHandle<HeartbeatMap>> = Lazy::new(MutexHandle::new);
+static PROCESS_LOCAL_STORAGE: Lazy<Mutex<HeartbeatMap>> = Lazy::new(|| {
     let result = MutexBuilder::new()
         .is_interprocess_capable(false)
-        .create(HashSet::new(), &PROCESS_LOCAL_MTX_HANDLE);
+        .create(HashMap::new(), &PROCESS_LOCAL_MTX_HANDLE);
 
     if result.is_err() {
         fatal_panic!(from "PROCESS_LOCAL_STORAGE", "Failed to create global monitoring storage");
@@ -103,7 +110,7 @@ impl NamedConceptMgmt for ProcessLocalMonitoring {
                                 "{} since the lock could not be acquired.", msg);
 
         let mut result = vec![];
-        for storage_name in guard.iter() {
+        for storage_name in guard.keys() {
             if let Some(v) = cfg.extract_name_from_path(storage_name) {
                 result.push(v);
             }
@@ -143,7 +150,7 @@ impl NamedConceptMgmt for ProcessLocalMonitoring {
                 "{} since the lock could not be acquired.", msg);
         }
 
-        Ok(guard.unwrap().remove(&storage_name))
+        Ok(guard.unwrap().remove(&storage_name).is_some())
     }
 
     fn remove_path_hint(
@@ -187,7 +194,29 @@ impl NamedConcept for Token {
     }
 }
 
-impl MonitoringToken for Token {}
+impl MonitoringToken for Token {
+    fn refresh_heartbeat(&mut self) -> Result<(), MonitoringRefreshHeartbeatError> {
+        let msg = "Unable to refresh heartbeat";
+        let now = fail!(from self, when Time::now_with_clock(ClockType::Monotonic),
+            with MonitoringRefreshHeartbeatError::InternalError,
+            "{} since the current time could not be acquired.", msg);
+
+        let mut guard = fail!(from self, when PROCESS_LOCAL_STORAGE.lock(),
+            with MonitoringRefreshHeartbeatError::InternalError,
+            "{} due to a failure while acquiring the lock.", msg);
+
+        match guard.get_mut(&self.config.path_for(&self.name)) {
+            Some(heartbeat) => {
+                *heartbeat = Some(now.as_duration());
+                Ok(())
+            }
+            None => {
+                fail!(from self, with MonitoringRefreshHeartbeatError::InternalError,
+                    "{} since the entry does not exist anymore.", msg);
+            }
+        }
+    }
+}
 
 impl Drop for Token {
     fn drop(&mut self) {
@@ -197,7 +226,7 @@ impl Drop for Token {
             "{} due to a failure while acquiring the lock.", msg);
 
         let full_name = self.config.path_for(&self.name);
-        if !guard.remove(&full_name) {
+        if guard.remove(&full_name).is_none() {
             fatal_panic!(from self,
                 "{} since the entry was not existing anymore. This should never happen!", msg);
         }
@@ -229,6 +258,27 @@ impl MonitoringMonitor for Monitor {
             None => Ok(State::DoesNotExist),
         }
     }
+
+    fn time_since_last_heartbeat(&self) -> Result<Option<Duration>, MonitoringStateError> {
+        let msg = "Failed to acquire time since last heartbeat";
+
+        let heartbeat = {
+            let guard = fail!(from self, when PROCESS_LOCAL_STORAGE.lock(),
+                with MonitoringStateError::InternalError,
+                "{} due to a failure while acquiring the lock.", msg);
+
+            match guard.get(&self.full_name) {
+                Some(Some(heartbeat)) => *heartbeat,
+                Some(None) | None => return Ok(None),
+            }
+        };
+
+        let now = fail!(from self, when Time::now_with_clock(ClockType::Monotonic),
+            with MonitoringStateError::InternalError,
+            "{} since the current time could not be acquired.", msg);
+
+        Ok(Some(now.as_duration().saturating_sub(heartbeat)))
+    }
 }
 
 #[derive(Debug)]
@@ -266,12 +316,12 @@ impl MonitoringBuilder<ProcessLocalMonitoring> for Builder {
             "{} due to a failure while acquiring the lock.", msg);
 
         let full_name = self.config.path_for(&self.name);
-        if guard.contains(&full_name) {
+        if guard.contains_key(&full_name) {
             fail!(from self, with MonitoringCreateTokenError::AlreadyExists,
                 "{} since the token already exists.", msg);
         }
 
-        guard.insert(full_name);
+        guard.insert(full_name, None);
 
         Ok(Token {
             name: self.name,
@@ -19,6 +19,8 @@ pub use iceoryx2_bb_system_types::file_name::FileName;
 pub use iceoryx2_bb_system_types::file_path::FilePath;
 pub use iceoryx2_bb_system_types::path::Path;
 
+use crate::named_concept_owner::{list_owners, NamedConceptOwner, NamedConceptOwnerListError};
+
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum NamedConceptDoesExistError {
     InsufficientPermissions,
@@ -185,6 +187,15 @@ pub trait NamedConceptMgmt: Debug {
     /// Returns a list of all available concepts with a custom configuration.
     fn list_cfg(cfg: &Self::Configuration) -> Result<Vec<FileName>, NamedConceptListError>;
 
+    /// Returns all processes that currently have the concept under a custom configuration open
+    /// or mapped, see [`crate::named_concept_owner`].
+    fn list_owners_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<Vec<NamedConceptOwner>, NamedConceptOwnerListError> {
+        list_owners(&cfg.path_for(name))
+    }
+
     /// The default prefix of every zero copy connection
     fn default_prefix() -> FileName {
         unsafe { FileName::new_unchecked(b"iox2_") }
@@ -0,0 +1,188 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Reports which processes currently have the underlying resource of a
+//! [`NamedConcept`](crate::named_concept::NamedConcept) open or mapped. It is a debug tool to
+//! find out why the resources of a stuck service or node are not cleaned up.
+//!
+//! On Linux, the file descriptors in `/proc/<pid>/fd` and the memory mappings in
+//! `/proc/<pid>/maps` of every process are scanned. Processes whose entries are not
+//! accessible, for instance since they belong to another user, are skipped.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//! use iceoryx2_cal::named_concept::*;
+//! use iceoryx2_cal::named_concept_owner::list_owners;
+//!
+//! let path = FilePath::new(b"/dev/shm/iox2_some_segment").unwrap();
+//! if let Ok(owners) = list_owners(&path) {
+//!     for owner in owners {
+//!         println!("{:?}", owner);
+//!     }
+//! }
+//! ```
+
+use iceoryx2_bb_posix::process::ProcessId;
+use iceoryx2_bb_system_types::file_path::FilePath;
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum NamedConceptOwnerListError {
+    InsufficientPermissions,
+    UnsupportedPlatform,
+    InternalError,
+}
+
+impl core::fmt::Display for NamedConceptOwnerListError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "NamedConceptOwnerListError::{:?}", self)
+    }
+}
+
+impl core::error::Error for NamedConceptOwnerListError {}
+
+/// A process that has the underlying resource of a
+/// [`NamedConcept`](crate::named_concept::NamedConcept) open or mapped.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct NamedConceptOwner {
+    pid: ProcessId,
+    executable: Option<String>,
+    has_open_handle: bool,
+    has_mapping: bool,
+}
+
+impl NamedConceptOwner {
+    /// Returns the [`ProcessId`] of the owning process.
+    pub fn pid(&self) -> ProcessId {
+        self.pid
+    }
+
+    /// Returns the path to the executable of the owning process when it is accessible.
+    pub fn executable(&self) -> Option<&str> {
+        self.executable.as_deref()
+    }
+
+    /// Returns true when the process holds an open file descriptor to the resource.
+    pub fn has_open_handle(&self) -> bool {
+        self.has_open_handle
+    }
+
+    /// Returns true when the resource is mapped into the address space of the process.
+    pub fn has_mapping(&self) -> bool {
+        self.has_mapping
+    }
+}
+
+/// Returns all processes that have the resource under the provided [`FilePath`] open or
+/// mapped. Shared memory is matched by its file name, since the operating system stores it
+/// outside of the path hint, e.g. under `/dev/shm` on Linux.
+pub fn list_owners(path: &FilePath) -> Result<Vec<NamedConceptOwner>, NamedConceptOwnerListError> {
+    let full_path = path.to_string();
+    let shm_path = format!("/dev/shm/{}", path.file_name());
+
+    list_owners_where(|target| target == full_path || target == shm_path)
+}
+
+#[cfg(target_os = "linux")]
+fn list_owners_where<F: Fn(&str) -> bool>(
+    is_resource: F,
+) -> Result<Vec<NamedConceptOwner>, NamedConceptOwnerListError> {
+    use iceoryx2_bb_log::fail;
+    use std::fs;
+    use std::io::ErrorKind;
+
+    let origin = "named_concept_owner::list_owners()";
+    let msg = "Unable to list the owners of the named concept";
+
+    let entries = match fs::read_dir("/proc") {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == ErrorKind::PermissionDenied => {
+            fail!(from origin, with NamedConceptOwnerListError::InsufficientPermissions,
+                "{} due to insufficient permissions to read /proc.", msg);
+        }
+        Err(e) => {
+            fail!(from origin, with NamedConceptOwnerListError::InternalError,
+                "{} since /proc could not be read ({:?}).", msg, e);
+        }
+    };
+
+    // deleted resources that are still in use are reported with a " (deleted)" suffix
+    let strip_deleted =
+        |target: &str| -> String { target.trim_end_matches(" (deleted)").to_string() };
+
+    let mut owners = vec![];
+    for entry in entries.flatten() {
+        let pid = match entry.file_name().to_str().and_then(|v| v.parse().ok()) {
+            Some(pid) => pid,
+            None => continue,
+        };
+        let process_path = entry.path();
+
+        let has_open_handle = match fs::read_dir(process_path.join("fd")) {
+            Ok(fds) => fds.flatten().any(|fd| match fs::read_link(fd.path()) {
+                Ok(target) => is_resource(&strip_deleted(&target.to_string_lossy())),
+                Err(_) => false,
+            }),
+            Err(_) => false,
+        };
+
+        let has_mapping = match fs::read_to_string(process_path.join("maps")) {
+            Ok(maps) => maps
+                .lines()
+                .filter_map(mapped_path)
+                .any(|target| is_resource(&strip_deleted(target))),
+            Err(_) => false,
+        };
+
+        if has_open_handle || has_mapping {
+            owners.push(NamedConceptOwner {
+                pid: ProcessId::new(pid),
+                executable: fs::read_link(process_path.join("exe"))
+                    .ok()
+                    .map(|v| v.to_string_lossy().to_string()),
+                has_open_handle,
+                has_mapping,
+            });
+        }
+    }
+
+    Ok(owners)
+}
+
+// address, permissions, offset, device and inode precede the path of a mapping in
+// /proc/<pid>/maps, anonymous mappings have no path
+#[cfg(target_os = "linux")]
+fn mapped_path(line: &str) -> Option<&str> {
+    let mut rest = line;
+    for _ in 0..5 {
+        rest = rest.trim_start();
+        rest = &rest[rest.find(char::is_whitespace)?..];
+    }
+
+    let path = rest.trim();
+    if path.is_empty() {
+        None
+    } else {
+        Some(path)
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn list_owners_where<F: Fn(&str) -> bool>(
+    _is_resource: F,
+) -> Result<Vec<NamedConceptOwner>, NamedConceptOwnerListError> {
+    use iceoryx2_bb_log::fail;
+
+    fail!(from "named_concept_owner::list_owners()", with NamedConceptOwnerListError::UnsupportedPlatform,
+        "Unable to list the owners of the named concept since it is not yet supported on this platform.");
+}
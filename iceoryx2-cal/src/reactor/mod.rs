@@ -56,6 +56,9 @@ pub trait Reactor: Sized + Debug {
         value: &'attachment F,
     ) -> Result<Self::Guard<'reactor, 'attachment>, ReactorAttachError>;
 
+    fn suspend(&self, fd: &FileDescriptor) -> bool;
+    fn resume(&self, fd: &FileDescriptor) -> bool;
+
     fn try_wait<F: FnMut(&FileDescriptor)>(&self, fn_call: F) -> Result<usize, ReactorWaitError>;
     fn timed_wait<F: FnMut(&FileDescriptor)>(
         &self,
@@ -127,6 +127,14 @@ impl crate::reactor::Reactor for Reactor {
         }
     }
 
+    fn suspend(&self, fd: &FileDescriptor) -> bool {
+        self.set.suspend(fd)
+    }
+
+    fn resume(&self, fd: &FileDescriptor) -> bool {
+        self.set.resume(fd)
+    }
+
     fn try_wait<F: FnMut(&FileDescriptor)>(
         &self,
         fn_call: F,
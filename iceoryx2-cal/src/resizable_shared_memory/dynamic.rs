@@ -17,7 +17,7 @@ use core::time::Duration;
 use core::{fmt::Debug, marker::PhantomData};
 
 use crate::shared_memory::{
-    AllocationStrategy, SegmentId, SharedMemoryForPoolAllocator, ShmPointer,
+    AllocationStrategy, HugePageSize, SegmentId, SharedMemoryForPoolAllocator, ShmPointer,
 };
 use crate::shared_memory::{
     PointerOffset, SharedMemory, SharedMemoryBuilder, SharedMemoryCreateError,
@@ -60,6 +60,8 @@ struct MemoryConfig<Allocator: ShmAllocator, Shm: SharedMemory<Allocator>> {
     base_name: FileName,
     shm: Shm::Configuration,
     allocator_config_hint: Allocator::Configuration,
+    read_only_payload: bool,
+    huge_pages: Option<HugePageSize>,
 }
 
 #[derive(Debug)]
@@ -67,6 +69,8 @@ struct ViewConfig<Allocator: ShmAllocator, Shm: SharedMemory<Allocator>> {
     base_name: FileName,
     shm: Shm::Configuration,
     shm_builder_timeout: Duration,
+    read_only_payload: bool,
+    huge_pages: Option<HugePageSize>,
     _data: PhantomData<Allocator>,
 }
 
@@ -128,6 +132,8 @@ where
                 base_name: *name,
                 shm: Shm::Configuration::default(),
                 shm_builder_timeout: Duration::ZERO,
+                read_only_payload: false,
+                huge_pages: None,
                 _data: PhantomData,
             },
         }
@@ -154,6 +160,16 @@ where
         self
     }
 
+    fn read_only_payload(mut self, value: bool) -> Self {
+        self.config.read_only_payload = value;
+        self
+    }
+
+    fn huge_pages(mut self, value: Option<HugePageSize>) -> Self {
+        self.config.huge_pages = value;
+        self
+    }
+
     fn open(self) -> Result<DynamicView<Allocator, Shm>, SharedMemoryOpenError> {
         let origin = format!("{:?}", self);
         let msg = "Unable to open ResizableSharedMemoryView";
@@ -198,6 +214,8 @@ where
                 base_name: *name,
                 allocator_config_hint: Allocator::Configuration::default(),
                 shm: Shm::Configuration::default(),
+                read_only_payload: false,
+                huge_pages: None,
             },
             shared_state: SharedState {
                 allocation_strategy: AllocationStrategy::default(),
@@ -243,6 +261,16 @@ where
         self
     }
 
+    fn read_only_payload(mut self, value: bool) -> Self {
+        self.config.read_only_payload = value;
+        self
+    }
+
+    fn huge_pages(mut self, value: Option<HugePageSize>) -> Self {
+        self.config.huge_pages = value;
+        self
+    }
+
     fn create(mut self) -> Result<DynamicMemory<Allocator, Shm>, SharedMemoryCreateError> {
         let msg = "Unable to create ResizableSharedMemory";
         let origin = format!("{:?}", self);
@@ -388,6 +416,65 @@ where
         let shared_memory_map = unsafe { &mut *self.shared_memory_map.get() };
         shared_memory_map.len()
     }
+
+    fn map_all_segments(&self) -> Result<usize, SharedMemoryOpenError> {
+        let msg = "Unable to map all segments";
+        let raw_names = match Shm::list_cfg(&self.view_config.shm) {
+            Ok(names) => names,
+            Err(e) => {
+                fail!(from self, with SharedMemoryOpenError::InternalError,
+                    "{msg} since the underlying shared memories could not be listed ({:?}).", e);
+            }
+        };
+
+        let mut segment_ids: Vec<SegmentId> = raw_names
+            .iter()
+            .filter_map(DynamicMemory::<Allocator, Shm>::extract_name_and_segment_id)
+            .filter(|(name, _)| *name == self.view_config.base_name)
+            .map(|(_, segment_id)| segment_id)
+            .collect();
+        segment_ids.sort_by_key(|segment_id| segment_id.value());
+
+        let shared_memory_map = unsafe { &mut *self.shared_memory_map.get() };
+
+        // unmap the segments that were removed by the owner and are no longer in use
+        let current_idx = self.current_idx.load(Ordering::Relaxed);
+        shared_memory_map.retain(|key, entry| {
+            key.value() == current_idx
+                || entry.chunk_count.load(Ordering::Relaxed) != 0
+                || segment_ids
+                    .iter()
+                    .any(|segment_id| segment_id.value() as usize == key.value())
+        });
+
+        let mut number_of_mapped_segments = 0;
+        for segment_id in segment_ids {
+            let key = SlotMapKey::new(segment_id.value() as usize);
+            if shared_memory_map.get(key).is_some() {
+                continue;
+            }
+
+            match DynamicMemory::open_segment(&self.view_config, segment_id) {
+                Ok(shm) => {
+                    shared_memory_map.insert_at(key, ShmEntry::new(shm));
+                    number_of_mapped_segments += 1;
+
+                    let current_idx = self.current_idx.load(Ordering::Relaxed);
+                    if current_idx == INVALID_KEY || current_idx < key.value() {
+                        self.current_idx.store(key.value(), Ordering::Relaxed);
+                    }
+                }
+                // the segment was released by the owner in the meantime
+                Err(SharedMemoryOpenError::DoesNotExist) => (),
+                Err(e) => {
+                    fail!(from self, with e,
+                        "{msg} since the segment {:?} could not be opened.", segment_id);
+                }
+            }
+        }
+
+        Ok(number_of_mapped_segments)
+    }
 }
 
 #[derive(Debug)]
@@ -532,7 +619,7 @@ where
 
             // check nymber of digits
             for byte in raw_segment_id.as_bytes() {
-                if byte.is_ascii_digit() {
+                if !byte.is_ascii_digit() {
                     return None;
                 }
             }
@@ -573,6 +660,8 @@ where
         Self::segment_builder(&config.base_name, &config.shm, segment_id)
             .has_ownership(true)
             .size(payload_size)
+            .read_only_payload(config.read_only_payload)
+            .huge_pages(config.huge_pages)
             .create(&config.allocator_config_hint)
     }
 
@@ -583,6 +672,8 @@ where
         Self::segment_builder(&config.base_name, &config.shm, segment_id)
             .has_ownership(false)
             .timeout(config.shm_builder_timeout)
+            .read_only_payload(config.read_only_payload)
+            .huge_pages(config.huge_pages)
             .open()
     }
 
@@ -710,6 +801,40 @@ where
                         segment_id),
         }
     }
+
+    fn reallocation_size_hint(&self, layout: Layout) -> Option<usize> {
+        let state = self.state();
+        if state.shared_state.allocation_strategy == AllocationStrategy::Static {
+            return None;
+        }
+
+        match state.shared_memory_map.get(state.current_idx) {
+            Some(entry) => {
+                if entry.shm.number_of_used_buckets() < entry.shm.number_of_buckets()
+                    && layout.size() <= entry.shm.bucket_size()
+                    && layout.align() <= entry.shm.max_alignment()
+                {
+                    None
+                } else {
+                    Some(
+                        entry
+                            .shm
+                            .allocator()
+                            .resize_hint(layout, state.shared_state.allocation_strategy)
+                            .payload_size,
+                    )
+                }
+            }
+            None => fatal_panic!(from self,
+                        "This should never happen! Unable to acquire the reallocation size hint since the current shared memory segment is not available!"),
+        }
+    }
+
+    fn for_each_segment<F: FnMut(SegmentId, &Shm)>(&self, mut callback: F) {
+        for (key, entry) in self.state().shared_memory_map.iter() {
+            callback(SegmentId::new(key.value() as u8), &entry.shm);
+        }
+    }
 }
 
 impl<Allocator: ShmAllocator, Shm: SharedMemory<Allocator>> ResizableSharedMemory<Allocator, Shm>
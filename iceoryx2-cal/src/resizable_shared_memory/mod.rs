@@ -98,7 +98,8 @@ use iceoryx2_bb_elementary::enum_gen;
 
 use crate::named_concept::*;
 use crate::shared_memory::{
-    SegmentId, SharedMemory, SharedMemoryCreateError, SharedMemoryOpenError, ShmPointer,
+    HugePageSize, SegmentId, SharedMemory, SharedMemoryCreateError, SharedMemoryOpenError,
+    ShmPointer,
 };
 use crate::shm_allocator::{PointerOffset, ShmAllocationError, ShmAllocator};
 
@@ -134,6 +135,16 @@ pub trait ResizableSharedMemoryViewBuilder<
     /// timeout.
     fn timeout(self, value: Duration) -> Self;
 
+    /// Maps the payload of every [`SharedMemory`] segment read-only, see
+    /// [`SharedMemoryBuilder::read_only_payload()`](crate::shared_memory::SharedMemoryBuilder::read_only_payload()).
+    /// By default it is false.
+    fn read_only_payload(self, value: bool) -> Self;
+
+    /// Maps every [`SharedMemory`] segment with huge pages, see
+    /// [`SharedMemoryBuilder::huge_pages()`](crate::shared_memory::SharedMemoryBuilder::huge_pages()).
+    /// By default it is [`None`].
+    fn huge_pages(self, value: Option<HugePageSize>) -> Self;
+
     /// Opens already existing [`SharedMemory`]. If it does not exist or the initialization is not
     /// yet finished the method will fail.
     fn open(self) -> Result<ResizableShmView, SharedMemoryOpenError>;
@@ -162,6 +173,17 @@ pub trait ResizableSharedMemoryBuilder<
     /// acquired.
     fn allocation_strategy(self, value: AllocationStrategy) -> Self;
 
+    /// Places the payload of every [`SharedMemory`] segment on separate pages so that a
+    /// [`ResizableSharedMemoryView`] can map it read-only, see
+    /// [`SharedMemoryBuilder::read_only_payload()`](crate::shared_memory::SharedMemoryBuilder::read_only_payload()).
+    /// By default it is false.
+    fn read_only_payload(self, value: bool) -> Self;
+
+    /// Backs every [`SharedMemory`] segment with huge pages, see
+    /// [`SharedMemoryBuilder::huge_pages()`](crate::shared_memory::SharedMemoryBuilder::huge_pages()).
+    /// By default it is [`None`].
+    fn huge_pages(self, value: Option<HugePageSize>) -> Self;
+
     /// Creates new [`SharedMemory`]. If it already exists the method will fail.
     fn create(self) -> Result<ResizableShm, SharedMemoryCreateError>;
 }
@@ -195,6 +217,12 @@ pub trait ResizableSharedMemoryView<Allocator: ShmAllocator, Shm: SharedMemory<A
 
     /// Returns the number of active [`SharedMemory`] segments.
     fn number_of_active_segments(&self) -> usize;
+
+    /// Opens and maps every [`SharedMemory`] segment of the [`ResizableSharedMemory`] that
+    /// exists and is not yet mapped into the processes space, so that
+    /// [`ResizableSharedMemoryView::register_and_translate_offset()`] does not need to map
+    /// them later. Returns the number of newly mapped segments.
+    fn map_all_segments(&self) -> Result<usize, SharedMemoryOpenError>;
 }
 
 /// The [`ResizableSharedMemory`] can be only owned by exactly one process that is allowed to
@@ -254,4 +282,13 @@ pub trait ResizableSharedMemoryForPoolAllocator<Shm: SharedMemory<PoolAllocator>
 
     /// Returns the bucket size of the corresponding [`PoolAllocator`]
     fn bucket_size(&self, segment_id: SegmentId) -> usize;
+
+    /// Returns the payload size of the [`SharedMemory`] segment that would be created when
+    /// the provided `layout` is allocated next. If the current segment can serve the
+    /// allocation or the [`AllocationStrategy`] forbids reallocation, it returns [`None`].
+    fn reallocation_size_hint(&self, layout: Layout) -> Option<usize>;
+
+    /// Calls the provided callback for every active [`SharedMemory`] segment with its
+    /// [`SegmentId`].
+    fn for_each_segment<F: FnMut(SegmentId, &Shm)>(&self, callback: F);
 }
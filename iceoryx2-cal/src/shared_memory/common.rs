@@ -12,12 +12,16 @@
 
 use core::marker::PhantomData;
 use core::mem::MaybeUninit;
+use core::ptr::NonNull;
 use core::{alloc::Layout, fmt::Debug};
 
 use crate::dynamic_storage::*;
 pub use crate::shared_memory::*;
 use iceoryx2_bb_elementary::allocator::BaseAllocator;
+use iceoryx2_bb_elementary::math::align;
 use iceoryx2_bb_log::{debug, fail};
+use iceoryx2_bb_posix::access_mode::AccessMode;
+use iceoryx2_bb_posix::memory_protection::MemoryProtection;
 use iceoryx2_bb_posix::system_configuration::SystemInfo;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::file_path::FilePath;
@@ -140,6 +144,8 @@ pub mod details {
         config: Configuration<Allocator, Storage>,
         timeout: Duration,
         has_ownership: bool,
+        read_only_payload: bool,
+        huge_pages: Option<HugePageSize>,
     }
 
     impl<Allocator: ShmAllocator + Debug, Storage: DynamicStorage<AllocatorDetails<Allocator>>>
@@ -152,6 +158,8 @@ pub mod details {
                 size: 0,
                 timeout: Duration::ZERO,
                 has_ownership: true,
+                read_only_payload: false,
+                huge_pages: None,
             }
         }
 
@@ -171,10 +179,14 @@ pub mod details {
             init_allocator: &mut BumpAllocator,
         ) -> bool {
             let msg = "Unable to initialize shared memory";
-            let res =
-                init_allocator.allocate(unsafe { Layout::from_size_align_unchecked(self.size, 1) });
+            let res = init_allocator.allocate(unsafe {
+                Layout::from_size_align_unchecked(
+                    self.payload_reservation_size(),
+                    self.payload_alignment(),
+                )
+            });
             let memory = match res {
-                Ok(m) => m,
+                Ok(m) => NonNull::slice_from_raw_parts(m.cast::<u8>(), self.size),
                 Err(e) => {
                     debug!(from self, "{} since the payload memory could not be acquired ({:?}).", msg, e);
                     return false;
@@ -195,6 +207,44 @@ pub mod details {
                 true
             }
         }
+
+        /// When the payload shall be read-only, it is placed on separate pages so that the
+        /// protection does not affect the writable management areas.
+        fn payload_alignment(&self) -> usize {
+            if self.read_only_payload {
+                SystemInfo::PageSize.value()
+            } else {
+                1
+            }
+        }
+
+        fn payload_reservation_size(&self) -> usize {
+            if self.read_only_payload {
+                align(self.size, SystemInfo::PageSize.value())
+            } else {
+                self.size
+            }
+        }
+
+        fn protect_payload(&self, payload_start: usize, payload_size: usize) -> bool {
+            let page_size = SystemInfo::PageSize.value();
+            let start = align(payload_start, page_size);
+            let end = payload_start + payload_size;
+            let end = end - end % page_size;
+
+            if end <= start {
+                return true;
+            }
+
+            match unsafe { MemoryProtection::set(start as *const _, end - start, AccessMode::Read) }
+            {
+                Ok(()) => true,
+                Err(e) => {
+                    debug!(from self, "Unable to map the payload read-only ({:?}).", e);
+                    false
+                }
+            }
+        }
     }
 
     impl<Allocator: ShmAllocator + Debug, Storage: DynamicStorage<AllocatorDetails<Allocator>>>
@@ -216,6 +266,16 @@ pub mod details {
             self
         }
 
+        fn read_only_payload(mut self, value: bool) -> Self {
+            self.read_only_payload = value;
+            self
+        }
+
+        fn huge_pages(mut self, value: Option<HugePageSize>) -> Self {
+            self.huge_pages = value;
+            self
+        }
+
         fn create(
             self,
             allocator_config: &Allocator::Configuration,
@@ -231,7 +291,12 @@ pub mod details {
 
             let storage = match Storage::Builder::new(&self.name)
                 .config(&self.config.dynamic_storage_config)
-                .supplementary_size(self.size + allocator_mgmt_size)
+                .huge_pages(self.huge_pages)
+                .supplementary_size(
+                    self.payload_reservation_size()
+                        + self.payload_alignment()
+                        + allocator_mgmt_size,
+                )
                 .has_ownership(self.has_ownership)
                 .initializer(|details, init_allocator| -> bool {
                     self.initialize(allocator_config, details, init_allocator)
@@ -275,6 +340,7 @@ pub mod details {
 
             let storage = match Storage::Builder::new(&self.name)
                 .config(&self.config.dynamic_storage_config)
+                .huge_pages(self.huge_pages)
                 .has_ownership(false)
                 .timeout(self.timeout)
                 .open()
@@ -311,8 +377,21 @@ pub mod details {
                     msg, self.size, payload_size);
             }
 
+            let payload_start_address = get_payload_start_address(&storage);
+            if self.read_only_payload {
+                if !Storage::does_support_memory_protection() {
+                    fail!(from self, with SharedMemoryOpenError::ReadOnlyPayloadNotSupported,
+                        "{} since the underlying storage does not support a read-only payload.", msg);
+                }
+
+                if !self.protect_payload(payload_start_address, payload_size) {
+                    fail!(from self, with SharedMemoryOpenError::ReadOnlyPayloadNotSupported,
+                        "{} since the payload could not be mapped read-only.", msg);
+                }
+            }
+
             Ok(Memory::<Allocator, Storage> {
-                payload_start_address: get_payload_start_address(&storage),
+                payload_start_address,
                 name: self.name,
                 storage,
                 _phantom: PhantomData,
@@ -459,5 +538,25 @@ pub mod details {
         fn bucket_size(&self) -> usize {
             unsafe { self.storage.get().allocator.assume_init_ref().bucket_size() }
         }
+
+        fn number_of_buckets(&self) -> usize {
+            unsafe {
+                self.storage
+                    .get()
+                    .allocator
+                    .assume_init_ref()
+                    .number_of_buckets() as usize
+            }
+        }
+
+        fn number_of_used_buckets(&self) -> usize {
+            unsafe {
+                self.storage
+                    .get()
+                    .allocator
+                    .assume_init_ref()
+                    .number_of_used_buckets()
+            }
+        }
     }
 }
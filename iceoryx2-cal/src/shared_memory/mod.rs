@@ -62,6 +62,7 @@ use core::{fmt::Debug, time::Duration};
 
 pub use crate::shm_allocator::*;
 use crate::static_storage::file::{NamedConcept, NamedConceptBuilder, NamedConceptMgmt};
+pub use iceoryx2_bb_posix::shared_memory::HugePageSize;
 use iceoryx2_bb_system_types::file_name::*;
 use pool_allocator::PoolAllocator;
 
@@ -84,6 +85,7 @@ pub enum SharedMemoryOpenError {
     WrongAllocatorSelected,
     InitializationNotYetFinalized,
     VersionMismatch,
+    ReadOnlyPayloadNotSupported,
     InternalError,
 }
 
@@ -124,6 +126,18 @@ pub trait SharedMemoryBuilder<Allocator: ShmAllocator, Shm: SharedMemory<Allocat
     /// timeout.
     fn timeout(self, value: Duration) -> Self;
 
+    /// Defines if the payload of the [`SharedMemory`] is read-only. When the [`SharedMemory`] is
+    /// created, the payload is placed on pages that are not shared with the writable
+    /// management areas. When it is opened, all pages that lie completely within the payload
+    /// are mapped read-only. When this is not supported, [`SharedMemoryBuilder::open()`] fails
+    /// with [`SharedMemoryOpenError::ReadOnlyPayloadNotSupported`]. By default it is false.
+    fn read_only_payload(self, value: bool) -> Self;
+
+    /// Requests that the [`SharedMemory`] is backed by huge pages of the provided size to reduce
+    /// the TLB misses when large payloads are accessed at a high rate. When this is not
+    /// supported, regular pages are used. By default it is [`None`].
+    fn huge_pages(self, value: Option<HugePageSize>) -> Self;
+
     /// Creates new [`SharedMemory`]. If it already exists the method will fail.
     fn create(
         self,
@@ -197,4 +211,10 @@ pub trait SharedMemoryForPoolAllocator: SharedMemory<PoolAllocator> {
 
     /// Returns the bucket size of the [`PoolAllocator`]
     fn bucket_size(&self) -> usize;
+
+    /// Returns the number of buckets the [`PoolAllocator`] manages
+    fn number_of_buckets(&self) -> usize;
+
+    /// Returns the number of buckets of the [`PoolAllocator`] that are currently in use
+    fn number_of_used_buckets(&self) -> usize;
 }
@@ -57,6 +57,10 @@ impl PoolAllocator {
         self.allocator.number_of_buckets()
     }
 
+    pub fn number_of_used_buckets(&self) -> usize {
+        self.number_of_used_buckets.load(Ordering::Relaxed)
+    }
+
     /// # Safety
     ///
     ///  * provided [`PointerOffset`] must be allocated with [`PoolAllocator::allocate()`]
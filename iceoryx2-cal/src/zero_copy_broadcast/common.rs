@@ -0,0 +1,1292 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[doc(hidden)]
+pub mod details {
+    use core::cell::UnsafeCell;
+    use core::fmt::Debug;
+    use core::marker::PhantomData;
+    use core::sync::atomic::Ordering;
+    use iceoryx2_bb_elementary::allocator::{AllocationError, BaseAllocator};
+    use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicU8, IoxAtomicUsize};
+
+    use crate::dynamic_storage::{
+        DynamicStorage, DynamicStorageBuilder, DynamicStorageCreateError, DynamicStorageOpenError,
+        DynamicStorageOpenOrCreateError,
+    };
+    use crate::named_concept::*;
+    use crate::shared_memory::SegmentId;
+    pub use crate::zero_copy_broadcast::*;
+    use iceoryx2_bb_container::vec::RelocatableVec;
+    use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+    use iceoryx2_bb_lock_free::spsc::index_queue::RelocatableIndexQueue;
+    use iceoryx2_bb_log::{fail, fatal_panic, warn};
+    use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+
+    #[derive(Debug, PartialEq, Eq, Copy)]
+    pub struct Configuration<Storage: DynamicStorage<SharedManagementData>> {
+        dynamic_storage_config: Storage::Configuration,
+        _data: PhantomData<Storage>,
+    }
+
+    impl<Storage: DynamicStorage<SharedManagementData>> Clone for Configuration<Storage> {
+        fn clone(&self) -> Self {
+            Self {
+                dynamic_storage_config: self.dynamic_storage_config.clone(),
+                _data: PhantomData,
+            }
+        }
+    }
+
+    impl<Storage: DynamicStorage<SharedManagementData>> Default for Configuration<Storage> {
+        fn default() -> Self {
+            Self {
+                dynamic_storage_config: Storage::Configuration::default()
+                    .path_hint(&Broadcast::<Storage>::default_path_hint())
+                    .prefix(&Broadcast::<Storage>::default_prefix())
+                    .suffix(&Broadcast::<Storage>::default_suffix()),
+                _data: PhantomData,
+            }
+        }
+    }
+
+    impl<Storage: DynamicStorage<SharedManagementData>> NamedConceptConfiguration
+        for Configuration<Storage>
+    {
+        fn prefix(mut self, value: &FileName) -> Self {
+            self.dynamic_storage_config = self.dynamic_storage_config.prefix(value);
+            self
+        }
+
+        fn get_prefix(&self) -> &FileName {
+            self.dynamic_storage_config.get_prefix()
+        }
+
+        fn suffix(mut self, value: &FileName) -> Self {
+            self.dynamic_storage_config = self.dynamic_storage_config.suffix(value);
+            self
+        }
+
+        fn path_hint(mut self, value: &Path) -> Self {
+            self.dynamic_storage_config = self.dynamic_storage_config.path_hint(value);
+            self
+        }
+
+        fn get_suffix(&self) -> &FileName {
+            self.dynamic_storage_config.get_suffix()
+        }
+
+        fn get_path_hint(&self) -> &Path {
+            self.dynamic_storage_config.get_path_hint()
+        }
+
+        fn path_for(&self, value: &FileName) -> FilePath {
+            self.dynamic_storage_config.path_for(value)
+        }
+
+        fn extract_name_from_file(&self, value: &FileName) -> Option<FileName> {
+            self.dynamic_storage_config.extract_name_from_file(value)
+        }
+    }
+
+    // The state of the broadcast contains the sender flag, the destruction flag and the number
+    // of connected receivers in the lower bits.
+    const MARKED_FOR_DESTRUCTION: u64 = 1 << 63;
+    const SENDER: u64 = 1 << 62;
+    const RECEIVER: u64 = 1;
+    const RECEIVER_COUNT_MASK: u64 = SENDER - 1;
+
+    const SLOT_FREE: u8 = 0;
+    const SLOT_RESERVED: u8 = 1;
+    const SLOT_CONNECTED: u8 = 2;
+
+    const NO_SAMPLE: u64 = u64::MAX;
+
+    fn cleanup_shared_memory<Storage: DynamicStorage<SharedManagementData>>(
+        storage: &Storage,
+        port_to_remove: u64,
+    ) {
+        let mut current_state = storage.get().state.load(Ordering::Relaxed);
+        if current_state & MARKED_FOR_DESTRUCTION != 0 {
+            warn!(from "common::ZeroCopyBroadcast::cleanup_shared_memory()",
+                    "Trying to remove a port from the broadcast {:?} which is already marked for destruction.", storage.name());
+            return;
+        }
+
+        loop {
+            let new_state = match current_state.saturating_sub(port_to_remove) {
+                0 => MARKED_FOR_DESTRUCTION,
+                v => v,
+            };
+
+            match storage.get().state.compare_exchange(
+                current_state,
+                new_state,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => {
+                    current_state = new_state;
+                    break;
+                }
+                Err(s) => {
+                    current_state = s;
+                }
+            }
+        }
+
+        if current_state == MARKED_FOR_DESTRUCTION {
+            storage.acquire_ownership()
+        }
+    }
+
+    #[derive(Debug)]
+    struct RingEntry {
+        // position in the ring + 1 of the entry, zero while the entry is written
+        sequence: IoxAtomicU64,
+        value: IoxAtomicU64,
+    }
+
+    #[derive(Debug)]
+    struct ReceiverSlot {
+        state: IoxAtomicU8,
+        generation: IoxAtomicU64,
+        receiver_id_high: IoxAtomicU64,
+        receiver_id_low: IoxAtomicU64,
+        read_position: IoxAtomicU64,
+        borrowed_samples: RelocatableVec<IoxAtomicU64>,
+        completion_channel: RelocatableIndexQueue,
+        missed_samples: IoxAtomicU64,
+        failed_releases: IoxAtomicU64,
+    }
+
+    impl ReceiverSlot {
+        fn new_uninit(max_borrowed_samples: usize, completion_channel_capacity: usize) -> Self {
+            Self {
+                state: IoxAtomicU8::new(SLOT_FREE),
+                generation: IoxAtomicU64::new(0),
+                receiver_id_high: IoxAtomicU64::new(0),
+                receiver_id_low: IoxAtomicU64::new(0),
+                read_position: IoxAtomicU64::new(0),
+                borrowed_samples: unsafe { RelocatableVec::new_uninit(max_borrowed_samples) },
+                completion_channel: unsafe {
+                    RelocatableIndexQueue::new_uninit(completion_channel_capacity)
+                },
+                missed_samples: IoxAtomicU64::new(0),
+                failed_releases: IoxAtomicU64::new(0),
+            }
+        }
+
+        const fn const_memory_size(
+            max_borrowed_samples: usize,
+            completion_channel_capacity: usize,
+        ) -> usize {
+            RelocatableVec::<IoxAtomicU64>::const_memory_size(max_borrowed_samples)
+                + RelocatableIndexQueue::const_memory_size(completion_channel_capacity)
+        }
+
+        unsafe fn init<T: BaseAllocator>(&mut self, allocator: &T) -> Result<(), AllocationError> {
+            self.borrowed_samples.init(allocator)?;
+            self.borrowed_samples
+                .fill_with(|| IoxAtomicU64::new(NO_SAMPLE));
+            self.completion_channel.init(allocator)
+        }
+
+        fn receiver_id(&self) -> u128 {
+            ((self.receiver_id_high.load(Ordering::Relaxed) as u128) << u64::BITS)
+                | self.receiver_id_low.load(Ordering::Relaxed) as u128
+        }
+
+        fn set_receiver_id(&self, value: u128) {
+            self.receiver_id_high
+                .store((value >> u64::BITS) as u64, Ordering::Relaxed);
+            self.receiver_id_low.store(value as u64, Ordering::Relaxed);
+        }
+    }
+
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct SharedManagementData {
+        ring: RelocatableVec<RingEntry>,
+        receiver_slots: RelocatableVec<ReceiverSlot>,
+        reference_counters: RelocatableVec<IoxAtomicU64>,
+        sample_sizes: RelocatableVec<IoxAtomicUsize>,
+        write_position: IoxAtomicU64,
+        state: IoxAtomicU64,
+        buffer_size: usize,
+        max_borrowed_samples: usize,
+        max_receivers: usize,
+        number_of_samples_per_segment: usize,
+        number_of_segments: u8,
+        enable_safe_overflow: bool,
+        sent_samples: IoxAtomicU64,
+        reclaimed_samples: IoxAtomicU64,
+        rejected_sends: IoxAtomicU64,
+    }
+
+    impl SharedManagementData {
+        fn new(
+            buffer_size: usize,
+            enable_safe_overflow: bool,
+            max_borrowed_samples: usize,
+            max_receivers: usize,
+            number_of_samples_per_segment: usize,
+            number_of_segments: u8,
+        ) -> Self {
+            Self {
+                ring: unsafe { RelocatableVec::new_uninit(buffer_size) },
+                receiver_slots: unsafe { RelocatableVec::new_uninit(max_receivers) },
+                reference_counters: unsafe {
+                    RelocatableVec::new_uninit(
+                        number_of_samples_per_segment * number_of_segments as usize,
+                    )
+                },
+                sample_sizes: unsafe { RelocatableVec::new_uninit(number_of_segments as usize) },
+                write_position: IoxAtomicU64::new(0),
+                state: IoxAtomicU64::new(0),
+                buffer_size,
+                max_borrowed_samples,
+                max_receivers,
+                number_of_samples_per_segment,
+                number_of_segments,
+                enable_safe_overflow,
+                sent_samples: IoxAtomicU64::new(0),
+                reclaimed_samples: IoxAtomicU64::new(0),
+                rejected_sends: IoxAtomicU64::new(0),
+            }
+        }
+
+        const fn const_memory_size(
+            buffer_size: usize,
+            max_borrowed_samples: usize,
+            max_receivers: usize,
+            number_of_samples: usize,
+            number_of_segments: u8,
+        ) -> usize {
+            let number_of_segments = number_of_segments as usize;
+            RelocatableVec::<RingEntry>::const_memory_size(buffer_size)
+                + RelocatableVec::<ReceiverSlot>::const_memory_size(max_receivers)
+                + ReceiverSlot::const_memory_size(
+                    max_borrowed_samples,
+                    completion_channel_size(buffer_size, max_borrowed_samples),
+                ) * max_receivers
+                + RelocatableVec::<IoxAtomicU64>::const_memory_size(
+                    number_of_samples * number_of_segments,
+                )
+                + RelocatableVec::<IoxAtomicUsize>::const_memory_size(number_of_segments)
+        }
+
+        fn number_of_receivers(&self) -> usize {
+            (self.state.load(Ordering::Relaxed) & RECEIVER_COUNT_MASK) as usize
+        }
+
+        fn reference_counter(&self, ptr: PointerOffset) -> Option<&IoxAtomicU64> {
+            let segment_id = ptr.segment_id().value() as usize;
+            if segment_id >= self.sample_sizes.len() {
+                return None;
+            }
+
+            let sample_size = self.sample_sizes[segment_id].load(Ordering::SeqCst);
+            if sample_size == 0 || ptr.offset() % sample_size != 0 {
+                return None;
+            }
+
+            let index = ptr.offset() / sample_size;
+            if index >= self.number_of_samples_per_segment {
+                return None;
+            }
+
+            Some(&self.reference_counters[segment_id * self.number_of_samples_per_segment + index])
+        }
+
+        // Drops one reference of the sample. The receiver that drops the last reference hands
+        // the sample back to the sender via its completion channel.
+        fn release_reference(&self, slot: &ReceiverSlot, value: u64) -> bool {
+            match self.reference_counter(PointerOffset::from_value(value)) {
+                Some(reference_counter) => {
+                    if reference_counter.fetch_sub(1, Ordering::SeqCst) == 1 {
+                        unsafe { slot.completion_channel.push(value) }
+                    } else {
+                        true
+                    }
+                }
+                None => false,
+            }
+        }
+
+        fn release_all_borrowed_samples(&self, slot: &ReceiverSlot) {
+            for borrowed_sample in slot.borrowed_samples.iter() {
+                let value = borrowed_sample.swap(NO_SAMPLE, Ordering::Relaxed);
+                if value != NO_SAMPLE && !self.release_reference(slot, value) {
+                    warn!(from "common::ZeroCopyBroadcast::release_all_borrowed_samples()",
+                        "Unable to release the sample {:?} of a disconnected receiver. The sample is lost until the sender reacquires all samples.",
+                        PointerOffset::from_value(value));
+                }
+            }
+        }
+
+        fn counters(&self, slot: Option<&ReceiverSlot>) -> ZeroCopyConnectionCounters {
+            let (overflowed_samples, failed_releases) = match slot {
+                Some(slot) => (
+                    slot.missed_samples.load(Ordering::Relaxed),
+                    slot.failed_releases.load(Ordering::Relaxed),
+                ),
+                None => self.receiver_slots.iter().fold((0, 0), |acc, slot| {
+                    (
+                        acc.0 + slot.missed_samples.load(Ordering::Relaxed),
+                        acc.1 + slot.failed_releases.load(Ordering::Relaxed),
+                    )
+                }),
+            };
+
+            ZeroCopyConnectionCounters {
+                sent_samples: self.sent_samples.load(Ordering::Relaxed),
+                reclaimed_samples: self.reclaimed_samples.load(Ordering::Relaxed),
+                overflowed_samples,
+                rejected_sends: self.rejected_sends.load(Ordering::Relaxed),
+                failed_releases,
+            }
+        }
+    }
+
+    const fn completion_channel_size(buffer_size: usize, max_borrowed_samples: usize) -> usize {
+        buffer_size + max_borrowed_samples + 1
+    }
+
+    #[derive(Debug)]
+    pub struct Builder<Storage: DynamicStorage<SharedManagementData>> {
+        name: FileName,
+        buffer_size: usize,
+        enable_safe_overflow: bool,
+        max_borrowed_samples: usize,
+        max_receivers: usize,
+        number_of_samples_per_segment: usize,
+        number_of_segments: u8,
+        receiver_id: u128,
+        initial_history: usize,
+        timeout: Duration,
+        config: Configuration<Storage>,
+    }
+
+    impl<Storage: DynamicStorage<SharedManagementData>> Builder<Storage> {
+        fn create_or_open_shm(&self) -> Result<Storage, ZeroCopyCreationError> {
+            let supplementary_size = SharedManagementData::const_memory_size(
+                self.buffer_size,
+                self.max_borrowed_samples,
+                self.max_receivers,
+                self.number_of_samples_per_segment,
+                self.number_of_segments,
+            );
+
+            let msg = "Failed to acquire underlying shared memory";
+            let storage = <<Storage as DynamicStorage<SharedManagementData>>::Builder<'_> as NamedConceptBuilder<
+            Storage,
+        >>::new(&self.name)
+        .config(&self.config.dynamic_storage_config)
+        .timeout(self.timeout)
+        .supplementary_size(supplementary_size)
+        .initializer(|data, allocator| {
+            fatal_panic!(from self, when unsafe { data.ring.init(allocator) },
+                        "{} since the ring buffer allocation failed. - This is an implementation bug!", msg);
+            fatal_panic!(from self, when unsafe { data.receiver_slots.init(allocator) },
+                        "{} since the receiver slot vector allocation failed. - This is an implementation bug!", msg);
+            fatal_panic!(from self, when unsafe { data.reference_counters.init(allocator) },
+                        "{} since the reference counter allocation failed. - This is an implementation bug!", msg);
+            fatal_panic!(from self, when unsafe { data.sample_sizes.init(allocator) },
+                        "{} since the sample size allocation failed. - This is an implementation bug!", msg);
+
+            unsafe {
+                data.ring.fill_with(|| RingEntry {
+                    sequence: IoxAtomicU64::new(0),
+                    value: IoxAtomicU64::new(0),
+                });
+                data.reference_counters.fill_with(|| IoxAtomicU64::new(0));
+                data.sample_sizes.fill_with(|| IoxAtomicUsize::new(0));
+                data.receiver_slots.fill_with(|| {
+                    ReceiverSlot::new_uninit(
+                        self.max_borrowed_samples,
+                        completion_channel_size(self.buffer_size, self.max_borrowed_samples),
+                    )
+                });
+            }
+
+            for (n, slot) in data.receiver_slots.iter_mut().enumerate() {
+                fatal_panic!(from self, when unsafe { slot.init(allocator) },
+                    "{} since the receiver slot {} failed to allocate memory. - This is an implementation bug!",
+                    msg, n);
+            }
+
+            true
+        })
+        .open_or_create(
+            SharedManagementData::new(
+                                    self.buffer_size,
+                                    self.enable_safe_overflow,
+                                    self.max_borrowed_samples,
+                                    self.max_receivers,
+                                    self.number_of_samples_per_segment,
+                                    self.number_of_segments
+                                )
+            );
+
+            let storage = match storage {
+                Ok(storage) => storage,
+                Err(DynamicStorageOpenOrCreateError::DynamicStorageCreateError(
+                    DynamicStorageCreateError::InsufficientPermissions,
+                )) => {
+                    fail!(from self, with ZeroCopyCreationError::InsufficientPermissions,
+                    "{} due to insufficient permissions to create underlying dynamic storage.", msg);
+                }
+                Err(DynamicStorageOpenOrCreateError::DynamicStorageOpenError(
+                    DynamicStorageOpenError::VersionMismatch,
+                )) => {
+                    fail!(from self, with ZeroCopyCreationError::VersionMismatch,
+                    "{} since the version of the broadcast does not match.", msg);
+                }
+                Err(DynamicStorageOpenOrCreateError::DynamicStorageOpenError(
+                    DynamicStorageOpenError::InitializationNotYetFinalized,
+                )) => {
+                    fail!(from self, with ZeroCopyCreationError::InitializationNotYetFinalized,
+                    "{} since the initialization of the zero copy broadcast is not finalized.", msg);
+                }
+                Err(e) => {
+                    fail!(from self, with ZeroCopyCreationError::InternalError,
+                    "{} due to an internal failure ({:?}).", msg, e);
+                }
+            };
+
+            if storage.has_ownership() {
+                storage.release_ownership();
+            } else {
+                let msg = "Failed to open existing broadcast";
+                let data = storage.get();
+
+                if data.buffer_size != self.buffer_size {
+                    fail!(from self, with ZeroCopyCreationError::IncompatibleBufferSize,
+                        "{} since the broadcast has a buffer size of {} but a buffer size of {} is required.",
+                        msg, data.buffer_size, self.buffer_size);
+                }
+
+                if data.max_borrowed_samples != self.max_borrowed_samples {
+                    fail!(from self, with ZeroCopyCreationError::IncompatibleMaxBorrowedSampleSetting,
+                        "{} since the max borrowed sample setting is set to {} but a value of {} is required.",
+                        msg, data.max_borrowed_samples, self.max_borrowed_samples);
+                }
+
+                if data.enable_safe_overflow != self.enable_safe_overflow {
+                    fail!(from self, with ZeroCopyCreationError::IncompatibleOverflowSetting,
+                        "{} since the safe overflow is set to {} but should be set to {}.",
+                        msg, data.enable_safe_overflow, self.enable_safe_overflow);
+                }
+
+                if data.max_receivers != self.max_receivers {
+                    fail!(from self, with ZeroCopyCreationError::IncompatibleMaxReceiversSetting,
+                        "{} since the max receivers setting is set to {} but should be set to {}.",
+                        msg, data.max_receivers, self.max_receivers);
+                }
+
+                if data.number_of_samples_per_segment != self.number_of_samples_per_segment {
+                    fail!(from self, with ZeroCopyCreationError::IncompatibleNumberOfSamples,
+                        "{} since the requested number of samples is set to {} but should be set to {}.",
+                        msg, self.number_of_samples_per_segment, data.number_of_samples_per_segment);
+                }
+
+                if data.number_of_segments != self.number_of_segments {
+                    fail!(from self, with ZeroCopyCreationError::IncompatibleNumberOfSegments,
+                        "{} since the requested number of segments is set to {} but should be set to {}.",
+                        msg, self.number_of_segments, data.number_of_segments);
+                }
+            }
+
+            Ok(storage)
+        }
+
+        fn reserve_sender(
+            &self,
+            mgmt_ref: &SharedManagementData,
+            msg: &str,
+        ) -> Result<(), ZeroCopyCreationError> {
+            let mut current_state = mgmt_ref.state.load(Ordering::Relaxed);
+
+            loop {
+                if current_state & MARKED_FOR_DESTRUCTION != 0 {
+                    fail!(from self, with ZeroCopyCreationError::InternalError,
+                        "{} since the broadcast is currently being cleaned up.", msg);
+                }
+
+                if current_state & SENDER != 0 {
+                    fail!(from self, with ZeroCopyCreationError::AnotherInstanceIsAlreadyConnected,
+                        "{} since a sender is already connected.", msg);
+                }
+
+                match mgmt_ref.state.compare_exchange(
+                    current_state,
+                    current_state | SENDER,
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return Ok(()),
+                    Err(v) => current_state = v,
+                }
+            }
+        }
+
+        fn reserve_receiver(
+            &self,
+            mgmt_ref: &SharedManagementData,
+            msg: &str,
+        ) -> Result<usize, ZeroCopyCreationError> {
+            let mut current_state = mgmt_ref.state.load(Ordering::Relaxed);
+
+            loop {
+                if current_state & MARKED_FOR_DESTRUCTION != 0 {
+                    fail!(from self, with ZeroCopyCreationError::InternalError,
+                        "{} since the broadcast is currently being cleaned up.", msg);
+                }
+
+                if (current_state & RECEIVER_COUNT_MASK) as usize >= mgmt_ref.max_receivers {
+                    fail!(from self, with ZeroCopyCreationError::ExceedsMaxSupportedReceivers,
+                        "{} since it would exceed the maximum supported number of {} receivers.",
+                        msg, mgmt_ref.max_receivers);
+                }
+
+                match mgmt_ref.state.compare_exchange(
+                    current_state,
+                    current_state + RECEIVER,
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => break,
+                    Err(v) => current_state = v,
+                }
+            }
+
+            // the receiver counter guarantees that a free slot exists
+            for (n, slot) in mgmt_ref.receiver_slots.iter().enumerate() {
+                if slot
+                    .state
+                    .compare_exchange(
+                        SLOT_FREE,
+                        SLOT_RESERVED,
+                        Ordering::Relaxed,
+                        Ordering::Relaxed,
+                    )
+                    .is_ok()
+                {
+                    return Ok(n);
+                }
+            }
+
+            mgmt_ref.state.fetch_sub(RECEIVER, Ordering::Relaxed);
+            fail!(from self, with ZeroCopyCreationError::InternalError,
+                "{} since no free receiver slot is available. The broadcast is maybe corrupted.", msg);
+        }
+    }
+
+    impl<Storage: DynamicStorage<SharedManagementData>> NamedConceptBuilder<Broadcast<Storage>>
+        for Builder<Storage>
+    {
+        fn new(name: &FileName) -> Self {
+            Self {
+                name: *name,
+                buffer_size: DEFAULT_BUFFER_SIZE,
+                enable_safe_overflow: DEFAULT_ENABLE_SAFE_OVERFLOW,
+                max_borrowed_samples: DEFAULT_MAX_BORROWED_SAMPLES,
+                max_receivers: DEFAULT_MAX_RECEIVERS,
+                number_of_samples_per_segment: 0,
+                number_of_segments: DEFAULT_MAX_SUPPORTED_SHARED_MEMORY_SEGMENTS,
+                receiver_id: 0,
+                initial_history: 0,
+                config: Configuration::default(),
+                timeout: Duration::ZERO,
+            }
+        }
+
+        fn config(mut self, config: &Configuration<Storage>) -> Self {
+            self.config = config.clone();
+            self
+        }
+    }
+
+    impl<Storage: DynamicStorage<SharedManagementData>> ZeroCopyBroadcastBuilder<Broadcast<Storage>>
+        for Builder<Storage>
+    {
+        fn buffer_size(mut self, value: usize) -> Self {
+            self.buffer_size = value.clamp(1, usize::MAX);
+            self
+        }
+
+        fn enable_safe_overflow(mut self, value: bool) -> Self {
+            self.enable_safe_overflow = value;
+            self
+        }
+
+        fn receiver_max_borrowed_samples(mut self, value: usize) -> Self {
+            self.max_borrowed_samples = value.clamp(1, usize::MAX);
+            self
+        }
+
+        fn max_receivers(mut self, value: usize) -> Self {
+            self.max_receivers = value.clamp(1, RECEIVER_COUNT_MASK as usize);
+            self
+        }
+
+        fn max_supported_shared_memory_segments(mut self, value: u8) -> Self {
+            self.number_of_segments = value.max(1);
+            self
+        }
+
+        fn number_of_samples_per_segment(mut self, value: usize) -> Self {
+            self.number_of_samples_per_segment = value;
+            self
+        }
+
+        fn receiver_id(mut self, value: u128) -> Self {
+            self.receiver_id = value;
+            self
+        }
+
+        fn initial_history(mut self, value: usize) -> Self {
+            self.initial_history = value;
+            self
+        }
+
+        fn timeout(mut self, value: Duration) -> Self {
+            self.timeout = value;
+            self
+        }
+
+        fn create_sender(
+            self,
+        ) -> Result<<Broadcast<Storage> as ZeroCopyBroadcast>::Sender, ZeroCopyCreationError>
+        {
+            let msg = "Unable to create sender";
+            let storage = fail!(from self, when self.create_or_open_shm(),
+                "{} since the corresponding broadcast could not be created or opened", msg);
+
+            self.reserve_sender(storage.get(), msg)?;
+
+            Ok(Sender {
+                storage,
+                name: self.name,
+            })
+        }
+
+        fn create_receiver(
+            self,
+        ) -> Result<<Broadcast<Storage> as ZeroCopyBroadcast>::Receiver, ZeroCopyCreationError>
+        {
+            let msg = "Unable to create receiver";
+            let storage = fail!(from self, when self.create_or_open_shm(),
+                "{} since the corresponding broadcast could not be created or opened", msg);
+
+            let slot_index = self.reserve_receiver(storage.get(), msg)?;
+            let mgmt = storage.get();
+            let slot = &mgmt.receiver_slots[slot_index];
+
+            let generation = slot.generation.fetch_add(1, Ordering::Relaxed) + 1;
+            slot.set_receiver_id(self.receiver_id);
+            slot.missed_samples.store(0, Ordering::Relaxed);
+            slot.failed_releases.store(0, Ordering::Relaxed);
+
+            let write_position = mgmt.write_position.load(Ordering::SeqCst);
+            let history = (self.initial_history as u64)
+                .min(mgmt.buffer_size as u64)
+                .min(write_position);
+            slot.read_position
+                .store(write_position - history, Ordering::SeqCst);
+            slot.state.store(SLOT_CONNECTED, Ordering::SeqCst);
+
+            Ok(Receiver {
+                storage,
+                slot_index,
+                generation,
+                borrow_counter: UnsafeCell::new(0),
+                name: self.name,
+            })
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct Sender<Storage: DynamicStorage<SharedManagementData>> {
+        storage: Storage,
+        name: FileName,
+    }
+
+    impl<Storage: DynamicStorage<SharedManagementData>> Drop for Sender<Storage> {
+        fn drop(&mut self) {
+            cleanup_shared_memory(&self.storage, SENDER);
+        }
+    }
+
+    impl<Storage: DynamicStorage<SharedManagementData>> Sender<Storage> {
+        // the ring buffer is full when at least one connected receiver did not yet consume
+        // the oldest entry
+        fn is_full(&self) -> bool {
+            let mgmt = self.storage.get();
+            let write_position = mgmt.write_position.load(Ordering::Relaxed);
+            let buffer_size = mgmt.buffer_size as u64;
+            if write_position < buffer_size {
+                return false;
+            }
+
+            mgmt.receiver_slots.iter().any(|slot| {
+                slot.state.load(Ordering::SeqCst) == SLOT_CONNECTED
+                    && slot.read_position.load(Ordering::SeqCst) + buffer_size <= write_position
+            })
+        }
+    }
+
+    impl<Storage: DynamicStorage<SharedManagementData>> NamedConcept for Sender<Storage> {
+        fn name(&self) -> &FileName {
+            &self.name
+        }
+    }
+
+    impl<Storage: DynamicStorage<SharedManagementData>> ZeroCopyBroadcastPortDetails
+        for Sender<Storage>
+    {
+        fn buffer_size(&self) -> usize {
+            self.storage.get().buffer_size
+        }
+
+        fn has_enabled_safe_overflow(&self) -> bool {
+            self.storage.get().enable_safe_overflow
+        }
+
+        fn max_borrowed_samples(&self) -> usize {
+            self.storage.get().max_borrowed_samples
+        }
+
+        fn max_receivers(&self) -> usize {
+            self.storage.get().max_receivers
+        }
+
+        fn max_supported_shared_memory_segments(&self) -> u8 {
+            self.storage.get().number_of_segments
+        }
+
+        fn number_of_receivers(&self) -> usize {
+            self.storage.get().number_of_receivers()
+        }
+
+        fn is_connected(&self) -> bool {
+            self.number_of_receivers() != 0
+        }
+
+        fn counters(&self) -> ZeroCopyConnectionCounters {
+            self.storage.get().counters(None)
+        }
+    }
+
+    impl<Storage: DynamicStorage<SharedManagementData>> ZeroCopyBroadcastSender for Sender<Storage> {
+        fn try_send(
+            &self,
+            ptr: PointerOffset,
+            sample_size: usize,
+        ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
+            let msg = "Unable to send sample";
+            let mgmt = self.storage.get();
+
+            if !mgmt.enable_safe_overflow && self.is_full() {
+                mgmt.rejected_sends.fetch_add(1, Ordering::Relaxed);
+                fail!(from self, with ZeroCopySendError::ReceiveBufferFull,
+                    "{} since the buffer of at least one receiver is full.", msg);
+            }
+
+            let segment_id = ptr.segment_id().value() as usize;
+            debug_assert!(segment_id < mgmt.number_of_segments as usize);
+            debug_assert!(ptr.offset() % sample_size == 0);
+            mgmt.sample_sizes[segment_id].store(sample_size, Ordering::SeqCst);
+
+            let reference_counter = match mgmt.reference_counter(ptr) {
+                Some(reference_counter) => reference_counter,
+                None => {
+                    fail!(from self, with ZeroCopySendError::ConnectionCorrupted,
+                        "{} since the offset {:?} does not point to a valid sample.", msg, ptr);
+                }
+            };
+
+            // the ring buffer holds one reference until the entry is overwritten
+            if reference_counter
+                .compare_exchange(0, 1, Ordering::SeqCst, Ordering::SeqCst)
+                .is_err()
+            {
+                fail!(from self, with ZeroCopySendError::UsedChunkListFull,
+                    "{} since the sample {:?} was already sent and is still in use.", msg, ptr);
+            }
+
+            let write_position = mgmt.write_position.load(Ordering::Relaxed);
+            let buffer_size = mgmt.buffer_size as u64;
+            let entry = &mgmt.ring[(write_position % buffer_size) as usize];
+            let overwritten_value = match write_position >= buffer_size {
+                true => Some(entry.value.load(Ordering::SeqCst)),
+                false => None,
+            };
+
+            entry.sequence.store(0, Ordering::SeqCst);
+            entry.value.store(ptr.as_value(), Ordering::SeqCst);
+            entry.sequence.store(write_position + 1, Ordering::SeqCst);
+            mgmt.write_position
+                .store(write_position + 1, Ordering::SeqCst);
+            #[cfg(feature = "sample_counters")]
+            mgmt.sent_samples.fetch_add(1, Ordering::Relaxed);
+
+            let overwritten = match overwritten_value {
+                None => return Ok(None),
+                Some(v) => PointerOffset::from_value(v),
+            };
+
+            match mgmt.reference_counter(overwritten) {
+                Some(reference_counter) => {
+                    if reference_counter.fetch_sub(1, Ordering::SeqCst) == 1 {
+                        Ok(Some(overwritten))
+                    } else {
+                        Ok(None)
+                    }
+                }
+                None => {
+                    fail!(from self, with ZeroCopySendError::ConnectionCorrupted,
+                        "{} since the overwritten entry contains the invalid offset {:?}.", msg, overwritten);
+                }
+            }
+        }
+
+        fn blocking_send(
+            &self,
+            ptr: PointerOffset,
+            sample_size: usize,
+        ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
+            if !self.storage.get().enable_safe_overflow {
+                AdaptiveWaitBuilder::new()
+                    .create()
+                    .unwrap()
+                    .wait_while(|| self.is_full())
+                    .unwrap();
+            }
+
+            self.try_send(ptr, sample_size)
+        }
+
+        fn blocking_send_with_timeout(
+            &self,
+            ptr: PointerOffset,
+            sample_size: usize,
+            timeout: Duration,
+        ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
+            if !self.storage.get().enable_safe_overflow {
+                // when the ring is still full after the timeout, try_send rejects the sample
+                AdaptiveWaitBuilder::new()
+                    .create()
+                    .unwrap()
+                    .timed_wait_while(|| -> Result<bool, ()> { Ok(self.is_full()) }, timeout)
+                    .unwrap();
+            }
+
+            self.try_send(ptr, sample_size)
+        }
+
+        fn reclaim(&self) -> Result<Option<PointerOffset>, ZeroCopyReclaimError> {
+            let mgmt = self.storage.get();
+            for slot in mgmt.receiver_slots.iter() {
+                if let Some(v) = unsafe { slot.completion_channel.pop() } {
+                    let pointer_offset = PointerOffset::from_value(v);
+                    if mgmt.reference_counter(pointer_offset).is_none() {
+                        fail!(from self, with ZeroCopyReclaimError::ReceiverReturnedCorruptedPointerOffset,
+                            "Unable to reclaim sample since the receiver returned a corrupted offset {:?}.",
+                            pointer_offset);
+                    }
+
+                    #[cfg(feature = "sample_counters")]
+                    mgmt.reclaimed_samples.fetch_add(1, Ordering::Relaxed);
+                    return Ok(Some(pointer_offset));
+                }
+            }
+
+            Ok(None)
+        }
+
+        unsafe fn acquire_used_offsets<F: FnMut(PointerOffset)>(&self, mut callback: F) {
+            let mgmt = self.storage.get();
+            for slot in mgmt.receiver_slots.iter() {
+                while let Some(v) = slot.completion_channel.pop() {
+                    callback(PointerOffset::from_value(v));
+                }
+            }
+
+            for (n, sample_size) in mgmt.sample_sizes.iter().enumerate() {
+                let sample_size = sample_size.load(Ordering::Relaxed);
+                let first = n * mgmt.number_of_samples_per_segment;
+                let reference_counters =
+                    &mgmt.reference_counters[first..first + mgmt.number_of_samples_per_segment];
+                for (index, reference_counter) in reference_counters.iter().enumerate() {
+                    if reference_counter.swap(0, Ordering::Relaxed) != 0 {
+                        callback(PointerOffset::from_offset_and_segment_id(
+                            index * sample_size,
+                            SegmentId::new(n as u8),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct Receiver<Storage: DynamicStorage<SharedManagementData>> {
+        storage: Storage,
+        slot_index: usize,
+        generation: u64,
+        borrow_counter: UnsafeCell<usize>,
+        name: FileName,
+    }
+
+    impl<Storage: DynamicStorage<SharedManagementData>> Drop for Receiver<Storage> {
+        fn drop(&mut self) {
+            // the slot was taken over by a forceful removal that already cleaned up
+            if self.is_of_previous_generation() {
+                return;
+            }
+
+            let mgmt = self.storage.get();
+            mgmt.release_all_borrowed_samples(self.slot());
+            self.slot().state.store(SLOT_FREE, Ordering::SeqCst);
+            cleanup_shared_memory(&self.storage, RECEIVER);
+        }
+    }
+
+    impl<Storage: DynamicStorage<SharedManagementData>> Receiver<Storage> {
+        #[allow(clippy::mut_from_ref)]
+        // convenience to access internal mutable object
+        fn borrow_counter(&self) -> &mut usize {
+            #[deny(clippy::mut_from_ref)]
+            unsafe {
+                &mut *self.borrow_counter.get()
+            }
+        }
+
+        fn slot(&self) -> &ReceiverSlot {
+            &self.storage.get().receiver_slots[self.slot_index]
+        }
+
+        fn is_of_previous_generation(&self) -> bool {
+            self.slot().generation.load(Ordering::Relaxed) != self.generation
+        }
+
+        // Acquires a reference to the sample of the entry when the entry still contains the
+        // sample of the expected sequence, otherwise it was overwritten in the meantime.
+        fn acquire_entry(&self, entry: &RingEntry, sequence: u64) -> Option<u64> {
+            let mgmt = self.storage.get();
+            if entry.sequence.load(Ordering::SeqCst) != sequence {
+                return None;
+            }
+
+            let value = entry.value.load(Ordering::SeqCst);
+            let reference_counter = mgmt.reference_counter(PointerOffset::from_value(value))?;
+            let mut current = reference_counter.load(Ordering::SeqCst);
+            loop {
+                if current == 0 {
+                    return None;
+                }
+
+                match reference_counter.compare_exchange(
+                    current,
+                    current + 1,
+                    Ordering::SeqCst,
+                    Ordering::SeqCst,
+                ) {
+                    Ok(_) => break,
+                    Err(v) => current = v,
+                }
+            }
+
+            if entry.sequence.load(Ordering::SeqCst) != sequence {
+                if !mgmt.release_reference(self.slot(), value) {
+                    self.slot().failed_releases.fetch_add(1, Ordering::Relaxed);
+                }
+                return None;
+            }
+
+            Some(value)
+        }
+    }
+
+    impl<Storage: DynamicStorage<SharedManagementData>> NamedConcept for Receiver<Storage> {
+        fn name(&self) -> &FileName {
+            &self.name
+        }
+    }
+
+    impl<Storage: DynamicStorage<SharedManagementData>> ZeroCopyBroadcastPortDetails
+        for Receiver<Storage>
+    {
+        fn buffer_size(&self) -> usize {
+            self.storage.get().buffer_size
+        }
+
+        fn has_enabled_safe_overflow(&self) -> bool {
+            self.storage.get().enable_safe_overflow
+        }
+
+        fn max_borrowed_samples(&self) -> usize {
+            self.storage.get().max_borrowed_samples
+        }
+
+        fn max_receivers(&self) -> usize {
+            self.storage.get().max_receivers
+        }
+
+        fn max_supported_shared_memory_segments(&self) -> u8 {
+            self.storage.get().number_of_segments
+        }
+
+        fn number_of_receivers(&self) -> usize {
+            self.storage.get().number_of_receivers()
+        }
+
+        fn is_connected(&self) -> bool {
+            self.storage.get().state.load(Ordering::Relaxed) & SENDER != 0
+        }
+
+        fn counters(&self) -> ZeroCopyConnectionCounters {
+            self.storage.get().counters(Some(self.slot()))
+        }
+    }
+
+    impl<Storage: DynamicStorage<SharedManagementData>> ZeroCopyBroadcastReceiver
+        for Receiver<Storage>
+    {
+        fn has_data(&self) -> bool {
+            self.number_of_pending_samples() != 0
+        }
+
+        fn number_of_pending_samples(&self) -> usize {
+            let mgmt = self.storage.get();
+            let write_position = mgmt.write_position.load(Ordering::SeqCst);
+            let read_position = self.slot().read_position.load(Ordering::Relaxed);
+            (write_position.saturating_sub(read_position) as usize).min(mgmt.buffer_size)
+        }
+
+        fn receive(&self) -> Result<Option<PointerOffset>, ZeroCopyReceiveError> {
+            let mgmt = self.storage.get();
+            if *self.borrow_counter() >= mgmt.max_borrowed_samples {
+                fail!(from self, with ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue,
+                    "Unable to receive another sample since already {} samples were borrowed and this would exceed the max borrow value of {}.",
+                    self.borrow_counter(), self.max_borrowed_samples());
+            }
+
+            let slot = self.slot();
+            let buffer_size = mgmt.buffer_size as u64;
+            loop {
+                let mut read_position = slot.read_position.load(Ordering::Relaxed);
+                let write_position = mgmt.write_position.load(Ordering::SeqCst);
+                if read_position >= write_position {
+                    return Ok(None);
+                }
+
+                // the sender overwrote entries this receiver did not consume in time
+                if write_position - read_position > buffer_size {
+                    slot.missed_samples.fetch_add(
+                        write_position - buffer_size - read_position,
+                        Ordering::Relaxed,
+                    );
+                    read_position = write_position - buffer_size;
+                }
+
+                let entry = &mgmt.ring[(read_position % buffer_size) as usize];
+                let value = self.acquire_entry(entry, read_position + 1);
+                slot.read_position
+                    .store(read_position + 1, Ordering::SeqCst);
+
+                match value {
+                    None => {
+                        slot.missed_samples.fetch_add(1, Ordering::Relaxed);
+                    }
+                    Some(value) => {
+                        match slot
+                            .borrowed_samples
+                            .iter()
+                            .find(|s| s.load(Ordering::Relaxed) == NO_SAMPLE)
+                        {
+                            Some(borrowed_sample) => {
+                                borrowed_sample.store(value, Ordering::Relaxed)
+                            }
+                            None => {
+                                fatal_panic!(from self,
+                                    "This should never happen! The borrowed sample list is full even though the borrow counter allows another sample.");
+                            }
+                        }
+
+                        *self.borrow_counter() += 1;
+                        return Ok(Some(PointerOffset::from_value(value)));
+                    }
+                }
+            }
+        }
+
+        fn release(&self, ptr: PointerOffset) -> Result<(), ZeroCopyReleaseError> {
+            let msg = "Unable to release pointer";
+            let mgmt = self.storage.get();
+            let slot = self.slot();
+
+            if self.is_of_previous_generation() {
+                slot.failed_releases.fetch_add(1, Ordering::Relaxed);
+                fail!(from self, with ZeroCopyReleaseError::ReceiverOfPreviousGeneration,
+                    "{} {:?} since the receiver was removed and its slot was taken over.", msg, ptr);
+            }
+
+            if slot.completion_channel.is_full() {
+                slot.failed_releases.fetch_add(1, Ordering::Relaxed);
+                fail!(from self, with ZeroCopyReleaseError::RetrieveBufferFull,
+                    "{} {:?} since the retrieve buffer is full.", msg, ptr);
+            }
+
+            let value = ptr.as_value();
+            match slot
+                .borrowed_samples
+                .iter()
+                .find(|s| s.load(Ordering::Relaxed) == value)
+            {
+                Some(borrowed_sample) => borrowed_sample.store(NO_SAMPLE, Ordering::Relaxed),
+                None => {
+                    slot.failed_releases.fetch_add(1, Ordering::Relaxed);
+                    warn!(from self, "{} {:?} since it was never received. The release is ignored.", msg, ptr);
+                    return Ok(());
+                }
+            }
+
+            if !mgmt.release_reference(slot, value) {
+                slot.failed_releases.fetch_add(1, Ordering::Relaxed);
+                warn!(from self, "{} {:?} since it does not point to a valid sample.", msg, ptr);
+            }
+
+            *self.borrow_counter() -= 1;
+            Ok(())
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct Broadcast<Storage: DynamicStorage<SharedManagementData>> {
+        _data: PhantomData<Storage>,
+    }
+
+    impl<Storage: DynamicStorage<SharedManagementData>> NamedConceptMgmt for Broadcast<Storage> {
+        type Configuration = Configuration<Storage>;
+
+        fn does_exist_cfg(
+            name: &FileName,
+            cfg: &Self::Configuration,
+        ) -> Result<bool, crate::static_storage::file::NamedConceptDoesExistError> {
+            Ok(fail!(from "ZeroCopyBroadcast::does_exist_cfg()",
+                    when Storage::does_exist_cfg(name, &cfg.dynamic_storage_config),
+                    "Failed to check if ZeroCopyBroadcast \"{}\" exists.",
+                    name))
+        }
+
+        fn list_cfg(
+            cfg: &Self::Configuration,
+        ) -> Result<Vec<FileName>, crate::static_storage::file::NamedConceptListError> {
+            Ok(fail!(from "ZeroCopyBroadcast::list_cfg()",
+                    when Storage::list_cfg(&cfg.dynamic_storage_config),
+                    "Failed to list all ZeroCopyBroadcasts."))
+        }
+
+        unsafe fn remove_cfg(
+            name: &FileName,
+            cfg: &Self::Configuration,
+        ) -> Result<bool, crate::static_storage::file::NamedConceptRemoveError> {
+            Ok(fail!(from "ZeroCopyBroadcast::remove_cfg()",
+                    when Storage::remove_cfg(name, &cfg.dynamic_storage_config),
+                    "Failed to remove ZeroCopyBroadcast \"{}\".", name))
+        }
+
+        fn remove_path_hint(_value: &Path) -> Result<(), NamedConceptPathHintRemoveError> {
+            Ok(())
+        }
+    }
+
+    impl<Storage: DynamicStorage<SharedManagementData>> Broadcast<Storage> {
+        fn open_storage(
+            name: &FileName,
+            config: &<Broadcast<Storage> as NamedConceptMgmt>::Configuration,
+            msg: &str,
+        ) -> Result<Storage, ZeroCopyPortRemoveError> {
+            let origin = "Broadcast::open_storage()";
+            match <<Storage as DynamicStorage<SharedManagementData>>::Builder<'_> as NamedConceptBuilder<
+                    Storage>>::new(name)
+                       .config(&config.dynamic_storage_config).open() {
+                           Ok(storage) => Ok(storage),
+                           Err(DynamicStorageOpenError::VersionMismatch) => {
+                               fail!(from origin, with ZeroCopyPortRemoveError::VersionMismatch,
+                                   "{msg} since the underlying dynamic storage has a different iceoryx2 version.");
+                           }
+                           Err(DynamicStorageOpenError::InitializationNotYetFinalized) => {
+                               fail!(from origin, with ZeroCopyPortRemoveError::InsufficientPermissions,
+                                   "{msg} due to insufficient permissions.");
+                           }
+                           Err(DynamicStorageOpenError::DoesNotExist) => {
+                               fail!(from origin, with ZeroCopyPortRemoveError::DoesNotExist,
+                                   "{msg} since the underlying dynamic storage does not exist.");
+                           }
+                           Err(DynamicStorageOpenError::InternalError) => {
+                               fail!(from origin, with ZeroCopyPortRemoveError::InternalError,
+                                   "{msg} due to an internal error.");
+                           }
+                       }
+        }
+    }
+
+    impl<Storage: DynamicStorage<SharedManagementData>> ZeroCopyBroadcast for Broadcast<Storage> {
+        type Sender = Sender<Storage>;
+        type Builder = Builder<Storage>;
+        type Receiver = Receiver<Storage>;
+
+        unsafe fn remove_sender(
+            name: &FileName,
+            config: &Self::Configuration,
+        ) -> Result<(), ZeroCopyPortRemoveError> {
+            let storage = Self::open_storage(
+                name,
+                config,
+                "Unable to remove forcefully the sender of the Zero Copy Broadcast",
+            )?;
+            cleanup_shared_memory(&storage, SENDER);
+            Ok(())
+        }
+
+        unsafe fn remove_receiver(
+            name: &FileName,
+            config: &Self::Configuration,
+            receiver_id: u128,
+        ) -> Result<(), ZeroCopyPortRemoveError> {
+            let storage = Self::open_storage(
+                name,
+                config,
+                "Unable to remove forcefully the receiver of the Zero Copy Broadcast",
+            )?;
+
+            let mgmt = storage.get();
+            for slot in mgmt.receiver_slots.iter() {
+                if slot.state.load(Ordering::SeqCst) == SLOT_CONNECTED
+                    && slot.receiver_id() == receiver_id
+                {
+                    // invalidates the removed receiver before its samples are released
+                    slot.generation.fetch_add(1, Ordering::Relaxed);
+                    mgmt.release_all_borrowed_samples(slot);
+                    slot.state.store(SLOT_FREE, Ordering::SeqCst);
+                    cleanup_shared_memory(&storage, RECEIVER);
+                }
+            }
+
+            Ok(())
+        }
+    }
+}
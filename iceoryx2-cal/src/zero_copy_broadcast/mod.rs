@@ -0,0 +1,190 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A one-to-many variant of the [`ZeroCopyConnection`](crate::zero_copy_connection::ZeroCopyConnection).
+//! One [`ZeroCopyBroadcastSender`] submits every [`PointerOffset`] once into a single ring
+//! buffer and every [`ZeroCopyBroadcastReceiver`] consumes it with its own read cursor. A
+//! sample is returned to the sender when it was overwritten in the ring and all receivers
+//! that received it have released it.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_cal::named_concept::*;
+//! use iceoryx2_cal::shm_allocator::{PointerOffset, SegmentId};
+//! use iceoryx2_cal::zero_copy_broadcast::*;
+//!
+//! fn example<Broadcast: ZeroCopyBroadcast>(name: &FileName) {
+//!     let sender = Broadcast::Builder::new(name)
+//!         .number_of_samples_per_segment(16)
+//!         .create_sender()
+//!         .unwrap();
+//!     let receiver_a = Broadcast::Builder::new(name)
+//!         .number_of_samples_per_segment(16)
+//!         .create_receiver()
+//!         .unwrap();
+//!     let receiver_b = Broadcast::Builder::new(name)
+//!         .number_of_samples_per_segment(16)
+//!         .create_receiver()
+//!         .unwrap();
+//!
+//!     // the sample is submitted once and received by both receivers
+//!     let offset = PointerOffset::from_offset_and_segment_id(0, SegmentId::new(0));
+//!     sender.try_send(offset, 128).unwrap();
+//!
+//!     let sample = receiver_a.receive().unwrap().unwrap();
+//!     receiver_a.release(sample).unwrap();
+//!     let sample = receiver_b.receive().unwrap().unwrap();
+//!     receiver_b.release(sample).unwrap();
+//! }
+//! ```
+
+pub mod common;
+pub mod posix_shared_memory;
+pub mod process_local;
+
+use core::fmt::Debug;
+use core::time::Duration;
+
+pub use crate::shared_memory::PointerOffset;
+use crate::static_storage::file::{NamedConcept, NamedConceptBuilder, NamedConceptMgmt};
+pub use crate::zero_copy_connection::{
+    ZeroCopyConnectionCounters, ZeroCopyCreationError, ZeroCopyPortRemoveError,
+    ZeroCopyReceiveError, ZeroCopyReclaimError, ZeroCopyReleaseError, ZeroCopySendError,
+};
+pub use iceoryx2_bb_system_types::file_name::*;
+pub use iceoryx2_bb_system_types::path::Path;
+
+pub const DEFAULT_BUFFER_SIZE: usize = 4;
+pub const DEFAULT_ENABLE_SAFE_OVERFLOW: bool = false;
+pub const DEFAULT_MAX_BORROWED_SAMPLES: usize = 4;
+pub const DEFAULT_MAX_RECEIVERS: usize = 8;
+pub const DEFAULT_MAX_SUPPORTED_SHARED_MEMORY_SEGMENTS: u8 = 1;
+
+pub trait ZeroCopyBroadcastBuilder<C: ZeroCopyBroadcast>: NamedConceptBuilder<C> {
+    /// Defines the capacity of the ring buffer that is shared by all receivers.
+    fn buffer_size(self, value: usize) -> Self;
+    fn enable_safe_overflow(self, value: bool) -> Self;
+    fn receiver_max_borrowed_samples(self, value: usize) -> Self;
+    /// Defines how many [`ZeroCopyBroadcastReceiver`]s can be connected at the same time.
+    fn max_receivers(self, value: usize) -> Self;
+    fn max_supported_shared_memory_segments(self, value: u8) -> Self;
+    fn number_of_samples_per_segment(self, value: usize) -> Self;
+    /// The id under which the [`ZeroCopyBroadcastReceiver`] is registered. It is required to
+    /// remove a dead receiver with [`ZeroCopyBroadcast::remove_receiver()`].
+    fn receiver_id(self, value: u128) -> Self;
+    /// Defines how many of the most recent samples that are still in the ring buffer are
+    /// delivered to a newly created [`ZeroCopyBroadcastReceiver`]. By default it is zero.
+    fn initial_history(self, value: usize) -> Self;
+    /// The timeout defines how long the [`ZeroCopyBroadcastBuilder`] should wait for
+    /// concurrent
+    /// [`ZeroCopyBroadcastBuilder::create_sender()`] or
+    /// [`ZeroCopyBroadcastBuilder::create_receiver()`] call to finalize its initialization.
+    /// By default it is set to [`Duration::ZERO`] for no timeout.
+    fn timeout(self, value: Duration) -> Self;
+
+    fn create_sender(self) -> Result<C::Sender, ZeroCopyCreationError>;
+    fn create_receiver(self) -> Result<C::Receiver, ZeroCopyCreationError>;
+}
+
+pub trait ZeroCopyBroadcastPortDetails {
+    fn buffer_size(&self) -> usize;
+    fn has_enabled_safe_overflow(&self) -> bool;
+    fn max_borrowed_samples(&self) -> usize;
+    fn max_receivers(&self) -> usize;
+    fn max_supported_shared_memory_segments(&self) -> u8;
+    /// Returns the number of currently connected [`ZeroCopyBroadcastReceiver`]s.
+    fn number_of_receivers(&self) -> usize;
+    fn is_connected(&self) -> bool;
+    /// Returns the current [`ZeroCopyConnectionCounters`]. The overflowed samples and failed
+    /// releases are the sum over all receivers on the [`ZeroCopyBroadcastSender`] side and
+    /// the values of the receiver itself on the [`ZeroCopyBroadcastReceiver`] side.
+    fn counters(&self) -> ZeroCopyConnectionCounters;
+}
+
+pub trait ZeroCopyBroadcastSender: Debug + ZeroCopyBroadcastPortDetails + NamedConcept {
+    /// Submits the [`PointerOffset`] once for all connected receivers. When the ring buffer
+    /// is full and safe overflow is enabled, the oldest entry is overwritten. If the
+    /// overwritten sample is no longer held by any receiver it is returned.
+    fn try_send(
+        &self,
+        ptr: PointerOffset,
+        sample_size: usize,
+    ) -> Result<Option<PointerOffset>, ZeroCopySendError>;
+
+    fn blocking_send(
+        &self,
+        ptr: PointerOffset,
+        sample_size: usize,
+    ) -> Result<Option<PointerOffset>, ZeroCopySendError>;
+
+    /// Like [`ZeroCopyBroadcastSender::blocking_send()`] but waits at most for the provided
+    /// timeout until the ring buffer has space again. When the ring buffer is still full after
+    /// the timeout has passed, it fails with [`ZeroCopySendError::ReceiveBufferFull`].
+    fn blocking_send_with_timeout(
+        &self,
+        ptr: PointerOffset,
+        sample_size: usize,
+        timeout: Duration,
+    ) -> Result<Option<PointerOffset>, ZeroCopySendError>;
+
+    /// Returns a sample that was released by the last receiver that held it.
+    fn reclaim(&self) -> Result<Option<PointerOffset>, ZeroCopyReclaimError>;
+
+    /// # Safety
+    ///
+    /// * must ensure that no receiver is still holding data, otherwise data races may occur on
+    ///     receiver side
+    /// * must ensure that [`ZeroCopyBroadcastSender::try_send()`] and
+    ///     [`ZeroCopyBroadcastSender::blocking_send()`] are not called after using this method
+    unsafe fn acquire_used_offsets<F: FnMut(PointerOffset)>(&self, callback: F);
+}
+
+pub trait ZeroCopyBroadcastReceiver: Debug + ZeroCopyBroadcastPortDetails + NamedConcept {
+    fn has_data(&self) -> bool;
+    fn number_of_pending_samples(&self) -> usize;
+    fn receive(&self) -> Result<Option<PointerOffset>, ZeroCopyReceiveError>;
+    fn release(&self, ptr: PointerOffset) -> Result<(), ZeroCopyReleaseError>;
+}
+
+pub trait ZeroCopyBroadcast: Debug + Sized + NamedConceptMgmt {
+    type Sender: ZeroCopyBroadcastSender;
+    type Receiver: ZeroCopyBroadcastReceiver;
+    type Builder: ZeroCopyBroadcastBuilder<Self>;
+
+    /// Removes the [`ZeroCopyBroadcastSender`] forcefully from the [`ZeroCopyBroadcast`].
+    ///
+    /// # Safety
+    ///
+    ///  * must ensure that the [`ZeroCopyBroadcastSender`] died while being connected.
+    unsafe fn remove_sender(
+        name: &FileName,
+        config: &Self::Configuration,
+    ) -> Result<(), ZeroCopyPortRemoveError>;
+
+    /// Removes the [`ZeroCopyBroadcastReceiver`] with the provided id forcefully from the
+    /// [`ZeroCopyBroadcast`] and releases all samples it still held.
+    ///
+    /// # Safety
+    ///
+    ///  * must ensure that the [`ZeroCopyBroadcastReceiver`] died while being connected.
+    unsafe fn remove_receiver(
+        name: &FileName,
+        config: &Self::Configuration,
+        receiver_id: u128,
+    ) -> Result<(), ZeroCopyPortRemoveError>;
+
+    /// The default suffix of every zero copy broadcast
+    fn default_suffix() -> FileName {
+        unsafe { FileName::new_unchecked(b".bc") }
+    }
+}
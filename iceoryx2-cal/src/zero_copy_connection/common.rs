@@ -12,12 +12,15 @@
 
 #[doc(hidden)]
 pub mod details {
-    use core::cell::UnsafeCell;
+    use core::cell::{Cell, UnsafeCell};
     use core::fmt::Debug;
     use core::marker::PhantomData;
+    use core::ops::RangeInclusive;
     use core::sync::atomic::Ordering;
     use iceoryx2_bb_elementary::allocator::{AllocationError, BaseAllocator};
-    use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicU8, IoxAtomicUsize};
+    use iceoryx2_pal_concurrency_sync::iox_atomic::{
+        IoxAtomicBool, IoxAtomicU64, IoxAtomicU8, IoxAtomicUsize,
+    };
 
     use crate::dynamic_storage::{
         DynamicStorage, DynamicStorageBuilder, DynamicStorageCreateError, DynamicStorageOpenError,
@@ -34,6 +37,7 @@ pub mod details {
     };
     use iceoryx2_bb_log::{fail, fatal_panic, warn};
     use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+    use std::collections::VecDeque;
 
     use self::used_chunk_list::RelocatableUsedChunkList;
 
@@ -157,6 +161,70 @@ pub mod details {
         }
     }
 
+    // The upper bits of every transported value contain the generation of the connection. It
+    // is increased whenever a receiver connects so that offsets of a previous receiver, that
+    // was removed and replaced, can be detected.
+    const GENERATION_SHIFT: u32 = u64::BITS - u8::BITS;
+
+    fn add_generation(value: u64, generation: u8) -> u64 {
+        debug_assert!(
+            value >> GENERATION_SHIFT == 0,
+            "The value {} must not use the bits that are reserved for the generation.",
+            value
+        );
+        value | ((generation as u64) << GENERATION_SHIFT)
+    }
+
+    fn remove_generation(value: u64) -> (PointerOffset, u8) {
+        (
+            PointerOffset::from_value(value & ((1u64 << GENERATION_SHIFT) - 1)),
+            (value >> GENERATION_SHIFT) as u8,
+        )
+    }
+
+    // A run of consecutive samples is transported as one value. It is marked with the bit
+    // below the generation and stores the index of the first sample instead of its offset,
+    // the number of samples in the run is stored in the bits in between.
+    const SAMPLE_RUN_FLAG: u64 = 1 << (GENERATION_SHIFT - 1);
+    const SAMPLE_RUN_LENGTH_SHIFT: u32 =
+        GENERATION_SHIFT - 1 - (u64::BITS - (MAX_SAMPLE_RUN_LENGTH as u64).leading_zeros());
+    const SAMPLE_RUN_INDEX_SHIFT: u32 = u8::BITS;
+
+    fn encode_sample_run(segment_id: SegmentId, first_index: usize, length: usize) -> u64 {
+        debug_assert!(
+            (first_index as u64) < 1 << (SAMPLE_RUN_LENGTH_SHIFT - SAMPLE_RUN_INDEX_SHIFT),
+            "The index {} of the first sample of the run exceeds the supported range.",
+            first_index
+        );
+        SAMPLE_RUN_FLAG
+            | ((length as u64) << SAMPLE_RUN_LENGTH_SHIFT)
+            | ((first_index as u64) << SAMPLE_RUN_INDEX_SHIFT)
+            | segment_id.value() as u64
+    }
+
+    // returns the segment id, the index of the first sample and the length of the run
+    fn decode_sample_run(ptr: PointerOffset) -> Option<(SegmentId, usize, usize)> {
+        let value = ptr.as_value();
+        if value & SAMPLE_RUN_FLAG == 0 {
+            return None;
+        }
+
+        Some((
+            ptr.segment_id(),
+            ((value & ((1 << SAMPLE_RUN_LENGTH_SHIFT) - 1)) >> SAMPLE_RUN_INDEX_SHIFT) as usize,
+            ((value & !SAMPLE_RUN_FLAG) >> SAMPLE_RUN_LENGTH_SHIFT) as usize,
+        ))
+    }
+
+    // the samples of a received run that were not yet handed out by the receiver
+    #[derive(Debug, Clone, Copy)]
+    struct PendingSampleRun {
+        segment_id: SegmentId,
+        next_index: usize,
+        remaining: usize,
+        sample_size: usize,
+    }
+
     #[derive(Debug)]
     struct SegmentDetails {
         used_chunk_list: RelocatableUsedChunkList,
@@ -180,61 +248,183 @@ pub mod details {
         }
     }
 
+    fn is_inline_sample(ptr: PointerOffset) -> bool {
+        ptr.segment_id().value() == INLINE_SAMPLE_SEGMENT_ID
+    }
+
+    // One word of an inline slot. It is only written by the sender while the slot is not
+    // in use and only read by the receiver after the slot was sent, the submission channel
+    // synchronizes both.
+    #[derive(Debug)]
+    #[repr(transparent)]
+    struct InlineWord(UnsafeCell<u64>);
+
+    unsafe impl Send for InlineWord {}
+    unsafe impl Sync for InlineWord {}
+
+    const INLINE_WORD_SIZE: usize = core::mem::size_of::<u64>();
+
     #[derive(Debug)]
     #[repr(C)]
     pub struct SharedManagementData {
+        // must always be the first field, see ZERO_COPY_CONNECTION_PROTOCOL_VERSION
+        protocol_version: u32,
         submission_channel: RelocatableSafelyOverflowingIndexQueue,
+        priority_submission_channel: RelocatableSafelyOverflowingIndexQueue,
         completion_channel: RelocatableIndexQueue,
         segment_details: RelocatableVec<SegmentDetails>,
+        inline_slot_list: RelocatableUsedChunkList,
+        inline_slots: RelocatableVec<InlineWord>,
+        inline_sample_size: usize,
+        number_of_inline_slots: usize,
+        max_sample_run_length: usize,
         max_borrowed_samples: usize,
         number_of_samples_per_segment: usize,
         number_of_segments: u8,
         state: IoxAtomicU8,
+        generation: IoxAtomicU8,
         init_state: IoxAtomicU64,
         enable_safe_overflow: bool,
+        enable_priority_lane: bool,
+        sent_samples: IoxAtomicU64,
+        reclaimed_samples: IoxAtomicU64,
+        overflowed_samples: IoxAtomicU64,
+        rejected_sends: IoxAtomicU64,
+        failed_releases: IoxAtomicU64,
+        is_above_high_watermark: IoxAtomicBool,
+        has_redelivery_request: IoxAtomicBool,
+        has_new_segment_announcement: IoxAtomicBool,
+        has_reclaim_notification_request: IoxAtomicBool,
     }
 
     impl SharedManagementData {
         fn new(
             submission_channel_buffer_capacity: usize,
+            priority_submission_channel_buffer_capacity: usize,
             completion_channel_buffer_capacity: usize,
             enable_safe_overflow: bool,
+            enable_priority_lane: bool,
             max_borrowed_samples: usize,
             number_of_samples_per_segment: usize,
             number_of_segments: u8,
+            inline_sample_size: usize,
+            number_of_inline_slots: usize,
+            max_sample_run_length: usize,
         ) -> Self {
             Self {
+                protocol_version: ZERO_COPY_CONNECTION_PROTOCOL_VERSION,
                 submission_channel: unsafe {
                     RelocatableSafelyOverflowingIndexQueue::new_uninit(
                         submission_channel_buffer_capacity,
                     )
                 },
+                priority_submission_channel: unsafe {
+                    RelocatableSafelyOverflowingIndexQueue::new_uninit(
+                        priority_submission_channel_buffer_capacity,
+                    )
+                },
                 completion_channel: unsafe {
                     RelocatableIndexQueue::new_uninit(completion_channel_buffer_capacity)
                 },
                 segment_details: unsafe { RelocatableVec::new_uninit(number_of_segments as usize) },
+                inline_slot_list: unsafe {
+                    RelocatableUsedChunkList::new_uninit(number_of_inline_slots.max(1))
+                },
+                inline_slots: unsafe {
+                    RelocatableVec::new_uninit(Self::number_of_inline_words(
+                        inline_sample_size,
+                        number_of_inline_slots,
+                    ))
+                },
+                inline_sample_size,
+                number_of_inline_slots,
+                max_sample_run_length,
                 state: IoxAtomicU8::new(State::None.value()),
+                generation: IoxAtomicU8::new(0),
                 init_state: IoxAtomicU64::new(0),
                 enable_safe_overflow,
+                enable_priority_lane,
                 max_borrowed_samples,
                 number_of_samples_per_segment,
                 number_of_segments,
+                sent_samples: IoxAtomicU64::new(0),
+                reclaimed_samples: IoxAtomicU64::new(0),
+                overflowed_samples: IoxAtomicU64::new(0),
+                rejected_sends: IoxAtomicU64::new(0),
+                failed_releases: IoxAtomicU64::new(0),
+                is_above_high_watermark: IoxAtomicBool::new(false),
+                has_redelivery_request: IoxAtomicBool::new(false),
+                has_new_segment_announcement: IoxAtomicBool::new(false),
+                has_reclaim_notification_request: IoxAtomicBool::new(false),
+            }
+        }
+
+        fn counters(&self) -> ZeroCopyConnectionCounters {
+            ZeroCopyConnectionCounters {
+                sent_samples: self.sent_samples.load(Ordering::Relaxed),
+                reclaimed_samples: self.reclaimed_samples.load(Ordering::Relaxed),
+                overflowed_samples: self.overflowed_samples.load(Ordering::Relaxed),
+                rejected_sends: self.rejected_sends.load(Ordering::Relaxed),
+                failed_releases: self.failed_releases.load(Ordering::Relaxed),
+            }
+        }
+
+        // the vector requires a capacity of at least one, it remains unused when inline
+        // samples are disabled
+        const fn number_of_inline_words(
+            inline_sample_size: usize,
+            number_of_inline_slots: usize,
+        ) -> usize {
+            let number_of_words = inline_sample_size / INLINE_WORD_SIZE * number_of_inline_slots;
+            if number_of_words == 0 {
+                1
+            } else {
+                number_of_words
+            }
+        }
+
+        fn inline_slot(&self, ptr: PointerOffset) -> Option<&[InlineWord]> {
+            let index = ptr.offset();
+            if !is_inline_sample(ptr) || index >= self.number_of_inline_slots {
+                return None;
             }
+
+            let words_per_slot = self.inline_sample_size / INLINE_WORD_SIZE;
+            Some(&self.inline_slots[index * words_per_slot..(index + 1) * words_per_slot])
+        }
+
+        fn release_inline_slot(&self, ptr: PointerOffset) -> bool {
+            ptr.offset() < self.number_of_inline_slots && self.inline_slot_list.remove(ptr.offset())
         }
 
         const fn const_memory_size(
             submission_channel_buffer_capacity: usize,
+            priority_submission_channel_buffer_capacity: usize,
             completion_channel_buffer_capacity: usize,
             number_of_samples: usize,
             number_of_segments: u8,
+            inline_sample_size: usize,
+            number_of_inline_slots: usize,
         ) -> usize {
             let number_of_segments = number_of_segments as usize;
             RelocatableIndexQueue::const_memory_size(completion_channel_buffer_capacity)
                 + RelocatableSafelyOverflowingIndexQueue::const_memory_size(
                     submission_channel_buffer_capacity,
                 )
+                + RelocatableSafelyOverflowingIndexQueue::const_memory_size(
+                    priority_submission_channel_buffer_capacity,
+                )
                 + SegmentDetails::const_memory_size(number_of_samples) * number_of_segments
                 + RelocatableVec::<SegmentDetails>::const_memory_size(number_of_segments)
+                + RelocatableUsedChunkList::const_memory_size(if number_of_inline_slots == 0 {
+                    1
+                } else {
+                    number_of_inline_slots
+                })
+                + RelocatableVec::<InlineWord>::const_memory_size(Self::number_of_inline_words(
+                    inline_sample_size,
+                    number_of_inline_slots,
+                ))
         }
     }
 
@@ -246,6 +436,12 @@ pub mod details {
         max_borrowed_samples: usize,
         number_of_samples_per_segment: usize,
         number_of_segments: u8,
+        high_watermark: Option<usize>,
+        low_watermark: Option<usize>,
+        enable_priority_lane: bool,
+        inline_sample_size: usize,
+        max_sample_run_length: usize,
+        accepted_protocol_versions: Option<RangeInclusive<u32>>,
         timeout: Duration,
         config: Configuration<Storage>,
     }
@@ -255,16 +451,45 @@ pub mod details {
             self.buffer_size
         }
 
+        // the queue requires a capacity of at least one, it remains unused when the
+        // priority lane is disabled
+        fn priority_submission_channel_size(&self) -> usize {
+            match self.enable_priority_lane {
+                true => self.buffer_size,
+                false => 1,
+            }
+        }
+
+        // every entry of the receive buffer can hold a run of samples
         fn completion_channel_size(&self) -> usize {
-            self.buffer_size + self.max_borrowed_samples + 1
+            let number_of_lanes = if self.enable_priority_lane { 2 } else { 1 };
+            number_of_lanes * self.buffer_size * self.max_sample_run_length
+                + self.max_borrowed_samples
+                + 1
+        }
+
+        // an inline slot is in use from the moment it is staged until the sender reclaimed
+        // it, therefore every channel entry and every borrowed sample requires a slot
+        fn number_of_inline_slots(&self) -> usize {
+            match self.inline_sample_size {
+                0 => 0,
+                _ => {
+                    self.submission_channel_size()
+                        + self.priority_submission_channel_size()
+                        + self.completion_channel_size()
+                }
+            }
         }
 
         fn create_or_open_shm(&self) -> Result<Storage, ZeroCopyCreationError> {
             let supplementary_size = SharedManagementData::const_memory_size(
                 self.submission_channel_size(),
+                self.priority_submission_channel_size(),
                 self.completion_channel_size(),
                 self.number_of_samples_per_segment,
                 self.number_of_segments,
+                self.inline_sample_size,
+                self.number_of_inline_slots(),
             );
 
             let msg = "Failed to acquire underlying shared memory";
@@ -273,10 +498,13 @@ pub mod details {
         >>::new(&self.name)
         .config(&self.config.dynamic_storage_config)
         .timeout(self.timeout)
+        .verify_package_version(self.accepted_protocol_versions.is_none())
         .supplementary_size(supplementary_size)
         .initializer(|data, allocator| {
             fatal_panic!(from self, when unsafe { data.submission_channel.init(allocator) },
                         "{} since the receive channel allocation failed. - This is an implementation bug!", msg);
+            fatal_panic!(from self, when unsafe { data.priority_submission_channel.init(allocator) },
+                        "{} since the priority receive channel allocation failed. - This is an implementation bug!", msg);
             fatal_panic!(from self, when unsafe { data.completion_channel.init(allocator) },
                         "{} since the retrieve channel allocation failed. - This is an implementation bug!", msg);
             fatal_panic!(from self, when unsafe { data.segment_details.init(allocator) },
@@ -297,16 +525,33 @@ pub mod details {
                     msg, n);
             }
 
+            fatal_panic!(from self, when unsafe { data.inline_slot_list.init(allocator) },
+                        "{} since the inline slot list allocation failed. - This is an implementation bug!", msg);
+            fatal_panic!(from self, when unsafe { data.inline_slots.init(allocator) },
+                        "{} since the inline slot vector allocation failed. - This is an implementation bug!", msg);
+
+            for _ in 0..data.inline_slots.capacity() {
+                if !unsafe { data.inline_slots.push(InlineWord(UnsafeCell::new(0))) } {
+                    fatal_panic!(from self,
+                        "{} since the inline slot could not be added. - This is an implementation bug!", msg);
+                }
+            }
+
             true
         })
         .open_or_create(
             SharedManagementData::new(
                                     self.submission_channel_size(),
+                                    self.priority_submission_channel_size(),
                                     self.completion_channel_size(),
                                     self.enable_safe_overflow,
+                                    self.enable_priority_lane,
                                     self.max_borrowed_samples,
                                     self.number_of_samples_per_segment,
-                                    self.number_of_segments
+                                    self.number_of_segments,
+                                    self.inline_sample_size,
+                                    self.number_of_inline_slots(),
+                                    self.max_sample_run_length
                                 )
             );
 
@@ -341,16 +586,38 @@ pub mod details {
             } else {
                 let msg = "Failed to open existing connection";
 
+                let protocol_version = storage.get().protocol_version;
+                let accepted_protocol_versions = self.accepted_protocol_versions.clone().unwrap_or(
+                    ZERO_COPY_CONNECTION_PROTOCOL_VERSION..=ZERO_COPY_CONNECTION_PROTOCOL_VERSION,
+                );
+                if !accepted_protocol_versions.contains(&protocol_version) {
+                    fail!(from self, with ZeroCopyCreationError::VersionMismatch,
+                        "{} since the connection has the protocol version {} but only the versions {:?} are accepted.",
+                        msg, protocol_version, accepted_protocol_versions);
+                }
+
                 if storage.get().submission_channel.capacity() != self.submission_channel_size() {
                     fail!(from self, with ZeroCopyCreationError::IncompatibleBufferSize,
                         "{} since the connection has a buffer size of {} but a buffer size of {} is required.",
                         msg, storage.get().submission_channel.capacity(), self.submission_channel_size());
                 }
 
+                if storage.get().enable_priority_lane != self.enable_priority_lane {
+                    fail!(from self, with ZeroCopyCreationError::IncompatiblePriorityLaneSetting,
+                        "{} since the priority lane is set to {} but should be set to {}.",
+                        msg, storage.get().enable_priority_lane, self.enable_priority_lane);
+                }
+
+                if storage.get().max_sample_run_length != self.max_sample_run_length {
+                    fail!(from self, with ZeroCopyCreationError::IncompatibleMaxSampleRunLength,
+                        "{} since the requested max sample run length is set to {} but should be set to {}.",
+                        msg, self.max_sample_run_length, storage.get().max_sample_run_length);
+                }
+
                 if storage.get().completion_channel.capacity() != self.completion_channel_size() {
                     fail!(from self, with ZeroCopyCreationError::IncompatibleMaxBorrowedSampleSetting,
                         "{} since the max borrowed sample setting is set to {} but a value of {} is required.",
-                        msg, storage.get().completion_channel.capacity() - storage.get().submission_channel.capacity(), self.max_borrowed_samples);
+                        msg, storage.get().completion_channel.capacity() + self.max_borrowed_samples - self.completion_channel_size(), self.max_borrowed_samples);
                 }
 
                 if storage.get().enable_safe_overflow != self.enable_safe_overflow {
@@ -377,6 +644,12 @@ pub mod details {
                         "{} since the requested number of segments is set to {} but should be set to {}.",
                         msg, self.number_of_segments, storage.get().number_of_segments);
                 }
+
+                if storage.get().inline_sample_size != self.inline_sample_size {
+                    fail!(from self, with ZeroCopyCreationError::IncompatibleInlineSampleSize,
+                        "{} since the requested inline sample size is set to {} but should be set to {}.",
+                        msg, self.inline_sample_size, storage.get().inline_sample_size);
+                }
             }
 
             Ok(storage)
@@ -426,6 +699,12 @@ pub mod details {
                 max_borrowed_samples: DEFAULT_MAX_BORROWED_SAMPLES,
                 number_of_samples_per_segment: 0,
                 number_of_segments: DEFAULT_MAX_SUPPORTED_SHARED_MEMORY_SEGMENTS,
+                high_watermark: None,
+                low_watermark: None,
+                enable_priority_lane: DEFAULT_ENABLE_PRIORITY_LANE,
+                inline_sample_size: DEFAULT_INLINE_SAMPLE_SIZE,
+                max_sample_run_length: DEFAULT_MAX_SAMPLE_RUN_LENGTH,
+                accepted_protocol_versions: None,
                 config: Configuration::default(),
                 timeout: Duration::ZERO,
             }
@@ -470,6 +749,45 @@ pub mod details {
             self
         }
 
+        fn high_watermark(mut self, value: usize) -> Self {
+            self.high_watermark = Some(value.clamp(1, usize::MAX));
+            self
+        }
+
+        fn low_watermark(mut self, value: usize) -> Self {
+            self.low_watermark = Some(value);
+            self
+        }
+
+        fn enable_priority_lane(mut self, value: bool) -> Self {
+            self.enable_priority_lane = value;
+            self
+        }
+
+        fn inline_sample_size(mut self, value: usize) -> Self {
+            self.inline_sample_size = value.next_multiple_of(INLINE_WORD_SIZE);
+            self
+        }
+
+        fn max_sample_run_length(mut self, value: usize) -> Self {
+            self.max_sample_run_length = value.clamp(1, MAX_SAMPLE_RUN_LENGTH);
+            self
+        }
+
+        fn accepted_protocol_versions(mut self, value: RangeInclusive<u32>) -> Self {
+            let start = *value
+                .start()
+                .max(&MIN_SUPPORTED_ZERO_COPY_CONNECTION_PROTOCOL_VERSION);
+            let end = *value.end().min(&ZERO_COPY_CONNECTION_PROTOCOL_VERSION);
+            if start > end {
+                warn!(from self,
+                    "The accepted protocol versions {:?} do not contain any supported protocol version ({}..={}). No existing connection can be opened.",
+                    value, MIN_SUPPORTED_ZERO_COPY_CONNECTION_PROTOCOL_VERSION, ZERO_COPY_CONNECTION_PROTOCOL_VERSION);
+            }
+            self.accepted_protocol_versions = Some(start..=end);
+            self
+        }
+
         fn create_sender(
             self,
         ) -> Result<<Connection<Storage> as ZeroCopyConnection>::Sender, ZeroCopyCreationError>
@@ -479,10 +797,28 @@ pub mod details {
             "{} since the corresponding connection could not be created or opened", msg);
 
             self.reserve_port(storage.get(), State::Sender.value(), msg)?;
+            storage
+                .get()
+                .is_above_high_watermark
+                .store(false, Ordering::Relaxed);
+            // requests that were made before the sender existed are obsolete
+            storage
+                .get()
+                .has_redelivery_request
+                .store(false, Ordering::Relaxed);
+
+            let low_watermark = match (self.high_watermark, self.low_watermark) {
+                (Some(high), Some(low)) if low < high => low,
+                (Some(high), _) => high - 1,
+                (None, _) => 0,
+            };
 
             Ok(Sender {
                 storage,
                 name: self.name,
+                high_watermark: self.high_watermark,
+                low_watermark,
+                overflowed_run_samples: UnsafeCell::new(VecDeque::new()),
             })
         }
 
@@ -495,10 +831,17 @@ pub mod details {
             "{} since the corresponding connection could not be created or opened", msg);
 
             self.reserve_port(storage.get(), State::Receiver.value(), msg)?;
+            let generation = storage
+                .get()
+                .generation
+                .fetch_add(1, Ordering::Relaxed)
+                .wrapping_add(1);
 
             Ok(Receiver {
                 storage,
                 borrow_counter: UnsafeCell::new(0),
+                pending_run: Cell::new(None),
+                generation,
                 name: self.name,
             })
         }
@@ -508,6 +851,9 @@ pub mod details {
     pub struct Sender<Storage: DynamicStorage<SharedManagementData>> {
         storage: Storage,
         name: FileName,
+        high_watermark: Option<usize>,
+        low_watermark: usize,
+        overflowed_run_samples: UnsafeCell<VecDeque<PointerOffset>>,
     }
 
     impl<Storage: DynamicStorage<SharedManagementData>> Drop for Sender<Storage> {
@@ -543,58 +889,186 @@ pub mod details {
             self.storage.get().state.load(Ordering::Relaxed)
                 == State::Sender.value() | State::Receiver.value()
         }
+
+        fn has_priority_lane(&self) -> bool {
+            self.storage.get().enable_priority_lane
+        }
+
+        fn inline_sample_size(&self) -> usize {
+            self.storage.get().inline_sample_size
+        }
+
+        fn max_sample_run_length(&self) -> usize {
+            self.storage.get().max_sample_run_length
+        }
+
+        fn protocol_version(&self) -> u32 {
+            self.storage.get().protocol_version
+        }
+
+        fn counters(&self) -> ZeroCopyConnectionCounters {
+            self.storage.get().counters()
+        }
     }
 
-    impl<Storage: DynamicStorage<SharedManagementData>> ZeroCopySender for Sender<Storage> {
-        fn try_send(
+    impl<Storage: DynamicStorage<SharedManagementData>> Sender<Storage> {
+        #[allow(clippy::mut_from_ref)]
+        // convenience to access internal mutable object
+        fn overflowed_run_samples(&self) -> &mut VecDeque<PointerOffset> {
+            #[deny(clippy::mut_from_ref)]
+            unsafe {
+                &mut *self.overflowed_run_samples.get()
+            }
+        }
+
+        fn send_impl(
             &self,
             ptr: PointerOffset,
             sample_size: usize,
+            enable_overflow: bool,
+            is_high_priority: bool,
         ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
             let msg = "Unable to send sample";
             let storage = self.storage.get();
+            let channel = match is_high_priority && storage.enable_priority_lane {
+                true => &storage.priority_submission_channel,
+                false => &storage.submission_channel,
+            };
 
-            if !storage.enable_safe_overflow && storage.submission_channel.is_full() {
+            if !enable_overflow && channel.is_full() {
+                storage.rejected_sends.fetch_add(1, Ordering::Relaxed);
+                if is_inline_sample(ptr) {
+                    storage.release_inline_slot(ptr);
+                }
                 fail!(from self, with ZeroCopySendError::ReceiveBufferFull,
                              "{} since the receive buffer is full.", msg);
             }
 
-            let segment_id = ptr.segment_id().value() as usize;
-            let segment_details = &storage.segment_details[segment_id];
-            segment_details
-                .sample_size
-                .store(sample_size, Ordering::Relaxed);
-            debug_assert!(ptr.offset() % sample_size == 0);
-            let index = ptr.offset() / sample_size;
+            // inline slots are already marked as used when they are staged
+            if !is_inline_sample(ptr) {
+                let segment_id = ptr.segment_id().value() as usize;
+                let segment_details = &storage.segment_details[segment_id];
+                segment_details
+                    .sample_size
+                    .store(sample_size, Ordering::Relaxed);
+                debug_assert!(ptr.offset() % sample_size == 0);
+                let index = ptr.offset() / sample_size;
 
-            debug_assert!(segment_id < storage.number_of_segments as usize);
+                debug_assert!(segment_id < storage.number_of_segments as usize);
 
-            let did_not_send_same_offset_twice = segment_details.used_chunk_list.insert(index);
-            debug_assert!(did_not_send_same_offset_twice);
+                let did_not_send_same_offset_twice = segment_details.used_chunk_list.insert(index);
+                debug_assert!(did_not_send_same_offset_twice);
+            }
 
-            match unsafe { storage.submission_channel.push(ptr.as_value()) } {
-                Some(v) => {
-                    let pointer_offset = PointerOffset::from_value(v);
-                    let segment_id = pointer_offset.segment_id().value() as usize;
-
-                    let segment_details = &storage.segment_details[segment_id];
-                    debug_assert!(
-                        pointer_offset.offset()
-                            % segment_details.sample_size.load(Ordering::Relaxed)
-                            == 0
-                    );
-                    let index = pointer_offset.offset()
-                        / segment_details.sample_size.load(Ordering::Relaxed);
+            debug_assert!(ptr.as_value() & SAMPLE_RUN_FLAG == 0);
+            let value = add_generation(ptr.as_value(), storage.generation.load(Ordering::Relaxed));
+            let overflow = unsafe { channel.push(value) };
+            #[cfg(feature = "sample_counters")]
+            storage.sent_samples.fetch_add(1, Ordering::Relaxed);
+
+            match overflow {
+                Some(v) => self.release_overflowed_entry(v, msg),
+                None => Ok(None),
+            }
+        }
+
+        fn release_overflowed_entry(
+            &self,
+            value: u64,
+            msg: &str,
+        ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
+            let storage = self.storage.get();
+            let (pointer_offset, _) = remove_generation(value);
+
+            if let Some((segment_id, first_index, length)) = decode_sample_run(pointer_offset) {
+                storage
+                    .overflowed_samples
+                    .fetch_add(length as u64, Ordering::Relaxed);
 
+                let segment_details = &storage.segment_details[segment_id.value() as usize];
+                let sample_size = segment_details.sample_size.load(Ordering::Relaxed);
+                for index in first_index..first_index + length {
                     if !segment_details.used_chunk_list.remove(index) {
                         fail!(from self, with ZeroCopySendError::ConnectionCorrupted,
-                        "{} since the invalid offset {:?} was returned on overflow.", msg, pointer_offset);
+                            "{} since the run {:?} that was returned on overflow contains the invalid index {}.",
+                            msg, pointer_offset, index);
                     }
+                }
+
+                // the remaining samples of the run are handed out by reclaim()
+                self.overflowed_run_samples()
+                    .extend((first_index + 1..first_index + length).map(|index| {
+                        PointerOffset::from_offset_and_segment_id(index * sample_size, segment_id)
+                    }));
 
-                    Ok(Some(pointer_offset))
+                return Ok(Some(PointerOffset::from_offset_and_segment_id(
+                    first_index * sample_size,
+                    segment_id,
+                )));
+            }
+
+            storage.overflowed_samples.fetch_add(1, Ordering::Relaxed);
+            if is_inline_sample(pointer_offset) {
+                if !storage.release_inline_slot(pointer_offset) {
+                    fail!(from self, with ZeroCopySendError::ConnectionCorrupted,
+                        "{} since the invalid inline offset {:?} was returned on overflow.", msg, pointer_offset);
                 }
-                None => Ok(None),
+                return Ok(None);
             }
+
+            let segment_id = pointer_offset.segment_id().value() as usize;
+
+            let segment_details = &storage.segment_details[segment_id];
+            debug_assert!(
+                pointer_offset.offset() % segment_details.sample_size.load(Ordering::Relaxed) == 0
+            );
+            let index =
+                pointer_offset.offset() / segment_details.sample_size.load(Ordering::Relaxed);
+
+            if !segment_details.used_chunk_list.remove(index) {
+                fail!(from self, with ZeroCopySendError::ConnectionCorrupted,
+                    "{} since the invalid offset {:?} was returned on overflow.", msg, pointer_offset);
+            }
+
+            Ok(Some(pointer_offset))
+        }
+    }
+
+    impl<Storage: DynamicStorage<SharedManagementData>> ZeroCopySender for Sender<Storage> {
+        fn try_send(
+            &self,
+            ptr: PointerOffset,
+            sample_size: usize,
+        ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
+            self.send_impl(
+                ptr,
+                sample_size,
+                self.storage.get().enable_safe_overflow,
+                false,
+            )
+        }
+
+        fn try_send_with_high_priority(
+            &self,
+            ptr: PointerOffset,
+            sample_size: usize,
+        ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
+            self.send_impl(
+                ptr,
+                sample_size,
+                self.storage.get().enable_safe_overflow,
+                true,
+            )
+        }
+
+        fn try_send_discarding_oldest(
+            &self,
+            ptr: PointerOffset,
+            sample_size: usize,
+        ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
+            // the overflow of the submission channel removes the oldest entry safely even
+            // when the receiver acquires samples concurrently
+            self.send_impl(ptr, sample_size, true, false)
         }
 
         fn blocking_send(
@@ -613,44 +1087,231 @@ pub mod details {
             self.try_send(ptr, sample_size)
         }
 
-        fn reclaim(&self) -> Result<Option<PointerOffset>, ZeroCopyReclaimError> {
-            let msg = "Unable to reclaim sample";
+        fn blocking_send_with_timeout(
+            &self,
+            ptr: PointerOffset,
+            sample_size: usize,
+            timeout: Duration,
+        ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
+            if !self.storage.get().enable_safe_overflow {
+                // when the buffer is still full after the timeout, try_send rejects the sample
+                AdaptiveWaitBuilder::new()
+                    .create()
+                    .unwrap()
+                    .timed_wait_while(
+                        || -> Result<bool, ()> {
+                            Ok(self.storage.get().submission_channel.is_full())
+                        },
+                        timeout,
+                    )
+                    .unwrap();
+            }
+
+            self.try_send(ptr, sample_size)
+        }
 
+        fn try_send_run(
+            &self,
+            ptr: PointerOffset,
+            number_of_samples: usize,
+            sample_size: usize,
+        ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
+            let msg = "Unable to send sample run";
             let storage = self.storage.get();
-            match unsafe { storage.completion_channel.pop() } {
+
+            if number_of_samples == 0 || storage.max_sample_run_length < number_of_samples {
+                fail!(from self, with ZeroCopySendError::ExceedsMaxSampleRunLength,
+                    "{} since the run contains {} samples but only runs with 1..={} samples are supported.",
+                    msg, number_of_samples, storage.max_sample_run_length);
+            }
+
+            if number_of_samples == 1 {
+                return self.try_send(ptr, sample_size);
+            }
+
+            debug_assert!(!is_inline_sample(ptr));
+            if !storage.enable_safe_overflow && storage.submission_channel.is_full() {
+                storage.rejected_sends.fetch_add(1, Ordering::Relaxed);
+                fail!(from self, with ZeroCopySendError::ReceiveBufferFull,
+                    "{} since the receive buffer is full.", msg);
+            }
+
+            let segment_id = ptr.segment_id();
+            debug_assert!((segment_id.value() as usize) < storage.number_of_segments as usize);
+            let segment_details = &storage.segment_details[segment_id.value() as usize];
+            segment_details
+                .sample_size
+                .store(sample_size, Ordering::Relaxed);
+            debug_assert!(ptr.offset() % sample_size == 0);
+            let first_index = ptr.offset() / sample_size;
+
+            for index in first_index..first_index + number_of_samples {
+                let did_not_send_same_offset_twice = segment_details.used_chunk_list.insert(index);
+                debug_assert!(did_not_send_same_offset_twice);
+            }
+
+            let value = add_generation(
+                encode_sample_run(segment_id, first_index, number_of_samples),
+                storage.generation.load(Ordering::Relaxed),
+            );
+            let overflow = unsafe { storage.submission_channel.push(value) };
+            #[cfg(feature = "sample_counters")]
+            storage
+                .sent_samples
+                .fetch_add(number_of_samples as u64, Ordering::Relaxed);
+
+            match overflow {
+                Some(v) => self.release_overflowed_entry(v, msg),
                 None => Ok(None),
-                Some(v) => {
-                    let pointer_offset = PointerOffset::from_value(v);
-                    let segment_id = pointer_offset.segment_id().value() as usize;
+            }
+        }
 
-                    debug_assert!(segment_id < storage.number_of_segments as usize);
+        fn reclaim(&self) -> Result<Option<PointerOffset>, ZeroCopyReclaimError> {
+            let msg = "Unable to reclaim sample";
 
-                    if segment_id >= storage.segment_details.len() {
-                        fail!(from self, with ZeroCopyReclaimError::ReceiverReturnedCorruptedPointerOffset,
-                            "{} since the receiver returned a non-existing segment id {:?}.",
-                            msg, pointer_offset);
-                    }
+            if let Some(pointer_offset) = self.overflowed_run_samples().pop_front() {
+                return Ok(Some(pointer_offset));
+            }
 
-                    let segment_details = &storage.segment_details[segment_id];
-                    debug_assert!(
-                        pointer_offset.offset()
-                            % segment_details.sample_size.load(Ordering::Relaxed)
-                            == 0
-                    );
-                    let index = pointer_offset.offset()
-                        / segment_details.sample_size.load(Ordering::Relaxed);
+            let storage = self.storage.get();
+            loop {
+                let v = match unsafe { storage.completion_channel.pop() } {
+                    None => return Ok(None),
+                    Some(v) => v,
+                };
+
+                let (pointer_offset, generation) = remove_generation(v);
+                if generation != storage.generation.load(Ordering::Relaxed) {
+                    fail!(from self, with ZeroCopyReclaimError::ReceiverReturnedPointerOffsetOfPreviousGeneration,
+                        "{} since the offset {:?} was returned by a receiver of the previous generation {}.",
+                        msg, pointer_offset, generation);
+                }
 
-                    if !segment_details.used_chunk_list.remove(index) {
+                // inline samples are owned by the connection and never handed to the sender
+                if is_inline_sample(pointer_offset) {
+                    if !storage.release_inline_slot(pointer_offset) {
                         fail!(from self, with ZeroCopyReclaimError::ReceiverReturnedCorruptedPointerOffset,
-                            "{} since the receiver returned a corrupted offset {:?}.",
+                            "{} since the receiver returned a corrupted inline offset {:?}.",
                             msg, pointer_offset);
                     }
-                    Ok(Some(pointer_offset))
+                    #[cfg(feature = "sample_counters")]
+                    storage.reclaimed_samples.fetch_add(1, Ordering::Relaxed);
+                    continue;
                 }
+
+                let segment_id = pointer_offset.segment_id().value() as usize;
+
+                debug_assert!(segment_id < storage.number_of_segments as usize);
+
+                if segment_id >= storage.segment_details.len() {
+                    fail!(from self, with ZeroCopyReclaimError::ReceiverReturnedCorruptedPointerOffset,
+                        "{} since the receiver returned a non-existing segment id {:?}.",
+                        msg, pointer_offset);
+                }
+
+                let segment_details = &storage.segment_details[segment_id];
+                debug_assert!(
+                    pointer_offset.offset() % segment_details.sample_size.load(Ordering::Relaxed)
+                        == 0
+                );
+                let index =
+                    pointer_offset.offset() / segment_details.sample_size.load(Ordering::Relaxed);
+
+                if !segment_details.used_chunk_list.remove(index) {
+                    fail!(from self, with ZeroCopyReclaimError::ReceiverReturnedCorruptedPointerOffset,
+                        "{} since the receiver returned a corrupted offset {:?}.",
+                        msg, pointer_offset);
+                }
+
+                #[cfg(feature = "sample_counters")]
+                storage.reclaimed_samples.fetch_add(1, Ordering::Relaxed);
+                return Ok(Some(pointer_offset));
+            }
+        }
+
+        fn fill_level(&self) -> usize {
+            let storage = self.storage.get();
+            storage.submission_channel.len() + storage.priority_submission_channel.len()
+        }
+
+        fn update_watermark_state(&self) -> Option<ZeroCopyWatermarkCrossing> {
+            let high_watermark = self.high_watermark?;
+            let storage = self.storage.get();
+            let fill_level = self.fill_level();
+            let is_above_high_watermark = storage.is_above_high_watermark.load(Ordering::Relaxed);
+
+            if !is_above_high_watermark && fill_level >= high_watermark {
+                storage
+                    .is_above_high_watermark
+                    .store(true, Ordering::Relaxed);
+                Some(ZeroCopyWatermarkCrossing::High)
+            } else if is_above_high_watermark && fill_level <= self.low_watermark {
+                storage
+                    .is_above_high_watermark
+                    .store(false, Ordering::Relaxed);
+                Some(ZeroCopyWatermarkCrossing::Low)
+            } else {
+                None
+            }
+        }
+
+        fn take_redelivery_request(&self) -> bool {
+            self.storage
+                .get()
+                .has_redelivery_request
+                .swap(false, Ordering::Relaxed)
+        }
+
+        fn announce_new_segment(&self) {
+            self.storage
+                .get()
+                .has_new_segment_announcement
+                .store(true, Ordering::Relaxed);
+        }
+
+        fn request_reclaim_notification(&self) {
+            self.storage
+                .get()
+                .has_reclaim_notification_request
+                .store(true, Ordering::Relaxed);
+            // the request must be visible before the sender checks the completion channel
+            // again, otherwise a release in between is never signaled
+            core::sync::atomic::fence(Ordering::SeqCst);
+        }
+
+        fn stage_inline(&self, sample: &[u8]) -> Option<PointerOffset> {
+            let storage = self.storage.get();
+            if sample.len() > storage.inline_sample_size {
+                return None;
             }
+
+            let index = (0..storage.number_of_inline_slots)
+                .find(|n| storage.inline_slot_list.insert(*n))?;
+            let ptr = PointerOffset::from_offset_and_segment_id(
+                index,
+                SegmentId::new(INLINE_SAMPLE_SEGMENT_ID),
+            );
+
+            // the slot is not in use, therefore the sender has exclusive access to it
+            let slot = storage.inline_slot(ptr)?;
+            for (word, bytes) in slot.iter().zip(sample.chunks(INLINE_WORD_SIZE)) {
+                let mut value = [0u8; INLINE_WORD_SIZE];
+                value[..bytes.len()].copy_from_slice(bytes);
+                unsafe { *word.0.get() = u64::from_ne_bytes(value) };
+            }
+
+            Some(ptr)
         }
 
         unsafe fn acquire_used_offsets<F: FnMut(PointerOffset)>(&self, mut callback: F) {
+            // the inline slots are owned by the connection
+            self.storage.get().inline_slot_list.remove_all(|_| {});
+
+            // the samples of overflowed runs are already removed from the used chunk lists
+            for pointer_offset in self.overflowed_run_samples().drain(..) {
+                callback(pointer_offset);
+            }
+
             for (n, segment_details) in self.storage.get().segment_details.iter().enumerate() {
                 segment_details.used_chunk_list.remove_all(|index| {
                     callback(PointerOffset::from_offset_and_segment_id(
@@ -666,6 +1327,8 @@ pub mod details {
     pub struct Receiver<Storage: DynamicStorage<SharedManagementData>> {
         storage: Storage,
         borrow_counter: UnsafeCell<usize>,
+        pending_run: Cell<Option<PendingSampleRun>>,
+        generation: u8,
         name: FileName,
     }
 
@@ -713,11 +1376,42 @@ pub mod details {
             self.storage.get().state.load(Ordering::Relaxed)
                 == State::Sender.value() | State::Receiver.value()
         }
+
+        fn has_priority_lane(&self) -> bool {
+            self.storage.get().enable_priority_lane
+        }
+
+        fn inline_sample_size(&self) -> usize {
+            self.storage.get().inline_sample_size
+        }
+
+        fn max_sample_run_length(&self) -> usize {
+            self.storage.get().max_sample_run_length
+        }
+
+        fn protocol_version(&self) -> u32 {
+            self.storage.get().protocol_version
+        }
+
+        fn counters(&self) -> ZeroCopyConnectionCounters {
+            self.storage.get().counters()
+        }
     }
 
     impl<Storage: DynamicStorage<SharedManagementData>> ZeroCopyReceiver for Receiver<Storage> {
         fn has_data(&self) -> bool {
-            !self.storage.get().submission_channel.is_empty()
+            let storage = self.storage.get();
+            self.pending_run.get().is_some()
+                || !storage.priority_submission_channel.is_empty()
+                || !storage.submission_channel.is_empty()
+        }
+
+        fn number_of_pending_samples(&self) -> usize {
+            let storage = self.storage.get();
+            let pending_run_samples = self.pending_run.get().map_or(0, |run| run.remaining);
+            storage.submission_channel.len()
+                + storage.priority_submission_channel.len()
+                + pending_run_samples
         }
 
         fn receive(&self) -> Result<Option<PointerOffset>, ZeroCopyReceiveError> {
@@ -727,22 +1421,120 @@ pub mod details {
                     self.borrow_counter(), self.max_borrowed_samples());
             }
 
-            match unsafe { self.storage.get().submission_channel.pop() } {
+            if let Some(run) = self.pending_run.get() {
+                self.pending_run.set(match run.remaining {
+                    1 => None,
+                    _ => Some(PendingSampleRun {
+                        next_index: run.next_index + 1,
+                        remaining: run.remaining - 1,
+                        ..run
+                    }),
+                });
+                *self.borrow_counter() += 1;
+                return Ok(Some(PointerOffset::from_offset_and_segment_id(
+                    run.next_index * run.sample_size,
+                    run.segment_id,
+                )));
+            }
+
+            let storage = self.storage.get();
+            // the priority lane is empty when it is disabled
+            let value = match unsafe { storage.priority_submission_channel.pop() } {
+                Some(v) => Some(v),
+                None => unsafe { storage.submission_channel.pop() },
+            };
+
+            match value {
                 None => Ok(None),
                 Some(v) => {
                     *self.borrow_counter() += 1;
-                    Ok(Some(PointerOffset::from_value(v)))
+                    let pointer_offset = remove_generation(v).0;
+                    match decode_sample_run(pointer_offset) {
+                        None => Ok(Some(pointer_offset)),
+                        Some((segment_id, first_index, length)) => {
+                            let sample_size = storage.segment_details[segment_id.value() as usize]
+                                .sample_size
+                                .load(Ordering::Relaxed);
+                            self.pending_run.set(Some(PendingSampleRun {
+                                segment_id,
+                                next_index: first_index + 1,
+                                remaining: length - 1,
+                                sample_size,
+                            }));
+                            Ok(Some(PointerOffset::from_offset_and_segment_id(
+                                first_index * sample_size,
+                                segment_id,
+                            )))
+                        }
+                    }
                 }
             }
         }
 
+        fn request_redelivery(&self) {
+            self.storage
+                .get()
+                .has_redelivery_request
+                .store(true, Ordering::Relaxed);
+        }
+
+        fn take_new_segment_announcement(&self) -> bool {
+            self.storage
+                .get()
+                .has_new_segment_announcement
+                .swap(false, Ordering::Relaxed)
+        }
+
+        fn take_reclaim_notification_request(&self) -> bool {
+            let storage = self.storage.get();
+            // the release path stays fence free as long as no sender waits. A request that
+            // races with the release is not observed here, the sender covers it by waiting
+            // only for a bounded time before it checks the completion channel again.
+            if !storage
+                .has_reclaim_notification_request
+                .load(Ordering::Relaxed)
+            {
+                return false;
+            }
+
+            // pairs with the fence in request_reclaim_notification(), the released sample
+            // must be visible in the completion channel before the request is consumed
+            core::sync::atomic::fence(Ordering::SeqCst);
+            storage
+                .has_reclaim_notification_request
+                .swap(false, Ordering::Relaxed)
+        }
+
+        fn inline_sample_address(&self, ptr: PointerOffset) -> Option<usize> {
+            self.storage
+                .get()
+                .inline_slot(ptr)
+                .map(|slot| slot.as_ptr() as usize)
+        }
+
         fn release(&self, ptr: PointerOffset) -> Result<(), ZeroCopyReleaseError> {
-            match unsafe { self.storage.get().completion_channel.push(ptr.as_value()) } {
+            let current_generation = self.storage.get().generation.load(Ordering::Relaxed);
+            if self.generation != current_generation {
+                self.storage
+                    .get()
+                    .failed_releases
+                    .fetch_add(1, Ordering::Relaxed);
+                fail!(from self, with ZeroCopyReleaseError::ReceiverOfPreviousGeneration,
+                    "Unable to release pointer {:?} since the receiver of generation {} was replaced by a receiver of generation {}.",
+                    ptr, self.generation, current_generation);
+            }
+
+            let value = add_generation(ptr.as_value(), self.generation);
+            match unsafe { self.storage.get().completion_channel.push(value) } {
                 true => {
                     *self.borrow_counter() -= 1;
                     Ok(())
                 }
                 false => {
+                    self.storage
+                        .get()
+                        .failed_releases
+                        .fetch_add(1, Ordering::Relaxed);
                     fail!(from self, with ZeroCopyReleaseError::RetrieveBufferFull,
                     "Unable to release pointer since the retrieve buffer is full.");
                 }
@@ -16,6 +16,7 @@ pub mod process_local;
 pub mod used_chunk_list;
 
 use core::fmt::Debug;
+use core::ops::RangeInclusive;
 use core::time::Duration;
 
 pub use crate::shared_memory::PointerOffset;
@@ -45,6 +46,11 @@ pub enum ZeroCopyCreationError {
     IncompatibleOverflowSetting,
     IncompatibleNumberOfSamples,
     IncompatibleNumberOfSegments,
+    IncompatibleMaxReceiversSetting,
+    IncompatiblePriorityLaneSetting,
+    IncompatibleInlineSampleSize,
+    IncompatibleMaxSampleRunLength,
+    ExceedsMaxSupportedReceivers,
 }
 
 impl core::fmt::Display for ZeroCopyCreationError {
@@ -60,6 +66,9 @@ pub enum ZeroCopySendError {
     ConnectionCorrupted,
     ReceiveBufferFull,
     UsedChunkListFull,
+    /// The run that was sent with [`ZeroCopySender::try_send_run()`] is empty or contains
+    /// more samples than [`ZeroCopyPortDetails::max_sample_run_length()`].
+    ExceedsMaxSampleRunLength,
 }
 
 impl core::fmt::Display for ZeroCopySendError {
@@ -86,6 +95,9 @@ impl core::error::Error for ZeroCopyReceiveError {}
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ZeroCopyReclaimError {
     ReceiverReturnedCorruptedPointerOffset,
+    /// The [`PointerOffset`] was released by a [`ZeroCopyReceiver`] that was replaced by a
+    /// newer [`ZeroCopyReceiver`] and is therefore rejected.
+    ReceiverReturnedPointerOffsetOfPreviousGeneration,
 }
 
 impl core::fmt::Display for ZeroCopyReclaimError {
@@ -99,6 +111,9 @@ impl core::error::Error for ZeroCopyReclaimError {}
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ZeroCopyReleaseError {
     RetrieveBufferFull,
+    /// The [`ZeroCopyReceiver`] was removed with [`ZeroCopyConnection::remove_receiver()`]
+    /// and a newer [`ZeroCopyReceiver`] took over the connection.
+    ReceiverOfPreviousGeneration,
 }
 
 impl core::fmt::Display for ZeroCopyReleaseError {
@@ -109,10 +124,81 @@ impl core::fmt::Display for ZeroCopyReleaseError {
 
 impl core::error::Error for ZeroCopyReleaseError {}
 
+/// Counts the events on a connection that indicate that data was lost or could not be handled.
+/// The counters are stored in the shared connection data and are therefore identical on
+/// the [`ZeroCopySender`] and [`ZeroCopyReceiver`] side.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ZeroCopyConnectionCounters {
+    /// The number of samples that were successfully delivered into the receive buffer with
+    /// [`ZeroCopySender::try_send()`] or [`ZeroCopySender::blocking_send()`]. It is only
+    /// counted with the `sample_counters` feature, otherwise it stays 0.
+    pub sent_samples: u64,
+    /// The number of samples that were returned by the receiver and reclaimed with
+    /// [`ZeroCopySender::reclaim()`]. It is only counted with the `sample_counters` feature,
+    /// otherwise it stays 0.
+    pub reclaimed_samples: u64,
+    /// The number of samples that were displaced from the receive buffer by a newer sample
+    /// since safe overflow is enabled.
+    pub overflowed_samples: u64,
+    /// The number of [`ZeroCopySender::try_send()`] calls that were rejected since the
+    /// receive buffer was full.
+    pub rejected_sends: u64,
+    /// The number of [`ZeroCopyReceiver::release()`] calls that failed.
+    pub failed_releases: u64,
+}
+
+/// Describes which watermark the fill level of the receive buffer crossed, see
+/// [`ZeroCopyConnectionBuilder::high_watermark()`] and
+/// [`ZeroCopyConnectionBuilder::low_watermark()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZeroCopyWatermarkCrossing {
+    /// The fill level reached the high watermark.
+    High,
+    /// The fill level dropped to or below the low watermark after it reached the high
+    /// watermark.
+    Low,
+}
+
 pub const DEFAULT_BUFFER_SIZE: usize = 4;
 pub const DEFAULT_ENABLE_SAFE_OVERFLOW: bool = false;
 pub const DEFAULT_MAX_BORROWED_SAMPLES: usize = 4;
 pub const DEFAULT_MAX_SUPPORTED_SHARED_MEMORY_SEGMENTS: u8 = 1;
+pub const DEFAULT_ENABLE_PRIORITY_LANE: bool = false;
+pub const DEFAULT_INLINE_SAMPLE_SIZE: usize = 0;
+pub const DEFAULT_MAX_SAMPLE_RUN_LENGTH: usize = 1;
+
+/// The maximum number of samples that can be transferred as one entry with
+/// [`ZeroCopySender::try_send_run()`].
+pub const MAX_SAMPLE_RUN_LENGTH: usize = (1 << 15) - 1;
+
+/// The segment id of every [`PointerOffset`] that is returned by
+/// [`ZeroCopySender::stage_inline()`]. It is never used by a shared memory segment since the
+/// number of segments is limited to [`u8::MAX`].
+pub const INLINE_SAMPLE_SEGMENT_ID: u8 = u8::MAX;
+
+/// The protocol version of the [`ZeroCopyConnection`]. It is stored in the shared data of the
+/// connection by the process that creates it and verified by every process that opens it.
+///
+/// # Compatibility Policy
+///
+///  * The protocol version is increased whenever the memory layout of the shared connection
+///    data or the meaning of the transferred values changes. It is independent of the
+///    iceoryx2 package version, releases that do not touch the connection keep it.
+///  * The protocol version is always the first field of the shared connection data so that it
+///    can be read by every implementation, even when the remaining layout differs.
+///  * An implementation can operate on every connection with a protocol version between
+///    [`MIN_SUPPORTED_ZERO_COPY_CONNECTION_PROTOCOL_VERSION`] and
+///    [`ZERO_COPY_CONNECTION_PROTOCOL_VERSION`].
+///  * By default, a connection can only be opened by a process with the same iceoryx2 package
+///    version. With [`ZeroCopyConnectionBuilder::accepted_protocol_versions()`] the package
+///    version is ignored and every connection with a supported protocol version in the
+///    provided range is accepted. This allows rolling upgrades where processes with different
+///    iceoryx2 versions are connected.
+pub const ZERO_COPY_CONNECTION_PROTOCOL_VERSION: u32 = 3;
+
+/// The oldest protocol version of a [`ZeroCopyConnection`] that the implementation supports.
+/// See [`ZERO_COPY_CONNECTION_PROTOCOL_VERSION`] for the compatibility policy.
+pub const MIN_SUPPORTED_ZERO_COPY_CONNECTION_PROTOCOL_VERSION: u32 = 3;
 
 pub trait ZeroCopyConnectionBuilder<C: ZeroCopyConnection>: NamedConceptBuilder<C> {
     fn buffer_size(self, value: usize) -> Self;
@@ -120,12 +206,48 @@ pub trait ZeroCopyConnectionBuilder<C: ZeroCopyConnection>: NamedConceptBuilder<
     fn receiver_max_borrowed_samples(self, value: usize) -> Self;
     fn max_supported_shared_memory_segments(self, value: u8) -> Self;
     fn number_of_samples_per_segment(self, value: usize) -> Self;
+    /// Defines the fill level of the receive buffer at which
+    /// [`ZeroCopySender::update_watermark_state()`] reports a
+    /// [`ZeroCopyWatermarkCrossing::High`]. It is a setting of the [`ZeroCopySender`] and
+    /// ignored by [`ZeroCopyConnectionBuilder::create_receiver()`]. By default, no watermark
+    /// is set.
+    fn high_watermark(self, value: usize) -> Self;
+    /// Defines the fill level of the receive buffer at which
+    /// [`ZeroCopySender::update_watermark_state()`] reports a
+    /// [`ZeroCopyWatermarkCrossing::Low`] after the high watermark was reached. When it is
+    /// not set or not less than the high watermark, it is one less than the high watermark.
+    fn low_watermark(self, value: usize) -> Self;
+    /// Defines if the connection has a second receive buffer with the same buffer size, the
+    /// high-priority lane. Samples that are sent with
+    /// [`ZeroCopySender::try_send_with_high_priority()`] are stored in it and
+    /// [`ZeroCopyReceiver::receive()`] drains it before the regular receive buffer. By
+    /// default, it is disabled.
+    fn enable_priority_lane(self, value: bool) -> Self;
+    /// Defines the maximum size of a sample that can be copied directly into the connection
+    /// with [`ZeroCopySender::stage_inline()`] instead of being transferred as offset into a
+    /// shared memory segment. The value is rounded up to a multiple of 8. By default, it is
+    /// set to [`DEFAULT_INLINE_SAMPLE_SIZE`] and inline samples are disabled.
+    fn inline_sample_size(self, value: usize) -> Self;
+    /// Defines the maximum number of consecutive samples that can be sent as one entry with
+    /// [`ZeroCopySender::try_send_run()`]. The value is limited to [`MAX_SAMPLE_RUN_LENGTH`].
+    /// The retrieve buffer grows with the value since every entry of the receive buffer can
+    /// hold a whole run. By default, it is set to [`DEFAULT_MAX_SAMPLE_RUN_LENGTH`] and every
+    /// entry contains a single sample.
+    fn max_sample_run_length(self, value: usize) -> Self;
     /// The timeout defines how long the [`ZeroCopyConnectionBuilder`] should wait for
     /// concurrent
     /// [`ZeroCopyConnectionBuilder::create_sender()`] or
     /// [`ZeroCopyConnectionBuilder::create_receiver()`] call to finalize its initialization.
     /// By default it is set to [`Duration::ZERO`] for no timeout.
     fn timeout(self, value: Duration) -> Self;
+    /// Defines the range of protocol versions that are accepted when an existing connection
+    /// is opened. The range is limited to the supported protocol versions and the iceoryx2
+    /// package version of the connection is no longer verified. If the protocol version of
+    /// the connection is outside of the range, the creation fails with
+    /// [`ZeroCopyCreationError::VersionMismatch`]. By default, only connections with the same
+    /// iceoryx2 package version are accepted.
+    /// See [`ZERO_COPY_CONNECTION_PROTOCOL_VERSION`] for the compatibility policy.
+    fn accepted_protocol_versions(self, value: RangeInclusive<u32>) -> Self;
 
     fn create_sender(self) -> Result<C::Sender, ZeroCopyCreationError>;
     fn create_receiver(self) -> Result<C::Receiver, ZeroCopyCreationError>;
@@ -137,6 +259,20 @@ pub trait ZeroCopyPortDetails {
     fn max_borrowed_samples(&self) -> usize;
     fn max_supported_shared_memory_segments(&self) -> u8;
     fn is_connected(&self) -> bool;
+    /// Returns true when the connection was created with
+    /// [`ZeroCopyConnectionBuilder::enable_priority_lane()`].
+    fn has_priority_lane(&self) -> bool;
+    /// Returns the maximum size of a sample that can be sent inline, see
+    /// [`ZeroCopyConnectionBuilder::inline_sample_size()`]. Returns 0 when inline samples
+    /// are disabled.
+    fn inline_sample_size(&self) -> usize;
+    /// Returns the maximum number of samples that can be sent as one entry, see
+    /// [`ZeroCopyConnectionBuilder::max_sample_run_length()`].
+    fn max_sample_run_length(&self) -> usize;
+    /// Returns the protocol version with which the connection was created.
+    fn protocol_version(&self) -> u32;
+    /// Returns the current [`ZeroCopyConnectionCounters`] of the connection.
+    fn counters(&self) -> ZeroCopyConnectionCounters;
 }
 
 pub trait ZeroCopySender: Debug + ZeroCopyPortDetails + NamedConcept {
@@ -152,8 +288,85 @@ pub trait ZeroCopySender: Debug + ZeroCopyPortDetails + NamedConcept {
         sample_size: usize,
     ) -> Result<Option<PointerOffset>, ZeroCopySendError>;
 
+    /// Like [`ZeroCopySender::blocking_send()`] but waits at most for the provided timeout
+    /// until the receive buffer has space again. When the receive buffer is still full after
+    /// the timeout has passed, it fails with [`ZeroCopySendError::ReceiveBufferFull`].
+    fn blocking_send_with_timeout(
+        &self,
+        ptr: PointerOffset,
+        sample_size: usize,
+        timeout: Duration,
+    ) -> Result<Option<PointerOffset>, ZeroCopySendError>;
+
+    /// Like [`ZeroCopySender::try_send()`] but when the receive buffer is full, the oldest
+    /// sample that was not yet received is removed from it and returned, independent of the
+    /// safe overflow setting. The removed sample is counted as overflowed sample.
+    fn try_send_discarding_oldest(
+        &self,
+        ptr: PointerOffset,
+        sample_size: usize,
+    ) -> Result<Option<PointerOffset>, ZeroCopySendError>;
+
+    /// Like [`ZeroCopySender::try_send()`] but submits the sample into the high-priority lane
+    /// that the [`ZeroCopyReceiver`] drains before the regular receive buffer. When the
+    /// connection has no priority lane, the sample is submitted into the regular receive
+    /// buffer.
+    fn try_send_with_high_priority(
+        &self,
+        ptr: PointerOffset,
+        sample_size: usize,
+    ) -> Result<Option<PointerOffset>, ZeroCopySendError>;
+
+    /// Like [`ZeroCopySender::try_send()`] but submits the `number_of_samples` consecutive
+    /// samples of the same segment, that start at the provided [`PointerOffset`], as one entry
+    /// into the receive buffer. The [`ZeroCopyReceiver`] receives them one by one. When the
+    /// entry of a run is displaced on overflow, the first sample of the run is returned and
+    /// the remaining samples are returned by the following [`ZeroCopySender::reclaim()`]
+    /// calls.
+    fn try_send_run(
+        &self,
+        ptr: PointerOffset,
+        number_of_samples: usize,
+        sample_size: usize,
+    ) -> Result<Option<PointerOffset>, ZeroCopySendError>;
+
     fn reclaim(&self) -> Result<Option<PointerOffset>, ZeroCopyReclaimError>;
 
+    /// Returns the number of entries that are currently stored in the receive buffer and the
+    /// high-priority lane. A run of samples, see [`ZeroCopySender::try_send_run()`], occupies
+    /// a single entry.
+    fn fill_level(&self) -> usize;
+
+    /// Compares the current fill level with the watermarks of the [`ZeroCopySender`] and
+    /// returns the [`ZeroCopyWatermarkCrossing`] when a watermark was crossed since the
+    /// last call. Returns [`None`] when no watermark is set or no watermark was crossed.
+    fn update_watermark_state(&self) -> Option<ZeroCopyWatermarkCrossing>;
+
+    /// Returns true when the [`ZeroCopyReceiver`] requested a redelivery with
+    /// [`ZeroCopyReceiver::request_redelivery()`] since the last call. The request is
+    /// consumed by this call.
+    fn take_redelivery_request(&self) -> bool;
+
+    /// Signals the [`ZeroCopyReceiver`] that the sender added a new shared memory segment,
+    /// so that the receiver can map it before it receives the first sample from it. Multiple
+    /// announcements before the receiver acquired them with
+    /// [`ZeroCopyReceiver::take_new_segment_announcement()`] are merged into one.
+    fn announce_new_segment(&self);
+
+    /// Requests the [`ZeroCopyReceiver`] to signal when it has released the next sample, see
+    /// [`ZeroCopyReceiver::take_reclaim_notification_request()`]. Used by a sender that waits
+    /// for returned samples, so that it can be woken up instead of polling.
+    fn request_reclaim_notification(&self);
+
+    /// Copies the sample into a free inline slot of the connection and returns the
+    /// [`PointerOffset`] of the slot. The returned offset can be sent like every other offset
+    /// and is reclaimed by the connection itself, it is never returned by
+    /// [`ZeroCopySender::reclaim()`], on overflow or by
+    /// [`ZeroCopySender::acquire_used_offsets()`]. Returns [`None`] when the sample is larger
+    /// than [`ZeroCopyPortDetails::inline_sample_size()`] or when no slot is available. The
+    /// staged slot must be sent with the next send call.
+    fn stage_inline(&self, sample: &[u8]) -> Option<PointerOffset>;
+
     /// # Safety
     ///
     /// * must ensure that no receiver is still holding data, otherwise data races may occur on
@@ -165,8 +378,33 @@ pub trait ZeroCopySender: Debug + ZeroCopyPortDetails + NamedConcept {
 
 pub trait ZeroCopyReceiver: Debug + ZeroCopyPortDetails + NamedConcept {
     fn has_data(&self) -> bool;
+    /// Returns the number of entries in the receive buffer plus the samples of a partially
+    /// received run. A run that was not yet received counts as one.
+    fn number_of_pending_samples(&self) -> usize;
     fn receive(&self) -> Result<Option<PointerOffset>, ZeroCopyReceiveError>;
     fn release(&self, ptr: PointerOffset) -> Result<(), ZeroCopyReleaseError>;
+
+    /// Signals the [`ZeroCopySender`] that the receiver would like to receive the
+    /// retained samples again. Multiple requests before the sender acquired them with
+    /// [`ZeroCopySender::take_redelivery_request()`] are merged into one.
+    fn request_redelivery(&self);
+
+    /// Returns true when the [`ZeroCopySender`] announced a new shared memory segment with
+    /// [`ZeroCopySender::announce_new_segment()`] since the last call. The announcement is
+    /// consumed by this call.
+    fn take_new_segment_announcement(&self) -> bool;
+
+    /// Returns true when the [`ZeroCopySender`] requested to be notified about released
+    /// samples with [`ZeroCopySender::request_reclaim_notification()`] since the last call.
+    /// Must be called after [`ZeroCopyReceiver::release()`]. The request is consumed by this
+    /// call. A request that is issued concurrently to the release may not be observed, the
+    /// sender must therefore wait only for a bounded time before it reclaims again.
+    fn take_reclaim_notification_request(&self) -> bool;
+
+    /// Returns the address of the inline sample when the [`PointerOffset`] was created with
+    /// [`ZeroCopySender::stage_inline()`], otherwise [`None`]. The address is aligned to 8 and
+    /// stays valid until the [`PointerOffset`] is released.
+    fn inline_sample_address(&self, ptr: PointerOffset) -> Option<usize>;
 }
 
 pub trait ZeroCopyConnection: Debug + Sized + NamedConceptMgmt {
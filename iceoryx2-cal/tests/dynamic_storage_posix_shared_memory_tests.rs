@@ -12,6 +12,7 @@
 
 mod dynamic_storage_posix_shared_memory {
     use core::time::Duration;
+    use iceoryx2_bb_container::semantic_string::SemanticString;
     use iceoryx2_bb_posix::creation_mode::CreationMode;
     use iceoryx2_bb_posix::permission::Permission;
     use iceoryx2_bb_posix::shared_memory::SharedMemoryBuilder;
@@ -102,4 +103,39 @@ mod dynamic_storage_posix_shared_memory {
         assert_that!(sut.err().unwrap(), eq DynamicStorageOpenError::InitializationNotYetFinalized);
         assert_that!(start.elapsed().unwrap(), ge TIMEOUT);
     }
+
+    #[test]
+    fn stale_arenas_are_removed_when_storage_is_created() {
+        type Sut = iceoryx2_cal::dynamic_storage::posix_shared_memory::Storage<TestData>;
+        const ARENA_SIZE: usize = 64;
+        let storage_name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+        let mut arena_name = config.path_for(&storage_name).file_name();
+        arena_name.push_bytes(b"__arena0").unwrap();
+
+        let stale_arena = SharedMemoryBuilder::new(&arena_name)
+            .creation_mode(CreationMode::PurgeAndCreate)
+            .size(ARENA_SIZE)
+            .has_ownership(false)
+            .create()
+            .unwrap();
+        unsafe {
+            stale_arena
+                .base_address()
+                .as_ptr()
+                .write_bytes(0xff, ARENA_SIZE)
+        };
+        drop(stale_arena);
+
+        let sut = <Sut as DynamicStorage<TestData>>::Builder::new(&storage_name)
+            .config(&config)
+            .create(TestData {})
+            .unwrap();
+
+        assert_that!(sut.append_arena(ARENA_SIZE), eq Ok(0));
+        let arena = sut.arena(0).unwrap();
+        let memory =
+            unsafe { core::slice::from_raw_parts(arena.base_address().as_ptr(), ARENA_SIZE) };
+        assert_that!(memory.iter().all(|v| *v == 0), eq true);
+    }
 }
@@ -775,6 +775,103 @@ mod dynamic_storage {
         assert_that!(unsafe { WrongTypeSut::remove_cfg(&storage_name, &wrong_type_config) }, eq Ok(false));
     }
 
+    #[test]
+    fn new_storage_has_no_arenas<
+        Sut: DynamicStorage<TestData>,
+        WrongTypeSut: DynamicStorage<u64>,
+    >() {
+        let storage_name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Sut::Builder::new(&storage_name)
+            .config(&config)
+            .create(TestData::new(0))
+            .unwrap();
+
+        assert_that!(sut.number_of_arenas(), eq 0);
+        assert_that!(sut.arena(0).err(), eq Some(DynamicStorageArenaError::DoesNotExist));
+    }
+
+    #[test]
+    fn appended_arenas_are_zeroed_and_writable<
+        Sut: DynamicStorage<TestData>,
+        WrongTypeSut: DynamicStorage<u64>,
+    >() {
+        const ARENA_SIZE: usize = 128;
+        let storage_name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Sut::Builder::new(&storage_name)
+            .config(&config)
+            .create(TestData::new(789))
+            .unwrap();
+
+        for i in 0..2 {
+            assert_that!(sut.append_arena(ARENA_SIZE), eq Ok(i));
+            assert_that!(sut.number_of_arenas(), eq i + 1);
+
+            let arena = sut.arena(i).unwrap();
+            assert_that!(arena.size(), ge ARENA_SIZE);
+
+            let memory = unsafe {
+                core::slice::from_raw_parts_mut(arena.base_address().as_ptr(), ARENA_SIZE)
+            };
+            assert_that!(memory.iter().all(|v| *v == 0), eq true);
+            memory.fill(i as u8 + 1);
+        }
+
+        assert_that!(sut.get().value.load(Ordering::Relaxed), eq 789);
+    }
+
+    #[test]
+    fn arenas_are_visible_to_opened_storage<
+        Sut: DynamicStorage<TestData>,
+        WrongTypeSut: DynamicStorage<u64>,
+    >() {
+        const ARENA_SIZE: usize = 64;
+        let storage_name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Sut::Builder::new(&storage_name)
+            .config(&config)
+            .create(TestData::new(0))
+            .unwrap();
+
+        let sut2 = Sut::Builder::new(&storage_name)
+            .config(&config)
+            .open()
+            .unwrap();
+
+        assert_that!(sut.append_arena(ARENA_SIZE), eq Ok(0));
+        let arena = sut.arena(0).unwrap();
+        unsafe { arena.base_address().as_ptr().write(42) };
+
+        assert_that!(sut2.number_of_arenas(), eq 1);
+        let arena2 = sut2.arena(0).unwrap();
+        assert_that!(unsafe { arena2.base_address().as_ptr().read() }, eq 42);
+    }
+
+    #[test]
+    fn append_arena_fails_when_max_number_of_arenas_is_exceeded<
+        Sut: DynamicStorage<TestData>,
+        WrongTypeSut: DynamicStorage<u64>,
+    >() {
+        let storage_name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Sut::Builder::new(&storage_name)
+            .config(&config)
+            .create(TestData::new(0))
+            .unwrap();
+
+        for i in 0..MAX_NUMBER_OF_ARENAS {
+            assert_that!(sut.append_arena(16), eq Ok(i));
+        }
+
+        assert_that!(sut.append_arena(16).err(), eq Some(DynamicStorageArenaError::ExceedsMaxNumberOfArenas));
+        assert_that!(sut.number_of_arenas(), eq MAX_NUMBER_OF_ARENAS);
+    }
+
     #[instantiate_tests(<iceoryx2_cal::dynamic_storage::posix_shared_memory::Storage<TestData>,
                          iceoryx2_cal::dynamic_storage::posix_shared_memory::Storage<u64>>)]
     mod posix_shared_memory {}
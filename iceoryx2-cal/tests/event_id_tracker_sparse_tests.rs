@@ -0,0 +1,124 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod event_id_tracker_sparse {
+    use core::ptr::NonNull;
+
+    use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+    use iceoryx2_bb_lock_free::mpmc::bit_set::RelocatableBitSet;
+    use iceoryx2_bb_memory::bump_allocator::*;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::event::id_tracker::adaptive::*;
+    use iceoryx2_cal::event::id_tracker::sparse::*;
+    use iceoryx2_cal::event::id_tracker::IdTracker;
+    use iceoryx2_cal::event::{NotifierNotifyError, TriggerId};
+
+    const MEMORY_SIZE: usize = 1024 * 1024;
+    const HUGE_CAPACITY: usize = u32::MAX as usize + 1;
+
+    fn memory() -> Box<[u8; MEMORY_SIZE]> {
+        Box::new([0u8; MEMORY_SIZE])
+    }
+
+    fn allocator(memory: &mut [u8]) -> BumpAllocator {
+        BumpAllocator::new(
+            NonNull::new(memory.as_mut_ptr() as *mut u8).unwrap(),
+            memory.len(),
+        )
+    }
+
+    #[test]
+    fn adaptive_tracker_uses_bitset_up_to_threshold() {
+        assert_that!(
+            RelocatableAdaptiveIdTracker::memory_size(SPARSE_ID_TRACKING_THRESHOLD), eq
+            RelocatableBitSet::memory_size(SPARSE_ID_TRACKING_THRESHOLD)
+        );
+        assert_that!(
+            RelocatableAdaptiveIdTracker::memory_size(SPARSE_ID_TRACKING_THRESHOLD + 1), eq
+            RelocatableSparseIdTracker::memory_size(SPARSE_ID_TRACKING_THRESHOLD + 1)
+        );
+    }
+
+    #[test]
+    fn memory_of_adaptive_tracker_does_not_grow_with_huge_capacities() {
+        let memory_size = RelocatableAdaptiveIdTracker::memory_size(HUGE_CAPACITY);
+
+        assert_that!(memory_size, le MEMORY_SIZE);
+        assert_that!(memory_size, lt RelocatableBitSet::memory_size(HUGE_CAPACITY));
+        assert_that!(
+            memory_size, eq
+            RelocatableAdaptiveIdTracker::memory_size(SPARSE_ID_TRACKING_THRESHOLD + 1)
+        );
+    }
+
+    #[test]
+    fn adaptive_tracker_with_huge_capacity_tracks_huge_ids() {
+        let mut memory = memory();
+        let mut sut = unsafe { RelocatableAdaptiveIdTracker::new_uninit(HUGE_CAPACITY) };
+        assert_that!(unsafe { sut.init(&allocator(&mut *memory)) }, is_ok);
+        assert_that!(sut.trigger_id_max(), eq TriggerId::new(HUGE_CAPACITY - 1));
+
+        let id = sut.trigger_id_max();
+        assert_that!(unsafe { sut.add(id) }, is_ok);
+        assert_that!(unsafe { sut.add(id) }, is_ok);
+        assert_that!(unsafe { sut.acquire() }, eq Some(id));
+        assert_that!(unsafe { sut.acquire() }, is_none);
+
+        let result = unsafe { sut.add(TriggerId::new(HUGE_CAPACITY)) };
+        assert_that!(result, eq Err(NotifierNotifyError::TriggerIdOutOfBounds));
+    }
+
+    #[test]
+    fn sparse_tracker_counts_how_often_an_id_was_added() {
+        let mut memory = memory();
+        let mut sut = unsafe { RelocatableSparseIdTracker::new_uninit(HUGE_CAPACITY) };
+        assert_that!(unsafe { sut.init(&allocator(&mut *memory)) }, is_ok);
+
+        for _ in 0..5 {
+            assert_that!(unsafe { sut.add(TriggerId::new(12)) }, is_ok);
+        }
+        assert_that!(unsafe { sut.add(TriggerId::new(4099)) }, is_ok);
+
+        let mut counts = vec![];
+        unsafe { sut.acquire_all_with_count(|id, count| counts.push((id, count))) };
+        assert_that!(counts, len 2);
+        assert_that!(counts, contains(TriggerId::new(12), 5));
+        assert_that!(counts, contains(TriggerId::new(4099), 1));
+
+        // the counter starts again after the id was acquired
+        assert_that!(unsafe { sut.add(TriggerId::new(12)) }, is_ok);
+        let mut counts = vec![];
+        unsafe { sut.acquire_all_with_count(|id, count| counts.push((id, count))) };
+        assert_that!(counts, eq vec![(TriggerId::new(12), 1)]);
+    }
+
+    #[test]
+    fn sparse_tracker_fails_when_too_many_ids_are_pending() {
+        let mut memory = memory();
+        let mut sut = unsafe { RelocatableSparseIdTracker::new_uninit(HUGE_CAPACITY) };
+        assert_that!(unsafe { sut.init(&allocator(&mut *memory)) }, is_ok);
+
+        for i in 0..SPARSE_ID_TRACKER_MAX_NUMBER_OF_SLOTS {
+            assert_that!(unsafe { sut.add(TriggerId::new(i * 4099)) }, is_ok);
+        }
+
+        let result = unsafe { sut.add(TriggerId::new(1)) };
+        assert_that!(result, eq Err(NotifierNotifyError::TooManyPendingTriggerIds));
+
+        let mut counter = 0;
+        unsafe { sut.acquire_all(|_| counter += 1) };
+        assert_that!(counter, eq SPARSE_ID_TRACKER_MAX_NUMBER_OF_SLOTS);
+
+        assert_that!(unsafe { sut.add(TriggerId::new(1)) }, is_ok);
+        assert_that!(unsafe { sut.acquire() }, eq Some(TriggerId::new(1)));
+    }
+}
@@ -16,6 +16,8 @@ mod event_id_tracker {
 
     use iceoryx2_bb_lock_free::mpmc::bit_set::RelocatableBitSet;
     use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::event::id_tracker::adaptive::RelocatableAdaptiveIdTracker;
+    use iceoryx2_cal::event::id_tracker::sparse::RelocatableSparseIdTracker;
     use iceoryx2_cal::event::{id_tracker::IdTracker, TriggerId};
 
     use core::ptr::NonNull;
@@ -159,4 +161,10 @@ mod event_id_tracker {
 
     #[instantiate_tests(<RelocatableBitSet>)]
     mod bitset {}
+
+    #[instantiate_tests(<RelocatableSparseIdTracker>)]
+    mod sparse {}
+
+    #[instantiate_tests(<RelocatableAdaptiveIdTracker>)]
+    mod adaptive {}
 }
@@ -562,6 +562,46 @@ mod event {
         assert_that!(event_id, is_none);
     }
 
+    #[test]
+    fn triggering_huge_trigger_ids_works<Sut: Event>() {
+        test_requires!(Sut::has_trigger_id_limit());
+
+        const TRIGGER_ID_MAX: TriggerId = TriggerId::new(u32::MAX as usize);
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_listener = Sut::ListenerBuilder::new(&name)
+            .trigger_id_max(TRIGGER_ID_MAX)
+            .config(&config)
+            .create()
+            .unwrap();
+        let sut_notifier = Sut::NotifierBuilder::new(&name)
+            .config(&config)
+            .open()
+            .unwrap();
+
+        let trigger_ids = [
+            TriggerId::new(0),
+            TriggerId::new(1 << 20),
+            TriggerId::new(TRIGGER_ID_MAX.as_value() - 1),
+            TRIGGER_ID_MAX,
+        ];
+
+        for id in trigger_ids {
+            assert_that!(sut_notifier.notify(id), is_ok);
+        }
+
+        let mut ids = HashSet::new();
+        for _ in 0..trigger_ids.len() {
+            let event_id = sut_listener.try_wait_one().unwrap().unwrap();
+            assert_that!(trigger_ids.contains(&event_id), eq true);
+            assert_that!(ids.insert(event_id), eq true);
+        }
+
+        let event_id = sut_listener.try_wait_one().unwrap();
+        assert_that!(event_id, is_none);
+    }
+
     fn wait_all_collects_all_triggers<Sut: Event, F: FnMut(&mut Vec<TriggerId>, &Sut::Listener)>(
         mut wait_call: F,
     ) {
@@ -595,6 +635,36 @@ mod event {
         }
     }
 
+    #[test]
+    fn notify_batch_delivers_all_triggers<Sut: Event>() {
+        const NUMBER_OF_TRIGGERS: usize = 8;
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_listener = Sut::ListenerBuilder::new(&name)
+            .trigger_id_max(TriggerId::new(NUMBER_OF_TRIGGERS))
+            .config(&config)
+            .create()
+            .unwrap();
+        let sut_notifier = Sut::NotifierBuilder::new(&name)
+            .config(&config)
+            .open()
+            .unwrap();
+
+        let ids: Vec<TriggerId> = (0..NUMBER_OF_TRIGGERS).map(TriggerId::new).collect();
+        assert_that!(sut_notifier.notify_batch(&ids), is_ok);
+
+        let mut vec_of_ids = vec![];
+        sut_listener
+            .timed_wait_all(|id| vec_of_ids.push(id), TIMEOUT * 1000)
+            .unwrap();
+
+        assert_that!(vec_of_ids, len NUMBER_OF_TRIGGERS);
+        for id in ids {
+            assert_that!(vec_of_ids, contains id);
+        }
+    }
+
     #[test]
     fn try_wait_all_collects_all_triggers<Sut: Event>() {
         wait_all_collects_all_triggers::<Sut, _>(|v, sut: &Sut::Listener| {
@@ -741,6 +811,10 @@ mod event {
     #[instantiate_tests(<iceoryx2_cal::event::unix_datagram_socket::EventImpl>)]
     mod unix_datagram {}
 
+    #[cfg(not(target_os = "windows"))]
+    #[instantiate_tests(<iceoryx2_cal::event::fifo::EventImpl>)]
+    mod fifo {}
+
     #[instantiate_tests(<iceoryx2_cal::event::sem_bitset_process_local::Event>)]
     mod sem_bitset_process_local {}
 
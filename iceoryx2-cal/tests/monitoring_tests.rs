@@ -84,6 +84,27 @@ mod monitoring {
         assert_that!(sut_monitor.state().unwrap(), eq State::DoesNotExist);
     }
 
+    #[test]
+    fn monitor_detects_refreshed_heartbeat<Sut: Monitoring>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_monitor = Sut::Builder::new(&name).config(&config).monitor().unwrap();
+        assert_that!(sut_monitor.time_since_last_heartbeat().unwrap(), is_none);
+
+        let mut sut_token = Sut::Builder::new(&name).config(&config).token().unwrap();
+        assert_that!(sut_monitor.time_since_last_heartbeat().unwrap(), is_none);
+
+        sut_token.refresh_heartbeat().unwrap();
+        std::thread::sleep(core::time::Duration::from_millis(10));
+        let elapsed = sut_monitor.time_since_last_heartbeat().unwrap();
+        assert_that!(elapsed, is_some);
+        assert_that!(elapsed.unwrap(), ge core::time::Duration::from_millis(10));
+
+        drop(sut_token);
+        assert_that!(sut_monitor.time_since_last_heartbeat().unwrap(), is_none);
+    }
+
     #[test]
     fn list_monitoring_token_works<Sut: Monitoring>() {
         let mut sut_names = vec![];
@@ -0,0 +1,53 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(target_os = "linux")]
+mod named_concept_owner {
+    use iceoryx2_bb_posix::process::Process;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::named_concept::*;
+    use iceoryx2_cal::shared_memory::*;
+    use iceoryx2_cal::shm_allocator::pool_allocator::{self, PoolAllocator};
+    use iceoryx2_cal::testing::generate_name;
+
+    type Sut = iceoryx2_cal::shared_memory::posix::Memory<PoolAllocator>;
+
+    #[test]
+    fn owners_of_existing_shared_memory_contain_current_process() {
+        let name = generate_name();
+        let config = <Sut as NamedConceptMgmt>::Configuration::default();
+
+        let _sut = <Sut as SharedMemory<PoolAllocator>>::Builder::new(&name)
+            .size(1024)
+            .create(&pool_allocator::Config {
+                bucket_layout: core::alloc::Layout::new::<u64>(),
+            })
+            .unwrap();
+
+        let owners = Sut::list_owners_cfg(&name, &config).unwrap();
+        let own_pid = Process::from_self().id();
+        let owner = owners.iter().find(|owner| owner.pid() == own_pid);
+
+        assert_that!(owner, is_some);
+        assert_that!(owner.unwrap().has_mapping(), eq true);
+    }
+
+    #[test]
+    fn owners_of_non_existing_shared_memory_are_empty() {
+        let name = generate_name();
+        let config = <Sut as NamedConceptMgmt>::Configuration::default();
+
+        let owners = Sut::list_owners_cfg(&name, &config).unwrap();
+
+        assert_that!(owners, len 0);
+    }
+}
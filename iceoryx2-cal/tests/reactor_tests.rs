@@ -160,6 +160,35 @@ mod reactor {
         assert_that!(triggered_fds[0], eq unsafe { attachment. listener.file_descriptor().native_handle() });
     }
 
+    #[test]
+    fn suspended_attachment_does_not_trigger_until_resumed<Sut: Reactor>() {
+        let sut = <<Sut as Reactor>::Builder>::new().create().unwrap();
+
+        let attachment = NotifierListenerPair::new();
+        attachment.notifier.notify(TriggerId::new(123)).unwrap();
+
+        let _guard = sut.attach(&attachment.listener);
+
+        assert_that!(sut.suspend(attachment.listener.file_descriptor()), eq true);
+        assert_that!(sut.suspend(attachment.listener.file_descriptor()), eq false);
+
+        let mut triggered_fds = vec![];
+        assert_that!(
+            sut.try_wait(|fd| triggered_fds.push(unsafe { fd.native_handle() })),
+            eq Ok(0)
+        );
+        assert_that!(triggered_fds, len 0);
+
+        assert_that!(sut.resume(attachment.listener.file_descriptor()), eq true);
+        assert_that!(sut.resume(attachment.listener.file_descriptor()), eq false);
+
+        assert_that!(
+            sut.try_wait(|fd| triggered_fds.push(unsafe { fd.native_handle() })),
+            eq Ok(1)
+        );
+        assert_that!(triggered_fds, len 1);
+    }
+
     #[test]
     fn try_wait_activates_as_long_as_there_is_data_to_read<Sut: Reactor>() {
         let sut = <<Sut as Reactor>::Builder>::new().create().unwrap();
@@ -676,6 +676,49 @@ mod resizable_shared_memory {
         }
     }
 
+    #[test]
+    fn map_all_segments_maps_every_existing_segment<
+        Shm: SharedMemory<DefaultAllocator>,
+        Sut: ResizableSharedMemory<DefaultAllocator, Shm>,
+    >() {
+        const TEST_VALUE: u64 = 7123891231;
+        let config = generate_isolated_config::<Sut>();
+        let storage_name = generate_name();
+
+        let sut_creator = Sut::MemoryBuilder::new(&storage_name)
+            .config(&config)
+            .max_chunk_layout_hint(Layout::new::<u8>())
+            .max_number_of_chunks_hint(123)
+            .allocation_strategy(AllocationStrategy::BestFit)
+            .create()
+            .unwrap();
+
+        let sut_viewer = Sut::ViewBuilder::new(&storage_name)
+            .config(&config)
+            .open()
+            .unwrap();
+        assert_that!(sut_viewer.map_all_segments(), eq Ok(1));
+        assert_that!(sut_viewer.number_of_active_segments(), eq 1);
+
+        sut_creator.allocate(Layout::new::<u8>()).unwrap();
+        sut_creator.allocate(Layout::new::<u16>()).unwrap();
+        let chunk = sut_creator.allocate(Layout::new::<u64>()).unwrap();
+        unsafe { (chunk.data_ptr as *mut u64).write(TEST_VALUE) };
+        assert_that!(sut_creator.number_of_active_segments(), eq 3);
+
+        assert_that!(sut_viewer.map_all_segments(), eq Ok(2));
+        assert_that!(sut_viewer.map_all_segments(), eq Ok(0));
+        assert_that!(sut_viewer.number_of_active_segments(), eq 3);
+
+        let translated_chunk = unsafe {
+            sut_viewer
+                .register_and_translate_offset(chunk.offset)
+                .unwrap()
+        };
+        assert_that!(unsafe { *(translated_chunk as *const u64) }, eq TEST_VALUE);
+        assert_that!(sut_viewer.number_of_active_segments(), eq 3);
+    }
+
     #[test]
     fn open_when_zero_segment_not_available_works<
         Shm: SharedMemory<DefaultAllocator>,
@@ -11,13 +11,15 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 mod shared_memory_posix_shared_memory_tests {
+    use core::alloc::Layout;
     use core::time::Duration;
     use iceoryx2_bb_posix::creation_mode::CreationMode;
     use iceoryx2_bb_posix::permission::Permission;
+    use iceoryx2_bb_posix::system_configuration::SystemInfo;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_cal::named_concept::*;
     use iceoryx2_cal::shared_memory::*;
-    use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
+    use iceoryx2_cal::shm_allocator::pool_allocator::{self, PoolAllocator};
     use iceoryx2_cal::testing::generate_name;
 
     const TIMEOUT: Duration = Duration::from_millis(100);
@@ -47,4 +49,54 @@ mod shared_memory_posix_shared_memory_tests {
         assert_that!(sut.err().unwrap(), eq SharedMemoryOpenError::InitializationNotYetFinalized);
         assert_that!(start.elapsed().unwrap(), ge TIMEOUT);
     }
+
+    #[test]
+    fn read_only_payload_mapping_provides_data_written_by_creator() {
+        type Sut = iceoryx2_cal::shared_memory::posix::Memory<PoolAllocator>;
+        let storage_name = generate_name();
+        let layout = Layout::new::<u64>();
+
+        let sut_create = <Sut as SharedMemory<PoolAllocator>>::Builder::new(&storage_name)
+            .size(4096)
+            .read_only_payload(true)
+            .create(&pool_allocator::Config {
+                bucket_layout: layout,
+            })
+            .unwrap();
+        assert_that!(sut_create.payload_start_address() % SystemInfo::PageSize.value(), eq 0);
+
+        let chunk = sut_create.allocate(layout).unwrap();
+        unsafe { (chunk.data_ptr as *mut u64).write(0x1234abcd) };
+
+        let sut_open = <Sut as SharedMemory<PoolAllocator>>::Builder::new(&storage_name)
+            .read_only_payload(true)
+            .open()
+            .unwrap();
+
+        let value = unsafe {
+            ((sut_open.payload_start_address() + chunk.offset.offset()) as *const u64).read()
+        };
+        assert_that!(value, eq 0x1234abcd);
+    }
+
+    #[test]
+    fn read_only_payload_mapping_is_not_supported_by_process_local_memory() {
+        type Sut = iceoryx2_cal::shared_memory::process_local::Memory<PoolAllocator>;
+        let storage_name = generate_name();
+
+        let _sut_create = <Sut as SharedMemory<PoolAllocator>>::Builder::new(&storage_name)
+            .size(4096)
+            .read_only_payload(true)
+            .create(&pool_allocator::Config {
+                bucket_layout: Layout::new::<u64>(),
+            })
+            .unwrap();
+
+        let sut_open = <Sut as SharedMemory<PoolAllocator>>::Builder::new(&storage_name)
+            .read_only_payload(true)
+            .open();
+
+        assert_that!(sut_open, is_err);
+        assert_that!(sut_open.err().unwrap(), eq SharedMemoryOpenError::ReadOnlyPayloadNotSupported);
+    }
 }
@@ -0,0 +1,493 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod zero_copy_broadcast {
+    use std::collections::HashSet;
+
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::named_concept::*;
+    use iceoryx2_cal::shm_allocator::{PointerOffset, SegmentId};
+    use iceoryx2_cal::testing::{generate_isolated_config, generate_name};
+    use iceoryx2_cal::zero_copy_broadcast;
+    use iceoryx2_cal::zero_copy_broadcast::*;
+
+    const SAMPLE_SIZE: usize = 123;
+    const NUMBER_OF_SAMPLES: usize = 64;
+
+    fn offset(index: usize) -> PointerOffset {
+        PointerOffset::from_offset_and_segment_id(index * SAMPLE_SIZE, SegmentId::new(0))
+    }
+
+    #[test]
+    fn create_non_existing_broadcast_works<Sut: ZeroCopyBroadcast>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        assert_that!(
+            Sut::Builder::new(&name)
+                .config(&config)
+                .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+                .create_receiver(),
+            is_ok
+        );
+        assert_that!(
+            Sut::Builder::new(&name)
+                .config(&config)
+                .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+                .create_sender(),
+            is_ok
+        );
+    }
+
+    #[test]
+    fn builder_sets_default_values<Sut: ZeroCopyBroadcast>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        assert_that!(sut_sender.buffer_size(), eq DEFAULT_BUFFER_SIZE);
+        assert_that!(sut_sender.max_borrowed_samples(), eq DEFAULT_MAX_BORROWED_SAMPLES);
+        assert_that!(sut_sender.has_enabled_safe_overflow(), eq DEFAULT_ENABLE_SAFE_OVERFLOW);
+        assert_that!(sut_sender.max_receivers(), eq DEFAULT_MAX_RECEIVERS);
+        assert_that!(
+            sut_sender.max_supported_shared_memory_segments(), eq
+            DEFAULT_MAX_SUPPORTED_SHARED_MEMORY_SEGMENTS
+        );
+        assert_that!(sut_sender.number_of_receivers(), eq 0);
+    }
+
+    #[test]
+    fn connection_state_is_tracked<Sut: ZeroCopyBroadcast>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        assert_that!(sut_sender.is_connected(), eq false);
+
+        let sut_receiver_1 = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+        let sut_receiver_2 = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+        assert_that!(sut_sender.is_connected(), eq true);
+        assert_that!(sut_sender.number_of_receivers(), eq 2);
+        assert_that!(sut_receiver_1.is_connected(), eq true);
+
+        drop(sut_receiver_2);
+        assert_that!(sut_sender.number_of_receivers(), eq 1);
+
+        drop(sut_sender);
+        assert_that!(sut_receiver_1.is_connected(), eq false);
+    }
+
+    #[test]
+    fn second_sender_fails<Sut: ZeroCopyBroadcast>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let _sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+
+        let result = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender();
+        assert_that!(result.err().unwrap(), eq ZeroCopyCreationError::AnotherInstanceIsAlreadyConnected);
+    }
+
+    #[test]
+    fn receivers_beyond_max_receivers_fail<Sut: ZeroCopyBroadcast>() {
+        const MAX_RECEIVERS: usize = 3;
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let mut receivers = vec![];
+        for _ in 0..MAX_RECEIVERS {
+            receivers.push(
+                Sut::Builder::new(&name)
+                    .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+                    .max_receivers(MAX_RECEIVERS)
+                    .config(&config)
+                    .create_receiver()
+                    .unwrap(),
+            );
+        }
+
+        let result = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .max_receivers(MAX_RECEIVERS)
+            .config(&config)
+            .create_receiver();
+        assert_that!(result.err().unwrap(), eq ZeroCopyCreationError::ExceedsMaxSupportedReceivers);
+
+        receivers.pop();
+        let result = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .max_receivers(MAX_RECEIVERS)
+            .config(&config)
+            .create_receiver();
+        assert_that!(result, is_ok);
+    }
+
+    #[test]
+    fn incompatible_max_receivers_setting_fails<Sut: ZeroCopyBroadcast>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let _sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .max_receivers(2)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+
+        let result = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .max_receivers(3)
+            .config(&config)
+            .create_receiver();
+        assert_that!(result.err().unwrap(), eq ZeroCopyCreationError::IncompatibleMaxReceiversSetting);
+    }
+
+    #[test]
+    fn sample_sent_once_is_received_by_all_receivers<Sut: ZeroCopyBroadcast>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver_1 = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+        let sut_receiver_2 = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        assert_that!(sut_sender.try_send(offset(1), SAMPLE_SIZE), eq Ok(None));
+        assert_that!(sut_receiver_1.has_data(), eq true);
+        assert_that!(sut_receiver_2.number_of_pending_samples(), eq 1);
+
+        assert_that!(sut_receiver_1.receive(), eq Ok(Some(offset(1))));
+        assert_that!(sut_receiver_2.receive(), eq Ok(Some(offset(1))));
+        assert_that!(sut_receiver_1.receive(), eq Ok(None));
+        assert_that!(sut_receiver_2.receive(), eq Ok(None));
+        #[cfg(feature = "sample_counters")]
+        assert_that!(sut_sender.counters().sent_samples, eq 1);
+    }
+
+    #[test]
+    fn sample_is_returned_when_overwritten_and_released_by_all_receivers<Sut: ZeroCopyBroadcast>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(1)
+            .enable_safe_overflow(true)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver_1 = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(1)
+            .enable_safe_overflow(true)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+        let sut_receiver_2 = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(1)
+            .enable_safe_overflow(true)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        assert_that!(sut_sender.try_send(offset(1), SAMPLE_SIZE), eq Ok(None));
+        let sample_1 = sut_receiver_1.receive().unwrap().unwrap();
+        let sample_2 = sut_receiver_2.receive().unwrap().unwrap();
+
+        // the receivers still hold the sample
+        assert_that!(sut_sender.try_send(offset(2), SAMPLE_SIZE), eq Ok(None));
+        assert_that!(sut_receiver_1.release(sample_1), is_ok);
+        assert_that!(sut_sender.reclaim(), eq Ok(None));
+        assert_that!(sut_receiver_2.release(sample_2), is_ok);
+        assert_that!(sut_sender.reclaim(), eq Ok(Some(offset(1))));
+
+        // no receiver holds the sample, it is returned when it is overwritten
+        assert_that!(sut_sender.try_send(offset(3), SAMPLE_SIZE), eq Ok(Some(offset(2))));
+    }
+
+    #[test]
+    fn send_fails_when_a_receiver_buffer_is_full_without_safe_overflow<Sut: ZeroCopyBroadcast>() {
+        const BUFFER_SIZE: usize = 2;
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(BUFFER_SIZE)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(BUFFER_SIZE)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        for n in 0..BUFFER_SIZE {
+            assert_that!(sut_sender.try_send(offset(n), SAMPLE_SIZE), eq Ok(None));
+        }
+
+        let result = sut_sender.try_send(offset(BUFFER_SIZE), SAMPLE_SIZE);
+        assert_that!(result.err().unwrap(), eq ZeroCopySendError::ReceiveBufferFull);
+        assert_that!(sut_sender.counters().rejected_sends, eq 1);
+
+        let sample = sut_receiver.receive().unwrap().unwrap();
+        assert_that!(sut_receiver.release(sample), is_ok);
+        assert_that!(sut_sender.try_send(offset(BUFFER_SIZE), SAMPLE_SIZE), eq Ok(Some(offset(0))));
+    }
+
+    #[test]
+    fn slow_receiver_misses_overwritten_samples_with_safe_overflow<Sut: ZeroCopyBroadcast>() {
+        const BUFFER_SIZE: usize = 2;
+        const NUMBER_OF_SENDS: usize = 5;
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(BUFFER_SIZE)
+            .enable_safe_overflow(true)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(BUFFER_SIZE)
+            .enable_safe_overflow(true)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        for n in 0..NUMBER_OF_SENDS {
+            assert_that!(sut_sender.try_send(offset(n), SAMPLE_SIZE), is_ok);
+        }
+
+        assert_that!(sut_receiver.number_of_pending_samples(), eq BUFFER_SIZE);
+        for n in NUMBER_OF_SENDS - BUFFER_SIZE..NUMBER_OF_SENDS {
+            let sample = sut_receiver.receive().unwrap().unwrap();
+            assert_that!(sample, eq offset(n));
+            assert_that!(sut_receiver.release(sample), is_ok);
+        }
+        assert_that!(sut_receiver.receive(), eq Ok(None));
+        assert_that!(
+            sut_receiver.counters().overflowed_samples,
+            eq(NUMBER_OF_SENDS - BUFFER_SIZE) as u64
+        );
+    }
+
+    #[test]
+    fn receiver_gets_initial_history<Sut: ZeroCopyBroadcast>() {
+        const BUFFER_SIZE: usize = 4;
+        const HISTORY: usize = 2;
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(BUFFER_SIZE)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+
+        for n in 0..BUFFER_SIZE {
+            assert_that!(sut_sender.try_send(offset(n), SAMPLE_SIZE), eq Ok(None));
+        }
+
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(BUFFER_SIZE)
+            .initial_history(HISTORY)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        assert_that!(sut_receiver.number_of_pending_samples(), eq HISTORY);
+        assert_that!(sut_receiver.receive(), eq Ok(Some(offset(BUFFER_SIZE - 2))));
+        assert_that!(sut_receiver.receive(), eq Ok(Some(offset(BUFFER_SIZE - 1))));
+        assert_that!(sut_receiver.receive(), eq Ok(None));
+    }
+
+    #[test]
+    fn receive_fails_when_exceeding_max_borrowed_samples<Sut: ZeroCopyBroadcast>() {
+        const MAX_BORROWED_SAMPLES: usize = 2;
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .receiver_max_borrowed_samples(MAX_BORROWED_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .receiver_max_borrowed_samples(MAX_BORROWED_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        for n in 0..MAX_BORROWED_SAMPLES + 1 {
+            assert_that!(sut_sender.try_send(offset(n), SAMPLE_SIZE), eq Ok(None));
+        }
+
+        let mut samples = vec![];
+        for _ in 0..MAX_BORROWED_SAMPLES {
+            samples.push(sut_receiver.receive().unwrap().unwrap());
+        }
+
+        let result = sut_receiver.receive();
+        assert_that!(result.err().unwrap(), eq ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue);
+
+        assert_that!(sut_receiver.release(samples[0]), is_ok);
+        assert_that!(sut_receiver.receive(), eq Ok(Some(offset(MAX_BORROWED_SAMPLES))));
+    }
+
+    #[test]
+    fn remove_receiver_releases_borrowed_samples_and_frees_slot<Sut: ZeroCopyBroadcast>() {
+        const RECEIVER_ID: u128 = 0xdeadbeef_12345678_abcdef01_87654321;
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(1)
+            .enable_safe_overflow(true)
+            .max_receivers(1)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(1)
+            .enable_safe_overflow(true)
+            .max_receivers(1)
+            .receiver_id(RECEIVER_ID)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        assert_that!(sut_sender.try_send(offset(1), SAMPLE_SIZE), eq Ok(None));
+        assert_that!(sut_receiver.receive(), eq Ok(Some(offset(1))));
+        assert_that!(sut_sender.try_send(offset(2), SAMPLE_SIZE), eq Ok(None));
+
+        core::mem::forget(sut_receiver);
+        assert_that!(
+            unsafe { Sut::remove_receiver(&name, &config, RECEIVER_ID) },
+            is_ok
+        );
+        assert_that!(sut_sender.number_of_receivers(), eq 0);
+        assert_that!(sut_sender.reclaim(), eq Ok(Some(offset(1))));
+
+        let result = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(1)
+            .enable_safe_overflow(true)
+            .max_receivers(1)
+            .config(&config)
+            .create_receiver();
+        assert_that!(result, is_ok);
+    }
+
+    #[test]
+    fn acquire_used_offsets_returns_all_sent_samples<Sut: ZeroCopyBroadcast>() {
+        const BUFFER_SIZE: usize = 4;
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(BUFFER_SIZE)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(BUFFER_SIZE)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        let mut expected = HashSet::new();
+        for n in 0..BUFFER_SIZE {
+            assert_that!(sut_sender.try_send(offset(n), SAMPLE_SIZE), eq Ok(None));
+            expected.insert(offset(n).as_value());
+        }
+        let _sample = sut_receiver.receive().unwrap().unwrap();
+
+        let mut offsets = HashSet::new();
+        unsafe {
+            sut_sender.acquire_used_offsets(|offset| {
+                assert_that!(offsets.insert(offset.as_value()), eq true);
+            })
+        };
+        assert_that!(offsets, eq expected);
+    }
+
+    #[test]
+    fn list_broadcasts_works<Sut: ZeroCopyBroadcast>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        assert_that!(Sut::does_exist_cfg(&name, &config), eq Ok(false));
+        let _sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+
+        assert_that!(Sut::does_exist_cfg(&name, &config), eq Ok(true));
+        assert_that!(Sut::list_cfg(&config).unwrap(), contains name);
+    }
+
+    #[instantiate_tests(<zero_copy_broadcast::posix_shared_memory::Broadcast>)]
+    mod posix_shared_memory {}
+
+    #[instantiate_tests(<zero_copy_broadcast::process_local::Broadcast>)]
+    mod process_local {}
+}
@@ -366,6 +366,148 @@ mod zero_copy_connection {
         assert_that!(sut_receiver.has_data(), eq true);
     }
 
+    #[test]
+    fn number_of_pending_samples_is_tracked_correctly<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(2)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(2)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        assert_that!(sut_receiver.number_of_pending_samples(), eq 0);
+        for i in 0..2 {
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(SAMPLE_SIZE * i), SAMPLE_SIZE),
+                is_ok
+            );
+            assert_that!(sut_receiver.number_of_pending_samples(), eq i + 1);
+        }
+
+        let sample = sut_receiver.receive().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(sut_receiver.number_of_pending_samples(), eq 1);
+    }
+
+    #[test]
+    fn rejected_sends_are_counted_on_both_sides<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(1)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(1)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        assert_that!(sut_sender.counters(), eq ZeroCopyConnectionCounters::default());
+        assert_that!(
+            sut_sender.try_send(PointerOffset::new(0), SAMPLE_SIZE),
+            is_ok
+        );
+        for i in 1..4 {
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(SAMPLE_SIZE * i), SAMPLE_SIZE),
+                is_err
+            );
+        }
+
+        assert_that!(sut_sender.counters().rejected_sends, eq 3);
+        assert_that!(sut_sender.counters().overflowed_samples, eq 0);
+        assert_that!(sut_receiver.counters(), eq sut_sender.counters());
+    }
+
+    #[test]
+    fn overflowed_samples_are_counted_on_both_sides<Sut: ZeroCopyConnection>() {
+        if !Sut::does_support_safe_overflow() {
+            return;
+        }
+
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(2)
+            .enable_safe_overflow(true)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(2)
+            .enable_safe_overflow(true)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        for i in 0..5 {
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(SAMPLE_SIZE * i), SAMPLE_SIZE),
+                is_ok
+            );
+        }
+
+        assert_that!(sut_sender.counters().overflowed_samples, eq 3);
+        assert_that!(sut_sender.counters().rejected_sends, eq 0);
+        assert_that!(sut_receiver.counters(), eq sut_sender.counters());
+    }
+
+    #[cfg(feature = "sample_counters")]
+    #[test]
+    fn sent_and_reclaimed_samples_are_counted_on_both_sides<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(4)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(4)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        for i in 0..3 {
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(SAMPLE_SIZE * i), SAMPLE_SIZE),
+                is_ok
+            );
+        }
+        assert_that!(sut_sender.counters().sent_samples, eq 3);
+        assert_that!(sut_sender.counters().reclaimed_samples, eq 0);
+
+        for _ in 0..2 {
+            let sample = sut_receiver.receive().unwrap().unwrap();
+            assert_that!(sut_receiver.release(sample), is_ok);
+        }
+        while let Ok(Some(_)) = sut_sender.reclaim() {}
+
+        assert_that!(sut_sender.counters().sent_samples, eq 3);
+        assert_that!(sut_sender.counters().reclaimed_samples, eq 2);
+        assert_that!(sut_receiver.counters(), eq sut_sender.counters());
+    }
+
     #[test]
     fn send_until_buffer_is_full_works<Sut: ZeroCopyConnection>() {
         let name = generate_name();
@@ -589,6 +731,145 @@ mod zero_copy_connection {
         });
     }
 
+    #[test]
+    fn blocking_send_with_timeout_fails_when_buffer_stays_full<Sut: ZeroCopyConnection>() {
+        let _watchdog = Watchdog::new();
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(1)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .buffer_size(1)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        assert_that!(
+            sut_sender.try_send(PointerOffset::new(SAMPLE_SIZE * 3), SAMPLE_SIZE),
+            is_ok
+        );
+
+        let now = Instant::now();
+        let result = sut_sender.blocking_send_with_timeout(
+            PointerOffset::new(SAMPLE_SIZE * 5),
+            SAMPLE_SIZE,
+            TIMEOUT,
+        );
+        assert_that!(now.elapsed(), time_at_least TIMEOUT);
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq ZeroCopySendError::ReceiveBufferFull);
+
+        let sample = sut_receiver.receive().unwrap().unwrap();
+        assert_that!(sut_receiver.release(sample), is_ok);
+        assert_that!(
+            sut_sender.blocking_send_with_timeout(
+                PointerOffset::new(SAMPLE_SIZE * 5),
+                SAMPLE_SIZE,
+                TIMEOUT
+            ),
+            is_ok
+        );
+    }
+
+    #[test]
+    fn send_discarding_oldest_replaces_oldest_sample_without_safe_overflow<
+        Sut: ZeroCopyConnection,
+    >() {
+        const BUFFER_SIZE: usize = 3;
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .enable_safe_overflow(false)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .enable_safe_overflow(false)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        for i in 0..BUFFER_SIZE {
+            assert_that!(
+                sut_sender.try_send_discarding_oldest(PointerOffset::new(i * SAMPLE_SIZE), SAMPLE_SIZE),
+                eq Ok(None)
+            );
+        }
+
+        let result =
+            sut_sender.try_send(PointerOffset::new(BUFFER_SIZE * SAMPLE_SIZE), SAMPLE_SIZE);
+        assert_that!(result.err(), eq Some(ZeroCopySendError::ReceiveBufferFull));
+
+        let result = sut_sender
+            .try_send_discarding_oldest(PointerOffset::new(BUFFER_SIZE * SAMPLE_SIZE), SAMPLE_SIZE);
+        assert_that!(result, eq Ok(Some(PointerOffset::new(0))));
+        assert_that!(sut_sender.counters().overflowed_samples, eq 1);
+
+        for i in 1..=BUFFER_SIZE {
+            let sample = sut_receiver.receive().unwrap();
+            assert_that!(sample, eq Some(PointerOffset::new(i * SAMPLE_SIZE)));
+        }
+    }
+
+    #[test]
+    fn redelivery_request_is_consumed_by_sender<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        assert_that!(sut_sender.take_redelivery_request(), eq false);
+
+        sut_receiver.request_redelivery();
+        sut_receiver.request_redelivery();
+        assert_that!(sut_sender.take_redelivery_request(), eq true);
+        assert_that!(sut_sender.take_redelivery_request(), eq false);
+    }
+
+    #[test]
+    fn reclaim_notification_request_is_consumed_by_receiver<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        assert_that!(sut_receiver.take_reclaim_notification_request(), eq false);
+
+        sut_sender.request_reclaim_notification();
+        sut_sender.request_reclaim_notification();
+        assert_that!(sut_receiver.take_reclaim_notification_request(), eq true);
+        assert_that!(sut_receiver.take_reclaim_notification_request(), eq false);
+    }
+
     #[test]
     fn send_samples_can_be_acquired<Sut: ZeroCopyConnection>() {
         const BUFFER_SIZE: usize = 10;
@@ -1391,6 +1672,536 @@ mod zero_copy_connection {
         assert_that!(unsafe { Sut::remove_sender(&name, &config) }, eq Err(ZeroCopyPortRemoveError::DoesNotExist));
     }
 
+    #[test]
+    fn release_of_replaced_receiver_is_rejected<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+        let offset = PointerOffset::new(2 * SAMPLE_SIZE);
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        assert_that!(sut_sender.try_send(offset, SAMPLE_SIZE), eq Ok(None));
+        assert_that!(sut_receiver.receive(), eq Ok(Some(offset)));
+
+        assert_that!(unsafe { Sut::remove_receiver(&name, &config) }, is_ok);
+        let _new_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        assert_that!(sut_receiver.release(offset).err(), eq Some(ZeroCopyReleaseError::ReceiverOfPreviousGeneration));
+        assert_that!(sut_sender.reclaim(), eq Ok(None));
+        assert_that!(sut_sender.counters().failed_releases, eq 1);
+
+        core::mem::forget(sut_receiver);
+    }
+
+    #[test]
+    fn reclaim_rejects_offsets_of_replaced_receiver<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+        let offset = PointerOffset::new(2 * SAMPLE_SIZE);
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        assert_that!(sut_sender.try_send(offset, SAMPLE_SIZE), eq Ok(None));
+        assert_that!(sut_receiver.receive(), eq Ok(Some(offset)));
+        assert_that!(sut_receiver.release(offset), is_ok);
+
+        core::mem::forget(sut_receiver);
+        assert_that!(unsafe { Sut::remove_receiver(&name, &config) }, is_ok);
+        let _new_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        assert_that!(sut_sender.reclaim().err(), eq Some(ZeroCopyReclaimError::ReceiverReturnedPointerOffsetOfPreviousGeneration));
+    }
+
+    #[test]
+    fn offsets_in_flight_are_received_and_released_by_new_receiver<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+        let offset = PointerOffset::new(2 * SAMPLE_SIZE);
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        assert_that!(sut_sender.try_send(offset, SAMPLE_SIZE), eq Ok(None));
+
+        core::mem::forget(sut_receiver);
+        assert_that!(unsafe { Sut::remove_receiver(&name, &config) }, is_ok);
+        let new_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        assert_that!(new_receiver.receive(), eq Ok(Some(offset)));
+        assert_that!(new_receiver.release(offset), is_ok);
+        assert_that!(sut_sender.reclaim(), eq Ok(Some(offset)));
+    }
+
+    #[test]
+    fn watermark_crossings_are_reported_once_with_hysteresis<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(4)
+            .high_watermark(3)
+            .low_watermark(1)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(4)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        for i in 0..2 {
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(SAMPLE_SIZE * i), SAMPLE_SIZE),
+                is_ok
+            );
+            assert_that!(sut_sender.update_watermark_state(), eq None);
+        }
+
+        assert_that!(
+            sut_sender.try_send(PointerOffset::new(SAMPLE_SIZE * 2), SAMPLE_SIZE),
+            is_ok
+        );
+        assert_that!(sut_sender.fill_level(), eq 3);
+        assert_that!(sut_sender.update_watermark_state(), eq Some(ZeroCopyWatermarkCrossing::High));
+        assert_that!(sut_sender.update_watermark_state(), eq None);
+
+        let sample = sut_receiver.receive().unwrap().unwrap();
+        sut_receiver.release(sample).unwrap();
+        assert_that!(sut_sender.fill_level(), eq 2);
+        assert_that!(sut_sender.update_watermark_state(), eq None);
+
+        let sample = sut_receiver.receive().unwrap().unwrap();
+        sut_receiver.release(sample).unwrap();
+        assert_that!(sut_sender.update_watermark_state(), eq Some(ZeroCopyWatermarkCrossing::Low));
+        assert_that!(sut_sender.update_watermark_state(), eq None);
+    }
+
+    #[test]
+    fn without_watermark_no_crossing_is_reported<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(1)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let _sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(1)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        assert_that!(
+            sut_sender.try_send(PointerOffset::new(0), SAMPLE_SIZE),
+            is_ok
+        );
+        assert_that!(sut_sender.fill_level(), eq 1);
+        assert_that!(sut_sender.update_watermark_state(), eq None);
+    }
+
+    #[test]
+    fn high_priority_samples_are_received_first<Sut: ZeroCopyConnection>() {
+        const BUFFER_SIZE: usize = 3;
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(BUFFER_SIZE)
+            .enable_priority_lane(true)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(BUFFER_SIZE)
+            .enable_priority_lane(true)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+        assert_that!(sut_sender.has_priority_lane(), eq true);
+        assert_that!(sut_receiver.has_priority_lane(), eq true);
+
+        // both lanes can be filled up to the buffer size
+        for i in 0..BUFFER_SIZE {
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(i * SAMPLE_SIZE), SAMPLE_SIZE),
+                eq Ok(None)
+            );
+            assert_that!(
+                sut_sender.try_send_with_high_priority(
+                    PointerOffset::new((BUFFER_SIZE + i) * SAMPLE_SIZE),
+                    SAMPLE_SIZE
+                ),
+                eq Ok(None)
+            );
+        }
+        assert_that!(sut_sender.fill_level(), eq 2 * BUFFER_SIZE);
+        assert_that!(sut_receiver.number_of_pending_samples(), eq 2 * BUFFER_SIZE);
+
+        let mut received = vec![];
+        while let Some(sample) = sut_receiver.receive().unwrap() {
+            received.push(sample);
+            sut_receiver.release(sample).unwrap();
+        }
+
+        let expected: Vec<_> = (BUFFER_SIZE..2 * BUFFER_SIZE)
+            .chain(0..BUFFER_SIZE)
+            .map(|i| PointerOffset::new(i * SAMPLE_SIZE))
+            .collect();
+        assert_that!(received, eq expected);
+    }
+
+    #[test]
+    fn high_priority_sample_without_priority_lane_is_received_in_order<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+        assert_that!(sut_sender.has_priority_lane(), eq false);
+
+        assert_that!(
+            sut_sender.try_send(PointerOffset::new(0), SAMPLE_SIZE),
+            is_ok
+        );
+        assert_that!(
+            sut_sender.try_send_with_high_priority(PointerOffset::new(SAMPLE_SIZE), SAMPLE_SIZE),
+            is_ok
+        );
+
+        assert_that!(sut_receiver.receive().unwrap(), eq Some(PointerOffset::new(0)));
+        assert_that!(sut_receiver.receive().unwrap(), eq Some(PointerOffset::new(SAMPLE_SIZE)));
+    }
+
+    #[test]
+    fn sender_and_receiver_must_have_same_priority_lane_setting<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let _sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .enable_priority_lane(true)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .enable_priority_lane(false)
+            .config(&config)
+            .create_receiver();
+
+        assert_that!(sut_receiver.err(), eq Some(ZeroCopyCreationError::IncompatiblePriorityLaneSetting));
+    }
+
+    #[test]
+    fn inline_samples_are_copied_into_the_connection<Sut: ZeroCopyConnection>() {
+        const INLINE_SAMPLE_SIZE: usize = 13;
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .inline_sample_size(INLINE_SAMPLE_SIZE)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .inline_sample_size(INLINE_SAMPLE_SIZE)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+        assert_that!(sut_sender.inline_sample_size(), eq 16);
+        assert_that!(sut_sender.stage_inline(&[0u8; 17]), eq None);
+
+        let payload: Vec<u8> = (0..INLINE_SAMPLE_SIZE as u8).collect();
+        let offset = sut_sender.stage_inline(&payload).unwrap();
+        assert_that!(offset.segment_id(), eq SegmentId::new(INLINE_SAMPLE_SEGMENT_ID));
+        assert_that!(sut_sender.try_send(offset, SAMPLE_SIZE), eq Ok(None));
+
+        let received = sut_receiver.receive().unwrap().unwrap();
+        assert_that!(received, eq offset);
+        let address = sut_receiver.inline_sample_address(received).unwrap();
+        assert_that!(address % 8, eq 0);
+        let received_payload =
+            unsafe { core::slice::from_raw_parts(address as *const u8, INLINE_SAMPLE_SIZE) };
+        assert_that!(received_payload, eq payload.as_slice());
+        assert_that!(sut_receiver.inline_sample_address(PointerOffset::new(0)), eq None);
+
+        // inline samples are reclaimed by the connection itself
+        sut_receiver.release(received).unwrap();
+        assert_that!(sut_sender.try_send(PointerOffset::new(0), SAMPLE_SIZE), eq Ok(None));
+        let received = sut_receiver.receive().unwrap().unwrap();
+        sut_receiver.release(received).unwrap();
+        assert_that!(sut_sender.reclaim(), eq Ok(Some(PointerOffset::new(0))));
+        assert_that!(sut_sender.reclaim(), eq Ok(None));
+        #[cfg(feature = "sample_counters")]
+        assert_that!(sut_sender.counters().reclaimed_samples, eq 2);
+    }
+
+    #[test]
+    fn inline_slots_of_rejected_samples_are_released<Sut: ZeroCopyConnection>() {
+        const BUFFER_SIZE: usize = 2;
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(BUFFER_SIZE)
+            .inline_sample_size(8)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let _sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(BUFFER_SIZE)
+            .inline_sample_size(8)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        for _ in 0..BUFFER_SIZE {
+            let offset = sut_sender.stage_inline(&[1, 2, 3]).unwrap();
+            assert_that!(sut_sender.try_send(offset, SAMPLE_SIZE), eq Ok(None));
+        }
+
+        // the slot of a rejected sample is reused by the next staged sample
+        let rejected = sut_sender.stage_inline(&[4, 5, 6]).unwrap();
+        assert_that!(
+            sut_sender.try_send(rejected, SAMPLE_SIZE).err(),
+            eq Some(ZeroCopySendError::ReceiveBufferFull)
+        );
+        assert_that!(sut_sender.stage_inline(&[7, 8, 9]), eq Some(rejected));
+    }
+
+    #[test]
+    fn sender_and_receiver_must_have_same_inline_sample_size<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let _sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .inline_sample_size(64)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver();
+
+        assert_that!(sut_receiver.err(), eq Some(ZeroCopyCreationError::IncompatibleInlineSampleSize));
+    }
+
+    #[test]
+    fn sample_run_is_sent_as_one_entry_and_received_sample_by_sample<Sut: ZeroCopyConnection>() {
+        const RUN_LENGTH: usize = 5;
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .max_sample_run_length(RUN_LENGTH)
+            .receiver_max_borrowed_samples(RUN_LENGTH)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .max_sample_run_length(RUN_LENGTH)
+            .receiver_max_borrowed_samples(RUN_LENGTH)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+        assert_that!(sut_sender.max_sample_run_length(), eq RUN_LENGTH);
+
+        let first_offset = PointerOffset::new(3 * SAMPLE_SIZE);
+        assert_that!(sut_sender.try_send_run(first_offset, RUN_LENGTH, SAMPLE_SIZE), eq Ok(None));
+        assert_that!(sut_sender.fill_level(), eq 1);
+        #[cfg(feature = "sample_counters")]
+        assert_that!(sut_sender.counters().sent_samples, eq RUN_LENGTH as u64);
+
+        let mut received = vec![];
+        while let Some(offset) = sut_receiver.receive().unwrap() {
+            received.push(offset);
+        }
+        assert_that!(received, len RUN_LENGTH);
+        for (n, offset) in received.iter().enumerate() {
+            assert_that!(*offset, eq PointerOffset::new((3 + n) * SAMPLE_SIZE));
+        }
+        assert_that!(sut_receiver.has_data(), eq false);
+
+        for offset in received.iter().rev() {
+            sut_receiver.release(*offset).unwrap();
+        }
+        let mut reclaimed = vec![];
+        while let Some(offset) = sut_sender.reclaim().unwrap() {
+            reclaimed.push(offset);
+        }
+        assert_that!(reclaimed, len RUN_LENGTH);
+    }
+
+    #[test]
+    fn sample_run_length_must_be_within_supported_range<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .max_sample_run_length(3)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+
+        assert_that!(
+            sut_sender.try_send_run(PointerOffset::new(0), 0, SAMPLE_SIZE).err(),
+            eq Some(ZeroCopySendError::ExceedsMaxSampleRunLength)
+        );
+        assert_that!(
+            sut_sender.try_send_run(PointerOffset::new(0), 4, SAMPLE_SIZE).err(),
+            eq Some(ZeroCopySendError::ExceedsMaxSampleRunLength)
+        );
+
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .max_sample_run_length(4)
+            .config(&config)
+            .create_receiver();
+        assert_that!(sut_receiver.err(), eq Some(ZeroCopyCreationError::IncompatibleMaxSampleRunLength));
+    }
+
+    #[test]
+    fn overflowed_sample_run_is_returned_completely<Sut: ZeroCopyConnection>() {
+        const RUN_LENGTH: usize = 3;
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(1)
+            .enable_safe_overflow(true)
+            .max_sample_run_length(RUN_LENGTH)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let _sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(1)
+            .enable_safe_overflow(true)
+            .max_sample_run_length(RUN_LENGTH)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        assert_that!(sut_sender.try_send_run(PointerOffset::new(0), RUN_LENGTH, SAMPLE_SIZE), eq Ok(None));
+        let overflow =
+            sut_sender.try_send(PointerOffset::new(RUN_LENGTH * SAMPLE_SIZE), SAMPLE_SIZE);
+        assert_that!(overflow, eq Ok(Some(PointerOffset::new(0))));
+        assert_that!(sut_sender.reclaim(), eq Ok(Some(PointerOffset::new(SAMPLE_SIZE))));
+        assert_that!(sut_sender.reclaim(), eq Ok(Some(PointerOffset::new(2 * SAMPLE_SIZE))));
+        assert_that!(sut_sender.reclaim(), eq Ok(None));
+        assert_that!(sut_sender.counters().overflowed_samples, eq RUN_LENGTH as u64);
+    }
+
+    #[test]
+    fn connection_with_accepted_protocol_version_can_be_opened<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .accepted_protocol_versions(0..=ZERO_COPY_CONNECTION_PROTOCOL_VERSION + 1)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        assert_that!(sut_sender.protocol_version(), eq ZERO_COPY_CONNECTION_PROTOCOL_VERSION);
+        assert_that!(sut_receiver.protocol_version(), eq ZERO_COPY_CONNECTION_PROTOCOL_VERSION);
+        assert_that!(sut_receiver.is_connected(), eq true);
+    }
+
+    #[test]
+    fn connection_with_protocol_version_outside_of_accepted_range_cannot_be_opened<
+        Sut: ZeroCopyConnection,
+    >() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let _sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .accepted_protocol_versions(
+                ZERO_COPY_CONNECTION_PROTOCOL_VERSION + 1
+                    ..=ZERO_COPY_CONNECTION_PROTOCOL_VERSION + 2,
+            )
+            .config(&config)
+            .create_receiver();
+
+        assert_that!(sut_receiver.err(), eq Some(ZeroCopyCreationError::VersionMismatch));
+    }
+
     #[instantiate_tests(<zero_copy_connection::posix_shared_memory::Connection>)]
     mod posix_shared_memory {}
 
@@ -0,0 +1,116 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use clap::Args;
+use clap::Parser;
+use clap::ValueEnum;
+use core::str::FromStr;
+
+use iceoryx2_cli::help_template;
+use iceoryx2_cli::Format;
+
+#[derive(Parser)]
+#[command(
+    name = "iox2-tune",
+    about = "Probe the system and recommend iceoryx2 configuration values",
+    long_about = None,
+    version = env!("CARGO_PKG_VERSION"),
+    disable_help_subcommand = true,
+    arg_required_else_help = false,
+    help_template = help_template("iox2 tune", false),
+)]
+pub struct Cli {
+    #[command(flatten)]
+    pub options: TuneOptions,
+
+    #[clap(long, short = 'f', value_enum, global = true, value_enum, default_value_t = Format::Ron)]
+    pub format: Format,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub struct CorePair {
+    pub sender: usize,
+    pub receiver: usize,
+}
+
+impl FromStr for CorePair {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (sender, receiver) = s
+            .split_once(':')
+            .ok_or_else(|| format!("'{}' is not a core pair of the form SENDER:RECEIVER", s))?;
+
+        Ok(CorePair {
+            sender: sender
+                .trim()
+                .parse()
+                .map_err(|_| format!("'{}' is not a valid cpu core", sender))?,
+            receiver: receiver
+                .trim()
+                .parse()
+                .map_err(|_| format!("'{}' is not a valid cpu core", receiver))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, serde::Serialize)]
+#[clap(rename_all = "PascalCase")]
+pub enum SegmentPlacement {
+    /// The data segment is placed in shared memory, used by `ipc::Service`
+    SharedMemory,
+    /// The data segment is placed in the process local heap, used by `local::Service`
+    ProcessLocal,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct TuneOptions {
+    #[clap(
+        long,
+        value_delimiter = ',',
+        help = "Core pairs of the form SENDER:RECEIVER that shall be probed [default: derived from the NUMA topology]"
+    )]
+    pub core_pairs: Vec<CorePair>,
+
+    #[clap(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        default_values_t = [SegmentPlacement::SharedMemory, SegmentPlacement::ProcessLocal],
+        help = "Data segment placement strategies that shall be probed"
+    )]
+    pub placements: Vec<SegmentPlacement>,
+
+    #[clap(
+        long,
+        value_delimiter = ',',
+        default_values_t = [1, 2, 4, 8, 16],
+        help = "Subscriber buffer sizes that shall be probed in the throughput probes"
+    )]
+    pub buffer_sizes: Vec<usize>,
+
+    #[clap(
+        short,
+        long,
+        default_value_t = 10000,
+        help = "Number of samples that are exchanged in every probe"
+    )]
+    pub iterations: u64,
+
+    #[clap(
+        short,
+        long,
+        default_value_t = 4096,
+        help = "Payload size in bytes that is used in every probe"
+    )]
+    pub payload_size: usize,
+}
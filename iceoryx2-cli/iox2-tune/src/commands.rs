@@ -0,0 +1,200 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use anyhow::{anyhow, Result};
+use iceoryx2::prelude::*;
+use iceoryx2_cli::Format;
+
+use crate::cli::{SegmentPlacement, TuneOptions};
+use crate::probes::{self, ProbeSetup, WaitStrategy};
+use crate::report::{LatencyProbe, Recommendation, SystemDescription, ThroughputProbe, TuneReport};
+
+// a buffer size is considered sufficient when it reaches this fraction of the best throughput
+const SUFFICIENT_THROUGHPUT_RATIO: f64 = 0.9;
+// adaptive waiting is recommended when it does not increase the latency by more than this factor
+const ACCEPTABLE_ADAPTIVE_WAIT_LATENCY_FACTOR: u64 = 2;
+const HUGEPAGE_RECOMMENDATION_THRESHOLD: usize = 2 * 1024 * 1024;
+
+fn latency(placement: SegmentPlacement, setup: &ProbeSetup) -> Result<Vec<LatencyProbe>> {
+    let mut probes = Vec::new();
+    for wait_strategy in [WaitStrategy::BusyWait, WaitStrategy::AdaptiveWait] {
+        let latency = match placement {
+            SegmentPlacement::SharedMemory => {
+                probes::latency::<ipc::Service>(setup, wait_strategy)?
+            }
+            SegmentPlacement::ProcessLocal => {
+                probes::latency::<local::Service>(setup, wait_strategy)?
+            }
+        };
+
+        probes.push(LatencyProbe {
+            placement,
+            core_pair: setup.core_pair,
+            wait_strategy,
+            latency_in_ns: latency.as_nanos() as u64,
+        });
+    }
+
+    Ok(probes)
+}
+
+fn throughput(
+    placement: SegmentPlacement,
+    setup: &ProbeSetup,
+    buffer_sizes: &[usize],
+) -> Result<Vec<ThroughputProbe>> {
+    let mut probes = Vec::new();
+    for &buffer_size in buffer_sizes {
+        let samples_per_second = match placement {
+            SegmentPlacement::SharedMemory => {
+                probes::throughput::<ipc::Service>(setup, buffer_size)?
+            }
+            SegmentPlacement::ProcessLocal => {
+                probes::throughput::<local::Service>(setup, buffer_size)?
+            }
+        };
+
+        probes.push(ThroughputProbe {
+            placement,
+            core_pair: setup.core_pair,
+            subscriber_max_buffer_size: buffer_size,
+            samples_per_second: samples_per_second as u64,
+            bytes_per_second: (samples_per_second * setup.payload_size as f64) as u64,
+        });
+    }
+
+    Ok(probes)
+}
+
+fn recommend(report: &TuneReport) -> Option<Recommendation> {
+    let best = report
+        .latency
+        .iter()
+        .filter(|probe| probe.wait_strategy == WaitStrategy::BusyWait)
+        .min_by_key(|probe| probe.latency_in_ns)?;
+
+    let adaptive_wait_latency = report
+        .latency
+        .iter()
+        .find(|probe| {
+            probe.wait_strategy == WaitStrategy::AdaptiveWait
+                && probe.placement == best.placement
+                && probe.core_pair == best.core_pair
+        })
+        .map(|probe| probe.latency_in_ns)
+        .unwrap_or(u64::MAX);
+    let use_adaptive_wait = adaptive_wait_latency
+        <= best
+            .latency_in_ns
+            .saturating_mul(ACCEPTABLE_ADAPTIVE_WAIT_LATENCY_FACTOR);
+
+    let candidates: Vec<&ThroughputProbe> = report
+        .throughput
+        .iter()
+        .filter(|probe| probe.placement == best.placement && probe.core_pair == best.core_pair)
+        .collect();
+    let best_throughput = candidates
+        .iter()
+        .map(|probe| probe.samples_per_second)
+        .max()
+        .unwrap_or(0);
+    let subscriber_max_buffer_size = candidates
+        .iter()
+        .filter(|probe| {
+            probe.samples_per_second as f64 >= best_throughput as f64 * SUFFICIENT_THROUGHPUT_RATIO
+        })
+        .map(|probe| probe.subscriber_max_buffer_size)
+        .min()
+        .unwrap_or(1);
+
+    let mut notes = Vec::new();
+    let memory_per_connection = report.payload_size * (subscriber_max_buffer_size + 1);
+    let use_hugepages = memory_per_connection >= HUGEPAGE_RECOMMENDATION_THRESHOLD;
+    if use_hugepages && report.system.hugepages.total == 0 {
+        notes.push(format!(
+            "Each connection requires at least {} bytes but no hugepages are configured. Consider reserving hugepages or enabling transparent hugepages for shared memory.",
+            memory_per_connection
+        ));
+    }
+
+    if report.system.numa_nodes.len() > 1 {
+        notes.push(
+            "The system has multiple NUMA nodes. Place communicating processes on cores of the same NUMA node when the latency across nodes is significantly higher."
+                .to_string(),
+        );
+    }
+
+    if use_adaptive_wait {
+        notes.push(
+            "Waiting adaptively costs little latency, prefer it over busy waiting to save cpu time."
+                .to_string(),
+        );
+    } else {
+        notes.push(
+            "Waiting adaptively increases the latency significantly, use busy waiting on dedicated cores for latency critical paths."
+                .to_string(),
+        );
+    }
+
+    Some(Recommendation {
+        core_pair: best.core_pair,
+        placement: best.placement,
+        subscriber_max_buffer_size,
+        publisher_max_loaned_samples: subscriber_max_buffer_size.max(2),
+        use_adaptive_wait,
+        use_hugepages,
+        notes,
+    })
+}
+
+pub fn tune(options: TuneOptions, format: Format) -> Result<()> {
+    if options.buffer_sizes.contains(&0) {
+        return Err(anyhow!("buffer sizes must be greater than zero"));
+    }
+
+    let system = SystemDescription::acquire();
+    let core_pairs = if options.core_pairs.is_empty() {
+        system.default_core_pairs()
+    } else {
+        options.core_pairs.clone()
+    };
+
+    let mut report = TuneReport {
+        system,
+        payload_size: options.payload_size,
+        iterations: options.iterations,
+        latency: Vec::new(),
+        throughput: Vec::new(),
+        recommendation: None,
+    };
+
+    for &placement in &options.placements {
+        for &core_pair in &core_pairs {
+            let setup = ProbeSetup {
+                core_pair,
+                payload_size: options.payload_size,
+                iterations: options.iterations,
+            };
+
+            report.latency.extend(latency(placement, &setup)?);
+            report
+                .throughput
+                .extend(throughput(placement, &setup, &options.buffer_sizes)?);
+        }
+    }
+
+    report.recommendation = recommend(&report);
+
+    print!("{}", format.as_string(&report)?);
+
+    Ok(())
+}
@@ -0,0 +1,237 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+
+use anyhow::{Context, Result};
+use iceoryx2::port::subscriber::Subscriber;
+use iceoryx2::prelude::*;
+use iceoryx2::service::port_factory::publish_subscribe::PortFactory as PublishSubscribePortFactory;
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+use iceoryx2_bb_posix::barrier::{BarrierBuilder, BarrierHandle};
+use iceoryx2_bb_posix::clock::Time;
+use iceoryx2_bb_posix::thread::ThreadBuilder;
+
+use crate::cli::CorePair;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub enum WaitStrategy {
+    BusyWait,
+    AdaptiveWait,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ProbeSetup {
+    pub core_pair: CorePair,
+    pub payload_size: usize,
+    pub iterations: u64,
+}
+
+fn create_service<S: Service>(
+    node: &Node<S>,
+    name: &str,
+    buffer_size: usize,
+    safe_overflow: bool,
+) -> Result<PublishSubscribePortFactory<S, [u8], ()>> {
+    let service_name = ServiceName::new(&format!("iox2-tune/{}/{}", std::process::id(), name))
+        .context("failed to create the service name of the probe")?;
+
+    node.service_builder(&service_name)
+        .publish_subscribe::<[u8]>()
+        .max_publishers(1)
+        .max_subscribers(1)
+        .history_size(0)
+        .subscriber_max_buffer_size(buffer_size)
+        .enable_safe_overflow(safe_overflow)
+        .create()
+        .context("failed to create the service of the probe")
+}
+
+fn wait_for_sample<S: Service>(subscriber: &Subscriber<S, [u8], ()>, wait_strategy: WaitStrategy) {
+    match wait_strategy {
+        WaitStrategy::BusyWait => {
+            while subscriber
+                .receive()
+                .expect("failed to receive sample")
+                .is_none()
+            {}
+        }
+        WaitStrategy::AdaptiveWait => {
+            let mut adaptive_wait = AdaptiveWaitBuilder::new()
+                .create()
+                .expect("failed to create adaptive wait");
+            while subscriber
+                .receive()
+                .expect("failed to receive sample")
+                .is_none()
+            {
+                adaptive_wait.wait().expect("failed to wait");
+            }
+        }
+    }
+}
+
+/// Measures the average one-way latency of a ping-pong between two threads pinned to the
+/// cores of the [`CorePair`].
+pub fn latency<S: Service>(setup: &ProbeSetup, wait_strategy: WaitStrategy) -> Result<Duration> {
+    let node = NodeBuilder::new()
+        .create::<S>()
+        .context("failed to create node")?;
+    let service_a2b = create_service(&node, "latency/a2b", 1, true)?;
+    let service_b2a = create_service(&node, "latency/b2a", 1, true)?;
+
+    let startup_barrier_handle = BarrierHandle::new();
+    let start_barrier_handle = BarrierHandle::new();
+    let startup_barrier = BarrierBuilder::new(3)
+        .create(&startup_barrier_handle)
+        .context("failed to create barrier")?;
+    let start_barrier = BarrierBuilder::new(3)
+        .create(&start_barrier_handle)
+        .context("failed to create barrier")?;
+
+    let ping = ThreadBuilder::new()
+        .affinity(setup.core_pair.sender)
+        .spawn(|| {
+            let publisher = service_a2b
+                .publisher_builder()
+                .initial_max_slice_len(setup.payload_size)
+                .create()
+                .expect("failed to create publisher");
+            let subscriber = service_b2a
+                .subscriber_builder()
+                .create()
+                .expect("failed to create subscriber");
+
+            startup_barrier.wait();
+            start_barrier.wait();
+
+            for _ in 0..setup.iterations {
+                let sample = publisher
+                    .loan_slice(setup.payload_size)
+                    .expect("failed to loan sample");
+                sample.send().expect("failed to send sample");
+                wait_for_sample(&subscriber, wait_strategy);
+            }
+        })
+        .context("failed to spawn ping thread")?;
+
+    let pong = ThreadBuilder::new()
+        .affinity(setup.core_pair.receiver)
+        .spawn(|| {
+            let publisher = service_b2a
+                .publisher_builder()
+                .initial_max_slice_len(setup.payload_size)
+                .create()
+                .expect("failed to create publisher");
+            let subscriber = service_a2b
+                .subscriber_builder()
+                .create()
+                .expect("failed to create subscriber");
+
+            startup_barrier.wait();
+            start_barrier.wait();
+
+            for _ in 0..setup.iterations {
+                wait_for_sample(&subscriber, wait_strategy);
+                let sample = publisher
+                    .loan_slice(setup.payload_size)
+                    .expect("failed to loan sample");
+                sample.send().expect("failed to send sample");
+            }
+        })
+        .context("failed to spawn pong thread")?;
+
+    startup_barrier.wait();
+    let start = Time::now().context("failed to acquire time")?;
+    start_barrier.wait();
+
+    drop(ping);
+    drop(pong);
+
+    let elapsed = start.elapsed().context("failed to measure time")?;
+    Ok(Duration::from_nanos(
+        (elapsed.as_nanos() / (setup.iterations.max(1) as u128 * 2)) as u64,
+    ))
+}
+
+/// Measures how many samples per second can be transferred from a publisher pinned to the
+/// sender core to a subscriber pinned to the receiver core of the [`CorePair`] when the
+/// subscriber has a buffer of the given size.
+pub fn throughput<S: Service>(setup: &ProbeSetup, buffer_size: usize) -> Result<f64> {
+    let node = NodeBuilder::new()
+        .create::<S>()
+        .context("failed to create node")?;
+    let service = create_service(
+        &node,
+        &format!("throughput/{}", buffer_size),
+        buffer_size,
+        false,
+    )?;
+
+    let startup_barrier_handle = BarrierHandle::new();
+    let start_barrier_handle = BarrierHandle::new();
+    let startup_barrier = BarrierBuilder::new(3)
+        .create(&startup_barrier_handle)
+        .context("failed to create barrier")?;
+    let start_barrier = BarrierBuilder::new(3)
+        .create(&start_barrier_handle)
+        .context("failed to create barrier")?;
+
+    let sender = ThreadBuilder::new()
+        .affinity(setup.core_pair.sender)
+        .spawn(|| {
+            let publisher = service
+                .publisher_builder()
+                .initial_max_slice_len(setup.payload_size)
+                .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+                .create()
+                .expect("failed to create publisher");
+
+            startup_barrier.wait();
+            start_barrier.wait();
+
+            for _ in 0..setup.iterations {
+                let sample = publisher
+                    .loan_slice(setup.payload_size)
+                    .expect("failed to loan sample");
+                sample.send().expect("failed to send sample");
+            }
+        })
+        .context("failed to spawn sender thread")?;
+
+    let receiver = ThreadBuilder::new()
+        .affinity(setup.core_pair.receiver)
+        .spawn(|| {
+            let subscriber = service
+                .subscriber_builder()
+                .create()
+                .expect("failed to create subscriber");
+
+            startup_barrier.wait();
+            start_barrier.wait();
+
+            for _ in 0..setup.iterations {
+                wait_for_sample(&subscriber, WaitStrategy::BusyWait);
+            }
+        })
+        .context("failed to spawn receiver thread")?;
+
+    startup_barrier.wait();
+    let start = Time::now().context("failed to acquire time")?;
+    start_barrier.wait();
+
+    drop(sender);
+    drop(receiver);
+
+    let elapsed = start.elapsed().context("failed to measure time")?;
+    Ok(setup.iterations as f64 / elapsed.as_secs_f64().max(f64::EPSILON))
+}
@@ -0,0 +1,193 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::fs;
+use std::path::Path;
+
+use iceoryx2_bb_posix::system_configuration::SystemInfo;
+
+use crate::cli::{CorePair, SegmentPlacement};
+use crate::probes::WaitStrategy;
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct NumaNode {
+    pub id: usize,
+    pub cpu_cores: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct HugepageInfo {
+    pub total: usize,
+    pub free: usize,
+    pub page_size_in_kb: usize,
+    pub transparent_hugepages_for_shmem: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SystemDescription {
+    pub number_of_cpu_cores: usize,
+    pub numa_nodes: Vec<NumaNode>,
+    pub hugepages: HugepageInfo,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct LatencyProbe {
+    pub placement: SegmentPlacement,
+    pub core_pair: CorePair,
+    pub wait_strategy: WaitStrategy,
+    pub latency_in_ns: u64,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ThroughputProbe {
+    pub placement: SegmentPlacement,
+    pub core_pair: CorePair,
+    pub subscriber_max_buffer_size: usize,
+    pub samples_per_second: u64,
+    pub bytes_per_second: u64,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Recommendation {
+    pub core_pair: CorePair,
+    pub placement: SegmentPlacement,
+    pub subscriber_max_buffer_size: usize,
+    pub publisher_max_loaned_samples: usize,
+    pub use_adaptive_wait: bool,
+    pub use_hugepages: bool,
+    pub notes: Vec<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct TuneReport {
+    pub system: SystemDescription,
+    pub payload_size: usize,
+    pub iterations: u64,
+    pub latency: Vec<LatencyProbe>,
+    pub throughput: Vec<ThroughputProbe>,
+    pub recommendation: Option<Recommendation>,
+}
+
+/// Parses cpu lists of the form `0-3,8,10-11` as used in `/sys/devices/system`.
+fn parse_cpu_list(list: &str) -> Vec<usize> {
+    let mut cpu_cores = Vec::new();
+    for range in list.trim().split(',').filter(|r| !r.is_empty()) {
+        match range.split_once('-') {
+            Some((first, last)) => {
+                if let (Ok(first), Ok(last)) = (first.parse::<usize>(), last.parse::<usize>()) {
+                    cpu_cores.extend(first..=last);
+                }
+            }
+            None => {
+                if let Ok(core) = range.parse::<usize>() {
+                    cpu_cores.push(core);
+                }
+            }
+        }
+    }
+    cpu_cores
+}
+
+fn numa_nodes(number_of_cpu_cores: usize) -> Vec<NumaNode> {
+    let mut nodes = Vec::new();
+    if let Ok(entries) = fs::read_dir("/sys/devices/system/node") {
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let id = match name.strip_prefix("node").map(|id| id.parse::<usize>()) {
+                Some(Ok(id)) => id,
+                _ => continue,
+            };
+
+            if let Ok(list) = fs::read_to_string(entry.path().join("cpulist")) {
+                nodes.push(NumaNode {
+                    id,
+                    cpu_cores: parse_cpu_list(&list),
+                });
+            }
+        }
+    }
+
+    if nodes.is_empty() {
+        // no NUMA information available, the system is treated as a single node
+        nodes.push(NumaNode {
+            id: 0,
+            cpu_cores: (0..number_of_cpu_cores).collect(),
+        });
+    }
+
+    nodes.sort_by_key(|node| node.id);
+    nodes
+}
+
+fn hugepages() -> HugepageInfo {
+    let mut info = HugepageInfo::default();
+    if let Ok(meminfo) = fs::read_to_string("/proc/meminfo") {
+        for line in meminfo.lines() {
+            let mut fields = line.split_whitespace();
+            let key = fields.next();
+            let value = fields.next().and_then(|v| v.parse().ok()).unwrap_or(0);
+            match key {
+                Some("HugePages_Total:") => info.total = value,
+                Some("HugePages_Free:") => info.free = value,
+                Some("Hugepagesize:") => info.page_size_in_kb = value,
+                _ => (),
+            }
+        }
+    }
+
+    let shmem_enabled = Path::new("/sys/kernel/mm/transparent_hugepage/shmem_enabled");
+    info.transparent_hugepages_for_shmem = fs::read_to_string(shmem_enabled)
+        .ok()
+        .map(|v| v.trim().to_string());
+
+    info
+}
+
+impl SystemDescription {
+    pub fn acquire() -> Self {
+        let number_of_cpu_cores = SystemInfo::NumberOfCpuCores.value();
+        SystemDescription {
+            number_of_cpu_cores,
+            numa_nodes: numa_nodes(number_of_cpu_cores),
+            hugepages: hugepages(),
+        }
+    }
+
+    /// Derives the core pairs that are probed when the user did not provide any. It contains
+    /// a pair of neighbouring cores for every NUMA node and a pair that crosses the first two
+    /// NUMA nodes.
+    pub fn default_core_pairs(&self) -> Vec<CorePair> {
+        let mut core_pairs = Vec::new();
+        for node in &self.numa_nodes {
+            if let [sender, receiver, ..] = node.cpu_cores[..] {
+                core_pairs.push(CorePair { sender, receiver });
+            }
+        }
+
+        if let [first, second, ..] = &self.numa_nodes[..] {
+            if let (Some(&sender), Some(&receiver)) =
+                (first.cpu_cores.first(), second.cpu_cores.first())
+            {
+                core_pairs.push(CorePair { sender, receiver });
+            }
+        }
+
+        if core_pairs.is_empty() {
+            core_pairs.push(CorePair {
+                sender: 0,
+                receiver: 0,
+            });
+        }
+
+        core_pairs
+    }
+}
@@ -0,0 +1,36 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use clap::Parser;
+
+use iceoryx2_cli::help_template;
+use iceoryx2_cli::Format;
+
+#[derive(Parser)]
+#[command(
+    name = "iox2-who-has",
+    about = "Show which processes have an iceoryx2 resource open or mapped",
+    long_about = None,
+    version = env!("CARGO_PKG_VERSION"),
+    disable_help_subcommand = true,
+    arg_required_else_help = true,
+    help_template = help_template("iox2 who-has", false),
+)]
+pub struct Cli {
+    #[clap(
+        help = "Path or file name of the resource, a file name is looked up in the iceoryx2 root path and the shared memory directory"
+    )]
+    pub resource: String,
+
+    #[clap(long, short = 'f', value_enum, global = true, value_enum, default_value_t = Format::Ron)]
+    pub format: Format,
+}
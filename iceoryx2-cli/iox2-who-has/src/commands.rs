@@ -0,0 +1,71 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use anyhow::{anyhow, Context, Result};
+use iceoryx2::prelude::*;
+use iceoryx2_cal::named_concept::{FileName, FilePath};
+use iceoryx2_cal::named_concept_owner::list_owners;
+use iceoryx2_cli::Format;
+use iceoryx2_pal_posix::posix::pid_t;
+
+#[derive(serde::Serialize)]
+struct Owner {
+    pid: pid_t,
+    executable: Option<String>,
+    has_open_handle: bool,
+    has_mapping: bool,
+}
+
+#[derive(serde::Serialize)]
+struct OwnerList {
+    resource: String,
+    num: usize,
+    owners: Vec<Owner>,
+}
+
+fn resource_path(resource: &str) -> Result<FilePath> {
+    if resource.contains('/') {
+        return FilePath::try_from(resource)
+            .map_err(|e| anyhow!("'{}' is not a valid path ({:?})", resource, e));
+    }
+
+    // shared memory is matched by its file name, therefore it is sufficient to look up
+    // bare file names in the root path
+    let file_name = FileName::try_from(resource)
+        .map_err(|e| anyhow!("'{}' is not a valid file name ({:?})", resource, e))?;
+    FilePath::from_path_and_file(Config::global_config().global.root_path(), &file_name)
+        .map_err(|e| anyhow!("'{}' is not a valid resource ({:?})", resource, e))
+}
+
+pub fn who_has(resource: String, format: Format) -> Result<()> {
+    let path = resource_path(&resource)?;
+    let owners = list_owners(&path).context("failed to retrieve the owners")?;
+
+    print!(
+        "{}",
+        format.as_string(&OwnerList {
+            resource: path.to_string(),
+            num: owners.len(),
+            owners: owners
+                .iter()
+                .map(|owner| Owner {
+                    pid: owner.pid().value(),
+                    executable: owner.executable().map(|v| v.to_string()),
+                    has_open_handle: owner.has_open_handle(),
+                    has_mapping: owner.has_mapping(),
+                })
+                .collect(),
+        })?
+    );
+
+    Ok(())
+}
@@ -0,0 +1,231 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#include "iox2/iceoryx2.h"
+
+#include "test.hpp"
+
+#include <atomic>
+#include <chrono>
+#include <cstdint>
+#include <cstring>
+#include <string>
+
+namespace {
+
+constexpr size_t PAYLOAD_SIZE = sizeof(uint64_t);
+
+auto generate_service_name() -> std::string {
+    static std::atomic<uint64_t> counter { 0 };
+    return "iox2-c-tests/request-response/"
+           + std::to_string(std::chrono::steady_clock::now().time_since_epoch().count()) + "/"
+           + std::to_string(counter.fetch_add(1));
+}
+
+class RequestResponseTest : public TestWithParam<iox2_service_type_e> {
+  protected:
+    void SetUp() override {
+        iox2_node_builder_h node_builder = iox2_node_builder_new(nullptr);
+        ASSERT_THAT(iox2_node_builder_create(node_builder, nullptr, GetParam(), &m_node), Eq(IOX2_OK));
+
+        const auto service_name_value = generate_service_name();
+        iox2_service_name_h service_name = nullptr;
+        ASSERT_THAT(
+            iox2_service_name_new(nullptr, service_name_value.c_str(), service_name_value.size(), &service_name),
+            Eq(IOX2_OK));
+
+        iox2_service_builder_h service_builder =
+            iox2_node_service_builder(&m_node, nullptr, iox2_cast_service_name_ptr(service_name));
+        iox2_service_builder_request_response_h service_builder_request_response =
+            iox2_service_builder_request_response(service_builder);
+        const auto ret_val =
+            iox2_service_builder_request_response_create(service_builder_request_response, nullptr, &m_service);
+        iox2_service_name_drop(service_name);
+        ASSERT_THAT(ret_val, Eq(IOX2_OK));
+    }
+
+    void TearDown() override {
+        if (m_service != nullptr) {
+            iox2_port_factory_request_response_drop(m_service);
+        }
+        if (m_node != nullptr) {
+            iox2_node_drop(m_node);
+        }
+    }
+
+    auto create_client() -> iox2_client_h {
+        iox2_port_factory_client_builder_h client_builder =
+            iox2_port_factory_request_response_client_builder(&m_service, nullptr);
+        iox2_port_factory_client_builder_set_initial_max_slice_len(&client_builder, PAYLOAD_SIZE);
+        iox2_client_h client = nullptr;
+        EXPECT_THAT(iox2_port_factory_client_builder_create(client_builder, nullptr, &client), Eq(IOX2_OK));
+        return client;
+    }
+
+    auto create_server() -> iox2_server_h {
+        iox2_port_factory_server_builder_h server_builder =
+            iox2_port_factory_request_response_server_builder(&m_service, nullptr);
+        iox2_port_factory_server_builder_set_initial_max_slice_len(&server_builder, PAYLOAD_SIZE);
+        iox2_server_h server = nullptr;
+        EXPECT_THAT(iox2_port_factory_server_builder_create(server_builder, nullptr, &server), Eq(IOX2_OK));
+        return server;
+    }
+
+    // NOLINTBEGIN(misc-non-private-member-variables-in-classes), required for tests
+    iox2_node_h m_node = nullptr;
+    iox2_port_factory_request_response_h m_service = nullptr;
+    // NOLINTEND(misc-non-private-member-variables-in-classes)
+};
+
+TEST_P(RequestResponseTest, ports_have_the_configured_max_slice_len) {
+    iox2_client_h client = create_client();
+    iox2_server_h server = create_server();
+    ASSERT_THAT(client, Ne(nullptr));
+    ASSERT_THAT(server, Ne(nullptr));
+
+    ASSERT_THAT(iox2_client_initial_max_slice_len(&client), Eq(PAYLOAD_SIZE));
+    ASSERT_THAT(iox2_server_initial_max_slice_len(&server), Eq(PAYLOAD_SIZE));
+
+    iox2_server_drop(server);
+    iox2_client_drop(client);
+}
+
+TEST_P(RequestResponseTest, server_without_requests_receives_nothing) {
+    iox2_server_h server = create_server();
+    ASSERT_THAT(server, Ne(nullptr));
+
+    bool has_requests = true;
+    ASSERT_THAT(iox2_server_has_requests(&server, &has_requests), Eq(IOX2_OK));
+    ASSERT_THAT(has_requests, Eq(false));
+
+    iox2_active_request_h active_request = nullptr;
+    ASSERT_THAT(iox2_server_receive(&server, nullptr, &active_request), Eq(IOX2_OK));
+    ASSERT_THAT(active_request, Eq(nullptr));
+
+    iox2_server_drop(server);
+}
+
+TEST_P(RequestResponseTest, loan_exceeding_max_slice_len_fails) {
+    iox2_client_h client = create_client();
+    ASSERT_THAT(client, Ne(nullptr));
+
+    iox2_request_mut_h request = nullptr;
+    ASSERT_THAT(iox2_client_loan_slice(&client, nullptr, &request, PAYLOAD_SIZE + 1),
+                Eq(iox2_loan_error_e_EXCEEDS_MAX_LOAN_SIZE));
+    ASSERT_THAT(request, Eq(nullptr));
+
+    iox2_client_drop(client);
+}
+
+TEST_P(RequestResponseTest, request_is_answered_by_server) {
+    constexpr uint64_t REQUEST_VALUE = 0x123456789abcdef0;
+    constexpr uint64_t RESPONSE_VALUE = REQUEST_VALUE + 1;
+
+    iox2_client_h client = create_client();
+    iox2_server_h server = create_server();
+    ASSERT_THAT(client, Ne(nullptr));
+    ASSERT_THAT(server, Ne(nullptr));
+
+    // send request
+    iox2_request_mut_h request = nullptr;
+    ASSERT_THAT(iox2_client_loan_slice(&client, nullptr, &request, PAYLOAD_SIZE), Eq(IOX2_OK));
+    void* request_payload = nullptr;
+    size_t request_payload_len = 0;
+    iox2_request_mut_payload_mut(&request, &request_payload, &request_payload_len);
+    ASSERT_THAT(request_payload_len, Eq(PAYLOAD_SIZE));
+    memcpy(request_payload, &REQUEST_VALUE, PAYLOAD_SIZE);
+
+    iox2_pending_response_h pending_response = nullptr;
+    ASSERT_THAT(iox2_request_mut_send(request, nullptr, &pending_response), Eq(IOX2_OK));
+    ASSERT_THAT(pending_response, Ne(nullptr));
+    ASSERT_THAT(iox2_pending_response_number_of_server_connections(&pending_response), Eq(1U));
+
+    // receive request and send response
+    iox2_active_request_h active_request = nullptr;
+    ASSERT_THAT(iox2_server_receive(&server, nullptr, &active_request), Eq(IOX2_OK));
+    ASSERT_THAT(active_request, Ne(nullptr));
+
+    const void* received_request_payload = nullptr;
+    size_t received_request_payload_len = 0;
+    iox2_active_request_payload(&active_request, &received_request_payload, &received_request_payload_len);
+    ASSERT_THAT(received_request_payload_len, Eq(PAYLOAD_SIZE));
+    uint64_t received_request_value = 0;
+    memcpy(&received_request_value, received_request_payload, PAYLOAD_SIZE);
+    ASSERT_THAT(received_request_value, Eq(REQUEST_VALUE));
+
+    iox2_response_mut_h response = nullptr;
+    ASSERT_THAT(iox2_active_request_loan_slice(&active_request, nullptr, &response, PAYLOAD_SIZE), Eq(IOX2_OK));
+    void* response_payload = nullptr;
+    size_t response_payload_len = 0;
+    iox2_response_mut_payload_mut(&response, &response_payload, &response_payload_len);
+    ASSERT_THAT(response_payload_len, Eq(PAYLOAD_SIZE));
+    memcpy(response_payload, &RESPONSE_VALUE, PAYLOAD_SIZE);
+    ASSERT_THAT(iox2_response_mut_send(response), Eq(IOX2_OK));
+    iox2_active_request_drop(active_request);
+
+    // receive response
+    iox2_response_h received_response = nullptr;
+    ASSERT_THAT(iox2_pending_response_receive(&pending_response, nullptr, &received_response), Eq(IOX2_OK));
+    ASSERT_THAT(received_response, Ne(nullptr));
+
+    const void* received_response_payload = nullptr;
+    size_t received_response_payload_len = 0;
+    iox2_response_payload(&received_response, &received_response_payload, &received_response_payload_len);
+    ASSERT_THAT(received_response_payload_len, Eq(PAYLOAD_SIZE));
+    uint64_t received_response_value = 0;
+    memcpy(&received_response_value, received_response_payload, PAYLOAD_SIZE);
+    ASSERT_THAT(received_response_value, Eq(RESPONSE_VALUE));
+    iox2_response_drop(received_response);
+
+    // the server sent exactly one response
+    iox2_response_h no_response = nullptr;
+    ASSERT_THAT(iox2_pending_response_receive(&pending_response, nullptr, &no_response), Eq(IOX2_OK));
+    ASSERT_THAT(no_response, Eq(nullptr));
+
+    iox2_pending_response_drop(pending_response);
+    iox2_server_drop(server);
+    iox2_client_drop(client);
+}
+
+TEST_P(RequestResponseTest, dropped_request_is_not_delivered) {
+    iox2_client_h client = create_client();
+    iox2_server_h server = create_server();
+    ASSERT_THAT(client, Ne(nullptr));
+    ASSERT_THAT(server, Ne(nullptr));
+
+    iox2_request_mut_h request = nullptr;
+    ASSERT_THAT(iox2_client_loan_slice(&client, nullptr, &request, PAYLOAD_SIZE), Eq(IOX2_OK));
+    iox2_request_mut_drop(request);
+
+    iox2_active_request_h active_request = nullptr;
+    ASSERT_THAT(iox2_server_receive(&server, nullptr, &active_request), Eq(IOX2_OK));
+    ASSERT_THAT(active_request, Eq(nullptr));
+
+    iox2_server_drop(server);
+    iox2_client_drop(client);
+}
+
+TEST(RequestResponseErrorString, error_enums_have_a_description) {
+    ASSERT_THAT(strlen(iox2_request_response_open_or_create_error_string(
+                    iox2_request_response_open_or_create_error_e_O_DOES_NOT_EXIST)),
+                Gt(1U));
+    ASSERT_THAT(strlen(iox2_loan_error_string(iox2_loan_error_e_EXCEEDS_MAX_LOAN_SIZE)), Gt(1U));
+    ASSERT_THAT(strlen(iox2_request_send_error_string(iox2_request_send_error_e_EXCEEDS_MAX_ACTIVE_REQUESTS)),
+                Gt(1U));
+    ASSERT_THAT(strlen(iox2_response_receive_error_string(iox2_response_receive_error_e_TIMEOUT)), Gt(1U));
+}
+
+INSTANTIATE_TEST_SUITE_P(ServiceTypes,
+                         RequestResponseTest,
+                         Values(iox2_service_type_e_IPC, iox2_service_type_e_LOCAL));
+
+} // namespace
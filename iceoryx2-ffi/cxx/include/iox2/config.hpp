@@ -81,6 +81,10 @@ class Service {
     auto static_config_storage_suffix() && -> const char*;
     /// Set the suffix of the static config file
     void set_static_config_storage_suffix(const iox::FileName& value) &&;
+    /// The suffix of the attribute index file
+    auto attribute_index_suffix() && -> const char*;
+    /// Set the suffix of the attribute index file
+    void set_attribute_index_suffix(const iox::FileName& value) &&;
     /// The suffix of the dynamic config file
     auto dynamic_config_storage_suffix() && -> const char*;
     /// Set the suffix of the dynamic config file
@@ -98,6 +102,19 @@ class Service {
     auto event_connection_suffix() && -> const char*;
     /// Set the suffix of a one-to-one connection
     void set_event_connection_suffix(const iox::FileName& value) &&;
+    /// The suffix of a one-to-many connection of a service with broadcasting enabled
+    auto broadcast_suffix() && -> const char*;
+    /// Set the suffix of a one-to-many connection of a service with broadcasting enabled
+    void set_broadcast_suffix(const iox::FileName& value) &&;
+    /// When true, a service can only be opened when its static config was signed with one of
+    /// the trusted keys
+    auto verify_service_signatures() && -> bool;
+    /// Enable/disable the verification of the service signatures
+    void set_verify_service_signatures(bool value) &&;
+    /// When true, the payload of the data segments is mapped read-only by the receiving ports
+    auto read_only_payload_mapping() && -> bool;
+    /// Enable/disable the read-only mapping of the payload on the receiving side
+    void set_read_only_payload_mapping(bool value) &&;
 
   private:
     friend class Global;
@@ -212,6 +229,10 @@ class Event {
     auto event_id_max_value() && -> size_t;
     /// Set the largest event id supported by the event service
     void set_event_id_max_value(size_t value) &&;
+    /// The maximum size in bytes of the payload that can be attached to a notification.
+    auto max_payload_size() && -> size_t;
+    /// Set the maximum size in bytes of the payload that can be attached to a notification.
+    void set_max_payload_size(size_t value) &&;
     /// Defines the event id value that is emitted after a new notifier was created.
     auto notifier_created_event() && -> iox::optional<size_t>;
     /// Sets the event id value that is emitted after a new notifier was created.
@@ -106,6 +106,10 @@ constexpr auto from<int, iox2::NodeCreationFailure>(const int value) noexcept ->
         return iox2::NodeCreationFailure::InsufficientPermissions;
     case iox2_node_creation_failure_e_INTERNAL_ERROR:
         return iox2::NodeCreationFailure::InternalError;
+    case iox2_node_creation_failure_e_NAMESPACE_CONFLICT:
+        return iox2::NodeCreationFailure::NamespaceConflict;
+    case iox2_node_creation_failure_e_INVALID_CONFIG:
+        return iox2::NodeCreationFailure::InvalidConfig;
     }
 
     IOX_UNREACHABLE();
@@ -120,6 +124,10 @@ from<iox2::NodeCreationFailure, iox2_node_creation_failure_e>(const iox2::NodeCr
         return iox2_node_creation_failure_e_INSUFFICIENT_PERMISSIONS;
     case iox2::NodeCreationFailure::InternalError:
         return iox2_node_creation_failure_e_INTERNAL_ERROR;
+    case iox2::NodeCreationFailure::NamespaceConflict:
+        return iox2_node_creation_failure_e_NAMESPACE_CONFLICT;
+    case iox2::NodeCreationFailure::InvalidConfig:
+        return iox2_node_creation_failure_e_INVALID_CONFIG;
     }
 
     IOX_UNREACHABLE();
@@ -318,10 +326,18 @@ constexpr auto from<int, iox2::EventOpenOrCreateError>(const int value) noexcept
         return iox2::EventOpenOrCreateError::OpenDoesNotSupportRequestedMaxEventId;
     case iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES:
         return iox2::EventOpenOrCreateError::OpenDoesNotSupportRequestedAmountOfNodes;
+    case iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_MAX_PAYLOAD_SIZE:
+        return iox2::EventOpenOrCreateError::OpenDoesNotSupportRequestedMaxPayloadSize;
     case iox2_event_open_or_create_error_e_O_EXCEEDS_MAX_NUMBER_OF_NODES:
         return iox2::EventOpenOrCreateError::OpenExceedsMaxNumberOfNodes;
     case iox2_event_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION:
         return iox2::EventOpenOrCreateError::OpenIsMarkedForDestruction;
+    case iox2_event_open_or_create_error_e_O_SIGNATURE_VERIFICATION_FAILED:
+        return iox2::EventOpenOrCreateError::OpenSignatureVerificationFailed;
+    case iox2_event_open_or_create_error_e_O_INCOMPATIBLE_NOTIFIER_ORIGIN_SETTING:
+        return iox2::EventOpenOrCreateError::OpenIncompatibleNotifierOriginSetting;
+    case iox2_event_open_or_create_error_e_O_IS_FROZEN:
+        return iox2::EventOpenOrCreateError::OpenIsFrozen;
     case iox2_event_open_or_create_error_e_O_INCOMPATIBLE_NOTIFIER_CREATED_EVENT:
         return iox2::EventOpenOrCreateError::OpenIncompatibleNotifierCreatedEvent;
     case iox2_event_open_or_create_error_e_O_INCOMPATIBLE_NOTIFIER_DROPPED_EVENT:
@@ -344,6 +360,8 @@ constexpr auto from<int, iox2::EventOpenOrCreateError>(const int value) noexcept
         return iox2::EventOpenOrCreateError::CreateOldConnectionsStillActive;
     case iox2_event_open_or_create_error_e_SYSTEM_IN_FLUX:
         return iox2::EventOpenOrCreateError::SystemInFlux;
+    case iox2_event_open_or_create_error_e_TIMED_OUT:
+        return iox2::EventOpenOrCreateError::TimedOut;
     }
 
     IOX_UNREACHABLE();
@@ -376,10 +394,18 @@ from<iox2::EventOpenOrCreateError, iox2_event_open_or_create_error_e>(const iox2
         return iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_MAX_EVENT_ID;
     case iox2::EventOpenOrCreateError::OpenDoesNotSupportRequestedAmountOfNodes:
         return iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES;
+    case iox2::EventOpenOrCreateError::OpenDoesNotSupportRequestedMaxPayloadSize:
+        return iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_MAX_PAYLOAD_SIZE;
     case iox2::EventOpenOrCreateError::OpenExceedsMaxNumberOfNodes:
         return iox2_event_open_or_create_error_e_O_EXCEEDS_MAX_NUMBER_OF_NODES;
     case iox2::EventOpenOrCreateError::OpenIsMarkedForDestruction:
         return iox2_event_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION;
+    case iox2::EventOpenOrCreateError::OpenSignatureVerificationFailed:
+        return iox2_event_open_or_create_error_e_O_SIGNATURE_VERIFICATION_FAILED;
+    case iox2::EventOpenOrCreateError::OpenIncompatibleNotifierOriginSetting:
+        return iox2_event_open_or_create_error_e_O_INCOMPATIBLE_NOTIFIER_ORIGIN_SETTING;
+    case iox2::EventOpenOrCreateError::OpenIsFrozen:
+        return iox2_event_open_or_create_error_e_O_IS_FROZEN;
 
     case iox2::EventOpenOrCreateError::CreateServiceInCorruptedState:
         return iox2_event_open_or_create_error_e_C_SERVICE_IN_CORRUPTED_STATE;
@@ -395,6 +421,10 @@ from<iox2::EventOpenOrCreateError, iox2_event_open_or_create_error_e>(const iox2
         return iox2_event_open_or_create_error_e_C_INSUFFICIENT_PERMISSIONS;
     case iox2::EventOpenOrCreateError::CreateOldConnectionsStillActive:
         return iox2_event_open_or_create_error_e_C_OLD_CONNECTION_STILL_ACTIVE;
+    case iox2::EventOpenOrCreateError::SystemInFlux:
+        return iox2_event_open_or_create_error_e_SYSTEM_IN_FLUX;
+    case iox2::EventOpenOrCreateError::TimedOut:
+        return iox2_event_open_or_create_error_e_TIMED_OUT;
     default:
         IOX_UNREACHABLE();
     }
@@ -432,10 +462,18 @@ constexpr auto from<int, iox2::EventOpenError>(const int value) noexcept -> iox2
         return iox2::EventOpenError::DoesNotSupportRequestedMaxEventId;
     case iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES:
         return iox2::EventOpenError::DoesNotSupportRequestedAmountOfNodes;
+    case iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_MAX_PAYLOAD_SIZE:
+        return iox2::EventOpenError::DoesNotSupportRequestedMaxPayloadSize;
     case iox2_event_open_or_create_error_e_O_EXCEEDS_MAX_NUMBER_OF_NODES:
         return iox2::EventOpenError::ExceedsMaxNumberOfNodes;
     case iox2_event_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION:
         return iox2::EventOpenError::IsMarkedForDestruction;
+    case iox2_event_open_or_create_error_e_O_SIGNATURE_VERIFICATION_FAILED:
+        return iox2::EventOpenError::SignatureVerificationFailed;
+    case iox2_event_open_or_create_error_e_O_INCOMPATIBLE_NOTIFIER_ORIGIN_SETTING:
+        return iox2::EventOpenError::IncompatibleNotifierOriginSetting;
+    case iox2_event_open_or_create_error_e_O_IS_FROZEN:
+        return iox2::EventOpenError::IsFrozen;
     default:
         IOX_UNREACHABLE();
     }
@@ -467,10 +505,18 @@ constexpr auto from<iox2::EventOpenError, iox2_event_open_or_create_error_e>(con
         return iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_MAX_EVENT_ID;
     case iox2::EventOpenError::DoesNotSupportRequestedAmountOfNodes:
         return iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES;
+    case iox2::EventOpenError::DoesNotSupportRequestedMaxPayloadSize:
+        return iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_MAX_PAYLOAD_SIZE;
     case iox2::EventOpenError::ExceedsMaxNumberOfNodes:
         return iox2_event_open_or_create_error_e_O_EXCEEDS_MAX_NUMBER_OF_NODES;
     case iox2::EventOpenError::IsMarkedForDestruction:
         return iox2_event_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION;
+    case iox2::EventOpenError::SignatureVerificationFailed:
+        return iox2_event_open_or_create_error_e_O_SIGNATURE_VERIFICATION_FAILED;
+    case iox2::EventOpenError::IncompatibleNotifierOriginSetting:
+        return iox2_event_open_or_create_error_e_O_INCOMPATIBLE_NOTIFIER_ORIGIN_SETTING;
+    case iox2::EventOpenError::IsFrozen:
+        return iox2_event_open_or_create_error_e_O_IS_FROZEN;
     default:
         IOX_UNREACHABLE();
     }
@@ -560,6 +606,10 @@ constexpr auto from<int, iox2::PublishSubscribeOpenOrCreateError>(const int valu
         return iox2::PublishSubscribeOpenOrCreateError::OpenDoesNotSupportRequestedAmountOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR:
         return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleOverflowBehavior;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_PAYLOAD_CHECKSUM_SETTING:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatiblePayloadChecksumSetting;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_GLOBAL_ORDERING_SETTING:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleGlobalOrderingSetting;
     case iox2_pub_sub_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS:
         return iox2::PublishSubscribeOpenOrCreateError::OpenInsufficientPermissions;
     case iox2_pub_sub_open_or_create_error_e_O_SERVICE_IN_CORRUPTED_STATE:
@@ -570,6 +620,16 @@ constexpr auto from<int, iox2::PublishSubscribeOpenOrCreateError>(const int valu
         return iox2::PublishSubscribeOpenOrCreateError::OpenExceedsMaxNumberOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION:
         return iox2::PublishSubscribeOpenOrCreateError::OpenIsMarkedForDestruction;
+    case iox2_pub_sub_open_or_create_error_e_O_SIGNATURE_VERIFICATION_FAILED:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenSignatureVerificationFailed;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_BROADCAST_SETTING:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleBroadcastSetting;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_PRIORITY_LANES_SETTING:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatiblePriorityLanesSetting;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_REPLAY_SETTING:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleReplaySetting;
+    case iox2_pub_sub_open_or_create_error_e_O_IS_FROZEN:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIsFrozen;
 
     case iox2_pub_sub_open_or_create_error_e_C_SERVICE_IN_CORRUPTED_STATE:
         return iox2::PublishSubscribeOpenOrCreateError::CreateServiceInCorruptedState;
@@ -589,6 +649,8 @@ constexpr auto from<int, iox2::PublishSubscribeOpenOrCreateError>(const int valu
         return iox2::PublishSubscribeOpenOrCreateError::CreateOldConnectionsStillActive;
     case iox2_pub_sub_open_or_create_error_e_SYSTEM_IN_FLUX:
         return iox2::PublishSubscribeOpenOrCreateError::SystemInFlux;
+    case iox2_pub_sub_open_or_create_error_e_TIMED_OUT:
+        return iox2::PublishSubscribeOpenOrCreateError::TimedOut;
     }
 
     IOX_UNREACHABLE();
@@ -622,6 +684,10 @@ constexpr auto from<int, iox2::PublishSubscribeOpenError>(const int value) noexc
         return iox2::PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR:
         return iox2::PublishSubscribeOpenError::IncompatibleOverflowBehavior;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_PAYLOAD_CHECKSUM_SETTING:
+        return iox2::PublishSubscribeOpenError::IncompatiblePayloadChecksumSetting;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_GLOBAL_ORDERING_SETTING:
+        return iox2::PublishSubscribeOpenError::IncompatibleGlobalOrderingSetting;
     case iox2_pub_sub_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS:
         return iox2::PublishSubscribeOpenError::InsufficientPermissions;
     case iox2_pub_sub_open_or_create_error_e_O_SERVICE_IN_CORRUPTED_STATE:
@@ -632,6 +698,16 @@ constexpr auto from<int, iox2::PublishSubscribeOpenError>(const int value) noexc
         return iox2::PublishSubscribeOpenError::ExceedsMaxNumberOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION:
         return iox2::PublishSubscribeOpenError::IsMarkedForDestruction;
+    case iox2_pub_sub_open_or_create_error_e_O_SIGNATURE_VERIFICATION_FAILED:
+        return iox2::PublishSubscribeOpenError::SignatureVerificationFailed;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_BROADCAST_SETTING:
+        return iox2::PublishSubscribeOpenError::IncompatibleBroadcastSetting;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_PRIORITY_LANES_SETTING:
+        return iox2::PublishSubscribeOpenError::IncompatiblePriorityLanesSetting;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_REPLAY_SETTING:
+        return iox2::PublishSubscribeOpenError::IncompatibleReplaySetting;
+    case iox2_pub_sub_open_or_create_error_e_O_IS_FROZEN:
+        return iox2::PublishSubscribeOpenError::IsFrozen;
     default:
         IOX_UNREACHABLE();
     }
@@ -665,6 +741,10 @@ constexpr auto from<iox2::PublishSubscribeOpenError, iox2_pub_sub_open_or_create
         return iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES;
     case iox2::PublishSubscribeOpenError::IncompatibleOverflowBehavior:
         return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR;
+    case iox2::PublishSubscribeOpenError::IncompatiblePayloadChecksumSetting:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_PAYLOAD_CHECKSUM_SETTING;
+    case iox2::PublishSubscribeOpenError::IncompatibleGlobalOrderingSetting:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_GLOBAL_ORDERING_SETTING;
     case iox2::PublishSubscribeOpenError::InsufficientPermissions:
         return iox2_pub_sub_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS;
     case iox2::PublishSubscribeOpenError::ServiceInCorruptedState:
@@ -675,6 +755,16 @@ constexpr auto from<iox2::PublishSubscribeOpenError, iox2_pub_sub_open_or_create
         return iox2_pub_sub_open_or_create_error_e_O_EXCEEDS_MAX_NUMBER_OF_NODES;
     case iox2::PublishSubscribeOpenError::IsMarkedForDestruction:
         return iox2_pub_sub_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION;
+    case iox2::PublishSubscribeOpenError::SignatureVerificationFailed:
+        return iox2_pub_sub_open_or_create_error_e_O_SIGNATURE_VERIFICATION_FAILED;
+    case iox2::PublishSubscribeOpenError::IncompatibleBroadcastSetting:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_BROADCAST_SETTING;
+    case iox2::PublishSubscribeOpenError::IncompatiblePriorityLanesSetting:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_PRIORITY_LANES_SETTING;
+    case iox2::PublishSubscribeOpenError::IncompatibleReplaySetting:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_REPLAY_SETTING;
+    case iox2::PublishSubscribeOpenError::IsFrozen:
+        return iox2_pub_sub_open_or_create_error_e_O_IS_FROZEN;
     default:
         IOX_UNREACHABLE();
     }
@@ -767,6 +857,10 @@ constexpr auto from<iox2::PublishSubscribeOpenOrCreateError, iox2_pub_sub_open_o
         return iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES;
     case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleOverflowBehavior:
         return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatiblePayloadChecksumSetting:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_PAYLOAD_CHECKSUM_SETTING;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleGlobalOrderingSetting:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_GLOBAL_ORDERING_SETTING;
     case iox2::PublishSubscribeOpenOrCreateError::OpenInsufficientPermissions:
         return iox2_pub_sub_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS;
     case iox2::PublishSubscribeOpenOrCreateError::OpenServiceInCorruptedState:
@@ -777,6 +871,16 @@ constexpr auto from<iox2::PublishSubscribeOpenOrCreateError, iox2_pub_sub_open_o
         return iox2_pub_sub_open_or_create_error_e_O_EXCEEDS_MAX_NUMBER_OF_NODES;
     case iox2::PublishSubscribeOpenOrCreateError::OpenIsMarkedForDestruction:
         return iox2_pub_sub_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenSignatureVerificationFailed:
+        return iox2_pub_sub_open_or_create_error_e_O_SIGNATURE_VERIFICATION_FAILED;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleBroadcastSetting:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_BROADCAST_SETTING;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatiblePriorityLanesSetting:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_PRIORITY_LANES_SETTING;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleReplaySetting:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_REPLAY_SETTING;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIsFrozen:
+        return iox2_pub_sub_open_or_create_error_e_O_IS_FROZEN;
     case iox2::PublishSubscribeOpenOrCreateError::CreateServiceInCorruptedState:
         return iox2_pub_sub_open_or_create_error_e_C_SERVICE_IN_CORRUPTED_STATE;
     case iox2::PublishSubscribeOpenOrCreateError::CreateSubscriberBufferMustBeLargerThanHistorySize:
@@ -793,6 +897,10 @@ constexpr auto from<iox2::PublishSubscribeOpenOrCreateError, iox2_pub_sub_open_o
         return iox2_pub_sub_open_or_create_error_e_C_HANGS_IN_CREATION;
     case iox2::PublishSubscribeOpenOrCreateError::CreateOldConnectionsStillActive:
         return iox2_pub_sub_open_or_create_error_e_C_OLD_CONNECTION_STILL_ACTIVE;
+    case iox2::PublishSubscribeOpenOrCreateError::SystemInFlux:
+        return iox2_pub_sub_open_or_create_error_e_SYSTEM_IN_FLUX;
+    case iox2::PublishSubscribeOpenOrCreateError::TimedOut:
+        return iox2_pub_sub_open_or_create_error_e_TIMED_OUT;
     default:
         IOX_UNREACHABLE();
     }
@@ -811,6 +919,8 @@ constexpr auto from<int, iox2::NotifierCreateError>(const int value) noexcept ->
     switch (error) {
     case iox2_notifier_create_error_e_EXCEEDS_MAX_SUPPORTED_NOTIFIERS:
         return iox2::NotifierCreateError::ExceedsMaxSupportedNotifiers;
+    case iox2_notifier_create_error_e_EXCEEDS_RESOURCE_BUDGET_OF_NODE:
+        return iox2::NotifierCreateError::ExceedsResourceBudgetOfNode;
     }
 
     IOX_UNREACHABLE();
@@ -823,6 +933,8 @@ from<iox2::NotifierCreateError, iox2_notifier_create_error_e>(const iox2::Notifi
     switch (value) {
     case iox2::NotifierCreateError::ExceedsMaxSupportedNotifiers:
         return iox2_notifier_create_error_e_EXCEEDS_MAX_SUPPORTED_NOTIFIERS;
+    case iox2::NotifierCreateError::ExceedsResourceBudgetOfNode:
+        return iox2_notifier_create_error_e_EXCEEDS_RESOURCE_BUDGET_OF_NODE;
     }
 
     IOX_UNREACHABLE();
@@ -842,6 +954,8 @@ constexpr auto from<int, iox2::ListenerCreateError>(const int value) noexcept ->
         return iox2::ListenerCreateError::ExceedsMaxSupportedListeners;
     case iox2_listener_create_error_e_RESOURCE_CREATION_FAILED:
         return iox2::ListenerCreateError::ResourceCreationFailed;
+    case iox2_listener_create_error_e_EXCEEDS_RESOURCE_BUDGET_OF_NODE:
+        return iox2::ListenerCreateError::ExceedsResourceBudgetOfNode;
     }
 
     IOX_UNREACHABLE();
@@ -856,6 +970,8 @@ from<iox2::ListenerCreateError, iox2_listener_create_error_e>(const iox2::Listen
         return iox2_listener_create_error_e_EXCEEDS_MAX_SUPPORTED_LISTENERS;
     case iox2::ListenerCreateError::ResourceCreationFailed:
         return iox2_listener_create_error_e_RESOURCE_CREATION_FAILED;
+    case iox2::ListenerCreateError::ExceedsResourceBudgetOfNode:
+        return iox2_listener_create_error_e_EXCEEDS_RESOURCE_BUDGET_OF_NODE;
     }
 
     IOX_UNREACHABLE();
@@ -877,6 +993,8 @@ constexpr auto from<int, iox2::NotifierNotifyError>(const int value) noexcept ->
         return iox2::NotifierNotifyError::MissedDeadline;
     case iox2_notifier_notify_error_e_UNABLE_TO_ACQUIRE_ELAPSED_TIME:
         return iox2::NotifierNotifyError::UnableToAcquireElapsedTime;
+    case iox2_notifier_notify_error_e_EXCEEDS_MAX_PAYLOAD_SIZE:
+        return iox2::NotifierNotifyError::ExceedsMaxPayloadSize;
     }
 
     IOX_UNREACHABLE();
@@ -893,6 +1011,8 @@ from<iox2::NotifierNotifyError, iox2_notifier_notify_error_e>(const iox2::Notifi
         return iox2_notifier_notify_error_e_MISSED_DEADLINE;
     case iox2::NotifierNotifyError::UnableToAcquireElapsedTime:
         return iox2_notifier_notify_error_e_UNABLE_TO_ACQUIRE_ELAPSED_TIME;
+    case iox2::NotifierNotifyError::ExceedsMaxPayloadSize:
+        return iox2_notifier_notify_error_e_EXCEEDS_MAX_PAYLOAD_SIZE;
     }
 
     IOX_UNREACHABLE();
@@ -947,6 +1067,14 @@ constexpr auto from<int, iox2::PublisherCreateError>(const int value) noexcept -
         return iox2::PublisherCreateError::ExceedsMaxSupportedPublishers;
     case iox2_publisher_create_error_e_UNABLE_TO_CREATE_DATA_SEGMENT:
         return iox2::PublisherCreateError::UnableToCreateDataSegment;
+    case iox2_publisher_create_error_e_EXCEEDS_RESOURCE_BUDGET_OF_NODE:
+        return iox2::PublisherCreateError::ExceedsResourceBudgetOfNode;
+    case iox2_publisher_create_error_e_UNABLE_TO_CREATE_PERSISTENT_HISTORY:
+        return iox2::PublisherCreateError::UnableToCreatePersistentHistory;
+    case iox2_publisher_create_error_e_VIOLATES_DETERMINISTIC_PROFILE:
+        return iox2::PublisherCreateError::ViolatesDeterministicProfile;
+    case iox2_publisher_create_error_e_UNABLE_TO_CREATE_RECLAIM_EVENT:
+        return iox2::PublisherCreateError::UnableToCreateReclaimEvent;
     }
 
     IOX_UNREACHABLE();
@@ -961,6 +1089,14 @@ from<iox2::PublisherCreateError, iox2_publisher_create_error_e>(const iox2::Publ
         return iox2_publisher_create_error_e_EXCEEDS_MAX_SUPPORTED_PUBLISHERS;
     case iox2::PublisherCreateError::UnableToCreateDataSegment:
         return iox2_publisher_create_error_e_UNABLE_TO_CREATE_DATA_SEGMENT;
+    case iox2::PublisherCreateError::ExceedsResourceBudgetOfNode:
+        return iox2_publisher_create_error_e_EXCEEDS_RESOURCE_BUDGET_OF_NODE;
+    case iox2::PublisherCreateError::UnableToCreatePersistentHistory:
+        return iox2_publisher_create_error_e_UNABLE_TO_CREATE_PERSISTENT_HISTORY;
+    case iox2::PublisherCreateError::ViolatesDeterministicProfile:
+        return iox2_publisher_create_error_e_VIOLATES_DETERMINISTIC_PROFILE;
+    case iox2::PublisherCreateError::UnableToCreateReclaimEvent:
+        return iox2_publisher_create_error_e_UNABLE_TO_CREATE_RECLAIM_EVENT;
     }
 
     IOX_UNREACHABLE();
@@ -980,6 +1116,12 @@ constexpr auto from<int, iox2::SubscriberCreateError>(const int value) noexcept
         return iox2::SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService;
     case iox2_subscriber_create_error_e_EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS:
         return iox2::SubscriberCreateError::ExceedsMaxSupportedSubscribers;
+    case iox2_subscriber_create_error_e_EXCEEDS_RESOURCE_BUDGET_OF_NODE:
+        return iox2::SubscriberCreateError::ExceedsResourceBudgetOfNode;
+    case iox2_subscriber_create_error_e_VIOLATES_DETERMINISTIC_PROFILE:
+        return iox2::SubscriberCreateError::ViolatesDeterministicProfile;
+    case iox2_subscriber_create_error_e_IN_PLACE_UPDATES_REQUIRE_WRITABLE_PAYLOAD:
+        return iox2::SubscriberCreateError::InPlaceUpdatesRequireWritablePayload;
     }
 
     IOX_UNREACHABLE();
@@ -994,6 +1136,12 @@ from<iox2::SubscriberCreateError, iox2_subscriber_create_error_e>(const iox2::Su
         return iox2_subscriber_create_error_e_BUFFER_SIZE_EXCEEDS_MAX_SUPPORTED_BUFFER_SIZE_OF_SERVICE;
     case iox2::SubscriberCreateError::ExceedsMaxSupportedSubscribers:
         return iox2_subscriber_create_error_e_EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS;
+    case iox2::SubscriberCreateError::ExceedsResourceBudgetOfNode:
+        return iox2_subscriber_create_error_e_EXCEEDS_RESOURCE_BUDGET_OF_NODE;
+    case iox2::SubscriberCreateError::ViolatesDeterministicProfile:
+        return iox2_subscriber_create_error_e_VIOLATES_DETERMINISTIC_PROFILE;
+    case iox2::SubscriberCreateError::InPlaceUpdatesRequireWritablePayload:
+        return iox2_subscriber_create_error_e_IN_PLACE_UPDATES_REQUIRE_WRITABLE_PAYLOAD;
     }
 
     IOX_UNREACHABLE();
@@ -1023,6 +1171,12 @@ constexpr auto from<int, iox2::PublisherSendError>(const int value) noexcept ->
         return iox2::PublisherSendError::LoanErrorInternalFailure;
     case iox2_publisher_send_error_e_CONNECTION_ERROR:
         return iox2::PublisherSendError::ConnectionError;
+    case iox2_publisher_send_error_e_REPLAY_NOT_ENABLED:
+        return iox2::PublisherSendError::ReplayNotEnabled;
+    case iox2_publisher_send_error_e_INVALID_RAW_SAMPLE:
+        return iox2::PublisherSendError::InvalidRawSample;
+    case iox2_publisher_send_error_e_LOAN_ERROR_EXCEEDS_RESOURCE_BUDGET_OF_NODE:
+        return iox2::PublisherSendError::LoanErrorExceedsResourceBudgetOfNode;
     }
 
     IOX_UNREACHABLE();
@@ -1047,6 +1201,12 @@ from<iox2::PublisherSendError, iox2_publisher_send_error_e>(const iox2::Publishe
         return iox2_publisher_send_error_e_LOAN_ERROR_INTERNAL_FAILURE;
     case iox2::PublisherSendError::ConnectionError:
         return iox2_publisher_send_error_e_CONNECTION_ERROR;
+    case iox2::PublisherSendError::ReplayNotEnabled:
+        return iox2_publisher_send_error_e_REPLAY_NOT_ENABLED;
+    case iox2::PublisherSendError::InvalidRawSample:
+        return iox2_publisher_send_error_e_INVALID_RAW_SAMPLE;
+    case iox2::PublisherSendError::LoanErrorExceedsResourceBudgetOfNode:
+        return iox2_publisher_send_error_e_LOAN_ERROR_EXCEEDS_RESOURCE_BUDGET_OF_NODE;
     }
 
     IOX_UNREACHABLE();
@@ -1067,6 +1227,12 @@ constexpr auto from<int, iox2::SubscriberReceiveError>(const int value) noexcept
         return iox2::SubscriberReceiveError::UnableToMapPublishersDataSegment;
     case iox2_subscriber_receive_error_e_EXCEEDS_MAX_BORROWED_SAMPLES:
         return iox2::SubscriberReceiveError::ExceedsMaxBorrowedSamples;
+    case iox2_subscriber_receive_error_e_PAYLOAD_CHECKSUM_MISMATCH:
+        return iox2::SubscriberReceiveError::PayloadChecksumMismatch;
+    case iox2_subscriber_receive_error_e_EXPIRED:
+        return iox2::SubscriberReceiveError::Expired;
+    case iox2_subscriber_receive_error_e_EXCEEDS_MAX_TOTAL_BORROWED_SAMPLES:
+        return iox2::SubscriberReceiveError::ExceedsMaxTotalBorrowedSamples;
     }
 
     IOX_UNREACHABLE();
@@ -1083,6 +1249,12 @@ from<iox2::SubscriberReceiveError, iox2_subscriber_receive_error_e>(const iox2::
         return iox2_subscriber_receive_error_e_UNABLE_TO_MAP_PUBLISHERS_DATA_SEGMENT;
     case iox2::SubscriberReceiveError::ExceedsMaxBorrowedSamples:
         return iox2_subscriber_receive_error_e_EXCEEDS_MAX_BORROWED_SAMPLES;
+    case iox2::SubscriberReceiveError::PayloadChecksumMismatch:
+        return iox2_subscriber_receive_error_e_PAYLOAD_CHECKSUM_MISMATCH;
+    case iox2::SubscriberReceiveError::Expired:
+        return iox2_subscriber_receive_error_e_EXPIRED;
+    case iox2::SubscriberReceiveError::ExceedsMaxTotalBorrowedSamples:
+        return iox2_subscriber_receive_error_e_EXCEEDS_MAX_TOTAL_BORROWED_SAMPLES;
     }
 
     IOX_UNREACHABLE();
@@ -1106,6 +1278,8 @@ constexpr auto from<int, iox2::PublisherLoanError>(const int value) noexcept ->
         return iox2::PublisherLoanError::ExceedsMaxLoanSize;
     case iox2_publisher_loan_error_e_INTERNAL_FAILURE:
         return iox2::PublisherLoanError::InternalFailure;
+    case iox2_publisher_loan_error_e_EXCEEDS_RESOURCE_BUDGET_OF_NODE:
+        return iox2::PublisherLoanError::ExceedsResourceBudgetOfNode;
     }
 
     IOX_UNREACHABLE();
@@ -1124,6 +1298,8 @@ from<iox2::PublisherLoanError, iox2_publisher_loan_error_e>(const iox2::Publishe
         return iox2_publisher_loan_error_e_EXCEEDS_MAX_LOAN_SIZE;
     case iox2::PublisherLoanError::InternalFailure:
         return iox2_publisher_loan_error_e_INTERNAL_FAILURE;
+    case iox2::PublisherLoanError::ExceedsResourceBudgetOfNode:
+        return iox2_publisher_loan_error_e_EXCEEDS_RESOURCE_BUDGET_OF_NODE;
     }
 
     IOX_UNREACHABLE();
@@ -1199,6 +1375,8 @@ constexpr auto from<int, iox2::UnableToDeliverStrategy>(const int value) noexcep
         return iox2::UnableToDeliverStrategy::Block;
     case iox2_unable_to_deliver_strategy_e_DISCARD_SAMPLE:
         return iox2::UnableToDeliverStrategy::DiscardSample;
+    case iox2_unable_to_deliver_strategy_e_DISCARD_OLDEST:
+        return iox2::UnableToDeliverStrategy::DiscardOldest;
     }
 
     IOX_UNREACHABLE();
@@ -1209,6 +1387,8 @@ constexpr auto from<iox2::UnableToDeliverStrategy, int>(const iox2::UnableToDeli
     switch (value) {
     case iox2::UnableToDeliverStrategy::DiscardSample:
         return iox2_unable_to_deliver_strategy_e_DISCARD_SAMPLE;
+    case iox2::UnableToDeliverStrategy::DiscardOldest:
+        return iox2_unable_to_deliver_strategy_e_DISCARD_OLDEST;
     case iox2::UnableToDeliverStrategy::Block:
         return iox2_unable_to_deliver_strategy_e_BLOCK;
     }
@@ -1407,6 +1587,8 @@ constexpr auto from<int, iox2::WaitSetAttachmentError>(const int value) noexcept
         return iox2::WaitSetAttachmentError::InsufficientCapacity;
     case iox2_waitset_attachment_error_e_INTERNAL_ERROR:
         return iox2::WaitSetAttachmentError::InternalError;
+    case iox2_waitset_attachment_error_e_INVALID_FILE_DESCRIPTOR:
+        return iox2::WaitSetAttachmentError::InvalidFileDescriptor;
     }
 
     IOX_UNREACHABLE();
@@ -1423,6 +1605,8 @@ from<iox2::WaitSetAttachmentError, iox2_waitset_attachment_error_e>(const iox2::
         return iox2_waitset_attachment_error_e_INSUFFICIENT_CAPACITY;
     case iox2::WaitSetAttachmentError::InternalError:
         return iox2_waitset_attachment_error_e_INTERNAL_ERROR;
+    case iox2::WaitSetAttachmentError::InvalidFileDescriptor:
+        return iox2_waitset_attachment_error_e_INVALID_FILE_DESCRIPTOR;
     }
 
     IOX_UNREACHABLE();
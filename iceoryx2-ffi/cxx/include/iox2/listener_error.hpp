@@ -28,6 +28,9 @@ enum class ListenerCreateError : uint8_t {
     /// An underlying resource of the [`Service`] could
     /// not be created
     ResourceCreationFailed,
+    /// The [`Listener`] would exceed the maximum number of ports of the
+    /// [`Node`], see [`NodeBuilder`].
+    ExceedsResourceBudgetOfNode,
 };
 
 /// Defines failures that can occur while waiting for a notification from a
@@ -33,7 +33,12 @@ enum class NodeCreationFailure : uint8_t {
     InsufficientPermissions,
     /// Maybe the configuration/system is broken since someone has
     /// removed/modified internal resources
-    InternalError
+    InternalError,
+    /// Another alive [`Node`] uses the same root path and prefix but an incompatible
+    /// configuration, e.g. different suffixes or directories.
+    NamespaceConflict,
+    /// The provided environment config or default settings failed the validation.
+    InvalidConfig
 };
 
 /// Failures of [`DeadNodeView::remove_stale_resources()`] that occur when the stale resources of
@@ -25,6 +25,9 @@ enum class NotifierCreateError : uint8_t {
     /// [`Notifier`]s
     /// can be created for a specific [`Service`].
     ExceedsMaxSupportedNotifiers,
+    /// The [`Notifier`] would exceed the maximum number of ports of the
+    /// [`Node`], see [`NodeBuilder`].
+    ExceedsResourceBudgetOfNode,
 };
 
 /// Defines the failures that can occur while a [`Notifier::notify()`] call.
@@ -41,6 +44,9 @@ enum class NotifierNotifyError : uint8_t {
     /// The notification was delivered but the elapsed system time could not be acquired.
     /// Therefore, it is unknown if the deadline was missed or not.
     UnableToAcquireElapsedTime,
+    /// A [`Notifier::notify_with_payload()`] was called and the provided payload is larger
+    /// than the maximum payload size of the [`Service`].
+    ExceedsMaxPayloadSize,
 };
 
 } // namespace iox2
@@ -15,6 +15,8 @@
 
 #include "iox/slice.hpp"
 
+#include <type_traits>
+
 namespace iox2 {
 
 template <typename T>
@@ -27,5 +29,14 @@ struct PayloadInfo<iox::Slice<T>> {
     using ValueType = typename iox::Slice<T>::ValueType;
 };
 
+/// Is true when the payload type provides a `static constexpr const char* IOX2_TYPE_DESCRIPTOR`
+/// that describes its memory layout. Services whose payload types have different descriptors
+/// are incompatible, even when the type name, size and alignment match.
+template <typename T, typename = void>
+struct HasTypeDescriptor : std::false_type { };
+
+template <typename T>
+struct HasTypeDescriptor<T, std::void_t<decltype(T::IOX2_TYPE_DESCRIPTOR)>> : std::true_type { };
+
 } // namespace iox2
 #endif
@@ -29,6 +29,18 @@ enum class PublisherCreateError : uint8_t {
     /// The datasegment in which the payload of the [`Publisher`] is stored,
     /// could not be created.
     UnableToCreateDataSegment,
+    /// The [`Publisher`] would exceed the memory budget or the maximum number
+    /// of ports of the [`Node`], see [`NodeBuilder`].
+    ExceedsResourceBudgetOfNode,
+    /// The shared memory of the persistent history of the [`Publisher`] could
+    /// not be created or opened.
+    UnableToCreatePersistentHistory,
+    /// The requested settings violate the deterministic profile that
+    /// iceoryx2 was built with.
+    ViolatesDeterministicProfile,
+    /// The event with which the [`Subscriber`]s wake up the [`Publisher`] in a
+    /// blocking loan could not be created.
+    UnableToCreateReclaimEvent,
 };
 
 /// Defines a failure that can occur in [`Publisher::loan()`] and
@@ -54,6 +66,9 @@ enum class PublisherLoanError : uint8_t {
     /// Errors that indicate either an implementation issue or a wrongly
     /// configured system.
     InternalFailure,
+    /// The [`Publisher`]s dynamic data segment would have to grow beyond the
+    /// memory budget of the [`Node`].
+    ExceedsResourceBudgetOfNode,
 };
 
 /// Failure that can be emitted when a [`SampleMut`] is sent via [`SampleMut::send()`].
@@ -81,6 +96,15 @@ enum class PublisherSendError : uint8_t {
     /// A failure occurred while establishing a connection to a
     /// [`Subscriber`]
     ConnectionError,
+    /// [`Publisher::publish_raw()`] was called but the [`Service`] was not created with
+    /// replay enabled.
+    ReplayNotEnabled,
+    /// The size of the header, user header or payload of a raw sample does not match the
+    /// layout of the [`Service`].
+    InvalidRawSample,
+    /// The [`Publisher`]s dynamic data segment would have to grow beyond the memory budget of
+    /// the [`Node`].
+    LoanErrorExceedsResourceBudgetOfNode,
 };
 
 } // namespace iox2
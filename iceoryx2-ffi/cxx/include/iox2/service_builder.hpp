@@ -38,6 +38,10 @@ class ServiceBuilder {
     /// [`MessagingPattern::Event`] [`Service`].
     auto event() && -> ServiceBuilderEvent<S>;
 
+    /// Defines the maximum time an `open_or_create` call is allowed to take, including all
+    /// retries. When it is exceeded the call fails with a `TimedOut` error.
+    auto timeout(iox::units::Duration timeout) && -> ServiceBuilder&&;
+
   private:
     template <ServiceType>
     friend class Node;
@@ -51,6 +55,15 @@ inline ServiceBuilder<S>::ServiceBuilder(iox2_node_h_ref node_handle, iox2_servi
     : m_handle { iox2_node_service_builder(node_handle, nullptr, service_name_ptr) } {
 }
 
+template <ServiceType S>
+inline auto ServiceBuilder<S>::timeout(const iox::units::Duration timeout) && -> ServiceBuilder&& {
+    iox2_service_builder_set_timeout(&m_handle,
+                                     timeout.toSeconds(),
+                                     timeout.toNanoseconds()
+                                         - (timeout.toSeconds() * iox::units::Duration::NANOSECS_PER_SEC));
+    return std::move(*this);
+}
+
 template <ServiceType S>
 inline auto ServiceBuilder<S>::event() && -> ServiceBuilderEvent<S> {
     return ServiceBuilderEvent<S> { m_handle };
@@ -50,6 +50,9 @@ enum class EventOpenError : uint8_t {
     /// The [`Service`] supports less [`Node`]s than
     /// requested.
     DoesNotSupportRequestedAmountOfNodes,
+    /// The [`Service`] supports a smaller notification payload than
+    /// requested.
+    DoesNotSupportRequestedMaxPayloadSize,
     /// The maximum number of [`Node`]s have already opened
     /// the [`Service`].
     ExceedsMaxNumberOfNodes,
@@ -59,6 +62,13 @@ enum class EventOpenError : uint8_t {
     /// [`Service`] should be
     /// recreatable.
     IsMarkedForDestruction,
+    /// The [`Service`] was not created by a trusted creator.
+    SignatureVerificationFailed,
+    /// The [`Service`] does not satisfy the requested notifier origin
+    /// setting.
+    IncompatibleNotifierOriginSetting,
+    /// The [`Service`] is frozen and does not accept new [`Node`]s.
+    IsFrozen,
 };
 
 /// Failures that can occur when a new [`MessagingPattern::Event`] [`Service`] shall be created.
@@ -134,6 +144,9 @@ enum class EventOpenOrCreateError : uint8_t {
     /// The [`Service`] supports less [`Node`]s than
     /// requested.
     OpenDoesNotSupportRequestedAmountOfNodes,
+    /// The [`Service`] supports a smaller notification payload than
+    /// requested.
+    OpenDoesNotSupportRequestedMaxPayloadSize,
     /// The maximum number of [`Node`]s have already opened
     /// the [`Service`].
     OpenExceedsMaxNumberOfNodes,
@@ -143,6 +156,13 @@ enum class EventOpenOrCreateError : uint8_t {
     /// [`Service`] should be
     /// recreatable.
     OpenIsMarkedForDestruction,
+    /// The [`Service`] was not created by a trusted creator.
+    OpenSignatureVerificationFailed,
+    /// The [`Service`] does not satisfy the requested notifier origin
+    /// setting.
+    OpenIncompatibleNotifierOriginSetting,
+    /// The [`Service`] is frozen and does not accept new [`Node`]s.
+    OpenIsFrozen,
 
     /// Some underlying resources of the [`Service`] are either missing,
     /// corrupted or unaccessible.
@@ -170,6 +190,9 @@ enum class EventOpenOrCreateError : uint8_t {
     /// Can occur when another process creates and removes the same [`Service`] repeatedly with a
     /// high frequency.
     SystemInFlux,
+    /// The timeout defined with [`ServiceBuilder::timeout()`] was exceeded before the
+    /// [`Service`] could be opened or created.
+    TimedOut,
 };
 
 } // namespace iox2
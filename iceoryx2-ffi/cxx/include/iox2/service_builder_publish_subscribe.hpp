@@ -151,6 +151,16 @@ inline void ServiceBuilderPublishSubscribe<Payload, UserHeader, S>::set_paramete
         IOX_PANIC("This should never happen! Implementation failure while setting the Payload-Type.");
     }
 
+    if constexpr (HasTypeDescriptor<ValueType>::value) {
+        const auto* payload_type_descriptor = ValueType::IOX2_TYPE_DESCRIPTOR;
+        const auto descriptor_result = iox2_service_builder_pub_sub_set_payload_type_descriptor(
+            &m_handle, payload_type_descriptor, strlen(payload_type_descriptor));
+
+        if (descriptor_result != IOX2_OK) {
+            IOX_PANIC("This should never happen! Implementation failure while setting the Payload-Type-Descriptor.");
+        }
+    }
+
     // user header type details
     const auto header_layout = iox::Layout::from<UserHeader>();
     const auto* user_header_type_name = typeid(UserHeader).name();
@@ -49,6 +49,10 @@ enum class PublishSubscribeOpenError : uint8_t {
     DoesNotSupportRequestedAmountOfNodes,
     /// The [`Service`] required overflow behavior is not compatible.
     IncompatibleOverflowBehavior,
+    /// The [`Service`] required payload checksum setting is not compatible.
+    IncompatiblePayloadChecksumSetting,
+    /// The [`Service`] required global ordering setting is not compatible.
+    IncompatibleGlobalOrderingSetting,
     /// The process has not enough permissions to open the [`Service`]
     InsufficientPermissions,
     /// Some underlying resources of the [`Service`] are either missing,
@@ -67,6 +71,16 @@ enum class PublishSubscribeOpenError : uint8_t {
     /// [`Service`] should be
     /// recreatable.
     IsMarkedForDestruction,
+    /// The [`Service`] was not created by a trusted creator.
+    SignatureVerificationFailed,
+    /// The [`Service`] required broadcast setting is not compatible.
+    IncompatibleBroadcastSetting,
+    /// The [`Service`] required priority lanes setting is not compatible.
+    IncompatiblePriorityLanesSetting,
+    /// The [`Service`] required replay setting is not compatible.
+    IncompatibleReplaySetting,
+    /// The [`Service`] is frozen and does not accept new [`Node`]s.
+    IsFrozen,
 };
 
 /// Errors that can occur when a new [`MessagingPattern::PublishSubscribe`] [`Service`] shall be created.
@@ -127,6 +141,10 @@ enum class PublishSubscribeOpenOrCreateError : uint8_t {
     OpenDoesNotSupportRequestedAmountOfNodes,
     /// The [`Service`] required overflow behavior is not compatible.
     OpenIncompatibleOverflowBehavior,
+    /// The [`Service`] required payload checksum setting is not compatible.
+    OpenIncompatiblePayloadChecksumSetting,
+    /// The [`Service`] required global ordering setting is not compatible.
+    OpenIncompatibleGlobalOrderingSetting,
     /// The process has not enough permissions to open the [`Service`]
     OpenInsufficientPermissions,
     /// Some underlying resources of the [`Service`] are either missing,
@@ -145,6 +163,16 @@ enum class PublishSubscribeOpenOrCreateError : uint8_t {
     /// [`Service`] should be
     /// recreatable.
     OpenIsMarkedForDestruction,
+    /// The [`Service`] was not created by a trusted creator.
+    OpenSignatureVerificationFailed,
+    /// The [`Service`] required broadcast setting is not compatible.
+    OpenIncompatibleBroadcastSetting,
+    /// The [`Service`] required priority lanes setting is not compatible.
+    OpenIncompatiblePriorityLanesSetting,
+    /// The [`Service`] required replay setting is not compatible.
+    OpenIncompatibleReplaySetting,
+    /// The [`Service`] is frozen and does not accept new [`Node`]s.
+    OpenIsFrozen,
 
     /// Some underlying resources of the [`Service`] are either missing,
     /// corrupted or unaccessible.
@@ -177,6 +205,9 @@ enum class PublishSubscribeOpenOrCreateError : uint8_t {
     /// Can occur when another process creates and removes the same [`Service`] repeatedly with a
     /// high frequency.
     SystemInFlux,
+    /// The timeout defined with [`ServiceBuilder::timeout()`] was exceeded before the
+    /// [`Service`] could be opened or created.
+    TimedOut,
 };
 
 } // namespace iox2
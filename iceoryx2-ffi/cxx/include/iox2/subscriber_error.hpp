@@ -27,7 +27,18 @@ enum class SubscriberReceiveError : uint8_t {
     FailedToEstablishConnection,
 
     /// Failures when mapping the corresponding data segment
-    UnableToMapPublishersDataSegment
+    UnableToMapPublishersDataSegment,
+
+    /// The checksum of the received payload does not match the checksum in the header.
+    /// The corrupted sample was discarded.
+    PayloadChecksumMismatch,
+
+    /// The [`Subscriber`] has expired and cannot receive samples anymore.
+    Expired,
+
+    /// All [`Subscriber`]s of the [`Service`] together have already borrowed the maximum
+    /// number of [`Sample`]s.
+    ExceedsMaxTotalBorrowedSamples
 };
 
 /// Describes the failures when a new [`Subscriber`] is created via the
@@ -42,6 +53,18 @@ enum class SubscriberCreateError : uint8_t {
     /// When the [`Subscriber`] requires a larger buffer size than the
     /// [`Service`] offers the creation will fail.
     BufferSizeExceedsMaxSupportedBufferSizeOfService,
+
+    /// The [`Subscriber`] would exceed the memory budget or the maximum number
+    /// of ports of the [`Node`], see [`NodeBuilder`].
+    ExceedsResourceBudgetOfNode,
+
+    /// The requested settings violate the deterministic profile that
+    /// iceoryx2 was built with.
+    ViolatesDeterministicProfile,
+
+    /// In place updates were enabled but the payload of the received samples
+    /// is mapped read-only.
+    InPlaceUpdatesRequireWritablePayload,
 };
 
 } // namespace iox2
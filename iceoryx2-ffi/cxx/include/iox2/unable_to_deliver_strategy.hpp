@@ -25,7 +25,10 @@ enum class UnableToDeliverStrategy : uint8_t {
     /// [`Sample`] from the buffer and there is space again
     Block,
     /// Do not deliver the [`Sample`].
-    DiscardSample
+    DiscardSample,
+    /// Replace the oldest [`Sample`] the [`Subscriber`] has not yet received with the new
+    /// [`Sample`].
+    DiscardOldest
 };
 } // namespace iox2
 
@@ -42,7 +42,9 @@ enum class WaitSetAttachmentError : uint8_t {
     /// The attachment is already attached.
     AlreadyAttached,
     /// An internal error has occurred.
-    InternalError
+    InternalError,
+    /// The file descriptor is not a valid open file descriptor.
+    InvalidFileDescriptor
 };
 
 /// Defines the failures that can occur when calling [`WaitSet::run()`].
@@ -202,6 +202,14 @@ void Event::set_event_id_max_value(size_t value) && {
     iox2_config_defaults_event_set_event_id_max_value(m_config, value);
 }
 
+auto Event::max_payload_size() && -> size_t {
+    return iox2_config_defaults_event_max_payload_size(m_config);
+}
+
+void Event::set_max_payload_size(size_t value) && {
+    iox2_config_defaults_event_set_max_payload_size(m_config, value);
+}
+
 auto Event::notifier_created_event() && -> iox::optional<size_t> {
     size_t value = 0;
     if (iox2_config_defaults_event_notifier_created_event(m_config, &value)) {
@@ -400,6 +408,14 @@ void Service::set_static_config_storage_suffix(const iox::FileName& value) && {
     iox2_config_global_service_set_static_config_storage_suffix(m_config, value.as_string().c_str());
 }
 
+auto Service::attribute_index_suffix() && -> const char* {
+    return iox2_config_global_service_attribute_index_suffix(m_config);
+}
+
+void Service::set_attribute_index_suffix(const iox::FileName& value) && {
+    iox2_config_global_service_set_attribute_index_suffix(m_config, value.as_string().c_str());
+}
+
 auto Service::dynamic_config_storage_suffix() && -> const char* {
     return iox2_config_global_service_dynamic_config_storage_suffix(m_config);
 }
@@ -436,6 +452,30 @@ auto Service::event_connection_suffix() && -> const char* {
 void Service::set_event_connection_suffix(const iox::FileName& value) && {
     iox2_config_global_service_set_event_connection_suffix(m_config, value.as_string().c_str());
 }
+
+auto Service::broadcast_suffix() && -> const char* {
+    return iox2_config_global_service_broadcast_suffix(m_config);
+}
+
+void Service::set_broadcast_suffix(const iox::FileName& value) && {
+    iox2_config_global_service_set_broadcast_suffix(m_config, value.as_string().c_str());
+}
+
+auto Service::verify_service_signatures() && -> bool {
+    return iox2_config_global_service_verify_service_signatures(m_config);
+}
+
+void Service::set_verify_service_signatures(bool value) && {
+    iox2_config_global_service_set_verify_service_signatures(m_config, value);
+}
+
+auto Service::read_only_payload_mapping() && -> bool {
+    return iox2_config_global_service_read_only_payload_mapping(m_config);
+}
+
+void Service::set_read_only_payload_mapping(bool value) && {
+    iox2_config_global_service_set_read_only_payload_mapping(m_config, value);
+}
 /////////////////////////
 // END: Service
 /////////////////////////
@@ -98,6 +98,14 @@ TEST(Config, defaults_event_event_id_max_value) {
     ASSERT_THAT(config.defaults().event().event_id_max_value(), Eq(test_value));
 }
 
+TEST(Config, defaults_event_max_payload_size) {
+    const auto test_value = 37;
+    auto config = Config();
+
+    config.defaults().event().set_max_payload_size(test_value);
+    ASSERT_THAT(config.defaults().event().max_payload_size(), Eq(test_value));
+}
+
 TEST(Config, defaults_publish_subscribe_max_subscribers) {
     const auto test_value = 313;
     auto config = Config();
@@ -171,6 +179,9 @@ TEST(Config, defaults_publish_subscribe_unable_to_deliver_strategy) {
     config.defaults().publish_subscribe().set_unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample);
     ASSERT_THAT(config.defaults().publish_subscribe().unable_to_deliver_strategy(),
                 Eq(UnableToDeliverStrategy::DiscardSample));
+    config.defaults().publish_subscribe().set_unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardOldest);
+    ASSERT_THAT(config.defaults().publish_subscribe().unable_to_deliver_strategy(),
+                Eq(UnableToDeliverStrategy::DiscardOldest));
 }
 
 TEST(Config, defaults_publish_subscribe_subscriber_expired_connection_buffer) {
@@ -205,6 +216,14 @@ TEST(Config, global_service_static_config_storage_suffix) {
     ASSERT_THAT(config.global().service().static_config_storage_suffix(), StrEq(test_value.as_string().c_str()));
 }
 
+TEST(Config, global_service_attribute_index_suffix) {
+    const auto test_value = iox::FileName::create("its_a_fishy_index").expect("");
+    auto config = Config();
+
+    config.global().service().set_attribute_index_suffix(test_value);
+    ASSERT_THAT(config.global().service().attribute_index_suffix(), StrEq(test_value.as_string().c_str()));
+}
+
 TEST(Config, global_service_dynamic_config_storage_suffix) {
     const auto test_value = iox::FileName::create("nala_runs_while_dreaming").expect("");
     auto config = Config();
@@ -237,6 +256,32 @@ TEST(Config, global_service_event_connection_suffix) {
     ASSERT_THAT(config.global().service().event_connection_suffix(), StrEq(test_value.as_string().c_str()));
 }
 
+TEST(Config, global_service_broadcast_suffix) {
+    const auto test_value = iox::FileName::create("one_voice_many_ears").expect("");
+    auto config = Config();
+
+    config.global().service().set_broadcast_suffix(test_value);
+    ASSERT_THAT(config.global().service().broadcast_suffix(), StrEq(test_value.as_string().c_str()));
+}
+
+TEST(Config, global_service_verify_service_signatures) {
+    auto config = Config();
+
+    config.global().service().set_verify_service_signatures(true);
+    ASSERT_THAT(config.global().service().verify_service_signatures(), Eq(true));
+    config.global().service().set_verify_service_signatures(false);
+    ASSERT_THAT(config.global().service().verify_service_signatures(), Eq(false));
+}
+
+TEST(Config, global_service_read_only_payload_mapping) {
+    auto config = Config();
+
+    config.global().service().set_read_only_payload_mapping(true);
+    ASSERT_THAT(config.global().service().read_only_payload_mapping(), Eq(true));
+    config.global().service().set_read_only_payload_mapping(false);
+    ASSERT_THAT(config.global().service().read_only_payload_mapping(), Eq(false));
+}
+
 TEST(Config, global_node_directory) {
     const auto test_value = iox::Path::create("eat/the/carrototier").expect("");
     auto config = Config();
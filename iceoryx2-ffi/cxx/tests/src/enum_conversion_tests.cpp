@@ -46,6 +46,7 @@ TEST(EnumConversionTest, listener_create_into_c_str) {
     using Sut = iox2::ListenerCreateError;
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxSupportedListeners)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ResourceCreationFailed)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsResourceBudgetOfNode)), 1U);
 }
 
 TEST(EnumConversionTest, listener_wait_into_c_str) {
@@ -66,6 +67,8 @@ TEST(EnumConversionTest, node_creation_failure_into_c_str) {
     using Sut = iox2::NodeCreationFailure;
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InsufficientPermissions)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InternalError)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::NamespaceConflict)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::InvalidConfig)), 1U);
 }
 
 TEST(EnumConversionTest, node_wait_failure_into_c_str) {
@@ -77,17 +80,23 @@ TEST(EnumConversionTest, node_wait_failure_into_c_str) {
 TEST(EnumConversionTest, notifier_create_into_c_str) {
     using Sut = iox2::NotifierCreateError;
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxSupportedNotifiers)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsResourceBudgetOfNode)), 1U);
 }
 
 TEST(EnumConversionTest, notifier_notify_into_c_str) {
     using Sut = iox2::NotifierNotifyError;
     ASSERT_GT(strlen(iox::into<const char*>(Sut::EventIdOutOfBounds)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxPayloadSize)), 1U);
 }
 
 TEST(EnumConversionTest, publisher_create_into_c_str) {
     using Sut = iox2::PublisherCreateError;
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxSupportedPublishers)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::UnableToCreateDataSegment)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsResourceBudgetOfNode)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::UnableToCreatePersistentHistory)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::ViolatesDeterministicProfile)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::UnableToCreateReclaimEvent)), 1U);
 }
 
 TEST(EnumConversionTest, publisher_loan_into_c_str) {
@@ -96,6 +105,7 @@ TEST(EnumConversionTest, publisher_loan_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxLoanedSamples)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxLoanSize)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InternalFailure)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsResourceBudgetOfNode)), 1U);
 }
 
 TEST(EnumConversionTest, publisher_send_into_c_str) {
@@ -107,6 +117,9 @@ TEST(EnumConversionTest, publisher_send_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::LoanErrorExceedsMaxLoanSize)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::LoanErrorInternalFailure)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ConnectionError)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::ReplayNotEnabled)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::InvalidRawSample)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::LoanErrorExceedsResourceBudgetOfNode)), 1U);
 }
 
 TEST(EnumConversionTest, event_open_into_c_str) {
@@ -121,9 +134,13 @@ TEST(EnumConversionTest, event_open_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::DoesNotSupportRequestedAmountOfNotifiers)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::DoesNotSupportRequestedAmountOfListeners)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::DoesNotSupportRequestedMaxEventId)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::DoesNotSupportRequestedMaxPayloadSize)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::DoesNotSupportRequestedAmountOfNodes)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxNumberOfNodes)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::IsMarkedForDestruction)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::SignatureVerificationFailed)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::IncompatibleNotifierOriginSetting)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::IsFrozen)), 1U);
 }
 
 TEST(EnumConversionTest, event_create_into_c_str) {
@@ -149,9 +166,13 @@ TEST(EnumConversionTest, event_open_or_create_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenDoesNotSupportRequestedAmountOfNotifiers)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenDoesNotSupportRequestedAmountOfListeners)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenDoesNotSupportRequestedMaxEventId)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenDoesNotSupportRequestedMaxPayloadSize)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenDoesNotSupportRequestedAmountOfNodes)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenExceedsMaxNumberOfNodes)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIsMarkedForDestruction)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenSignatureVerificationFailed)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIncompatibleNotifierOriginSetting)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIsFrozen)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateServiceInCorruptedState)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateInternalFailure)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateIsBeingCreatedByAnotherInstance)), 1U);
@@ -159,6 +180,8 @@ TEST(EnumConversionTest, event_open_or_create_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateHangsInCreation)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateInsufficientPermissions)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateOldConnectionsStillActive)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::SystemInFlux)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::TimedOut)), 1U);
 }
 
 TEST(EnumConversionTest, publish_subscribe_open_into_c_str) {
@@ -175,11 +198,18 @@ TEST(EnumConversionTest, publish_subscribe_open_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::DoesNotSupportRequestedAmountOfSubscribers)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::DoesNotSupportRequestedAmountOfNodes)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::IncompatibleOverflowBehavior)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::IncompatiblePayloadChecksumSetting)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::IncompatibleGlobalOrderingSetting)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InsufficientPermissions)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ServiceInCorruptedState)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::HangsInCreation)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxNumberOfNodes)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::IsMarkedForDestruction)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::SignatureVerificationFailed)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::IncompatibleBroadcastSetting)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::IncompatiblePriorityLanesSetting)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::IncompatibleReplaySetting)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::IsFrozen)), 1U);
 }
 
 TEST(EnumConversionTest, publish_subscribe_create_into_c_str) {
@@ -207,11 +237,18 @@ TEST(EnumConversionTest, publish_subscribe_open_or_create_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenDoesNotSupportRequestedAmountOfSubscribers)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenDoesNotSupportRequestedAmountOfNodes)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIncompatibleOverflowBehavior)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIncompatiblePayloadChecksumSetting)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIncompatibleGlobalOrderingSetting)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenInsufficientPermissions)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenServiceInCorruptedState)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenHangsInCreation)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenExceedsMaxNumberOfNodes)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIsMarkedForDestruction)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenSignatureVerificationFailed)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIncompatibleBroadcastSetting)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIncompatiblePriorityLanesSetting)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIncompatibleReplaySetting)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::OpenIsFrozen)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateServiceInCorruptedState)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateSubscriberBufferMustBeLargerThanHistorySize)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateAlreadyExists)), 1U);
@@ -220,6 +257,8 @@ TEST(EnumConversionTest, publish_subscribe_open_or_create_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateIsBeingCreatedByAnotherInstance)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateOldConnectionsStillActive)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::CreateHangsInCreation)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::SystemInFlux)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::TimedOut)), 1U);
 }
 
 TEST(EnumConversionTest, service_details_into_c_str) {
@@ -244,12 +283,18 @@ TEST(EnumConversionTest, subscriber_receive_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxBorrowedSamples)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::FailedToEstablishConnection)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::UnableToMapPublishersDataSegment)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::PayloadChecksumMismatch)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::Expired)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxTotalBorrowedSamples)), 1U);
 }
 
 TEST(EnumConversionTest, subscriber_create_into_c_str) {
     using Sut = iox2::SubscriberCreateError;
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxSupportedSubscribers)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::BufferSizeExceedsMaxSupportedBufferSizeOfService)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsResourceBudgetOfNode)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::ViolatesDeterministicProfile)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::InPlaceUpdatesRequireWritablePayload)), 1U);
 }
 
 TEST(EnumConversionTest, waitset_create_into_c_str) {
@@ -262,6 +307,7 @@ TEST(EnumConversionTest, waitset_attachment_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InsufficientCapacity)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::AlreadyAttached)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InternalError)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::InvalidFileDescriptor)), 1U);
 }
 
 TEST(EnumConversionTest, waitset_run_into_c_str) {
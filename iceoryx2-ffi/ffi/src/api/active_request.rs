@@ -0,0 +1,270 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types)]
+
+use crate::api::{
+    c_size_t, iox2_response_mut_h, iox2_response_mut_t, iox2_service_type_e, AssertNonNullHandle,
+    HandleToType, IntoCInt, RequestPayloadFfi, ResponseMutUnion, ResponsePayloadFfi, IOX2_OK,
+};
+
+use iceoryx2::active_request::ActiveRequest;
+use iceoryx2::prelude::*;
+use iceoryx2_bb_elementary::static_assert::*;
+use iceoryx2_ffi_macros::iceoryx2_ffi;
+
+use core::ffi::{c_int, c_void};
+use core::mem::ManuallyDrop;
+
+// BEGIN types definition
+
+pub(super) union ActiveRequestUnion {
+    ipc: ManuallyDrop<ActiveRequest<ipc::Service, RequestPayloadFfi, (), ResponsePayloadFfi, ()>>,
+    local:
+        ManuallyDrop<ActiveRequest<local::Service, RequestPayloadFfi, (), ResponsePayloadFfi, ()>>,
+}
+
+impl ActiveRequestUnion {
+    pub(super) fn new_ipc(
+        active_request: ActiveRequest<ipc::Service, RequestPayloadFfi, (), ResponsePayloadFfi, ()>,
+    ) -> Self {
+        Self {
+            ipc: ManuallyDrop::new(active_request),
+        }
+    }
+    pub(super) fn new_local(
+        active_request: ActiveRequest<
+            local::Service,
+            RequestPayloadFfi,
+            (),
+            ResponsePayloadFfi,
+            (),
+        >,
+    ) -> Self {
+        Self {
+            local: ManuallyDrop::new(active_request),
+        }
+    }
+}
+
+#[repr(C)]
+#[repr(align(8))] // alignment of Option<ActiveRequestUnion>
+pub struct iox2_active_request_storage_t {
+    internal: [u8; 96], // magic number obtained with size_of::<Option<ActiveRequestUnion>>()
+}
+
+#[repr(C)]
+#[iceoryx2_ffi(ActiveRequestUnion)]
+pub struct iox2_active_request_t {
+    service_type: iox2_service_type_e,
+    value: iox2_active_request_storage_t,
+    deleter: fn(*mut iox2_active_request_t),
+}
+
+impl iox2_active_request_t {
+    pub(super) fn init(
+        &mut self,
+        service_type: iox2_service_type_e,
+        value: ActiveRequestUnion,
+        deleter: fn(*mut iox2_active_request_t),
+    ) {
+        self.service_type = service_type;
+        self.value.init(value);
+        self.deleter = deleter;
+    }
+}
+
+pub struct iox2_active_request_h_t;
+/// The owning handle for `iox2_active_request_t`. Passing the handle to an function transfers the ownership.
+pub type iox2_active_request_h = *mut iox2_active_request_h_t;
+/// The non-owning handle for `iox2_active_request_t`. Passing the handle to an function does not transfers the ownership.
+pub type iox2_active_request_h_ref = *const iox2_active_request_h;
+
+impl AssertNonNullHandle for iox2_active_request_h {
+    fn assert_non_null(self) {
+        debug_assert!(!self.is_null());
+    }
+}
+
+impl AssertNonNullHandle for iox2_active_request_h_ref {
+    fn assert_non_null(self) {
+        debug_assert!(!self.is_null());
+        unsafe {
+            debug_assert!(!(*self).is_null());
+        }
+    }
+}
+
+impl HandleToType for iox2_active_request_h {
+    type Target = *mut iox2_active_request_t;
+
+    fn as_type(self) -> Self::Target {
+        self as *mut _ as _
+    }
+}
+
+impl HandleToType for iox2_active_request_h_ref {
+    type Target = *mut iox2_active_request_t;
+
+    fn as_type(self) -> Self::Target {
+        unsafe { *self as *mut _ as _ }
+    }
+}
+
+// END type definition
+
+// BEGIN C API
+
+/// Acquires the requests payload.
+///
+/// # Safety
+///
+/// * `handle` obtained by [`iox2_server_receive()`](crate::iox2_server_receive())
+/// * `payload_ptr` a valid, non-null pointer pointing to a [`*const c_void`] pointer.
+/// * `number_of_bytes` (optional) either a null pointer or a valid pointer pointing to a [`c_size_t`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_active_request_payload(
+    handle: iox2_active_request_h_ref,
+    payload_ptr: *mut *const c_void,
+    number_of_bytes: *mut c_size_t,
+) {
+    handle.assert_non_null();
+    debug_assert!(!payload_ptr.is_null());
+
+    let active_request = &mut *handle.as_type();
+
+    let payload = match active_request.service_type {
+        iox2_service_type_e::IPC => active_request.value.as_mut().ipc.payload(),
+        iox2_service_type_e::LOCAL => active_request.value.as_mut().local.payload(),
+    };
+
+    *payload_ptr = payload.as_ptr().cast();
+
+    if !number_of_bytes.is_null() {
+        *number_of_bytes = payload.len() as c_size_t;
+    }
+}
+
+/// Loans memory for a response to the active request.
+///
+/// # Arguments
+///
+/// * `active_request_handle` - Must be a valid [`iox2_active_request_h_ref`]
+///   obtained by [`iox2_server_receive`](crate::iox2_server_receive).
+/// * `response_struct_ptr` - Must be either a NULL pointer or a pointer to a valid [`iox2_response_mut_t`].
+///   If it is a NULL pointer, the storage will be allocated on the heap.
+/// * `response_handle_ptr` - An uninitialized or dangling [`iox2_response_mut_h`] handle which will be initialized by this function call if a response is obtained, otherwise it will be set to NULL.
+/// * `number_of_bytes` - The number of bytes of the response payload.
+///
+/// Return [`IOX2_OK`] on success, otherwise [`iox2_loan_error_e`](crate::iox2_loan_error_e).
+///
+/// # Safety
+///
+/// * `active_request_handle` is valid and non-null
+/// * The `response_handle_ptr` is pointing to a valid [`iox2_response_mut_h`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_active_request_loan_slice(
+    active_request_handle: iox2_active_request_h_ref,
+    response_struct_ptr: *mut iox2_response_mut_t,
+    response_handle_ptr: *mut iox2_response_mut_h,
+    number_of_bytes: c_size_t,
+) -> c_int {
+    active_request_handle.assert_non_null();
+    debug_assert!(!response_handle_ptr.is_null());
+
+    *response_handle_ptr = core::ptr::null_mut();
+
+    let init_response_struct_ptr = |response_struct_ptr: *mut iox2_response_mut_t| {
+        let mut response_struct_ptr = response_struct_ptr;
+        fn no_op(_: *mut iox2_response_mut_t) {}
+        let mut deleter: fn(*mut iox2_response_mut_t) = no_op;
+        if response_struct_ptr.is_null() {
+            response_struct_ptr = iox2_response_mut_t::alloc();
+            deleter = iox2_response_mut_t::dealloc;
+        }
+        debug_assert!(!response_struct_ptr.is_null());
+
+        (response_struct_ptr, deleter)
+    };
+
+    let active_request = &mut *active_request_handle.as_type();
+
+    match active_request.service_type {
+        iox2_service_type_e::IPC => match active_request
+            .value
+            .as_ref()
+            .ipc
+            .loan_slice(number_of_bytes)
+        {
+            Ok(response) => {
+                let (response_struct_ptr, deleter) = init_response_struct_ptr(response_struct_ptr);
+                (*response_struct_ptr).init(
+                    active_request.service_type,
+                    ResponseMutUnion::new_ipc(response),
+                    deleter,
+                );
+                *response_handle_ptr = (*response_struct_ptr).as_handle();
+                IOX2_OK
+            }
+            Err(error) => error.into_c_int(),
+        },
+        iox2_service_type_e::LOCAL => match active_request
+            .value
+            .as_ref()
+            .local
+            .loan_slice(number_of_bytes)
+        {
+            Ok(response) => {
+                let (response_struct_ptr, deleter) = init_response_struct_ptr(response_struct_ptr);
+                (*response_struct_ptr).init(
+                    active_request.service_type,
+                    ResponseMutUnion::new_local(response),
+                    deleter,
+                );
+                *response_handle_ptr = (*response_struct_ptr).as_handle();
+                IOX2_OK
+            }
+            Err(error) => error.into_c_int(),
+        },
+    }
+}
+
+/// This function needs to be called to destroy the active request! Afterwards, the client
+/// is notified that no further responses will arrive.
+///
+/// # Arguments
+///
+/// * `active_request_handle` - A valid [`iox2_active_request_h`]
+///
+/// # Safety
+///
+/// * The `active_request_handle` is invalid after the return of this function and leads to undefined behavior if used in another function call!
+/// * The corresponding [`iox2_active_request_t`] can be re-used with a call to
+///   [`iox2_server_receive`](crate::iox2_server_receive)!
+#[no_mangle]
+pub unsafe extern "C" fn iox2_active_request_drop(active_request_handle: iox2_active_request_h) {
+    debug_assert!(!active_request_handle.is_null());
+
+    let active_request = &mut *active_request_handle.as_type();
+
+    match active_request.service_type {
+        iox2_service_type_e::IPC => {
+            ManuallyDrop::drop(&mut active_request.value.as_mut().ipc);
+        }
+        iox2_service_type_e::LOCAL => {
+            ManuallyDrop::drop(&mut active_request.value.as_mut().local);
+        }
+    }
+    (active_request.deleter)(active_request);
+}
+
+// END C API
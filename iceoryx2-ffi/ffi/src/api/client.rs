@@ -0,0 +1,354 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types)]
+
+use crate::api::{
+    c_size_t, iox2_request_mut_h, iox2_request_mut_t, iox2_service_type_e, AssertNonNullHandle,
+    HandleToType, IntoCInt, RequestMutUnion, RequestPayloadFfi, ResponsePayloadFfi, IOX2_OK,
+};
+
+use iceoryx2::port::client::{Client, RequestSendError};
+use iceoryx2::port::LoanError;
+use iceoryx2::prelude::*;
+use iceoryx2_bb_elementary::static_assert::*;
+use iceoryx2_bb_elementary::AsCStr;
+use iceoryx2_ffi_macros::iceoryx2_ffi;
+use iceoryx2_ffi_macros::CStrRepr;
+
+use core::ffi::{c_char, c_int};
+use core::mem::ManuallyDrop;
+
+// BEGIN types definition
+
+#[repr(C)]
+#[derive(Copy, Clone, CStrRepr)]
+pub enum iox2_loan_error_e {
+    OUT_OF_MEMORY = IOX2_OK as isize + 1,
+    EXCEEDS_MAX_LOANS,
+    EXCEEDS_MAX_LOAN_SIZE,
+    INTERNAL_FAILURE,
+}
+
+impl IntoCInt for LoanError {
+    fn into_c_int(self) -> c_int {
+        (match self {
+            LoanError::OutOfMemory => iox2_loan_error_e::OUT_OF_MEMORY,
+            LoanError::ExceedsMaxLoans => iox2_loan_error_e::EXCEEDS_MAX_LOANS,
+            LoanError::ExceedsMaxLoanSize => iox2_loan_error_e::EXCEEDS_MAX_LOAN_SIZE,
+            LoanError::InternalFailure => iox2_loan_error_e::INTERNAL_FAILURE,
+        }) as c_int
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, CStrRepr)]
+pub enum iox2_request_send_error_e {
+    CONNECTION_CORRUPTED = IOX2_OK as isize + 1,
+    EXCEEDS_MAX_ACTIVE_REQUESTS,
+    LOAN_ERROR_OUT_OF_MEMORY,
+    LOAN_ERROR_EXCEEDS_MAX_LOANS,
+    LOAN_ERROR_EXCEEDS_MAX_LOAN_SIZE,
+    LOAN_ERROR_INTERNAL_FAILURE,
+    CONNECTION_ERROR,
+    FIRE_AND_FORGET_REQUESTS_NOT_ENABLED,
+}
+
+impl IntoCInt for RequestSendError {
+    fn into_c_int(self) -> c_int {
+        (match self {
+            RequestSendError::ConnectionCorrupted => {
+                iox2_request_send_error_e::CONNECTION_CORRUPTED
+            }
+            RequestSendError::ExceedsMaxActiveRequests => {
+                iox2_request_send_error_e::EXCEEDS_MAX_ACTIVE_REQUESTS
+            }
+            RequestSendError::LoanError(LoanError::OutOfMemory) => {
+                iox2_request_send_error_e::LOAN_ERROR_OUT_OF_MEMORY
+            }
+            RequestSendError::LoanError(LoanError::ExceedsMaxLoans) => {
+                iox2_request_send_error_e::LOAN_ERROR_EXCEEDS_MAX_LOANS
+            }
+            RequestSendError::LoanError(LoanError::ExceedsMaxLoanSize) => {
+                iox2_request_send_error_e::LOAN_ERROR_EXCEEDS_MAX_LOAN_SIZE
+            }
+            RequestSendError::LoanError(LoanError::InternalFailure) => {
+                iox2_request_send_error_e::LOAN_ERROR_INTERNAL_FAILURE
+            }
+            RequestSendError::ConnectionError(_) => iox2_request_send_error_e::CONNECTION_ERROR,
+            RequestSendError::FireAndForgetRequestsNotEnabled => {
+                iox2_request_send_error_e::FIRE_AND_FORGET_REQUESTS_NOT_ENABLED
+            }
+        }) as c_int
+    }
+}
+
+pub(super) union ClientUnion {
+    ipc: ManuallyDrop<Client<ipc::Service, RequestPayloadFfi, (), ResponsePayloadFfi, ()>>,
+    local: ManuallyDrop<Client<local::Service, RequestPayloadFfi, (), ResponsePayloadFfi, ()>>,
+}
+
+impl ClientUnion {
+    pub(super) fn new_ipc(
+        client: Client<ipc::Service, RequestPayloadFfi, (), ResponsePayloadFfi, ()>,
+    ) -> Self {
+        Self {
+            ipc: ManuallyDrop::new(client),
+        }
+    }
+    pub(super) fn new_local(
+        client: Client<local::Service, RequestPayloadFfi, (), ResponsePayloadFfi, ()>,
+    ) -> Self {
+        Self {
+            local: ManuallyDrop::new(client),
+        }
+    }
+}
+
+#[repr(C)]
+#[repr(align(16))] // alignment of Option<ClientUnion>
+pub struct iox2_client_storage_t {
+    internal: [u8; 64], // magic number obtained with size_of::<Option<ClientUnion>>()
+}
+
+#[repr(C)]
+#[iceoryx2_ffi(ClientUnion)]
+pub struct iox2_client_t {
+    service_type: iox2_service_type_e,
+    value: iox2_client_storage_t,
+    deleter: fn(*mut iox2_client_t),
+}
+
+impl iox2_client_t {
+    pub(super) fn init(
+        &mut self,
+        service_type: iox2_service_type_e,
+        value: ClientUnion,
+        deleter: fn(*mut iox2_client_t),
+    ) {
+        self.service_type = service_type;
+        self.value.init(value);
+        self.deleter = deleter;
+    }
+}
+
+pub struct iox2_client_h_t;
+/// The owning handle for `iox2_client_t`. Passing the handle to an function transfers the ownership.
+pub type iox2_client_h = *mut iox2_client_h_t;
+/// The non-owning handle for `iox2_client_t`. Passing the handle to an function does not transfers the ownership.
+pub type iox2_client_h_ref = *const iox2_client_h;
+
+impl AssertNonNullHandle for iox2_client_h {
+    fn assert_non_null(self) {
+        debug_assert!(!self.is_null());
+    }
+}
+
+impl AssertNonNullHandle for iox2_client_h_ref {
+    fn assert_non_null(self) {
+        debug_assert!(!self.is_null());
+        unsafe {
+            debug_assert!(!(*self).is_null());
+        }
+    }
+}
+
+impl HandleToType for iox2_client_h {
+    type Target = *mut iox2_client_t;
+
+    fn as_type(self) -> Self::Target {
+        self as *mut _ as _
+    }
+}
+
+impl HandleToType for iox2_client_h_ref {
+    type Target = *mut iox2_client_t;
+
+    fn as_type(self) -> Self::Target {
+        unsafe { *self as *mut _ as _ }
+    }
+}
+
+// END type definition
+
+// BEGIN C API
+
+/// Returns a string literal describing the provided [`iox2_loan_error_e`].
+///
+/// # Arguments
+///
+/// * `error` - The error value for which a description should be returned
+///
+/// # Returns
+///
+/// A pointer to a null-terminated string containing the error message.
+/// The string is stored in the .rodata section of the binary.
+///
+/// # Safety
+///
+/// The returned pointer must not be modified or freed and is valid as long as the program runs.
+#[no_mangle]
+pub unsafe extern "C" fn iox2_loan_error_string(error: iox2_loan_error_e) -> *const c_char {
+    error.as_const_cstr().as_ptr() as *const c_char
+}
+
+/// Returns a string literal describing the provided [`iox2_request_send_error_e`].
+///
+/// # Arguments
+///
+/// * `error` - The error value for which a description should be returned
+///
+/// # Returns
+///
+/// A pointer to a null-terminated string containing the error message.
+/// The string is stored in the .rodata section of the binary.
+///
+/// # Safety
+///
+/// The returned pointer must not be modified or freed and is valid as long as the program runs.
+#[no_mangle]
+pub unsafe extern "C" fn iox2_request_send_error_string(
+    error: iox2_request_send_error_e,
+) -> *const c_char {
+    error.as_const_cstr().as_ptr() as *const c_char
+}
+
+/// Returns the maximum number of bytes a request of the client can contain.
+///
+/// # Arguments
+///
+/// * `client_handle` - Must be a valid [`iox2_client_h_ref`]
+///   obtained by [`iox2_port_factory_client_builder_create`](crate::iox2_port_factory_client_builder_create).
+///
+/// # Safety
+///
+/// * `client_handle` must be valid handles
+#[no_mangle]
+pub unsafe extern "C" fn iox2_client_initial_max_slice_len(
+    client_handle: iox2_client_h_ref,
+) -> c_size_t {
+    client_handle.assert_non_null();
+
+    let client = &mut *client_handle.as_type();
+
+    match client.service_type {
+        iox2_service_type_e::IPC => client.value.as_ref().ipc.initial_max_slice_len(),
+        iox2_service_type_e::LOCAL => client.value.as_ref().local.initial_max_slice_len(),
+    }
+}
+
+/// Loans a zero initialized request with `number_of_bytes` bytes from the client. The
+/// request is written in place and sent with [`iox2_request_mut_send`](crate::iox2_request_mut_send).
+///
+/// # Arguments
+///
+/// * `client_handle` - Must be a valid [`iox2_client_h_ref`]
+///   obtained by [`iox2_port_factory_client_builder_create`](crate::iox2_port_factory_client_builder_create).
+/// * `request_struct_ptr` - Must be either a NULL pointer or a pointer to a valid [`iox2_request_mut_t`].
+///   If it is a NULL pointer, the storage will be allocated on the heap.
+/// * `request_handle_ptr` - An uninitialized or dangling [`iox2_request_mut_h`] handle which will be initialized by this function call.
+/// * `number_of_bytes` - The size of the request payload
+///
+/// Return [`IOX2_OK`] on success, otherwise [`iox2_loan_error_e`].
+///
+/// # Safety
+///
+/// * `client_handle` is valid and non-null
+/// * `request_handle_ptr` is valid and non-null
+#[no_mangle]
+pub unsafe extern "C" fn iox2_client_loan_slice(
+    client_handle: iox2_client_h_ref,
+    request_struct_ptr: *mut iox2_request_mut_t,
+    request_handle_ptr: *mut iox2_request_mut_h,
+    number_of_bytes: c_size_t,
+) -> c_int {
+    client_handle.assert_non_null();
+    debug_assert!(!request_handle_ptr.is_null());
+
+    *request_handle_ptr = core::ptr::null_mut();
+
+    let init_request_struct_ptr = |request_struct_ptr: *mut iox2_request_mut_t| {
+        let mut request_struct_ptr = request_struct_ptr;
+        fn no_op(_: *mut iox2_request_mut_t) {}
+        let mut deleter: fn(*mut iox2_request_mut_t) = no_op;
+        if request_struct_ptr.is_null() {
+            request_struct_ptr = iox2_request_mut_t::alloc();
+            deleter = iox2_request_mut_t::dealloc;
+        }
+        debug_assert!(!request_struct_ptr.is_null());
+
+        (request_struct_ptr, deleter)
+    };
+
+    let client = &mut *client_handle.as_type();
+
+    match client.service_type {
+        iox2_service_type_e::IPC => match client.value.as_ref().ipc.loan_slice(number_of_bytes) {
+            Ok(request) => {
+                let (request_struct_ptr, deleter) = init_request_struct_ptr(request_struct_ptr);
+                (*request_struct_ptr).init(
+                    client.service_type,
+                    RequestMutUnion::new_ipc(request),
+                    deleter,
+                );
+                *request_handle_ptr = (*request_struct_ptr).as_handle();
+            }
+            Err(error) => return error.into_c_int(),
+        },
+        iox2_service_type_e::LOCAL => {
+            match client.value.as_ref().local.loan_slice(number_of_bytes) {
+                Ok(request) => {
+                    let (request_struct_ptr, deleter) = init_request_struct_ptr(request_struct_ptr);
+                    (*request_struct_ptr).init(
+                        client.service_type,
+                        RequestMutUnion::new_local(request),
+                        deleter,
+                    );
+                    *request_handle_ptr = (*request_struct_ptr).as_handle();
+                }
+                Err(error) => return error.into_c_int(),
+            }
+        }
+    }
+
+    IOX2_OK
+}
+
+/// This function needs to be called to destroy the client!
+///
+/// # Arguments
+///
+/// * `client_handle` - A valid [`iox2_client_h`]
+///
+/// # Safety
+///
+/// * The `client_handle` is invalid after the return of this function and leads to undefined behavior if used in another function call!
+/// * The corresponding [`iox2_client_t`] can be re-used with a call to
+///   [`iox2_port_factory_client_builder_create`](crate::iox2_port_factory_client_builder_create)!
+#[no_mangle]
+pub unsafe extern "C" fn iox2_client_drop(client_handle: iox2_client_h) {
+    client_handle.assert_non_null();
+
+    let client = &mut *client_handle.as_type();
+
+    match client.service_type {
+        iox2_service_type_e::IPC => {
+            ManuallyDrop::drop(&mut client.value.as_mut().ipc);
+        }
+        iox2_service_type_e::LOCAL => {
+            ManuallyDrop::drop(&mut client.value.as_mut().local);
+        }
+    }
+    (client.deleter)(client);
+}
+
+// END C API
@@ -875,6 +875,60 @@ pub unsafe extern "C" fn iox2_config_global_service_set_static_config_storage_su
     }
 }
 
+/// Returns the suffix of the attribute index file
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_service_attribute_index_suffix(
+    handle: iox2_config_h_ref,
+) -> *const c_char {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .global
+        .service
+        .attribute_index_suffix
+        .as_c_str()
+}
+
+/// Sets the suffix of the attribute index file
+///
+/// Returns: [`iox2_semantic_string_error_e`](crate::api::iox2_semantic_string_error_e) when an
+/// invalid file name was provided
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+/// * `value` - A valid file name containing the suffix
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_service_set_attribute_index_suffix(
+    handle: iox2_config_h_ref,
+    value: *const c_char,
+) -> c_int {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    match FileName::from_c_str(value) {
+        Ok(n) => {
+            config
+                .value
+                .as_mut()
+                .value
+                .global
+                .service
+                .attribute_index_suffix = n;
+            IOX2_OK as _
+        }
+        Err(e) => e as c_int,
+    }
+}
+
 /// Returns the suffix of the dynamic config file
 ///
 /// # Safety
@@ -1076,6 +1130,141 @@ pub unsafe extern "C" fn iox2_config_global_service_set_event_connection_suffix(
         Err(e) => e as c_int,
     }
 }
+
+/// Returns the suffix of a one-to-many connection of a service with broadcasting enabled
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_service_broadcast_suffix(
+    handle: iox2_config_h_ref,
+) -> *const c_char {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .global
+        .service
+        .broadcast_suffix
+        .as_c_str()
+}
+
+/// Sets the suffix of a one-to-many connection of a service with broadcasting enabled
+///
+/// Returns: [`iox2_semantic_string_error_e`](crate::api::iox2_semantic_string_error_e) when an
+/// invalid file name was provided
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+/// * `value` - A valid file name containing the suffix
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_service_set_broadcast_suffix(
+    handle: iox2_config_h_ref,
+    value: *const c_char,
+) -> c_int {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    match FileName::from_c_str(value) {
+        Ok(n) => {
+            config.value.as_mut().value.global.service.broadcast_suffix = n;
+            IOX2_OK as _
+        }
+        Err(e) => e as c_int,
+    }
+}
+
+/// When true, a service can only be opened when its static config was signed with one of the
+/// trusted keys.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_service_verify_service_signatures(
+    handle: iox2_config_h_ref,
+) -> bool {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .global
+        .service
+        .verify_service_signatures
+}
+
+/// Enable/disable the verification of the service signatures
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_service_set_verify_service_signatures(
+    handle: iox2_config_h_ref,
+    value: bool,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config
+        .value
+        .as_mut()
+        .value
+        .global
+        .service
+        .verify_service_signatures = value;
+}
+
+/// When true, the payload of the data segments is mapped read-only by the receiving ports.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_service_read_only_payload_mapping(
+    handle: iox2_config_h_ref,
+) -> bool {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .global
+        .service
+        .read_only_payload_mapping
+}
+
+/// Enable/disable the read-only mapping of the payload on the receiving side
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_service_set_read_only_payload_mapping(
+    handle: iox2_config_h_ref,
+    value: bool,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config
+        .value
+        .as_mut()
+        .value
+        .global
+        .service
+        .read_only_payload_mapping = value;
+}
 /////////////////
 // END: service
 /////////////////
@@ -1900,6 +2089,37 @@ pub unsafe extern "C" fn iox2_config_defaults_event_set_event_id_max_value(
         .event
         .event_id_max_value = value;
 }
+
+/// Returns the maximum size in bytes of the payload that can be attached to a notification
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_event_max_payload_size(
+    handle: iox2_config_h_ref,
+) -> c_size_t {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config.value.as_ref().value.defaults.event.max_payload_size
+}
+
+/// Sets the maximum size in bytes of the payload that can be attached to a notification
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_event_set_max_payload_size(
+    handle: iox2_config_h_ref,
+    value: c_size_t,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config.value.as_mut().value.defaults.event.max_payload_size = value;
+}
 //////////////////////////
 // END: event
 //////////////////////////
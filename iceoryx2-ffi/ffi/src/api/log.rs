@@ -16,8 +16,9 @@
 // BEGIN type definition
 
 use iceoryx2_bb_log::{
-    get_log_level, set_log_level, set_logger, Log, LogLevel, __internal_print_log_msg,
+    __internal_print_log_msg, get_log_level,
     logger::{use_console_logger, use_file_logger},
+    set_log_level, set_logger, Log, LogLevel,
 };
 
 use core::ffi::{c_char, CStr};
@@ -19,10 +19,12 @@ use iceoryx2_ffi_macros::CStrRepr;
 
 use core::ffi::{c_char, c_int, c_void};
 
+mod active_request;
 mod attribute;
 mod attribute_set;
 mod attribute_specifier;
 mod attribute_verifier;
+mod client;
 mod config;
 mod event_id;
 mod file_descriptor;
@@ -35,21 +37,30 @@ mod node_builder;
 mod node_id;
 mod node_name;
 mod notifier;
+mod pending_response;
+mod port_factory_client_builder;
 mod port_factory_event;
 mod port_factory_listener_builder;
 mod port_factory_notifier_builder;
 mod port_factory_pub_sub;
 mod port_factory_publisher_builder;
+mod port_factory_request_response;
+mod port_factory_server_builder;
 mod port_factory_subscriber_builder;
 mod publish_subscribe_header;
 mod publisher;
 mod quirks_correction;
+mod request_mut;
+mod response;
+mod response_mut;
 mod sample;
 mod sample_mut;
+mod server;
 mod service;
 mod service_builder;
 mod service_builder_event;
 mod service_builder_pub_sub;
+mod service_builder_request_response;
 mod service_name;
 mod signal_handling_mode;
 mod static_config;
@@ -65,10 +76,12 @@ mod waitset_attachment_id;
 mod waitset_builder;
 mod waitset_guard;
 
+pub use active_request::*;
 pub use attribute::*;
 pub use attribute_set::*;
 pub use attribute_specifier::*;
 pub use attribute_verifier::*;
+pub use client::*;
 pub use config::*;
 pub use event_id::*;
 pub use file_descriptor::*;
@@ -80,21 +93,30 @@ pub use node_builder::*;
 pub use node_id::*;
 pub use node_name::*;
 pub use notifier::*;
+pub use pending_response::*;
+pub use port_factory_client_builder::*;
 pub use port_factory_event::*;
 pub use port_factory_listener_builder::*;
 pub use port_factory_notifier_builder::*;
 pub use port_factory_pub_sub::*;
 pub use port_factory_publisher_builder::*;
+pub use port_factory_request_response::*;
+pub use port_factory_server_builder::*;
 pub use port_factory_subscriber_builder::*;
 pub use publish_subscribe_header::*;
 pub use publisher::*;
 pub use quirks_correction::*;
+pub use request_mut::*;
+pub use response::*;
+pub use response_mut::*;
 pub use sample::*;
 pub use sample_mut::*;
+pub use server::*;
 pub use service::*;
 pub use service_builder::*;
 pub use service_builder_event::*;
 pub use service_builder_pub_sub::*;
+pub use service_builder_request_response::*;
 pub use service_name::*;
 pub use signal_handling_mode::*;
 pub use static_config::*;
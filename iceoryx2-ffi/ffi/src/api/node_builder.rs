@@ -36,6 +36,8 @@ use super::iox2_signal_handling_mode_e;
 pub enum iox2_node_creation_failure_e {
     INSUFFICIENT_PERMISSIONS = IOX2_OK as isize + 1,
     INTERNAL_ERROR,
+    NAMESPACE_CONFLICT,
+    INVALID_CONFIG,
 }
 
 impl IntoCInt for NodeCreationFailure {
@@ -45,6 +47,10 @@ impl IntoCInt for NodeCreationFailure {
                 iox2_node_creation_failure_e::INSUFFICIENT_PERMISSIONS
             }
             NodeCreationFailure::InternalError => iox2_node_creation_failure_e::INTERNAL_ERROR,
+            NodeCreationFailure::NamespaceConflict => {
+                iox2_node_creation_failure_e::NAMESPACE_CONFLICT
+            }
+            NodeCreationFailure::InvalidConfig => iox2_node_creation_failure_e::INVALID_CONFIG,
         }) as c_int
     }
 }
@@ -52,7 +58,7 @@ impl IntoCInt for NodeCreationFailure {
 #[repr(C)]
 #[repr(align(8))] // alignment of Option<NodeBuilder>
 pub struct iox2_node_builder_storage_t {
-    internal: [u8; 18696], // magic number obtained with size_of::<NodeBuilder>()
+    internal: [u8; 18728], // magic number obtained with size_of::<NodeBuilder>()
 }
 
 #[repr(C)]
@@ -35,6 +35,7 @@ pub enum iox2_notifier_notify_error_e {
     EVENT_ID_OUT_OF_BOUNDS = IOX2_OK as isize + 1,
     MISSED_DEADLINE,
     UNABLE_TO_ACQUIRE_ELAPSED_TIME,
+    EXCEEDS_MAX_PAYLOAD_SIZE,
 }
 
 impl IntoCInt for NotifierNotifyError {
@@ -47,6 +48,9 @@ impl IntoCInt for NotifierNotifyError {
             NotifierNotifyError::UnableToAcquireElapsedTime => {
                 iox2_notifier_notify_error_e::UNABLE_TO_ACQUIRE_ELAPSED_TIME
             }
+            NotifierNotifyError::ExceedsMaxPayloadSize => {
+                iox2_notifier_notify_error_e::EXCEEDS_MAX_PAYLOAD_SIZE
+            }
         }) as c_int
     }
 }
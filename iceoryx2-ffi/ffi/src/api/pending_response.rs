@@ -0,0 +1,331 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types)]
+
+use crate::api::{
+    c_size_t, iox2_response_h, iox2_response_t, iox2_service_type_e, AssertNonNullHandle,
+    HandleToType, IntoCInt, RequestPayloadFfi, ResponsePayloadFfi, ResponseUnion, IOX2_OK,
+};
+
+use iceoryx2::pending_response::PendingResponse;
+use iceoryx2::port::client::ResponseReceiveError;
+use iceoryx2::port::update_connections::ConnectionFailure;
+use iceoryx2::prelude::*;
+use iceoryx2_bb_elementary::static_assert::*;
+use iceoryx2_bb_elementary::AsCStr;
+use iceoryx2_ffi_macros::iceoryx2_ffi;
+use iceoryx2_ffi_macros::CStrRepr;
+
+use core::ffi::{c_char, c_int};
+use core::mem::ManuallyDrop;
+
+// BEGIN types definition
+
+#[repr(C)]
+#[derive(Copy, Clone, CStrRepr)]
+pub enum iox2_response_receive_error_e {
+    EXCEEDS_MAX_BORROWED_RESPONSES = IOX2_OK as isize + 1,
+    FAILED_TO_ESTABLISH_CONNECTION,
+    UNABLE_TO_MAP_SERVERS_DATA_SEGMENT,
+    TIMEOUT,
+    INCOMPATIBLE_ERROR_TYPE,
+    RECEIVED_ERROR_RESPONSE,
+}
+
+impl IntoCInt for ResponseReceiveError {
+    fn into_c_int(self) -> c_int {
+        (match self {
+            ResponseReceiveError::ExceedsMaxBorrowedResponses => {
+                iox2_response_receive_error_e::EXCEEDS_MAX_BORROWED_RESPONSES
+            }
+            ResponseReceiveError::ConnectionFailure(
+                ConnectionFailure::FailedToEstablishConnection(_),
+            ) => iox2_response_receive_error_e::FAILED_TO_ESTABLISH_CONNECTION,
+            ResponseReceiveError::ConnectionFailure(
+                ConnectionFailure::UnableToMapPublishersDataSegment(_),
+            ) => iox2_response_receive_error_e::UNABLE_TO_MAP_SERVERS_DATA_SEGMENT,
+            ResponseReceiveError::Timeout => iox2_response_receive_error_e::TIMEOUT,
+            ResponseReceiveError::IncompatibleErrorType => {
+                iox2_response_receive_error_e::INCOMPATIBLE_ERROR_TYPE
+            }
+            ResponseReceiveError::ReceivedErrorResponse => {
+                iox2_response_receive_error_e::RECEIVED_ERROR_RESPONSE
+            }
+        }) as c_int
+    }
+}
+
+pub(super) union PendingResponseUnion {
+    ipc: ManuallyDrop<PendingResponse<ipc::Service, RequestPayloadFfi, (), ResponsePayloadFfi, ()>>,
+    local: ManuallyDrop<
+        PendingResponse<local::Service, RequestPayloadFfi, (), ResponsePayloadFfi, ()>,
+    >,
+}
+
+impl PendingResponseUnion {
+    pub(super) fn new_ipc(
+        pending_response: PendingResponse<
+            ipc::Service,
+            RequestPayloadFfi,
+            (),
+            ResponsePayloadFfi,
+            (),
+        >,
+    ) -> Self {
+        Self {
+            ipc: ManuallyDrop::new(pending_response),
+        }
+    }
+    pub(super) fn new_local(
+        pending_response: PendingResponse<
+            local::Service,
+            RequestPayloadFfi,
+            (),
+            ResponsePayloadFfi,
+            (),
+        >,
+    ) -> Self {
+        Self {
+            local: ManuallyDrop::new(pending_response),
+        }
+    }
+}
+
+#[repr(C)]
+#[repr(align(8))] // alignment of Option<PendingResponseUnion>
+pub struct iox2_pending_response_storage_t {
+    internal: [u8; 48], // magic number obtained with size_of::<Option<PendingResponseUnion>>()
+}
+
+#[repr(C)]
+#[iceoryx2_ffi(PendingResponseUnion)]
+pub struct iox2_pending_response_t {
+    service_type: iox2_service_type_e,
+    value: iox2_pending_response_storage_t,
+    deleter: fn(*mut iox2_pending_response_t),
+}
+
+impl iox2_pending_response_t {
+    pub(super) fn init(
+        &mut self,
+        service_type: iox2_service_type_e,
+        value: PendingResponseUnion,
+        deleter: fn(*mut iox2_pending_response_t),
+    ) {
+        self.service_type = service_type;
+        self.value.init(value);
+        self.deleter = deleter;
+    }
+}
+
+pub struct iox2_pending_response_h_t;
+/// The owning handle for `iox2_pending_response_t`. Passing the handle to an function transfers the ownership.
+pub type iox2_pending_response_h = *mut iox2_pending_response_h_t;
+/// The non-owning handle for `iox2_pending_response_t`. Passing the handle to an function does not transfers the ownership.
+pub type iox2_pending_response_h_ref = *const iox2_pending_response_h;
+
+impl AssertNonNullHandle for iox2_pending_response_h {
+    fn assert_non_null(self) {
+        debug_assert!(!self.is_null());
+    }
+}
+
+impl AssertNonNullHandle for iox2_pending_response_h_ref {
+    fn assert_non_null(self) {
+        debug_assert!(!self.is_null());
+        unsafe {
+            debug_assert!(!(*self).is_null());
+        }
+    }
+}
+
+impl HandleToType for iox2_pending_response_h {
+    type Target = *mut iox2_pending_response_t;
+
+    fn as_type(self) -> Self::Target {
+        self as *mut _ as _
+    }
+}
+
+impl HandleToType for iox2_pending_response_h_ref {
+    type Target = *mut iox2_pending_response_t;
+
+    fn as_type(self) -> Self::Target {
+        unsafe { *self as *mut _ as _ }
+    }
+}
+
+// END type definition
+
+// BEGIN C API
+
+/// Returns a string literal describing the provided [`iox2_response_receive_error_e`].
+///
+/// # Arguments
+///
+/// * `error` - The error value for which a description should be returned
+///
+/// # Returns
+///
+/// A pointer to a null-terminated string containing the error message.
+/// The string is stored in the .rodata section of the binary.
+///
+/// # Safety
+///
+/// The returned pointer must not be modified or freed and is valid as long as the program runs.
+#[no_mangle]
+pub unsafe extern "C" fn iox2_response_receive_error_string(
+    error: iox2_response_receive_error_e,
+) -> *const c_char {
+    error.as_const_cstr().as_ptr() as *const c_char
+}
+
+/// Returns the number of servers that received the request.
+///
+/// # Arguments
+///
+/// * `pending_response_handle` - Must be a valid [`iox2_pending_response_h_ref`]
+///   obtained by [`iox2_request_mut_send`](crate::iox2_request_mut_send).
+///
+/// # Safety
+///
+/// * `pending_response_handle` must be valid handles
+#[no_mangle]
+pub unsafe extern "C" fn iox2_pending_response_number_of_server_connections(
+    pending_response_handle: iox2_pending_response_h_ref,
+) -> c_size_t {
+    pending_response_handle.assert_non_null();
+
+    let pending_response = &mut *pending_response_handle.as_type();
+
+    match pending_response.service_type {
+        iox2_service_type_e::IPC => pending_response
+            .value
+            .as_ref()
+            .ipc
+            .number_of_server_connections(),
+        iox2_service_type_e::LOCAL => pending_response
+            .value
+            .as_ref()
+            .local
+            .number_of_server_connections(),
+    }
+}
+
+/// Takes the next response that a server sent for the request.
+///
+/// # Arguments
+///
+/// * `pending_response_handle` - Must be a valid [`iox2_pending_response_h_ref`]
+///   obtained by [`iox2_request_mut_send`](crate::iox2_request_mut_send).
+/// * `response_struct_ptr` - Must be either a NULL pointer or a pointer to a valid [`iox2_response_t`].
+///   If it is a NULL pointer, the storage will be allocated on the heap.
+/// * `response_handle_ptr` - An uninitialized or dangling [`iox2_response_h`] handle which will be initialized by this function call if a response is obtained, otherwise it will be set to NULL.
+///
+/// Returns IOX2_OK on success, an [`iox2_response_receive_error_e`] otherwise.
+/// Attention, no available response is not an error and even with IOX2_OK it is possible to get a NULL in `response_handle_ptr`.
+///
+/// # Safety
+///
+/// * The `pending_response_handle` is still valid after the return of this function and can be use in another function call.
+/// * The `response_handle_ptr` is pointing to a valid [`iox2_response_h`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_pending_response_receive(
+    pending_response_handle: iox2_pending_response_h_ref,
+    response_struct_ptr: *mut iox2_response_t,
+    response_handle_ptr: *mut iox2_response_h,
+) -> c_int {
+    pending_response_handle.assert_non_null();
+    debug_assert!(!response_handle_ptr.is_null());
+
+    *response_handle_ptr = core::ptr::null_mut();
+
+    let init_response_struct_ptr = |response_struct_ptr: *mut iox2_response_t| {
+        let mut response_struct_ptr = response_struct_ptr;
+        fn no_op(_: *mut iox2_response_t) {}
+        let mut deleter: fn(*mut iox2_response_t) = no_op;
+        if response_struct_ptr.is_null() {
+            response_struct_ptr = iox2_response_t::alloc();
+            deleter = iox2_response_t::dealloc;
+        }
+        debug_assert!(!response_struct_ptr.is_null());
+
+        (response_struct_ptr, deleter)
+    };
+
+    let pending_response = &mut *pending_response_handle.as_type();
+
+    match pending_response.service_type {
+        iox2_service_type_e::IPC => match pending_response.value.as_ref().ipc.receive() {
+            Ok(Some(response)) => {
+                let (response_struct_ptr, deleter) = init_response_struct_ptr(response_struct_ptr);
+                (*response_struct_ptr).init(
+                    pending_response.service_type,
+                    ResponseUnion::new_ipc(response),
+                    deleter,
+                );
+                *response_handle_ptr = (*response_struct_ptr).as_handle();
+            }
+            Ok(None) => (),
+            Err(error) => return error.into_c_int(),
+        },
+        iox2_service_type_e::LOCAL => match pending_response.value.as_ref().local.receive() {
+            Ok(Some(response)) => {
+                let (response_struct_ptr, deleter) = init_response_struct_ptr(response_struct_ptr);
+                (*response_struct_ptr).init(
+                    pending_response.service_type,
+                    ResponseUnion::new_local(response),
+                    deleter,
+                );
+                *response_handle_ptr = (*response_struct_ptr).as_handle();
+            }
+            Ok(None) => (),
+            Err(error) => return error.into_c_int(),
+        },
+    }
+
+    IOX2_OK
+}
+
+/// This function needs to be called to destroy the pending response! Afterwards, the request
+/// is no longer active and all further responses are discarded.
+///
+/// # Arguments
+///
+/// * `pending_response_handle` - A valid [`iox2_pending_response_h`]
+///
+/// # Safety
+///
+/// * The `pending_response_handle` is invalid after the return of this function and leads to undefined behavior if used in another function call!
+/// * The corresponding [`iox2_pending_response_t`] can be re-used with a call to
+///   [`iox2_request_mut_send`](crate::iox2_request_mut_send)!
+#[no_mangle]
+pub unsafe extern "C" fn iox2_pending_response_drop(
+    pending_response_handle: iox2_pending_response_h,
+) {
+    debug_assert!(!pending_response_handle.is_null());
+
+    let pending_response = &mut *pending_response_handle.as_type();
+
+    match pending_response.service_type {
+        iox2_service_type_e::IPC => {
+            ManuallyDrop::drop(&mut pending_response.value.as_mut().ipc);
+        }
+        iox2_service_type_e::LOCAL => {
+            ManuallyDrop::drop(&mut pending_response.value.as_mut().local);
+        }
+    }
+    (pending_response.deleter)(pending_response);
+}
+
+// END C API
@@ -35,6 +35,7 @@ use core::mem::ManuallyDrop;
 pub enum iox2_listener_create_error_e {
     EXCEEDS_MAX_SUPPORTED_LISTENERS = IOX2_OK as isize + 1,
     RESOURCE_CREATION_FAILED,
+    EXCEEDS_RESOURCE_BUDGET_OF_NODE,
 }
 
 impl IntoCInt for ListenerCreateError {
@@ -46,6 +47,9 @@ impl IntoCInt for ListenerCreateError {
             ListenerCreateError::ResourceCreationFailed => {
                 iox2_listener_create_error_e::RESOURCE_CREATION_FAILED
             }
+            ListenerCreateError::ExceedsResourceBudgetOfNode => {
+                iox2_listener_create_error_e::EXCEEDS_RESOURCE_BUDGET_OF_NODE
+            }
         }) as c_int
     }
 }
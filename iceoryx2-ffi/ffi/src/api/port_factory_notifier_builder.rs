@@ -34,6 +34,7 @@ use core::mem::ManuallyDrop;
 #[derive(Copy, Clone, CStrRepr)]
 pub enum iox2_notifier_create_error_e {
     EXCEEDS_MAX_SUPPORTED_NOTIFIERS = IOX2_OK as isize + 1,
+    EXCEEDS_RESOURCE_BUDGET_OF_NODE,
 }
 
 impl IntoCInt for NotifierCreateError {
@@ -42,6 +43,9 @@ impl IntoCInt for NotifierCreateError {
             NotifierCreateError::ExceedsMaxSupportedNotifiers => {
                 iox2_notifier_create_error_e::EXCEEDS_MAX_SUPPORTED_NOTIFIERS
             }
+            NotifierCreateError::ExceedsResourceBudgetOfNode => {
+                iox2_notifier_create_error_e::EXCEEDS_RESOURCE_BUDGET_OF_NODE
+            }
         }) as c_int
     }
 }
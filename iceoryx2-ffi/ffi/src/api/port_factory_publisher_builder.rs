@@ -60,6 +60,10 @@ impl From<iox2_allocation_strategy_e> for AllocationStrategy {
 pub enum iox2_publisher_create_error_e {
     EXCEEDS_MAX_SUPPORTED_PUBLISHERS = IOX2_OK as isize + 1,
     UNABLE_TO_CREATE_DATA_SEGMENT,
+    EXCEEDS_RESOURCE_BUDGET_OF_NODE,
+    UNABLE_TO_CREATE_PERSISTENT_HISTORY,
+    VIOLATES_DETERMINISTIC_PROFILE,
+    UNABLE_TO_CREATE_RECLAIM_EVENT,
 }
 
 impl IntoCInt for PublisherCreateError {
@@ -71,6 +75,18 @@ impl IntoCInt for PublisherCreateError {
             PublisherCreateError::UnableToCreateDataSegment => {
                 iox2_publisher_create_error_e::UNABLE_TO_CREATE_DATA_SEGMENT
             }
+            PublisherCreateError::ExceedsResourceBudgetOfNode => {
+                iox2_publisher_create_error_e::EXCEEDS_RESOURCE_BUDGET_OF_NODE
+            }
+            PublisherCreateError::UnableToCreatePersistentHistory => {
+                iox2_publisher_create_error_e::UNABLE_TO_CREATE_PERSISTENT_HISTORY
+            }
+            PublisherCreateError::ViolatesDeterministicProfile => {
+                iox2_publisher_create_error_e::VIOLATES_DETERMINISTIC_PROFILE
+            }
+            PublisherCreateError::UnableToCreateReclaimEvent => {
+                iox2_publisher_create_error_e::UNABLE_TO_CREATE_RECLAIM_EVENT
+            }
         }) as c_int
     }
 }
@@ -102,6 +118,7 @@ impl PortFactoryPublisherBuilderUnion {
 pub enum iox2_unable_to_deliver_strategy_e {
     BLOCK,
     DISCARD_SAMPLE,
+    DISCARD_OLDEST,
 }
 
 impl From<iox2_unable_to_deliver_strategy_e> for UnableToDeliverStrategy {
@@ -111,6 +128,9 @@ impl From<iox2_unable_to_deliver_strategy_e> for UnableToDeliverStrategy {
             iox2_unable_to_deliver_strategy_e::DISCARD_SAMPLE => {
                 UnableToDeliverStrategy::DiscardSample
             }
+            iox2_unable_to_deliver_strategy_e::DISCARD_OLDEST => {
+                UnableToDeliverStrategy::DiscardOldest
+            }
         }
     }
 }
@@ -118,10 +138,16 @@ impl From<iox2_unable_to_deliver_strategy_e> for UnableToDeliverStrategy {
 impl From<UnableToDeliverStrategy> for iox2_unable_to_deliver_strategy_e {
     fn from(value: UnableToDeliverStrategy) -> Self {
         match value {
-            UnableToDeliverStrategy::Block => iox2_unable_to_deliver_strategy_e::BLOCK,
+            // the C API does not support a timeout yet, therefore it is reported as blocking
+            UnableToDeliverStrategy::Block | UnableToDeliverStrategy::BlockWithTimeout(_) => {
+                iox2_unable_to_deliver_strategy_e::BLOCK
+            }
             UnableToDeliverStrategy::DiscardSample => {
                 iox2_unable_to_deliver_strategy_e::DISCARD_SAMPLE
             }
+            UnableToDeliverStrategy::DiscardOldest => {
+                iox2_unable_to_deliver_strategy_e::DISCARD_OLDEST
+            }
         }
     }
 }
@@ -0,0 +1,287 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types)]
+
+use crate::api::{
+    iox2_port_factory_client_builder_h, iox2_port_factory_client_builder_t,
+    iox2_port_factory_server_builder_h, iox2_port_factory_server_builder_t, iox2_service_type_e,
+    AssertNonNullHandle, HandleToType, PortFactoryClientBuilderUnion,
+    PortFactoryServerBuilderUnion, RequestPayloadFfi, ResponsePayloadFfi,
+};
+
+use iceoryx2::prelude::*;
+use iceoryx2::service::port_factory::request_response::PortFactory;
+use iceoryx2_bb_elementary::static_assert::*;
+use iceoryx2_ffi_macros::iceoryx2_ffi;
+
+use core::mem::ManuallyDrop;
+
+use super::iox2_attribute_set_h_ref;
+
+// BEGIN types definition
+
+pub(super) union PortFactoryRequestResponseUnion {
+    ipc: ManuallyDrop<PortFactory<ipc::Service, RequestPayloadFfi, (), ResponsePayloadFfi, ()>>,
+    local: ManuallyDrop<PortFactory<local::Service, RequestPayloadFfi, (), ResponsePayloadFfi, ()>>,
+}
+
+impl PortFactoryRequestResponseUnion {
+    pub(super) fn new_ipc(
+        port_factory: PortFactory<ipc::Service, RequestPayloadFfi, (), ResponsePayloadFfi, ()>,
+    ) -> Self {
+        Self {
+            ipc: ManuallyDrop::new(port_factory),
+        }
+    }
+    pub(super) fn new_local(
+        port_factory: PortFactory<local::Service, RequestPayloadFfi, (), ResponsePayloadFfi, ()>,
+    ) -> Self {
+        Self {
+            local: ManuallyDrop::new(port_factory),
+        }
+    }
+}
+
+#[repr(C)]
+#[repr(align(8))] // alignment of Option<PortFactoryRequestResponseUnion>
+pub struct iox2_port_factory_request_response_storage_t {
+    internal: [u8; 1656], // magic number obtained with size_of::<Option<PortFactoryRequestResponseUnion>>()
+}
+
+#[repr(C)]
+#[iceoryx2_ffi(PortFactoryRequestResponseUnion)]
+pub struct iox2_port_factory_request_response_t {
+    service_type: iox2_service_type_e,
+    value: iox2_port_factory_request_response_storage_t,
+    deleter: fn(*mut iox2_port_factory_request_response_t),
+}
+
+impl iox2_port_factory_request_response_t {
+    pub(super) fn init(
+        &mut self,
+        service_type: iox2_service_type_e,
+        value: PortFactoryRequestResponseUnion,
+        deleter: fn(*mut iox2_port_factory_request_response_t),
+    ) {
+        self.service_type = service_type;
+        self.value.init(value);
+        self.deleter = deleter;
+    }
+}
+
+pub struct iox2_port_factory_request_response_h_t;
+/// The owning handle for `iox2_port_factory_request_response_t`. Passing the handle to an function transfers the ownership.
+pub type iox2_port_factory_request_response_h = *mut iox2_port_factory_request_response_h_t;
+/// The non-owning handle for `iox2_port_factory_request_response_t`. Passing the handle to an function does not transfers the ownership.
+pub type iox2_port_factory_request_response_h_ref = *const iox2_port_factory_request_response_h;
+
+impl AssertNonNullHandle for iox2_port_factory_request_response_h {
+    fn assert_non_null(self) {
+        debug_assert!(!self.is_null());
+    }
+}
+
+impl AssertNonNullHandle for iox2_port_factory_request_response_h_ref {
+    fn assert_non_null(self) {
+        debug_assert!(!self.is_null());
+        unsafe {
+            debug_assert!(!(*self).is_null());
+        }
+    }
+}
+
+impl HandleToType for iox2_port_factory_request_response_h {
+    type Target = *mut iox2_port_factory_request_response_t;
+
+    fn as_type(self) -> Self::Target {
+        self as *mut _ as _
+    }
+}
+
+impl HandleToType for iox2_port_factory_request_response_h_ref {
+    type Target = *mut iox2_port_factory_request_response_t;
+
+    fn as_type(self) -> Self::Target {
+        unsafe { *self as *mut _ as _ }
+    }
+}
+
+// END type definition
+
+// BEGIN C API
+
+/// Instantiates a [`iox2_port_factory_client_builder_h`] to build a client.
+///
+/// # Arguments
+///
+/// * `port_factory_handle` - Must be a valid [`iox2_port_factory_request_response_h_ref`] obtained
+///   by e.g. [`iox2_service_builder_request_response_open_or_create`](crate::iox2_service_builder_request_response_open_or_create).
+/// * `client_builder_struct_ptr` - Must be either a NULL pointer or a pointer to a valid [`iox2_port_factory_client_builder_t`].
+///   If it is a NULL pointer, the storage will be allocated on the heap.
+///
+/// Returns the [`iox2_port_factory_client_builder_h`] handle for the client builder.
+///
+/// # Safety
+///
+/// * The `port_factory_handle` is still valid after the return of this function and can be use in another function call.
+/// * The `port_factory_handle` must live longer than the returned [`iox2_port_factory_client_builder_h`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_port_factory_request_response_client_builder(
+    port_factory_handle: iox2_port_factory_request_response_h_ref,
+    client_builder_struct_ptr: *mut iox2_port_factory_client_builder_t,
+) -> iox2_port_factory_client_builder_h {
+    port_factory_handle.assert_non_null();
+
+    let mut client_builder_struct_ptr = client_builder_struct_ptr;
+    fn no_op(_: *mut iox2_port_factory_client_builder_t) {}
+    let mut deleter: fn(*mut iox2_port_factory_client_builder_t) = no_op;
+    if client_builder_struct_ptr.is_null() {
+        client_builder_struct_ptr = iox2_port_factory_client_builder_t::alloc();
+        deleter = iox2_port_factory_client_builder_t::dealloc;
+    }
+    debug_assert!(!client_builder_struct_ptr.is_null());
+
+    let port_factory = &mut *port_factory_handle.as_type();
+    match port_factory.service_type {
+        iox2_service_type_e::IPC => {
+            let client_builder = port_factory.value.as_ref().ipc.client_builder();
+            (*client_builder_struct_ptr).init(
+                port_factory.service_type,
+                PortFactoryClientBuilderUnion::new_ipc(client_builder),
+                deleter,
+            );
+        }
+        iox2_service_type_e::LOCAL => {
+            let client_builder = port_factory.value.as_ref().local.client_builder();
+            (*client_builder_struct_ptr).init(
+                port_factory.service_type,
+                PortFactoryClientBuilderUnion::new_local(client_builder),
+                deleter,
+            );
+        }
+    };
+
+    (*client_builder_struct_ptr).as_handle()
+}
+
+/// Instantiates a [`iox2_port_factory_server_builder_h`] to build a server.
+///
+/// # Arguments
+///
+/// * `port_factory_handle` - Must be a valid [`iox2_port_factory_request_response_h_ref`] obtained
+///   by e.g. [`iox2_service_builder_request_response_open_or_create`](crate::iox2_service_builder_request_response_open_or_create).
+/// * `server_builder_struct_ptr` - Must be either a NULL pointer or a pointer to a valid [`iox2_port_factory_server_builder_t`].
+///   If it is a NULL pointer, the storage will be allocated on the heap.
+///
+/// Returns the [`iox2_port_factory_server_builder_h`] handle for the server builder.
+///
+/// # Safety
+///
+/// * The `port_factory_handle` is still valid after the return of this function and can be use in another function call.
+/// * The `port_factory_handle` must live longer than the returned [`iox2_port_factory_server_builder_h`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_port_factory_request_response_server_builder(
+    port_factory_handle: iox2_port_factory_request_response_h_ref,
+    server_builder_struct_ptr: *mut iox2_port_factory_server_builder_t,
+) -> iox2_port_factory_server_builder_h {
+    port_factory_handle.assert_non_null();
+
+    let mut server_builder_struct_ptr = server_builder_struct_ptr;
+    fn no_op(_: *mut iox2_port_factory_server_builder_t) {}
+    let mut deleter: fn(*mut iox2_port_factory_server_builder_t) = no_op;
+    if server_builder_struct_ptr.is_null() {
+        server_builder_struct_ptr = iox2_port_factory_server_builder_t::alloc();
+        deleter = iox2_port_factory_server_builder_t::dealloc;
+    }
+    debug_assert!(!server_builder_struct_ptr.is_null());
+
+    let port_factory = &mut *port_factory_handle.as_type();
+    match port_factory.service_type {
+        iox2_service_type_e::IPC => {
+            let server_builder = port_factory.value.as_ref().ipc.server_builder();
+            (*server_builder_struct_ptr).init(
+                port_factory.service_type,
+                PortFactoryServerBuilderUnion::new_ipc(server_builder),
+                deleter,
+            );
+        }
+        iox2_service_type_e::LOCAL => {
+            let server_builder = port_factory.value.as_ref().local.server_builder();
+            (*server_builder_struct_ptr).init(
+                port_factory.service_type,
+                PortFactoryServerBuilderUnion::new_local(server_builder),
+                deleter,
+            );
+        }
+    };
+
+    (*server_builder_struct_ptr).as_handle()
+}
+
+/// Returnes the services attributes.
+///
+/// # Safety
+///
+/// * The `port_factory_handle` must be valid.
+/// * The `port_factory_handle` must live longer than the returned `iox2_attribute_set_h_ref`.
+#[no_mangle]
+pub unsafe extern "C" fn iox2_port_factory_request_response_attributes(
+    port_factory_handle: iox2_port_factory_request_response_h_ref,
+) -> iox2_attribute_set_h_ref {
+    use iceoryx2::prelude::PortFactory;
+
+    port_factory_handle.assert_non_null();
+
+    let port_factory = &mut *port_factory_handle.as_type();
+    match port_factory.service_type {
+        iox2_service_type_e::IPC => {
+            (port_factory.value.as_ref().ipc.attributes() as *const AttributeSet).cast()
+        }
+        iox2_service_type_e::LOCAL => {
+            (port_factory.value.as_ref().local.attributes() as *const AttributeSet).cast()
+        }
+    }
+}
+
+/// This function needs to be called to destroy the port factory!
+///
+/// # Arguments
+///
+/// * `port_factory_handle` - A valid [`iox2_port_factory_request_response_h`]
+///
+/// # Safety
+///
+/// * The `port_factory_handle` is invalid after the return of this function and leads to undefined behavior if used in another function call!
+/// * The corresponding [`iox2_port_factory_request_response_t`] can be re-used with a call to
+///   [`iox2_service_builder_request_response_open_or_create`](crate::iox2_service_builder_request_response_open_or_create) or
+///   [`iox2_service_builder_request_response_open`](crate::iox2_service_builder_request_response_open)!
+#[no_mangle]
+pub unsafe extern "C" fn iox2_port_factory_request_response_drop(
+    port_factory_handle: iox2_port_factory_request_response_h,
+) {
+    debug_assert!(!port_factory_handle.is_null());
+
+    let port_factory = &mut *port_factory_handle.as_type();
+
+    match port_factory.service_type {
+        iox2_service_type_e::IPC => {
+            ManuallyDrop::drop(&mut port_factory.value.as_mut().ipc);
+        }
+        iox2_service_type_e::LOCAL => {
+            ManuallyDrop::drop(&mut port_factory.value.as_mut().local);
+        }
+    }
+    (port_factory.deleter)(port_factory);
+}
+
+// END C API
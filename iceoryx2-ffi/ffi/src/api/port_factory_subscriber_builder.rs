@@ -35,6 +35,9 @@ use core::mem::ManuallyDrop;
 pub enum iox2_subscriber_create_error_e {
     EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS = IOX2_OK as isize + 1,
     BUFFER_SIZE_EXCEEDS_MAX_SUPPORTED_BUFFER_SIZE_OF_SERVICE,
+    EXCEEDS_RESOURCE_BUDGET_OF_NODE,
+    VIOLATES_DETERMINISTIC_PROFILE,
+    IN_PLACE_UPDATES_REQUIRE_WRITABLE_PAYLOAD,
 }
 
 impl IntoCInt for SubscriberCreateError {
@@ -46,6 +49,15 @@ impl IntoCInt for SubscriberCreateError {
             SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService => {
                 iox2_subscriber_create_error_e::BUFFER_SIZE_EXCEEDS_MAX_SUPPORTED_BUFFER_SIZE_OF_SERVICE
             }
+            SubscriberCreateError::ExceedsResourceBudgetOfNode => {
+                iox2_subscriber_create_error_e::EXCEEDS_RESOURCE_BUDGET_OF_NODE
+            }
+            SubscriberCreateError::ViolatesDeterministicProfile => {
+                iox2_subscriber_create_error_e::VIOLATES_DETERMINISTIC_PROFILE
+            }
+            SubscriberCreateError::InPlaceUpdatesRequireWritablePayload => {
+                iox2_subscriber_create_error_e::IN_PLACE_UPDATES_REQUIRE_WRITABLE_PAYLOAD
+            }
         }) as c_int
     }
 }
@@ -43,6 +43,9 @@ pub enum iox2_publisher_send_error_e {
     LOAN_ERROR_EXCEEDS_MAX_LOAN_SIZE,
     LOAN_ERROR_INTERNAL_FAILURE,
     CONNECTION_ERROR,
+    REPLAY_NOT_ENABLED,
+    INVALID_RAW_SAMPLE,
+    LOAN_ERROR_EXCEEDS_RESOURCE_BUDGET_OF_NODE,
 }
 
 impl IntoCInt for PublisherSendError {
@@ -66,7 +69,12 @@ impl IntoCInt for PublisherSendError {
             PublisherSendError::LoanError(PublisherLoanError::InternalFailure) => {
                 iox2_publisher_send_error_e::LOAN_ERROR_INTERNAL_FAILURE
             }
+            PublisherSendError::LoanError(PublisherLoanError::ExceedsResourceBudgetOfNode) => {
+                iox2_publisher_send_error_e::LOAN_ERROR_EXCEEDS_RESOURCE_BUDGET_OF_NODE
+            }
             PublisherSendError::ConnectionError(_) => iox2_publisher_send_error_e::CONNECTION_ERROR,
+            PublisherSendError::ReplayNotEnabled => iox2_publisher_send_error_e::REPLAY_NOT_ENABLED,
+            PublisherSendError::InvalidRawSample => iox2_publisher_send_error_e::INVALID_RAW_SAMPLE,
         }) as c_int
     }
 }
@@ -82,6 +90,9 @@ impl IntoCInt for PublisherLoanError {
                 iox2_publisher_loan_error_e::EXCEEDS_MAX_LOAN_SIZE
             }
             PublisherLoanError::InternalFailure => iox2_publisher_loan_error_e::INTERNAL_FAILURE,
+            PublisherLoanError::ExceedsResourceBudgetOfNode => {
+                iox2_publisher_loan_error_e::EXCEEDS_RESOURCE_BUDGET_OF_NODE
+            }
         }) as c_int
     }
 }
@@ -93,6 +104,7 @@ pub enum iox2_publisher_loan_error_e {
     EXCEEDS_MAX_LOANED_SAMPLES,
     EXCEEDS_MAX_LOAN_SIZE,
     INTERNAL_FAILURE,
+    EXCEEDS_RESOURCE_BUDGET_OF_NODE,
 }
 
 pub(super) union PublisherUnion {
@@ -0,0 +1,268 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types)]
+
+use crate::api::{
+    c_size_t, iox2_pending_response_h, iox2_pending_response_t, iox2_service_type_e,
+    AssertNonNullHandle, HandleToType, IntoCInt, PendingResponseUnion, RequestPayloadFfi,
+    ResponsePayloadFfi, IOX2_OK,
+};
+
+use iceoryx2::prelude::*;
+use iceoryx2::request_mut::RequestMut;
+use iceoryx2_bb_elementary::static_assert::*;
+use iceoryx2_ffi_macros::iceoryx2_ffi;
+
+use core::ffi::{c_int, c_void};
+use core::mem::ManuallyDrop;
+
+// BEGIN types definition
+
+pub(super) union RequestMutUnion {
+    ipc: ManuallyDrop<RequestMut<ipc::Service, RequestPayloadFfi, (), ResponsePayloadFfi, ()>>,
+    local: ManuallyDrop<RequestMut<local::Service, RequestPayloadFfi, (), ResponsePayloadFfi, ()>>,
+}
+
+impl RequestMutUnion {
+    pub(super) fn new_ipc(
+        request: RequestMut<ipc::Service, RequestPayloadFfi, (), ResponsePayloadFfi, ()>,
+    ) -> Self {
+        Self {
+            ipc: ManuallyDrop::new(request),
+        }
+    }
+    pub(super) fn new_local(
+        request: RequestMut<local::Service, RequestPayloadFfi, (), ResponsePayloadFfi, ()>,
+    ) -> Self {
+        Self {
+            local: ManuallyDrop::new(request),
+        }
+    }
+}
+
+#[repr(C)]
+#[repr(align(8))] // alignment of Option<RequestMutUnion>
+pub struct iox2_request_mut_storage_t {
+    internal: [u8; 96], // magic number obtained with size_of::<Option<RequestMutUnion>>()
+}
+
+#[repr(C)]
+#[iceoryx2_ffi(RequestMutUnion)]
+pub struct iox2_request_mut_t {
+    service_type: iox2_service_type_e,
+    value: iox2_request_mut_storage_t,
+    deleter: fn(*mut iox2_request_mut_t),
+}
+
+impl iox2_request_mut_t {
+    pub(super) fn init(
+        &mut self,
+        service_type: iox2_service_type_e,
+        value: RequestMutUnion,
+        deleter: fn(*mut iox2_request_mut_t),
+    ) {
+        self.service_type = service_type;
+        self.value.init(value);
+        self.deleter = deleter;
+    }
+}
+
+pub struct iox2_request_mut_h_t;
+/// The owning handle for `iox2_request_mut_t`. Passing the handle to an function transfers the ownership.
+pub type iox2_request_mut_h = *mut iox2_request_mut_h_t;
+/// The non-owning handle for `iox2_request_mut_t`. Passing the handle to an function does not transfers the ownership.
+pub type iox2_request_mut_h_ref = *const iox2_request_mut_h;
+
+impl AssertNonNullHandle for iox2_request_mut_h {
+    fn assert_non_null(self) {
+        debug_assert!(!self.is_null());
+    }
+}
+
+impl AssertNonNullHandle for iox2_request_mut_h_ref {
+    fn assert_non_null(self) {
+        debug_assert!(!self.is_null());
+        unsafe {
+            debug_assert!(!(*self).is_null());
+        }
+    }
+}
+
+impl HandleToType for iox2_request_mut_h {
+    type Target = *mut iox2_request_mut_t;
+
+    fn as_type(self) -> Self::Target {
+        self as *mut _ as _
+    }
+}
+
+impl HandleToType for iox2_request_mut_h_ref {
+    type Target = *mut iox2_request_mut_t;
+
+    fn as_type(self) -> Self::Target {
+        unsafe { *self as *mut _ as _ }
+    }
+}
+
+// END type definition
+
+// BEGIN C API
+
+/// Acquires the requests mutable payload.
+///
+/// # Safety
+///
+/// * `handle` obtained by [`iox2_client_loan_slice()`](crate::iox2_client_loan_slice())
+/// * `payload_ptr` a valid, non-null pointer pointing to a [`*mut c_void`] pointer.
+/// * `number_of_bytes` (optional) either a null pointer or a valid pointer pointing to a [`c_size_t`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_request_mut_payload_mut(
+    handle: iox2_request_mut_h_ref,
+    payload_ptr: *mut *mut c_void,
+    number_of_bytes: *mut c_size_t,
+) {
+    handle.assert_non_null();
+    debug_assert!(!payload_ptr.is_null());
+
+    let request = &mut *handle.as_type();
+
+    let payload = match request.service_type {
+        iox2_service_type_e::IPC => request.value.as_mut().ipc.payload_mut(),
+        iox2_service_type_e::LOCAL => request.value.as_mut().local.payload_mut(),
+    };
+
+    *payload_ptr = payload.as_mut_ptr().cast();
+
+    if !number_of_bytes.is_null() {
+        *number_of_bytes = payload.len() as c_size_t;
+    }
+}
+
+/// Takes the ownership of the request and sends it to all connected servers. The returned
+/// [`iox2_pending_response_h`] is used to receive the responses.
+///
+/// # Arguments
+///
+/// * `request_handle` - obtained by [`iox2_client_loan_slice()`](crate::iox2_client_loan_slice())
+/// * `pending_response_struct_ptr` - Must be either a NULL pointer or a pointer to a valid [`iox2_pending_response_t`].
+///   If it is a NULL pointer, the storage will be allocated on the heap.
+/// * `pending_response_handle_ptr` - An uninitialized or dangling [`iox2_pending_response_h`] handle which will be initialized by this function call.
+///
+/// Return [`IOX2_OK`] on success, otherwise [`iox2_request_send_error_e`](crate::iox2_request_send_error_e).
+///
+/// # Safety
+///
+/// * `request_handle` is invalid after the return of this function
+/// * `pending_response_handle_ptr` is valid and non-null
+#[no_mangle]
+pub unsafe extern "C" fn iox2_request_mut_send(
+    request_handle: iox2_request_mut_h,
+    pending_response_struct_ptr: *mut iox2_pending_response_t,
+    pending_response_handle_ptr: *mut iox2_pending_response_h,
+) -> c_int {
+    debug_assert!(!request_handle.is_null());
+    debug_assert!(!pending_response_handle_ptr.is_null());
+
+    *pending_response_handle_ptr = core::ptr::null_mut();
+
+    let init_pending_response_struct_ptr =
+        |pending_response_struct_ptr: *mut iox2_pending_response_t| {
+            let mut pending_response_struct_ptr = pending_response_struct_ptr;
+            fn no_op(_: *mut iox2_pending_response_t) {}
+            let mut deleter: fn(*mut iox2_pending_response_t) = no_op;
+            if pending_response_struct_ptr.is_null() {
+                pending_response_struct_ptr = iox2_pending_response_t::alloc();
+                deleter = iox2_pending_response_t::dealloc;
+            }
+            debug_assert!(!pending_response_struct_ptr.is_null());
+
+            (pending_response_struct_ptr, deleter)
+        };
+
+    let request_struct = &mut *request_handle.as_type();
+    let service_type = request_struct.service_type;
+
+    let request = request_struct
+        .value
+        .as_option_mut()
+        .take()
+        .unwrap_or_else(|| panic!("Trying to send an already sent request!"));
+    (request_struct.deleter)(request_struct);
+
+    match service_type {
+        iox2_service_type_e::IPC => {
+            let request = ManuallyDrop::into_inner(request.ipc);
+            match request.send() {
+                Ok(pending_response) => {
+                    let (pending_response_struct_ptr, deleter) =
+                        init_pending_response_struct_ptr(pending_response_struct_ptr);
+                    (*pending_response_struct_ptr).init(
+                        service_type,
+                        PendingResponseUnion::new_ipc(pending_response),
+                        deleter,
+                    );
+                    *pending_response_handle_ptr = (*pending_response_struct_ptr).as_handle();
+                }
+                Err(error) => return error.into_c_int(),
+            }
+        }
+        iox2_service_type_e::LOCAL => {
+            let request = ManuallyDrop::into_inner(request.local);
+            match request.send() {
+                Ok(pending_response) => {
+                    let (pending_response_struct_ptr, deleter) =
+                        init_pending_response_struct_ptr(pending_response_struct_ptr);
+                    (*pending_response_struct_ptr).init(
+                        service_type,
+                        PendingResponseUnion::new_local(pending_response),
+                        deleter,
+                    );
+                    *pending_response_handle_ptr = (*pending_response_struct_ptr).as_handle();
+                }
+                Err(error) => return error.into_c_int(),
+            }
+        }
+    }
+
+    IOX2_OK
+}
+
+/// This function needs to be called to destroy a request that was not sent!
+///
+/// # Arguments
+///
+/// * `request_handle` - A valid [`iox2_request_mut_h`]
+///
+/// # Safety
+///
+/// * The `request_handle` is invalid after the return of this function and leads to undefined behavior if used in another function call!
+/// * The corresponding [`iox2_request_mut_t`] can be re-used with a call to
+///   [`iox2_client_loan_slice`](crate::iox2_client_loan_slice)!
+#[no_mangle]
+pub unsafe extern "C" fn iox2_request_mut_drop(request_handle: iox2_request_mut_h) {
+    debug_assert!(!request_handle.is_null());
+
+    let request = &mut *request_handle.as_type();
+
+    match request.service_type {
+        iox2_service_type_e::IPC => {
+            ManuallyDrop::drop(&mut request.value.as_mut().ipc);
+        }
+        iox2_service_type_e::LOCAL => {
+            ManuallyDrop::drop(&mut request.value.as_mut().local);
+        }
+    }
+    (request.deleter)(request);
+}
+
+// END C API
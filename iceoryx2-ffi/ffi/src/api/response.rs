@@ -0,0 +1,173 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types)]
+
+use crate::api::{
+    c_size_t, iox2_service_type_e, AssertNonNullHandle, HandleToType, ResponsePayloadFfi,
+};
+
+use iceoryx2::prelude::*;
+use iceoryx2::response::Response;
+use iceoryx2_bb_elementary::static_assert::*;
+use iceoryx2_ffi_macros::iceoryx2_ffi;
+
+use core::ffi::c_void;
+use core::mem::ManuallyDrop;
+
+// BEGIN types definition
+
+pub(super) union ResponseUnion {
+    ipc: ManuallyDrop<Response<ipc::Service, ResponsePayloadFfi, ()>>,
+    local: ManuallyDrop<Response<local::Service, ResponsePayloadFfi, ()>>,
+}
+
+impl ResponseUnion {
+    pub(super) fn new_ipc(response: Response<ipc::Service, ResponsePayloadFfi, ()>) -> Self {
+        Self {
+            ipc: ManuallyDrop::new(response),
+        }
+    }
+    pub(super) fn new_local(response: Response<local::Service, ResponsePayloadFfi, ()>) -> Self {
+        Self {
+            local: ManuallyDrop::new(response),
+        }
+    }
+}
+
+#[repr(C)]
+#[repr(align(8))] // alignment of Option<ResponseUnion>
+pub struct iox2_response_storage_t {
+    internal: [u8; 80], // magic number obtained with size_of::<Option<ResponseUnion>>()
+}
+
+#[repr(C)]
+#[iceoryx2_ffi(ResponseUnion)]
+pub struct iox2_response_t {
+    service_type: iox2_service_type_e,
+    value: iox2_response_storage_t,
+    deleter: fn(*mut iox2_response_t),
+}
+
+impl iox2_response_t {
+    pub(super) fn init(
+        &mut self,
+        service_type: iox2_service_type_e,
+        value: ResponseUnion,
+        deleter: fn(*mut iox2_response_t),
+    ) {
+        self.service_type = service_type;
+        self.value.init(value);
+        self.deleter = deleter;
+    }
+}
+
+pub struct iox2_response_h_t;
+/// The owning handle for `iox2_response_t`. Passing the handle to an function transfers the ownership.
+pub type iox2_response_h = *mut iox2_response_h_t;
+/// The non-owning handle for `iox2_response_t`. Passing the handle to an function does not transfers the ownership.
+pub type iox2_response_h_ref = *const iox2_response_h;
+
+impl AssertNonNullHandle for iox2_response_h {
+    fn assert_non_null(self) {
+        debug_assert!(!self.is_null());
+    }
+}
+
+impl AssertNonNullHandle for iox2_response_h_ref {
+    fn assert_non_null(self) {
+        debug_assert!(!self.is_null());
+        unsafe {
+            debug_assert!(!(*self).is_null());
+        }
+    }
+}
+
+impl HandleToType for iox2_response_h {
+    type Target = *mut iox2_response_t;
+
+    fn as_type(self) -> Self::Target {
+        self as *mut _ as _
+    }
+}
+
+impl HandleToType for iox2_response_h_ref {
+    type Target = *mut iox2_response_t;
+
+    fn as_type(self) -> Self::Target {
+        unsafe { *self as *mut _ as _ }
+    }
+}
+
+// END type definition
+
+// BEGIN C API
+
+/// Acquires the responses payload.
+///
+/// # Safety
+///
+/// * `handle` obtained by [`iox2_pending_response_receive()`](crate::iox2_pending_response_receive())
+/// * `payload_ptr` a valid, non-null pointer pointing to a [`*const c_void`] pointer.
+/// * `number_of_bytes` (optional) either a null pointer or a valid pointer pointing to a [`c_size_t`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_response_payload(
+    handle: iox2_response_h_ref,
+    payload_ptr: *mut *const c_void,
+    number_of_bytes: *mut c_size_t,
+) {
+    handle.assert_non_null();
+    debug_assert!(!payload_ptr.is_null());
+
+    let response = &mut *handle.as_type();
+
+    let payload = match response.service_type {
+        iox2_service_type_e::IPC => response.value.as_mut().ipc.payload(),
+        iox2_service_type_e::LOCAL => response.value.as_mut().local.payload(),
+    };
+
+    *payload_ptr = payload.as_ptr().cast();
+
+    if !number_of_bytes.is_null() {
+        *number_of_bytes = payload.len() as c_size_t;
+    }
+}
+
+/// This function needs to be called to destroy the response!
+///
+/// # Arguments
+///
+/// * `response_handle` - A valid [`iox2_response_h`]
+///
+/// # Safety
+///
+/// * The `response_handle` is invalid after the return of this function and leads to undefined behavior if used in another function call!
+/// * The corresponding [`iox2_response_t`] can be re-used with a call to
+///   [`iox2_pending_response_receive`](crate::iox2_pending_response_receive)!
+#[no_mangle]
+pub unsafe extern "C" fn iox2_response_drop(response_handle: iox2_response_h) {
+    debug_assert!(!response_handle.is_null());
+
+    let response = &mut *response_handle.as_type();
+
+    match response.service_type {
+        iox2_service_type_e::IPC => {
+            ManuallyDrop::drop(&mut response.value.as_mut().ipc);
+        }
+        iox2_service_type_e::LOCAL => {
+            ManuallyDrop::drop(&mut response.value.as_mut().local);
+        }
+    }
+    (response.deleter)(response);
+}
+
+// END C API
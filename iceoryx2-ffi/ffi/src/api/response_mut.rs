@@ -0,0 +1,278 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types)]
+
+use crate::api::{
+    c_size_t, iox2_service_type_e, AssertNonNullHandle, HandleToType, IntoCInt, ResponsePayloadFfi,
+    IOX2_OK,
+};
+
+use iceoryx2::port::server::ResponseSendError;
+use iceoryx2::port::LoanError;
+use iceoryx2::prelude::*;
+use iceoryx2::response_mut::ResponseMut;
+use iceoryx2_bb_elementary::static_assert::*;
+use iceoryx2_bb_elementary::AsCStr;
+use iceoryx2_ffi_macros::iceoryx2_ffi;
+use iceoryx2_ffi_macros::CStrRepr;
+
+use core::ffi::{c_char, c_int, c_void};
+use core::mem::ManuallyDrop;
+
+// BEGIN types definition
+
+#[repr(C)]
+#[derive(Copy, Clone, CStrRepr)]
+pub enum iox2_response_send_error_e {
+    CONNECTION_BROKEN_SINCE_CLIENT_NO_LONGER_EXISTS = IOX2_OK as isize + 1,
+    CONNECTION_CORRUPTED,
+    RECEIVE_BUFFER_FULL,
+    LOAN_ERROR_OUT_OF_MEMORY,
+    LOAN_ERROR_EXCEEDS_MAX_LOANS,
+    LOAN_ERROR_EXCEEDS_MAX_LOAN_SIZE,
+    LOAN_ERROR_INTERNAL_FAILURE,
+    INCOMPATIBLE_ERROR_TYPE,
+}
+
+impl IntoCInt for ResponseSendError {
+    fn into_c_int(self) -> c_int {
+        (match self {
+            ResponseSendError::ConnectionBrokenSinceClientNoLongerExists => {
+                iox2_response_send_error_e::CONNECTION_BROKEN_SINCE_CLIENT_NO_LONGER_EXISTS
+            }
+            ResponseSendError::ConnectionCorrupted => {
+                iox2_response_send_error_e::CONNECTION_CORRUPTED
+            }
+            ResponseSendError::ReceiveBufferFull => iox2_response_send_error_e::RECEIVE_BUFFER_FULL,
+            ResponseSendError::LoanError(LoanError::OutOfMemory) => {
+                iox2_response_send_error_e::LOAN_ERROR_OUT_OF_MEMORY
+            }
+            ResponseSendError::LoanError(LoanError::ExceedsMaxLoans) => {
+                iox2_response_send_error_e::LOAN_ERROR_EXCEEDS_MAX_LOANS
+            }
+            ResponseSendError::LoanError(LoanError::ExceedsMaxLoanSize) => {
+                iox2_response_send_error_e::LOAN_ERROR_EXCEEDS_MAX_LOAN_SIZE
+            }
+            ResponseSendError::LoanError(LoanError::InternalFailure) => {
+                iox2_response_send_error_e::LOAN_ERROR_INTERNAL_FAILURE
+            }
+            ResponseSendError::IncompatibleErrorType => {
+                iox2_response_send_error_e::INCOMPATIBLE_ERROR_TYPE
+            }
+        }) as c_int
+    }
+}
+
+pub(super) union ResponseMutUnion {
+    ipc: ManuallyDrop<ResponseMut<ipc::Service, ResponsePayloadFfi, ()>>,
+    local: ManuallyDrop<ResponseMut<local::Service, ResponsePayloadFfi, ()>>,
+}
+
+impl ResponseMutUnion {
+    pub(super) fn new_ipc(response: ResponseMut<ipc::Service, ResponsePayloadFfi, ()>) -> Self {
+        Self {
+            ipc: ManuallyDrop::new(response),
+        }
+    }
+    pub(super) fn new_local(response: ResponseMut<local::Service, ResponsePayloadFfi, ()>) -> Self {
+        Self {
+            local: ManuallyDrop::new(response),
+        }
+    }
+}
+
+#[repr(C)]
+#[repr(align(8))] // alignment of Option<ResponseMutUnion>
+pub struct iox2_response_mut_storage_t {
+    internal: [u8; 96], // magic number obtained with size_of::<Option<ResponseMutUnion>>()
+}
+
+#[repr(C)]
+#[iceoryx2_ffi(ResponseMutUnion)]
+pub struct iox2_response_mut_t {
+    service_type: iox2_service_type_e,
+    value: iox2_response_mut_storage_t,
+    deleter: fn(*mut iox2_response_mut_t),
+}
+
+impl iox2_response_mut_t {
+    pub(super) fn init(
+        &mut self,
+        service_type: iox2_service_type_e,
+        value: ResponseMutUnion,
+        deleter: fn(*mut iox2_response_mut_t),
+    ) {
+        self.service_type = service_type;
+        self.value.init(value);
+        self.deleter = deleter;
+    }
+}
+
+pub struct iox2_response_mut_h_t;
+/// The owning handle for `iox2_response_mut_t`. Passing the handle to an function transfers the ownership.
+pub type iox2_response_mut_h = *mut iox2_response_mut_h_t;
+/// The non-owning handle for `iox2_response_mut_t`. Passing the handle to an function does not transfers the ownership.
+pub type iox2_response_mut_h_ref = *const iox2_response_mut_h;
+
+impl AssertNonNullHandle for iox2_response_mut_h {
+    fn assert_non_null(self) {
+        debug_assert!(!self.is_null());
+    }
+}
+
+impl AssertNonNullHandle for iox2_response_mut_h_ref {
+    fn assert_non_null(self) {
+        debug_assert!(!self.is_null());
+        unsafe {
+            debug_assert!(!(*self).is_null());
+        }
+    }
+}
+
+impl HandleToType for iox2_response_mut_h {
+    type Target = *mut iox2_response_mut_t;
+
+    fn as_type(self) -> Self::Target {
+        self as *mut _ as _
+    }
+}
+
+impl HandleToType for iox2_response_mut_h_ref {
+    type Target = *mut iox2_response_mut_t;
+
+    fn as_type(self) -> Self::Target {
+        unsafe { *self as *mut _ as _ }
+    }
+}
+
+// END type definition
+
+// BEGIN C API
+
+/// Returns a string literal describing the provided [`iox2_response_send_error_e`].
+///
+/// # Arguments
+///
+/// * `error` - The error value for which a description should be returned
+///
+/// # Returns
+///
+/// A pointer to a null-terminated string containing the error message.
+/// The string is stored in the .rodata section of the binary.
+///
+/// # Safety
+///
+/// The returned pointer must not be modified or freed and is valid as long as the program runs.
+#[no_mangle]
+pub unsafe extern "C" fn iox2_response_send_error_string(
+    error: iox2_response_send_error_e,
+) -> *const c_char {
+    error.as_const_cstr().as_ptr() as *const c_char
+}
+
+/// Acquires the responses mutable payload.
+///
+/// # Safety
+///
+/// * `handle` obtained by [`iox2_active_request_loan_slice()`](crate::iox2_active_request_loan_slice())
+/// * `payload_ptr` a valid, non-null pointer pointing to a [`*mut c_void`] pointer.
+/// * `number_of_bytes` (optional) either a null pointer or a valid pointer pointing to a [`c_size_t`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_response_mut_payload_mut(
+    handle: iox2_response_mut_h_ref,
+    payload_ptr: *mut *mut c_void,
+    number_of_bytes: *mut c_size_t,
+) {
+    handle.assert_non_null();
+    debug_assert!(!payload_ptr.is_null());
+
+    let response = &mut *handle.as_type();
+
+    let payload = match response.service_type {
+        iox2_service_type_e::IPC => response.value.as_mut().ipc.payload_mut(),
+        iox2_service_type_e::LOCAL => response.value.as_mut().local.payload_mut(),
+    };
+
+    *payload_ptr = payload.as_mut_ptr().cast();
+
+    if !number_of_bytes.is_null() {
+        *number_of_bytes = payload.len() as c_size_t;
+    }
+}
+
+/// Takes the ownership of the response and sends it to the client that sent the corresponding
+/// request.
+///
+/// # Arguments
+///
+/// * `response_handle` - obtained by [`iox2_active_request_loan_slice()`](crate::iox2_active_request_loan_slice())
+///
+/// Return [`IOX2_OK`] on success, otherwise [`iox2_response_send_error_e`].
+///
+/// # Safety
+///
+/// * `response_handle` is invalid after the return of this function
+#[no_mangle]
+pub unsafe extern "C" fn iox2_response_mut_send(response_handle: iox2_response_mut_h) -> c_int {
+    debug_assert!(!response_handle.is_null());
+
+    let response_struct = &mut *response_handle.as_type();
+    let service_type = response_struct.service_type;
+
+    let response = response_struct
+        .value
+        .as_option_mut()
+        .take()
+        .unwrap_or_else(|| panic!("Trying to send an already sent response!"));
+    (response_struct.deleter)(response_struct);
+
+    let result = match service_type {
+        iox2_service_type_e::IPC => ManuallyDrop::into_inner(response.ipc).send(),
+        iox2_service_type_e::LOCAL => ManuallyDrop::into_inner(response.local).send(),
+    };
+
+    match result {
+        Ok(()) => IOX2_OK,
+        Err(error) => error.into_c_int(),
+    }
+}
+
+/// This function needs to be called to destroy a response that was not sent!
+///
+/// # Arguments
+///
+/// * `response_handle` - A valid [`iox2_response_mut_h`]
+///
+/// # Safety
+///
+/// * The `response_handle` is invalid after the return of this function and leads to undefined behavior if used in another function call!
+/// * The corresponding [`iox2_response_mut_t`] can be re-used with a call to
+///   [`iox2_active_request_loan_slice`](crate::iox2_active_request_loan_slice)!
+#[no_mangle]
+pub unsafe extern "C" fn iox2_response_mut_drop(response_handle: iox2_response_mut_h) {
+    debug_assert!(!response_handle.is_null());
+
+    let response = &mut *response_handle.as_type();
+
+    match response.service_type {
+        iox2_service_type_e::IPC => {
+            ManuallyDrop::drop(&mut response.value.as_mut().ipc);
+        }
+        iox2_service_type_e::LOCAL => {
+            ManuallyDrop::drop(&mut response.value.as_mut().local);
+        }
+    }
+    (response.deleter)(response);
+}
+
+// END C API
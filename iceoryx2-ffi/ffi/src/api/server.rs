@@ -0,0 +1,340 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types)]
+
+use crate::api::{
+    c_size_t, iox2_active_request_h, iox2_active_request_t, iox2_service_type_e,
+    ActiveRequestUnion, AssertNonNullHandle, HandleToType, IntoCInt, RequestPayloadFfi,
+    ResponsePayloadFfi, IOX2_OK,
+};
+
+use iceoryx2::port::server::{Server, ServerReceiveError};
+use iceoryx2::port::update_connections::ConnectionFailure;
+use iceoryx2::prelude::*;
+use iceoryx2_bb_elementary::static_assert::*;
+use iceoryx2_bb_elementary::AsCStr;
+use iceoryx2_ffi_macros::iceoryx2_ffi;
+use iceoryx2_ffi_macros::CStrRepr;
+
+use core::ffi::{c_char, c_int};
+use core::mem::ManuallyDrop;
+
+// BEGIN types definition
+
+#[repr(C)]
+#[derive(Copy, Clone, CStrRepr)]
+pub enum iox2_server_receive_error_e {
+    EXCEEDS_MAX_BORROWED_REQUESTS = IOX2_OK as isize + 1,
+    FAILED_TO_ESTABLISH_CONNECTION,
+    UNABLE_TO_MAP_CLIENTS_DATA_SEGMENT,
+}
+
+impl IntoCInt for ServerReceiveError {
+    fn into_c_int(self) -> c_int {
+        (match self {
+            ServerReceiveError::ExceedsMaxBorrowedRequests => {
+                iox2_server_receive_error_e::EXCEEDS_MAX_BORROWED_REQUESTS
+            }
+            ServerReceiveError::ConnectionFailure(
+                ConnectionFailure::FailedToEstablishConnection(_),
+            ) => iox2_server_receive_error_e::FAILED_TO_ESTABLISH_CONNECTION,
+            ServerReceiveError::ConnectionFailure(
+                ConnectionFailure::UnableToMapPublishersDataSegment(_),
+            ) => iox2_server_receive_error_e::UNABLE_TO_MAP_CLIENTS_DATA_SEGMENT,
+        }) as c_int
+    }
+}
+
+pub(super) union ServerUnion {
+    ipc: ManuallyDrop<Server<ipc::Service, RequestPayloadFfi, (), ResponsePayloadFfi, ()>>,
+    local: ManuallyDrop<Server<local::Service, RequestPayloadFfi, (), ResponsePayloadFfi, ()>>,
+}
+
+impl ServerUnion {
+    pub(super) fn new_ipc(
+        server: Server<ipc::Service, RequestPayloadFfi, (), ResponsePayloadFfi, ()>,
+    ) -> Self {
+        Self {
+            ipc: ManuallyDrop::new(server),
+        }
+    }
+    pub(super) fn new_local(
+        server: Server<local::Service, RequestPayloadFfi, (), ResponsePayloadFfi, ()>,
+    ) -> Self {
+        Self {
+            local: ManuallyDrop::new(server),
+        }
+    }
+}
+
+#[repr(C)]
+#[repr(align(16))] // alignment of Option<ServerUnion>
+pub struct iox2_server_storage_t {
+    internal: [u8; 64], // magic number obtained with size_of::<Option<ServerUnion>>()
+}
+
+#[repr(C)]
+#[iceoryx2_ffi(ServerUnion)]
+pub struct iox2_server_t {
+    service_type: iox2_service_type_e,
+    value: iox2_server_storage_t,
+    deleter: fn(*mut iox2_server_t),
+}
+
+impl iox2_server_t {
+    pub(super) fn init(
+        &mut self,
+        service_type: iox2_service_type_e,
+        value: ServerUnion,
+        deleter: fn(*mut iox2_server_t),
+    ) {
+        self.service_type = service_type;
+        self.value.init(value);
+        self.deleter = deleter;
+    }
+}
+
+pub struct iox2_server_h_t;
+/// The owning handle for `iox2_server_t`. Passing the handle to an function transfers the ownership.
+pub type iox2_server_h = *mut iox2_server_h_t;
+/// The non-owning handle for `iox2_server_t`. Passing the handle to an function does not transfers the ownership.
+pub type iox2_server_h_ref = *const iox2_server_h;
+
+impl AssertNonNullHandle for iox2_server_h {
+    fn assert_non_null(self) {
+        debug_assert!(!self.is_null());
+    }
+}
+
+impl AssertNonNullHandle for iox2_server_h_ref {
+    fn assert_non_null(self) {
+        debug_assert!(!self.is_null());
+        unsafe {
+            debug_assert!(!(*self).is_null());
+        }
+    }
+}
+
+impl HandleToType for iox2_server_h {
+    type Target = *mut iox2_server_t;
+
+    fn as_type(self) -> Self::Target {
+        self as *mut _ as _
+    }
+}
+
+impl HandleToType for iox2_server_h_ref {
+    type Target = *mut iox2_server_t;
+
+    fn as_type(self) -> Self::Target {
+        unsafe { *self as *mut _ as _ }
+    }
+}
+
+// END type definition
+
+// BEGIN C API
+
+/// Returns a string literal describing the provided [`iox2_server_receive_error_e`].
+///
+/// # Arguments
+///
+/// * `error` - The error value for which a description should be returned
+///
+/// # Returns
+///
+/// A pointer to a null-terminated string containing the error message.
+/// The string is stored in the .rodata section of the binary.
+///
+/// # Safety
+///
+/// The returned pointer must not be modified or freed and is valid as long as the program runs.
+#[no_mangle]
+pub unsafe extern "C" fn iox2_server_receive_error_string(
+    error: iox2_server_receive_error_e,
+) -> *const c_char {
+    error.as_const_cstr().as_ptr() as *const c_char
+}
+
+/// Returns the maximum number of bytes a response of the server can contain.
+///
+/// # Arguments
+///
+/// * `server_handle` - Must be a valid [`iox2_server_h_ref`]
+///   obtained by [`iox2_port_factory_server_builder_create`](crate::iox2_port_factory_server_builder_create).
+///
+/// # Safety
+///
+/// * `server_handle` must be valid handles
+#[no_mangle]
+pub unsafe extern "C" fn iox2_server_initial_max_slice_len(
+    server_handle: iox2_server_h_ref,
+) -> c_size_t {
+    server_handle.assert_non_null();
+
+    let server = &mut *server_handle.as_type();
+
+    match server.service_type {
+        iox2_service_type_e::IPC => server.value.as_ref().ipc.initial_max_slice_len(),
+        iox2_service_type_e::LOCAL => server.value.as_ref().local.initial_max_slice_len(),
+    }
+}
+
+/// Takes the next request from the servers queue.
+///
+/// # Arguments
+///
+/// * `server_handle` - Must be a valid [`iox2_server_h_ref`]
+///   obtained by [`iox2_port_factory_server_builder_create`](crate::iox2_port_factory_server_builder_create).
+/// * `active_request_struct_ptr` - Must be either a NULL pointer or a pointer to a valid [`iox2_active_request_t`].
+///   If it is a NULL pointer, the storage will be allocated on the heap.
+/// * `active_request_handle_ptr` - An uninitialized or dangling [`iox2_active_request_h`] handle which will be initialized by this function call if a request is obtained, otherwise it will be set to NULL.
+///
+/// Returns IOX2_OK on success, an [`iox2_server_receive_error_e`] otherwise.
+/// Attention, an empty server queue is not an error and even with IOX2_OK it is possible to get a NULL in `active_request_handle_ptr`.
+///
+/// # Safety
+///
+/// * The `server_handle` is still valid after the return of this function and can be use in another function call.
+/// * The `active_request_handle_ptr` is pointing to a valid [`iox2_active_request_h`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_server_receive(
+    server_handle: iox2_server_h_ref,
+    active_request_struct_ptr: *mut iox2_active_request_t,
+    active_request_handle_ptr: *mut iox2_active_request_h,
+) -> c_int {
+    server_handle.assert_non_null();
+    debug_assert!(!active_request_handle_ptr.is_null());
+
+    *active_request_handle_ptr = core::ptr::null_mut();
+
+    let init_active_request_struct_ptr = |active_request_struct_ptr: *mut iox2_active_request_t| {
+        let mut active_request_struct_ptr = active_request_struct_ptr;
+        fn no_op(_: *mut iox2_active_request_t) {}
+        let mut deleter: fn(*mut iox2_active_request_t) = no_op;
+        if active_request_struct_ptr.is_null() {
+            active_request_struct_ptr = iox2_active_request_t::alloc();
+            deleter = iox2_active_request_t::dealloc;
+        }
+        debug_assert!(!active_request_struct_ptr.is_null());
+
+        (active_request_struct_ptr, deleter)
+    };
+
+    let server = &mut *server_handle.as_type();
+
+    match server.service_type {
+        iox2_service_type_e::IPC => match server.value.as_ref().ipc.receive() {
+            Ok(Some(active_request)) => {
+                let (active_request_struct_ptr, deleter) =
+                    init_active_request_struct_ptr(active_request_struct_ptr);
+                (*active_request_struct_ptr).init(
+                    server.service_type,
+                    ActiveRequestUnion::new_ipc(active_request),
+                    deleter,
+                );
+                *active_request_handle_ptr = (*active_request_struct_ptr).as_handle();
+            }
+            Ok(None) => (),
+            Err(error) => return error.into_c_int(),
+        },
+        iox2_service_type_e::LOCAL => match server.value.as_ref().local.receive() {
+            Ok(Some(active_request)) => {
+                let (active_request_struct_ptr, deleter) =
+                    init_active_request_struct_ptr(active_request_struct_ptr);
+                (*active_request_struct_ptr).init(
+                    server.service_type,
+                    ActiveRequestUnion::new_local(active_request),
+                    deleter,
+                );
+                *active_request_handle_ptr = (*active_request_struct_ptr).as_handle();
+            }
+            Ok(None) => (),
+            Err(error) => return error.into_c_int(),
+        },
+    }
+
+    IOX2_OK
+}
+
+/// Returns true when the server has requests that can be acquired with [`iox2_server_receive`], otherwise false.
+///
+/// # Arguments
+///
+/// * `server_handle` - Must be a valid [`iox2_server_h_ref`]
+///   obtained by [`iox2_port_factory_server_builder_create`](crate::iox2_port_factory_server_builder_create).
+/// * `result_ptr` - A non-null pointer to a bool that will contain the result.
+///
+/// Returns IOX2_OK on success, an [`iox2_connection_failure_e`](crate::iox2_connection_failure_e) otherwise.
+///
+/// # Safety
+///
+/// * The `server_handle` is still valid after the return of this function and can be use in another function call.
+/// * The `result_ptr` is pointing to a valid bool.
+#[no_mangle]
+pub unsafe extern "C" fn iox2_server_has_requests(
+    server_handle: iox2_server_h_ref,
+    result_ptr: *mut bool,
+) -> c_int {
+    server_handle.assert_non_null();
+    debug_assert!(!result_ptr.is_null());
+
+    let server = &mut *server_handle.as_type();
+
+    match server.service_type {
+        iox2_service_type_e::IPC => match server.value.as_ref().ipc.has_requests() {
+            Ok(v) => {
+                *result_ptr = v;
+                IOX2_OK
+            }
+            Err(error) => error.into_c_int(),
+        },
+        iox2_service_type_e::LOCAL => match server.value.as_ref().local.has_requests() {
+            Ok(v) => {
+                *result_ptr = v;
+                IOX2_OK
+            }
+            Err(error) => error.into_c_int(),
+        },
+    }
+}
+
+/// This function needs to be called to destroy the server!
+///
+/// # Arguments
+///
+/// * `server_handle` - A valid [`iox2_server_h`]
+///
+/// # Safety
+///
+/// * The `server_handle` is invalid after the return of this function and leads to undefined behavior if used in another function call!
+/// * The corresponding [`iox2_server_t`] can be re-used with a call to
+///   [`iox2_port_factory_server_builder_create`](crate::iox2_port_factory_server_builder_create)!
+#[no_mangle]
+pub unsafe extern "C" fn iox2_server_drop(server_handle: iox2_server_h) {
+    server_handle.assert_non_null();
+
+    let server = &mut *server_handle.as_type();
+
+    match server.service_type {
+        iox2_service_type_e::IPC => {
+            ManuallyDrop::drop(&mut server.value.as_mut().ipc);
+        }
+        iox2_service_type_e::LOCAL => {
+            ManuallyDrop::drop(&mut server.value.as_mut().local);
+        }
+    }
+    (server.deleter)(server);
+}
+
+// END C API
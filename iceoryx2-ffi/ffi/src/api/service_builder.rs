@@ -18,24 +18,30 @@ use iceoryx2::prelude::*;
 use iceoryx2::service::builder::publish_subscribe::{CustomHeaderMarker, CustomPayloadMarker};
 use iceoryx2::service::builder::{
     event::Builder as ServiceBuilderEvent, publish_subscribe::Builder as ServiceBuilderPubSub,
-    Builder as ServiceBuilderBase,
+    request_response::Builder as ServiceBuilderRequestResponse, Builder as ServiceBuilderBase,
 };
 use iceoryx2_bb_elementary::static_assert::*;
 use iceoryx2_ffi_macros::iceoryx2_ffi;
 
 use core::mem::ManuallyDrop;
 use core::mem::MaybeUninit;
+use core::time::Duration;
 
 // BEGIN types definition
 
 pub(super) type UserHeaderFfi = CustomHeaderMarker;
 pub(super) type PayloadFfi = [CustomPayloadMarker];
 pub(super) type UninitPayloadFfi = [MaybeUninit<CustomPayloadMarker>];
+pub(super) type RequestPayloadFfi = [u8];
+pub(super) type ResponsePayloadFfi = [u8];
 
 pub(super) union ServiceBuilderUnionNested<S: Service> {
     pub(super) base: ManuallyDrop<ServiceBuilderBase<S>>,
     pub(super) event: ManuallyDrop<ServiceBuilderEvent<S>>,
     pub(super) pub_sub: ManuallyDrop<ServiceBuilderPubSub<PayloadFfi, UserHeaderFfi, S>>,
+    pub(super) request_response: ManuallyDrop<
+        ServiceBuilderRequestResponse<RequestPayloadFfi, (), ResponsePayloadFfi, (), S>,
+    >,
 }
 
 pub(super) union ServiceBuilderUnion {
@@ -70,6 +76,22 @@ impl ServiceBuilderUnion {
         }
     }
 
+    pub(super) fn new_ipc_request_response(
+        service_builder: ServiceBuilderRequestResponse<
+            RequestPayloadFfi,
+            (),
+            ResponsePayloadFfi,
+            (),
+            ipc::Service,
+        >,
+    ) -> Self {
+        Self {
+            ipc: ManuallyDrop::new(ServiceBuilderUnionNested::<ipc::Service> {
+                request_response: ManuallyDrop::new(service_builder),
+            }),
+        }
+    }
+
     pub(super) fn new_local_base(service_builder: ServiceBuilderBase<local::Service>) -> Self {
         Self {
             local: ManuallyDrop::new(ServiceBuilderUnionNested::<local::Service> {
@@ -95,12 +117,28 @@ impl ServiceBuilderUnion {
             }),
         }
     }
+
+    pub(super) fn new_local_request_response(
+        service_builder: ServiceBuilderRequestResponse<
+            RequestPayloadFfi,
+            (),
+            ResponsePayloadFfi,
+            (),
+            local::Service,
+        >,
+    ) -> Self {
+        Self {
+            local: ManuallyDrop::new(ServiceBuilderUnionNested::<local::Service> {
+                request_response: ManuallyDrop::new(service_builder),
+            }),
+        }
+    }
 }
 
 #[repr(C)]
 #[repr(align(8))] // alignment of Option<ServiceBuilderUnion>
 pub struct iox2_service_builder_storage_t {
-    internal: [u8; 632], // magic number obtained with size_of::<Option<ServiceBuilderUnion>>()
+    internal: [u8; 680], // magic number obtained with size_of::<Option<ServiceBuilderUnion>>()
 }
 
 #[repr(C)]
@@ -142,6 +180,13 @@ pub type iox2_service_builder_pub_sub_h = *mut iox2_service_builder_pub_sub_h_t;
 /// The non-owning handle for `iox2_service_builder_t` which is already configured as event. Passing the handle to an function does not transfers the ownership.
 pub type iox2_service_builder_pub_sub_h_ref = *const iox2_service_builder_pub_sub_h;
 
+pub struct iox2_service_builder_request_response_h_t;
+/// The owning handle for `iox2_service_builder_t` which is already configured as request-response. Passing the handle to an function transfers the ownership.
+pub type iox2_service_builder_request_response_h = *mut iox2_service_builder_request_response_h_t;
+/// The non-owning handle for `iox2_service_builder_t` which is already configured as request-response. Passing the handle to an function does not transfers the ownership.
+pub type iox2_service_builder_request_response_h_ref =
+    *const iox2_service_builder_request_response_h;
+
 impl AssertNonNullHandle for iox2_service_builder_event_h {
     fn assert_non_null(self) {
         debug_assert!(!self.is_null());
@@ -172,6 +217,21 @@ impl AssertNonNullHandle for iox2_service_builder_pub_sub_h_ref {
     }
 }
 
+impl AssertNonNullHandle for iox2_service_builder_request_response_h {
+    fn assert_non_null(self) {
+        debug_assert!(!self.is_null());
+    }
+}
+
+impl AssertNonNullHandle for iox2_service_builder_request_response_h_ref {
+    fn assert_non_null(self) {
+        debug_assert!(!self.is_null());
+        unsafe {
+            debug_assert!(!(*self).is_null());
+        }
+    }
+}
+
 impl HandleToType for iox2_service_builder_h {
     type Target = *mut iox2_service_builder_t;
 
@@ -220,10 +280,72 @@ impl HandleToType for iox2_service_builder_pub_sub_h_ref {
     }
 }
 
+impl HandleToType for iox2_service_builder_request_response_h {
+    type Target = *mut iox2_service_builder_t;
+
+    fn as_type(self) -> Self::Target {
+        self as *mut _ as _
+    }
+}
+
+impl HandleToType for iox2_service_builder_request_response_h_ref {
+    type Target = *mut iox2_service_builder_t;
+
+    fn as_type(self) -> Self::Target {
+        unsafe { *self as *mut _ as _ }
+    }
+}
+
 // END type definition
 
 // BEGIN C API
 
+/// Defines the maximum time the `open_or_create` call of the messaging pattern specific
+/// service builder is allowed to take, including all retries.
+///
+/// # Arguments
+///
+/// * `service_builder_handle` - Must be a valid [`iox2_service_builder_h_ref`] obtained by [`iox2_node_service_builder`](crate::iox2_node_service_builder)
+/// * `seconds` - the second part of the timeout
+/// * `nanoseconds` - the nanosecond part of the timeout
+///
+/// # Safety
+///
+/// * `service_builder_handle` must be valid handles
+#[no_mangle]
+pub unsafe extern "C" fn iox2_service_builder_set_timeout(
+    service_builder_handle: iox2_service_builder_h_ref,
+    seconds: u64,
+    nanoseconds: u32,
+) {
+    debug_assert!(!service_builder_handle.is_null());
+    debug_assert!(!(*service_builder_handle).is_null());
+
+    let timeout = Duration::from_secs(seconds) + Duration::from_nanos(nanoseconds as u64);
+    let service_builders_struct = unsafe { &mut *service_builder_handle.as_type() };
+
+    match service_builders_struct.service_type {
+        iox2_service_type_e::IPC => {
+            let service_builder =
+                ManuallyDrop::take(&mut service_builders_struct.value.as_mut().ipc);
+
+            let service_builder = ManuallyDrop::into_inner(service_builder.base);
+            service_builders_struct.set(ServiceBuilderUnion::new_ipc_base(
+                service_builder.timeout(timeout),
+            ));
+        }
+        iox2_service_type_e::LOCAL => {
+            let service_builder =
+                ManuallyDrop::take(&mut service_builders_struct.value.as_mut().local);
+
+            let service_builder = ManuallyDrop::into_inner(service_builder.base);
+            service_builders_struct.set(ServiceBuilderUnion::new_local_base(
+                service_builder.timeout(timeout),
+            ));
+        }
+    }
+}
+
 /// This function transform the [`iox2_service_builder_h`] to an event service builder.
 ///
 /// # Arguments
@@ -313,4 +435,48 @@ pub unsafe extern "C" fn iox2_service_builder_pub_sub(
     service_builder_handle as *mut _ as _
 }
 
+/// This function transform the [`iox2_service_builder_h`] to a request-response service builder.
+/// The request and response payloads are byte slices without user header.
+///
+/// # Arguments
+///
+/// * `service_builder_handle` - Must be a valid [`iox2_service_builder_h`] obtained by [`iox2_node_service_builder`](crate::iox2_node_service_builder)
+///
+/// Returns a [`iox2_service_builder_request_response_h`] for the request-response service builder
+///
+/// # Safety
+///
+/// * The `service_builder_handle` is invalid after this call; The corresponding `iox2_service_builder_t` is now owned by the returned handle.
+#[no_mangle]
+pub unsafe extern "C" fn iox2_service_builder_request_response(
+    service_builder_handle: iox2_service_builder_h,
+) -> iox2_service_builder_request_response_h {
+    debug_assert!(!service_builder_handle.is_null());
+
+    let service_builders_struct = unsafe { &mut *service_builder_handle.as_type() };
+
+    match service_builders_struct.service_type {
+        iox2_service_type_e::IPC => {
+            let service_builder =
+                ManuallyDrop::take(&mut service_builders_struct.value.as_mut().ipc);
+
+            let service_builder = ManuallyDrop::into_inner(service_builder.base);
+            service_builders_struct.set(ServiceBuilderUnion::new_ipc_request_response(
+                service_builder.request_response::<RequestPayloadFfi, ResponsePayloadFfi>(),
+            ));
+        }
+        iox2_service_type_e::LOCAL => {
+            let service_builder =
+                ManuallyDrop::take(&mut service_builders_struct.value.as_mut().local);
+
+            let service_builder = ManuallyDrop::into_inner(service_builder.base);
+            service_builders_struct.set(ServiceBuilderUnion::new_local_request_response(
+                service_builder.request_response::<RequestPayloadFfi, ResponsePayloadFfi>(),
+            ));
+        }
+    }
+
+    service_builder_handle as *mut _ as _
+}
+
 // END C API
@@ -87,6 +87,16 @@ pub enum iox2_event_open_or_create_error_e {
     C_OLD_CONNECTION_STILL_ACTIVE,
     #[CStr = "same service is created and removed repeatedly"]
     SYSTEM_IN_FLUX,
+    #[CStr = "timed out"]
+    TIMED_OUT,
+    #[CStr = "does not support requested max payload size"]
+    O_DOES_NOT_SUPPORT_REQUESTED_MAX_PAYLOAD_SIZE,
+    #[CStr = "signature verification failed"]
+    O_SIGNATURE_VERIFICATION_FAILED,
+    #[CStr = "incompatible notifier origin setting"]
+    O_INCOMPATIBLE_NOTIFIER_ORIGIN_SETTING,
+    #[CStr = "is frozen"]
+    O_IS_FROZEN,
 }
 
 impl IntoCInt for EventOpenError {
@@ -123,6 +133,9 @@ impl IntoCInt for EventOpenError {
             EventOpenError::DoesNotSupportRequestedAmountOfNodes => {
                 iox2_event_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES
             }
+            EventOpenError::DoesNotSupportRequestedMaxPayloadSize => {
+                iox2_event_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_MAX_PAYLOAD_SIZE
+            }
             EventOpenError::ExceedsMaxNumberOfNodes => {
                 iox2_event_open_or_create_error_e::O_EXCEEDS_MAX_NUMBER_OF_NODES
             }
@@ -141,6 +154,13 @@ impl IntoCInt for EventOpenError {
             EventOpenError::IncompatibleDeadline => {
                 iox2_event_open_or_create_error_e::O_INCOMPATIBLE_DEADLINE
             }
+            EventOpenError::SignatureVerificationFailed => {
+                iox2_event_open_or_create_error_e::O_SIGNATURE_VERIFICATION_FAILED
+            }
+            EventOpenError::IncompatibleNotifierOriginSetting => {
+                iox2_event_open_or_create_error_e::O_INCOMPATIBLE_NOTIFIER_ORIGIN_SETTING
+            }
+            EventOpenError::IsFrozen => iox2_event_open_or_create_error_e::O_IS_FROZEN,
         }) as c_int
     }
 }
@@ -174,7 +194,12 @@ impl IntoCInt for EventOpenOrCreateError {
         match self {
             EventOpenOrCreateError::EventOpenError(error) => error.into_c_int(),
             EventOpenOrCreateError::EventCreateError(error) => error.into_c_int(),
-            e => e.into_c_int(),
+            EventOpenOrCreateError::SystemInFlux => {
+                iox2_event_open_or_create_error_e::SYSTEM_IN_FLUX as c_int
+            }
+            EventOpenOrCreateError::TimedOut => {
+                iox2_event_open_or_create_error_e::TIMED_OUT as c_int
+            }
         }
     }
 }
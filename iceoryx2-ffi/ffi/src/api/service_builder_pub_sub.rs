@@ -66,6 +66,10 @@ pub enum iox2_pub_sub_open_or_create_error_e {
     O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES,
     #[CStr = "incompatible overflow behavior"]
     O_INCOMPATIBLE_OVERFLOW_BEHAVIOR,
+    #[CStr = "incompatible payload checksum setting"]
+    O_INCOMPATIBLE_PAYLOAD_CHECKSUM_SETTING,
+    #[CStr = "incompatible global ordering setting"]
+    O_INCOMPATIBLE_GLOBAL_ORDERING_SETTING,
     #[CStr = "insufficient permissions"]
     O_INSUFFICIENT_PERMISSIONS,
     #[CStr = "service in corrupted state"]
@@ -94,6 +98,18 @@ pub enum iox2_pub_sub_open_or_create_error_e {
     C_HANGS_IN_CREATION,
     #[CStr = "same service is created and removed repeatedly"]
     SYSTEM_IN_FLUX,
+    #[CStr = "timed out"]
+    TIMED_OUT,
+    #[CStr = "signature verification failed"]
+    O_SIGNATURE_VERIFICATION_FAILED,
+    #[CStr = "incompatible broadcast setting"]
+    O_INCOMPATIBLE_BROADCAST_SETTING,
+    #[CStr = "incompatible priority lanes setting"]
+    O_INCOMPATIBLE_PRIORITY_LANES_SETTING,
+    #[CStr = "incompatible replay setting"]
+    O_INCOMPATIBLE_REPLAY_SETTING,
+    #[CStr = "is frozen"]
+    O_IS_FROZEN,
 }
 
 impl IntoCInt for PublishSubscribeOpenError {
@@ -133,6 +149,12 @@ impl IntoCInt for PublishSubscribeOpenError {
          PublishSubscribeOpenError::IncompatibleOverflowBehavior => {
              iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_OVERFLOW_BEHAVIOR
          }
+         PublishSubscribeOpenError::IncompatiblePayloadChecksumSetting => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_PAYLOAD_CHECKSUM_SETTING
+         }
+         PublishSubscribeOpenError::IncompatibleGlobalOrderingSetting => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_GLOBAL_ORDERING_SETTING
+         }
          PublishSubscribeOpenError::InsufficientPermissions => {
              iox2_pub_sub_open_or_create_error_e::O_INSUFFICIENT_PERMISSIONS
          }
@@ -148,6 +170,19 @@ impl IntoCInt for PublishSubscribeOpenError {
          PublishSubscribeOpenError::IsMarkedForDestruction => {
              iox2_pub_sub_open_or_create_error_e::O_IS_MARKED_FOR_DESTRUCTION
          }
+         PublishSubscribeOpenError::SignatureVerificationFailed => {
+             iox2_pub_sub_open_or_create_error_e::O_SIGNATURE_VERIFICATION_FAILED
+         }
+         PublishSubscribeOpenError::IncompatibleBroadcastSetting => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_BROADCAST_SETTING
+         }
+         PublishSubscribeOpenError::IncompatiblePriorityLanesSetting => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_PRIORITY_LANES_SETTING
+         }
+         PublishSubscribeOpenError::IncompatibleReplaySetting => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_REPLAY_SETTING
+         }
+         PublishSubscribeOpenError::IsFrozen => iox2_pub_sub_open_or_create_error_e::O_IS_FROZEN,
         }) as c_int
     }
 }
@@ -187,7 +222,12 @@ impl IntoCInt for PublishSubscribeOpenOrCreateError {
             PublishSubscribeOpenOrCreateError::PublishSubscribeCreateError(error) => {
                 error.into_c_int()
             }
-            e => e.into_c_int(),
+            PublishSubscribeOpenOrCreateError::SystemInFlux => {
+                iox2_pub_sub_open_or_create_error_e::SYSTEM_IN_FLUX as c_int
+            }
+            PublishSubscribeOpenOrCreateError::TimedOut => {
+                iox2_pub_sub_open_or_create_error_e::TIMED_OUT as c_int
+            }
         }
     }
 }
@@ -226,6 +266,7 @@ impl From<iox2_type_variant_e> for TypeVariant {
 pub enum iox2_type_detail_error_e {
     INVALID_TYPE_NAME = IOX2_OK as isize + 1,
     INVALID_SIZE_OR_ALIGNMENT_VALUE,
+    INVALID_TYPE_DESCRIPTOR,
 }
 
 // END type definition
@@ -407,6 +448,65 @@ pub unsafe extern "C" fn iox2_service_builder_pub_sub_set_payload_type_details(
     IOX2_OK
 }
 
+/// Sets the payload type descriptor for the builder. When the service is opened and both
+/// sides provided a descriptor, they must be equal, otherwise the types are incompatible.
+///
+/// # Arguments
+///
+/// * `service_builder_handle` - Must be a valid [`iox2_service_builder_pub_sub_h_ref`]
+///   obtained by [`iox2_service_builder_pub_sub`](crate::iox2_service_builder_pub_sub).
+/// * `type_descriptor_str` - Must string that describes the layout of the payload type.
+/// * `type_descriptor_len` - The length of the type descriptor string, not including a null
+///
+/// Returns IOX2_OK on success, an [`iox2_type_detail_error_e`] otherwise.
+///
+/// # Safety
+///
+/// * `service_builder_handle` must be valid handles
+/// * `type_descriptor_str` must be a valid pointer to an utf8 string
+#[no_mangle]
+pub unsafe extern "C" fn iox2_service_builder_pub_sub_set_payload_type_descriptor(
+    service_builder_handle: iox2_service_builder_pub_sub_h_ref,
+    type_descriptor_str: *const c_char,
+    type_descriptor_len: c_size_t,
+) -> c_int {
+    service_builder_handle.assert_non_null();
+    debug_assert!(!type_descriptor_str.is_null());
+
+    let type_descriptor = slice::from_raw_parts(type_descriptor_str as _, type_descriptor_len as _);
+
+    let type_descriptor = if let Ok(type_descriptor) = str::from_utf8(type_descriptor) {
+        type_descriptor
+    } else {
+        return iox2_type_detail_error_e::INVALID_TYPE_DESCRIPTOR as c_int;
+    };
+
+    let service_builder_struct = unsafe { &mut *service_builder_handle.as_type() };
+
+    match service_builder_struct.service_type {
+        iox2_service_type_e::IPC => {
+            let service_builder =
+                ManuallyDrop::take(&mut service_builder_struct.value.as_mut().ipc);
+
+            let service_builder = ManuallyDrop::into_inner(service_builder.pub_sub);
+            service_builder_struct.set(ServiceBuilderUnion::new_ipc_pub_sub(
+                service_builder.__internal_set_payload_type_descriptor(type_descriptor),
+            ));
+        }
+        iox2_service_type_e::LOCAL => {
+            let service_builder =
+                ManuallyDrop::take(&mut service_builder_struct.value.as_mut().local);
+
+            let service_builder = ManuallyDrop::into_inner(service_builder.pub_sub);
+            service_builder_struct.set(ServiceBuilderUnion::new_local_pub_sub(
+                service_builder.__internal_set_payload_type_descriptor(type_descriptor),
+            ));
+        }
+    }
+
+    IOX2_OK
+}
+
 /// Sets the max nodes for the builder
 ///
 /// # Arguments
@@ -0,0 +1,575 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types)]
+
+use crate::api::{
+    c_size_t, iox2_port_factory_request_response_h, iox2_port_factory_request_response_t,
+    iox2_service_builder_request_response_h, iox2_service_builder_request_response_h_ref,
+    iox2_service_type_e, AssertNonNullHandle, HandleToType, IntoCInt,
+    PortFactoryRequestResponseUnion, RequestPayloadFfi, ResponsePayloadFfi, ServiceBuilderUnion,
+    IOX2_OK,
+};
+
+use iceoryx2::prelude::*;
+use iceoryx2::service::builder::request_response::{
+    Builder, RequestResponseCreateError, RequestResponseOpenError, RequestResponseOpenOrCreateError,
+};
+use iceoryx2::service::port_factory::request_response::PortFactory;
+use iceoryx2_bb_elementary::AsCStr;
+use iceoryx2_ffi_macros::CStrRepr;
+
+use core::ffi::{c_char, c_int};
+use core::mem::ManuallyDrop;
+
+// BEGIN types definition
+
+#[repr(C)]
+#[derive(Copy, Clone, CStrRepr)]
+pub enum iox2_request_response_open_or_create_error_e {
+    #[CStr = "does not exist"]
+    O_DOES_NOT_EXIST = IOX2_OK as isize + 1,
+    #[CStr = "does not support requested amount of active responses"]
+    O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_ACTIVE_RESPONSES,
+    #[CStr = "does not support requested amount of active requests"]
+    O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_ACTIVE_REQUESTS,
+    #[CStr = "does not support requested amount of borrowed responses"]
+    O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_BORROWED_RESPONSES,
+    #[CStr = "does not support requested amount of borrowed requests"]
+    O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_BORROWED_REQUESTS,
+    #[CStr = "does not support requested response buffer size"]
+    O_DOES_NOT_SUPPORT_REQUESTED_RESPONSE_BUFFER_SIZE,
+    #[CStr = "does not support requested request buffer size"]
+    O_DOES_NOT_SUPPORT_REQUESTED_REQUEST_BUFFER_SIZE,
+    #[CStr = "does not support requested amount of servers"]
+    O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_SERVERS,
+    #[CStr = "does not support requested amount of clients"]
+    O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_CLIENTS,
+    #[CStr = "does not support requested amount of nodes"]
+    O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES,
+    #[CStr = "exceeds max number of nodes"]
+    O_EXCEEDS_MAX_NUMBER_OF_NODES,
+    #[CStr = "hangs in creation"]
+    O_HANGS_IN_CREATION,
+    #[CStr = "incompatible request type"]
+    O_INCOMPATIBLE_REQUEST_TYPE,
+    #[CStr = "incompatible response type"]
+    O_INCOMPATIBLE_RESPONSE_TYPE,
+    #[CStr = "incompatible error type"]
+    O_INCOMPATIBLE_ERROR_TYPE,
+    #[CStr = "incompatible attributes"]
+    O_INCOMPATIBLE_ATTRIBUTES,
+    #[CStr = "incompatible messaging pattern"]
+    O_INCOMPATIBLE_MESSAGING_PATTERN,
+    #[CStr = "incompatible overflow behavior for requests"]
+    O_INCOMPATIBLE_OVERFLOW_BEHAVIOR_FOR_REQUESTS,
+    #[CStr = "incompatible overflow behavior for responses"]
+    O_INCOMPATIBLE_OVERFLOW_BEHAVIOR_FOR_RESPONSES,
+    #[CStr = "incompatible fire and forget requests setting"]
+    O_INCOMPATIBLE_FIRE_AND_FORGET_REQUESTS,
+    #[CStr = "insufficient permissions"]
+    O_INSUFFICIENT_PERMISSIONS,
+    #[CStr = "internal failure"]
+    O_INTERNAL_FAILURE,
+    #[CStr = "is marked for destruction"]
+    O_IS_MARKED_FOR_DESTRUCTION,
+    #[CStr = "is frozen"]
+    O_IS_FROZEN,
+    #[CStr = "service in corrupted state"]
+    O_SERVICE_IN_CORRUPTED_STATE,
+    #[CStr = "signature verification failed"]
+    O_SIGNATURE_VERIFICATION_FAILED,
+    #[CStr = "already exists"]
+    C_ALREADY_EXISTS,
+    #[CStr = "internal failure"]
+    C_INTERNAL_FAILURE,
+    #[CStr = "is being created by another instance"]
+    C_IS_BEING_CREATED_BY_ANOTHER_INSTANCE,
+    #[CStr = "insufficient permissions"]
+    C_INSUFFICIENT_PERMISSIONS,
+    #[CStr = "hangs in creation"]
+    C_HANGS_IN_CREATION,
+    #[CStr = "service in corrupted state"]
+    C_SERVICE_IN_CORRUPTED_STATE,
+    #[CStr = "same service is created and removed repeatedly"]
+    SYSTEM_IN_FLUX,
+    #[CStr = "timed out"]
+    TIMED_OUT,
+}
+
+impl IntoCInt for RequestResponseOpenError {
+    fn into_c_int(self) -> c_int {
+        (match self {
+            RequestResponseOpenError::DoesNotExist => {
+                iox2_request_response_open_or_create_error_e::O_DOES_NOT_EXIST
+            }
+            RequestResponseOpenError::DoesNotSupportRequestedAmountOfActiveResponses => {
+                iox2_request_response_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_ACTIVE_RESPONSES
+            }
+            RequestResponseOpenError::DoesNotSupportRequestedAmountOfActiveRequests => {
+                iox2_request_response_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_ACTIVE_REQUESTS
+            }
+            RequestResponseOpenError::DoesNotSupportRequestedAmountOfBorrowedResponses => {
+                iox2_request_response_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_BORROWED_RESPONSES
+            }
+            RequestResponseOpenError::DoesNotSupportRequestedAmountOfBorrowedRequests => {
+                iox2_request_response_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_BORROWED_REQUESTS
+            }
+            RequestResponseOpenError::DoesNotSupportRequestedResponseBufferSize => {
+                iox2_request_response_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_RESPONSE_BUFFER_SIZE
+            }
+            RequestResponseOpenError::DoesNotSupportRequestedRequestBufferSize => {
+                iox2_request_response_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_REQUEST_BUFFER_SIZE
+            }
+            RequestResponseOpenError::DoesNotSupportRequestedAmountOfServers => {
+                iox2_request_response_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_SERVERS
+            }
+            RequestResponseOpenError::DoesNotSupportRequestedAmountOfClients => {
+                iox2_request_response_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_CLIENTS
+            }
+            RequestResponseOpenError::DoesNotSupportRequestedAmountOfNodes => {
+                iox2_request_response_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES
+            }
+            RequestResponseOpenError::ExceedsMaxNumberOfNodes => {
+                iox2_request_response_open_or_create_error_e::O_EXCEEDS_MAX_NUMBER_OF_NODES
+            }
+            RequestResponseOpenError::HangsInCreation => {
+                iox2_request_response_open_or_create_error_e::O_HANGS_IN_CREATION
+            }
+            RequestResponseOpenError::IncompatibleRequestType => {
+                iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_REQUEST_TYPE
+            }
+            RequestResponseOpenError::IncompatibleResponseType => {
+                iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_RESPONSE_TYPE
+            }
+            RequestResponseOpenError::IncompatibleErrorType => {
+                iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_ERROR_TYPE
+            }
+            RequestResponseOpenError::IncompatibleAttributes => {
+                iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_ATTRIBUTES
+            }
+            RequestResponseOpenError::IncompatibleMessagingPattern => {
+                iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_MESSAGING_PATTERN
+            }
+            RequestResponseOpenError::IncompatibleOverflowBehaviorForRequests => {
+                iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_OVERFLOW_BEHAVIOR_FOR_REQUESTS
+            }
+            RequestResponseOpenError::IncompatibleOverflowBehaviorForResponses => {
+                iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_OVERFLOW_BEHAVIOR_FOR_RESPONSES
+            }
+            RequestResponseOpenError::IncompatibleFireAndForgetRequests => {
+                iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_FIRE_AND_FORGET_REQUESTS
+            }
+            RequestResponseOpenError::InsufficientPermissions => {
+                iox2_request_response_open_or_create_error_e::O_INSUFFICIENT_PERMISSIONS
+            }
+            RequestResponseOpenError::InternalFailure => {
+                iox2_request_response_open_or_create_error_e::O_INTERNAL_FAILURE
+            }
+            RequestResponseOpenError::IsMarkedForDestruction => {
+                iox2_request_response_open_or_create_error_e::O_IS_MARKED_FOR_DESTRUCTION
+            }
+            RequestResponseOpenError::IsFrozen => {
+                iox2_request_response_open_or_create_error_e::O_IS_FROZEN
+            }
+            RequestResponseOpenError::ServiceInCorruptedState => {
+                iox2_request_response_open_or_create_error_e::O_SERVICE_IN_CORRUPTED_STATE
+            }
+            RequestResponseOpenError::SignatureVerificationFailed => {
+                iox2_request_response_open_or_create_error_e::O_SIGNATURE_VERIFICATION_FAILED
+            }
+        }) as c_int
+    }
+}
+
+impl IntoCInt for RequestResponseCreateError {
+    fn into_c_int(self) -> c_int {
+        (match self {
+            RequestResponseCreateError::AlreadyExists => {
+                iox2_request_response_open_or_create_error_e::C_ALREADY_EXISTS
+            }
+            RequestResponseCreateError::InternalFailure => {
+                iox2_request_response_open_or_create_error_e::C_INTERNAL_FAILURE
+            }
+            RequestResponseCreateError::IsBeingCreatedByAnotherInstance => {
+                iox2_request_response_open_or_create_error_e::C_IS_BEING_CREATED_BY_ANOTHER_INSTANCE
+            }
+            RequestResponseCreateError::InsufficientPermissions => {
+                iox2_request_response_open_or_create_error_e::C_INSUFFICIENT_PERMISSIONS
+            }
+            RequestResponseCreateError::HangsInCreation => {
+                iox2_request_response_open_or_create_error_e::C_HANGS_IN_CREATION
+            }
+            RequestResponseCreateError::ServiceInCorruptedState => {
+                iox2_request_response_open_or_create_error_e::C_SERVICE_IN_CORRUPTED_STATE
+            }
+        }) as c_int
+    }
+}
+
+impl IntoCInt for RequestResponseOpenOrCreateError {
+    fn into_c_int(self) -> c_int {
+        match self {
+            RequestResponseOpenOrCreateError::RequestResponseOpenError(error) => error.into_c_int(),
+            RequestResponseOpenOrCreateError::RequestResponseCreateError(error) => {
+                error.into_c_int()
+            }
+            RequestResponseOpenOrCreateError::SystemInFlux => {
+                iox2_request_response_open_or_create_error_e::SYSTEM_IN_FLUX as c_int
+            }
+            RequestResponseOpenOrCreateError::TimedOut => {
+                iox2_request_response_open_or_create_error_e::TIMED_OUT as c_int
+            }
+        }
+    }
+}
+
+// END type definition
+
+// BEGIN C API
+
+/// Returns a string literal describing the provided [`iox2_request_response_open_or_create_error_e`].
+///
+/// # Arguments
+///
+/// * `error` - The error value for which a description should be returned
+///
+/// # Returns
+///
+/// A pointer to a null-terminated string containing the error message.
+/// The string is stored in the .rodata section of the binary.
+///
+/// # Safety
+///
+/// The returned pointer must not be modified or freed and is valid as long as the program runs.
+#[no_mangle]
+pub unsafe extern "C" fn iox2_request_response_open_or_create_error_string(
+    error: iox2_request_response_open_or_create_error_e,
+) -> *const c_char {
+    error.as_const_cstr().as_ptr() as *const c_char
+}
+
+/// Sets the max number of active requests per client
+///
+/// # Arguments
+///
+/// * `service_builder_handle` - Must be a valid [`iox2_service_builder_request_response_h_ref`]
+///   obtained by [`iox2_service_builder_request_response`](crate::iox2_service_builder_request_response).
+/// * `value` - The value to set the max active requests to
+///
+/// # Safety
+///
+/// * `service_builder_handle` must be valid handles
+#[no_mangle]
+pub unsafe extern "C" fn iox2_service_builder_request_response_set_max_active_requests(
+    service_builder_handle: iox2_service_builder_request_response_h_ref,
+    value: c_size_t,
+) {
+    service_builder_handle.assert_non_null();
+
+    let service_builder_struct = unsafe { &mut *service_builder_handle.as_type() };
+
+    match service_builder_struct.service_type {
+        iox2_service_type_e::IPC => {
+            let service_builder =
+                ManuallyDrop::take(&mut service_builder_struct.value.as_mut().ipc);
+
+            let service_builder = ManuallyDrop::into_inner(service_builder.request_response);
+            service_builder_struct.set(ServiceBuilderUnion::new_ipc_request_response(
+                service_builder.max_active_requests(value),
+            ));
+        }
+        iox2_service_type_e::LOCAL => {
+            let service_builder =
+                ManuallyDrop::take(&mut service_builder_struct.value.as_mut().local);
+
+            let service_builder = ManuallyDrop::into_inner(service_builder.request_response);
+            service_builder_struct.set(ServiceBuilderUnion::new_local_request_response(
+                service_builder.max_active_requests(value),
+            ));
+        }
+    }
+}
+
+/// Sets the max clients for the builder
+///
+/// # Arguments
+///
+/// * `service_builder_handle` - Must be a valid [`iox2_service_builder_request_response_h_ref`]
+///   obtained by [`iox2_service_builder_request_response`](crate::iox2_service_builder_request_response).
+/// * `value` - The value to set the max clients to
+///
+/// # Safety
+///
+/// * `service_builder_handle` must be valid handles
+#[no_mangle]
+pub unsafe extern "C" fn iox2_service_builder_request_response_set_max_clients(
+    service_builder_handle: iox2_service_builder_request_response_h_ref,
+    value: c_size_t,
+) {
+    service_builder_handle.assert_non_null();
+
+    let service_builder_struct = unsafe { &mut *service_builder_handle.as_type() };
+
+    match service_builder_struct.service_type {
+        iox2_service_type_e::IPC => {
+            let service_builder =
+                ManuallyDrop::take(&mut service_builder_struct.value.as_mut().ipc);
+
+            let service_builder = ManuallyDrop::into_inner(service_builder.request_response);
+            service_builder_struct.set(ServiceBuilderUnion::new_ipc_request_response(
+                service_builder.max_clients(value),
+            ));
+        }
+        iox2_service_type_e::LOCAL => {
+            let service_builder =
+                ManuallyDrop::take(&mut service_builder_struct.value.as_mut().local);
+
+            let service_builder = ManuallyDrop::into_inner(service_builder.request_response);
+            service_builder_struct.set(ServiceBuilderUnion::new_local_request_response(
+                service_builder.max_clients(value),
+            ));
+        }
+    }
+}
+
+/// Sets the max servers for the builder
+///
+/// # Arguments
+///
+/// * `service_builder_handle` - Must be a valid [`iox2_service_builder_request_response_h_ref`]
+///   obtained by [`iox2_service_builder_request_response`](crate::iox2_service_builder_request_response).
+/// * `value` - The value to set the max servers to
+///
+/// # Safety
+///
+/// * `service_builder_handle` must be valid handles
+#[no_mangle]
+pub unsafe extern "C" fn iox2_service_builder_request_response_set_max_servers(
+    service_builder_handle: iox2_service_builder_request_response_h_ref,
+    value: c_size_t,
+) {
+    service_builder_handle.assert_non_null();
+
+    let service_builder_struct = unsafe { &mut *service_builder_handle.as_type() };
+
+    match service_builder_struct.service_type {
+        iox2_service_type_e::IPC => {
+            let service_builder =
+                ManuallyDrop::take(&mut service_builder_struct.value.as_mut().ipc);
+
+            let service_builder = ManuallyDrop::into_inner(service_builder.request_response);
+            service_builder_struct.set(ServiceBuilderUnion::new_ipc_request_response(
+                service_builder.max_servers(value),
+            ));
+        }
+        iox2_service_type_e::LOCAL => {
+            let service_builder =
+                ManuallyDrop::take(&mut service_builder_struct.value.as_mut().local);
+
+            let service_builder = ManuallyDrop::into_inner(service_builder.request_response);
+            service_builder_struct.set(ServiceBuilderUnion::new_local_request_response(
+                service_builder.max_servers(value),
+            ));
+        }
+    }
+}
+
+/// Opens a request-response service or creates the service if it does not exist and returns a port factory to create clients and servers.
+///
+/// # Arguments
+///
+/// * `service_builder_handle` - Must be a valid [`iox2_service_builder_request_response_h`]
+///   obtained by [`iox2_service_builder_request_response`](crate::iox2_service_builder_request_response)
+/// * `port_factory_struct_ptr` - Must be either a NULL pointer or a pointer to a valid
+///   [`iox2_port_factory_request_response_t`]. If it is a NULL pointer, the storage will be allocated on the heap.
+/// * `port_factory_handle_ptr` - An uninitialized or dangling [`iox2_port_factory_request_response_h`] handle which will be initialized by this function call.
+///
+/// Returns IOX2_OK on success, an [`iox2_request_response_open_or_create_error_e`] otherwise.
+///
+/// # Safety
+///
+/// * The `service_builder_handle` is invalid after the return of this function and leads to undefined behavior if used in another function call!
+/// * The corresponding [`iox2_service_builder_t`](crate::iox2_service_builder_t) can be re-used with
+///   a call to [`iox2_node_service_builder`](crate::iox2_node_service_builder)!
+#[no_mangle]
+pub unsafe extern "C" fn iox2_service_builder_request_response_open_or_create(
+    service_builder_handle: iox2_service_builder_request_response_h,
+    port_factory_struct_ptr: *mut iox2_port_factory_request_response_t,
+    port_factory_handle_ptr: *mut iox2_port_factory_request_response_h,
+) -> c_int {
+    iox2_service_builder_request_response_open_create_impl(
+        service_builder_handle,
+        port_factory_struct_ptr,
+        port_factory_handle_ptr,
+        |service_builder| service_builder.open_or_create(),
+        |service_builder| service_builder.open_or_create(),
+    )
+}
+
+/// Opens a request-response service and returns a port factory to create clients and servers.
+///
+/// # Arguments
+///
+/// * `service_builder_handle` - Must be a valid [`iox2_service_builder_request_response_h`]
+///   obtained by [`iox2_service_builder_request_response`](crate::iox2_service_builder_request_response)
+/// * `port_factory_struct_ptr` - Must be either a NULL pointer or a pointer to a valid
+///   [`iox2_port_factory_request_response_t`]. If it is a NULL pointer, the storage will be allocated on the heap.
+/// * `port_factory_handle_ptr` - An uninitialized or dangling [`iox2_port_factory_request_response_h`] handle which will be initialized by this function call.
+///
+/// Returns IOX2_OK on success, an [`iox2_request_response_open_or_create_error_e`] otherwise. Note, only the errors annotated with `O_` are relevant.
+///
+/// # Safety
+///
+/// * The `service_builder_handle` is invalid after the return of this function and leads to undefined behavior if used in another function call!
+/// * The corresponding [`iox2_service_builder_t`](crate::iox2_service_builder_t) can be re-used with
+///   a call to [`iox2_node_service_builder`](crate::iox2_node_service_builder)!
+#[no_mangle]
+pub unsafe extern "C" fn iox2_service_builder_request_response_open(
+    service_builder_handle: iox2_service_builder_request_response_h,
+    port_factory_struct_ptr: *mut iox2_port_factory_request_response_t,
+    port_factory_handle_ptr: *mut iox2_port_factory_request_response_h,
+) -> c_int {
+    iox2_service_builder_request_response_open_create_impl(
+        service_builder_handle,
+        port_factory_struct_ptr,
+        port_factory_handle_ptr,
+        |service_builder| service_builder.open(),
+        |service_builder| service_builder.open(),
+    )
+}
+
+/// Creates a request-response service and returns a port factory to create clients and servers.
+///
+/// # Arguments
+///
+/// * `service_builder_handle` - Must be a valid [`iox2_service_builder_request_response_h`]
+///   obtained by [`iox2_service_builder_request_response`](crate::iox2_service_builder_request_response)
+/// * `port_factory_struct_ptr` - Must be either a NULL pointer or a pointer to a valid
+///   [`iox2_port_factory_request_response_t`]. If it is a NULL pointer, the storage will be allocated on the heap.
+/// * `port_factory_handle_ptr` - An uninitialized or dangling [`iox2_port_factory_request_response_h`] handle which will be initialized by this function call.
+///
+/// Returns IOX2_OK on success, an [`iox2_request_response_open_or_create_error_e`] otherwise. Note, only the errors annotated with `C_` are relevant.
+///
+/// # Safety
+///
+/// * The `service_builder_handle` is invalid after the return of this function and leads to undefined behavior if used in another function call!
+/// * The corresponding [`iox2_service_builder_t`](crate::iox2_service_builder_t) can be re-used with
+///   a call to [`iox2_node_service_builder`](crate::iox2_node_service_builder)!
+#[no_mangle]
+pub unsafe extern "C" fn iox2_service_builder_request_response_create(
+    service_builder_handle: iox2_service_builder_request_response_h,
+    port_factory_struct_ptr: *mut iox2_port_factory_request_response_t,
+    port_factory_handle_ptr: *mut iox2_port_factory_request_response_h,
+) -> c_int {
+    iox2_service_builder_request_response_open_create_impl(
+        service_builder_handle,
+        port_factory_struct_ptr,
+        port_factory_handle_ptr,
+        |service_builder| service_builder.create(),
+        |service_builder| service_builder.create(),
+    )
+}
+
+#[allow(clippy::type_complexity)]
+unsafe fn iox2_service_builder_request_response_open_create_impl<E: IntoCInt>(
+    service_builder_handle: iox2_service_builder_request_response_h,
+    port_factory_struct_ptr: *mut iox2_port_factory_request_response_t,
+    port_factory_handle_ptr: *mut iox2_port_factory_request_response_h,
+    func_ipc: impl FnOnce(
+        Builder<RequestPayloadFfi, (), ResponsePayloadFfi, (), ipc::Service>,
+    ) -> Result<
+        PortFactory<ipc::Service, RequestPayloadFfi, (), ResponsePayloadFfi, ()>,
+        E,
+    >,
+    func_local: impl FnOnce(
+        Builder<RequestPayloadFfi, (), ResponsePayloadFfi, (), local::Service>,
+    ) -> Result<
+        PortFactory<local::Service, RequestPayloadFfi, (), ResponsePayloadFfi, ()>,
+        E,
+    >,
+) -> c_int {
+    service_builder_handle.assert_non_null();
+    debug_assert!(!port_factory_handle_ptr.is_null());
+
+    let init_port_factory_struct_ptr =
+        |port_factory_struct_ptr: *mut iox2_port_factory_request_response_t| {
+            let mut port_factory_struct_ptr = port_factory_struct_ptr;
+            fn no_op(_: *mut iox2_port_factory_request_response_t) {}
+            let mut deleter: fn(*mut iox2_port_factory_request_response_t) = no_op;
+            if port_factory_struct_ptr.is_null() {
+                port_factory_struct_ptr = iox2_port_factory_request_response_t::alloc();
+                deleter = iox2_port_factory_request_response_t::dealloc;
+            }
+            debug_assert!(!port_factory_struct_ptr.is_null());
+
+            (port_factory_struct_ptr, deleter)
+        };
+
+    let service_builder_struct = unsafe { &mut *service_builder_handle.as_type() };
+    let service_type = service_builder_struct.service_type;
+    let service_builder = service_builder_struct
+        .value
+        .as_option_mut()
+        .take()
+        .unwrap_or_else(|| {
+            panic!("Trying to use an invalid 'iox2_service_builder_request_response_h'!");
+        });
+    (service_builder_struct.deleter)(service_builder_struct);
+
+    match service_type {
+        iox2_service_type_e::IPC => {
+            let service_builder = ManuallyDrop::into_inner(service_builder.ipc);
+            let service_builder = ManuallyDrop::into_inner(service_builder.request_response);
+
+            match func_ipc(service_builder) {
+                Ok(port_factory) => {
+                    let (port_factory_struct_ptr, deleter) =
+                        init_port_factory_struct_ptr(port_factory_struct_ptr);
+                    (*port_factory_struct_ptr).init(
+                        service_type,
+                        PortFactoryRequestResponseUnion::new_ipc(port_factory),
+                        deleter,
+                    );
+                    *port_factory_handle_ptr = (*port_factory_struct_ptr).as_handle();
+                }
+                Err(error) => {
+                    return error.into_c_int();
+                }
+            }
+        }
+        iox2_service_type_e::LOCAL => {
+            let service_builder = ManuallyDrop::into_inner(service_builder.local);
+            let service_builder = ManuallyDrop::into_inner(service_builder.request_response);
+
+            match func_local(service_builder) {
+                Ok(port_factory) => {
+                    let (port_factory_struct_ptr, deleter) =
+                        init_port_factory_struct_ptr(port_factory_struct_ptr);
+                    (*port_factory_struct_ptr).init(
+                        service_type,
+                        PortFactoryRequestResponseUnion::new_local(port_factory),
+                        deleter,
+                    );
+                    *port_factory_handle_ptr = (*port_factory_struct_ptr).as_handle();
+                }
+                Err(error) => {
+                    return error.into_c_int();
+                }
+            }
+        }
+    }
+
+    IOX2_OK
+}
+
+// END C API
@@ -37,6 +37,9 @@ pub enum iox2_subscriber_receive_error_e {
     EXCEEDS_MAX_BORROWED_SAMPLES = IOX2_OK as isize + 1,
     FAILED_TO_ESTABLISH_CONNECTION,
     UNABLE_TO_MAP_PUBLISHERS_DATA_SEGMENT,
+    PAYLOAD_CHECKSUM_MISMATCH,
+    EXPIRED,
+    EXCEEDS_MAX_TOTAL_BORROWED_SAMPLES,
 }
 
 impl IntoCInt for SubscriberReceiveError {
@@ -51,6 +54,13 @@ impl IntoCInt for SubscriberReceiveError {
             SubscriberReceiveError::ConnectionFailure(
                 ConnectionFailure::UnableToMapPublishersDataSegment(_),
             ) => iox2_subscriber_receive_error_e::UNABLE_TO_MAP_PUBLISHERS_DATA_SEGMENT,
+            SubscriberReceiveError::PayloadChecksumMismatch => {
+                iox2_subscriber_receive_error_e::PAYLOAD_CHECKSUM_MISMATCH
+            }
+            SubscriberReceiveError::Expired => iox2_subscriber_receive_error_e::EXPIRED,
+            SubscriberReceiveError::ExceedsMaxTotalBorrowedSamples => {
+                iox2_subscriber_receive_error_e::EXCEEDS_MAX_TOTAL_BORROWED_SAMPLES
+            }
         }) as c_int
     }
 }
@@ -88,6 +88,7 @@ pub enum iox2_waitset_attachment_error_e {
     INSUFFICIENT_CAPACITY = IOX2_OK as isize + 1,
     ALREADY_ATTACHED,
     INTERNAL_ERROR,
+    INVALID_FILE_DESCRIPTOR,
 }
 
 impl IntoCInt for WaitSetAttachmentError {
@@ -102,6 +103,9 @@ impl IntoCInt for WaitSetAttachmentError {
             WaitSetAttachmentError::InternalError => {
                 iox2_waitset_attachment_error_e::INTERNAL_ERROR
             }
+            WaitSetAttachmentError::InvalidFileDescriptor => {
+                iox2_waitset_attachment_error_e::INVALID_FILE_DESCRIPTOR
+            }
         }) as c_int
     }
 }
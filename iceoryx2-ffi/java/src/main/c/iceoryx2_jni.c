@@ -0,0 +1,610 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#include "iox2/iceoryx2.h"
+
+#include "org_eclipse_iceoryx2_ActiveRequest.h"
+#include "org_eclipse_iceoryx2_Client.h"
+#include "org_eclipse_iceoryx2_Node.h"
+#include "org_eclipse_iceoryx2_PendingResponse.h"
+#include "org_eclipse_iceoryx2_PublishSubscribeService.h"
+#include "org_eclipse_iceoryx2_Publisher.h"
+#include "org_eclipse_iceoryx2_RequestMut.h"
+#include "org_eclipse_iceoryx2_RequestResponseService.h"
+#include "org_eclipse_iceoryx2_Response.h"
+#include "org_eclipse_iceoryx2_ResponseMut.h"
+#include "org_eclipse_iceoryx2_Sample.h"
+#include "org_eclipse_iceoryx2_SampleMut.h"
+#include "org_eclipse_iceoryx2_Server.h"
+#include "org_eclipse_iceoryx2_Subscriber.h"
+
+#include <jni.h>
+#include <stdalign.h>
+#include <stdint.h>
+#include <string.h>
+
+// The handles of the C binding are stored as jlong in the java objects. All structs are allocated
+// on the heap by the C binding (struct_ptr is always NULL), so that the handles remain valid when
+// the java object is moved by the garbage collector.
+
+static const char* const PAYLOAD_TYPE_NAME = "u8";
+
+static void throw_iceoryx2_exception(JNIEnv* env, const char* message, int error_code) {
+    jclass exception_class = (*env)->FindClass(env, "org/eclipse/iceoryx2/Iceoryx2Exception");
+    if (exception_class == NULL) {
+        return;
+    }
+
+    jmethodID constructor = (*env)->GetMethodID(env, exception_class, "<init>", "(Ljava/lang/String;I)V");
+    if (constructor == NULL) {
+        return;
+    }
+
+    jstring java_message = (*env)->NewStringUTF(env, message);
+    jobject exception = (*env)->NewObject(env, exception_class, constructor, java_message, (jint) error_code);
+    if (exception != NULL) {
+        (*env)->Throw(env, (jthrowable) exception);
+    }
+}
+
+static jlong to_jlong(void* handle) {
+    return (jlong) (intptr_t) handle;
+}
+
+static void* from_jlong(jlong handle) {
+    return (void*) (intptr_t) handle;
+}
+
+// Node
+
+JNIEXPORT jlong JNICALL Java_org_eclipse_iceoryx2_Node_nativeCreate(JNIEnv* env,
+                                                                     jclass clazz,
+                                                                     jstring name,
+                                                                     jint service_type) {
+    (void) clazz;
+
+    const char* name_value = (*env)->GetStringUTFChars(env, name, NULL);
+    if (name_value == NULL) {
+        return 0;
+    }
+
+    iox2_node_name_h node_name = NULL;
+    int ret_val = iox2_node_name_new(NULL, name_value, strlen(name_value), &node_name);
+    (*env)->ReleaseStringUTFChars(env, name, name_value);
+    if (ret_val != IOX2_OK) {
+        throw_iceoryx2_exception(env, iox2_semantic_string_error_string(ret_val), ret_val);
+        return 0;
+    }
+
+    iox2_node_builder_h node_builder = iox2_node_builder_new(NULL);
+    iox2_node_builder_set_name(&node_builder, iox2_cast_node_name_ptr(node_name));
+    iox2_node_name_drop(node_name);
+
+    iox2_node_h node = NULL;
+    ret_val = iox2_node_builder_create(node_builder, NULL, (iox2_service_type_e) service_type, &node);
+    if (ret_val != IOX2_OK) {
+        throw_iceoryx2_exception(env, "Unable to create node.", ret_val);
+        return 0;
+    }
+
+    return to_jlong(node);
+}
+
+JNIEXPORT void JNICALL Java_org_eclipse_iceoryx2_Node_nativeWait(JNIEnv* env,
+                                                                  jclass clazz,
+                                                                  jlong handle,
+                                                                  jlong seconds,
+                                                                  jint nanoseconds) {
+    (void) clazz;
+
+    iox2_node_h node = from_jlong(handle);
+    int ret_val = iox2_node_wait(&node, (uint64_t) seconds, (uint32_t) nanoseconds);
+    if (ret_val != IOX2_OK) {
+        throw_iceoryx2_exception(env, "Node wait was interrupted by a signal.", ret_val);
+    }
+}
+
+JNIEXPORT jlong JNICALL Java_org_eclipse_iceoryx2_Node_nativeOpenOrCreatePublishSubscribe(JNIEnv* env,
+                                                                                          jclass clazz,
+                                                                                          jlong handle,
+                                                                                          jstring service_name) {
+    (void) clazz;
+
+    const char* service_name_value = (*env)->GetStringUTFChars(env, service_name, NULL);
+    if (service_name_value == NULL) {
+        return 0;
+    }
+
+    iox2_service_name_h name = NULL;
+    int ret_val = iox2_service_name_new(NULL, service_name_value, strlen(service_name_value), &name);
+    (*env)->ReleaseStringUTFChars(env, service_name, service_name_value);
+    if (ret_val != IOX2_OK) {
+        throw_iceoryx2_exception(env, iox2_semantic_string_error_string(ret_val), ret_val);
+        return 0;
+    }
+
+    iox2_node_h node = from_jlong(handle);
+    iox2_service_builder_h service_builder = iox2_node_service_builder(&node, NULL, iox2_cast_service_name_ptr(name));
+    iox2_service_builder_pub_sub_h service_builder_pub_sub = iox2_service_builder_pub_sub(service_builder);
+    iox2_service_name_drop(name);
+
+    // the payload is a slice of bytes, the same type details as a rust [u8] payload
+    ret_val = iox2_service_builder_pub_sub_set_payload_type_details(&service_builder_pub_sub,
+                                                                    iox2_type_variant_e_DYNAMIC,
+                                                                    PAYLOAD_TYPE_NAME,
+                                                                    strlen(PAYLOAD_TYPE_NAME),
+                                                                    sizeof(uint8_t),
+                                                                    alignof(uint8_t));
+    if (ret_val != IOX2_OK) {
+        throw_iceoryx2_exception(env, "Unable to set the payload type details.", ret_val);
+        return 0;
+    }
+
+    iox2_port_factory_pub_sub_h service = NULL;
+    ret_val = iox2_service_builder_pub_sub_open_or_create(service_builder_pub_sub, NULL, &service);
+    if (ret_val != IOX2_OK) {
+        throw_iceoryx2_exception(env, iox2_pub_sub_open_or_create_error_string(ret_val), ret_val);
+        return 0;
+    }
+
+    return to_jlong(service);
+}
+
+JNIEXPORT jlong JNICALL Java_org_eclipse_iceoryx2_Node_nativeOpenOrCreateRequestResponse(JNIEnv* env,
+                                                                                          jclass clazz,
+                                                                                          jlong handle,
+                                                                                          jstring service_name) {
+    (void) clazz;
+
+    const char* service_name_value = (*env)->GetStringUTFChars(env, service_name, NULL);
+    if (service_name_value == NULL) {
+        return 0;
+    }
+
+    iox2_service_name_h name = NULL;
+    int ret_val = iox2_service_name_new(NULL, service_name_value, strlen(service_name_value), &name);
+    (*env)->ReleaseStringUTFChars(env, service_name, service_name_value);
+    if (ret_val != IOX2_OK) {
+        throw_iceoryx2_exception(env, iox2_semantic_string_error_string(ret_val), ret_val);
+        return 0;
+    }
+
+    // request and response payload are slices of bytes, the C binding defines the type details
+    iox2_node_h node = from_jlong(handle);
+    iox2_service_builder_h service_builder = iox2_node_service_builder(&node, NULL, iox2_cast_service_name_ptr(name));
+    iox2_service_builder_request_response_h service_builder_request_response =
+        iox2_service_builder_request_response(service_builder);
+    iox2_service_name_drop(name);
+
+    iox2_port_factory_request_response_h service = NULL;
+    ret_val = iox2_service_builder_request_response_open_or_create(service_builder_request_response, NULL, &service);
+    if (ret_val != IOX2_OK) {
+        throw_iceoryx2_exception(env, iox2_request_response_open_or_create_error_string(ret_val), ret_val);
+        return 0;
+    }
+
+    return to_jlong(service);
+}
+
+JNIEXPORT void JNICALL Java_org_eclipse_iceoryx2_Node_nativeDrop(JNIEnv* env, jclass clazz, jlong handle) {
+    (void) env;
+    (void) clazz;
+
+    iox2_node_drop(from_jlong(handle));
+}
+
+// PublishSubscribeService
+
+JNIEXPORT jlong JNICALL Java_org_eclipse_iceoryx2_PublishSubscribeService_nativeCreatePublisher(
+    JNIEnv* env, jclass clazz, jlong handle, jlong initial_max_slice_len) {
+    (void) clazz;
+
+    iox2_port_factory_pub_sub_h service = from_jlong(handle);
+    iox2_port_factory_publisher_builder_h publisher_builder = iox2_port_factory_pub_sub_publisher_builder(&service, NULL);
+    iox2_port_factory_publisher_builder_set_initial_max_slice_len(&publisher_builder, (size_t) initial_max_slice_len);
+
+    iox2_publisher_h publisher = NULL;
+    int ret_val = iox2_port_factory_publisher_builder_create(publisher_builder, NULL, &publisher);
+    if (ret_val != IOX2_OK) {
+        throw_iceoryx2_exception(env, iox2_publisher_create_error_string(ret_val), ret_val);
+        return 0;
+    }
+
+    return to_jlong(publisher);
+}
+
+JNIEXPORT jlong JNICALL Java_org_eclipse_iceoryx2_PublishSubscribeService_nativeCreateSubscriber(JNIEnv* env,
+                                                                                                 jclass clazz,
+                                                                                                 jlong handle) {
+    (void) clazz;
+
+    iox2_port_factory_pub_sub_h service = from_jlong(handle);
+    iox2_port_factory_subscriber_builder_h subscriber_builder =
+        iox2_port_factory_pub_sub_subscriber_builder(&service, NULL);
+
+    iox2_subscriber_h subscriber = NULL;
+    int ret_val = iox2_port_factory_subscriber_builder_create(subscriber_builder, NULL, &subscriber);
+    if (ret_val != IOX2_OK) {
+        throw_iceoryx2_exception(env, iox2_subscriber_create_error_string(ret_val), ret_val);
+        return 0;
+    }
+
+    return to_jlong(subscriber);
+}
+
+JNIEXPORT void JNICALL Java_org_eclipse_iceoryx2_PublishSubscribeService_nativeDrop(JNIEnv* env,
+                                                                                    jclass clazz,
+                                                                                    jlong handle) {
+    (void) env;
+    (void) clazz;
+
+    iox2_port_factory_pub_sub_drop(from_jlong(handle));
+}
+
+// RequestResponseService
+
+JNIEXPORT jlong JNICALL Java_org_eclipse_iceoryx2_RequestResponseService_nativeCreateClient(
+    JNIEnv* env, jclass clazz, jlong handle, jlong initial_max_slice_len) {
+    (void) clazz;
+
+    iox2_port_factory_request_response_h service = from_jlong(handle);
+    iox2_port_factory_client_builder_h client_builder = iox2_port_factory_request_response_client_builder(&service, NULL);
+    iox2_port_factory_client_builder_set_initial_max_slice_len(&client_builder, (size_t) initial_max_slice_len);
+
+    iox2_client_h client = NULL;
+    int ret_val = iox2_port_factory_client_builder_create(client_builder, NULL, &client);
+    if (ret_val != IOX2_OK) {
+        throw_iceoryx2_exception(env, iox2_client_create_error_string(ret_val), ret_val);
+        return 0;
+    }
+
+    return to_jlong(client);
+}
+
+JNIEXPORT jlong JNICALL Java_org_eclipse_iceoryx2_RequestResponseService_nativeCreateServer(
+    JNIEnv* env, jclass clazz, jlong handle, jlong initial_max_slice_len) {
+    (void) clazz;
+
+    iox2_port_factory_request_response_h service = from_jlong(handle);
+    iox2_port_factory_server_builder_h server_builder = iox2_port_factory_request_response_server_builder(&service, NULL);
+    iox2_port_factory_server_builder_set_initial_max_slice_len(&server_builder, (size_t) initial_max_slice_len);
+
+    iox2_server_h server = NULL;
+    int ret_val = iox2_port_factory_server_builder_create(server_builder, NULL, &server);
+    if (ret_val != IOX2_OK) {
+        throw_iceoryx2_exception(env, iox2_server_create_error_string(ret_val), ret_val);
+        return 0;
+    }
+
+    return to_jlong(server);
+}
+
+JNIEXPORT void JNICALL Java_org_eclipse_iceoryx2_RequestResponseService_nativeDrop(JNIEnv* env,
+                                                                                   jclass clazz,
+                                                                                   jlong handle) {
+    (void) env;
+    (void) clazz;
+
+    iox2_port_factory_request_response_drop(from_jlong(handle));
+}
+
+// Publisher
+
+JNIEXPORT jlong JNICALL Java_org_eclipse_iceoryx2_Publisher_nativeLoan(JNIEnv* env,
+                                                                       jclass clazz,
+                                                                       jlong handle,
+                                                                       jlong number_of_bytes) {
+    (void) clazz;
+
+    iox2_publisher_h publisher = from_jlong(handle);
+    iox2_sample_mut_h sample = NULL;
+    int ret_val = iox2_publisher_loan_slice_uninit(&publisher, NULL, &sample, (size_t) number_of_bytes);
+    if (ret_val != IOX2_OK) {
+        throw_iceoryx2_exception(env, iox2_publisher_loan_error_string(ret_val), ret_val);
+        return 0;
+    }
+
+    return to_jlong(sample);
+}
+
+JNIEXPORT void JNICALL Java_org_eclipse_iceoryx2_Publisher_nativeDrop(JNIEnv* env, jclass clazz, jlong handle) {
+    (void) env;
+    (void) clazz;
+
+    iox2_publisher_drop(from_jlong(handle));
+}
+
+// SampleMut
+
+JNIEXPORT jobject JNICALL Java_org_eclipse_iceoryx2_SampleMut_nativePayload(JNIEnv* env, jclass clazz, jlong handle) {
+    (void) clazz;
+
+    iox2_sample_mut_h sample = from_jlong(handle);
+    void* payload = NULL;
+    size_t number_of_bytes = 0;
+    iox2_sample_mut_payload_mut(&sample, &payload, &number_of_bytes);
+
+    return (*env)->NewDirectByteBuffer(env, payload, (jlong) number_of_bytes);
+}
+
+JNIEXPORT jlong JNICALL Java_org_eclipse_iceoryx2_SampleMut_nativeSend(JNIEnv* env, jclass clazz, jlong handle) {
+    (void) clazz;
+
+    size_t number_of_recipients = 0;
+    int ret_val = iox2_sample_mut_send(from_jlong(handle), &number_of_recipients);
+    if (ret_val != IOX2_OK) {
+        throw_iceoryx2_exception(env, iox2_publisher_send_error_string(ret_val), ret_val);
+        return 0;
+    }
+
+    return (jlong) number_of_recipients;
+}
+
+JNIEXPORT void JNICALL Java_org_eclipse_iceoryx2_SampleMut_nativeDrop(JNIEnv* env, jclass clazz, jlong handle) {
+    (void) env;
+    (void) clazz;
+
+    iox2_sample_mut_drop(from_jlong(handle));
+}
+
+// Subscriber
+
+JNIEXPORT jlong JNICALL Java_org_eclipse_iceoryx2_Subscriber_nativeReceive(JNIEnv* env, jclass clazz, jlong handle) {
+    (void) clazz;
+
+    iox2_subscriber_h subscriber = from_jlong(handle);
+    iox2_sample_h sample = NULL;
+    int ret_val = iox2_subscriber_receive(&subscriber, NULL, &sample);
+    if (ret_val != IOX2_OK) {
+        throw_iceoryx2_exception(env, iox2_subscriber_receive_error_string(ret_val), ret_val);
+        return 0;
+    }
+
+    return to_jlong(sample);
+}
+
+JNIEXPORT void JNICALL Java_org_eclipse_iceoryx2_Subscriber_nativeDrop(JNIEnv* env, jclass clazz, jlong handle) {
+    (void) env;
+    (void) clazz;
+
+    iox2_subscriber_drop(from_jlong(handle));
+}
+
+// Sample
+
+JNIEXPORT jobject JNICALL Java_org_eclipse_iceoryx2_Sample_nativePayload(JNIEnv* env, jclass clazz, jlong handle) {
+    (void) clazz;
+
+    iox2_sample_h sample = from_jlong(handle);
+    const void* payload = NULL;
+    size_t number_of_bytes = 0;
+    iox2_sample_payload(&sample, &payload, &number_of_bytes);
+
+    // the java side wraps the buffer with asReadOnlyBuffer(), the const cast is never written through
+    return (*env)->NewDirectByteBuffer(env, (void*) payload, (jlong) number_of_bytes);
+}
+
+JNIEXPORT void JNICALL Java_org_eclipse_iceoryx2_Sample_nativeDrop(JNIEnv* env, jclass clazz, jlong handle) {
+    (void) env;
+    (void) clazz;
+
+    iox2_sample_drop(from_jlong(handle));
+}
+
+// Client
+
+JNIEXPORT jlong JNICALL Java_org_eclipse_iceoryx2_Client_nativeLoan(JNIEnv* env,
+                                                                    jclass clazz,
+                                                                    jlong handle,
+                                                                    jlong number_of_bytes) {
+    (void) clazz;
+
+    iox2_client_h client = from_jlong(handle);
+    iox2_request_mut_h request = NULL;
+    int ret_val = iox2_client_loan_slice(&client, NULL, &request, (size_t) number_of_bytes);
+    if (ret_val != IOX2_OK) {
+        throw_iceoryx2_exception(env, iox2_loan_error_string(ret_val), ret_val);
+        return 0;
+    }
+
+    return to_jlong(request);
+}
+
+JNIEXPORT void JNICALL Java_org_eclipse_iceoryx2_Client_nativeDrop(JNIEnv* env, jclass clazz, jlong handle) {
+    (void) env;
+    (void) clazz;
+
+    iox2_client_drop(from_jlong(handle));
+}
+
+// RequestMut
+
+JNIEXPORT jobject JNICALL Java_org_eclipse_iceoryx2_RequestMut_nativePayload(JNIEnv* env, jclass clazz, jlong handle) {
+    (void) clazz;
+
+    iox2_request_mut_h request = from_jlong(handle);
+    void* payload = NULL;
+    size_t number_of_bytes = 0;
+    iox2_request_mut_payload_mut(&request, &payload, &number_of_bytes);
+
+    return (*env)->NewDirectByteBuffer(env, payload, (jlong) number_of_bytes);
+}
+
+JNIEXPORT jlong JNICALL Java_org_eclipse_iceoryx2_RequestMut_nativeSend(JNIEnv* env, jclass clazz, jlong handle) {
+    (void) clazz;
+
+    iox2_pending_response_h pending_response = NULL;
+    int ret_val = iox2_request_mut_send(from_jlong(handle), NULL, &pending_response);
+    if (ret_val != IOX2_OK) {
+        throw_iceoryx2_exception(env, iox2_request_send_error_string(ret_val), ret_val);
+        return 0;
+    }
+
+    return to_jlong(pending_response);
+}
+
+JNIEXPORT void JNICALL Java_org_eclipse_iceoryx2_RequestMut_nativeDrop(JNIEnv* env, jclass clazz, jlong handle) {
+    (void) env;
+    (void) clazz;
+
+    iox2_request_mut_drop(from_jlong(handle));
+}
+
+// PendingResponse
+
+JNIEXPORT jlong JNICALL Java_org_eclipse_iceoryx2_PendingResponse_nativeReceive(JNIEnv* env,
+                                                                                jclass clazz,
+                                                                                jlong handle) {
+    (void) clazz;
+
+    iox2_pending_response_h pending_response = from_jlong(handle);
+    iox2_response_h response = NULL;
+    int ret_val = iox2_pending_response_receive(&pending_response, NULL, &response);
+    if (ret_val != IOX2_OK) {
+        throw_iceoryx2_exception(env, iox2_response_receive_error_string(ret_val), ret_val);
+        return 0;
+    }
+
+    return to_jlong(response);
+}
+
+JNIEXPORT jlong JNICALL Java_org_eclipse_iceoryx2_PendingResponse_nativeNumberOfServerConnections(JNIEnv* env,
+                                                                                                  jclass clazz,
+                                                                                                  jlong handle) {
+    (void) env;
+    (void) clazz;
+
+    iox2_pending_response_h pending_response = from_jlong(handle);
+    return (jlong) iox2_pending_response_number_of_server_connections(&pending_response);
+}
+
+JNIEXPORT void JNICALL Java_org_eclipse_iceoryx2_PendingResponse_nativeDrop(JNIEnv* env, jclass clazz, jlong handle) {
+    (void) env;
+    (void) clazz;
+
+    iox2_pending_response_drop(from_jlong(handle));
+}
+
+// Response
+
+JNIEXPORT jobject JNICALL Java_org_eclipse_iceoryx2_Response_nativePayload(JNIEnv* env, jclass clazz, jlong handle) {
+    (void) clazz;
+
+    iox2_response_h response = from_jlong(handle);
+    const void* payload = NULL;
+    size_t number_of_bytes = 0;
+    iox2_response_payload(&response, &payload, &number_of_bytes);
+
+    // the java side wraps the buffer with asReadOnlyBuffer(), the const cast is never written through
+    return (*env)->NewDirectByteBuffer(env, (void*) payload, (jlong) number_of_bytes);
+}
+
+JNIEXPORT void JNICALL Java_org_eclipse_iceoryx2_Response_nativeDrop(JNIEnv* env, jclass clazz, jlong handle) {
+    (void) env;
+    (void) clazz;
+
+    iox2_response_drop(from_jlong(handle));
+}
+
+// Server
+
+JNIEXPORT jlong JNICALL Java_org_eclipse_iceoryx2_Server_nativeReceive(JNIEnv* env, jclass clazz, jlong handle) {
+    (void) clazz;
+
+    iox2_server_h server = from_jlong(handle);
+    iox2_active_request_h active_request = NULL;
+    int ret_val = iox2_server_receive(&server, NULL, &active_request);
+    if (ret_val != IOX2_OK) {
+        throw_iceoryx2_exception(env, iox2_server_receive_error_string(ret_val), ret_val);
+        return 0;
+    }
+
+    return to_jlong(active_request);
+}
+
+JNIEXPORT void JNICALL Java_org_eclipse_iceoryx2_Server_nativeDrop(JNIEnv* env, jclass clazz, jlong handle) {
+    (void) env;
+    (void) clazz;
+
+    iox2_server_drop(from_jlong(handle));
+}
+
+// ActiveRequest
+
+JNIEXPORT jobject JNICALL Java_org_eclipse_iceoryx2_ActiveRequest_nativePayload(JNIEnv* env,
+                                                                                jclass clazz,
+                                                                                jlong handle) {
+    (void) clazz;
+
+    iox2_active_request_h active_request = from_jlong(handle);
+    const void* payload = NULL;
+    size_t number_of_bytes = 0;
+    iox2_active_request_payload(&active_request, &payload, &number_of_bytes);
+
+    // the java side wraps the buffer with asReadOnlyBuffer(), the const cast is never written through
+    return (*env)->NewDirectByteBuffer(env, (void*) payload, (jlong) number_of_bytes);
+}
+
+JNIEXPORT jlong JNICALL Java_org_eclipse_iceoryx2_ActiveRequest_nativeLoan(JNIEnv* env,
+                                                                           jclass clazz,
+                                                                           jlong handle,
+                                                                           jlong number_of_bytes) {
+    (void) clazz;
+
+    iox2_active_request_h active_request = from_jlong(handle);
+    iox2_response_mut_h response = NULL;
+    int ret_val = iox2_active_request_loan_slice(&active_request, NULL, &response, (size_t) number_of_bytes);
+    if (ret_val != IOX2_OK) {
+        throw_iceoryx2_exception(env, iox2_loan_error_string(ret_val), ret_val);
+        return 0;
+    }
+
+    return to_jlong(response);
+}
+
+JNIEXPORT void JNICALL Java_org_eclipse_iceoryx2_ActiveRequest_nativeDrop(JNIEnv* env, jclass clazz, jlong handle) {
+    (void) env;
+    (void) clazz;
+
+    iox2_active_request_drop(from_jlong(handle));
+}
+
+// ResponseMut
+
+JNIEXPORT jobject JNICALL Java_org_eclipse_iceoryx2_ResponseMut_nativePayload(JNIEnv* env,
+                                                                              jclass clazz,
+                                                                              jlong handle) {
+    (void) clazz;
+
+    iox2_response_mut_h response = from_jlong(handle);
+    void* payload = NULL;
+    size_t number_of_bytes = 0;
+    iox2_response_mut_payload_mut(&response, &payload, &number_of_bytes);
+
+    return (*env)->NewDirectByteBuffer(env, payload, (jlong) number_of_bytes);
+}
+
+JNIEXPORT void JNICALL Java_org_eclipse_iceoryx2_ResponseMut_nativeSend(JNIEnv* env, jclass clazz, jlong handle) {
+    (void) clazz;
+
+    int ret_val = iox2_response_mut_send(from_jlong(handle));
+    if (ret_val != IOX2_OK) {
+        throw_iceoryx2_exception(env, iox2_response_send_error_string(ret_val), ret_val);
+    }
+}
+
+JNIEXPORT void JNICALL Java_org_eclipse_iceoryx2_ResponseMut_nativeDrop(JNIEnv* env, jclass clazz, jlong handle) {
+    (void) env;
+    (void) clazz;
+
+    iox2_response_mut_drop(from_jlong(handle));
+}
@@ -0,0 +1,69 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+package org.eclipse.iceoryx2;
+
+import java.nio.ByteBuffer;
+
+/**
+ * A received request. Responses are loaned and sent via the active request. Closing it returns
+ * the memory to the client and signals that no further responses will be sent.
+ */
+public final class ActiveRequest implements AutoCloseable {
+    private long handle;
+    private ByteBuffer payload;
+
+    ActiveRequest(long handle) {
+        this.handle = handle;
+    }
+
+    /**
+     * Returns a read-only direct {@link ByteBuffer} that views the payload in the shared memory.
+     * It must not be accessed after the request was closed.
+     */
+    public ByteBuffer payload() {
+        if (payload == null) {
+            payload = nativePayload(handle()).asReadOnlyBuffer();
+        }
+        return payload;
+    }
+
+    /**
+     * Loans a zero initialized response with the given number of bytes directly from the shared
+     * memory. The payload can be written via {@link ResponseMut#payload()} without any copy.
+     */
+    public ResponseMut loan(int numberOfBytes) {
+        return new ResponseMut(nativeLoan(handle(), numberOfBytes));
+    }
+
+    @Override
+    public void close() {
+        if (handle != 0) {
+            nativeDrop(handle);
+            handle = 0;
+            payload = null;
+        }
+    }
+
+    private long handle() {
+        if (handle == 0) {
+            throw new IllegalStateException("The request was already closed.");
+        }
+        return handle;
+    }
+
+    private static native ByteBuffer nativePayload(long handle);
+
+    private static native long nativeLoan(long handle, long numberOfBytes);
+
+    private static native void nativeDrop(long handle);
+}
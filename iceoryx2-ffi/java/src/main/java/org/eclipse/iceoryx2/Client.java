@@ -0,0 +1,49 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+package org.eclipse.iceoryx2;
+
+/** Sends byte slice requests to all connected servers. */
+public final class Client implements AutoCloseable {
+    private long handle;
+
+    Client(long handle) {
+        this.handle = handle;
+    }
+
+    /**
+     * Loans a zero initialized request with the given number of bytes directly from the shared
+     * memory. The payload can be written via {@link RequestMut#payload()} without any copy.
+     */
+    public RequestMut loan(int numberOfBytes) {
+        return new RequestMut(nativeLoan(handle(), numberOfBytes));
+    }
+
+    @Override
+    public void close() {
+        if (handle != 0) {
+            nativeDrop(handle);
+            handle = 0;
+        }
+    }
+
+    private long handle() {
+        if (handle == 0) {
+            throw new IllegalStateException("The client was already closed.");
+        }
+        return handle;
+    }
+
+    private static native long nativeLoan(long handle, long numberOfBytes);
+
+    private static native void nativeDrop(long handle);
+}
@@ -0,0 +1,28 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+package org.eclipse.iceoryx2;
+
+/** Thrown when a call into the iceoryx2 C binding fails. */
+public class Iceoryx2Exception extends RuntimeException {
+    private final int errorCode;
+
+    public Iceoryx2Exception(String message, int errorCode) {
+        super(message);
+        this.errorCode = errorCode;
+    }
+
+    /** Returns the error code of the underlying C function, see the corresponding iox2_*_e enum. */
+    public int errorCode() {
+        return errorCode;
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+package org.eclipse.iceoryx2;
+
+import java.time.Duration;
+
+/**
+ * The central entry point of iceoryx2. Owns all resources of the process and creates or opens
+ * services.
+ *
+ * <p>A node must be closed after all services, ports and samples created from it were closed.
+ */
+public final class Node implements AutoCloseable {
+    static {
+        NativeLibrary.load();
+    }
+
+    private long handle;
+
+    private Node(long handle) {
+        this.handle = handle;
+    }
+
+    /** Creates a new node with the provided name for the given {@link ServiceType}. */
+    public static Node create(String name, ServiceType serviceType) {
+        return new Node(nativeCreate(name, serviceType.value));
+    }
+
+    /**
+     * Waits for the given cycle time. Throws an {@link Iceoryx2Exception} when the process
+     * received a termination request or an interrupt signal.
+     */
+    public void waitCycle(Duration cycleTime) {
+        nativeWait(handle(), cycleTime.getSeconds(), cycleTime.getNano());
+    }
+
+    /**
+     * Opens the publish-subscribe service with the given name or creates it when it does not
+     * exist. The payload is a slice of bytes.
+     */
+    public PublishSubscribeService openOrCreatePublishSubscribe(String serviceName) {
+        return new PublishSubscribeService(nativeOpenOrCreatePublishSubscribe(handle(), serviceName));
+    }
+
+    /**
+     * Opens the request-response service with the given name or creates it when it does not
+     * exist. The request and the response payload are slices of bytes.
+     */
+    public RequestResponseService openOrCreateRequestResponse(String serviceName) {
+        return new RequestResponseService(nativeOpenOrCreateRequestResponse(handle(), serviceName));
+    }
+
+    @Override
+    public void close() {
+        if (handle != 0) {
+            nativeDrop(handle);
+            handle = 0;
+        }
+    }
+
+    private long handle() {
+        if (handle == 0) {
+            throw new IllegalStateException("The node was already closed.");
+        }
+        return handle;
+    }
+
+    private static native long nativeCreate(String name, int serviceType);
+
+    private static native void nativeWait(long handle, long seconds, int nanoseconds);
+
+    private static native long nativeOpenOrCreatePublishSubscribe(long handle, String serviceName);
+
+    private static native long nativeOpenOrCreateRequestResponse(long handle, String serviceName);
+
+    private static native void nativeDrop(long handle);
+}
@@ -0,0 +1,57 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+package org.eclipse.iceoryx2;
+
+/**
+ * A sent request that waits for responses. Closing it ends the request, all further responses
+ * are discarded.
+ */
+public final class PendingResponse implements AutoCloseable {
+    private long handle;
+
+    PendingResponse(long handle) {
+        this.handle = handle;
+    }
+
+    /** Receives the next response or returns {@code null} when no response is available. */
+    public Response receive() {
+        long responseHandle = nativeReceive(handle());
+        return responseHandle == 0 ? null : new Response(responseHandle);
+    }
+
+    /** Returns the number of servers that received the request. */
+    public long numberOfServerConnections() {
+        return nativeNumberOfServerConnections(handle());
+    }
+
+    @Override
+    public void close() {
+        if (handle != 0) {
+            nativeDrop(handle);
+            handle = 0;
+        }
+    }
+
+    private long handle() {
+        if (handle == 0) {
+            throw new IllegalStateException("The pending response was already closed.");
+        }
+        return handle;
+    }
+
+    private static native long nativeReceive(long handle);
+
+    private static native long nativeNumberOfServerConnections(long handle);
+
+    private static native void nativeDrop(long handle);
+}
@@ -0,0 +1,56 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+package org.eclipse.iceoryx2;
+
+/** A publish-subscribe service with a byte slice payload. Creates publishers and subscribers. */
+public final class PublishSubscribeService implements AutoCloseable {
+    private long handle;
+
+    PublishSubscribeService(long handle) {
+        this.handle = handle;
+    }
+
+    /**
+     * Creates a new publisher. The initial max slice length defines how many bytes can be loaned
+     * at most with {@link Publisher#loan(int)}.
+     */
+    public Publisher createPublisher(int initialMaxSliceLength) {
+        return new Publisher(nativeCreatePublisher(handle(), initialMaxSliceLength));
+    }
+
+    /** Creates a new subscriber. */
+    public Subscriber createSubscriber() {
+        return new Subscriber(nativeCreateSubscriber(handle()));
+    }
+
+    @Override
+    public void close() {
+        if (handle != 0) {
+            nativeDrop(handle);
+            handle = 0;
+        }
+    }
+
+    private long handle() {
+        if (handle == 0) {
+            throw new IllegalStateException("The service was already closed.");
+        }
+        return handle;
+    }
+
+    private static native long nativeCreatePublisher(long handle, long initialMaxSliceLength);
+
+    private static native long nativeCreateSubscriber(long handle);
+
+    private static native void nativeDrop(long handle);
+}
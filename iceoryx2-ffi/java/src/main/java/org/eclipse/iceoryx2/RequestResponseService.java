@@ -0,0 +1,62 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+package org.eclipse.iceoryx2;
+
+/**
+ * A request-response service with byte slice request and response payloads. Creates clients and
+ * servers.
+ */
+public final class RequestResponseService implements AutoCloseable {
+    private long handle;
+
+    RequestResponseService(long handle) {
+        this.handle = handle;
+    }
+
+    /**
+     * Creates a new client. The initial max slice length defines how many bytes can be loaned at
+     * most with {@link Client#loan(int)}.
+     */
+    public Client createClient(int initialMaxSliceLength) {
+        return new Client(nativeCreateClient(handle(), initialMaxSliceLength));
+    }
+
+    /**
+     * Creates a new server. The initial max slice length defines how many bytes can be loaned at
+     * most with {@link ActiveRequest#loan(int)}.
+     */
+    public Server createServer(int initialMaxSliceLength) {
+        return new Server(nativeCreateServer(handle(), initialMaxSliceLength));
+    }
+
+    @Override
+    public void close() {
+        if (handle != 0) {
+            nativeDrop(handle);
+            handle = 0;
+        }
+    }
+
+    private long handle() {
+        if (handle == 0) {
+            throw new IllegalStateException("The service was already closed.");
+        }
+        return handle;
+    }
+
+    private static native long nativeCreateClient(long handle, long initialMaxSliceLength);
+
+    private static native long nativeCreateServer(long handle, long initialMaxSliceLength);
+
+    private static native void nativeDrop(long handle);
+}
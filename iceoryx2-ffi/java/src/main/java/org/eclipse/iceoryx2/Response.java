@@ -0,0 +1,52 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+package org.eclipse.iceoryx2;
+
+import java.nio.ByteBuffer;
+
+/** A received response. Closing it returns the memory to the server. */
+public final class Response implements AutoCloseable {
+    private long handle;
+    private ByteBuffer payload;
+
+    Response(long handle) {
+        this.handle = handle;
+    }
+
+    /**
+     * Returns a read-only direct {@link ByteBuffer} that views the payload in the shared memory.
+     * It must not be accessed after the response was closed.
+     */
+    public ByteBuffer payload() {
+        if (handle == 0) {
+            throw new IllegalStateException("The response was already closed.");
+        }
+        if (payload == null) {
+            payload = nativePayload(handle).asReadOnlyBuffer();
+        }
+        return payload;
+    }
+
+    @Override
+    public void close() {
+        if (handle != 0) {
+            nativeDrop(handle);
+            handle = 0;
+            payload = null;
+        }
+    }
+
+    private static native ByteBuffer nativePayload(long handle);
+
+    private static native void nativeDrop(long handle);
+}
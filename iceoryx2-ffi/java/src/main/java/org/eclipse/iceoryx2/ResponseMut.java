@@ -0,0 +1,69 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+package org.eclipse.iceoryx2;
+
+import java.nio.ByteBuffer;
+
+/**
+ * A loaned response that is not yet sent. Closing it without sending returns the memory to the
+ * server.
+ */
+public final class ResponseMut implements AutoCloseable {
+    private long handle;
+    private ByteBuffer payload;
+
+    ResponseMut(long handle) {
+        this.handle = handle;
+    }
+
+    /**
+     * Returns a direct {@link ByteBuffer} that views the payload in the shared memory. It must
+     * not be accessed after the response was sent or closed.
+     */
+    public ByteBuffer payload() {
+        if (payload == null) {
+            payload = nativePayload(handle());
+        }
+        return payload;
+    }
+
+    /** Sends the response to the client that sent the corresponding request. */
+    public void send() {
+        long responseHandle = handle();
+        handle = 0;
+        payload = null;
+        nativeSend(responseHandle);
+    }
+
+    @Override
+    public void close() {
+        if (handle != 0) {
+            nativeDrop(handle);
+            handle = 0;
+            payload = null;
+        }
+    }
+
+    private long handle() {
+        if (handle == 0) {
+            throw new IllegalStateException("The response was already sent or closed.");
+        }
+        return handle;
+    }
+
+    private static native ByteBuffer nativePayload(long handle);
+
+    private static native void nativeSend(long handle);
+
+    private static native void nativeDrop(long handle);
+}
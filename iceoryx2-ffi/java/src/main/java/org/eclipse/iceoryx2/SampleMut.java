@@ -0,0 +1,69 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+package org.eclipse.iceoryx2;
+
+import java.nio.ByteBuffer;
+
+/**
+ * A loaned sample that is not yet sent. Closing it without sending returns the memory to the
+ * publisher.
+ */
+public final class SampleMut implements AutoCloseable {
+    private long handle;
+    private ByteBuffer payload;
+
+    SampleMut(long handle) {
+        this.handle = handle;
+    }
+
+    /**
+     * Returns a direct {@link ByteBuffer} that views the payload in the shared memory. It must
+     * not be accessed after the sample was sent or closed.
+     */
+    public ByteBuffer payload() {
+        if (payload == null) {
+            payload = nativePayload(handle());
+        }
+        return payload;
+    }
+
+    /** Sends the sample and returns the number of subscribers that received it. */
+    public long send() {
+        long numberOfRecipients = nativeSend(handle());
+        handle = 0;
+        payload = null;
+        return numberOfRecipients;
+    }
+
+    @Override
+    public void close() {
+        if (handle != 0) {
+            nativeDrop(handle);
+            handle = 0;
+            payload = null;
+        }
+    }
+
+    private long handle() {
+        if (handle == 0) {
+            throw new IllegalStateException("The sample was already sent or closed.");
+        }
+        return handle;
+    }
+
+    private static native ByteBuffer nativePayload(long handle);
+
+    private static native long nativeSend(long handle);
+
+    private static native void nativeDrop(long handle);
+}
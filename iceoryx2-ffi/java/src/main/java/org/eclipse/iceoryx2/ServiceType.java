@@ -0,0 +1,27 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+package org.eclipse.iceoryx2;
+
+/** Defines the communication mechanism of a {@link Node} and all of its services. */
+public enum ServiceType {
+    /** Communication is restricted to the current process. */
+    LOCAL(0),
+    /** Communication between processes. */
+    IPC(1);
+
+    final int value;
+
+    ServiceType(int value) {
+        this.value = value;
+    }
+}
@@ -0,0 +1,47 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+package org.eclipse.iceoryx2;
+
+/** Receives byte slices from all connected publishers. */
+public final class Subscriber implements AutoCloseable {
+    private long handle;
+
+    Subscriber(long handle) {
+        this.handle = handle;
+    }
+
+    /** Receives the next sample or returns {@code null} when no sample is available. */
+    public Sample receive() {
+        long sampleHandle = nativeReceive(handle());
+        return sampleHandle == 0 ? null : new Sample(sampleHandle);
+    }
+
+    @Override
+    public void close() {
+        if (handle != 0) {
+            nativeDrop(handle);
+            handle = 0;
+        }
+    }
+
+    private long handle() {
+        if (handle == 0) {
+            throw new IllegalStateException("The subscriber was already closed.");
+        }
+        return handle;
+    }
+
+    private static native long nativeReceive(long handle);
+
+    private static native void nativeDrop(long handle);
+}
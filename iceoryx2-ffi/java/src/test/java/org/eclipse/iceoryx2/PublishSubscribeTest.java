@@ -0,0 +1,88 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+package org.eclipse.iceoryx2;
+
+/**
+ * Sends a sample from a publisher to a subscriber, for every {@link ServiceType}. Exits with a
+ * non-zero code when a check fails.
+ */
+public final class PublishSubscribeTest {
+    private PublishSubscribeTest() {}
+
+    public static void main(String[] args) {
+        for (ServiceType serviceType : ServiceType.values()) {
+            sampleIsReceivedBySubscriber(serviceType);
+            closedPortsCannotBeUsed(serviceType);
+        }
+        System.out.println("PublishSubscribeTest passed");
+    }
+
+    private static void sampleIsReceivedBySubscriber(ServiceType serviceType) {
+        final long payloadValue = 0x0fed_cba9_8765_4321L;
+
+        try (Node node = Node.create("publish-subscribe-test", serviceType);
+                PublishSubscribeService service =
+                        node.openOrCreatePublishSubscribe(uniqueServiceName(serviceType));
+                Subscriber subscriber = service.createSubscriber();
+                Publisher publisher = service.createPublisher(Long.BYTES)) {
+            check(subscriber.receive() == null, "a subscriber without publisher receives nothing");
+
+            SampleMut sample = publisher.loan(Long.BYTES);
+            check(sample.payload().capacity() == Long.BYTES, "the sample has the loaned size");
+            sample.payload().putLong(0, payloadValue);
+            check(sample.send() == 1, "one subscriber received the sample");
+
+            try (Sample receivedSample = subscriber.receive()) {
+                check(receivedSample != null, "the subscriber receives the sample");
+                check(receivedSample.payload().isReadOnly(), "the sample payload is read-only");
+                check(receivedSample.payload().getLong(0) == payloadValue, "the sample payload is received");
+            }
+
+            check(subscriber.receive() == null, "the publisher sent exactly one sample");
+        }
+    }
+
+    private static void closedPortsCannotBeUsed(ServiceType serviceType) {
+        try (Node node = Node.create("publish-subscribe-test", serviceType);
+                PublishSubscribeService service =
+                        node.openOrCreatePublishSubscribe(uniqueServiceName(serviceType));
+                Publisher publisher = service.createPublisher(Long.BYTES)) {
+            SampleMut sample = publisher.loan(Long.BYTES);
+            sample.close();
+            checkThrowsIllegalState(sample::payload, "a closed sample has no payload");
+
+            Subscriber subscriber = service.createSubscriber();
+            subscriber.close();
+            checkThrowsIllegalState(subscriber::receive, "a closed subscriber cannot receive");
+        }
+    }
+
+    private static String uniqueServiceName(ServiceType serviceType) {
+        return "iox2-java-tests/publish-subscribe/" + serviceType + "/" + System.nanoTime();
+    }
+
+    private static void checkThrowsIllegalState(Runnable action, String description) {
+        try {
+            action.run();
+        } catch (IllegalStateException e) {
+            return;
+        }
+        throw new AssertionError("check failed: " + description);
+    }
+
+    private static void check(boolean condition, String description) {
+        if (!condition) {
+            throw new AssertionError("check failed: " + description);
+        }
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+package org.eclipse.iceoryx2;
+
+/**
+ * Sends a request from a client to a server and the response back, for every {@link ServiceType}.
+ * Exits with a non-zero code when a check fails.
+ */
+public final class RequestResponseTest {
+    private RequestResponseTest() {}
+
+    public static void main(String[] args) {
+        for (ServiceType serviceType : ServiceType.values()) {
+            requestIsAnsweredByServer(serviceType);
+            closedPortsCannotBeUsed(serviceType);
+        }
+        System.out.println("RequestResponseTest passed");
+    }
+
+    private static void requestIsAnsweredByServer(ServiceType serviceType) {
+        final long requestValue = 0x1234_5678_9abc_def0L;
+        final long responseValue = requestValue + 1;
+
+        try (Node node = Node.create("request-response-test", serviceType);
+                RequestResponseService service =
+                        node.openOrCreateRequestResponse(uniqueServiceName(serviceType));
+                Client client = service.createClient(Long.BYTES);
+                Server server = service.createServer(Long.BYTES)) {
+            check(server.receive() == null, "a server without requests receives nothing");
+
+            RequestMut request = client.loan(Long.BYTES);
+            check(request.payload().capacity() == Long.BYTES, "the request has the loaned size");
+            request.payload().putLong(0, requestValue);
+
+            try (PendingResponse pendingResponse = request.send()) {
+                check(pendingResponse.numberOfServerConnections() == 1, "one server received the request");
+
+                try (ActiveRequest activeRequest = server.receive()) {
+                    check(activeRequest != null, "the server receives the request");
+                    check(activeRequest.payload().isReadOnly(), "the request payload is read-only");
+                    check(activeRequest.payload().getLong(0) == requestValue, "the request payload is received");
+
+                    ResponseMut response = activeRequest.loan(Long.BYTES);
+                    response.payload().putLong(0, responseValue);
+                    response.send();
+                }
+
+                try (Response response = pendingResponse.receive()) {
+                    check(response != null, "the client receives the response");
+                    check(response.payload().isReadOnly(), "the response payload is read-only");
+                    check(response.payload().getLong(0) == responseValue, "the response payload is received");
+                }
+
+                check(pendingResponse.receive() == null, "the server sent exactly one response");
+            }
+        }
+    }
+
+    private static void closedPortsCannotBeUsed(ServiceType serviceType) {
+        try (Node node = Node.create("request-response-test", serviceType);
+                RequestResponseService service =
+                        node.openOrCreateRequestResponse(uniqueServiceName(serviceType));
+                Client client = service.createClient(Long.BYTES)) {
+            RequestMut request = client.loan(Long.BYTES);
+            request.close();
+            checkThrowsIllegalState(request::payload, "a closed request has no payload");
+
+            Server server = service.createServer(Long.BYTES);
+            server.close();
+            checkThrowsIllegalState(server::receive, "a closed server cannot receive");
+        }
+    }
+
+    private static String uniqueServiceName(ServiceType serviceType) {
+        return "iox2-java-tests/request-response/" + serviceType + "/" + System.nanoTime();
+    }
+
+    private static void checkThrowsIllegalState(Runnable action, String description) {
+        try {
+            action.run();
+        } catch (IllegalStateException e) {
+            return;
+        }
+        throw new AssertionError("check failed: " + description);
+    }
+
+    private static void check(boolean condition, String description) {
+        if (!condition) {
+            throw new AssertionError("check failed: " + description);
+        }
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_log::{fail, trace};
+use iceoryx2_bb_posix::file::{AccessMode, FileBuilder, FileOpenError};
+use iceoryx2_bb_system_types::file_path::FilePath;
+use serde::{Deserialize, Serialize};
+
+use super::service_description::{ServiceDescription, TypeDescription};
+
+/// Failures occurring while loading a [`Mapping`] with [`Mapping::from_file()`] or
+/// [`Mapping::from_toml()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum MappingCreationError {
+    /// The mapping file could not be read.
+    FailedToReadMappingFileContents,
+    /// Parts of the mapping could not be deserialized. Indicates some kind of syntax error.
+    UnableToDeserializeContents,
+    /// Insufficient permissions to open the mapping file.
+    InsufficientPermissions,
+    /// The provided mapping file does not exist
+    MappingFileDoesNotExist,
+    /// Since the mapping file could not be opened
+    UnableToOpenMappingFile,
+}
+
+impl core::fmt::Display for MappingCreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "MappingCreationError::{:?}", self)
+    }
+}
+
+impl core::error::Error for MappingCreationError {}
+
+/// Defines in which direction the data of a [`TopicMapping`] flows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Direction {
+    /// Samples of the iceoryx2 service are published to the iceoryx classic subscribers.
+    ToClassic,
+    /// Samples of the iceoryx classic publisher are published on the iceoryx2 service.
+    FromClassic,
+}
+
+fn default_max_number_of_elements() -> usize {
+    1
+}
+
+/// Maps an iceoryx classic topic to an iceoryx2 publish-subscribe service.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct TopicMapping {
+    /// The service description of the iceoryx classic topic
+    #[serde(flatten)]
+    pub classic: ServiceDescription,
+    /// The name of the iceoryx2 service. When it is not set, the name is derived with
+    /// [`ServiceDescription::to_service_name()`].
+    #[serde(default)]
+    pub service_name: Option<String>,
+    /// The payload type of the topic
+    #[serde(rename = "type")]
+    pub payload_type: TypeDescription,
+    /// The maximum number of elements of a slice payload, see [`TypeDescription::is_slice`]
+    #[serde(default = "default_max_number_of_elements")]
+    pub max_number_of_elements: usize,
+    /// In which direction the data is forwarded
+    pub direction: Direction,
+}
+
+impl TopicMapping {
+    /// Returns the maximum number of payload bytes of a sample of the topic.
+    pub fn max_payload_size(&self) -> usize {
+        match self.payload_type.is_slice {
+            true => self.payload_type.size * self.max_number_of_elements,
+            false => self.payload_type.size,
+        }
+    }
+}
+
+/// The mapping between iceoryx classic topics and iceoryx2 services, usually loaded from a
+/// TOML file.
+///
+/// ```toml
+/// [[topic]]
+/// service = "Radar"
+/// instance = "FrontLeft"
+/// event = "Object"
+/// direction = "from-classic"
+///
+/// [topic.type]
+/// type-name = "radar::Object"
+/// size = 24
+/// alignment = 8
+///
+/// [[topic]]
+/// service = "Radar"
+/// instance = "FrontLeft"
+/// event = "Config"
+/// service-name = "Radar/Config"
+/// direction = "to-classic"
+/// max-number-of-elements = 64
+///
+/// [topic.type]
+/// type-name = "u8"
+/// size = 1
+/// alignment = 1
+/// is-slice = true
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Mapping {
+    /// The iceoryx classic topics mapped to publish-subscribe services
+    #[serde(default)]
+    pub topic: Vec<TopicMapping>,
+}
+
+impl Mapping {
+    /// Deserializes a [`Mapping`] from a TOML string.
+    pub fn from_toml(contents: &str) -> Result<Mapping, MappingCreationError> {
+        match toml::from_str(contents) {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                fail!(from "Mapping::from_toml()", with MappingCreationError::UnableToDeserializeContents,
+                    "Failed to create mapping since the contents could not be deserialized ({}).", e);
+            }
+        }
+    }
+
+    /// Loads a [`Mapping`] from a TOML file.
+    pub fn from_file(mapping_file: &FilePath) -> Result<Mapping, MappingCreationError> {
+        let msg = "Failed to create mapping";
+        let origin = "Mapping::from_file()";
+
+        let file = match FileBuilder::new(mapping_file).open_existing(AccessMode::Read) {
+            Ok(file) => file,
+            Err(FileOpenError::InsufficientPermissions) => {
+                fail!(from origin, with MappingCreationError::InsufficientPermissions,
+                    "{} since the mapping file \"{}\" could not be opened due to insufficient permissions.",
+                    msg, mapping_file);
+            }
+            Err(FileOpenError::FileDoesNotExist) => {
+                fail!(from origin, with MappingCreationError::MappingFileDoesNotExist,
+                    "{} since the mapping file \"{}\" does not exist.", msg, mapping_file);
+            }
+            Err(e) => {
+                fail!(from origin, with MappingCreationError::UnableToOpenMappingFile,
+                    "{} since the mapping file \"{}\" could not be open due to an internal error ({:?}).",
+                    msg, mapping_file, e);
+            }
+        };
+
+        let mut contents = String::new();
+        fail!(from origin, when file.read_to_string(&mut contents),
+            with MappingCreationError::FailedToReadMappingFileContents,
+            "{} since the mapping file contents could not be read.", msg);
+
+        let mapping = Self::from_toml(&contents)?;
+        trace!(from mapping, "Loaded.");
+        Ok(mapping)
+    }
+}
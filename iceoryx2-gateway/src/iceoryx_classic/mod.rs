@@ -0,0 +1,406 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A mapping layer between iceoryx classic (Eclipse iceoryx 2.x, C++) topics and iceoryx2
+//! services, so that a fleet of applications can be migrated incrementally.
+//!
+//!  * An iceoryx classic topic, identified by its [`ServiceDescription`], is mapped to a
+//!    publish-subscribe service with the [`ServiceName`] `"{service}/{instance}/{event}"` or
+//!    the name provided in the [`TopicMapping`].
+//!  * iceoryx classic transfers no type information, the [`TypeDescription`] of the
+//!    [`TopicMapping`] is translated into the payload type details of the iceoryx2 service, so
+//!    that iceoryx2 applications use the service like every other typed service. Arrays of
+//!    the C++ type are translated into slices.
+//!
+//! The payload is copied once per direction, the memory layout of the C++ type and the type
+//! of the iceoryx2 application must be identical.
+//!
+//! # Scope
+//!
+//! This module provides only the mapping and the forwarding on the iceoryx2 side. It does not
+//! ship a connection to the iceoryx classic runtime (RouDi) and does not link against
+//! iceoryx classic, since the iceoryx classic C binding is a C++ build dependency that is not
+//! available to this crate. The bridging process provides the connection by implementing the
+//! [`ClassicEndpoint`], usually on top of the `iox_pub_*` and `iox_sub_*` functions of the
+//! iceoryx classic C binding.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_gateway::iceoryx_classic::*;
+//! use core::time::Duration;
+//!
+//! # #[derive(Debug)]
+//! # struct RouDiEndpoint;
+//! # impl ClassicEndpoint for RouDiEndpoint {
+//! #     fn offer(&mut self, _: &ServiceDescription, _: &TypeDescription) -> Result<(), ClassicEndpointError> { Ok(()) }
+//! #     fn subscribe(&mut self, _: &ServiceDescription, _: &TypeDescription) -> Result<(), ClassicEndpointError> { Ok(()) }
+//! #     fn publish(&mut self, _: &ServiceDescription, _: &[u8]) -> Result<(), ClassicEndpointError> { Ok(()) }
+//! #     fn take(&mut self, _: &ServiceDescription, _: &mut [u8]) -> Result<Option<usize>, ClassicEndpointError> { Ok(None) }
+//! # }
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let mapping = Mapping::from_toml(r#"
+//!     [[topic]]
+//!     service = "Radar"
+//!     instance = "FrontLeft"
+//!     event = "Object"
+//!     direction = "from-classic"
+//!
+//!     [topic.type]
+//!     type-name = "radar::Object"
+//!     size = 24
+//!     alignment = 8
+//! "#)?;
+//!
+//! // implemented by the bridging process with the iceoryx classic C binding
+//! let endpoint = RouDiEndpoint;
+//! let mut gateway = Gateway::create(&node, &mapping, endpoint)?;
+//!
+//! while node.wait(Duration::from_millis(10)).is_ok() {
+//!     gateway.process()?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+mod mapping;
+mod service_description;
+
+pub use mapping::*;
+pub use service_description::*;
+
+use core::fmt::Debug;
+
+use iceoryx2::port::publisher::Publisher;
+use iceoryx2::port::subscriber::Subscriber;
+use iceoryx2::prelude::*;
+use iceoryx2::service::builder::publish_subscribe::CustomPayloadMarker;
+use iceoryx2_bb_log::{fail, warn};
+
+/// Failures that a [`ClassicEndpoint`] reports to the [`Gateway`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClassicEndpointError {
+    /// The iceoryx classic runtime is not available, e.g. since RouDi is not running.
+    RuntimeUnavailable,
+    /// The publisher or subscriber of the iceoryx classic topic could not be created.
+    UnableToCreatePort,
+    /// The payload could not be published or taken, e.g. since no chunk could be loaned.
+    TransmissionFailed,
+}
+
+impl core::fmt::Display for ClassicEndpointError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ClassicEndpointError::{:?}", self)
+    }
+}
+
+impl core::error::Error for ClassicEndpointError {}
+
+/// The connection of the [`Gateway`] to the iceoryx classic runtime. No implementation is
+/// provided by this crate, it is implemented by the bridging process, usually on top of the
+/// iceoryx classic C binding, and owns one iceoryx classic publisher or subscriber per
+/// [`ServiceDescription`].
+pub trait ClassicEndpoint: Debug {
+    /// Creates an iceoryx classic publisher that offers the topic. It is called once for
+    /// every [`TopicMapping`] with [`Direction::ToClassic`].
+    fn offer(
+        &mut self,
+        service: &ServiceDescription,
+        payload_type: &TypeDescription,
+    ) -> Result<(), ClassicEndpointError>;
+
+    /// Creates an iceoryx classic subscriber for the topic. It is called once for every
+    /// [`TopicMapping`] with [`Direction::FromClassic`].
+    fn subscribe(
+        &mut self,
+        service: &ServiceDescription,
+        payload_type: &TypeDescription,
+    ) -> Result<(), ClassicEndpointError>;
+
+    /// Copies the payload into a chunk of the iceoryx classic publisher of the topic and
+    /// publishes it.
+    fn publish(
+        &mut self,
+        service: &ServiceDescription,
+        payload: &[u8],
+    ) -> Result<(), ClassicEndpointError>;
+
+    /// Copies the payload of the next chunk that the iceoryx classic subscriber of the topic
+    /// received into the buffer, releases the chunk and returns the number of payload bytes.
+    /// Returns [`None`] when no chunk was received.
+    fn take(
+        &mut self,
+        service: &ServiceDescription,
+        buffer: &mut [u8],
+    ) -> Result<Option<usize>, ClassicEndpointError>;
+}
+
+/// Failures that can occur when a [`Gateway`] is created with [`Gateway::create()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GatewayCreateError {
+    /// A [`ServiceDescription`] in the [`Mapping`] contains an invalid identifier.
+    InvalidServiceDescription,
+    /// A service name in the [`Mapping`] is not a valid [`ServiceName`].
+    InvalidServiceName,
+    /// The iceoryx2 service could not be created or opened, e.g. since it exists with a
+    /// different payload type.
+    UnableToOpenService,
+    /// The publisher or subscriber port of the iceoryx2 service could not be created.
+    UnableToCreatePort,
+    /// The [`ClassicEndpoint`] could not offer or subscribe to the iceoryx classic topic.
+    UnableToConnectToClassic,
+}
+
+impl core::fmt::Display for GatewayCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "GatewayCreateError::{:?}", self)
+    }
+}
+
+impl core::error::Error for GatewayCreateError {}
+
+/// Failures that can occur in [`Gateway::process()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GatewayProcessError {
+    /// A sample could not be received from an iceoryx2 service.
+    ReceiveFromServiceFailed,
+    /// A sample could not be published on an iceoryx2 service.
+    PublishToServiceFailed,
+    /// A payload could not be taken from the [`ClassicEndpoint`].
+    ReceiveFromClassicFailed,
+    /// A payload could not be published with the [`ClassicEndpoint`].
+    PublishToClassicFailed,
+}
+
+impl core::fmt::Display for GatewayProcessError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "GatewayProcessError::{:?}", self)
+    }
+}
+
+impl core::error::Error for GatewayProcessError {}
+
+#[derive(Debug)]
+struct OutboundTopic<S: Service> {
+    subscriber: Subscriber<S, [CustomPayloadMarker], ()>,
+    classic: ServiceDescription,
+    payload_type: TypeDescription,
+}
+
+#[derive(Debug)]
+struct InboundTopic<S: Service> {
+    publisher: Publisher<S, [CustomPayloadMarker], ()>,
+    classic: ServiceDescription,
+    payload_type: TypeDescription,
+    max_payload_size: usize,
+}
+
+/// Forwards the data between the iceoryx2 services and the iceoryx classic topics as defined
+/// in the [`Mapping`]. The forwarding is performed on every call of [`Gateway::process()`].
+#[derive(Debug)]
+pub struct Gateway<S: Service, E: ClassicEndpoint> {
+    endpoint: E,
+    outbound: Vec<OutboundTopic<S>>,
+    inbound: Vec<InboundTopic<S>>,
+    buffer: Vec<u8>,
+}
+
+impl<S: Service, E: ClassicEndpoint> Gateway<S, E> {
+    /// Creates a new [`Gateway`] that opens or creates all iceoryx2 services defined in the
+    /// [`Mapping`] and offers or subscribes to the corresponding iceoryx classic topics with
+    /// the provided [`ClassicEndpoint`].
+    pub fn create(
+        node: &Node<S>,
+        mapping: &Mapping,
+        endpoint: E,
+    ) -> Result<Self, GatewayCreateError> {
+        let mut new_self = Self {
+            endpoint,
+            outbound: vec![],
+            inbound: vec![],
+            buffer: vec![],
+        };
+
+        for topic in &mapping.topic {
+            new_self.add_topic(node, topic)?;
+        }
+
+        Ok(new_self)
+    }
+
+    /// Returns a reference to the [`ClassicEndpoint`].
+    pub fn endpoint(&self) -> &E {
+        &self.endpoint
+    }
+
+    fn add_topic(
+        &mut self,
+        node: &Node<S>,
+        mapping: &TopicMapping,
+    ) -> Result<(), GatewayCreateError> {
+        let msg = "Unable to add topic to iceoryx classic gateway";
+        if !mapping.classic.is_valid() {
+            fail!(from self, with GatewayCreateError::InvalidServiceDescription,
+                "{} since the service description {:?} contains an invalid identifier.",
+                msg, mapping.classic);
+        }
+
+        let service_name = match &mapping.service_name {
+            Some(name) => fail!(from self, when ServiceName::new(name),
+                with GatewayCreateError::InvalidServiceName,
+                "{} since \"{}\" is not a valid service name.", msg, name),
+            None => fail!(from self, when mapping.classic.to_service_name(),
+                with GatewayCreateError::InvalidServiceName,
+                "{} since the service description {:?} cannot be translated into a service name.",
+                msg, mapping.classic),
+        };
+
+        let service = fail!(from self, when unsafe {
+                node.service_builder(&service_name)
+                    .publish_subscribe::<[CustomPayloadMarker]>()
+                    .__internal_set_payload_type_details(&mapping.payload_type.to_type_detail())
+                    .open_or_create()
+            },
+            with GatewayCreateError::UnableToOpenService,
+            "{} since the service \"{}\" with the payload type {:?} could not be opened or created.",
+            msg, service_name, mapping.payload_type);
+
+        match mapping.direction {
+            Direction::ToClassic => {
+                let subscriber = fail!(from self, when service.subscriber_builder().create(),
+                    with GatewayCreateError::UnableToCreatePort,
+                    "{} since the subscriber for \"{}\" could not be created.", msg, service_name);
+                fail!(from self, when self.endpoint.offer(&mapping.classic, &mapping.payload_type),
+                    with GatewayCreateError::UnableToConnectToClassic,
+                    "{} since the iceoryx classic topic {:?} could not be offered.", msg, mapping.classic);
+                self.outbound.push(OutboundTopic {
+                    subscriber,
+                    classic: mapping.classic.clone(),
+                    payload_type: mapping.payload_type.clone(),
+                });
+            }
+            Direction::FromClassic => {
+                let publisher = fail!(from self, when service
+                        .publisher_builder()
+                        .initial_max_slice_len(mapping.max_number_of_elements)
+                        .create(),
+                    with GatewayCreateError::UnableToCreatePort,
+                    "{} since the publisher for \"{}\" could not be created.", msg, service_name);
+                fail!(from self, when self.endpoint.subscribe(&mapping.classic, &mapping.payload_type),
+                    with GatewayCreateError::UnableToConnectToClassic,
+                    "{} since the iceoryx classic topic {:?} could not be subscribed.", msg, mapping.classic);
+                let max_payload_size = mapping.max_payload_size();
+                self.buffer
+                    .resize(self.buffer.len().max(max_payload_size), 0);
+                self.inbound.push(InboundTopic {
+                    publisher,
+                    classic: mapping.classic.clone(),
+                    payload_type: mapping.payload_type.clone(),
+                    max_payload_size,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Forwards all samples received from the iceoryx2 services to the iceoryx classic topics
+    /// and publishes all payloads received from the iceoryx classic topics on the
+    /// corresponding iceoryx2 services. Returns the number of forwarded samples.
+    pub fn process(&mut self) -> Result<usize, GatewayProcessError> {
+        Ok(self.forward_to_classic()? + self.forward_to_services()?)
+    }
+
+    fn forward_to_classic(&mut self) -> Result<usize, GatewayProcessError> {
+        let msg = "Unable to forward samples to iceoryx classic";
+        let mut number_of_samples = 0;
+
+        for topic in &self.outbound {
+            loop {
+                let sample = match unsafe { topic.subscriber.receive_custom_payload() } {
+                    Ok(Some(sample)) => sample,
+                    Ok(None) => break,
+                    Err(e) => {
+                        fail!(from self, with GatewayProcessError::ReceiveFromServiceFailed,
+                            "{} since a sample could not be received ({:?}).", msg, e);
+                    }
+                };
+
+                // CustomPayloadMarker is a transparent byte
+                let payload = sample.payload();
+                let payload = unsafe {
+                    core::slice::from_raw_parts(payload.as_ptr().cast::<u8>(), payload.len())
+                };
+
+                fail!(from self, when self.endpoint.publish(&topic.classic, payload),
+                    with GatewayProcessError::PublishToClassicFailed,
+                    "{} since the payload of type {:?} could not be published on the topic {:?}.",
+                    msg, topic.payload_type, topic.classic);
+                number_of_samples += 1;
+            }
+        }
+
+        Ok(number_of_samples)
+    }
+
+    fn forward_to_services(&mut self) -> Result<usize, GatewayProcessError> {
+        let msg = "Unable to forward iceoryx classic payloads to the services";
+        let mut number_of_samples = 0;
+
+        for topic in &self.inbound {
+            loop {
+                let buffer = &mut self.buffer[..topic.max_payload_size];
+                let number_of_bytes = match self.endpoint.take(&topic.classic, buffer) {
+                    Ok(Some(number_of_bytes)) => number_of_bytes,
+                    Ok(None) => break,
+                    Err(e) => {
+                        fail!(from self, with GatewayProcessError::ReceiveFromClassicFailed,
+                            "{} since the topic {:?} could not be taken ({:?}).", msg, topic.classic, e);
+                    }
+                };
+
+                if number_of_bytes > topic.max_payload_size
+                    || !topic.payload_type.is_valid_payload_size(number_of_bytes)
+                {
+                    warn!(from self,
+                        "Dropping payload of size {} from the topic {:?} since it is no valid instance of the type {:?}.",
+                        number_of_bytes, topic.classic, topic.payload_type);
+                    continue;
+                }
+
+                let number_of_elements = match topic.payload_type.is_slice {
+                    true => number_of_bytes / topic.payload_type.size,
+                    false => 1,
+                };
+
+                let mut sample = fail!(from self, when unsafe { topic.publisher.loan_custom_payload(number_of_elements) },
+                    with GatewayProcessError::PublishToServiceFailed,
+                    "{} since a sample could not be loaned.", msg);
+                unsafe {
+                    core::ptr::copy_nonoverlapping(
+                        self.buffer.as_ptr(),
+                        sample.payload_mut().as_mut_ptr().cast::<u8>(),
+                        number_of_bytes,
+                    )
+                };
+                fail!(from self, when unsafe { sample.assume_init() }.send(),
+                    with GatewayProcessError::PublishToServiceFailed,
+                    "{} since the sample could not be sent.", msg);
+                number_of_samples += 1;
+            }
+        }
+
+        Ok(number_of_samples)
+    }
+}
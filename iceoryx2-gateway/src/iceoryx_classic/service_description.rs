@@ -0,0 +1,178 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::prelude::ServiceName;
+use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+use iceoryx2_bb_log::fail;
+use serde::{Deserialize, Serialize};
+
+/// The maximum length of every identifier of a [`ServiceDescription`]. It corresponds to the
+/// capacity of the `IdString_t` of iceoryx classic.
+pub const MAX_ID_STRING_LENGTH: usize = 100;
+
+/// Failures that can occur when a [`ServiceDescription`] is created or translated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceDescriptionError {
+    /// An identifier is empty, longer than [`MAX_ID_STRING_LENGTH`] or contains a `/`.
+    InvalidIdentifier,
+    /// The [`ServiceName`] does not consist of exactly three segments or the resulting
+    /// [`ServiceName`] is invalid.
+    InvalidServiceName,
+}
+
+impl core::fmt::Display for ServiceDescriptionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ServiceDescriptionError::{:?}", self)
+    }
+}
+
+impl core::error::Error for ServiceDescriptionError {}
+
+/// The service description of iceoryx classic that identifies a topic by its service,
+/// instance and event. It is translated into the iceoryx2 [`ServiceName`]
+/// `"{service}/{instance}/{event}"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ServiceDescription {
+    /// The service identifier
+    pub service: String,
+    /// The instance identifier
+    pub instance: String,
+    /// The event identifier
+    pub event: String,
+}
+
+fn is_valid_identifier(value: &str) -> bool {
+    !value.is_empty() && value.len() <= MAX_ID_STRING_LENGTH && !value.contains('/')
+}
+
+impl ServiceDescription {
+    /// Creates a new [`ServiceDescription`]. Every identifier must be non-empty, must not be
+    /// longer than [`MAX_ID_STRING_LENGTH`] and must not contain a `/`, since it is the
+    /// separator of the segments of the translated [`ServiceName`].
+    pub fn new(
+        service: &str,
+        instance: &str,
+        event: &str,
+    ) -> Result<Self, ServiceDescriptionError> {
+        let new_self = Self {
+            service: service.to_string(),
+            instance: instance.to_string(),
+            event: event.to_string(),
+        };
+
+        if !new_self.is_valid() {
+            fail!(from "ServiceDescription::new()", with ServiceDescriptionError::InvalidIdentifier,
+                "Unable to create service description since {:?} contains an invalid identifier.",
+                new_self);
+        }
+
+        Ok(new_self)
+    }
+
+    /// Returns true when all identifiers satisfy the requirements of
+    /// [`ServiceDescription::new()`]. It is required for descriptions that were deserialized.
+    pub fn is_valid(&self) -> bool {
+        is_valid_identifier(&self.service)
+            && is_valid_identifier(&self.instance)
+            && is_valid_identifier(&self.event)
+    }
+
+    /// Translates the [`ServiceDescription`] into the iceoryx2 [`ServiceName`]
+    /// `"{service}/{instance}/{event}"`.
+    pub fn to_service_name(&self) -> Result<ServiceName, ServiceDescriptionError> {
+        let msg = "Unable to translate the service description into a service name";
+        if !self.is_valid() {
+            fail!(from self, with ServiceDescriptionError::InvalidIdentifier,
+                "{} since it contains an invalid identifier.", msg);
+        }
+
+        let name = format!("{}/{}/{}", self.service, self.instance, self.event);
+        Ok(fail!(from self, when ServiceName::new(&name),
+            with ServiceDescriptionError::InvalidServiceName,
+            "{} since \"{}\" is not a valid service name.", msg, name))
+    }
+
+    /// Translates an iceoryx2 [`ServiceName`] with exactly three segments back into a
+    /// [`ServiceDescription`].
+    pub fn from_service_name(name: &ServiceName) -> Result<Self, ServiceDescriptionError> {
+        let segments: Vec<&str> = name.as_str().split('/').collect();
+        if segments.len() != 3 {
+            fail!(from "ServiceDescription::from_service_name()",
+                with ServiceDescriptionError::InvalidServiceName,
+                "Unable to translate the service name \"{}\" since it does not consist of a service, instance and event segment.",
+                name.as_str());
+        }
+
+        Self::new(segments[0], segments[1], segments[2])
+    }
+}
+
+/// Describes the payload type of an iceoryx classic topic. iceoryx classic transfers no type
+/// information, therefore the description is provided by the [`Mapping`](crate::iceoryx_classic::Mapping)
+/// and translated into the [`TypeDetail`] that iceoryx2 uses to verify that publishers and
+/// subscribers are compatible.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct TypeDescription {
+    /// The type name of the payload in the iceoryx2 service. For a Rust application it is the
+    /// output of [`core::any::type_name()`] of the counterpart of the C++ type.
+    pub type_name: String,
+    /// The size of the C++ type in bytes
+    pub size: usize,
+    /// The alignment of the C++ type in bytes
+    pub alignment: usize,
+    /// True when the iceoryx classic application publishes arrays of the type, they are
+    /// translated into slices.
+    #[serde(default)]
+    pub is_slice: bool,
+}
+
+impl TypeDescription {
+    /// Creates the [`TypeDescription`] of a Rust type that has the same memory layout as the
+    /// C++ type of the iceoryx classic application.
+    pub fn of<T>() -> Self {
+        Self::from_type_detail(&TypeDetail::__internal_new::<T>(TypeVariant::FixedSize))
+    }
+
+    /// Translates the [`TypeDetail`] of an iceoryx2 payload into a [`TypeDescription`].
+    pub fn from_type_detail(value: &TypeDetail) -> Self {
+        Self {
+            type_name: value.type_name.clone(),
+            size: value.size,
+            alignment: value.alignment,
+            is_slice: value.variant == TypeVariant::Dynamic,
+        }
+    }
+
+    /// Translates the [`TypeDescription`] into the [`TypeDetail`] of the iceoryx2 payload.
+    pub fn to_type_detail(&self) -> TypeDetail {
+        TypeDetail {
+            variant: match self.is_slice {
+                true => TypeVariant::Dynamic,
+                false => TypeVariant::FixedSize,
+            },
+            type_name: self.type_name.clone(),
+            size: self.size,
+            alignment: self.alignment,
+        }
+    }
+
+    /// Returns true when a payload with the provided number of bytes is a valid instance of
+    /// the type, or in case of a slice, a valid array of the type.
+    pub fn is_valid_payload_size(&self, number_of_bytes: usize) -> bool {
+        match self.is_slice {
+            true => self.size != 0 && number_of_bytes % self.size == 0,
+            false => number_of_bytes == self.size,
+        }
+    }
+}
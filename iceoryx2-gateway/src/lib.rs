@@ -0,0 +1,28 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![warn(clippy::alloc_instead_of_core)]
+#![warn(clippy::std_instead_of_alloc)]
+#![warn(clippy::std_instead_of_core)]
+#![warn(missing_docs)]
+
+//! Gateways that connect iceoryx2 services with other communication protocols.
+
+/// Maps iceoryx classic topics to iceoryx2 services. The connection to the iceoryx classic
+/// runtime is provided by the user.
+pub mod iceoryx_classic;
+
+/// Mirrors publish-subscribe services to a remote host over the network.
+pub mod network;
+
+/// Translates iceoryx2 services to SOME/IP service instances.
+pub mod someip;
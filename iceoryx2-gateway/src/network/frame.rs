@@ -0,0 +1,253 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::alloc::Layout;
+
+use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+
+/// The size of the [`FrameHeader`] on the wire in bytes.
+pub const FRAME_HEADER_SIZE: usize = 20;
+
+/// Identifies a frame of the network gateway, the ASCII characters `"IOX2"`.
+pub const FRAME_MAGIC: u32 = 0x494F5832;
+
+/// The wire format version that is supported by the gateway.
+pub const FRAME_VERSION: u8 = 0x01;
+
+/// The frame types of the network gateway.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameType {
+    /// Contains the payload of a sample of an announced topic
+    Sample = 0x00,
+    /// Signals that the sending gateway is alive, contains no payload
+    Heartbeat = 0x01,
+    /// Contains the [`Announcement`] of a topic, sent before its first sample
+    Announcement = 0x02,
+    /// Requests the [`Announcement`] of all topics, contains no payload
+    AnnouncementRequest = 0x03,
+}
+
+impl FrameType {
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0x00 => Some(FrameType::Sample),
+            0x01 => Some(FrameType::Heartbeat),
+            0x02 => Some(FrameType::Announcement),
+            0x03 => Some(FrameType::AnnouncementRequest),
+            _ => None,
+        }
+    }
+}
+
+/// Failures that can occur when a [`FrameHeader`] or an [`Announcement`] is deserialized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameDecodeError {
+    /// The buffer is smaller than the header or smaller than announced in the length field.
+    InsufficientData,
+    /// The frame does not start with [`FRAME_MAGIC`].
+    InvalidMagic,
+    /// The wire format version is not [`FRAME_VERSION`].
+    UnsupportedVersion,
+    /// The frame type is unknown.
+    UnknownFrameType,
+    /// A string, the type variant or the size and alignment of the payload type of an
+    /// [`Announcement`] is invalid.
+    InvalidAnnouncement,
+}
+
+impl core::fmt::Display for FrameDecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "FrameDecodeError::{:?}", self)
+    }
+}
+
+impl core::error::Error for FrameDecodeError {}
+
+/// The header that precedes every frame of the network gateway. All fields are transmitted in
+/// network byte order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameHeader {
+    /// The [`FrameType`] of the frame
+    pub frame_type: FrameType,
+    /// Identifies the topic of the sending gateway, assigned with the [`Announcement`]
+    pub topic_id: u16,
+    /// Identifies the lifetime of the sending gateway, changes when the gateway is restarted
+    pub session_id: u32,
+    /// Incremented with every frame of the session, used to detect lost frames
+    pub sequence_number: u32,
+    /// The length of the payload that follows the header
+    pub payload_length: u32,
+}
+
+impl FrameHeader {
+    /// Serializes the [`FrameHeader`] into the first [`FRAME_HEADER_SIZE`] bytes of the buffer.
+    ///
+    /// # Panics
+    ///
+    ///  * When the buffer is smaller than [`FRAME_HEADER_SIZE`].
+    pub fn write_to(&self, buffer: &mut [u8]) {
+        buffer[0..4].copy_from_slice(&FRAME_MAGIC.to_be_bytes());
+        buffer[4] = FRAME_VERSION;
+        buffer[5] = self.frame_type as u8;
+        buffer[6..8].copy_from_slice(&self.topic_id.to_be_bytes());
+        buffer[8..12].copy_from_slice(&self.session_id.to_be_bytes());
+        buffer[12..16].copy_from_slice(&self.sequence_number.to_be_bytes());
+        buffer[16..20].copy_from_slice(&self.payload_length.to_be_bytes());
+    }
+
+    /// Deserializes the [`FrameHeader`] from the buffer. The buffer must contain at least the
+    /// header and the payload announced in the length field.
+    pub fn from_bytes(buffer: &[u8]) -> Result<Self, FrameDecodeError> {
+        if buffer.len() < FRAME_HEADER_SIZE {
+            return Err(FrameDecodeError::InsufficientData);
+        }
+
+        let u32_at =
+            |i: usize| u32::from_be_bytes([buffer[i], buffer[i + 1], buffer[i + 2], buffer[i + 3]]);
+
+        if u32_at(0) != FRAME_MAGIC {
+            return Err(FrameDecodeError::InvalidMagic);
+        }
+
+        if buffer[4] != FRAME_VERSION {
+            return Err(FrameDecodeError::UnsupportedVersion);
+        }
+
+        let frame_type = match FrameType::from_u8(buffer[5]) {
+            Some(v) => v,
+            None => return Err(FrameDecodeError::UnknownFrameType),
+        };
+
+        let payload_length = u32_at(16);
+        if buffer.len() < FRAME_HEADER_SIZE + payload_length as usize {
+            return Err(FrameDecodeError::InsufficientData);
+        }
+
+        Ok(Self {
+            frame_type,
+            topic_id: u16::from_be_bytes([buffer[6], buffer[7]]),
+            session_id: u32_at(8),
+            sequence_number: u32_at(12),
+            payload_length,
+        })
+    }
+}
+
+/// The payload of a [`FrameType::Announcement`]. It contains the parts of the static
+/// configuration of a publish-subscribe service that the receiving gateway requires to create
+/// a compatible service on the remote host.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Announcement {
+    /// The name of the publish-subscribe service
+    pub service_name: String,
+    /// The [`TypeDetail`] of the payload
+    pub payload_type: TypeDetail,
+    /// The optional descriptor of the payload type
+    pub payload_type_descriptor: Option<String>,
+}
+
+impl Announcement {
+    /// Serializes the [`Announcement`] and appends it to the buffer.
+    pub fn write_to(&self, buffer: &mut Vec<u8>) {
+        let write_string = |buffer: &mut Vec<u8>, value: &str| {
+            buffer.extend_from_slice(&(value.len() as u16).to_be_bytes());
+            buffer.extend_from_slice(value.as_bytes());
+        };
+
+        write_string(buffer, &self.service_name);
+        buffer.push(match self.payload_type.variant {
+            TypeVariant::FixedSize => 0,
+            TypeVariant::Dynamic => 1,
+        });
+        buffer.extend_from_slice(&(self.payload_type.size as u32).to_be_bytes());
+        buffer.extend_from_slice(&(self.payload_type.alignment as u32).to_be_bytes());
+        write_string(buffer, &self.payload_type.type_name);
+        match &self.payload_type_descriptor {
+            Some(descriptor) => {
+                buffer.push(1);
+                write_string(buffer, descriptor);
+            }
+            None => buffer.push(0),
+        }
+    }
+
+    /// Deserializes the [`Announcement`] from the payload of a
+    /// [`FrameType::Announcement`].
+    pub fn from_bytes(buffer: &[u8]) -> Result<Self, FrameDecodeError> {
+        let mut reader = Reader {
+            buffer,
+            position: 0,
+        };
+
+        let service_name = reader.string()?;
+        let variant = match reader.take(1)?[0] {
+            0 => TypeVariant::FixedSize,
+            1 => TypeVariant::Dynamic,
+            _ => return Err(FrameDecodeError::InvalidAnnouncement),
+        };
+        let size = reader.u32()? as usize;
+        let alignment = reader.u32()? as usize;
+        // the type details are used to calculate the memory layout of the samples and must
+        // therefore describe a valid layout
+        if !alignment.is_power_of_two() || Layout::from_size_align(size, alignment).is_err() {
+            return Err(FrameDecodeError::InvalidAnnouncement);
+        }
+        let type_name = reader.string()?;
+        let payload_type_descriptor = match reader.take(1)?[0] {
+            0 => None,
+            1 => Some(reader.string()?),
+            _ => return Err(FrameDecodeError::InvalidAnnouncement),
+        };
+
+        Ok(Self {
+            service_name,
+            payload_type: TypeDetail {
+                variant,
+                type_name,
+                size,
+                alignment,
+            },
+            payload_type_descriptor,
+        })
+    }
+}
+
+struct Reader<'a> {
+    buffer: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, length: usize) -> Result<&'a [u8], FrameDecodeError> {
+        if self.buffer.len() < self.position + length {
+            return Err(FrameDecodeError::InsufficientData);
+        }
+
+        self.position += length;
+        Ok(&self.buffer[self.position - length..self.position])
+    }
+
+    fn u32(&mut self) -> Result<u32, FrameDecodeError> {
+        let v = self.take(4)?;
+        Ok(u32::from_be_bytes([v[0], v[1], v[2], v[3]]))
+    }
+
+    fn string(&mut self) -> Result<String, FrameDecodeError> {
+        let length = self.take(2)?;
+        let length = u16::from_be_bytes([length[0], length[1]]) as usize;
+        match core::str::from_utf8(self.take(length)?) {
+            Ok(v) => Ok(v.to_string()),
+            Err(_) => Err(FrameDecodeError::InvalidAnnouncement),
+        }
+    }
+}
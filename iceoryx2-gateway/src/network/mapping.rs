@@ -0,0 +1,175 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_log::{fail, trace};
+use iceoryx2_bb_posix::file::{AccessMode, FileBuilder, FileOpenError};
+use iceoryx2_bb_system_types::file_path::FilePath;
+use serde::{Deserialize, Serialize};
+
+/// Failures occurring while loading a [`Mapping`] with [`Mapping::from_file()`] or
+/// [`Mapping::from_toml()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum MappingCreationError {
+    /// The mapping file could not be read.
+    FailedToReadMappingFileContents,
+    /// Parts of the mapping could not be deserialized. Indicates some kind of syntax error.
+    UnableToDeserializeContents,
+    /// Insufficient permissions to open the mapping file.
+    InsufficientPermissions,
+    /// The provided mapping file does not exist
+    MappingFileDoesNotExist,
+    /// Since the mapping file could not be opened
+    UnableToOpenMappingFile,
+}
+
+impl core::fmt::Display for MappingCreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "MappingCreationError::{:?}", self)
+    }
+}
+
+impl core::error::Error for MappingCreationError {}
+
+/// The network endpoints of the gateway and the timing of the connection handling.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Endpoint {
+    /// The IPv4 address the gateway binds to, e.g. `"0.0.0.0"`
+    pub local_address: String,
+    /// The UDP port the gateway binds to
+    pub local_port: u16,
+    /// The IPv4 address of the remote gateway
+    pub remote_address: String,
+    /// The UDP port of the remote gateway
+    pub remote_port: u16,
+    /// The interval in milliseconds in which a heartbeat is sent to the remote gateway
+    #[serde(default = "default_heartbeat_interval_ms")]
+    pub heartbeat_interval_ms: u64,
+    /// The time in milliseconds without any frame from the remote gateway after which it is
+    /// considered as disconnected
+    #[serde(default = "default_peer_timeout_ms")]
+    pub peer_timeout_ms: u64,
+    /// The interval in milliseconds in which the local services are discovered
+    #[serde(default = "default_discovery_interval_ms")]
+    pub discovery_interval_ms: u64,
+}
+
+fn default_heartbeat_interval_ms() -> u64 {
+    1000
+}
+
+fn default_peer_timeout_ms() -> u64 {
+    3000
+}
+
+fn default_discovery_interval_ms() -> u64 {
+    1000
+}
+
+/// Selects the local publish-subscribe services that are mirrored to the remote gateway.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MirrorMapping {
+    /// The name of the service. When it ends with `*` all services whose name starts with the
+    /// preceding characters are mirrored.
+    pub service_name: String,
+}
+
+impl MirrorMapping {
+    /// Returns true when the service with the provided name is selected.
+    pub fn matches(&self, service_name: &str) -> bool {
+        match self.service_name.strip_suffix('*') {
+            Some(prefix) => service_name.starts_with(prefix),
+            None => service_name == self.service_name,
+        }
+    }
+}
+
+/// Defines which local services are mirrored to which remote gateway, usually loaded from a
+/// TOML file.
+///
+/// ```toml
+/// [endpoint]
+/// local-address = "0.0.0.0"
+/// local-port = 40100
+/// remote-address = "192.168.0.20"
+/// remote-port = 40100
+/// heartbeat-interval-ms = 1000
+/// peer-timeout-ms = 3000
+///
+/// [[mirror]]
+/// service-name = "Vehicle/Speed"
+///
+/// [[mirror]]
+/// service-name = "Camera/*"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Mapping {
+    /// The network endpoints
+    pub endpoint: Endpoint,
+    /// The local services that are mirrored to the remote gateway
+    #[serde(default)]
+    pub mirror: Vec<MirrorMapping>,
+}
+
+impl Mapping {
+    /// Returns true when the service with the provided name is selected by any
+    /// [`MirrorMapping`].
+    pub fn is_mirrored(&self, service_name: &str) -> bool {
+        self.mirror.iter().any(|m| m.matches(service_name))
+    }
+
+    /// Deserializes a [`Mapping`] from a TOML string.
+    pub fn from_toml(contents: &str) -> Result<Mapping, MappingCreationError> {
+        match toml::from_str(contents) {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                fail!(from "Mapping::from_toml()", with MappingCreationError::UnableToDeserializeContents,
+                    "Failed to create mapping since the contents could not be deserialized ({}).", e);
+            }
+        }
+    }
+
+    /// Loads a [`Mapping`] from a TOML file.
+    pub fn from_file(mapping_file: &FilePath) -> Result<Mapping, MappingCreationError> {
+        let msg = "Failed to create mapping";
+        let origin = "Mapping::from_file()";
+
+        let file = match FileBuilder::new(mapping_file).open_existing(AccessMode::Read) {
+            Ok(file) => file,
+            Err(FileOpenError::InsufficientPermissions) => {
+                fail!(from origin, with MappingCreationError::InsufficientPermissions,
+                    "{} since the mapping file \"{}\" could not be opened due to insufficient permissions.",
+                    msg, mapping_file);
+            }
+            Err(FileOpenError::FileDoesNotExist) => {
+                fail!(from origin, with MappingCreationError::MappingFileDoesNotExist,
+                    "{} since the mapping file \"{}\" does not exist.", msg, mapping_file);
+            }
+            Err(e) => {
+                fail!(from origin, with MappingCreationError::UnableToOpenMappingFile,
+                    "{} since the mapping file \"{}\" could not be open due to an internal error ({:?}).",
+                    msg, mapping_file, e);
+            }
+        };
+
+        let mut contents = String::new();
+        fail!(from origin, when file.read_to_string(&mut contents),
+            with MappingCreationError::FailedToReadMappingFileContents,
+            "{} since the mapping file contents could not be read.", msg);
+
+        let mapping = Self::from_toml(&contents)?;
+        trace!(from mapping, "Loaded.");
+        Ok(mapping)
+    }
+}
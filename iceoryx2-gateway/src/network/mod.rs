@@ -0,0 +1,758 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Mirrors local publish-subscribe services to a remote host, so that iceoryx2 applications
+//! on multiple hosts can communicate without hand-rolled bridges.
+//!
+//! Every host runs a [`Gateway`] that is connected to the gateway of the other host.
+//!
+//!  * The [`Gateway`] discovers the local services selected by the [`MirrorMapping`]s,
+//!    subscribes to them and sends every received sample as [`FrameType::Sample`] to the
+//!    remote gateway.
+//!  * Before the first sample of a service, its static configuration, the service name and
+//!    the payload type, is sent as [`Announcement`]. The remote gateway opens or creates a
+//!    service with the same name and payload type and republishes the samples on it.
+//!  * Both gateways exchange heartbeats. When the remote gateway is restarted or reconnects
+//!    after a timeout, all services are announced again.
+//!
+//! Only services without a user header are mirrored. Samples that were published by the
+//! gateway itself are not sent back, therefore a service can be mirrored in both directions.
+//! The only transport is UDP, samples that do not fit into a single datagram are dropped.
+//! Lost datagrams are detected with the sequence number of the [`FrameHeader`] but not
+//! retransmitted. A connection oriented transport like TCP, that would deliver large or
+//! reliable samples, is not part of this gateway.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_gateway::network::{Gateway, Mapping};
+//! use core::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let mapping = Mapping::from_toml(r#"
+//!     [endpoint]
+//!     local-address = "0.0.0.0"
+//!     local-port = 40100
+//!     remote-address = "192.168.0.20"
+//!     remote-port = 40100
+//!
+//!     [[mirror]]
+//!     service-name = "Vehicle/*"
+//! "#)?;
+//!
+//! let mut gateway = Gateway::create(&node, &mapping)?;
+//!
+//! while node.wait(Duration::from_millis(10)).is_ok() {
+//!     gateway.process(&node)?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+mod frame;
+mod mapping;
+
+pub use frame::*;
+pub use mapping::*;
+
+use core::fmt::Debug;
+use core::net::Ipv4Addr;
+use core::str::FromStr;
+use core::time::Duration;
+use std::time::Instant;
+
+use iceoryx2::port::publisher::Publisher;
+use iceoryx2::port::subscriber::Subscriber;
+use iceoryx2::prelude::*;
+use iceoryx2::service::builder::publish_subscribe::CustomPayloadMarker;
+use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+use iceoryx2_bb_log::{debug, fail, info, warn};
+use iceoryx2_bb_posix::udp_socket::{UdpServer, UdpServerBuilder};
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_system_types::ipv4_address::Ipv4Address;
+use iceoryx2_bb_system_types::port::Port;
+
+/// The maximum size of a frame, the maximum payload of a UDP datagram over IPv4.
+pub const MAX_FRAME_SIZE: usize = 65507;
+
+/// Failures that can occur when a [`Gateway`] is created with [`Gateway::create()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GatewayCreateError {
+    /// An address in the [`Endpoint`] is not a valid IPv4 address.
+    InvalidAddress,
+    /// The UDP socket could not be created or bound to the local endpoint.
+    UnableToCreateSocket,
+    /// The session id that identifies the lifetime of the [`Gateway`] could not be generated.
+    UnableToCreateSessionId,
+}
+
+impl core::fmt::Display for GatewayCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "GatewayCreateError::{:?}", self)
+    }
+}
+
+impl core::error::Error for GatewayCreateError {}
+
+/// Failures that can occur in [`Gateway::process()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GatewayProcessError {
+    /// The local services could not be listed.
+    DiscoveryFailed,
+    /// A sample could not be received from a local service.
+    ReceiveFromServiceFailed,
+    /// A sample could not be published on a local service.
+    PublishToServiceFailed,
+    /// A frame could not be received from the remote gateway.
+    ReceiveFromNetworkFailed,
+    /// A frame could not be sent to the remote gateway.
+    SendToNetworkFailed,
+}
+
+impl core::fmt::Display for GatewayProcessError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "GatewayProcessError::{:?}", self)
+    }
+}
+
+impl core::error::Error for GatewayProcessError {}
+
+#[derive(Debug)]
+struct OutboundTopic<S: Service> {
+    subscriber: Subscriber<S, [CustomPayloadMarker], ()>,
+    announcement: Announcement,
+}
+
+#[derive(Debug)]
+struct InboundTopic<S: Service> {
+    // is None when the announced service is incompatible to the local service
+    publisher: Option<Publisher<S, [CustomPayloadMarker], ()>>,
+    // is None until the topic is announced by the current session of the remote gateway
+    topic_id: Option<u16>,
+    announcement: Announcement,
+}
+
+#[derive(Debug)]
+struct Peer {
+    session_id: u32,
+    last_sequence_number: u32,
+    last_activity: Instant,
+    is_connected: bool,
+}
+
+/// Mirrors the local services selected by the [`Mapping`] to the remote gateway and
+/// republishes the services of the remote gateway locally. The forwarding, the discovery and
+/// the connection handling are performed on every call of [`Gateway::process()`].
+#[derive(Debug)]
+pub struct Gateway<S: Service> {
+    mapping: Mapping,
+    socket: UdpServer,
+    remote_address: Ipv4Address,
+    remote_port: Port,
+    session_id: u32,
+    sequence_number: u32,
+    peer: Option<Peer>,
+    last_heartbeat: Option<Instant>,
+    last_discovery: Option<Instant>,
+    last_announcement_request: Option<Instant>,
+    number_of_lost_frames: u64,
+    outbound: Vec<OutboundTopic<S>>,
+    inbound: Vec<InboundTopic<S>>,
+    send_buffer: Vec<u8>,
+    receive_buffer: Vec<u8>,
+}
+
+fn to_ipv4_address(value: &str) -> Option<Ipv4Address> {
+    Ipv4Addr::from_str(value).ok().map(|v| {
+        let octets = v.octets();
+        Ipv4Address::new(octets[0], octets[1], octets[2], octets[3])
+    })
+}
+
+fn is_due(last: Option<Instant>, interval_ms: u64) -> bool {
+    match last {
+        Some(last) => last.elapsed() >= Duration::from_millis(interval_ms),
+        None => true,
+    }
+}
+
+impl<S: Service> Gateway<S> {
+    /// Creates a new [`Gateway`] that binds the UDP socket to the local [`Endpoint`]. The
+    /// services are discovered and announced in [`Gateway::process()`].
+    pub fn create(node: &Node<S>, mapping: &Mapping) -> Result<Self, GatewayCreateError> {
+        let msg = "Unable to create network gateway";
+        let origin = "Gateway::create()";
+
+        let (local_address, remote_address) = match (
+            to_ipv4_address(&mapping.endpoint.local_address),
+            to_ipv4_address(&mapping.endpoint.remote_address),
+        ) {
+            (Some(local), Some(remote)) => (local, remote),
+            _ => {
+                fail!(from origin, with GatewayCreateError::InvalidAddress,
+                    "{} since the endpoint {:?} contains an invalid IPv4 address.", msg, mapping.endpoint);
+            }
+        };
+
+        let socket = fail!(from origin, when UdpServerBuilder::new()
+                                .address(local_address)
+                                .port(Port::new(mapping.endpoint.local_port))
+                                .listen(),
+                with GatewayCreateError::UnableToCreateSocket,
+                "{} since the UDP socket could not be bound to {}:{}.",
+                msg, mapping.endpoint.local_address, mapping.endpoint.local_port);
+
+        let unique_id = fail!(from origin, when UniqueSystemId::new(),
+                with GatewayCreateError::UnableToCreateSessionId,
+                "{} since the session id could not be generated.", msg);
+        let session_id = unique_id.creation_time().nanoseconds() ^ unique_id.pid().value() as u32;
+
+        debug!(from origin, "Created gateway for the node {:?} with the session id {}.",
+            node.id(), session_id);
+
+        Ok(Self {
+            mapping: mapping.clone(),
+            socket,
+            remote_address,
+            remote_port: Port::new(mapping.endpoint.remote_port),
+            session_id,
+            sequence_number: 0,
+            peer: None,
+            last_heartbeat: None,
+            last_discovery: None,
+            last_announcement_request: None,
+            number_of_lost_frames: 0,
+            outbound: vec![],
+            inbound: vec![],
+            send_buffer: vec![0; MAX_FRAME_SIZE],
+            receive_buffer: vec![0; MAX_FRAME_SIZE],
+        })
+    }
+
+    /// Returns the session id that identifies the lifetime of the [`Gateway`].
+    pub fn session_id(&self) -> u32 {
+        self.session_id
+    }
+
+    /// Returns true when a frame of the remote gateway was received within the
+    /// [`Endpoint::peer_timeout_ms`].
+    pub fn is_peer_connected(&self) -> bool {
+        self.peer.as_ref().is_some_and(|peer| peer.is_connected)
+    }
+
+    /// Returns the number of frames of the remote gateway that were lost in transmission.
+    pub fn number_of_lost_frames(&self) -> u64 {
+        self.number_of_lost_frames
+    }
+
+    /// Returns the number of local services that are mirrored to the remote gateway.
+    pub fn number_of_mirrored_services(&self) -> usize {
+        self.outbound.len()
+    }
+
+    /// Discovers new local services, sends heartbeats, forwards all samples of the mirrored
+    /// services to the remote gateway and publishes all samples received from the remote
+    /// gateway on the corresponding local services. Returns the number of forwarded samples.
+    pub fn process(&mut self, node: &Node<S>) -> Result<usize, GatewayProcessError> {
+        self.update_connection()?;
+
+        if is_due(
+            self.last_discovery,
+            self.mapping.endpoint.discovery_interval_ms,
+        ) {
+            self.last_discovery = Some(Instant::now());
+            self.discover_services(node)?;
+        }
+
+        Ok(self.forward_to_network()? + self.forward_to_services(node)?)
+    }
+
+    fn update_connection(&mut self) -> Result<(), GatewayProcessError> {
+        let peer_timeout = Duration::from_millis(self.mapping.endpoint.peer_timeout_ms);
+        if let Some(peer) = &mut self.peer {
+            if peer.is_connected && peer.last_activity.elapsed() > peer_timeout {
+                peer.is_connected = false;
+                warn!(from "Gateway::update_connection()",
+                    "Lost the connection to the remote gateway with the session id {} since no frame was received for {:?}.",
+                    peer.session_id, peer_timeout);
+            }
+        }
+
+        if is_due(
+            self.last_heartbeat,
+            self.mapping.endpoint.heartbeat_interval_ms,
+        ) {
+            self.last_heartbeat = Some(Instant::now());
+            self.send_frame(FrameType::Heartbeat, 0, 0)?;
+        }
+
+        Ok(())
+    }
+
+    fn discover_services(&mut self, node: &Node<S>) -> Result<(), GatewayProcessError> {
+        let without_user_header = TypeDetail::__internal_new::<()>(TypeVariant::FixedSize);
+        let mut new_services = vec![];
+
+        let list_result = S::list(node.config(), |details| {
+            if let MessagingPattern::PublishSubscribe(config) =
+                details.static_details.messaging_pattern()
+            {
+                let service_name = details.static_details.name().as_str();
+                let type_details = config.message_type_details();
+                if type_details.user_header == without_user_header
+                    && self.mapping.is_mirrored(service_name)
+                    && !self
+                        .outbound
+                        .iter()
+                        .any(|t| t.announcement.service_name == service_name)
+                {
+                    new_services.push(Announcement {
+                        service_name: service_name.to_string(),
+                        payload_type: type_details.payload.clone(),
+                        payload_type_descriptor: type_details.payload_type_descriptor.clone(),
+                    });
+                }
+            }
+            CallbackProgression::Continue
+        });
+
+        fail!(from self, when list_result,
+            with GatewayProcessError::DiscoveryFailed,
+            "Unable to discover the local services since they could not be listed.");
+
+        for announcement in new_services {
+            if self.outbound.len() > u16::MAX as usize {
+                warn!(from self,
+                    "Unable to mirror the service \"{}\" since the maximum number of mirrored services is reached.",
+                    announcement.service_name);
+                break;
+            }
+
+            if let Some(subscriber) = self.create_subscriber(node, &announcement) {
+                let topic_id = self.outbound.len() as u16;
+                self.outbound.push(OutboundTopic {
+                    subscriber,
+                    announcement,
+                });
+                self.announce(topic_id)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn create_subscriber(
+        &self,
+        node: &Node<S>,
+        announcement: &Announcement,
+    ) -> Option<Subscriber<S, [CustomPayloadMarker], ()>> {
+        let msg = "Unable to mirror the service";
+        // the service was listed before, the name is valid
+        let service_name = ServiceName::new(&announcement.service_name).ok()?;
+
+        let mut builder = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .__internal_set_payload_type_details(&announcement.payload_type)
+        };
+        if let Some(descriptor) = &announcement.payload_type_descriptor {
+            builder = unsafe { builder.__internal_set_payload_type_descriptor(descriptor) };
+        }
+
+        // the service may disappear or reject new subscribers, the discovery tries it again
+        let service = match builder.open() {
+            Ok(service) => service,
+            Err(e) => {
+                debug!(from self, "{} \"{}\" since it could not be opened ({:?}).",
+                    msg, announcement.service_name, e);
+                return None;
+            }
+        };
+
+        match service.subscriber_builder().create() {
+            Ok(subscriber) => {
+                info!(from self, "Mirroring the service \"{}\" with the payload type {:?}.",
+                    announcement.service_name, announcement.payload_type);
+                Some(subscriber)
+            }
+            Err(e) => {
+                debug!(from self, "{} \"{}\" since the subscriber could not be created ({:?}).",
+                    msg, announcement.service_name, e);
+                None
+            }
+        }
+    }
+
+    fn send_frame(
+        &mut self,
+        frame_type: FrameType,
+        topic_id: u16,
+        payload_length: usize,
+    ) -> Result<(), GatewayProcessError> {
+        self.sequence_number = self.sequence_number.wrapping_add(1);
+        let header = FrameHeader {
+            frame_type,
+            topic_id,
+            session_id: self.session_id,
+            sequence_number: self.sequence_number,
+            payload_length: payload_length as u32,
+        };
+        header.write_to(&mut self.send_buffer);
+
+        fail!(from self, when self.socket.send_to(
+                &self.send_buffer[..FRAME_HEADER_SIZE + payload_length],
+                self.remote_address,
+                self.remote_port),
+            with GatewayProcessError::SendToNetworkFailed,
+            "Unable to send the frame {:?} to the remote gateway.", header);
+
+        Ok(())
+    }
+
+    fn announce(&mut self, topic_id: u16) -> Result<(), GatewayProcessError> {
+        let mut payload = vec![];
+        self.outbound[topic_id as usize]
+            .announcement
+            .write_to(&mut payload);
+        self.send_buffer[FRAME_HEADER_SIZE..FRAME_HEADER_SIZE + payload.len()]
+            .copy_from_slice(&payload);
+        self.send_frame(FrameType::Announcement, topic_id, payload.len())
+    }
+
+    fn announce_all(&mut self) -> Result<(), GatewayProcessError> {
+        for topic_id in 0..self.outbound.len() {
+            self.announce(topic_id as u16)?;
+        }
+
+        Ok(())
+    }
+
+    fn request_announcements(&mut self) -> Result<(), GatewayProcessError> {
+        // the remote gateway answers with all announcements, one request per heartbeat
+        // interval suffices
+        if is_due(
+            self.last_announcement_request,
+            self.mapping.endpoint.heartbeat_interval_ms,
+        ) {
+            self.last_announcement_request = Some(Instant::now());
+            self.send_frame(FrameType::AnnouncementRequest, 0, 0)?;
+        }
+
+        Ok(())
+    }
+
+    fn forward_to_network(&mut self) -> Result<usize, GatewayProcessError> {
+        let msg = "Unable to forward samples to the remote gateway";
+        let mut number_of_samples = 0;
+
+        for topic_id in 0..self.outbound.len() {
+            loop {
+                let sample =
+                    match unsafe { self.outbound[topic_id].subscriber.receive_custom_payload() } {
+                        Ok(Some(sample)) => sample,
+                        Ok(None) => break,
+                        Err(e) => {
+                            fail!(from self, with GatewayProcessError::ReceiveFromServiceFailed,
+                                "{} since a sample could not be received ({:?}).", msg, e);
+                        }
+                    };
+
+                // samples that were received from the remote gateway are not sent back
+                if self
+                    .inbound
+                    .iter()
+                    .filter_map(|t| t.publisher.as_ref())
+                    .any(|p| p.id() == sample.origin())
+                {
+                    continue;
+                }
+
+                // CustomPayloadMarker is a transparent byte
+                let payload = sample.payload();
+                let payload = unsafe {
+                    core::slice::from_raw_parts(payload.as_ptr().cast::<u8>(), payload.len())
+                };
+
+                if FRAME_HEADER_SIZE + payload.len() > MAX_FRAME_SIZE {
+                    warn!(from self,
+                        "Dropping sample of size {} of the service \"{}\" since it exceeds the maximum frame size of {}.",
+                        payload.len(), self.outbound[topic_id].announcement.service_name, MAX_FRAME_SIZE);
+                    continue;
+                }
+
+                let payload_length = payload.len();
+                self.send_buffer[FRAME_HEADER_SIZE..FRAME_HEADER_SIZE + payload_length]
+                    .copy_from_slice(payload);
+                drop(sample);
+
+                self.send_frame(FrameType::Sample, topic_id as u16, payload_length)?;
+                number_of_samples += 1;
+            }
+        }
+
+        Ok(number_of_samples)
+    }
+
+    fn forward_to_services(&mut self, node: &Node<S>) -> Result<usize, GatewayProcessError> {
+        let msg = "Unable to forward frames to the services";
+        let mut number_of_samples = 0;
+
+        loop {
+            let details = match self.socket.try_receive_from(&mut self.receive_buffer) {
+                Ok(Some(details)) => details,
+                Ok(None) => break,
+                Err(e) => {
+                    fail!(from self, with GatewayProcessError::ReceiveFromNetworkFailed,
+                        "{} since the socket could not receive data ({:?}).", msg, e);
+                }
+            };
+
+            if details.source_ip != self.remote_address {
+                warn!(from self, "Dropping frame from {}:{} since it is not the remote gateway.",
+                    details.source_ip, details.source_port);
+                continue;
+            }
+
+            let header =
+                match FrameHeader::from_bytes(&self.receive_buffer[..details.number_of_bytes]) {
+                    Ok(header) => header,
+                    Err(e) => {
+                        warn!(from self, "Dropping invalid frame from {}:{} ({:?}).",
+                            details.source_ip, details.source_port, e);
+                        continue;
+                    }
+                };
+
+            self.update_peer(&header)?;
+
+            let payload_range =
+                FRAME_HEADER_SIZE..FRAME_HEADER_SIZE + header.payload_length as usize;
+            match header.frame_type {
+                FrameType::Heartbeat => (),
+                FrameType::AnnouncementRequest => self.announce_all()?,
+                FrameType::Announcement => {
+                    match Announcement::from_bytes(&self.receive_buffer[payload_range]) {
+                        Ok(announcement) => {
+                            self.add_inbound_topic(node, header.topic_id, announcement)
+                        }
+                        Err(e) => {
+                            warn!(from self, "Dropping invalid announcement of the topic {} ({:?}).",
+                                header.topic_id, e);
+                        }
+                    }
+                }
+                FrameType::Sample => {
+                    if self.publish_sample(header.topic_id, payload_range)? {
+                        number_of_samples += 1;
+                    }
+                }
+            }
+        }
+
+        Ok(number_of_samples)
+    }
+
+    fn update_peer(&mut self, header: &FrameHeader) -> Result<(), GatewayProcessError> {
+        let now = Instant::now();
+        let mut has_reconnected = false;
+
+        match &mut self.peer {
+            Some(peer) if peer.session_id == header.session_id => {
+                let number_of_missing_frames = header
+                    .sequence_number
+                    .wrapping_sub(peer.last_sequence_number.wrapping_add(1));
+                // a large difference indicates a reordered frame and not a lost one
+                if number_of_missing_frames < u32::MAX / 2 {
+                    self.number_of_lost_frames += number_of_missing_frames as u64;
+                    peer.last_sequence_number = header.sequence_number;
+                }
+                peer.last_activity = now;
+
+                if !peer.is_connected {
+                    peer.is_connected = true;
+                    has_reconnected = true;
+                    info!(from "Gateway::update_peer()",
+                        "Reconnected to the remote gateway with the session id {}.", peer.session_id);
+                }
+            }
+            peer => {
+                match peer {
+                    Some(previous) => {
+                        info!(from "Gateway::update_peer()",
+                            "The remote gateway was restarted, the session id changed from {} to {}.",
+                            previous.session_id, header.session_id);
+                    }
+                    None => {
+                        info!(from "Gateway::update_peer()",
+                            "Connected to the remote gateway with the session id {}.", header.session_id);
+                    }
+                }
+
+                *peer = Some(Peer {
+                    session_id: header.session_id,
+                    last_sequence_number: header.sequence_number,
+                    last_activity: now,
+                    is_connected: true,
+                });
+
+                // the topic ids are only valid within a session of the remote gateway
+                for topic in &mut self.inbound {
+                    topic.topic_id = None;
+                }
+                has_reconnected = true;
+                self.last_announcement_request = None;
+                self.request_announcements()?;
+            }
+        }
+
+        // the remote gateway may have missed the announcements while it was not reachable
+        if has_reconnected {
+            self.announce_all()?;
+        }
+
+        Ok(())
+    }
+
+    fn add_inbound_topic(&mut self, node: &Node<S>, topic_id: u16, announcement: Announcement) {
+        for topic in &mut self.inbound {
+            if topic.topic_id == Some(topic_id) {
+                topic.topic_id = None;
+            }
+        }
+
+        if let Some(topic) = self
+            .inbound
+            .iter_mut()
+            .find(|t| t.announcement == announcement)
+        {
+            topic.topic_id = Some(topic_id);
+            return;
+        }
+
+        let publisher = self.create_publisher(node, &announcement);
+        self.inbound.push(InboundTopic {
+            publisher,
+            topic_id: Some(topic_id),
+            announcement,
+        });
+    }
+
+    fn create_publisher(
+        &self,
+        node: &Node<S>,
+        announcement: &Announcement,
+    ) -> Option<Publisher<S, [CustomPayloadMarker], ()>> {
+        let msg = "Unable to republish the announced service";
+        let service_name = match ServiceName::new(&announcement.service_name) {
+            Ok(service_name) => service_name,
+            Err(e) => {
+                warn!(from self, "{} since \"{}\" is not a valid service name ({:?}).",
+                    msg, announcement.service_name, e);
+                return None;
+            }
+        };
+
+        let mut builder = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .__internal_set_payload_type_details(&announcement.payload_type)
+        };
+        if let Some(descriptor) = &announcement.payload_type_descriptor {
+            builder = unsafe { builder.__internal_set_payload_type_descriptor(descriptor) };
+        }
+
+        let service = match builder.open_or_create() {
+            Ok(service) => service,
+            Err(e) => {
+                warn!(from self,
+                    "{} \"{}\" with the payload type {:?} since it could not be opened or created ({:?}).",
+                    msg, announcement.service_name, announcement.payload_type, e);
+                return None;
+            }
+        };
+
+        let mut publisher_builder = service.publisher_builder();
+        // the deterministic profile rejects dynamic allocation strategies, the payload is then
+        // limited to the initial max slice length
+        if announcement.payload_type.variant == TypeVariant::Dynamic
+            && !iceoryx2::deterministic_profile::IS_ENABLED
+        {
+            publisher_builder =
+                publisher_builder.allocation_strategy(AllocationStrategy::PowerOfTwo);
+        }
+
+        match publisher_builder.create() {
+            Ok(publisher) => Some(publisher),
+            Err(e) => {
+                warn!(from self, "{} \"{}\" since the publisher could not be created ({:?}).",
+                    msg, announcement.service_name, e);
+                None
+            }
+        }
+    }
+
+    fn publish_sample(
+        &mut self,
+        topic_id: u16,
+        payload_range: core::ops::Range<usize>,
+    ) -> Result<bool, GatewayProcessError> {
+        let msg = "Unable to publish the sample of the remote gateway";
+        let topic = match self.inbound.iter().find(|t| t.topic_id == Some(topic_id)) {
+            Some(topic) => topic,
+            None => {
+                // the announcement was lost, the remote gateway announces all topics again
+                self.request_announcements()?;
+                return Ok(false);
+            }
+        };
+
+        let publisher = match &topic.publisher {
+            Some(publisher) => publisher,
+            None => return Ok(false),
+        };
+
+        let payload = &self.receive_buffer[payload_range];
+        let payload_type = &topic.announcement.payload_type;
+        let number_of_elements = match payload_type.variant {
+            TypeVariant::FixedSize if payload.len() == payload_type.size => 1,
+            TypeVariant::Dynamic
+                if payload_type.size != 0 && payload.len() % payload_type.size == 0 =>
+            {
+                payload.len() / payload_type.size
+            }
+            _ => {
+                warn!(from self,
+                    "Dropping sample of size {} of the service \"{}\" since it is no valid instance of the payload type {:?}.",
+                    payload.len(), topic.announcement.service_name, payload_type);
+                return Ok(false);
+            }
+        };
+
+        let mut sample = fail!(from self, when unsafe { publisher.loan_custom_payload(number_of_elements) },
+            with GatewayProcessError::PublishToServiceFailed,
+            "{} since a sample could not be loaned.", msg);
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                payload.as_ptr(),
+                sample.payload_mut().as_mut_ptr().cast::<u8>(),
+                payload.len(),
+            )
+        };
+        fail!(from self, when unsafe { sample.assume_init() }.send(),
+            with GatewayProcessError::PublishToServiceFailed,
+            "{} since the sample could not be sent.", msg);
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+/// The size of the SOME/IP header on the wire in bytes.
+pub const HEADER_SIZE: usize = 16;
+
+/// The SOME/IP protocol version that is supported by the gateway.
+pub const PROTOCOL_VERSION: u8 = 0x01;
+
+/// The number of bytes of the header that are included in the length field: request id,
+/// protocol version, interface version, message type and return code.
+const LENGTH_OFFSET: u32 = 8;
+
+/// The SOME/IP message types.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageType {
+    /// A request expecting a response
+    Request = 0x00,
+    /// A fire and forget request
+    RequestNoReturn = 0x01,
+    /// An event or field notification
+    Notification = 0x02,
+    /// The response to a [`MessageType::Request`]
+    Response = 0x80,
+    /// The response to a [`MessageType::Request`] containing an error
+    Error = 0x81,
+}
+
+impl MessageType {
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0x00 => Some(MessageType::Request),
+            0x01 => Some(MessageType::RequestNoReturn),
+            0x02 => Some(MessageType::Notification),
+            0x80 => Some(MessageType::Response),
+            0x81 => Some(MessageType::Error),
+            _ => None,
+        }
+    }
+}
+
+/// Failures that can occur when a [`Header`] is deserialized with [`Header::from_bytes()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeaderDecodeError {
+    /// The buffer is smaller than [`HEADER_SIZE`] or smaller than announced in the length field.
+    InsufficientData,
+    /// The protocol version is not [`PROTOCOL_VERSION`].
+    UnsupportedProtocolVersion,
+    /// The message type is unknown.
+    UnknownMessageType,
+}
+
+impl core::fmt::Display for HeaderDecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "HeaderDecodeError::{:?}", self)
+    }
+}
+
+impl core::error::Error for HeaderDecodeError {}
+
+/// The header that precedes every SOME/IP message. All fields are transmitted in network
+/// byte order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Header {
+    /// The SOME/IP service id
+    pub service_id: u16,
+    /// The method id, or for events and fields the event/notifier id
+    pub method_id: u16,
+    /// The client id of the requester
+    pub client_id: u16,
+    /// The session id, incremented with every message
+    pub session_id: u16,
+    /// The interface version of the service
+    pub interface_version: u8,
+    /// The [`MessageType`] of the message
+    pub message_type: MessageType,
+    /// The return code, `0` on success
+    pub return_code: u8,
+    /// The length of the payload that follows the header
+    pub payload_length: u32,
+}
+
+impl Header {
+    /// Serializes the [`Header`] into the first [`HEADER_SIZE`] bytes of the buffer.
+    ///
+    /// # Panics
+    ///
+    ///  * When the buffer is smaller than [`HEADER_SIZE`].
+    pub fn write_to(&self, buffer: &mut [u8]) {
+        buffer[0..2].copy_from_slice(&self.service_id.to_be_bytes());
+        buffer[2..4].copy_from_slice(&self.method_id.to_be_bytes());
+        buffer[4..8].copy_from_slice(&(self.payload_length + LENGTH_OFFSET).to_be_bytes());
+        buffer[8..10].copy_from_slice(&self.client_id.to_be_bytes());
+        buffer[10..12].copy_from_slice(&self.session_id.to_be_bytes());
+        buffer[12] = PROTOCOL_VERSION;
+        buffer[13] = self.interface_version;
+        buffer[14] = self.message_type as u8;
+        buffer[15] = self.return_code;
+    }
+
+    /// Deserializes the [`Header`] from the buffer. The buffer must contain at least the
+    /// header and the payload announced in the length field.
+    pub fn from_bytes(buffer: &[u8]) -> Result<Self, HeaderDecodeError> {
+        if buffer.len() < HEADER_SIZE {
+            return Err(HeaderDecodeError::InsufficientData);
+        }
+
+        let u16_at = |i: usize| u16::from_be_bytes([buffer[i], buffer[i + 1]]);
+        let length = u32::from_be_bytes([buffer[4], buffer[5], buffer[6], buffer[7]]);
+
+        if length < LENGTH_OFFSET || buffer.len() < HEADER_SIZE + (length - LENGTH_OFFSET) as usize
+        {
+            return Err(HeaderDecodeError::InsufficientData);
+        }
+
+        if buffer[12] != PROTOCOL_VERSION {
+            return Err(HeaderDecodeError::UnsupportedProtocolVersion);
+        }
+
+        let message_type = match MessageType::from_u8(buffer[14]) {
+            Some(v) => v,
+            None => return Err(HeaderDecodeError::UnknownMessageType),
+        };
+
+        Ok(Self {
+            service_id: u16_at(0),
+            method_id: u16_at(2),
+            client_id: u16_at(8),
+            session_id: u16_at(10),
+            interface_version: buffer[13],
+            message_type,
+            return_code: buffer[15],
+            payload_length: length - LENGTH_OFFSET,
+        })
+    }
+}
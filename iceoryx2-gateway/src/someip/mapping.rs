@@ -0,0 +1,208 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_log::{fail, trace};
+use iceoryx2_bb_posix::file::{AccessMode, FileBuilder, FileOpenError};
+use iceoryx2_bb_system_types::file_path::FilePath;
+use serde::{Deserialize, Serialize};
+
+/// Failures occurring while loading a [`Mapping`] with [`Mapping::from_file()`] or
+/// [`Mapping::from_toml()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum MappingCreationError {
+    /// The mapping file could not be read.
+    FailedToReadMappingFileContents,
+    /// Parts of the mapping could not be deserialized. Indicates some kind of syntax error.
+    UnableToDeserializeContents,
+    /// Insufficient permissions to open the mapping file.
+    InsufficientPermissions,
+    /// The provided mapping file does not exist
+    MappingFileDoesNotExist,
+    /// Since the mapping file could not be opened
+    UnableToOpenMappingFile,
+}
+
+impl core::fmt::Display for MappingCreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "MappingCreationError::{:?}", self)
+    }
+}
+
+impl core::error::Error for MappingCreationError {}
+
+/// Defines in which direction the data of an [`EventMapping`] or [`MethodMapping`] flows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Direction {
+    /// Samples of the iceoryx2 service are sent as SOME/IP notifications. Requests of iceoryx2
+    /// clients are sent as SOME/IP requests to the remote ECU and its responses are returned
+    /// to the iceoryx2 client.
+    ToSomeIp,
+    /// SOME/IP notifications are published as samples on the iceoryx2 service. SOME/IP
+    /// requests of the remote ECU are sent to the iceoryx2 servers and their responses are
+    /// returned as SOME/IP responses.
+    FromSomeIp,
+}
+
+/// The network endpoints of the gateway.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Endpoint {
+    /// The IPv4 address the gateway binds to, e.g. `"0.0.0.0"`
+    pub local_address: String,
+    /// The UDP port the gateway binds to
+    pub local_port: u16,
+    /// The IPv4 address of the remote SOME/IP ECU
+    pub remote_address: String,
+    /// The UDP port of the remote SOME/IP ECU
+    pub remote_port: u16,
+    /// The client id used in the SOME/IP header of every outgoing message
+    #[serde(default)]
+    pub client_id: u16,
+    /// The interface version used in the SOME/IP header of every outgoing message
+    #[serde(default = "default_interface_version")]
+    pub interface_version: u8,
+}
+
+fn default_interface_version() -> u8 {
+    1
+}
+
+/// Maps a SOME/IP event to an iceoryx2 publish-subscribe service. When used as
+/// [`Mapping::field`] the iceoryx2 service is created with a history of one so that late joining
+/// subscribers receive the latest field value.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct EventMapping {
+    /// The name of the iceoryx2 publish-subscribe service with the payload type `[u8]`
+    pub service_name: String,
+    /// The SOME/IP service id
+    pub someip_service_id: u16,
+    /// The SOME/IP event id or notifier id of the field
+    pub someip_event_id: u16,
+    /// In which direction the data is forwarded
+    pub direction: Direction,
+}
+
+/// Maps a SOME/IP method to an iceoryx2 request-response service with the request and
+/// response payload type `[u8]`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MethodMapping {
+    /// The name of the iceoryx2 request-response service
+    pub service_name: String,
+    /// The SOME/IP service id
+    pub someip_service_id: u16,
+    /// The SOME/IP method id
+    pub someip_method_id: u16,
+    /// [`Direction::ToSomeIp`] when the method is provided by the remote ECU,
+    /// [`Direction::FromSomeIp`] when it is provided by iceoryx2 servers
+    pub direction: Direction,
+    /// The time in milliseconds after which a request without response is discarded
+    #[serde(default = "default_response_timeout_ms")]
+    pub response_timeout_ms: u64,
+}
+
+fn default_response_timeout_ms() -> u64 {
+    1000
+}
+
+/// The mapping between iceoryx2 services and SOME/IP service instances, usually loaded from a
+/// TOML file.
+///
+/// ```toml
+/// [endpoint]
+/// local-address = "0.0.0.0"
+/// local-port = 30501
+/// remote-address = "192.168.0.10"
+/// remote-port = 30501
+///
+/// [[event]]
+/// service-name = "Vehicle/Speed"
+/// someip-service-id = 0x1234
+/// someip-event-id = 0x8001
+/// direction = "from-some-ip"
+///
+/// [[field]]
+/// service-name = "Vehicle/Mode"
+/// someip-service-id = 0x1234
+/// someip-event-id = 0x8002
+/// direction = "to-some-ip"
+///
+/// [[method]]
+/// service-name = "Vehicle/SetMode"
+/// someip-service-id = 0x1234
+/// someip-method-id = 0x0001
+/// direction = "to-some-ip"
+/// response-timeout-ms = 500
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Mapping {
+    /// The network endpoints
+    pub endpoint: Endpoint,
+    /// SOME/IP events mapped to publish-subscribe services
+    #[serde(default)]
+    pub event: Vec<EventMapping>,
+    /// SOME/IP fields mapped to latched publish-subscribe services
+    #[serde(default)]
+    pub field: Vec<EventMapping>,
+    /// SOME/IP methods mapped to request-response services
+    #[serde(default)]
+    pub method: Vec<MethodMapping>,
+}
+
+impl Mapping {
+    /// Deserializes a [`Mapping`] from a TOML string.
+    pub fn from_toml(contents: &str) -> Result<Mapping, MappingCreationError> {
+        match toml::from_str(contents) {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                fail!(from "Mapping::from_toml()", with MappingCreationError::UnableToDeserializeContents,
+                    "Failed to create mapping since the contents could not be deserialized ({}).", e);
+            }
+        }
+    }
+
+    /// Loads a [`Mapping`] from a TOML file.
+    pub fn from_file(mapping_file: &FilePath) -> Result<Mapping, MappingCreationError> {
+        let msg = "Failed to create mapping";
+        let origin = "Mapping::from_file()";
+
+        let file = match FileBuilder::new(mapping_file).open_existing(AccessMode::Read) {
+            Ok(file) => file,
+            Err(FileOpenError::InsufficientPermissions) => {
+                fail!(from origin, with MappingCreationError::InsufficientPermissions,
+                    "{} since the mapping file \"{}\" could not be opened due to insufficient permissions.",
+                    msg, mapping_file);
+            }
+            Err(FileOpenError::FileDoesNotExist) => {
+                fail!(from origin, with MappingCreationError::MappingFileDoesNotExist,
+                    "{} since the mapping file \"{}\" does not exist.", msg, mapping_file);
+            }
+            Err(e) => {
+                fail!(from origin, with MappingCreationError::UnableToOpenMappingFile,
+                    "{} since the mapping file \"{}\" could not be open due to an internal error ({:?}).",
+                    msg, mapping_file, e);
+            }
+        };
+
+        let mut contents = String::new();
+        fail!(from origin, when file.read_to_string(&mut contents),
+            with MappingCreationError::FailedToReadMappingFileContents,
+            "{} since the mapping file contents could not be read.", msg);
+
+        let mapping = Self::from_toml(&contents)?;
+        trace!(from mapping, "Loaded.");
+        Ok(mapping)
+    }
+}
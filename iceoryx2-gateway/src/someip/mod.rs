@@ -0,0 +1,739 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Translates iceoryx2 services into SOME/IP service instances so that classic automotive ECUs
+//! can interoperate with iceoryx2 based applications.
+//!
+//!  * SOME/IP events are mapped to publish-subscribe services with the payload type `[u8]`
+//!  * SOME/IP fields are mapped to publish-subscribe services with a history of one, so that
+//!    the latest value is latched and delivered to every late joining subscriber
+//!  * SOME/IP methods are mapped to request-response services with the request and response
+//!    payload type `[u8]`. Methods provided by the remote ECU are offered to iceoryx2 clients
+//!    by a server of the gateway, methods provided by iceoryx2 servers are called by a client
+//!    of the gateway. Exactly one response is forwarded per request.
+//!
+//! The payload is forwarded as is, the (de-)serialization is the responsibility of the
+//! application. Only SOME/IP over UDP is supported, service discovery (SOME/IP-SD) is not
+//! performed, the remote endpoint is defined in the [`Mapping`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_gateway::someip::{Gateway, Mapping};
+//! use core::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let mapping = Mapping::from_toml(r#"
+//!     [endpoint]
+//!     local-address = "0.0.0.0"
+//!     local-port = 30501
+//!     remote-address = "192.168.0.10"
+//!     remote-port = 30501
+//!
+//!     [[event]]
+//!     service-name = "Vehicle/Speed"
+//!     someip-service-id = 0x1234
+//!     someip-event-id = 0x8001
+//!     direction = "from-some-ip"
+//! "#)?;
+//!
+//! let mut gateway = Gateway::create(&node, &mapping)?;
+//!
+//! while node.wait(Duration::from_millis(10)).is_ok() {
+//!     gateway.process()?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+mod header;
+mod mapping;
+
+pub use header::*;
+pub use mapping::*;
+
+use core::fmt::Debug;
+use core::net::Ipv4Addr;
+use core::str::FromStr;
+use core::time::Duration;
+use std::time::Instant;
+
+use iceoryx2::active_request::ActiveRequest;
+use iceoryx2::pending_response::PendingResponse;
+use iceoryx2::port::client::{Client, ResponseReceiveError};
+use iceoryx2::port::publisher::Publisher;
+use iceoryx2::port::server::Server;
+use iceoryx2::port::subscriber::Subscriber;
+use iceoryx2::prelude::*;
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::udp_socket::{UdpServer, UdpServerBuilder};
+use iceoryx2_bb_system_types::ipv4_address::Ipv4Address;
+use iceoryx2_bb_system_types::port::Port;
+
+/// The maximum size of a SOME/IP message transported via UDP.
+pub const MAX_UDP_MESSAGE_SIZE: usize = 1416;
+
+/// The SOME/IP return code `E_NOT_OK`, sent when the iceoryx2 server responded with an error
+/// or the request could not be delivered.
+pub const RETURN_CODE_NOT_OK: u8 = 0x01;
+
+/// The SOME/IP return code `E_NOT_REACHABLE`, sent when no iceoryx2 server is connected or
+/// no response was received within the response timeout.
+pub const RETURN_CODE_NOT_REACHABLE: u8 = 0x02;
+
+/// Failures that can occur when a [`Gateway`] is created with [`Gateway::create()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GatewayCreateError {
+    /// An address in the [`Endpoint`] is not a valid IPv4 address.
+    InvalidAddress,
+    /// A service name in the [`Mapping`] is not a valid [`ServiceName`].
+    InvalidServiceName,
+    /// The UDP socket could not be created or bound to the local endpoint.
+    UnableToCreateSocket,
+    /// The iceoryx2 service could not be created or opened.
+    UnableToOpenService,
+    /// The publisher, subscriber, client or server port of the iceoryx2 service could not be
+    /// created.
+    UnableToCreatePort,
+}
+
+impl core::fmt::Display for GatewayCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "GatewayCreateError::{:?}", self)
+    }
+}
+
+impl core::error::Error for GatewayCreateError {}
+
+/// Failures that can occur in [`Gateway::process()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GatewayProcessError {
+    /// A sample could not be received from an iceoryx2 service.
+    ReceiveFromServiceFailed,
+    /// A sample could not be published on an iceoryx2 service.
+    PublishToServiceFailed,
+    /// A SOME/IP message could not be received.
+    ReceiveFromNetworkFailed,
+    /// A SOME/IP message could not be sent.
+    SendToNetworkFailed,
+}
+
+impl core::fmt::Display for GatewayProcessError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "GatewayProcessError::{:?}", self)
+    }
+}
+
+impl core::error::Error for GatewayProcessError {}
+
+#[derive(Debug)]
+struct OutboundTopic<S: Service> {
+    subscriber: Subscriber<S, [u8], ()>,
+    service_id: u16,
+    event_id: u16,
+}
+
+#[derive(Debug)]
+struct InboundTopic<S: Service> {
+    publisher: Publisher<S, [u8], ()>,
+    service_id: u16,
+    event_id: u16,
+}
+
+/// A method of the remote ECU that is offered to iceoryx2 clients.
+#[derive(Debug)]
+struct OutboundMethod<S: Service> {
+    server: Server<S, [u8], (), [u8], ()>,
+    service_id: u16,
+    method_id: u16,
+    response_timeout: Duration,
+}
+
+/// An iceoryx2 request that was forwarded to the remote ECU and awaits its SOME/IP response.
+#[derive(Debug)]
+struct OutboundCall<S: Service> {
+    active_request: ActiveRequest<S, [u8], (), [u8], ()>,
+    service_id: u16,
+    method_id: u16,
+    session_id: u16,
+    deadline: Instant,
+}
+
+/// A method of the iceoryx2 servers that is offered to the remote ECU.
+#[derive(Debug)]
+struct InboundMethod<S: Service> {
+    client: Client<S, [u8], (), [u8], ()>,
+    service_id: u16,
+    method_id: u16,
+    response_timeout: Duration,
+}
+
+/// A SOME/IP request that was forwarded to the iceoryx2 servers and awaits their response.
+#[derive(Debug)]
+struct InboundCall<S: Service> {
+    pending_response: PendingResponse<S, [u8], (), [u8], ()>,
+    request: Header,
+}
+
+/// Forwards the data between the iceoryx2 services and the SOME/IP network as defined in the
+/// [`Mapping`]. The forwarding is performed on every call of [`Gateway::process()`].
+#[derive(Debug)]
+pub struct Gateway<S: Service> {
+    socket: UdpServer,
+    remote_address: Ipv4Address,
+    remote_port: Port,
+    client_id: u16,
+    interface_version: u8,
+    session_id: u16,
+    outbound: Vec<OutboundTopic<S>>,
+    inbound: Vec<InboundTopic<S>>,
+    outbound_methods: Vec<OutboundMethod<S>>,
+    outbound_calls: Vec<OutboundCall<S>>,
+    inbound_methods: Vec<InboundMethod<S>>,
+    inbound_calls: Vec<InboundCall<S>>,
+    buffer: Vec<u8>,
+}
+
+fn to_ipv4_address(value: &str) -> Option<Ipv4Address> {
+    Ipv4Addr::from_str(value).ok().map(|v| {
+        let octets = v.octets();
+        Ipv4Address::new(octets[0], octets[1], octets[2], octets[3])
+    })
+}
+
+impl<S: Service> Gateway<S> {
+    /// Creates a new [`Gateway`] that opens or creates all iceoryx2 services defined in the
+    /// [`Mapping`] and binds the UDP socket to the local [`Endpoint`].
+    pub fn create(node: &Node<S>, mapping: &Mapping) -> Result<Self, GatewayCreateError> {
+        let msg = "Unable to create SOME/IP gateway";
+        let origin = "Gateway::create()";
+
+        let (local_address, remote_address) = match (
+            to_ipv4_address(&mapping.endpoint.local_address),
+            to_ipv4_address(&mapping.endpoint.remote_address),
+        ) {
+            (Some(local), Some(remote)) => (local, remote),
+            _ => {
+                fail!(from origin, with GatewayCreateError::InvalidAddress,
+                    "{} since the endpoint {:?} contains an invalid IPv4 address.", msg, mapping.endpoint);
+            }
+        };
+
+        let socket = fail!(from origin, when UdpServerBuilder::new()
+                                .address(local_address)
+                                .port(Port::new(mapping.endpoint.local_port))
+                                .listen(),
+                with GatewayCreateError::UnableToCreateSocket,
+                "{} since the UDP socket could not be bound to {}:{}.",
+                msg, mapping.endpoint.local_address, mapping.endpoint.local_port);
+
+        let mut new_self = Self {
+            socket,
+            remote_address,
+            remote_port: Port::new(mapping.endpoint.remote_port),
+            client_id: mapping.endpoint.client_id,
+            interface_version: mapping.endpoint.interface_version,
+            session_id: 0,
+            outbound: vec![],
+            inbound: vec![],
+            outbound_methods: vec![],
+            outbound_calls: vec![],
+            inbound_methods: vec![],
+            inbound_calls: vec![],
+            buffer: vec![0; MAX_UDP_MESSAGE_SIZE],
+        };
+
+        for event in &mapping.event {
+            new_self.add_topic(node, event, 0)?;
+        }
+
+        for field in &mapping.field {
+            new_self.add_topic(node, field, 1)?;
+        }
+
+        for method in &mapping.method {
+            new_self.add_method(node, method)?;
+        }
+
+        Ok(new_self)
+    }
+
+    fn add_method(
+        &mut self,
+        node: &Node<S>,
+        mapping: &MethodMapping,
+    ) -> Result<(), GatewayCreateError> {
+        let msg = "Unable to add method to SOME/IP gateway";
+        let service_name = fail!(from self, when ServiceName::new(&mapping.service_name),
+            with GatewayCreateError::InvalidServiceName,
+            "{} since \"{}\" is not a valid service name.", msg, mapping.service_name);
+
+        let service = fail!(from self, when node
+                .service_builder(&service_name)
+                .request_response::<[u8], [u8]>()
+                .open_or_create(),
+            with GatewayCreateError::UnableToOpenService,
+            "{} since the service \"{}\" could not be opened or created.", msg, mapping.service_name);
+
+        let response_timeout = Duration::from_millis(mapping.response_timeout_ms);
+        match mapping.direction {
+            Direction::ToSomeIp => {
+                let server = fail!(from self, when service
+                        .server_builder()
+                        .initial_max_slice_len(MAX_UDP_MESSAGE_SIZE - HEADER_SIZE)
+                        .create(),
+                    with GatewayCreateError::UnableToCreatePort,
+                    "{} since the server for \"{}\" could not be created.", msg, mapping.service_name);
+                self.outbound_methods.push(OutboundMethod {
+                    server,
+                    service_id: mapping.someip_service_id,
+                    method_id: mapping.someip_method_id,
+                    response_timeout,
+                });
+            }
+            Direction::FromSomeIp => {
+                let client = fail!(from self, when service
+                        .client_builder()
+                        .initial_max_slice_len(MAX_UDP_MESSAGE_SIZE - HEADER_SIZE)
+                        .create(),
+                    with GatewayCreateError::UnableToCreatePort,
+                    "{} since the client for \"{}\" could not be created.", msg, mapping.service_name);
+                self.inbound_methods.push(InboundMethod {
+                    client,
+                    service_id: mapping.someip_service_id,
+                    method_id: mapping.someip_method_id,
+                    response_timeout,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn add_topic(
+        &mut self,
+        node: &Node<S>,
+        mapping: &EventMapping,
+        history_size: usize,
+    ) -> Result<(), GatewayCreateError> {
+        let msg = "Unable to add topic to SOME/IP gateway";
+        let service_name = fail!(from self, when ServiceName::new(&mapping.service_name),
+            with GatewayCreateError::InvalidServiceName,
+            "{} since \"{}\" is not a valid service name.", msg, mapping.service_name);
+
+        let service = fail!(from self, when node
+                .service_builder(&service_name)
+                .publish_subscribe::<[u8]>()
+                .history_size(history_size)
+                .open_or_create(),
+            with GatewayCreateError::UnableToOpenService,
+            "{} since the service \"{}\" could not be opened or created.", msg, mapping.service_name);
+
+        match mapping.direction {
+            Direction::ToSomeIp => {
+                let subscriber = fail!(from self, when service.subscriber_builder().create(),
+                    with GatewayCreateError::UnableToCreatePort,
+                    "{} since the subscriber for \"{}\" could not be created.", msg, mapping.service_name);
+                self.outbound.push(OutboundTopic {
+                    subscriber,
+                    service_id: mapping.someip_service_id,
+                    event_id: mapping.someip_event_id,
+                });
+            }
+            Direction::FromSomeIp => {
+                let publisher = fail!(from self, when service
+                        .publisher_builder()
+                        .initial_max_slice_len(MAX_UDP_MESSAGE_SIZE - HEADER_SIZE)
+                        .create(),
+                    with GatewayCreateError::UnableToCreatePort,
+                    "{} since the publisher for \"{}\" could not be created.", msg, mapping.service_name);
+                self.inbound.push(InboundTopic {
+                    publisher,
+                    service_id: mapping.someip_service_id,
+                    event_id: mapping.someip_event_id,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Forwards all samples and requests received from the iceoryx2 services as SOME/IP
+    /// notifications and requests, publishes all received SOME/IP notifications on the
+    /// corresponding iceoryx2 services and exchanges the responses of pending method calls in
+    /// both directions. Returns the number of forwarded messages.
+    pub fn process(&mut self) -> Result<usize, GatewayProcessError> {
+        let number_of_messages = self.forward_to_network()?
+            + self.forward_requests_to_network()?
+            + self.forward_to_services()?
+            + self.forward_responses_to_network()?;
+        self.expire_outbound_calls();
+        Ok(number_of_messages)
+    }
+
+    fn next_session_id(&mut self) -> u16 {
+        // session id 0 is reserved for "session handling inactive"
+        self.session_id = self.session_id.wrapping_add(1).max(1);
+        self.session_id
+    }
+
+    fn send_to_network(
+        &mut self,
+        header: &Header,
+        payload_len: usize,
+    ) -> Result<(), GatewayProcessError> {
+        header.write_to(&mut self.buffer);
+        fail!(from self, when self.socket.send_to(
+                &self.buffer[..HEADER_SIZE + payload_len],
+                self.remote_address,
+                self.remote_port),
+            with GatewayProcessError::SendToNetworkFailed,
+            "Unable to send SOME/IP message {:?} since the socket could not send the data.", header);
+        Ok(())
+    }
+
+    fn send_error_to_network(
+        &mut self,
+        request: &Header,
+        return_code: u8,
+    ) -> Result<(), GatewayProcessError> {
+        let header = Header {
+            message_type: MessageType::Error,
+            return_code,
+            payload_length: 0,
+            ..*request
+        };
+        self.send_to_network(&header, 0)
+    }
+
+    fn forward_requests_to_network(&mut self) -> Result<usize, GatewayProcessError> {
+        let msg = "Unable to forward requests to the SOME/IP network";
+        let mut number_of_messages = 0;
+
+        for i in 0..self.outbound_methods.len() {
+            loop {
+                let active_request = match self.outbound_methods[i].server.receive() {
+                    Ok(Some(active_request)) => active_request,
+                    Ok(None) => break,
+                    Err(e) => {
+                        fail!(from self, with GatewayProcessError::ReceiveFromServiceFailed,
+                            "{} since a request could not be received ({:?}).", msg, e);
+                    }
+                };
+
+                let payload = active_request.payload();
+                if HEADER_SIZE + payload.len() > MAX_UDP_MESSAGE_SIZE {
+                    warn!(from self,
+                        "Dropping request of size {} since it exceeds the maximum SOME/IP UDP message size of {}.",
+                        payload.len(), MAX_UDP_MESSAGE_SIZE);
+                    continue;
+                }
+
+                let message_type = if active_request.is_fire_and_forget() {
+                    MessageType::RequestNoReturn
+                } else {
+                    MessageType::Request
+                };
+                let header = Header {
+                    service_id: self.outbound_methods[i].service_id,
+                    method_id: self.outbound_methods[i].method_id,
+                    client_id: self.client_id,
+                    session_id: self.next_session_id(),
+                    interface_version: self.interface_version,
+                    message_type,
+                    return_code: 0,
+                    payload_length: payload.len() as u32,
+                };
+                self.buffer[HEADER_SIZE..HEADER_SIZE + payload.len()].copy_from_slice(payload);
+                self.send_to_network(&header, payload.len())?;
+                number_of_messages += 1;
+
+                if message_type == MessageType::Request {
+                    self.outbound_calls.push(OutboundCall {
+                        active_request,
+                        service_id: header.service_id,
+                        method_id: header.method_id,
+                        session_id: header.session_id,
+                        deadline: Instant::now() + self.outbound_methods[i].response_timeout,
+                    });
+                }
+            }
+        }
+
+        Ok(number_of_messages)
+    }
+
+    fn forward_responses_to_network(&mut self) -> Result<usize, GatewayProcessError> {
+        let mut number_of_messages = 0;
+
+        let mut i = 0;
+        while i < self.inbound_calls.len() {
+            let request = self.inbound_calls[i].request;
+            let pending_response = &self.inbound_calls[i].pending_response;
+
+            if pending_response.number_of_server_connections() == 0 {
+                warn!(from self, "No iceoryx2 server is connected to answer the SOME/IP request {:?}.",
+                    request);
+                self.inbound_calls.swap_remove(i);
+                self.send_error_to_network(&request, RETURN_CODE_NOT_REACHABLE)?;
+                number_of_messages += 1;
+                continue;
+            }
+
+            match pending_response.receive() {
+                Ok(None) => i += 1,
+                Ok(Some(response)) => {
+                    let payload = response.payload();
+                    if HEADER_SIZE + payload.len() > MAX_UDP_MESSAGE_SIZE {
+                        warn!(from self,
+                            "Unable to forward response of size {} since it exceeds the maximum SOME/IP UDP message size of {}.",
+                            payload.len(), MAX_UDP_MESSAGE_SIZE);
+                        drop(response);
+                        self.inbound_calls.swap_remove(i);
+                        self.send_error_to_network(&request, RETURN_CODE_NOT_OK)?;
+                        number_of_messages += 1;
+                        continue;
+                    }
+
+                    let header = Header {
+                        message_type: MessageType::Response,
+                        return_code: 0,
+                        payload_length: payload.len() as u32,
+                        ..request
+                    };
+                    self.buffer[HEADER_SIZE..HEADER_SIZE + payload.len()].copy_from_slice(payload);
+                    drop(response);
+                    self.inbound_calls.swap_remove(i);
+                    self.send_to_network(&header, header.payload_length as usize)?;
+                    number_of_messages += 1;
+                }
+                Err(e) => {
+                    let return_code = match e {
+                        ResponseReceiveError::Timeout => RETURN_CODE_NOT_REACHABLE,
+                        _ => RETURN_CODE_NOT_OK,
+                    };
+                    warn!(from self, "Responding with an error to the SOME/IP request {:?} since no response could be received ({:?}).",
+                        request, e);
+                    self.inbound_calls.swap_remove(i);
+                    self.send_error_to_network(&request, return_code)?;
+                    number_of_messages += 1;
+                }
+            }
+        }
+
+        Ok(number_of_messages)
+    }
+
+    fn expire_outbound_calls(&mut self) {
+        let now = Instant::now();
+        self.outbound_calls.retain(|call| {
+            if call.deadline <= now {
+                warn!(from "Gateway::expire_outbound_calls()",
+                    "Discarding the request with session id {} since the remote ECU did not respond in time.",
+                    call.session_id);
+                return false;
+            }
+            true
+        });
+    }
+
+    fn forward_to_network(&mut self) -> Result<usize, GatewayProcessError> {
+        let msg = "Unable to forward samples to the SOME/IP network";
+        let mut number_of_messages = 0;
+
+        for i in 0..self.outbound.len() {
+            loop {
+                let sample = match self.outbound[i].subscriber.receive() {
+                    Ok(Some(sample)) => sample,
+                    Ok(None) => break,
+                    Err(e) => {
+                        fail!(from self, with GatewayProcessError::ReceiveFromServiceFailed,
+                            "{} since a sample could not be received ({:?}).", msg, e);
+                    }
+                };
+
+                let payload = sample.payload();
+                if HEADER_SIZE + payload.len() > MAX_UDP_MESSAGE_SIZE {
+                    warn!(from self,
+                        "Dropping sample of size {} since it exceeds the maximum SOME/IP UDP message size of {}.",
+                        payload.len(), MAX_UDP_MESSAGE_SIZE);
+                    continue;
+                }
+
+                let header = Header {
+                    service_id: self.outbound[i].service_id,
+                    method_id: self.outbound[i].event_id,
+                    client_id: self.client_id,
+                    session_id: self.next_session_id(),
+                    interface_version: self.interface_version,
+                    message_type: MessageType::Notification,
+                    return_code: 0,
+                    payload_length: payload.len() as u32,
+                };
+                self.buffer[HEADER_SIZE..HEADER_SIZE + payload.len()].copy_from_slice(payload);
+                self.send_to_network(&header, payload.len())?;
+                number_of_messages += 1;
+            }
+        }
+
+        Ok(number_of_messages)
+    }
+
+    fn forward_to_services(&mut self) -> Result<usize, GatewayProcessError> {
+        let msg = "Unable to forward SOME/IP messages to the services";
+        let mut number_of_messages = 0;
+
+        loop {
+            let details = match self.socket.try_receive_from(&mut self.buffer) {
+                Ok(Some(details)) => details,
+                Ok(None) => break,
+                Err(e) => {
+                    fail!(from self, with GatewayProcessError::ReceiveFromNetworkFailed,
+                        "{} since the socket could not receive data ({:?}).", msg, e);
+                }
+            };
+
+            let data = &self.buffer[..details.number_of_bytes];
+            let header = match Header::from_bytes(data) {
+                Ok(header) => header,
+                Err(e) => {
+                    warn!(from self, "Dropping invalid SOME/IP message from {}:{} ({:?}).",
+                        details.source_ip, details.source_port, e);
+                    continue;
+                }
+            };
+
+            let payload = &data[HEADER_SIZE..HEADER_SIZE + header.payload_length as usize];
+            match header.message_type {
+                MessageType::Notification => (),
+                MessageType::Request | MessageType::RequestNoReturn => {
+                    number_of_messages += self.forward_request_to_services(header)?;
+                    continue;
+                }
+                MessageType::Response | MessageType::Error => {
+                    number_of_messages += self.forward_response_to_services(header)?;
+                    continue;
+                }
+            }
+
+            let topic = self
+                .inbound
+                .iter()
+                .find(|t| t.service_id == header.service_id && t.event_id == header.method_id);
+
+            if let Some(topic) = topic {
+                let sample = fail!(from self, when topic.publisher.loan_slice_uninit(payload.len()),
+                    with GatewayProcessError::PublishToServiceFailed,
+                    "{} since a sample could not be loaned.", msg);
+                fail!(from self, when sample.write_from_slice(payload).send(),
+                    with GatewayProcessError::PublishToServiceFailed,
+                    "{} since the sample could not be sent.", msg);
+                number_of_messages += 1;
+            }
+        }
+
+        Ok(number_of_messages)
+    }
+
+    fn forward_request_to_services(
+        &mut self,
+        header: Header,
+    ) -> Result<usize, GatewayProcessError> {
+        let msg = "Unable to forward SOME/IP request to the services";
+        let method = match self
+            .inbound_methods
+            .iter()
+            .find(|m| m.service_id == header.service_id && m.method_id == header.method_id)
+        {
+            Some(method) => method,
+            None => return Ok(0),
+        };
+
+        let payload = &self.buffer[HEADER_SIZE..HEADER_SIZE + header.payload_length as usize];
+        let pending_response = match method.client.loan_slice_uninit(payload.len()) {
+            Ok(request) => match request
+                .write_from_slice(payload)
+                .send_with_timeout(method.response_timeout)
+            {
+                Ok(pending_response) => Some(pending_response),
+                Err(e) => {
+                    warn!(from self, "{} since the request could not be sent ({:?}).", msg, e);
+                    None
+                }
+            },
+            Err(e) => {
+                warn!(from self, "{} since a request could not be loaned ({:?}).", msg, e);
+                None
+            }
+        };
+
+        let pending_response = match pending_response {
+            Some(pending_response) => pending_response,
+            None => {
+                if header.message_type == MessageType::Request {
+                    self.send_error_to_network(&header, RETURN_CODE_NOT_OK)?;
+                }
+                return Ok(0);
+            }
+        };
+
+        // responses to fire and forget requests are discarded by dropping the pending response
+        if header.message_type == MessageType::Request {
+            self.inbound_calls.push(InboundCall {
+                pending_response,
+                request: header,
+            });
+        }
+
+        Ok(1)
+    }
+
+    fn forward_response_to_services(
+        &mut self,
+        header: Header,
+    ) -> Result<usize, GatewayProcessError> {
+        let msg = "Unable to forward SOME/IP response to the services";
+        self.expire_outbound_calls();
+
+        let call = match self.outbound_calls.iter().position(|c| {
+            header.client_id == self.client_id
+                && c.service_id == header.service_id
+                && c.method_id == header.method_id
+                && c.session_id == header.session_id
+        }) {
+            Some(index) => self.outbound_calls.swap_remove(index),
+            None => {
+                warn!(from self, "Dropping SOME/IP message {:?} since it does not belong to a pending request.",
+                    header);
+                return Ok(0);
+            }
+        };
+
+        if header.message_type == MessageType::Error {
+            warn!(from self, "Dropping request since the remote ECU responded with the return code {}.",
+                header.return_code);
+            return Ok(0);
+        }
+
+        let payload = &self.buffer[HEADER_SIZE..HEADER_SIZE + header.payload_length as usize];
+        let response = fail!(from self, when call.active_request.loan_slice_uninit(payload.len()),
+            with GatewayProcessError::PublishToServiceFailed,
+            "{} since a response could not be loaned.", msg);
+        fail!(from self, when response.write_from_slice(payload).send(),
+            with GatewayProcessError::PublishToServiceFailed,
+            "{} since the response could not be sent.", msg);
+
+        Ok(1)
+    }
+}
@@ -0,0 +1,190 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod iceoryx_classic_gateway {
+    use std::collections::{HashMap, VecDeque};
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_gateway::iceoryx_classic::*;
+
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct RadarObject {
+        x: u64,
+        y: u64,
+    }
+
+    // stands in for iceoryx classic, every published payload can be taken again
+    #[derive(Debug, Default)]
+    struct LoopbackEndpoint {
+        offered: Vec<ServiceDescription>,
+        topics: HashMap<ServiceDescription, VecDeque<Vec<u8>>>,
+    }
+
+    impl ClassicEndpoint for LoopbackEndpoint {
+        fn offer(
+            &mut self,
+            service: &ServiceDescription,
+            _payload_type: &TypeDescription,
+        ) -> Result<(), ClassicEndpointError> {
+            self.offered.push(service.clone());
+            Ok(())
+        }
+
+        fn subscribe(
+            &mut self,
+            service: &ServiceDescription,
+            _payload_type: &TypeDescription,
+        ) -> Result<(), ClassicEndpointError> {
+            self.topics.entry(service.clone()).or_default();
+            Ok(())
+        }
+
+        fn publish(
+            &mut self,
+            service: &ServiceDescription,
+            payload: &[u8],
+        ) -> Result<(), ClassicEndpointError> {
+            self.topics
+                .entry(service.clone())
+                .or_default()
+                .push_back(payload.to_vec());
+            Ok(())
+        }
+
+        fn take(
+            &mut self,
+            service: &ServiceDescription,
+            buffer: &mut [u8],
+        ) -> Result<Option<usize>, ClassicEndpointError> {
+            match self.topics.get_mut(service).and_then(|v| v.pop_front()) {
+                Some(payload) => {
+                    buffer[..payload.len()].copy_from_slice(&payload);
+                    Ok(Some(payload.len()))
+                }
+                None => Ok(None),
+            }
+        }
+    }
+
+    fn generate_identifier() -> String {
+        format!("gateway_{}", UniqueSystemId::new().unwrap().value())
+    }
+
+    fn mapping_for(classic: &ServiceDescription, direction: Direction) -> Mapping {
+        Mapping {
+            topic: vec![TopicMapping {
+                classic: classic.clone(),
+                service_name: None,
+                payload_type: TypeDescription::of::<RadarObject>(),
+                max_number_of_elements: 1,
+                direction,
+            }],
+        }
+    }
+
+    #[test]
+    fn payload_of_classic_topic_is_published_on_typed_service<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let classic = ServiceDescription::new("Radar", &generate_identifier(), "Object").unwrap();
+
+        let object = RadarObject { x: 12, y: 34 };
+        let bytes = unsafe {
+            core::slice::from_raw_parts(
+                (&object as *const RadarObject).cast::<u8>(),
+                core::mem::size_of::<RadarObject>(),
+            )
+        };
+        let mut endpoint = LoopbackEndpoint::default();
+        endpoint.publish(&classic, bytes).unwrap();
+        endpoint.publish(&classic, &bytes[1..]).unwrap();
+
+        let mut sut = Gateway::create(
+            &node,
+            &mapping_for(&classic, Direction::FromClassic),
+            endpoint,
+        )
+        .unwrap();
+
+        let service = node
+            .service_builder(&classic.to_service_name().unwrap())
+            .publish_subscribe::<RadarObject>()
+            .open()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        // the payload with an invalid size is dropped
+        assert_that!(sut.process(), eq Ok(1));
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq object);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn samples_of_typed_service_are_published_on_classic_topic<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let classic = ServiceDescription::new("Radar", &generate_identifier(), "Object").unwrap();
+
+        let mut sut = Gateway::create(
+            &node,
+            &mapping_for(&classic, Direction::ToClassic),
+            LoopbackEndpoint::default(),
+        )
+        .unwrap();
+        assert_that!(sut.endpoint().offered, eq vec![classic.clone()]);
+
+        let service = node
+            .service_builder(&classic.to_service_name().unwrap())
+            .publish_subscribe::<RadarObject>()
+            .open()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        publisher.send_copy(RadarObject { x: 56, y: 78 }).unwrap();
+
+        assert_that!(sut.process(), eq Ok(1));
+        let published = &sut.endpoint().topics[&classic];
+        assert_that!(published, len 1);
+        assert_that!(published[0], len core::mem::size_of::<RadarObject>());
+    }
+
+    #[test]
+    fn service_with_different_payload_type_cannot_be_mapped<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let classic = ServiceDescription::new("Radar", &generate_identifier(), "Object").unwrap();
+
+        let _service = node
+            .service_builder(&classic.to_service_name().unwrap())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut = Gateway::create(
+            &node,
+            &mapping_for(&classic, Direction::FromClassic),
+            LoopbackEndpoint::default(),
+        );
+        assert_that!(sut.err(), eq Some(GatewayCreateError::UnableToOpenService));
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
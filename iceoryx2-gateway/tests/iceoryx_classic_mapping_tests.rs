@@ -0,0 +1,112 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::prelude::*;
+use iceoryx2::service::static_config::message_type_details::TypeVariant;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_gateway::iceoryx_classic::*;
+
+#[test]
+fn service_description_is_translated_into_service_name_and_back() {
+    let sut = ServiceDescription::new("Radar", "FrontLeft", "Object").unwrap();
+
+    let service_name = sut.to_service_name().unwrap();
+    assert_that!(service_name.as_str(), eq "Radar/FrontLeft/Object");
+    assert_that!(ServiceDescription::from_service_name(&service_name), eq Ok(sut));
+
+    let service_name = ServiceName::new("Radar/Object").unwrap();
+    assert_that!(
+        ServiceDescription::from_service_name(&service_name),
+        eq Err(ServiceDescriptionError::InvalidServiceName)
+    );
+}
+
+#[test]
+fn service_description_with_invalid_identifier_cannot_be_created() {
+    let too_long = "a".repeat(MAX_ID_STRING_LENGTH + 1);
+
+    assert_that!(ServiceDescription::new("", "FrontLeft", "Object"), eq Err(ServiceDescriptionError::InvalidIdentifier));
+    assert_that!(ServiceDescription::new("Radar", "Front/Left", "Object"), eq Err(ServiceDescriptionError::InvalidIdentifier));
+    assert_that!(ServiceDescription::new("Radar", "FrontLeft", &too_long), eq Err(ServiceDescriptionError::InvalidIdentifier));
+}
+
+#[test]
+fn type_description_is_translated_into_type_detail() {
+    let sut = TypeDescription {
+        type_name: "radar::Object".to_string(),
+        size: 24,
+        alignment: 8,
+        is_slice: true,
+    };
+
+    let type_detail = sut.to_type_detail();
+    assert_that!(type_detail.variant, eq TypeVariant::Dynamic);
+    assert_that!(type_detail.type_name, eq "radar::Object");
+    assert_that!(type_detail.size, eq 24);
+    assert_that!(type_detail.alignment, eq 8);
+    assert_that!(TypeDescription::from_type_detail(&type_detail), eq sut);
+
+    assert_that!(sut.is_valid_payload_size(48), eq true);
+    assert_that!(sut.is_valid_payload_size(50), eq false);
+    assert_that!(TypeDescription::of::<u64>().is_valid_payload_size(16), eq false);
+    assert_that!(TypeDescription::of::<u64>().is_valid_payload_size(8), eq true);
+}
+
+#[test]
+fn iceoryx_classic_mapping_can_be_deserialized_from_toml() {
+    let sut = Mapping::from_toml(
+        r#"
+        [[topic]]
+        service = "Radar"
+        instance = "FrontLeft"
+        event = "Object"
+        direction = "from-classic"
+
+        [topic.type]
+        type-name = "radar::Object"
+        size = 24
+        alignment = 8
+
+        [[topic]]
+        service = "Radar"
+        instance = "FrontLeft"
+        event = "Config"
+        service-name = "Radar/Config"
+        direction = "to-classic"
+        max-number-of-elements = 64
+
+        [topic.type]
+        type-name = "u8"
+        size = 1
+        alignment = 1
+        is-slice = true
+        "#,
+    )
+    .unwrap();
+
+    assert_that!(sut.topic, len 2);
+    assert_that!(sut.topic[0].classic, eq ServiceDescription::new("Radar", "FrontLeft", "Object").unwrap());
+    assert_that!(sut.topic[0].service_name, eq None);
+    assert_that!(sut.topic[0].direction, eq Direction::FromClassic);
+    assert_that!(sut.topic[0].max_payload_size(), eq 24);
+    assert_that!(sut.topic[1].service_name, eq Some("Radar/Config".to_string()));
+    assert_that!(sut.topic[1].direction, eq Direction::ToClassic);
+    assert_that!(sut.topic[1].payload_type.is_slice, eq true);
+    assert_that!(sut.topic[1].max_payload_size(), eq 64);
+}
+
+#[test]
+fn iceoryx_classic_mapping_with_invalid_content_fails() {
+    let sut = Mapping::from_toml("[[topic]]\nservice = 123");
+
+    assert_that!(sut, eq Err(MappingCreationError::UnableToDeserializeContents));
+}
@@ -0,0 +1,112 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_gateway::network::*;
+
+fn sut() -> FrameHeader {
+    FrameHeader {
+        frame_type: FrameType::Sample,
+        topic_id: 0x0102,
+        session_id: 0x03040506,
+        sequence_number: 0x0708090a,
+        payload_length: 4,
+    }
+}
+
+#[test]
+fn network_frame_header_serialization_is_network_byte_order() {
+    let mut buffer = [0u8; FRAME_HEADER_SIZE];
+    sut().write_to(&mut buffer);
+
+    assert_that!(buffer, eq [b'I', b'O', b'X', b'2', FRAME_VERSION, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0, 0, 0, 4]);
+}
+
+#[test]
+fn network_frame_header_deserialization_restores_serialized_header() {
+    let mut buffer = [0u8; FRAME_HEADER_SIZE + 4];
+    sut().write_to(&mut buffer);
+
+    assert_that!(FrameHeader::from_bytes(&buffer), eq Ok(sut()));
+}
+
+#[test]
+fn network_frame_header_deserialization_fails_when_payload_is_missing() {
+    let mut buffer = [0u8; FRAME_HEADER_SIZE + 3];
+    sut().write_to(&mut buffer);
+
+    assert_that!(FrameHeader::from_bytes(&buffer), eq Err(FrameDecodeError::InsufficientData));
+    assert_that!(FrameHeader::from_bytes(&buffer[..4]), eq Err(FrameDecodeError::InsufficientData));
+}
+
+#[test]
+fn network_frame_header_deserialization_fails_with_invalid_header() {
+    let mut buffer = [0u8; FRAME_HEADER_SIZE + 4];
+    sut().write_to(&mut buffer);
+
+    buffer[0] = b'X';
+    assert_that!(FrameHeader::from_bytes(&buffer), eq Err(FrameDecodeError::InvalidMagic));
+
+    sut().write_to(&mut buffer);
+    buffer[4] = FRAME_VERSION + 1;
+    assert_that!(FrameHeader::from_bytes(&buffer), eq Err(FrameDecodeError::UnsupportedVersion));
+
+    sut().write_to(&mut buffer);
+    buffer[5] = 0x42;
+    assert_that!(FrameHeader::from_bytes(&buffer), eq Err(FrameDecodeError::UnknownFrameType));
+}
+
+#[test]
+fn network_announcement_deserialization_restores_serialized_announcement() {
+    let announcements = [
+        Announcement {
+            service_name: "Vehicle/Speed".to_string(),
+            payload_type: TypeDetail::__internal_new::<u64>(TypeVariant::FixedSize),
+            payload_type_descriptor: None,
+        },
+        Announcement {
+            service_name: "Camera/Front".to_string(),
+            payload_type: TypeDetail::__internal_new::<u8>(TypeVariant::Dynamic),
+            payload_type_descriptor: Some("sha256:1234".to_string()),
+        },
+    ];
+
+    for announcement in announcements {
+        let mut buffer = vec![];
+        announcement.write_to(&mut buffer);
+
+        assert_that!(Announcement::from_bytes(&buffer), eq Ok(announcement.clone()));
+        assert_that!(Announcement::from_bytes(&buffer[..buffer.len() - 1]), eq Err(FrameDecodeError::InsufficientData));
+    }
+}
+
+#[test]
+fn network_announcement_deserialization_fails_with_invalid_payload_layout() {
+    let invalid_layouts = [(8, 0), (0, 0), (8, 3), (8, 12), (8, (1 << 31) + 1)];
+
+    for (size, alignment) in invalid_layouts {
+        let mut payload_type = TypeDetail::__internal_new::<u64>(TypeVariant::FixedSize);
+        payload_type.size = size;
+        payload_type.alignment = alignment;
+        let announcement = Announcement {
+            service_name: "Vehicle/Speed".to_string(),
+            payload_type,
+            payload_type_descriptor: None,
+        };
+
+        let mut buffer = vec![];
+        announcement.write_to(&mut buffer);
+
+        assert_that!(Announcement::from_bytes(&buffer), eq Err(FrameDecodeError::InvalidAnnouncement));
+    }
+}
@@ -0,0 +1,229 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod network_gateway {
+    use core::sync::atomic::{AtomicU16, Ordering};
+    use core::time::Duration;
+
+    use iceoryx2::port::subscriber::Subscriber;
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::process::Process;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_gateway::network::*;
+
+    const MAX_NUMBER_OF_ITERATIONS: usize = 1000;
+
+    fn generate_ports() -> (u16, u16) {
+        static COUNTER: AtomicU16 = AtomicU16::new(0);
+        // distinct port ranges for concurrently running test processes
+        let base = 41000 + (Process::from_self().id().value() as u16 % 400) * 50;
+        let offset = COUNTER.fetch_add(2, Ordering::Relaxed);
+        (base + offset, base + offset + 1)
+    }
+
+    fn mapping(local_port: u16, remote_port: u16, mirror: &[&ServiceName]) -> Mapping {
+        Mapping {
+            endpoint: Endpoint {
+                local_address: "127.0.0.1".to_string(),
+                local_port,
+                remote_address: "127.0.0.1".to_string(),
+                remote_port,
+                heartbeat_interval_ms: 10,
+                peer_timeout_ms: 1000,
+                discovery_interval_ms: 10,
+            },
+            mirror: mirror
+                .iter()
+                .map(|service_name| MirrorMapping {
+                    service_name: service_name.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    // the two isolated nodes represent two hosts
+    fn create_nodes<Sut: Service>() -> (Node<Sut>, Node<Sut>) {
+        let node_a = NodeBuilder::new()
+            .config(&generate_isolated_config())
+            .create::<Sut>()
+            .unwrap();
+        let node_b = NodeBuilder::new()
+            .config(&generate_isolated_config())
+            .create::<Sut>()
+            .unwrap();
+        (node_a, node_b)
+    }
+
+    fn open_remote_subscriber<Sut: Service>(
+        gateway_a: &mut Gateway<Sut>,
+        node_a: &Node<Sut>,
+        gateway_b: &mut Gateway<Sut>,
+        node_b: &Node<Sut>,
+        service_name: &ServiceName,
+    ) -> Option<Subscriber<Sut, u64, ()>> {
+        for _ in 0..MAX_NUMBER_OF_ITERATIONS {
+            gateway_a.process(node_a).unwrap();
+            gateway_b.process(node_b).unwrap();
+
+            if let Ok(service) = node_b
+                .service_builder(service_name)
+                .publish_subscribe::<u64>()
+                .open()
+            {
+                return Some(service.subscriber_builder().create().unwrap());
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        None
+    }
+
+    fn transmit<Sut: Service>(
+        gateway_a: &mut Gateway<Sut>,
+        node_a: &Node<Sut>,
+        gateway_b: &mut Gateway<Sut>,
+        node_b: &Node<Sut>,
+        publisher: &iceoryx2::port::publisher::Publisher<Sut, u64, ()>,
+        subscriber: &Subscriber<Sut, u64, ()>,
+        value: u64,
+    ) -> Option<u64> {
+        for _ in 0..MAX_NUMBER_OF_ITERATIONS {
+            publisher.send_copy(value).unwrap();
+            gateway_a.process(node_a).unwrap();
+            gateway_b.process(node_b).unwrap();
+
+            // samples of a previous session of the remote gateway may still be in flight
+            while let Some(sample) = subscriber.receive().unwrap() {
+                if *sample == value {
+                    return Some(*sample);
+                }
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        None
+    }
+
+    #[test]
+    fn gateway_with_invalid_address_fails<Sut: Service>() {
+        let (node_a, _node_b) = create_nodes::<Sut>();
+        let (port_a, port_b) = generate_ports();
+        let mut sut = mapping(port_a, port_b, &[]);
+        sut.endpoint.remote_address = "not an address".to_string();
+
+        assert_that!(Gateway::create(&node_a, &sut).err(), eq Some(GatewayCreateError::InvalidAddress));
+    }
+
+    #[test]
+    fn gateway_mirrors_samples_to_remote_gateway<Sut: Service>() {
+        let (node_a, node_b) = create_nodes::<Sut>();
+        let (port_a, port_b) = generate_ports();
+        let service_name = generate_service_name();
+
+        let service = node_a
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        let mut gateway_a =
+            Gateway::create(&node_a, &mapping(port_a, port_b, &[&service_name])).unwrap();
+        let mut gateway_b = Gateway::create(&node_b, &mapping(port_b, port_a, &[])).unwrap();
+        assert_that!(gateway_a.is_peer_connected(), eq false);
+
+        let subscriber = open_remote_subscriber(
+            &mut gateway_a,
+            &node_a,
+            &mut gateway_b,
+            &node_b,
+            &service_name,
+        );
+        assert_that!(subscriber, is_some);
+        let subscriber = subscriber.unwrap();
+
+        let received = transmit(
+            &mut gateway_a,
+            &node_a,
+            &mut gateway_b,
+            &node_b,
+            &publisher,
+            &subscriber,
+            8912,
+        );
+        assert_that!(received, eq Some(8912));
+        assert_that!(gateway_a.number_of_mirrored_services(), eq 1);
+        assert_that!(gateway_a.is_peer_connected(), eq true);
+        assert_that!(gateway_b.is_peer_connected(), eq true);
+    }
+
+    #[test]
+    fn gateway_reconnects_after_remote_gateway_restart<Sut: Service>() {
+        let (node_a, node_b) = create_nodes::<Sut>();
+        let (port_a, port_b) = generate_ports();
+        let service_name = generate_service_name();
+        let mapping_a = mapping(port_a, port_b, &[&service_name]);
+
+        let service = node_a
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        let mut gateway_a = Gateway::create(&node_a, &mapping_a).unwrap();
+        let mut gateway_b = Gateway::create(&node_b, &mapping(port_b, port_a, &[])).unwrap();
+
+        let subscriber = open_remote_subscriber(
+            &mut gateway_a,
+            &node_a,
+            &mut gateway_b,
+            &node_b,
+            &service_name,
+        )
+        .unwrap();
+        let received = transmit(
+            &mut gateway_a,
+            &node_a,
+            &mut gateway_b,
+            &node_b,
+            &publisher,
+            &subscriber,
+            1,
+        );
+        assert_that!(received, eq Some(1));
+
+        let old_session_id = gateway_a.session_id();
+        drop(gateway_a);
+        let mut gateway_a = Gateway::create(&node_a, &mapping_a).unwrap();
+        assert_that!(gateway_a.session_id(), ne old_session_id);
+
+        let received = transmit(
+            &mut gateway_a,
+            &node_a,
+            &mut gateway_b,
+            &node_b,
+            &publisher,
+            &subscriber,
+            2,
+        );
+        assert_that!(received, eq Some(2));
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
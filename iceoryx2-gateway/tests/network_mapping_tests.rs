@@ -0,0 +1,77 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_gateway::network::*;
+
+#[test]
+fn network_mapping_can_be_deserialized_from_toml() {
+    let sut = Mapping::from_toml(
+        r#"
+        [endpoint]
+        local-address = "0.0.0.0"
+        local-port = 40100
+        remote-address = "192.168.0.20"
+        remote-port = 40101
+        peer-timeout-ms = 500
+
+        [[mirror]]
+        service-name = "Vehicle/Speed"
+
+        [[mirror]]
+        service-name = "Camera/*"
+        "#,
+    )
+    .unwrap();
+
+    assert_that!(sut.endpoint.local_port, eq 40100);
+    assert_that!(sut.endpoint.remote_port, eq 40101);
+    assert_that!(sut.endpoint.heartbeat_interval_ms, eq 1000);
+    assert_that!(sut.endpoint.peer_timeout_ms, eq 500);
+    assert_that!(sut.mirror, len 2);
+}
+
+#[test]
+fn network_mapping_matches_service_names_and_prefixes() {
+    let sut = Mapping {
+        endpoint: Endpoint {
+            local_address: "0.0.0.0".to_string(),
+            local_port: 40100,
+            remote_address: "127.0.0.1".to_string(),
+            remote_port: 40101,
+            heartbeat_interval_ms: 1000,
+            peer_timeout_ms: 3000,
+            discovery_interval_ms: 1000,
+        },
+        mirror: vec![
+            MirrorMapping {
+                service_name: "Vehicle/Speed".to_string(),
+            },
+            MirrorMapping {
+                service_name: "Camera/*".to_string(),
+            },
+        ],
+    };
+
+    assert_that!(sut.is_mirrored("Vehicle/Speed"), eq true);
+    assert_that!(sut.is_mirrored("Vehicle/SpeedLimit"), eq false);
+    assert_that!(sut.is_mirrored("Camera/Front"), eq true);
+    assert_that!(sut.is_mirrored("Camera/"), eq true);
+    assert_that!(sut.is_mirrored("Cameras"), eq false);
+}
+
+#[test]
+fn network_mapping_with_invalid_content_fails() {
+    let sut = Mapping::from_toml("[endpoint]\nlocal-port = \"not a port\"");
+
+    assert_that!(sut, eq Err(MappingCreationError::UnableToDeserializeContents));
+}
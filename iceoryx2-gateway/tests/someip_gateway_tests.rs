@@ -0,0 +1,180 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod someip_gateway {
+    use core::sync::atomic::{AtomicU16, Ordering};
+    use core::time::Duration;
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::process::Process;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_gateway::someip::*;
+
+    const MAX_NUMBER_OF_ITERATIONS: usize = 1000;
+
+    fn generate_ports() -> (u16, u16) {
+        static COUNTER: AtomicU16 = AtomicU16::new(0);
+        // distinct port ranges for concurrently running test processes
+        let base = 21000 + (Process::from_self().id().value() as u16 % 400) * 50;
+        let offset = COUNTER.fetch_add(2, Ordering::Relaxed);
+        (base + offset, base + offset + 1)
+    }
+
+    fn mapping(local_port: u16, remote_port: u16, direction: Direction) -> Mapping {
+        Mapping {
+            endpoint: Endpoint {
+                local_address: "127.0.0.1".to_string(),
+                local_port,
+                remote_address: "127.0.0.1".to_string(),
+                remote_port,
+                client_id: 0x10,
+                interface_version: 1,
+            },
+            event: vec![],
+            field: vec![],
+            method: vec![MethodMapping {
+                service_name: "Vehicle/SetMode".to_string(),
+                someip_service_id: 0x1234,
+                someip_method_id: 0x0001,
+                direction,
+                response_timeout_ms: 1000,
+            }],
+        }
+    }
+
+    // the two isolated nodes represent the iceoryx2 host and the remote ECU
+    fn create_nodes<Sut: Service>() -> (Node<Sut>, Node<Sut>) {
+        let node_a = NodeBuilder::new()
+            .config(&generate_isolated_config())
+            .create::<Sut>()
+            .unwrap();
+        let node_b = NodeBuilder::new()
+            .config(&generate_isolated_config())
+            .create::<Sut>()
+            .unwrap();
+        (node_a, node_b)
+    }
+
+    #[test]
+    fn gateway_forwards_method_call_and_response<Sut: Service>() {
+        let service_name = ServiceName::new("Vehicle/SetMode").unwrap();
+        let (node_a, node_b) = create_nodes::<Sut>();
+        let (port_a, port_b) = generate_ports();
+
+        let mut gateway_a =
+            Gateway::create(&node_a, &mapping(port_a, port_b, Direction::ToSomeIp)).unwrap();
+        let mut gateway_b =
+            Gateway::create(&node_b, &mapping(port_b, port_a, Direction::FromSomeIp)).unwrap();
+
+        let client = node_a
+            .service_builder(&service_name)
+            .request_response::<[u8], [u8]>()
+            .open()
+            .unwrap()
+            .client_builder()
+            .initial_max_slice_len(16)
+            .create()
+            .unwrap();
+        let server = node_b
+            .service_builder(&service_name)
+            .request_response::<[u8], [u8]>()
+            .open()
+            .unwrap()
+            .server_builder()
+            .initial_max_slice_len(16)
+            .create()
+            .unwrap();
+
+        let pending_response = client
+            .loan_slice_uninit(3)
+            .unwrap()
+            .write_from_slice(&[1, 2, 3])
+            .send()
+            .unwrap();
+
+        let mut received_response = None;
+        for _ in 0..MAX_NUMBER_OF_ITERATIONS {
+            gateway_a.process().unwrap();
+            gateway_b.process().unwrap();
+
+            while let Some(active_request) = server.receive().unwrap() {
+                let payload: Vec<u8> = active_request.payload().iter().map(|v| v * 2).collect();
+                active_request
+                    .loan_slice_uninit(payload.len())
+                    .unwrap()
+                    .write_from_slice(&payload)
+                    .send()
+                    .unwrap();
+            }
+
+            if let Some(response) = pending_response.receive().unwrap() {
+                received_response = Some(response.payload().to_vec());
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        assert_that!(received_response, eq Some(vec![2, 4, 6]));
+    }
+
+    #[test]
+    fn gateway_responds_with_error_when_no_server_is_connected<Sut: Service>() {
+        let service_name = ServiceName::new("Vehicle/SetMode").unwrap();
+        let (node_a, node_b) = create_nodes::<Sut>();
+        let (port_a, port_b) = generate_ports();
+
+        let mut gateway_a =
+            Gateway::create(&node_a, &mapping(port_a, port_b, Direction::ToSomeIp)).unwrap();
+        let mut gateway_b =
+            Gateway::create(&node_b, &mapping(port_b, port_a, Direction::FromSomeIp)).unwrap();
+
+        let client = node_a
+            .service_builder(&service_name)
+            .request_response::<[u8], [u8]>()
+            .open()
+            .unwrap()
+            .client_builder()
+            .initial_max_slice_len(16)
+            .create()
+            .unwrap();
+
+        let pending_response = client
+            .loan_slice_uninit(1)
+            .unwrap()
+            .write_from_slice(&[1])
+            .send()
+            .unwrap();
+
+        // the remote gateway answers with a SOME/IP error which closes the request without
+        // response, the local gateway discards it
+        let mut number_of_forwarded_messages = 0;
+        for _ in 0..MAX_NUMBER_OF_ITERATIONS {
+            number_of_forwarded_messages += gateway_a.process().unwrap();
+            number_of_forwarded_messages += gateway_b.process().unwrap();
+            if number_of_forwarded_messages >= 2 {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        assert_that!(number_of_forwarded_messages, ge 2);
+        assert_that!(pending_response.receive().unwrap().is_none(), eq true);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
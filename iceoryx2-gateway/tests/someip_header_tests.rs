@@ -0,0 +1,70 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_gateway::someip::*;
+
+fn sut() -> Header {
+    Header {
+        service_id: 0x1234,
+        method_id: 0x8001,
+        client_id: 0x0010,
+        session_id: 0x0002,
+        interface_version: 3,
+        message_type: MessageType::Notification,
+        return_code: 0,
+        payload_length: 4,
+    }
+}
+
+#[test]
+fn someip_header_serialization_is_network_byte_order() {
+    let mut buffer = [0u8; HEADER_SIZE];
+    sut().write_to(&mut buffer);
+
+    assert_that!(buffer, eq [0x12, 0x34, 0x80, 0x01, 0, 0, 0, 12, 0x00, 0x10, 0x00, 0x02, PROTOCOL_VERSION, 3, 0x02, 0]);
+}
+
+#[test]
+fn someip_header_deserialization_restores_serialized_header() {
+    let mut buffer = [0u8; HEADER_SIZE + 4];
+    sut().write_to(&mut buffer);
+
+    assert_that!(Header::from_bytes(&buffer), eq Ok(sut()));
+}
+
+#[test]
+fn someip_header_deserialization_fails_when_payload_is_missing() {
+    let mut buffer = [0u8; HEADER_SIZE + 3];
+    sut().write_to(&mut buffer);
+
+    assert_that!(Header::from_bytes(&buffer), eq Err(HeaderDecodeError::InsufficientData));
+    assert_that!(Header::from_bytes(&buffer[..4]), eq Err(HeaderDecodeError::InsufficientData));
+}
+
+#[test]
+fn someip_header_deserialization_fails_with_unknown_message_type() {
+    let mut buffer = [0u8; HEADER_SIZE + 4];
+    sut().write_to(&mut buffer);
+    buffer[14] = 0x42;
+
+    assert_that!(Header::from_bytes(&buffer), eq Err(HeaderDecodeError::UnknownMessageType));
+}
+
+#[test]
+fn someip_header_deserialization_fails_with_unsupported_protocol_version() {
+    let mut buffer = [0u8; HEADER_SIZE + 4];
+    sut().write_to(&mut buffer);
+    buffer[12] = PROTOCOL_VERSION + 1;
+
+    assert_that!(Header::from_bytes(&buffer), eq Err(HeaderDecodeError::UnsupportedProtocolVersion));
+}
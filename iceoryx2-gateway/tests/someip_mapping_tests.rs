@@ -0,0 +1,66 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_gateway::someip::*;
+
+#[test]
+fn someip_mapping_can_be_deserialized_from_toml() {
+    let sut = Mapping::from_toml(
+        r#"
+        [endpoint]
+        local-address = "0.0.0.0"
+        local-port = 30501
+        remote-address = "192.168.0.10"
+        remote-port = 30502
+
+        [[event]]
+        service-name = "Vehicle/Speed"
+        someip-service-id = 0x1234
+        someip-event-id = 0x8001
+        direction = "from-some-ip"
+
+        [[field]]
+        service-name = "Vehicle/Mode"
+        someip-service-id = 0x1234
+        someip-event-id = 0x8002
+        direction = "to-some-ip"
+
+        [[method]]
+        service-name = "Vehicle/SetMode"
+        someip-service-id = 0x1234
+        someip-method-id = 0x0001
+        direction = "from-some-ip"
+        "#,
+    )
+    .unwrap();
+
+    assert_that!(sut.endpoint.local_port, eq 30501);
+    assert_that!(sut.endpoint.remote_port, eq 30502);
+    assert_that!(sut.endpoint.interface_version, eq 1);
+    assert_that!(sut.event, len 1);
+    assert_that!(sut.event[0].direction, eq Direction::FromSomeIp);
+    assert_that!(sut.field, len 1);
+    assert_that!(sut.field[0].someip_event_id, eq 0x8002);
+    assert_that!(sut.field[0].direction, eq Direction::ToSomeIp);
+    assert_that!(sut.method, len 1);
+    assert_that!(sut.method[0].someip_method_id, eq 0x0001);
+    assert_that!(sut.method[0].direction, eq Direction::FromSomeIp);
+    assert_that!(sut.method[0].response_timeout_ms, eq 1000);
+}
+
+#[test]
+fn someip_mapping_with_invalid_content_fails() {
+    let sut = Mapping::from_toml("[endpoint]\nlocal-port = \"not a port\"");
+
+    assert_that!(sut, eq Err(MappingCreationError::UnableToDeserializeContents));
+}
@@ -51,6 +51,10 @@ pub const MCL_CURRENT: int = libc::MCL_CURRENT as _;
 pub const MCL_FUTURE: int = libc::MCL_FUTURE as _;
 pub const MAP_SHARED: int = libc::MAP_SHARED as _;
 pub const MAP_FAILED: *mut void = u64::MAX as *mut void;
+#[cfg(target_os = "linux")]
+pub const MADV_HUGEPAGE: int = libc::MADV_HUGEPAGE as _;
+#[cfg(not(target_os = "linux"))]
+pub const MADV_HUGEPAGE: int = libc::MADV_NORMAL as _;
 
 pub const PTHREAD_BARRIER_SERIAL_THREAD: int = libc::PTHREAD_BARRIER_SERIAL_THREAD as _;
 pub const PTHREAD_EXPLICIT_SCHED: int = libc::PTHREAD_EXPLICIT_SCHED as _;
@@ -120,6 +124,8 @@ pub const RLIMIT_RTTIME: __rlim_t = 15;
 pub const RLIMIT_NLIMITS: __rlim_t = 16;
 pub const RLIMIT_INFINITY: __rlim_t = __rlim_t::MAX;
 
+pub const RUSAGE_SELF: int = libc::RUSAGE_SELF as _;
+
 pub const SCHED_OTHER: int = libc::SCHED_OTHER as _;
 pub const SCHED_FIFO: int = libc::SCHED_FIFO as _;
 pub const SCHED_RR: int = libc::SCHED_RR as _;
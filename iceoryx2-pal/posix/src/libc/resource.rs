@@ -22,3 +22,7 @@ pub unsafe fn getrlimit(resource: int, rlim: *mut rlimit) -> int {
 pub unsafe fn setrlimit(resource: int, rlim: *const rlimit) -> int {
     libc::setrlimit(resource as _, rlim)
 }
+
+pub unsafe fn getrusage(who: int, usage: *mut rusage) -> int {
+    libc::getrusage(who as _, usage)
+}
@@ -98,6 +98,9 @@ impl Struct for flock {}
 pub type rlimit = libc::rlimit;
 impl Struct for rlimit {}
 
+pub type rusage = libc::rusage;
+impl Struct for rusage {}
+
 pub type sched_param = libc::sched_param;
 impl Struct for sched_param {}
 
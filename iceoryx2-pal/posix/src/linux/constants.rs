@@ -51,6 +51,7 @@ pub const MCL_CURRENT: int = crate::internal::MCL_CURRENT as _;
 pub const MCL_FUTURE: int = crate::internal::MCL_FUTURE as _;
 pub const MAP_SHARED: int = crate::internal::MAP_SHARED as _;
 pub const MAP_FAILED: *mut void = u64::MAX as *mut void;
+pub const MADV_HUGEPAGE: int = crate::internal::MADV_HUGEPAGE as _;
 
 pub const PTHREAD_BARRIER_SERIAL_THREAD: int = crate::internal::PTHREAD_BARRIER_SERIAL_THREAD as _;
 pub const PTHREAD_EXPLICIT_SCHED: int = crate::internal::PTHREAD_EXPLICIT_SCHED as _;
@@ -122,6 +123,8 @@ pub const RLIMIT_RTTIME: __rlim_t = 15;
 pub const RLIMIT_NLIMITS: __rlim_t = 16;
 pub const RLIMIT_INFINITY: __rlim_t = __rlim_t::MAX;
 
+pub const RUSAGE_SELF: int = 0;
+
 pub const SCHED_OTHER: int = crate::internal::SCHED_OTHER as _;
 pub const SCHED_FIFO: int = crate::internal::SCHED_FIFO as _;
 pub const SCHED_RR: int = crate::internal::SCHED_RR as _;
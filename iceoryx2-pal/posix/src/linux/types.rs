@@ -98,6 +98,9 @@ impl Struct for flock {}
 pub type rlimit = crate::internal::rlimit;
 impl Struct for rlimit {}
 
+pub type rusage = crate::internal::rusage;
+impl Struct for rusage {}
+
 pub type sched_param = crate::internal::sched_param;
 impl Struct for sched_param {}
 
@@ -22,3 +22,7 @@ pub unsafe fn getrlimit(resource: int, rlim: *mut rlimit) -> int {
 pub unsafe fn setrlimit(resource: int, rlim: *const rlimit) -> int {
     crate::internal::setrlimit(resource, rlim)
 }
+
+pub unsafe fn getrusage(who: int, usage: *mut rusage) -> int {
+    crate::internal::getrusage(who, usage)
+}
@@ -30,3 +30,7 @@ pub unsafe fn stat(path: *const c_char, buf: *mut stat_t) -> int {
 pub unsafe fn umask(mask: mode_t) -> mode_t {
     crate::internal::umask(mask)
 }
+
+pub unsafe fn mkfifo(pathname: *const c_char, mode: mode_t) -> int {
+    crate::internal::mkfifo(pathname, mode)
+}
@@ -52,6 +52,7 @@ pub const MCL_CURRENT: int = 16;
 pub const MCL_FUTURE: int = 32;
 pub const MAP_SHARED: int = 64;
 pub const MAP_FAILED: *mut void = 0 as *mut void;
+pub const MADV_HUGEPAGE: int = 128;
 
 pub const PTHREAD_MUTEX_NORMAL: int = 1;
 pub const PTHREAD_MUTEX_RECURSIVE: int = 2;
@@ -91,6 +92,8 @@ pub const RLIMIT_RTTIME: __rlim_t = 15;
 pub const RLIMIT_NLIMITS: __rlim_t = 16;
 pub const RLIMIT_INFINITY: __rlim_t = __rlim_t::MAX;
 
+pub const RUSAGE_SELF: int = 0;
+
 pub const SCHED_OTHER: int = 1;
 pub const SCHED_FIFO: int = 2;
 pub const SCHED_RR: int = 4;
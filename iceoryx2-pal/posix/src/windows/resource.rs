@@ -14,7 +14,14 @@
 #![allow(clippy::missing_safety_doc)]
 #![allow(unused_variables)]
 
+use windows_sys::Win32::{
+    Foundation::FILETIME,
+    System::Threading::{GetCurrentProcess, GetProcessTimes},
+};
+
 use crate::posix::types::*;
+use crate::posix::{Errno, Struct, RUSAGE_SELF};
+use crate::win32call;
 
 pub unsafe fn getrlimit(resource: int, rlim: *mut rlimit) -> int {
     0
@@ -23,3 +30,34 @@ pub unsafe fn getrlimit(resource: int, rlim: *mut rlimit) -> int {
 pub unsafe fn setrlimit(resource: int, rlim: *const rlimit) -> int {
     0
 }
+
+impl Struct for FILETIME {}
+
+pub unsafe fn getrusage(who: int, usage: *mut rusage) -> int {
+    if who != RUSAGE_SELF {
+        Errno::set(Errno::EINVAL);
+        return -1;
+    }
+
+    let mut creation_time = FILETIME::new();
+    let mut exit_time = FILETIME::new();
+    let mut kernel_time = FILETIME::new();
+    let mut user_time = FILETIME::new();
+    let (result, _) = win32call! { GetProcessTimes(GetCurrentProcess(), &mut creation_time, &mut exit_time, &mut kernel_time, &mut user_time) };
+    if result == 0 {
+        return -1;
+    }
+
+    // FILETIME counts in 100ns intervals
+    let to_timeval = |value: FILETIME| {
+        let value_in_us = (((value.dwHighDateTime as u64) << 32) | value.dwLowDateTime as u64) / 10;
+        timeval {
+            tv_sec: (value_in_us / 1_000_000) as _,
+            tv_usec: (value_in_us % 1_000_000) as _,
+        }
+    };
+
+    (*usage).ru_utime = to_timeval(user_time);
+    (*usage).ru_stime = to_timeval(kernel_time);
+    0
+}
@@ -60,3 +60,8 @@ pub unsafe fn stat(path: *const c_char, buf: *mut stat_t) -> int {
 pub unsafe fn umask(mask: mode_t) -> mode_t {
     mode_t::MAX
 }
+
+pub unsafe fn mkfifo(pathname: *const c_char, mode: mode_t) -> int {
+    Errno::set(Errno::ENOTSUP);
+    -1
+}
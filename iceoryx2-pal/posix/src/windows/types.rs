@@ -192,6 +192,12 @@ pub struct rlimit {
 }
 impl Struct for rlimit {}
 
+pub struct rusage {
+    pub ru_utime: timeval,
+    pub ru_stime: timeval,
+}
+impl Struct for rusage {}
+
 pub struct sched_param {
     pub sched_priority: int,
 }
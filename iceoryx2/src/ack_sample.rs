@@ -0,0 +1,152 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #   .publish_subscribe::<u64>()
+//! #   .open_or_create()?;
+//! # let subscriber = service.subscriber_builder().create_ack_subscriber()?;
+//!
+//! while let Some(sample) = subscriber.receive()? {
+//!     println!("received: {:?}", *sample);
+//!     println!("number of deliveries: {}", sample.number_of_deliveries());
+//!     sample.ack()?;
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::{fmt::Debug, ops::Deref};
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+use crate::port::ack_subscriber::{AckError, AckSubscriberBackend};
+use crate::port::port_identifiers::UniquePublisherId;
+use crate::sample::Sample;
+use crate::service::header::publish_subscribe::Header;
+
+/// A [`Sample`] that was received by an
+/// [`AckSubscriber`](crate::port::ack_subscriber::AckSubscriber). It must be acknowledged with
+/// [`AckSample::ack()`] when it was processed successfully. When it is rejected with
+/// [`AckSample::nack()`] or goes out of scope without being acknowledged, it is delivered again.
+pub struct AckSample<Service: crate::service::Service, Payload: Debug + ?Sized, UserHeader: Debug> {
+    backend: Arc<AckSubscriberBackend<Service, Payload, UserHeader>>,
+    sample: Arc<Sample<Service, Payload, UserHeader>>,
+    delivery_id: u64,
+    number_of_deliveries: u64,
+    is_settled: bool,
+}
+
+impl<Service: crate::service::Service, Payload: Debug + ?Sized, UserHeader: Debug> Debug
+    for AckSample<Service, Payload, UserHeader>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "AckSample<{}, {}, {}> {{ sample: {:?}, delivery_id: {}, number_of_deliveries: {} }}",
+            core::any::type_name::<Payload>(),
+            core::any::type_name::<UserHeader>(),
+            core::any::type_name::<Service>(),
+            self.sample,
+            self.delivery_id,
+            self.number_of_deliveries
+        )
+    }
+}
+
+impl<Service: crate::service::Service, Payload: Debug + ?Sized, UserHeader: Debug> Deref
+    for AckSample<Service, Payload, UserHeader>
+{
+    type Target = Payload;
+    fn deref(&self) -> &Self::Target {
+        self.sample.payload()
+    }
+}
+
+impl<Service: crate::service::Service, Payload: Debug + ?Sized, UserHeader: Debug> Drop
+    for AckSample<Service, Payload, UserHeader>
+{
+    fn drop(&mut self) {
+        if !self.is_settled {
+            // an expired delivery was already scheduled for redelivery
+            let _ = self.backend.nack(self.delivery_id);
+        }
+    }
+}
+
+impl<Service: crate::service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
+    AckSample<Service, Payload, UserHeader>
+{
+    pub(crate) fn new(
+        backend: &Arc<AckSubscriberBackend<Service, Payload, UserHeader>>,
+        sample: Arc<Sample<Service, Payload, UserHeader>>,
+        delivery_id: u64,
+        number_of_deliveries: u64,
+    ) -> Self {
+        Self {
+            backend: backend.clone(),
+            sample,
+            delivery_id,
+            number_of_deliveries,
+            is_settled: false,
+        }
+    }
+
+    /// Returns a reference to the payload of the [`AckSample`]
+    pub fn payload(&self) -> &Payload {
+        self.sample.payload()
+    }
+
+    /// Returns a reference to the user_header of the [`AckSample`]
+    pub fn user_header(&self) -> &UserHeader {
+        self.sample.user_header()
+    }
+
+    /// Returns a reference to the [`Header`] of the [`AckSample`].
+    pub fn header(&self) -> &Header {
+        self.sample.header()
+    }
+
+    /// Returns the [`UniquePublisherId`] of the [`Publisher`](crate::port::publisher::Publisher)
+    pub fn origin(&self) -> UniquePublisherId {
+        self.sample.origin()
+    }
+
+    /// Returns how often the [`AckSample`] was delivered, including this delivery. A value
+    /// greater than one indicates that a previous delivery was rejected or has expired.
+    pub fn number_of_deliveries(&self) -> u64 {
+        self.number_of_deliveries
+    }
+
+    /// Acknowledges the [`AckSample`] and releases it. If the acknowledgement timeout
+    /// passed before, [`AckError::DeliveryExpired`] is returned since the sample was already
+    /// scheduled for redelivery.
+    pub fn ack(mut self) -> Result<(), AckError> {
+        self.is_settled = true;
+        self.backend.ack(self.delivery_id)
+    }
+
+    /// Rejects the [`AckSample`] so that it is delivered again. If the acknowledgement timeout
+    /// passed before, [`AckError::DeliveryExpired`] is returned since the sample was already
+    /// scheduled for redelivery.
+    pub fn nack(mut self) -> Result<(), AckError> {
+        self.is_settled = true;
+        self.backend.nack(self.delivery_id)
+    }
+}
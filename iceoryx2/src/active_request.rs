@@ -0,0 +1,402 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let server = service.server_builder().create()?;
+//!
+//! while let Some(active_request) = server.receive()? {
+//!     println!("client port id: {:?}", active_request.origin());
+//!     println!("request: {}", *active_request);
+//!
+//!     let mut response = active_request.loan()?;
+//!     *response.payload_mut() = *active_request + 1;
+//!     response.send()?;
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::ops::Deref;
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::shm_allocator::{AllocationStrategy, PointerOffset};
+use iceoryx2_cal::zero_copy_connection::ZeroCopyPortDetails;
+
+use crate::port::details::client_connections::Connection;
+use crate::port::port_identifiers::UniqueClientId;
+use crate::port::server::{ResponseSendError, ServerBackend};
+use crate::port::LoanError;
+use crate::raw_sample::{RawSample, RawSampleMut};
+use crate::response_mut::ResponseMut;
+use crate::response_mut_uninit::ResponseMutUninit;
+use crate::service::header::request_response::{
+    RequestHeader as Header, ResponseHeader as ResponseHeaderType,
+};
+
+/// A request that was received by a [`Server`](crate::port::server::Server) with
+/// [`Server::receive()`](crate::port::server::Server::receive()). It provides read access to
+/// the request and can be used to send an arbitrary number of responses back to the
+/// [`Client`](crate::port::client::Client). When it goes out of scope the request is returned
+/// to the [`Client`](crate::port::client::Client).
+pub struct ActiveRequest<
+    Service: crate::service::Service,
+    RequestPayload: Debug + ?Sized,
+    RequestHeader: Debug,
+    ResponsePayload: Debug + ?Sized,
+    ResponseHeader: Debug,
+> {
+    ptr: RawSample<Header, RequestHeader, RequestPayload>,
+    server_backend: Arc<ServerBackend<Service>>,
+    connection: Arc<Connection<Service>>,
+    offset: PointerOffset,
+    _response_payload: PhantomData<ResponsePayload>,
+    _response_header: PhantomData<ResponseHeader>,
+}
+
+impl<
+        Service: crate::service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > Debug
+    for ActiveRequest<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "ActiveRequest<{}, {}, {}, {}, {}> {{ ptr: {:?}, offset: {:?} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<RequestPayload>(),
+            core::any::type_name::<RequestHeader>(),
+            core::any::type_name::<ResponsePayload>(),
+            core::any::type_name::<ResponseHeader>(),
+            self.ptr,
+            self.offset
+        )
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > Deref
+    for ActiveRequest<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    type Target = RequestPayload;
+    fn deref(&self) -> &Self::Target {
+        self.ptr.as_payload_ref()
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > Drop
+    for ActiveRequest<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    fn drop(&mut self) {
+        self.connection.release_request(self.offset);
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > ActiveRequest<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    pub(crate) fn new(
+        server_backend: &Arc<ServerBackend<Service>>,
+        connection: Arc<Connection<Service>>,
+        offset: PointerOffset,
+        ptr: RawSample<Header, RequestHeader, RequestPayload>,
+    ) -> Self {
+        Self {
+            ptr,
+            server_backend: server_backend.clone(),
+            connection,
+            offset,
+            _response_payload: PhantomData,
+            _response_header: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the iceoryx2 internal [`Header`] of the request.
+    pub fn header(&self) -> &Header {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns a reference to the user defined request header.
+    pub fn user_header(&self) -> &RequestHeader {
+        self.ptr.as_user_header_ref()
+    }
+
+    /// Returns a reference to the payload of the request.
+    pub fn payload(&self) -> &RequestPayload {
+        self.ptr.as_payload_ref()
+    }
+
+    /// Returns the [`UniqueClientId`] of the [`Client`](crate::port::client::Client) that
+    /// sent the request.
+    pub fn origin(&self) -> UniqueClientId {
+        self.connection.client_port_id
+    }
+
+    /// Returns true when the request was sent as fire and forget request. The
+    /// [`Client`](crate::port::client::Client) does not expect any response and discards all
+    /// responses that are sent nevertheless.
+    pub fn is_fire_and_forget(&self) -> bool {
+        self.header().is_fire_and_forget()
+    }
+
+    /// Returns true as long as the [`Client`](crate::port::client::Client) that sent the
+    /// request is able to receive responses.
+    pub fn is_connected(&self) -> bool {
+        self.connection.response_sender.is_connected()
+    }
+
+    /// Sends the provided value as error response to the
+    /// [`Client`](crate::port::client::Client) that sent the request. The
+    /// [`Service`](crate::service::Service) must have been created with
+    /// [`error_type::<E>()`](crate::service::builder::request_response::Builder::error_type()),
+    /// otherwise [`ResponseSendError::IncompatibleErrorType`] is returned. The
+    /// [`Client`](crate::port::client::Client) receives it with
+    /// [`PendingResponse::receive_result()`](crate::pending_response::PendingResponse::receive_result()).
+    pub fn send_error<E: Debug>(&self, value: E) -> Result<(), ResponseSendError> {
+        let msg = "Unable to send error response";
+        let static_config = self.server_backend.static_config();
+        let details = match &static_config.error_message_type_details {
+            Some(details) if static_config.has_error_type::<E>() => details,
+            _ => {
+                fail!(from self, with ResponseSendError::IncompatibleErrorType,
+                    "{} since the service does not use the error type \"{}\".",
+                    msg, core::any::type_name::<E>());
+            }
+        };
+
+        let shm_pointer = fail!(from self, when self.server_backend.allocate(details.sample_layout(1)),
+                                "{} since the loan of the error response failed.", msg);
+
+        let header_ptr = shm_pointer.data_ptr as *mut ResponseHeaderType;
+        let user_header_ptr =
+            details.user_header_ptr_from_header(shm_pointer.data_ptr) as *mut ResponseHeader;
+        let payload_ptr =
+            details.payload_ptr_from_header(shm_pointer.data_ptr) as *mut MaybeUninit<E>;
+
+        let mut header =
+            ResponseHeaderType::new(self.server_backend.port_id(), self.header().request_id(), 1);
+        header.set_error(true);
+        unsafe { header_ptr.write(header) };
+
+        let ptr = unsafe { RawSampleMut::new_unchecked(header_ptr, user_header_ptr, payload_ptr) };
+
+        ResponseMutUninit::<Service, MaybeUninit<E>, ResponseHeader>::new(
+            &self.server_backend,
+            &self.connection,
+            ptr,
+            shm_pointer.offset,
+        )
+        .write_payload(value)
+        .send()
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseHeader: Debug,
+    > ActiveRequest<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    /// Loans memory for a response without initializing the payload. The response is
+    /// returned when the [`ResponseMutUninit`] or [`ResponseMut`] goes out of scope without
+    /// being sent.
+    #[cfg_attr(feature = "loan_diagnostics", track_caller)]
+    pub fn loan_uninit(
+        &self,
+    ) -> Result<ResponseMutUninit<Service, MaybeUninit<ResponsePayload>, ResponseHeader>, LoanError>
+    {
+        let details = &self
+            .server_backend
+            .static_config()
+            .response_message_type_details;
+        let shm_pointer = self.server_backend.allocate(details.sample_layout(1))?;
+
+        let header_ptr = shm_pointer.data_ptr as *mut ResponseHeaderType;
+        let user_header_ptr =
+            details.user_header_ptr_from_header(shm_pointer.data_ptr) as *mut ResponseHeader;
+        let payload_ptr = details.payload_ptr_from_header(shm_pointer.data_ptr)
+            as *mut MaybeUninit<ResponsePayload>;
+
+        unsafe {
+            header_ptr.write(ResponseHeaderType::new(
+                self.server_backend.port_id(),
+                self.header().request_id(),
+                1,
+            ))
+        };
+
+        let ptr = unsafe { RawSampleMut::new_unchecked(header_ptr, user_header_ptr, payload_ptr) };
+
+        Ok(ResponseMutUninit::new(
+            &self.server_backend,
+            &self.connection,
+            ptr,
+            shm_pointer.offset,
+        ))
+    }
+
+    /// Copies the provided value into a new response and sends it to the
+    /// [`Client`](crate::port::client::Client) that sent the request.
+    #[cfg_attr(feature = "loan_diagnostics", track_caller)]
+    pub fn send_copy(&self, value: ResponsePayload) -> Result<(), ResponseSendError> {
+        let msg = "Unable to send copy of response";
+        let response = fail!(from self, when self.loan_uninit(),
+                                "{} since the loan of the response failed.", msg);
+
+        response.write_payload(value).send()
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + Default,
+        ResponseHeader: Debug,
+    > ActiveRequest<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    /// Loans memory for a response and initializes the payload with [`Default::default()`].
+    #[cfg_attr(feature = "loan_diagnostics", track_caller)]
+    pub fn loan(&self) -> Result<ResponseMut<Service, ResponsePayload, ResponseHeader>, LoanError> {
+        Ok(self
+            .loan_uninit()?
+            .write_payload(ResponsePayload::default()))
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseHeader: Debug,
+    > ActiveRequest<Service, RequestPayload, RequestHeader, [ResponsePayload], ResponseHeader>
+{
+    /// Loans memory for a response with a slice of `slice_len` elements without initializing
+    /// the payload. The response is returned when the [`ResponseMutUninit`] or
+    /// [`ResponseMut`] goes out of scope without being sent.
+    #[cfg_attr(feature = "loan_diagnostics", track_caller)]
+    pub fn loan_slice_uninit(
+        &self,
+        slice_len: usize,
+    ) -> Result<ResponseMutUninit<Service, [MaybeUninit<ResponsePayload>], ResponseHeader>, LoanError>
+    {
+        let config = self.server_backend.config();
+        if config.allocation_strategy == AllocationStrategy::Static
+            && config.initial_max_slice_len < slice_len
+        {
+            fail!(from self, with LoanError::ExceedsMaxLoanSize,
+                "Unable to loan slice with {} elements since it would exceed the max supported slice length of {}.",
+                slice_len, config.initial_max_slice_len);
+        }
+
+        let details = &self
+            .server_backend
+            .static_config()
+            .response_message_type_details;
+        let shm_pointer = self
+            .server_backend
+            .allocate(details.sample_layout(slice_len))?;
+
+        let header_ptr = shm_pointer.data_ptr as *mut ResponseHeaderType;
+        let user_header_ptr =
+            details.user_header_ptr_from_header(shm_pointer.data_ptr) as *mut ResponseHeader;
+        let payload_ptr = details.payload_ptr_from_header(shm_pointer.data_ptr)
+            as *mut MaybeUninit<ResponsePayload>;
+
+        unsafe {
+            header_ptr.write(ResponseHeaderType::new(
+                self.server_backend.port_id(),
+                self.header().request_id(),
+                slice_len as _,
+            ))
+        };
+
+        let ptr = unsafe {
+            RawSampleMut::new_unchecked(
+                header_ptr,
+                user_header_ptr,
+                core::slice::from_raw_parts_mut(payload_ptr, slice_len),
+            )
+        };
+
+        Ok(ResponseMutUninit::<
+            Service,
+            [MaybeUninit<ResponsePayload>],
+            ResponseHeader,
+        >::new(
+            &self.server_backend,
+            &self.connection,
+            ptr,
+            shm_pointer.offset,
+        ))
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + Default,
+        ResponseHeader: Debug,
+    > ActiveRequest<Service, RequestPayload, RequestHeader, [ResponsePayload], ResponseHeader>
+{
+    /// Loans memory for a response with a slice of `slice_len` elements and initializes every
+    /// element with [`Default::default()`].
+    #[cfg_attr(feature = "loan_diagnostics", track_caller)]
+    pub fn loan_slice(
+        &self,
+        slice_len: usize,
+    ) -> Result<ResponseMut<Service, [ResponsePayload], ResponseHeader>, LoanError> {
+        Ok(self
+            .loan_slice_uninit(slice_len)?
+            .write_from_fn(|_| ResponsePayload::default()))
+    }
+}
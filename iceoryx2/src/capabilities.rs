@@ -0,0 +1,132 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Reports the compile-time and platform dependent limits of the deployed iceoryx2 build, so
+//! that generic frameworks can adapt their behavior instead of hardcoding assumptions.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! let capabilities = iceoryx2::capabilities::<ipc::Service>();
+//!
+//! if !capabilities.does_support_safe_overflow() {
+//!     println!("samples are discarded when the buffer of a subscriber is full");
+//! }
+//! println!("max supported alignment: {}", capabilities.max_supported_alignment());
+//! ```
+
+use iceoryx2_bb_posix::system_configuration::SystemInfo;
+use iceoryx2_cal::event::Event;
+use iceoryx2_cal::zero_copy_connection::ZeroCopyConnection;
+
+use crate::deterministic_profile;
+use crate::port::details::data_segment::{DataSegment, DataSegmentType};
+use crate::port::event_payload::MAX_EVENT_PAYLOAD_SIZE;
+use crate::service::Service;
+
+/// The limits and supported features of an iceoryx2 build for a specific [`Service`] type,
+/// see [`capabilities()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capabilities {
+    max_number_of_segments: u8,
+    max_supported_alignment: usize,
+    does_support_safe_overflow: bool,
+    has_configurable_buffer_size: bool,
+    has_event_id_limit: bool,
+    max_event_id_value: usize,
+    max_event_payload_size: usize,
+    max_lock_free_atomic_width: usize,
+    is_deterministic_profile_enabled: bool,
+}
+
+impl Capabilities {
+    /// Returns the maximum number of segments the data segment of a
+    /// [`Publisher`](crate::port::publisher::Publisher) can grow to with a dynamic
+    /// [`AllocationStrategy`](iceoryx2_cal::shm_allocator::AllocationStrategy).
+    pub fn max_number_of_segments(&self) -> u8 {
+        self.max_number_of_segments
+    }
+
+    /// Returns the maximum alignment of payload and user header types that the shared memory
+    /// supports.
+    pub fn max_supported_alignment(&self) -> usize {
+        self.max_supported_alignment
+    }
+
+    /// Returns true when the connection between ports supports safe overflow, see
+    /// [`Builder::enable_safe_overflow()`](crate::service::builder::publish_subscribe::Builder::enable_safe_overflow()).
+    pub fn does_support_safe_overflow(&self) -> bool {
+        self.does_support_safe_overflow
+    }
+
+    /// Returns true when the buffer size of the connection between ports can be configured.
+    pub fn has_configurable_buffer_size(&self) -> bool {
+        self.has_configurable_buffer_size
+    }
+
+    /// Returns true when the underlying event mechanism restricts the range of event ids.
+    pub fn has_event_id_limit(&self) -> bool {
+        self.has_event_id_limit
+    }
+
+    /// Returns the largest `event_id_max_value` that an event service without notifier origin
+    /// supports, see
+    /// [`Builder::event_id_max_value()`](crate::service::builder::event::Builder::event_id_max_value()).
+    pub fn max_event_id_value(&self) -> usize {
+        self.max_event_id_value
+    }
+
+    /// Returns the maximum number of bytes that can be attached to a notification.
+    pub fn max_event_payload_size(&self) -> usize {
+        self.max_event_payload_size
+    }
+
+    /// Returns the width in bits of the widest atomic that is lock-free on the target
+    /// platform. Wider atomics are emulated with a lock.
+    pub fn max_lock_free_atomic_width(&self) -> usize {
+        self.max_lock_free_atomic_width
+    }
+
+    /// Returns true when iceoryx2 was built with the [`deterministic_profile`].
+    pub fn is_deterministic_profile_enabled(&self) -> bool {
+        self.is_deterministic_profile_enabled
+    }
+}
+
+/// Returns the [`Capabilities`] of the deployed iceoryx2 build for the provided [`Service`]
+/// type.
+pub fn capabilities<S: Service>() -> Capabilities {
+    let max_lock_free_atomic_width = if cfg!(target_has_atomic = "64") {
+        64
+    } else if cfg!(target_has_atomic = "32") {
+        32
+    } else if cfg!(target_has_atomic = "16") {
+        16
+    } else {
+        8
+    };
+
+    Capabilities {
+        max_number_of_segments: DataSegment::<S>::max_number_of_segments(DataSegmentType::Dynamic),
+        max_supported_alignment: SystemInfo::PageSize.value(),
+        does_support_safe_overflow: S::Connection::does_support_safe_overflow(),
+        has_configurable_buffer_size: S::Connection::has_configurable_buffer_size(),
+        has_event_id_limit: S::Event::has_trigger_id_limit(),
+        // the event id after the max event id is reserved to wake up the listener on shutdown
+        max_event_id_value: usize::MAX - 2,
+        max_event_payload_size: MAX_EVENT_PAYLOAD_SIZE,
+        max_lock_free_atomic_width,
+        is_deterministic_profile_enabled: deterministic_profile::IS_ENABLED,
+    }
+}
@@ -36,6 +36,29 @@
 //! # }
 //! ```
 //!
+//! ## Compose Config From Layers
+//!
+//! The structural settings, like paths, prefixes and suffixes, are represented by the
+//! [`EnvironmentConfig`] and the per messaging pattern QoS by the [`Defaults`]. Both layers
+//! can be validated independently and combined with [`Config::from_layers()`] or
+//! passed separately to the [`NodeBuilder`](crate::node::NodeBuilder).
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::config::{Defaults, EnvironmentConfig, PublishSubscribe};
+//! use iceoryx2_bb_system_types::file_name::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let environment = EnvironmentConfig::default().prefix(&FileName::new(b"my_app_")?);
+//! let defaults = Defaults::default()
+//!     .publish_subscribe(&PublishSubscribe::default().max_publishers(5)?)?;
+//!
+//! let custom_config = Config::from_layers(&environment, &defaults);
+//! custom_config.validate()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! ## Set Global Config From Custom File
 //!
 //! The [`crate::config::Config::setup_global_config_from_file()`] call must be the first
@@ -68,22 +91,50 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Generate Config From Environment Overlays
+//!
+//! With [`crate::config::Config::from_files()`] multiple config files are merged into one
+//! [`Config`]. The files are applied in the provided order on top of the default
+//! config, therefore an entry of a later file overrides the same entry of all
+//! earlier files. Overlay files do not need to be complete, they only have to contain the
+//! entries that shall be overridden.
+//!
+//! ```no_run
+//! use iceoryx2::config::Config;
+//! use iceoryx2_bb_system_types::file_path::FilePath;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let (custom_config, provenance) = Config::from_files_with_provenance(&[
+//!     FilePath::new(b"config/iceoryx2.toml")?,
+//!     FilePath::new(b"config/iceoryx2.prod.toml")?,
+//! ])?;
+//!
+//! if let Some(file) = provenance.source_of("defaults.publish-subscribe.max-publishers") {
+//!     println!("max-publishers was set by {}", file);
+//! }
+//! # Ok(())
+//! # }
+//! ```
 
 use core::time::Duration;
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_elementary::{lazy_singleton::*, CallbackProgression};
 use iceoryx2_bb_posix::{
     file::{FileBuilder, FileOpenError},
-    shared_memory::AccessMode,
+    shared_memory::{AccessMode, HugePageSize},
     system_configuration::get_global_config_path,
 };
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::file_path::FilePath;
 use iceoryx2_bb_system_types::path::Path;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 use iceoryx2_bb_log::{fail, fatal_panic, trace, warn};
 
+use crate::port::event_payload::MAX_EVENT_PAYLOAD_SIZE;
 use crate::service::port_factory::publisher::UnableToDeliverStrategy;
 
 const DEFAULT_CONFIG_FILE_NAME: &[u8] = b"iceoryx2.toml";
@@ -121,6 +172,31 @@ impl core::fmt::Display for ConfigCreationError {
 
 impl core::error::Error for ConfigCreationError {}
 
+/// Failures that are detected by [`EnvironmentConfig::validate()`],
+/// [`Defaults::validate()`] or [`Config::validate()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ConfigValidationError {
+    /// The root path under which all files are created is not an absolute path.
+    RootPathIsNotAbsolute,
+    /// The service or node directory is not relative to the root path.
+    DirectoryIsNotRelative,
+    /// At least two resources use the same suffix and would overwrite each other.
+    AmbiguousSuffix,
+    /// A maximum number of ports, nodes or a buffer size is set to zero.
+    ZeroCapacity,
+    /// The default event payload size exceeds
+    /// [`MAX_EVENT_PAYLOAD_SIZE`](crate::port::event_payload::MAX_EVENT_PAYLOAD_SIZE).
+    EventPayloadSizeTooLarge,
+}
+
+impl core::fmt::Display for ConfigValidationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ConfigValidationError::{:?}", self)
+    }
+}
+
+impl core::error::Error for ConfigValidationError {}
+
 /// All configurable settings of a [`crate::service::Service`].
 #[non_exhaustive]
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
@@ -132,6 +208,9 @@ pub struct Service {
     pub publisher_data_segment_suffix: FileName,
     /// The suffix of the static config file
     pub static_config_storage_suffix: FileName,
+    /// The suffix of the attribute index file that is used to filter services by attributes
+    /// without reading the whole static config
+    pub attribute_index_suffix: FileName,
     /// The suffix of the dynamic config file
     pub dynamic_config_storage_suffix: FileName,
     /// Defines the time of how long another process will wait until the service creation is
@@ -141,6 +220,99 @@ pub struct Service {
     pub connection_suffix: FileName,
     /// The suffix of a one-to-one connection
     pub event_connection_suffix: FileName,
+    /// The suffix of a one-to-many connection that is used by publishers of services with
+    /// broadcasting enabled
+    pub broadcast_suffix: FileName,
+    /// The key that is used to sign the static config of every created service, see
+    /// [`crate::service::static_config::signature`]. When it is not set, the static configs
+    /// are not signed. Signing requires the `signing` feature.
+    pub signing_key: Option<ServiceSigningKey>,
+    /// When true, a service can only be opened when its static config was signed with one of
+    /// the [`Service::trusted_keys`].
+    pub verify_service_signatures: bool,
+    /// The public keys of the trusted service creators.
+    pub trusted_keys: Vec<TrustedKey>,
+    /// When true, the payload of the data segments is placed on separate pages and mapped
+    /// read-only by the subscribers, servers and clients that receive it. Buggy consumers
+    /// cannot corrupt the memory of the sender anymore but the data segments require more
+    /// memory. Platforms that do not support memory protection fail to connect.
+    pub read_only_payload_mapping: bool,
+    /// When set, the data segments of publishers, servers and clients are requested to be
+    /// backed by huge pages of the given size. It reduces the TLB misses of services with large
+    /// samples and a high rate at the cost of memory, since every segment is rounded up to the
+    /// huge page size. When the system does not support it, regular pages are used.
+    pub data_segment_huge_pages: Option<HugePageSize>,
+}
+
+/// The ed25519 key that is used to sign the static config of created services.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct ServiceSigningKey {
+    /// The id of the key. It is stored next to the signature so that the opening side can
+    /// identify the corresponding [`TrustedKey`].
+    pub key_id: String,
+    /// The hex encoded 32 byte ed25519 secret key.
+    pub secret_key: String,
+}
+
+impl core::fmt::Debug for ServiceSigningKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "ServiceSigningKey {{ key_id: {:?}, secret_key: <redacted> }}",
+            self.key_id
+        )
+    }
+}
+
+impl ServiceSigningKey {
+    /// Creates a new [`ServiceSigningKey`] from a key id and the hex encoded 32 byte ed25519
+    /// secret key.
+    pub fn new(key_id: &str, secret_key: &str) -> Self {
+        Self {
+            key_id: key_id.into(),
+            secret_key: secret_key.into(),
+        }
+    }
+}
+
+/// The ed25519 public key of a trusted service creator.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct TrustedKey {
+    /// The id of the key, it must be equal to the [`ServiceSigningKey::key_id`] of the creator.
+    pub key_id: String,
+    /// The hex encoded 32 byte ed25519 public key.
+    pub public_key: String,
+}
+
+impl TrustedKey {
+    /// Creates a new [`TrustedKey`] from a key id and the hex encoded 32 byte ed25519 public
+    /// key.
+    pub fn new(key_id: &str, public_key: &str) -> Self {
+        Self {
+            key_id: key_id.into(),
+            public_key: public_key.into(),
+        }
+    }
+}
+
+/// The thresholds of the load shedding of a [`crate::node::Node`], see
+/// [`crate::node::load_shedding`].
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct LoadShedding {
+    /// The CPU load in percent of the CPU budget of the [`crate::node::Node`] above which the
+    /// samples of low-priority subscribers are shed.
+    pub shedding_threshold: u32,
+    /// The CPU load in percent of the CPU budget of the [`crate::node::Node`] below which the
+    /// shedding stops again. Must be smaller than the `shedding_threshold`.
+    pub recovery_threshold: u32,
+    /// The minimum time between two CPU load measurements.
+    pub measurement_interval: Duration,
 }
 
 /// All configurable settings of a [`crate::node::Node`].
@@ -164,6 +336,8 @@ pub struct Node {
     /// cleans up all their stale resources whenever an existing [`Node`](crate::node::Node) is
     /// going out of scope.
     pub cleanup_dead_nodes_on_destruction: bool,
+    /// The thresholds when the samples of low-priority subscribers are shed
+    pub load_shedding: LoadShedding,
 }
 
 /// The global settings
@@ -221,6 +395,190 @@ impl Global {
     }
 }
 
+/// The structural settings of an iceoryx2 instance, the paths, prefixes and suffixes of all
+/// files that are created. Every process that shall communicate with each other must use the
+/// same [`EnvironmentConfig`]. It can be acquired from a [`Config`] with
+/// [`Config::environment()`] and passed independently of the [`Defaults`] to the
+/// [`NodeBuilder`](crate::node::NodeBuilder).
+///
+/// # Example
+///
+/// ```
+/// use iceoryx2::prelude::*;
+/// use iceoryx2::config::EnvironmentConfig;
+/// use iceoryx2_bb_system_types::path::*;
+/// use iceoryx2_bb_system_types::file_name::*;
+///
+/// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+/// let environment = EnvironmentConfig::default()
+///     .prefix(&FileName::new(b"my_app_")?)
+///     .service_directory(&Path::new(b"my_services")?);
+/// environment.validate()?;
+///
+/// let node = NodeBuilder::new()
+///     .environment_config(&environment)
+///     .create::<ipc::Service>()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct EnvironmentConfig {
+    root_path: Path,
+    prefix: FileName,
+    service_directory: Path,
+    publisher_data_segment_suffix: FileName,
+    static_config_storage_suffix: FileName,
+    attribute_index_suffix: FileName,
+    dynamic_config_storage_suffix: FileName,
+    connection_suffix: FileName,
+    event_connection_suffix: FileName,
+    broadcast_suffix: FileName,
+    node_directory: Path,
+    monitor_suffix: FileName,
+    node_static_config_suffix: FileName,
+    service_tag_suffix: FileName,
+}
+
+impl Default for EnvironmentConfig {
+    fn default() -> Self {
+        Config::default().environment()
+    }
+}
+
+impl EnvironmentConfig {
+    /// Defines the path under which all other directories or files will be created
+    pub fn root_path(mut self, value: &Path) -> Self {
+        self.root_path = *value;
+        self
+    }
+
+    /// Defines the prefix used for all files created during runtime
+    pub fn prefix(mut self, value: &FileName) -> Self {
+        self.prefix = *value;
+        self
+    }
+
+    /// Defines the directory, relative to the root path, in which all service files are
+    /// stored
+    pub fn service_directory(mut self, value: &Path) -> Self {
+        self.service_directory = *value;
+        self
+    }
+
+    /// Defines the suffix of the publishers data segment
+    pub fn publisher_data_segment_suffix(mut self, value: &FileName) -> Self {
+        self.publisher_data_segment_suffix = *value;
+        self
+    }
+
+    /// Defines the suffix of the static config file
+    pub fn static_config_storage_suffix(mut self, value: &FileName) -> Self {
+        self.static_config_storage_suffix = *value;
+        self
+    }
+
+    /// Defines the suffix of the attribute index file
+    pub fn attribute_index_suffix(mut self, value: &FileName) -> Self {
+        self.attribute_index_suffix = *value;
+        self
+    }
+
+    /// Defines the suffix of the dynamic config file
+    pub fn dynamic_config_storage_suffix(mut self, value: &FileName) -> Self {
+        self.dynamic_config_storage_suffix = *value;
+        self
+    }
+
+    /// Defines the suffix of a one-to-one connection
+    pub fn connection_suffix(mut self, value: &FileName) -> Self {
+        self.connection_suffix = *value;
+        self
+    }
+
+    /// Defines the suffix of a one-to-one event connection
+    pub fn event_connection_suffix(mut self, value: &FileName) -> Self {
+        self.event_connection_suffix = *value;
+        self
+    }
+
+    /// Defines the suffix of a one-to-many connection
+    pub fn broadcast_suffix(mut self, value: &FileName) -> Self {
+        self.broadcast_suffix = *value;
+        self
+    }
+
+    /// Defines the directory, relative to the root path, in which all node files are stored
+    pub fn node_directory(mut self, value: &Path) -> Self {
+        self.node_directory = *value;
+        self
+    }
+
+    /// Defines the suffix of the monitor token
+    pub fn monitor_suffix(mut self, value: &FileName) -> Self {
+        self.monitor_suffix = *value;
+        self
+    }
+
+    /// Defines the suffix of the files where the node configuration is stored
+    pub fn node_static_config_suffix(mut self, value: &FileName) -> Self {
+        self.node_static_config_suffix = *value;
+        self
+    }
+
+    /// Defines the suffix of the service tags
+    pub fn service_tag_suffix(mut self, value: &FileName) -> Self {
+        self.service_tag_suffix = *value;
+        self
+    }
+
+    /// Checks that the [`EnvironmentConfig`] describes a consistent file layout. The root path
+    /// must be absolute, the service and node directory must be relative to it and all
+    /// suffixes of files that are stored in the same directory must be distinct.
+    pub fn validate(&self) -> Result<(), ConfigValidationError> {
+        let msg = "Invalid environment config";
+        if !self.root_path.is_absolute() {
+            fail!(from self, with ConfigValidationError::RootPathIsNotAbsolute,
+                "{} since the root path \"{}\" is not absolute.", msg, self.root_path);
+        }
+
+        if self.service_directory.is_absolute() || self.node_directory.is_absolute() {
+            fail!(from self, with ConfigValidationError::DirectoryIsNotRelative,
+                "{} since the service directory \"{}\" and the node directory \"{}\" must be relative to the root path.",
+                msg, self.service_directory, self.node_directory);
+        }
+
+        let service_suffixes = [
+            &self.publisher_data_segment_suffix,
+            &self.static_config_storage_suffix,
+            &self.attribute_index_suffix,
+            &self.dynamic_config_storage_suffix,
+            &self.connection_suffix,
+            &self.event_connection_suffix,
+            &self.broadcast_suffix,
+        ];
+        let node_suffixes = [
+            &self.monitor_suffix,
+            &self.node_static_config_suffix,
+            &self.service_tag_suffix,
+        ];
+
+        let has_duplicates = |suffixes: &[&FileName]| {
+            suffixes
+                .iter()
+                .enumerate()
+                .any(|(n, suffix)| suffixes[n + 1..].contains(suffix))
+        };
+
+        if has_duplicates(&service_suffixes) || has_duplicates(&node_suffixes) {
+            fail!(from self, with ConfigValidationError::AmbiguousSuffix,
+                "{} since at least two resources use the same suffix.", msg);
+        }
+
+        Ok(())
+    }
+}
+
 /// Default settings. These values are used when the user in the code does not specify anything
 /// else.
 #[non_exhaustive]
@@ -300,6 +658,10 @@ pub struct Event {
     pub notifier_dropped_event: Option<usize>,
     /// Defines the event id value that is emitted if a notifier was identified as dead.
     pub notifier_dead_event: Option<usize>,
+    /// The maximum size in bytes of the payload that can be attached to a notification, see
+    /// [`crate::port::notifier::Notifier::notify_with_payload()`]. Must not exceed
+    /// [`crate::port::event_payload::MAX_EVENT_PAYLOAD_SIZE`].
+    pub max_payload_size: usize,
 }
 
 /// Default settings for the request response messaging pattern. These settings are used unless
@@ -332,6 +694,385 @@ pub struct RequestResonse {
     /// The maximum amount of supported [`crate::node::Node`]s. Defines indirectly how many
     /// processes can open the service at the same time.
     pub max_nodes: usize,
+    /// Defines if a [`crate::port::client::Client`] can send requests that do not expect
+    /// any response and therefore do not occupy an active request.
+    pub enable_fire_and_forget_requests: bool,
+}
+
+impl Default for Defaults {
+    fn default() -> Self {
+        Config::default().defaults
+    }
+}
+
+impl Defaults {
+    /// Sets the default settings for the messaging pattern publish-subscribe. Fails when the
+    /// settings are not valid, see [`PublishSubscribe::validate()`].
+    pub fn publish_subscribe(
+        mut self,
+        value: &PublishSubscribe,
+    ) -> Result<Self, ConfigValidationError> {
+        value.validate()?;
+        self.publish_subscribe = value.clone();
+        Ok(self)
+    }
+
+    /// Sets the default settings for the messaging pattern event. Fails when the settings
+    /// are not valid, see [`Event::validate()`].
+    pub fn event(mut self, value: &Event) -> Result<Self, ConfigValidationError> {
+        value.validate()?;
+        self.event = value.clone();
+        Ok(self)
+    }
+
+    /// Sets the default settings for the messaging pattern request-response. Fails when the
+    /// settings are not valid, see [`RequestResonse::validate()`].
+    pub fn request_response(
+        mut self,
+        value: &RequestResonse,
+    ) -> Result<Self, ConfigValidationError> {
+        value.validate()?;
+        self.request_response = value.clone();
+        Ok(self)
+    }
+
+    /// Checks that the [`Defaults`] can be used to create services, see
+    /// [`PublishSubscribe::validate()`], [`Event::validate()`] and
+    /// [`RequestResonse::validate()`].
+    pub fn validate(&self) -> Result<(), ConfigValidationError> {
+        self.publish_subscribe.validate()?;
+        self.event.validate()?;
+        self.request_response.validate()
+    }
+}
+
+fn non_zero_capacity<T: core::fmt::Debug>(
+    origin: &T,
+    name: &str,
+    value: usize,
+) -> Result<usize, ConfigValidationError> {
+    if value == 0 {
+        fail!(from origin, with ConfigValidationError::ZeroCapacity,
+            "Unable to set \"{}\" since the value must be at least one.", name);
+    }
+
+    Ok(value)
+}
+
+impl Default for PublishSubscribe {
+    fn default() -> Self {
+        Defaults::default().publish_subscribe
+    }
+}
+
+impl PublishSubscribe {
+    /// Defines the maximum amount of supported [`crate::port::subscriber::Subscriber`].
+    /// Fails when the value is zero.
+    pub fn max_subscribers(mut self, value: usize) -> Result<Self, ConfigValidationError> {
+        self.max_subscribers = non_zero_capacity(&self, "max_subscribers", value)?;
+        Ok(self)
+    }
+
+    /// Defines the maximum amount of supported [`crate::port::publisher::Publisher`].
+    /// Fails when the value is zero.
+    pub fn max_publishers(mut self, value: usize) -> Result<Self, ConfigValidationError> {
+        self.max_publishers = non_zero_capacity(&self, "max_publishers", value)?;
+        Ok(self)
+    }
+
+    /// Defines the maximum amount of supported [`crate::node::Node`]s. Fails when the value
+    /// is zero.
+    pub fn max_nodes(mut self, value: usize) -> Result<Self, ConfigValidationError> {
+        self.max_nodes = non_zero_capacity(&self, "max_nodes", value)?;
+        Ok(self)
+    }
+
+    /// Defines the maximum buffer size of a [`crate::port::subscriber::Subscriber`].
+    /// Fails when the value is zero.
+    pub fn subscriber_max_buffer_size(
+        mut self,
+        value: usize,
+    ) -> Result<Self, ConfigValidationError> {
+        self.subscriber_max_buffer_size =
+            non_zero_capacity(&self, "subscriber_max_buffer_size", value)?;
+        Ok(self)
+    }
+
+    /// Defines how many [`crate::sample::Sample`]s a [`crate::port::subscriber::Subscriber`]
+    /// can hold at the same time. Fails when the value is zero.
+    pub fn subscriber_max_borrowed_samples(
+        mut self,
+        value: usize,
+    ) -> Result<Self, ConfigValidationError> {
+        self.subscriber_max_borrowed_samples =
+            non_zero_capacity(&self, "subscriber_max_borrowed_samples", value)?;
+        Ok(self)
+    }
+
+    /// Defines how many [`crate::sample_mut::SampleMut`]s a
+    /// [`crate::port::publisher::Publisher`] can loan at the same time. Fails when the value
+    /// is zero.
+    pub fn publisher_max_loaned_samples(
+        mut self,
+        value: usize,
+    ) -> Result<Self, ConfigValidationError> {
+        self.publisher_max_loaned_samples =
+            non_zero_capacity(&self, "publisher_max_loaned_samples", value)?;
+        Ok(self)
+    }
+
+    /// Defines the maximum history size a [`crate::port::subscriber::Subscriber`] can
+    /// request.
+    pub fn publisher_history_size(mut self, value: usize) -> Self {
+        self.publisher_history_size = value;
+        self
+    }
+
+    /// Enables or disables the safe overflow of the [`crate::port::subscriber::Subscriber`]
+    /// buffer.
+    pub fn enable_safe_overflow(mut self, value: bool) -> Self {
+        self.enable_safe_overflow = value;
+        self
+    }
+
+    /// Defines the deliver strategy of the [`crate::port::publisher::Publisher`] when safe
+    /// overflow is deactivated.
+    pub fn unable_to_deliver_strategy(mut self, value: UnableToDeliverStrategy) -> Self {
+        self.unable_to_deliver_strategy = value;
+        self
+    }
+
+    /// Defines the size of the buffer that contains expired connections of a
+    /// [`crate::port::subscriber::Subscriber`].
+    pub fn subscriber_expired_connection_buffer(mut self, value: usize) -> Self {
+        self.subscriber_expired_connection_buffer = value;
+        self
+    }
+
+    /// Checks that every maximum number of ports and nodes as well as every buffer size is
+    /// at least one.
+    pub fn validate(&self) -> Result<(), ConfigValidationError> {
+        let capacities = [
+            self.max_subscribers,
+            self.max_publishers,
+            self.max_nodes,
+            self.subscriber_max_buffer_size,
+            self.subscriber_max_borrowed_samples,
+            self.publisher_max_loaned_samples,
+        ];
+
+        if capacities.contains(&0) {
+            fail!(from self, with ConfigValidationError::ZeroCapacity,
+                "Invalid publish-subscribe default settings since at least one maximum number of ports or nodes or one buffer size is zero.");
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for Event {
+    fn default() -> Self {
+        Defaults::default().event
+    }
+}
+
+impl Event {
+    /// Defines the maximum amount of supported [`crate::port::listener::Listener`].
+    /// Fails when the value is zero.
+    pub fn max_listeners(mut self, value: usize) -> Result<Self, ConfigValidationError> {
+        self.max_listeners = non_zero_capacity(&self, "max_listeners", value)?;
+        Ok(self)
+    }
+
+    /// Defines the maximum amount of supported [`crate::port::notifier::Notifier`].
+    /// Fails when the value is zero.
+    pub fn max_notifiers(mut self, value: usize) -> Result<Self, ConfigValidationError> {
+        self.max_notifiers = non_zero_capacity(&self, "max_notifiers", value)?;
+        Ok(self)
+    }
+
+    /// Defines the maximum amount of supported [`crate::node::Node`]s. Fails when the value
+    /// is zero.
+    pub fn max_nodes(mut self, value: usize) -> Result<Self, ConfigValidationError> {
+        self.max_nodes = non_zero_capacity(&self, "max_nodes", value)?;
+        Ok(self)
+    }
+
+    /// Defines the largest event id supported by the event service.
+    pub fn event_id_max_value(mut self, value: usize) -> Self {
+        self.event_id_max_value = value;
+        self
+    }
+
+    /// Defines the maximum allowed time between two consecutive notifications.
+    pub fn deadline(mut self, value: Option<Duration>) -> Self {
+        self.deadline = value;
+        self
+    }
+
+    /// Defines the event id value that is emitted after a new notifier was created.
+    pub fn notifier_created_event(mut self, value: Option<usize>) -> Self {
+        self.notifier_created_event = value;
+        self
+    }
+
+    /// Defines the event id value that is emitted before a new notifier is dropped.
+    pub fn notifier_dropped_event(mut self, value: Option<usize>) -> Self {
+        self.notifier_dropped_event = value;
+        self
+    }
+
+    /// Defines the event id value that is emitted if a notifier was identified as dead.
+    pub fn notifier_dead_event(mut self, value: Option<usize>) -> Self {
+        self.notifier_dead_event = value;
+        self
+    }
+
+    /// Defines the maximum size in bytes of the payload that can be attached to a
+    /// notification. Fails when the value exceeds
+    /// [`crate::port::event_payload::MAX_EVENT_PAYLOAD_SIZE`].
+    pub fn max_payload_size(mut self, value: usize) -> Result<Self, ConfigValidationError> {
+        self.max_payload_size = value;
+        self.validate_payload_size()?;
+        Ok(self)
+    }
+
+    fn validate_payload_size(&self) -> Result<(), ConfigValidationError> {
+        if self.max_payload_size > MAX_EVENT_PAYLOAD_SIZE {
+            fail!(from self, with ConfigValidationError::EventPayloadSizeTooLarge,
+                "Invalid event default settings since the event payload size of {} exceeds the maximum supported size of {}.",
+                self.max_payload_size, MAX_EVENT_PAYLOAD_SIZE);
+        }
+
+        Ok(())
+    }
+
+    /// Checks that every maximum number of ports and nodes is at least one and that the
+    /// payload size does not exceed [`crate::port::event_payload::MAX_EVENT_PAYLOAD_SIZE`].
+    pub fn validate(&self) -> Result<(), ConfigValidationError> {
+        let capacities = [self.max_listeners, self.max_notifiers, self.max_nodes];
+
+        if capacities.contains(&0) {
+            fail!(from self, with ConfigValidationError::ZeroCapacity,
+                "Invalid event default settings since at least one maximum number of ports or nodes is zero.");
+        }
+
+        self.validate_payload_size()
+    }
+}
+
+impl Default for RequestResonse {
+    fn default() -> Self {
+        Defaults::default().request_response
+    }
+}
+
+impl RequestResonse {
+    /// Defines if the request buffer of the [`Service`] safely overflows.
+    pub fn enable_safe_overflow_for_requests(mut self, value: bool) -> Self {
+        self.enable_safe_overflow_for_requests = value;
+        self
+    }
+
+    /// Defines if the response buffer of the [`Service`] safely overflows.
+    pub fn enable_safe_overflow_for_responses(mut self, value: bool) -> Self {
+        self.enable_safe_overflow_for_responses = value;
+        self
+    }
+
+    /// Defines the maximum of active responses a [`crate::port::server::Server`] can hold in
+    /// parallel. Fails when the value is zero.
+    pub fn max_active_responses(mut self, value: usize) -> Result<Self, ConfigValidationError> {
+        self.max_active_responses = non_zero_capacity(&self, "max_active_responses", value)?;
+        Ok(self)
+    }
+
+    /// Defines the maximum of active requests a [`crate::port::client::Client`] can hold in
+    /// parallel. Fails when the value is zero.
+    pub fn max_active_requests(mut self, value: usize) -> Result<Self, ConfigValidationError> {
+        self.max_active_requests = non_zero_capacity(&self, "max_active_requests", value)?;
+        Ok(self)
+    }
+
+    /// Defines the maximum number of responses a [`crate::port::client::Client`] can borrow
+    /// from an active request. Fails when the value is zero.
+    pub fn max_borrowed_responses(mut self, value: usize) -> Result<Self, ConfigValidationError> {
+        self.max_borrowed_responses = non_zero_capacity(&self, "max_borrowed_responses", value)?;
+        Ok(self)
+    }
+
+    /// Defines the maximum number of requests a [`crate::port::server::Server`] can borrow.
+    /// Fails when the value is zero.
+    pub fn max_borrowed_requests(mut self, value: usize) -> Result<Self, ConfigValidationError> {
+        self.max_borrowed_requests = non_zero_capacity(&self, "max_borrowed_requests", value)?;
+        Ok(self)
+    }
+
+    /// Defines the maximum buffer size for responses for an active request. Fails when the
+    /// value is zero.
+    pub fn max_response_buffer_size(mut self, value: usize) -> Result<Self, ConfigValidationError> {
+        self.max_response_buffer_size =
+            non_zero_capacity(&self, "max_response_buffer_size", value)?;
+        Ok(self)
+    }
+
+    /// Defines the maximum buffer size for requests for a [`crate::port::server::Server`].
+    /// Fails when the value is zero.
+    pub fn max_request_buffer_size(mut self, value: usize) -> Result<Self, ConfigValidationError> {
+        self.max_request_buffer_size = non_zero_capacity(&self, "max_request_buffer_size", value)?;
+        Ok(self)
+    }
+
+    /// Defines the maximum amount of supported [`crate::port::server::Server`].
+    /// Fails when the value is zero.
+    pub fn max_servers(mut self, value: usize) -> Result<Self, ConfigValidationError> {
+        self.max_servers = non_zero_capacity(&self, "max_servers", value)?;
+        Ok(self)
+    }
+
+    /// Defines the maximum amount of supported [`crate::port::client::Client`].
+    /// Fails when the value is zero.
+    pub fn max_clients(mut self, value: usize) -> Result<Self, ConfigValidationError> {
+        self.max_clients = non_zero_capacity(&self, "max_clients", value)?;
+        Ok(self)
+    }
+
+    /// Defines the maximum amount of supported [`crate::node::Node`]s. Fails when the value
+    /// is zero.
+    pub fn max_nodes(mut self, value: usize) -> Result<Self, ConfigValidationError> {
+        self.max_nodes = non_zero_capacity(&self, "max_nodes", value)?;
+        Ok(self)
+    }
+
+    /// Defines if a [`crate::port::client::Client`] can send requests that do not expect
+    /// any response.
+    pub fn enable_fire_and_forget_requests(mut self, value: bool) -> Self {
+        self.enable_fire_and_forget_requests = value;
+        self
+    }
+
+    /// Checks that every maximum number of ports and nodes as well as every buffer size is
+    /// at least one.
+    pub fn validate(&self) -> Result<(), ConfigValidationError> {
+        let capacities = [
+            self.max_active_responses,
+            self.max_active_requests,
+            self.max_borrowed_responses,
+            self.max_borrowed_requests,
+            self.max_response_buffer_size,
+            self.max_request_buffer_size,
+            self.max_servers,
+            self.max_clients,
+            self.max_nodes,
+        ];
+
+        if capacities.contains(&0) {
+            fail!(from self, with ConfigValidationError::ZeroCapacity,
+                "Invalid request-response default settings since at least one maximum number of ports or nodes or one buffer size is zero.");
+        }
+
+        Ok(())
+    }
 }
 
 /// Represents the configuration that iceoryx2 will utilize. It is divided into two sections:
@@ -348,6 +1089,73 @@ pub struct Config {
     pub defaults: Defaults,
 }
 
+/// Records which config file set which config entry when a [`Config`] was created with
+/// [`Config::from_files_with_provenance()`]. Entries are identified by their fully qualified
+/// key as it is written in the config file, e.g. `"global.service.creation-timeout.secs"`.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ConfigProvenance {
+    entries: BTreeMap<String, FilePath>,
+}
+
+impl ConfigProvenance {
+    /// Returns the config file that set the entry with the provided key. If the entry was
+    /// not set by any config file, the default value is used and [`None`] is returned.
+    pub fn source_of(&self, key: &str) -> Option<&FilePath> {
+        self.entries.get(key)
+    }
+
+    /// Returns an iterator over all entries that were set by a config file together with the
+    /// config file that set them.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &FilePath)> {
+        self.entries.iter().map(|(key, file)| (key.as_str(), file))
+    }
+
+    /// Returns the number of entries that were set by a config file.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true when no entry was set by a config file.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn merge(
+        &mut self,
+        base: &mut toml::Table,
+        overlay: toml::Table,
+        prefix: &str,
+        source: &FilePath,
+    ) {
+        for (key, value) in overlay {
+            let full_key = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", prefix, key)
+            };
+
+            match value {
+                toml::Value::Table(overlay_table) => {
+                    let entry = base
+                        .entry(key)
+                        .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+                    if !entry.is_table() {
+                        *entry = toml::Value::Table(toml::Table::new());
+                    }
+
+                    if let toml::Value::Table(base_table) = entry {
+                        self.merge(base_table, overlay_table, &full_key, source);
+                    }
+                }
+                value => {
+                    base.insert(key, value);
+                    self.entries.insert(full_key, *source);
+                }
+            }
+        }
+    }
+}
+
 static ICEORYX2_CONFIG: LazySingleton<Config> = LazySingleton::<Config>::new();
 
 impl Default for Config {
@@ -361,10 +1169,17 @@ impl Default for Config {
                     directory: Path::new(b"services").unwrap(),
                     publisher_data_segment_suffix: FileName::new(b".publisher_data").unwrap(),
                     static_config_storage_suffix: FileName::new(b".service").unwrap(),
+                    attribute_index_suffix: FileName::new(b".attributes").unwrap(),
                     dynamic_config_storage_suffix: FileName::new(b".dynamic").unwrap(),
                     creation_timeout: Duration::from_millis(500),
                     connection_suffix: FileName::new(b".connection").unwrap(),
                     event_connection_suffix: FileName::new(b".event").unwrap(),
+                    broadcast_suffix: FileName::new(b".broadcast").unwrap(),
+                    signing_key: None,
+                    verify_service_signatures: false,
+                    trusted_keys: Vec::new(),
+                    read_only_payload_mapping: false,
+                    data_segment_huge_pages: None,
                 },
                 node: Node {
                     directory: Path::new(b"nodes").unwrap(),
@@ -373,6 +1188,11 @@ impl Default for Config {
                     service_tag_suffix: FileName::new(b".service_tag").unwrap(),
                     cleanup_dead_nodes_on_creation: true,
                     cleanup_dead_nodes_on_destruction: true,
+                    load_shedding: LoadShedding {
+                        shedding_threshold: 90,
+                        recovery_threshold: 70,
+                        measurement_interval: Duration::from_secs(1),
+                    },
                 },
             },
             defaults: Defaults {
@@ -388,6 +1208,7 @@ impl Default for Config {
                     max_servers: 2,
                     max_clients: 8,
                     max_nodes: 20,
+                    enable_fire_and_forget_requests: false,
                 },
                 publish_subscribe: PublishSubscribe {
                     max_subscribers: 8,
@@ -410,6 +1231,7 @@ impl Default for Config {
                     notifier_created_event: None,
                     notifier_dropped_event: None,
                     notifier_dead_event: None,
+                    max_payload_size: 0,
                 },
             },
         }
@@ -423,6 +1245,62 @@ impl Config {
             "This should never happen! The relative local config path contains invalid symbols.")
     }
 
+    /// Creates a new [`Config`] from the structural [`EnvironmentConfig`] and the [`Defaults`].
+    /// All remaining global settings have their default value.
+    pub fn from_layers(environment: &EnvironmentConfig, defaults: &Defaults) -> Config {
+        let mut config = Config {
+            defaults: defaults.clone(),
+            ..Config::default()
+        };
+        config.set_environment(environment);
+        config
+    }
+
+    /// Returns the structural settings of the [`Config`] as [`EnvironmentConfig`].
+    pub fn environment(&self) -> EnvironmentConfig {
+        EnvironmentConfig {
+            root_path: *self.global.root_path(),
+            prefix: self.global.prefix,
+            service_directory: self.global.service.directory,
+            publisher_data_segment_suffix: self.global.service.publisher_data_segment_suffix,
+            static_config_storage_suffix: self.global.service.static_config_storage_suffix,
+            attribute_index_suffix: self.global.service.attribute_index_suffix,
+            dynamic_config_storage_suffix: self.global.service.dynamic_config_storage_suffix,
+            connection_suffix: self.global.service.connection_suffix,
+            event_connection_suffix: self.global.service.event_connection_suffix,
+            broadcast_suffix: self.global.service.broadcast_suffix,
+            node_directory: self.global.node.directory,
+            monitor_suffix: self.global.node.monitor_suffix,
+            node_static_config_suffix: self.global.node.static_config_suffix,
+            service_tag_suffix: self.global.node.service_tag_suffix,
+        }
+    }
+
+    /// Replaces the structural settings of the [`Config`] with the [`EnvironmentConfig`].
+    pub fn set_environment(&mut self, value: &EnvironmentConfig) {
+        self.global.set_root_path(&value.root_path);
+        self.global.prefix = value.prefix;
+        self.global.service.directory = value.service_directory;
+        self.global.service.publisher_data_segment_suffix = value.publisher_data_segment_suffix;
+        self.global.service.static_config_storage_suffix = value.static_config_storage_suffix;
+        self.global.service.attribute_index_suffix = value.attribute_index_suffix;
+        self.global.service.dynamic_config_storage_suffix = value.dynamic_config_storage_suffix;
+        self.global.service.connection_suffix = value.connection_suffix;
+        self.global.service.event_connection_suffix = value.event_connection_suffix;
+        self.global.service.broadcast_suffix = value.broadcast_suffix;
+        self.global.node.directory = value.node_directory;
+        self.global.node.monitor_suffix = value.monitor_suffix;
+        self.global.node.static_config_suffix = value.node_static_config_suffix;
+        self.global.node.service_tag_suffix = value.service_tag_suffix;
+    }
+
+    /// Validates the [`EnvironmentConfig`] and the [`Defaults`] of the [`Config`], see
+    /// [`EnvironmentConfig::validate()`] and [`Defaults::validate()`].
+    pub fn validate(&self) -> Result<(), ConfigValidationError> {
+        self.environment().validate()?;
+        self.defaults.validate()
+    }
+
     /// The name of the default iceoryx2 config file
     pub fn default_config_file_name() -> FileName {
         fatal_panic!(from "Config::default_config_file",
@@ -493,28 +1371,26 @@ impl Config {
         Ok(())
     }
 
-    /// Loads a configuration from a file. On success it returns a [`Config`] object otherwise a
-    /// [`ConfigCreationError`] describing the failure.
-    pub fn from_file(config_file: &FilePath) -> Result<Config, ConfigCreationError> {
-        let msg = "Failed to create config";
-        let mut new_config = Self::default();
+    fn read_config_file(config_file: &FilePath) -> Result<String, ConfigCreationError> {
+        let msg = "Failed to read config file";
+        let origin = "Config::read_config_file()";
 
         let file = match FileBuilder::new(config_file).open_existing(AccessMode::Read) {
             Ok(file) => file,
             Err(FileOpenError::InsufficientPermissions) => {
-                fail!(from new_config,
+                fail!(from origin,
                       with ConfigCreationError::InsufficientPermissions,
                       "{} since the config file \"{}\" could not be opened due to insufficient permissions.",
                       msg, config_file);
             }
             Err(FileOpenError::FileDoesNotExist) => {
-                fail!(from new_config,
+                fail!(from origin,
                       with ConfigCreationError::ConfigFileDoesNotExist,
                       "{} since the config file \"{}\" does not exist.",
                       msg, config_file);
             }
             Err(e) => {
-                fail!(from new_config,
+                fail!(from origin,
                       with ConfigCreationError::UnableToOpenConfigFile,
                       "{} since the config file \"{}\" could not be open due to an internal error ({:?}).",
                       msg, config_file, e);
@@ -522,9 +1398,20 @@ impl Config {
         };
 
         let mut contents = String::new();
-        fail!(from new_config, when file.read_to_string(&mut contents),
+        fail!(from origin, when file.read_to_string(&mut contents),
                 with ConfigCreationError::FailedToReadConfigFileContents,
-                "{} since the config file contents could not be read.", msg);
+                "{} since the config file \"{}\" contents could not be read.", msg, config_file);
+
+        Ok(contents)
+    }
+
+    /// Loads a configuration from a file. On success it returns a [`Config`] object otherwise a
+    /// [`ConfigCreationError`] describing the failure.
+    pub fn from_file(config_file: &FilePath) -> Result<Config, ConfigCreationError> {
+        let msg = "Failed to create config";
+        let mut new_config = Self::default();
+
+        let contents = Self::read_config_file(config_file)?;
 
         match toml::from_str(&contents) {
             Ok(v) => new_config = v,
@@ -538,6 +1425,60 @@ impl Config {
         Ok(new_config)
     }
 
+    /// Loads a configuration by merging multiple config files, e.g. `iceoryx2.toml` and
+    /// `iceoryx2.prod.toml`. The files are applied in the provided order on top of
+    /// [`Config::default()`], an entry in a later file overrides the same entry in all earlier
+    /// files. In contrast to [`Config::from_file()`], the files may contain only a subset of
+    /// all entries.
+    /// On success it returns a [`Config`] object otherwise a [`ConfigCreationError`] describing
+    /// the failure.
+    pub fn from_files(config_files: &[FilePath]) -> Result<Config, ConfigCreationError> {
+        Ok(Self::from_files_with_provenance(config_files)?.0)
+    }
+
+    /// Same as [`Config::from_files()`] but additionally returns the [`ConfigProvenance`] that
+    /// can be used to query which config file set a specific entry.
+    pub fn from_files_with_provenance(
+        config_files: &[FilePath],
+    ) -> Result<(Config, ConfigProvenance), ConfigCreationError> {
+        let msg = "Failed to create config from files";
+        let origin = "Config::from_files_with_provenance()";
+
+        let mut provenance = ConfigProvenance::default();
+        let mut merged_config = match toml::Value::try_from(Self::default()) {
+            Ok(toml::Value::Table(table)) => table,
+            _ => {
+                fatal_panic!(from origin,
+                    "This should never happen! The default config could not be serialized into a table.");
+            }
+        };
+
+        for config_file in config_files {
+            let contents = Self::read_config_file(config_file)?;
+            let overlay = match toml::from_str::<toml::Table>(&contents) {
+                Ok(v) => v,
+                Err(e) => {
+                    fail!(from origin, with ConfigCreationError::UnableToDeserializeContents,
+                        "{} since the contents of \"{}\" could not be deserialized ({}).",
+                        msg, config_file, e);
+                }
+            };
+
+            provenance.merge(&mut merged_config, overlay, "", config_file);
+        }
+
+        let new_config: Config = match toml::Value::Table(merged_config).try_into() {
+            Ok(v) => v,
+            Err(e) => {
+                fail!(from origin, with ConfigCreationError::UnableToDeserializeContents,
+                    "{} since the merged contents could not be deserialized ({}).", msg, e);
+            }
+        };
+
+        trace!(from new_config, "Loaded from {} files.", config_files.len());
+        Ok((new_config, provenance))
+    }
+
     /// Sets up the global configuration from a file. If the global configuration was already setup
     /// it will print a warning and does not load the file. It returns the [`Config`] when the file
     /// could be successfully loaded otherwise a [`ConfigCreationError`] describing the error.
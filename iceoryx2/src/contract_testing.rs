@@ -0,0 +1,355 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Contract tests for the publish-subscribe messaging pattern that can be used in downstream
+//! projects to verify that a payload type together with a specific quality of service behaves
+//! as expected.
+//!
+//! The [`publish_subscribe_contract_tests!`](crate::publish_subscribe_contract_tests) macro
+//! generates the whole suite for [`ipc::Service`](crate::service::ipc::Service) and
+//! [`local::Service`](crate::service::local::Service). Every test creates its own isolated
+//! service so that the tests can be executed in parallel.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::contract_testing::PublishSubscribeContract;
+//!
+//! #[derive(Debug, PartialEq)]
+//! #[repr(C)]
+//! pub struct Position {
+//!     x: f64,
+//!     y: f64,
+//! }
+//!
+//! iceoryx2::publish_subscribe_contract_tests!(
+//!     position_contract,
+//!     Position,
+//!     |n| Position { x: n as f64, y: 0.5 * n as f64 },
+//!     PublishSubscribeContract {
+//!         history_size: 2,
+//!         ..Default::default()
+//!     }
+//! );
+//! # fn main() {}
+//! ```
+
+use core::fmt::Debug;
+
+use crate::node::{Node, NodeBuilder};
+use crate::port::update_connections::UpdateConnections;
+use crate::service::port_factory::publish_subscribe::PortFactory;
+use crate::service::port_factory::publisher::UnableToDeliverStrategy;
+use crate::service::Service;
+use crate::testing::{generate_isolated_config, generate_service_name};
+
+/// The quality of service the contract tests are executed with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublishSubscribeContract {
+    /// The buffer size of every [`Subscriber`](crate::port::subscriber::Subscriber).
+    pub subscriber_max_buffer_size: usize,
+    /// The number of samples a [`Subscriber`](crate::port::subscriber::Subscriber) can
+    /// borrow in parallel.
+    pub subscriber_max_borrowed_samples: usize,
+    /// The number of samples that are delivered to late joining
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s.
+    pub history_size: usize,
+    /// Defines if the oldest sample is replaced when the buffer of a
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) is full.
+    pub enable_safe_overflow: bool,
+}
+
+impl Default for PublishSubscribeContract {
+    fn default() -> Self {
+        Self {
+            subscriber_max_buffer_size: 2,
+            subscriber_max_borrowed_samples: 2,
+            history_size: 0,
+            enable_safe_overflow: true,
+        }
+    }
+}
+
+impl PublishSubscribeContract {
+    fn create_service<S: Service, Payload: Debug + 'static>(
+        &self,
+        node: &Node<S>,
+        max_publishers: usize,
+    ) -> PortFactory<S, Payload, ()> {
+        node.service_builder(&generate_service_name())
+            .publish_subscribe::<Payload>()
+            .max_publishers(max_publishers)
+            .subscriber_max_buffer_size(self.subscriber_max_buffer_size)
+            .subscriber_max_borrowed_samples(self.subscriber_max_borrowed_samples)
+            .history_size(self.history_size)
+            .enable_safe_overflow(self.enable_safe_overflow)
+            .create()
+            .unwrap_or_else(|e| {
+                panic!(
+                    "The contract {:?} does not describe a valid service ({:?}).",
+                    self, e
+                )
+            })
+    }
+
+    fn number_of_history_samples(&self) -> u64 {
+        self.history_size.min(self.subscriber_max_buffer_size) as u64
+    }
+}
+
+fn create_node<S: Service>() -> Node<S> {
+    NodeBuilder::new()
+        .config(&generate_isolated_config())
+        .create::<S>()
+        .expect("The node for the contract test can be created.")
+}
+
+/// Loans a sample, writes the payload generated for `n` into it and sends it. Returns the
+/// number of [`Subscriber`](crate::port::subscriber::Subscriber)s that received it.
+fn send<S: Service, Payload: Debug + 'static>(
+    publisher: &crate::port::publisher::Publisher<S, Payload, ()>,
+    generator: &impl Fn(u64) -> Payload,
+    n: u64,
+) -> usize {
+    publisher
+        .loan_uninit()
+        .expect("A sample can be loaned.")
+        .write_payload(generator(n))
+        .send()
+        .expect("A sample can be sent.")
+}
+
+fn receive_and_verify<S: Service, Payload: Debug + PartialEq + 'static>(
+    subscriber: &crate::port::subscriber::Subscriber<S, Payload, ()>,
+    generator: &impl Fn(u64) -> Payload,
+    n: u64,
+) {
+    let sample = subscriber
+        .receive()
+        .expect("A sample can be received.")
+        .unwrap_or_else(|| {
+            panic!(
+                "The sample with the payload {:?} is available.",
+                generator(n)
+            )
+        });
+    assert_eq!(*sample, generator(n));
+}
+
+fn verify_no_more_samples<S: Service, Payload: Debug + 'static>(
+    subscriber: &crate::port::subscriber::Subscriber<S, Payload, ()>,
+) {
+    let sample = subscriber.receive().expect("A sample can be received.");
+    assert!(
+        sample.is_none(),
+        "No more samples are expected but {:?} was received.",
+        sample
+    );
+}
+
+/// Every sample that is loaned, written and sent is received with the identical payload.
+pub fn loan_send_receive_roundtrip<S: Service, Payload: Debug + PartialEq + 'static>(
+    contract: &PublishSubscribeContract,
+    generator: impl Fn(u64) -> Payload,
+) {
+    let node = create_node::<S>();
+    let service = contract.create_service::<S, Payload>(&node, 1);
+    let subscriber = service.subscriber_builder().create().unwrap();
+    let publisher = service.publisher_builder().create().unwrap();
+
+    for n in 0..contract.subscriber_max_buffer_size as u64 {
+        assert_eq!(send(&publisher, &generator, n), 1);
+    }
+
+    for n in 0..contract.subscriber_max_buffer_size as u64 {
+        receive_and_verify(&subscriber, &generator, n);
+    }
+    verify_no_more_samples(&subscriber);
+}
+
+/// When the buffer of the [`Subscriber`](crate::port::subscriber::Subscriber) is full, the
+/// oldest sample is replaced with safe overflow and the newest sample is discarded without.
+pub fn overflow_behavior<S: Service, Payload: Debug + PartialEq + 'static>(
+    contract: &PublishSubscribeContract,
+    generator: impl Fn(u64) -> Payload,
+) {
+    let node = create_node::<S>();
+    let service = contract.create_service::<S, Payload>(&node, 1);
+    let subscriber = service.subscriber_builder().create().unwrap();
+    let publisher = service
+        .publisher_builder()
+        .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+        .create()
+        .unwrap();
+
+    let buffer_size = contract.subscriber_max_buffer_size as u64;
+    for n in 0..=buffer_size {
+        send(&publisher, &generator, n);
+    }
+
+    let expected_samples = if contract.enable_safe_overflow {
+        1..=buffer_size
+    } else {
+        0..=buffer_size - 1
+    };
+
+    for n in expected_samples {
+        receive_and_verify(&subscriber, &generator, n);
+    }
+    verify_no_more_samples(&subscriber);
+}
+
+/// A late joining [`Subscriber`](crate::port::subscriber::Subscriber) receives the latest
+/// samples of the history in the order they were sent.
+pub fn history_correctness<S: Service, Payload: Debug + PartialEq + 'static>(
+    contract: &PublishSubscribeContract,
+    generator: impl Fn(u64) -> Payload,
+) {
+    let node = create_node::<S>();
+    let service = contract.create_service::<S, Payload>(&node, 1);
+    let publisher = service.publisher_builder().create().unwrap();
+
+    let number_of_samples = 2 * contract.history_size as u64 + 1;
+    for n in 0..number_of_samples {
+        assert_eq!(send(&publisher, &generator, n), 0);
+    }
+
+    let subscriber = service.subscriber_builder().create().unwrap();
+    publisher
+        .update_connections()
+        .expect("The publisher can connect to the new subscriber.");
+
+    for n in number_of_samples - contract.number_of_history_samples()..number_of_samples {
+        receive_and_verify(&subscriber, &generator, n);
+    }
+    verify_no_more_samples(&subscriber);
+}
+
+/// Late joining [`Subscriber`](crate::port::subscriber::Subscriber)s and
+/// [`Publisher`](crate::port::publisher::Publisher)s are connected and take part in the
+/// communication like all other ports.
+pub fn late_join_behavior<S: Service, Payload: Debug + PartialEq + 'static>(
+    contract: &PublishSubscribeContract,
+    generator: impl Fn(u64) -> Payload,
+) {
+    let node = create_node::<S>();
+    let service = contract.create_service::<S, Payload>(&node, 2);
+    let publisher = service.publisher_builder().create().unwrap();
+    assert_eq!(send(&publisher, &generator, 0), 0);
+
+    let subscriber = service.subscriber_builder().create().unwrap();
+    let late_publisher = service.publisher_builder().create().unwrap();
+    publisher
+        .update_connections()
+        .expect("The publisher can connect to the new subscriber.");
+
+    if contract.number_of_history_samples() != 0 {
+        receive_and_verify(&subscriber, &generator, 0);
+    }
+    verify_no_more_samples(&subscriber);
+
+    assert_eq!(send(&publisher, &generator, 1), 1);
+    receive_and_verify(&subscriber, &generator, 1);
+
+    assert_eq!(send(&late_publisher, &generator, 2), 1);
+    receive_and_verify(&subscriber, &generator, 2);
+    verify_no_more_samples(&subscriber);
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __publish_subscribe_contract_tests_for_service {
+    ($name:ident, $service:ty, $payload:ty, $generator:expr, $contract:expr) => {
+        mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            fn contract() -> $crate::contract_testing::PublishSubscribeContract {
+                $contract
+            }
+
+            #[test]
+            fn loan_send_receive_roundtrip() {
+                $crate::contract_testing::loan_send_receive_roundtrip::<$service, $payload>(
+                    &contract(),
+                    $generator,
+                );
+            }
+
+            #[test]
+            fn overflow_behavior() {
+                $crate::contract_testing::overflow_behavior::<$service, $payload>(
+                    &contract(),
+                    $generator,
+                );
+            }
+
+            #[test]
+            fn history_correctness() {
+                $crate::contract_testing::history_correctness::<$service, $payload>(
+                    &contract(),
+                    $generator,
+                );
+            }
+
+            #[test]
+            fn late_join_behavior() {
+                $crate::contract_testing::late_join_behavior::<$service, $payload>(
+                    &contract(),
+                    $generator,
+                );
+            }
+        }
+    };
+}
+
+/// Generates the publish-subscribe contract test suite for a payload type in a module called
+/// `$name`, see [`contract_testing`](crate::contract_testing). The tests are executed for
+/// [`ipc::Service`](crate::service::ipc::Service) and
+/// [`local::Service`](crate::service::local::Service).
+///
+/// * `$payload` - the payload type under test
+/// * `$generator` - a closure that creates a distinct payload for every `u64` it is called with
+/// * `$contract` - (optional) the [`PublishSubscribeContract`] with the quality of service
+///   the tests are executed with, uses [`PublishSubscribeContract::default()`] when omitted
+#[macro_export]
+macro_rules! publish_subscribe_contract_tests {
+    ($name:ident, $payload:ty, $generator:expr) => {
+        $crate::publish_subscribe_contract_tests!(
+            $name,
+            $payload,
+            $generator,
+            $crate::contract_testing::PublishSubscribeContract::default()
+        );
+    };
+    ($name:ident, $payload:ty, $generator:expr, $contract:expr) => {
+        mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $crate::__publish_subscribe_contract_tests_for_service!(
+                ipc,
+                $crate::service::ipc::Service,
+                $payload,
+                $generator,
+                $contract
+            );
+            $crate::__publish_subscribe_contract_tests_for_service!(
+                local,
+                $crate::service::local::Service,
+                $payload,
+                $generator,
+                $contract
+            );
+        }
+    };
+}
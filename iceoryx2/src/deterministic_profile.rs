@@ -0,0 +1,62 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The deterministic profile bundles all settings that are required for a bounded jitter in
+//! the communication path. It is enabled with the `deterministic_profile` feature flag and
+//! replaces a checklist of individual port settings with a single switch.
+//!
+//! When enabled, every port is created with the following settings and the creation fails when
+//! a setting that violates the profile is explicitly requested:
+//!
+//!  * [`Publisher`](crate::port::publisher::Publisher)s, [`Client`](crate::port::client::Client)s
+//!    and [`Server`](crate::port::server::Server)s use [`AllocationStrategy::Static`]. The
+//!    data segment is preallocated and never grows, therefore no dynamic segments are created
+//!    while sending.
+//!  * [`Subscriber`](crate::port::subscriber::Subscriber)s map the data segments of all
+//!    [`Publisher`](crate::port::publisher::Publisher)s eagerly when the connection is
+//!    established instead of on the first received sample.
+//!  * [`Subscriber`](crate::port::subscriber::Subscriber)s do not receive heap copies of
+//!    samples, since this requires a heap allocation on every receive.
+//!
+//! The builder methods for these settings remain available so that enabling the feature does not
+//! break any code. Requesting a violating setting makes the port creation fail with
+//! `ViolatesDeterministicProfile`, which is also reported through the C API.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::deterministic_profile;
+//!
+//! if deterministic_profile::IS_ENABLED {
+//!     println!("all ports are created with the deterministic profile");
+//! }
+//! ```
+
+use iceoryx2_cal::shm_allocator::AllocationStrategy;
+
+/// True when iceoryx2 was built with the `deterministic_profile` feature flag.
+pub const IS_ENABLED: bool = cfg!(feature = "deterministic_profile");
+
+pub(crate) fn default_eager_segment_mapping() -> bool {
+    IS_ENABLED
+}
+
+pub(crate) fn allows_allocation_strategy(value: AllocationStrategy) -> bool {
+    !IS_ENABLED || value == AllocationStrategy::Static
+}
+
+pub(crate) fn allows_subscriber_settings(
+    eager_segment_mapping: bool,
+    receive_heap_copies: bool,
+) -> bool {
+    !IS_ENABLED || (eager_segment_mapping && !receive_heap_copies)
+}
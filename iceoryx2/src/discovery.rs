@@ -0,0 +1,210 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`Tracker`] monitors the [`Service`]s of a [`Config`] and reports every [`Service`]
+//! that was added or removed since the last [`Tracker::sync()`] as [`DiscoveryEvent`].
+//!
+//! A [`Tracker`] created with [`Tracker::with_notifications()`] additionally announces the
+//! detected changes via the event service [`DISCOVERY_SERVICE_NAME`] with the
+//! [`SERVICE_ADDED_EVENT`] and [`SERVICE_REMOVED_EVENT`]. Other processes can attach a
+//! [`Listener`](crate::port::listener::Listener) of this service to a
+//! [`WaitSet`](crate::waitset::WaitSet) and synchronize their own [`Tracker`] only when
+//! something has changed instead of polling [`Service::list()`] in a loop.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::discovery::{DiscoveryEvent, Tracker};
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let mut tracker = Tracker::<ipc::Service>::new(node.config());
+//!
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! for event in tracker.sync()? {
+//!     match event {
+//!         DiscoveryEvent::Added(service) => println!("added: {}", service.name()),
+//!         DiscoveryEvent::Removed(service) => println!("removed: {}", service.name()),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_log::{fail, warn};
+
+use crate::config::Config;
+use crate::node::Node;
+use crate::port::event_id::EventId;
+use crate::port::notifier::Notifier;
+use crate::service::service_id::ServiceId;
+use crate::service::service_name::ServiceName;
+use crate::service::static_config::StaticConfig;
+use crate::service::{Service, ServiceListError};
+
+/// The name of the event service via which a [`Tracker`] created with
+/// [`Tracker::with_notifications()`] announces the detected changes.
+pub const DISCOVERY_SERVICE_NAME: &str = "iox2/discovery/services";
+
+/// The [`EventId`] that is emitted when at least one [`Service`] was added.
+pub const SERVICE_ADDED_EVENT: EventId = EventId::new(0);
+
+/// The [`EventId`] that is emitted when at least one [`Service`] was removed.
+pub const SERVICE_REMOVED_EVENT: EventId = EventId::new(1);
+
+/// Failures that can occur when a [`Tracker`] is created with
+/// [`Tracker::with_notifications()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum TrackerCreateError {
+    /// The event service [`DISCOVERY_SERVICE_NAME`] could not be created or opened.
+    UnableToOpenDiscoveryService,
+    /// The [`Notifier`] of the event service [`DISCOVERY_SERVICE_NAME`] could not be created.
+    UnableToCreateNotifier,
+}
+
+impl core::fmt::Display for TrackerCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "TrackerCreateError::{:?}", self)
+    }
+}
+
+impl core::error::Error for TrackerCreateError {}
+
+/// A change that was detected by [`Tracker::sync()`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum DiscoveryEvent {
+    /// The [`Service`] with the contained [`StaticConfig`] was added.
+    Added(StaticConfig),
+    /// The [`Service`] with the contained [`StaticConfig`] was removed.
+    Removed(StaticConfig),
+}
+
+/// Keeps track of all [`Service`]s of a [`Config`], see the [module](crate::discovery)
+/// documentation.
+#[derive(Debug)]
+pub struct Tracker<S: Service> {
+    config: Config,
+    services: HashMap<ServiceId, StaticConfig>,
+    notifier: Option<Notifier<S>>,
+}
+
+impl<S: Service> Tracker<S> {
+    /// Creates a new [`Tracker`] that tracks the [`Service`]s of the provided [`Config`]. It
+    /// does not know any [`Service`] yet, therefore the first [`Tracker::sync()`] reports all
+    /// existing [`Service`]s as added.
+    pub fn new(config: &Config) -> Self {
+        Self {
+            config: config.clone(),
+            services: HashMap::new(),
+            notifier: None,
+        }
+    }
+
+    /// Creates a new [`Tracker`] that tracks the [`Service`]s of the [`Config`] of the
+    /// [`Node`] and announces every change detected in [`Tracker::sync()`] via the event
+    /// service [`DISCOVERY_SERVICE_NAME`].
+    pub fn with_notifications(node: &Node<S>) -> Result<Self, TrackerCreateError> {
+        let msg = "Unable to create tracker with notifications";
+        let service_name: ServiceName = fail!(from "Tracker::with_notifications()",
+            when DISCOVERY_SERVICE_NAME.try_into(),
+            with TrackerCreateError::UnableToOpenDiscoveryService,
+            "{} since \"{}\" is not a valid service name.", msg, DISCOVERY_SERVICE_NAME);
+
+        let service = fail!(from "Tracker::with_notifications()",
+            when node.service_builder(&service_name).event().open_or_create(),
+            with TrackerCreateError::UnableToOpenDiscoveryService,
+            "{} since the discovery service could not be created or opened.", msg);
+
+        let notifier = fail!(from "Tracker::with_notifications()",
+            when service.notifier_builder().create(),
+            with TrackerCreateError::UnableToCreateNotifier,
+            "{} since the notifier of the discovery service could not be created.", msg);
+
+        let mut tracker = Self::new(node.config());
+        tracker.notifier = Some(notifier);
+        Ok(tracker)
+    }
+
+    /// Compares the currently existing [`Service`]s with the [`Service`]s known from the last
+    /// call and returns every [`Service`] that was added or removed in between.
+    pub fn sync(&mut self) -> Result<Vec<DiscoveryEvent>, ServiceListError> {
+        let mut current_services = HashMap::new();
+        fail!(from self, when S::list(&self.config, |service| {
+                current_services.insert(
+                    service.static_details.service_id().clone(),
+                    service.static_details,
+                );
+                CallbackProgression::Continue
+            }),
+            "Unable to synchronize the tracker since the services could not be listed.");
+
+        let mut events = Vec::new();
+        for (id, static_config) in &current_services {
+            if !self.services.contains_key(id) {
+                events.push(DiscoveryEvent::Added(static_config.clone()));
+            }
+        }
+
+        for (id, static_config) in self.services.drain() {
+            if !current_services.contains_key(&id) {
+                events.push(DiscoveryEvent::Removed(static_config));
+            }
+        }
+
+        self.services = current_services;
+        self.announce(&events);
+
+        Ok(events)
+    }
+
+    /// Returns the [`StaticConfig`] of the [`Service`] with the provided [`ServiceId`] when
+    /// it was present during the last [`Tracker::sync()`].
+    pub fn get(&self, id: &ServiceId) -> Option<&StaticConfig> {
+        self.services.get(id)
+    }
+
+    /// Returns an iterator over the [`StaticConfig`]s of all [`Service`]s that were present
+    /// during the last [`Tracker::sync()`].
+    pub fn services(&self) -> impl Iterator<Item = &StaticConfig> {
+        self.services.values()
+    }
+
+    fn announce(&self, events: &[DiscoveryEvent]) {
+        let notifier = match &self.notifier {
+            Some(notifier) => notifier,
+            None => return,
+        };
+
+        let has_added = events.iter().any(|e| matches!(e, DiscoveryEvent::Added(_)));
+        let has_removed = events
+            .iter()
+            .any(|e| matches!(e, DiscoveryEvent::Removed(_)));
+
+        for (is_changed, event_id) in [
+            (has_added, SERVICE_ADDED_EVENT),
+            (has_removed, SERVICE_REMOVED_EVENT),
+        ] {
+            if is_changed {
+                if let Err(e) = notifier.notify_with_custom_event_id(event_id) {
+                    warn!(from self, "Unable to announce the detected changes ({:?}).", e);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,398 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`Introspection`] enumerates all [`Node`]s, [`Service`]s, ports and connections of a
+//! [`Config`] as plain Rust structs. Monitoring tools can be built on top of it instead of
+//! reading the internal shared memory layouts of iceoryx2, which may change between releases.
+//!
+//! Every call acquires a new snapshot of the system. Since other processes create and remove
+//! entities concurrently, a snapshot can already be outdated when it is returned.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::introspection::Introspection;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let introspection = Introspection::<ipc::Service>::new(Config::global_config());
+//!
+//! for node in introspection.nodes()? {
+//!     println!("node {:?} is {:?}", node.id, node.liveness);
+//! }
+//!
+//! for service in introspection.services()? {
+//!     println!("service {}", service.static_config.name());
+//!     if let Some(dynamic) = service.dynamic {
+//!         for port in dynamic.ports {
+//!             println!("  {:?} {} of node {:?}", port.kind, port.id, port.node_id);
+//!         }
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use core::marker::PhantomData;
+use std::collections::HashSet;
+
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::named_concept::{NamedConceptListError, NamedConceptMgmt};
+
+use crate::config::Config;
+use crate::node::node_name::NodeName;
+use crate::node::{Node, NodeId, NodeListFailure, NodeState, NodeView};
+use crate::service::config_scheme::connection_config;
+use crate::service::dynamic_config::DynamicConfig;
+use crate::service::static_config::messaging_pattern::MessagingPattern;
+use crate::service::static_config::StaticConfig;
+use crate::service::{open_dynamic_config, Service, ServiceDetailsError, ServiceListError};
+
+/// Failures that can occur when the system is inspected with the [`Introspection`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum IntrospectionError {
+    /// The process has insufficient permissions to access the resources of iceoryx2.
+    InsufficientPermissions,
+    /// The process received an interrupt signal while acquiring the snapshot.
+    Interrupt,
+    /// A [`Service`] was created with a different iceoryx2 version.
+    VersionMismatch,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalError,
+}
+
+impl core::fmt::Display for IntrospectionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "IntrospectionError::{:?}", self)
+    }
+}
+
+impl core::error::Error for IntrospectionError {}
+
+/// Describes whether the process that owns a [`Node`] is still alive.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum NodeLiveness {
+    /// The [`Node`]s process is alive.
+    Alive,
+    /// The [`Node`]s process died without cleaning up its resources.
+    Dead,
+    /// The process has insufficient permissions to identify the [`Node`] as dead or alive.
+    Inaccessible,
+    /// The resources of the [`Node`] are missing or inconsistent.
+    Undefined,
+}
+
+/// A [`Node`] of the inspected [`Config`].
+#[derive(Debug, Clone)]
+pub struct NodeInfo {
+    /// The [`NodeId`] of the [`Node`].
+    pub id: NodeId,
+    /// Whether the [`Node`]s process is still alive.
+    pub liveness: NodeLiveness,
+    /// The [`NodeName`], when the details of the [`Node`] are accessible.
+    pub name: Option<NodeName>,
+    /// The executable of the owning process, when the details of the [`Node`] are accessible.
+    pub executable: Option<FileName>,
+}
+
+/// The kind of a port of a [`Service`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum PortKind {
+    /// A [`Publisher`](crate::port::publisher::Publisher).
+    Publisher,
+    /// A [`Subscriber`](crate::port::subscriber::Subscriber).
+    Subscriber,
+    /// A [`Notifier`](crate::port::notifier::Notifier).
+    Notifier,
+    /// A [`Listener`](crate::port::listener::Listener).
+    Listener,
+    /// A [`Server`](crate::port::server::Server).
+    Server,
+    /// A [`Client`](crate::port::client::Client).
+    Client,
+}
+
+/// A port of a [`Service`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub struct PortInfo {
+    /// The kind of the port.
+    pub kind: PortKind,
+    /// The system-wide unique id of the port.
+    pub id: u128,
+    /// The [`NodeId`] of the [`Node`] that owns the port.
+    pub node_id: NodeId,
+    /// The capacity of the port. The number of samples a
+    /// [`Publisher`](crate::port::publisher::Publisher) can loan, the receive buffer size of a
+    /// [`Subscriber`](crate::port::subscriber::Subscriber), the request buffer size of a
+    /// [`Server`](crate::port::server::Server) and the response buffer size of a
+    /// [`Client`](crate::port::client::Client). Event ports do not have a buffer.
+    pub buffer_size: Option<usize>,
+}
+
+/// An established connection between two ports of a [`Service`]. A request response
+/// [`Service`] has a separate connection for the requests and the responses.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub struct ConnectionInfo {
+    /// The id of the sending port.
+    pub sender: u128,
+    /// The id of the receiving port.
+    pub receiver: u128,
+}
+
+/// The runtime information of a [`Service`].
+#[derive(Debug, Clone)]
+pub struct ServiceDynamicInfo {
+    /// The [`NodeId`]s of all [`Node`]s that opened the [`Service`].
+    pub nodes: Vec<NodeId>,
+    /// All ports of the [`Service`].
+    pub ports: Vec<PortInfo>,
+    /// All established connections between the ports of the [`Service`].
+    pub connections: Vec<ConnectionInfo>,
+    /// The number of samples that are currently held by all
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s of a publish subscribe
+    /// [`Service`]. It is always zero for all other messaging patterns.
+    pub number_of_borrowed_samples: usize,
+}
+
+/// A [`Service`] of the inspected [`Config`].
+#[derive(Debug, Clone)]
+pub struct ServiceInfo {
+    /// The [`StaticConfig`] that never changes during the lifetime of the [`Service`].
+    pub static_config: StaticConfig,
+    /// The runtime information, [`None`] when the [`Service`] was removed or is not yet
+    /// initialized.
+    pub dynamic: Option<ServiceDynamicInfo>,
+}
+
+/// Acquires snapshots of all [`Node`]s and [`Service`]s of a [`Config`], see the
+/// [module](crate::introspection) documentation.
+#[derive(Debug)]
+pub struct Introspection<S: Service> {
+    config: Config,
+    _service: PhantomData<S>,
+}
+
+impl<S: Service> Introspection<S> {
+    /// Creates a new [`Introspection`] for all entities of the provided [`Config`].
+    pub fn new(config: &Config) -> Self {
+        Self {
+            config: config.clone(),
+            _service: PhantomData,
+        }
+    }
+
+    /// Returns all [`Node`]s.
+    pub fn nodes(&self) -> Result<Vec<NodeInfo>, IntrospectionError> {
+        let mut nodes = vec![];
+        let result = Node::<S>::list(&self.config, |state| {
+            let (liveness, details) = match &state {
+                NodeState::Alive(view) => (NodeLiveness::Alive, view.details().clone()),
+                NodeState::Dead(view) => (NodeLiveness::Dead, view.details().clone()),
+                NodeState::Inaccessible(_) => (NodeLiveness::Inaccessible, None),
+                NodeState::Undefined(_) => (NodeLiveness::Undefined, None),
+            };
+
+            nodes.push(NodeInfo {
+                id: *state.node_id(),
+                liveness,
+                name: details.as_ref().map(|d| d.name().clone()),
+                executable: details.as_ref().map(|d| *d.executable()),
+            });
+            CallbackProgression::Continue
+        });
+
+        match result {
+            Ok(()) => Ok(nodes),
+            Err(e) => {
+                let e = match e {
+                    NodeListFailure::InsufficientPermissions => {
+                        IntrospectionError::InsufficientPermissions
+                    }
+                    NodeListFailure::Interrupt => IntrospectionError::Interrupt,
+                    NodeListFailure::InternalError => IntrospectionError::InternalError,
+                };
+                fail!(from self, with e,
+                    "Unable to acquire all nodes since the node list could not be acquired ({:?}).", e);
+            }
+        }
+    }
+
+    /// Returns all [`Service`]s including their ports and connections.
+    pub fn services(&self) -> Result<Vec<ServiceInfo>, IntrospectionError> {
+        let msg = "Unable to acquire all services";
+        let mut static_configs = vec![];
+        if let Err(e) = S::list(&self.config, |details| {
+            static_configs.push(details.static_details);
+            CallbackProgression::Continue
+        }) {
+            let e = match e {
+                ServiceListError::InsufficientPermissions => {
+                    IntrospectionError::InsufficientPermissions
+                }
+                ServiceListError::InternalError => IntrospectionError::InternalError,
+            };
+            fail!(from self, with e,
+                "{} since the service list could not be acquired ({:?}).", msg, e);
+        }
+
+        let connections = self.connections()?;
+        let mut services = vec![];
+        for static_config in static_configs {
+            let dynamic_config = match open_dynamic_config::<S>(
+                &self.config,
+                static_config.service_id(),
+            ) {
+                Ok(dynamic_config) => dynamic_config,
+                Err(ServiceDetailsError::VersionMismatch) => {
+                    fail!(from self, with IntrospectionError::VersionMismatch,
+                        "{} since the service \"{}\" was created with a different iceoryx2 version.",
+                        msg, static_config.name());
+                }
+                Err(e) => {
+                    fail!(from self, with IntrospectionError::InternalError,
+                        "{} since the dynamic config of the service \"{}\" could not be opened ({:?}).",
+                        msg, static_config.name(), e);
+                }
+            };
+
+            let dynamic =
+                dynamic_config.map(|d| Self::dynamic_info(&static_config, d.get(), &connections));
+            services.push(ServiceInfo {
+                static_config,
+                dynamic,
+            });
+        }
+
+        Ok(services)
+    }
+
+    fn connections(&self) -> Result<Vec<ConnectionInfo>, IntrospectionError> {
+        let msg = "Unable to acquire all connections";
+        let names = match <S::Connection as NamedConceptMgmt>::list_cfg(&connection_config::<S>(
+            &self.config,
+        )) {
+            Ok(names) => names,
+            Err(NamedConceptListError::InsufficientPermissions) => {
+                fail!(from self, with IntrospectionError::InsufficientPermissions,
+                    "{} due to insufficient permissions.", msg);
+            }
+            Err(NamedConceptListError::InternalError) => {
+                fail!(from self, with IntrospectionError::InternalError,
+                    "{} due to an internal error.", msg);
+            }
+        };
+
+        Ok(names
+            .iter()
+            .filter_map(|name| {
+                let (sender, receiver) = core::str::from_utf8(name.as_bytes())
+                    .ok()?
+                    .split_once('_')?;
+                Some(ConnectionInfo {
+                    sender: sender.parse().ok()?,
+                    receiver: receiver.parse().ok()?,
+                })
+            })
+            .collect())
+    }
+
+    fn dynamic_info(
+        static_config: &StaticConfig,
+        dynamic_config: &DynamicConfig,
+        connections: &[ConnectionInfo],
+    ) -> ServiceDynamicInfo {
+        let mut nodes = vec![];
+        dynamic_config.list_node_ids(|node_id| {
+            nodes.push(*node_id);
+            CallbackProgression::Continue
+        });
+
+        let mut ports = vec![];
+        let mut number_of_borrowed_samples = 0;
+        let mut port = |kind, id, node_id, buffer_size| {
+            ports.push(PortInfo {
+                kind,
+                id,
+                node_id,
+                buffer_size,
+            })
+        };
+
+        match static_config.messaging_pattern() {
+            MessagingPattern::PublishSubscribe(_) => {
+                let pubsub = dynamic_config.publish_subscribe();
+                pubsub.__internal_list_publishers(|p| {
+                    port(
+                        PortKind::Publisher,
+                        p.publisher_id.value(),
+                        p.node_id,
+                        Some(p.number_of_samples),
+                    )
+                });
+                pubsub.__internal_list_subscribers(|s| {
+                    port(
+                        PortKind::Subscriber,
+                        s.subscriber_id.value(),
+                        s.node_id,
+                        Some(s.buffer_size),
+                    )
+                });
+                number_of_borrowed_samples = pubsub.number_of_borrowed_samples();
+            }
+            MessagingPattern::Event(_) => {
+                let event = dynamic_config.event();
+                event.__internal_list_notifiers(|n| {
+                    port(PortKind::Notifier, n.notifier_id.value(), n.node_id, None)
+                });
+                event.__internal_list_listeners(|l| {
+                    port(PortKind::Listener, l.listener_id.value(), l.node_id, None)
+                });
+            }
+            MessagingPattern::RequestResponse(_) => {
+                let reqres = dynamic_config.request_response();
+                reqres.__internal_list_servers(|s| {
+                    port(
+                        PortKind::Server,
+                        s.server_port_id.value(),
+                        s.node_id,
+                        Some(s.request_buffer_size),
+                    )
+                });
+                reqres.__internal_list_clients(|c| {
+                    port(
+                        PortKind::Client,
+                        c.client_port_id.value(),
+                        c.node_id,
+                        Some(c.response_buffer_size),
+                    )
+                });
+            }
+        }
+
+        let port_ids: HashSet<u128> = ports.iter().map(|p| p.id).collect();
+        let connections = connections
+            .iter()
+            .filter(|c| port_ids.contains(&c.sender) && port_ids.contains(&c.receiver))
+            .copied()
+            .collect();
+
+        ServiceDynamicInfo {
+            nodes,
+            ports,
+            connections,
+            number_of_borrowed_samples,
+        }
+    }
+}
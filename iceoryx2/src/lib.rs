@@ -288,17 +288,63 @@
 #[cfg(doctest)]
 mod compiletests;
 
+/// A sample that was received by an
+/// [`AckSubscriber`](crate::port::ack_subscriber::AckSubscriber) and that must be acknowledged.
+pub mod ack_sample;
+
+/// A request that was received by a [`Server`](crate::port::server::Server) and that is used
+/// to send responses back to the [`Client`](crate::port::client::Client).
+pub mod active_request;
+
+/// Reports the compile-time and platform dependent limits of the deployed iceoryx2 build
+pub mod capabilities;
+
 /// Handles iceoryx2s global configuration
 pub mod config;
 
+/// Contract tests for payload types and quality of service settings of downstream projects
+pub mod contract_testing;
+
+/// Enforces the settings required for bounded jitter when iceoryx2 is built with the
+/// `deterministic_profile` feature flag
+pub mod deterministic_profile;
+
+/// Detects added and removed services and optionally announces them via an event service
+pub mod discovery;
+
+/// The error response that is received by a [`Client`](crate::port::client::Client).
+pub mod error_response;
+
+/// Enumerates nodes, services, ports and connections as plain structs for monitoring tools
+pub mod introspection;
+
 /// Central instance that owns all service entities and can handle incoming event in an event loop
 pub mod node;
 
+/// The handle of a sent request, used by the [`Client`](crate::port::client::Client) to
+/// receive the corresponding responses.
+pub mod pending_response;
+
 /// The ports or communication endpoints of iceoryx2
 pub mod port;
 
 pub(crate) mod raw_sample;
 
+/// The request that is sent by a [`Client`](crate::port::client::Client).
+pub mod request_mut;
+
+/// The uninitialized request that is loaned by a [`Client`](crate::port::client::Client).
+pub mod request_mut_uninit;
+
+/// The response that is received by a [`Client`](crate::port::client::Client).
+pub mod response;
+
+/// The response that is sent by a [`Server`](crate::port::server::Server).
+pub mod response_mut;
+
+/// The uninitialized response that is loaned by a [`Server`](crate::port::server::Server).
+pub mod response_mut_uninit;
+
 /// The payload that is received by a [`Subscriber`](crate::port::subscriber::Subscriber).
 pub mod sample;
 
@@ -324,3 +370,5 @@ pub mod testing;
 /// Event handling mechanism to wait on multiple [`Listener`](crate::port::listener::Listener)s
 /// in one call, realizing the reactor pattern. (Event multiplexer)
 pub mod waitset;
+
+pub use capabilities::capabilities;
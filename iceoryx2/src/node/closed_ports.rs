@@ -0,0 +1,72 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::sync::atomic::Ordering;
+use std::sync::Mutex;
+
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+
+use crate::port::port_identifiers::UniquePortId;
+
+/// Keeps track of all ports of a [`Node`](crate::node::Node) that were closed with
+/// [`Node::close_services()`](crate::node::Node::close_services()) while the port handle is
+/// still owned by the user. The dynamic config entries of those ports were already released
+/// and must not be released a second time when the port is dropped.
+#[derive(Debug)]
+pub(crate) struct ClosedPorts {
+    ports: Mutex<Vec<UniquePortId>>,
+    // avoids the lock in the hot path when no port was ever closed
+    has_closed_ports: IoxAtomicBool,
+}
+
+impl ClosedPorts {
+    pub(crate) fn new() -> Self {
+        Self {
+            ports: Mutex::new(vec![]),
+            has_closed_ports: IoxAtomicBool::new(false),
+        }
+    }
+
+    pub(crate) fn is_closed(&self, port_id: UniquePortId) -> bool {
+        self.has_closed_ports.load(Ordering::Relaxed)
+            && self.ports.lock().unwrap().contains(&port_id)
+    }
+
+    /// Calls `close` while no port can be released concurrently. Every port that is passed to
+    /// the provided callback is marked as closed. Returns the number of closed ports.
+    pub(crate) fn close<Close: FnOnce(&mut dyn FnMut(UniquePortId))>(&self, close: Close) -> usize {
+        let mut ports = self.ports.lock().unwrap();
+        let number_of_ports = ports.len();
+        close(&mut |port_id| ports.push(port_id));
+        self.has_closed_ports
+            .store(!ports.is_empty(), Ordering::Relaxed);
+        ports.len() - number_of_ports
+    }
+
+    /// Calls `release` when the port was not closed, otherwise the port is forgotten since its
+    /// resources were already released.
+    pub(crate) fn release_unless_closed<Release: FnOnce()>(
+        &self,
+        port_id: UniquePortId,
+        release: Release,
+    ) {
+        let mut ports = self.ports.lock().unwrap();
+        match ports.iter().position(|p| *p == port_id) {
+            Some(index) => {
+                ports.swap_remove(index);
+                self.has_closed_ports
+                    .store(!ports.is_empty(), Ordering::Relaxed);
+            }
+            None => release(),
+        }
+    }
+}
@@ -0,0 +1,310 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The evidence log records every state transition of the services, ports and connections of a
+//! [`Node`](crate::node::Node) with a monotonic timestamp. It is enabled with
+//! [`NodeBuilder::evidence_log()`](crate::node::NodeBuilder::evidence_log()) and stored in an
+//! append-only file per [`Node`](crate::node::Node) that outlives the
+//! [`Node`](crate::node::Node), so that it can serve as traceability evidence for
+//! functional-safety assessments.
+//!
+//! The file is bounded in size. When a record does not fit anymore it is dropped and counted,
+//! see [`Node::number_of_dropped_evidence_records()`](crate::node::Node::number_of_dropped_evidence_records()).
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::node::evidence_log::export;
+//! use iceoryx2_bb_system_types::path::Path;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new()
+//!     .evidence_log(&Path::new(b"/var/log/iceoryx2")?, 1024 * 1024)
+//!     .create::<ipc::Service>()?;
+//!
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! for record in node.export_evidence_log()? {
+//!     println!("{:?} {} {} {}", record.timestamp, record.entity, record.transition,
+//!         record.description);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::{Arguments, Display};
+use core::sync::atomic::Ordering;
+use core::time::Duration;
+use std::sync::Mutex;
+
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::clock::{ClockType, Time};
+use iceoryx2_bb_posix::creation_mode::CreationMode;
+use iceoryx2_bb_posix::file::{AccessMode, File, FileBuilder};
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorManagement;
+use iceoryx2_bb_posix::permission::Permission;
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
+
+use super::NodeCreationFailure;
+
+/// The entity whose state transition is recorded in an [`EvidenceRecord`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EvidenceEntity {
+    /// A [`Service`](crate::service::Service) of the [`Node`](crate::node::Node).
+    Service,
+    /// A port like a [`Publisher`](crate::port::publisher::Publisher) or a
+    /// [`Subscriber`](crate::port::subscriber::Subscriber).
+    Port,
+    /// A connection between two ports.
+    Connection,
+}
+
+impl EvidenceEntity {
+    fn as_str(&self) -> &'static str {
+        match self {
+            EvidenceEntity::Service => "service",
+            EvidenceEntity::Port => "port",
+            EvidenceEntity::Connection => "connection",
+        }
+    }
+
+    fn from_str(value: &str) -> Option<Self> {
+        [
+            EvidenceEntity::Service,
+            EvidenceEntity::Port,
+            EvidenceEntity::Connection,
+        ]
+        .into_iter()
+        .find(|e| e.as_str() == value)
+    }
+}
+
+impl Display for EvidenceEntity {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The state transition of an [`EvidenceEntity`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum StateTransition {
+    /// The entity was created or opened.
+    Created,
+    /// The connection was established.
+    Connected,
+    /// The connection could not be established and the port operates without it.
+    Degraded,
+    /// The entity was destroyed or closed.
+    Destroyed,
+}
+
+impl StateTransition {
+    fn as_str(&self) -> &'static str {
+        match self {
+            StateTransition::Created => "created",
+            StateTransition::Connected => "connected",
+            StateTransition::Degraded => "degraded",
+            StateTransition::Destroyed => "destroyed",
+        }
+    }
+
+    fn from_str(value: &str) -> Option<Self> {
+        [
+            StateTransition::Created,
+            StateTransition::Connected,
+            StateTransition::Degraded,
+            StateTransition::Destroyed,
+        ]
+        .into_iter()
+        .find(|t| t.as_str() == value)
+    }
+}
+
+impl Display for StateTransition {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A single entry of the evidence log.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EvidenceRecord {
+    /// The monotonic point in time when the transition happened.
+    pub timestamp: Duration,
+    /// The entity that performed the transition.
+    pub entity: EvidenceEntity,
+    /// The transition itself.
+    pub transition: StateTransition,
+    /// Identifies the entity, e.g. the port id and the name of its service.
+    pub description: String,
+}
+
+impl EvidenceRecord {
+    fn parse(line: &str) -> Option<Self> {
+        let mut parts = line.splitn(4, ' ');
+        let (secs, nanos) = parts.next()?.split_once('.')?;
+        let entity = EvidenceEntity::from_str(parts.next()?)?;
+        let transition = StateTransition::from_str(parts.next()?)?;
+        let description = parts.next().unwrap_or("").to_string();
+
+        Some(Self {
+            timestamp: Duration::new(secs.parse().ok()?, nanos.parse().ok()?),
+            entity,
+            transition,
+            description,
+        })
+    }
+}
+
+/// Failures that can occur when the evidence log is exported with [`export()`] or
+/// [`Node::export_evidence_log()`](crate::node::Node::export_evidence_log()).
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EvidenceLogExportError {
+    /// The [`Node`](crate::node::Node) was created without evidence log.
+    NotEnabled,
+    /// The evidence log file could not be opened or read.
+    UnableToReadFile,
+    /// The evidence log file contains a record that is not valid.
+    CorruptedRecord,
+}
+
+impl core::fmt::Display for EvidenceLogExportError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "EvidenceLogExportError::{:?}", self)
+    }
+}
+
+impl core::error::Error for EvidenceLogExportError {}
+
+/// Reads all [`EvidenceRecord`]s from the evidence log file at the provided path. It can be
+/// used to export the evidence log of a [`Node`](crate::node::Node) that no longer exists.
+pub fn export(path: &FilePath) -> Result<Vec<EvidenceRecord>, EvidenceLogExportError> {
+    let origin = "evidence_log::export()";
+    let msg = "Unable to export the evidence log";
+    let file = fail!(from origin, when FileBuilder::new(path).open_existing(AccessMode::Read),
+        with EvidenceLogExportError::UnableToReadFile,
+        "{} since the file {} could not be opened.", msg, path);
+
+    let mut content = String::new();
+    fail!(from origin, when file.read_to_string(&mut content),
+        with EvidenceLogExportError::UnableToReadFile,
+        "{} since the file {} could not be read.", msg, path);
+
+    let mut records = Vec::new();
+    for line in content.lines() {
+        match EvidenceRecord::parse(line) {
+            Some(record) => records.push(record),
+            None => {
+                fail!(from origin, with EvidenceLogExportError::CorruptedRecord,
+                    "{} since the file {} contains the invalid record \"{}\".", msg, path, line);
+            }
+        }
+    }
+
+    Ok(records)
+}
+
+#[derive(Debug)]
+struct LogFile {
+    file: File,
+    size: u64,
+}
+
+#[derive(Debug)]
+pub(crate) struct EvidenceLog {
+    path: FilePath,
+    max_size: u64,
+    log_file: Mutex<LogFile>,
+    number_of_dropped_records: IoxAtomicU64,
+}
+
+impl EvidenceLog {
+    pub(crate) fn new(path: &FilePath, max_size: usize) -> Result<Self, NodeCreationFailure> {
+        let msg = "Unable to create evidence log";
+        let file = fail!(from "EvidenceLog::new()",
+            when FileBuilder::new(path)
+                .creation_mode(CreationMode::OpenOrCreate)
+                .permission(Permission::OWNER_READ | Permission::OWNER_WRITE)
+                .create(),
+            with NodeCreationFailure::InternalError,
+            "{} since the file {} could not be opened or created.", msg, path);
+
+        let size = fail!(from "EvidenceLog::new()", when file.metadata(),
+            with NodeCreationFailure::InternalError,
+            "{} since the size of the file {} could not be acquired.", msg, path)
+        .size();
+
+        Ok(Self {
+            path: path.clone(),
+            max_size: max_size as u64,
+            log_file: Mutex::new(LogFile { file, size }),
+            number_of_dropped_records: IoxAtomicU64::new(0),
+        })
+    }
+
+    pub(crate) fn path(&self) -> &FilePath {
+        &self.path
+    }
+
+    pub(crate) fn number_of_dropped_records(&self) -> u64 {
+        self.number_of_dropped_records.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn record(
+        &self,
+        entity: EvidenceEntity,
+        transition: StateTransition,
+        description: Arguments,
+    ) {
+        let timestamp = match Time::now_with_clock(ClockType::Monotonic) {
+            Ok(now) => now.as_duration(),
+            Err(e) => {
+                warn!(from self, "Unable to acquire the timestamp of the evidence record ({:?}). The record is dropped.", e);
+                self.number_of_dropped_records
+                    .fetch_add(1, Ordering::Relaxed);
+                return;
+            }
+        };
+
+        // the description must not break the line based format
+        let description = description.to_string().replace('\n', " ");
+        let line = format!(
+            "{}.{:09} {} {} {}\n",
+            timestamp.as_secs(),
+            timestamp.subsec_nanos(),
+            entity,
+            transition,
+            description
+        );
+
+        let mut log_file = self.log_file.lock().unwrap();
+        if log_file.size + line.len() as u64 > self.max_size {
+            self.number_of_dropped_records
+                .fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+
+        let position = log_file.size;
+        match log_file.file.write_at(position, line.as_bytes()) {
+            Ok(written) => log_file.size += written,
+            Err(e) => {
+                warn!(from self, "Unable to write the evidence record \"{}\" ({:?}).", line.trim_end(), e);
+                self.number_of_dropped_records
+                    .fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+}
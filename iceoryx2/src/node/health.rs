@@ -0,0 +1,215 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`HealthMonitor`] supervises all [`Node`]s of a [`Config`]. A [`Node`] is considered
+//! alive as long as its process is alive and, when the [`Node`] runs in supervised mode, see
+//! [`NodeBuilder::heartbeat_interval()`](crate::node::NodeBuilder::heartbeat_interval()), its
+//! heartbeat was refreshed within the timeout of the [`HealthMonitor`].
+//!
+//! Every transition that is detected in [`HealthMonitor::check()`] is announced via the event
+//! service [`HEALTH_SERVICE_NAME`] with [`NODE_DIED_EVENT`] or [`NODE_ALIVE_EVENT`]. Other
+//! [`Node`]s can attach a [`Listener`](crate::port::listener::Listener) of this service to a
+//! [`WaitSet`](crate::waitset::WaitSet) instead of detecting dead [`Node`]s only during the
+//! cleanup runs on [`Node`] creation and destruction.
+//!
+//! # Example
+//!
+//! ```
+//! use core::time::Duration;
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::node::health::{HealthMonitor, NodeHealthEvent};
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new()
+//!     .heartbeat_interval(Duration::from_millis(100))
+//!     .create::<ipc::Service>()?;
+//!
+//! let mut health_monitor = HealthMonitor::new(&node, Duration::from_millis(500))?;
+//!
+//! for event in health_monitor.check()? {
+//!     match event {
+//!         NodeHealthEvent::NodeDied(id) => println!("node died: {:?}", id),
+//!         NodeHealthEvent::NodeAlive(id) => println!("node alive: {:?}", id),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use core::time::Duration;
+use std::collections::HashSet;
+
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_log::{debug, fail, warn};
+
+use crate::config::Config;
+use crate::node::{Node, NodeId, NodeListFailure, NodeState, NodeView};
+use crate::port::event_id::EventId;
+use crate::port::notifier::Notifier;
+use crate::service;
+use crate::service::service_name::ServiceName;
+
+/// The name of the event service via which a [`HealthMonitor`] announces the detected
+/// transitions.
+pub const HEALTH_SERVICE_NAME: &str = "iox2/node/health";
+
+/// The [`EventId`] that is emitted when at least one [`Node`] is no longer alive.
+pub const NODE_DIED_EVENT: EventId = EventId::new(0);
+
+/// The [`EventId`] that is emitted when at least one [`Node`] became alive.
+pub const NODE_ALIVE_EVENT: EventId = EventId::new(1);
+
+/// Failures that can occur when a [`HealthMonitor`] is created with [`HealthMonitor::new()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum HealthMonitorCreateError {
+    /// The event service [`HEALTH_SERVICE_NAME`] could not be created or opened.
+    UnableToOpenHealthService,
+    /// The [`Notifier`] of the event service [`HEALTH_SERVICE_NAME`] could not be created.
+    UnableToCreateNotifier,
+}
+
+impl core::fmt::Display for HealthMonitorCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "HealthMonitorCreateError::{:?}", self)
+    }
+}
+
+impl core::error::Error for HealthMonitorCreateError {}
+
+/// A transition that was detected by [`HealthMonitor::check()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum NodeHealthEvent {
+    /// The [`Node`] is no longer alive. Either its process died or terminated or its heartbeat
+    /// was not refreshed within the timeout.
+    NodeDied(NodeId),
+    /// The [`Node`] was detected for the first time or its heartbeat was refreshed again.
+    NodeAlive(NodeId),
+}
+
+/// Supervises the [`Node`]s of a [`Config`], see the [module](crate::node::health)
+/// documentation.
+#[derive(Debug)]
+pub struct HealthMonitor<Service: service::Service> {
+    config: Config,
+    timeout: Duration,
+    alive_nodes: HashSet<NodeId>,
+    notifier: Notifier<Service>,
+}
+
+impl<Service: service::Service> HealthMonitor<Service> {
+    /// Creates a new [`HealthMonitor`] that supervises the [`Node`]s of the [`Config`] of the
+    /// provided [`Node`]. A [`Node`] whose heartbeat is older than the provided timeout is
+    /// considered dead. [`Node`]s that never refreshed their heartbeat are supervised only by
+    /// the state of their process.
+    pub fn new(node: &Node<Service>, timeout: Duration) -> Result<Self, HealthMonitorCreateError> {
+        let msg = "Unable to create health monitor";
+        let service_name: ServiceName = fail!(from "HealthMonitor::new()",
+            when HEALTH_SERVICE_NAME.try_into(),
+            with HealthMonitorCreateError::UnableToOpenHealthService,
+            "{} since \"{}\" is not a valid service name.", msg, HEALTH_SERVICE_NAME);
+
+        let service = fail!(from "HealthMonitor::new()",
+            when node.service_builder(&service_name).event().open_or_create(),
+            with HealthMonitorCreateError::UnableToOpenHealthService,
+            "{} since the health service could not be created or opened.", msg);
+
+        let notifier = fail!(from "HealthMonitor::new()",
+            when service.notifier_builder().create(),
+            with HealthMonitorCreateError::UnableToCreateNotifier,
+            "{} since the notifier of the health service could not be created.", msg);
+
+        Ok(Self {
+            config: node.config().clone(),
+            timeout,
+            alive_nodes: HashSet::new(),
+            notifier,
+        })
+    }
+
+    /// Returns the timeout after which a [`Node`] without a refreshed heartbeat is considered
+    /// dead.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Returns true when the [`Node`] with the provided [`NodeId`] was alive during the last
+    /// [`HealthMonitor::check()`], otherwise false.
+    pub fn is_alive(&self, node_id: &NodeId) -> bool {
+        self.alive_nodes.contains(node_id)
+    }
+
+    /// Determines the health of all [`Node`]s, returns every transition since the last call and
+    /// announces them via the event service [`HEALTH_SERVICE_NAME`].
+    pub fn check(&mut self) -> Result<Vec<NodeHealthEvent>, NodeListFailure> {
+        let mut process_alive_nodes = vec![];
+        fail!(from self, when Node::<Service>::list(&self.config, |node_state| {
+                if let NodeState::Alive(view) = node_state {
+                    process_alive_nodes.push(*view.id());
+                }
+                CallbackProgression::Continue
+            }),
+            "Unable to check the node health since the nodes could not be listed.");
+
+        let mut current_alive_nodes = HashSet::new();
+        for node_id in process_alive_nodes {
+            match Node::<Service>::get_time_since_last_heartbeat(&self.config, &node_id) {
+                Ok(Some(elapsed)) if elapsed > self.timeout => {
+                    debug!(from self,
+                        "The heartbeat of the node {:?} expired {:?} ago.", node_id, elapsed - self.timeout);
+                }
+                Ok(_) => {
+                    current_alive_nodes.insert(node_id);
+                }
+                Err(e) => {
+                    debug!(from self,
+                        "The node {:?} is considered alive since its heartbeat could not be read ({:?}).", node_id, e);
+                    current_alive_nodes.insert(node_id);
+                }
+            }
+        }
+
+        let mut events = Vec::new();
+        for node_id in &current_alive_nodes {
+            if !self.alive_nodes.contains(node_id) {
+                events.push(NodeHealthEvent::NodeAlive(*node_id));
+            }
+        }
+
+        for node_id in &self.alive_nodes {
+            if !current_alive_nodes.contains(node_id) {
+                events.push(NodeHealthEvent::NodeDied(*node_id));
+            }
+        }
+
+        self.alive_nodes = current_alive_nodes;
+        self.announce(&events);
+
+        Ok(events)
+    }
+
+    fn announce(&self, events: &[NodeHealthEvent]) {
+        let has_died = events
+            .iter()
+            .any(|e| matches!(e, NodeHealthEvent::NodeDied(_)));
+        let has_alive = events
+            .iter()
+            .any(|e| matches!(e, NodeHealthEvent::NodeAlive(_)));
+
+        for (is_changed, event_id) in [(has_died, NODE_DIED_EVENT), (has_alive, NODE_ALIVE_EVENT)] {
+            if is_changed {
+                if let Err(e) = self.notifier.notify_with_custom_event_id(event_id) {
+                    warn!(from self, "Unable to announce the detected transitions ({:?}).", e);
+                }
+            }
+        }
+    }
+}
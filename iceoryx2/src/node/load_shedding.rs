@@ -0,0 +1,287 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Every [`Node`](crate::node::Node) monitors the CPU load of its process relative to its CPU
+//! budget, all CPU cores of the system. The CPU time of the process is acquired with
+//! `getrusage`. On Linux, when the cgroup of the process defines a CPU quota, the quota is the
+//! budget and the CPU time is taken from the cgroup instead. As soon as the load exceeds
+//! [`LoadShedding::shedding_threshold`](crate::config::LoadShedding::shedding_threshold) the
+//! low-priority [`Subscriber`](crate::port::subscriber::Subscriber)s, the ones with a
+//! [`ShedPolicy`] other than [`ShedPolicy::Disabled`], discard samples until the load drops
+//! below [`LoadShedding::recovery_threshold`](crate::config::LoadShedding::recovery_threshold).
+//!
+//! The load is measured with [`Node::measure_cpu_load()`](crate::node::Node::measure_cpu_load())
+//! or by a [`WaitSet`](crate::waitset::WaitSet) that monitors the
+//! [`Node`](crate::node::Node), see
+//! [`WaitSet::monitor_load()`](crate::waitset::WaitSet::monitor_load()).
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::node::load_shedding::ShedPolicy;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! // receives only every 10th sample while the node is overloaded
+//! let subscriber = service.subscriber_builder()
+//!     .shed_policy(ShedPolicy::KeepEveryNth(10))
+//!     .create()?;
+//!
+//! let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+//! waitset.monitor_load(&node);
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::sync::atomic::Ordering;
+use core::time::Duration;
+
+use iceoryx2_bb_log::{debug, fail};
+use iceoryx2_bb_posix::clock::{ClockType, Time};
+use iceoryx2_bb_posix::process::Process;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU32};
+
+use std::sync::Mutex;
+
+use crate::config::LoadShedding;
+
+/// Defines how a [`Subscriber`](crate::port::subscriber::Subscriber) behaves while the
+/// [`Node`](crate::node::Node) sheds load. The discarded samples are released immediately,
+/// therefore the [`Publisher`](crate::port::publisher::Publisher)s are never blocked by a
+/// shedding [`Subscriber`](crate::port::subscriber::Subscriber).
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub enum ShedPolicy {
+    /// The [`Subscriber`](crate::port::subscriber::Subscriber) receives all samples, independent
+    /// of the load.
+    #[default]
+    Disabled,
+    /// The [`Subscriber`](crate::port::subscriber::Subscriber) discards all samples while the
+    /// load is shed.
+    Pause,
+    /// The [`Subscriber`](crate::port::subscriber::Subscriber) receives only every n-th sample
+    /// while the load is shed. The smallest possible value is `1`.
+    KeepEveryNth(u32),
+}
+
+/// Failures that can occur when the CPU load is measured with
+/// [`Node::measure_cpu_load()`](crate::node::Node::measure_cpu_load()).
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum CpuLoadMeasurementError {
+    /// The CPU usage of the process cannot be acquired on this platform. The load can still be
+    /// provided with [`Node::report_cpu_load()`](crate::node::Node::report_cpu_load()).
+    UnsupportedPlatform,
+    /// The CPU usage of the process or the current time could not be acquired.
+    InternalError,
+}
+
+impl core::fmt::Display for CpuLoadMeasurementError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "CpuLoadMeasurementError::{:?}", self)
+    }
+}
+
+impl core::error::Error for CpuLoadMeasurementError {}
+
+const UNMEASURED_LOAD: u32 = u32::MAX;
+
+#[derive(Debug, Clone, Copy)]
+struct CpuUsage {
+    cpu_time: Duration,
+    budget_in_millicores: u64,
+}
+
+#[derive(Debug)]
+struct Measurement {
+    wall_time: Duration,
+    cpu_time: Duration,
+}
+
+/// Tracks the CPU load of the process of a [`Node`](crate::node::Node) and decides with
+/// hysteresis if the low-priority subscribers shall shed their samples.
+#[derive(Debug)]
+pub(crate) struct LoadMonitor {
+    shedding_threshold: u32,
+    recovery_threshold: u32,
+    measurement_interval: Duration,
+    cpu_load: IoxAtomicU32,
+    is_shedding: IoxAtomicBool,
+    last_measurement: Mutex<Option<Measurement>>,
+}
+
+impl LoadMonitor {
+    pub(crate) fn new(config: &LoadShedding) -> Self {
+        Self {
+            shedding_threshold: config.shedding_threshold,
+            recovery_threshold: config.recovery_threshold.min(config.shedding_threshold),
+            measurement_interval: config.measurement_interval,
+            cpu_load: IoxAtomicU32::new(UNMEASURED_LOAD),
+            is_shedding: IoxAtomicBool::new(false),
+            last_measurement: Mutex::new(None),
+        }
+    }
+
+    pub(crate) fn cpu_load(&self) -> Option<u32> {
+        match self.cpu_load.load(Ordering::Relaxed) {
+            UNMEASURED_LOAD => None,
+            v => Some(v),
+        }
+    }
+
+    pub(crate) fn is_shedding(&self) -> bool {
+        self.is_shedding.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn report(&self, cpu_load: u32) {
+        let cpu_load = cpu_load.min(UNMEASURED_LOAD - 1);
+        self.cpu_load.store(cpu_load, Ordering::Relaxed);
+
+        if self.shedding_threshold < cpu_load {
+            if !self.is_shedding.swap(true, Ordering::Relaxed) {
+                debug!(from self, "Start shedding load since the CPU load is {}%.", cpu_load);
+            }
+        } else if cpu_load < self.recovery_threshold
+            && self.is_shedding.swap(false, Ordering::Relaxed)
+        {
+            debug!(from self, "Stop shedding load since the CPU load is {}%.", cpu_load);
+        }
+    }
+
+    /// Measures the CPU load when the measurement interval has passed since the last
+    /// measurement, otherwise it does nothing. The first call only acquires the reference
+    /// point for the next measurement.
+    pub(crate) fn measure(&self) -> Result<(), CpuLoadMeasurementError> {
+        let msg = "Unable to measure the CPU load";
+        let now = match Time::now_with_clock(ClockType::Monotonic) {
+            Ok(now) => now.as_duration(),
+            Err(e) => {
+                fail!(from self, with CpuLoadMeasurementError::InternalError,
+                    "{} since the current time could not be acquired ({:?}).", msg, e);
+            }
+        };
+
+        // another thread is measuring right now
+        let mut last_measurement = match self.last_measurement.try_lock() {
+            Ok(guard) => guard,
+            Err(_) => return Ok(()),
+        };
+
+        if let Some(last) = &*last_measurement {
+            if now.saturating_sub(last.wall_time) < self.measurement_interval {
+                return Ok(());
+            }
+        }
+
+        let usage = read_cpu_usage()?;
+
+        if let Some(last) = &*last_measurement {
+            let wall_time = now.saturating_sub(last.wall_time).as_micros();
+            let cpu_time = usage.cpu_time.saturating_sub(last.cpu_time).as_micros();
+            let budget = wall_time * usage.budget_in_millicores as u128;
+            if budget != 0 {
+                let cpu_load = cpu_time * 100 * 1000 / budget;
+                self.report(cpu_load.min(u32::MAX as u128) as u32);
+            }
+        }
+
+        *last_measurement = Some(Measurement {
+            wall_time: now,
+            cpu_time: usage.cpu_time,
+        });
+
+        Ok(())
+    }
+}
+
+/// Decides if a [`Subscriber`](crate::port::subscriber::Subscriber) with the given
+/// [`ShedPolicy`] discards the next sample. The counter holds the number of samples since the
+/// last delivered one.
+pub(crate) fn shall_discard(policy: ShedPolicy, counter: &mut u64) -> bool {
+    match policy {
+        ShedPolicy::Disabled => false,
+        ShedPolicy::Pause => true,
+        ShedPolicy::KeepEveryNth(n) => {
+            *counter += 1;
+            if *counter < n.max(1) as u64 {
+                true
+            } else {
+                *counter = 0;
+                false
+            }
+        }
+    }
+}
+
+fn number_of_cpu_cores() -> u64 {
+    use iceoryx2_bb_posix::system_configuration::SystemInfo;
+
+    (SystemInfo::NumberOfCpuCores.value() as u64).max(1)
+}
+
+// The cgroup v2 usage refines the measurement on Linux. It is only used when the cgroup
+// defines a CPU quota, otherwise it contains the usage of unrelated processes that share the
+// cgroup.
+#[cfg(target_os = "linux")]
+fn read_cgroup_cpu_usage() -> Option<CpuUsage> {
+    use std::fs;
+
+    let cgroups = fs::read_to_string("/proc/self/cgroup").ok()?;
+    let cgroup = cgroups.lines().find_map(|line| line.strip_prefix("0::"))?;
+    let directory = format!("/sys/fs/cgroup{}", cgroup.trim_end_matches('/'));
+
+    let cpu_max = fs::read_to_string(format!("{directory}/cpu.max")).ok()?;
+    let mut cpu_max = cpu_max.split_whitespace();
+    let quota: u64 = cpu_max.next()?.parse().ok()?;
+    let period: u64 = cpu_max.next()?.parse().ok()?;
+    if period == 0 {
+        return None;
+    }
+
+    let cpu_stat = fs::read_to_string(format!("{directory}/cpu.stat")).ok()?;
+    let usage_in_us: u64 = cpu_stat
+        .lines()
+        .find_map(|line| line.strip_prefix("usage_usec "))?
+        .trim()
+        .parse()
+        .ok()?;
+
+    Some(CpuUsage {
+        cpu_time: Duration::from_micros(usage_in_us),
+        budget_in_millicores: (quota * 1000 / period).clamp(1, number_of_cpu_cores() * 1000),
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_cgroup_cpu_usage() -> Option<CpuUsage> {
+    None
+}
+
+fn read_cpu_usage() -> Result<CpuUsage, CpuLoadMeasurementError> {
+    if let Some(usage) = read_cgroup_cpu_usage() {
+        return Ok(usage);
+    }
+
+    let cpu_time = fail!(from "load_shedding::read_cpu_usage()",
+        when Process::cpu_time_of_self(),
+        with CpuLoadMeasurementError::InternalError,
+        "Unable to acquire the CPU usage of the process since the CPU time could not be acquired.");
+
+    Ok(CpuUsage {
+        cpu_time,
+        budget_in_millicores: number_of_cpu_cores() * 1000,
+    })
+}
@@ -139,25 +139,67 @@
 //! # }
 //! ```
 
+/// Records the state transitions of the services, ports and connections of a [`Node`] for
+/// certification evidence.
+pub mod evidence_log;
+/// Detects [`Node`]s that died or whose heartbeat expired and announces it via an event
+/// service.
+pub mod health;
+/// Sheds the samples of low-priority subscribers when the CPU budget of the [`Node`] is
+/// exceeded.
+pub mod load_shedding;
+/// Detects iceoryx2 installations that share the same root path and prefix but use
+/// incompatible configs.
+pub mod namespace_reservation;
 /// The name for a node.
 pub mod node_name;
+/// Lists all resources a [`Node`] and its services require, to generate sandbox profiles
+/// ahead of time.
+pub mod resource_manifest;
+
+pub(crate) mod closed_ports;
+pub(crate) mod port_registry;
+pub(crate) mod resource_budget;
+pub(crate) mod shutdown_intent;
 
 #[doc(hidden)]
 pub mod testing;
 
+use crate::node::closed_ports::ClosedPorts;
+use crate::node::evidence_log::{
+    EvidenceEntity, EvidenceLog, EvidenceLogExportError, EvidenceRecord, StateTransition,
+};
+use crate::node::load_shedding::{CpuLoadMeasurementError, LoadMonitor};
+use crate::node::namespace_reservation::{
+    create_namespace_reservation, remove_namespace_reservation,
+};
 use crate::node::node_name::NodeName;
+use crate::node::port_registry::PortRegistry;
+use crate::node::resource_budget::ResourceBudget;
+use crate::node::resource_manifest::ResourceManifestBuilder;
+use crate::node::shutdown_intent::ShutdownIntent;
+use crate::port::update_connections::ConnectionFailure;
 use crate::service::builder::{Builder, OpenDynamicStorageFailure};
 use crate::service::config_scheme::{
     node_details_path, node_monitoring_config, service_tag_config,
 };
+use crate::service::dynamic_config::PortCleanupAction;
+use crate::service::messaging_pattern::MessagingPattern;
 use crate::service::service_id::ServiceId;
 use crate::service::service_name::ServiceName;
+use crate::service::static_config::messaging_pattern::MessagingPattern as StaticMessagingPattern;
+use crate::service::static_config::StaticConfig;
 use crate::service::{
-    self, remove_service_tag, remove_static_service_config, ServiceRemoveNodeError,
+    self, open_dynamic_config, remove_service_tag, remove_static_service_config,
+    ServiceDetailsError, ServiceRemoveNodeError,
 };
 use crate::signal_handling_mode::SignalHandlingMode;
-use crate::{config::Config, service::config_scheme::node_details_config};
-use core::cell::UnsafeCell;
+use crate::{
+    config::{Config, Defaults, EnvironmentConfig},
+    service::config_scheme::node_details_config,
+};
+use core::cell::{Cell, UnsafeCell};
+use core::fmt::Arguments;
 use core::marker::PhantomData;
 use core::sync::atomic::Ordering;
 use core::time::Duration;
@@ -165,14 +207,17 @@ use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
 use iceoryx2_bb_log::{debug, fail, fatal_panic, trace, warn};
-use iceoryx2_bb_posix::clock::{nanosleep, NanosleepError, Time};
+use iceoryx2_bb_posix::clock::{nanosleep, ClockType, NanosleepError, Time};
 use iceoryx2_bb_posix::process::{Process, ProcessId};
 use iceoryx2_bb_posix::signal::SignalHandler;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_bb_system_types::path::Path;
 use iceoryx2_cal::named_concept::{NamedConceptPathHintRemoveError, NamedConceptRemoveError};
 use iceoryx2_cal::{
-    monitoring::*, named_concept::NamedConceptListError, serialize::*, static_storage::*,
+    dynamic_storage::DynamicStorage, monitoring::*, named_concept::NamedConceptListError,
+    serialize::*, static_storage::*,
 };
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
 
@@ -217,6 +262,13 @@ pub enum NodeCreationFailure {
     InsufficientPermissions,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
     InternalError,
+    /// Another alive [`Node`] uses the same root path and prefix but an incompatible
+    /// [`EnvironmentConfig`], e.g. different suffixes or directories. See
+    /// [`namespace_reservation`].
+    NamespaceConflict,
+    /// The provided [`EnvironmentConfig`] or [`Defaults`] failed the validation, see
+    /// [`EnvironmentConfig::validate()`] and [`Defaults::validate()`].
+    InvalidConfig,
 }
 
 impl core::fmt::Display for NodeCreationFailure {
@@ -285,6 +337,87 @@ impl core::fmt::Display for NodeCleanupFailure {
 
 impl core::error::Error for NodeCleanupFailure {}
 
+/// Failures that can occur when the ports of services are closed with
+/// [`Node::close_services()`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NodeCloseServicesFailure {
+    /// The static or dynamic details of at least one [`Service`](crate::service::Service) of
+    /// the [`Node`] could not be acquired. All other matching services were closed.
+    UnableToAcquireServiceDetails(ServiceDetailsError),
+}
+
+impl core::fmt::Display for NodeCloseServicesFailure {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "NodeCloseServicesFailure::{:?}", self)
+    }
+}
+
+impl core::error::Error for NodeCloseServicesFailure {}
+
+/// Selects the [`Service`](crate::service::Service)s whose ports are closed with
+/// [`Node::close_services()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ServiceFilter {
+    /// Selects the [`Service`](crate::service::Service)s with the provided [`ServiceName`]
+    /// regardless of their [`MessagingPattern`].
+    Name(ServiceName),
+    /// Selects all [`Service`](crate::service::Service)s of the provided [`MessagingPattern`].
+    MessagingPattern(MessagingPattern),
+}
+
+impl ServiceFilter {
+    fn matches(&self, static_config: &StaticConfig) -> bool {
+        match self {
+            ServiceFilter::Name(name) => static_config.name() == name,
+            ServiceFilter::MessagingPattern(pattern) => {
+                let pattern_of_service = match static_config.messaging_pattern() {
+                    StaticMessagingPattern::PublishSubscribe(_) => {
+                        MessagingPattern::PublishSubscribe
+                    }
+                    StaticMessagingPattern::Event(_) => MessagingPattern::Event,
+                    StaticMessagingPattern::RequestResponse(_) => MessagingPattern::RequestResponse,
+                };
+                *pattern == pattern_of_service
+            }
+        }
+    }
+}
+
+/// Failures that can occur when the resources of all ports of a [`Node`] are acquired with
+/// [`Node::prefault_and_lock()`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NodePrefaultFailure {
+    /// At least one connection of a port could not be established.
+    ConnectionFailure(ConnectionFailure),
+    /// At least one port of the [`Node`] is owned by a different thread than the caller. The
+    /// resources of those ports cannot be acquired from the calling thread.
+    PortOwnedByAnotherThread,
+}
+
+impl core::fmt::Display for NodePrefaultFailure {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "NodePrefaultFailure::{:?}", self)
+    }
+}
+
+impl core::error::Error for NodePrefaultFailure {}
+
+/// The failures that can occur when the heartbeat of a [`Node`] is refreshed with
+/// [`Node::refresh_heartbeat()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum NodeHeartbeatFailure {
+    /// The heartbeat could not be written into the monitoring resources of the [`Node`].
+    InternalError,
+}
+
+impl core::fmt::Display for NodeHeartbeatFailure {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "NodeHeartbeatFailure::{:?}", self)
+    }
+}
+
+impl core::error::Error for NodeHeartbeatFailure {}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 enum NodeReadStorageFailure {
     ReadError,
@@ -715,6 +848,7 @@ fn remove_node<Service: service::Service>(
     let detail_storages = acquire_all_node_detail_storages::<Service>(&origin, &details_config)?;
     remove_detail_storages::<Service>(&origin, detail_storages, &details_config)?;
     remove_node_details_directory::<Service>(config, &id)?;
+    remove_namespace_reservation::<Service>(config, &id)?;
 
     Ok(true)
 }
@@ -777,6 +911,10 @@ impl RegisteredServices {
                 "This should never happen! The service with the {:?} was not registered.", service_id);
         }
     }
+
+    pub(crate) fn service_ids(&self) -> Vec<ServiceId> {
+        self.data.lock().unwrap().keys().cloned().collect()
+    }
 }
 
 #[derive(Debug)]
@@ -786,7 +924,14 @@ pub(crate) struct SharedNode<Service: service::Service> {
     monitoring_token: UnsafeCell<Option<<Service::Monitoring as Monitoring>::Token>>,
     registered_services: RegisteredServices,
     signal_handling_mode: SignalHandlingMode,
+    resource_budget: Arc<ResourceBudget>,
+    load_monitor: Arc<LoadMonitor>,
+    port_registry: PortRegistry<Service>,
+    shutdown_intent: ShutdownIntent<Service>,
+    closed_ports: ClosedPorts,
+    evidence_log: Option<EvidenceLog>,
     _details_storage: Service::StaticStorage,
+    _namespace_reservation: Service::StaticStorage,
 }
 
 unsafe impl<Service: service::Service> Send for SharedNode<Service> {}
@@ -804,6 +949,39 @@ impl<Service: service::Service> SharedNode<Service> {
     pub(crate) fn registered_services(&self) -> &RegisteredServices {
         &self.registered_services
     }
+
+    pub(crate) fn resource_budget(&self) -> &Arc<ResourceBudget> {
+        &self.resource_budget
+    }
+
+    pub(crate) fn load_monitor(&self) -> &Arc<LoadMonitor> {
+        &self.load_monitor
+    }
+
+    pub(crate) fn port_registry(&self) -> &PortRegistry<Service> {
+        &self.port_registry
+    }
+
+    pub(crate) fn shutdown_intent(&self) -> &ShutdownIntent<Service> {
+        &self.shutdown_intent
+    }
+
+    pub(crate) fn closed_ports(&self) -> &ClosedPorts {
+        &self.closed_ports
+    }
+
+    /// Records the state transition in the evidence log when the [`Node`] was created with
+    /// [`NodeBuilder::evidence_log()`], otherwise it does nothing.
+    pub(crate) fn record_evidence(
+        &self,
+        entity: EvidenceEntity,
+        transition: StateTransition,
+        description: Arguments,
+    ) {
+        if let Some(evidence_log) = &self.evidence_log {
+            evidence_log.record(entity, transition, description);
+        }
+    }
 }
 
 impl<Service: service::Service> Drop for SharedNode<Service> {
@@ -829,6 +1007,8 @@ impl<Service: service::Service> Drop for SharedNode<Service> {
 #[derive(Debug)]
 pub struct Node<Service: service::Service> {
     shared: Arc<SharedNode<Service>>,
+    heartbeat_interval: Option<Duration>,
+    next_heartbeat: Cell<Duration>,
 }
 
 unsafe impl<Service: service::Service> Send for Node<Service> {}
@@ -849,6 +1029,342 @@ impl<Service: service::Service> Node<Service> {
         &self.shared.id
     }
 
+    /// Returns the memory budget in bytes that all ports of the [`Node`] can use together. If
+    /// no budget was defined with [`NodeBuilder::memory_budget()`] it returns [`None`].
+    pub fn memory_budget(&self) -> Option<usize> {
+        self.shared.resource_budget.memory_budget()
+    }
+
+    /// Returns the maximum number of ports the [`Node`] can create. If no limit was
+    /// defined with [`NodeBuilder::max_ports()`] it returns [`None`].
+    pub fn max_ports(&self) -> Option<usize> {
+        self.shared.resource_budget.max_ports()
+    }
+
+    /// Returns the memory in bytes that is currently used by all ports of the [`Node`].
+    pub fn used_memory(&self) -> usize {
+        self.shared.resource_budget.used_memory()
+    }
+
+    /// Returns the number of ports that currently exist and were created by the [`Node`].
+    pub fn number_of_ports(&self) -> usize {
+        self.shared.resource_budget.number_of_ports()
+    }
+
+    /// Measures the CPU load of the process relative to the CPU budget of the [`Node`] and
+    /// starts or stops the shedding of the low-priority
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s, see
+    /// [`load_shedding`](crate::node::load_shedding). The measurement is skipped when the
+    /// [`LoadShedding::measurement_interval`](crate::config::LoadShedding::measurement_interval)
+    /// has not yet passed since the last measurement.
+    pub fn measure_cpu_load(&self) -> Result<(), CpuLoadMeasurementError> {
+        self.shared.load_monitor.measure()
+    }
+
+    /// Sets the CPU load in percent of the CPU budget of the [`Node`] and starts or stops the
+    /// shedding of the low-priority [`Subscriber`](crate::port::subscriber::Subscriber)s
+    /// accordingly. Useful when the load is acquired from a custom source.
+    pub fn report_cpu_load(&self, cpu_load: u32) {
+        self.shared.load_monitor.report(cpu_load)
+    }
+
+    /// Returns the last measured or reported CPU load in percent of the CPU budget of the
+    /// [`Node`]. If the load was not yet acquired it returns [`None`].
+    pub fn cpu_load(&self) -> Option<u32> {
+        self.shared.load_monitor.cpu_load()
+    }
+
+    /// Returns true when the low-priority [`Subscriber`](crate::port::subscriber::Subscriber)s
+    /// of the [`Node`] currently shed their samples.
+    pub fn is_shedding_load(&self) -> bool {
+        self.shared.load_monitor.is_shedding()
+    }
+
+    pub(crate) fn load_monitor(&self) -> &Arc<LoadMonitor> {
+        &self.shared.load_monitor
+    }
+
+    /// Acquires all resources of the ports that were created by the [`Node`] upfront and locks
+    /// the [`Node`] afterwards. Ports usually establish connections to new participants
+    /// lazily, for instance when a [`Publisher`](crate::port::publisher::Publisher) sends a
+    /// sample, which requires the opening of files and shared memory objects on the hot path.
+    /// After this call no port of the [`Node`] establishes new connections anymore, this
+    /// includes ports that are created afterwards. Connections to participants that appear
+    /// later are therefore never established. This allows the process to enter a strict
+    /// seccomp or landlock sandbox after the initialization.
+    ///
+    /// The [`Node`] is locked even when the call fails.
+    ///
+    /// The call must be performed in the thread that owns the ports, all other ports are
+    /// skipped and [`NodePrefaultFailure::PortOwnedByAnotherThread`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let publisher = service.publisher_builder().create()?;
+    /// let _subscriber = service.subscriber_builder().create()?;
+    ///
+    /// node.prefault_and_lock()?;
+    /// // enter the sandbox here
+    ///
+    /// publisher.send_copy(1234)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn prefault_and_lock(&self) -> Result<(), NodePrefaultFailure> {
+        self.shared.port_registry.prefault_and_lock()
+    }
+
+    /// Returns true if the [`Node`] was locked with [`Node::prefault_and_lock()`], otherwise
+    /// false.
+    pub fn is_locked(&self) -> bool {
+        self.shared.port_registry.is_locked()
+    }
+
+    /// Informs all ports that were created by the [`Node`] that the process intends to shut
+    /// down within the provided `grace` period. Every port reports it with
+    /// `is_shutdown_requested()` and every [`Listener`](crate::port::listener::Listener) of the
+    /// [`Node`] is woken up so that the [`WaitSet`](crate::waitset::WaitSet)s it is attached to
+    /// return and the threads can wind down in a coordinated fashion. The wake-up does not
+    /// deliver an [`EventId`](crate::port::event_id::EventId) to the user.
+    ///
+    /// Calling it multiple times wakes up the [`Listener`](crate::port::listener::Listener)s
+    /// again but does not restart the grace period.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use core::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let publisher = service.publisher_builder().create()?;
+    ///
+    /// node.broadcast_shutdown_intent(Duration::from_millis(100));
+    ///
+    /// if publisher.is_shutdown_requested() {
+    ///     publisher.send_copy(0)?; // send final sample
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn broadcast_shutdown_intent(&self, grace: Duration) {
+        self.shared
+            .shutdown_intent
+            .broadcast(grace, self.shared.config());
+    }
+
+    /// Returns true when a shutdown intent was broadcast with
+    /// [`Node::broadcast_shutdown_intent()`], otherwise false.
+    pub fn is_shutdown_requested(&self) -> bool {
+        self.shared.shutdown_intent.is_requested()
+    }
+
+    /// Returns the remaining grace period of the shutdown intent that was broadcast with
+    /// [`Node::broadcast_shutdown_intent()`]. If no shutdown intent was broadcast it returns
+    /// [`None`].
+    pub fn remaining_shutdown_grace_period(&self) -> Option<Duration> {
+        self.shared.shutdown_intent.remaining_grace_period()
+    }
+
+    /// Closes all ports of the [`Node`] that belong to a [`Service`](crate::service::Service)
+    /// selected by the [`ServiceFilter`] and returns the number of closed ports. The
+    /// [`Node`], the service handles and all ports of other services stay alive.
+    ///
+    /// The closed ports are removed from the services so that all remote ports release their
+    /// connections to them. The port handles stay valid but are disconnected: a closed
+    /// [`Publisher`](crate::port::publisher::Publisher) delivers no more samples, a closed
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) receives no more samples and a closed
+    /// [`Notifier`](crate::port::notifier::Notifier) does not notify anyone. They shall be
+    /// dropped before the subsystem is restarted with new ports. Samples that are still held
+    /// by a closed [`Subscriber`](crate::port::subscriber::Subscriber) shall be released before
+    /// since the remote [`Publisher`](crate::port::publisher::Publisher)s reclaim them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::node::ServiceFilter;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let pubsub = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    /// let event = node.service_builder(&"My/Funk/Event".try_into()?)
+    ///     .event()
+    ///     .open_or_create()?;
+    ///
+    /// let publisher = pubsub.publisher_builder().create()?;
+    /// let _notifier = event.notifier_builder().create()?;
+    ///
+    /// // restart the publish-subscribe subsystem, the notifier stays connected
+    /// node.close_services(&ServiceFilter::MessagingPattern(MessagingPattern::PublishSubscribe))?;
+    /// drop(publisher);
+    /// let _publisher = pubsub.publisher_builder().create()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn close_services(
+        &self,
+        filter: &ServiceFilter,
+    ) -> Result<usize, NodeCloseServicesFailure> {
+        let msg = "Unable to close the ports of all selected services";
+        let mut number_of_closed_ports = 0;
+        let mut ret_val = Ok(());
+
+        for service_id in self.shared.registered_services.service_ids() {
+            let static_config = match service::details::<Service>(
+                self.config(),
+                &service_id.0.into(),
+            ) {
+                Ok(Some(details)) => details.static_details,
+                Ok(None) => continue,
+                Err(e) => {
+                    warn!(from self,
+                        "{} since the static details of the service {:?} could not be acquired ({:?}).",
+                        msg, service_id, e);
+                    ret_val = Err(NodeCloseServicesFailure::UnableToAcquireServiceDetails(e));
+                    continue;
+                }
+            };
+
+            if !filter.matches(&static_config) {
+                continue;
+            }
+
+            let dynamic_config = match open_dynamic_config::<Service>(self.config(), &service_id) {
+                Ok(Some(dynamic_config)) => dynamic_config,
+                Ok(None) => continue,
+                Err(e) => {
+                    warn!(from self,
+                        "{} since the dynamic details of the service {} could not be acquired ({:?}).",
+                        msg, static_config.name(), e);
+                    ret_val = Err(NodeCloseServicesFailure::UnableToAcquireServiceDetails(e));
+                    continue;
+                }
+            };
+
+            number_of_closed_ports += self.shared.closed_ports.close(|mark_as_closed| unsafe {
+                dynamic_config
+                    .get()
+                    .remove_ports_of_node(self.id(), |port_id| {
+                        self.shared.record_evidence(
+                            EvidenceEntity::Port,
+                            StateTransition::Destroyed,
+                            format_args!("{:?} of service {}", port_id, static_config.name()),
+                        );
+                        mark_as_closed(port_id);
+                        PortCleanupAction::RemovePort
+                    })
+            });
+        }
+
+        ret_val?;
+        Ok(number_of_closed_ports)
+    }
+
+    /// Returns the interval in which the [`Node`] refreshes its heartbeat in [`Node::wait()`].
+    /// If the [`Node`] was not created in supervised mode with
+    /// [`NodeBuilder::heartbeat_interval()`] it returns [`None`].
+    pub fn heartbeat_interval(&self) -> Option<Duration> {
+        self.heartbeat_interval
+    }
+
+    /// Refreshes the heartbeat of the [`Node`] in its monitoring resources. A
+    /// [`HealthMonitor`](crate::node::health::HealthMonitor) considers a [`Node`] whose
+    /// heartbeat was not refreshed within its timeout as dead. A [`Node`] that was created
+    /// with [`NodeBuilder::heartbeat_interval()`] refreshes its heartbeat automatically in
+    /// [`Node::wait()`].
+    pub fn refresh_heartbeat(&self) -> Result<(), NodeHeartbeatFailure> {
+        // The token is only accessed by the node itself and the node is not Sync, therefore
+        // there is no concurrent access.
+        let token = match unsafe { &mut *self.shared.monitoring_token.get() } {
+            Some(token) => token,
+            None => {
+                fail!(from self, with NodeHeartbeatFailure::InternalError,
+                    "Unable to refresh heartbeat since the node has no monitoring token.");
+            }
+        };
+
+        fail!(from self, when token.refresh_heartbeat(),
+            with NodeHeartbeatFailure::InternalError,
+            "Unable to refresh heartbeat since it could not be written.");
+
+        Ok(())
+    }
+
+    fn refresh_heartbeat_when_due(&self) {
+        let interval = match self.heartbeat_interval {
+            Some(interval) => interval,
+            None => return,
+        };
+
+        let now = match Time::now_with_clock(ClockType::Monotonic) {
+            Ok(now) => now.as_duration(),
+            Err(e) => {
+                warn!(from self,
+                    "Unable to refresh heartbeat since the current time could not be acquired ({:?}).", e);
+                return;
+            }
+        };
+
+        if now < self.next_heartbeat.get() {
+            return;
+        }
+
+        if self.refresh_heartbeat().is_ok() {
+            self.next_heartbeat.set(now + interval);
+        }
+    }
+
+    /// Returns a [`ResourceManifestBuilder`] to list every directory, file, shared memory
+    /// object and socket that the [`Node`] and the added services will require, before they
+    /// are created. See the [`resource_manifest`](crate::node::resource_manifest) module.
+    pub fn resource_manifest(&self) -> ResourceManifestBuilder<Service> {
+        ResourceManifestBuilder::new(self)
+    }
+
+    /// Returns the path of the evidence log file when the [`Node`] was created with
+    /// [`NodeBuilder::evidence_log()`], otherwise [`None`].
+    pub fn evidence_log_path(&self) -> Option<&FilePath> {
+        self.shared.evidence_log.as_ref().map(|log| log.path())
+    }
+
+    /// Reads all [`EvidenceRecord`]s that were recorded so far, see
+    /// [`NodeBuilder::evidence_log()`].
+    pub fn export_evidence_log(&self) -> Result<Vec<EvidenceRecord>, EvidenceLogExportError> {
+        match &self.shared.evidence_log {
+            Some(evidence_log) => evidence_log::export(evidence_log.path()),
+            None => {
+                fail!(from self, with EvidenceLogExportError::NotEnabled,
+                    "Unable to export the evidence log since the node was created without evidence log.");
+            }
+        }
+    }
+
+    /// Returns the number of [`EvidenceRecord`]s that were dropped since the evidence log
+    /// reached its maximum size or could not be written.
+    pub fn number_of_dropped_evidence_records(&self) -> u64 {
+        self.shared
+            .evidence_log
+            .as_ref()
+            .map_or(0, |log| log.number_of_dropped_records())
+    }
+
     /// Instantiates a [`ServiceBuilder`](Builder) for a service with the provided name.
     pub fn service_builder(&self, name: &ServiceName) -> Builder<Service> {
         Builder::new(name, self.shared.clone())
@@ -920,9 +1436,14 @@ impl<Service: service::Service> Node<Service> {
     /// Waits until the cycle time has passed. It returns [`NodeWaitFailure::TerminationRequest`]
     /// when a `SIGTERM` signal was received or [`NodeWaitFailure::Interrupt`] when a `SIGINT`
     /// signal was received.
+    ///
+    /// When the [`Node`] was created with [`NodeBuilder::heartbeat_interval()`] the heartbeat
+    /// is refreshed whenever the interval has passed. The cycle time should therefore be
+    /// smaller than the heartbeat interval.
     pub fn wait(&self, cycle_time: Duration) -> Result<(), NodeWaitFailure> {
         let msg = "Unable to wait on node";
         self.handle_termination_request(msg)?;
+        self.refresh_heartbeat_when_due();
 
         match nanosleep(cycle_time) {
             Ok(()) => {
@@ -1071,6 +1592,36 @@ impl<Service: service::Service> Node<Service> {
         }
     }
 
+    pub(crate) fn get_time_since_last_heartbeat(
+        config: &Config,
+        node_id: &NodeId,
+    ) -> Result<Option<Duration>, NodeListFailure> {
+        let msg = "Unable to acquire time since last heartbeat";
+        let origin = format!(
+            "Node::get_time_since_last_heartbeat({:?}, {:?})",
+            config, node_id
+        );
+
+        let monitor = fail!(from origin,
+            when <Service::Monitoring as Monitoring>::Builder::new(&node_id.as_file_name())
+                .config(&node_monitoring_config::<Service>(config))
+                .monitor(),
+            with NodeListFailure::InternalError,
+            "{} since the node monitor could not be acquired.", msg);
+
+        match monitor.time_since_last_heartbeat() {
+            Ok(v) => Ok(v),
+            Err(MonitoringStateError::Interrupt) => {
+                fail!(from origin, with NodeListFailure::Interrupt,
+                    "{} due to an interrupt signal while reading the heartbeat.", msg);
+            }
+            Err(MonitoringStateError::InternalError) => {
+                fail!(from origin, with NodeListFailure::InternalError,
+                    "{} due to an internal error while reading the heartbeat.", msg);
+            }
+        }
+    }
+
     fn open_node_storage(
         config: &Config,
         node_id: &NodeId,
@@ -1195,6 +1746,12 @@ pub struct NodeBuilder {
     name: Option<NodeName>,
     signal_handling_mode: SignalHandlingMode,
     config: Option<Config>,
+    environment_config: Option<EnvironmentConfig>,
+    defaults: Option<Defaults>,
+    memory_budget: Option<usize>,
+    max_ports: Option<usize>,
+    heartbeat_interval: Option<Duration>,
+    evidence_log: Option<(Path, usize)>,
 }
 
 impl NodeBuilder {
@@ -1224,6 +1781,58 @@ impl NodeBuilder {
         self
     }
 
+    /// Replaces the structural settings, the paths, prefixes and suffixes, of the config of the
+    /// [`Node`] with the provided [`EnvironmentConfig`]. The remaining settings are taken from
+    /// [`NodeBuilder::config()`] or the global config. When the [`EnvironmentConfig`] is not
+    /// valid, see [`EnvironmentConfig::validate()`], the [`Node`] creation fails.
+    pub fn environment_config(mut self, value: &EnvironmentConfig) -> Self {
+        self.environment_config = Some(value.clone());
+        self
+    }
+
+    /// Replaces the [`Defaults`] of the config of the [`Node`]. The remaining settings are
+    /// taken from [`NodeBuilder::config()`] or the global config. When the [`Defaults`] are
+    /// not valid, see [`Defaults::validate()`], the [`Node`] creation fails.
+    pub fn defaults(mut self, value: &Defaults) -> Self {
+        self.defaults = Some(value.clone());
+        self
+    }
+
+    /// Defines the maximum amount of memory in bytes that all ports of the [`Node`] can
+    /// acquire together, like the data segments of the
+    /// [`Publisher`](crate::port::publisher::Publisher)s or the buffers of the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s. When the creation of a port would
+    /// exceed the budget it fails.
+    pub fn memory_budget(mut self, bytes: usize) -> Self {
+        self.memory_budget = Some(bytes);
+        self
+    }
+
+    /// Defines the maximum number of ports the [`Node`] can create across all of its services.
+    /// When the creation of a port would exceed the limit it fails.
+    pub fn max_ports(mut self, value: usize) -> Self {
+        self.max_ports = Some(value);
+        self
+    }
+
+    /// Creates the [`Node`] in supervised mode. The [`Node`] refreshes its heartbeat on
+    /// creation and in [`Node::wait()`] whenever the provided interval has passed, so that a
+    /// [`HealthMonitor`](crate::node::health::HealthMonitor) can detect a [`Node`] that is
+    /// alive but no longer makes progress.
+    pub fn heartbeat_interval(mut self, value: Duration) -> Self {
+        self.heartbeat_interval = Some(value);
+        self
+    }
+
+    /// Enables the evidence log, see [`evidence_log`]. Every state transition of the services,
+    /// ports and connections of the [`Node`] is appended to the file `<node_id>.evidence` in
+    /// the provided directory as long as the file does not exceed `max_size` bytes. The file
+    /// is not removed when the [`Node`] goes out of scope.
+    pub fn evidence_log(mut self, directory: &Path, max_size: usize) -> Self {
+        self.evidence_log = Some((directory.clone(), max_size));
+        self
+    }
+
     /// Creates a new [`Node`] for a specific [`service::Service`]. All entities owned by the
     /// [`Node`] will have the same [`service::Service`].
     pub fn create<Service: service::Service>(self) -> Result<Node<Service>, NodeCreationFailure> {
@@ -1239,24 +1848,62 @@ impl NodeBuilder {
         self,
         node_id: UniqueSystemId,
     ) -> Result<Node<Service>, NodeCreationFailure> {
-        let config = if let Some(ref config) = self.config {
+        let msg = "Unable to create node";
+        let mut config = if let Some(ref config) = self.config {
             config.clone()
         } else {
             Config::global_config().clone()
         };
 
+        if let Some(ref environment_config) = self.environment_config {
+            fail!(from self, when environment_config.validate(),
+                with NodeCreationFailure::InvalidConfig,
+                "{msg} since the provided environment config is invalid.");
+            config.set_environment(environment_config);
+        }
+
+        if let Some(ref defaults) = self.defaults {
+            fail!(from self, when defaults.validate(),
+                with NodeCreationFailure::InvalidConfig,
+                "{msg} since the provided default settings are invalid.");
+            config.defaults = defaults.clone();
+        }
+
         if config.global.node.cleanup_dead_nodes_on_creation {
             Node::<Service>::cleanup_dead_nodes(&config);
         }
 
-        let msg = "Unable to create node";
         let monitor_name = fatal_panic!(from self, when FileName::new(node_id.value().to_string().as_bytes()),
                                 "This should never happen! {msg} since the UniqueSystemId is not a valid file name.");
         let (details_storage, details) =
             self.create_node_details_storage::<Service>(&config, &NodeId(node_id))?;
         let monitoring_token = self.create_token::<Service>(&config, &monitor_name)?;
+        let namespace_reservation = match create_namespace_reservation::<Service>(
+            &config,
+            &NodeId(node_id),
+            details.name(),
+        ) {
+            Ok(reservation) => reservation,
+            Err(e) => {
+                warn!(from self, when remove_node::<Service>(NodeId(node_id), &config),
+                    "Unable to remove the node resources after the namespace reservation failed.");
+                return Err(e);
+            }
+        };
+        let evidence_log = match &self.evidence_log {
+            Some((directory, max_size)) => {
+                let mut file_name = monitor_name.clone();
+                fatal_panic!(from self, when file_name.push_bytes(b".evidence"),
+                    "This should never happen! {msg} since the evidence log file name is not valid.");
+                let path = fail!(from self, when FilePath::from_path_and_file(directory, &file_name),
+                    with NodeCreationFailure::InternalError,
+                    "{msg} since the evidence log path exceeds the maximum path length.");
+                Some(EvidenceLog::new(&path, *max_size)?)
+            }
+            None => None,
+        };
 
-        Ok(Node {
+        let node = Node {
             shared: Arc::new(SharedNode {
                 id: NodeId(node_id),
                 monitoring_token: UnsafeCell::new(Some(monitoring_token)),
@@ -1264,10 +1911,22 @@ impl NodeBuilder {
                     data: Mutex::new(HashMap::new()),
                 },
                 _details_storage: details_storage,
+                _namespace_reservation: namespace_reservation,
                 signal_handling_mode: self.signal_handling_mode,
+                resource_budget: Arc::new(ResourceBudget::new(self.memory_budget, self.max_ports)),
+                load_monitor: Arc::new(LoadMonitor::new(&config.global.node.load_shedding)),
+                port_registry: PortRegistry::new(),
+                shutdown_intent: ShutdownIntent::new(),
+                closed_ports: ClosedPorts::new(),
+                evidence_log,
                 details,
             }),
-        })
+            heartbeat_interval: self.heartbeat_interval,
+            next_heartbeat: Cell::new(Duration::ZERO),
+        };
+        node.refresh_heartbeat_when_due();
+
+        Ok(node)
     }
 
     fn create_token<Service: service::Service>(
@@ -0,0 +1,260 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Every [`Node`] reserves its namespace, the combination of root path and prefix, together
+//! with its [`EnvironmentConfig`]. When two iceoryx2 installations share the same namespace
+//! but use different suffixes or directories, they interpret each others resources
+//! differently, which leads to hard to diagnose failures like corrupted services later on.
+//!
+//! Therefore, the creation of a [`Node`] fails with
+//! [`NodeCreationFailure::NamespaceConflict`] when an alive [`Node`] already reserved the
+//! same namespace with a different [`EnvironmentConfig`]. The offending [`Node`]s are listed
+//! in the error log and can be acquired with [`conflicting_nodes()`]. Reservations of dead
+//! [`Node`]s are removed during the check.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::node::namespace_reservation::conflicting_nodes;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! for node in conflicting_nodes::<ipc::Service>(Config::global_config())? {
+//!     println!("node {:?} with the name \"{}\" of process {} uses an incompatible config",
+//!         node.id(), node.name(), node.id().pid());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use core::time::Duration;
+
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_cal::monitoring::State;
+use iceoryx2_cal::named_concept::{
+    NamedConceptListError, NamedConceptMgmt, NamedConceptRemoveError,
+};
+use iceoryx2_cal::serialize::Serialize;
+use iceoryx2_cal::static_storage::*;
+
+use crate::config::{Config, Defaults, EnvironmentConfig};
+use crate::node::node_name::NodeName;
+use crate::node::{Node, NodeCleanupFailure, NodeCreationFailure, NodeId, NodeListFailure};
+use crate::service;
+use crate::service::config_scheme::namespace_reservation_config;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct Reservation {
+    name: NodeName,
+    environment: EnvironmentConfig,
+}
+
+/// An alive [`Node`] that reserved the same namespace with an incompatible
+/// [`EnvironmentConfig`].
+#[derive(Debug, Clone)]
+pub struct ConflictingNode {
+    id: NodeId,
+    name: NodeName,
+    environment: EnvironmentConfig,
+}
+
+impl ConflictingNode {
+    /// Returns the [`NodeId`] of the conflicting [`Node`]. It contains the
+    /// [`ProcessId`](iceoryx2_bb_posix::process::ProcessId) of the owning process.
+    pub fn id(&self) -> &NodeId {
+        &self.id
+    }
+
+    /// Returns the [`NodeName`] of the conflicting [`Node`].
+    pub fn name(&self) -> &NodeName {
+        &self.name
+    }
+
+    /// Returns the [`EnvironmentConfig`] the conflicting [`Node`] was created with.
+    pub fn environment(&self) -> &EnvironmentConfig {
+        &self.environment
+    }
+}
+
+/// Returns all alive [`Node`]s that reserved the namespace of the provided [`Config`] with an
+/// incompatible [`EnvironmentConfig`].
+pub fn conflicting_nodes<Service: service::Service>(
+    config: &Config,
+) -> Result<Vec<ConflictingNode>, NodeListFailure> {
+    let origin = "namespace_reservation::conflicting_nodes()";
+    let msg = "Unable to acquire all nodes with a conflicting namespace reservation";
+    let storage_config = namespace_reservation_config::<Service>(config);
+
+    let reservations = match <Service::StaticStorage as NamedConceptMgmt>::list_cfg(&storage_config)
+    {
+        Ok(reservations) => reservations,
+        Err(NamedConceptListError::InsufficientPermissions) => {
+            fail!(from origin, with NodeListFailure::InsufficientPermissions,
+                    "{} due to insufficient permissions while listing all reservations.", msg);
+        }
+        Err(NamedConceptListError::InternalError) => {
+            fail!(from origin, with NodeListFailure::InternalError,
+                    "{} due to an internal failure while listing all reservations.", msg);
+        }
+    };
+
+    let environment = config.environment();
+    let mut conflicts = vec![];
+    for reservation_name in reservations {
+        let node_id = match core::str::from_utf8(reservation_name.as_bytes())
+            .ok()
+            .and_then(|v| v.parse::<u128>().ok())
+        {
+            Some(v) => NodeId(v.into()),
+            None => continue,
+        };
+
+        let reservation = match read_reservation::<Service>(&storage_config, &reservation_name) {
+            Some(reservation) => reservation,
+            None => continue,
+        };
+
+        let reserved_config = Config::from_layers(&reservation.environment, &Defaults::default());
+        if reserved_config.global.prefix != config.global.prefix {
+            continue;
+        }
+
+        match Node::<Service>::get_node_state(&reserved_config, &node_id)? {
+            State::Alive => {
+                if reservation.environment != environment {
+                    conflicts.push(ConflictingNode {
+                        id: node_id,
+                        name: reservation.name,
+                        environment: reservation.environment,
+                    });
+                }
+            }
+            State::Dead | State::DoesNotExist => {
+                warn!(from origin, when remove_namespace_reservation::<Service>(config, &node_id),
+                    "Unable to remove the stale namespace reservation of the dead node {:?}.", node_id);
+            }
+        }
+    }
+
+    Ok(conflicts)
+}
+
+fn read_reservation<Service: service::Service>(
+    storage_config: &<Service::StaticStorage as NamedConceptMgmt>::Configuration,
+    reservation_name: &FileName,
+) -> Option<Reservation> {
+    let storage = <Service::StaticStorage as StaticStorage>::Builder::new(reservation_name)
+        .config(storage_config)
+        .has_ownership(false)
+        .open(Duration::ZERO)
+        .ok()?;
+
+    let mut content = vec![0u8; storage.len() as usize];
+    storage.read(content.as_mut_slice()).ok()?;
+    Service::ConfigSerializer::deserialize::<Reservation>(&content).ok()
+}
+
+pub(crate) fn create_namespace_reservation<Service: service::Service>(
+    config: &Config,
+    node_id: &NodeId,
+    node_name: &NodeName,
+) -> Result<Service::StaticStorage, NodeCreationFailure> {
+    let origin = "namespace_reservation::create_namespace_reservation()";
+    let msg = "Unable to reserve the namespace of the node";
+
+    let reservation = Reservation {
+        name: node_name.clone(),
+        environment: config.environment(),
+    };
+    let serialized_reservation = fail!(from origin,
+        when Service::ConfigSerializer::serialize(&reservation),
+        with NodeCreationFailure::InternalError,
+        "{} since the reservation could not be serialized.", msg);
+
+    let storage =
+        match <Service::StaticStorage as StaticStorage>::Builder::new(&node_id.as_file_name())
+            .config(&namespace_reservation_config::<Service>(config))
+            .has_ownership(false)
+            .create(&serialized_reservation)
+        {
+            Ok(storage) => storage,
+            Err(StaticStorageCreateError::InsufficientPermissions) => {
+                fail!(from origin, with NodeCreationFailure::InsufficientPermissions,
+                "{} due to insufficient permissions to create the reservation.", msg);
+            }
+            Err(e) => {
+                fail!(from origin, with NodeCreationFailure::InternalError,
+                "{} due to a failure while creating the reservation ({:?}).", msg, e);
+            }
+        };
+
+    // the reservation is created before the check, so that two nodes that are created
+    // concurrently with conflicting configs detect each other
+    let conflicts = match conflicting_nodes::<Service>(config) {
+        Ok(conflicts) => conflicts,
+        Err(e) => {
+            warn!(from origin, when remove_namespace_reservation::<Service>(config, node_id),
+                "Unable to remove the namespace reservation after the failed conflict detection.");
+            fail!(from origin, with NodeCreationFailure::InternalError,
+                "{} since the reservations of the other nodes could not be checked ({:?}).", msg, e);
+        }
+    };
+
+    if !conflicts.is_empty() {
+        warn!(from origin, when remove_namespace_reservation::<Service>(config, node_id),
+            "Unable to remove the namespace reservation after a conflict was detected.");
+
+        let offending_nodes = conflicts
+            .iter()
+            .map(|n| {
+                format!(
+                    "node {:?} with the name \"{}\" of process {}",
+                    n.id,
+                    n.name,
+                    n.id.pid()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        fail!(from origin, with NodeCreationFailure::NamespaceConflict,
+            "{} since the root path \"{}\" and prefix \"{}\" are already used with an incompatible config by: {}.",
+            msg, config.global.root_path(), config.global.prefix, offending_nodes);
+    }
+
+    Ok(storage)
+}
+
+pub(crate) fn remove_namespace_reservation<Service: service::Service>(
+    config: &Config,
+    node_id: &NodeId,
+) -> Result<(), NodeCleanupFailure> {
+    let origin = format!("remove_namespace_reservation({:?}, {:?})", config, node_id);
+    let msg = "Unable to remove namespace reservation";
+
+    match unsafe {
+        <Service::StaticStorage as NamedConceptMgmt>::remove_cfg(
+            &node_id.as_file_name(),
+            &namespace_reservation_config::<Service>(config),
+        )
+    } {
+        Ok(_) => Ok(()),
+        Err(NamedConceptRemoveError::InsufficientPermissions) => {
+            fail!(from origin, with NodeCleanupFailure::InsufficientPermissions,
+                "{} due to insufficient permissions.", msg);
+        }
+        Err(NamedConceptRemoveError::InternalError) => {
+            fail!(from origin, with NodeCleanupFailure::InternalError,
+                "{} due to an internal error.", msg);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::sync::atomic::Ordering;
+use std::sync::Mutex;
+use std::thread::ThreadId;
+
+extern crate alloc;
+use alloc::sync::Weak;
+
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+
+use super::NodePrefaultFailure;
+use crate::port::details::publisher_connections::PublisherConnections;
+use crate::port::notifier::ListenerConnections;
+use crate::port::publisher::PublisherBackend;
+use crate::port::update_connections::ConnectionFailure;
+use crate::service;
+
+#[derive(Debug)]
+pub(crate) enum RegisteredPort<Service: service::Service> {
+    Publisher(Weak<PublisherBackend<Service>>),
+    Subscriber(Weak<PublisherConnections<Service>>),
+    Notifier(Weak<ListenerConnections<Service>>),
+}
+
+impl<Service: service::Service> RegisteredPort<Service> {
+    fn is_alive(&self) -> bool {
+        match self {
+            RegisteredPort::Publisher(p) => p.strong_count() != 0,
+            RegisteredPort::Subscriber(p) => p.strong_count() != 0,
+            RegisteredPort::Notifier(p) => p.strong_count() != 0,
+        }
+    }
+
+    fn acquire_connections(&self) -> Result<(), ConnectionFailure> {
+        match self {
+            RegisteredPort::Publisher(p) => match p.upgrade() {
+                Some(p) => p.update_connections(),
+                None => Ok(()),
+            },
+            RegisteredPort::Subscriber(p) => match p.upgrade() {
+                Some(p) => p.update_connections(),
+                None => Ok(()),
+            },
+            RegisteredPort::Notifier(p) => {
+                if let Some(p) = p.upgrade() {
+                    p.update_connections();
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Keeps track of all ports of a [`Node`](crate::node::Node) that establish connections
+/// lazily so that all of their resources can be acquired upfront. As soon as the registry
+/// is locked no port is allowed to open new resources anymore.
+#[derive(Debug)]
+pub(crate) struct PortRegistry<Service: service::Service> {
+    ports: Mutex<Vec<(ThreadId, RegisteredPort<Service>)>>,
+    is_locked: IoxAtomicBool,
+}
+
+impl<Service: service::Service> PortRegistry<Service> {
+    pub(crate) fn new() -> Self {
+        Self {
+            ports: Mutex::new(vec![]),
+            is_locked: IoxAtomicBool::new(false),
+        }
+    }
+
+    pub(crate) fn register(&self, port: RegisteredPort<Service>) {
+        let mut ports = self.ports.lock().unwrap();
+        ports.retain(|(_, p)| p.is_alive());
+        ports.push((std::thread::current().id(), port));
+    }
+
+    pub(crate) fn is_locked(&self) -> bool {
+        self.is_locked.load(Ordering::Relaxed)
+    }
+
+    /// Establishes all outstanding connections of all registered ports and locks the registry
+    /// afterwards. Ports that live in another thread are skipped.
+    pub(crate) fn prefault_and_lock(&self) -> Result<(), NodePrefaultFailure> {
+        let msg = "Unable to acquire all resources of the node upfront";
+        let current_thread = std::thread::current().id();
+        let mut ports = self.ports.lock().unwrap();
+        ports.retain(|(_, p)| p.is_alive());
+
+        let mut ret_val = Ok(());
+        for (thread_id, port) in ports.iter() {
+            if *thread_id != current_thread {
+                warn!(from self,
+                    "{} since the port {:?} is owned by another thread.", msg, port);
+                ret_val = ret_val.and(Err(NodePrefaultFailure::PortOwnedByAnotherThread));
+                continue;
+            }
+
+            if let Err(e) = port.acquire_connections() {
+                warn!(from self,
+                    "{} since the connections of the port {:?} could not be established ({:?}).",
+                    msg, port, e);
+                ret_val = ret_val.and(Err(NodePrefaultFailure::ConnectionFailure(e)));
+            }
+        }
+
+        self.is_locked.store(true, Ordering::Relaxed);
+
+        if let Err(e) = ret_val {
+            fail!(from self, with e,
+                "{} since at least one port failed. The node is locked nevertheless.", msg);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::sync::atomic::Ordering;
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum ResourceReservationError {
+    ExceedsMaxPorts,
+    ExceedsMemoryBudget,
+}
+
+/// Tracks the resources all ports of a [`Node`](crate::node::Node) have acquired and enforces
+/// the quotas defined with
+/// [`NodeBuilder::memory_budget()`](crate::node::NodeBuilder::memory_budget()) and
+/// [`NodeBuilder::max_ports()`](crate::node::NodeBuilder::max_ports()).
+#[derive(Debug)]
+pub(crate) struct ResourceBudget {
+    memory_budget: Option<usize>,
+    max_ports: Option<usize>,
+    used_memory: IoxAtomicUsize,
+    number_of_ports: IoxAtomicUsize,
+}
+
+impl ResourceBudget {
+    pub(crate) fn new(memory_budget: Option<usize>, max_ports: Option<usize>) -> Self {
+        Self {
+            memory_budget,
+            max_ports,
+            used_memory: IoxAtomicUsize::new(0),
+            number_of_ports: IoxAtomicUsize::new(0),
+        }
+    }
+
+    pub(crate) fn memory_budget(&self) -> Option<usize> {
+        self.memory_budget
+    }
+
+    pub(crate) fn max_ports(&self) -> Option<usize> {
+        self.max_ports
+    }
+
+    pub(crate) fn used_memory(&self) -> usize {
+        self.used_memory.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn number_of_ports(&self) -> usize {
+        self.number_of_ports.load(Ordering::Relaxed)
+    }
+
+    fn acquire(counter: &IoxAtomicUsize, amount: usize, limit: Option<usize>) -> bool {
+        counter
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| {
+                let new_value = current.checked_add(amount)?;
+                match limit {
+                    Some(limit) if limit < new_value => None,
+                    _ => Some(new_value),
+                }
+            })
+            .is_ok()
+    }
+
+    /// Reserves one port and `memory` bytes. The resources are released as soon as the
+    /// returned [`ResourceReservation`] goes out of scope.
+    pub(crate) fn reserve(
+        self: &Arc<Self>,
+        memory: usize,
+    ) -> Result<ResourceReservation, ResourceReservationError> {
+        let msg = "Unable to reserve node resources for a new port";
+        if !Self::acquire(&self.number_of_ports, 1, self.max_ports) {
+            fail!(from self, with ResourceReservationError::ExceedsMaxPorts,
+                "{} since it would exceed the maximum number of ports of {:?}.",
+                msg, self.max_ports);
+        }
+
+        if !Self::acquire(&self.used_memory, memory, self.memory_budget) {
+            self.number_of_ports.fetch_sub(1, Ordering::Relaxed);
+            fail!(from self, with ResourceReservationError::ExceedsMemoryBudget,
+                "{} since the port requires {} bytes and the memory budget of {:?} bytes has only {} bytes left.",
+                msg, memory, self.memory_budget,
+                self.memory_budget.unwrap_or(usize::MAX).saturating_sub(self.used_memory()));
+        }
+
+        Ok(ResourceReservation {
+            budget: self.clone(),
+            memory: IoxAtomicUsize::new(memory),
+        })
+    }
+}
+
+/// The resources a single port has reserved from the [`ResourceBudget`] of its
+/// [`Node`](crate::node::Node).
+#[derive(Debug)]
+pub(crate) struct ResourceReservation {
+    budget: Arc<ResourceBudget>,
+    memory: IoxAtomicUsize,
+}
+
+impl ResourceReservation {
+    /// Adjusts the reserved memory to `memory` bytes. Growing the reservation fails when it
+    /// would exceed the memory budget of the [`Node`](crate::node::Node), shrinking it
+    /// releases the surplus back to the [`ResourceBudget`].
+    pub(crate) fn resize(&self, memory: usize) -> Result<(), ResourceReservationError> {
+        let current = self.memory.load(Ordering::Relaxed);
+        if current < memory {
+            let additional_memory = memory - current;
+            if !ResourceBudget::acquire(
+                &self.budget.used_memory,
+                additional_memory,
+                self.budget.memory_budget,
+            ) {
+                fail!(from self, with ResourceReservationError::ExceedsMemoryBudget,
+                    "Unable to grow the reserved port memory to {} bytes since the additional {} bytes exceed the memory budget of {:?} bytes.",
+                    memory, additional_memory, self.budget.memory_budget);
+            }
+        } else {
+            self.budget
+                .used_memory
+                .fetch_sub(current - memory, Ordering::Relaxed);
+        }
+
+        self.memory.store(memory, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+impl Drop for ResourceReservation {
+    fn drop(&mut self) {
+        self.budget
+            .used_memory
+            .fetch_sub(self.memory.load(Ordering::Relaxed), Ordering::Relaxed);
+        self.budget.number_of_ports.fetch_sub(1, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,337 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`ResourceManifest`] lists every directory, file, shared memory object and socket that
+//! a [`Node`] and a set of [`Service`]s including all of their ports require - before any of
+//! them is created. It can be used to generate sandbox profiles, like AppArmor, SELinux or
+//! landlock rules, ahead of time for security-hardened deployments.
+//!
+//! The resources of ports are identified by unique port ids that are generated when the port
+//! is created. Their [`Resource::path()`] contains therefore a `*` wildcard, see
+//! [`Resource::is_pattern()`].
+//!
+//! The paths are derived from the [`Config`](crate::config::Config) of the [`Node`] and the
+//! building blocks of the [`Service`] type. Process-local [`Service`] variants, like
+//! `local::Service`, only emulate the listed resources in the memory of the process.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//!
+//! let manifest = node
+//!     .resource_manifest()
+//!     .publish_subscribe(&"My/Funk/ServiceName".try_into()?)
+//!     .event(&"My/Funk/EventName".try_into()?)
+//!     .create();
+//!
+//! for resource in manifest.resources() {
+//!     println!("{:?} {} ({})", resource.kind(), resource.path(), resource.purpose());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_bb_system_types::path::Path;
+use iceoryx2_cal::named_concept::NamedConceptConfiguration;
+
+use crate::node::Node;
+use crate::service::config_scheme::{
+    attribute_index_config, broadcast_config, connection_config, data_segment_config,
+    dynamic_config_storage_config, event_config, namespace_reservation_config, node_details_config,
+    node_details_path, node_monitoring_config, resizable_data_segment_config, service_tag_config,
+    static_config_storage_config,
+};
+use crate::service::messaging_pattern::MessagingPattern;
+use crate::service::naming_scheme::persistent_history_name;
+use crate::service::service_id::ServiceId;
+use crate::service::service_name::ServiceName;
+use crate::service::Service;
+
+const PORT_ID_PLACEHOLDER: &str = "iox2_resource_manifest_port_id";
+
+/// Defines what kind of operating system resource a [`Resource`] is.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ResourceKind {
+    /// A directory that contains other resources.
+    Directory,
+    /// A regular file.
+    File,
+    /// A shared memory object. The [`Resource::path()`] is the name of the object, on Linux it
+    /// is located in `/dev/shm`.
+    SharedMemory,
+    /// A unix domain socket.
+    Socket,
+}
+
+/// A single resource of a [`ResourceManifest`].
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub struct Resource {
+    kind: ResourceKind,
+    purpose: &'static str,
+    path: String,
+}
+
+impl Resource {
+    /// Returns the [`ResourceKind`].
+    pub fn kind(&self) -> ResourceKind {
+        self.kind
+    }
+
+    /// Returns a human readable description of what the resource is used for.
+    pub fn purpose(&self) -> &'static str {
+        self.purpose
+    }
+
+    /// Returns the path of the resource. When it [`Resource::is_pattern()`], the port specific
+    /// part is replaced with a `*` wildcard.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns true when the [`Resource::path()`] contains a `*` wildcard, otherwise false.
+    pub fn is_pattern(&self) -> bool {
+        self.path.contains('*')
+    }
+}
+
+/// Lists all resources that are required by a [`Node`] and a set of [`Service`]s, see the
+/// [module](crate::node::resource_manifest) documentation.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ResourceManifest {
+    resources: Vec<Resource>,
+}
+
+impl ResourceManifest {
+    /// Returns all [`Resource`]s of the [`ResourceManifest`].
+    pub fn resources(&self) -> &[Resource] {
+        &self.resources
+    }
+
+    fn add(&mut self, kind: ResourceKind, purpose: &'static str, path: String) {
+        let resource = Resource {
+            kind,
+            purpose,
+            path,
+        };
+
+        if !self.resources.contains(&resource) {
+            self.resources.push(resource);
+        }
+    }
+
+    fn add_directory(&mut self, purpose: &'static str, path: &Path) {
+        self.add(ResourceKind::Directory, purpose, path.to_string());
+    }
+
+    fn add_concept<Config: NamedConceptConfiguration>(
+        &mut self,
+        kind: ResourceKind,
+        purpose: &'static str,
+        config: &Config,
+        name: &FileName,
+    ) {
+        let file_path = config.path_for(name);
+        let path = match kind {
+            ResourceKind::SharedMemory => file_path.file_name().to_string(),
+            _ => file_path.to_string(),
+        };
+
+        self.add(kind, purpose, path.replace(PORT_ID_PLACEHOLDER, "*"));
+    }
+}
+
+/// Collects the [`Service`]s whose resources shall be part of the [`ResourceManifest`].
+/// Created with [`Node::resource_manifest()`].
+#[derive(Debug)]
+pub struct ResourceManifestBuilder<'node, S: Service> {
+    node: &'node Node<S>,
+    services: Vec<(ServiceName, MessagingPattern)>,
+}
+
+impl<'node, S: Service> ResourceManifestBuilder<'node, S> {
+    pub(crate) fn new(node: &'node Node<S>) -> Self {
+        Self {
+            node,
+            services: Vec::new(),
+        }
+    }
+
+    /// Adds the resources of the publish-subscribe [`Service`] with the provided
+    /// [`ServiceName`] and all of its ports.
+    pub fn publish_subscribe(mut self, name: &ServiceName) -> Self {
+        self.services
+            .push((name.clone(), MessagingPattern::PublishSubscribe));
+        self
+    }
+
+    /// Adds the resources of the event [`Service`] with the provided [`ServiceName`] and all
+    /// of its ports.
+    pub fn event(mut self, name: &ServiceName) -> Self {
+        self.services.push((name.clone(), MessagingPattern::Event));
+        self
+    }
+
+    /// Adds the resources of the request-response [`Service`] with the provided
+    /// [`ServiceName`] and all of its ports.
+    pub fn request_response(mut self, name: &ServiceName) -> Self {
+        self.services
+            .push((name.clone(), MessagingPattern::RequestResponse));
+        self
+    }
+
+    /// Creates the [`ResourceManifest`] of the [`Node`] and all added [`Service`]s.
+    pub fn create(self) -> ResourceManifest {
+        let config = self.node.config();
+        let node_id = self.node.id();
+        let port_id = fatal_panic!(from "ResourceManifestBuilder::create()",
+            when FileName::new(PORT_ID_PLACEHOLDER.as_bytes()),
+            "This should never happen! The port id placeholder is not a valid file name.");
+
+        let mut manifest = ResourceManifest::default();
+        manifest.add_directory("root directory", config.global.root_path());
+        manifest.add_directory("node directory", &config.global.node_dir());
+        manifest.add_directory("service directory", &config.global.service_dir());
+        manifest.add_directory(
+            "node details directory",
+            &node_details_path(config, node_id),
+        );
+        manifest.add_concept(
+            ResourceKind::File,
+            "node details",
+            &node_details_config::<S>(config, node_id),
+            &FileName::new(b"node").unwrap(),
+        );
+        manifest.add_concept(
+            ResourceKind::File,
+            "node monitoring token",
+            &node_monitoring_config::<S>(config),
+            &node_id.as_file_name(),
+        );
+        manifest.add_concept(
+            ResourceKind::File,
+            "namespace reservation",
+            &namespace_reservation_config::<S>(config),
+            &node_id.as_file_name(),
+        );
+        if let Some(path) = self.node.evidence_log_path() {
+            manifest.add(ResourceKind::File, "evidence log", path.to_string());
+        }
+
+        for (name, messaging_pattern) in &self.services {
+            let service_id = ServiceId::new::<S::ServiceNameHasher>(name, *messaging_pattern);
+            let service_file_name: FileName = service_id.0.into();
+
+            manifest.add_concept(
+                ResourceKind::File,
+                "service static config",
+                &static_config_storage_config::<S>(config),
+                &service_file_name,
+            );
+            manifest.add_concept(
+                ResourceKind::File,
+                "service attribute index",
+                &attribute_index_config::<S>(config),
+                &service_file_name,
+            );
+            manifest.add_concept(
+                ResourceKind::SharedMemory,
+                "service dynamic config",
+                &dynamic_config_storage_config::<S>(config),
+                &service_file_name,
+            );
+            manifest.add_concept(
+                ResourceKind::File,
+                "service tag of the node",
+                &service_tag_config::<S>(config, node_id),
+                &service_file_name,
+            );
+
+            match messaging_pattern {
+                MessagingPattern::PublishSubscribe => {
+                    manifest.add_concept(
+                        ResourceKind::SharedMemory,
+                        "publisher data segment",
+                        &data_segment_config::<S>(config),
+                        &port_id,
+                    );
+                    manifest.add_concept(
+                        ResourceKind::SharedMemory,
+                        "publisher dynamic data segment",
+                        &resizable_data_segment_config::<S>(config),
+                        &port_id,
+                    );
+                    manifest.add_concept(
+                        ResourceKind::SharedMemory,
+                        "publisher persistent history",
+                        &data_segment_config::<S>(config),
+                        &persistent_history_name(&service_id),
+                    );
+                    manifest.add_concept(
+                        ResourceKind::SharedMemory,
+                        "publisher subscriber connection",
+                        &connection_config::<S>(config),
+                        &port_id,
+                    );
+                    manifest.add_concept(
+                        ResourceKind::SharedMemory,
+                        "publisher broadcast",
+                        &broadcast_config::<S>(config),
+                        &port_id,
+                    );
+                    manifest.add_concept(
+                        ResourceKind::Socket,
+                        "publisher reclaim event",
+                        &event_config::<S>(config),
+                        &port_id,
+                    );
+                    manifest.add_concept(
+                        ResourceKind::Socket,
+                        "subscriber readiness event",
+                        &event_config::<S>(config),
+                        &port_id,
+                    );
+                }
+                MessagingPattern::Event => {
+                    manifest.add_concept(
+                        ResourceKind::Socket,
+                        "listener event",
+                        &event_config::<S>(config),
+                        &port_id,
+                    );
+                }
+                MessagingPattern::RequestResponse => {
+                    manifest.add_concept(
+                        ResourceKind::SharedMemory,
+                        "client and server data segment",
+                        &data_segment_config::<S>(config),
+                        &port_id,
+                    );
+                    manifest.add_concept(
+                        ResourceKind::SharedMemory,
+                        "client server connection",
+                        &connection_config::<S>(config),
+                        &port_id,
+                    );
+                }
+            }
+        }
+
+        manifest
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::marker::PhantomData;
+use core::sync::atomic::Ordering;
+use core::time::Duration;
+use std::sync::Mutex;
+
+use iceoryx2_bb_log::{debug, warn};
+use iceoryx2_bb_posix::clock::{ClockType, Time};
+use iceoryx2_cal::event::{Notifier, NotifierBuilder, TriggerId};
+use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+
+use crate::config::Config;
+use crate::port::port_identifiers::UniqueListenerId;
+use crate::service;
+use crate::service::config_scheme::event_config;
+use crate::service::naming_scheme::event_concept_name;
+
+/// Stores the shutdown intent of a [`Node`](crate::node::Node) and wakes up all
+/// [`Listener`](crate::port::listener::Listener)s of the [`Node`](crate::node::Node) so that
+/// the [`WaitSet`](crate::waitset::WaitSet)s they are attached to return.
+#[derive(Debug)]
+pub(crate) struct ShutdownIntent<Service: service::Service> {
+    is_requested: IoxAtomicBool,
+    deadline: Mutex<Option<(Option<Time>, Duration)>>,
+    listeners: Mutex<Vec<(UniqueListenerId, TriggerId)>>,
+    _service: PhantomData<Service>,
+}
+
+impl<Service: service::Service> ShutdownIntent<Service> {
+    pub(crate) fn new() -> Self {
+        Self {
+            is_requested: IoxAtomicBool::new(false),
+            deadline: Mutex::new(None),
+            listeners: Mutex::new(vec![]),
+            _service: PhantomData,
+        }
+    }
+
+    pub(crate) fn is_requested(&self) -> bool {
+        self.is_requested.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn remaining_grace_period(&self) -> Option<Duration> {
+        let (start, grace) = (*self.deadline.lock().unwrap())?;
+        let start = match start {
+            Some(start) => start,
+            None => return Some(Duration::ZERO),
+        };
+
+        match start.elapsed() {
+            Ok(elapsed) => Some(grace.saturating_sub(elapsed)),
+            Err(e) => {
+                warn!(from self,
+                    "Unable to acquire the remaining shutdown grace period since the elapsed time could not be acquired ({:?}).", e);
+                Some(Duration::ZERO)
+            }
+        }
+    }
+
+    /// Registers a [`Listener`](crate::port::listener::Listener) that shall be woken up with
+    /// the provided [`TriggerId`] when the shutdown intent is broadcast.
+    pub(crate) fn register_listener(&self, listener_id: UniqueListenerId, wake_up_id: TriggerId) {
+        self.listeners
+            .lock()
+            .unwrap()
+            .push((listener_id, wake_up_id));
+    }
+
+    pub(crate) fn unregister_listener(&self, listener_id: UniqueListenerId) {
+        self.listeners
+            .lock()
+            .unwrap()
+            .retain(|(id, _)| *id != listener_id);
+    }
+
+    pub(crate) fn broadcast(&self, grace: Duration, config: &Config) {
+        {
+            let mut deadline = self.deadline.lock().unwrap();
+            if deadline.is_none() {
+                match Time::now_with_clock(ClockType::Monotonic) {
+                    Ok(now) => *deadline = Some((Some(now), grace)),
+                    Err(e) => {
+                        warn!(from self,
+                            "The shutdown grace period is considered as expired since the current time could not be acquired ({:?}).", e);
+                        *deadline = Some((None, Duration::ZERO));
+                    }
+                }
+            }
+        }
+
+        self.is_requested.store(true, Ordering::Relaxed);
+
+        let event_config = event_config::<Service>(config);
+        for (listener_id, wake_up_id) in self.listeners.lock().unwrap().iter() {
+            let event_name = event_concept_name(listener_id);
+            match <Service::Event as iceoryx2_cal::event::Event>::NotifierBuilder::new(&event_name)
+                .config(&event_config)
+                .open()
+            {
+                Ok(notifier) => {
+                    if let Err(e) = notifier.notify(*wake_up_id) {
+                        warn!(from self,
+                            "Unable to wake up the listener {:?} to broadcast the shutdown intent ({:?}).",
+                            listener_id, e);
+                    }
+                }
+                Err(e) => {
+                    debug!(from self,
+                        "Unable to wake up the listener {:?} to broadcast the shutdown intent since the connection could not be established ({:?}).",
+                        listener_id, e);
+                }
+            }
+        }
+    }
+}
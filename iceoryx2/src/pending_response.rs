@@ -0,0 +1,419 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let client = service.client_builder().create()?;
+//!
+//! let pending_response = client.send_copy(1234)?;
+//! println!("request {} reached {} servers", pending_response.request_id(),
+//!     pending_response.number_of_server_connections());
+//!
+//! while let Some(response) = pending_response.receive()? {
+//!     println!("received response: {}", *response);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::shm_allocator::PointerOffset;
+
+use crate::error_response::ErrorResponse;
+#[cfg(feature = "tokio")]
+use crate::port::async_listener::AsyncListener;
+#[cfg(feature = "tokio")]
+use crate::port::client::ResponseReceiveAsyncError;
+use crate::port::client::{ClientBackend, ResponseReceiveError};
+use crate::port::details::server_connections::Connection;
+use crate::raw_sample::RawSample;
+use crate::response::Response;
+use crate::service::header::request_response::ResponseHeader as Header;
+
+/// Represents a request that was sent by a [`Client`](crate::port::client::Client) and is
+/// still active. All responses of the [`Server`](crate::port::server::Server)s are received
+/// with [`PendingResponse::receive()`] or, when the [`Server`](crate::port::server::Server)
+/// may answer with an error response, with [`PendingResponse::receive_result()`]. When it goes
+/// out of scope the request is no longer active and all further responses are discarded.
+pub struct PendingResponse<
+    Service: crate::service::Service,
+    RequestPayload: Debug + ?Sized,
+    RequestHeader: Debug,
+    ResponsePayload: Debug + ?Sized,
+    ResponseHeader: Debug,
+> {
+    client_backend: Arc<ClientBackend<Service>>,
+    request_id: u64,
+    number_of_server_connections: usize,
+    _request_payload: PhantomData<RequestPayload>,
+    _request_header: PhantomData<RequestHeader>,
+    _response_payload: PhantomData<ResponsePayload>,
+    _response_header: PhantomData<ResponseHeader>,
+}
+
+impl<
+        Service: crate::service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > Debug
+    for PendingResponse<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "PendingResponse<{}, {}, {}, {}, {}> {{ client_backend: {:?}, request_id: {}, number_of_server_connections: {} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<RequestPayload>(),
+            core::any::type_name::<RequestHeader>(),
+            core::any::type_name::<ResponsePayload>(),
+            core::any::type_name::<ResponseHeader>(),
+            self.client_backend,
+            self.request_id,
+            self.number_of_server_connections
+        )
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > Drop
+    for PendingResponse<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    fn drop(&mut self) {
+        self.client_backend.deactivate_request(self.request_id);
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > PendingResponse<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    pub(crate) fn new(
+        client_backend: &Arc<ClientBackend<Service>>,
+        request_id: u64,
+        number_of_server_connections: usize,
+    ) -> Self {
+        Self {
+            client_backend: client_backend.clone(),
+            request_id,
+            number_of_server_connections,
+            _request_payload: PhantomData,
+            _request_header: PhantomData,
+            _response_payload: PhantomData,
+            _response_header: PhantomData,
+        }
+    }
+
+    /// Returns the id of the request, see
+    /// [`RequestHeader::request_id()`](crate::service::header::request_response::RequestHeader::request_id()).
+    pub fn request_id(&self) -> u64 {
+        self.request_id
+    }
+
+    /// Returns the number of [`Server`](crate::port::server::Server)s the request was
+    /// delivered to.
+    pub fn number_of_server_connections(&self) -> usize {
+        self.number_of_server_connections
+    }
+
+    /// Returns true when the request was sent with
+    /// [`RequestMut::send_with_timeout()`](crate::request_mut::RequestMut::send_with_timeout())
+    /// and no response was received before the timeout passed. An expired request is no
+    /// longer active and [`PendingResponse::receive()`] returns
+    /// [`ResponseReceiveError::Timeout`].
+    pub fn has_timed_out(&self) -> bool {
+        self.client_backend.is_request_expired(self.request_id)
+    }
+
+    #[cfg(feature = "tokio")]
+    async fn receive_async_impl<
+        F: Fn(
+            &Self,
+        ) -> Result<
+            Option<Response<Service, ResponsePayload, ResponseHeader>>,
+            ResponseReceiveError,
+        >,
+    >(
+        &self,
+        listener: &AsyncListener<Service>,
+        receive_call: F,
+    ) -> Result<Response<Service, ResponsePayload, ResponseHeader>, ResponseReceiveAsyncError> {
+        loop {
+            if let Some(response) = receive_call(self)? {
+                return Ok(response);
+            }
+
+            listener.wait_all(|_| {}).await?;
+        }
+    }
+
+    fn receive_response(
+        &self,
+    ) -> Result<Option<(Arc<Connection<Service>>, PointerOffset, usize)>, ResponseReceiveError>
+    {
+        let (connection, offset, address) =
+            match self.client_backend.receive_response(self.request_id)? {
+                Some(v) => v,
+                None => return Ok(None),
+            };
+
+        if unsafe { (*(address as *const Header)).is_error() } {
+            self.client_backend
+                .unreceive_response(self.request_id, connection, offset, address);
+            fail!(from self, with ResponseReceiveError::ReceivedErrorResponse,
+                "Unable to receive response since the server sent an error response that must be received with receive_result().");
+        }
+
+        Ok(Some((connection, offset, address)))
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn receive_response_or_error<E: Debug>(
+        &self,
+    ) -> Result<
+        Option<
+            Result<
+                (Arc<Connection<Service>>, PointerOffset, usize),
+                ErrorResponse<Service, E, ResponseHeader>,
+            >,
+        >,
+        ResponseReceiveError,
+    > {
+        let static_config = self.client_backend.static_config();
+        if !static_config.has_error_type::<E>() {
+            fail!(from self, with ResponseReceiveError::IncompatibleErrorType,
+                "Unable to receive response since the service does not use the error type \"{}\".",
+                core::any::type_name::<E>());
+        }
+
+        let (connection, offset, address) =
+            match self.client_backend.receive_response(self.request_id)? {
+                Some(v) => v,
+                None => return Ok(None),
+            };
+
+        let header_ptr = address as *const Header;
+        if !unsafe { (*header_ptr).is_error() } {
+            return Ok(Some(Ok((connection, offset, address))));
+        }
+
+        // has_error_type() ensures that the error type details exist
+        let details = static_config.error_message_type_details.as_ref().unwrap();
+        let user_header_ptr =
+            details.user_header_ptr_from_header(header_ptr.cast()) as *const ResponseHeader;
+        let payload_ptr = details.payload_ptr_from_header(header_ptr.cast()) as *const E;
+
+        Ok(Some(Err(ErrorResponse {
+            ptr: unsafe { RawSample::new_unchecked(header_ptr, user_header_ptr, payload_ptr) },
+            connection,
+            offset,
+        })))
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseHeader: Debug,
+    > PendingResponse<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    /// Receives the next [`Response`] that a [`Server`](crate::port::server::Server) sent for
+    /// this request. If no response is available [`None`] is returned. If the next response
+    /// is an error response [`ResponseReceiveError::ReceivedErrorResponse`] is returned and it
+    /// has to be received with [`PendingResponse::receive_result()`].
+    pub fn receive(
+        &self,
+    ) -> Result<Option<Response<Service, ResponsePayload, ResponseHeader>>, ResponseReceiveError>
+    {
+        Ok(self
+            .receive_response()?
+            .map(|(connection, offset, address)| self.to_response(connection, offset, address)))
+    }
+
+    /// Receives the next [`Response`] asynchronously. When no response is available the task
+    /// is suspended until the provided [`AsyncListener`] is notified, e.g. by a
+    /// [`Notifier`](crate::port::notifier::Notifier) that the
+    /// [`Server`](crate::port::server::Server) side triggers after every sent response. The
+    /// runtime thread is not blocked while waiting.
+    ///
+    /// Only available with the `tokio` feature.
+    #[cfg(feature = "tokio")]
+    pub async fn receive_async(
+        &self,
+        listener: &AsyncListener<Service>,
+    ) -> Result<Response<Service, ResponsePayload, ResponseHeader>, ResponseReceiveAsyncError> {
+        self.receive_async_impl(listener, Self::receive).await
+    }
+
+    /// Receives the next [`Response`] or [`ErrorResponse`] that a
+    /// [`Server`](crate::port::server::Server) sent for this request. If no response is
+    /// available [`None`] is returned. `E` must be the type that was defined with
+    /// [`error_type()`](crate::service::builder::request_response::Builder::error_type()),
+    /// otherwise [`ResponseReceiveError::IncompatibleErrorType`] is returned.
+    #[allow(clippy::type_complexity)]
+    pub fn receive_result<E: Debug>(
+        &self,
+    ) -> Result<
+        Option<
+            Result<
+                Response<Service, ResponsePayload, ResponseHeader>,
+                ErrorResponse<Service, E, ResponseHeader>,
+            >,
+        >,
+        ResponseReceiveError,
+    > {
+        Ok(self.receive_response_or_error::<E>()?.map(|result| {
+            result
+                .map(|(connection, offset, address)| self.to_response(connection, offset, address))
+        }))
+    }
+
+    fn to_response(
+        &self,
+        connection: Arc<Connection<Service>>,
+        offset: PointerOffset,
+        address: usize,
+    ) -> Response<Service, ResponsePayload, ResponseHeader> {
+        let details = &self
+            .client_backend
+            .static_config()
+            .response_message_type_details;
+        let header_ptr = address as *const Header;
+        let user_header_ptr =
+            details.user_header_ptr_from_header(header_ptr.cast()) as *const ResponseHeader;
+        let payload_ptr =
+            details.payload_ptr_from_header(header_ptr.cast()) as *const ResponsePayload;
+
+        Response {
+            ptr: unsafe { RawSample::new_unchecked(header_ptr, user_header_ptr, payload_ptr) },
+            connection,
+            offset,
+        }
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseHeader: Debug,
+    > PendingResponse<Service, RequestPayload, RequestHeader, [ResponsePayload], ResponseHeader>
+{
+    /// Receives the next [`Response`] that a [`Server`](crate::port::server::Server) sent for
+    /// this request. If no response is available [`None`] is returned. If the next response
+    /// is an error response [`ResponseReceiveError::ReceivedErrorResponse`] is returned and it
+    /// has to be received with [`PendingResponse::receive_result()`].
+    pub fn receive(
+        &self,
+    ) -> Result<Option<Response<Service, [ResponsePayload], ResponseHeader>>, ResponseReceiveError>
+    {
+        Ok(self
+            .receive_response()?
+            .map(|(connection, offset, address)| self.to_response(connection, offset, address)))
+    }
+
+    /// Receives the next [`Response`] with a slice payload asynchronously. When no response
+    /// is available the task is suspended until the provided [`AsyncListener`] is notified.
+    /// The runtime thread is not blocked while waiting.
+    ///
+    /// Only available with the `tokio` feature.
+    #[cfg(feature = "tokio")]
+    pub async fn receive_async(
+        &self,
+        listener: &AsyncListener<Service>,
+    ) -> Result<Response<Service, [ResponsePayload], ResponseHeader>, ResponseReceiveAsyncError>
+    {
+        self.receive_async_impl(listener, Self::receive).await
+    }
+
+    /// Receives the next [`Response`] or [`ErrorResponse`] that a
+    /// [`Server`](crate::port::server::Server) sent for this request. If no response is
+    /// available [`None`] is returned. `E` must be the type that was defined with
+    /// [`error_type()`](crate::service::builder::request_response::Builder::error_type()),
+    /// otherwise [`ResponseReceiveError::IncompatibleErrorType`] is returned.
+    #[allow(clippy::type_complexity)]
+    pub fn receive_result<E: Debug>(
+        &self,
+    ) -> Result<
+        Option<
+            Result<
+                Response<Service, [ResponsePayload], ResponseHeader>,
+                ErrorResponse<Service, E, ResponseHeader>,
+            >,
+        >,
+        ResponseReceiveError,
+    > {
+        Ok(self.receive_response_or_error::<E>()?.map(|result| {
+            result
+                .map(|(connection, offset, address)| self.to_response(connection, offset, address))
+        }))
+    }
+
+    fn to_response(
+        &self,
+        connection: Arc<Connection<Service>>,
+        offset: PointerOffset,
+        address: usize,
+    ) -> Response<Service, [ResponsePayload], ResponseHeader> {
+        let details = &self
+            .client_backend
+            .static_config()
+            .response_message_type_details;
+        let header_ptr = address as *const Header;
+        let user_header_ptr =
+            details.user_header_ptr_from_header(header_ptr.cast()) as *const ResponseHeader;
+        let payload_ptr =
+            details.payload_ptr_from_header(header_ptr.cast()) as *const ResponsePayload;
+        let number_of_elements = unsafe { (*header_ptr).number_of_elements() };
+
+        Response {
+            ptr: unsafe {
+                RawSample::<Header, ResponseHeader, [ResponsePayload]>::new_slice_unchecked(
+                    header_ptr,
+                    user_header_ptr,
+                    core::slice::from_raw_parts(payload_ptr, number_of_elements as _),
+                )
+            },
+            connection,
+            offset,
+        }
+    }
+}
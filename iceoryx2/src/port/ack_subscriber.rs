@@ -0,0 +1,402 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use core::time::Duration;
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! let subscriber = service.subscriber_builder()
+//!     .ack_timeout(Duration::from_secs(1))
+//!     .create_ack_subscriber()?;
+//!
+//! while let Some(sample) = subscriber.receive()? {
+//!     if *sample % 2 == 0 {
+//!         // processing succeeded, the sample is released
+//!         sample.ack()?;
+//!     } else {
+//!         // processing failed, the sample is delivered again
+//!         sample.nack()?;
+//!         break;
+//!     }
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::cell::{Cell, RefCell};
+use core::fmt::Debug;
+use core::time::Duration;
+
+extern crate alloc;
+use alloc::collections::VecDeque;
+use alloc::sync::Arc;
+
+use iceoryx2_bb_log::warn;
+use iceoryx2_bb_posix::clock::{ClockType, Time};
+
+use super::port_identifiers::UniqueSubscriberId;
+use super::subscriber::{Subscriber, SubscriberReceiveError};
+use super::update_connections::ConnectionFailure;
+use crate::ack_sample::AckSample;
+use crate::sample::Sample;
+use crate::service;
+
+/// Defines the failures that can occur when an [`AckSample`] is acknowledged with
+/// [`AckSample::ack()`] or rejected with [`AckSample::nack()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum AckError {
+    /// The acknowledgement timeout of the [`AckSubscriber`] passed before the [`AckSample`]
+    /// was acknowledged or rejected. The sample was already scheduled for redelivery.
+    DeliveryExpired,
+}
+
+impl core::fmt::Display for AckError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "AckError::{:?}", self)
+    }
+}
+
+impl core::error::Error for AckError {}
+
+type ReceiveResult<Service, Payload, UserHeader> =
+    Result<Option<Sample<Service, Payload, UserHeader>>, SubscriberReceiveError>;
+
+#[derive(Debug)]
+struct Delivery<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> {
+    delivery_id: u64,
+    member_id: u64,
+    sample: Arc<Sample<Service, Payload, UserHeader>>,
+    number_of_deliveries: u64,
+    delivered_at: Option<Time>,
+}
+
+#[derive(Debug)]
+struct Redelivery<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> {
+    sample: Arc<Sample<Service, Payload, UserHeader>>,
+    number_of_deliveries: u64,
+    // the group member that rejected the sample or let it expire
+    previous_member_id: u64,
+}
+
+impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
+    From<Delivery<Service, Payload, UserHeader>> for Redelivery<Service, Payload, UserHeader>
+{
+    fn from(value: Delivery<Service, Payload, UserHeader>) -> Self {
+        Self {
+            sample: value.sample,
+            number_of_deliveries: value.number_of_deliveries,
+            previous_member_id: value.member_id,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct AckSubscriberBackend<
+    Service: service::Service,
+    Payload: Debug + ?Sized,
+    UserHeader: Debug,
+> {
+    ack_timeout: Option<Duration>,
+    delivery_id_counter: Cell<u64>,
+    member_id_counter: Cell<u64>,
+    number_of_members: Cell<usize>,
+    in_flight: RefCell<Vec<Delivery<Service, Payload, UserHeader>>>,
+    redeliveries: RefCell<VecDeque<Redelivery<Service, Payload, UserHeader>>>,
+}
+
+impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
+    AckSubscriberBackend<Service, Payload, UserHeader>
+{
+    fn is_expired(&self, delivery: &Delivery<Service, Payload, UserHeader>) -> bool {
+        match (self.ack_timeout, delivery.delivered_at) {
+            (Some(timeout), Some(delivered_at)) => match delivered_at.elapsed() {
+                Ok(elapsed) => timeout <= elapsed,
+                Err(e) => {
+                    warn!(from self,
+                        "Unable to acquire the elapsed time of delivery {} ({:?}). The delivery is considered as not expired.",
+                        delivery.delivery_id, e);
+                    false
+                }
+            },
+            _ => false,
+        }
+    }
+
+    fn expire_in_flight_samples(&self) {
+        let mut in_flight = self.in_flight.borrow_mut();
+        let mut redeliveries = self.redeliveries.borrow_mut();
+
+        let mut n = 0;
+        while n < in_flight.len() {
+            if self.is_expired(&in_flight[n]) {
+                redeliveries.push_back(in_flight.remove(n).into());
+            } else {
+                n += 1;
+            }
+        }
+    }
+
+    fn add_member(&self) -> u64 {
+        let member_id = self.member_id_counter.get();
+        self.member_id_counter.set(member_id + 1);
+        self.number_of_members.set(self.number_of_members.get() + 1);
+        member_id
+    }
+
+    fn remove_member(&self) {
+        self.number_of_members.set(self.number_of_members.get() - 1);
+    }
+
+    // A sample is redelivered to another group member than the one that rejected it or let
+    // it expire. When the group has no other member it is delivered to the same member again.
+    fn redelivery_position(&self, member_id: u64) -> Option<usize> {
+        let redeliveries = self.redeliveries.borrow();
+        match self.number_of_members.get() {
+            1 => (!redeliveries.is_empty()).then_some(0),
+            _ => redeliveries
+                .iter()
+                .position(|redelivery| redelivery.previous_member_id != member_id),
+        }
+    }
+
+    fn take_redelivery(&self, member_id: u64) -> Option<Redelivery<Service, Payload, UserHeader>> {
+        let position = self.redelivery_position(member_id)?;
+        self.redeliveries.borrow_mut().remove(position)
+    }
+
+    fn deliver(
+        self: &Arc<Self>,
+        member_id: u64,
+        sample: Arc<Sample<Service, Payload, UserHeader>>,
+        previous_deliveries: u64,
+    ) -> AckSample<Service, Payload, UserHeader> {
+        let delivery_id = self.delivery_id_counter.get();
+        self.delivery_id_counter.set(delivery_id + 1);
+
+        let delivered_at = match self.ack_timeout {
+            Some(_) => match Time::now_with_clock(ClockType::Monotonic) {
+                Ok(now) => Some(now),
+                Err(e) => {
+                    warn!(from self,
+                        "Unable to acquire the current time ({:?}). The delivery {} will not expire.",
+                        e, delivery_id);
+                    None
+                }
+            },
+            None => None,
+        };
+
+        let number_of_deliveries = previous_deliveries + 1;
+        self.in_flight.borrow_mut().push(Delivery {
+            delivery_id,
+            member_id,
+            sample: sample.clone(),
+            number_of_deliveries,
+            delivered_at,
+        });
+
+        AckSample::new(self, sample, delivery_id, number_of_deliveries)
+    }
+
+    fn take_in_flight(
+        &self,
+        delivery_id: u64,
+    ) -> Result<Delivery<Service, Payload, UserHeader>, AckError> {
+        let mut in_flight = self.in_flight.borrow_mut();
+        match in_flight
+            .iter()
+            .position(|delivery| delivery.delivery_id == delivery_id)
+        {
+            Some(n) => Ok(in_flight.remove(n)),
+            None => Err(AckError::DeliveryExpired),
+        }
+    }
+
+    pub(crate) fn ack(&self, delivery_id: u64) -> Result<(), AckError> {
+        self.take_in_flight(delivery_id)?;
+        Ok(())
+    }
+
+    pub(crate) fn nack(&self, delivery_id: u64) -> Result<(), AckError> {
+        let delivery = self.take_in_flight(delivery_id)?;
+        self.redeliveries.borrow_mut().push_back(delivery.into());
+        Ok(())
+    }
+}
+
+/// A [`Subscriber`] that hands out [`AckSample`]s that must be acknowledged explicitly with
+/// [`AckSample::ack()`]. Samples that are rejected with [`AckSample::nack()`], that are dropped
+/// without acknowledgement or that are not acknowledged before the acknowledgement timeout
+/// passed, see
+/// [`PortFactorySubscriber::ack_timeout()`](crate::service::port_factory::subscriber::PortFactorySubscriber::ack_timeout()),
+/// are delivered again with the next [`AckSubscriber::receive()`] call.
+///
+/// Additional members of the group of an [`AckSubscriber`] are created with
+/// [`AckSubscriber::create_group_member()`]. All members share the underlying [`Subscriber`],
+/// so that every sample is delivered to only one of them. A sample that is delivered again
+/// goes to another group member than the one that rejected it or let it expire. Only when no
+/// other member exists it is delivered to the same member again.
+///
+/// Every sample that was not yet acknowledged stays borrowed from the
+/// [`Publisher`](crate::port::publisher::Publisher) and counts towards the maximum number of
+/// borrowed samples of the [`Subscriber`].
+#[derive(Debug)]
+pub struct AckSubscriber<
+    Service: service::Service,
+    Payload: Debug + ?Sized + 'static,
+    UserHeader: Debug,
+> {
+    subscriber: Arc<Subscriber<Service, Payload, UserHeader>>,
+    backend: Arc<AckSubscriberBackend<Service, Payload, UserHeader>>,
+    member_id: u64,
+}
+
+impl<Service: service::Service, Payload: Debug + ?Sized + 'static, UserHeader: Debug> Drop
+    for AckSubscriber<Service, Payload, UserHeader>
+{
+    fn drop(&mut self) {
+        self.backend.remove_member();
+    }
+}
+
+impl<Service: service::Service, Payload: Debug + ?Sized + 'static, UserHeader: Debug>
+    AckSubscriber<Service, Payload, UserHeader>
+{
+    pub(crate) fn new(
+        subscriber: Subscriber<Service, Payload, UserHeader>,
+        ack_timeout: Option<Duration>,
+    ) -> Self {
+        let backend = Arc::new(AckSubscriberBackend {
+            ack_timeout,
+            delivery_id_counter: Cell::new(0),
+            member_id_counter: Cell::new(0),
+            number_of_members: Cell::new(0),
+            in_flight: RefCell::new(Vec::new()),
+            redeliveries: RefCell::new(VecDeque::new()),
+        });
+
+        Self {
+            subscriber: Arc::new(subscriber),
+            member_id: backend.add_member(),
+            backend,
+        }
+    }
+
+    /// Creates a new member of the group of this [`AckSubscriber`]. It shares the underlying
+    /// [`Subscriber`] and the acknowledgement timeout with all other members. Samples that
+    /// are rejected or expire are delivered again to another member of the group.
+    pub fn create_group_member(&self) -> Self {
+        Self {
+            subscriber: self.subscriber.clone(),
+            backend: self.backend.clone(),
+            member_id: self.backend.add_member(),
+        }
+    }
+
+    /// Returns the number of [`AckSubscriber`]s in the group of this [`AckSubscriber`],
+    /// including itself.
+    pub fn number_of_group_members(&self) -> usize {
+        self.backend.number_of_members.get()
+    }
+
+    /// Returns the [`UniqueSubscriberId`] of the underlying [`Subscriber`]
+    pub fn id(&self) -> UniqueSubscriberId {
+        self.subscriber.id()
+    }
+
+    /// Returns the acknowledgement timeout after which a sample that was neither acknowledged
+    /// nor rejected is delivered again.
+    pub fn ack_timeout(&self) -> Option<Duration> {
+        self.backend.ack_timeout
+    }
+
+    /// Returns the number of samples that were received by the group but not yet
+    /// acknowledged. This includes the samples that are waiting for their redelivery.
+    pub fn number_of_unacknowledged_samples(&self) -> usize {
+        self.backend.in_flight.borrow().len() + self.backend.redeliveries.borrow().len()
+    }
+
+    /// Returns true if the [`AckSubscriber`] has samples that can be received, either new
+    /// samples or samples that are waiting for their redelivery to this group member.
+    pub fn has_samples(&self) -> Result<bool, ConnectionFailure> {
+        self.backend.expire_in_flight_samples();
+        if self.backend.redelivery_position(self.member_id).is_some() {
+            return Ok(true);
+        }
+
+        self.subscriber.has_samples()
+    }
+
+    fn receive_impl<F>(
+        &self,
+        receive_call: F,
+    ) -> Result<Option<AckSample<Service, Payload, UserHeader>>, SubscriberReceiveError>
+    where
+        F: FnOnce(
+            &Subscriber<Service, Payload, UserHeader>,
+        ) -> ReceiveResult<Service, Payload, UserHeader>,
+    {
+        self.backend.expire_in_flight_samples();
+
+        if let Some(redelivery) = self.backend.take_redelivery(self.member_id) {
+            return Ok(Some(self.backend.deliver(
+                self.member_id,
+                redelivery.sample,
+                redelivery.number_of_deliveries,
+            )));
+        }
+
+        match receive_call(&self.subscriber)? {
+            Some(sample) => Ok(Some(self.backend.deliver(
+                self.member_id,
+                Arc::new(sample),
+                0,
+            ))),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<Service: service::Service, Payload: Debug + 'static, UserHeader: Debug>
+    AckSubscriber<Service, Payload, UserHeader>
+{
+    /// Receives the next [`AckSample`]. Samples that are waiting for their redelivery to this
+    /// group member are received before new samples. If no sample could be received [`None`] is returned.
+    /// If a failure occurs [`SubscriberReceiveError`] is returned.
+    pub fn receive(
+        &self,
+    ) -> Result<Option<AckSample<Service, Payload, UserHeader>>, SubscriberReceiveError> {
+        self.receive_impl(|subscriber| subscriber.receive())
+    }
+}
+
+impl<Service: service::Service, Payload: Debug + 'static, UserHeader: Debug>
+    AckSubscriber<Service, [Payload], UserHeader>
+{
+    /// Receives the next [`AckSample`]. Samples that are waiting for their redelivery to this
+    /// group member are received before new samples. If no sample could be received [`None`] is returned.
+    /// If a failure occurs [`SubscriberReceiveError`] is returned.
+    pub fn receive(
+        &self,
+    ) -> Result<Option<AckSample<Service, [Payload], UserHeader>>, SubscriberReceiveError> {
+        self.receive_impl(|subscriber| subscriber.receive())
+    }
+}
@@ -0,0 +1,169 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::port::async_listener::AsyncListener;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let event = node.service_builder(&"MyEventName".try_into()?)
+//!     .event()
+//!     .open_or_create()?;
+//!
+//! let listener = AsyncListener::new(event.listener_builder().create()?)?;
+//!
+//! loop {
+//!     // suspends the task until an event arrives, the runtime thread is not blocked
+//!     let event_id = listener.wait_one().await?;
+//!     println!("event was triggered with id: {:?}", event_id);
+//! }
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use std::os::fd::{AsRawFd, RawFd};
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+use iceoryx2_cal::event::ListenerWaitError;
+use tokio::io::unix::{AsyncFd, AsyncFdReadyGuard};
+
+use super::event_id::EventId;
+use super::listener::Listener;
+use crate::service;
+
+/// Defines the failures that can occur when an [`AsyncListener`] is created with
+/// [`AsyncListener::new()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum AsyncListenerCreateError {
+    /// The file descriptor of the [`Listener`] could not be registered at the tokio runtime.
+    /// Either the call was not made from within a tokio runtime or the IO driver of the
+    /// runtime is not enabled.
+    RuntimeRegistrationFailure,
+}
+
+impl core::fmt::Display for AsyncListenerCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "AsyncListenerCreateError::{:?}", self)
+    }
+}
+
+impl core::error::Error for AsyncListenerCreateError {}
+
+#[derive(Debug)]
+struct ListenerFd(RawFd);
+
+impl AsRawFd for ListenerFd {
+    fn as_raw_fd(&self) -> RawFd {
+        self.0
+    }
+}
+
+/// Wraps a [`Listener`] so that it can be awaited in a tokio runtime. The file descriptor of
+/// the [`Listener`] is registered at the IO driver of the runtime, therefore no blocking
+/// thread is required while waiting for events.
+pub struct AsyncListener<Service: service::Service> {
+    // must be dropped before the listener so that the file descriptor is deregistered first
+    async_fd: AsyncFd<ListenerFd>,
+    listener: Listener<Service>,
+}
+
+impl<Service: service::Service> Debug for AsyncListener<Service> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "AsyncListener<{}> {{ listener: {:?} }}",
+            core::any::type_name::<Service>(),
+            self.listener
+        )
+    }
+}
+
+impl<Service: service::Service> AsyncListener<Service>
+where
+    Listener<Service>: FileDescriptorBased,
+{
+    /// Registers the [`Listener`] at the tokio runtime the call is made from.
+    pub fn new(listener: Listener<Service>) -> Result<Self, AsyncListenerCreateError> {
+        let fd = ListenerFd(unsafe { listener.file_descriptor().native_handle() });
+        let async_fd = match AsyncFd::new(fd) {
+            Ok(async_fd) => async_fd,
+            Err(e) => {
+                fail!(from listener, with AsyncListenerCreateError::RuntimeRegistrationFailure,
+                    "Unable to create AsyncListener since the listener could not be registered at the tokio runtime ({:?}).", e);
+            }
+        };
+
+        Ok(Self { async_fd, listener })
+    }
+}
+
+impl<Service: service::Service> AsyncListener<Service> {
+    /// Returns a reference to the underlying [`Listener`].
+    pub fn listener(&self) -> &Listener<Service> {
+        &self.listener
+    }
+
+    /// Releases the [`Listener`] from the tokio runtime and returns it.
+    pub fn into_inner(self) -> Listener<Service> {
+        let Self { async_fd, listener } = self;
+        drop(async_fd);
+        listener
+    }
+
+    async fn readable(&self) -> Result<AsyncFdReadyGuard<'_, ListenerFd>, ListenerWaitError> {
+        match self.async_fd.readable().await {
+            Ok(guard) => Ok(guard),
+            Err(e) => {
+                fail!(from self, with ListenerWaitError::InternalFailure,
+                    "Unable to wait for events since the readiness of the listener could not be acquired from the tokio runtime ({:?}).", e);
+            }
+        }
+    }
+
+    /// Suspends the task until at least one [`EventId`] was received and returns it.
+    pub async fn wait_one(&self) -> Result<EventId, ListenerWaitError> {
+        loop {
+            let mut guard = self.readable().await?;
+            match self.listener.try_wait_one()? {
+                Some(event_id) => return Ok(event_id),
+                None => guard.clear_ready(),
+            }
+        }
+    }
+
+    /// Suspends the task until at least one [`EventId`] was received and calls the provided
+    /// callback for every received [`EventId`].
+    pub async fn wait_all<F: FnMut(EventId)>(
+        &self,
+        mut callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        loop {
+            let mut guard = self.readable().await?;
+            let mut number_of_events = 0;
+            self.listener.try_wait_all(|event_id| {
+                number_of_events += 1;
+                callback(event_id)
+            })?;
+
+            // the listener was drained, new events are signalled by a new readiness
+            guard.clear_ready();
+            if number_of_events != 0 {
+                return Ok(());
+            }
+        }
+    }
+}
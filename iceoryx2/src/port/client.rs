@@ -10,5 +10,1618 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-/// TODO
-pub struct Client {}
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let server = service.server_builder().create()?;
+//! let client = service.client_builder().create()?;
+//!
+//! let request = client.loan_uninit()?;
+//! let request = request.write_payload(1234);
+//! let pending_response = request.send()?;
+//!
+//! if let Some(active_request) = server.receive()? {
+//!     active_request.send_copy(5678)?;
+//! }
+//!
+//! while let Some(response) = pending_response.receive()? {
+//!     println!("received response: {}", *response);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::cell::{RefCell, UnsafeCell};
+use core::fmt::Debug;
+use core::sync::atomic::Ordering;
+use core::time::Duration;
+use core::{alloc::Layout, marker::PhantomData, mem::MaybeUninit};
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+use iceoryx2_bb_elementary::allocator::AllocationError;
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_bb_posix::adaptive_wait::{AdaptiveTimedWaitWhileError, AdaptiveWaitBuilder};
+use iceoryx2_bb_posix::clock::{ClockType, Time};
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::named_concept::{NamedConceptMgmt, NamedConceptRemoveError};
+use iceoryx2_cal::shared_memory::ShmPointer;
+use iceoryx2_cal::shm_allocator::{AllocationStrategy, PointerOffset, ShmAllocationError};
+use iceoryx2_cal::zero_copy_connection::{
+    ZeroCopyConnection, ZeroCopyPortDetails, ZeroCopyReceiveError, ZeroCopyReceiver,
+    ZeroCopySendError, ZeroCopySender,
+};
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicUsize};
+
+#[cfg(feature = "tokio")]
+use super::async_listener::AsyncListener;
+use super::details::data_segment::{DataSegment, DataSegmentType};
+use super::details::segment_state::SegmentState;
+use super::details::server_connections::{Connection, ServerConnections};
+#[cfg(feature = "loan_diagnostics")]
+use super::loan_diagnostics::{LoanTracker, OutstandingLoan};
+use super::port_identifiers::{UniqueClientId, UniquePortId};
+use super::publisher::{
+    connections, handle_port_remove_error, RemovePubSubPortFromAllConnectionsError,
+};
+use super::update_connections::{ConnectionFailure, UpdateConnections};
+use super::LoanError;
+use crate::config;
+use crate::deterministic_profile;
+use crate::node::evidence_log::{EvidenceEntity, StateTransition};
+use crate::pending_response::PendingResponse;
+use crate::raw_sample::RawSampleMut;
+use crate::request_mut::RequestMut;
+use crate::request_mut_uninit::RequestMutUninit;
+use crate::response::Response;
+use crate::service::config_scheme::{connection_config, data_segment_config};
+use crate::service::dynamic_config::request_response::{ClientDetails, ServerDetails};
+use crate::service::header;
+use crate::service::naming_scheme::{
+    client_data_segment_name, extract_receiver_id_from_connection,
+    extract_sender_id_from_connection,
+};
+use crate::service::port_factory::client::LocalClientConfig;
+use crate::service::static_config::request_response;
+use crate::service::{self, ServiceState};
+#[cfg(feature = "tokio")]
+use iceoryx2_cal::event::ListenerWaitError;
+
+/// Defines a failure that can occur when a [`Client`] is created with
+/// [`crate::service::port_factory::client::PortFactoryClient`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ClientCreateError {
+    /// The maximum amount of [`Client`]s that can connect to a
+    /// [`Service`](crate::service::Service) is
+    /// defined in [`crate::config::Config`]. When this is exceeded no more [`Client`]s
+    /// can be created for a specific [`Service`](crate::service::Service).
+    ExceedsMaxSupportedClients,
+    /// The datasegment in which the requests of the [`Client`] are stored, could not be created.
+    UnableToCreateDataSegment,
+    /// The requested settings violate the
+    /// [`deterministic_profile`](crate::deterministic_profile) that iceoryx2 was built with.
+    ViolatesDeterministicProfile,
+}
+
+impl core::fmt::Display for ClientCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ClientCreateError::{:?}", self)
+    }
+}
+
+impl core::error::Error for ClientCreateError {}
+
+/// Failure that can be emitted when a request is sent via [`RequestMut::send()`] or
+/// [`Client::send_copy()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum RequestSendError {
+    /// A connection between the [`Client`] and a
+    /// [`Server`](crate::port::server::Server) is corrupted.
+    ConnectionCorrupted,
+    /// The [`Client`] has already the maximum number of active requests, see
+    /// [`crate::service::builder::request_response::Builder::max_active_requests()`].
+    /// A request stays active as long as its [`PendingResponse`] exists.
+    ExceedsMaxActiveRequests,
+    /// A failure occurred while acquiring memory for the request
+    LoanError(LoanError),
+    /// A failure occurred while establishing a connection to a
+    /// [`Server`](crate::port::server::Server)
+    ConnectionError(ConnectionFailure),
+    /// A fire and forget request was sent but the [`Service`](crate::service::Service) was not
+    /// created with
+    /// [`crate::service::builder::request_response::Builder::enable_fire_and_forget_requests()`].
+    FireAndForgetRequestsNotEnabled,
+}
+
+impl From<LoanError> for RequestSendError {
+    fn from(value: LoanError) -> Self {
+        RequestSendError::LoanError(value)
+    }
+}
+
+impl From<ConnectionFailure> for RequestSendError {
+    fn from(value: ConnectionFailure) -> Self {
+        RequestSendError::ConnectionError(value)
+    }
+}
+
+impl core::fmt::Display for RequestSendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "RequestSendError::{:?}", self)
+    }
+}
+
+impl core::error::Error for RequestSendError {}
+
+/// Defines the failures that can occur when a request is sent with
+/// [`Client::send_copy_async()`].
+#[cfg(feature = "tokio")]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum RequestSendAsyncError {
+    /// The request could not be sent, see [`RequestSendError`].
+    SendError(RequestSendError),
+    /// Waiting on the [`AsyncListener`] for the next notification failed.
+    WaitError(ListenerWaitError),
+}
+
+#[cfg(feature = "tokio")]
+impl From<RequestSendError> for RequestSendAsyncError {
+    fn from(value: RequestSendError) -> Self {
+        RequestSendAsyncError::SendError(value)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl From<ListenerWaitError> for RequestSendAsyncError {
+    fn from(value: ListenerWaitError) -> Self {
+        RequestSendAsyncError::WaitError(value)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl core::fmt::Display for RequestSendAsyncError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "RequestSendAsyncError::{:?}", self)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl core::error::Error for RequestSendAsyncError {}
+
+/// Defines the failure that can occur when a response is received with
+/// [`PendingResponse::receive()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ResponseReceiveError {
+    /// The maximum amount of responses that can be borrowed at the same time was reached.
+    /// A [`Response`](crate::response::Response) has to be dropped before the next one can be
+    /// received.
+    ExceedsMaxBorrowedResponses,
+    /// The data segment of the [`Server`](crate::port::server::Server) that sent the response
+    /// could not be mapped.
+    ConnectionFailure(ConnectionFailure),
+    /// The request was sent with [`RequestMut::send_with_timeout()`] and no response was
+    /// received before the timeout passed. The request is no longer active and all further
+    /// responses are discarded.
+    Timeout,
+    /// [`PendingResponse::receive_result()`] was called with an error type that the
+    /// [`Service`](crate::service::Service) does not use.
+    IncompatibleErrorType,
+    /// A [`Server`](crate::port::server::Server) sent an error response that can only be
+    /// received with [`PendingResponse::receive_result()`].
+    ReceivedErrorResponse,
+}
+
+impl From<ConnectionFailure> for ResponseReceiveError {
+    fn from(value: ConnectionFailure) -> Self {
+        ResponseReceiveError::ConnectionFailure(value)
+    }
+}
+
+impl core::fmt::Display for ResponseReceiveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ResponseReceiveError::{:?}", self)
+    }
+}
+
+impl core::error::Error for ResponseReceiveError {}
+
+/// Defines the failures that can occur when a response is received with
+/// [`PendingResponse::receive_async()`].
+#[cfg(feature = "tokio")]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ResponseReceiveAsyncError {
+    /// The response could not be received, see [`ResponseReceiveError`].
+    ReceiveError(ResponseReceiveError),
+    /// Waiting on the [`AsyncListener`] for the next notification failed.
+    WaitError(ListenerWaitError),
+}
+
+#[cfg(feature = "tokio")]
+impl From<ResponseReceiveError> for ResponseReceiveAsyncError {
+    fn from(value: ResponseReceiveError) -> Self {
+        ResponseReceiveAsyncError::ReceiveError(value)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl From<ListenerWaitError> for ResponseReceiveAsyncError {
+    fn from(value: ListenerWaitError) -> Self {
+        ResponseReceiveAsyncError::WaitError(value)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl core::fmt::Display for ResponseReceiveAsyncError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ResponseReceiveAsyncError::{:?}", self)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl core::error::Error for ResponseReceiveAsyncError {}
+
+/// Defines the failures that can occur when a request is sent and its response is awaited with
+/// [`Client::call()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum CallError {
+    /// The request could not be sent.
+    SendError(RequestSendError),
+    /// The response could not be received.
+    ReceiveError(ResponseReceiveError),
+    /// The request was not delivered to any [`Server`](crate::port::server::Server).
+    NoConnectedServer,
+    /// No response was received before the timeout passed.
+    Timeout,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+}
+
+impl From<RequestSendError> for CallError {
+    fn from(value: RequestSendError) -> Self {
+        CallError::SendError(value)
+    }
+}
+
+impl From<ResponseReceiveError> for CallError {
+    fn from(value: ResponseReceiveError) -> Self {
+        CallError::ReceiveError(value)
+    }
+}
+
+impl core::fmt::Display for CallError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "CallError::{:?}", self)
+    }
+}
+
+impl core::error::Error for CallError {}
+
+/// A request that was sent with [`RequestMut::send()`] or [`RequestMut::send_with_timeout()`]
+/// and whose [`PendingResponse`] still exists.
+#[derive(Debug, Clone, Copy)]
+struct ActiveRequestDetails {
+    request_id: u64,
+    deadline: Option<(Time, Duration)>,
+    has_received_response: bool,
+}
+
+impl ActiveRequestDetails {
+    fn is_expired(&self) -> bool {
+        if self.has_received_response {
+            return false;
+        }
+
+        match self.deadline {
+            Some((start, timeout)) => match start.elapsed() {
+                Ok(elapsed) => timeout <= elapsed,
+                Err(_) => true,
+            },
+            None => false,
+        }
+    }
+}
+
+/// A response that was received for another active request than the one that called
+/// [`PendingResponse::receive()`]. It is kept until its own [`PendingResponse`] receives it.
+#[derive(Debug)]
+struct StashedResponse<Service: service::Service> {
+    request_id: u64,
+    connection: Arc<Connection<Service>>,
+    offset: PointerOffset,
+    address: usize,
+}
+
+#[derive(Debug)]
+pub(crate) struct ClientBackend<Service: service::Service> {
+    segment_states: Vec<SegmentState>,
+    data_segment: DataSegment<Service>,
+    config: LocalClientConfig,
+    port_id: UniqueClientId,
+    service_state: Arc<ServiceState<Service>>,
+
+    server_connections: ServerConnections<Service>,
+    server_list_state: UnsafeCell<ContainerState<ServerDetails>>,
+    request_id_counter: IoxAtomicU64,
+    loan_counter: IoxAtomicUsize,
+    #[cfg(feature = "loan_diagnostics")]
+    loan_tracker: LoanTracker,
+    active_requests: RefCell<Vec<ActiveRequestDetails>>,
+    expired_requests: RefCell<Vec<u64>>,
+    stashed_responses: RefCell<Vec<StashedResponse<Service>>>,
+}
+
+impl<Service: service::Service> ClientBackend<Service> {
+    #[cfg_attr(feature = "loan_diagnostics", track_caller)]
+    fn allocate(&self, layout: Layout) -> Result<ShmPointer, LoanError> {
+        let msg = "Unable to allocate request with";
+        let max_loans = self.static_config().max_active_requests;
+
+        if self.loan_counter.load(Ordering::Relaxed) >= max_loans {
+            fail!(from self, with LoanError::ExceedsMaxLoans,
+                "{} {:?} since already {} requests were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned request to loan another request.{}",
+                msg, layout, self.loan_counter.load(Ordering::Relaxed), max_loans,
+                self.oldest_loan_description());
+        }
+
+        self.retrieve_returned_samples();
+
+        let shm_pointer = match self.data_segment.allocate(layout) {
+            Ok(shm_pointer) => shm_pointer,
+            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
+                fail!(from self, with LoanError::OutOfMemory,
+                    "{} {:?} since the underlying shared memory is out of memory.", msg, layout);
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge))
+            | Err(ShmAllocationError::AllocationError(AllocationError::AlignmentFailure)) => {
+                fatal_panic!(from self, "{} {:?} since the system seems to be corrupted.", msg, layout);
+            }
+            Err(v) => {
+                fail!(from self, with LoanError::InternalFailure,
+                    "{} {:?} since an internal failure occurred ({:?}).", msg, layout, v);
+            }
+        };
+
+        if self.borrow_sample(shm_pointer.offset) != 0 {
+            fatal_panic!(from self,
+                "{} since the allocated request is already in use! This should never happen!", msg);
+        }
+
+        self.loan_counter.fetch_add(1, Ordering::Relaxed);
+        #[cfg(feature = "loan_diagnostics")]
+        self.loan_tracker
+            .add(shm_pointer.offset, core::panic::Location::caller());
+        Ok(shm_pointer)
+    }
+
+    fn oldest_loan_description(&self) -> String {
+        #[cfg(feature = "loan_diagnostics")]
+        return self.loan_tracker.oldest_loan_description();
+        #[cfg(not(feature = "loan_diagnostics"))]
+        String::new()
+    }
+
+    fn segment_state(&self, offset: PointerOffset) -> &SegmentState {
+        &self.segment_states[offset.segment_id().value() as usize]
+    }
+
+    fn borrow_sample(&self, offset: PointerOffset) -> u64 {
+        let segment_state = self.segment_state(offset);
+        if segment_state.payload_size() == 0 {
+            segment_state.set_payload_size(self.data_segment.bucket_size(offset.segment_id()));
+        }
+        segment_state.borrow_sample(offset.offset())
+    }
+
+    fn release_sample(&self, offset: PointerOffset) {
+        if self.segment_state(offset).release_sample(offset.offset()) == 1 {
+            unsafe { self.data_segment.deallocate_bucket(offset) };
+        }
+    }
+
+    fn retrieve_returned_samples(&self) {
+        for i in 0..self.server_connections.len() {
+            if let Some(ref connection) = self.server_connections.get(i) {
+                loop {
+                    match connection.request_sender.reclaim() {
+                        Ok(Some(offset)) => self.release_sample(offset),
+                        Ok(None) => break,
+                        Err(e) => {
+                            warn!(from self, "Unable to reclaim requests from connection {:?} due to {:?}. This may lead to a situation where no more requests will be delivered to this connection.", connection, e);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn remove_connection(&self, i: usize) {
+        if let Some(connection) = self.server_connections.get(i) {
+            // # SAFETY: the receiver no longer exist, therefore we can
+            //           reacquire all delivered requests
+            unsafe {
+                connection
+                    .request_sender
+                    .acquire_used_offsets(|offset| self.release_sample(offset))
+            };
+
+            self.server_connections.remove(i);
+        }
+    }
+
+    pub(crate) fn return_loaned_request(&self, offset: PointerOffset) {
+        self.release_sample(offset);
+        self.loan_counter.fetch_sub(1, Ordering::Relaxed);
+        #[cfg(feature = "loan_diagnostics")]
+        self.loan_tracker.remove(offset);
+    }
+
+    pub(crate) fn static_config(&self) -> &request_response::StaticConfig {
+        &self.server_connections.static_config
+    }
+
+    fn populate_server_channels(&self) -> Result<(), ConnectionFailure> {
+        let mut visited_indices = vec![];
+        visited_indices.resize(self.server_connections.capacity(), None);
+
+        unsafe {
+            (*self.server_list_state.get()).for_each(|h, details| {
+                visited_indices[h.index() as usize] = Some(*details);
+                CallbackProgression::Continue
+            })
+        };
+
+        let mut result = Ok(());
+        for (i, index) in visited_indices.iter().enumerate() {
+            match index {
+                Some(server_details) => {
+                    let create_connection = match self.server_connections.get(i) {
+                        None => true,
+                        Some(connection) => {
+                            let is_outdated =
+                                connection.server_port_id != server_details.server_port_id;
+                            if is_outdated {
+                                self.remove_connection(i);
+                            }
+                            is_outdated
+                        }
+                    };
+
+                    if create_connection {
+                        match self.server_connections.create(i, server_details) {
+                            Ok(()) => {
+                                if let Some(callback) = &self.config.server_connected_callback {
+                                    callback.call(server_details.server_port_id);
+                                }
+                            }
+                            Err(e) => {
+                                warn!(from self,
+                                    "Unable to establish connection to new server {:?}.",
+                                    server_details.server_port_id);
+                                result = Err(e);
+                            }
+                        }
+                    }
+                }
+                None => self.remove_connection(i),
+            }
+        }
+
+        result
+    }
+
+    pub(crate) fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        // a closed port establishes no new connections
+        if self
+            .service_state
+            .shared_node
+            .closed_ports()
+            .is_closed(UniquePortId::Client(self.port_id))
+        {
+            return Ok(());
+        }
+
+        if unsafe {
+            self.service_state
+                .dynamic_storage
+                .get()
+                .request_response()
+                .servers
+                .update_state(&mut *self.server_list_state.get())
+        } {
+            fail!(from self, when self.populate_server_channels(),
+                "Connections were updated only partially since at least one connection to a Server port failed.");
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn prepare_connections(&self) -> Result<usize, ConnectionFailure> {
+        // a closed port establishes no new connections
+        if !self
+            .service_state
+            .shared_node
+            .closed_ports()
+            .is_closed(UniquePortId::Client(self.port_id))
+        {
+            unsafe {
+                self.service_state
+                    .dynamic_storage
+                    .get()
+                    .request_response()
+                    .servers
+                    .update_state(&mut *self.server_list_state.get())
+            };
+
+            // populates the channels even when the server list is unchanged, so that
+            // connections that failed previously are established again
+            fail!(from self, when self.populate_server_channels(),
+                "Unable to prepare all connections since at least one connection to a Server port failed.");
+        }
+
+        Ok((0..self.server_connections.len())
+            .filter(|i| self.server_connections.get(*i).is_some())
+            .count())
+    }
+
+    pub(crate) fn send_request(
+        &self,
+        header: &mut header::request_response::RequestHeader,
+        offset: PointerOffset,
+        fire_and_forget: bool,
+        timeout: Option<Duration>,
+    ) -> Result<(u64, usize), RequestSendError> {
+        let msg = "Unable to send request";
+        if fire_and_forget && !self.static_config().enable_fire_and_forget_requests {
+            fail!(from self, with RequestSendError::FireAndForgetRequestsNotEnabled,
+                "{} since the service does not support fire and forget requests.", msg);
+        }
+
+        fail!(from self, when self.update_connections(),
+            "{} since the connections could not be updated.", msg);
+
+        self.expire_requests();
+
+        let max_active_requests = self.static_config().max_active_requests;
+        if !fire_and_forget && self.active_requests.borrow().len() >= max_active_requests {
+            fail!(from self, with RequestSendError::ExceedsMaxActiveRequests,
+                "{} since it would exceed the maximum number of {} active requests.",
+                msg, max_active_requests);
+        }
+
+        let request_id = self.request_id_counter.fetch_add(1, Ordering::Relaxed);
+        header.set_request_id(request_id);
+        header.set_fire_and_forget(fire_and_forget);
+
+        self.retrieve_returned_samples();
+
+        let sample_size = self.segment_state(offset).payload_size();
+        let mut number_of_server_connections = 0;
+        for i in 0..self.server_connections.len() {
+            if let Some(ref connection) = self.server_connections.get(i) {
+                match connection.request_sender.try_send(offset, sample_size) {
+                    Ok(overflow) => {
+                        self.borrow_sample(offset);
+                        number_of_server_connections += 1;
+
+                        if let Some(old) = overflow {
+                            self.release_sample(old);
+                        }
+                    }
+                    Err(ZeroCopySendError::ReceiveBufferFull)
+                    | Err(ZeroCopySendError::UsedChunkListFull)
+                    | Err(ZeroCopySendError::ExceedsMaxSampleRunLength) => {
+                        warn!(from self,
+                            "Unable to deliver request to server {:?} since its receive buffer is full.",
+                            connection.server_port_id);
+                    }
+                    Err(ZeroCopySendError::ConnectionCorrupted) => {
+                        fail!(from self, with RequestSendError::ConnectionCorrupted,
+                            "{} since the connection to server {:?} is corrupted.",
+                            msg, connection.server_port_id);
+                    }
+                }
+            }
+        }
+
+        if !fire_and_forget {
+            let deadline = match timeout {
+                Some(timeout) => match Time::now_with_clock(ClockType::Monotonic) {
+                    Ok(now) => Some((now, timeout)),
+                    Err(e) => {
+                        warn!(from self,
+                            "The request {} is sent without timeout since the current time could not be acquired ({:?}).",
+                            request_id, e);
+                        None
+                    }
+                },
+                None => None,
+            };
+
+            self.active_requests
+                .borrow_mut()
+                .push(ActiveRequestDetails {
+                    request_id,
+                    deadline,
+                    has_received_response: false,
+                });
+        }
+
+        Ok((request_id, number_of_server_connections))
+    }
+
+    pub(crate) fn deactivate_request(&self, request_id: u64) {
+        self.active_requests
+            .borrow_mut()
+            .retain(|request| request.request_id != request_id);
+        self.expired_requests
+            .borrow_mut()
+            .retain(|id| *id != request_id);
+
+        self.release_stashed_responses(request_id);
+    }
+
+    fn release_stashed_responses(&self, request_id: u64) {
+        self.stashed_responses.borrow_mut().retain(|response| {
+            if response.request_id != request_id {
+                return true;
+            }
+
+            response.connection.release_response(response.offset);
+            false
+        });
+    }
+
+    /// Closes all active requests whose timeout has passed without receiving a response
+    /// and releases all of their resources.
+    fn expire_requests(&self) {
+        let mut expired_request_ids = Vec::new();
+        self.active_requests.borrow_mut().retain(|request| {
+            if !request.is_expired() {
+                return true;
+            }
+
+            expired_request_ids.push(request.request_id);
+            false
+        });
+
+        if expired_request_ids.is_empty() {
+            return;
+        }
+
+        for request_id in &expired_request_ids {
+            self.release_stashed_responses(*request_id);
+        }
+
+        self.service_state
+            .dynamic_storage
+            .get()
+            .request_response()
+            .increment_expired_requests(expired_request_ids.len() as u64);
+        self.expired_requests
+            .borrow_mut()
+            .extend_from_slice(&expired_request_ids);
+    }
+
+    pub(crate) fn is_request_expired(&self, request_id: u64) -> bool {
+        self.expire_requests();
+        self.expired_requests.borrow().contains(&request_id)
+    }
+
+    fn is_request_active(&self, request_id: u64) -> bool {
+        self.active_requests
+            .borrow()
+            .iter()
+            .any(|request| request.request_id == request_id)
+    }
+
+    fn mark_response_received(&self, request_id: u64) {
+        if let Some(request) = self
+            .active_requests
+            .borrow_mut()
+            .iter_mut()
+            .find(|request| request.request_id == request_id)
+        {
+            request.has_received_response = true;
+        }
+    }
+
+    pub(crate) fn number_of_active_requests(&self) -> usize {
+        self.expire_requests();
+        self.active_requests.borrow().len()
+    }
+
+    pub(crate) fn receive_response(
+        &self,
+        request_id: u64,
+    ) -> Result<Option<(Arc<Connection<Service>>, PointerOffset, usize)>, ResponseReceiveError>
+    {
+        let msg = "Unable to receive response";
+
+        if self.is_request_expired(request_id) {
+            fail!(from self, with ResponseReceiveError::Timeout,
+                "{} since the request {} timed out before a response was received.", msg, request_id);
+        }
+
+        {
+            let mut stashed_responses = self.stashed_responses.borrow_mut();
+            if let Some(pos) = stashed_responses
+                .iter()
+                .position(|response| response.request_id == request_id)
+            {
+                let response = stashed_responses.remove(pos);
+                self.mark_response_received(request_id);
+                return Ok(Some((
+                    response.connection,
+                    response.offset,
+                    response.address,
+                )));
+            }
+        }
+
+        for i in 0..self.server_connections.len() {
+            if let Some(ref connection) = self.server_connections.get(i) {
+                loop {
+                    let offset = match connection.response_receiver.receive() {
+                        Ok(Some(offset)) => offset,
+                        Ok(None) => break,
+                        Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                            fail!(from self, with ResponseReceiveError::ExceedsMaxBorrowedResponses,
+                                "{} since it would exceed the maximum of {} borrowed responses.",
+                                msg, connection.response_receiver.max_borrowed_samples());
+                        }
+                    };
+
+                    let address = match connection
+                        .data_segment
+                        .register_and_translate_offset(offset)
+                    {
+                        Ok(address) => address,
+                        Err(e) => {
+                            fail!(from self, with ResponseReceiveError::ConnectionFailure(ConnectionFailure::UnableToMapPublishersDataSegment(e)),
+                                "{} since the received offset {:?} from server {:?} could not be registered and translated.",
+                                msg, offset, connection.server_port_id);
+                        }
+                    };
+
+                    let response_request_id = unsafe {
+                        (*(address as *const header::request_response::ResponseHeader)).request_id()
+                    };
+
+                    if response_request_id == request_id {
+                        self.mark_response_received(request_id);
+                        return Ok(Some((connection.clone(), offset, address)));
+                    }
+
+                    if self.is_request_active(response_request_id) {
+                        self.mark_response_received(response_request_id);
+                        self.stashed_responses.borrow_mut().push(StashedResponse {
+                            request_id: response_request_id,
+                            connection: connection.clone(),
+                            offset,
+                            address,
+                        });
+                    } else {
+                        // nobody waits for the response anymore
+                        connection.release_response(offset);
+                    }
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Puts a received response back so that it is the next one
+    /// [`ClientBackend::receive_response()`] returns for its request.
+    pub(crate) fn unreceive_response(
+        &self,
+        request_id: u64,
+        connection: Arc<Connection<Service>>,
+        offset: PointerOffset,
+        address: usize,
+    ) {
+        self.stashed_responses.borrow_mut().insert(
+            0,
+            StashedResponse {
+                request_id,
+                connection,
+                offset,
+                address,
+            },
+        );
+    }
+}
+
+/// Sends requests to a [`Server`](crate::port::server::Server) and receives the corresponding
+/// responses via the [`PendingResponse`] that is returned when the request is sent.
+#[derive(Debug)]
+pub struct Client<
+    Service: service::Service,
+    RequestPayload: Debug + ?Sized,
+    RequestHeader: Debug,
+    ResponsePayload: Debug + ?Sized,
+    ResponseHeader: Debug,
+> {
+    backend: Arc<ClientBackend<Service>>,
+    dynamic_client_handle: Option<ContainerHandle>,
+    _request_payload: PhantomData<RequestPayload>,
+    _request_header: PhantomData<RequestHeader>,
+    _response_payload: PhantomData<ResponsePayload>,
+    _response_header: PhantomData<ResponseHeader>,
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > Drop for Client<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    fn drop(&mut self) {
+        if let Some(handle) = self.dynamic_client_handle {
+            let service_state = &self.backend.service_state;
+            service_state
+                .shared_node
+                .closed_ports()
+                .release_unless_closed(UniquePortId::Client(self.id()), || {
+                    service_state.shared_node.record_evidence(
+                        EvidenceEntity::Port,
+                        StateTransition::Destroyed,
+                        format_args!(
+                            "{:?} of service {}",
+                            self.id(),
+                            service_state.static_config.name()
+                        ),
+                    );
+                    service_state
+                        .dynamic_storage
+                        .get()
+                        .request_response()
+                        .release_client_handle(handle)
+                });
+        }
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > Client<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    pub(crate) fn new(
+        service: &Service,
+        static_config: &request_response::StaticConfig,
+        config: LocalClientConfig,
+    ) -> Result<Self, ClientCreateError> {
+        let msg = "Unable to create Client port";
+        let origin = "Client::new()";
+
+        if !deterministic_profile::allows_allocation_strategy(config.allocation_strategy) {
+            fail!(from origin, with ClientCreateError::ViolatesDeterministicProfile,
+                "{} since the allocation strategy {:?} violates the deterministic profile.",
+                msg, config.allocation_strategy);
+        }
+
+        let port_id = UniqueClientId::new();
+        let server_list = &service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .servers;
+
+        let number_of_requests = static_config.number_of_requests_per_client();
+        let data_segment_type =
+            DataSegmentType::new_from_allocation_strategy(config.allocation_strategy);
+        let max_number_of_segments =
+            DataSegment::<Service>::max_number_of_segments(data_segment_type);
+        let global_config = service.__internal_state().shared_node.config();
+        let data_segment = fail!(from origin,
+                when DataSegment::create_segment(
+                    &client_data_segment_name(&port_id),
+                    global_config,
+                    static_config
+                        .request_message_type_details
+                        .sample_layout(config.initial_max_slice_len),
+                    number_of_requests,
+                    data_segment_type,
+                    config.allocation_strategy),
+                with ClientCreateError::UnableToCreateDataSegment,
+                "{} since the data segment could not be created.", msg);
+
+        let client_details = ClientDetails {
+            client_port_id: port_id,
+            node_id: *service.__internal_state().shared_node.id(),
+            number_of_requests,
+            response_buffer_size: static_config.response_buffer_size_per_client(),
+            max_slice_len: config.initial_max_slice_len,
+            data_segment_type,
+            max_number_of_segments,
+        };
+
+        let backend = Arc::new(ClientBackend {
+            segment_states: (0..max_number_of_segments)
+                .map(|_| SegmentState::new(number_of_requests))
+                .collect(),
+            data_segment,
+            config,
+            port_id,
+            service_state: service.__internal_state().clone(),
+            server_connections: ServerConnections::new(
+                server_list.capacity(),
+                service.__internal_state().shared_node.clone(),
+                port_id,
+                static_config,
+                number_of_requests,
+                max_number_of_segments,
+            ),
+            server_list_state: UnsafeCell::new(unsafe { server_list.get_state() }),
+            request_id_counter: IoxAtomicU64::new(0),
+            loan_counter: IoxAtomicUsize::new(0),
+            #[cfg(feature = "loan_diagnostics")]
+            loan_tracker: LoanTracker::default(),
+            active_requests: RefCell::new(Vec::with_capacity(static_config.max_active_requests)),
+            expired_requests: RefCell::new(Vec::new()),
+            stashed_responses: RefCell::new(Vec::new()),
+        });
+
+        let mut new_self = Self {
+            backend,
+            dynamic_client_handle: None,
+            _request_payload: PhantomData,
+            _request_header: PhantomData,
+            _response_payload: PhantomData,
+            _response_header: PhantomData,
+        };
+
+        if let Err(e) = new_self.backend.populate_server_channels() {
+            warn!(from new_self, "The new Client port is unable to connect to every Server port, caused by {:?}.", e);
+        }
+
+        core::sync::atomic::compiler_fence(Ordering::SeqCst);
+
+        // !MUST! be the last task otherwise a client is added to the dynamic config without the
+        // creation of all required resources
+        let dynamic_client_handle = match service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .add_client_id(client_details)
+        {
+            Some(handle) => handle,
+            None => {
+                fail!(from origin, with ClientCreateError::ExceedsMaxSupportedClients,
+                    "{} since it would exceed the maximum supported amount of clients of {}.",
+                    msg, static_config.max_clients);
+            }
+        };
+
+        new_self.dynamic_client_handle = Some(dynamic_client_handle);
+
+        new_self.backend.service_state.shared_node.record_evidence(
+            EvidenceEntity::Port,
+            StateTransition::Created,
+            format_args!(
+                "{:?} of service {}",
+                new_self.id(),
+                new_self.backend.service_state.static_config.name()
+            ),
+        );
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`UniqueClientId`] of the [`Client`]
+    pub fn id(&self) -> UniqueClientId {
+        self.backend.port_id
+    }
+
+    /// Establishes the connections to all [`Server`](crate::port::server::Server)s of the
+    /// service eagerly and returns the number of connected
+    /// [`Server`](crate::port::server::Server)s. Connections are otherwise established lazily
+    /// when a request is sent, so that the first request to a new
+    /// [`Server`](crate::port::server::Server) pays the connection setup. In contrast to
+    /// [`UpdateConnections::update_connections()`], connections that failed before are
+    /// established again.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// let client = service.client_builder()
+    ///     .set_server_connected_callback(Some(|server_id| {
+    ///         println!("connected to server {:?}", server_id);
+    ///     }))
+    ///     .create()?;
+    ///
+    /// let server = service.server_builder().create()?;
+    /// let number_of_servers = client.prepare_connections()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn prepare_connections(&self) -> Result<usize, ConnectionFailure> {
+        self.backend.prepare_connections()
+    }
+
+    /// Returns all requests that are currently loaned from the [`Client`] together with the
+    /// code location and the point in time of the loan.
+    #[cfg(feature = "loan_diagnostics")]
+    pub fn outstanding_loans(&self) -> Vec<OutstandingLoan> {
+        self.backend.loan_tracker.outstanding_loans()
+    }
+
+    /// Returns the number of requests whose [`PendingResponse`] still exists.
+    pub fn number_of_active_requests(&self) -> usize {
+        self.backend.number_of_active_requests()
+    }
+
+    /// Returns the maximum slice length configured for this [`Client`].
+    pub fn initial_max_slice_len(&self) -> usize {
+        self.backend.config.initial_max_slice_len
+    }
+
+    fn user_header_ptr(&self, header: *const header::request_response::RequestHeader) -> *const u8 {
+        self.backend
+            .static_config()
+            .request_message_type_details
+            .user_header_ptr_from_header(header.cast())
+            .cast()
+    }
+
+    fn payload_ptr(&self, header: *const header::request_response::RequestHeader) -> *const u8 {
+        self.backend
+            .static_config()
+            .request_message_type_details
+            .payload_ptr_from_header(header.cast())
+            .cast()
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > Client<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    /// Loans memory for a request without initializing the payload. The request is returned
+    /// when the [`RequestMutUninit`] or [`RequestMut`] goes out of scope without being sent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let client = service.client_builder().create()?;
+    ///
+    /// let request = client.loan_uninit()?;
+    /// let request = request.write_payload(42);
+    /// let pending_response = request.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "loan_diagnostics", track_caller)]
+    pub fn loan_uninit(
+        &self,
+    ) -> Result<
+        RequestMutUninit<
+            Service,
+            MaybeUninit<RequestPayload>,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        LoanError,
+    > {
+        let shm_pointer = self.backend.allocate(
+            self.backend
+                .static_config()
+                .request_message_type_details
+                .sample_layout(1),
+        )?;
+
+        let header_ptr = shm_pointer.data_ptr as *mut header::request_response::RequestHeader;
+        let user_header_ptr = self.user_header_ptr(header_ptr) as *mut RequestHeader;
+        let payload_ptr = self.payload_ptr(header_ptr) as *mut MaybeUninit<RequestPayload>;
+
+        unsafe {
+            header_ptr.write(header::request_response::RequestHeader::new(
+                self.backend.port_id,
+                1,
+            ))
+        };
+
+        let ptr = unsafe { RawSampleMut::new_unchecked(header_ptr, user_header_ptr, payload_ptr) };
+
+        Ok(RequestMutUninit::new(
+            &self.backend,
+            ptr,
+            shm_pointer.offset,
+        ))
+    }
+
+    /// Copies the provided value into a new request and sends it to all connected
+    /// [`Server`](crate::port::server::Server)s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let client = service.client_builder().create()?;
+    ///
+    /// let pending_response = client.send_copy(1234)?;
+    /// println!("request {} was sent", pending_response.request_id());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "loan_diagnostics", track_caller)]
+    pub fn send_copy(
+        &self,
+        value: RequestPayload,
+    ) -> Result<
+        PendingResponse<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>,
+        RequestSendError,
+    > {
+        let msg = "Unable to send copy of request";
+        let request = fail!(from self, when self.loan_uninit(),
+                                "{} since the loan of the request failed.", msg);
+
+        request.write_payload(value).send()
+    }
+
+    /// Copies the provided value into a new request and sends it asynchronously. When no
+    /// [`Server`](crate::port::server::Server) is connected the task is suspended until the
+    /// provided [`AsyncListener`] is notified, e.g. by a
+    /// [`Notifier`](crate::port::notifier::Notifier) that the
+    /// [`Server`](crate::port::server::Server) side triggers after a
+    /// [`Server`](crate::port::server::Server) was created. The runtime thread is not blocked
+    /// while waiting.
+    ///
+    /// Only available with the `tokio` feature.
+    #[cfg(feature = "tokio")]
+    pub async fn send_copy_async(
+        &self,
+        value: RequestPayload,
+        listener: &AsyncListener<Service>,
+    ) -> Result<
+        PendingResponse<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>,
+        RequestSendAsyncError,
+    > {
+        while self.prepare_connections().map_err(RequestSendError::from)? == 0 {
+            listener.wait_all(|_| {}).await?;
+        }
+
+        Ok(self.send_copy(value)?)
+    }
+
+    /// Copies the provided value into a new fire and forget request and sends it to all
+    /// connected [`Server`](crate::port::server::Server)s, see
+    /// [`RequestMut::send_and_forget()`]. Returns the number of
+    /// [`Server`](crate::port::server::Server)s that received the request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .enable_fire_and_forget_requests(true)
+    /// #     .open_or_create()?;
+    /// #
+    /// # let client = service.client_builder().create()?;
+    ///
+    /// let number_of_servers = client.send_copy_and_forget(1234)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_copy_and_forget(&self, value: RequestPayload) -> Result<usize, RequestSendError> {
+        let msg = "Unable to send copy of fire and forget request";
+        let request = fail!(from self, when self.loan_uninit(),
+                                "{} since the loan of the request failed.", msg);
+
+        request.write_payload(value).send_and_forget()
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseHeader: Debug,
+    > Client<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    /// Copies the provided value into a new request, sends it to all connected
+    /// [`Server`](crate::port::server::Server)s and waits at most for the provided timeout
+    /// until the first [`Response`] arrives. The request is closed afterwards and all
+    /// further responses are discarded. Since the [`Server`](crate::port::server::Server)
+    /// does not announce its responses, the [`Client`] waits adaptively like
+    /// [`Subscriber::wait_for_readiness()`](crate::port::subscriber::Subscriber::wait_for_readiness()).
+    ///
+    /// For streams of responses or multiple [`Server`](crate::port::server::Server)s use
+    /// [`Client::send_copy()`] and [`PendingResponse::receive()`] instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::port::client::CallError;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let client = service.client_builder().create()?;
+    ///
+    /// match client.call(1234, Duration::from_millis(10)) {
+    ///     Ok(response) => println!("received response: {}", *response),
+    ///     Err(CallError::NoConnectedServer) => println!("no server available"),
+    ///     Err(e) => println!("call failed: {}", e),
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn call(
+        &self,
+        value: RequestPayload,
+        timeout: Duration,
+    ) -> Result<Response<Service, ResponsePayload, ResponseHeader>, CallError> {
+        let msg = "Unable to call the server";
+        let pending_response = fail!(from self, when self.send_copy(value),
+                "{} since the request could not be sent.", msg);
+
+        if pending_response.number_of_server_connections() == 0 {
+            fail!(from self, with CallError::NoConnectedServer,
+                "{} since the request {} was not delivered to any server.",
+                msg, pending_response.request_id());
+        }
+
+        let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                with CallError::InternalFailure,
+                "{} since the adaptive wait could not be created.", msg);
+
+        let mut response = None;
+        let wait_result = adaptive_wait.timed_wait_while(
+            || -> Result<bool, ResponseReceiveError> {
+                response = pending_response.receive()?;
+                Ok(response.is_none())
+            },
+            timeout,
+        );
+
+        match wait_result {
+            Ok(_) => match response {
+                Some(response) => Ok(response),
+                None => {
+                    fail!(from self, with CallError::Timeout,
+                        "{} since no response to the request {} was received within {:?}.",
+                        msg, pending_response.request_id(), timeout);
+                }
+            },
+            Err(AdaptiveTimedWaitWhileError::PredicateFailure(e)) => {
+                fail!(from self, with CallError::ReceiveError(e),
+                    "{} since the response could not be received ({:?}).", msg, e);
+            }
+            Err(AdaptiveTimedWaitWhileError::AdaptiveWaitError(e)) => {
+                fail!(from self, with CallError::InternalFailure,
+                    "{} since the adaptive wait failed ({:?}).", msg, e);
+            }
+        }
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug + Default,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > Client<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    /// Loans memory for a request and initializes the payload with [`Default::default()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let client = service.client_builder().create()?;
+    ///
+    /// let mut request = client.loan()?;
+    /// *request.payload_mut() = 42;
+    /// let pending_response = request.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "loan_diagnostics", track_caller)]
+    pub fn loan(
+        &self,
+    ) -> Result<
+        RequestMut<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>,
+        LoanError,
+    > {
+        Ok(self.loan_uninit()?.write_payload(RequestPayload::default()))
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > Client<Service, [RequestPayload], RequestHeader, ResponsePayload, ResponseHeader>
+{
+    /// Loans memory for a request with a slice of `slice_len` elements without initializing
+    /// the payload. The request is returned when the [`RequestMutUninit`] or [`RequestMut`]
+    /// goes out of scope without being sent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<[u64], u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let client = service.client_builder()
+    ///                         .initial_max_slice_len(32)
+    ///                         .create()?;
+    ///
+    /// let request = client.loan_slice_uninit(5)?;
+    /// let request = request.write_from_fn(|n| n as u64 * 2);
+    /// let pending_response = request.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "loan_diagnostics", track_caller)]
+    pub fn loan_slice_uninit(
+        &self,
+        slice_len: usize,
+    ) -> Result<
+        RequestMutUninit<
+            Service,
+            [MaybeUninit<RequestPayload>],
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        LoanError,
+    > {
+        let max_slice_len = self.backend.config.initial_max_slice_len;
+        if self.backend.config.allocation_strategy == AllocationStrategy::Static
+            && max_slice_len < slice_len
+        {
+            fail!(from self, with LoanError::ExceedsMaxLoanSize,
+                "Unable to loan slice with {} elements since it would exceed the max supported slice length of {}.",
+                slice_len, max_slice_len);
+        }
+
+        let shm_pointer = self.backend.allocate(
+            self.backend
+                .static_config()
+                .request_message_type_details
+                .sample_layout(slice_len),
+        )?;
+
+        let header_ptr = shm_pointer.data_ptr as *mut header::request_response::RequestHeader;
+        let user_header_ptr = self.user_header_ptr(header_ptr) as *mut RequestHeader;
+        let payload_ptr = self.payload_ptr(header_ptr) as *mut MaybeUninit<RequestPayload>;
+
+        unsafe {
+            header_ptr.write(header::request_response::RequestHeader::new(
+                self.backend.port_id,
+                slice_len as _,
+            ))
+        };
+
+        let ptr = unsafe {
+            RawSampleMut::new_unchecked(
+                header_ptr,
+                user_header_ptr,
+                core::slice::from_raw_parts_mut(payload_ptr, slice_len),
+            )
+        };
+
+        Ok(RequestMutUninit::<
+            Service,
+            [MaybeUninit<RequestPayload>],
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >::new(&self.backend, ptr, shm_pointer.offset))
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug + Default,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > Client<Service, [RequestPayload], RequestHeader, ResponsePayload, ResponseHeader>
+{
+    /// Loans memory for a request with a slice of `slice_len` elements and initializes every
+    /// element with [`Default::default()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<[u64], u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let client = service.client_builder()
+    ///                         .initial_max_slice_len(32)
+    ///                         .create()?;
+    ///
+    /// let mut request = client.loan_slice(5)?;
+    /// request.payload_mut()[2] = 42;
+    /// let pending_response = request.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "loan_diagnostics", track_caller)]
+    pub fn loan_slice(
+        &self,
+        slice_len: usize,
+    ) -> Result<
+        RequestMut<Service, [RequestPayload], RequestHeader, ResponsePayload, ResponseHeader>,
+        LoanError,
+    > {
+        Ok(self
+            .loan_slice_uninit(slice_len)?
+            .write_from_fn(|_| RequestPayload::default()))
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > UpdateConnections
+    for Client<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.backend.update_connections()
+    }
+}
+
+pub(crate) unsafe fn remove_data_segment_of_client<Service: service::Service>(
+    port_id: &UniqueClientId,
+    config: &config::Config,
+) -> Result<(), NamedConceptRemoveError> {
+    let origin = format!(
+        "remove_data_segment_of_client::<{}>::({:?})",
+        core::any::type_name::<Service>(),
+        port_id
+    );
+
+    fail!(from origin, when <Service::SharedMemory as NamedConceptMgmt>::remove_cfg(
+            &client_data_segment_name(port_id),
+            &data_segment_config::<Service>(config),
+        ), "Unable to remove the clients data segment."
+    );
+
+    Ok(())
+}
+
+pub(crate) unsafe fn remove_client_from_all_connections<Service: service::Service>(
+    port_id: &UniqueClientId,
+    config: &config::Config,
+) -> Result<(), RemovePubSubPortFromAllConnectionsError> {
+    let origin = format!(
+        "remove_client_from_all_connections::<{}>::({:?})",
+        core::any::type_name::<Service>(),
+        port_id
+    );
+    let msg = "Unable to remove the client from all connections";
+
+    let connection_config = connection_config::<Service>(config);
+    let connection_list = connections::<Service>(&origin, msg, &connection_config)?;
+
+    let mut ret_val = Ok(());
+    for connection in connection_list {
+        // the client is the sender of its request connections and the receiver of its
+        // response connections
+        let result = if extract_sender_id_from_connection(&connection) == port_id.value() {
+            handle_port_remove_error(
+                Service::Connection::remove_sender(&connection, &connection_config),
+                &origin,
+                msg,
+                &connection,
+            )
+        } else if extract_receiver_id_from_connection(&connection) == port_id.value() {
+            handle_port_remove_error(
+                Service::Connection::remove_receiver(&connection, &connection_config),
+                &origin,
+                msg,
+                &connection,
+            )
+        } else {
+            Ok(())
+        };
+
+        if ret_val.is_ok() {
+            ret_val = result;
+        }
+    }
+
+    ret_val
+}
@@ -0,0 +1,126 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Describes the current state of the data segment of a
+//! [`Publisher`](crate::port::publisher::Publisher). It helps to tune the capacity settings
+//! like [`max_loaned_samples`](crate::service::port_factory::publisher::PortFactoryPublisher::max_loaned_samples())
+//! or the [`AllocationStrategy`](iceoryx2_cal::shm_allocator::AllocationStrategy).
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #     .publish_subscribe::<u64>()
+//! #     .open_or_create()?;
+//! let publisher = service.publisher_builder().create()?;
+//! let sample = publisher.loan_uninit()?;
+//!
+//! let info = publisher.data_segment_info();
+//! println!("used buckets: {}, free buckets: {}, total bytes: {}",
+//!     info.number_of_used_buckets(), info.number_of_free_buckets(), info.total_bytes());
+//!
+//! for segment in info.segments() {
+//!     println!("segment {} with bucket size {}", segment.segment_id(), segment.bucket_size());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+/// The state of a single shared memory segment of the data segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SegmentInfo {
+    pub(crate) segment_id: u8,
+    pub(crate) bucket_size: usize,
+    pub(crate) number_of_buckets: usize,
+    pub(crate) number_of_used_buckets: usize,
+}
+
+impl SegmentInfo {
+    /// Returns the id of the segment.
+    pub fn segment_id(&self) -> u8 {
+        self.segment_id
+    }
+
+    /// Returns the size in bytes of a single bucket, the memory of one sample.
+    pub fn bucket_size(&self) -> usize {
+        self.bucket_size
+    }
+
+    /// Returns the number of buckets of the segment.
+    pub fn number_of_buckets(&self) -> usize {
+        self.number_of_buckets
+    }
+
+    /// Returns the number of buckets that are currently loaned, sent or not yet reclaimed.
+    pub fn number_of_used_buckets(&self) -> usize {
+        self.number_of_used_buckets
+    }
+
+    /// Returns the number of buckets that are currently not in use.
+    pub fn number_of_free_buckets(&self) -> usize {
+        self.number_of_buckets - self.number_of_used_buckets
+    }
+
+    /// Returns the number of bytes that are occupied by all buckets of the segment.
+    pub fn total_bytes(&self) -> usize {
+        self.bucket_size * self.number_of_buckets
+    }
+}
+
+/// The state of the data segment of a [`Publisher`](crate::port::publisher::Publisher),
+/// acquired with [`Publisher::data_segment_info()`](crate::port::publisher::Publisher::data_segment_info()).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataSegmentInfo {
+    pub(crate) is_dynamic: bool,
+    pub(crate) segments: Vec<SegmentInfo>,
+}
+
+impl DataSegmentInfo {
+    /// Returns true when the data segment is resized on demand, see
+    /// [`AllocationStrategy`](iceoryx2_cal::shm_allocator::AllocationStrategy).
+    pub fn is_dynamic(&self) -> bool {
+        self.is_dynamic
+    }
+
+    /// Returns the [`SegmentInfo`] of every currently allocated segment.
+    pub fn segments(&self) -> &[SegmentInfo] {
+        &self.segments
+    }
+
+    /// Returns the number of currently allocated segments. A static data segment has always
+    /// exactly one segment.
+    pub fn number_of_segments(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Returns the number of buckets of all segments that are currently in use.
+    pub fn number_of_used_buckets(&self) -> usize {
+        self.segments.iter().map(|s| s.number_of_used_buckets).sum()
+    }
+
+    /// Returns the number of buckets of all segments that are currently not in use.
+    pub fn number_of_free_buckets(&self) -> usize {
+        self.segments
+            .iter()
+            .map(|s| s.number_of_free_buckets())
+            .sum()
+    }
+
+    /// Returns the number of bytes that are occupied by the buckets of all segments.
+    pub fn total_bytes(&self) -> usize {
+        self.segments.iter().map(|s| s.total_bytes()).sum()
+    }
+}
@@ -13,6 +13,7 @@
 use core::alloc::Layout;
 
 use iceoryx2_bb_log::fail;
+use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_cal::{
     event::NamedConceptBuilder,
     resizable_shared_memory::*,
@@ -28,6 +29,7 @@ use iceoryx2_cal::{
 
 use crate::{
     config,
+    port::data_segment_info::{DataSegmentInfo, SegmentInfo},
     service::{
         self,
         config_scheme::{data_segment_config, resizable_data_segment_config},
@@ -70,47 +72,101 @@ impl<Service: service::Service> DataSegment<Service> {
         global_config: &config::Config,
         sample_layout: Layout,
         allocation_strategy: AllocationStrategy,
+    ) -> Result<Self, SharedMemoryCreateError> {
+        Self::create_segment(
+            &data_segment_name(&details.publisher_id),
+            global_config,
+            sample_layout,
+            details.number_of_samples,
+            details.data_segment_type,
+            allocation_strategy,
+        )
+    }
+
+    pub(crate) fn create_segment(
+        segment_name: &FileName,
+        global_config: &config::Config,
+        sample_layout: Layout,
+        number_of_samples: usize,
+        data_segment_type: DataSegmentType,
+        allocation_strategy: AllocationStrategy,
+    ) -> Result<Self, SharedMemoryCreateError> {
+        match data_segment_type {
+            DataSegmentType::Static => Self::create_static_segment(
+                segment_name,
+                global_config,
+                sample_layout,
+                number_of_samples,
+            ),
+            DataSegmentType::Dynamic => Self::create_dynamic_segment(
+                segment_name,
+                global_config,
+                sample_layout,
+                number_of_samples,
+                allocation_strategy,
+            ),
+        }
+    }
+
+    fn create_dynamic_segment(
+        segment_name: &FileName,
+        global_config: &config::Config,
+        sample_layout: Layout,
+        number_of_samples: usize,
+        allocation_strategy: AllocationStrategy,
+    ) -> Result<Self, SharedMemoryCreateError> {
+        let msg = "Unable to create the dynamic data segment since the underlying shared memory could not be created.";
+        let origin = "DataSegment::create_dynamic_segment()";
+
+        let segment_config = resizable_data_segment_config::<Service>(global_config);
+        let memory = fail!(from origin,
+            when <<Service::ResizableSharedMemory as ResizableSharedMemory<
+                PoolAllocator,
+                Service::SharedMemory,
+            >>::MemoryBuilder as NamedConceptBuilder<Service::ResizableSharedMemory>>::new(
+                segment_name,
+            )
+            .config(&segment_config)
+            .max_number_of_chunks_hint(number_of_samples)
+            .max_chunk_layout_hint(sample_layout)
+            .allocation_strategy(allocation_strategy)
+            .read_only_payload(global_config.global.service.read_only_payload_mapping)
+            .huge_pages(global_config.global.service.data_segment_huge_pages)
+            .create(),
+            "{msg}");
+
+        Ok(Self {
+            memory: MemoryType::Dynamic(memory),
+        })
+    }
+
+    pub(crate) fn create_static_segment(
+        segment_name: &FileName,
+        global_config: &config::Config,
+        sample_layout: Layout,
+        number_of_samples: usize,
     ) -> Result<Self, SharedMemoryCreateError> {
         let allocator_config = shm_allocator::pool_allocator::Config {
             bucket_layout: sample_layout,
         };
-        let msg = "Unable to create the data segment since the underlying shared memory could not be created.";
-        let origin = "DataSegment::create()";
-
-        let segment_name = data_segment_name(&details.publisher_id);
-        let memory = match details.data_segment_type {
-            DataSegmentType::Static => {
-                let segment_config = data_segment_config::<Service>(global_config);
-                let memory = fail!(from origin,
-                                when <<Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder as NamedConceptBuilder<
-                                Service::SharedMemory,
-                                    >>::new(&segment_name)
-                                    .config(&segment_config)
-                                    .size(sample_layout.size() * details.number_of_samples + sample_layout.align() - 1)
-                                    .create(&allocator_config),
-                                "{msg}");
-                MemoryType::Static(memory)
-            }
-            DataSegmentType::Dynamic => {
-                let segment_config = resizable_data_segment_config::<Service>(global_config);
-                let memory = fail!(from origin,
-                    when <<Service::ResizableSharedMemory as ResizableSharedMemory<
-                        PoolAllocator,
+        let msg = "Unable to create the static data segment since the underlying shared memory could not be created.";
+        let origin = "DataSegment::create_static_segment()";
+
+        let segment_config = data_segment_config::<Service>(global_config);
+        let memory = fail!(from origin,
+                        when <<Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder as NamedConceptBuilder<
                         Service::SharedMemory,
-                    >>::MemoryBuilder as NamedConceptBuilder<Service::ResizableSharedMemory>>::new(
-                        &segment_name,
-                    )
-                    .config(&segment_config)
-                    .max_number_of_chunks_hint(details.number_of_samples)
-                    .max_chunk_layout_hint(sample_layout)
-                    .allocation_strategy(allocation_strategy)
-                    .create(),
-                    "{msg}");
-                MemoryType::Dynamic(memory)
-            }
-        };
+                            >>::new(segment_name)
+                            .config(&segment_config)
+                            .size(sample_layout.size() * number_of_samples + sample_layout.align() - 1)
+                            .read_only_payload(global_config.global.service.read_only_payload_mapping)
+                            .huge_pages(global_config.global.service.data_segment_huge_pages)
+                            .create(&allocator_config),
+                        "{msg}");
 
-        Ok(Self { memory })
+        Ok(Self {
+            memory: MemoryType::Static(memory),
+        })
     }
 
     pub(crate) fn allocate(&self, layout: Layout) -> Result<ShmPointer, ShmAllocationError> {
@@ -145,6 +201,47 @@ impl<Service: service::Service> DataSegment<Service> {
         }
     }
 
+    /// Returns the address of the chunk with the provided [`PointerOffset`] in the memory of
+    /// the [`DataSegment`].
+    pub(crate) fn chunk_address(&self, offset: PointerOffset) -> usize {
+        match &self.memory {
+            MemoryType::Static(memory) => memory.payload_start_address() + offset.offset(),
+            MemoryType::Dynamic(memory) => {
+                let mut address = 0;
+                memory.for_each_segment(|segment_id, memory| {
+                    if segment_id == offset.segment_id() {
+                        address = memory.payload_start_address() + offset.offset();
+                    }
+                });
+                address
+            }
+        }
+    }
+
+    /// Returns the size of all shared memory segments the [`DataSegment`] currently owns.
+    pub(crate) fn memory_size(&self) -> usize {
+        match &self.memory {
+            MemoryType::Static(memory) => memory.size(),
+            MemoryType::Dynamic(memory) => {
+                let mut size = 0;
+                memory.for_each_segment(|_, memory| size += memory.size());
+                size
+            }
+        }
+    }
+
+    /// Returns the memory size a dynamic [`DataSegment`] would own after allocating a chunk
+    /// with the provided [`Layout`], including a new segment the allocation would create.
+    /// Static [`DataSegment`]s never grow, therefore it returns [`None`] for them.
+    pub(crate) fn required_memory_for(&self, layout: Layout) -> Option<usize> {
+        match &self.memory {
+            MemoryType::Static(_) => None,
+            MemoryType::Dynamic(memory) => {
+                Some(self.memory_size() + memory.reallocation_size_hint(layout).unwrap_or(0))
+            }
+        }
+    }
+
     pub(crate) fn bucket_size(&self, segment_id: SegmentId) -> usize {
         match &self.memory {
             MemoryType::Static(memory) => memory.bucket_size(),
@@ -152,6 +249,34 @@ impl<Service: service::Service> DataSegment<Service> {
         }
     }
 
+    pub(crate) fn info(&self) -> DataSegmentInfo {
+        match &self.memory {
+            MemoryType::Static(memory) => DataSegmentInfo {
+                is_dynamic: false,
+                segments: vec![Self::segment_info(SegmentId::new(0), memory)],
+            },
+            MemoryType::Dynamic(memory) => {
+                let mut segments = Vec::with_capacity(memory.number_of_active_segments());
+                memory.for_each_segment(|segment_id, memory| {
+                    segments.push(Self::segment_info(segment_id, memory))
+                });
+                DataSegmentInfo {
+                    is_dynamic: true,
+                    segments,
+                }
+            }
+        }
+    }
+
+    fn segment_info(segment_id: SegmentId, memory: &Service::SharedMemory) -> SegmentInfo {
+        SegmentInfo {
+            segment_id: segment_id.value(),
+            bucket_size: memory.bucket_size(),
+            number_of_buckets: memory.number_of_buckets(),
+            number_of_used_buckets: memory.number_of_used_buckets(),
+        }
+    }
+
     pub(crate) fn max_number_of_segments(data_segment_type: DataSegmentType) -> u8 {
         match data_segment_type {
             DataSegmentType::Static => 1,
@@ -183,40 +308,71 @@ impl<Service: service::Service> DataSegmentView<Service> {
         details: &PublisherDetails,
         global_config: &config::Config,
     ) -> Result<Self, SharedMemoryOpenError> {
-        let segment_name = data_segment_name(&details.publisher_id);
-        let origin = "DataSegment::open()";
-        let msg =
-            "Unable to open data segment since the underlying shared memory could not be opened.";
-
-        let memory = match details.data_segment_type {
-            DataSegmentType::Static => {
-                let segment_config = data_segment_config::<Service>(global_config);
-                let memory = fail!(from origin,
-                            when <Service::SharedMemory as SharedMemory<PoolAllocator>>::
-                                Builder::new(&segment_name)
-                                .config(&segment_config)
-                                .timeout(global_config.global.service.creation_timeout)
-                                .open(),
-                            "{msg}");
-                MemoryViewType::Static(memory)
-            }
-            DataSegmentType::Dynamic => {
-                let segment_config = resizable_data_segment_config::<Service>(global_config);
-                let memory = fail!(from origin,
-                    when <<Service::ResizableSharedMemory as ResizableSharedMemory<
-                        PoolAllocator,
-                        Service::SharedMemory,
-                    >>::ViewBuilder as NamedConceptBuilder<Service::ResizableSharedMemory>>::new(
-                        &segment_name,
-                    )
-                    .config(&segment_config)
-                    .open(),
+        Self::open_segment(
+            &data_segment_name(&details.publisher_id),
+            global_config,
+            details.data_segment_type,
+        )
+    }
+
+    pub(crate) fn open_segment(
+        segment_name: &FileName,
+        global_config: &config::Config,
+        data_segment_type: DataSegmentType,
+    ) -> Result<Self, SharedMemoryOpenError> {
+        match data_segment_type {
+            DataSegmentType::Static => Self::open_static_segment(segment_name, global_config),
+            DataSegmentType::Dynamic => Self::open_dynamic_segment(segment_name, global_config),
+        }
+    }
+
+    fn open_dynamic_segment(
+        segment_name: &FileName,
+        global_config: &config::Config,
+    ) -> Result<Self, SharedMemoryOpenError> {
+        let origin = "DataSegment::open_dynamic_segment()";
+        let msg = "Unable to open dynamic data segment since the underlying shared memory could not be opened.";
+
+        let segment_config = resizable_data_segment_config::<Service>(global_config);
+        let memory = fail!(from origin,
+            when <<Service::ResizableSharedMemory as ResizableSharedMemory<
+                PoolAllocator,
+                Service::SharedMemory,
+            >>::ViewBuilder as NamedConceptBuilder<Service::ResizableSharedMemory>>::new(
+                segment_name,
+            )
+            .config(&segment_config)
+            .read_only_payload(global_config.global.service.read_only_payload_mapping)
+            .huge_pages(global_config.global.service.data_segment_huge_pages)
+            .open(),
+            "{msg}");
+
+        Ok(Self {
+            memory: MemoryViewType::Dynamic(memory),
+        })
+    }
+
+    pub(crate) fn open_static_segment(
+        segment_name: &FileName,
+        global_config: &config::Config,
+    ) -> Result<Self, SharedMemoryOpenError> {
+        let origin = "DataSegment::open_static_segment()";
+        let msg = "Unable to open static data segment since the underlying shared memory could not be opened.";
+
+        let segment_config = data_segment_config::<Service>(global_config);
+        let memory = fail!(from origin,
+                    when <Service::SharedMemory as SharedMemory<PoolAllocator>>::
+                        Builder::new(segment_name)
+                        .config(&segment_config)
+                        .timeout(global_config.global.service.creation_timeout)
+                        .read_only_payload(global_config.global.service.read_only_payload_mapping)
+                        .huge_pages(global_config.global.service.data_segment_huge_pages)
+                        .open(),
                     "{msg}");
-                MemoryViewType::Dynamic(memory)
-            }
-        };
 
-        Ok(Self { memory })
+        Ok(Self {
+            memory: MemoryViewType::Static(memory),
+        })
     }
 
     pub(crate) fn register_and_translate_offset(
@@ -238,6 +394,17 @@ impl<Service: service::Service> DataSegmentView<Service> {
         }
     }
 
+    /// Maps all segments of a dynamic data segment that are not yet mapped and returns the
+    /// number of newly mapped segments.
+    pub(crate) fn map_all_segments(&self) -> Result<usize, SharedMemoryOpenError> {
+        match &self.memory {
+            MemoryViewType::Static(_) => Ok(0),
+            MemoryViewType::Dynamic(memory) => Ok(fail!(from self,
+                when memory.map_all_segments(),
+                "Unable to map all segments of the dynamic data segment.")),
+        }
+    }
+
     pub(crate) unsafe fn unregister_offset(&self, offset: PointerOffset) {
         if let MemoryViewType::Dynamic(memory) = &self.memory {
             memory.unregister_offset(offset);
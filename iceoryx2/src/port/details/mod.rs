@@ -10,6 +10,11 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+pub(crate) mod client_connections;
 pub(crate) mod data_segment;
+pub(crate) mod pacer;
+pub(crate) mod persistent_history;
 pub(crate) mod publisher_connections;
+pub(crate) mod segment_state;
+pub(crate) mod server_connections;
 pub(crate) mod subscriber_connections;
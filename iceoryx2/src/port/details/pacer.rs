@@ -0,0 +1,64 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::cell::Cell;
+use core::time::Duration;
+
+use iceoryx2_bb_log::warn;
+use iceoryx2_bb_posix::clock::{nanosleep_with_clock, ClockType, Time};
+
+const NANOSECONDS_PER_MILLISECOND: u64 = 1_000_000;
+
+/// Spreads the deliveries into one connection so that at most the configured number of
+/// samples per millisecond are delivered. The timing is based on the monotonic clock and the
+/// caller is suspended instead of busy waiting.
+#[derive(Debug)]
+pub(crate) struct Pacer {
+    interval: Option<Duration>,
+    next_delivery: Cell<Duration>,
+}
+
+impl Pacer {
+    pub(crate) fn new(max_samples_per_ms: Option<usize>) -> Self {
+        Self {
+            interval: max_samples_per_ms
+                .map(|v| Duration::from_nanos(NANOSECONDS_PER_MILLISECOND / (v.max(1) as u64))),
+            next_delivery: Cell::new(Duration::ZERO),
+        }
+    }
+
+    /// Suspends the caller until the next delivery is allowed and reserves the following
+    /// delivery slot.
+    pub(crate) fn wait_for_next_delivery(&self) {
+        let interval = match self.interval {
+            Some(interval) => interval,
+            None => return,
+        };
+
+        let now = match Time::now_with_clock(ClockType::Monotonic) {
+            Ok(now) => now.as_duration(),
+            Err(e) => {
+                warn!(from self, "Unable to pace the delivery since the current time could not be acquired ({:?}).", e);
+                return;
+            }
+        };
+
+        let next_delivery = self.next_delivery.get();
+        if now < next_delivery {
+            if let Err(e) = nanosleep_with_clock(next_delivery - now, ClockType::Monotonic) {
+                warn!(from self, "The paced delivery was interrupted ({:?}).", e);
+            }
+        }
+
+        self.next_delivery.set(now.max(next_delivery) + interval);
+    }
+}
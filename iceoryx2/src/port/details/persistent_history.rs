@@ -0,0 +1,244 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::{alloc::Layout, sync::atomic::Ordering};
+
+use iceoryx2_bb_elementary::math::align;
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_cal::{
+    event::NamedConceptBuilder,
+    named_concept::{NamedConceptMgmt, NamedConceptRemoveError},
+    shared_memory::{
+        SharedMemory, SharedMemoryBuilder, SharedMemoryCreateError, SharedMemoryOpenError,
+    },
+    shm_allocator::{self, pool_allocator::PoolAllocator},
+};
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicUsize};
+
+use crate::{
+    config,
+    service::{
+        self, config_scheme::data_segment_config, naming_scheme::persistent_history_name,
+        service_id::ServiceId,
+    },
+};
+
+const MAX_CREATE_OR_OPEN_RETRIES: usize = 5;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum PersistentHistoryCreateError {
+    UnableToCreateOrOpen,
+    IncompatibleLayout,
+}
+
+// The shared memory is zeroed on creation, therefore every member must have a valid zero state.
+#[repr(C)]
+struct Management {
+    next_sequence: IoxAtomicU64,
+}
+
+// A sequence number of zero marks an empty slot or a slot that is currently written.
+#[repr(C)]
+struct Slot {
+    sequence: IoxAtomicU64,
+    size: IoxAtomicUsize,
+}
+
+/// Ring buffer of serialized samples that is stored in a shared memory segment that is owned
+/// by the [`Service`](crate::service::Service) instead of the
+/// [`Publisher`](crate::port::publisher::Publisher) so that it outlives the
+/// [`Publisher`](crate::port::publisher::Publisher).
+#[derive(Debug)]
+pub(crate) struct PersistentHistory<Service: service::Service> {
+    memory: Service::SharedMemory,
+    number_of_slots: usize,
+    alignment: usize,
+    slot_stride: usize,
+}
+
+impl<Service: service::Service> PersistentHistory<Service> {
+    /// Opens the persistent history of the [`Service`](crate::service::Service) or creates it
+    /// when it does not yet exist. The slot size is defined by the first
+    /// [`Publisher`](crate::port::publisher::Publisher) that creates it.
+    pub(crate) fn open_or_create(
+        service_id: &ServiceId,
+        global_config: &config::Config,
+        number_of_slots: usize,
+        sample_layout: Layout,
+    ) -> Result<Self, PersistentHistoryCreateError> {
+        let msg = "Unable to open or create the persistent history";
+        let origin = "PersistentHistory::open_or_create()";
+
+        let alignment = sample_layout.align().max(core::mem::align_of::<Slot>());
+        let slot_header_size = align(core::mem::size_of::<Slot>(), alignment);
+        let memory_overhead = 2 * alignment - 1;
+        let name = persistent_history_name(service_id);
+        let segment_config = data_segment_config::<Service>(global_config);
+        let allocator_config = shm_allocator::pool_allocator::Config {
+            bucket_layout: Layout::from_size_align(alignment, alignment).unwrap(),
+        };
+
+        for _ in 0..MAX_CREATE_OR_OPEN_RETRIES {
+            let slot_stride = slot_header_size + align(sample_layout.size(), alignment);
+            match <<Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder as NamedConceptBuilder<
+                Service::SharedMemory,
+            >>::new(&name)
+            .config(&segment_config)
+            .has_ownership(false)
+            .size(memory_overhead + slot_stride * number_of_slots)
+            .create(&allocator_config)
+            {
+                Ok(memory) => {
+                    return Ok(Self {
+                        memory,
+                        number_of_slots,
+                        alignment,
+                        slot_stride,
+                    })
+                }
+                Err(SharedMemoryCreateError::AlreadyExists) => (),
+                Err(e) => {
+                    fail!(from origin, with PersistentHistoryCreateError::UnableToCreateOrOpen,
+                        "{msg} since the underlying shared memory could not be created ({:?}).", e);
+                }
+            }
+
+            match <Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder::new(&name)
+                .config(&segment_config)
+                .has_ownership(false)
+                .timeout(global_config.global.service.creation_timeout)
+                .open()
+            {
+                Ok(memory) => {
+                    let slot_stride =
+                        memory.size().saturating_sub(memory_overhead) / number_of_slots;
+                    if slot_stride <= slot_header_size || slot_stride % alignment != 0 {
+                        fail!(from origin, with PersistentHistoryCreateError::IncompatibleLayout,
+                            "{msg} since the existing persistent history has an incompatible layout.");
+                    }
+
+                    return Ok(Self {
+                        memory,
+                        number_of_slots,
+                        alignment,
+                        slot_stride,
+                    });
+                }
+                Err(SharedMemoryOpenError::DoesNotExist) => (),
+                Err(e) => {
+                    fail!(from origin, with PersistentHistoryCreateError::UnableToCreateOrOpen,
+                        "{msg} since the underlying shared memory could not be opened ({:?}).", e);
+                }
+            }
+        }
+
+        fail!(from origin, with PersistentHistoryCreateError::UnableToCreateOrOpen,
+            "{msg} since it was removed and recreated concurrently too often.");
+    }
+
+    fn base_address(&self) -> usize {
+        align(self.memory.payload_start_address(), self.alignment)
+    }
+
+    fn management(&self) -> &Management {
+        unsafe { &*(self.base_address() as *const Management) }
+    }
+
+    fn slot(&self, index: usize) -> &Slot {
+        unsafe {
+            &*((self.base_address() + self.alignment + index * self.slot_stride) as *const Slot)
+        }
+    }
+
+    fn slot_data(&self, index: usize) -> *mut u8 {
+        (self.slot(index) as *const Slot as usize
+            + align(core::mem::size_of::<Slot>(), self.alignment)) as *mut u8
+    }
+
+    fn slot_capacity(&self) -> usize {
+        self.slot_stride - align(core::mem::size_of::<Slot>(), self.alignment)
+    }
+
+    /// Copies the sample into the slot that contains the oldest entry. Samples that exceed
+    /// the slot capacity are not stored.
+    ///
+    /// # Safety
+    ///
+    ///  * `data` must point to at least `size` valid bytes
+    pub(crate) unsafe fn store(&self, data: *const u8, size: usize) {
+        if self.slot_capacity() < size {
+            warn!(from self,
+                "The sample with a size of {} bytes is not stored in the persistent history since it exceeds the slot capacity of {} bytes.",
+                size, self.slot_capacity());
+            return;
+        }
+
+        let sequence = self
+            .management()
+            .next_sequence
+            .fetch_add(1, Ordering::Relaxed)
+            + 1;
+        let index = ((sequence - 1) % self.number_of_slots as u64) as usize;
+        let slot = self.slot(index);
+
+        slot.sequence.store(0, Ordering::Relaxed);
+        core::sync::atomic::fence(Ordering::Release);
+        core::ptr::copy_nonoverlapping(data, self.slot_data(index), size);
+        slot.size.store(size, Ordering::Relaxed);
+        slot.sequence.store(sequence, Ordering::Release);
+    }
+
+    /// Calls the callback for every stored sample, starting with the oldest one.
+    pub(crate) fn for_each_in_order<F: FnMut(&[u8])>(&self, mut callback: F) {
+        let mut entries = Vec::with_capacity(self.number_of_slots);
+        for index in 0..self.number_of_slots {
+            let slot = self.slot(index);
+            let sequence = slot.sequence.load(Ordering::Acquire);
+            if sequence == 0 {
+                continue;
+            }
+
+            let size = slot.size.load(Ordering::Relaxed).min(self.slot_capacity());
+            let data = unsafe { core::slice::from_raw_parts(self.slot_data(index), size) }.to_vec();
+
+            core::sync::atomic::fence(Ordering::Acquire);
+            if slot.sequence.load(Ordering::Relaxed) == sequence {
+                entries.push((sequence, data));
+            }
+        }
+
+        entries.sort_by_key(|(sequence, _)| *sequence);
+        for (_, data) in entries {
+            callback(&data);
+        }
+    }
+}
+
+/// Removes the persistent history of the [`Service`](crate::service::Service). Returns
+/// `true` when it existed, otherwise `false`.
+///
+/// # Safety
+///
+///  * the [`Service`](crate::service::Service) must no longer be in use
+pub(crate) unsafe fn remove_persistent_history<Service: service::Service>(
+    service_id: &ServiceId,
+    config: &config::Config,
+) -> Result<bool, NamedConceptRemoveError> {
+    let origin = "remove_persistent_history()";
+
+    Ok(fail!(from origin,
+        when <Service::SharedMemory as NamedConceptMgmt>::remove_cfg(
+            &persistent_history_name(service_id),
+            &data_segment_config::<Service>(config),
+        ),
+        "Unable to remove the persistent history of the service {:?}.", service_id))
+}
@@ -10,32 +10,139 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use core::cell::UnsafeCell;
+use core::cell::{Cell, UnsafeCell};
 
 extern crate alloc;
 use alloc::sync::Arc;
 
 use crate::{
-    port::port_identifiers::{UniquePublisherId, UniqueSubscriberId},
+    port::port_identifiers::{UniquePortId, UniquePublisherId, UniqueSubscriberId},
     service::{
-        self, config_scheme::connection_config,
-        dynamic_config::publish_subscribe::PublisherDetails, naming_scheme::connection_name,
-        static_config::publish_subscribe::StaticConfig, ServiceState,
+        self,
+        config_scheme::{broadcast_config, connection_config, event_config},
+        dynamic_config::publish_subscribe::PublisherDetails,
+        naming_scheme::{broadcast_name, connection_name, reclaim_event_name},
+        static_config::publish_subscribe::StaticConfig,
+        ServiceState,
     },
 };
 
+use crate::node::evidence_log::{EvidenceEntity, StateTransition};
 use crate::port::update_connections::ConnectionFailure;
-use iceoryx2_bb_log::fail;
+use crate::port::{DegrationAction, DegrationCallback};
+use iceoryx2_bb_container::queue::Queue;
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_lock_free::mpmc::container::ContainerState;
+use iceoryx2_bb_log::{debug, fail, warn};
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::event::{Event, Notifier, NotifierBuilder, TriggerId};
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::shared_memory::SharedMemoryOpenError;
+use iceoryx2_cal::zero_copy_broadcast::{
+    ZeroCopyBroadcast, ZeroCopyBroadcastBuilder, ZeroCopyBroadcastPortDetails,
+    ZeroCopyBroadcastReceiver,
+};
 use iceoryx2_cal::zero_copy_connection::*;
 
 use super::data_segment::DataSegmentView;
 
+/// The receiving end of either the dedicated connection to a publisher or, when the service
+/// has broadcasting enabled, of the broadcast of the publisher.
+#[derive(Debug)]
+pub(crate) enum Receiver<Service: service::Service> {
+    Connection(<Service::Connection as ZeroCopyConnection>::Receiver),
+    Broadcast(<Service::Broadcast as ZeroCopyBroadcast>::Receiver),
+}
+
+impl<Service: service::Service> Receiver<Service> {
+    pub(crate) fn has_data(&self) -> bool {
+        match self {
+            Self::Connection(receiver) => receiver.has_data(),
+            Self::Broadcast(receiver) => receiver.has_data(),
+        }
+    }
+
+    pub(crate) fn number_of_pending_samples(&self) -> usize {
+        match self {
+            Self::Connection(receiver) => receiver.number_of_pending_samples(),
+            Self::Broadcast(receiver) => receiver.number_of_pending_samples(),
+        }
+    }
+
+    pub(crate) fn receive(&self) -> Result<Option<PointerOffset>, ZeroCopyReceiveError> {
+        match self {
+            Self::Connection(receiver) => receiver.receive(),
+            Self::Broadcast(receiver) => receiver.receive(),
+        }
+    }
+
+    pub(crate) fn release(&self, ptr: PointerOffset) -> Result<(), ZeroCopyReleaseError> {
+        match self {
+            Self::Connection(receiver) => receiver.release(ptr),
+            Self::Broadcast(receiver) => receiver.release(ptr),
+        }
+    }
+
+    pub(crate) fn max_borrowed_samples(&self) -> usize {
+        match self {
+            Self::Connection(receiver) => receiver.max_borrowed_samples(),
+            Self::Broadcast(receiver) => receiver.max_borrowed_samples(),
+        }
+    }
+
+    pub(crate) fn counters(&self) -> ZeroCopyConnectionCounters {
+        match self {
+            Self::Connection(receiver) => receiver.counters(),
+            Self::Broadcast(receiver) => receiver.counters(),
+        }
+    }
+
+    /// Returns false when the receiver does not support a redelivery request. The
+    /// broadcast receivers read the history directly from the ring buffer of the publisher.
+    pub(crate) fn request_redelivery(&self) -> bool {
+        match self {
+            Self::Connection(receiver) => {
+                receiver.request_redelivery();
+                true
+            }
+            Self::Broadcast(_) => false,
+        }
+    }
+
+    /// Returns the address of the sample when it was delivered inline by the publisher.
+    /// The broadcast receivers do not support inline samples.
+    pub(crate) fn inline_sample_address(&self, ptr: PointerOffset) -> Option<usize> {
+        match self {
+            Self::Connection(receiver) => receiver.inline_sample_address(ptr),
+            Self::Broadcast(_) => None,
+        }
+    }
+
+    /// Returns true when the publisher announced a new data segment since the last call.
+    /// The broadcast receivers do not receive announcements.
+    pub(crate) fn take_new_segment_announcement(&self) -> bool {
+        match self {
+            Self::Connection(receiver) => receiver.take_new_segment_announcement(),
+            Self::Broadcast(_) => false,
+        }
+    }
+
+    /// Returns true when the publisher waits for released samples and must be notified.
+    /// The broadcast receivers do not support reclaim notifications.
+    pub(crate) fn take_reclaim_notification_request(&self) -> bool {
+        match self {
+            Self::Connection(receiver) => receiver.take_reclaim_notification_request(),
+            Self::Broadcast(_) => false,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct Connection<Service: service::Service> {
-    pub(crate) receiver: <Service::Connection as ZeroCopyConnection>::Receiver,
+    pub(crate) receiver: Receiver<Service>,
     pub(crate) data_segment: DataSegmentView<Service>,
     pub(crate) publisher_id: UniquePublisherId,
+    reclaim_notifier: Option<<Service::Event as Event>::Notifier>,
 }
 
 impl<Service: service::Service> Connection<Service> {
@@ -49,7 +156,8 @@ impl<Service: service::Service> Connection<Service> {
         );
 
         let global_config = this.service_state.shared_node.config();
-        let receiver = fail!(from this,
+        let receiver = match this.static_config.enable_broadcast {
+            false => Receiver::Connection(fail!(from this,
                         when <Service::Connection as ZeroCopyConnection>::
                             Builder::new( &connection_name(details.publisher_id, this.subscriber_id))
                                     .config(&connection_config::<Service>(global_config))
@@ -58,20 +166,118 @@ impl<Service: service::Service> Connection<Service> {
                                     .enable_safe_overflow(this.static_config.enable_safe_overflow)
                                     .number_of_samples_per_segment(details.number_of_samples)
                                     .max_supported_shared_memory_segments(details.max_number_of_segments)
+                                    .enable_priority_lane(this.static_config.enable_priority_lanes)
+                                    .inline_sample_size(this.static_config.inline_sample_size())
+                                    .timeout(global_config.global.service.creation_timeout)
+                                    .create_receiver(),
+                        "{} since the zero copy connection could not be established.", msg)),
+            // all subscribers share the ring buffer of the publisher, therefore the buffer
+            // size of the service is used
+            true => Receiver::Broadcast(fail!(from this,
+                        when <Service::Broadcast as ZeroCopyBroadcast>::
+                            Builder::new(&broadcast_name(details.publisher_id))
+                                    .config(&broadcast_config::<Service>(global_config))
+                                    .buffer_size(this.static_config.subscriber_max_buffer_size)
+                                    .receiver_max_borrowed_samples(this.static_config.subscriber_max_borrowed_samples)
+                                    .enable_safe_overflow(this.static_config.enable_safe_overflow)
+                                    .max_receivers(this.static_config.max_subscribers)
+                                    .number_of_samples_per_segment(details.number_of_samples)
+                                    .max_supported_shared_memory_segments(details.max_number_of_segments)
+                                    .receiver_id(this.subscriber_id.value())
+                                    .initial_history(this.static_config.history_size)
                                     .timeout(global_config.global.service.creation_timeout)
                                     .create_receiver(),
-                        "{} since the zero copy connection could not be established.", msg);
+                        "{} since the zero copy broadcast could not be established.", msg)),
+        };
 
         let data_segment = fail!(from this,
                             when DataSegmentView::open(details, global_config),
                             "{} since the publishers data segment could not be opened.", msg);
 
+        if this.eager_segment_mapping {
+            fail!(from this, when data_segment.map_all_segments(),
+                "{} since the segments of the publishers data segment could not be mapped.", msg);
+        }
+
+        // the broadcast receivers do not support reclaim notifications
+        let reclaim_notifier = match receiver {
+            Receiver::Connection(_) => Self::open_reclaim_notifier(details, global_config),
+            Receiver::Broadcast(_) => None,
+        };
+
         Ok(Self {
             receiver,
             data_segment,
             publisher_id: details.publisher_id,
+            reclaim_notifier,
         })
     }
+
+    fn open_reclaim_notifier(
+        details: &PublisherDetails,
+        global_config: &crate::config::Config,
+    ) -> Option<<Service::Event as Event>::Notifier> {
+        // a publisher that is removed concurrently has no reclaim event anymore, it does not
+        // wait for returned samples either
+        match <Service::Event as Event>::NotifierBuilder::new(&reclaim_event_name(
+            &details.publisher_id,
+        ))
+        .config(&event_config::<Service>(global_config))
+        .open()
+        {
+            Ok(notifier) => Some(notifier),
+            Err(e) => {
+                debug!(from "Connection::open_reclaim_notifier()",
+                    "Unable to open the reclaim event of the publisher {:?} ({:?}).",
+                    details.publisher_id, e);
+                None
+            }
+        }
+    }
+
+    /// Returns the sample to the publisher and wakes up the publisher when it waits in a
+    /// blocking loan for returned samples.
+    pub(crate) fn release(&self, ptr: PointerOffset) -> Result<(), ZeroCopyReleaseError> {
+        self.receiver.release(ptr)?;
+
+        if self.receiver.take_reclaim_notification_request() {
+            self.notify_publisher_about_reclaimed_sample();
+        }
+
+        Ok(())
+    }
+
+    fn notify_publisher_about_reclaimed_sample(&self) {
+        if let Some(notifier) = &self.reclaim_notifier {
+            if let Err(e) = notifier.notify(TriggerId::new(0)) {
+                debug!(from self, "Unable to notify the publisher {:?} about the reclaimed sample ({:?}).",
+                    self.publisher_id, e);
+            }
+        }
+    }
+
+    /// Translates a received offset into the address of the sample. Samples that were
+    /// delivered inline reside in the connection, all other samples in the data segment of
+    /// the publisher.
+    pub(crate) fn register_and_translate_offset(
+        &self,
+        offset: PointerOffset,
+    ) -> Result<usize, SharedMemoryOpenError> {
+        match self.receiver.inline_sample_address(offset) {
+            Some(address) => Ok(address),
+            None => self.data_segment.register_and_translate_offset(offset),
+        }
+    }
+
+    /// # Safety
+    ///
+    ///  * the offset must have been registered with
+    ///    [`Connection::register_and_translate_offset()`] and must not be used afterwards
+    pub(crate) unsafe fn unregister_offset(&self, offset: PointerOffset) {
+        if self.receiver.inline_sample_address(offset).is_none() {
+            self.data_segment.unregister_offset(offset);
+        }
+    }
 }
 #[derive(Debug)]
 pub(crate) struct PublisherConnections<Service: service::Service> {
@@ -80,6 +286,13 @@ pub(crate) struct PublisherConnections<Service: service::Service> {
     pub(crate) service_state: Arc<ServiceState<Service>>,
     pub(crate) static_config: StaticConfig,
     pub(crate) buffer_size: usize,
+    publisher_list_state: UnsafeCell<ContainerState<PublisherDetails>>,
+    to_be_removed_connections: UnsafeCell<Queue<Arc<Connection<Service>>>>,
+    overflowed_connections: UnsafeCell<Vec<Arc<Connection<Service>>>>,
+    has_expired_connection_backlog: bool,
+    degration_callback: Option<DegrationCallback<'static>>,
+    eager_segment_mapping: bool,
+    is_disconnected: Cell<bool>,
 }
 
 impl<Service: service::Service> PublisherConnections<Service> {
@@ -89,16 +302,196 @@ impl<Service: service::Service> PublisherConnections<Service> {
         service_state: Arc<ServiceState<Service>>,
         static_config: &StaticConfig,
         buffer_size: usize,
+        degration_callback: Option<DegrationCallback<'static>>,
+        eager_segment_mapping: bool,
+        has_expired_connection_backlog: bool,
     ) -> Self {
+        let publisher_list_state = unsafe {
+            service_state
+                .dynamic_storage
+                .get()
+                .publish_subscribe()
+                .publishers
+                .get_state()
+        };
+        let expired_connection_buffer = service_state
+            .shared_node
+            .config()
+            .defaults
+            .publish_subscribe
+            .subscriber_expired_connection_buffer;
+
         Self {
             connections: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
             subscriber_id,
             service_state,
             static_config: static_config.clone(),
             buffer_size,
+            publisher_list_state: UnsafeCell::new(publisher_list_state),
+            to_be_removed_connections: UnsafeCell::new(Queue::new(expired_connection_buffer)),
+            overflowed_connections: UnsafeCell::new(vec![]),
+            has_expired_connection_backlog,
+            degration_callback,
+            eager_segment_mapping,
+            is_disconnected: Cell::new(false),
+        }
+    }
+
+    /// Removes all connections permanently. Afterwards no new connections are established.
+    pub(crate) fn disconnect_all(&self) {
+        self.is_disconnected.set(true);
+        for i in 0..self.len() {
+            self.remove(i);
+        }
+    }
+
+    // only used internally as convinience function
+    #[allow(clippy::mut_from_ref)]
+    pub(crate) fn to_be_removed_connections(&self) -> &mut Queue<Arc<Connection<Service>>> {
+        #[deny(clippy::mut_from_ref)]
+        unsafe {
+            &mut *self.to_be_removed_connections.get()
+        }
+    }
+
+    /// Returns all expired connections with undelivered samples that did not fit into the
+    /// expired connection buffer. Their samples are moved into the owned backlog of the
+    /// subscriber.
+    pub(crate) fn take_overflowed_connections(&self) -> Vec<Arc<Connection<Service>>> {
+        core::mem::take(unsafe { &mut *self.overflowed_connections.get() })
+    }
+
+    pub(crate) fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        if self.is_disconnected.get() {
+            return Ok(());
+        }
+
+        if self
+            .service_state
+            .shared_node
+            .closed_ports()
+            .is_closed(UniquePortId::Subscriber(self.subscriber_id))
+        {
+            self.disconnect_all();
+            return Ok(());
+        }
+
+        if unsafe {
+            self.service_state
+                .dynamic_storage
+                .get()
+                .publish_subscribe()
+                .publishers
+                .update_state(&mut *self.publisher_list_state.get())
+        } {
+            fail!(from self, when self.populate_publisher_channels(),
+                "Connections were updated only partially since at least one connection to a publisher failed.");
+        }
+
+        if self.eager_segment_mapping {
+            self.map_announced_segments();
+        }
+
+        Ok(())
+    }
+
+    fn map_announced_segments(&self) {
+        for i in 0..self.len() {
+            if let Some(connection) = self.get(i) {
+                if connection.receiver.take_new_segment_announcement() {
+                    if let Err(e) = connection.data_segment.map_all_segments() {
+                        warn!(from self,
+                            "Unable to map the new segments of publisher {:?} ({:?}). They are mapped when the first sample is received from them.",
+                            connection.publisher_id, e);
+                    }
+                }
+            }
         }
     }
 
+    pub(crate) fn populate_publisher_channels(&self) -> Result<(), ConnectionFailure> {
+        let mut visited_indices = vec![];
+        visited_indices.resize(self.capacity(), None);
+
+        unsafe {
+            (*self.publisher_list_state.get()).for_each(|h, details| {
+                visited_indices[h.index() as usize] = Some(*details);
+                CallbackProgression::Continue
+            })
+        };
+
+        let prepare_connection_removal = |i| {
+            if let Some(connection) = self.get(i) {
+                if connection.receiver.has_data()
+                    && !self.to_be_removed_connections().push(connection.clone())
+                {
+                    if self.has_expired_connection_backlog {
+                        unsafe { &mut *self.overflowed_connections.get() }.push(connection.clone());
+                    } else {
+                        warn!(from self, "Expired connection buffer exceeded. A publisher disconnected with undelivered samples that will be discarded. Increase the config entry `defaults.publish-subscribe.subscriber-expired-connection-buffer` to mitigate the problem.");
+                    }
+                }
+            }
+        };
+
+        // update all connections
+        for (i, index) in visited_indices.iter().enumerate() {
+            match index {
+                Some(details) => {
+                    let create_connection = match self.get(i) {
+                        None => true,
+                        Some(connection) => connection.publisher_id != details.publisher_id,
+                    };
+
+                    if create_connection {
+                        prepare_connection_removal(i);
+
+                        if self.service_state.shared_node.port_registry().is_locked() {
+                            warn!(from self,
+                                "Unable to establish connection to new publisher {:?} since the node is locked.",
+                                details.publisher_id);
+                            self.remove(i);
+                            continue;
+                        }
+
+                        match self.create(i, details) {
+                            Ok(()) => (),
+                            Err(e) => match &self.degration_callback {
+                                None => {
+                                    warn!(from self, "Unable to establish connection to new publisher {:?}.", details.publisher_id)
+                                }
+                                Some(c) => {
+                                    match c.call(
+                                        self.service_state.static_config.clone(),
+                                        details.publisher_id,
+                                        self.subscriber_id,
+                                    ) {
+                                        DegrationAction::Ignore => (),
+                                        DegrationAction::Warn => {
+                                            warn!(from self, "Unable to establish connection to new publisher {:?}.",
+                                        details.publisher_id)
+                                        }
+                                        DegrationAction::Fail => {
+                                            fail!(from self, with e, "Unable to establish connection to new publisher {:?}.",
+                                        details.publisher_id);
+                                        }
+                                    }
+                                }
+                            },
+                        }
+                    }
+                }
+                None => {
+                    prepare_connection_removal(i);
+
+                    self.remove(i)
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn subscriber_id(&self) -> UniqueSubscriberId {
         self.subscriber_id
     }
@@ -121,13 +514,39 @@ impl<Service: service::Service> PublisherConnections<Service> {
         index: usize,
         details: &PublisherDetails,
     ) -> Result<(), ConnectionFailure> {
-        *self.get_mut(index) = Some(Arc::new(Connection::new(self, details)?));
-
-        Ok(())
+        let shared_node = &self.service_state.shared_node;
+        match Connection::new(self, details) {
+            Ok(connection) => {
+                *self.get_mut(index) = Some(Arc::new(connection));
+                shared_node.record_evidence(
+                    EvidenceEntity::Connection,
+                    StateTransition::Connected,
+                    format_args!("{:?} -> {:?}", details.publisher_id, self.subscriber_id),
+                );
+                Ok(())
+            }
+            Err(e) => {
+                shared_node.record_evidence(
+                    EvidenceEntity::Connection,
+                    StateTransition::Degraded,
+                    format_args!(
+                        "{:?} -> {:?} ({:?})",
+                        details.publisher_id, self.subscriber_id, e
+                    ),
+                );
+                Err(e)
+            }
+        }
     }
 
     pub(crate) fn remove(&self, index: usize) {
-        *self.get_mut(index) = None;
+        if let Some(connection) = self.get_mut(index).take() {
+            self.service_state.shared_node.record_evidence(
+                EvidenceEntity::Connection,
+                StateTransition::Destroyed,
+                format_args!("{:?} -> {:?}", connection.publisher_id, self.subscriber_id),
+            );
+        }
     }
 
     pub(crate) fn len(&self) -> usize {
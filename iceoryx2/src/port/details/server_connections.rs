@@ -0,0 +1,175 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::cell::UnsafeCell;
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::zero_copy_connection::{
+    PointerOffset, ZeroCopyConnection, ZeroCopyConnectionBuilder, ZeroCopyReceiver,
+    ZeroCopyReleaseError,
+};
+
+use crate::node::SharedNode;
+use crate::port::port_identifiers::{UniqueClientId, UniqueServerId};
+use crate::port::update_connections::ConnectionFailure;
+use crate::service::config_scheme::connection_config;
+use crate::service::dynamic_config::request_response::ServerDetails;
+use crate::service::naming_scheme::{
+    request_connection_name, response_connection_name, server_data_segment_name,
+};
+use crate::service::{self, static_config::request_response::StaticConfig};
+
+use super::data_segment::DataSegmentView;
+
+/// The connection of a [`Client`](crate::port::client::Client) to a
+/// [`Server`](crate::port::server::Server). Requests are sent via the `request_sender`, the
+/// responses of the [`Server`](crate::port::server::Server) are received via the
+/// `response_receiver` and reside in the servers data segment.
+#[derive(Debug)]
+pub(crate) struct Connection<Service: service::Service> {
+    pub(crate) request_sender: <Service::Connection as ZeroCopyConnection>::Sender,
+    pub(crate) response_receiver: <Service::Connection as ZeroCopyConnection>::Receiver,
+    pub(crate) data_segment: DataSegmentView<Service>,
+    pub(crate) server_port_id: UniqueServerId,
+}
+
+impl<Service: service::Service> Connection<Service> {
+    fn new(
+        this: &ServerConnections<Service>,
+        details: &ServerDetails,
+    ) -> Result<Self, ConnectionFailure> {
+        let msg = format!(
+            "Unable to establish connection to server {:?} from client {:?}",
+            details.server_port_id, this.client_port_id
+        );
+        let global_config = this.shared_node.config();
+
+        let request_sender = fail!(from this, when <Service::Connection as ZeroCopyConnection>::
+                        Builder::new( &request_connection_name(this.client_port_id, details.server_port_id))
+                                .config(&connection_config::<Service>(global_config))
+                                .buffer_size(details.request_buffer_size)
+                                .receiver_max_borrowed_samples(this.static_config.max_borrowed_requests)
+                                .enable_safe_overflow(this.static_config.enable_safe_overflow_for_requests)
+                                .number_of_samples_per_segment(this.number_of_requests)
+                                .max_supported_shared_memory_segments(this.max_number_of_segments)
+                                .timeout(global_config.global.service.creation_timeout)
+                                .create_sender(),
+                        "{} since the request connection could not be established.", msg);
+
+        let response_receiver = fail!(from this, when <Service::Connection as ZeroCopyConnection>::
+                        Builder::new( &response_connection_name(details.server_port_id, this.client_port_id))
+                                .config(&connection_config::<Service>(global_config))
+                                .buffer_size(this.static_config.response_buffer_size_per_client())
+                                .receiver_max_borrowed_samples(this.static_config.max_borrowed_responses_per_client())
+                                .enable_safe_overflow(this.static_config.enable_safe_overflow_for_responses)
+                                .number_of_samples_per_segment(details.number_of_responses)
+                                .max_supported_shared_memory_segments(details.max_number_of_segments)
+                                .timeout(global_config.global.service.creation_timeout)
+                                .create_receiver(),
+                        "{} since the response connection could not be established.", msg);
+
+        let data_segment = fail!(from this,
+                            when DataSegmentView::open_segment(&server_data_segment_name(&details.server_port_id), global_config, details.data_segment_type),
+                            "{} since the servers data segment could not be opened.", msg);
+
+        Ok(Self {
+            request_sender,
+            response_receiver,
+            data_segment,
+            server_port_id: details.server_port_id,
+        })
+    }
+
+    /// Returns a received response back to the server.
+    pub(crate) fn release_response(&self, offset: PointerOffset) {
+        unsafe { self.data_segment.unregister_offset(offset) };
+
+        match self.response_receiver.release(offset) {
+            Ok(()) => (),
+            Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
+                fatal_panic!(from self, "This should never happen! The servers retrieve channel is full and the response cannot be returned.");
+            }
+            Err(ZeroCopyReleaseError::ReceiverOfPreviousGeneration) => {
+                warn!(from self, "Unable to return the response to the server since the receiver was considered dead and replaced. The server recovers the response when it cleans up the connection.");
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ServerConnections<Service: service::Service> {
+    connections: Vec<UnsafeCell<Option<Arc<Connection<Service>>>>>,
+    client_port_id: UniqueClientId,
+    shared_node: Arc<SharedNode<Service>>,
+    pub(crate) static_config: StaticConfig,
+    number_of_requests: usize,
+    max_number_of_segments: u8,
+}
+
+impl<Service: service::Service> ServerConnections<Service> {
+    pub(crate) fn new(
+        capacity: usize,
+        shared_node: Arc<SharedNode<Service>>,
+        client_port_id: UniqueClientId,
+        static_config: &StaticConfig,
+        number_of_requests: usize,
+        max_number_of_segments: u8,
+    ) -> Self {
+        Self {
+            connections: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
+            client_port_id,
+            shared_node,
+            static_config: static_config.clone(),
+            number_of_requests,
+            max_number_of_segments,
+        }
+    }
+
+    pub(crate) fn get(&self, index: usize) -> &Option<Arc<Connection<Service>>> {
+        unsafe { &*self.connections[index].get() }
+    }
+
+    // only used internally as convinience function
+    #[allow(clippy::mut_from_ref)]
+    fn get_mut(&self, index: usize) -> &mut Option<Arc<Connection<Service>>> {
+        #[deny(clippy::mut_from_ref)]
+        unsafe {
+            &mut *self.connections[index].get()
+        }
+    }
+
+    pub(crate) fn create(
+        &self,
+        index: usize,
+        details: &ServerDetails,
+    ) -> Result<(), ConnectionFailure> {
+        *self.get_mut(index) = Some(Arc::new(Connection::new(self, details)?));
+
+        Ok(())
+    }
+
+    pub(crate) fn remove(&self, index: usize) {
+        *self.get_mut(index) = None;
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.connections.len()
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.connections.capacity()
+    }
+}
@@ -21,9 +21,12 @@ use iceoryx2_cal::zero_copy_connection::{
     ZeroCopyConnection, ZeroCopyConnectionBuilder, ZeroCopyCreationError,
 };
 
+use super::pacer::Pacer;
+use crate::node::evidence_log::{EvidenceEntity, StateTransition};
 use crate::node::SharedNode;
 use crate::service::config_scheme::connection_config;
 use crate::service::dynamic_config::publish_subscribe::SubscriberDetails;
+use crate::service::port_factory::publisher::{LocalPublisherConfig, UnableToDeliverStrategy};
 use crate::{
     port::port_identifiers::{UniquePublisherId, UniqueSubscriberId},
     service,
@@ -34,6 +37,9 @@ use crate::{
 pub(crate) struct Connection<Service: service::Service> {
     pub(crate) sender: <Service::Connection as ZeroCopyConnection>::Sender,
     pub(crate) subscriber_id: UniqueSubscriberId,
+    pub(crate) is_delivery_enabled: bool,
+    pub(crate) unable_to_deliver_strategy: UnableToDeliverStrategy,
+    pub(crate) pacer: Pacer,
 }
 
 impl<Service: service::Service> Connection<Service> {
@@ -41,6 +47,8 @@ impl<Service: service::Service> Connection<Service> {
         this: &SubscriberConnections<Service>,
         subscriber_details: SubscriberDetails,
         number_of_samples: usize,
+        is_delivery_enabled: bool,
+        unable_to_deliver_strategy: UnableToDeliverStrategy,
     ) -> Result<Self, ZeroCopyCreationError> {
         let msg = format!(
             "Unable to establish connection to subscriber {:?} from publisher {:?}",
@@ -52,21 +60,35 @@ impl<Service: service::Service> Connection<Service> {
                 msg, subscriber_details.buffer_size, this.static_config.subscriber_max_buffer_size);
         }
 
-        let sender = fail!(from this, when <Service::Connection as ZeroCopyConnection>::
-                        Builder::new( &connection_name(this.port_id, subscriber_details.subscriber_id))
-                                .config(&connection_config::<Service>(this.shared_node.config()))
-                                .buffer_size(subscriber_details.buffer_size)
-                                .receiver_max_borrowed_samples(this.static_config.subscriber_max_borrowed_samples)
-                                .enable_safe_overflow(this.static_config.enable_safe_overflow)
-                                .number_of_samples_per_segment(number_of_samples)
-                                .max_supported_shared_memory_segments(this.max_number_of_segments)
-                                .timeout(this.shared_node.config().global.service.creation_timeout)
-                                .create_sender(),
-                        "{}.", msg);
+        let mut builder = <Service::Connection as ZeroCopyConnection>::Builder::new(
+            &connection_name(this.port_id, subscriber_details.subscriber_id),
+        )
+        .config(&connection_config::<Service>(this.shared_node.config()))
+        .buffer_size(subscriber_details.buffer_size)
+        .receiver_max_borrowed_samples(this.static_config.subscriber_max_borrowed_samples)
+        .enable_safe_overflow(this.static_config.enable_safe_overflow)
+        .number_of_samples_per_segment(number_of_samples)
+        .max_supported_shared_memory_segments(this.max_number_of_segments)
+        .enable_priority_lane(this.static_config.enable_priority_lanes)
+        .inline_sample_size(this.static_config.inline_sample_size())
+        .timeout(this.shared_node.config().global.service.creation_timeout);
+
+        if let Some(value) = this.high_watermark {
+            builder = builder.high_watermark(value);
+        }
+
+        if let Some(value) = this.low_watermark {
+            builder = builder.low_watermark(value);
+        }
+
+        let sender = fail!(from this, when builder.create_sender(), "{}.", msg);
 
         Ok(Self {
             sender,
             subscriber_id: subscriber_details.subscriber_id,
+            is_delivery_enabled,
+            unable_to_deliver_strategy,
+            pacer: Pacer::new(this.pacing),
         })
     }
 }
@@ -79,6 +101,9 @@ pub(crate) struct SubscriberConnections<Service: service::Service> {
     pub(crate) static_config: StaticConfig,
     number_of_samples: usize,
     max_number_of_segments: u8,
+    high_watermark: Option<usize>,
+    low_watermark: Option<usize>,
+    pacing: Option<usize>,
 }
 
 impl<Service: service::Service> SubscriberConnections<Service> {
@@ -89,6 +114,7 @@ impl<Service: service::Service> SubscriberConnections<Service> {
         static_config: &StaticConfig,
         number_of_samples: usize,
         max_number_of_segments: u8,
+        config: &LocalPublisherConfig,
     ) -> Self {
         Self {
             connections: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
@@ -97,6 +123,9 @@ impl<Service: service::Service> SubscriberConnections<Service> {
             static_config: static_config.clone(),
             number_of_samples,
             max_number_of_segments,
+            high_watermark: config.high_watermark,
+            low_watermark: config.low_watermark,
+            pacing: config.pacing,
         }
     }
 
@@ -114,21 +143,48 @@ impl<Service: service::Service> SubscriberConnections<Service> {
     }
 
     pub(crate) fn remove(&self, index: usize) {
-        *self.get_mut(index) = None
+        if let Some(connection) = self.get_mut(index).take() {
+            self.shared_node.record_evidence(
+                EvidenceEntity::Connection,
+                StateTransition::Destroyed,
+                format_args!("{:?} -> {:?}", self.port_id, connection.subscriber_id),
+            );
+        }
     }
 
     pub(crate) fn create(
         &self,
         index: usize,
         subscriber_details: SubscriberDetails,
+        is_delivery_enabled: bool,
+        unable_to_deliver_strategy: UnableToDeliverStrategy,
     ) -> Result<(), ZeroCopyCreationError> {
-        *self.get_mut(index) = Some(Connection::new(
+        let subscriber_id = subscriber_details.subscriber_id;
+        match Connection::new(
             self,
             subscriber_details,
             self.number_of_samples,
-        )?);
-
-        Ok(())
+            is_delivery_enabled,
+            unable_to_deliver_strategy,
+        ) {
+            Ok(connection) => {
+                *self.get_mut(index) = Some(connection);
+                self.shared_node.record_evidence(
+                    EvidenceEntity::Connection,
+                    StateTransition::Connected,
+                    format_args!("{:?} -> {:?}", self.port_id, subscriber_id),
+                );
+                Ok(())
+            }
+            Err(e) => {
+                self.shared_node.record_evidence(
+                    EvidenceEntity::Connection,
+                    StateTransition::Degraded,
+                    format_args!("{:?} -> {:?} ({:?})", self.port_id, subscriber_id, e),
+                );
+                Err(e)
+            }
+        }
     }
 
     pub(crate) fn len(&self) -> usize {
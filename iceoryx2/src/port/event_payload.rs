@@ -0,0 +1,176 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let event = node.service_builder(&"MyEventName".try_into()?)
+//!     .event()
+//!     .max_payload_size(8)
+//!     .open_or_create()?;
+//!
+//! let listener = event.listener_builder().create()?;
+//! let notifier = event.notifier_builder().create()?;
+//!
+//! let status_code: u32 = 42;
+//! notifier.notify_with_payload(&status_code.to_le_bytes())?;
+//!
+//! if let Some(event_id) = listener.try_wait_one()? {
+//!     if let Some(payload) = listener.payload() {
+//!         println!("event {:?} with payload {:?}", event_id, payload.as_bytes());
+//!     }
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::sync::atomic::Ordering;
+
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicUsize};
+
+/// The largest payload in bytes that can be attached to a notification.
+pub const MAX_EVENT_PAYLOAD_SIZE: usize = 64;
+
+const NUMBER_OF_WORDS: usize = MAX_EVENT_PAYLOAD_SIZE / core::mem::size_of::<u64>();
+
+/// The small fixed-size payload that a [`Notifier`](crate::port::notifier::Notifier) attached
+/// to its latest notification, see
+/// [`Notifier::notify_with_payload()`](crate::port::notifier::Notifier::notify_with_payload()).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct EventPayload {
+    data: [u8; MAX_EVENT_PAYLOAD_SIZE],
+    len: usize,
+}
+
+impl Debug for EventPayload {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "EventPayload {{ data: {:?} }}", self.as_bytes())
+    }
+}
+
+impl core::ops::Deref for EventPayload {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_bytes()
+    }
+}
+
+impl EventPayload {
+    /// Returns the bytes of the payload.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data[..self.len]
+    }
+
+    /// Returns the number of bytes of the payload.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true when the payload contains no bytes.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// Stores the payload of the latest notification of an event service in the dynamic config.
+/// Writers and readers are synchronized with a sequence counter. An odd counter value signals
+/// an ongoing write, a counter value of zero that no payload was ever written.
+#[repr(C)]
+#[derive(Debug)]
+pub(crate) struct EventPayloadStorage {
+    sequence: IoxAtomicU64,
+    len: IoxAtomicUsize,
+    data: [IoxAtomicU64; NUMBER_OF_WORDS],
+}
+
+impl EventPayloadStorage {
+    pub(crate) fn new() -> Self {
+        Self {
+            sequence: IoxAtomicU64::new(0),
+            len: IoxAtomicUsize::new(0),
+            data: core::array::from_fn(|_| IoxAtomicU64::new(0)),
+        }
+    }
+
+    /// Stores the payload. The caller has to ensure that the payload is not larger than
+    /// [`MAX_EVENT_PAYLOAD_SIZE`].
+    pub(crate) fn write(&self, payload: &[u8]) {
+        debug_assert!(payload.len() <= MAX_EVENT_PAYLOAD_SIZE);
+
+        let mut sequence = self.sequence.load(Ordering::Relaxed);
+        loop {
+            if sequence & 1 == 1 {
+                core::hint::spin_loop();
+                sequence = self.sequence.load(Ordering::Relaxed);
+                continue;
+            }
+
+            match self.sequence.compare_exchange_weak(
+                sequence,
+                sequence + 1,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(v) => sequence = v,
+            }
+        }
+        core::sync::atomic::fence(Ordering::Release);
+
+        let mut buffer = [0u8; MAX_EVENT_PAYLOAD_SIZE];
+        buffer[..payload.len()].copy_from_slice(payload);
+        for (n, word) in self.data.iter().enumerate() {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(&buffer[n * 8..(n + 1) * 8]);
+            word.store(u64::from_ne_bytes(bytes), Ordering::Relaxed);
+        }
+        self.len.store(payload.len(), Ordering::Relaxed);
+
+        self.sequence.store(sequence + 2, Ordering::Release);
+    }
+
+    /// Returns the latest stored payload or [`None`] when no payload was stored so far.
+    pub(crate) fn read(&self) -> Option<EventPayload> {
+        loop {
+            let sequence = self.sequence.load(Ordering::Acquire);
+            if sequence == 0 {
+                return None;
+            }
+
+            if sequence & 1 == 1 {
+                core::hint::spin_loop();
+                continue;
+            }
+
+            let mut payload = EventPayload {
+                data: [0u8; MAX_EVENT_PAYLOAD_SIZE],
+                len: self.len.load(Ordering::Relaxed),
+            };
+            for (n, word) in self.data.iter().enumerate() {
+                payload.data[n * 8..(n + 1) * 8]
+                    .copy_from_slice(&word.load(Ordering::Relaxed).to_ne_bytes());
+            }
+
+            core::sync::atomic::fence(Ordering::Acquire);
+            if self.sequence.load(Ordering::Relaxed) == sequence {
+                payload.len = payload.len.min(MAX_EVENT_PAYLOAD_SIZE);
+                return Some(payload);
+            }
+        }
+    }
+}
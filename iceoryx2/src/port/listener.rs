@@ -65,13 +65,18 @@ use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use iceoryx2_cal::event::{ListenerBuilder, ListenerWaitError, NamedConceptMgmt, TriggerId};
 use iceoryx2_cal::named_concept::{NamedConceptBuilder, NamedConceptRemoveError};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
 
 use crate::config::Config;
+use crate::node::evidence_log::{EvidenceEntity, StateTransition};
+use crate::node::resource_budget::ResourceReservation;
+use crate::node::NodeId;
+use crate::port::port_identifiers::{UniqueListenerId, UniqueNotifierId, UniquePortId};
+use crate::service;
 use crate::service::config_scheme::event_config;
 use crate::service::dynamic_config::event::ListenerDetails;
 use crate::service::naming_scheme::event_concept_name;
 use crate::service::ServiceState;
-use crate::{port::port_identifiers::UniqueListenerId, service};
 use core::sync::atomic::Ordering;
 use core::time::Duration;
 
@@ -79,6 +84,7 @@ extern crate alloc;
 use alloc::sync::Arc;
 
 use super::event_id::EventId;
+use super::event_payload::EventPayload;
 
 /// Defines the failures that can occur when a [`Listener`] is created with the
 /// [`crate::service::port_factory::listener::PortFactoryListener`].
@@ -91,6 +97,9 @@ pub enum ListenerCreateError {
     ExceedsMaxSupportedListeners,
     /// An underlying resource of the [`Service`](crate::service::Service) could not be created
     ResourceCreationFailed,
+    /// The [`Listener`] would exceed the maximum number of ports of the
+    /// [`Node`](crate::node::Node), see [`NodeBuilder`](crate::node::NodeBuilder).
+    ExceedsResourceBudgetOfNode,
 }
 
 impl core::fmt::Display for ListenerCreateError {
@@ -101,6 +110,28 @@ impl core::fmt::Display for ListenerCreateError {
 
 impl core::error::Error for ListenerCreateError {}
 
+/// Identifies the [`Notifier`](crate::port::notifier::Notifier) that sent a notification, see
+/// [`Listener::try_wait_one_with_origin()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct NotifierOrigin {
+    notifier_id: UniqueNotifierId,
+    node_id: NodeId,
+}
+
+impl NotifierOrigin {
+    /// Returns the [`UniqueNotifierId`] of the [`Notifier`](crate::port::notifier::Notifier)
+    /// that sent the notification.
+    pub fn notifier_id(&self) -> UniqueNotifierId {
+        self.notifier_id
+    }
+
+    /// Returns the [`NodeId`] of the [`Node`](crate::node::Node) that owns the
+    /// [`Notifier`](crate::port::notifier::Notifier).
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+}
+
 /// Represents the receiving endpoint of an event based communication.
 #[derive(Debug)]
 pub struct Listener<Service: service::Service> {
@@ -108,6 +139,11 @@ pub struct Listener<Service: service::Service> {
     listener: <Service::Event as iceoryx2_cal::event::Event>::Listener,
     service_state: Arc<ServiceState<Service>>,
     listener_id: UniqueListenerId,
+    shutdown_wake_up_id: Option<EventId>,
+    trigger_id_stride: usize,
+    // the last notification time after which this listener reported a missed deadline
+    reported_missed_deadline: IoxAtomicU64,
+    _resource_reservation: ResourceReservation,
 }
 
 impl<Service: service::Service> FileDescriptorBased for Listener<Service>
@@ -126,12 +162,31 @@ impl<Service: service::Service> SynchronousMultiplexing for Listener<Service> wh
 
 impl<Service: service::Service> Drop for Listener<Service> {
     fn drop(&mut self) {
+        self.service_state
+            .shared_node
+            .shutdown_intent()
+            .unregister_listener(self.listener_id);
+
         if let Some(handle) = self.dynamic_listener_handle {
             self.service_state
-                .dynamic_storage
-                .get()
-                .event()
-                .release_listener_handle(handle)
+                .shared_node
+                .closed_ports()
+                .release_unless_closed(UniquePortId::Listener(self.id()), || {
+                    self.service_state.shared_node.record_evidence(
+                        EvidenceEntity::Port,
+                        StateTransition::Destroyed,
+                        format_args!(
+                            "{:?} of service {}",
+                            self.id(),
+                            self.service_state.static_config.name()
+                        ),
+                    );
+                    self.service_state
+                        .dynamic_storage
+                        .get()
+                        .event()
+                        .release_listener_handle(handle)
+                });
         }
     }
 }
@@ -142,12 +197,30 @@ impl<Service: service::Service> Listener<Service> {
         let origin = "Listener::new()";
         let listener_id = UniqueListenerId::new();
 
+        let resource_reservation = fail!(from origin,
+                when service.__internal_state().shared_node.resource_budget().reserve(0),
+                with ListenerCreateError::ExceedsResourceBudgetOfNode,
+                "{} since it would exceed the resource budget of the node.", msg);
+
         let event_name = event_concept_name(&listener_id);
         let event_config = event_config::<Service>(service.__internal_state().shared_node.config());
 
+        // the event id after the max event id is reserved to wake up the listener when the
+        // node broadcasts its shutdown intent, notifiers are not allowed to use it
+        let static_config = service.__internal_state().static_config.event();
+        let event_id_max_value = static_config.event_id_max_value;
+        let trigger_id_stride = static_config.trigger_id_stride();
+        let shutdown_wake_up_id = event_id_max_value
+            .checked_add(1)
+            .and_then(|v| v.checked_mul(trigger_id_stride))
+            .map(EventId::new);
+        let trigger_id_max = shutdown_wake_up_id.unwrap_or(EventId::new(
+            event_id_max_value * trigger_id_stride + trigger_id_stride - 1,
+        ));
+
         let listener = fail!(from origin,
                              when <Service::Event as iceoryx2_cal::event::Event>::ListenerBuilder::new(&event_name).config(&event_config)
-                                .trigger_id_max(TriggerId::new(service.__internal_state().static_config.event().event_id_max_value))
+                                .trigger_id_max(trigger_id_max)
                                 .create(),
                              with ListenerCreateError::ResourceCreationFailed,
                              "{} since the underlying event concept \"{}\" could not be created.", msg, event_name);
@@ -157,6 +230,10 @@ impl<Service: service::Service> Listener<Service> {
             dynamic_listener_handle: None,
             listener,
             listener_id,
+            shutdown_wake_up_id,
+            trigger_id_stride,
+            reported_missed_deadline: IoxAtomicU64::new(u64::MAX),
+            _resource_reservation: resource_reservation,
         };
 
         core::sync::atomic::compiler_fence(Ordering::SeqCst);
@@ -182,6 +259,24 @@ impl<Service: service::Service> Listener<Service> {
 
         new_self.dynamic_listener_handle = Some(dynamic_listener_handle);
 
+        if let Some(id) = shutdown_wake_up_id {
+            service
+                .__internal_state()
+                .shared_node
+                .shutdown_intent()
+                .register_listener(listener_id, id);
+        }
+
+        new_self.service_state.shared_node.record_evidence(
+            EvidenceEntity::Port,
+            StateTransition::Created,
+            format_args!(
+                "{:?} of service {}",
+                new_self.id(),
+                new_self.service_state.static_config.name()
+            ),
+        );
+
         Ok(new_self)
     }
 
@@ -194,12 +289,31 @@ impl<Service: service::Service> Listener<Service> {
             .map(|v| v.value)
     }
 
+    /// Returns how often the deadline of the corresponding
+    /// [`Service`](crate::service::Service) was missed. When the service was created with
+    /// [`Builder::deadline_missed_event()`](crate::service::builder::event::Builder::deadline_missed_event())
+    /// every missed deadline is additionally reported as [`EventId`] by the wait calls.
+    pub fn missed_deadlines(&self) -> u64 {
+        self.detect_missed_deadline();
+        self.service_state
+            .dynamic_storage
+            .get()
+            .event()
+            .number_of_missed_deadlines()
+    }
+
     /// Non-blocking wait for new [`EventId`]s. Collects all [`EventId`]s that were received and
     /// calls the provided callback is with the [`EventId`] as input argument.
-    pub fn try_wait_all<F: FnMut(EventId)>(&self, callback: F) -> Result<(), ListenerWaitError> {
+    pub fn try_wait_all<F: FnMut(EventId)>(
+        &self,
+        mut callback: F,
+    ) -> Result<(), ListenerWaitError> {
         use iceoryx2_cal::event::Listener;
-        fail!(from self, when self.listener.try_wait_all(callback),
+        fail!(from self, when self.listener.try_wait_all(self.without_shutdown_wake_up(&mut callback)),
             "Failed to while calling try_wait on underlying event::Listener");
+        if let Some(event_id) = self.missed_deadline_event() {
+            callback(event_id);
+        }
         Ok(())
     }
 
@@ -208,12 +322,16 @@ impl<Service: service::Service> Listener<Service> {
     /// calls the provided callback is with the [`EventId`] as input argument.
     pub fn timed_wait_all<F: FnMut(EventId)>(
         &self,
-        callback: F,
+        mut callback: F,
         timeout: Duration,
     ) -> Result<(), ListenerWaitError> {
         use iceoryx2_cal::event::Listener;
-        fail!(from self, when self.listener.timed_wait_all(callback, timeout),
+        let timeout = self.until_deadline_or(timeout);
+        fail!(from self, when self.listener.timed_wait_all(self.without_shutdown_wake_up(&mut callback), timeout),
             "Failed to while calling timed_wait({:?}) on underlying event::Listener", timeout);
+        if let Some(event_id) = self.missed_deadline_event() {
+            callback(event_id);
+        }
         Ok(())
     }
 
@@ -222,21 +340,83 @@ impl<Service: service::Service> Listener<Service> {
     /// calls the provided callback is with the [`EventId`] as input argument.
     pub fn blocking_wait_all<F: FnMut(EventId)>(
         &self,
-        callback: F,
+        mut callback: F,
     ) -> Result<(), ListenerWaitError> {
         use iceoryx2_cal::event::Listener;
-        fail!(from self, when self.listener.blocking_wait_all(callback),
-            "Failed to while calling blocking_wait on underlying event::Listener");
+        match self.time_until_deadline() {
+            Some(timeout) => {
+                fail!(from self, when self.listener.timed_wait_all(self.without_shutdown_wake_up(&mut callback), timeout),
+                    "Failed to while calling timed_wait({:?}) on underlying event::Listener", timeout);
+            }
+            None => {
+                fail!(from self, when self.listener.blocking_wait_all(self.without_shutdown_wake_up(&mut callback)),
+                    "Failed to while calling blocking_wait on underlying event::Listener");
+            }
+        }
+        if let Some(event_id) = self.missed_deadline_event() {
+            callback(event_id);
+        }
         Ok(())
     }
 
+    /// Non-blocking wait for new [`EventId`]s. Returns every received [`EventId`] once together
+    /// with the number of times it was notified since the last wait. Event implementations
+    /// that store only a flag per [`EventId`] report a count of 1.
+    pub fn try_wait_all_with_count(
+        &self,
+    ) -> Result<impl Iterator<Item = (EventId, u64)>, ListenerWaitError> {
+        use iceoryx2_cal::event::Listener;
+        let mut counts = vec![];
+        fail!(from self, when self.listener.try_wait_all_with_count(self.collect_with_count(&mut counts)),
+            "Failed to call try_wait_all_with_count on underlying event::Listener");
+        Ok(self.with_missed_deadline_event(counts))
+    }
+
+    /// Blocking wait for new [`EventId`]s until the provided timeout has passed. Unblocks as
+    /// soon as an [`EventId`] was received and returns every received [`EventId`] once together
+    /// with the number of times it was notified since the last wait, see
+    /// [`Listener::try_wait_all_with_count()`].
+    pub fn timed_wait_all_with_count(
+        &self,
+        timeout: Duration,
+    ) -> Result<impl Iterator<Item = (EventId, u64)>, ListenerWaitError> {
+        use iceoryx2_cal::event::Listener;
+        let timeout = self.until_deadline_or(timeout);
+        let mut counts = vec![];
+        fail!(from self, when self.listener.timed_wait_all_with_count(self.collect_with_count(&mut counts), timeout),
+            "Failed to call timed_wait_all_with_count({:?}) on underlying event::Listener", timeout);
+        Ok(self.with_missed_deadline_event(counts))
+    }
+
+    /// Blocking wait for new [`EventId`]s. Unblocks as soon as an [`EventId`] was received and
+    /// returns every received [`EventId`] once together with the number of times it was
+    /// notified since the last wait, see [`Listener::try_wait_all_with_count()`].
+    pub fn blocking_wait_all_with_count(
+        &self,
+    ) -> Result<impl Iterator<Item = (EventId, u64)>, ListenerWaitError> {
+        use iceoryx2_cal::event::Listener;
+        let mut counts = vec![];
+        match self.time_until_deadline() {
+            Some(timeout) => {
+                fail!(from self, when self.listener.timed_wait_all_with_count(self.collect_with_count(&mut counts), timeout),
+                    "Failed to call timed_wait_all_with_count({:?}) on underlying event::Listener", timeout);
+            }
+            None => {
+                fail!(from self, when self.listener.blocking_wait_all_with_count(self.collect_with_count(&mut counts)),
+                    "Failed to call blocking_wait_all_with_count on underlying event::Listener");
+            }
+        }
+        Ok(self.with_missed_deadline_event(counts))
+    }
+
     /// Non-blocking wait for a new [`EventId`]. If no [`EventId`] was notified it returns [`None`].
     /// On error it returns [`ListenerWaitError`] is returned which describes the error
     /// in detail.
     pub fn try_wait_one(&self) -> Result<Option<EventId>, ListenerWaitError> {
-        use iceoryx2_cal::event::Listener;
-        Ok(fail!(from self, when self.listener.try_wait_one(),
-            "Failed to while calling try_wait on underlying event::Listener"))
+        Ok(self
+            .try_wait_one_trigger()?
+            .map(|trigger_id| self.event_id(trigger_id))
+            .or_else(|| self.missed_deadline_event()))
     }
 
     /// Blocking wait for a new [`EventId`] until either an [`EventId`] was received or the timeout
@@ -244,9 +424,10 @@ impl<Service: service::Service> Listener<Service> {
     /// On error it returns [`ListenerWaitError`] is returned which describes the error
     /// in detail.
     pub fn timed_wait_one(&self, timeout: Duration) -> Result<Option<EventId>, ListenerWaitError> {
-        use iceoryx2_cal::event::Listener;
-        Ok(fail!(from self, when self.listener.timed_wait_one(timeout),
-            "Failed to while calling timed_wait({:?}) on underlying event::Listener", timeout))
+        Ok(self
+            .timed_wait_one_trigger(timeout)?
+            .map(|trigger_id| self.event_id(trigger_id))
+            .or_else(|| self.missed_deadline_event()))
     }
 
     /// Blocking wait for a new [`EventId`].
@@ -254,15 +435,311 @@ impl<Service: service::Service> Listener<Service> {
     /// On error it returns [`ListenerWaitError`] is returned which describes the error
     /// in detail.
     pub fn blocking_wait_one(&self) -> Result<Option<EventId>, ListenerWaitError> {
+        Ok(self
+            .blocking_wait_one_trigger()?
+            .map(|trigger_id| self.event_id(trigger_id))
+            .or_else(|| self.missed_deadline_event()))
+    }
+
+    /// Non-blocking wait for a new [`EventId`] that returns additionally the [`NotifierOrigin`]
+    /// of the [`Notifier`](crate::port::notifier::Notifier) that sent it. The origin is only
+    /// available when the [`Service`](crate::service::Service) was created with
+    /// [`Builder::enable_notifier_origin()`](crate::service::builder::event::Builder::enable_notifier_origin())
+    /// and the [`Notifier`](crate::port::notifier::Notifier) still exists, otherwise it is
+    /// [`None`]. If no [`EventId`] was notified it returns [`None`].
+    pub fn try_wait_one_with_origin(
+        &self,
+    ) -> Result<Option<(EventId, Option<NotifierOrigin>)>, ListenerWaitError> {
+        Ok(self
+            .try_wait_one_trigger()?
+            .map(|trigger_id| self.event_id_and_origin(trigger_id))
+            .or_else(|| self.missed_deadline_event().map(|id| (id, None))))
+    }
+
+    /// Like [`Listener::timed_wait_one()`] but returns additionally the [`NotifierOrigin`], see
+    /// [`Listener::try_wait_one_with_origin()`].
+    pub fn timed_wait_one_with_origin(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<(EventId, Option<NotifierOrigin>)>, ListenerWaitError> {
+        Ok(self
+            .timed_wait_one_trigger(timeout)?
+            .map(|trigger_id| self.event_id_and_origin(trigger_id))
+            .or_else(|| self.missed_deadline_event().map(|id| (id, None))))
+    }
+
+    /// Like [`Listener::blocking_wait_one()`] but returns additionally the [`NotifierOrigin`],
+    /// see [`Listener::try_wait_one_with_origin()`].
+    pub fn blocking_wait_one_with_origin(
+        &self,
+    ) -> Result<Option<(EventId, Option<NotifierOrigin>)>, ListenerWaitError> {
+        Ok(self
+            .blocking_wait_one_trigger()?
+            .map(|trigger_id| self.event_id_and_origin(trigger_id))
+            .or_else(|| self.missed_deadline_event().map(|id| (id, None))))
+    }
+
+    fn try_wait_one_trigger(&self) -> Result<Option<TriggerId>, ListenerWaitError> {
+        use iceoryx2_cal::event::Listener;
+        loop {
+            let trigger_id = fail!(from self, when self.listener.try_wait_one(),
+                "Failed to while calling try_wait on underlying event::Listener");
+
+            if !self.is_shutdown_wake_up(trigger_id)
+                && !self.is_reported_missed_deadline(trigger_id)
+            {
+                return Ok(trigger_id);
+            }
+        }
+    }
+
+    fn timed_wait_one_trigger(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<TriggerId>, ListenerWaitError> {
+        use iceoryx2_cal::event::Listener;
+        let timeout = self.until_deadline_or(timeout);
+        let trigger_id = fail!(from self, when self.listener.timed_wait_one(timeout),
+            "Failed to while calling timed_wait({:?}) on underlying event::Listener", timeout);
+
+        match self.is_shutdown_wake_up(trigger_id) || self.is_reported_missed_deadline(trigger_id) {
+            true => self.try_wait_one_trigger(),
+            false => Ok(trigger_id),
+        }
+    }
+
+    fn blocking_wait_one_trigger(&self) -> Result<Option<TriggerId>, ListenerWaitError> {
         use iceoryx2_cal::event::Listener;
-        Ok(fail!(from self, when self.listener.blocking_wait_one(),
-            "Failed to while calling blocking_wait on underlying event::Listener"))
+        let trigger_id = match self.time_until_deadline() {
+            Some(timeout) => {
+                fail!(from self, when self.listener.timed_wait_one(timeout),
+                    "Failed to while calling timed_wait({:?}) on underlying event::Listener", timeout)
+            }
+            None => {
+                fail!(from self, when self.listener.blocking_wait_one(),
+                    "Failed to while calling blocking_wait on underlying event::Listener")
+            }
+        };
+
+        match self.is_shutdown_wake_up(trigger_id) || self.is_reported_missed_deadline(trigger_id) {
+            true => self.try_wait_one_trigger(),
+            false => Ok(trigger_id),
+        }
+    }
+
+    /// Returns the payload that was attached to the latest notification of the
+    /// [`Service`](crate::service::Service) with
+    /// [`Notifier::notify_with_payload()`](crate::port::notifier::Notifier::notify_with_payload())
+    /// or [`None`] when no payload was attached so far. When multiple
+    /// [`Notifier`](crate::port::notifier::Notifier)s attach payloads concurrently, the latest
+    /// payload overwrites all previous ones.
+    pub fn payload(&self) -> Option<EventPayload> {
+        self.service_state
+            .dynamic_storage
+            .get()
+            .event()
+            .latest_payload()
     }
 
     /// Returns the [`UniqueListenerId`] of the [`Listener`]
     pub fn id(&self) -> UniqueListenerId {
         self.listener_id
     }
+
+    /// Returns true when the [`Node`](crate::node::Node) that created the [`Listener`]
+    /// broadcast its shutdown intent with
+    /// [`Node::broadcast_shutdown_intent()`](crate::node::Node::broadcast_shutdown_intent()).
+    /// The broadcast wakes up the [`Listener`] and the
+    /// [`WaitSet`](crate::waitset::WaitSet)s it is attached to without delivering an
+    /// [`EventId`].
+    pub fn is_shutdown_requested(&self) -> bool {
+        self.service_state
+            .shared_node
+            .shutdown_intent()
+            .is_requested()
+    }
+
+    fn is_shutdown_wake_up(&self, trigger_id: Option<TriggerId>) -> bool {
+        trigger_id.is_some() && trigger_id == self.shutdown_wake_up_id
+    }
+
+    // Returns the time of the last notification, in nanoseconds since the service creation,
+    // when the deadline after it was missed. The miss is counted in the dynamic config.
+    fn detect_missed_deadline(&self) -> Option<u64> {
+        let deadline = self.service_state.static_config.event().deadline?;
+        let dynamic_config = self.service_state.dynamic_storage.get().event();
+        let last_notification = dynamic_config
+            .elapsed_time_since_last_notification
+            .load(Ordering::Relaxed);
+        let duration_since_creation = deadline.creation_time.elapsed().ok()?;
+        let duration_since_last_notification =
+            duration_since_creation.saturating_sub(Duration::from_nanos(last_notification));
+
+        if duration_since_last_notification <= deadline.value {
+            return None;
+        }
+
+        dynamic_config.register_missed_deadline(last_notification);
+        Some(last_notification)
+    }
+
+    // Returns the deadline missed event once for every missed deadline.
+    fn missed_deadline_event(&self) -> Option<EventId> {
+        let event_id = self
+            .service_state
+            .static_config
+            .event()
+            .deadline_missed_event()?;
+        let last_notification = self.detect_missed_deadline()?;
+
+        if self
+            .reported_missed_deadline
+            .swap(last_notification, Ordering::Relaxed)
+            == last_notification
+        {
+            return None;
+        }
+
+        Some(event_id)
+    }
+
+    // The deadline missed event of a delayed notifier is dropped when the listener has
+    // reported the missed deadline already on its own.
+    fn is_reported_missed_deadline(&self, trigger_id: Option<TriggerId>) -> bool {
+        let trigger_id = match trigger_id {
+            Some(v) => v,
+            None => return false,
+        };
+
+        if Some(self.event_id(trigger_id))
+            != self
+                .service_state
+                .static_config
+                .event()
+                .deadline_missed_event()
+        {
+            return false;
+        }
+
+        let last_missed_deadline = self
+            .service_state
+            .dynamic_storage
+            .get()
+            .event()
+            .last_missed_deadline();
+        self.reported_missed_deadline
+            .swap(last_missed_deadline, Ordering::Relaxed)
+            == last_missed_deadline
+    }
+
+    // Returns the time until the deadline is missed when the service reports missed
+    // deadlines and the current one was not reported yet.
+    fn time_until_deadline(&self) -> Option<Duration> {
+        let static_config = self.service_state.static_config.event();
+        static_config.deadline_missed_event()?;
+        let deadline = static_config.deadline?;
+        let last_notification = self
+            .service_state
+            .dynamic_storage
+            .get()
+            .event()
+            .elapsed_time_since_last_notification
+            .load(Ordering::Relaxed);
+
+        if self.reported_missed_deadline.load(Ordering::Relaxed) == last_notification {
+            return None;
+        }
+
+        let duration_since_creation = deadline.creation_time.elapsed().ok()?;
+        let duration_since_last_notification =
+            duration_since_creation.saturating_sub(Duration::from_nanos(last_notification));
+
+        // one extra microsecond so that the deadline has definitely passed on wake up
+        Some(
+            deadline
+                .value
+                .saturating_sub(duration_since_last_notification)
+                + Duration::from_micros(1),
+        )
+    }
+
+    fn until_deadline_or(&self, timeout: Duration) -> Duration {
+        match self.time_until_deadline() {
+            Some(v) => v.min(timeout),
+            None => timeout,
+        }
+    }
+
+    fn event_id(&self, trigger_id: TriggerId) -> EventId {
+        EventId::new(trigger_id.as_value() / self.trigger_id_stride)
+    }
+
+    fn event_id_and_origin(&self, trigger_id: TriggerId) -> (EventId, Option<NotifierOrigin>) {
+        let origin = match self
+            .service_state
+            .static_config
+            .event()
+            .has_notifier_origin()
+        {
+            true => self
+                .service_state
+                .dynamic_storage
+                .get()
+                .event()
+                .notifier_details_at(trigger_id.as_value() % self.trigger_id_stride)
+                .map(|details| NotifierOrigin {
+                    notifier_id: details.notifier_id,
+                    node_id: details.node_id,
+                }),
+            false => None,
+        };
+
+        (self.event_id(trigger_id), origin)
+    }
+
+    fn collect_with_count<'a>(
+        &'a self,
+        counts: &'a mut Vec<(EventId, u64)>,
+    ) -> impl FnMut(TriggerId, u64) + 'a {
+        move |trigger_id, count| {
+            if !self.is_shutdown_wake_up(Some(trigger_id))
+                && !self.is_reported_missed_deadline(Some(trigger_id))
+            {
+                add_count(counts, self.event_id(trigger_id), count)
+            }
+        }
+    }
+
+    fn with_missed_deadline_event(
+        &self,
+        mut counts: Vec<(EventId, u64)>,
+    ) -> impl Iterator<Item = (EventId, u64)> {
+        if let Some(event_id) = self.missed_deadline_event() {
+            add_count(&mut counts, event_id, 1);
+        }
+        counts.into_iter()
+    }
+
+    fn without_shutdown_wake_up<F: FnMut(EventId)>(
+        &self,
+        mut callback: F,
+    ) -> impl FnMut(TriggerId) + '_ {
+        move |trigger_id| {
+            if !self.is_shutdown_wake_up(Some(trigger_id))
+                && !self.is_reported_missed_deadline(Some(trigger_id))
+            {
+                callback(self.event_id(trigger_id))
+            }
+        }
+    }
+}
+
+// the notifications of different notifiers with the same EventId are merged into one count
+fn add_count(counts: &mut Vec<(EventId, u64)>, event_id: EventId, count: u64) {
+    match counts.iter_mut().find(|(id, _)| *id == event_id) {
+        Some((_, value)) => *value += count,
+        None => counts.push((event_id, count)),
+    }
 }
 
 pub(crate) unsafe fn remove_connection_of_listener<Service: service::Service>(
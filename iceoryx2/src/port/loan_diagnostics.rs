@@ -0,0 +1,177 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Only available with the `loan_diagnostics` feature. Every loan of a port records the code
+//! location that acquired it and when, so that a
+//! [`PublisherLoanError::ExceedsMaxLoanedSamples`](crate::port::publisher::PublisherLoanError::ExceedsMaxLoanedSamples)
+//! or a [`LoanError::ExceedsMaxLoans`](crate::port::LoanError::ExceedsMaxLoans) can be traced
+//! back to the code path that holds on to the samples. The error log contains the location and
+//! the age of the oldest outstanding loan.
+//!
+//! The loans are tracked for
+//!  * samples loaned from a [`Publisher`](crate::port::publisher::Publisher), see
+//!    [`Publisher::outstanding_loans()`](crate::port::publisher::Publisher::outstanding_loans()),
+//!  * requests loaned from a [`Client`](crate::port::client::Client), see
+//!    [`Client::outstanding_loans()`](crate::port::client::Client::outstanding_loans()),
+//!  * responses loaned from an [`ActiveRequest`](crate::active_request::ActiveRequest), see
+//!    [`Server::outstanding_loans()`](crate::port::server::Server::outstanding_loans()).
+//!
+//! When the `loan_backtraces` feature is enabled additionally the full backtrace of every
+//! loan is captured, see [`OutstandingLoan::backtrace()`].
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #     .publish_subscribe::<u64>()
+//! #     .open_or_create()?;
+//! let publisher = service.publisher_builder().create()?;
+//! let sample = publisher.loan_uninit()?;
+//!
+//! for loan in publisher.outstanding_loans() {
+//!     println!("sample loaned at {} {:?} ago", loan.location(), loan.age());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use core::panic::Location;
+use core::time::Duration;
+use std::sync::Mutex;
+
+#[cfg(feature = "loan_backtraces")]
+use std::backtrace::Backtrace;
+
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_posix::clock::Time;
+use iceoryx2_cal::shm_allocator::PointerOffset;
+
+#[cfg(feature = "loan_backtraces")]
+extern crate alloc;
+#[cfg(feature = "loan_backtraces")]
+use alloc::sync::Arc;
+
+/// Describes a sample that was loaned from a port and is not yet sent or dropped.
+#[derive(Debug, Clone)]
+#[cfg_attr(not(feature = "loan_backtraces"), derive(Copy))]
+pub struct OutstandingLoan {
+    location: &'static Location<'static>,
+    timestamp: Time,
+    offset: PointerOffset,
+    #[cfg(feature = "loan_backtraces")]
+    backtrace: Arc<Backtrace>,
+}
+
+// the backtrace is only additional information and does not identify the loan
+impl PartialEq for OutstandingLoan {
+    fn eq(&self, other: &Self) -> bool {
+        self.location == other.location
+            && self.timestamp == other.timestamp
+            && self.offset == other.offset
+    }
+}
+
+impl Eq for OutstandingLoan {}
+
+impl OutstandingLoan {
+    /// Returns the source code location where the sample was loaned.
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+
+    /// Returns the point in time when the sample was loaned.
+    pub fn timestamp(&self) -> Time {
+        self.timestamp
+    }
+
+    /// Returns how long the sample is already loaned. If the elapsed time could not be
+    /// acquired it returns [`Duration::ZERO`].
+    pub fn age(&self) -> Duration {
+        self.timestamp.elapsed().unwrap_or_default()
+    }
+
+    /// Returns the backtrace of the call that loaned the sample. Only available with the
+    /// `loan_backtraces` feature.
+    #[cfg(feature = "loan_backtraces")]
+    pub fn backtrace(&self) -> &Backtrace {
+        &self.backtrace
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct LoanTracker {
+    loans: Mutex<Vec<OutstandingLoan>>,
+}
+
+impl LoanTracker {
+    fn loans(&self) -> std::sync::MutexGuard<'_, Vec<OutstandingLoan>> {
+        match self.loans.lock() {
+            Ok(guard) => guard,
+            Err(_) => {
+                fatal_panic!(from self, "This should never happen! The loan tracker mutex is poisoned.")
+            }
+        }
+    }
+
+    pub(crate) fn add(&self, offset: PointerOffset, location: &'static Location<'static>) {
+        self.loans().push(OutstandingLoan {
+            location,
+            timestamp: Time::now().unwrap_or_default(),
+            offset,
+            #[cfg(feature = "loan_backtraces")]
+            backtrace: Arc::new(Backtrace::force_capture()),
+        });
+    }
+
+    pub(crate) fn remove(&self, offset: PointerOffset) {
+        let mut loans = self.loans();
+        if let Some(index) = loans.iter().position(|loan| loan.offset == offset) {
+            loans.swap_remove(index);
+        }
+    }
+
+    pub(crate) fn outstanding_loans(&self) -> Vec<OutstandingLoan> {
+        self.loans().clone()
+    }
+
+    fn oldest_loan(&self) -> Option<OutstandingLoan> {
+        self.loans()
+            .iter()
+            .min_by_key(|loan| loan.timestamp.as_duration())
+            .cloned()
+    }
+
+    /// Describes the oldest outstanding loan for the error log when the maximum number of
+    /// loans is exceeded. Empty when nothing is loaned.
+    pub(crate) fn oldest_loan_description(&self) -> String {
+        match self.oldest_loan() {
+            #[cfg(not(feature = "loan_backtraces"))]
+            Some(loan) => format!(
+                " The oldest outstanding loan was acquired {:?} ago at {}.",
+                loan.age(),
+                loan.location()
+            ),
+            #[cfg(feature = "loan_backtraces")]
+            Some(loan) => format!(
+                " The oldest outstanding loan was acquired {:?} ago at {}:\n{}",
+                loan.age(),
+                loan.location(),
+                loan.backtrace()
+            ),
+            None => String::new(),
+        }
+    }
+}
@@ -16,18 +16,39 @@ use tiny_fn::tiny_fn;
 
 pub(crate) mod details;
 
+/// Receiving endpoint (port) for publish-subscribe based communication that hands out samples
+/// which must be acknowledged.
+pub mod ack_subscriber;
+/// Wraps a [`Listener`](crate::port::listener::Listener) so that it can be awaited in a tokio
+/// runtime.
+#[cfg(feature = "tokio")]
+pub mod async_listener;
 /// Sends requests to a [`Server`](crate::port::server::Server) and receives responses.
 pub mod client;
+/// Describes the current state of the data segment of a
+/// [`Publisher`](crate::port::publisher::Publisher).
+pub mod data_segment_info;
 /// Defines the event id used to identify the source of an event.
 pub mod event_id;
+/// Defines the small fixed-size payload that can be attached to a notification.
+pub mod event_payload;
 /// Receiving endpoint (port) for event based communication
 pub mod listener;
+/// Tracks the code locations of all outstanding loans of a port.
+#[cfg(feature = "loan_diagnostics")]
+pub mod loan_diagnostics;
 /// Sending endpoint (port) for event based communication
 pub mod notifier;
 /// Defines port specific unique ids. Used to identify source/destination while communicating.
 pub mod port_identifiers;
 /// Sending endpoint (port) for publish-subscribe based communication
 pub mod publisher;
+/// Sending endpoint (port) for publish-subscribe based communication that serializes its
+/// payload with serde
+pub mod serde_publisher;
+/// Receiving endpoint (port) for publish-subscribe based communication that deserializes its
+/// payload with serde
+pub mod serde_subscriber;
 /// Receives requests from a [`Client`](crate::port::client::Client) port and sends back responses.
 pub mod server;
 /// Receiving endpoint (port) for publish-subscribe based communication
@@ -38,6 +59,38 @@ pub mod update_connections;
 
 use crate::port::port_identifiers::*;
 use crate::service;
+use crate::service::dynamic_config::publish_subscribe::SubscriberDetails;
+
+pub use iceoryx2_cal::zero_copy_connection::ZeroCopyConnectionCounters;
+
+/// Defines a failure that can occur when a [`Client`](crate::port::client::Client) loans a
+/// request or an [`ActiveRequest`](crate::active_request::ActiveRequest) loans a response.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum LoanError {
+    /// The data segment of the port does not have any more memory left
+    OutOfMemory,
+    /// The maximum amount of samples a user can borrow in parallel is defined in the
+    /// [`StaticConfig`](crate::service::static_config::request_response::StaticConfig) of the
+    /// [`Service`](crate::service::Service). When this is exceeded the loan fails.
+    ExceedsMaxLoans,
+    /// The provided slice size exceeds the configured max slice size of the port. A new port
+    /// has to be created with a greater
+    /// [`PortFactoryClient::initial_max_slice_len()`](crate::service::port_factory::client::PortFactoryClient::initial_max_slice_len())
+    /// or
+    /// [`PortFactoryServer::initial_max_slice_len()`](crate::service::port_factory::server::PortFactoryServer::initial_max_slice_len())
+    /// to loan a slice with this size.
+    ExceedsMaxLoanSize,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+}
+
+impl core::fmt::Display for LoanError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "LoanError::{:?}", self)
+    }
+}
+
+impl core::error::Error for LoanError {}
 
 /// Defines the action a port shall take when an internal failure occurs. Can happen when the
 /// system is corrupted and files are modified by non-iceoryx2 instances. Is used as return value of
@@ -62,3 +115,56 @@ impl Debug for DegrationCallback<'_> {
         write!(f, "")
     }
 }
+
+tiny_fn! {
+    /// Decides if a [`Publisher`](crate::port::publisher::Publisher) delivers its samples to
+    /// the [`Subscriber`](crate::port::subscriber::Subscriber) with the provided details.
+    pub struct DeliveryFilter = Fn(subscriber: SubscriberDetails) -> bool;
+}
+
+impl Debug for DeliveryFilter<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "")
+    }
+}
+
+/// Describes which watermark the fill level of the buffer of a connected
+/// [`Subscriber`](crate::port::subscriber::Subscriber) crossed. Is passed to the
+/// [`BackpressureCallback`] of the [`Publisher`](crate::port::publisher::Publisher).
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum BackpressureEvent {
+    /// The fill level reached the high watermark. When the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) does not catch up, further samples
+    /// are blocked, discarded or overflow, depending on the
+    /// [`UnableToDeliverStrategy`](crate::service::port_factory::publisher::UnableToDeliverStrategy)
+    /// and the safe overflow setting of the [`Service`](crate::service::Service).
+    HighWatermarkReached,
+    /// The fill level dropped to or below the low watermark after it reached the high
+    /// watermark.
+    LowWatermarkReached,
+}
+
+tiny_fn! {
+    /// Is called by a [`Publisher`](crate::port::publisher::Publisher) whenever the fill level of
+    /// the buffer of a connected [`Subscriber`](crate::port::subscriber::Subscriber) crosses a
+    /// watermark.
+    pub struct BackpressureCallback = Fn(subscriber_id: UniqueSubscriberId, event: BackpressureEvent);
+}
+
+impl Debug for BackpressureCallback<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "")
+    }
+}
+
+tiny_fn! {
+    /// Is called by a [`Client`](crate::port::client::Client) whenever it established the
+    /// connection to a new [`Server`](crate::port::server::Server).
+    pub struct ServerConnectedCallback = Fn(server_id: UniqueServerId);
+}
+
+impl Debug for ServerConnectedCallback<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "")
+    }
+}
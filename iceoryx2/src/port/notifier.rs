@@ -36,8 +36,10 @@
 //! ```
 
 use super::{event_id::EventId, port_identifiers::UniqueListenerId};
+use crate::node::evidence_log::{EvidenceEntity, StateTransition};
 use crate::{
-    port::port_identifiers::UniqueNotifierId,
+    node::{port_registry::RegisteredPort, resource_budget::ResourceReservation},
+    port::port_identifiers::{UniqueNotifierId, UniquePortId},
     service::{
         self,
         config_scheme::event_config,
@@ -66,6 +68,9 @@ pub enum NotifierCreateError {
     /// defined in [`crate::config::Config`]. When this is exceeded no more [`Notifier`]s
     /// can be created for a specific [`Service`](crate::service::Service).
     ExceedsMaxSupportedNotifiers,
+    /// The [`Notifier`] would exceed the maximum number of ports of the
+    /// [`Node`](crate::node::Node), see [`NodeBuilder`](crate::node::NodeBuilder).
+    ExceedsResourceBudgetOfNode,
 }
 
 impl core::fmt::Display for NotifierCreateError {
@@ -90,6 +95,9 @@ pub enum NotifierNotifyError {
     /// The notification was delivered but the elapsed system time could not be acquired.
     /// Therefore, it is unknown if the deadline was missed or not.
     UnableToAcquireElapsedTime,
+    /// A [`Notifier::notify_with_payload()`] was called and the provided payload is larger
+    /// than the maximum payload size of the [`Service`](crate::service::Service).
+    ExceedsMaxPayloadSize,
 }
 
 impl core::fmt::Display for NotifierNotifyError {
@@ -107,16 +115,26 @@ struct Connection<Service: service::Service> {
 }
 
 #[derive(Debug)]
-struct ListenerConnections<Service: service::Service> {
+pub(crate) struct ListenerConnections<Service: service::Service> {
     #[allow(clippy::type_complexity)]
     connections: Vec<UnsafeCell<Option<Connection<Service>>>>,
+    listener_list_state: UnsafeCell<ContainerState<ListenerDetails>>,
     service_state: Arc<ServiceState<Service>>,
 }
 
 impl<Service: service::Service> ListenerConnections<Service> {
     fn new(size: usize, service_state: Arc<ServiceState<Service>>) -> Self {
+        let listener_list_state = unsafe {
+            service_state
+                .dynamic_storage
+                .get()
+                .event()
+                .listeners
+                .get_state()
+        };
         let mut new_self = Self {
             connections: vec![],
+            listener_list_state: UnsafeCell::new(listener_list_state),
             service_state,
         };
 
@@ -165,6 +183,60 @@ impl<Service: service::Service> ListenerConnections<Service> {
         }
     }
 
+    pub(crate) fn update_connections(&self) {
+        if unsafe {
+            self.service_state
+                .dynamic_storage
+                .get()
+                .event()
+                .listeners
+                .update_state(&mut *self.listener_list_state.get())
+        } {
+            self.populate_listener_channels();
+        }
+    }
+
+    fn populate_listener_channels(&self) {
+        let mut visited_indices = vec![];
+        visited_indices.resize(self.len(), None);
+
+        unsafe {
+            (*self.listener_list_state.get()).for_each(|h, listener_id| {
+                visited_indices[h.index() as usize] = Some(*listener_id);
+                CallbackProgression::Continue
+            })
+        };
+
+        for (i, index) in visited_indices.iter().enumerate() {
+            match index {
+                Some(details) => {
+                    let create_connection = match self.get(i) {
+                        None => true,
+                        Some(connection) => {
+                            let is_connected = connection.listener_id != details.listener_id;
+                            if is_connected {
+                                self.remove(i);
+                            }
+                            is_connected
+                        }
+                    };
+
+                    if create_connection {
+                        if self.service_state.shared_node.port_registry().is_locked() {
+                            warn!(from self,
+                                "Unable to establish connection to new listener {:?} since the node is locked.",
+                                details.listener_id);
+                            continue;
+                        }
+
+                        self.create(i, details.listener_id);
+                    }
+                }
+                None => self.remove(i),
+            }
+        }
+    }
+
     fn get(&self, index: usize) -> &Option<Connection<Service>> {
         unsafe { &(*self.connections[index].get()) }
     }
@@ -186,13 +258,13 @@ impl<Service: service::Service> ListenerConnections<Service> {
 /// Represents the sending endpoint of an event based communication.
 #[derive(Debug)]
 pub struct Notifier<Service: service::Service> {
-    listener_connections: ListenerConnections<Service>,
-    listener_list_state: UnsafeCell<ContainerState<ListenerDetails>>,
+    listener_connections: Arc<ListenerConnections<Service>>,
     default_event_id: EventId,
     event_id_max_value: usize,
     dynamic_notifier_handle: Option<ContainerHandle>,
     notifier_id: UniqueNotifierId,
     on_drop_notification: Option<EventId>,
+    _resource_reservation: ResourceReservation,
 }
 
 impl<Service: service::Service> Drop for Notifier<Service> {
@@ -205,12 +277,26 @@ impl<Service: service::Service> Drop for Notifier<Service> {
         }
 
         if let Some(handle) = self.dynamic_notifier_handle {
-            self.listener_connections
-                .service_state
-                .dynamic_storage
-                .get()
-                .event()
-                .release_notifier_handle(handle)
+            let service_state = &self.listener_connections.service_state;
+            service_state
+                .shared_node
+                .closed_ports()
+                .release_unless_closed(UniquePortId::Notifier(self.id()), || {
+                    service_state.shared_node.record_evidence(
+                        EvidenceEntity::Port,
+                        StateTransition::Destroyed,
+                        format_args!(
+                            "{:?} of service {}",
+                            self.id(),
+                            service_state.static_config.name()
+                        ),
+                    );
+                    service_state
+                        .dynamic_storage
+                        .get()
+                        .event()
+                        .release_notifier_handle(handle)
+                });
         }
     }
 }
@@ -258,21 +344,26 @@ impl<Service: service::Service> Notifier<Service> {
             .event()
             .listeners;
 
+        let resource_reservation = fail!(from origin,
+                when service.__internal_state().shared_node.resource_budget().reserve(0),
+                with NotifierCreateError::ExceedsResourceBudgetOfNode,
+                "{} since it would exceed the resource budget of the node.", msg);
+
         let static_config = service.__internal_state().static_config.event();
         let mut new_self = Self {
-            listener_connections: ListenerConnections::new(
+            listener_connections: Arc::new(ListenerConnections::new(
                 listener_list.capacity(),
                 service.__internal_state().clone(),
-            ),
+            )),
             default_event_id,
-            listener_list_state: unsafe { UnsafeCell::new(listener_list.get_state()) },
             event_id_max_value: static_config.event_id_max_value,
             dynamic_notifier_handle: None,
             notifier_id,
             on_drop_notification: None,
+            _resource_reservation: resource_reservation,
         };
 
-        new_self.populate_listener_channels();
+        new_self.listener_connections.populate_listener_channels();
 
         core::sync::atomic::compiler_fence(Ordering::SeqCst);
 
@@ -297,55 +388,33 @@ impl<Service: service::Service> Notifier<Service> {
         };
         new_self.dynamic_notifier_handle = Some(dynamic_notifier_handle);
 
-        Ok(new_self)
-    }
-
-    fn update_connections(&self) {
-        if unsafe {
-            self.listener_connections
-                .service_state
-                .dynamic_storage
-                .get()
-                .event()
-                .listeners
-                .update_state(&mut *self.listener_list_state.get())
-        } {
-            self.populate_listener_channels();
-        }
-    }
-
-    fn populate_listener_channels(&self) {
-        let mut visited_indices = vec![];
-        visited_indices.resize(self.listener_connections.len(), None);
-
-        unsafe {
-            (*self.listener_list_state.get()).for_each(|h, listener_id| {
-                visited_indices[h.index() as usize] = Some(*listener_id);
-                CallbackProgression::Continue
-            })
-        };
+        service
+            .__internal_state()
+            .shared_node
+            .port_registry()
+            .register(RegisteredPort::Notifier(Arc::downgrade(
+                &new_self.listener_connections,
+            )));
 
-        for (i, index) in visited_indices.iter().enumerate() {
-            match index {
-                Some(details) => {
-                    let create_connection = match self.listener_connections.get(i) {
-                        None => true,
-                        Some(connection) => {
-                            let is_connected = connection.listener_id != details.listener_id;
-                            if is_connected {
-                                self.listener_connections.remove(i);
-                            }
-                            is_connected
-                        }
-                    };
+        new_self
+            .listener_connections
+            .service_state
+            .shared_node
+            .record_evidence(
+                EvidenceEntity::Port,
+                StateTransition::Created,
+                format_args!(
+                    "{:?} of service {}",
+                    new_self.id(),
+                    new_self
+                        .listener_connections
+                        .service_state
+                        .static_config
+                        .name()
+                ),
+            );
 
-                    if create_connection {
-                        self.listener_connections.create(i, details.listener_id);
-                    }
-                }
-                None => self.listener_connections.remove(i),
-            }
-        }
+        Ok(new_self)
     }
 
     /// Returns the [`UniqueNotifierId`] of the [`Notifier`]
@@ -353,6 +422,17 @@ impl<Service: service::Service> Notifier<Service> {
         self.notifier_id
     }
 
+    /// Returns true when the [`Node`](crate::node::Node) that created the [`Notifier`]
+    /// broadcast its shutdown intent with
+    /// [`Node::broadcast_shutdown_intent()`](crate::node::Node::broadcast_shutdown_intent()).
+    pub fn is_shutdown_requested(&self) -> bool {
+        self.listener_connections
+            .service_state
+            .shared_node
+            .shutdown_intent()
+            .is_requested()
+    }
+
     /// Notifies all [`crate::port::listener::Listener`] connected to the service with the default
     /// event id provided on creation.
     /// On success the number of
@@ -381,21 +461,77 @@ impl<Service: service::Service> Notifier<Service> {
         &self,
         value: EventId,
     ) -> Result<usize, NotifierNotifyError> {
-        let msg = "Unable to notify event";
-        self.update_connections();
+        self.notify_impl(&[value], None)
+    }
 
-        use iceoryx2_cal::event::Notifier;
-        let mut number_of_triggered_listeners = 0;
+    /// Attaches the payload to the notification and notifies all
+    /// [`crate::port::listener::Listener`] connected to the service with the default event id
+    /// provided on creation. The [`crate::port::listener::Listener`] can read the payload of
+    /// the latest notification with
+    /// [`Listener::payload()`](crate::port::listener::Listener::payload()).
+    /// On success the number of
+    /// [`crate::port::listener::Listener`]s that were notified otherwise it returns
+    /// [`NotifierNotifyError`].
+    pub fn notify_with_payload(&self, payload: &[u8]) -> Result<usize, NotifierNotifyError> {
+        self.notify_impl(&[self.default_event_id], Some(payload))
+    }
 
-        if self.event_id_max_value < value.as_value() {
-            fail!(from self, with NotifierNotifyError::EventIdOutOfBounds,
-                            "{} since the EventId {:?} exceeds the maximum supported EventId value of {}.",
-                            msg, value, self.event_id_max_value);
+    /// Attaches the payload to the notification and notifies all
+    /// [`crate::port::listener::Listener`] connected to the service with a custom [`EventId`].
+    /// On success the number of
+    /// [`crate::port::listener::Listener`]s that were notified otherwise it returns
+    /// [`NotifierNotifyError`].
+    pub fn notify_with_custom_event_id_and_payload(
+        &self,
+        value: EventId,
+        payload: &[u8],
+    ) -> Result<usize, NotifierNotifyError> {
+        self.notify_impl(&[value], Some(payload))
+    }
+
+    /// Notifies all [`crate::port::listener::Listener`] connected to the service with all
+    /// provided [`EventId`]s at once. The [`EventId`]s are merged in the pending
+    /// [`EventId`]s of every [`crate::port::listener::Listener`] which is woken up only once,
+    /// so that an event storm does not wake up the [`crate::port::listener::Listener`]s for
+    /// every single [`EventId`].
+    /// On success the number of
+    /// [`crate::port::listener::Listener`]s that were notified otherwise it returns
+    /// [`NotifierNotifyError`].
+    pub fn notify_batch(&self, values: &[EventId]) -> Result<usize, NotifierNotifyError> {
+        self.notify_impl(values, None)
+    }
+
+    // encodes the index of the notifier in the dynamic config into the trigger id when the
+    // service has the notifier origin enabled
+    fn trigger_id(&self, value: EventId) -> EventId {
+        let static_config = self
+            .listener_connections
+            .service_state
+            .static_config
+            .event();
+        if !static_config.has_notifier_origin() {
+            return value;
         }
 
+        let notifier_index = self
+            .dynamic_notifier_handle
+            .map(|handle| handle.index() as usize)
+            .unwrap_or(0);
+        EventId::new(value.as_value() * static_config.trigger_id_stride() + notifier_index)
+    }
+
+    fn notify_listeners(&self, trigger_ids: &[EventId]) -> usize {
+        use iceoryx2_cal::event::Notifier;
+        let mut number_of_triggered_listeners = 0;
+
         for i in 0..self.listener_connections.len() {
             if let Some(ref connection) = self.listener_connections.get(i) {
-                match connection.notifier.notify(value) {
+                let result = match trigger_ids {
+                    [trigger_id] => connection.notifier.notify(*trigger_id),
+                    _ => connection.notifier.notify_batch(trigger_ids),
+                };
+
+                match result {
                     Err(iceoryx2_cal::event::NotifierNotifyError::Disconnected) => {
                         self.listener_connections.remove(i);
                     }
@@ -410,6 +546,71 @@ impl<Service: service::Service> Notifier<Service> {
             }
         }
 
+        number_of_triggered_listeners
+    }
+
+    fn notify_impl(
+        &self,
+        values: &[EventId],
+        payload: Option<&[u8]>,
+    ) -> Result<usize, NotifierNotifyError> {
+        let msg = "Unable to notify event";
+        if self
+            .listener_connections
+            .service_state
+            .shared_node
+            .closed_ports()
+            .is_closed(UniquePortId::Notifier(self.id()))
+        {
+            return Ok(0);
+        }
+
+        self.listener_connections.update_connections();
+
+        if let Some(value) = values
+            .iter()
+            .find(|v| self.event_id_max_value < v.as_value())
+        {
+            fail!(from self, with NotifierNotifyError::EventIdOutOfBounds,
+                            "{} since the EventId {:?} exceeds the maximum supported EventId value of {}.",
+                            msg, value, self.event_id_max_value);
+        }
+
+        if values.is_empty() {
+            return Ok(0);
+        }
+
+        if let Some(payload) = payload {
+            let max_payload_size = self
+                .listener_connections
+                .service_state
+                .static_config
+                .event()
+                .max_payload_size;
+            if max_payload_size < payload.len() {
+                fail!(from self, with NotifierNotifyError::ExceedsMaxPayloadSize,
+                    "{} since the payload of {} bytes exceeds the maximum supported payload size of {} bytes.",
+                    msg, payload.len(), max_payload_size);
+            }
+
+            self.listener_connections
+                .service_state
+                .dynamic_storage
+                .get()
+                .event()
+                .store_payload(payload);
+        }
+
+        let number_of_triggered_listeners = match values {
+            [value] => self.notify_listeners(&[self.trigger_id(*value)]),
+            _ => self.notify_listeners(
+                &values
+                    .iter()
+                    .map(|v| self.trigger_id(*v))
+                    .collect::<Vec<_>>(),
+            ),
+        };
+
         if let Some(deadline) = self
             .listener_connections
             .service_state
@@ -423,12 +624,13 @@ impl<Service: service::Service> Notifier<Service> {
                                 "{} but the elapsed system time could not be acquired which is required for deadline handling.",
                                 msg);
 
-            let previous_duration_since_creation = self
+            let dynamic_config = self
                 .listener_connections
                 .service_state
                 .dynamic_storage
                 .get()
-                .event()
+                .event();
+            let previous_duration_since_creation = dynamic_config
                 .elapsed_time_since_last_notification
                 .swap(duration_since_creation.as_nanos() as u64, Ordering::Relaxed);
 
@@ -437,6 +639,20 @@ impl<Service: service::Service> Notifier<Service> {
             );
 
             if deadline.value < duration_since_last_notification {
+                // the listeners are informed only when none of them detected the missed
+                // deadline already
+                if dynamic_config.register_missed_deadline(previous_duration_since_creation) {
+                    if let Some(event_id) = self
+                        .listener_connections
+                        .service_state
+                        .static_config
+                        .event()
+                        .deadline_missed_event()
+                    {
+                        self.notify_listeners(&[self.trigger_id(event_id)]);
+                    }
+                }
+
                 fail!(from self, with NotifierNotifyError::MissedDeadline,
                 "{} but the deadline was hit. The service requires a notification after {:?} but {:?} passed without a notification.",
                 msg, deadline.value, duration_since_last_notification);
@@ -101,50 +101,86 @@
 //! # }
 //! ```
 
+use super::data_segment_info::DataSegmentInfo;
 use super::details::data_segment::{DataSegment, DataSegmentType};
-use super::port_identifiers::UniquePublisherId;
-use super::UniqueSubscriberId;
+use super::details::pacer::Pacer;
+use super::details::persistent_history::PersistentHistory;
+use super::details::segment_state::SegmentState;
+#[cfg(feature = "loan_diagnostics")]
+use super::loan_diagnostics::{LoanTracker, OutstandingLoan};
+use super::port_identifiers::{UniquePortId, UniquePublisherId};
+use super::{UniqueSubscriberId, ZeroCopyConnectionCounters};
+use crate::deterministic_profile;
+use crate::node::evidence_log::{EvidenceEntity, StateTransition};
+use crate::node::port_registry::RegisteredPort;
+use crate::node::resource_budget::{ResourceReservation, ResourceReservationError};
 use crate::port::details::subscriber_connections::*;
 use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
-use crate::port::DegrationAction;
+use crate::port::{BackpressureEvent, DegrationAction};
 use crate::raw_sample::RawSampleMut;
+use crate::sample::Sample;
 use crate::sample_mut_uninit::SampleMutUninit;
 use crate::service::builder::publish_subscribe::CustomPayloadMarker;
-use crate::service::config_scheme::{connection_config, data_segment_config};
-use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
+use crate::service::config_scheme::{
+    broadcast_config, connection_config, data_segment_config, event_config,
+};
+use crate::service::dynamic_config::publish_subscribe::{
+    PublisherDetails, ReadyPublisherDetails, SubscriberDetails,
+};
 use crate::service::header::publish_subscribe::Header;
 use crate::service::naming_scheme::{
-    data_segment_name, extract_publisher_id_from_connection, extract_subscriber_id_from_connection,
+    broadcast_name, data_segment_name, extract_publisher_id_from_connection,
+    extract_subscriber_id_from_connection, readiness_event_name, reclaim_event_name,
 };
-use crate::service::port_factory::publisher::{LocalPublisherConfig, UnableToDeliverStrategy};
+use crate::service::port_factory::publisher::{
+    HistoryPersistence, LocalPublisherConfig, UnableToDeliverStrategy,
+};
+use crate::service::port_factory::subscriber::DeliveryQos;
 use crate::service::static_config::message_type_details::TypeVariant;
 use crate::service::static_config::publish_subscribe::{self};
 use crate::service::{self, ServiceState};
 use crate::{config, sample_mut::SampleMut};
 use core::any::TypeId;
-use core::cell::UnsafeCell;
+use core::cell::{Cell, RefCell, UnsafeCell};
 use core::fmt::Debug;
 use core::sync::atomic::Ordering;
+use core::time::Duration;
 use core::{alloc::Layout, marker::PhantomData, mem::MaybeUninit};
 use iceoryx2_bb_container::queue::Queue;
 use iceoryx2_bb_elementary::allocator::AllocationError;
+use iceoryx2_bb_elementary::crc32c::crc32c;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_log::{debug, error, fail, fatal_panic, warn};
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+use iceoryx2_bb_posix::clock::{ClockType, Time};
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
-use iceoryx2_cal::event::NamedConceptMgmt;
-use iceoryx2_cal::named_concept::{NamedConceptListError, NamedConceptRemoveError};
+use iceoryx2_cal::event::{
+    Event, Listener, ListenerBuilder, ListenerWaitError, NamedConceptMgmt, Notifier,
+    NotifierBuilder, TriggerId,
+};
+use iceoryx2_cal::named_concept::{
+    NamedConceptBuilder, NamedConceptListError, NamedConceptRemoveError,
+};
 use iceoryx2_cal::shared_memory::ShmPointer;
 use iceoryx2_cal::shm_allocator::{AllocationStrategy, PointerOffset, ShmAllocationError};
+use iceoryx2_cal::zero_copy_broadcast::{
+    ZeroCopyBroadcast, ZeroCopyBroadcastBuilder, ZeroCopyBroadcastPortDetails,
+    ZeroCopyBroadcastSender,
+};
 use iceoryx2_cal::zero_copy_connection::{
     ZeroCopyConnection, ZeroCopyCreationError, ZeroCopyPortDetails, ZeroCopyPortRemoveError,
-    ZeroCopySendError, ZeroCopySender,
+    ZeroCopySendError, ZeroCopySender, ZeroCopyWatermarkCrossing,
 };
-use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64, IoxAtomicUsize};
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicUsize};
 
 extern crate alloc;
 use alloc::sync::Arc;
+use std::collections::HashMap;
+
+// upper bound for a single wait of a blocking loan for returned samples
+const RECLAIM_RECHECK_INTERVAL: Duration = Duration::from_millis(100);
 
 /// Defines a failure that can occur when a [`Publisher`] is created with
 /// [`crate::service::port_factory::publisher::PortFactoryPublisher`].
@@ -157,6 +193,19 @@ pub enum PublisherCreateError {
     ExceedsMaxSupportedPublishers,
     /// The datasegment in which the payload of the [`Publisher`] is stored, could not be created.
     UnableToCreateDataSegment,
+    /// The [`Publisher`] would exceed the memory budget or the maximum number of ports of the
+    /// [`Node`](crate::node::Node), see [`NodeBuilder`](crate::node::NodeBuilder).
+    ExceedsResourceBudgetOfNode,
+    /// The [`Publisher`] was created with
+    /// [`HistoryPersistence::SharedMemory`] but the shared memory of the persistent history could
+    /// not be created or opened.
+    UnableToCreatePersistentHistory,
+    /// The requested settings violate the
+    /// [`deterministic_profile`](crate::deterministic_profile) that iceoryx2 was built with.
+    ViolatesDeterministicProfile,
+    /// The event with which the [`Subscriber`](crate::port::subscriber::Subscriber)s wake up
+    /// the [`Publisher`] in a blocking loan could not be created.
+    UnableToCreateReclaimEvent,
 }
 
 impl core::fmt::Display for PublisherCreateError {
@@ -184,6 +233,10 @@ pub enum PublisherLoanError {
     ExceedsMaxLoanSize,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
     InternalFailure,
+    /// The [`Publisher`]s dynamic data segment would have to grow beyond the memory budget of
+    /// the [`Node`](crate::node::Node), see
+    /// [`NodeBuilder::memory_budget()`](crate::node::NodeBuilder::memory_budget()).
+    ExceedsResourceBudgetOfNode,
 }
 
 impl core::fmt::Display for PublisherLoanError {
@@ -208,6 +261,14 @@ pub enum PublisherSendError {
     /// A failure occurred while establishing a connection to a
     /// [`Subscriber`](crate::port::subscriber::Subscriber)
     ConnectionError(ConnectionFailure),
+    /// [`Publisher::publish_raw()`] was called but the [`Service`](crate::service::Service)
+    /// was not created with
+    /// [`Builder::enable_replay()`](crate::service::builder::publish_subscribe::Builder::enable_replay()).
+    ReplayNotEnabled,
+    /// The size of the header, user header or payload provided to
+    /// [`Publisher::publish_raw()`] does not match the layout of the
+    /// [`Service`](crate::service::Service).
+    InvalidRawSample,
 }
 
 impl From<PublisherLoanError> for PublisherSendError {
@@ -230,55 +291,63 @@ impl core::fmt::Display for PublisherSendError {
 
 impl core::error::Error for PublisherSendError {}
 
-#[derive(Debug, Eq, PartialEq, Clone, Copy)]
-pub(crate) enum RemovePubSubPortFromAllConnectionsError {
-    CleanupRaceDetected,
-    InsufficientPermissions,
-    VersionMismatch,
-    InternalError,
+/// Defines the lane on which a [`crate::sample_mut::SampleMut`] is delivered with
+/// [`crate::sample_mut::SampleMut::send_with_priority()`], see
+/// [`Builder::enable_priority_lanes()`](crate::service::builder::publish_subscribe::Builder::enable_priority_lanes()).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Priority {
+    /// The sample is delivered like a sample sent with
+    /// [`crate::sample_mut::SampleMut::send()`].
+    #[default]
+    Normal,
+    /// The sample is delivered on the high-priority lane that the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) drains before the regular buffer.
+    /// The [`UnableToDeliverStrategy`] is not applied, a sample that does not fit into the
+    /// high-priority lane is discarded or, with safe overflow, replaces the oldest sample in it.
+    High,
 }
 
-#[derive(Debug)]
-struct SegmentState {
-    sample_reference_counter: Vec<IoxAtomicU64>,
-    payload_size: IoxAtomicUsize,
+/// Defines the failure that can occur when the number of parallel loans of a [`Publisher`] is
+/// adjusted with [`Publisher::set_max_loaned_samples()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum PublisherSetMaxLoanedSamplesError {
+    /// The value exceeds the upper bound that was defined with
+    /// [`PortFactoryPublisher::max_loaned_samples_limit()`](crate::service::port_factory::publisher::PortFactoryPublisher::max_loaned_samples_limit())
+    /// when the [`Publisher`] was created.
+    ExceedsLimit,
 }
 
-impl SegmentState {
-    fn new(number_of_samples: usize) -> Self {
-        let mut sample_reference_counter = Vec::with_capacity(number_of_samples);
-        for _ in 0..number_of_samples {
-            sample_reference_counter.push(IoxAtomicU64::new(0));
-        }
-
-        Self {
-            sample_reference_counter,
-            payload_size: IoxAtomicUsize::new(0),
-        }
+impl core::fmt::Display for PublisherSetMaxLoanedSamplesError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "PublisherSetMaxLoanedSamplesError::{:?}", self)
     }
+}
 
-    fn set_payload_size(&self, value: usize) {
-        self.payload_size.store(value, Ordering::Relaxed);
-    }
+impl core::error::Error for PublisherSetMaxLoanedSamplesError {}
 
-    fn payload_size(&self) -> usize {
-        self.payload_size.load(Ordering::Relaxed)
-    }
+/// Defines the failure that can occur when waiting for
+/// [`Subscriber`](crate::port::subscriber::Subscriber)s with
+/// [`Publisher::wait_for_subscribers()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum PublisherWaitForSubscribersError {
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+}
 
-    fn sample_index(&self, distance_to_chunk: usize) -> usize {
-        debug_assert!(distance_to_chunk % self.payload_size() == 0);
-        distance_to_chunk / self.payload_size()
+impl core::fmt::Display for PublisherWaitForSubscribersError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "PublisherWaitForSubscribersError::{:?}", self)
     }
+}
 
-    fn borrow_sample(&self, distance_to_chunk: usize) -> u64 {
-        self.sample_reference_counter[self.sample_index(distance_to_chunk)]
-            .fetch_add(1, Ordering::Relaxed)
-    }
+impl core::error::Error for PublisherWaitForSubscribersError {}
 
-    fn release_sample(&self, distance_to_chunk: usize) -> u64 {
-        self.sample_reference_counter[self.sample_index(distance_to_chunk)]
-            .fetch_sub(1, Ordering::Relaxed)
-    }
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub(crate) enum RemovePubSubPortFromAllConnectionsError {
+    CleanupRaceDetected,
+    InsufficientPermissions,
+    VersionMismatch,
+    InternalError,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -287,6 +356,23 @@ struct OffsetAndSize {
     size: usize,
 }
 
+pub(crate) struct FullyReleasedCallback(Box<dyn FnOnce()>);
+
+impl Debug for FullyReleasedCallback {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "")
+    }
+}
+
+// A sample that was sent while the publisher was paused. The header pointer stays valid
+// since the publisher holds a reference to the sample until it is delivered.
+#[derive(Debug, Clone, Copy)]
+struct PausedSample {
+    header: *mut Header,
+    offset: PointerOffset,
+    size: usize,
+}
+
 #[derive(Debug)]
 struct AllocationPair {
     shm_pointer: ShmPointer,
@@ -302,17 +388,39 @@ pub(crate) struct PublisherBackend<Service: service::Service> {
     service_state: Arc<ServiceState<Service>>,
 
     subscriber_connections: SubscriberConnections<Service>,
+    broadcast: Option<<Service::Broadcast as ZeroCopyBroadcast>::Sender>,
+    broadcast_pacer: Pacer,
     subscriber_list_state: UnsafeCell<ContainerState<SubscriberDetails>>,
     history: Option<UnsafeCell<Queue<OffsetAndSize>>>,
+    persistent_history: Option<PersistentHistory<Service>>,
+    fully_released_callbacks: RefCell<HashMap<u64, FullyReleasedCallback>>,
+    paused_samples: RefCell<Vec<PausedSample>>,
+    number_of_recipients: Cell<usize>,
+    reclaim_listener: <Service::Event as Event>::Listener,
+    reclaim_notifier: <Service::Event as Event>::Notifier,
+    number_of_loan_waiters: IoxAtomicUsize,
     static_config: crate::service::static_config::StaticConfig,
     loan_counter: IoxAtomicUsize,
+    max_loaned_samples: IoxAtomicUsize,
+    resource_reservation: ResourceReservation,
+    #[cfg(feature = "loan_diagnostics")]
+    loan_tracker: LoanTracker,
     is_active: IoxAtomicBool,
+    is_ready: IoxAtomicBool,
+    is_paused: IoxAtomicBool,
 }
 
 impl<Service: service::Service> PublisherBackend<Service> {
-    fn allocate(&self, layout: Layout) -> Result<AllocationPair, ShmAllocationError> {
-        self.retrieve_returned_samples();
+    /// Reserves the memory of the resource budget of the [`Node`](crate::node::Node) that a
+    /// dynamic data segment requires to allocate a chunk with the provided [`Layout`].
+    fn reserve_memory_for(&self, layout: Layout) -> Result<(), ResourceReservationError> {
+        match self.data_segment.required_memory_for(layout) {
+            Some(memory) => self.resource_reservation.resize(memory),
+            None => Ok(()),
+        }
+    }
 
+    fn allocate(&self, layout: Layout) -> Result<AllocationPair, ShmAllocationError> {
         let msg = "Unable to allocate Sample";
         let shm_pointer = self.data_segment.allocate(layout)?;
         let (ref_count, sample_size) = self.borrow_sample(shm_pointer.offset);
@@ -334,10 +442,21 @@ impl<Service: service::Service> PublisherBackend<Service> {
         if segment_state.payload_size() == 0 {
             payload_size = self.data_segment.bucket_size(segment_id);
             segment_state.set_payload_size(payload_size);
+            self.announce_new_segment();
         }
         (segment_state.borrow_sample(offset.offset()), payload_size)
     }
 
+    /// Announces a newly used segment to all subscribers so that they can map it before they
+    /// receive the first sample from it.
+    fn announce_new_segment(&self) {
+        for i in 0..self.subscriber_connections.len() {
+            if let Some(ref connection) = self.subscriber_connections.get(i) {
+                connection.sender.announce_new_segment();
+            }
+        }
+    }
+
     fn release_sample(&self, offset: PointerOffset) {
         if self.segment_states[offset.segment_id().value() as usize].release_sample(offset.offset())
             == 1
@@ -345,16 +464,75 @@ impl<Service: service::Service> PublisherBackend<Service> {
             unsafe {
                 self.data_segment.deallocate_bucket(offset);
             }
+
+            let callback = self
+                .fully_released_callbacks
+                .borrow_mut()
+                .remove(&offset.as_value());
+            if let Some(callback) = callback {
+                callback.0();
+            }
+        }
+    }
+
+    pub(crate) fn set_fully_released_callback(
+        &self,
+        offset: PointerOffset,
+        callback: Box<dyn FnOnce()>,
+    ) {
+        self.fully_released_callbacks
+            .borrow_mut()
+            .insert(offset.as_value(), FullyReleasedCallback(callback));
+    }
+
+    /// Requests all subscribers to notify the publisher when they release their next sample.
+    fn request_reclaim_notifications(&self) {
+        for i in 0..self.subscriber_connections.len() {
+            if let Some(ref connection) = self.subscriber_connections.get(i) {
+                connection.sender.request_reclaim_notification();
+            }
+        }
+    }
+
+    /// Waits until a subscriber released a sample, a loan was returned or the timeout has
+    /// passed.
+    fn wait_for_reclaim_notification(&self, timeout: Duration) -> Result<(), ListenerWaitError> {
+        self.reclaim_listener.timed_wait_all(|_| {}, timeout)
+    }
+
+    /// Decrements the loan counter and wakes up the threads that wait in a blocking loan for
+    /// a returned loan.
+    fn release_loan(&self) {
+        self.loan_counter.fetch_sub(1, Ordering::SeqCst);
+        if self.number_of_loan_waiters.load(Ordering::SeqCst) != 0 {
+            if let Err(e) = self.reclaim_notifier.notify(TriggerId::new(0)) {
+                debug!(from self, "Unable to wake up the threads that wait for a returned loan ({:?}).", e);
+            }
         }
     }
 
     fn retrieve_returned_samples(&self) {
+        if let Some(broadcast) = &self.broadcast {
+            loop {
+                match broadcast.reclaim() {
+                    Ok(Some(ptr_dist)) => {
+                        self.release_sample(ptr_dist);
+                    }
+                    Ok(None) => break,
+                    Err(e) => {
+                        warn!(from self, "Unable to reclaim samples from the broadcast {:?} due to {:?}. This may lead to a situation where samples are never returned to the publisher.", broadcast, e);
+                        break;
+                    }
+                }
+            }
+        }
+
         for i in 0..self.subscriber_connections.len() {
             if let Some(ref connection) = self.subscriber_connections.get(i) {
                 loop {
                     match connection.sender.reclaim() {
                         Ok(Some(ptr_dist)) => {
-                            self.release_sample(ptr_dist);
+                            self.reclaim_sample(i, ptr_dist);
                         }
                         Ok(None) => break,
                         Err(e) => {
@@ -366,6 +544,56 @@ impl<Service: service::Service> PublisherBackend<Service> {
         }
     }
 
+    fn reclaim_sample(&self, connection_index: usize, offset: PointerOffset) {
+        let header = self.data_segment.chunk_address(offset) as *mut Header;
+        if unsafe { (*header).is_handed_back() } {
+            // SAFETY: the subscriber that handed the sample back held the only reference
+            self.republish_handed_back_sample(connection_index, unsafe { &mut *header }, offset);
+        }
+
+        self.release_sample(offset);
+    }
+
+    // A sample that was updated in place and handed back by a subscriber is delivered as a new
+    // publication to all other subscribers without copying it.
+    fn republish_handed_back_sample(
+        &self,
+        connection_index: usize,
+        header: &mut Header,
+        offset: PointerOffset,
+    ) {
+        let message_type_details = &self.static_config.publish_subscribe().message_type_details;
+        let number_of_elements = header.number_of_elements() as usize;
+        self.prepare_header_for_delivery(header);
+        header.set_exclusive(self.has_exclusive_recipient(Some(connection_index)));
+
+        if self.has_payload_checksum() {
+            let payload = unsafe {
+                core::slice::from_raw_parts(
+                    message_type_details
+                        .payload_ptr_from_header((header as *const Header).cast())
+                        .cast::<u8>(),
+                    message_type_details.payload.size * number_of_elements,
+                )
+            };
+            header.set_payload_checksum(Some(crc32c(payload)));
+        }
+
+        let sample_size = message_type_details
+            .sample_layout(number_of_elements)
+            .size();
+        self.add_sample_to_history(header, offset, sample_size);
+        if let Err(e) = self.deliver_sample_to_connections(
+            offset,
+            sample_size,
+            Priority::Normal,
+            header.is_exclusive(),
+            Some(connection_index),
+        ) {
+            warn!(from self, "Unable to republish the sample {:?} that was handed back by a subscriber ({:?}).", offset, e);
+        }
+    }
+
     fn remove_connection(&self, i: usize) {
         if let Some(connection) = self.subscriber_connections.get(i) {
             // # SAFETY: the receiver no longer exist, therefore we can
@@ -382,10 +610,26 @@ impl<Service: service::Service> PublisherBackend<Service> {
 
     pub(crate) fn return_loaned_sample(&self, distance_to_chunk: PointerOffset) {
         self.release_sample(distance_to_chunk);
-        self.loan_counter.fetch_sub(1, Ordering::Relaxed);
+        self.release_loan();
+        #[cfg(feature = "loan_diagnostics")]
+        self.loan_tracker.remove(distance_to_chunk);
+    }
+
+    fn add_sample_to_history(&self, header: &Header, offset: PointerOffset, sample_size: usize) {
+        if let Some(persistent_history) = &self.persistent_history {
+            let size = self
+                .static_config
+                .publish_subscribe()
+                .message_type_details
+                .sample_layout(header.number_of_elements() as usize)
+                .size();
+            unsafe { persistent_history.store((header as *const Header).cast(), size) };
+        }
+
+        self.push_to_history(offset, sample_size);
     }
 
-    fn add_sample_to_history(&self, offset: PointerOffset, sample_size: usize) {
+    fn push_to_history(&self, offset: PointerOffset, sample_size: usize) {
         match &self.history {
             None => (),
             Some(history) => {
@@ -402,30 +646,168 @@ impl<Service: service::Service> PublisherBackend<Service> {
         }
     }
 
+    // Restored samples keep their original header, including the id of the publisher that
+    // sent them initially.
+    fn restore_persistent_history(&self) {
+        let persistent_history = match &self.persistent_history {
+            None => return,
+            Some(persistent_history) => persistent_history,
+        };
+
+        let alignment = self
+            .static_config
+            .publish_subscribe()
+            .message_type_details
+            .sample_layout(1)
+            .align();
+
+        persistent_history.for_each_in_order(|data| {
+            let layout = unsafe { Layout::from_size_align_unchecked(data.len(), alignment) };
+            match self.allocate(layout, false) {
+                Ok(chunk) => {
+                    unsafe {
+                        core::ptr::copy_nonoverlapping(
+                            data.as_ptr(),
+                            chunk.shm_pointer.data_ptr,
+                            data.len(),
+                        )
+                    };
+                    self.push_to_history(chunk.shm_pointer.offset, chunk.sample_size);
+                    // subscribers receive the history directly from the broadcast ring buffer
+                    if let Some(broadcast) = &self.broadcast {
+                        self.deliver_sample_to_broadcast(
+                            broadcast,
+                            chunk.shm_pointer.offset,
+                            chunk.sample_size,
+                        );
+                    }
+                    self.release_sample(chunk.shm_pointer.offset);
+                }
+                Err(e) => {
+                    warn!(from self, "Unable to restore a sample from the persistent history due to {:?}.", e);
+                }
+            }
+        });
+    }
+
     fn deliver_sample(
         &self,
         offset: PointerOffset,
         sample_size: usize,
+        priority: Priority,
+        is_exclusive: bool,
     ) -> Result<usize, PublisherSendError> {
         self.retrieve_returned_samples();
-        let deliver_call = match self.config.unable_to_deliver_strategy {
-            UnableToDeliverStrategy::Block => {
-                <Service::Connection as ZeroCopyConnection>::Sender::blocking_send
-            }
-            UnableToDeliverStrategy::DiscardSample => {
-                <Service::Connection as ZeroCopyConnection>::Sender::try_send
+        self.deliver_sample_to_connections(offset, sample_size, priority, is_exclusive, None)
+    }
+
+    fn deliver_sample_to_broadcast(
+        &self,
+        broadcast: &<Service::Broadcast as ZeroCopyBroadcast>::Sender,
+        offset: PointerOffset,
+        sample_size: usize,
+    ) -> usize {
+        let result = match self.config.unable_to_deliver_strategy {
+            UnableToDeliverStrategy::Block => broadcast.blocking_send(offset, sample_size),
+            UnableToDeliverStrategy::BlockWithTimeout(timeout) => {
+                broadcast.blocking_send_with_timeout(offset, sample_size, timeout)
             }
+            UnableToDeliverStrategy::DiscardSample => broadcast.try_send(offset, sample_size),
+            // the ring buffer is shared by all subscribers, the oldest sample cannot be
+            // discarded for a single slow subscriber
+            UnableToDeliverStrategy::DiscardOldest => broadcast.try_send(offset, sample_size),
         };
 
+        match result {
+            Err(ZeroCopySendError::ReceiveBufferFull)
+            | Err(ZeroCopySendError::UsedChunkListFull)
+            | Err(ZeroCopySendError::ExceedsMaxSampleRunLength) => 0,
+            Err(ZeroCopySendError::ConnectionCorrupted) => {
+                error!(from self,
+                    "While delivering the sample: {:?} a corrupted broadcast was detected.", offset);
+                0
+            }
+            Ok(overflow) => {
+                self.borrow_sample(offset);
+
+                if let Some(old) = overflow {
+                    self.release_sample(old)
+                }
+
+                broadcast.number_of_receivers()
+            }
+        }
+    }
+
+    fn deliver_sample_to_connections(
+        &self,
+        offset: PointerOffset,
+        sample_size: usize,
+        priority: Priority,
+        is_exclusive: bool,
+        excluded_connection: Option<usize>,
+    ) -> Result<usize, PublisherSendError> {
+        if let Some(broadcast) = &self.broadcast {
+            self.broadcast_pacer.wait_for_next_delivery();
+            return Ok(self.deliver_sample_to_broadcast(broadcast, offset, sample_size));
+        }
+
+        let deliver_call =
+            |sender: &<Service::Connection as ZeroCopyConnection>::Sender,
+             offset: PointerOffset,
+             strategy: UnableToDeliverStrategy| match (priority, strategy) {
+                (Priority::High, _) => sender.try_send_with_high_priority(offset, sample_size),
+                (Priority::Normal, UnableToDeliverStrategy::Block) => {
+                    sender.blocking_send(offset, sample_size)
+                }
+                (Priority::Normal, UnableToDeliverStrategy::BlockWithTimeout(timeout)) => {
+                    sender.blocking_send_with_timeout(offset, sample_size, timeout)
+                }
+                (Priority::Normal, UnableToDeliverStrategy::DiscardSample) => {
+                    sender.try_send(offset, sample_size)
+                }
+                (Priority::Normal, UnableToDeliverStrategy::DiscardOldest) => {
+                    sender.try_send_discarding_oldest(offset, sample_size)
+                }
+            };
+
         let mut number_of_recipients = 0;
         for i in 0..self.subscriber_connections.len() {
             if let Some(ref connection) = self.subscriber_connections.get(i) {
-                match deliver_call(&connection.sender, offset, sample_size) {
+                if !connection.is_delivery_enabled || excluded_connection == Some(i) {
+                    continue;
+                }
+
+                // a subscriber that connected after the sample was marked as exclusive must
+                // not receive it, otherwise two subscribers could update it in place
+                if is_exclusive && number_of_recipients != 0 {
+                    warn!(from self,
+                        "The exclusive sample {:?} is not delivered to the subscriber {:?} since it was already delivered to another subscriber.",
+                        offset, connection.subscriber_id);
+                    continue;
+                }
+
+                // an exclusive sample must stay in the data segment since the subscriber may
+                // update it in place and hand it back
+                let inline_offset = match is_exclusive {
+                    true => None,
+                    false => self.stage_inline(&connection.sender, offset, sample_size),
+                };
+
+                connection.pacer.wait_for_next_delivery();
+                match deliver_call(
+                    &connection.sender,
+                    inline_offset.unwrap_or(offset),
+                    connection.unable_to_deliver_strategy,
+                ) {
                     Err(ZeroCopySendError::ReceiveBufferFull)
-                    | Err(ZeroCopySendError::UsedChunkListFull) => {
+                    | Err(ZeroCopySendError::UsedChunkListFull)
+                    | Err(ZeroCopySendError::ExceedsMaxSampleRunLength) => {
                         /* causes no problem
                          *   blocking_send => can never happen
+                         *   blocking_send_with_timeout => the buffer was full until the timeout
                          *   try_send => we tried and expect that the buffer is full
+                         *   try_send_discarding_oldest => can never happen
                          * */
                     }
                     Err(ZeroCopySendError::ConnectionCorrupted) => {
@@ -455,7 +837,10 @@ impl<Service: service::Service> PublisherBackend<Service> {
                         }
                     }
                     Ok(overflow) => {
-                        self.borrow_sample(offset);
+                        // an inline copy does not hold a reference to the sample
+                        if inline_offset.is_none() {
+                            self.borrow_sample(offset);
+                        }
                         number_of_recipients += 1;
 
                         if let Some(old) = overflow {
@@ -463,12 +848,64 @@ impl<Service: service::Service> PublisherBackend<Service> {
                         }
                     }
                 }
+
+                self.update_backpressure_state(connection);
             }
         }
         Ok(number_of_recipients)
     }
 
+    fn stage_inline(
+        &self,
+        sender: &<Service::Connection as ZeroCopyConnection>::Sender,
+        offset: PointerOffset,
+        sample_size: usize,
+    ) -> Option<PointerOffset> {
+        if sample_size > sender.inline_sample_size() {
+            return None;
+        }
+
+        let sample = unsafe {
+            core::slice::from_raw_parts(
+                self.data_segment.chunk_address(offset) as *const u8,
+                sample_size,
+            )
+        };
+        sender.stage_inline(sample)
+    }
+
+    fn update_backpressure_state(&self, connection: &Connection<Service>) {
+        let event = match connection.sender.update_watermark_state() {
+            Some(ZeroCopyWatermarkCrossing::High) => BackpressureEvent::HighWatermarkReached,
+            Some(ZeroCopyWatermarkCrossing::Low) => BackpressureEvent::LowWatermarkReached,
+            None => return,
+        };
+
+        match &self.config.backpressure_callback {
+            Some(c) => c.call(connection.subscriber_id, event),
+            None => {
+                if event == BackpressureEvent::HighWatermarkReached {
+                    warn!(from self,
+                        "The buffer of subscriber {:?} reached the high watermark with {} of {} samples.",
+                        connection.subscriber_id, connection.sender.fill_level(), connection.sender.buffer_size());
+                }
+            }
+        }
+    }
+
     fn populate_subscriber_channels(&self) -> Result<(), ZeroCopyCreationError> {
+        let result = self.establish_subscriber_channels();
+        // the number of recipients changes only with the connections, the send path reuses it
+        self.number_of_recipients.set(self.count_recipients());
+        result
+    }
+
+    fn establish_subscriber_channels(&self) -> Result<(), ZeroCopyCreationError> {
+        // the subscribers connect themselves to the broadcast
+        if self.broadcast.is_some() {
+            return Ok(());
+        }
+
         let mut visited_indices = vec![];
         visited_indices.resize(self.subscriber_connections.capacity(), None);
 
@@ -495,7 +932,25 @@ impl<Service: service::Service> PublisherBackend<Service> {
                     };
 
                     if create_connection {
-                        match self.subscriber_connections.create(i, *subscriber_details) {
+                        if self.service_state.shared_node.port_registry().is_locked() {
+                            warn!(from self,
+                                "Unable to establish connection to new subscriber {:?} since the node is locked.",
+                                subscriber_details.subscriber_id);
+                            continue;
+                        }
+
+                        let is_delivery_enabled = match &self.config.delivery_filter {
+                            Some(filter) => filter.call(*subscriber_details),
+                            None => true,
+                        };
+
+                        match self.subscriber_connections.create(
+                            i,
+                            *subscriber_details,
+                            is_delivery_enabled,
+                            self.config
+                                .unable_to_deliver_strategy_for(subscriber_details.delivery_qos),
+                        ) {
                             Ok(()) => match &self.subscriber_connections.get(i) {
                                 Some(connection) => self.deliver_sample_history(connection),
                                 None => {
@@ -536,7 +991,18 @@ impl<Service: service::Service> PublisherBackend<Service> {
         Ok(())
     }
 
-    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+    fn is_closed(&self) -> bool {
+        self.service_state
+            .shared_node
+            .closed_ports()
+            .is_closed(UniquePortId::Publisher(self.port_id))
+    }
+
+    pub(crate) fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        if self.is_closed() {
+            return Ok(());
+        }
+
         if unsafe {
             self.service_state
                 .dynamic_storage
@@ -549,10 +1015,23 @@ impl<Service: service::Service> PublisherBackend<Service> {
                 "Connections were updated only partially since at least one connection to a Subscriber port failed.");
         }
 
+        for i in 0..self.subscriber_connections.len() {
+            if let Some(ref connection) = self.subscriber_connections.get(i) {
+                if connection.sender.take_redelivery_request() {
+                    self.deliver_sample_history(connection);
+                }
+                self.update_backpressure_state(connection);
+            }
+        }
+
         Ok(())
     }
 
     fn deliver_sample_history(&self, connection: &Connection<Service>) {
+        if !connection.is_delivery_enabled {
+            return;
+        }
+
         match &self.history {
             None => (),
             Some(history) => {
@@ -582,35 +1061,217 @@ impl<Service: service::Service> PublisherBackend<Service> {
         }
     }
 
+    pub(crate) fn is_ready(&self) -> bool {
+        self.is_ready.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn prepare_header_for_delivery(&self, header: &mut Header) {
+        if self
+            .subscriber_connections
+            .static_config
+            .has_send_timestamp()
+        {
+            // a sample without a valid timestamp is treated as the oldest sample
+            let send_timestamp = match Time::now_with_clock(ClockType::Monotonic) {
+                Ok(now) => now.as_duration().as_nanos() as u64,
+                Err(_) => 0,
+            };
+            header.set_send_timestamp(send_timestamp);
+        }
+
+        header.set_publisher_ready(self.is_ready());
+        header.set_global_sequence_number(self.acquire_global_sequence_number());
+        header.set_handed_back(false);
+    }
+
+    // The sample is exclusive when exactly one subscriber holds a reference to it after the
+    // delivery. The history and the broadcast hold additional references. Must be called
+    // after the connections were updated.
+    fn has_exclusive_recipient(&self, excluded_connection: Option<usize>) -> bool {
+        if self.broadcast.is_some() || self.history.is_some() {
+            return false;
+        }
+
+        let is_excluded_recipient = excluded_connection.is_some_and(|i| {
+            self.subscriber_connections
+                .get(i)
+                .as_ref()
+                .is_some_and(|connection| connection.is_delivery_enabled)
+        });
+
+        self.number_of_recipients.get() - is_excluded_recipient as usize == 1
+    }
+
+    fn count_recipients(&self) -> usize {
+        (0..self.subscriber_connections.len())
+            .filter(|i| {
+                self.subscriber_connections
+                    .get(*i)
+                    .as_ref()
+                    .is_some_and(|connection| connection.is_delivery_enabled)
+            })
+            .count()
+    }
+
+    pub(crate) fn has_payload_checksum(&self) -> bool {
+        self.static_config
+            .publish_subscribe()
+            .enable_payload_checksum
+    }
+
+    pub(crate) fn sample_size(&self, number_of_elements: usize) -> usize {
+        self.static_config
+            .publish_subscribe()
+            .message_type_details
+            .sample_layout(number_of_elements)
+            .size()
+    }
+
+    pub(crate) fn acquire_global_sequence_number(&self) -> Option<u64> {
+        if !self
+            .static_config
+            .publish_subscribe()
+            .enable_global_ordering
+        {
+            return None;
+        }
+
+        Some(
+            self.service_state
+                .dynamic_storage
+                .get()
+                .publish_subscribe()
+                .acquire_global_sequence_number(),
+        )
+    }
+
+    // The paused sample keeps counting as loaned sample until it is delivered, so that the
+    // number of buffered samples is bounded by the max loaned samples of the publisher.
+    fn buffer_paused_sample(&self, header: &mut Header, offset: PointerOffset, sample_size: usize) {
+        self.borrow_sample(offset);
+        self.loan_counter.fetch_add(1, Ordering::Relaxed);
+        self.paused_samples.borrow_mut().push(PausedSample {
+            header,
+            offset,
+            size: sample_size,
+        });
+    }
+
+    pub(crate) fn pause(&self) {
+        self.is_paused.store(true, Ordering::Relaxed);
+    }
+
+    pub(crate) fn is_paused(&self) -> bool {
+        self.is_paused.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn resume(&self) -> Result<usize, PublisherSendError> {
+        if !self.is_paused.swap(false, Ordering::Relaxed) {
+            return Ok(0);
+        }
+
+        let paused_samples = core::mem::take(&mut *self.paused_samples.borrow_mut());
+        let result = self.send_samples(
+            paused_samples
+                .iter()
+                .map(|s| (unsafe { &mut *s.header }, s.offset, s.size)),
+        );
+
+        for sample in paused_samples {
+            self.release_sample(sample.offset);
+            self.release_loan();
+        }
+
+        result
+    }
+
     pub(crate) fn send_sample(
         &self,
+        header: &mut Header,
+        offset: PointerOffset,
+        sample_size: usize,
+    ) -> Result<usize, PublisherSendError> {
+        self.send_sample_with_priority(header, offset, sample_size, Priority::Normal)
+    }
+
+    pub(crate) fn send_sample_with_priority(
+        &self,
+        header: &mut Header,
         offset: PointerOffset,
         sample_size: usize,
+        priority: Priority,
     ) -> Result<usize, PublisherSendError> {
         let msg = "Unable to send sample";
-        if !self.is_active.load(Ordering::Relaxed) {
+        if !self.is_active.load(Ordering::Relaxed) || self.is_closed() {
             fail!(from self, with PublisherSendError::ConnectionBrokenSincePublisherNoLongerExists,
                 "{} since the connections could not be updated.", msg);
         }
 
+        if self.is_paused() {
+            self.buffer_paused_sample(header, offset, sample_size);
+            return Ok(0);
+        }
+
         fail!(from self, when self.update_connections(),
             "{} since the connections could not be updated.", msg);
 
-        self.add_sample_to_history(offset, sample_size);
-        self.deliver_sample(offset, sample_size)
+        header.set_exclusive(self.has_exclusive_recipient(None));
+        self.add_sample_to_history(header, offset, sample_size);
+        self.deliver_sample(offset, sample_size, priority, header.is_exclusive())
     }
-}
 
-/// Sending endpoint of a publish-subscriber based communication.
-#[derive(Debug)]
-pub struct Publisher<
-    Service: service::Service,
-    Payload: Debug + ?Sized + 'static,
-    UserHeader: Debug,
-> {
-    pub(crate) backend: Arc<PublisherBackend<Service>>,
-    dynamic_publisher_handle: Option<ContainerHandle>,
-    payload_size: usize,
+    /// Sends multiple samples with one connection update and one reclaim pass. Returns the
+    /// sum of the recipients of all samples.
+    pub(crate) fn send_samples<'a, I: Iterator<Item = (&'a mut Header, PointerOffset, usize)>>(
+        &self,
+        samples: I,
+    ) -> Result<usize, PublisherSendError> {
+        let msg = "Unable to send batch of samples";
+        if !self.is_active.load(Ordering::Relaxed) || self.is_closed() {
+            fail!(from self, with PublisherSendError::ConnectionBrokenSincePublisherNoLongerExists,
+                "{} since the connections could not be updated.", msg);
+        }
+
+        if self.is_paused() {
+            for (header, offset, sample_size) in samples {
+                self.buffer_paused_sample(header, offset, sample_size);
+            }
+            return Ok(0);
+        }
+
+        fail!(from self, when self.update_connections(),
+            "{} since the connections could not be updated.", msg);
+
+        self.retrieve_returned_samples();
+
+        let mut number_of_deliveries = 0;
+        for (header, offset, sample_size) in samples {
+            header.set_exclusive(self.has_exclusive_recipient(None));
+            self.add_sample_to_history(header, offset, sample_size);
+            number_of_deliveries += self.deliver_sample_to_connections(
+                offset,
+                sample_size,
+                Priority::Normal,
+                header.is_exclusive(),
+                None,
+            )?;
+        }
+
+        Ok(number_of_deliveries)
+    }
+}
+
+/// Sending endpoint of a publish-subscriber based communication.
+#[derive(Debug)]
+pub struct Publisher<
+    Service: service::Service,
+    Payload: Debug + ?Sized + 'static,
+    UserHeader: Debug,
+> {
+    pub(crate) backend: Arc<PublisherBackend<Service>>,
+    dynamic_publisher_handle: Option<ContainerHandle>,
+    ready_publisher_handle: Cell<Option<ContainerHandle>>,
+    payload_size: usize,
     _payload: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
 }
@@ -619,14 +1280,33 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> Drop
     for Publisher<Service, Payload, UserHeader>
 {
     fn drop(&mut self) {
-        if let Some(handle) = self.dynamic_publisher_handle {
-            self.backend
-                .service_state
-                .dynamic_storage
-                .get()
-                .publish_subscribe()
-                .release_publisher_handle(handle)
-        }
+        let dynamic_config = self.backend.service_state.dynamic_storage.get();
+        self.backend
+            .service_state
+            .shared_node
+            .closed_ports()
+            .release_unless_closed(UniquePortId::Publisher(self.id()), || {
+                if let Some(handle) = self.ready_publisher_handle.get() {
+                    dynamic_config
+                        .publish_subscribe()
+                        .release_ready_publisher_handle(handle)
+                }
+
+                if let Some(handle) = self.dynamic_publisher_handle {
+                    self.backend.service_state.shared_node.record_evidence(
+                        EvidenceEntity::Port,
+                        StateTransition::Destroyed,
+                        format_args!(
+                            "{:?} of service {}",
+                            self.id(),
+                            self.backend.service_state.static_config.name()
+                        ),
+                    );
+                    dynamic_config
+                        .publish_subscribe()
+                        .release_publisher_handle(handle)
+                }
+            });
     }
 }
 
@@ -641,6 +1321,13 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         let msg = "Unable to create Publisher port";
         let origin = "Publisher::new()";
         let port_id = UniquePublisherId::new();
+
+        if !deterministic_profile::allows_allocation_strategy(config.allocation_strategy) {
+            fail!(from origin, with PublisherCreateError::ViolatesDeterministicProfile,
+                "{} since the allocation strategy {:?} violates the deterministic profile.",
+                msg, config.allocation_strategy);
+        }
+
         let subscriber_list = &service
             .__internal_state()
             .dynamic_storage
@@ -652,7 +1339,7 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             .__internal_state()
             .static_config
             .messaging_pattern
-            .required_amount_of_samples_per_data_segment(config.max_loaned_samples);
+            .required_amount_of_samples_per_data_segment(config.max_loaned_samples_limit());
 
         let data_segment_type =
             DataSegmentType::new_from_allocation_strategy(config.allocation_strategy);
@@ -674,13 +1361,74 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         };
         let global_config = service.__internal_state().shared_node.config();
 
+        let resource_reservation = fail!(from origin,
+                when service.__internal_state().shared_node.resource_budget()
+                    .reserve(sample_layout.size() * number_of_samples),
+                with PublisherCreateError::ExceedsResourceBudgetOfNode,
+                "{} since it would exceed the resource budget of the node.", msg);
+
         let data_segment = fail!(from origin,
                 when DataSegment::create(&publisher_details, global_config, sample_layout, config.allocation_strategy),
                 with PublisherCreateError::UnableToCreateDataSegment,
                 "{} since the data segment could not be acquired.", msg);
 
+        if let Some(memory) = data_segment.required_memory_for(sample_layout) {
+            fail!(from origin,
+                when resource_reservation.resize(memory),
+                with PublisherCreateError::ExceedsResourceBudgetOfNode,
+                "{} since the initial dynamic data segment would exceed the resource budget of the node.", msg);
+        }
+
+        let reclaim_listener = fail!(from origin,
+                when <Service::Event as Event>::ListenerBuilder::new(&reclaim_event_name(&port_id))
+                    .config(&event_config::<Service>(global_config))
+                    .create(),
+                with PublisherCreateError::UnableToCreateReclaimEvent,
+                "{} since the reclaim event could not be created.", msg);
+
+        let reclaim_notifier = fail!(from origin,
+                when <Service::Event as Event>::NotifierBuilder::new(&reclaim_event_name(&port_id))
+                    .config(&event_config::<Service>(global_config))
+                    .open(),
+                with PublisherCreateError::UnableToCreateReclaimEvent,
+                "{} since the reclaim event could not be opened.", msg);
+
+        let broadcast = match static_config.enable_broadcast {
+            false => None,
+            true => Some(fail!(from origin,
+                when <Service::Broadcast as ZeroCopyBroadcast>::Builder::new(&broadcast_name(port_id))
+                    .config(&broadcast_config::<Service>(global_config))
+                    .buffer_size(static_config.subscriber_max_buffer_size)
+                    .receiver_max_borrowed_samples(static_config.subscriber_max_borrowed_samples)
+                    .enable_safe_overflow(static_config.enable_safe_overflow)
+                    .max_receivers(static_config.max_subscribers)
+                    .number_of_samples_per_segment(number_of_samples)
+                    .max_supported_shared_memory_segments(max_number_of_segments)
+                    .timeout(global_config.global.service.creation_timeout)
+                    .create_sender(),
+                with PublisherCreateError::UnableToCreateDataSegment,
+                "{} since the broadcast could not be created.", msg)),
+        };
+
+        let persistent_history = match config.history_persistence {
+            HistoryPersistence::SharedMemory if static_config.history_size != 0 => {
+                Some(fail!(from origin,
+                when PersistentHistory::open_or_create(
+                    service.__internal_state().static_config.service_id(),
+                    global_config,
+                    static_config.history_size,
+                    sample_layout),
+                with PublisherCreateError::UnableToCreatePersistentHistory,
+                "{} since the persistent history could not be created or opened.", msg))
+            }
+            _ => None,
+        };
+
+        let initially_ready = config.initially_ready;
         let backend = Arc::new(PublisherBackend {
             is_active: IoxAtomicBool::new(true),
+            is_ready: IoxAtomicBool::new(false),
+            is_paused: IoxAtomicBool::new(false),
             data_segment,
             segment_states: {
                 let mut v: Vec<SegmentState> = Vec::with_capacity(max_number_of_segments as usize);
@@ -698,15 +1446,29 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                 static_config,
                 number_of_samples,
                 max_number_of_segments,
+                &config,
             ),
+            broadcast,
+            broadcast_pacer: Pacer::new(config.pacing),
             config,
             subscriber_list_state: unsafe { UnsafeCell::new(subscriber_list.get_state()) },
             history: match static_config.history_size == 0 {
                 true => None,
                 false => Some(UnsafeCell::new(Queue::new(static_config.history_size))),
             },
+            persistent_history,
+            fully_released_callbacks: RefCell::new(HashMap::new()),
+            paused_samples: RefCell::new(Vec::new()),
+            number_of_recipients: Cell::new(0),
+            reclaim_listener,
+            reclaim_notifier,
+            number_of_loan_waiters: IoxAtomicUsize::new(0),
             static_config: service.__internal_state().static_config.clone(),
             loan_counter: IoxAtomicUsize::new(0),
+            max_loaned_samples: IoxAtomicUsize::new(config.max_loaned_samples),
+            resource_reservation,
+            #[cfg(feature = "loan_diagnostics")]
+            loan_tracker: LoanTracker::default(),
         });
 
         let payload_size = backend
@@ -716,9 +1478,12 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             .payload
             .size;
 
+        backend.restore_persistent_history();
+
         let mut new_self = Self {
             backend,
             dynamic_publisher_handle: None,
+            ready_publisher_handle: Cell::new(None),
             payload_size,
             _payload: PhantomData,
             _user_header: PhantomData,
@@ -749,39 +1514,457 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
 
         new_self.dynamic_publisher_handle = Some(dynamic_publisher_handle);
 
+        if initially_ready {
+            new_self.announce_ready();
+        }
+
+        service
+            .__internal_state()
+            .shared_node
+            .port_registry()
+            .register(RegisteredPort::Publisher(Arc::downgrade(&new_self.backend)));
+
+        new_self.backend.service_state.shared_node.record_evidence(
+            EvidenceEntity::Port,
+            StateTransition::Created,
+            format_args!(
+                "{:?} of service {}",
+                new_self.id(),
+                new_self.backend.service_state.static_config.name()
+            ),
+        );
+
         Ok(new_self)
     }
 
+    /// Announces to all [`Subscriber`](crate::port::subscriber::Subscriber)s that the
+    /// [`Publisher`] is ready and that its samples contain valid data. It is only required when
+    /// the [`Publisher`] was created with
+    /// [`PortFactoryPublisher::initially_ready(false)`](crate::service::port_factory::publisher::PortFactoryPublisher::initially_ready()),
+    /// for instance when a sensor has to be calibrated first. Calling it multiple times has no
+    /// effect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// let publisher = service.publisher_builder()
+    ///                        .initially_ready(false)
+    ///                        .create()?;
+    ///
+    /// // samples sent here are marked as sent by a publisher that is not ready
+    /// publisher.send_copy(0)?;
+    ///
+    /// publisher.announce_ready();
+    /// publisher.send_copy(42)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn announce_ready(&self) {
+        if self.backend.is_ready.swap(true, Ordering::Relaxed) {
+            return;
+        }
+
+        let handle = match self
+            .backend
+            .service_state
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .add_ready_publisher_id(ReadyPublisherDetails {
+                publisher_id: self.backend.port_id,
+                node_id: *self.backend.service_state.shared_node.id(),
+            }) {
+            Some(handle) => handle,
+            None => {
+                fatal_panic!(from self,
+                    "This should never happen! Unable to announce readiness since the ready publisher list is full.");
+            }
+        };
+
+        self.ready_publisher_handle.set(Some(handle));
+        self.notify_subscribers_waiting_for_readiness();
+    }
+
+    // only subscribers that wait in Subscriber::wait_for_readiness() have a readiness event,
+    // the others are skipped since the notifier cannot be opened
+    fn notify_subscribers_waiting_for_readiness(&self) {
+        let global_config = self.backend.service_state.shared_node.config();
+        self.backend
+            .service_state
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .__internal_list_subscribers(|details| {
+                if let Ok(notifier) = <Service::Event as Event>::NotifierBuilder::new(
+                    &readiness_event_name(&details.subscriber_id),
+                )
+                .config(&event_config::<Service>(global_config))
+                .open()
+                {
+                    if let Err(e) = notifier.notify(TriggerId::new(0)) {
+                        warn!(from self, "Unable to notify the subscriber {:?} about the readiness ({:?}).",
+                            details.subscriber_id, e);
+                    }
+                }
+            });
+    }
+
+    /// Returns true when the [`Publisher`] has announced its readiness, otherwise false.
+    /// See [`Publisher::announce_ready()`].
+    pub fn is_ready(&self) -> bool {
+        self.backend.is_ready()
+    }
+
+    /// Returns the number of [`Subscriber`](crate::port::subscriber::Subscriber)s that are
+    /// currently registered at the [`Service`](crate::service::Service).
+    pub fn number_of_subscribers(&self) -> usize {
+        self.backend
+            .service_state
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .number_of_subscribers()
+    }
+
+    /// Blocks until at least `min_count` [`Subscriber`](crate::port::subscriber::Subscriber)s
+    /// are registered at the [`Service`](crate::service::Service) or the `timeout` has passed.
+    /// Returns true when enough [`Subscriber`](crate::port::subscriber::Subscriber)s are
+    /// available. In this case the [`Publisher`] is connected to them before the call returns
+    /// so that the next sample is delivered to all of them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use core::time::Duration;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// let publisher = service.publisher_builder().create()?;
+    ///
+    /// if publisher.wait_for_subscribers(1, Duration::from_millis(10))? {
+    ///     publisher.send_copy(1234)?;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wait_for_subscribers(
+        &self,
+        min_count: usize,
+        timeout: Duration,
+    ) -> Result<bool, PublisherWaitForSubscribersError> {
+        let msg = "Unable to wait for subscribers";
+        let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                with PublisherWaitForSubscribersError::InternalFailure,
+                "{} since the adaptive wait could not be created.", msg);
+
+        let has_subscribers = match adaptive_wait.timed_wait_while(
+            || -> Result<bool, ()> { Ok(self.number_of_subscribers() < min_count) },
+            timeout,
+        ) {
+            Ok(has_subscribers) => has_subscribers,
+            Err(e) => {
+                fail!(from self, with PublisherWaitForSubscribersError::InternalFailure,
+                    "{} since the adaptive wait failed ({:?}).", msg, e);
+            }
+        };
+
+        if has_subscribers {
+            if let Err(e) = self.backend.update_connections() {
+                warn!(from self,
+                    "Unable to connect to all subscribers after waiting for them ({:?}). The connection will be retried with the next delivery.", e);
+            }
+        }
+
+        Ok(has_subscribers)
+    }
+
+    /// Pauses the delivery of the [`Publisher`] without disconnecting it from its
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s. Samples can still be loaned and
+    /// sent but they are buffered until [`Publisher::resume()`] is called. A buffered sample
+    /// still counts as loaned sample, therefore at most
+    /// [`PortFactoryPublisher::max_loaned_samples()`](crate::service::port_factory::publisher::PortFactoryPublisher::max_loaned_samples())
+    /// samples can be buffered. Further loans are rejected with
+    /// [`PublisherLoanError::ExceedsMaxLoanedSamples`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// let publisher = service.publisher_builder().create()?;
+    ///
+    /// publisher.pause();
+    /// // the sample is buffered until the publisher is resumed
+    /// publisher.send_copy(1234)?;
+    ///
+    /// // delivers the buffered sample
+    /// publisher.resume()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn pause(&self) {
+        self.backend.pause();
+    }
+
+    /// Resumes a paused [`Publisher`] and delivers all samples that were sent while it was
+    /// paused in the order they were sent. Returns the sum of the number of recipients of
+    /// every buffered sample. Calling it on a [`Publisher`] that is not paused has no effect.
+    pub fn resume(&self) -> Result<usize, PublisherSendError> {
+        self.backend.resume()
+    }
+
+    /// Returns true when the [`Publisher`] is paused, see [`Publisher::pause()`].
+    pub fn is_paused(&self) -> bool {
+        self.backend.is_paused()
+    }
+
+    /// Returns the number of samples the [`Publisher`] can loan in parallel.
+    pub fn max_loaned_samples(&self) -> usize {
+        self.backend.max_loaned_samples.load(Ordering::Relaxed)
+    }
+
+    /// Adjusts the number of samples the [`Publisher`] can loan in parallel. It can be raised
+    /// up to the limit defined with
+    /// [`PortFactoryPublisher::max_loaned_samples_limit()`](crate::service::port_factory::publisher::PortFactoryPublisher::max_loaned_samples_limit()).
+    /// When it is lowered below the number of currently loaned samples, the loaned samples stay
+    /// valid and further loans are rejected until enough samples were sent or released.
+    pub fn set_max_loaned_samples(
+        &self,
+        value: usize,
+    ) -> Result<(), PublisherSetMaxLoanedSamplesError> {
+        let limit = self.backend.config.max_loaned_samples_limit();
+        if limit < value {
+            fail!(from self, with PublisherSetMaxLoanedSamplesError::ExceedsLimit,
+                "Unable to set the maximum number of parallel loans to {} since it exceeds the limit of {}.",
+                value, limit);
+        }
+
+        self.backend
+            .max_loaned_samples
+            .store(value, Ordering::Relaxed);
+        Ok(())
+    }
+
     /// Returns the [`UniquePublisherId`] of the [`Publisher`]
     pub fn id(&self) -> UniquePublisherId {
         self.backend.port_id
     }
 
+    /// Returns true when the [`Node`](crate::node::Node) that created the [`Publisher`]
+    /// broadcast its shutdown intent with
+    /// [`Node::broadcast_shutdown_intent()`](crate::node::Node::broadcast_shutdown_intent()).
+    pub fn is_shutdown_requested(&self) -> bool {
+        self.backend
+            .service_state
+            .shared_node
+            .shutdown_intent()
+            .is_requested()
+    }
+
+    /// Reclaims all samples that were returned by the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s. Samples that were handed back
+    /// with [`Sample::hand_back()`] are delivered as new publication to all other
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s. It is done implicitly whenever
+    /// the [`Publisher`] sends or loans a sample.
+    pub fn reclaim_returned_samples(&self) {
+        self.backend.retrieve_returned_samples();
+    }
+
     /// Returns the strategy the [`Publisher`] follows when a [`SampleMut`] cannot be delivered
     /// since the [`Subscriber`](crate::port::subscriber::Subscriber)s buffer is full.
     pub fn unable_to_deliver_strategy(&self) -> UnableToDeliverStrategy {
         self.backend.config.unable_to_deliver_strategy
     }
 
+    /// Returns the strategy the [`Publisher`] follows when a [`SampleMut`] cannot be delivered
+    /// to a [`Subscriber`](crate::port::subscriber::Subscriber) with the provided
+    /// [`DeliveryQos`] hint.
+    pub fn unable_to_deliver_strategy_for(&self, qos: DeliveryQos) -> UnableToDeliverStrategy {
+        self.backend.config.unable_to_deliver_strategy_for(qos)
+    }
+
     /// Returns the maximum slice length configured for this [`Publisher`].
     pub fn initial_max_slice_len(&self) -> usize {
         self.backend.config.initial_max_slice_len
     }
 
+    /// Sends all provided [`SampleMut`]s to all connected
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s. In contrast to calling
+    /// [`SampleMut::send()`] for every sample, the connections are updated and the returned
+    /// samples are reclaimed only once for the whole batch.
+    ///
+    /// On success the sum of the number of recipients of every sample is returned, otherwise a
+    /// [`PublisherSendError`] describing the failure. All samples that could not be sent are
+    /// returned to the [`Publisher`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let samples = publisher
+    ///     .loan_uninit_batch(2)?
+    ///     .into_iter()
+    ///     .enumerate()
+    ///     .map(|(n, sample)| sample.write_payload(n as u64))
+    ///     .collect();
+    ///
+    /// publisher.send_batch(samples)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_batch(
+        &self,
+        mut samples: Vec<SampleMut<Service, Payload, UserHeader>>,
+    ) -> Result<usize, PublisherSendError> {
+        for sample in samples.iter_mut() {
+            sample.prepare_for_delivery();
+        }
+
+        let is_own_sample = |sample: &SampleMut<Service, Payload, UserHeader>| {
+            Arc::ptr_eq(&sample.publisher_backend, &self.backend)
+        };
+
+        let mut number_of_deliveries = self.backend.send_samples(
+            samples
+                .iter_mut()
+                .filter(|sample| is_own_sample(sample))
+                .map(|sample| {
+                    (
+                        sample.ptr.as_header_mut(),
+                        sample.offset_to_chunk,
+                        sample.sample_size,
+                    )
+                }),
+        )?;
+
+        // samples that were loaned from another publisher are sent with the publisher they
+        // belong to
+        for sample in samples.iter_mut().filter(|sample| !is_own_sample(sample)) {
+            number_of_deliveries += sample.publisher_backend.send_sample(
+                sample.ptr.as_header_mut(),
+                sample.offset_to_chunk,
+                sample.sample_size,
+            )?;
+        }
+
+        Ok(number_of_deliveries)
+    }
+
+    /// Returns the [`ZeroCopyConnectionCounters`] of every established connection to a
+    /// [`Subscriber`](crate::port::subscriber::Subscriber). The counters reveal how many
+    /// samples were sent and reclaimed, how many were lost due to an overflow or a full
+    /// buffer and help to diagnose
+    /// mismatching QoS settings between [`Publisher`] and
+    /// [`Subscriber`](crate::port::subscriber::Subscriber). The sent and reclaimed samples
+    /// are only counted when iceoryx2 is built with the `sample_counters` feature.
+    /// When the [`Service`](crate::service::Service) has broadcasting enabled, there are no
+    /// dedicated connections and the list is empty.
+    pub fn connection_counters(&self) -> Vec<(UniqueSubscriberId, ZeroCopyConnectionCounters)> {
+        let connections = &self.backend.subscriber_connections;
+        (0..connections.len())
+            .filter_map(|i| {
+                connections
+                    .get(i)
+                    .as_ref()
+                    .map(|c| (c.subscriber_id, c.sender.counters()))
+            })
+            .collect()
+    }
+
+    /// Returns the current state of the data segment of the [`Publisher`], like the number of
+    /// used and free buckets, the number of allocated segments and their bucket sizes. See
+    /// [`DataSegmentInfo`].
+    pub fn data_segment_info(&self) -> DataSegmentInfo {
+        self.backend.data_segment.info()
+    }
+
+    /// Returns all samples that are currently loaned from the [`Publisher`] together with the
+    /// code location and the point in time of the loan.
+    #[cfg(feature = "loan_diagnostics")]
+    pub fn outstanding_loans(&self) -> Vec<OutstandingLoan> {
+        self.backend.loan_tracker.outstanding_loans()
+    }
+
+    /// Describes the oldest outstanding loan for the error log of
+    /// [`PublisherLoanError::ExceedsMaxLoanedSamples`]. Empty without the `loan_diagnostics`
+    /// feature.
+    fn oldest_loan_description(&self) -> String {
+        #[cfg(feature = "loan_diagnostics")]
+        return self.backend.loan_tracker.oldest_loan_description();
+        #[cfg(not(feature = "loan_diagnostics"))]
+        String::new()
+    }
+
+    #[cfg_attr(feature = "loan_diagnostics", track_caller)]
     fn allocate(&self, layout: Layout) -> Result<AllocationPair, PublisherLoanError> {
+        self.allocate_impl(layout, true)
+    }
+
+    #[cfg_attr(feature = "loan_diagnostics", track_caller)]
+    fn allocate_impl(
+        &self,
+        layout: Layout,
+        reclaim_returned_samples: bool,
+    ) -> Result<AllocationPair, PublisherLoanError> {
         let msg = "Unable to allocate Sample with";
 
-        if self.backend.loan_counter.load(Ordering::Relaxed)
-            >= self.backend.config.max_loaned_samples
-        {
+        let max_loaned_samples = self.backend.max_loaned_samples.load(Ordering::Relaxed);
+        if self.backend.loan_counter.load(Ordering::Relaxed) >= max_loaned_samples {
             fail!(from self, with PublisherLoanError::ExceedsMaxLoanedSamples,
-                "{} {:?} since already {} samples were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned sample to loan another sample.",
-                msg, layout, self.backend.loan_counter.load(Ordering::Relaxed), self.backend.config.max_loaned_samples);
+                "{} {:?} since already {} samples were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned sample to loan another sample.{}",
+                msg, layout, self.backend.loan_counter.load(Ordering::Relaxed), max_loaned_samples,
+                self.oldest_loan_description());
+        }
+
+        if reclaim_returned_samples {
+            self.backend.retrieve_returned_samples();
+        }
+
+        if self.backend.reserve_memory_for(layout).is_err() {
+            fail!(from self, with PublisherLoanError::ExceedsResourceBudgetOfNode,
+                "{} {:?} since growing the data segment would exceed the memory budget of the node.",
+                msg, layout);
         }
 
         match self.backend.allocate(layout) {
             Ok(chunk) => {
                 self.backend.loan_counter.fetch_add(1, Ordering::Relaxed);
+                #[cfg(feature = "loan_diagnostics")]
+                self.backend
+                    .loan_tracker
+                    .add(chunk.shm_pointer.offset, core::panic::Location::caller());
                 Ok(chunk)
             }
             Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
@@ -799,6 +1982,87 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         }
     }
 
+    fn loan_blocking_impl<T, F: FnMut() -> Result<T, PublisherLoanError>>(
+        &self,
+        timeout: Duration,
+        mut loan: F,
+    ) -> Result<T, PublisherLoanError> {
+        let msg = "Unable to loan sample with a timeout";
+        let start = fail!(from self, when Time::now_with_clock(ClockType::Monotonic),
+                with PublisherLoanError::InternalFailure,
+                "{} since the current time could not be acquired.", msg);
+        let mut has_requested_notifications = false;
+        let mut is_waiting_for_loan = false;
+
+        let result = loop {
+            let mut has_new_waker = false;
+            let reason = match loan() {
+                Ok(sample) => break Ok(sample),
+                // the subscribers return samples, the subscribers are asked to wake up the
+                // publisher when they release the next sample
+                Err(PublisherLoanError::OutOfMemory) => {
+                    if !has_requested_notifications {
+                        self.backend.request_reclaim_notifications();
+                        has_requested_notifications = true;
+                        has_new_waker = true;
+                    }
+                    PublisherLoanError::OutOfMemory
+                }
+                // loans are returned by other threads that drop or send their sample, they
+                // wake up the publisher as soon as a waiter is registered
+                Err(PublisherLoanError::ExceedsMaxLoanedSamples) => {
+                    if !is_waiting_for_loan {
+                        self.backend
+                            .number_of_loan_waiters
+                            .fetch_add(1, Ordering::SeqCst);
+                        is_waiting_for_loan = true;
+                        has_new_waker = true;
+                    }
+                    PublisherLoanError::ExceedsMaxLoanedSamples
+                }
+                Err(e) => break Err(e),
+            };
+
+            let elapsed = match start.elapsed() {
+                Ok(elapsed) => elapsed,
+                Err(e) => {
+                    error!(from self, "{} since the elapsed time could not be acquired ({:?}).", msg, e);
+                    break Err(PublisherLoanError::InternalFailure);
+                }
+            };
+            if elapsed >= timeout {
+                error!(from self, "{} since no sample was returned within the timeout of {:?}.", msg, timeout);
+                break Err(reason);
+            }
+
+            if has_new_waker {
+                // a sample or loan that was returned before the request became visible is not
+                // signaled, therefore the loan is retried once before waiting
+                core::sync::atomic::fence(Ordering::SeqCst);
+                continue;
+            }
+
+            // the wait is interrupted regularly since broadcast subscribers and the cleanup
+            // of dead subscribers return samples without notifying the publisher
+            if let Err(e) = self
+                .backend
+                .wait_for_reclaim_notification((timeout - elapsed).min(RECLAIM_RECHECK_INTERVAL))
+            {
+                error!(from self, "{} since the wait for returned samples failed ({:?}).", msg, e);
+                break Err(PublisherLoanError::InternalFailure);
+            }
+            has_requested_notifications = false;
+        };
+
+        if is_waiting_for_loan {
+            self.backend
+                .number_of_loan_waiters
+                .fetch_sub(1, Ordering::SeqCst);
+        }
+
+        result
+    }
+
     fn sample_layout(&self, number_of_elements: usize) -> Layout {
         self.backend
             .subscriber_connections
@@ -807,6 +2071,45 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             .sample_layout(number_of_elements)
     }
 
+    fn user_header_size(&self) -> usize {
+        let message_type_details = &self
+            .backend
+            .subscriber_connections
+            .static_config
+            .message_type_details;
+
+        match message_type_details.user_header_version {
+            // an older versioned user header covers only the beginning of the user header of
+            // the service
+            Some(_) => {
+                core::mem::size_of::<UserHeader>().min(message_type_details.user_header.size)
+            }
+            None => message_type_details.user_header.size,
+        }
+    }
+
+    /// Zeroes the part of the user header of the service that is not covered by an older
+    /// versioned user header so that newer subscribers never read stale data.
+    fn clear_unknown_user_header_fields(&self, user_header_ptr: *mut UserHeader) {
+        let service_user_header_size = self
+            .backend
+            .subscriber_connections
+            .static_config
+            .message_type_details
+            .user_header
+            .size;
+        let user_header_size = self.user_header_size();
+
+        if user_header_size < service_user_header_size {
+            unsafe {
+                user_header_ptr
+                    .cast::<u8>()
+                    .add(user_header_size)
+                    .write_bytes(0, service_user_header_size - user_header_size)
+            };
+        }
+    }
+
     fn user_header_ptr(&self, header: *const Header) -> *const u8 {
         self.backend
             .subscriber_connections
@@ -825,6 +2128,26 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             .cast()
     }
 
+    // a versioned user header of the source may be older or newer, only the fields both
+    // sides know are copied
+    fn copy_user_header_from<SourceService: service::Service>(
+        &self,
+        source: &Sample<SourceService, Payload, UserHeader>,
+        destination: *mut UserHeader,
+    ) {
+        let number_of_bytes = (source.header().user_header_size() as usize)
+            .min(core::mem::size_of::<UserHeader>())
+            .min(self.user_header_size());
+
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                (source.user_header() as *const UserHeader).cast::<u8>(),
+                destination.cast::<u8>(),
+                number_of_bytes,
+            )
+        };
+    }
+
     fn payload_type_variant(&self) -> TypeVariant {
         self.backend
             .subscriber_connections
@@ -863,6 +2186,7 @@ impl<Service: service::Service, Payload: Debug + Sized, UserHeader: Debug>
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "loan_diagnostics", track_caller)]
     pub fn send_copy(&self, value: Payload) -> Result<usize, PublisherSendError> {
         let msg = "Unable to send copy of payload";
         let sample = fail!(from self, when self.loan_uninit(),
@@ -871,6 +2195,64 @@ impl<Service: service::Service, Payload: Debug + Sized, UserHeader: Debug>
         sample.write_payload(value).send()
     }
 
+    /// Forwards a [`Sample`] that was received from another
+    /// [`Service`](crate::service::Service) with the same payload and user header type, as
+    /// required by relay or bridge processes. The [`Sample`] is copied with a single memcpy
+    /// into a newly loaned sample and released before the copy is delivered.
+    ///
+    /// A zero-copy hand-over is not possible. A [`Sample`] points into the data segment of the
+    /// source [`Publisher`] and the [`Subscriber`](crate::port::subscriber::Subscriber)s of
+    /// this service only map the data segments of their own [`Publisher`]s. Furthermore, the
+    /// source [`Publisher`] reclaims the chunk as soon as its own subscribers released it and
+    /// has no knowledge of the subscribers of another service.
+    ///
+    /// On success it returns the number of [`crate::port::subscriber::Subscriber`]s that
+    /// received the data, otherwise a [`PublisherSendError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let source = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let destination = node.service_builder(&"My/Funk/Relay".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let subscriber = source.subscriber_builder().create()?;
+    /// # let publisher = destination.publisher_builder().create()?;
+    ///
+    /// while let Some(sample) = subscriber.receive()? {
+    ///     publisher.forward(sample)?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "loan_diagnostics", track_caller)]
+    pub fn forward<SourceService: service::Service>(
+        &self,
+        sample: Sample<SourceService, Payload, UserHeader>,
+    ) -> Result<usize, PublisherSendError> {
+        let msg = "Unable to forward sample";
+        let mut destination = fail!(from self, when self.loan_uninit(),
+                                    "{} since the loan of a sample failed.", msg);
+
+        self.copy_user_header_from(&sample, destination.user_header_mut());
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                sample.payload(),
+                destination.payload_mut().as_mut_ptr(),
+                1,
+            );
+            drop(sample);
+            destination.assume_init().send()
+        }
+    }
+
     /// Loans/allocates a [`SampleMutUninit`] from the underlying data segment of the [`Publisher`].
     /// The user has to initialize the payload before it can be sent.
     ///
@@ -898,15 +2280,32 @@ impl<Service: service::Service, Payload: Debug + Sized, UserHeader: Debug>
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "loan_diagnostics", track_caller)]
     pub fn loan_uninit(
         &self,
     ) -> Result<SampleMutUninit<Service, MaybeUninit<Payload>, UserHeader>, PublisherLoanError>
     {
-        let chunk = self.allocate(self.sample_layout(1))?;
+        self.loan_uninit_impl(true)
+    }
+
+    #[cfg_attr(feature = "loan_diagnostics", track_caller)]
+    fn loan_uninit_impl(
+        &self,
+        reclaim_returned_samples: bool,
+    ) -> Result<SampleMutUninit<Service, MaybeUninit<Payload>, UserHeader>, PublisherLoanError>
+    {
+        let chunk = self.allocate_impl(self.sample_layout(1), reclaim_returned_samples)?;
         let header_ptr = chunk.shm_pointer.data_ptr as *mut Header;
         let user_header_ptr = self.user_header_ptr(header_ptr) as *mut UserHeader;
         let payload_ptr = self.payload_ptr(header_ptr) as *mut MaybeUninit<Payload>;
-        unsafe { header_ptr.write(Header::new(self.backend.port_id, 1)) };
+        unsafe {
+            header_ptr.write(Header::new(
+                self.backend.port_id,
+                1,
+                self.user_header_size() as _,
+            ))
+        };
+        self.clear_unknown_user_header_fields(user_header_ptr);
 
         let sample =
             unsafe { RawSampleMut::new_unchecked(header_ptr, user_header_ptr, payload_ptr) };
@@ -919,6 +2318,105 @@ impl<Service: service::Service, Payload: Debug + Sized, UserHeader: Debug>
             ),
         )
     }
+
+    /// Loans/allocates a [`SampleMutUninit`] like [`Publisher::loan_uninit()`] but when the
+    /// underlying data segment is out of memory, it waits up to `timeout` for samples to be
+    /// returned by the [`Subscriber`](crate::port::subscriber::Subscriber)s instead of failing
+    /// instantly. The [`Publisher`] sleeps until a
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) signals a returned sample.
+    /// When the maximum number of loaned samples is reached, it waits until another thread
+    /// drops or sends one of its loaned samples.
+    ///
+    /// On failure it returns [`PublisherLoanError`] describing the failure. When the timeout
+    /// has passed, [`PublisherLoanError::OutOfMemory`] or
+    /// [`PublisherLoanError::ExceedsMaxLoanedSamples`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use core::time::Duration;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder()
+    ///                          .create()?;
+    ///
+    /// let sample = publisher.loan_uninit_with_timeout(Duration::from_millis(10))?;
+    /// let sample = sample.write_payload(42);
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_uninit_with_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<SampleMutUninit<Service, MaybeUninit<Payload>, UserHeader>, PublisherLoanError>
+    {
+        self.loan_blocking_impl(timeout, || self.loan_uninit())
+    }
+
+    /// Loans/allocates `number_of_samples` [`SampleMutUninit`]s at once. The samples returned
+    /// by the [`Subscriber`](crate::port::subscriber::Subscriber)s are reclaimed only once for
+    /// the whole batch. Together with [`Publisher::send_batch()`] it reduces the per sample
+    /// overhead of high frequency [`Publisher`]s.
+    ///
+    /// On failure it returns [`PublisherLoanError`] describing the failure and no sample is
+    /// loaned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder()
+    ///                          .max_loaned_samples(2)
+    ///                          .create()?;
+    ///
+    /// let samples = publisher.loan_uninit_batch(2)?;
+    /// let samples = samples.into_iter().map(|sample| sample.write_payload(42)).collect();
+    ///
+    /// publisher.send_batch(samples)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "loan_diagnostics", track_caller)]
+    pub fn loan_uninit_batch(
+        &self,
+        number_of_samples: usize,
+    ) -> Result<Vec<SampleMutUninit<Service, MaybeUninit<Payload>, UserHeader>>, PublisherLoanError>
+    {
+        let loan_counter = self.backend.loan_counter.load(Ordering::Relaxed);
+        let max_loaned_samples = self.backend.max_loaned_samples.load(Ordering::Relaxed);
+        if max_loaned_samples < loan_counter + number_of_samples {
+            fail!(from self, with PublisherLoanError::ExceedsMaxLoanedSamples,
+                "Unable to loan a batch of {} samples since already {} samples were loaned and it would exceed the maximum of parallel loans of {}.{}",
+                number_of_samples, loan_counter, max_loaned_samples,
+                self.oldest_loan_description());
+        }
+
+        self.backend.retrieve_returned_samples();
+
+        let mut samples = Vec::with_capacity(number_of_samples);
+        for _ in 0..number_of_samples {
+            samples.push(self.loan_uninit_impl(false)?);
+        }
+
+        Ok(samples)
+    }
 }
 
 impl<Service: service::Service, Payload: Default + Debug + Sized, UserHeader: Debug>
@@ -951,9 +2449,88 @@ impl<Service: service::Service, Payload: Default + Debug + Sized, UserHeader: De
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "loan_diagnostics", track_caller)]
     pub fn loan(&self) -> Result<SampleMut<Service, Payload, UserHeader>, PublisherLoanError> {
         Ok(self.loan_uninit()?.write_payload(Payload::default()))
     }
+
+    /// Loans/allocates a [`crate::sample_mut::SampleMut`] like [`Publisher::loan()`] but when
+    /// no memory is available it waits up to `timeout` for samples to be returned. See
+    /// [`Publisher::loan_uninit_with_timeout()`] for details.
+    ///
+    /// On failure it returns [`PublisherLoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use core::time::Duration;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let mut sample = publisher.loan_with_timeout(Duration::from_millis(10))?;
+    /// *sample.payload_mut() = 42;
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_with_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<SampleMut<Service, Payload, UserHeader>, PublisherLoanError> {
+        Ok(self
+            .loan_uninit_with_timeout(timeout)?
+            .write_payload(Payload::default()))
+    }
+
+    /// Loans/allocates `number_of_samples` [`crate::sample_mut::SampleMut`]s at once and
+    /// initializes them with the default value. See [`Publisher::loan_uninit_batch()`] for
+    /// details.
+    ///
+    /// On failure it returns [`PublisherLoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let mut samples = publisher.loan_batch(2)?;
+    /// for sample in samples.iter_mut() {
+    ///     *sample.payload_mut() = 42;
+    /// }
+    ///
+    /// publisher.send_batch(samples)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "loan_diagnostics", track_caller)]
+    pub fn loan_batch(
+        &self,
+        number_of_samples: usize,
+    ) -> Result<Vec<SampleMut<Service, Payload, UserHeader>>, PublisherLoanError> {
+        Ok(self
+            .loan_uninit_batch(number_of_samples)?
+            .into_iter()
+            .map(|sample| sample.write_payload(Payload::default()))
+            .collect())
+    }
 }
 ////////////////////////
 // END: typed API
@@ -996,6 +2573,7 @@ impl<Service: service::Service, Payload: Default + Debug, UserHeader: Debug>
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "loan_diagnostics", track_caller)]
     pub fn loan_slice(
         &self,
         number_of_elements: usize,
@@ -1003,6 +2581,45 @@ impl<Service: service::Service, Payload: Default + Debug, UserHeader: Debug>
         let sample = self.loan_slice_uninit(number_of_elements)?;
         Ok(sample.write_from_fn(|_| Payload::default()))
     }
+
+    /// Loans/allocates a [`crate::sample_mut::SampleMut`] like [`Publisher::loan_slice()`] but
+    /// when no memory is available it waits up to `timeout` for samples to be returned. See
+    /// [`Publisher::loan_slice_uninit_with_timeout()`] for details.
+    ///
+    /// On failure it returns [`PublisherLoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use core::time::Duration;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[u64]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder()
+    ///                          .initial_max_slice_len(120)
+    ///                          .create()?;
+    ///
+    /// let mut sample = publisher.loan_slice_with_timeout(5, Duration::from_millis(10))?;
+    /// sample.payload_mut()[2] = 42;
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_slice_with_timeout(
+        &self,
+        number_of_elements: usize,
+        timeout: Duration,
+    ) -> Result<SampleMut<Service, [Payload], UserHeader>, PublisherLoanError> {
+        let sample = self.loan_slice_uninit_with_timeout(number_of_elements, timeout)?;
+        Ok(sample.write_from_fn(|_| Payload::default()))
+    }
 }
 
 impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
@@ -1035,6 +2652,7 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
     /// sample.send()?;
     /// # Ok::<_, Box<dyn core::error::Error>>(())
     /// ```
+    #[cfg_attr(feature = "loan_diagnostics", track_caller)]
     pub fn loan_slice_uninit(
         &self,
         slice_len: usize,
@@ -1046,6 +2664,72 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
         unsafe { self.loan_slice_uninit_impl(slice_len, slice_len) }
     }
 
+    /// Loans/allocates a [`SampleMutUninit`] like [`Publisher::loan_slice_uninit()`] but when
+    /// the underlying data segment is out of memory, it waits up to `timeout` for samples to be
+    /// returned by the [`Subscriber`](crate::port::subscriber::Subscriber)s instead of failing
+    /// instantly. See [`Publisher::loan_uninit_with_timeout()`] for details.
+    ///
+    /// On failure it returns [`PublisherLoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use core::time::Duration;
+    ///
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[usize]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder()
+    ///                          .initial_max_slice_len(120)
+    ///                          .create()?;
+    ///
+    /// let sample = publisher.loan_slice_uninit_with_timeout(5, Duration::from_millis(10))?;
+    /// let sample = sample.write_from_fn(|n| n * 2);
+    ///
+    /// sample.send()?;
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    pub fn loan_slice_uninit_with_timeout(
+        &self,
+        slice_len: usize,
+        timeout: Duration,
+    ) -> Result<SampleMutUninit<Service, [MaybeUninit<Payload>], UserHeader>, PublisherLoanError>
+    {
+        self.loan_blocking_impl(timeout, || self.loan_slice_uninit(slice_len))
+    }
+
+    /// Forwards a [`Sample`] that was received from another
+    /// [`Service`](crate::service::Service) with the same payload and user header type, see
+    /// [`Publisher::forward()`]. The payload is always copied, even when both services share
+    /// the same layout. It fails with [`PublisherLoanError::ExceedsMaxLoanSize`] when the slice
+    /// is longer than the [`Publisher`] supports.
+    #[cfg_attr(feature = "loan_diagnostics", track_caller)]
+    pub fn forward<SourceService: service::Service>(
+        &self,
+        sample: Sample<SourceService, [Payload], UserHeader>,
+    ) -> Result<usize, PublisherSendError> {
+        let msg = "Unable to forward sample";
+        let slice_len = sample.payload().len();
+        let mut destination = fail!(from self, when self.loan_slice_uninit(slice_len),
+                                    "{} since the loan of a sample with {} elements failed.", msg, slice_len);
+
+        self.copy_user_header_from(&sample, destination.user_header_mut());
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                sample.payload().as_ptr(),
+                destination.payload_mut().as_mut_ptr().cast::<Payload>(),
+                slice_len,
+            );
+            drop(sample);
+            destination.assume_init().send()
+        }
+    }
+
+    #[cfg_attr(feature = "loan_diagnostics", track_caller)]
     unsafe fn loan_slice_uninit_impl(
         &self,
         slice_len: usize,
@@ -1066,7 +2750,14 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
         let header_ptr = chunk.shm_pointer.data_ptr as *mut Header;
         let user_header_ptr = self.user_header_ptr(header_ptr) as *mut UserHeader;
         let payload_ptr = self.payload_ptr(header_ptr) as *mut MaybeUninit<Payload>;
-        unsafe { header_ptr.write(Header::new(self.backend.port_id, slice_len as _)) };
+        unsafe {
+            header_ptr.write(Header::new(
+                self.backend.port_id,
+                slice_len as _,
+                self.user_header_size() as _,
+            ))
+        };
+        self.clear_unknown_user_header_fields(user_header_ptr);
 
         let sample = unsafe {
             RawSampleMut::new_unchecked(
@@ -1098,6 +2789,7 @@ impl<Service: service::Service, UserHeader: Debug>
     ///  * The [`SampleMutUninit`] will contain `slice_len` * `MessageTypeDetails::payload.size`
     ///     elements of type [`CustomPayloadMarker`].
     #[doc(hidden)]
+    #[cfg_attr(feature = "loan_diagnostics", track_caller)]
     pub unsafe fn loan_custom_payload(
         &self,
         slice_len: usize,
@@ -1110,6 +2802,81 @@ impl<Service: service::Service, UserHeader: Debug>
 
         self.loan_slice_uninit_impl(slice_len, self.payload_size * slice_len)
     }
+
+    /// Reconstructs and sends a sample exactly as it was captured, for instance by a recorder
+    /// or a network gateway. In contrast to [`SampleMut::send()`] the send timestamp, the
+    /// sequence number and the payload checksum of the captured
+    /// [`Header`](crate::service::header::publish_subscribe::Header) are preserved. Requires a
+    /// [`Service`](crate::service::Service) that was created with
+    /// [`Builder::enable_replay()`](crate::service::builder::publish_subscribe::Builder::enable_replay()).
+    ///
+    /// On success it returns the number of [`crate::port::subscriber::Subscriber`]s that
+    /// received the data, otherwise a [`PublisherSendError`] describing the failure.
+    ///
+    /// # Safety
+    ///
+    ///  * `header_bytes` must be the bytes of a [`Header`](crate::service::header::publish_subscribe::Header)
+    ///    that was captured from a [`crate::sample::Sample`] of a service with the same
+    ///    [`MessageTypeDetails`](crate::service::static_config::message_type_details::MessageTypeDetails)
+    ///  * `user_header_bytes` and `payload_bytes` must be the user header and payload bytes
+    ///    of the same captured [`crate::sample::Sample`]
+    pub unsafe fn publish_raw(
+        &self,
+        header_bytes: &[u8],
+        user_header_bytes: &[u8],
+        payload_bytes: &[u8],
+    ) -> Result<usize, PublisherSendError> {
+        let msg = "Unable to publish raw sample";
+        let static_config = &self.backend.subscriber_connections.static_config;
+        if !static_config.enable_replay {
+            fail!(from self, with PublisherSendError::ReplayNotEnabled,
+                "{} since the service was not created with replay enabled.", msg);
+        }
+
+        if header_bytes.len() != core::mem::size_of::<Header>() {
+            fail!(from self, with PublisherSendError::InvalidRawSample,
+                "{} since the header has a size of {} bytes but {} bytes are required.",
+                msg, header_bytes.len(), core::mem::size_of::<Header>());
+        }
+
+        let header = core::ptr::read_unaligned(header_bytes.as_ptr().cast::<Header>());
+        let slice_len = header.number_of_elements() as usize;
+        let user_header_size = header.user_header_size() as usize;
+
+        if user_header_bytes.len() != user_header_size
+            || static_config.message_type_details.user_header.size < user_header_size
+        {
+            fail!(from self, with PublisherSendError::InvalidRawSample,
+                "{} since the user header has a size of {} bytes but the captured header defines {} bytes and the service supports at most {} bytes.",
+                msg, user_header_bytes.len(), user_header_size, static_config.message_type_details.user_header.size);
+        }
+
+        if payload_bytes.len() != self.payload_size * slice_len {
+            fail!(from self, with PublisherSendError::InvalidRawSample,
+                "{} since the payload has a size of {} bytes but {} elements with {} bytes each are required.",
+                msg, payload_bytes.len(), slice_len, self.payload_size);
+        }
+
+        let mut sample = self.loan_custom_payload(slice_len)?.assume_init();
+        // the delivery state is determined by the send like for every other sample
+        sample.ptr.as_header_mut().copy_replayed_fields(&header);
+        core::ptr::copy_nonoverlapping(
+            user_header_bytes.as_ptr(),
+            (sample.ptr.as_user_header_mut() as *mut UserHeader).cast::<u8>(),
+            user_header_size,
+        );
+        core::ptr::copy_nonoverlapping(
+            payload_bytes.as_ptr(),
+            sample.ptr.as_payload_mut().as_mut_ptr().cast::<u8>(),
+            payload_bytes.len(),
+        );
+
+        sample.publisher_backend.send_sample(
+            sample.ptr.as_header_mut(),
+            sample.offset_to_chunk,
+            sample.sample_size,
+        )
+    }
 }
 ////////////////////////
 // END: sliced API
@@ -1139,10 +2906,16 @@ pub(crate) unsafe fn remove_data_segment_of_publisher<Service: service::Service>
         ), "Unable to remove the publishers data segment."
     );
 
+    fail!(from origin, when <Service::Event as NamedConceptMgmt>::remove_cfg(
+            &reclaim_event_name(port_id),
+            &event_config::<Service>(config),
+        ), "Unable to remove the publishers reclaim event."
+    );
+
     Ok(())
 }
 
-fn connections<Service: service::Service>(
+pub(crate) fn connections<Service: service::Service>(
     origin: &str,
     msg: &str,
     config: &<Service::Connection as NamedConceptMgmt>::Configuration,
@@ -1160,7 +2933,25 @@ fn connections<Service: service::Service>(
     }
 }
 
-fn handle_port_remove_error(
+fn broadcasts<Service: service::Service>(
+    origin: &str,
+    msg: &str,
+    config: &<Service::Broadcast as NamedConceptMgmt>::Configuration,
+) -> Result<Vec<FileName>, RemovePubSubPortFromAllConnectionsError> {
+    match <Service::Broadcast as NamedConceptMgmt>::list_cfg(config) {
+        Ok(list) => Ok(list),
+        Err(NamedConceptListError::InsufficientPermissions) => {
+            fail!(from origin, with RemovePubSubPortFromAllConnectionsError::InsufficientPermissions,
+                    "{} due to insufficient permissions to list all broadcasts.", msg);
+        }
+        Err(NamedConceptListError::InternalError) => {
+            fail!(from origin, with RemovePubSubPortFromAllConnectionsError::InternalError,
+                "{} due to an internal error while listing all broadcasts.", msg);
+        }
+    }
+}
+
+pub(crate) fn handle_port_remove_error(
     result: Result<(), ZeroCopyPortRemoveError>,
     origin: &str,
     msg: &str,
@@ -1218,6 +3009,18 @@ pub(crate) unsafe fn remove_publisher_from_all_connections<Service: service::Ser
         }
     }
 
+    // only publishers of services with broadcasting enabled own a broadcast
+    let broadcast = broadcast_name(*port_id);
+    let broadcast_config = broadcast_config::<Service>(config);
+    let result = match Service::Broadcast::remove_sender(&broadcast, &broadcast_config) {
+        Err(ZeroCopyPortRemoveError::DoesNotExist) => Ok(()),
+        result => handle_port_remove_error(result, &origin, msg, &broadcast),
+    };
+
+    if ret_val.is_ok() {
+        ret_val = result;
+    }
+
     ret_val
 }
 
@@ -1252,5 +3055,19 @@ pub(crate) unsafe fn remove_subscriber_from_all_connections<Service: service::Se
         }
     }
 
+    let broadcast_config = broadcast_config::<Service>(config);
+    for broadcast in broadcasts::<Service>(&origin, msg, &broadcast_config)? {
+        let result = handle_port_remove_error(
+            Service::Broadcast::remove_receiver(&broadcast, &broadcast_config, port_id.value()),
+            &origin,
+            msg,
+            &broadcast,
+        );
+
+        if ret_val.is_ok() {
+            ret_val = result;
+        }
+    }
+
     ret_val
 }
@@ -0,0 +1,147 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_cal::shm_allocator::AllocationStrategy;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Serialize, Deserialize)]
+//! struct Message {
+//!     text: String,
+//!     values: Vec<u64>,
+//! }
+//!
+//! # #[cfg(feature = "deterministic_profile")]
+//! # fn main() {}
+//! # #[cfg(not(feature = "deterministic_profile"))]
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe_serde::<Message>()
+//!     .open_or_create()?;
+//!
+//! let publisher = service.publisher_builder()
+//!     .allocation_strategy(AllocationStrategy::PowerOfTwo)
+//!     .create_serde_publisher::<Message>()?;
+//!
+//! publisher.send(&Message {
+//!     text: "hello".to_string(),
+//!     values: vec![1, 2, 3],
+//! })?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::serialize::cdr::Cdr;
+use iceoryx2_cal::serialize::Serialize;
+
+use super::port_identifiers::UniquePublisherId;
+use super::publisher::{Publisher, PublisherLoanError, PublisherSendError};
+use super::update_connections::{ConnectionFailure, UpdateConnections};
+use crate::service;
+
+/// Failure that can be emitted when a payload is sent with [`SerdePublisher::send()`].
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum SerdePublisherSendError {
+    /// The payload could not be serialized.
+    SerializationFailed,
+    /// The serialized payload could not be sent.
+    SendError(PublisherSendError),
+}
+
+impl From<PublisherSendError> for SerdePublisherSendError {
+    fn from(value: PublisherSendError) -> Self {
+        SerdePublisherSendError::SendError(value)
+    }
+}
+
+impl From<PublisherLoanError> for SerdePublisherSendError {
+    fn from(value: PublisherLoanError) -> Self {
+        SerdePublisherSendError::SendError(PublisherSendError::LoanError(value))
+    }
+}
+
+impl core::fmt::Display for SerdePublisherSendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "SerdePublisherSendError::{:?}", self)
+    }
+}
+
+impl core::error::Error for SerdePublisherSendError {}
+
+/// A [`Publisher`] of a service created with
+/// [`Builder::publish_subscribe_serde()`](crate::service::builder::Builder::publish_subscribe_serde()).
+/// Every payload is serialized into a loaned `[u8]` slice sample, therefore the payload type
+/// can contain heap indirections, at the cost of one serialization per sample.
+///
+/// The payload must fit into the slice that the [`Publisher`] can loan, see
+/// [`PortFactoryPublisher::initial_max_slice_len()`](crate::service::port_factory::publisher::PortFactoryPublisher::initial_max_slice_len())
+/// and
+/// [`PortFactoryPublisher::allocation_strategy()`](crate::service::port_factory::publisher::PortFactoryPublisher::allocation_strategy()).
+#[derive(Debug)]
+pub struct SerdePublisher<Service: service::Service, Payload: Debug + serde::Serialize> {
+    publisher: Publisher<Service, [u8], ()>,
+    _payload: PhantomData<Payload>,
+}
+
+impl<Service: service::Service, Payload: Debug + serde::Serialize>
+    SerdePublisher<Service, Payload>
+{
+    pub(crate) fn new(publisher: Publisher<Service, [u8], ()>) -> Self {
+        Self {
+            publisher,
+            _payload: PhantomData,
+        }
+    }
+
+    /// Returns the [`UniquePublisherId`] of the underlying [`Publisher`]
+    pub fn id(&self) -> UniquePublisherId {
+        self.publisher.id()
+    }
+
+    /// Serializes the payload into a new sample and sends it to all connected
+    /// [`SerdeSubscriber`](crate::port::serde_subscriber::SerdeSubscriber)s. On success the
+    /// number of recipients is returned.
+    pub fn send(&self, value: &Payload) -> Result<usize, SerdePublisherSendError> {
+        let msg = "Unable to send the payload";
+        let bytes = fail!(from self, when Cdr::serialize(value),
+            with SerdePublisherSendError::SerializationFailed,
+            "{} since it could not be serialized.", msg);
+
+        let sample = fail!(from self, when self.publisher.loan_slice_uninit(bytes.len()),
+            to SerdePublisherSendError,
+            "{} since a sample with {} bytes for the serialized payload could not be loaned.",
+            msg, bytes.len());
+
+        let number_of_recipients = fail!(from self, when sample.write_from_slice(&bytes).send(),
+            to SerdePublisherSendError,
+            "{} since the sample with the serialized payload could not be delivered.", msg);
+
+        Ok(number_of_recipients)
+    }
+}
+
+impl<Service: service::Service, Payload: Debug + serde::Serialize> UpdateConnections
+    for SerdePublisher<Service, Payload>
+{
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.publisher.update_connections()
+    }
+}
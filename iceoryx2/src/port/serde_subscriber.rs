@@ -0,0 +1,138 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Serialize, Deserialize)]
+//! struct Message {
+//!     text: String,
+//!     values: Vec<u64>,
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe_serde::<Message>()
+//!     .open_or_create()?;
+//!
+//! let subscriber = service.subscriber_builder()
+//!     .create_serde_subscriber::<Message>()?;
+//!
+//! while let Some(message) = subscriber.receive()? {
+//!     println!("received: {:?}", message);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::serialize::cdr::Cdr;
+use iceoryx2_cal::serialize::Serialize;
+
+use super::port_identifiers::UniqueSubscriberId;
+use super::subscriber::{Subscriber, SubscriberReceiveError};
+use super::update_connections::{ConnectionFailure, UpdateConnections};
+use crate::service;
+
+/// Failure that can be emitted when a payload is received with
+/// [`SerdeSubscriber::receive()`].
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum SerdeSubscriberReceiveError {
+    /// The received sample could not be deserialized into the payload type. The sample was
+    /// released.
+    DeserializationFailed,
+    /// The serialized payload could not be received.
+    ReceiveError(SubscriberReceiveError),
+}
+
+impl From<SubscriberReceiveError> for SerdeSubscriberReceiveError {
+    fn from(value: SubscriberReceiveError) -> Self {
+        SerdeSubscriberReceiveError::ReceiveError(value)
+    }
+}
+
+impl core::fmt::Display for SerdeSubscriberReceiveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "SerdeSubscriberReceiveError::{:?}", self)
+    }
+}
+
+impl core::error::Error for SerdeSubscriberReceiveError {}
+
+/// A [`Subscriber`] of a service created with
+/// [`Builder::publish_subscribe_serde()`](crate::service::builder::Builder::publish_subscribe_serde()).
+/// Every received sample is deserialized into an owned payload and released back to the
+/// [`Publisher`](crate::port::publisher::Publisher) immediately.
+#[derive(Debug)]
+pub struct SerdeSubscriber<Service: service::Service, Payload: Debug + serde::de::DeserializeOwned>
+{
+    subscriber: Subscriber<Service, [u8], ()>,
+    _payload: PhantomData<Payload>,
+}
+
+impl<Service: service::Service, Payload: Debug + serde::de::DeserializeOwned>
+    SerdeSubscriber<Service, Payload>
+{
+    pub(crate) fn new(subscriber: Subscriber<Service, [u8], ()>) -> Self {
+        Self {
+            subscriber,
+            _payload: PhantomData,
+        }
+    }
+
+    /// Returns the [`UniqueSubscriberId`] of the underlying [`Subscriber`]
+    pub fn id(&self) -> UniqueSubscriberId {
+        self.subscriber.id()
+    }
+
+    /// Returns true if the [`SerdeSubscriber`] has samples in the buffer that can be received
+    /// with [`SerdeSubscriber::receive()`].
+    pub fn has_samples(&self) -> Result<bool, ConnectionFailure> {
+        self.subscriber.has_samples()
+    }
+
+    /// Receives the next sample and deserializes it into the payload. If no sample could be
+    /// received [`None`] is returned. If a failure occurs [`SerdeSubscriberReceiveError`] is
+    /// returned.
+    pub fn receive(&self) -> Result<Option<Payload>, SerdeSubscriberReceiveError> {
+        let sample = match fail!(from self, when self.subscriber.receive(),
+                to SerdeSubscriberReceiveError,
+                "Unable to receive another payload since the underlying sample could not be received.")
+        {
+            Some(sample) => sample,
+            None => return Ok(None),
+        };
+
+        let payload = fail!(from self, when Cdr::deserialize(sample.payload()),
+            with SerdeSubscriberReceiveError::DeserializationFailed,
+            "Unable to receive another payload since the sample from publisher {:?} could not be deserialized.",
+            sample.origin());
+
+        Ok(Some(payload))
+    }
+}
+
+impl<Service: service::Service, Payload: Debug + serde::de::DeserializeOwned> UpdateConnections
+    for SerdeSubscriber<Service, Payload>
+{
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.subscriber.update_connections()
+    }
+}
@@ -10,5 +10,1017 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-/// TODO
-pub struct Server {}
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let server = service.server_builder().create()?;
+//!
+//! while let Some(active_request) = server.receive()? {
+//!     println!("received request: {}", *active_request);
+//!
+//!     // a stream of responses can be sent for every request
+//!     active_request.send_copy(1)?;
+//!
+//!     let response = active_request.loan_uninit()?;
+//!     response.write_payload(2).send()?;
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::alloc::Layout;
+use core::cell::{Cell, UnsafeCell};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::sync::atomic::Ordering;
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+use iceoryx2_bb_elementary::allocator::AllocationError;
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::named_concept::{NamedConceptMgmt, NamedConceptRemoveError};
+use iceoryx2_cal::shared_memory::ShmPointer;
+use iceoryx2_cal::shm_allocator::{PointerOffset, ShmAllocationError};
+use iceoryx2_cal::zero_copy_connection::{
+    ZeroCopyConnection, ZeroCopyPortDetails, ZeroCopyReceiveError, ZeroCopyReceiver,
+    ZeroCopySendError, ZeroCopySender,
+};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
+
+#[cfg(feature = "tokio")]
+use super::async_listener::AsyncListener;
+use super::details::client_connections::{ClientConnections, Connection};
+use super::details::data_segment::{DataSegment, DataSegmentType};
+use super::details::segment_state::SegmentState;
+#[cfg(feature = "loan_diagnostics")]
+use super::loan_diagnostics::{LoanTracker, OutstandingLoan};
+use super::port_identifiers::{UniquePortId, UniqueServerId};
+use super::publisher::{
+    connections, handle_port_remove_error, RemovePubSubPortFromAllConnectionsError,
+};
+use super::update_connections::{ConnectionFailure, UpdateConnections};
+use super::LoanError;
+use crate::active_request::ActiveRequest;
+use crate::config;
+use crate::deterministic_profile;
+use crate::node::evidence_log::{EvidenceEntity, StateTransition};
+use crate::raw_sample::RawSample;
+use crate::service::config_scheme::{connection_config, data_segment_config};
+use crate::service::dynamic_config::request_response::{ClientDetails, ServerDetails};
+use crate::service::header;
+use crate::service::naming_scheme::{
+    extract_receiver_id_from_connection, extract_sender_id_from_connection,
+    server_data_segment_name,
+};
+use crate::service::port_factory::server::LocalServerConfig;
+use crate::service::static_config::request_response;
+use crate::service::{self, ServiceState};
+#[cfg(feature = "tokio")]
+use iceoryx2_cal::event::ListenerWaitError;
+
+/// Defines a failure that can occur when a [`Server`] is created with
+/// [`crate::service::port_factory::server::PortFactoryServer`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ServerCreateError {
+    /// The maximum amount of [`Server`]s that can connect to a
+    /// [`Service`](crate::service::Service) is
+    /// defined in [`crate::config::Config`]. When this is exceeded no more [`Server`]s
+    /// can be created for a specific [`Service`](crate::service::Service).
+    ExceedsMaxSupportedServers,
+    /// The datasegment in which the responses of the [`Server`] are stored, could not be created.
+    UnableToCreateDataSegment,
+    /// The requested settings violate the
+    /// [`deterministic_profile`](crate::deterministic_profile) that iceoryx2 was built with.
+    ViolatesDeterministicProfile,
+}
+
+impl core::fmt::Display for ServerCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ServerCreateError::{:?}", self)
+    }
+}
+
+impl core::error::Error for ServerCreateError {}
+
+/// Defines the failure that can occur when a request is received with [`Server::receive()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ServerReceiveError {
+    /// The maximum amount of requests that can be borrowed at the same time was reached. An
+    /// [`ActiveRequest`] has to be dropped before the next one can be received.
+    ExceedsMaxBorrowedRequests,
+    /// Not all connections to the [`Client`](crate::port::client::Client)s could be established
+    /// or the data segment of a [`Client`](crate::port::client::Client) could not be mapped.
+    ConnectionFailure(ConnectionFailure),
+}
+
+impl From<ConnectionFailure> for ServerReceiveError {
+    fn from(value: ConnectionFailure) -> Self {
+        ServerReceiveError::ConnectionFailure(value)
+    }
+}
+
+impl core::fmt::Display for ServerReceiveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ServerReceiveError::{:?}", self)
+    }
+}
+
+impl core::error::Error for ServerReceiveError {}
+
+/// Defines the failures that can occur when a request is received with
+/// [`Server::receive_async()`].
+#[cfg(feature = "tokio")]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ServerReceiveAsyncError {
+    /// The request could not be received, see [`ServerReceiveError`].
+    ReceiveError(ServerReceiveError),
+    /// Waiting on the [`AsyncListener`] for the next notification failed.
+    WaitError(ListenerWaitError),
+}
+
+#[cfg(feature = "tokio")]
+impl From<ServerReceiveError> for ServerReceiveAsyncError {
+    fn from(value: ServerReceiveError) -> Self {
+        ServerReceiveAsyncError::ReceiveError(value)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl From<ListenerWaitError> for ServerReceiveAsyncError {
+    fn from(value: ListenerWaitError) -> Self {
+        ServerReceiveAsyncError::WaitError(value)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl core::fmt::Display for ServerReceiveAsyncError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ServerReceiveAsyncError::{:?}", self)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl core::error::Error for ServerReceiveAsyncError {}
+
+/// Failure that can be emitted when a response is sent via
+/// [`ResponseMut::send()`](crate::response_mut::ResponseMut::send()) or
+/// [`ActiveRequest::send_copy()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ResponseSendError {
+    /// The [`Client`](crate::port::client::Client) that sent the request went out of scope
+    /// and nobody can receive the response anymore.
+    ConnectionBrokenSinceClientNoLongerExists,
+    /// The connection between the [`Server`] and the
+    /// [`Client`](crate::port::client::Client) is corrupted.
+    ConnectionCorrupted,
+    /// The response buffer of the [`Client`](crate::port::client::Client) is full and the
+    /// response could not be delivered.
+    ReceiveBufferFull,
+    /// A failure occurred while acquiring memory for the response
+    LoanError(LoanError),
+    /// The error response was sent with
+    /// [`ActiveRequest::send_error()`](crate::active_request::ActiveRequest::send_error())
+    /// but the [`Service`](crate::service::Service) has no error type or a different one.
+    IncompatibleErrorType,
+}
+
+impl From<LoanError> for ResponseSendError {
+    fn from(value: LoanError) -> Self {
+        ResponseSendError::LoanError(value)
+    }
+}
+
+impl core::fmt::Display for ResponseSendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ResponseSendError::{:?}", self)
+    }
+}
+
+impl core::error::Error for ResponseSendError {}
+
+#[derive(Debug)]
+pub(crate) struct ServerBackend<Service: service::Service> {
+    segment_states: Vec<SegmentState>,
+    data_segment: DataSegment<Service>,
+    config: LocalServerConfig,
+    port_id: UniqueServerId,
+    service_state: Arc<ServiceState<Service>>,
+
+    client_connections: ClientConnections<Service>,
+    client_list_state: UnsafeCell<ContainerState<ClientDetails>>,
+    loan_counter: IoxAtomicUsize,
+    #[cfg(feature = "loan_diagnostics")]
+    loan_tracker: LoanTracker,
+    next_connection: Cell<usize>,
+}
+
+impl<Service: service::Service> ServerBackend<Service> {
+    #[cfg_attr(feature = "loan_diagnostics", track_caller)]
+    pub(crate) fn allocate(&self, layout: Layout) -> Result<ShmPointer, LoanError> {
+        let msg = "Unable to allocate response";
+        let max_loans = self.static_config().max_active_responses;
+
+        if self.loan_counter.load(Ordering::Relaxed) >= max_loans {
+            fail!(from self, with LoanError::ExceedsMaxLoans,
+                "{} since already {} responses were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned response to loan another response.{}",
+                msg, self.loan_counter.load(Ordering::Relaxed), max_loans,
+                self.oldest_loan_description());
+        }
+
+        self.retrieve_returned_samples();
+
+        let shm_pointer = match self.data_segment.allocate(layout) {
+            Ok(shm_pointer) => shm_pointer,
+            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
+                fail!(from self, with LoanError::OutOfMemory,
+                    "{} {:?} since the underlying shared memory is out of memory.", msg, layout);
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge))
+            | Err(ShmAllocationError::AllocationError(AllocationError::AlignmentFailure)) => {
+                fatal_panic!(from self, "{} {:?} since the system seems to be corrupted.", msg, layout);
+            }
+            Err(v) => {
+                fail!(from self, with LoanError::InternalFailure,
+                    "{} {:?} since an internal failure occurred ({:?}).", msg, layout, v);
+            }
+        };
+
+        if self.borrow_sample(shm_pointer.offset) != 0 {
+            fatal_panic!(from self,
+                "{} since the allocated response is already in use! This should never happen!", msg);
+        }
+
+        self.loan_counter.fetch_add(1, Ordering::Relaxed);
+        #[cfg(feature = "loan_diagnostics")]
+        self.loan_tracker
+            .add(shm_pointer.offset, core::panic::Location::caller());
+        Ok(shm_pointer)
+    }
+
+    fn oldest_loan_description(&self) -> String {
+        #[cfg(feature = "loan_diagnostics")]
+        return self.loan_tracker.oldest_loan_description();
+        #[cfg(not(feature = "loan_diagnostics"))]
+        String::new()
+    }
+
+    fn segment_state(&self, offset: PointerOffset) -> &SegmentState {
+        &self.segment_states[offset.segment_id().value() as usize]
+    }
+
+    fn borrow_sample(&self, offset: PointerOffset) -> u64 {
+        let segment_state = self.segment_state(offset);
+        if segment_state.payload_size() == 0 {
+            segment_state.set_payload_size(self.data_segment.bucket_size(offset.segment_id()));
+        }
+        segment_state.borrow_sample(offset.offset())
+    }
+
+    fn release_sample(&self, offset: PointerOffset) {
+        if self.segment_state(offset).release_sample(offset.offset()) == 1 {
+            unsafe { self.data_segment.deallocate_bucket(offset) };
+        }
+    }
+
+    fn retrieve_returned_samples(&self) {
+        for i in 0..self.client_connections.len() {
+            if let Some(ref connection) = self.client_connections.get(i) {
+                loop {
+                    match connection.response_sender.reclaim() {
+                        Ok(Some(offset)) => self.release_sample(offset),
+                        Ok(None) => break,
+                        Err(e) => {
+                            warn!(from self, "Unable to reclaim responses from connection {:?} due to {:?}. This may lead to a situation where no more responses will be delivered to this connection.", connection, e);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn remove_connection(&self, i: usize) {
+        if let Some(connection) = self.client_connections.get(i) {
+            // # SAFETY: the receiver no longer exist, therefore we can
+            //           reacquire all delivered responses
+            unsafe {
+                connection
+                    .response_sender
+                    .acquire_used_offsets(|offset| self.release_sample(offset))
+            };
+
+            self.client_connections.remove(i);
+        }
+    }
+
+    pub(crate) fn return_loaned_response(&self, offset: PointerOffset) {
+        self.release_sample(offset);
+        self.loan_counter.fetch_sub(1, Ordering::Relaxed);
+        #[cfg(feature = "loan_diagnostics")]
+        self.loan_tracker.remove(offset);
+    }
+
+    pub(crate) fn port_id(&self) -> UniqueServerId {
+        self.port_id
+    }
+
+    pub(crate) fn static_config(&self) -> &request_response::StaticConfig {
+        &self.client_connections.static_config
+    }
+
+    pub(crate) fn config(&self) -> &LocalServerConfig {
+        &self.config
+    }
+
+    fn populate_client_channels(&self) -> Result<(), ConnectionFailure> {
+        let mut visited_indices = vec![];
+        visited_indices.resize(self.client_connections.capacity(), None);
+
+        unsafe {
+            (*self.client_list_state.get()).for_each(|h, details| {
+                visited_indices[h.index() as usize] = Some(*details);
+                CallbackProgression::Continue
+            })
+        };
+
+        let mut result = Ok(());
+        for (i, index) in visited_indices.iter().enumerate() {
+            match index {
+                Some(client_details) => {
+                    let create_connection = match self.client_connections.get(i) {
+                        None => true,
+                        Some(connection) => {
+                            let is_outdated =
+                                connection.client_port_id != client_details.client_port_id;
+                            if is_outdated {
+                                self.remove_connection(i);
+                            }
+                            is_outdated
+                        }
+                    };
+
+                    if create_connection {
+                        if let Err(e) = self.client_connections.create(i, client_details) {
+                            warn!(from self,
+                                "Unable to establish connection to new client {:?}.",
+                                client_details.client_port_id);
+                            result = Err(e);
+                        }
+                    }
+                }
+                None => self.remove_connection(i),
+            }
+        }
+
+        result
+    }
+
+    pub(crate) fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        // a closed port establishes no new connections
+        if self
+            .service_state
+            .shared_node
+            .closed_ports()
+            .is_closed(UniquePortId::Server(self.port_id))
+        {
+            return Ok(());
+        }
+
+        if unsafe {
+            self.service_state
+                .dynamic_storage
+                .get()
+                .request_response()
+                .clients
+                .update_state(&mut *self.client_list_state.get())
+        } {
+            fail!(from self, when self.populate_client_channels(),
+                "Connections were updated only partially since at least one connection to a Client port failed.");
+        }
+
+        Ok(())
+    }
+
+    fn receive_from_connection(
+        &self,
+        connection: &Arc<Connection<Service>>,
+    ) -> Result<Option<(PointerOffset, usize)>, ServerReceiveError> {
+        let msg = "Unable to receive request";
+        match connection.request_receiver.receive() {
+            Ok(None) => Ok(None),
+            Ok(Some(offset)) => match connection
+                .data_segment
+                .register_and_translate_offset(offset)
+            {
+                Ok(address) => Ok(Some((offset, address))),
+                Err(e) => {
+                    fail!(from self, with ServerReceiveError::ConnectionFailure(ConnectionFailure::UnableToMapPublishersDataSegment(e)),
+                        "{} since the received offset {:?} from client {:?} could not be registered and translated.",
+                        msg, offset, connection.client_port_id);
+                }
+            },
+            Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                fail!(from self, with ServerReceiveError::ExceedsMaxBorrowedRequests,
+                    "{} since it would exceed the maximum of {} borrowed requests.",
+                    msg, connection.request_receiver.max_borrowed_samples());
+            }
+        }
+    }
+
+    pub(crate) fn receive_request(
+        &self,
+    ) -> Result<Option<(Arc<Connection<Service>>, PointerOffset, usize)>, ServerReceiveError> {
+        if let Err(e) = self.update_connections() {
+            fail!(from self, with ServerReceiveError::ConnectionFailure(e),
+                "Some requests are not being received since not all connections to clients could be established.");
+        }
+
+        // round robin, so that a single client cannot starve all the others
+        let number_of_connections = self.client_connections.len();
+        let start = self.next_connection.get();
+        for n in 0..number_of_connections {
+            let i = (start + n) % number_of_connections;
+            if let Some(ref connection) = self.client_connections.get(i) {
+                if let Some((offset, address)) = self.receive_from_connection(connection)? {
+                    self.next_connection.set((i + 1) % number_of_connections);
+                    return Ok(Some((connection.clone(), offset, address)));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub(crate) fn has_requests(&self) -> Result<bool, ConnectionFailure> {
+        fail!(from self, when self.update_connections(),
+            "Some requests are not being received since not all connections to clients could be established.");
+
+        for i in 0..self.client_connections.len() {
+            if let Some(ref connection) = self.client_connections.get(i) {
+                if connection.request_receiver.has_data() {
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+
+    pub(crate) fn send_response(
+        &self,
+        connection: &Connection<Service>,
+        offset: PointerOffset,
+    ) -> Result<(), ResponseSendError> {
+        let msg = "Unable to send response";
+
+        if !connection.response_sender.is_connected() {
+            fail!(from self, with ResponseSendError::ConnectionBrokenSinceClientNoLongerExists,
+                "{} since the client {:?} is no longer connected.", msg, connection.client_port_id);
+        }
+
+        self.retrieve_returned_samples();
+
+        match connection
+            .response_sender
+            .try_send(offset, self.segment_state(offset).payload_size())
+        {
+            Ok(overflow) => {
+                self.borrow_sample(offset);
+
+                if let Some(old) = overflow {
+                    self.release_sample(old);
+                }
+
+                Ok(())
+            }
+            Err(ZeroCopySendError::ReceiveBufferFull)
+            | Err(ZeroCopySendError::UsedChunkListFull)
+            | Err(ZeroCopySendError::ExceedsMaxSampleRunLength) => {
+                fail!(from self, with ResponseSendError::ReceiveBufferFull,
+                    "{} since the response buffer of client {:?} is full.",
+                    msg, connection.client_port_id);
+            }
+            Err(ZeroCopySendError::ConnectionCorrupted) => {
+                fail!(from self, with ResponseSendError::ConnectionCorrupted,
+                    "{} since the connection to client {:?} is corrupted.",
+                    msg, connection.client_port_id);
+            }
+        }
+    }
+}
+
+/// Receives requests from [`Client`](crate::port::client::Client)s as [`ActiveRequest`]s and
+/// sends a stream of responses back via the [`ActiveRequest`].
+#[derive(Debug)]
+pub struct Server<
+    Service: service::Service,
+    RequestPayload: Debug + ?Sized,
+    RequestHeader: Debug,
+    ResponsePayload: Debug + ?Sized,
+    ResponseHeader: Debug,
+> {
+    backend: Arc<ServerBackend<Service>>,
+    dynamic_server_handle: Option<ContainerHandle>,
+    _request_payload: PhantomData<RequestPayload>,
+    _request_header: PhantomData<RequestHeader>,
+    _response_payload: PhantomData<ResponsePayload>,
+    _response_header: PhantomData<ResponseHeader>,
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > Drop for Server<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    fn drop(&mut self) {
+        if let Some(handle) = self.dynamic_server_handle {
+            let service_state = &self.backend.service_state;
+            service_state
+                .shared_node
+                .closed_ports()
+                .release_unless_closed(UniquePortId::Server(self.id()), || {
+                    service_state.shared_node.record_evidence(
+                        EvidenceEntity::Port,
+                        StateTransition::Destroyed,
+                        format_args!(
+                            "{:?} of service {}",
+                            self.id(),
+                            service_state.static_config.name()
+                        ),
+                    );
+                    service_state
+                        .dynamic_storage
+                        .get()
+                        .request_response()
+                        .release_server_handle(handle)
+                });
+        }
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > Server<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    #[cfg(feature = "tokio")]
+    async fn receive_async_impl<
+        F: Fn(
+            &Self,
+        ) -> Result<
+            Option<
+                ActiveRequest<
+                    Service,
+                    RequestPayload,
+                    RequestHeader,
+                    ResponsePayload,
+                    ResponseHeader,
+                >,
+            >,
+            ServerReceiveError,
+        >,
+    >(
+        &self,
+        listener: &AsyncListener<Service>,
+        receive_call: F,
+    ) -> Result<
+        ActiveRequest<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>,
+        ServerReceiveAsyncError,
+    > {
+        loop {
+            if let Some(active_request) = receive_call(self)? {
+                return Ok(active_request);
+            }
+
+            listener.wait_all(|_| {}).await?;
+        }
+    }
+
+    pub(crate) fn new(
+        service: &Service,
+        static_config: &request_response::StaticConfig,
+        config: LocalServerConfig,
+    ) -> Result<Self, ServerCreateError> {
+        let msg = "Unable to create Server port";
+        let origin = "Server::new()";
+
+        if !deterministic_profile::allows_allocation_strategy(config.allocation_strategy) {
+            fail!(from origin, with ServerCreateError::ViolatesDeterministicProfile,
+                "{} since the allocation strategy {:?} violates the deterministic profile.",
+                msg, config.allocation_strategy);
+        }
+
+        let port_id = UniqueServerId::new();
+        let client_list = &service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .clients;
+
+        let number_of_responses = static_config.number_of_responses_per_server();
+        let data_segment_type =
+            DataSegmentType::new_from_allocation_strategy(config.allocation_strategy);
+        let max_number_of_segments =
+            DataSegment::<Service>::max_number_of_segments(data_segment_type);
+        let global_config = service.__internal_state().shared_node.config();
+        let data_segment = fail!(from origin,
+                when DataSegment::create_segment(
+                    &server_data_segment_name(&port_id),
+                    global_config,
+                    static_config.response_sample_layout(config.initial_max_slice_len),
+                    number_of_responses,
+                    data_segment_type,
+                    config.allocation_strategy),
+                with ServerCreateError::UnableToCreateDataSegment,
+                "{} since the data segment could not be created.", msg);
+
+        let server_details = ServerDetails {
+            server_port_id: port_id,
+            node_id: *service.__internal_state().shared_node.id(),
+            request_buffer_size: static_config.max_request_buffer_size,
+            number_of_responses,
+            max_slice_len: config.initial_max_slice_len,
+            data_segment_type,
+            max_number_of_segments,
+        };
+
+        let backend = Arc::new(ServerBackend {
+            segment_states: (0..max_number_of_segments)
+                .map(|_| SegmentState::new(number_of_responses))
+                .collect(),
+            data_segment,
+            config,
+            port_id,
+            service_state: service.__internal_state().clone(),
+            client_connections: ClientConnections::new(
+                client_list.capacity(),
+                service.__internal_state().shared_node.clone(),
+                port_id,
+                static_config,
+                server_details.request_buffer_size,
+                number_of_responses,
+                max_number_of_segments,
+            ),
+            client_list_state: UnsafeCell::new(unsafe { client_list.get_state() }),
+            loan_counter: IoxAtomicUsize::new(0),
+            #[cfg(feature = "loan_diagnostics")]
+            loan_tracker: LoanTracker::default(),
+            next_connection: Cell::new(0),
+        });
+
+        let mut new_self = Self {
+            backend,
+            dynamic_server_handle: None,
+            _request_payload: PhantomData,
+            _request_header: PhantomData,
+            _response_payload: PhantomData,
+            _response_header: PhantomData,
+        };
+
+        if let Err(e) = new_self.backend.populate_client_channels() {
+            warn!(from new_self, "The new Server port is unable to connect to every Client port, caused by {:?}.", e);
+        }
+
+        core::sync::atomic::compiler_fence(Ordering::SeqCst);
+
+        // !MUST! be the last task otherwise a server is added to the dynamic config without the
+        // creation of all required resources
+        let dynamic_server_handle = match service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .add_server_id(server_details)
+        {
+            Some(handle) => handle,
+            None => {
+                fail!(from origin, with ServerCreateError::ExceedsMaxSupportedServers,
+                    "{} since it would exceed the maximum supported amount of servers of {}.",
+                    msg, static_config.max_servers);
+            }
+        };
+
+        new_self.dynamic_server_handle = Some(dynamic_server_handle);
+
+        new_self.backend.service_state.shared_node.record_evidence(
+            EvidenceEntity::Port,
+            StateTransition::Created,
+            format_args!(
+                "{:?} of service {}",
+                new_self.id(),
+                new_self.backend.service_state.static_config.name()
+            ),
+        );
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`UniqueServerId`] of the [`Server`]
+    pub fn id(&self) -> UniqueServerId {
+        self.backend.port_id
+    }
+
+    /// Returns all responses that are currently loaned from the
+    /// [`ActiveRequest`](crate::active_request::ActiveRequest)s of the [`Server`] together
+    /// with the code location and the point in time of the loan.
+    #[cfg(feature = "loan_diagnostics")]
+    pub fn outstanding_loans(&self) -> Vec<OutstandingLoan> {
+        self.backend.loan_tracker.outstanding_loans()
+    }
+
+    /// Returns true if the [`Server`] has requests in its buffer that can be received with
+    /// [`Server::receive()`].
+    pub fn has_requests(&self) -> Result<bool, ConnectionFailure> {
+        self.backend.has_requests()
+    }
+
+    /// Returns the maximum slice length configured for this [`Server`].
+    pub fn initial_max_slice_len(&self) -> usize {
+        self.backend.config.initial_max_slice_len
+    }
+
+    fn user_header_ptr(&self, header: *const header::request_response::RequestHeader) -> *const u8 {
+        self.backend
+            .static_config()
+            .request_message_type_details
+            .user_header_ptr_from_header(header.cast())
+            .cast()
+    }
+
+    fn payload_ptr(&self, header: *const header::request_response::RequestHeader) -> *const u8 {
+        self.backend
+            .static_config()
+            .request_message_type_details
+            .payload_ptr_from_header(header.cast())
+            .cast()
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > Server<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    /// Receives a request from a [`Client`](crate::port::client::Client). The
+    /// [`ActiveRequest`] is used to send responses back to the
+    /// [`Client`](crate::port::client::Client). If no request could be received [`None`] is
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let server = service.server_builder().create()?;
+    ///
+    /// while let Some(active_request) = server.receive()? {
+    ///     println!("received request {} with id {}",
+    ///         *active_request, active_request.header().request_id());
+    ///     active_request.send_copy(*active_request + 1)?;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn receive(
+        &self,
+    ) -> Result<
+        Option<
+            ActiveRequest<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>,
+        >,
+        ServerReceiveError,
+    > {
+        let (connection, offset, address) = match self.backend.receive_request()? {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+
+        let header_ptr = address as *const header::request_response::RequestHeader;
+        let user_header_ptr = self.user_header_ptr(header_ptr) as *const RequestHeader;
+        let payload_ptr = self.payload_ptr(header_ptr) as *const RequestPayload;
+        let ptr = unsafe { RawSample::new_unchecked(header_ptr, user_header_ptr, payload_ptr) };
+
+        Ok(Some(ActiveRequest::new(
+            &self.backend,
+            connection,
+            offset,
+            ptr,
+        )))
+    }
+
+    /// Receives the next request asynchronously. When no request is available the task is
+    /// suspended until the provided [`AsyncListener`] is notified, e.g. by a
+    /// [`Notifier`](crate::port::notifier::Notifier) that the
+    /// [`Client`](crate::port::client::Client) side triggers after every sent request. The
+    /// runtime thread is not blocked while waiting.
+    ///
+    /// Only available with the `tokio` feature.
+    #[cfg(feature = "tokio")]
+    pub async fn receive_async(
+        &self,
+        listener: &AsyncListener<Service>,
+    ) -> Result<
+        ActiveRequest<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>,
+        ServerReceiveAsyncError,
+    > {
+        self.receive_async_impl(listener, Self::receive).await
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > Server<Service, [RequestPayload], RequestHeader, ResponsePayload, ResponseHeader>
+{
+    /// Receives a request with a slice payload from a [`Client`](crate::port::client::Client).
+    /// The [`ActiveRequest`] is used to send responses back to the
+    /// [`Client`](crate::port::client::Client). If no request could be received [`None`] is
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<[u64], u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let server = service.server_builder().create()?;
+    ///
+    /// while let Some(active_request) = server.receive()? {
+    ///     println!("received request with {} elements", active_request.payload().len());
+    ///     active_request.send_copy(active_request.payload().iter().sum())?;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn receive(
+        &self,
+    ) -> Result<
+        Option<
+            ActiveRequest<
+                Service,
+                [RequestPayload],
+                RequestHeader,
+                ResponsePayload,
+                ResponseHeader,
+            >,
+        >,
+        ServerReceiveError,
+    > {
+        let (connection, offset, address) = match self.backend.receive_request()? {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+
+        let header_ptr = address as *const header::request_response::RequestHeader;
+        let user_header_ptr = self.user_header_ptr(header_ptr) as *const RequestHeader;
+        let payload_ptr = self.payload_ptr(header_ptr) as *const RequestPayload;
+        let number_of_elements = unsafe { (*header_ptr).number_of_elements() };
+        let ptr = unsafe {
+            RawSample::<header::request_response::RequestHeader, RequestHeader, [RequestPayload]>::new_slice_unchecked(
+                header_ptr,
+                user_header_ptr,
+                core::slice::from_raw_parts(payload_ptr, number_of_elements as _),
+            )
+        };
+
+        Ok(Some(ActiveRequest::new(
+            &self.backend,
+            connection,
+            offset,
+            ptr,
+        )))
+    }
+
+    /// Receives the next request with a slice payload asynchronously. When no request is
+    /// available the task is suspended until the provided [`AsyncListener`] is notified. The
+    /// runtime thread is not blocked while waiting.
+    ///
+    /// Only available with the `tokio` feature.
+    #[cfg(feature = "tokio")]
+    pub async fn receive_async(
+        &self,
+        listener: &AsyncListener<Service>,
+    ) -> Result<
+        ActiveRequest<Service, [RequestPayload], RequestHeader, ResponsePayload, ResponseHeader>,
+        ServerReceiveAsyncError,
+    > {
+        self.receive_async_impl(listener, Self::receive).await
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > UpdateConnections
+    for Server<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.backend.update_connections()
+    }
+}
+
+pub(crate) unsafe fn remove_data_segment_of_server<Service: service::Service>(
+    port_id: &UniqueServerId,
+    config: &config::Config,
+) -> Result<(), NamedConceptRemoveError> {
+    let origin = format!(
+        "remove_data_segment_of_server::<{}>::({:?})",
+        core::any::type_name::<Service>(),
+        port_id
+    );
+
+    fail!(from origin, when <Service::SharedMemory as NamedConceptMgmt>::remove_cfg(
+            &server_data_segment_name(port_id),
+            &data_segment_config::<Service>(config),
+        ), "Unable to remove the servers data segment."
+    );
+
+    Ok(())
+}
+
+pub(crate) unsafe fn remove_server_from_all_connections<Service: service::Service>(
+    port_id: &UniqueServerId,
+    config: &config::Config,
+) -> Result<(), RemovePubSubPortFromAllConnectionsError> {
+    let origin = format!(
+        "remove_server_from_all_connections::<{}>::({:?})",
+        core::any::type_name::<Service>(),
+        port_id
+    );
+    let msg = "Unable to remove the server from all connections";
+
+    let connection_config = connection_config::<Service>(config);
+    let connection_list = connections::<Service>(&origin, msg, &connection_config)?;
+
+    let mut ret_val = Ok(());
+    for connection in connection_list {
+        // the server is the receiver of its request connections and the sender of its
+        // response connections
+        let result = if extract_receiver_id_from_connection(&connection) == port_id.value() {
+            handle_port_remove_error(
+                Service::Connection::remove_receiver(&connection, &connection_config),
+                &origin,
+                msg,
+                &connection,
+            )
+        } else if extract_sender_id_from_connection(&connection) == port_id.value() {
+            handle_port_remove_error(
+                Service::Connection::remove_sender(&connection, &connection_config),
+                &origin,
+                msg,
+                &connection,
+            )
+        } else {
+            Ok(())
+        };
+
+        if ret_val.is_ok() {
+            ret_val = result;
+        }
+    }
+
+    ret_val
+}
@@ -31,35 +31,53 @@
 //! # }
 //! ```
 
+use core::alloc::Layout;
 use core::any::TypeId;
-use core::cell::UnsafeCell;
+use core::cell::{Cell, RefCell, UnsafeCell};
 use core::fmt::Debug;
 use core::marker::PhantomData;
 use core::sync::atomic::Ordering;
+use core::time::Duration;
 
 extern crate alloc;
+use alloc::collections::VecDeque;
 use alloc::sync::Arc;
 
-use iceoryx2_bb_container::queue::Queue;
-use iceoryx2_bb_elementary::CallbackProgression;
-use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
-use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_elementary::crc32c::crc32c;
+use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_bb_posix::clock::{ClockType, Time};
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::event::{Event, Listener, ListenerBuilder};
+use iceoryx2_cal::named_concept::{NamedConceptBuilder, NamedConceptMgmt, NamedConceptRemoveError};
 use iceoryx2_cal::zero_copy_connection::*;
 
-use crate::port::DegrationAction;
-use crate::sample::SampleDetails;
+use crate::config;
+use crate::deterministic_profile;
+use crate::node::evidence_log::{EvidenceEntity, StateTransition};
+use crate::node::load_shedding::{self, LoadMonitor, ShedPolicy};
+use crate::node::port_registry::RegisteredPort;
+use crate::node::resource_budget::ResourceReservation;
+use crate::sample::{BorrowedSampleGuard, HeapCopy, SampleDetails, SampleStorage};
 use crate::service::builder::publish_subscribe::CustomPayloadMarker;
-use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
+use crate::service::config_scheme::event_config;
+use crate::service::dynamic_config::publish_subscribe::SubscriberDetails;
 use crate::service::header::publish_subscribe::Header;
-use crate::service::port_factory::subscriber::SubscriberConfig;
+use crate::service::naming_scheme::readiness_event_name;
+use crate::service::port_factory::subscriber::{
+    AutoExpire, DeliveryQos, FairnessPolicy, SampleFilter, SubscriberConfig,
+};
 use crate::service::static_config::publish_subscribe::StaticConfig;
 use crate::{raw_sample::RawSample, sample::Sample, service};
 
+#[cfg(feature = "tokio")]
+use super::async_listener::AsyncListener;
 use super::details::publisher_connections::{Connection, PublisherConnections};
-use super::port_identifiers::UniqueSubscriberId;
+use super::port_identifiers::{UniquePortId, UniquePublisherId, UniqueSubscriberId};
 use super::update_connections::{ConnectionFailure, UpdateConnections};
-use super::DegrationCallback;
+use super::ZeroCopyConnectionCounters;
+#[cfg(feature = "tokio")]
+use iceoryx2_cal::event::ListenerWaitError;
 
 /// Defines the failure that can occur when receiving data with [`Subscriber::receive()`].
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
@@ -69,9 +87,24 @@ pub enum SubscriberReceiveError {
     /// fails.
     ExceedsMaxBorrowedSamples,
 
+    /// All [`Subscriber`]s of the [`Service`](crate::service::Service) together have already
+    /// borrowed the maximum number of [`Sample`]s, see
+    /// [`Builder::max_total_borrowed_samples()`](crate::service::builder::publish_subscribe::Builder::max_total_borrowed_samples()).
+    ExceedsMaxTotalBorrowedSamples,
+
     /// Occurs when a [`Subscriber`] is unable to connect to a corresponding
     /// [`Publisher`](crate::port::publisher::Publisher).
     ConnectionFailure(ConnectionFailure),
+
+    /// The [`Service`](crate::service::Service) was created with
+    /// [`Builder::enable_payload_checksum()`](crate::service::builder::publish_subscribe::Builder::enable_payload_checksum())
+    /// and the checksum of the received payload does not match the checksum in the
+    /// [`Header`]. The corrupted sample was discarded.
+    PayloadChecksumMismatch,
+
+    /// The [`Subscriber`] has expired, see
+    /// [`PortFactorySubscriber::auto_expire()`](crate::service::port_factory::subscriber::PortFactorySubscriber::auto_expire()).
+    Expired,
 }
 
 impl core::fmt::Display for SubscriberReceiveError {
@@ -82,6 +115,57 @@ impl core::fmt::Display for SubscriberReceiveError {
 
 impl core::error::Error for SubscriberReceiveError {}
 
+/// Defines the failures that can occur when a [`Sample`] is received with
+/// [`Subscriber::receive_async()`].
+#[cfg(feature = "tokio")]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SubscriberReceiveAsyncError {
+    /// The [`Sample`] could not be received, see [`SubscriberReceiveError`].
+    ReceiveError(SubscriberReceiveError),
+    /// Waiting on the [`AsyncListener`] for the next notification failed.
+    WaitError(ListenerWaitError),
+}
+
+#[cfg(feature = "tokio")]
+impl From<SubscriberReceiveError> for SubscriberReceiveAsyncError {
+    fn from(value: SubscriberReceiveError) -> Self {
+        SubscriberReceiveAsyncError::ReceiveError(value)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl From<ListenerWaitError> for SubscriberReceiveAsyncError {
+    fn from(value: ListenerWaitError) -> Self {
+        SubscriberReceiveAsyncError::WaitError(value)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl core::fmt::Display for SubscriberReceiveAsyncError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "SubscriberReceiveAsyncError::{:?}", self)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl core::error::Error for SubscriberReceiveAsyncError {}
+
+/// Defines the failure that can occur when waiting for a ready
+/// [`Publisher`](crate::port::publisher::Publisher) with [`Subscriber::wait_for_readiness()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SubscriberWaitForReadinessError {
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+}
+
+impl core::fmt::Display for SubscriberWaitForReadinessError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "SubscriberWaitForReadinessError::{:?}", self)
+    }
+}
+
+impl core::error::Error for SubscriberWaitForReadinessError {}
+
 /// Describes the failures when a new [`Subscriber`] is created via the
 /// [`crate::service::port_factory::subscriber::PortFactorySubscriber`].
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
@@ -94,6 +178,17 @@ pub enum SubscriberCreateError {
     /// When the [`Subscriber`] requires a larger buffer size than the
     /// [`Service`](crate::service::Service) offers the creation will fail.
     BufferSizeExceedsMaxSupportedBufferSizeOfService,
+    /// The [`Subscriber`] would exceed the memory budget or the maximum number of ports of the
+    /// [`Node`](crate::node::Node), see [`NodeBuilder`](crate::node::NodeBuilder).
+    ExceedsResourceBudgetOfNode,
+    /// The requested settings violate the
+    /// [`deterministic_profile`](crate::deterministic_profile) that iceoryx2 was built with.
+    ViolatesDeterministicProfile,
+    /// In place updates were enabled with
+    /// [`PortFactorySubscriber::enable_in_place_updates()`](crate::service::port_factory::subscriber::PortFactorySubscriber::enable_in_place_updates())
+    /// but the payload is mapped read-only, see
+    /// [`Service::read_only_payload_mapping`](crate::config::Service::read_only_payload_mapping).
+    InPlaceUpdatesRequireWritablePayload,
 }
 
 impl core::fmt::Display for SubscriberCreateError {
@@ -111,13 +206,31 @@ pub struct Subscriber<
     Payload: Debug + ?Sized + 'static,
     UserHeader: Debug,
 > {
-    dynamic_subscriber_handle: Option<ContainerHandle>,
-    publisher_connections: PublisherConnections<Service>,
-    to_be_removed_connections: UnsafeCell<Queue<Arc<Connection<Service>>>>,
+    dynamic_subscriber_handle: Cell<Option<ContainerHandle>>,
+    publisher_connections: Arc<PublisherConnections<Service>>,
     static_config: crate::service::static_config::StaticConfig,
-    degration_callback: Option<DegrationCallback<'static>>,
-
-    publisher_list_state: UnsafeCell<ContainerState<PublisherDetails>>,
+    ignore_unready_publishers: bool,
+    fairness_policy: FairnessPolicy,
+    round_robin_cursor: Cell<usize>,
+    staged_samples: UnsafeCell<Vec<Option<(SampleDetails<Service>, usize)>>>,
+    auto_expire: Option<AutoExpire>,
+    creation_time: Option<Time>,
+    number_of_received_samples: Cell<u64>,
+    is_expired: Cell<bool>,
+    is_paused: Cell<bool>,
+    filter: Option<SampleFilter<Service, Payload, UserHeader>>,
+    receive_heap_copies: bool,
+    expired_connection_backlog: UnsafeCell<VecDeque<(HeapCopy, UniquePublisherId)>>,
+    expired_connection_backlog_size: Cell<usize>,
+    max_expired_connection_backlog_size: usize,
+    delivery_qos: DeliveryQos,
+    in_place_updates: bool,
+    shed_policy: ShedPolicy,
+    load_monitor: Arc<LoadMonitor>,
+    samples_since_last_kept: Cell<u64>,
+    number_of_shed_samples: Cell<u64>,
+    readiness_listener: RefCell<Option<<Service::Event as Event>::Listener>>,
+    _resource_reservation: ResourceReservation,
     _payload: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
 }
@@ -126,13 +239,13 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> Drop
     for Subscriber<Service, Payload, UserHeader>
 {
     fn drop(&mut self) {
-        if let Some(handle) = self.dynamic_subscriber_handle {
-            self.publisher_connections
-                .service_state
-                .dynamic_storage
-                .get()
-                .publish_subscribe()
-                .release_subscriber_handle(handle)
+        let staged_samples = core::mem::take(self.staged_samples.get_mut());
+        for (details, _) in staged_samples.into_iter().flatten() {
+            self.discard_sample(&details.publisher_connection, details.offset);
+        }
+
+        if let Some(handle) = self.dynamic_subscriber_handle.get() {
+            self.release_dynamic_subscriber_handle(handle, true);
         }
     }
 }
@@ -140,15 +253,56 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> Drop
 impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
     Subscriber<Service, Payload, UserHeader>
 {
+    #[cfg(feature = "tokio")]
+    async fn receive_async_impl<
+        F: Fn(&Self) -> Result<Option<Sample<Service, Payload, UserHeader>>, SubscriberReceiveError>,
+    >(
+        &self,
+        listener: &AsyncListener<Service>,
+        receive_call: F,
+    ) -> Result<Sample<Service, Payload, UserHeader>, SubscriberReceiveAsyncError> {
+        loop {
+            if let Some(sample) = receive_call(self)? {
+                return Ok(sample);
+            }
+
+            listener.wait_all(|_| {}).await?;
+        }
+    }
+
     pub(crate) fn new(
         service: &Service,
         static_config: &StaticConfig,
         config: SubscriberConfig,
+        filter: Option<SampleFilter<Service, Payload, UserHeader>>,
     ) -> Result<Self, SubscriberCreateError> {
         let msg = "Failed to create Subscriber port";
         let origin = "Subscriber::new()";
         let subscriber_id = UniqueSubscriberId::new();
 
+        if !deterministic_profile::allows_subscriber_settings(
+            config.eager_segment_mapping,
+            config.receive_heap_copies,
+        ) {
+            fail!(from origin, with SubscriberCreateError::ViolatesDeterministicProfile,
+                "{} since lazy segment mapping or receiving heap copies violates the deterministic profile.",
+                msg);
+        }
+
+        if config.in_place_updates
+            && service
+                .__internal_state()
+                .shared_node
+                .config()
+                .global
+                .service
+                .read_only_payload_mapping
+        {
+            fail!(from origin, with SubscriberCreateError::InPlaceUpdatesRequireWritablePayload,
+                "{} since in place updates require a writable payload but the payload is mapped read-only.",
+                msg);
+        }
+
         let publisher_list = &service
             .__internal_state()
             .dynamic_storage
@@ -168,34 +322,74 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             None => static_config.subscriber_max_buffer_size,
         };
 
-        let publisher_connections = PublisherConnections::new(
+        // every connection to a publisher has a receive buffer of offsets
+        let buffer_memory =
+            buffer_size * publisher_list.capacity() * core::mem::size_of::<PointerOffset>();
+        let resource_reservation = fail!(from origin,
+                when service.__internal_state().shared_node.resource_budget().reserve(buffer_memory),
+                with SubscriberCreateError::ExceedsResourceBudgetOfNode,
+                "{} since it would exceed the resource budget of the node.", msg);
+
+        let publisher_connections = Arc::new(PublisherConnections::new(
             publisher_list.capacity(),
             subscriber_id,
             service.__internal_state().clone(),
             static_config,
             buffer_size,
-        );
+            config.degration_callback,
+            config.eager_segment_mapping,
+            config.expired_connection_backlog.is_some(),
+        ));
 
-        let mut new_self = Self {
-            to_be_removed_connections: UnsafeCell::new(Queue::new(
-                service
-                    .__internal_state()
-                    .shared_node
-                    .config()
-                    .defaults
-                    .publish_subscribe
-                    .subscriber_expired_connection_buffer,
-            )),
-            degration_callback: config.degration_callback,
+        let creation_time = match config.auto_expire {
+            Some(AutoExpire::AfterDuration(_)) => {
+                match Time::now_with_clock(ClockType::Monotonic) {
+                    Ok(time) => Some(time),
+                    Err(e) => {
+                        warn!(from origin,
+                            "The new subscriber will not expire after the defined duration since the current time could not be acquired ({:?}).", e);
+                        None
+                    }
+                }
+            }
+            _ => None,
+        };
+
+        let new_self = Self {
+            ignore_unready_publishers: config.ignore_unready_publishers,
+            fairness_policy: config.fairness_policy,
+            round_robin_cursor: Cell::new(0),
+            staged_samples: UnsafeCell::new((0..publisher_list.capacity()).map(|_| None).collect()),
             publisher_connections,
-            publisher_list_state: UnsafeCell::new(unsafe { publisher_list.get_state() }),
-            dynamic_subscriber_handle: None,
+            dynamic_subscriber_handle: Cell::new(None),
+            auto_expire: config.auto_expire,
+            creation_time,
+            number_of_received_samples: Cell::new(0),
+            is_expired: Cell::new(false),
+            is_paused: Cell::new(false),
+            filter,
+            receive_heap_copies: config.receive_heap_copies,
+            expired_connection_backlog: UnsafeCell::new(VecDeque::new()),
+            expired_connection_backlog_size: Cell::new(0),
+            max_expired_connection_backlog_size: config.expired_connection_backlog.unwrap_or(0),
+            delivery_qos: config.delivery_qos,
+            in_place_updates: config.in_place_updates,
+            shed_policy: config.shed_policy,
+            load_monitor: service
+                .__internal_state()
+                .shared_node
+                .load_monitor()
+                .clone(),
+            samples_since_last_kept: Cell::new(0),
+            number_of_shed_samples: Cell::new(0),
+            readiness_listener: RefCell::new(None),
             static_config: service.__internal_state().static_config.clone(),
+            _resource_reservation: resource_reservation,
             _payload: PhantomData,
             _user_header: PhantomData,
         };
 
-        if let Err(e) = new_self.populate_publisher_channels() {
+        if let Err(e) = new_self.publisher_connections.populate_publisher_channels() {
             warn!(from new_self, "The new subscriber is unable to connect to every publisher, caused by {:?}.", e);
         }
 
@@ -212,6 +406,7 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                 subscriber_id,
                 buffer_size,
                 node_id: *service.__internal_state().shared_node.id(),
+                delivery_qos: config.delivery_qos,
             }) {
             Some(unique_index) => unique_index,
             None => {
@@ -221,81 +416,94 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             }
         };
 
-        new_self.dynamic_subscriber_handle = Some(dynamic_subscriber_handle);
+        new_self
+            .dynamic_subscriber_handle
+            .set(Some(dynamic_subscriber_handle));
+
+        service
+            .__internal_state()
+            .shared_node
+            .port_registry()
+            .register(RegisteredPort::Subscriber(Arc::downgrade(
+                &new_self.publisher_connections,
+            )));
+
+        new_self
+            .publisher_connections
+            .service_state
+            .shared_node
+            .record_evidence(
+                EvidenceEntity::Port,
+                StateTransition::Created,
+                format_args!(
+                    "{:?} of service {}",
+                    new_self.id(),
+                    new_self
+                        .publisher_connections
+                        .service_state
+                        .static_config
+                        .name()
+                ),
+            );
 
         Ok(new_self)
     }
 
-    fn populate_publisher_channels(&self) -> Result<(), ConnectionFailure> {
-        let mut visited_indices = vec![];
-        visited_indices.resize(self.publisher_connections.capacity(), None);
-
-        unsafe {
-            (*self.publisher_list_state.get()).for_each(|h, details| {
-                visited_indices[h.index() as usize] = Some(*details);
-                CallbackProgression::Continue
-            })
-        };
+    fn discard_sample(&self, connection: &Arc<Connection<Service>>, offset: PointerOffset) {
+        unsafe { connection.unregister_offset(offset) };
+        self.release_offset(connection, offset);
+    }
 
-        let prepare_connection_removal = |i| {
-            if let Some(connection) = self.publisher_connections.get(i) {
-                if connection.receiver.has_data()
-                    && !unsafe { &mut *self.to_be_removed_connections.get() }
-                        .push(connection.clone())
-                {
-                    warn!(from self, "Expired connection buffer exceeded. A publisher disconnected with undelivered samples that will be discarded. Increase the config entry `defaults.publish-subscribe.subscriber-expired-connection-buffer` to mitigate the problem.");
-                }
+    // releases all pending samples of the connection except the newest one without mapping
+    // them into the process space
+    fn discard_stale_samples(&self, connection: &Arc<Connection<Service>>) {
+        while connection.receiver.number_of_pending_samples() > 1 {
+            match connection.receiver.receive() {
+                Ok(Some(offset)) => self.release_offset(connection, offset),
+                Ok(None) | Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => return,
             }
-        };
-
-        // update all connections
-        for (i, index) in visited_indices.iter().enumerate() {
-            match index {
-                Some(details) => {
-                    let create_connection = match self.publisher_connections.get(i) {
-                        None => true,
-                        Some(connection) => connection.publisher_id != details.publisher_id,
-                    };
+        }
+    }
 
-                    if create_connection {
-                        prepare_connection_removal(i);
-
-                        match self.publisher_connections.create(i, details) {
-                            Ok(()) => (),
-                            Err(e) => match &self.degration_callback {
-                                None => {
-                                    warn!(from self, "Unable to establish connection to new publisher {:?}.", details.publisher_id)
-                                }
-                                Some(c) => {
-                                    match c.call(
-                                        self.static_config.clone(),
-                                        details.publisher_id,
-                                        self.publisher_connections.subscriber_id(),
-                                    ) {
-                                        DegrationAction::Ignore => (),
-                                        DegrationAction::Warn => {
-                                            warn!(from self, "Unable to establish connection to new publisher {:?}.",
-                                        details.publisher_id)
-                                        }
-                                        DegrationAction::Fail => {
-                                            fail!(from self, with e, "Unable to establish connection to new publisher {:?}.",
-                                        details.publisher_id);
-                                        }
-                                    }
-                                }
-                            },
-                        }
-                    }
-                }
-                None => {
-                    prepare_connection_removal(i);
+    fn release_offset(&self, connection: &Arc<Connection<Service>>, offset: PointerOffset) {
+        match connection.release(offset) {
+            Ok(()) => (),
+            Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
+                fatal_panic!(from self, "This should never happen! The publishers retrieve channel is full and the sample cannot be returned.");
+            }
+            Err(ZeroCopyReleaseError::ReceiverOfPreviousGeneration) => {
+                warn!(from self, "Unable to return the sample to the publisher since the subscriber was considered dead and replaced. The publisher recovers the sample when it cleans up the connection.");
+            }
+        }
+    }
 
-                    self.publisher_connections.remove(i)
-                }
+    fn acquire_borrowed_sample(
+        &self,
+        max_total_borrowed_samples: usize,
+    ) -> Result<BorrowedSampleGuard<Service>, SubscriberReceiveError> {
+        let subscriber_slot = match self.dynamic_subscriber_handle.get() {
+            Some(handle) => handle.index(),
+            None => {
+                fatal_panic!(from self, "This should never happen! The subscriber is not registered in the dynamic config of the service.");
             }
+        };
+
+        let service_state = &self.publisher_connections.service_state;
+        if !service_state
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .acquire_borrowed_sample(subscriber_slot, max_total_borrowed_samples)
+        {
+            fail!(from self, with SubscriberReceiveError::ExceedsMaxTotalBorrowedSamples,
+                "Unable to receive another sample since all subscribers together would exceed the maximum {} of borrowed samples.",
+                max_total_borrowed_samples);
         }
 
-        Ok(())
+        Ok(BorrowedSampleGuard::new(
+            service_state.clone(),
+            subscriber_slot,
+        ))
     }
 
     fn receive_from_connection(
@@ -303,20 +511,31 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         connection: &Arc<Connection<Service>>,
     ) -> Result<Option<(SampleDetails<Service>, usize)>, SubscriberReceiveError> {
         let msg = "Unable to receive another sample";
-        match connection.receiver.receive() {
-            Ok(data) => match data {
-                None => Ok(None),
-                Some(offset) => {
+        loop {
+            let borrow_guard = match self
+                .static_config
+                .publish_subscribe()
+                .max_total_borrowed_samples
+            {
+                None => None,
+                Some(_) if !connection.receiver.has_data() => return Ok(None),
+                Some(max_total_borrowed_samples) => {
+                    Some(self.acquire_borrowed_sample(max_total_borrowed_samples)?)
+                }
+            };
+
+            match connection.receiver.receive() {
+                Ok(None) => return Ok(None),
+                Ok(Some(offset)) => {
                     let details = SampleDetails {
                         publisher_connection: connection.clone(),
                         offset,
                         origin: connection.publisher_id,
+                        in_place_updates: self.in_place_updates,
+                        _borrow_guard: borrow_guard,
                     };
 
-                    let offset = match connection
-                        .data_segment
-                        .register_and_translate_offset(offset)
-                    {
+                    let offset = match connection.register_and_translate_offset(offset) {
                         Ok(offset) => offset,
                         Err(e) => {
                             fail!(from self, with SubscriberReceiveError::ConnectionFailure(ConnectionFailure::UnableToMapPublishersDataSegment(e)),
@@ -325,14 +544,130 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                         }
                     };
 
-                    Ok(Some((details, offset)))
+                    let header = offset as *const Header;
+                    if !self.has_valid_payload_checksum(header) {
+                        self.discard_sample(connection, details.offset);
+                        fail!(from self, with SubscriberReceiveError::PayloadChecksumMismatch,
+                            "{} since the payload checksum of the sample from publisher {:?} does not match. The sample was discarded.",
+                            msg, connection.publisher_id);
+                    }
+
+                    if self.ignore_unready_publishers && !unsafe { (*header).is_publisher_ready() }
+                    {
+                        self.discard_sample(connection, details.offset);
+                        continue;
+                    }
+
+                    if self.is_stale(header) {
+                        self.discard_sample(connection, details.offset);
+                        continue;
+                    }
+
+                    return Ok(Some((details, offset)));
                 }
-            },
-            Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
-                fail!(from self, with SubscriberReceiveError::ExceedsMaxBorrowedSamples,
-                    "{} since it would exceed the maximum {} of borrowed samples.",
-                    msg, connection.receiver.max_borrowed_samples());
+                Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                    fail!(from self, with SubscriberReceiveError::ExceedsMaxBorrowedSamples,
+                        "{} since it would exceed the maximum {} of borrowed samples.",
+                        msg, connection.receiver.max_borrowed_samples());
+                }
+            }
+        }
+    }
+
+    /// Returns true when at least one [`Publisher`](crate::port::publisher::Publisher) of the
+    /// [`Service`](crate::service::Service) has announced its readiness with
+    /// [`Publisher::announce_ready()`](crate::port::publisher::Publisher::announce_ready()).
+    /// The [`Publisher`](crate::port::publisher::Publisher) does not have to be connected to
+    /// the [`Subscriber`] yet, the connection is established with the next
+    /// [`Subscriber::receive()`] or [`UpdateConnections::update_connections()`].
+    pub fn has_ready_publisher(&self) -> bool {
+        self.publisher_connections
+            .service_state
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .number_of_ready_publishers()
+            > 0
+    }
+
+    /// Waits until at least one [`Publisher`](crate::port::publisher::Publisher) has announced
+    /// its readiness or the timeout has passed. Returns true when a ready
+    /// [`Publisher`](crate::port::publisher::Publisher) exists, otherwise false. See
+    /// [`Subscriber::has_ready_publisher()`].
+    ///
+    /// On the first call the [`Subscriber`] creates a readiness event that is notified by
+    /// every [`Publisher`](crate::port::publisher::Publisher) that announces its readiness,
+    /// therefore the call blocks without polling.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use core::time::Duration;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// let subscriber = service.subscriber_builder()
+    ///                         .ignore_samples_from_unready_publishers(true)
+    ///                         .create()?;
+    ///
+    /// if subscriber.wait_for_readiness(Duration::from_millis(10))? {
+    ///     while let Some(sample) = subscriber.receive()? {
+    ///         println!("received: {:?}", *sample);
+    ///     }
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wait_for_readiness(
+        &self,
+        timeout: Duration,
+    ) -> Result<bool, SubscriberWaitForReadinessError> {
+        let msg = "Unable to wait for a ready publisher";
+        let start = fail!(from self, when Time::now_with_clock(ClockType::Monotonic),
+                with SubscriberWaitForReadinessError::InternalFailure,
+                "{} since the current time could not be acquired.", msg);
+
+        // the event is created before the readiness is checked, therefore every publisher that
+        // announces its readiness afterwards notifies this subscriber
+        let mut readiness_listener = self.readiness_listener.borrow_mut();
+        let listener = match readiness_listener.take() {
+            Some(listener) => listener,
+            None => {
+                let global_config = self
+                    .publisher_connections
+                    .service_state
+                    .shared_node
+                    .config();
+                fail!(from self, when <Service::Event as Event>::ListenerBuilder::new(&readiness_event_name(&self.id()))
+                        .config(&event_config::<Service>(global_config))
+                        .create(),
+                    with SubscriberWaitForReadinessError::InternalFailure,
+                    "{} since the readiness event could not be created.", msg)
+            }
+        };
+        let listener = readiness_listener.insert(listener);
+
+        loop {
+            if self.has_ready_publisher() {
+                return Ok(true);
+            }
+
+            let elapsed = fail!(from self, when start.elapsed(),
+                    with SubscriberWaitForReadinessError::InternalFailure,
+                    "{} since the elapsed time could not be acquired.", msg);
+            if elapsed >= timeout {
+                return Ok(false);
             }
+
+            fail!(from self, when listener.timed_wait_all(|_| {}, timeout - elapsed),
+                with SubscriberWaitForReadinessError::InternalFailure,
+                "{} since the wait on the readiness event failed.", msg);
         }
     }
 
@@ -341,16 +676,251 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         self.publisher_connections.subscriber_id()
     }
 
+    /// Returns true when the [`Node`](crate::node::Node) that created the [`Subscriber`]
+    /// broadcast its shutdown intent with
+    /// [`Node::broadcast_shutdown_intent()`](crate::node::Node::broadcast_shutdown_intent()).
+    pub fn is_shutdown_requested(&self) -> bool {
+        self.publisher_connections
+            .service_state
+            .shared_node
+            .shutdown_intent()
+            .is_requested()
+    }
+
     /// Returns the internal buffer size of the [`Subscriber`].
     pub fn buffer_size(&self) -> usize {
         self.publisher_connections.buffer_size
     }
 
+    /// Returns the [`DeliveryQos`] hint of the [`Subscriber`].
+    pub fn delivery_qos(&self) -> DeliveryQos {
+        self.delivery_qos
+    }
+
+    /// Returns the [`ShedPolicy`] of the [`Subscriber`].
+    pub fn shed_policy(&self) -> ShedPolicy {
+        self.shed_policy
+    }
+
+    /// Returns the number of samples the [`Subscriber`] has discarded while the
+    /// [`Node`](crate::node::Node) was shedding load, see [`ShedPolicy`].
+    pub fn number_of_shed_samples(&self) -> u64 {
+        self.number_of_shed_samples.get()
+    }
+
+    /// Returns the [`ZeroCopyConnectionCounters`] of every established connection to a
+    /// [`Publisher`](crate::port::publisher::Publisher).
+    pub fn connection_counters(&self) -> Vec<(UniquePublisherId, ZeroCopyConnectionCounters)> {
+        let connections = &self.publisher_connections;
+        (0..connections.len())
+            .filter_map(|i| {
+                connections
+                    .get(i)
+                    .as_ref()
+                    .map(|c| (c.publisher_id, c.receiver.counters()))
+            })
+            .collect()
+    }
+
+    /// Returns true when the [`Subscriber`] has expired, see
+    /// [`PortFactorySubscriber::auto_expire()`](crate::service::port_factory::subscriber::PortFactorySubscriber::auto_expire()).
+    /// An expired [`Subscriber`] is disconnected from all
+    /// [`Publisher`](crate::port::publisher::Publisher)s and cannot receive samples anymore.
+    pub fn is_expired(&self) -> bool {
+        self.has_expired()
+    }
+
+    fn has_expired(&self) -> bool {
+        if self.is_expired.get() {
+            return true;
+        }
+
+        let has_expired = match self.auto_expire {
+            None => false,
+            Some(AutoExpire::AfterSamples(n)) => n <= self.number_of_received_samples.get(),
+            Some(AutoExpire::AfterDuration(duration)) => match &self.creation_time {
+                Some(creation_time) => match creation_time.elapsed() {
+                    Ok(elapsed) => duration <= elapsed,
+                    Err(e) => {
+                        warn!(from self, "Unable to acquire the elapsed time since the creation of the subscriber to detect its expiration ({:?}).", e);
+                        false
+                    }
+                },
+                None => false,
+            },
+        };
+
+        if has_expired {
+            self.expire();
+        }
+
+        has_expired
+    }
+
+    fn release_dynamic_subscriber_handle(&self, handle: ContainerHandle, record_evidence: bool) {
+        let service_state = &self.publisher_connections.service_state;
+        service_state
+            .shared_node
+            .closed_ports()
+            .release_unless_closed(UniquePortId::Subscriber(self.id()), || {
+                if record_evidence {
+                    service_state.shared_node.record_evidence(
+                        EvidenceEntity::Port,
+                        StateTransition::Destroyed,
+                        format_args!(
+                            "{:?} of service {}",
+                            self.id(),
+                            service_state.static_config.name()
+                        ),
+                    );
+                }
+                service_state
+                    .dynamic_storage
+                    .get()
+                    .publish_subscribe()
+                    .release_subscriber_handle(handle)
+            });
+    }
+
+    fn expire(&self) {
+        self.is_expired.set(true);
+
+        if let Some(handle) = self.dynamic_subscriber_handle.take() {
+            self.release_dynamic_subscriber_handle(handle, false);
+        }
+
+        let staged_samples = unsafe { &mut *self.staged_samples.get() };
+        for staged_sample in staged_samples.iter_mut() {
+            if let Some((details, _)) = staged_sample.take() {
+                self.discard_sample(&details.publisher_connection, details.offset);
+            }
+        }
+
+        unsafe { &mut *self.expired_connection_backlog.get() }.clear();
+        self.expired_connection_backlog_size.set(0);
+
+        self.publisher_connections.disconnect_all();
+    }
+
+    /// Pauses the [`Subscriber`] without disconnecting it from its
+    /// [`Publisher`](crate::port::publisher::Publisher)s. While paused, the [`Subscriber`] does
+    /// not receive any samples, the
+    /// [`Publisher`](crate::port::publisher::Publisher)s keep on delivering into its buffer and
+    /// when the buffer is full the overflow settings of the
+    /// [`Service`](crate::service::Service) are applied.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// let subscriber = service.subscriber_builder().create()?;
+    ///
+    /// subscriber.pause();
+    /// // returns always None while the subscriber is paused
+    /// assert!(subscriber.receive()?.is_none());
+    ///
+    /// subscriber.resume();
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn pause(&self) {
+        self.is_paused.set(true);
+    }
+
+    /// Resumes a paused [`Subscriber`]. All samples that are in the buffer can be received
+    /// again. Calling it on a [`Subscriber`] that is not paused has no effect.
+    pub fn resume(&self) {
+        self.is_paused.set(false);
+    }
+
+    /// Returns true when the [`Subscriber`] is paused, see [`Subscriber::pause()`].
+    pub fn is_paused(&self) -> bool {
+        self.is_paused.get()
+    }
+
+    /// Requests every connected [`Publisher`](crate::port::publisher::Publisher) to deliver
+    /// its retained history again, like it does when the connection is established. The
+    /// [`Publisher`](crate::port::publisher::Publisher) delivers the history with its next
+    /// send or
+    /// [`UpdateConnections::update_connections()`](crate::port::update_connections::UpdateConnections::update_connections())
+    /// call. Samples that are still in the buffer of the [`Subscriber`] are not removed,
+    /// therefore they may be received twice.
+    ///
+    /// Useful when the consumer lost its state and shall be restored without recreating the
+    /// [`Subscriber`]. When the [`Service`](crate::service::Service) has broadcasting enabled,
+    /// the request has no effect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .history_size(2)
+    /// #     .open_or_create()?;
+    /// #
+    /// let subscriber = service.subscriber_builder().create()?;
+    ///
+    /// // the consumer was reset and needs the latest state again
+    /// subscriber.request_history()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn request_history(&self) -> Result<(), ConnectionFailure> {
+        if self.has_expired() {
+            return Ok(());
+        }
+
+        fail!(from self, when self.update_connections(),
+                "Unable to request the history since not all connections to publishers could be established.");
+
+        for id in 0..self.publisher_connections.len() {
+            if let Some(ref connection) = &self.publisher_connections.get(id) {
+                if !connection.receiver.request_redelivery() {
+                    warn!(from self,
+                        "Unable to request the history from publisher {:?} since the service delivers it via broadcast.",
+                        connection.publisher_id);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Returns true if the [`Subscriber`] has samples in the buffer that can be received with [`Subscriber::receive`].
+    /// A paused [`Subscriber`] never has samples.
     pub fn has_samples(&self) -> Result<bool, ConnectionFailure> {
+        if self.has_expired() || self.is_paused.get() {
+            return Ok(false);
+        }
+
         fail!(from self, when self.update_connections(),
                 "Some samples are not being received since not all connections to publishers could be established.");
 
+        self.move_overflowed_connections_into_backlog();
+        if !unsafe { &*self.expired_connection_backlog.get() }.is_empty() {
+            return Ok(true);
+        }
+
+        if unsafe { &*self.staged_samples.get() }
+            .iter()
+            .any(|s| s.is_some())
+        {
+            return Ok(true);
+        }
+
         for id in 0..self.publisher_connections.len() {
             if let Some(ref connection) = &self.publisher_connections.get(id) {
                 if connection.receiver.has_data() {
@@ -364,6 +934,157 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
 
     fn receive_impl(
         &self,
+    ) -> Result<Option<(SampleDetails<Service>, usize)>, SubscriberReceiveError> {
+        if self.has_expired() {
+            fail!(from self, with SubscriberReceiveError::Expired,
+                "Unable to receive another sample since the subscriber has expired.");
+        }
+
+        if self.is_paused.get() {
+            return Ok(None);
+        }
+
+        self.receive_from_publishers()
+    }
+
+    fn shall_shed_sample(&self) -> bool {
+        if self.shed_policy == ShedPolicy::Disabled {
+            return false;
+        }
+
+        let mut counter = self.samples_since_last_kept.get();
+        let shall_shed = if self.load_monitor.is_shedding() {
+            load_shedding::shall_discard(self.shed_policy, &mut counter)
+        } else {
+            counter = 0;
+            false
+        };
+        self.samples_since_last_kept.set(counter);
+
+        if shall_shed {
+            self.number_of_shed_samples
+                .set(self.number_of_shed_samples.get() + 1);
+        }
+
+        shall_shed
+    }
+
+    fn receive_filtered<F: Fn(usize) -> RawSample<Header, UserHeader, Payload>>(
+        &self,
+        create_raw_sample: F,
+    ) -> Result<Option<Sample<Service, Payload, UserHeader>>, SubscriberReceiveError> {
+        let from_backlog = |(copy, origin): (HeapCopy, UniquePublisherId)| Sample {
+            ptr: create_raw_sample(copy.address()),
+            storage: SampleStorage::Heap { copy, origin },
+        };
+
+        loop {
+            let sample = match self.receive_from_backlog() {
+                Some(entry) => from_backlog(entry),
+                None => match self.receive_impl()? {
+                    Some((details, absolute_address)) => Sample {
+                        ptr: create_raw_sample(absolute_address),
+                        storage: SampleStorage::SharedMemory(details),
+                    },
+                    // receiving may have moved samples of expired publishers into the backlog
+                    None => match self.receive_from_backlog() {
+                        Some(entry) => from_backlog(entry),
+                        None => return Ok(None),
+                    },
+                },
+            };
+
+            // samples that do not match the filter are released when they go out of scope
+            if let Some(filter) = &self.filter {
+                if !filter.call(&sample) {
+                    continue;
+                }
+            }
+
+            if self.shall_shed_sample() {
+                continue;
+            }
+
+            let sample = if self.receive_heap_copies && !sample.is_heap_copy() {
+                let origin = sample.origin();
+                let copy = self.create_heap_copy(sample.header());
+                let heap_address = copy.address();
+                // releases the shared memory sample back to the publisher
+                drop(sample);
+
+                Sample {
+                    ptr: create_raw_sample(heap_address),
+                    storage: SampleStorage::Heap { copy, origin },
+                }
+            } else {
+                sample
+            };
+
+            if self.auto_expire.is_some() {
+                self.number_of_received_samples
+                    .set(self.number_of_received_samples.get() + 1);
+                self.has_expired();
+            }
+
+            return Ok(Some(sample));
+        }
+    }
+
+    fn receive_latest_filtered<F: Fn(usize) -> RawSample<Header, UserHeader, Payload>>(
+        &self,
+        create_raw_sample: F,
+    ) -> Result<Option<Sample<Service, Payload, UserHeader>>, SubscriberReceiveError> {
+        if !self.has_expired() && !self.is_paused.get() {
+            if let Err(e) = self.update_connections() {
+                fail!(from self,
+                    with SubscriberReceiveError::ConnectionFailure(e),
+                    "Unable to receive the latest sample since not all connections to publishers could be established.");
+            }
+
+            if let Some(connection) = self
+                .publisher_connections
+                .to_be_removed_connections()
+                .peek()
+            {
+                self.discard_stale_samples(connection);
+            }
+
+            for id in 0..self.publisher_connections.len() {
+                if let Some(ref connection) = self.publisher_connections.get(id) {
+                    self.discard_stale_samples(connection);
+                }
+            }
+        }
+
+        // at most one sample per publisher is left, the older ones are released when they are
+        // replaced
+        let mut latest: Option<Sample<Service, Payload, UserHeader>> = None;
+        loop {
+            let sample = match self.receive_filtered(&create_raw_sample) {
+                Ok(Some(sample)) => sample,
+                Ok(None) => break,
+                Err(
+                    SubscriberReceiveError::ExceedsMaxBorrowedSamples
+                    | SubscriberReceiveError::ExceedsMaxTotalBorrowedSamples,
+                ) if latest.is_some() => break,
+                Err(e) => return Err(e),
+            };
+
+            let is_newer = match &latest {
+                Some(l) => l.header().send_timestamp() <= sample.header().send_timestamp(),
+                None => true,
+            };
+
+            if is_newer {
+                latest = Some(sample);
+            }
+        }
+
+        Ok(latest)
+    }
+
+    fn receive_from_publishers(
+        &self,
     ) -> Result<Option<(SampleDetails<Service>, usize)>, SubscriberReceiveError> {
         if let Err(e) = self.update_connections() {
             fail!(from self,
@@ -371,7 +1092,9 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                 "Some samples are not being received since not all connections to publishers could be established.");
         }
 
-        let to_be_removed_connections = unsafe { &mut *self.to_be_removed_connections.get() };
+        self.move_overflowed_connections_into_backlog();
+
+        let to_be_removed_connections = self.publisher_connections.to_be_removed_connections();
 
         if let Some(connection) = to_be_removed_connections.peek() {
             if let Some((details, absolute_address)) = self.receive_from_connection(connection)? {
@@ -381,11 +1104,91 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             }
         }
 
-        for id in 0..self.publisher_connections.len() {
+        match self.fairness_policy {
+            FairnessPolicy::FixedOrder => self.receive_in_order(0),
+            FairnessPolicy::RoundRobin => self.receive_round_robin(),
+            FairnessPolicy::Weighted => self.receive_weighted(),
+            FairnessPolicy::OldestTimestampFirst => self.receive_oldest_first(),
+        }
+    }
+
+    fn receive_from_backlog(&self) -> Option<(HeapCopy, UniquePublisherId)> {
+        if self.is_paused.get() || self.has_expired() {
+            return None;
+        }
+
+        let entry = unsafe { &mut *self.expired_connection_backlog.get() }.pop_front()?;
+        self.expired_connection_backlog_size
+            .set(self.expired_connection_backlog_size.get() - entry.0.size());
+        Some(entry)
+    }
+
+    // Copies the undelivered samples of expired connections that exceeded the expired
+    // connection buffer into the owned backlog. Afterwards, the connections are dropped and the
+    // data segments of the dead publishers can be released.
+    fn move_overflowed_connections_into_backlog(&self) {
+        let backlog = unsafe { &mut *self.expired_connection_backlog.get() };
+        for connection in self.publisher_connections.take_overflowed_connections() {
+            loop {
+                let offset = match connection.receiver.receive() {
+                    Ok(Some(offset)) => offset,
+                    Ok(None) => break,
+                    Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                        warn!(from self,
+                            "Unable to move all samples of the expired publisher {:?} into the backlog since it would exceed the maximum {} of borrowed samples. The remaining samples are discarded.",
+                            connection.publisher_id, connection.receiver.max_borrowed_samples());
+                        break;
+                    }
+                };
+
+                let header = match connection.register_and_translate_offset(offset) {
+                    Ok(absolute_address) => absolute_address as *const Header,
+                    Err(e) => {
+                        warn!(from self,
+                            "Unable to move a sample of the expired publisher {:?} into the backlog since the offset {:?} could not be registered and translated ({:?}). The sample is discarded.",
+                            connection.publisher_id, offset, e);
+                        self.release_offset(&connection, offset);
+                        continue;
+                    }
+                };
+
+                let is_deliverable = self.has_valid_payload_checksum(header)
+                    && !(self.ignore_unready_publishers
+                        && !unsafe { (*header).is_publisher_ready() })
+                    && !self.is_stale(header);
+
+                if is_deliverable {
+                    let size = self.heap_copy_layout(header).size();
+                    let backlog_size = self.expired_connection_backlog_size.get();
+                    if backlog_size + size <= self.max_expired_connection_backlog_size {
+                        backlog.push_back((self.create_heap_copy(header), connection.publisher_id));
+                        self.expired_connection_backlog_size
+                            .set(backlog_size + size);
+                    } else {
+                        warn!(from self,
+                            "Unable to move a sample of the expired publisher {:?} into the backlog since it would exceed the maximum backlog size of {} bytes. The sample is discarded.",
+                            connection.publisher_id, self.max_expired_connection_backlog_size);
+                    }
+                }
+
+                self.discard_sample(&connection, offset);
+            }
+        }
+    }
+
+    fn receive_in_order(
+        &self,
+        start: usize,
+    ) -> Result<Option<(SampleDetails<Service>, usize)>, SubscriberReceiveError> {
+        let number_of_connections = self.publisher_connections.len();
+        for n in 0..number_of_connections {
+            let id = (start + n) % number_of_connections;
             if let Some(ref mut connection) = &mut self.publisher_connections.get_mut(id) {
                 if let Some((details, absolute_address)) =
                     self.receive_from_connection(connection)?
                 {
+                    self.round_robin_cursor
+                        .set((id + 1) % number_of_connections);
                     return Ok(Some((details, absolute_address)));
                 }
             }
@@ -394,6 +1197,104 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         Ok(None)
     }
 
+    fn receive_round_robin(
+        &self,
+    ) -> Result<Option<(SampleDetails<Service>, usize)>, SubscriberReceiveError> {
+        self.receive_in_order(self.round_robin_cursor.get())
+    }
+
+    fn receive_weighted(
+        &self,
+    ) -> Result<Option<(SampleDetails<Service>, usize)>, SubscriberReceiveError> {
+        let mut largest_backlog = None;
+        let mut largest_number_of_pending_samples = 0;
+        for id in 0..self.publisher_connections.len() {
+            if let Some(ref connection) = &self.publisher_connections.get(id) {
+                let number_of_pending_samples = connection.receiver.number_of_pending_samples();
+                if number_of_pending_samples > largest_number_of_pending_samples {
+                    largest_number_of_pending_samples = number_of_pending_samples;
+                    largest_backlog = Some(id);
+                }
+            }
+        }
+
+        // the connection could have delivered only samples that are discarded, then the
+        // remaining connections are processed in order
+        match largest_backlog {
+            Some(id) => self.receive_in_order(id),
+            None => Ok(None),
+        }
+    }
+
+    fn receive_oldest_first(
+        &self,
+    ) -> Result<Option<(SampleDetails<Service>, usize)>, SubscriberReceiveError> {
+        let staged_samples = unsafe { &mut *self.staged_samples.get() };
+
+        for id in 0..self.publisher_connections.len() {
+            if staged_samples[id].is_some() {
+                continue;
+            }
+
+            if let Some(ref connection) = &self.publisher_connections.get(id) {
+                match self.receive_from_connection(connection) {
+                    Ok(sample) => staged_samples[id] = sample,
+                    Err(
+                        SubscriberReceiveError::ExceedsMaxBorrowedSamples
+                        | SubscriberReceiveError::ExceedsMaxTotalBorrowedSamples,
+                    ) if staged_samples.iter().any(|s| s.is_some()) => break,
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+
+        let oldest = staged_samples
+            .iter()
+            .enumerate()
+            .filter_map(|(id, s)| {
+                s.as_ref().map(|(_, absolute_address)| {
+                    (id, unsafe {
+                        (*(*absolute_address as *const Header)).send_timestamp()
+                    })
+                })
+            })
+            .min_by_key(|(_, send_timestamp)| *send_timestamp);
+
+        Ok(oldest.and_then(|(id, _)| staged_samples[id].take()))
+    }
+
+    fn is_stale(&self, header: *const Header) -> bool {
+        let lifetime = match self.static_config.publish_subscribe().sample_lifetime {
+            Some(lifetime) => lifetime,
+            None => return false,
+        };
+
+        match Time::now_with_clock(ClockType::Monotonic) {
+            Ok(now) => unsafe { (*header).is_stale(now.as_duration().as_nanos() as u64, lifetime) },
+            Err(_) => false,
+        }
+    }
+
+    fn has_valid_payload_checksum(&self, header: *const Header) -> bool {
+        let static_config = &self.publisher_connections.static_config;
+        if !static_config.enable_payload_checksum {
+            return true;
+        }
+
+        let header_ref = unsafe { &*header };
+        match header_ref.payload_checksum() {
+            None => true,
+            Some(checksum) => {
+                let number_of_bytes = header_ref.number_of_elements() as usize
+                    * static_config.message_type_details.payload.size;
+                let payload = unsafe {
+                    core::slice::from_raw_parts(self.payload_ptr(header), number_of_bytes)
+                };
+                crc32c(payload) == checksum
+            }
+        }
+    }
+
     fn payload_ptr(&self, header: *const Header) -> *const u8 {
         self.publisher_connections
             .static_config
@@ -409,26 +1310,65 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             .user_header_ptr_from_header(header.cast())
             .cast()
     }
+
+    fn heap_copy_layout(&self, header: *const Header) -> Layout {
+        let details = &self
+            .publisher_connections
+            .static_config
+            .message_type_details;
+        let number_of_elements = unsafe { (*header).number_of_elements() } as usize;
+        let sample_layout = details.sample_layout(number_of_elements);
+        let alignment = sample_layout
+            .align()
+            .max(details.user_header.alignment)
+            .max(details.payload.alignment);
+
+        unsafe { Layout::from_size_align_unchecked(sample_layout.size(), alignment) }
+    }
+
+    // The offsets of user header and payload depend on the alignment of the header address,
+    // therefore every part is copied on its own into the heap memory.
+    fn create_heap_copy(&self, header: *const Header) -> HeapCopy {
+        let details = &self
+            .publisher_connections
+            .static_config
+            .message_type_details;
+        let number_of_elements = unsafe { (*header).number_of_elements() } as usize;
+
+        unsafe {
+            let copy = HeapCopy::new(self.heap_copy_layout(header));
+            let target = copy.address() as *const Header;
+
+            core::ptr::copy_nonoverlapping(
+                header.cast::<u8>(),
+                target as *mut u8,
+                details.header.size,
+            );
+            core::ptr::copy_nonoverlapping(
+                self.user_header_ptr(header),
+                self.user_header_ptr(target) as *mut u8,
+                details.user_header.size,
+            );
+            core::ptr::copy_nonoverlapping(
+                self.payload_ptr(header),
+                self.payload_ptr(target) as *mut u8,
+                details.payload.size * number_of_elements,
+            );
+
+            copy
+        }
+    }
 }
 
 impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> UpdateConnections
     for Subscriber<Service, Payload, UserHeader>
 {
     fn update_connections(&self) -> Result<(), ConnectionFailure> {
-        if unsafe {
-            self.publisher_connections
-                .service_state
-                .dynamic_storage
-                .get()
-                .publish_subscribe()
-                .publishers
-                .update_state(&mut *self.publisher_list_state.get())
-        } {
-            fail!(from self, when self.populate_publisher_channels(),
-                "Connections were updated only partially since at least one connection to a publisher failed.");
+        if self.has_expired() {
+            return Ok(());
         }
 
-        Ok(())
+        self.publisher_connections.update_connections()
     }
 }
 
@@ -440,15 +1380,44 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
     pub fn receive(
         &self,
     ) -> Result<Option<Sample<Service, Payload, UserHeader>>, SubscriberReceiveError> {
-        Ok(self.receive_impl()?.map(|(details, absolute_address)| {
+        self.receive_filtered(|absolute_address| {
             let header_ptr = absolute_address as *const Header;
             let user_header_ptr = self.user_header_ptr(header_ptr).cast();
             let payload_ptr = self.payload_ptr(header_ptr).cast();
-            Sample {
-                details,
-                ptr: unsafe { RawSample::new_unchecked(header_ptr, user_header_ptr, payload_ptr) },
-            }
-        }))
+            unsafe { RawSample::new_unchecked(header_ptr, user_header_ptr, payload_ptr) }
+        })
+    }
+
+    /// Drains the buffer and returns only the newest [`crate::sample::Sample`] of all connected
+    /// [`crate::port::publisher::Publisher`]s. All older samples are released inside the port
+    /// without being handed to the user. If no sample could be received [`None`] is returned.
+    /// When a [`Subscriber`] filter is set, it is applied to the newest sample of every
+    /// publisher only. The samples of different publishers are compared by their send
+    /// timestamp, see
+    /// [`Builder::enable_send_timestamp()`](crate::service::builder::publish_subscribe::Builder::enable_send_timestamp()).
+    pub fn receive_latest(
+        &self,
+    ) -> Result<Option<Sample<Service, Payload, UserHeader>>, SubscriberReceiveError> {
+        self.receive_latest_filtered(|absolute_address| {
+            let header_ptr = absolute_address as *const Header;
+            let user_header_ptr = self.user_header_ptr(header_ptr).cast();
+            let payload_ptr = self.payload_ptr(header_ptr).cast();
+            unsafe { RawSample::new_unchecked(header_ptr, user_header_ptr, payload_ptr) }
+        })
+    }
+
+    /// Receives the next [`crate::sample::Sample`] asynchronously. When no sample is available
+    /// the task is suspended until the provided [`AsyncListener`] is notified, e.g. by a
+    /// [`Notifier`](crate::port::notifier::Notifier) that the sending side triggers after every
+    /// sent sample. The runtime thread is not blocked while waiting.
+    ///
+    /// Only available with the `tokio` feature.
+    #[cfg(feature = "tokio")]
+    pub async fn receive_async(
+        &self,
+        listener: &AsyncListener<Service>,
+    ) -> Result<Sample<Service, Payload, UserHeader>, SubscriberReceiveAsyncError> {
+        self.receive_async_impl(listener, Self::receive).await
     }
 }
 
@@ -462,23 +1431,62 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
     ) -> Result<Option<Sample<Service, [Payload], UserHeader>>, SubscriberReceiveError> {
         debug_assert!(TypeId::of::<Payload>() != TypeId::of::<CustomPayloadMarker>());
 
-        Ok(self.receive_impl()?.map(|(details, absolute_address)| {
+        self.receive_filtered(|absolute_address| {
             let header_ptr = absolute_address as *const Header;
             let user_header_ptr = self.user_header_ptr(header_ptr).cast();
             let payload_ptr = self.payload_ptr(header_ptr).cast();
             let number_of_elements = unsafe { (*header_ptr).number_of_elements() };
 
-            Sample {
-                details,
-                ptr: unsafe {
-                    RawSample::<Header, UserHeader, [Payload]>::new_slice_unchecked(
-                        header_ptr,
-                        user_header_ptr,
-                        core::slice::from_raw_parts(payload_ptr, number_of_elements as _),
-                    )
-                },
+            unsafe {
+                RawSample::<Header, UserHeader, [Payload]>::new_slice_unchecked(
+                    header_ptr,
+                    user_header_ptr,
+                    core::slice::from_raw_parts(payload_ptr, number_of_elements as _),
+                )
+            }
+        })
+    }
+
+    /// Drains the buffer and returns only the newest [`crate::sample::Sample`] of all connected
+    /// [`crate::port::publisher::Publisher`]s. All older samples are released inside the port
+    /// without being handed to the user. If no sample could be received [`None`] is returned.
+    /// When a [`Subscriber`] filter is set, it is applied to the newest sample of every
+    /// publisher only. The samples of different publishers are compared by their send
+    /// timestamp, see
+    /// [`Builder::enable_send_timestamp()`](crate::service::builder::publish_subscribe::Builder::enable_send_timestamp()).
+    pub fn receive_latest(
+        &self,
+    ) -> Result<Option<Sample<Service, [Payload], UserHeader>>, SubscriberReceiveError> {
+        debug_assert!(TypeId::of::<Payload>() != TypeId::of::<CustomPayloadMarker>());
+
+        self.receive_latest_filtered(|absolute_address| {
+            let header_ptr = absolute_address as *const Header;
+            let user_header_ptr = self.user_header_ptr(header_ptr).cast();
+            let payload_ptr = self.payload_ptr(header_ptr).cast();
+            let number_of_elements = unsafe { (*header_ptr).number_of_elements() };
+
+            unsafe {
+                RawSample::<Header, UserHeader, [Payload]>::new_slice_unchecked(
+                    header_ptr,
+                    user_header_ptr,
+                    core::slice::from_raw_parts(payload_ptr, number_of_elements as _),
+                )
             }
-        }))
+        })
+    }
+
+    /// Receives the next [`crate::sample::Sample`] asynchronously. When no sample is available
+    /// the task is suspended until the provided [`AsyncListener`] is notified, e.g. by a
+    /// [`Notifier`](crate::port::notifier::Notifier) that the sending side triggers after every
+    /// sent sample. The runtime thread is not blocked while waiting.
+    ///
+    /// Only available with the `tokio` feature.
+    #[cfg(feature = "tokio")]
+    pub async fn receive_async(
+        &self,
+        listener: &AsyncListener<Service>,
+    ) -> Result<Sample<Service, [Payload], UserHeader>, SubscriberReceiveAsyncError> {
+        self.receive_async_impl(listener, Self::receive).await
     }
 }
 
@@ -499,7 +1507,7 @@ impl<Service: service::Service, UserHeader: Debug>
         &self,
     ) -> Result<Option<Sample<Service, [CustomPayloadMarker], UserHeader>>, SubscriberReceiveError>
     {
-        Ok(self.receive_impl()?.map(|(details, absolute_address)| {
+        self.receive_filtered(|absolute_address| {
             let header_ptr = absolute_address as *const Header;
             let user_header_ptr = self.user_header_ptr(header_ptr).cast();
             let payload_ptr = self.payload_ptr(header_ptr).cast();
@@ -512,16 +1520,32 @@ impl<Service: service::Service, UserHeader: Debug>
                     .payload
                     .size;
 
-            Sample {
-                details,
-                ptr: unsafe {
-                    RawSample::<Header, UserHeader, [CustomPayloadMarker]>::new_slice_unchecked(
-                        header_ptr,
-                        user_header_ptr,
-                        core::slice::from_raw_parts(payload_ptr, number_of_bytes),
-                    )
-                },
+            unsafe {
+                RawSample::<Header, UserHeader, [CustomPayloadMarker]>::new_slice_unchecked(
+                    header_ptr,
+                    user_header_ptr,
+                    core::slice::from_raw_parts(payload_ptr, number_of_bytes),
+                )
             }
-        }))
+        })
     }
 }
+
+pub(crate) unsafe fn remove_readiness_event_of_subscriber<Service: service::Service>(
+    port_id: &UniqueSubscriberId,
+    config: &config::Config,
+) -> Result<(), NamedConceptRemoveError> {
+    let origin = format!(
+        "remove_readiness_event_of_subscriber::<{}>::({:?})",
+        core::any::type_name::<Service>(),
+        port_id
+    );
+
+    fail!(from origin, when <Service::Event as NamedConceptMgmt>::remove_cfg(
+            &readiness_event_name(port_id),
+            &event_config::<Service>(config),
+        ), "Unable to remove the subscribers readiness event."
+    );
+
+    Ok(())
+}
@@ -13,17 +13,23 @@
 pub use crate::config::Config;
 pub use crate::node::{node_name::NodeName, Node, NodeBuilder, NodeState};
 pub use crate::port::event_id::EventId;
+pub use crate::port::event_payload::EventPayload;
 pub use crate::service::messaging_pattern::MessagingPattern;
 pub use crate::service::{
-    attribute::AttributeSet, attribute::AttributeSpecifier, attribute::AttributeVerifier, ipc,
-    local, port_factory::publisher::UnableToDeliverStrategy, port_factory::PortFactory,
+    attribute::AttributeQuery, attribute::AttributeSet, attribute::AttributeSpecifier,
+    attribute::AttributeVerifier, ipc, local, partition::Partition,
+    port_factory::publisher::UnableToDeliverStrategy, port_factory::PortFactory,
     service_name::ServiceName, Service, ServiceDetails,
 };
+pub use crate::service_name;
 pub use crate::signal_handling_mode::SignalHandlingMode;
-pub use crate::waitset::{WaitSet, WaitSetAttachmentId, WaitSetBuilder, WaitSetGuard};
-pub use iceoryx2_bb_derive_macros::PlacementDefault;
+pub use crate::waitset::{
+    WaitSet, WaitSetAttachmentId, WaitSetBuilder, WaitSetGroup, WaitSetGuard,
+};
+pub use iceoryx2_bb_derive_macros::{PlacementDefault, UserHeader};
 pub use iceoryx2_bb_elementary::alignment::Alignment;
 pub use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+pub use iceoryx2_bb_elementary::versioned_user_header::VersionedUserHeader;
 pub use iceoryx2_bb_elementary::CallbackProgression;
 pub use iceoryx2_bb_log::set_log_level;
 pub use iceoryx2_bb_log::LogLevel;
@@ -69,6 +69,28 @@ impl<Header, UserHeader, Payload: ?Sized> RawSample<Header, UserHeader, Payload>
     pub(crate) fn as_payload_ref(&self) -> &Payload {
         unsafe { &*self.payload }
     }
+
+    /// Acquires the underlying header as mutable reference.
+    ///
+    /// # Safety
+    ///
+    /// * the underlying memory must be writable and not be accessed by anyone else
+    #[must_use]
+    #[inline(always)]
+    pub(crate) unsafe fn as_header_mut_unchecked(&mut self) -> &mut Header {
+        &mut *(self.header as *mut Header)
+    }
+
+    /// Acquires the underlying data as mutable reference.
+    ///
+    /// # Safety
+    ///
+    /// * the underlying memory must be writable and not be accessed by anyone else
+    #[must_use]
+    #[inline(always)]
+    pub(crate) unsafe fn as_payload_mut_unchecked(&mut self) -> &mut Payload {
+        &mut *(self.payload as *mut Payload)
+    }
 }
 
 impl<Header, UserHeader, Payload> RawSample<Header, UserHeader, Payload> {
@@ -108,7 +130,7 @@ impl<Header, UserHeader, Payload> Clone for RawSample<Header, UserHeader, Payloa
 
 impl<Header, UserHeader, Payload> Copy for RawSample<Header, UserHeader, Payload> {}
 
-impl<Header, UserHeader, Payload> fmt::Debug for RawSample<Header, UserHeader, Payload> {
+impl<Header, UserHeader, Payload: ?Sized> fmt::Debug for RawSample<Header, UserHeader, Payload> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
@@ -164,6 +186,13 @@ impl<Header, UserHeader, Payload: ?Sized> RawSampleMut<Header, UserHeader, Paylo
         unsafe { &*self.header }
     }
 
+    /// Acquires the underlying header as mutable reference.
+    #[must_use]
+    #[inline(always)]
+    pub(crate) fn as_header_mut(&mut self) -> &mut Header {
+        unsafe { &mut *self.header }
+    }
+
     /// Acquires the underlying payload as reference.
     #[must_use]
     #[inline(always)]
@@ -202,7 +231,7 @@ impl<Header, UserHeader, Payload> Clone for RawSampleMut<Header, UserHeader, Pay
 
 impl<Header, UserHeader, Payload> Copy for RawSampleMut<Header, UserHeader, Payload> {}
 
-impl<Header, UserHeader, Payload> fmt::Debug for RawSampleMut<Header, UserHeader, Payload> {
+impl<Header, UserHeader, Payload: ?Sized> fmt::Debug for RawSampleMut<Header, UserHeader, Payload> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
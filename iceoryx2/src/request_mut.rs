@@ -0,0 +1,207 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let client = service.client_builder().create()?;
+//!
+//! let mut request = client.loan()?;
+//! *request.payload_mut() = 1234;
+//!
+//! println!("client port id: {:?}", request.header().client_port_id());
+//! let pending_response = request.send()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::time::Duration;
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+use iceoryx2_cal::shm_allocator::PointerOffset;
+
+use crate::pending_response::PendingResponse;
+use crate::port::client::{ClientBackend, RequestSendError};
+use crate::raw_sample::RawSampleMut;
+use crate::service::header::request_response::RequestHeader as Header;
+
+/// The request of a [`Client`](crate::port::client::Client) that was loaned with
+/// [`Client::loan()`](crate::port::client::Client::loan()). It can be sent to all connected
+/// [`Server`](crate::port::server::Server)s with [`RequestMut::send()`]. When it goes out of
+/// scope without being sent, the memory is returned to the
+/// [`Client`](crate::port::client::Client).
+pub struct RequestMut<
+    Service: crate::service::Service,
+    RequestPayload: Debug + ?Sized,
+    RequestHeader: Debug,
+    ResponsePayload: Debug + ?Sized,
+    ResponseHeader: Debug,
+> {
+    pub(crate) client_backend: Arc<ClientBackend<Service>>,
+    pub(crate) ptr: RawSampleMut<Header, RequestHeader, RequestPayload>,
+    pub(crate) offset_to_chunk: PointerOffset,
+    pub(crate) _response_payload: PhantomData<ResponsePayload>,
+    pub(crate) _response_header: PhantomData<ResponseHeader>,
+}
+
+impl<
+        Service: crate::service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > Debug
+    for RequestMut<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "RequestMut<{}, {}, {}, {}, {}> {{ client_backend: {:?}, offset_to_chunk: {:?} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<RequestPayload>(),
+            core::any::type_name::<RequestHeader>(),
+            core::any::type_name::<ResponsePayload>(),
+            core::any::type_name::<ResponseHeader>(),
+            self.client_backend,
+            self.offset_to_chunk
+        )
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > Drop for RequestMut<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    fn drop(&mut self) {
+        self.client_backend
+            .return_loaned_request(self.offset_to_chunk);
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > RequestMut<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    /// Returns a reference to the iceoryx2 internal [`Header`] of the request.
+    pub fn header(&self) -> &Header {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns a reference to the user defined request header.
+    pub fn user_header(&self) -> &RequestHeader {
+        self.ptr.as_user_header_ref()
+    }
+
+    /// Returns a mutable reference to the user defined request header.
+    pub fn user_header_mut(&mut self) -> &mut RequestHeader {
+        self.ptr.as_user_header_mut()
+    }
+
+    /// Returns a reference to the payload of the request.
+    pub fn payload(&self) -> &RequestPayload {
+        self.ptr.as_payload_ref()
+    }
+
+    /// Returns a mutable reference to the payload of the request.
+    pub fn payload_mut(&mut self) -> &mut RequestPayload {
+        self.ptr.as_payload_mut()
+    }
+
+    /// Sends the request to all connected [`Server`](crate::port::server::Server)s. The
+    /// returned [`PendingResponse`] receives the responses. The request stays active until
+    /// the [`PendingResponse`] goes out of scope.
+    pub fn send(
+        mut self,
+    ) -> Result<
+        PendingResponse<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>,
+        RequestSendError,
+    > {
+        let (request_id, number_of_server_connections) = self.client_backend.send_request(
+            self.ptr.as_header_mut(),
+            self.offset_to_chunk,
+            false,
+            None,
+        )?;
+
+        Ok(PendingResponse::new(
+            &self.client_backend,
+            request_id,
+            number_of_server_connections,
+        ))
+    }
+
+    /// Sends the request to all connected [`Server`](crate::port::server::Server)s like
+    /// [`RequestMut::send()`] but closes the request automatically when no response was
+    /// received within `timeout`. Afterwards, the active request slot is reclaimed, all
+    /// further responses are discarded and [`PendingResponse::receive()`] returns
+    /// [`ResponseReceiveError::Timeout`](crate::port::client::ResponseReceiveError::Timeout).
+    pub fn send_with_timeout(
+        mut self,
+        timeout: Duration,
+    ) -> Result<
+        PendingResponse<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>,
+        RequestSendError,
+    > {
+        let (request_id, number_of_server_connections) = self.client_backend.send_request(
+            self.ptr.as_header_mut(),
+            self.offset_to_chunk,
+            false,
+            Some(timeout),
+        )?;
+
+        Ok(PendingResponse::new(
+            &self.client_backend,
+            request_id,
+            number_of_server_connections,
+        ))
+    }
+
+    /// Sends the request as fire and forget request to all connected
+    /// [`Server`](crate::port::server::Server)s and returns the number of
+    /// [`Server`](crate::port::server::Server)s that received it. The request does not
+    /// occupy an active request slot and all responses to it are discarded. The
+    /// [`Server`](crate::port::server::Server) can identify such requests with
+    /// [`Header::is_fire_and_forget()`].
+    /// Requires a [`Service`](crate::service::Service) that was created with
+    /// [`Builder::enable_fire_and_forget_requests()`](crate::service::builder::request_response::Builder::enable_fire_and_forget_requests()).
+    pub fn send_and_forget(mut self) -> Result<usize, RequestSendError> {
+        let (_, number_of_server_connections) = self.client_backend.send_request(
+            self.ptr.as_header_mut(),
+            self.offset_to_chunk,
+            true,
+            None,
+        )?;
+
+        Ok(number_of_server_connections)
+    }
+}
@@ -0,0 +1,297 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let client = service.client_builder().create()?;
+//!
+//! let request = client.loan_uninit()?;
+//! let request = request.write_payload(1234);
+//! let pending_response = request.send()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+use iceoryx2_cal::shm_allocator::PointerOffset;
+
+use crate::port::client::ClientBackend;
+use crate::raw_sample::RawSampleMut;
+use crate::request_mut::RequestMut;
+use crate::service::header::request_response::RequestHeader as Header;
+
+/// The uninitialized request of a [`Client`](crate::port::client::Client) that was loaned
+/// with [`Client::loan_uninit()`](crate::port::client::Client::loan_uninit()). It has to be
+/// initialized with [`RequestMutUninit::write_payload()`] or
+/// [`RequestMutUninit::assume_init()`] before it can be sent.
+pub struct RequestMutUninit<
+    Service: crate::service::Service,
+    RequestPayload: Debug + ?Sized,
+    RequestHeader: Debug,
+    ResponsePayload: Debug + ?Sized,
+    ResponseHeader: Debug,
+> {
+    request: RequestMut<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>,
+}
+
+impl<
+        Service: crate::service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > Debug
+    for RequestMutUninit<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "RequestMutUninit {{ request: {:?} }}", self.request)
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > RequestMutUninit<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    /// Returns a reference to the iceoryx2 internal [`Header`] of the request.
+    pub fn header(&self) -> &Header {
+        self.request.header()
+    }
+
+    /// Returns a reference to the user defined request header.
+    pub fn user_header(&self) -> &RequestHeader {
+        self.request.user_header()
+    }
+
+    /// Returns a mutable reference to the user defined request header.
+    pub fn user_header_mut(&mut self) -> &mut RequestHeader {
+        self.request.user_header_mut()
+    }
+
+    /// Returns a reference to the uninitialized payload of the request.
+    pub fn payload(&self) -> &RequestPayload {
+        self.request.payload()
+    }
+
+    /// Returns a mutable reference to the uninitialized payload of the request.
+    pub fn payload_mut(&mut self) -> &mut RequestPayload {
+        self.request.payload_mut()
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        RequestPayload: Debug,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    >
+    RequestMutUninit<
+        Service,
+        MaybeUninit<RequestPayload>,
+        RequestHeader,
+        ResponsePayload,
+        ResponseHeader,
+    >
+{
+    pub(crate) fn new(
+        client_backend: &Arc<ClientBackend<Service>>,
+        ptr: RawSampleMut<Header, RequestHeader, MaybeUninit<RequestPayload>>,
+        offset_to_chunk: PointerOffset,
+    ) -> Self {
+        Self {
+            request: RequestMut {
+                client_backend: client_backend.clone(),
+                ptr,
+                offset_to_chunk,
+                _response_payload: PhantomData,
+                _response_header: PhantomData,
+            },
+        }
+    }
+
+    /// Writes the payload into the request and labels it as initialized.
+    pub fn write_payload(
+        mut self,
+        value: RequestPayload,
+    ) -> RequestMut<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader> {
+        self.payload_mut().write(value);
+        unsafe { self.assume_init() }
+    }
+
+    /// Extracts the value of the [`core::mem::MaybeUninit<RequestPayload>`] container and
+    /// labels the request as initialized
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that [`core::mem::MaybeUninit<RequestPayload>`] really is
+    /// initialized. Calling this when the content is not fully initialized causes immediate
+    /// undefined behavior.
+    pub unsafe fn assume_init(
+        self,
+    ) -> RequestMut<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader> {
+        // the transmute is not nice but safe since MaybeUninit is #[repr(transparent)] to the inner type
+        core::mem::transmute(self.request)
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        RequestPayload: Debug,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    >
+    RequestMutUninit<
+        Service,
+        [MaybeUninit<RequestPayload>],
+        RequestHeader,
+        ResponsePayload,
+        ResponseHeader,
+    >
+{
+    pub(crate) fn new(
+        client_backend: &Arc<ClientBackend<Service>>,
+        ptr: RawSampleMut<Header, RequestHeader, [MaybeUninit<RequestPayload>]>,
+        offset_to_chunk: PointerOffset,
+    ) -> Self {
+        Self {
+            request: RequestMut {
+                client_backend: client_backend.clone(),
+                ptr,
+                offset_to_chunk,
+                _response_payload: PhantomData,
+                _response_header: PhantomData,
+            },
+        }
+    }
+
+    /// Extracts the value of the slice of [`core::mem::MaybeUninit<RequestPayload>`] and
+    /// labels the request as initialized
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that every element of the slice of
+    /// [`core::mem::MaybeUninit<RequestPayload>`] is initialized. Calling this when the content
+    /// is not fully initialized causes immediate undefined behavior.
+    pub unsafe fn assume_init(
+        self,
+    ) -> RequestMut<Service, [RequestPayload], RequestHeader, ResponsePayload, ResponseHeader> {
+        // the transmute is not nice but safe since MaybeUninit is #[repr(transparent)] to the inner type
+        core::mem::transmute(self.request)
+    }
+
+    /// Writes the payload into the request by calling the `initializer` for every element
+    /// and labels the request as initialized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<[usize], u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let client = service.client_builder().initial_max_slice_len(16).create()?;
+    ///
+    /// let request = client.loan_slice_uninit(8)?;
+    /// let request = request.write_from_fn(|n| n * 2);
+    /// let pending_response = request.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_from_fn<F: FnMut(usize) -> RequestPayload>(
+        mut self,
+        mut initializer: F,
+    ) -> RequestMut<Service, [RequestPayload], RequestHeader, ResponsePayload, ResponseHeader> {
+        for (i, element) in self.payload_mut().iter_mut().enumerate() {
+            element.write(initializer(i));
+        }
+
+        // SAFETY: this is safe since the payload was initialized on the line above
+        unsafe { self.assume_init() }
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        RequestPayload: Debug + Copy,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    >
+    RequestMutUninit<
+        Service,
+        [MaybeUninit<RequestPayload>],
+        RequestHeader,
+        ResponsePayload,
+        ResponseHeader,
+    >
+{
+    /// Writes the payload into the request by mem copying the provided slice and labels the
+    /// request as initialized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<[u8], u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let client = service.client_builder().initial_max_slice_len(16).create()?;
+    ///
+    /// let request = client.loan_slice_uninit(3)?;
+    /// let request = request.write_from_slice(&[1, 2, 3]);
+    /// let pending_response = request.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_from_slice(
+        mut self,
+        value: &[RequestPayload],
+    ) -> RequestMut<Service, [RequestPayload], RequestHeader, ResponsePayload, ResponseHeader> {
+        self.payload_mut().copy_from_slice(unsafe {
+            core::mem::transmute::<&[RequestPayload], &[MaybeUninit<RequestPayload>]>(value)
+        });
+        unsafe { self.assume_init() }
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let client = service.client_builder().create()?;
+//! let pending_response = client.send_copy(1234)?;
+//!
+//! while let Some(response) = pending_response.receive()? {
+//!     println!("server port id: {:?}", response.header().server_port_id());
+//!     println!("payload: {}", *response);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::ops::Deref;
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+use iceoryx2_cal::shm_allocator::PointerOffset;
+
+use crate::port::details::server_connections::Connection;
+use crate::port::port_identifiers::UniqueServerId;
+use crate::raw_sample::RawSample;
+use crate::service::header::request_response::ResponseHeader as Header;
+
+/// It stores the payload of a response and is acquired by the
+/// [`Client`](crate::port::client::Client) via
+/// [`PendingResponse::receive()`](crate::pending_response::PendingResponse::receive()).
+pub struct Response<
+    Service: crate::service::Service,
+    ResponsePayload: Debug + ?Sized,
+    ResponseHeader: Debug,
+> {
+    pub(crate) ptr: RawSample<Header, ResponseHeader, ResponsePayload>,
+    pub(crate) connection: Arc<Connection<Service>>,
+    pub(crate) offset: PointerOffset,
+}
+
+impl<Service: crate::service::Service, ResponsePayload: Debug + ?Sized, ResponseHeader: Debug> Debug
+    for Response<Service, ResponsePayload, ResponseHeader>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Response<{}, {}, {}> {{ ptr: {:?}, offset: {:?} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<ResponsePayload>(),
+            core::any::type_name::<ResponseHeader>(),
+            self.ptr,
+            self.offset
+        )
+    }
+}
+
+impl<Service: crate::service::Service, ResponsePayload: Debug + ?Sized, ResponseHeader: Debug> Deref
+    for Response<Service, ResponsePayload, ResponseHeader>
+{
+    type Target = ResponsePayload;
+    fn deref(&self) -> &Self::Target {
+        self.ptr.as_payload_ref()
+    }
+}
+
+impl<Service: crate::service::Service, ResponsePayload: Debug + ?Sized, ResponseHeader: Debug> Drop
+    for Response<Service, ResponsePayload, ResponseHeader>
+{
+    fn drop(&mut self) {
+        self.connection.release_response(self.offset);
+    }
+}
+
+impl<Service: crate::service::Service, ResponsePayload: Debug + ?Sized, ResponseHeader: Debug>
+    Response<Service, ResponsePayload, ResponseHeader>
+{
+    /// Returns a reference to the iceoryx2 internal [`Header`] of the [`Response`].
+    pub fn header(&self) -> &Header {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns a reference to the user defined response header.
+    pub fn user_header(&self) -> &ResponseHeader {
+        self.ptr.as_user_header_ref()
+    }
+
+    /// Returns a reference to the payload of the [`Response`].
+    pub fn payload(&self) -> &ResponsePayload {
+        self.ptr.as_payload_ref()
+    }
+
+    /// Returns the [`UniqueServerId`] of the [`Server`](crate::port::server::Server) that
+    /// sent the [`Response`].
+    pub fn origin(&self) -> UniqueServerId {
+        self.connection.server_port_id
+    }
+}
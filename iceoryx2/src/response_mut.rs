@@ -0,0 +1,125 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let server = service.server_builder().create()?;
+//!
+//! while let Some(active_request) = server.receive()? {
+//!     let mut response = active_request.loan()?;
+//!     *response.payload_mut() = 5678;
+//!
+//!     println!("server port id: {:?}", response.header().server_port_id());
+//!     response.send()?;
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+use iceoryx2_cal::shm_allocator::PointerOffset;
+
+use crate::port::details::client_connections::Connection;
+use crate::port::server::{ResponseSendError, ServerBackend};
+use crate::raw_sample::RawSampleMut;
+use crate::service::header::request_response::ResponseHeader as Header;
+
+/// The response of a [`Server`](crate::port::server::Server) that was loaned with
+/// [`ActiveRequest::loan()`](crate::active_request::ActiveRequest::loan()). It is sent to
+/// the [`Client`](crate::port::client::Client) of the corresponding request with
+/// [`ResponseMut::send()`]. When it goes out of scope without being sent, the memory is
+/// returned to the [`Server`](crate::port::server::Server).
+pub struct ResponseMut<
+    Service: crate::service::Service,
+    ResponsePayload: Debug + ?Sized,
+    ResponseHeader: Debug,
+> {
+    pub(crate) server_backend: Arc<ServerBackend<Service>>,
+    pub(crate) connection: Arc<Connection<Service>>,
+    pub(crate) ptr: RawSampleMut<Header, ResponseHeader, ResponsePayload>,
+    pub(crate) offset_to_chunk: PointerOffset,
+}
+
+impl<Service: crate::service::Service, ResponsePayload: Debug + ?Sized, ResponseHeader: Debug> Debug
+    for ResponseMut<Service, ResponsePayload, ResponseHeader>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "ResponseMut<{}, {}, {}> {{ server_backend: {:?}, offset_to_chunk: {:?} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<ResponsePayload>(),
+            core::any::type_name::<ResponseHeader>(),
+            self.server_backend,
+            self.offset_to_chunk
+        )
+    }
+}
+
+impl<Service: crate::service::Service, ResponsePayload: Debug + ?Sized, ResponseHeader: Debug> Drop
+    for ResponseMut<Service, ResponsePayload, ResponseHeader>
+{
+    fn drop(&mut self) {
+        self.server_backend
+            .return_loaned_response(self.offset_to_chunk);
+    }
+}
+
+impl<Service: crate::service::Service, ResponsePayload: Debug + ?Sized, ResponseHeader: Debug>
+    ResponseMut<Service, ResponsePayload, ResponseHeader>
+{
+    /// Returns a reference to the iceoryx2 internal [`Header`] of the response.
+    pub fn header(&self) -> &Header {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns a reference to the user defined response header.
+    pub fn user_header(&self) -> &ResponseHeader {
+        self.ptr.as_user_header_ref()
+    }
+
+    /// Returns a mutable reference to the user defined response header.
+    pub fn user_header_mut(&mut self) -> &mut ResponseHeader {
+        self.ptr.as_user_header_mut()
+    }
+
+    /// Returns a reference to the payload of the response.
+    pub fn payload(&self) -> &ResponsePayload {
+        self.ptr.as_payload_ref()
+    }
+
+    /// Returns a mutable reference to the payload of the response.
+    pub fn payload_mut(&mut self) -> &mut ResponsePayload {
+        self.ptr.as_payload_mut()
+    }
+
+    /// Sends the response to the [`Client`](crate::port::client::Client) that sent the
+    /// corresponding request.
+    pub fn send(self) -> Result<(), ResponseSendError> {
+        self.server_backend
+            .send_response(&self.connection, self.offset_to_chunk)
+    }
+}
@@ -0,0 +1,250 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let server = service.server_builder().create()?;
+//!
+//! while let Some(active_request) = server.receive()? {
+//!     let response = active_request.loan_uninit()?;
+//!     let response = response.write_payload(5678);
+//!     response.send()?;
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::mem::MaybeUninit;
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+use iceoryx2_cal::shm_allocator::PointerOffset;
+
+use crate::port::details::client_connections::Connection;
+use crate::port::server::ServerBackend;
+use crate::raw_sample::RawSampleMut;
+use crate::response_mut::ResponseMut;
+use crate::service::header::request_response::ResponseHeader as Header;
+
+/// The uninitialized response of a [`Server`](crate::port::server::Server) that was loaned
+/// with [`ActiveRequest::loan_uninit()`](crate::active_request::ActiveRequest::loan_uninit()).
+/// It has to be initialized with [`ResponseMutUninit::write_payload()`] or
+/// [`ResponseMutUninit::assume_init()`] before it can be sent.
+pub struct ResponseMutUninit<
+    Service: crate::service::Service,
+    ResponsePayload: Debug + ?Sized,
+    ResponseHeader: Debug,
+> {
+    response: ResponseMut<Service, ResponsePayload, ResponseHeader>,
+}
+
+impl<Service: crate::service::Service, ResponsePayload: Debug + ?Sized, ResponseHeader: Debug> Debug
+    for ResponseMutUninit<Service, ResponsePayload, ResponseHeader>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ResponseMutUninit {{ response: {:?} }}", self.response)
+    }
+}
+
+impl<Service: crate::service::Service, ResponsePayload: Debug + ?Sized, ResponseHeader: Debug>
+    ResponseMutUninit<Service, ResponsePayload, ResponseHeader>
+{
+    /// Returns a reference to the iceoryx2 internal [`Header`] of the response.
+    pub fn header(&self) -> &Header {
+        self.response.header()
+    }
+
+    /// Returns a reference to the user defined response header.
+    pub fn user_header(&self) -> &ResponseHeader {
+        self.response.user_header()
+    }
+
+    /// Returns a mutable reference to the user defined response header.
+    pub fn user_header_mut(&mut self) -> &mut ResponseHeader {
+        self.response.user_header_mut()
+    }
+
+    /// Returns a reference to the uninitialized payload of the response.
+    pub fn payload(&self) -> &ResponsePayload {
+        self.response.payload()
+    }
+
+    /// Returns a mutable reference to the uninitialized payload of the response.
+    pub fn payload_mut(&mut self) -> &mut ResponsePayload {
+        self.response.payload_mut()
+    }
+}
+
+impl<Service: crate::service::Service, ResponsePayload: Debug, ResponseHeader: Debug>
+    ResponseMutUninit<Service, MaybeUninit<ResponsePayload>, ResponseHeader>
+{
+    pub(crate) fn new(
+        server_backend: &Arc<ServerBackend<Service>>,
+        connection: &Arc<Connection<Service>>,
+        ptr: RawSampleMut<Header, ResponseHeader, MaybeUninit<ResponsePayload>>,
+        offset_to_chunk: PointerOffset,
+    ) -> Self {
+        Self {
+            response: ResponseMut {
+                server_backend: server_backend.clone(),
+                connection: connection.clone(),
+                ptr,
+                offset_to_chunk,
+            },
+        }
+    }
+
+    /// Writes the payload into the response and labels it as initialized.
+    pub fn write_payload(
+        mut self,
+        value: ResponsePayload,
+    ) -> ResponseMut<Service, ResponsePayload, ResponseHeader> {
+        self.payload_mut().write(value);
+        unsafe { self.assume_init() }
+    }
+
+    /// Extracts the value of the [`core::mem::MaybeUninit<ResponsePayload>`] container and
+    /// labels the response as initialized
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that [`core::mem::MaybeUninit<ResponsePayload>`] really is
+    /// initialized. Calling this when the content is not fully initialized causes immediate
+    /// undefined behavior.
+    pub unsafe fn assume_init(self) -> ResponseMut<Service, ResponsePayload, ResponseHeader> {
+        // the transmute is not nice but safe since MaybeUninit is #[repr(transparent)] to the inner type
+        core::mem::transmute(self.response)
+    }
+}
+
+impl<Service: crate::service::Service, ResponsePayload: Debug, ResponseHeader: Debug>
+    ResponseMutUninit<Service, [MaybeUninit<ResponsePayload>], ResponseHeader>
+{
+    pub(crate) fn new(
+        server_backend: &Arc<ServerBackend<Service>>,
+        connection: &Arc<Connection<Service>>,
+        ptr: RawSampleMut<Header, ResponseHeader, [MaybeUninit<ResponsePayload>]>,
+        offset_to_chunk: PointerOffset,
+    ) -> Self {
+        Self {
+            response: ResponseMut {
+                server_backend: server_backend.clone(),
+                connection: connection.clone(),
+                ptr,
+                offset_to_chunk,
+            },
+        }
+    }
+
+    /// Extracts the value of the slice of [`core::mem::MaybeUninit<ResponsePayload>`] and
+    /// labels the response as initialized
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that every element of the slice of
+    /// [`core::mem::MaybeUninit<ResponsePayload>`] is initialized. Calling this when the content
+    /// is not fully initialized causes immediate undefined behavior.
+    pub unsafe fn assume_init(self) -> ResponseMut<Service, [ResponsePayload], ResponseHeader> {
+        // the transmute is not nice but safe since MaybeUninit is #[repr(transparent)] to the inner type
+        core::mem::transmute(self.response)
+    }
+
+    /// Writes the payload into the response by calling the `initializer` for every element
+    /// and labels the response as initialized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, [usize]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let server = service.server_builder().initial_max_slice_len(16).create()?;
+    ///
+    /// while let Some(active_request) = server.receive()? {
+    ///     let response = active_request.loan_slice_uninit(8)?;
+    ///     let response = response.write_from_fn(|n| n * 2);
+    ///     response.send()?;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_from_fn<F: FnMut(usize) -> ResponsePayload>(
+        mut self,
+        mut initializer: F,
+    ) -> ResponseMut<Service, [ResponsePayload], ResponseHeader> {
+        for (i, element) in self.payload_mut().iter_mut().enumerate() {
+            element.write(initializer(i));
+        }
+
+        // SAFETY: this is safe since the payload was initialized on the line above
+        unsafe { self.assume_init() }
+    }
+}
+
+impl<Service: crate::service::Service, ResponsePayload: Debug + Copy, ResponseHeader: Debug>
+    ResponseMutUninit<Service, [MaybeUninit<ResponsePayload>], ResponseHeader>
+{
+    /// Writes the payload into the response by mem copying the provided slice and labels the
+    /// response as initialized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, [u8]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let server = service.server_builder().initial_max_slice_len(16).create()?;
+    ///
+    /// while let Some(active_request) = server.receive()? {
+    ///     let response = active_request.loan_slice_uninit(3)?;
+    ///     let response = response.write_from_slice(&[1, 2, 3]);
+    ///     response.send()?;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_from_slice(
+        mut self,
+        value: &[ResponsePayload],
+    ) -> ResponseMut<Service, [ResponsePayload], ResponseHeader> {
+        self.payload_mut().copy_from_slice(unsafe {
+            core::mem::transmute::<&[ResponsePayload], &[MaybeUninit<ResponsePayload>]>(value)
+        });
+        unsafe { self.assume_init() }
+    }
+}
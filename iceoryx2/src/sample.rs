@@ -30,24 +30,115 @@
 //! # }
 //! ```
 
-use core::{fmt::Debug, ops::Deref};
+use core::{alloc::Layout, fmt::Debug, ops::Deref, time::Duration};
 
 extern crate alloc;
 use alloc::sync::Arc;
 
-use iceoryx2_bb_log::fatal_panic;
-use iceoryx2_cal::zero_copy_connection::{PointerOffset, ZeroCopyReceiver, ZeroCopyReleaseError};
+use iceoryx2_bb_elementary::versioned_user_header::VersionedUserHeader;
+use iceoryx2_bb_log::{fatal_panic, warn};
+use iceoryx2_bb_posix::clock::{ClockType, Time};
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::zero_copy_connection::{PointerOffset, ZeroCopyReleaseError};
 
 use crate::port::details::publisher_connections::Connection;
 use crate::port::port_identifiers::UniquePublisherId;
 use crate::raw_sample::RawSample;
+use crate::service::builder::publish_subscribe::CustomHeaderMarker;
 use crate::service::header::publish_subscribe::Header;
+use crate::service::ServiceState;
+
+/// Accounts a received sample in the number of borrowed samples of all subscribers of the
+/// service until it is dropped, see
+/// [`Builder::max_total_borrowed_samples()`](crate::service::builder::publish_subscribe::Builder::max_total_borrowed_samples()).
+pub(crate) struct BorrowedSampleGuard<Service: crate::service::Service> {
+    service_state: Arc<ServiceState<Service>>,
+    subscriber_slot: u32,
+}
+
+impl<Service: crate::service::Service> Debug for BorrowedSampleGuard<Service> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "BorrowedSampleGuard<{}> {{ subscriber_slot: {} }}",
+            core::any::type_name::<Service>(),
+            self.subscriber_slot
+        )
+    }
+}
+
+impl<Service: crate::service::Service> BorrowedSampleGuard<Service> {
+    pub(crate) fn new(service_state: Arc<ServiceState<Service>>, subscriber_slot: u32) -> Self {
+        Self {
+            service_state,
+            subscriber_slot,
+        }
+    }
+}
+
+impl<Service: crate::service::Service> Drop for BorrowedSampleGuard<Service> {
+    fn drop(&mut self) {
+        self.service_state
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .release_borrowed_sample(self.subscriber_slot);
+    }
+}
 
 #[derive(Debug)]
 pub(crate) struct SampleDetails<Service: crate::service::Service> {
     pub(crate) publisher_connection: Arc<Connection<Service>>,
     pub(crate) offset: PointerOffset,
     pub(crate) origin: UniquePublisherId,
+    pub(crate) in_place_updates: bool,
+    pub(crate) _borrow_guard: Option<BorrowedSampleGuard<Service>>,
+}
+
+/// Heap allocated copy of a sample that was received from shared memory.
+#[derive(Debug)]
+pub(crate) struct HeapCopy {
+    memory: *mut u8,
+    layout: Layout,
+}
+
+impl HeapCopy {
+    /// Allocates uninitialized heap memory with the provided [`Layout`].
+    ///
+    /// # Safety
+    ///
+    ///  * `layout.size()` must be greater than zero
+    pub(crate) unsafe fn new(layout: Layout) -> Self {
+        let memory = alloc::alloc::alloc(layout);
+        if memory.is_null() {
+            alloc::alloc::handle_alloc_error(layout);
+        }
+
+        Self { memory, layout }
+    }
+
+    pub(crate) fn address(&self) -> usize {
+        self.memory as usize
+    }
+
+    pub(crate) fn size(&self) -> usize {
+        self.layout.size()
+    }
+}
+
+impl Drop for HeapCopy {
+    fn drop(&mut self) {
+        unsafe { alloc::alloc::dealloc(self.memory, self.layout) };
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum SampleStorage<Service: crate::service::Service> {
+    SharedMemory(SampleDetails<Service>),
+    Heap {
+        copy: HeapCopy,
+        origin: UniquePublisherId,
+    },
 }
 
 /// It stores the payload and is acquired by the [`Subscriber`](crate::port::subscriber::Subscriber) whenever
@@ -55,7 +146,7 @@ pub(crate) struct SampleDetails<Service: crate::service::Service> {
 /// [`Subscriber::receive()`](crate::port::subscriber::Subscriber::receive()).
 pub struct Sample<Service: crate::service::Service, Payload: Debug + ?Sized, UserHeader> {
     pub(crate) ptr: RawSample<Header, UserHeader, Payload>,
-    pub(crate) details: SampleDetails<Service>,
+    pub(crate) storage: SampleStorage<Service>,
 }
 
 impl<Service: crate::service::Service, Payload: Debug + ?Sized, UserHeader> Debug
@@ -64,11 +155,11 @@ impl<Service: crate::service::Service, Payload: Debug + ?Sized, UserHeader> Debu
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
-            "Sample<{}, {}, {}> {{ details: {:?} }}",
+            "Sample<{}, {}, {}> {{ storage: {:?} }}",
             core::any::type_name::<Payload>(),
             core::any::type_name::<UserHeader>(),
             core::any::type_name::<Service>(),
-            self.details
+            self.storage
         )
     }
 }
@@ -86,23 +177,26 @@ impl<Service: crate::service::Service, Payload: Debug + ?Sized, UserHeader> Drop
     for Sample<Service, Payload, UserHeader>
 {
     fn drop(&mut self) {
+        // a heap copy has released its shared memory already on receive
+        let details = match &self.storage {
+            SampleStorage::SharedMemory(details) => details,
+            SampleStorage::Heap { .. } => return,
+        };
+
         unsafe {
-            self.details
+            details
                 .publisher_connection
-                .data_segment
-                .unregister_offset(self.details.offset)
+                .unregister_offset(details.offset)
         };
 
-        match self
-            .details
-            .publisher_connection
-            .receiver
-            .release(self.details.offset)
-        {
+        match details.publisher_connection.release(details.offset) {
             Ok(()) => (),
             Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
                 fatal_panic!(from self, "This should never happen! The publishers retrieve channel is full and the sample cannot be returned.");
             }
+            Err(ZeroCopyReleaseError::ReceiverOfPreviousGeneration) => {
+                warn!(from self, "Unable to return the sample to the publisher since the subscriber was considered dead and replaced. The publisher recovers the sample when it cleans up the connection.");
+            }
         }
     }
 }
@@ -125,8 +219,100 @@ impl<Service: crate::service::Service, Payload: Debug + ?Sized, UserHeader>
         self.ptr.as_header_ref()
     }
 
+    /// Returns the time that passed since the [`Sample`] was sent, measured with the monotonic
+    /// clock. Returns [`None`] without reading the clock when the [`Header`] has no valid send
+    /// timestamp, see [`Header::send_timestamp()`].
+    pub fn latency(&self) -> Option<Duration> {
+        let send_timestamp = self.header().send_timestamp()?;
+        let now = Time::now_with_clock(ClockType::Monotonic).ok()?;
+        Some(now.as_duration().saturating_sub(send_timestamp))
+    }
+
     /// Returns the [`UniquePublisherId`] of the [`Publisher`](crate::port::publisher::Publisher)
     pub fn origin(&self) -> UniquePublisherId {
-        self.details.origin
+        match &self.storage {
+            SampleStorage::SharedMemory(details) => details.origin,
+            SampleStorage::Heap { origin, .. } => *origin,
+        }
+    }
+
+    /// Returns true when the [`Sample`] is an owned heap copy that does not occupy any shared
+    /// memory of the [`Publisher`](crate::port::publisher::Publisher), see
+    /// [`PortFactorySubscriber::receive_heap_copies()`](crate::service::port_factory::subscriber::PortFactorySubscriber::receive_heap_copies()).
+    pub fn is_heap_copy(&self) -> bool {
+        matches!(self.storage, SampleStorage::Heap { .. })
+    }
+
+    /// Returns true when the [`Sample`] can be updated in place with [`Sample::payload_mut()`]
+    /// and handed back with [`Sample::hand_back()`]. This requires that the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) was created with
+    /// [`PortFactorySubscriber::enable_in_place_updates()`](crate::service::port_factory::subscriber::PortFactorySubscriber::enable_in_place_updates())
+    /// and that the [`Sample`] is exclusive, see [`Header::is_exclusive()`].
+    pub fn is_updatable_in_place(&self) -> bool {
+        match &self.storage {
+            SampleStorage::SharedMemory(details) => {
+                details.in_place_updates && self.header().is_exclusive()
+            }
+            SampleStorage::Heap { .. } => false,
+        }
+    }
+
+    /// Returns a mutable reference to the payload of the [`Sample`] when it is updatable in
+    /// place, see [`Sample::is_updatable_in_place()`]. Otherwise it returns [`None`].
+    pub fn payload_mut(&mut self) -> Option<&mut Payload> {
+        if !self.is_updatable_in_place() {
+            return None;
+        }
+
+        // SAFETY: the sample is exclusive, no one else holds a reference to the payload
+        Some(unsafe { self.ptr.as_payload_mut_unchecked() })
+    }
+
+    /// Hands the [`Sample`] back to its [`Publisher`](crate::port::publisher::Publisher)
+    /// which delivers it as new publication to all other
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s without copying it. The
+    /// delivery happens the next time the [`Publisher`](crate::port::publisher::Publisher)
+    /// reclaims its returned samples, e.g. when it sends or loans a sample. When the
+    /// [`Sample`] is not updatable in place, see [`Sample::is_updatable_in_place()`], it is
+    /// returned unchanged.
+    pub fn hand_back(mut self) -> Result<(), Self> {
+        if !self.is_updatable_in_place() {
+            return Err(self);
+        }
+
+        // SAFETY: the sample is exclusive, no one else holds a reference to the header
+        unsafe { self.ptr.as_header_mut_unchecked() }.set_handed_back(true);
+        Ok(())
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        Payload: Debug + ?Sized,
+        UserHeader: VersionedUserHeader + Clone,
+    > Sample<Service, Payload, UserHeader>
+{
+    /// Returns a copy of the [`VersionedUserHeader`] of the [`Sample`] where all fields that
+    /// were not written by a [`Publisher`](crate::port::publisher::Publisher) with an older
+    /// version of the user header are set to their default values.
+    pub fn user_header_with_defaults(&self) -> UserHeader {
+        let mut user_header = self.user_header().clone();
+        user_header.fill_missing_fields(self.header().user_header_size() as usize);
+        user_header
+    }
+}
+
+impl<Service: crate::service::Service, Payload: Debug + ?Sized>
+    Sample<Service, Payload, CustomHeaderMarker>
+{
+    /// Returns the runtime-sized user header of the [`Sample`] as bytes, see
+    /// [`Builder::user_header_size()`](crate::service::builder::publish_subscribe::Builder::user_header_size()).
+    pub fn user_header_bytes(&self) -> &[u8] {
+        unsafe {
+            core::slice::from_raw_parts(
+                (self.user_header() as *const CustomHeaderMarker).cast(),
+                self.header().user_header_size() as usize,
+            )
+        }
     }
 }
@@ -64,10 +64,12 @@
 //! ```
 
 use crate::{
-    port::publisher::{PublisherBackend, PublisherSendError},
+    port::publisher::{Priority, PublisherBackend, PublisherSendError},
     raw_sample::RawSampleMut,
+    service::builder::publish_subscribe::CustomHeaderMarker,
     service::header::publish_subscribe::Header,
 };
+use iceoryx2_bb_elementary::crc32c::crc32c;
 use iceoryx2_cal::shared_memory::*;
 
 use core::fmt::{Debug, Formatter};
@@ -263,6 +265,45 @@ impl<
         self.ptr.as_payload_mut()
     }
 
+    /// Registers a callback that is called as soon as the memory of the [`SampleMut`] is
+    /// released, meaning every [`crate::port::subscriber::Subscriber`] has returned the sample
+    /// and it is no longer part of the history of the [`crate::port::publisher::Publisher`].
+    /// It is also called when the [`SampleMut`] is dropped without being sent. Can be used to
+    /// couple external resources, like a GPU buffer, to the lifetime of the sample.
+    ///
+    /// The callback is called from within the [`crate::port::publisher::Publisher`] when it
+    /// reclaims the returned samples, for instance while loaning or sending. When the
+    /// [`crate::port::publisher::Publisher`] goes out of scope before the sample was released
+    /// the callback is dropped without being called. Registering a second callback replaces
+    /// the first one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let mut sample = publisher.loan()?;
+    /// *sample.payload_mut() = 4567;
+    /// sample.on_fully_released(|| println!("sample was consumed by everyone"));
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on_fully_released<F: FnOnce() + 'static>(&mut self, callback: F) {
+        self.publisher_backend
+            .set_fully_released_callback(self.offset_to_chunk, Box::new(callback));
+    }
+
     /// Send a previously loaned [`crate::port::publisher::Publisher::loan_uninit()`] or
     /// [`crate::port::publisher::Publisher::loan()`] [`SampleMut`] to all connected
     /// [`crate::port::subscriber::Subscriber`]s of the service.
@@ -291,8 +332,238 @@ impl<
     /// # Ok(())
     /// # }
     /// ```
-    pub fn send(self) -> Result<usize, PublisherSendError> {
+    pub fn send(mut self) -> Result<usize, PublisherSendError> {
+        self.prepare_for_delivery();
+
+        self.publisher_backend.send_sample(
+            self.ptr.as_header_mut(),
+            self.offset_to_chunk,
+            self.sample_size,
+        )
+    }
+
+    /// Sends the [`SampleMut`] like [`SampleMut::send()`] but with the provided [`Priority`].
+    /// When the [`Service`](crate::service::Service) was created with
+    /// [`Builder::enable_priority_lanes()`](crate::service::builder::publish_subscribe::Builder::enable_priority_lanes()),
+    /// a sample with [`Priority::High`] is received before all samples with
+    /// [`Priority::Normal`], otherwise it is delivered like any other sample.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::port::publisher::Priority;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .enable_priority_lanes(true)
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let mut sample = publisher.loan()?;
+    /// *sample.payload_mut() = 4567;
+    ///
+    /// sample.send_with_priority(Priority::High)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_with_priority(mut self, priority: Priority) -> Result<usize, PublisherSendError> {
+        self.prepare_for_delivery();
+
+        self.publisher_backend.send_sample_with_priority(
+            self.ptr.as_header_mut(),
+            self.offset_to_chunk,
+            self.sample_size,
+            priority,
+        )
+    }
+
+    /// Sends the [`SampleMut`] like [`SampleMut::send()`] but does not compute the payload
+    /// checksum, even when the [`Service`](crate::service::Service) was created with
+    /// [`Builder::enable_payload_checksum()`](crate::service::builder::publish_subscribe::Builder::enable_payload_checksum()).
+    /// Useful for large samples where the latency of the checksum computation is not acceptable.
+    /// The [`crate::port::subscriber::Subscriber`] does not verify samples without checksum.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .enable_payload_checksum(true)
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let mut sample = publisher.loan()?;
+    /// *sample.payload_mut() = 4567;
+    ///
+    /// sample.send_without_payload_checksum()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_without_payload_checksum(mut self) -> Result<usize, PublisherSendError> {
+        self.prepare_header_for_delivery();
+        self.ptr.as_header_mut().set_payload_checksum(None);
+        self.publisher_backend.send_sample(
+            self.ptr.as_header_mut(),
+            self.offset_to_chunk,
+            self.sample_size,
+        )
+    }
+
+    pub(crate) fn prepare_for_delivery(&mut self) {
+        self.prepare_header_for_delivery();
+
+        if self.publisher_backend.has_payload_checksum() {
+            let checksum = crc32c(self.payload_bytes());
+            self.ptr
+                .as_header_mut()
+                .set_payload_checksum(Some(checksum));
+        }
+    }
+
+    fn prepare_header_for_delivery(&mut self) {
         self.publisher_backend
-            .send_sample(self.offset_to_chunk, self.sample_size)
+            .prepare_header_for_delivery(self.ptr.as_header_mut());
+    }
+
+    fn payload_bytes(&self) -> &[u8] {
+        let payload = self.ptr.as_payload_ref();
+        unsafe {
+            core::slice::from_raw_parts(
+                (payload as *const M).cast::<u8>(),
+                core::mem::size_of_val(payload),
+            )
+        }
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        M: Debug, // `M` is either a `Payload` or a `MaybeUninit<Payload>`
+        UserHeader,
+    > SampleMut<Service, [M], UserHeader>
+{
+    /// Shortens the slice payload of the [`SampleMut`] to `len` elements. The
+    /// [`Header::number_of_elements()`] is adjusted accordingly, therefore the
+    /// [`crate::port::subscriber::Subscriber`]s receive only the first `len` elements and the
+    /// remaining elements are neither transferred nor validated. When `len` is greater or equal
+    /// to the current length, it has no effect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[u8]>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().initial_max_slice_len(128).create()?;
+    ///
+    /// let mut sample = publisher.loan_slice(128)?;
+    /// sample.payload_mut()[..5].copy_from_slice(b"hello");
+    /// sample.truncate(5);
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        let number_of_elements = self.header().number_of_elements() as usize;
+        if number_of_elements <= len {
+            return;
+        }
+
+        // custom payloads consist of multiple slice elements per payload element
+        let payload = self.ptr.as_payload_mut();
+        let slice_len = payload.len() / number_of_elements * len;
+        let payload_ptr = payload.as_mut_ptr();
+
+        let header = self.ptr.as_header_mut();
+        header.set_number_of_elements(len as u64);
+        let header_ptr = header as *mut Header;
+        let user_header_ptr = self.ptr.as_user_header_mut() as *mut UserHeader;
+
+        self.ptr = unsafe {
+            RawSampleMut::new_unchecked(
+                header_ptr,
+                user_header_ptr,
+                core::slice::from_raw_parts_mut(payload_ptr, slice_len),
+            )
+        };
+        self.sample_size = self
+            .sample_size
+            .min(self.publisher_backend.sample_size(len));
+    }
+
+    /// Shortens the slice payload to `len` elements with [`SampleMut::truncate()`] and sends
+    /// it afterwards with [`SampleMut::send()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[u8]>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().initial_max_slice_len(128).create()?;
+    ///
+    /// let mut sample = publisher.loan_slice(128)?;
+    /// sample.payload_mut()[..5].copy_from_slice(b"hello");
+    ///
+    /// sample.send_truncated(5)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_truncated(mut self, len: usize) -> Result<usize, PublisherSendError> {
+        self.truncate(len);
+        self.send()
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        M: Debug + ?Sized, // `M` is either a `Payload` or a `MaybeUninit<Payload>`
+    > SampleMut<Service, M, CustomHeaderMarker>
+{
+    /// Returns the runtime-sized user header of the sample as bytes, see
+    /// [`Builder::user_header_size()`](crate::service::builder::publish_subscribe::Builder::user_header_size()).
+    pub fn user_header_bytes(&self) -> &[u8] {
+        unsafe {
+            core::slice::from_raw_parts(
+                (self.user_header() as *const CustomHeaderMarker).cast(),
+                self.header().user_header_size() as usize,
+            )
+        }
+    }
+
+    /// Returns the runtime-sized user header of the sample as mutable bytes, see
+    /// [`Builder::user_header_size()`](crate::service::builder::publish_subscribe::Builder::user_header_size()).
+    pub fn user_header_bytes_mut(&mut self) -> &mut [u8] {
+        let len = self.header().user_header_size() as usize;
+        unsafe {
+            core::slice::from_raw_parts_mut(
+                (self.user_header_mut() as *mut CustomHeaderMarker).cast(),
+                len,
+            )
+        }
     }
 }
@@ -99,6 +99,7 @@ use iceoryx2_cal::shm_allocator::PointerOffset;
 
 use crate::{
     port::publisher::PublisherBackend, raw_sample::RawSampleMut, sample_mut::SampleMut,
+    service::builder::publish_subscribe::CustomHeaderMarker,
     service::header::publish_subscribe::Header,
 };
 
@@ -260,6 +261,22 @@ impl<Service: crate::service::Service, Payload: Debug + ?Sized, UserHeader>
     }
 }
 
+impl<Service: crate::service::Service, Payload: Debug + ?Sized>
+    SampleMutUninit<Service, Payload, CustomHeaderMarker>
+{
+    /// Returns the runtime-sized user header of the sample as bytes, see
+    /// [`Builder::user_header_size()`](crate::service::builder::publish_subscribe::Builder::user_header_size()).
+    pub fn user_header_bytes(&self) -> &[u8] {
+        self.sample.user_header_bytes()
+    }
+
+    /// Returns the runtime-sized user header of the sample as mutable bytes, see
+    /// [`Builder::user_header_size()`](crate::service::builder::publish_subscribe::Builder::user_header_size()).
+    pub fn user_header_bytes_mut(&mut self) -> &mut [u8] {
+        self.sample.user_header_bytes_mut()
+    }
+}
+
 impl<Service: crate::service::Service, Payload: Debug, UserHeader>
     SampleMutUninit<Service, MaybeUninit<Payload>, UserHeader>
 {
@@ -402,6 +419,37 @@ impl<Service: crate::service::Service, Payload: Debug, UserHeader>
         core::mem::transmute(self.sample)
     }
 
+    /// Shortens the slice payload to `len` elements, see [`SampleMut::truncate()`]. Only the
+    /// first `len` elements must be initialized before the sample is labeled as initialized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[usize]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder().initial_max_slice_len(32).create()?;
+    ///
+    /// let mut sample = publisher.loan_slice_uninit(32)?;
+    /// // only 3 elements are available
+    /// sample.truncate(3);
+    /// let sample = sample.write_from_fn(|n| n + 1);
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        self.sample.truncate(len)
+    }
+
     /// Writes the payload to the sample and labels the sample as initialized
     ///
     /// # Example
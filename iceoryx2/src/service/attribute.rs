@@ -217,6 +217,68 @@ impl AttributeVerifier {
     }
 }
 
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct AttributeQueryTerm {
+    key: String,
+    value: Option<String>,
+}
+
+/// Represents a query that is used to discover [`crate::service::Service`]s by their
+/// [`Attribute`]s with [`crate::service::Service::list_where()`]. A
+/// [`crate::service::Service`] matches when all terms of the query are satisfied.
+///
+/// # Example
+///
+/// ```
+/// use iceoryx2::prelude::*;
+///
+/// let query = AttributeQuery::key("sensor-type")
+///     .equals("lidar")
+///     .and(AttributeQuery::key("calibration"));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AttributeQuery {
+    terms: Vec<AttributeQueryTerm>,
+}
+
+impl AttributeQuery {
+    /// Creates a new query that requires that the key is defined.
+    pub fn key(key: &str) -> Self {
+        Self {
+            terms: vec![AttributeQueryTerm {
+                key: key.into(),
+                value: None,
+            }],
+        }
+    }
+
+    /// Requires that the most recently added key has the provided value. When the key has
+    /// multiple values, one of them must be equal to the provided value.
+    pub fn equals(mut self, value: &str) -> Self {
+        if let Some(term) = self.terms.last_mut() {
+            term.value = Some(value.into());
+        }
+        self
+    }
+
+    /// Combines two queries. The resulting query is satisfied when both queries are satisfied.
+    pub fn and(mut self, rhs: AttributeQuery) -> Self {
+        self.terms.extend(rhs.terms);
+        self
+    }
+
+    /// Returns true when the [`AttributeSet`] satisfies the query, otherwise false.
+    pub fn matches(&self, attributes: &AttributeSet) -> bool {
+        self.terms.iter().all(|term| {
+            let values = attributes.get_vec(&term.key);
+            match &term.value {
+                None => !values.is_empty(),
+                Some(value) => values.contains(&value.as_str()),
+            }
+        })
+    }
+}
+
 /// Represents all service attributes. They can be set when the service is created.
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct AttributeSet(Vec<Attribute>);
@@ -242,6 +304,10 @@ impl AttributeSet {
         self.0.sort();
     }
 
+    pub(crate) fn remove(&mut self, key: &str) {
+        self.0.retain(|p| p.key != key);
+    }
+
     fn get_vec(&self, key: &str) -> Vec<&str> {
         self.0
             .iter()
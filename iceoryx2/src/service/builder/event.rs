@@ -15,6 +15,7 @@
 //! See [`crate::service`]
 //!
 pub use crate::port::event_id::EventId;
+use crate::port::event_payload::MAX_EVENT_PAYLOAD_SIZE;
 use crate::service::builder::OpenDynamicStorageFailure;
 use crate::service::port_factory::event;
 use crate::service::static_config::messaging_pattern::MessagingPattern;
@@ -68,12 +69,24 @@ pub enum EventOpenError {
     DoesNotSupportRequestedMaxEventId,
     /// The [`Service`] supports less [`Node`](crate::node::Node)s than requested.
     DoesNotSupportRequestedAmountOfNodes,
+    /// The [`Service`] supports a smaller notification payload than requested.
+    DoesNotSupportRequestedMaxPayloadSize,
     /// The maximum number of [`Node`](crate::node::Node)s have already opened the [`Service`].
     ExceedsMaxNumberOfNodes,
     /// The [`Service`] is marked for destruction and currently cleaning up since no one is using it anymore.
     /// When the call creation call is repeated with a little delay the [`Service`] should be
     /// recreatable.
     IsMarkedForDestruction,
+    /// The [`Service`] was frozen with [`Service::freeze_partition()`] and does not accept new
+    /// [`Node`](crate::node::Node)s until it is unfrozen.
+    IsFrozen,
+    /// The [`Service`] was not created by a trusted creator. Either its static config is not
+    /// signed with one of the trusted keys or the signature is invalid, see
+    /// [`crate::service::static_config::signature`].
+    SignatureVerificationFailed,
+    /// The [`Service`] does not satisfy the requested notifier origin setting, see
+    /// [`Builder::enable_notifier_origin()`].
+    IncompatibleNotifierOriginSetting,
 }
 
 impl core::fmt::Display for EventOpenError {
@@ -145,6 +158,9 @@ pub enum EventOpenOrCreateError {
     /// Can occur when another process creates and removes the same [`Service`] repeatedly with a
     /// high frequency.
     SystemInFlux,
+    /// The timeout defined with [`crate::service::builder::Builder::timeout()`] was exceeded
+    /// before the [`Service`] could be opened or created.
+    TimedOut,
 }
 
 impl From<EventOpenError> for EventOpenOrCreateError {
@@ -186,9 +202,12 @@ pub struct Builder<ServiceType: service::Service> {
     verify_max_nodes: bool,
     verify_event_id_max_value: bool,
     verify_deadline: bool,
+    verify_deadline_missed_event: bool,
     verify_notifier_created_event: bool,
     verify_notifier_dropped_event: bool,
     verify_notifier_dead_event: bool,
+    verify_max_payload_size: bool,
+    verify_enable_notifier_origin: bool,
 }
 
 impl<ServiceType: service::Service> Builder<ServiceType> {
@@ -200,9 +219,12 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
             verify_max_nodes: false,
             verify_event_id_max_value: false,
             verify_deadline: false,
+            verify_deadline_missed_event: false,
             verify_notifier_dead_event: false,
             verify_notifier_created_event: false,
             verify_notifier_dropped_event: false,
+            verify_max_payload_size: false,
+            verify_enable_notifier_origin: false,
         };
 
         new_self.base.service_config.messaging_pattern = MessagingPattern::Event(
@@ -240,6 +262,24 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         self
     }
 
+    /// If the [`Service`] is created it defines the event that every
+    /// [`Listener`](crate::port::listener::Listener) receives when no
+    /// [`Notifier`](crate::port::notifier::Notifier) sent a notification within the
+    /// [`Builder::deadline()`]. It is reported once per missed deadline.
+    pub fn deadline_missed_event(mut self, value: EventId) -> Self {
+        self.config_details().deadline_missed_event = Some(value.as_value());
+        self.verify_deadline_missed_event = true;
+        self
+    }
+
+    /// If the [`Service`] is created it disables the event that is emitted when the
+    /// [`Builder::deadline()`] was missed.
+    pub fn disable_deadline_missed_event(mut self) -> Self {
+        self.config_details().deadline_missed_event = None;
+        self.verify_deadline_missed_event = true;
+        self
+    }
+
     /// If the [`Service`] is created it defines how many [`Node`](crate::node::Node)s shall
     /// be able to open it in parallel. If an existing [`Service`] is opened it defines how many
     /// [`Node`](crate::node::Node)s must be at least supported.
@@ -258,6 +298,26 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         self
     }
 
+    /// If the [`Service`] is created it defines the maximum size in bytes of the payload that
+    /// can be attached to a notification, at most [`MAX_EVENT_PAYLOAD_SIZE`]. If an existing
+    /// [`Service`] is opened it defines the payload size that must be at least supported.
+    pub fn max_payload_size(mut self, value: usize) -> Self {
+        self.config_details().max_payload_size = value;
+        self.verify_max_payload_size = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines if every notification carries the origin of
+    /// the [`crate::port::notifier::Notifier`] that sent it. The origin can be acquired with
+    /// [`Listener::try_wait_one_with_origin()`](crate::port::listener::Listener::try_wait_one_with_origin()).
+    /// If an existing [`Service`] is opened it requires the service to have the same setting.
+    /// By default, it is disabled.
+    pub fn enable_notifier_origin(mut self, value: bool) -> Self {
+        self.config_details().enable_notifier_origin = value;
+        self.verify_enable_notifier_origin = true;
+        self
+    }
+
     /// If the [`Service`] is created it defines how many [`crate::port::notifier::Notifier`] shall
     /// be supported at most. If an existing [`Service`] is opened it defines how many
     /// [`crate::port::notifier::Notifier`] must be at least supported.
@@ -340,8 +400,16 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
     ) -> Result<event::PortFactory<ServiceType>, EventOpenOrCreateError> {
         let msg = "Unable to open or create event service";
 
+        self.base.timeout.start();
+        let timeout = self.base.timeout;
+
         let mut retry_count = 0;
         loop {
+            if timeout.has_expired() {
+                fail!(from self, with EventOpenOrCreateError::TimedOut,
+                    "{} since the timeout was exceeded.", msg);
+            }
+
             if RETRY_LIMIT < retry_count {
                 fail!(from self,
                       with EventOpenOrCreateError::SystemInFlux,
@@ -350,8 +418,28 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
             }
             retry_count += 1;
 
-            match self.base.is_service_available(msg)? {
-                Some(_) => return Ok(self.open_with_attributes(required_attributes)?),
+            let is_service_available = match self.base.is_service_available(msg) {
+                Ok(v) => v,
+                Err(e) => {
+                    if timeout.has_expired() {
+                        fail!(from self, with EventOpenOrCreateError::TimedOut,
+                            "{} since the timeout was exceeded while checking the service availability ({:?}).", msg, e);
+                    }
+                    return Err(e.into());
+                }
+            };
+
+            match is_service_available {
+                Some(_) => match self.open_with_attributes(required_attributes) {
+                    Ok(factory) => return Ok(factory),
+                    Err(e) => {
+                        if timeout.has_expired() {
+                            fail!(from timeout, with EventOpenOrCreateError::TimedOut,
+                                "{} since the timeout was exceeded while opening the service ({:?}).", msg, e);
+                        }
+                        return Err(e.into());
+                    }
+                },
                 None => {
                     match self.create_impl(&AttributeSpecifier(
                         required_attributes.attributes().clone(),
@@ -361,7 +449,13 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                         | Err(EventCreateError::IsBeingCreatedByAnotherInstance) => {
                             continue;
                         }
-                        Err(e) => return Err(e.into()),
+                        Err(e) => {
+                            if timeout.has_expired() {
+                                fail!(from self, with EventOpenOrCreateError::TimedOut,
+                                    "{} since the timeout was exceeded while creating the service ({:?}).", msg, e);
+                            }
+                            return Err(e.into());
+                        }
                     }
                 }
             }
@@ -389,6 +483,15 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                         "{} since the event does not exist.", msg);
                 }
                 Some((static_config, static_storage)) => {
+                    if self
+                        .base
+                        .verify_service_config_signature(&static_config)
+                        .is_err()
+                    {
+                        fail!(from self, with EventOpenError::SignatureVerificationFailed,
+                            "{} since the service was not created by a trusted creator.", msg);
+                    }
+
                     let event_static_config =
                         self.verify_service_configuration(&static_config, required_attributes)?;
 
@@ -402,6 +505,10 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                             fail!(from self, with EventOpenError::IsMarkedForDestruction,
                                 "{} since the service is marked for destruction.", msg);
                         }
+                        Err(OpenDynamicStorageFailure::IsFrozen) => {
+                            fail!(from self, with EventOpenError::IsFrozen,
+                                "{} since the service is frozen.", msg);
+                        }
                         Err(OpenDynamicStorageFailure::ExceedsMaxNumberOfNodes) => {
                             fail!(from self, with EventOpenError::ExceedsMaxNumberOfNodes,
                                 "{} since it would exceed the maximum number of supported nodes.", msg);
@@ -531,6 +638,11 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                 };
 
                 self.base.service_config.attributes = attributes.0.clone();
+                if self.base.sign_service_config().is_err() {
+                    fail!(from self, with EventCreateError::InternalFailure,
+                        "{} since the static config could not be signed.", msg);
+                }
+                self.base.create_attribute_index();
 
                 let service_config = fail!(from self, when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
                                             with EventCreateError::ServiceInCorruptedState,
@@ -580,6 +692,29 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
             warn!(from origin, "Setting the maximum amount of nodes to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_nodes = 1;
         }
+
+        if settings.max_payload_size > MAX_EVENT_PAYLOAD_SIZE {
+            warn!(from origin, "The maximum payload size of {} bytes exceeds the largest supported payload size. Adjust it to {}, the largest supported value.",
+                settings.max_payload_size, MAX_EVENT_PAYLOAD_SIZE);
+            settings.max_payload_size = MAX_EVENT_PAYLOAD_SIZE;
+        }
+
+        // the notifier index is encoded into the trigger id and the event id after the max
+        // event id is reserved to wake up the listener on shutdown
+        let event_id_max_value = (usize::MAX / settings.trigger_id_stride()).saturating_sub(2);
+        if settings.enable_notifier_origin && settings.event_id_max_value > event_id_max_value {
+            warn!(from origin, "The maximum event id value of {} exceeds the largest supported value when the notifier origin is enabled. Adjust it to {}, the largest supported value.",
+                settings.event_id_max_value, event_id_max_value);
+            settings.event_id_max_value = event_id_max_value;
+        }
+
+        if let Some(event_id) = settings.deadline_missed_event {
+            if event_id > settings.event_id_max_value {
+                warn!(from origin, "The deadline missed event id {} exceeds the maximum event id value. Adjust it to {}, the largest supported value.",
+                    event_id, settings.event_id_max_value);
+                settings.deadline_missed_event = Some(settings.event_id_max_value);
+            }
+        }
     }
 
     fn verify_service_configuration(
@@ -636,6 +771,14 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                 msg, existing_settings.max_nodes, required_settings.max_nodes);
         }
 
+        if self.verify_max_payload_size
+            && existing_settings.max_payload_size < required_settings.max_payload_size
+        {
+            fail!(from self, with EventOpenError::DoesNotSupportRequestedMaxPayloadSize,
+                "{} since the event supports only payloads of at most {} bytes but {} bytes are required.",
+                msg, existing_settings.max_payload_size, required_settings.max_payload_size);
+        }
+
         if self.verify_notifier_created_event
             && existing_settings.notifier_created_event != required_settings.notifier_created_event
         {
@@ -660,6 +803,14 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                 msg, existing_settings.notifier_dead_event, required_settings.notifier_dead_event);
         }
 
+        if self.verify_enable_notifier_origin
+            && existing_settings.enable_notifier_origin != required_settings.enable_notifier_origin
+        {
+            fail!(from self, with EventOpenError::IncompatibleNotifierOriginSetting,
+                "{} since the service has an incompatible notifier origin setting.",
+                msg);
+        }
+
         if self.verify_deadline
             && existing_settings.deadline.map(|v| v.value)
                 != required_settings.deadline.map(|v| v.value)
@@ -669,6 +820,14 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                 msg, existing_settings.deadline, required_settings.deadline);
         }
 
+        if self.verify_deadline_missed_event
+            && existing_settings.deadline_missed_event != required_settings.deadline_missed_event
+        {
+            fail!(from self, with EventOpenError::IncompatibleDeadline,
+                "{} since the deadline_missed_event id is {:?} but the value {:?} is required.",
+                msg, existing_settings.deadline_missed_event, required_settings.deadline_missed_event);
+        }
+
         Ok(*existing_settings)
     }
 }
@@ -30,10 +30,13 @@ use crate::service::dynamic_config::RegisterNodeResult;
 use crate::service::static_config::*;
 use core::fmt::Debug;
 use core::marker::PhantomData;
+use core::time::Duration;
 use iceoryx2_bb_elementary::enum_gen;
 use iceoryx2_bb_log::fail;
 use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_log::warn;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_posix::clock::{ClockType, Time};
 use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
 use iceoryx2_cal::dynamic_storage::DynamicStorageOpenError;
 use iceoryx2_cal::dynamic_storage::{DynamicStorage, DynamicStorageBuilder};
@@ -46,9 +49,11 @@ use iceoryx2_cal::static_storage::*;
 extern crate alloc;
 use alloc::sync::Arc;
 
+use super::config_scheme::attribute_index_config;
 use super::config_scheme::dynamic_config_storage_config;
 use super::config_scheme::service_tag_config;
 use super::config_scheme::static_config_storage_config;
+use super::partition::Partition;
 use super::service_name::ServiceName;
 use super::Service;
 
@@ -67,7 +72,8 @@ enum_gen! {
     OpenDynamicStorageFailure
   entry:
     IsMarkedForDestruction,
-    ExceedsMaxNumberOfNodes
+    ExceedsMaxNumberOfNodes,
+    IsFrozen
   mapping:
     DynamicStorageOpenError
 }
@@ -96,6 +102,53 @@ impl core::fmt::Display for ReadStaticStorageFailure {
 
 impl core::error::Error for ReadStaticStorageFailure {}
 
+/// Tracks the time that is left for an `open_or_create` call when the user has defined a
+/// timeout with [`Builder::timeout()`].
+#[derive(Debug, Clone, Copy)]
+struct TimeoutBudget {
+    timeout: Option<Duration>,
+    start: Option<Time>,
+}
+
+impl TimeoutBudget {
+    fn new(timeout: Option<Duration>) -> Self {
+        Self {
+            timeout,
+            start: None,
+        }
+    }
+
+    fn start(&mut self) {
+        if self.timeout.is_none() {
+            return;
+        }
+
+        match Time::now_with_clock(ClockType::Monotonic) {
+            Ok(now) => self.start = Some(now),
+            Err(e) => {
+                warn!(from "TimeoutBudget::start()",
+                    "Unable to acquire the current time, the timeout is considered as exceeded ({:?}).", e);
+                self.timeout = Some(Duration::ZERO);
+            }
+        }
+    }
+
+    fn remaining(&self) -> Option<Duration> {
+        let timeout = self.timeout?;
+        match self.start {
+            None => Some(timeout),
+            Some(start) => match start.elapsed() {
+                Ok(elapsed) => Some(timeout.saturating_sub(elapsed)),
+                Err(_) => Some(Duration::ZERO),
+            },
+        }
+    }
+
+    fn has_expired(&self) -> bool {
+        self.remaining() == Some(Duration::ZERO)
+    }
+}
+
 /// Builder to create or open [`Service`]s
 ///
 /// # Example
@@ -105,6 +158,8 @@ impl core::error::Error for ReadStaticStorageFailure {}
 pub struct Builder<S: Service> {
     name: ServiceName,
     shared_node: Arc<SharedNode<S>>,
+    timeout: Option<Duration>,
+    partition: Option<Partition>,
     _phantom_s: PhantomData<S>,
 }
 
@@ -113,21 +168,45 @@ impl<S: Service> Builder<S> {
         Self {
             name: name.clone(),
             shared_node,
+            timeout: None,
+            partition: None,
             _phantom_s: PhantomData,
         }
     }
 
+    /// Defines the maximum time an `open_or_create` call of the messaging pattern specific
+    /// builder is allowed to take. The timeout is applied across all retries and the opening
+    /// of the underlying resources of the [`Service`]. When it is exceeded the call fails
+    /// with a `TimedOut` error. By default, only the retry limit applies.
+    pub fn timeout(mut self, value: Duration) -> Self {
+        self.timeout = Some(value);
+        self
+    }
+
+    /// Defines the [`Partition`] the [`Service`] belongs to when it is created. When an existing
+    /// [`Service`] is opened, it keeps the [`Partition`] it was created with.
+    pub fn partition(mut self, value: &Partition) -> Self {
+        self.partition = Some(value.clone());
+        self
+    }
+
+    fn with_partition(&self, mut static_config: StaticConfig) -> StaticConfig {
+        static_config.partition = self.partition.clone();
+        static_config
+    }
+
     /// Create a new builder to create a
     /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse) [`Service`].
-    pub fn request_response<RequestPayload: Debug, ResponsePayload: Debug>(
+    pub fn request_response<RequestPayload: Debug + ?Sized, ResponsePayload: Debug + ?Sized>(
         self,
     ) -> request_response::Builder<RequestPayload, (), ResponsePayload, (), S> {
         BuilderWithServiceType::new(
-            StaticConfig::new_request_response::<S::ServiceNameHasher>(
+            self.with_partition(StaticConfig::new_request_response::<S::ServiceNameHasher>(
                 &self.name,
                 self.shared_node.config(),
-            ),
+            )),
             self.shared_node,
+            self.timeout,
         )
         .request_response::<RequestPayload, ResponsePayload>()
     }
@@ -138,21 +217,43 @@ impl<S: Service> Builder<S> {
         self,
     ) -> publish_subscribe::Builder<PayloadType, (), S> {
         BuilderWithServiceType::new(
-            StaticConfig::new_publish_subscribe::<S::ServiceNameHasher>(
+            self.with_partition(StaticConfig::new_publish_subscribe::<S::ServiceNameHasher>(
                 &self.name,
                 self.shared_node.config(),
-            ),
+            )),
             self.shared_node,
+            self.timeout,
         )
         .publish_subscribe()
     }
 
+    /// Create a new builder to create a
+    /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) [`Service`]
+    /// whose payload is serialized with serde into a `[u8]` slice. It supports payload types
+    /// with heap indirections, like [`String`] or [`Vec`], when the serialization cost is
+    /// acceptable. The ports are created with
+    /// [`PortFactoryPublisher::create_serde_publisher()`](crate::service::port_factory::publisher::PortFactoryPublisher::create_serde_publisher())
+    /// and
+    /// [`PortFactorySubscriber::create_serde_subscriber()`](crate::service::port_factory::subscriber::PortFactorySubscriber::create_serde_subscriber()).
+    pub fn publish_subscribe_serde<
+        PayloadType: Debug + serde::Serialize + serde::de::DeserializeOwned,
+    >(
+        self,
+    ) -> publish_subscribe::Builder<[u8], (), S> {
+        self.publish_subscribe::<[u8]>()
+            .serialized_payload::<PayloadType>()
+    }
+
     /// Create a new builder to create a
     /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event) [`Service`].
     pub fn event(self) -> event::Builder<S> {
         BuilderWithServiceType::new(
-            StaticConfig::new_event::<S::ServiceNameHasher>(&self.name, self.shared_node.config()),
+            self.with_partition(StaticConfig::new_event::<S::ServiceNameHasher>(
+                &self.name,
+                self.shared_node.config(),
+            )),
             self.shared_node,
+            self.timeout,
         )
         .event()
     }
@@ -163,19 +264,34 @@ impl<S: Service> Builder<S> {
 pub struct BuilderWithServiceType<ServiceType: service::Service> {
     service_config: StaticConfig,
     shared_node: Arc<SharedNode<ServiceType>>,
+    timeout: TimeoutBudget,
     _phantom_data: PhantomData<ServiceType>,
 }
 
 impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
-    fn new(service_config: StaticConfig, shared_node: Arc<SharedNode<ServiceType>>) -> Self {
+    fn new(
+        service_config: StaticConfig,
+        shared_node: Arc<SharedNode<ServiceType>>,
+        timeout: Option<Duration>,
+    ) -> Self {
         Self {
             service_config,
             shared_node,
+            timeout: TimeoutBudget::new(timeout),
             _phantom_data: PhantomData,
         }
     }
 
-    fn request_response<RequestPayload: Debug, ResponsePayload: Debug>(
+    // the creation timeout of the config bounded by the remaining timeout of the builder
+    fn creation_timeout(&self) -> Duration {
+        let creation_timeout = self.shared_node.config().global.service.creation_timeout;
+        match self.timeout.remaining() {
+            Some(remaining) => creation_timeout.min(remaining),
+            None => creation_timeout,
+        }
+    }
+
+    fn request_response<RequestPayload: Debug + ?Sized, ResponsePayload: Debug + ?Sized>(
         self,
     ) -> request_response::Builder<RequestPayload, (), ResponsePayload, (), ServiceType> {
         request_response::Builder::new(self)
@@ -198,7 +314,7 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         let static_storage_config =
             static_config_storage_config::<ServiceType>(self.shared_node.config());
         let file_name_uuid = self.service_config.service_id().0.into();
-        let creation_timeout = self.shared_node.config().global.service.creation_timeout;
+        let creation_timeout = self.creation_timeout();
 
         match <ServiceType::StaticStorage as NamedConceptMgmt>::does_exist_cfg(
             &file_name_uuid,
@@ -309,7 +425,7 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
                 >>::Builder<'_> as NamedConceptBuilder<
                     ServiceType::DynamicStorage,
                 >>::new(&self.service_config.service_id().0.into())
-                    .timeout(self.shared_node.config().global.service.creation_timeout)
+                    .timeout(self.creation_timeout())
                     .config(&dynamic_config_storage_config::<ServiceType>(self.shared_node.config()))
                 .has_ownership(false)
                 .open(),
@@ -318,6 +434,11 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         self.shared_node
             .registered_services()
             .add_or(self.service_config.service_id(), || {
+                if storage.get().is_frozen() {
+                    fail!(from self, with OpenDynamicStorageFailure::IsFrozen,
+                        "{} since the service is frozen and does not accept new nodes.", msg);
+                }
+
                 let node_id = self.shared_node.id();
                 match storage.get().register_node_id(*node_id) {
                     Ok(handle) => Ok(handle),
@@ -359,6 +480,74 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         }
     }
 
+    fn sign_service_config(&mut self) -> Result<(), signature::SignatureError> {
+        match &self.shared_node.config().global.service.signing_key {
+            Some(key) => signature::sign::<ServiceType>(&mut self.service_config, key),
+            None => Ok(()),
+        }
+    }
+
+    fn verify_service_config_signature(
+        &self,
+        service_config: &StaticConfig,
+    ) -> Result<(), signature::SignatureError> {
+        let config = &self.shared_node.config().global.service;
+        if !config.verify_service_signatures {
+            return Ok(());
+        }
+
+        signature::verify::<ServiceType>(service_config, &config.trusted_keys)
+    }
+
+    // The attribute index is only an optimization for the discovery, therefore a failure is not
+    // fatal, the discovery falls back to the static config.
+    fn create_attribute_index(&self) {
+        let msg = "Unable to create the attribute index of the service";
+        let attribute_index_config =
+            attribute_index_config::<ServiceType>(self.shared_node.config());
+        let file_name_uuid = self.service_config.service_id().0.into();
+
+        let content = match ServiceType::ConfigSerializer::serialize(
+            &self.service_config.attributes,
+        ) {
+            Ok(content) => content,
+            Err(e) => {
+                warn!(from self, "{} since the attributes could not be serialized ({:?}).", msg, e);
+                return;
+            }
+        };
+
+        for _ in 0..RETRY_LIMIT {
+            match <<ServiceType::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<
+                ServiceType::StaticStorage,
+            >>::new(&file_name_uuid)
+            .config(&attribute_index_config)
+            .has_ownership(false)
+            .create(&content)
+            {
+                Ok(_) => return,
+                Err(StaticStorageCreateError::AlreadyExists) => {
+                    // stale attribute index of a previous instance of the service
+                    if let Err(e) = unsafe {
+                        <ServiceType::StaticStorage as NamedConceptMgmt>::remove_cfg(
+                            &file_name_uuid,
+                            &attribute_index_config,
+                        )
+                    } {
+                        warn!(from self, "{} since the stale attribute index could not be removed ({:?}).", msg, e);
+                        return;
+                    }
+                }
+                Err(e) => {
+                    warn!(from self, "{} ({:?}).", msg, e);
+                    return;
+                }
+            }
+        }
+
+        warn!(from self, "{} since a stale attribute index could not be replaced.", msg);
+    }
+
     fn create_static_config_storage(
         &self,
     ) -> Result<<ServiceType::StaticStorage as StaticStorage>::Locked, StaticStorageCreateError>
@@ -15,6 +15,7 @@
 //! See [`crate::service`]
 //!
 use core::marker::PhantomData;
+use core::time::Duration;
 
 use crate::service;
 use crate::service::dynamic_config::publish_subscribe::DynamicConfigSettings;
@@ -24,6 +25,7 @@ use crate::service::static_config::messaging_pattern::MessagingPattern;
 use crate::service::*;
 use builder::RETRY_LIMIT;
 use iceoryx2_bb_elementary::alignment::Alignment;
+use iceoryx2_bb_elementary::versioned_user_header::VersionedUserHeader;
 use iceoryx2_bb_log::{fail, fatal_panic, warn};
 use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
 use iceoryx2_cal::serialize::Serialize;
@@ -73,6 +75,16 @@ pub enum PublishSubscribeOpenError {
     DoesNotSupportRequestedAmountOfNodes,
     /// The [`Service`] required overflow behavior is not compatible.
     IncompatibleOverflowBehavior,
+    /// The [`Service`] required payload checksum setting is not compatible.
+    IncompatiblePayloadChecksumSetting,
+    /// The [`Service`] required global ordering setting is not compatible.
+    IncompatibleGlobalOrderingSetting,
+    /// The [`Service`] required broadcast setting is not compatible.
+    IncompatibleBroadcastSetting,
+    /// The [`Service`] required priority lanes setting is not compatible.
+    IncompatiblePriorityLanesSetting,
+    /// The [`Service`] required replay setting is not compatible.
+    IncompatibleReplaySetting,
     /// The process has not enough permissions to open the [`Service`]
     InsufficientPermissions,
     /// Some underlying resources of the [`Service`] are either missing, corrupted or unaccessible.
@@ -86,6 +98,13 @@ pub enum PublishSubscribeOpenError {
     /// When the call creation call is repeated with a little delay the [`Service`] should be
     /// recreatable.
     IsMarkedForDestruction,
+    /// The [`Service`] was frozen with [`Service::freeze_partition()`] and does not accept new
+    /// [`Node`](crate::node::Node)s until it is unfrozen.
+    IsFrozen,
+    /// The [`Service`] was not created by a trusted creator. Either its static config is not
+    /// signed with one of the trusted keys or the signature is invalid, see
+    /// [`crate::service::static_config::signature`].
+    SignatureVerificationFailed,
 }
 
 impl core::fmt::Display for PublishSubscribeOpenError {
@@ -185,6 +204,9 @@ pub enum PublishSubscribeOpenOrCreateError {
     /// Can occur when another process creates and removes the same [`Service`] repeatedly with a
     /// high frequency.
     SystemInFlux,
+    /// The timeout defined with [`crate::service::builder::Builder::timeout()`] was exceeded
+    /// before the [`Service`] could be opened or created.
+    TimedOut,
 }
 
 impl From<ServiceAvailabilityState> for PublishSubscribeOpenOrCreateError {
@@ -224,12 +246,20 @@ pub struct Builder<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: serv
     override_alignment: Option<usize>,
     override_payload_type: Option<TypeDetail>,
     override_user_header_type: Option<TypeDetail>,
+    override_payload_type_descriptor: Option<String>,
+    user_header_version: Option<u16>,
     verify_number_of_subscribers: bool,
     verify_number_of_publishers: bool,
     verify_subscriber_max_buffer_size: bool,
     verify_subscriber_max_borrowed_samples: bool,
+    verify_max_total_borrowed_samples: bool,
     verify_publisher_history_size: bool,
     verify_enable_safe_overflow: bool,
+    verify_enable_payload_checksum: bool,
+    verify_enable_global_ordering: bool,
+    verify_enable_broadcast: bool,
+    verify_enable_priority_lanes: bool,
+    verify_enable_replay: bool,
     verify_max_nodes: bool,
     _data: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
@@ -246,11 +276,19 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
             verify_subscriber_max_buffer_size: false,
             verify_publisher_history_size: false,
             verify_subscriber_max_borrowed_samples: false,
+            verify_max_total_borrowed_samples: false,
             verify_enable_safe_overflow: false,
+            verify_enable_payload_checksum: false,
+            verify_enable_global_ordering: false,
+            verify_enable_broadcast: false,
+            verify_enable_priority_lanes: false,
+            verify_enable_replay: false,
             verify_max_nodes: false,
             override_alignment: None,
             override_payload_type: None,
             override_user_header_type: None,
+            override_payload_type_descriptor: None,
+            user_header_version: None,
             _data: PhantomData,
             _user_header: PhantomData,
         };
@@ -309,6 +347,22 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
         unsafe { core::mem::transmute::<Self, Builder<Payload, M, ServiceType>>(self) }
     }
 
+    /// Sets the user header type of the [`Service`] to a [`VersionedUserHeader`], usually
+    /// implemented with `#[derive(UserHeader)]`. When an existing [`Service`] is opened, the
+    /// user header is compatible when it is the same type in the same or an older version
+    /// than the one the [`Service`] was created with. Older versions ignore the unknown
+    /// trailing fields, see
+    /// [`Sample::user_header_with_defaults()`](crate::sample::Sample::user_header_with_defaults())
+    /// for newer versions that receive samples of older
+    /// [`Publisher`](crate::port::publisher::Publisher)s.
+    pub fn versioned_user_header<M: Debug + VersionedUserHeader>(
+        self,
+    ) -> Builder<Payload, M, ServiceType> {
+        let mut builder = self.user_header::<M>();
+        builder.user_header_version = Some(M::VERSION);
+        builder
+    }
+
     /// If the [`Service`] is created, it defines the [`Alignment`] of the payload for the service. If
     /// an existing [`Service`] is opened it requires the service to have at least the defined
     /// [`Alignment`]. If the Payload [`Alignment`] is greater than the provided [`Alignment`]
@@ -326,6 +380,111 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
         self
     }
 
+    /// If the [`Service`] is created, defines if every [`crate::sample::Sample`] carries a
+    /// CRC-32C checksum of its payload that is verified on
+    /// [`crate::port::subscriber::Subscriber::receive()`]. It detects corrupted payloads caused
+    /// by faulty hardware or by processes that write into samples they do not own. If an
+    /// existing [`Service`] is opened it requires the service to have the defined setting.
+    pub fn enable_payload_checksum(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_payload_checksum = value;
+        self.verify_enable_payload_checksum = true;
+        self
+    }
+
+    /// If the [`Service`] is created, defines if every [`crate::sample::Sample`] is stamped with
+    /// a monotonic service-wide sequence number when it is sent. It allows a
+    /// [`crate::port::subscriber::Subscriber`] that receives from multiple
+    /// [`crate::port::publisher::Publisher`]s to restore the send order or to detect
+    /// interleaving, see
+    /// [`Header::global_sequence_number()`](crate::service::header::publish_subscribe::Header::global_sequence_number()).
+    /// If an existing [`Service`] is opened it requires the service to have the defined setting.
+    pub fn enable_global_ordering(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_global_ordering = value;
+        self.verify_enable_global_ordering = true;
+        self
+    }
+
+    /// If the [`Service`] is created, defines if every [`crate::port::publisher::Publisher`]
+    /// submits a [`crate::sample::Sample`] once into a single ring buffer that is shared by all
+    /// [`crate::port::subscriber::Subscriber`]s instead of sending it over a dedicated connection
+    /// to every [`crate::port::subscriber::Subscriber`]. It reduces the cost of a send with many
+    /// [`crate::port::subscriber::Subscriber`]s. All [`crate::port::subscriber::Subscriber`]s
+    /// share the buffer size of [`Builder::subscriber_max_buffer_size()`], a custom
+    /// [`crate::port::subscriber::Subscriber`] buffer size and delivery filters are not applied.
+    /// If an existing [`Service`] is opened it requires the service to have the defined setting.
+    pub fn enable_broadcast(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_broadcast = value;
+        self.verify_enable_broadcast = true;
+        self
+    }
+
+    /// If the [`Service`] is created, defines if every connection between a
+    /// [`crate::port::publisher::Publisher`] and a [`crate::port::subscriber::Subscriber`] has
+    /// a second buffer, the high-priority lane, with the size of the
+    /// [`crate::port::subscriber::Subscriber`] buffer. Samples that are sent with
+    /// [`crate::sample_mut::SampleMut::send_with_priority()`] and [`Priority::High`](crate::port::publisher::Priority::High)
+    /// are stored in it and received before all samples with
+    /// [`Priority::Normal`](crate::port::publisher::Priority::Normal). The priority lanes are
+    /// not available when broadcasting is enabled with [`Builder::enable_broadcast()`].
+    /// If an existing [`Service`] is opened it requires the service to have the defined setting.
+    pub fn enable_priority_lanes(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_priority_lanes = value;
+        self.verify_enable_priority_lanes = true;
+        self
+    }
+
+    /// If the [`Service`] is created, defines the maximum payload size in bytes up to which a
+    /// [`crate::sample::Sample`] is copied directly into the connection of every
+    /// [`crate::port::subscriber::Subscriber`] instead of being delivered as reference into the
+    /// data segment of the [`crate::port::publisher::Publisher`]. It trades a copy for the
+    /// reference counting of the sample and reduces the latency of small samples. Samples with
+    /// an alignment larger than 8 and samples that are sent exclusively are always delivered
+    /// by reference. When broadcasting is enabled with [`Builder::enable_broadcast()`] the
+    /// setting is ignored. By default, it is 0 and inline delivery is disabled. If an existing
+    /// [`Service`] is opened, the setting of the existing [`Service`] is used.
+    pub fn inline_delivery_threshold(mut self, value: usize) -> Self {
+        self.config_details_mut().inline_delivery_threshold = value;
+        self
+    }
+
+    /// If the [`Service`] is created, defines if every [`crate::sample::Sample`] is stamped with
+    /// the monotonic point in time when it was sent, see
+    /// [`Header::send_timestamp()`](crate::service::header::publish_subscribe::Header::send_timestamp()).
+    /// The timestamp is required by [`crate::sample::Sample::latency()`],
+    /// [`FairnessPolicy::OldestTimestampFirst`](crate::service::port_factory::subscriber::FairnessPolicy::OldestTimestampFirst)
+    /// and to compare the samples of different [`crate::port::publisher::Publisher`]s in
+    /// [`crate::port::subscriber::Subscriber::receive_latest()`]. Without it, the
+    /// [`crate::port::publisher::Publisher`] does not read the clock on send. By default, it is
+    /// disabled. If an existing [`Service`] is opened, the setting of the existing [`Service`]
+    /// is used.
+    pub fn enable_send_timestamp(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_send_timestamp = value;
+        self
+    }
+
+    /// If the [`Service`] is created, defines the lifetime of a [`crate::sample::Sample`].
+    /// Samples whose send timestamp is older than the lifetime are stale and are skipped and
+    /// released by [`crate::port::subscriber::Subscriber::receive()`]. The lifetime is measured
+    /// with the monotonic clock of the system and enables the send timestamp implicitly, see
+    /// [`Builder::enable_send_timestamp()`]. By default, samples never become stale. If an
+    /// existing [`Service`] is opened, the setting of the existing [`Service`] is used.
+    pub fn sample_lifetime(mut self, value: Duration) -> Self {
+        self.config_details_mut().sample_lifetime = Some(value);
+        self
+    }
+
+    /// If the [`Service`] is created, defines if
+    /// [`crate::port::publisher::Publisher::publish_raw()`] is allowed to re-inject captured
+    /// samples with their original [`Header`](crate::service::header::publish_subscribe::Header),
+    /// including timestamps and sequence numbers. It is intended for recorders and network
+    /// gateways. If an existing [`Service`] is opened it requires the service to have the
+    /// defined setting.
+    pub fn enable_replay(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_replay = value;
+        self.verify_enable_replay = true;
+        self
+    }
+
     /// If the [`Service`] is created it defines how many [`crate::sample::Sample`] a
     /// [`crate::port::subscriber::Subscriber`] can borrow at most in parallel. If an existing
     /// [`Service`] is opened it defines the minimum required.
@@ -335,6 +494,18 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
         self
     }
 
+    /// If the [`Service`] is created it defines how many [`crate::sample::Sample`]s all
+    /// [`crate::port::subscriber::Subscriber`]s together can borrow at most in parallel. It
+    /// reduces the size of the data segment of every [`crate::port::publisher::Publisher`]
+    /// when it is smaller than the sum of all
+    /// [`Builder::subscriber_max_borrowed_samples()`]. If an existing [`Service`] is opened it
+    /// defines the minimum required.
+    pub fn max_total_borrowed_samples(mut self, value: usize) -> Self {
+        self.config_details_mut().max_total_borrowed_samples = Some(value);
+        self.verify_max_total_borrowed_samples = true;
+        self
+    }
+
     /// If the [`Service`] is created it defines the maximum history size a
     /// [`crate::port::subscriber::Subscriber`] can request on connection. If an existing
     /// [`Service`] is opened it defines the minimum required.
@@ -391,6 +562,12 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
             settings.subscriber_max_borrowed_samples = 1;
         }
 
+        if settings.max_total_borrowed_samples == Some(0) {
+            warn!(from origin,
+                "Setting the maximum total borrowed samples to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_total_borrowed_samples = Some(1);
+        }
+
         if settings.subscriber_max_buffer_size == 0 {
             warn!(from origin,
                 "Setting the subscribers buffer size to 0 is not supported. Adjust it to 1, the smallest supported value.");
@@ -482,6 +659,19 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                                 msg, existing_settings.subscriber_max_borrowed_samples, required_settings.subscriber_max_borrowed_samples);
         }
 
+        if self.verify_max_total_borrowed_samples {
+            if let (Some(existing), Some(required)) = (
+                existing_settings.max_total_borrowed_samples,
+                required_settings.max_total_borrowed_samples,
+            ) {
+                if existing < required {
+                    fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedMinSubscriberBorrowedSamples,
+                                "{} since the service supports only {} borrowed samples in total but {} borrowed samples in total were requested.",
+                                msg, existing, required);
+                }
+            }
+        }
+
         if self.verify_enable_safe_overflow
             && existing_settings.enable_safe_overflow != required_settings.enable_safe_overflow
         {
@@ -490,6 +680,47 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                                 msg);
         }
 
+        if self.verify_enable_payload_checksum
+            && existing_settings.enable_payload_checksum
+                != required_settings.enable_payload_checksum
+        {
+            fail!(from self, with PublishSubscribeOpenError::IncompatiblePayloadChecksumSetting,
+                                "{} since the service has an incompatible payload checksum setting.",
+                                msg);
+        }
+
+        if self.verify_enable_global_ordering
+            && existing_settings.enable_global_ordering != required_settings.enable_global_ordering
+        {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleGlobalOrderingSetting,
+                                "{} since the service has an incompatible global ordering setting.",
+                                msg);
+        }
+
+        if self.verify_enable_broadcast
+            && existing_settings.enable_broadcast != required_settings.enable_broadcast
+        {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleBroadcastSetting,
+                                "{} since the service has an incompatible broadcast setting.",
+                                msg);
+        }
+
+        if self.verify_enable_priority_lanes
+            && existing_settings.enable_priority_lanes != required_settings.enable_priority_lanes
+        {
+            fail!(from self, with PublishSubscribeOpenError::IncompatiblePriorityLanesSetting,
+                                "{} since the service has an incompatible priority lanes setting.",
+                                msg);
+        }
+
+        if self.verify_enable_replay
+            && existing_settings.enable_replay != required_settings.enable_replay
+        {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleReplaySetting,
+                                "{} since the service has an incompatible replay setting.",
+                                msg);
+        }
+
         if self.verify_max_nodes && existing_settings.max_nodes < required_settings.max_nodes {
             fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes,
                                 "{} since the service supports only {} nodes but {} are required.",
@@ -576,6 +807,11 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                 };
 
                 self.base.service_config.attributes = attributes.0.clone();
+                if self.base.sign_service_config().is_err() {
+                    fail!(from self, with PublishSubscribeCreateError::InternalFailure,
+                        "{} since the static config could not be signed.", msg);
+                }
+                self.base.create_attribute_index();
                 let service_config = fail!(from self,
                             when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
                             with PublishSubscribeCreateError::ServiceInCorruptedState,
@@ -624,6 +860,15 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                         "{} since the service does not exist.", msg);
                 }
                 Some((static_config, static_storage)) => {
+                    if self
+                        .base
+                        .verify_service_config_signature(&static_config)
+                        .is_err()
+                    {
+                        fail!(from self, with PublishSubscribeOpenError::SignatureVerificationFailed,
+                            "{} since the service was not created by a trusted creator.", msg);
+                    }
+
                     let pub_sub_static_config =
                         self.verify_service_configuration(&static_config, attributes)?;
 
@@ -637,6 +882,10 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                             fail!(from self, with PublishSubscribeOpenError::IsMarkedForDestruction,
                                 "{} since the service is marked for destruction.", msg);
                         }
+                        Err(OpenDynamicStorageFailure::IsFrozen) => {
+                            fail!(from self, with PublishSubscribeOpenError::IsFrozen,
+                                "{} since the service is frozen.", msg);
+                        }
                         Err(OpenDynamicStorageFailure::ExceedsMaxNumberOfNodes) => {
                             fail!(from self, with PublishSubscribeOpenError::ExceedsMaxNumberOfNodes,
                                 "{} since it would exceed the maximum number of supported nodes.", msg);
@@ -694,8 +943,16 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
     > {
         let msg = "Unable to open or create publish subscribe service";
 
+        self.base.timeout.start();
+        let timeout = self.base.timeout;
+
         let mut retry_count = 0;
         loop {
+            if timeout.has_expired() {
+                fail!(from self, with PublishSubscribeOpenOrCreateError::TimedOut,
+                    "{} since the timeout was exceeded.", msg);
+            }
+
             if RETRY_LIMIT < retry_count {
                 fail!(from self,
                       with PublishSubscribeOpenOrCreateError::SystemInFlux,
@@ -704,11 +961,28 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
             }
             retry_count += 1;
 
-            match self.is_service_available(msg)? {
+            let is_service_available = match self.is_service_available(msg) {
+                Ok(v) => v,
+                Err(e) => {
+                    if timeout.has_expired() {
+                        fail!(from self, with PublishSubscribeOpenOrCreateError::TimedOut,
+                            "{} since the timeout was exceeded while checking the service availability ({:?}).", msg, e);
+                    }
+                    return Err(e.into());
+                }
+            };
+
+            match is_service_available {
                 Some(_) => match self.open_impl(attributes) {
                     Ok(factory) => return Ok(factory),
                     Err(PublishSubscribeOpenError::DoesNotExist) => continue,
-                    Err(e) => return Err(e.into()),
+                    Err(e) => {
+                        if timeout.has_expired() {
+                            fail!(from self, with PublishSubscribeOpenOrCreateError::TimedOut,
+                                "{} since the timeout was exceeded while opening the service ({:?}).", msg, e);
+                        }
+                        return Err(e.into());
+                    }
                 },
                 None => {
                     match self.create_impl(&AttributeSpecifier(attributes.attributes().clone())) {
@@ -717,7 +991,13 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                         | Err(PublishSubscribeCreateError::IsBeingCreatedByAnotherInstance) => {
                             continue;
                         }
-                        Err(e) => return Err(e.into()),
+                        Err(e) => {
+                            if timeout.has_expired() {
+                                fail!(from self, with PublishSubscribeOpenOrCreateError::TimedOut,
+                                    "{} since the timeout was exceeded while creating the service ({:?}).", msg, e);
+                            }
+                            return Err(e.into());
+                        }
                     }
                 }
             }
@@ -739,6 +1019,18 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
     }
 }
 
+impl<ServiceType: service::Service> Builder<[u8], (), ServiceType> {
+    pub(crate) fn serialized_payload<Payload>(mut self) -> Self {
+        self.override_payload_type = Some(TypeDetail {
+            variant: TypeVariant::Dynamic,
+            type_name: format!("serialized<{}>", core::any::type_name::<Payload>()),
+            size: 1,
+            alignment: 1,
+        });
+        self
+    }
+}
+
 impl<UserHeader: Debug, ServiceType: service::Service>
     Builder<[CustomPayloadMarker], UserHeader, ServiceType>
 {
@@ -747,6 +1039,12 @@ impl<UserHeader: Debug, ServiceType: service::Service>
         self.override_payload_type = Some(value.clone());
         self
     }
+
+    #[doc(hidden)]
+    pub unsafe fn __internal_set_payload_type_descriptor(mut self, value: &str) -> Self {
+        self.override_payload_type_descriptor = Some(value.to_string());
+        self
+    }
 }
 
 impl<Payload: Debug + ?Sized, ServiceType: service::Service>
@@ -757,6 +1055,58 @@ impl<Payload: Debug + ?Sized, ServiceType: service::Service>
         self.override_user_header_type = Some(value.clone());
         self
     }
+
+    /// Defines the size of the user header in bytes when the size is known only at runtime,
+    /// e.g. for language bindings or gateways that attach variable metadata. If the
+    /// [`Service`] is opened, it requires the same user header size. The user header is
+    /// accessed as bytes with
+    /// [`SampleMut::user_header_bytes_mut()`](crate::sample_mut::SampleMut::user_header_bytes_mut())
+    /// and [`Sample::user_header_bytes()`](crate::sample::Sample::user_header_bytes()).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::service::builder::publish_subscribe::CustomHeaderMarker;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .user_header::<CustomHeaderMarker>()
+    ///     .user_header_size(24)
+    ///     .open_or_create()?;
+    ///
+    /// let publisher = service.publisher_builder().create()?;
+    /// let mut sample = publisher.loan()?;
+    /// sample.user_header_bytes_mut().copy_from_slice(b"content-type: text/plain");
+    /// sample.send()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn user_header_size(mut self, size: usize) -> Self {
+        let alignment = self
+            .override_user_header_type
+            .as_ref()
+            .map_or(1, |details| details.alignment);
+        self.override_user_header_type =
+            Some(TypeDetail::new_runtime_sized_user_header(size, alignment));
+        self
+    }
+
+    /// Defines the [`Alignment`] of the runtime-sized user header, see
+    /// [`Builder::user_header_size()`]. By default it is [`Alignment::ALIGN_1`].
+    pub fn user_header_alignment(mut self, alignment: Alignment) -> Self {
+        let size = self
+            .override_user_header_type
+            .as_ref()
+            .map_or(0, |details| details.size);
+        self.override_user_header_type = Some(TypeDetail::new_runtime_sized_user_header(
+            size,
+            alignment.value(),
+        ));
+        self
+    }
 }
 
 impl<Payload: Debug, UserHeader: Debug, ServiceType: service::Service>
@@ -774,6 +1124,13 @@ impl<Payload: Debug, UserHeader: Debug, ServiceType: service::Service>
             self.config_details_mut().message_type_details.user_header = details.clone();
         }
 
+        self.config_details_mut()
+            .message_type_details
+            .payload_type_descriptor = self.override_payload_type_descriptor.clone();
+        self.config_details_mut()
+            .message_type_details
+            .user_header_version = self.user_header_version;
+
         self.adjust_payload_alignment();
     }
 
@@ -866,6 +1223,13 @@ impl<Payload: Debug, UserHeader: Debug, ServiceType: service::Service>
             self.config_details_mut().message_type_details.user_header = details.clone();
         }
 
+        self.config_details_mut()
+            .message_type_details
+            .payload_type_descriptor = self.override_payload_type_descriptor.clone();
+        self.config_details_mut()
+            .message_type_details
+            .user_header_version = self.user_header_version;
+
         self.adjust_payload_alignment();
     }
 
@@ -26,7 +26,7 @@ use iceoryx2_cal::dynamic_storage::{DynamicStorageCreateError, DynamicStorageOpe
 use iceoryx2_cal::serialize::Serialize;
 use iceoryx2_cal::static_storage::{StaticStorage, StaticStorageCreateError, StaticStorageLocked};
 
-use super::message_type_details::{MessageTypeDetails, TypeVariant};
+use super::message_type_details::{MessageTypeDetails, TypeDetail, TypeVariant};
 use super::{ServiceState, RETRY_LIMIT};
 
 /// Errors that can occur when an existing [`MessagingPattern::RequestResponse`] [`Service`] shall
@@ -62,6 +62,8 @@ pub enum RequestResponseOpenError {
     IncompatibleRequestType,
     /// The [`Service`] has the wrong response payload type, response header type or type alignment.
     IncompatibleResponseType,
+    /// The [`Service`] has a different error response type or no error response type at all.
+    IncompatibleErrorType,
     /// The [`AttributeVerifier`] required attributes that the [`Service`] does not satisfy.
     IncompatibleAttributes,
     /// The [`Service`] has the wrong messaging pattern.
@@ -70,6 +72,8 @@ pub enum RequestResponseOpenError {
     IncompatibleOverflowBehaviorForRequests,
     /// The [`Service`] required overflow behavior for responses is not compatible.
     IncompatibleOverflowBehaviorForResponses,
+    /// The [`Service`] does not have the required fire and forget request setting.
+    IncompatibleFireAndForgetRequests,
     /// The process has not enough permissions to open the [`Service`].
     InsufficientPermissions,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
@@ -78,8 +82,15 @@ pub enum RequestResponseOpenError {
     /// When the call creation call is repeated with a little delay the [`Service`] should be
     /// recreatable.
     IsMarkedForDestruction,
+    /// The [`Service`] was frozen with [`Service::freeze_partition()`] and does not accept new
+    /// [`Node`](crate::node::Node)s until it is unfrozen.
+    IsFrozen,
     /// Some underlying resources of the [`Service`] are either missing, corrupted or unaccessible.
     ServiceInCorruptedState,
+    /// The [`Service`] was not created by a trusted creator. Either its static config is not
+    /// signed with one of the trusted keys or the signature is invalid, see
+    /// [`crate::service::static_config::signature`].
+    SignatureVerificationFailed,
 }
 
 impl core::fmt::Display for RequestResponseOpenError {
@@ -99,6 +110,9 @@ impl From<ServiceAvailabilityState> for RequestResponseOpenError {
             ServiceAvailabilityState::IncompatibleResponseType => {
                 RequestResponseOpenError::IncompatibleResponseType
             }
+            ServiceAvailabilityState::IncompatibleErrorType => {
+                RequestResponseOpenError::IncompatibleErrorType
+            }
             ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleMessagingPattern) => {
                 RequestResponseOpenError::IncompatibleMessagingPattern
             }
@@ -146,6 +160,7 @@ impl From<ServiceAvailabilityState> for RequestResponseCreateError {
         match value {
             ServiceAvailabilityState::IncompatibleRequestType
             | ServiceAvailabilityState::IncompatibleResponseType
+            | ServiceAvailabilityState::IncompatibleErrorType
             | ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleMessagingPattern) => {
                 RequestResponseCreateError::AlreadyExists
             }
@@ -173,6 +188,9 @@ pub enum RequestResponseOpenOrCreateError {
     /// Can occur when another process creates and removes the same [`Service`] repeatedly with a
     /// high frequency.
     SystemInFlux,
+    /// The timeout defined with [`crate::service::builder::Builder::timeout()`] was exceeded
+    /// before the [`Service`] could be opened or created.
+    TimedOut,
 }
 
 impl From<ServiceAvailabilityState> for RequestResponseOpenOrCreateError {
@@ -206,6 +224,7 @@ enum ServiceAvailabilityState {
     ServiceState(ServiceState),
     IncompatibleRequestType,
     IncompatibleResponseType,
+    IncompatibleErrorType,
 }
 
 /// Builder to create new [`MessagingPattern::RequestResponse`] based [`Service`]s
@@ -215,17 +234,19 @@ enum ServiceAvailabilityState {
 /// See [`crate::service`]
 #[derive(Debug)]
 pub struct Builder<
-    RequestPayload: Debug,
+    RequestPayload: Debug + ?Sized,
     RequestHeader: Debug,
-    ResponsePayload: Debug,
+    ResponsePayload: Debug + ?Sized,
     ResponseHeader: Debug,
     ServiceType: Service,
 > {
     base: builder::BuilderWithServiceType<ServiceType>,
     override_request_alignment: Option<usize>,
     override_response_alignment: Option<usize>,
+    error_payload_type_details: Option<TypeDetail>,
     verify_enable_safe_overflow_for_requests: bool,
     verify_enable_safe_overflow_for_responses: bool,
+    verify_enable_fire_and_forget_requests: bool,
     verify_max_active_responses: bool,
     verify_max_active_requests: bool,
     verify_max_borrowed_responses: bool,
@@ -243,9 +264,9 @@ pub struct Builder<
 }
 
 impl<
-        RequestPayload: Debug,
+        RequestPayload: Debug + ?Sized,
         RequestHeader: Debug,
-        ResponsePayload: Debug,
+        ResponsePayload: Debug + ?Sized,
         ResponseHeader: Debug,
         ServiceType: Service,
     > Builder<RequestPayload, RequestHeader, ResponsePayload, ResponseHeader, ServiceType>
@@ -255,8 +276,10 @@ impl<
             base,
             override_request_alignment: None,
             override_response_alignment: None,
+            error_payload_type_details: None,
             verify_enable_safe_overflow_for_requests: false,
             verify_enable_safe_overflow_for_responses: false,
+            verify_enable_fire_and_forget_requests: false,
             verify_max_active_responses: false,
             verify_max_active_requests: false,
             verify_max_borrowed_responses: false,
@@ -315,6 +338,21 @@ impl<
         }
     }
 
+    /// Defines a dedicated error payload type of the [`Service`]. A
+    /// [`Server`](crate::port::server::Server) can then answer a request either with a response
+    /// or with an error response, so that errors do not have to be encoded into the response
+    /// payload. The error response uses the same response user header.
+    /// The [`Server`](crate::port::server::Server) sends it with
+    /// [`ActiveRequest::send_error()`](crate::active_request::ActiveRequest::send_error()) and
+    /// the [`Client`](crate::port::client::Client) receives it with
+    /// [`PendingResponse::receive_result()`](crate::pending_response::PendingResponse::receive_result()).
+    /// If an existing [`Service`] is opened it requires the service to have the same error type.
+    pub fn error_type<E: Debug>(mut self) -> Self {
+        self.error_payload_type_details =
+            Some(TypeDetail::__internal_new::<E>(TypeVariant::FixedSize));
+        self
+    }
+
     /// If the [`Service`] is created, it defines the request [`Alignment`] of the payload for the
     /// service. If an existing [`Service`] is opened it requires the service to have at least the
     /// defined [`Alignment`]. If the Payload [`Alignment`] is greater than the provided
@@ -351,6 +389,18 @@ impl<
         self
     }
 
+    /// If the [`Service`] is created, defines if [`Client`](crate::port::client::Client)s can
+    /// send fire and forget requests with
+    /// [`RequestMut::send_and_forget()`](crate::request_mut::RequestMut::send_and_forget()).
+    /// Those requests do not expect any response and therefore do not occupy an active
+    /// request slot of the [`Client`](crate::port::client::Client).
+    /// If an existing [`Service`] is opened it requires the service to have the defined setting.
+    pub fn enable_fire_and_forget_requests(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_fire_and_forget_requests = value;
+        self.verify_enable_fire_and_forget_requests = true;
+        self
+    }
+
     /// Defines how many active responses a [`Client`](crate::port::client::Client) can hold in
     /// parallel. The objects are used to receive the samples to a request that was sent earlier
     /// to a [`Server`](crate::port::server::Server)
@@ -534,6 +584,15 @@ impl<
                 msg);
         }
 
+        if self.verify_enable_fire_and_forget_requests
+            && existing_configuration.enable_fire_and_forget_requests
+                != required_configuration.enable_fire_and_forget_requests
+        {
+            fail!(from self, with RequestResponseOpenError::IncompatibleFireAndForgetRequests,
+                "{} since the service has an incompatible fire and forget request setting.",
+                msg);
+        }
+
         if self.verify_max_active_responses
             && existing_configuration.max_active_responses
                 < required_configuration.max_active_responses
@@ -646,6 +705,13 @@ impl<
                         self.config_details().response_message_type_details);
                 }
 
+                if !self.has_compatible_error_type(config.request_response()) {
+                    fail!(from self, with ServiceAvailabilityState::IncompatibleErrorType,
+                        "{} since the services uses the error type \"{:?}\" which is not compatible to the requested type \"{:?}\".",
+                        error_msg, &config.request_response().error_message_type_details,
+                        self.config_details().error_message_type_details);
+                }
+
                 Ok(Some((config, storage)))
             }
             Ok(None) => Ok(None),
@@ -653,10 +719,33 @@ impl<
         }
     }
 
+    fn has_compatible_error_type(
+        &self,
+        existing_configuration: &static_config::request_response::StaticConfig,
+    ) -> bool {
+        match (
+            &self.config_details().error_message_type_details,
+            &existing_configuration.error_message_type_details,
+        ) {
+            (None, None) => true,
+            (Some(required), Some(existing)) => required.is_compatible_to(existing),
+            _ => false,
+        }
+    }
+
     fn create_impl(
         &mut self,
         attributes: &AttributeSpecifier,
-    ) -> Result<request_response::PortFactory<ServiceType>, RequestResponseCreateError> {
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseCreateError,
+    > {
         let msg = "Unable to create request response service";
         self.adjust_configuration_to_meaningful_values();
 
@@ -724,6 +813,11 @@ impl<
                 };
 
                 self.base.service_config.attributes = attributes.0.clone();
+                if self.base.sign_service_config().is_err() {
+                    fail!(from self, with RequestResponseCreateError::InternalFailure,
+                        "{} since the static config could not be signed.", msg);
+                }
+                self.base.create_attribute_index();
                 let serialized_service_config = fail!(from self,
                           when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
                           with RequestResponseCreateError::ServiceInCorruptedState,
@@ -756,7 +850,16 @@ impl<
     fn open_impl(
         &mut self,
         attributes: &AttributeVerifier,
-    ) -> Result<request_response::PortFactory<ServiceType>, RequestResponseOpenError> {
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseOpenError,
+    > {
         const OPEN_RETRY_LIMIT: usize = 5;
         let msg = "Unable to open request response service";
 
@@ -769,6 +872,15 @@ impl<
                         msg);
                 }
                 Some((static_config, static_storage)) => {
+                    if self
+                        .base
+                        .verify_service_config_signature(&static_config)
+                        .is_err()
+                    {
+                        fail!(from self, with RequestResponseOpenError::SignatureVerificationFailed,
+                            "{} since the service was not created by a trusted creator.", msg);
+                    }
+
                     let request_response_static_config =
                         self.verify_service_configuration(&static_config, attributes)?;
 
@@ -783,6 +895,10 @@ impl<
                                 "{} since the service is marked for destruction.",
                                 msg);
                         }
+                        Err(OpenDynamicStorageFailure::IsFrozen) => {
+                            fail!(from self, with RequestResponseOpenError::IsFrozen,
+                                "{} since the service is frozen.", msg);
+                        }
                         Err(OpenDynamicStorageFailure::ExceedsMaxNumberOfNodes) => {
                             fail!(from self, with RequestResponseOpenError::ExceedsMaxNumberOfNodes,
                                 "{} since it would exceed the maximum number of supported nodes.",
@@ -836,11 +952,28 @@ impl<
     fn open_or_create_impl(
         mut self,
         attributes: &AttributeVerifier,
-    ) -> Result<request_response::PortFactory<ServiceType>, RequestResponseOpenOrCreateError> {
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseOpenOrCreateError,
+    > {
         let msg = "Unable to open or create request response service";
 
+        self.base.timeout.start();
+        let timeout = self.base.timeout;
+
         let mut retry_count = 0;
         loop {
+            if timeout.has_expired() {
+                fail!(from self, with RequestResponseOpenOrCreateError::TimedOut,
+                    "{} since the timeout was exceeded.", msg);
+            }
+
             if RETRY_LIMIT < retry_count {
                 fail!(from self,
                       with RequestResponseOpenOrCreateError::SystemInFlux,
@@ -849,11 +982,28 @@ impl<
             }
             retry_count += 1;
 
-            match self.is_service_available(msg)? {
+            let is_service_available = match self.is_service_available(msg) {
+                Ok(v) => v,
+                Err(e) => {
+                    if timeout.has_expired() {
+                        fail!(from self, with RequestResponseOpenOrCreateError::TimedOut,
+                            "{} since the timeout was exceeded while checking the service availability ({:?}).", msg, e);
+                    }
+                    return Err(e.into());
+                }
+            };
+
+            match is_service_available {
                 Some(_) => match self.open_impl(attributes) {
                     Ok(factory) => return Ok(factory),
                     Err(RequestResponseOpenError::DoesNotExist) => continue,
-                    Err(e) => return Err(e.into()),
+                    Err(e) => {
+                        if timeout.has_expired() {
+                            fail!(from self, with RequestResponseOpenOrCreateError::TimedOut,
+                                "{} since the timeout was exceeded while opening the service ({:?}).", msg, e);
+                        }
+                        return Err(e.into());
+                    }
                 },
                 None => {
                     match self.create_impl(&AttributeSpecifier(attributes.attributes().clone())) {
@@ -862,25 +1012,37 @@ impl<
                         | Err(RequestResponseCreateError::IsBeingCreatedByAnotherInstance) => {
                             continue;
                         }
-                        Err(e) => return Err(e.into()),
+                        Err(e) => {
+                            if timeout.has_expired() {
+                                fail!(from self, with RequestResponseOpenOrCreateError::TimedOut,
+                                    "{} since the timeout was exceeded while creating the service ({:?}).", msg, e);
+                            }
+                            return Err(e.into());
+                        }
                     }
                 }
             }
         }
     }
 
-    fn prepare_message_type_details(&mut self) {
-        self.config_details_mut().request_message_type_details = MessageTypeDetails::from::<
-            header::request_response::RequestHeader,
-            RequestHeader,
-            RequestPayload,
-        >(TypeVariant::FixedSize);
-
-        self.config_details_mut().response_message_type_details = MessageTypeDetails::from::<
-            header::request_response::ResponseHeader,
-            ResponseHeader,
-            ResponsePayload,
-        >(TypeVariant::FixedSize);
+    fn set_message_type_details(
+        &mut self,
+        request_message_type_details: MessageTypeDetails,
+        response_message_type_details: MessageTypeDetails,
+    ) {
+        self.config_details_mut().request_message_type_details = request_message_type_details;
+        self.config_details_mut().response_message_type_details = response_message_type_details;
+
+        self.config_details_mut().error_message_type_details =
+            self.error_payload_type_details.clone().map(|payload| {
+                let mut details = MessageTypeDetails::from::<
+                    header::request_response::ResponseHeader,
+                    ResponseHeader,
+                    (),
+                >(TypeVariant::FixedSize);
+                details.payload = payload;
+                details
+            });
 
         if let Some(alignment) = self.override_request_alignment {
             self.config_details_mut()
@@ -906,12 +1068,311 @@ impl<
                 .max(alignment);
         }
     }
+}
+
+impl<
+        RequestPayload: Debug,
+        RequestHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseHeader: Debug,
+        ServiceType: Service,
+    > Builder<RequestPayload, RequestHeader, ResponsePayload, ResponseHeader, ServiceType>
+{
+    fn prepare_message_type_details(&mut self) {
+        self.set_message_type_details(
+            MessageTypeDetails::from::<
+                header::request_response::RequestHeader,
+                RequestHeader,
+                RequestPayload,
+            >(TypeVariant::FixedSize),
+            MessageTypeDetails::from::<
+                header::request_response::ResponseHeader,
+                ResponseHeader,
+                ResponsePayload,
+            >(TypeVariant::FixedSize),
+        );
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created.
+    pub fn open_or_create(
+        self,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseOpenOrCreateError,
+    > {
+        self.open_or_create_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. It defines a set of attributes.
+    ///
+    /// If the [`Service`] already exists all attribute requirements must be satisfied,
+    /// and service payload type must be the same, otherwise the open process will fail.
+    /// If the [`Service`] does not exist the required attributes will be defined in the [`Service`].
+    pub fn open_or_create_with_attributes(
+        mut self,
+        required_attributes: &AttributeVerifier,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseOpenOrCreateError,
+    > {
+        self.prepare_message_type_details();
+        self.open_or_create_impl(required_attributes)
+    }
+
+    /// Opens an existing [`Service`].
+    pub fn open(
+        self,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseOpenError,
+    > {
+        self.open_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// Opens an existing [`Service`] with attribute requirements. If the defined attribute
+    /// requirements are not satisfied the open process will fail.
+    pub fn open_with_attributes(
+        mut self,
+        required_attributes: &AttributeVerifier,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseOpenError,
+    > {
+        self.prepare_message_type_details();
+        self.open_impl(required_attributes)
+    }
+
+    /// Creates a new [`Service`].
+    pub fn create(
+        self,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseCreateError,
+    > {
+        self.create_with_attributes(&AttributeSpecifier::new())
+    }
+
+    /// Creates a new [`Service`] with a set of attributes.
+    pub fn create_with_attributes(
+        mut self,
+        attributes: &AttributeSpecifier,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseCreateError,
+    > {
+        self.prepare_message_type_details();
+        self.create_impl(attributes)
+    }
+}
+
+impl<
+        RequestPayload: Debug,
+        RequestHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseHeader: Debug,
+        ServiceType: Service,
+    > Builder<[RequestPayload], RequestHeader, ResponsePayload, ResponseHeader, ServiceType>
+{
+    fn prepare_message_type_details(&mut self) {
+        self.set_message_type_details(
+            MessageTypeDetails::from::<
+                header::request_response::RequestHeader,
+                RequestHeader,
+                RequestPayload,
+            >(TypeVariant::Dynamic),
+            MessageTypeDetails::from::<
+                header::request_response::ResponseHeader,
+                ResponseHeader,
+                ResponsePayload,
+            >(TypeVariant::FixedSize),
+        );
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created.
+    pub fn open_or_create(
+        self,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            [RequestPayload],
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseOpenOrCreateError,
+    > {
+        self.open_or_create_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. It defines a set of attributes.
+    ///
+    /// If the [`Service`] already exists all attribute requirements must be satisfied,
+    /// and service payload type must be the same, otherwise the open process will fail.
+    /// If the [`Service`] does not exist the required attributes will be defined in the [`Service`].
+    pub fn open_or_create_with_attributes(
+        mut self,
+        required_attributes: &AttributeVerifier,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            [RequestPayload],
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseOpenOrCreateError,
+    > {
+        self.prepare_message_type_details();
+        self.open_or_create_impl(required_attributes)
+    }
+
+    /// Opens an existing [`Service`].
+    pub fn open(
+        self,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            [RequestPayload],
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseOpenError,
+    > {
+        self.open_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// Opens an existing [`Service`] with attribute requirements. If the defined attribute
+    /// requirements are not satisfied the open process will fail.
+    pub fn open_with_attributes(
+        mut self,
+        required_attributes: &AttributeVerifier,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            [RequestPayload],
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseOpenError,
+    > {
+        self.prepare_message_type_details();
+        self.open_impl(required_attributes)
+    }
+
+    /// Creates a new [`Service`].
+    pub fn create(
+        self,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            [RequestPayload],
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseCreateError,
+    > {
+        self.create_with_attributes(&AttributeSpecifier::new())
+    }
+
+    /// Creates a new [`Service`] with a set of attributes.
+    pub fn create_with_attributes(
+        mut self,
+        attributes: &AttributeSpecifier,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            [RequestPayload],
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseCreateError,
+    > {
+        self.prepare_message_type_details();
+        self.create_impl(attributes)
+    }
+}
+
+impl<
+        RequestPayload: Debug,
+        RequestHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseHeader: Debug,
+        ServiceType: Service,
+    > Builder<RequestPayload, RequestHeader, [ResponsePayload], ResponseHeader, ServiceType>
+{
+    fn prepare_message_type_details(&mut self) {
+        self.set_message_type_details(
+            MessageTypeDetails::from::<
+                header::request_response::RequestHeader,
+                RequestHeader,
+                RequestPayload,
+            >(TypeVariant::FixedSize),
+            MessageTypeDetails::from::<
+                header::request_response::ResponseHeader,
+                ResponseHeader,
+                ResponsePayload,
+            >(TypeVariant::Dynamic),
+        );
+    }
 
     /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
     /// created.
     pub fn open_or_create(
         self,
-    ) -> Result<request_response::PortFactory<ServiceType>, RequestResponseOpenOrCreateError> {
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            [ResponsePayload],
+            ResponseHeader,
+        >,
+        RequestResponseOpenOrCreateError,
+    > {
         self.open_or_create_with_attributes(&AttributeVerifier::new())
     }
 
@@ -924,7 +1385,16 @@ impl<
     pub fn open_or_create_with_attributes(
         mut self,
         required_attributes: &AttributeVerifier,
-    ) -> Result<request_response::PortFactory<ServiceType>, RequestResponseOpenOrCreateError> {
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            [ResponsePayload],
+            ResponseHeader,
+        >,
+        RequestResponseOpenOrCreateError,
+    > {
         self.prepare_message_type_details();
         self.open_or_create_impl(required_attributes)
     }
@@ -932,7 +1402,16 @@ impl<
     /// Opens an existing [`Service`].
     pub fn open(
         self,
-    ) -> Result<request_response::PortFactory<ServiceType>, RequestResponseOpenError> {
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            [ResponsePayload],
+            ResponseHeader,
+        >,
+        RequestResponseOpenError,
+    > {
         self.open_with_attributes(&AttributeVerifier::new())
     }
 
@@ -941,7 +1420,16 @@ impl<
     pub fn open_with_attributes(
         mut self,
         required_attributes: &AttributeVerifier,
-    ) -> Result<request_response::PortFactory<ServiceType>, RequestResponseOpenError> {
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            [ResponsePayload],
+            ResponseHeader,
+        >,
+        RequestResponseOpenError,
+    > {
         self.prepare_message_type_details();
         self.open_impl(required_attributes)
     }
@@ -949,7 +1437,16 @@ impl<
     /// Creates a new [`Service`].
     pub fn create(
         self,
-    ) -> Result<request_response::PortFactory<ServiceType>, RequestResponseCreateError> {
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            [ResponsePayload],
+            ResponseHeader,
+        >,
+        RequestResponseCreateError,
+    > {
         self.create_with_attributes(&AttributeSpecifier::new())
     }
 
@@ -957,7 +1454,149 @@ impl<
     pub fn create_with_attributes(
         mut self,
         attributes: &AttributeSpecifier,
-    ) -> Result<request_response::PortFactory<ServiceType>, RequestResponseCreateError> {
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            [ResponsePayload],
+            ResponseHeader,
+        >,
+        RequestResponseCreateError,
+    > {
+        self.prepare_message_type_details();
+        self.create_impl(attributes)
+    }
+}
+
+impl<
+        RequestPayload: Debug,
+        RequestHeader: Debug,
+        ResponsePayload: Debug,
+        ResponseHeader: Debug,
+        ServiceType: Service,
+    > Builder<[RequestPayload], RequestHeader, [ResponsePayload], ResponseHeader, ServiceType>
+{
+    fn prepare_message_type_details(&mut self) {
+        self.set_message_type_details(
+            MessageTypeDetails::from::<
+                header::request_response::RequestHeader,
+                RequestHeader,
+                RequestPayload,
+            >(TypeVariant::Dynamic),
+            MessageTypeDetails::from::<
+                header::request_response::ResponseHeader,
+                ResponseHeader,
+                ResponsePayload,
+            >(TypeVariant::Dynamic),
+        );
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created.
+    pub fn open_or_create(
+        self,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            [RequestPayload],
+            RequestHeader,
+            [ResponsePayload],
+            ResponseHeader,
+        >,
+        RequestResponseOpenOrCreateError,
+    > {
+        self.open_or_create_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. It defines a set of attributes.
+    ///
+    /// If the [`Service`] already exists all attribute requirements must be satisfied,
+    /// and service payload type must be the same, otherwise the open process will fail.
+    /// If the [`Service`] does not exist the required attributes will be defined in the [`Service`].
+    pub fn open_or_create_with_attributes(
+        mut self,
+        required_attributes: &AttributeVerifier,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            [RequestPayload],
+            RequestHeader,
+            [ResponsePayload],
+            ResponseHeader,
+        >,
+        RequestResponseOpenOrCreateError,
+    > {
+        self.prepare_message_type_details();
+        self.open_or_create_impl(required_attributes)
+    }
+
+    /// Opens an existing [`Service`].
+    pub fn open(
+        self,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            [RequestPayload],
+            RequestHeader,
+            [ResponsePayload],
+            ResponseHeader,
+        >,
+        RequestResponseOpenError,
+    > {
+        self.open_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// Opens an existing [`Service`] with attribute requirements. If the defined attribute
+    /// requirements are not satisfied the open process will fail.
+    pub fn open_with_attributes(
+        mut self,
+        required_attributes: &AttributeVerifier,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            [RequestPayload],
+            RequestHeader,
+            [ResponsePayload],
+            ResponseHeader,
+        >,
+        RequestResponseOpenError,
+    > {
+        self.prepare_message_type_details();
+        self.open_impl(required_attributes)
+    }
+
+    /// Creates a new [`Service`].
+    pub fn create(
+        self,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            [RequestPayload],
+            RequestHeader,
+            [ResponsePayload],
+            ResponseHeader,
+        >,
+        RequestResponseCreateError,
+    > {
+        self.create_with_attributes(&AttributeSpecifier::new())
+    }
+
+    /// Creates a new [`Service`] with a set of attributes.
+    pub fn create_with_attributes(
+        mut self,
+        attributes: &AttributeSpecifier,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            [RequestPayload],
+            RequestHeader,
+            [ResponsePayload],
+            ResponseHeader,
+        >,
+        RequestResponseCreateError,
+    > {
         self.prepare_message_type_details();
         self.create_impl(attributes)
     }
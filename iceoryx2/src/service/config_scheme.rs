@@ -12,6 +12,7 @@
 
 use crate::{config, node::NodeId};
 use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_cal::named_concept::{NamedConceptConfiguration, NamedConceptMgmt};
 
 pub(crate) fn dynamic_config_storage_config<Service: crate::service::Service>(
@@ -39,6 +40,13 @@ pub(crate) fn static_config_storage_config<Service: crate::service::Service>(
         .path_hint(&path_hint)
 }
 
+pub(crate) fn attribute_index_config<Service: crate::service::Service>(
+    global_config: &config::Config,
+) -> <Service::StaticStorage as NamedConceptMgmt>::Configuration {
+    static_config_storage_config::<Service>(global_config)
+        .suffix(&global_config.global.service.attribute_index_suffix)
+}
+
 pub(crate) fn connection_config<Service: crate::service::Service>(
     global_config: &config::Config,
 ) -> <Service::Connection as NamedConceptMgmt>::Configuration {
@@ -48,6 +56,15 @@ pub(crate) fn connection_config<Service: crate::service::Service>(
         .path_hint(global_config.global.root_path())
 }
 
+pub(crate) fn broadcast_config<Service: crate::service::Service>(
+    global_config: &config::Config,
+) -> <Service::Broadcast as NamedConceptMgmt>::Configuration {
+    <<Service::Broadcast as NamedConceptMgmt>::Configuration>::default()
+        .prefix(&global_config.global.prefix)
+        .suffix(&global_config.global.service.broadcast_suffix)
+        .path_hint(global_config.global.root_path())
+}
+
 pub(crate) fn event_config<Service: crate::service::Service>(
     global_config: &config::Config,
 ) -> <Service::Event as NamedConceptMgmt>::Configuration {
@@ -105,6 +122,14 @@ pub(crate) fn node_details_config<Service: crate::service::Service>(
         .path_hint(&node_details_path(global_config, node_id))
 }
 
+pub(crate) fn namespace_reservation_config<Service: crate::service::Service>(
+    global_config: &config::Config,
+) -> <Service::StaticStorage as NamedConceptMgmt>::Configuration {
+    <<Service::StaticStorage as NamedConceptMgmt>::Configuration>::default()
+        .suffix(&FileName::new(b".namespace").unwrap())
+        .path_hint(global_config.global.root_path())
+}
+
 pub(crate) fn service_tag_config<Service: crate::service::Service>(
     global_config: &config::Config,
     node_id: &NodeId,
@@ -0,0 +1,353 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::control_channel::ControlMessage;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! // every participant acquires its own handle to the control channel
+//! let control_sender = service.control_channel();
+//! let control_receiver = service.control_channel();
+//!
+//! // please lower your rate to 100 samples per second
+//! const LOWER_RATE: u32 = 1;
+//! control_sender.send(ControlMessage::new(LOWER_RATE, 100))?;
+//!
+//! while let Some(message) = control_receiver.receive() {
+//!     println!("received control message {} with value {}", message.id(), message.value());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::sync::atomic::Ordering;
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
+
+use crate::service::{self, ServiceState};
+
+/// The number of [`ControlMessage`]s the control channel of a [`Service`](crate::service::Service)
+/// can hold. When more messages are sent before they are received the oldest messages are
+/// overridden.
+pub const CONTROL_CHANNEL_CAPACITY: usize = 16;
+
+/// The first [`ControlMessage::id()`] that is reserved for iceoryx2 internal purposes, like
+/// protocol negotiation. Users can only send messages with an id that is smaller.
+pub const CONTROL_MESSAGE_RESERVED_ID_START: u32 = 0xffff_0000;
+
+/// A small, fixed-size message that can be exchanged via the control channel of a
+/// [`Service`](crate::service::Service).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ControlMessage {
+    id: u32,
+    value: u64,
+}
+
+impl ControlMessage {
+    /// Creates a new [`ControlMessage`]. The meaning of `id` and `value` is defined by the user.
+    pub fn new(id: u32, value: u64) -> Self {
+        Self { id, value }
+    }
+
+    /// Returns the id of the [`ControlMessage`]
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Returns the value of the [`ControlMessage`]
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// Returns true if the [`ControlMessage`] is reserved for iceoryx2 internal purposes.
+    pub fn is_reserved(&self) -> bool {
+        CONTROL_MESSAGE_RESERVED_ID_START <= self.id
+    }
+}
+
+/// Defines a failure that can occur in [`ControlChannel::send()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ControlChannelSendError {
+    /// The [`ControlMessage::id()`] is in the range that is reserved for iceoryx2.
+    ReservedMessageId,
+}
+
+impl core::fmt::Display for ControlChannelSendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ControlChannelSendError::{:?}", self)
+    }
+}
+
+impl core::error::Error for ControlChannelSendError {}
+
+#[repr(C)]
+#[derive(Debug)]
+struct ControlChannelSlot {
+    // seqlock of the slot: 2 * position + 1 while the message of write position `position` is
+    // written, 2 * position + 2 when it is complete and 0 when the slot was never written
+    sequence: IoxAtomicU64,
+    // every word contains the lower 32 bits of the write position in its upper half so that a
+    // reader can detect words that a lapped sender wrote after a newer sender took over the slot
+    id: IoxAtomicU64,
+    value_low: IoxAtomicU64,
+    value_high: IoxAtomicU64,
+}
+
+impl ControlChannelSlot {
+    fn new() -> Self {
+        Self {
+            sequence: IoxAtomicU64::new(0),
+            id: IoxAtomicU64::new(0),
+            value_low: IoxAtomicU64::new(0),
+            value_high: IoxAtomicU64::new(0),
+        }
+    }
+
+    fn tagged(position: u64, word: u32) -> u64 {
+        (position << 32) | word as u64
+    }
+
+    fn untag(position: u64, word: u64) -> Option<u32> {
+        if word >> 32 == position & 0xffff_ffff {
+            Some(word as u32)
+        } else {
+            None
+        }
+    }
+
+    fn is_complete(&self, position: u64) -> bool {
+        2 * position + 2 <= self.sequence.load(Ordering::Acquire)
+    }
+}
+
+/// The shared memory part of the control channel that is stored in the
+/// [`DynamicConfig`](crate::service::dynamic_config::DynamicConfig) of every service. It is a
+/// lock-free broadcast ring buffer, every reader maintains its own read position. Every slot
+/// is protected by a seqlock. Senders never wait for each other: a sender that laps onto a slot
+/// that is still written takes it over and readers discard the message when the words of both
+/// senders were mixed. A sender that dies while writing therefore blocks neither other
+/// senders nor readers.
+#[repr(C)]
+#[derive(Debug)]
+pub(crate) struct ControlChannelData {
+    write_position: IoxAtomicU64,
+    slots: [ControlChannelSlot; CONTROL_CHANNEL_CAPACITY],
+}
+
+impl ControlChannelData {
+    pub(crate) fn new() -> Self {
+        Self {
+            write_position: IoxAtomicU64::new(0),
+            slots: core::array::from_fn(|_| ControlChannelSlot::new()),
+        }
+    }
+
+    pub(crate) fn write_position(&self) -> u64 {
+        self.write_position.load(Ordering::Acquire)
+    }
+
+    fn slot(&self, position: u64) -> &ControlChannelSlot {
+        &self.slots[position as usize % CONTROL_CHANNEL_CAPACITY]
+    }
+
+    pub(crate) fn push(&self, message: ControlMessage) {
+        let position = self.write_position.fetch_add(1, Ordering::AcqRel);
+        let slot = self.slot(position);
+
+        // the sequence only grows, when a sender with a newer write position already
+        // overrode the slot the message is lost like any other overridden message
+        if slot.sequence.fetch_max(2 * position + 1, Ordering::AcqRel) > 2 * position + 1 {
+            return;
+        }
+
+        core::sync::atomic::fence(Ordering::Release);
+        slot.id.store(
+            ControlChannelSlot::tagged(position, message.id),
+            Ordering::Relaxed,
+        );
+        slot.value_low.store(
+            ControlChannelSlot::tagged(position, message.value as u32),
+            Ordering::Relaxed,
+        );
+        slot.value_high.store(
+            ControlChannelSlot::tagged(position, (message.value >> 32) as u32),
+            Ordering::Relaxed,
+        );
+
+        // fails when a newer sender took over the slot in the meantime
+        let _ = slot.sequence.compare_exchange(
+            2 * position + 1,
+            2 * position + 2,
+            Ordering::Release,
+            Ordering::Relaxed,
+        );
+    }
+
+    /// Returns true when a message after `read_position` is already complete. Then the
+    /// message at `read_position` belongs to a sender that died or was interrupted while
+    /// writing and it is skipped instead of blocking the reader.
+    fn is_overtaken(&self, read_position: u64, write_position: u64) -> bool {
+        (read_position + 1..write_position)
+            .any(|position| self.slot(position).is_complete(position))
+    }
+
+    /// Tries to read the message at `read_position`. The position is advanced when a message
+    /// was read, when it was already overridden by a newer message or when it was torn.
+    pub(crate) fn pop(&self, read_position: &mut u64) -> Option<ControlMessage> {
+        loop {
+            let write_position = self.write_position();
+            if *read_position >= write_position {
+                return None;
+            }
+
+            // the reader was too slow and messages were overridden
+            if write_position - *read_position > CONTROL_CHANNEL_CAPACITY as u64 {
+                *read_position = write_position - CONTROL_CHANNEL_CAPACITY as u64;
+            }
+
+            let slot = self.slot(*read_position);
+            let expected_sequence = 2 * *read_position + 2;
+            let sequence = slot.sequence.load(Ordering::Acquire);
+
+            if sequence > expected_sequence {
+                // overridden by a newer message
+                *read_position += 1;
+                continue;
+            }
+
+            if sequence < expected_sequence {
+                if self.is_overtaken(*read_position, write_position) {
+                    *read_position += 1;
+                    continue;
+                }
+
+                // the sender has not yet finished writing the message
+                return None;
+            }
+
+            let id = slot.id.load(Ordering::Relaxed);
+            let value_low = slot.value_low.load(Ordering::Relaxed);
+            let value_high = slot.value_high.load(Ordering::Relaxed);
+            core::sync::atomic::fence(Ordering::Acquire);
+
+            let is_unchanged = slot.sequence.load(Ordering::Relaxed) == expected_sequence;
+            let position = *read_position;
+            *read_position += 1;
+
+            match (
+                is_unchanged,
+                ControlChannelSlot::untag(position, id),
+                ControlChannelSlot::untag(position, value_low),
+                ControlChannelSlot::untag(position, value_high),
+            ) {
+                (true, Some(id), Some(value_low), Some(value_high)) => {
+                    return Some(ControlMessage {
+                        id,
+                        value: ((value_high as u64) << 32) | value_low as u64,
+                    })
+                }
+                // the message was overridden while reading or torn by a lapped sender
+                _ => continue,
+            }
+        }
+    }
+}
+
+/// A handle to the low-latency control channel of a [`Service`](crate::service::Service). Every
+/// [`ControlMessage`] that is sent is received by all [`ControlChannel`]s of the
+/// [`Service`](crate::service::Service) that were created before the message was sent. It can
+/// be used for lightweight coordination between participants without creating a second
+/// [`Service`](crate::service::Service).
+///
+/// Acquired via the `control_channel()` method of the service, e.g.
+/// [`publish_subscribe::PortFactory::control_channel()`](crate::service::port_factory::publish_subscribe::PortFactory::control_channel()).
+pub struct ControlChannel<Service: service::Service> {
+    service_state: Arc<ServiceState<Service>>,
+    read_position: IoxAtomicU64,
+}
+
+impl<Service: service::Service> Debug for ControlChannel<Service> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "ControlChannel<{}> {{ service: {:?}, read_position: {} }}",
+            core::any::type_name::<Service>(),
+            self.service_state.static_config.name(),
+            self.read_position.load(Ordering::Relaxed)
+        )
+    }
+}
+
+impl<Service: service::Service> ControlChannel<Service> {
+    pub(crate) fn new(service_state: Arc<ServiceState<Service>>) -> Self {
+        let read_position = service_state
+            .dynamic_storage
+            .get()
+            .control_channel()
+            .write_position();
+
+        Self {
+            service_state,
+            read_position: IoxAtomicU64::new(read_position),
+        }
+    }
+
+    fn data(&self) -> &ControlChannelData {
+        self.service_state.dynamic_storage.get().control_channel()
+    }
+
+    /// Sends a [`ControlMessage`] to all [`ControlChannel`]s of the
+    /// [`Service`](crate::service::Service). Fails when the message id is reserved for
+    /// iceoryx2 internal purposes.
+    pub fn send(&self, message: ControlMessage) -> Result<(), ControlChannelSendError> {
+        if message.is_reserved() {
+            fail!(from self, with ControlChannelSendError::ReservedMessageId,
+                "Unable to send control message {:?} since the id is reserved for internal purposes.",
+                message);
+        }
+
+        self.data().push(message);
+        Ok(())
+    }
+
+    /// Receives the next [`ControlMessage`]. If no message is available [`None`] is returned.
+    /// When the [`ControlChannel`] is not polled frequently enough, the oldest messages are
+    /// overridden and lost.
+    pub fn receive(&self) -> Option<ControlMessage> {
+        let mut read_position = self.read_position.load(Ordering::Relaxed);
+        let message = self.data().pop(&mut read_position);
+        self.read_position.store(read_position, Ordering::Relaxed);
+        message
+    }
+
+    /// Returns true if at least one [`ControlMessage`] is available for
+    /// [`ControlChannel::receive()`].
+    pub fn has_messages(&self) -> bool {
+        self.read_position.load(Ordering::Relaxed) < self.data().write_position()
+    }
+}
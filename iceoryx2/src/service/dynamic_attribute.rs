@@ -0,0 +1,366 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Dynamic attributes are key-value pairs that are stored in the dynamic config of a
+//! [`Service`](crate::service::Service). In contrast to the
+//! [`attribute`](crate::service::attribute)s that are fixed when the service is created, they
+//! can be updated by every participant during the lifetime of the service, for instance to
+//! publish deployment metadata like the owner, the state or the active configuration.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! let writer = service.attributes_mut();
+//! let reader = service.attributes_mut();
+//!
+//! writer.set("state", "running")?;
+//!
+//! if reader.has_changed() {
+//!     for attribute in reader.list().iter() {
+//!         println!("key {}, value {}", attribute.key(), attribute.value());
+//!     }
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::sync::atomic::Ordering;
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{
+    IoxAtomicBool, IoxAtomicU16, IoxAtomicU64, IoxAtomicU8,
+};
+
+use crate::service::attribute::AttributeSet;
+use crate::service::{self, ServiceState};
+
+/// The maximum number of dynamic attributes a [`Service`](crate::service::Service) can hold.
+pub const DYNAMIC_ATTRIBUTE_CAPACITY: usize = 16;
+
+/// The maximum length in bytes of the key of a dynamic attribute.
+pub const DYNAMIC_ATTRIBUTE_KEY_LENGTH: usize = 64;
+
+/// The maximum length in bytes of the value of a dynamic attribute.
+pub const DYNAMIC_ATTRIBUTE_VALUE_LENGTH: usize = 256;
+
+/// Defines a failure that can occur in [`DynamicAttributes::set()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum DynamicAttributeSetError {
+    /// The key is empty.
+    EmptyKey,
+    /// The key is longer than [`DYNAMIC_ATTRIBUTE_KEY_LENGTH`].
+    KeyTooLong,
+    /// The value is longer than [`DYNAMIC_ATTRIBUTE_VALUE_LENGTH`].
+    ValueTooLong,
+    /// The key is new and all [`DYNAMIC_ATTRIBUTE_CAPACITY`] attributes are already in use.
+    ExceedsMaxNumberOfAttributes,
+}
+
+impl core::fmt::Display for DynamicAttributeSetError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "DynamicAttributeSetError::{:?}", self)
+    }
+}
+
+impl core::error::Error for DynamicAttributeSetError {}
+
+#[repr(C)]
+#[derive(Debug)]
+struct DynamicAttributeSlot {
+    is_used: IoxAtomicBool,
+    key_len: IoxAtomicU16,
+    value_len: IoxAtomicU16,
+    key: [IoxAtomicU8; DYNAMIC_ATTRIBUTE_KEY_LENGTH],
+    value: [IoxAtomicU8; DYNAMIC_ATTRIBUTE_VALUE_LENGTH],
+}
+
+impl DynamicAttributeSlot {
+    fn new() -> Self {
+        Self {
+            is_used: IoxAtomicBool::new(false),
+            key_len: IoxAtomicU16::new(0),
+            value_len: IoxAtomicU16::new(0),
+            key: core::array::from_fn(|_| IoxAtomicU8::new(0)),
+            value: core::array::from_fn(|_| IoxAtomicU8::new(0)),
+        }
+    }
+
+    fn store(&self, key: &[u8], value: &[u8]) {
+        for (dst, src) in self.key.iter().zip(key) {
+            dst.store(*src, Ordering::Relaxed);
+        }
+        for (dst, src) in self.value.iter().zip(value) {
+            dst.store(*src, Ordering::Relaxed);
+        }
+        self.key_len.store(key.len() as u16, Ordering::Relaxed);
+        self.value_len.store(value.len() as u16, Ordering::Relaxed);
+        self.is_used.store(true, Ordering::Relaxed);
+    }
+
+    fn load_key(&self) -> Vec<u8> {
+        let len = (self.key_len.load(Ordering::Relaxed) as usize).min(DYNAMIC_ATTRIBUTE_KEY_LENGTH);
+        self.key[..len]
+            .iter()
+            .map(|v| v.load(Ordering::Relaxed))
+            .collect()
+    }
+
+    fn load_value(&self) -> Vec<u8> {
+        let len =
+            (self.value_len.load(Ordering::Relaxed) as usize).min(DYNAMIC_ATTRIBUTE_VALUE_LENGTH);
+        self.value[..len]
+            .iter()
+            .map(|v| v.load(Ordering::Relaxed))
+            .collect()
+    }
+
+    fn has_key(&self, key: &[u8]) -> bool {
+        self.is_used.load(Ordering::Relaxed) && self.load_key() == key
+    }
+}
+
+/// The shared memory part of the dynamic attributes that is stored in the
+/// [`DynamicConfig`](crate::service::dynamic_config::DynamicConfig) of every service. The
+/// attributes are protected by a sequence lock, the `change_counter` is odd while an attribute
+/// is written and is increased with every change.
+#[repr(C)]
+#[derive(Debug)]
+pub(crate) struct DynamicAttributeData {
+    change_counter: IoxAtomicU64,
+    slots: [DynamicAttributeSlot; DYNAMIC_ATTRIBUTE_CAPACITY],
+}
+
+impl DynamicAttributeData {
+    pub(crate) fn new() -> Self {
+        Self {
+            change_counter: IoxAtomicU64::new(0),
+            slots: core::array::from_fn(|_| DynamicAttributeSlot::new()),
+        }
+    }
+
+    pub(crate) fn change_counter(&self) -> u64 {
+        self.change_counter.load(Ordering::Acquire)
+    }
+
+    fn lock(&self) -> u64 {
+        loop {
+            let counter = self.change_counter.load(Ordering::Relaxed);
+            if counter % 2 == 0
+                && self
+                    .change_counter
+                    .compare_exchange_weak(
+                        counter,
+                        counter + 1,
+                        Ordering::Acquire,
+                        Ordering::Relaxed,
+                    )
+                    .is_ok()
+            {
+                core::sync::atomic::fence(Ordering::Release);
+                return counter + 1;
+            }
+            core::hint::spin_loop();
+        }
+    }
+
+    fn unlock(&self, counter: u64) {
+        self.change_counter.store(counter + 1, Ordering::Release);
+    }
+
+    /// Adds the attribute or replaces the value of an existing key. Returns false when the key
+    /// is new and all slots are in use.
+    pub(crate) fn set(&self, key: &[u8], value: &[u8]) -> bool {
+        let counter = self.lock();
+
+        let slot = match self.slots.iter().find(|slot| slot.has_key(key)) {
+            Some(slot) => Some(slot),
+            None => self
+                .slots
+                .iter()
+                .find(|slot| !slot.is_used.load(Ordering::Relaxed)),
+        };
+
+        let ret_val = match slot {
+            Some(slot) => {
+                slot.store(key, value);
+                true
+            }
+            None => false,
+        };
+
+        self.unlock(counter);
+        ret_val
+    }
+
+    pub(crate) fn remove(&self, key: &[u8]) -> bool {
+        let counter = self.lock();
+
+        let ret_val = match self.slots.iter().find(|slot| slot.has_key(key)) {
+            Some(slot) => {
+                slot.is_used.store(false, Ordering::Relaxed);
+                true
+            }
+            None => false,
+        };
+
+        self.unlock(counter);
+        ret_val
+    }
+
+    /// Returns a consistent snapshot of all attributes together with the change counter the
+    /// snapshot belongs to.
+    pub(crate) fn snapshot(&self) -> (u64, Vec<(Vec<u8>, Vec<u8>)>) {
+        loop {
+            let counter = self.change_counter();
+            if counter % 2 == 1 {
+                // a writer is currently updating the attributes
+                core::hint::spin_loop();
+                continue;
+            }
+
+            let attributes = self
+                .slots
+                .iter()
+                .filter(|slot| slot.is_used.load(Ordering::Relaxed))
+                .map(|slot| (slot.load_key(), slot.load_value()))
+                .collect();
+            core::sync::atomic::fence(Ordering::Acquire);
+
+            if self.change_counter.load(Ordering::Relaxed) == counter {
+                return (counter, attributes);
+            }
+        }
+    }
+}
+
+/// A handle to the dynamic attributes of a [`Service`](crate::service::Service). Every
+/// participant can set, replace and remove attributes at runtime, all other handles can detect
+/// the change with [`DynamicAttributes::has_changed()`]. Every key has exactly one value.
+///
+/// Acquired via [`PortFactory::attributes_mut()`](crate::service::port_factory::PortFactory::attributes_mut()).
+pub struct DynamicAttributes<Service: service::Service> {
+    service_state: Arc<ServiceState<Service>>,
+    last_seen_change: IoxAtomicU64,
+}
+
+impl<Service: service::Service> Debug for DynamicAttributes<Service> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "DynamicAttributes<{}> {{ service: {:?}, last_seen_change: {} }}",
+            core::any::type_name::<Service>(),
+            self.service_state.static_config.name(),
+            self.last_seen_change.load(Ordering::Relaxed)
+        )
+    }
+}
+
+impl<Service: service::Service> DynamicAttributes<Service> {
+    pub(crate) fn new(service_state: Arc<ServiceState<Service>>) -> Self {
+        let last_seen_change = service_state
+            .dynamic_storage
+            .get()
+            .dynamic_attributes()
+            .change_counter();
+
+        Self {
+            service_state,
+            last_seen_change: IoxAtomicU64::new(last_seen_change),
+        }
+    }
+
+    fn data(&self) -> &DynamicAttributeData {
+        self.service_state
+            .dynamic_storage
+            .get()
+            .dynamic_attributes()
+    }
+
+    /// Sets the value of the attribute with the provided key. When the key already exists its
+    /// value is replaced.
+    pub fn set(&self, key: &str, value: &str) -> Result<(), DynamicAttributeSetError> {
+        let msg = "Unable to set dynamic attribute";
+        if key.is_empty() {
+            fail!(from self, with DynamicAttributeSetError::EmptyKey,
+                "{msg} since the key is empty.");
+        }
+
+        if key.len() > DYNAMIC_ATTRIBUTE_KEY_LENGTH {
+            fail!(from self, with DynamicAttributeSetError::KeyTooLong,
+                "{msg} \"{key}\" since the key exceeds the maximum length of {DYNAMIC_ATTRIBUTE_KEY_LENGTH}.");
+        }
+
+        if value.len() > DYNAMIC_ATTRIBUTE_VALUE_LENGTH {
+            fail!(from self, with DynamicAttributeSetError::ValueTooLong,
+                "{msg} \"{key}\" since the value exceeds the maximum length of {DYNAMIC_ATTRIBUTE_VALUE_LENGTH}.");
+        }
+
+        if !self.data().set(key.as_bytes(), value.as_bytes()) {
+            fail!(from self, with DynamicAttributeSetError::ExceedsMaxNumberOfAttributes,
+                "{msg} \"{key}\" since it would exceed the maximum number of {DYNAMIC_ATTRIBUTE_CAPACITY} dynamic attributes.");
+        }
+
+        Ok(())
+    }
+
+    /// Removes the attribute with the provided key. Returns true when the attribute existed,
+    /// otherwise false.
+    pub fn remove(&self, key: &str) -> bool {
+        self.data().remove(key.as_bytes())
+    }
+
+    /// Returns the value of the attribute with the provided key. If the key does not exist
+    /// [`None`] is returned.
+    pub fn get(&self, key: &str) -> Option<String> {
+        let (_, attributes) = self.data().snapshot();
+        attributes
+            .into_iter()
+            .find(|(k, _)| k == key.as_bytes())
+            .map(|(_, v)| String::from_utf8_lossy(&v).into_owned())
+    }
+
+    /// Returns a snapshot of all dynamic attributes. Afterwards,
+    /// [`DynamicAttributes::has_changed()`] returns false until the attributes are changed again.
+    pub fn list(&self) -> AttributeSet {
+        let (counter, attributes) = self.data().snapshot();
+        self.last_seen_change.store(counter, Ordering::Relaxed);
+
+        let mut attribute_set = AttributeSet::new();
+        for (key, value) in attributes {
+            attribute_set.add(
+                &String::from_utf8_lossy(&key),
+                &String::from_utf8_lossy(&value),
+            );
+        }
+        attribute_set
+    }
+
+    /// Returns true when the dynamic attributes were changed since this handle was created
+    /// or since the last call to [`DynamicAttributes::list()`].
+    pub fn has_changed(&self) -> bool {
+        self.last_seen_change.load(Ordering::Relaxed) != self.data().change_counter()
+    }
+}
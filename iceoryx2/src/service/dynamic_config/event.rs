@@ -26,6 +26,8 @@
 //! # Ok(())
 //! # }
 //! ```
+use core::sync::atomic::Ordering;
+
 use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::ReleaseMode};
 use iceoryx2_bb_log::fatal_panic;
@@ -34,7 +36,10 @@ use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
 
 use crate::{
     node::NodeId,
-    port::port_identifiers::{UniqueListenerId, UniqueNotifierId, UniquePortId},
+    port::{
+        event_payload::{EventPayload, EventPayloadStorage},
+        port_identifiers::{UniqueListenerId, UniqueNotifierId, UniquePortId},
+    },
 };
 
 use super::PortCleanupAction;
@@ -54,6 +59,10 @@ pub struct DynamicConfig {
     pub(crate) listeners: Container<ListenerDetails>,
     pub(crate) notifiers: Container<NotifierDetails>,
     pub(crate) elapsed_time_since_last_notification: IoxAtomicU64,
+    // the elapsed_time_since_last_notification for which the last missed deadline was counted
+    last_missed_deadline: IoxAtomicU64,
+    missed_deadlines: IoxAtomicU64,
+    payload: EventPayloadStorage,
 }
 
 #[doc(hidden)]
@@ -78,6 +87,9 @@ impl DynamicConfig {
             listeners: unsafe { Container::new_uninit(config.number_of_listeners) },
             notifiers: unsafe { Container::new_uninit(config.number_of_notifiers) },
             elapsed_time_since_last_notification: IoxAtomicU64::new(0),
+            last_missed_deadline: IoxAtomicU64::new(u64::MAX),
+            missed_deadlines: IoxAtomicU64::new(0),
+            payload: EventPayloadStorage::new(),
         }
     }
 
@@ -105,6 +117,60 @@ impl DynamicConfig {
         self.notifiers.len()
     }
 
+    /// Returns how often no [`crate::port::notifier::Notifier`] sent a notification within
+    /// the deadline of the service. A missed deadline is counted when it is detected, either
+    /// by a [`crate::port::listener::Listener`] or by the delayed notification itself.
+    pub fn number_of_missed_deadlines(&self) -> u64 {
+        self.missed_deadlines.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn last_missed_deadline(&self) -> u64 {
+        self.last_missed_deadline.load(Ordering::Relaxed)
+    }
+
+    /// Counts the missed deadline that followed the notification at `last_notification`.
+    /// Returns false when it was already counted.
+    pub(crate) fn register_missed_deadline(&self, last_notification: u64) -> bool {
+        let mut current = self.last_missed_deadline.load(Ordering::Relaxed);
+        loop {
+            if current == last_notification {
+                return false;
+            }
+
+            match self.last_missed_deadline.compare_exchange_weak(
+                current,
+                last_notification,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => {
+                    self.missed_deadlines.fetch_add(1, Ordering::Relaxed);
+                    return true;
+                }
+                Err(v) => current = v,
+            }
+        }
+    }
+
+    /// Returns the number of concurrent mutation anomalies that were detected in the port
+    /// registries of the service. Anomalies are only detected when iceoryx2 is built with the
+    /// feature `dynamic_config_validation`.
+    pub fn number_of_detected_anomalies(&self) -> u64 {
+        self.listeners.number_of_detected_anomalies()
+            + self.notifiers.number_of_detected_anomalies()
+    }
+
+    /// Returns the payload that was attached to the latest notification with
+    /// [`crate::port::notifier::Notifier::notify_with_payload()`]. If no payload was attached
+    /// so far, it returns [`None`].
+    pub fn latest_payload(&self) -> Option<EventPayload> {
+        self.payload.read()
+    }
+
+    pub(crate) fn store_payload(&self, payload: &[u8]) {
+        self.payload.write(payload)
+    }
+
     #[doc(hidden)]
     pub fn __internal_list_listeners<F: FnMut(&ListenerDetails)>(&self, mut callback: F) {
         let state = unsafe { self.listeners.get_state() };
@@ -159,6 +225,20 @@ impl DynamicConfig {
             });
     }
 
+    pub(crate) fn notifier_details_at(&self, index: usize) -> Option<NotifierDetails> {
+        let mut notifier_details = None;
+        unsafe { self.notifiers.get_state() }.for_each(|handle, details| {
+            if handle.index() as usize == index {
+                notifier_details = Some(*details);
+                CallbackProgression::Stop
+            } else {
+                CallbackProgression::Continue
+            }
+        });
+
+        notifier_details
+    }
+
     pub(crate) fn add_listener_id(&self, id: ListenerDetails) -> Option<ContainerHandle> {
         unsafe { self.listeners.add(id).ok() }
     }
@@ -26,6 +26,7 @@ pub mod publish_subscribe;
 pub mod request_response;
 
 use core::fmt::Display;
+use core::sync::atomic::Ordering;
 use iceoryx2_bb_container::queue::RelocatableContainer;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_lock_free::mpmc::{
@@ -34,8 +35,13 @@ use iceoryx2_bb_lock_free::mpmc::{
 };
 use iceoryx2_bb_log::{fail, fatal_panic};
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
 
-use crate::{node::NodeId, port::port_identifiers::UniquePortId};
+use crate::{
+    node::NodeId,
+    port::port_identifiers::UniquePortId,
+    service::{control_channel::ControlChannelData, dynamic_attribute::DynamicAttributeData},
+};
 
 #[derive(Debug, PartialEq, Eq)]
 pub(crate) enum PortCleanupAction {
@@ -71,6 +77,9 @@ pub(crate) enum MessagingPattern {
 pub struct DynamicConfig {
     messaging_pattern: MessagingPattern,
     nodes: Container<NodeId>,
+    control_channel: ControlChannelData,
+    dynamic_attributes: DynamicAttributeData,
+    is_frozen: IoxAtomicBool,
 }
 
 impl Display for DynamicConfig {
@@ -91,6 +100,9 @@ impl DynamicConfig {
         Self {
             messaging_pattern,
             nodes: unsafe { Container::new_uninit(max_number_of_nodes) },
+            control_channel: ControlChannelData::new(),
+            dynamic_attributes: DynamicAttributeData::new(),
+            is_frozen: IoxAtomicBool::new(false),
         }
     }
 
@@ -115,15 +127,7 @@ impl DynamicConfig {
         node_id: &NodeId,
         port_cleanup_callback: PortCleanup,
     ) -> Result<DeregisterNodeState, RemoveDeadNodeResult> {
-        match self.messaging_pattern {
-            MessagingPattern::PublishSubscribe(ref v) => {
-                v.remove_dead_node_id(node_id, port_cleanup_callback)
-            }
-            MessagingPattern::Event(ref v) => v.remove_dead_node_id(node_id, port_cleanup_callback),
-            MessagingPattern::RequestResponse(ref v) => {
-                v.remove_dead_node_id(node_id, port_cleanup_callback)
-            }
-        };
+        self.remove_ports_of_node(node_id, port_cleanup_callback);
 
         let mut ret_val = Err(RemoveDeadNodeResult::NodeNotRegistered);
         self.nodes
@@ -140,6 +144,26 @@ impl DynamicConfig {
         ret_val
     }
 
+    /// Releases all ports of the provided [`NodeId`] for which the `port_cleanup_callback`
+    /// returns [`PortCleanupAction::RemovePort`]. The [`NodeId`] itself stays registered.
+    pub(crate) unsafe fn remove_ports_of_node<
+        PortCleanup: FnMut(UniquePortId) -> PortCleanupAction,
+    >(
+        &self,
+        node_id: &NodeId,
+        port_cleanup_callback: PortCleanup,
+    ) {
+        match self.messaging_pattern {
+            MessagingPattern::PublishSubscribe(ref v) => {
+                v.remove_dead_node_id(node_id, port_cleanup_callback)
+            }
+            MessagingPattern::Event(ref v) => v.remove_dead_node_id(node_id, port_cleanup_callback),
+            MessagingPattern::RequestResponse(ref v) => {
+                v.remove_dead_node_id(node_id, port_cleanup_callback)
+            }
+        };
+    }
+
     pub(crate) fn register_node_id(
         &self,
         node_id: NodeId,
@@ -167,6 +191,16 @@ impl DynamicConfig {
         self.nodes.is_locked()
     }
 
+    /// A frozen service does not accept new nodes. Nodes that are already registered are not
+    /// affected.
+    pub(crate) fn set_frozen(&self, value: bool) -> bool {
+        self.is_frozen.swap(value, Ordering::Relaxed) != value
+    }
+
+    pub(crate) fn is_frozen(&self) -> bool {
+        self.is_frozen.load(Ordering::Relaxed)
+    }
+
     pub(crate) fn deregister_node_id(&self, handle: ContainerHandle) -> DeregisterNodeState {
         if unsafe { self.nodes.remove(handle, ReleaseMode::LockIfLastIndex) }
             == ReleaseState::Locked
@@ -177,6 +211,14 @@ impl DynamicConfig {
         }
     }
 
+    pub(crate) fn control_channel(&self) -> &ControlChannelData {
+        &self.control_channel
+    }
+
+    pub(crate) fn dynamic_attributes(&self) -> &DynamicAttributeData {
+        &self.dynamic_attributes
+    }
+
     pub(crate) fn request_response(&self) -> &request_response::DynamicConfig {
         match &self.messaging_pattern {
             MessagingPattern::RequestResponse(ref v) => v,
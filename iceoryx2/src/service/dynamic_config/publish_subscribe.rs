@@ -22,14 +22,19 @@
 //!     .open_or_create()?;
 //!
 //! println!("number of active publishers:      {:?}", pubsub.dynamic_config().number_of_publishers());
+//! println!("number of ready publishers:       {:?}", pubsub.dynamic_config().number_of_ready_publishers());
 //! println!("number of active subscribers:     {:?}", pubsub.dynamic_config().number_of_subscribers());
 //! # Ok(())
 //! # }
 //! ```
+use core::sync::atomic::Ordering;
+
+use iceoryx2_bb_container::vec::RelocatableVec;
 use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::ReleaseMode};
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicUsize};
 
 use crate::{
     node::NodeId,
@@ -37,6 +42,7 @@ use crate::{
         details::data_segment::DataSegmentType,
         port_identifiers::{UniquePortId, UniquePublisherId, UniqueSubscriberId},
     },
+    service::port_factory::subscriber::DeliveryQos,
 };
 
 use super::PortCleanupAction;
@@ -60,6 +66,14 @@ pub struct PublisherDetails {
     pub max_number_of_segments: u8,
 }
 
+#[doc(hidden)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct ReadyPublisherDetails {
+    pub publisher_id: UniquePublisherId,
+    pub node_id: NodeId,
+}
+
 #[doc(hidden)]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -67,6 +81,7 @@ pub struct SubscriberDetails {
     pub subscriber_id: UniqueSubscriberId,
     pub node_id: NodeId,
     pub buffer_size: usize,
+    pub delivery_qos: DeliveryQos,
 }
 
 /// The dynamic configuration of an [`crate::service::messaging_pattern::MessagingPattern::Event`]
@@ -76,6 +91,12 @@ pub struct SubscriberDetails {
 pub struct DynamicConfig {
     pub(crate) subscribers: Container<SubscriberDetails>,
     pub(crate) publishers: Container<PublisherDetails>,
+    pub(crate) ready_publishers: Container<ReadyPublisherDetails>,
+    pub(crate) global_sequence_number: IoxAtomicU64,
+    // the borrowed samples of every subscriber slot, so that the samples of a dead subscriber
+    // can be removed from the total
+    pub(crate) borrowed_samples: RelocatableVec<IoxAtomicUsize>,
+    pub(crate) total_borrowed_samples: IoxAtomicUsize,
 }
 
 impl DynamicConfig {
@@ -83,6 +104,10 @@ impl DynamicConfig {
         Self {
             subscribers: unsafe { Container::new_uninit(config.number_of_subscribers) },
             publishers: unsafe { Container::new_uninit(config.number_of_publishers) },
+            ready_publishers: unsafe { Container::new_uninit(config.number_of_publishers) },
+            global_sequence_number: IoxAtomicU64::new(0),
+            borrowed_samples: unsafe { RelocatableVec::new_uninit(config.number_of_subscribers) },
+            total_borrowed_samples: IoxAtomicUsize::new(0),
         }
     }
 
@@ -93,11 +118,22 @@ impl DynamicConfig {
         fatal_panic!(from self,
             when self.publishers.init(allocator),
             "This should never happen! Unable to initialize publisher port id container.");
+        fatal_panic!(from self,
+            when self.ready_publishers.init(allocator),
+            "This should never happen! Unable to initialize ready publisher port id container.");
+        fatal_panic!(from self,
+            when self.borrowed_samples.init(allocator),
+            "This should never happen! Unable to initialize borrowed samples counters.");
+        for _ in 0..self.borrowed_samples.capacity() {
+            self.borrowed_samples.push(IoxAtomicUsize::new(0));
+        }
     }
 
     pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
         Container::<SubscriberDetails>::memory_size(config.number_of_subscribers)
             + Container::<PublisherDetails>::memory_size(config.number_of_publishers)
+            + Container::<ReadyPublisherDetails>::memory_size(config.number_of_publishers)
+            + RelocatableVec::<IoxAtomicUsize>::memory_size(config.number_of_subscribers)
     }
 
     pub(crate) unsafe fn remove_dead_node_id<
@@ -120,6 +156,17 @@ impl DynamicConfig {
                 CallbackProgression::Continue
             });
 
+        self.ready_publishers
+            .get_state()
+            .for_each(|handle: ContainerHandle, ready_publisher| {
+                if ready_publisher.node_id == *node_id
+                    && !self.contains_publisher(ready_publisher.publisher_id)
+                {
+                    self.release_ready_publisher_handle(handle);
+                }
+                CallbackProgression::Continue
+            });
+
         self.subscribers
             .get_state()
             .for_each(|handle: ContainerHandle, registered_subscriber| {
@@ -128,6 +175,7 @@ impl DynamicConfig {
                         registered_subscriber.subscriber_id,
                     )) == PortCleanupAction::RemovePort
                 {
+                    self.remove_borrowed_samples_of(handle.index());
                     self.release_subscriber_handle(handle);
                 }
                 CallbackProgression::Continue
@@ -139,11 +187,31 @@ impl DynamicConfig {
         self.publishers.len()
     }
 
+    /// Returns how many [`crate::port::publisher::Publisher`] ports are currently connected and
+    /// have announced that they are ready, see
+    /// [`Publisher::announce_ready()`](crate::port::publisher::Publisher::announce_ready()).
+    pub fn number_of_ready_publishers(&self) -> usize {
+        self.ready_publishers.len()
+    }
+
     /// Returns how many [`crate::port::subscriber::Subscriber`] ports are currently connected.
     pub fn number_of_subscribers(&self) -> usize {
         self.subscribers.len()
     }
 
+    /// Returns the number of concurrent mutation anomalies that were detected in the port
+    /// registries of the service. Anomalies are only detected when iceoryx2 is built with the
+    /// feature `dynamic_config_validation`.
+    pub fn number_of_detected_anomalies(&self) -> u64 {
+        self.subscribers.number_of_detected_anomalies()
+            + self.publishers.number_of_detected_anomalies()
+            + self.ready_publishers.number_of_detected_anomalies()
+    }
+
+    pub(crate) fn acquire_global_sequence_number(&self) -> u64 {
+        self.global_sequence_number.fetch_add(1, Ordering::Relaxed)
+    }
+
     #[doc(hidden)]
     pub fn __internal_list_subscribers<F: FnMut(&SubscriberDetails)>(&self, mut callback: F) {
         let state = unsafe { self.subscribers.get_state() };
@@ -172,6 +240,50 @@ impl DynamicConfig {
         unsafe { self.subscribers.remove(handle, ReleaseMode::Default) };
     }
 
+    /// Returns how many [`crate::sample::Sample`]s are currently borrowed by all
+    /// [`crate::port::subscriber::Subscriber`] ports together.
+    pub fn number_of_borrowed_samples(&self) -> usize {
+        self.total_borrowed_samples.load(Ordering::Relaxed)
+    }
+
+    /// Accounts another borrowed sample for the subscriber in the provided slot. Returns false
+    /// when it would exceed `max_total_borrowed_samples`.
+    pub(crate) fn acquire_borrowed_sample(
+        &self,
+        subscriber_slot: u32,
+        max_total_borrowed_samples: usize,
+    ) -> bool {
+        if self
+            .total_borrowed_samples
+            .fetch_update(Ordering::AcqRel, Ordering::Relaxed, |total| {
+                (total < max_total_borrowed_samples).then_some(total + 1)
+            })
+            .is_err()
+        {
+            return false;
+        }
+
+        self.borrowed_samples[subscriber_slot as usize].fetch_add(1, Ordering::Relaxed);
+        true
+    }
+
+    pub(crate) fn release_borrowed_sample(&self, subscriber_slot: u32) {
+        // the samples of a subscriber that was removed as dead were already subtracted
+        if self.borrowed_samples[subscriber_slot as usize]
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |v| v.checked_sub(1))
+            .is_ok()
+        {
+            self.total_borrowed_samples.fetch_sub(1, Ordering::AcqRel);
+        }
+    }
+
+    fn remove_borrowed_samples_of(&self, subscriber_slot: u32) {
+        let borrowed_samples =
+            self.borrowed_samples[subscriber_slot as usize].swap(0, Ordering::Relaxed);
+        self.total_borrowed_samples
+            .fetch_sub(borrowed_samples, Ordering::AcqRel);
+    }
+
     pub(crate) fn add_publisher_id(&self, details: PublisherDetails) -> Option<ContainerHandle> {
         unsafe { self.publishers.add(details).ok() }
     }
@@ -179,4 +291,28 @@ impl DynamicConfig {
     pub(crate) fn release_publisher_handle(&self, handle: ContainerHandle) {
         unsafe { self.publishers.remove(handle, ReleaseMode::Default) };
     }
+
+    pub(crate) fn add_ready_publisher_id(
+        &self,
+        details: ReadyPublisherDetails,
+    ) -> Option<ContainerHandle> {
+        unsafe { self.ready_publishers.add(details).ok() }
+    }
+
+    pub(crate) fn release_ready_publisher_handle(&self, handle: ContainerHandle) {
+        unsafe { self.ready_publishers.remove(handle, ReleaseMode::Default) };
+    }
+
+    fn contains_publisher(&self, publisher_id: UniquePublisherId) -> bool {
+        let mut contains_publisher = false;
+        unsafe { self.publishers.get_state() }.for_each(|_, details| {
+            if details.publisher_id == publisher_id {
+                contains_publisher = true;
+                return CallbackProgression::Stop;
+            }
+            CallbackProgression::Continue
+        });
+
+        contains_publisher
+    }
 }
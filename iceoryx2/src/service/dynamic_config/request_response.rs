@@ -10,13 +10,22 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::sync::atomic::Ordering;
+
 use iceoryx2_bb_container::queue::RelocatableContainer;
 use iceoryx2_bb_elementary::CallbackProgression;
-use iceoryx2_bb_lock_free::mpmc::container::Container;
+use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::ReleaseMode};
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
 
-use crate::{node::NodeId, port::port_identifiers::UniquePortId};
+use crate::{
+    node::NodeId,
+    port::{
+        details::data_segment::DataSegmentType,
+        port_identifiers::{UniqueClientId, UniquePortId, UniqueServerId},
+    },
+};
 
 use super::PortCleanupAction;
 
@@ -24,14 +33,26 @@ use super::PortCleanupAction;
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
 pub struct ServerDetails {
-    _stub: usize,
+    pub server_port_id: UniqueServerId,
+    pub node_id: NodeId,
+    pub request_buffer_size: usize,
+    pub number_of_responses: usize,
+    pub max_slice_len: usize,
+    pub data_segment_type: DataSegmentType,
+    pub max_number_of_segments: u8,
 }
 
 #[doc(hidden)]
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
 pub struct ClientDetails {
-    _stub: usize,
+    pub client_port_id: UniqueClientId,
+    pub node_id: NodeId,
+    pub number_of_requests: usize,
+    pub response_buffer_size: usize,
+    pub max_slice_len: usize,
+    pub data_segment_type: DataSegmentType,
+    pub max_number_of_segments: u8,
 }
 
 #[repr(C)]
@@ -49,6 +70,7 @@ pub(crate) struct DynamicConfigSettings {
 pub struct DynamicConfig {
     pub(crate) servers: Container<ServerDetails>,
     pub(crate) clients: Container<ClientDetails>,
+    number_of_expired_requests: IoxAtomicU64,
 }
 
 impl DynamicConfig {
@@ -56,6 +78,7 @@ impl DynamicConfig {
         Self {
             servers: unsafe { Container::new_uninit(config.number_of_servers) },
             clients: unsafe { Container::new_uninit(config.number_of_clients) },
+            number_of_expired_requests: IoxAtomicU64::new(0),
         }
     }
 
@@ -83,14 +106,71 @@ impl DynamicConfig {
         self.servers.len()
     }
 
+    /// Returns how many requests that were sent with
+    /// [`crate::request_mut::RequestMut::send_with_timeout()`] expired without receiving a
+    /// response.
+    pub fn number_of_expired_requests(&self) -> u64 {
+        self.number_of_expired_requests.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn increment_expired_requests(&self, value: u64) {
+        self.number_of_expired_requests
+            .fetch_add(value, Ordering::Relaxed);
+    }
+
+    /// Returns the number of concurrent mutation anomalies that were detected in the port
+    /// registries of the service. Anomalies are only detected when iceoryx2 is built with the
+    /// feature `dynamic_config_validation`.
+    pub fn number_of_detected_anomalies(&self) -> u64 {
+        self.servers.number_of_detected_anomalies() + self.clients.number_of_detected_anomalies()
+    }
+
     pub(crate) unsafe fn remove_dead_node_id<
         PortCleanup: FnMut(UniquePortId) -> PortCleanupAction,
     >(
         &self,
-        _node_id: &NodeId,
-        mut _port_cleanup_callback: PortCleanup,
+        node_id: &NodeId,
+        mut port_cleanup_callback: PortCleanup,
     ) {
-        todo!()
+        self.servers
+            .get_state()
+            .for_each(|handle: ContainerHandle, registered_server| {
+                if registered_server.node_id == *node_id
+                    && port_cleanup_callback(UniquePortId::Server(registered_server.server_port_id))
+                        == PortCleanupAction::RemovePort
+                {
+                    self.release_server_handle(handle);
+                }
+                CallbackProgression::Continue
+            });
+
+        self.clients
+            .get_state()
+            .for_each(|handle: ContainerHandle, registered_client| {
+                if registered_client.node_id == *node_id
+                    && port_cleanup_callback(UniquePortId::Client(registered_client.client_port_id))
+                        == PortCleanupAction::RemovePort
+                {
+                    self.release_client_handle(handle);
+                }
+                CallbackProgression::Continue
+            });
+    }
+
+    pub(crate) fn add_server_id(&self, details: ServerDetails) -> Option<ContainerHandle> {
+        unsafe { self.servers.add(details).ok() }
+    }
+
+    pub(crate) fn release_server_handle(&self, handle: ContainerHandle) {
+        unsafe { self.servers.remove(handle, ReleaseMode::Default) };
+    }
+
+    pub(crate) fn add_client_id(&self, details: ClientDetails) -> Option<ContainerHandle> {
+        unsafe { self.clients.add(details).ok() }
+    }
+
+    pub(crate) fn release_client_handle(&self, handle: ContainerHandle) {
+        unsafe { self.clients.remove(handle, ReleaseMode::Default) };
     }
 
     #[doc(hidden)]
@@ -104,7 +184,7 @@ impl DynamicConfig {
     }
 
     #[doc(hidden)]
-    pub fn __internal_list_publishers<F: FnMut(&ClientDetails)>(&self, mut callback: F) {
+    pub fn __internal_list_clients<F: FnMut(&ClientDetails)>(&self, mut callback: F) {
         let state = unsafe { self.clients.get_state() };
 
         state.for_each(|_, details| {
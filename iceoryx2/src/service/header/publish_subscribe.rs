@@ -31,6 +31,8 @@
 //! # }
 //! ```
 
+use core::time::Duration;
+
 use crate::port::port_identifiers::UniquePublisherId;
 
 /// Sample header used by
@@ -40,21 +42,113 @@ use crate::port::port_identifiers::UniquePublisherId;
 pub struct Header {
     publisher_port_id: UniquePublisherId,
     number_of_elements: u64,
+    send_timestamp: u64,
+    global_sequence_number: u64,
+    user_header_size: u64,
+    payload_checksum: u32,
+    has_payload_checksum: bool,
+    has_global_sequence_number: bool,
+    is_publisher_ready: bool,
+    is_exclusive: bool,
+    is_handed_back: bool,
 }
 
 impl Header {
-    pub(crate) fn new(publisher_port_id: UniquePublisherId, number_of_elements: u64) -> Self {
+    pub(crate) fn new(
+        publisher_port_id: UniquePublisherId,
+        number_of_elements: u64,
+        user_header_size: u64,
+    ) -> Self {
         Self {
             publisher_port_id,
             number_of_elements,
+            send_timestamp: 0,
+            global_sequence_number: 0,
+            user_header_size,
+            payload_checksum: 0,
+            has_payload_checksum: false,
+            has_global_sequence_number: false,
+            is_publisher_ready: false,
+            is_exclusive: false,
+            is_handed_back: false,
         }
     }
 
+    pub(crate) fn set_publisher_ready(&mut self, value: bool) {
+        self.is_publisher_ready = value;
+    }
+
+    pub(crate) fn set_exclusive(&mut self, value: bool) {
+        self.is_exclusive = value;
+    }
+
+    pub(crate) fn set_handed_back(&mut self, value: bool) {
+        self.is_handed_back = value;
+    }
+
+    pub(crate) fn is_handed_back(&self) -> bool {
+        self.is_handed_back
+    }
+
+    pub(crate) fn set_number_of_elements(&mut self, value: u64) {
+        self.number_of_elements = value;
+    }
+
+    pub(crate) fn set_send_timestamp(&mut self, value: u64) {
+        self.send_timestamp = value;
+    }
+
+    pub(crate) fn set_payload_checksum(&mut self, value: Option<u32>) {
+        self.has_payload_checksum = value.is_some();
+        self.payload_checksum = value.unwrap_or(0);
+    }
+
+    pub(crate) fn set_global_sequence_number(&mut self, value: Option<u64>) {
+        self.has_global_sequence_number = value.is_some();
+        self.global_sequence_number = value.unwrap_or(0);
+    }
+
+    /// Copies the fields of a captured header that are visible to the user. The delivery
+    /// state, like the exclusiveness of the captured sample, belongs to the original delivery
+    /// and is not copied.
+    pub(crate) fn copy_replayed_fields(&mut self, captured: &Header) {
+        self.publisher_port_id = captured.publisher_port_id;
+        self.number_of_elements = captured.number_of_elements;
+        self.send_timestamp = captured.send_timestamp;
+        self.global_sequence_number = captured.global_sequence_number;
+        self.has_global_sequence_number = captured.has_global_sequence_number;
+        self.user_header_size = captured.user_header_size;
+        self.payload_checksum = captured.payload_checksum;
+        self.has_payload_checksum = captured.has_payload_checksum;
+        self.is_publisher_ready = captured.is_publisher_ready;
+    }
+
+    /// Returns true when the sample was sent longer ago than `lifetime` relative to the
+    /// monotonic point in time `now` in nanoseconds. Samples without a valid send timestamp
+    /// never become stale.
+    pub(crate) fn is_stale(&self, now: u64, lifetime: Duration) -> bool {
+        self.send_timestamp != 0
+            && now.saturating_sub(self.send_timestamp) > lifetime.as_nanos() as u64
+    }
+
     /// Returns the [`UniquePublisherId`] of the source [`crate::port::publisher::Publisher`].
     pub fn publisher_id(&self) -> UniquePublisherId {
         self.publisher_port_id
     }
 
+    /// Returns the point in time of the monotonic clock when the sample was sent or [`None`]
+    /// when the [`crate::port::publisher::Publisher`] was unable to acquire the time or the
+    /// service has no send timestamps, see
+    /// [`Builder::enable_send_timestamp()`](crate::service::builder::publish_subscribe::Builder::enable_send_timestamp()).
+    /// The monotonic clock is shared by all processes on the same host, see
+    /// [`Sample::latency()`](crate::sample::Sample::latency()).
+    pub fn send_timestamp(&self) -> Option<Duration> {
+        match self.send_timestamp {
+            0 => None,
+            v => Some(Duration::from_nanos(v)),
+        }
+    }
+
     /// Returns how many elements are stored inside the sample's payload.
     ///
     /// # Details when using
@@ -67,4 +161,56 @@ impl Header {
     pub fn number_of_elements(&self) -> u64 {
         self.number_of_elements
     }
+
+    /// Returns the size of the user header in bytes. It is either the size of the user header
+    /// type or, when using
+    /// [`CustomHeaderMarker`](crate::service::builder::publish_subscribe::CustomHeaderMarker),
+    /// the size defined with
+    /// [`Builder::user_header_size()`](crate::service::builder::publish_subscribe::Builder::user_header_size()).
+    pub fn user_header_size(&self) -> u64 {
+        self.user_header_size
+    }
+
+    /// Returns true when the source [`crate::port::publisher::Publisher`] had announced its
+    /// readiness with
+    /// [`Publisher::announce_ready()`](crate::port::publisher::Publisher::announce_ready())
+    /// when the sample was sent.
+    pub fn is_publisher_ready(&self) -> bool {
+        self.is_publisher_ready
+    }
+
+    /// Returns true when the sample was delivered to exactly one
+    /// [`crate::port::subscriber::Subscriber`] and no other party holds a reference to it.
+    /// Only exclusive samples can be updated in place with
+    /// [`Sample::payload_mut()`](crate::sample::Sample::payload_mut()).
+    pub fn is_exclusive(&self) -> bool {
+        self.is_exclusive
+    }
+
+    /// Returns the CRC-32C checksum of the payload when the
+    /// [`Service`](crate::service::Service) was created with
+    /// [`Builder::enable_payload_checksum()`](crate::service::builder::publish_subscribe::Builder::enable_payload_checksum())
+    /// and the sample was not sent with
+    /// [`SampleMut::send_without_payload_checksum()`](crate::sample_mut::SampleMut::send_without_payload_checksum()).
+    /// Otherwise it returns [`None`].
+    pub fn payload_checksum(&self) -> Option<u32> {
+        match self.has_payload_checksum {
+            true => Some(self.payload_checksum),
+            false => None,
+        }
+    }
+
+    /// Returns the service-wide sequence number of the sample when the
+    /// [`Service`](crate::service::Service) was created with
+    /// [`Builder::enable_global_ordering()`](crate::service::builder::publish_subscribe::Builder::enable_global_ordering()).
+    /// The sequence number is strictly increasing over all
+    /// [`crate::port::publisher::Publisher`]s of the [`Service`](crate::service::Service) and
+    /// allows a [`crate::port::subscriber::Subscriber`] to restore the send order of samples
+    /// from multiple [`crate::port::publisher::Publisher`]s. Otherwise it returns [`None`].
+    pub fn global_sequence_number(&self) -> Option<u64> {
+        match self.has_global_sequence_number {
+            true => Some(self.global_sequence_number),
+            false => None,
+        }
+    }
 }
@@ -10,14 +10,112 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use crate::port::port_identifiers::{UniqueClientId, UniqueServerId};
+
 /// Request header used by
 /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
 #[derive(Debug, Copy, Clone)]
 #[repr(C)]
-pub struct RequestHeader {}
+pub struct RequestHeader {
+    client_port_id: UniqueClientId,
+    request_id: u64,
+    number_of_elements: u64,
+    is_fire_and_forget: bool,
+}
+
+impl RequestHeader {
+    pub(crate) fn new(client_port_id: UniqueClientId, number_of_elements: u64) -> Self {
+        Self {
+            client_port_id,
+            request_id: 0,
+            number_of_elements,
+            is_fire_and_forget: false,
+        }
+    }
+
+    pub(crate) fn set_request_id(&mut self, value: u64) {
+        self.request_id = value;
+    }
+
+    pub(crate) fn set_fire_and_forget(&mut self, value: bool) {
+        self.is_fire_and_forget = value;
+    }
+
+    /// Returns the [`UniqueClientId`] of the [`crate::port::client::Client`] that sent the
+    /// request.
+    pub fn client_port_id(&self) -> UniqueClientId {
+        self.client_port_id
+    }
+
+    /// Returns the id of the request. It is unique for every request that was sent by the
+    /// same [`crate::port::client::Client`].
+    pub fn request_id(&self) -> u64 {
+        self.request_id
+    }
+
+    /// Returns how many elements are stored inside the request's payload.
+    pub fn number_of_elements(&self) -> u64 {
+        self.number_of_elements
+    }
+
+    /// Returns true when the request was sent with
+    /// [`RequestMut::send_and_forget()`](crate::request_mut::RequestMut::send_and_forget())
+    /// and the [`crate::port::client::Client`] does not expect any response.
+    pub fn is_fire_and_forget(&self) -> bool {
+        self.is_fire_and_forget
+    }
+}
 
 /// Response header used by
 /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
 #[derive(Debug, Copy, Clone)]
 #[repr(C)]
-pub struct ResponseHeader {}
+pub struct ResponseHeader {
+    server_port_id: UniqueServerId,
+    request_id: u64,
+    number_of_elements: u64,
+    is_error: bool,
+}
+
+impl ResponseHeader {
+    pub(crate) fn new(
+        server_port_id: UniqueServerId,
+        request_id: u64,
+        number_of_elements: u64,
+    ) -> Self {
+        Self {
+            server_port_id,
+            request_id,
+            number_of_elements,
+            is_error: false,
+        }
+    }
+
+    pub(crate) fn set_error(&mut self, value: bool) {
+        self.is_error = value;
+    }
+
+    /// Returns the [`UniqueServerId`] of the [`crate::port::server::Server`] that sent the
+    /// response.
+    pub fn server_port_id(&self) -> UniqueServerId {
+        self.server_port_id
+    }
+
+    /// Returns the id of the request the response belongs to, see
+    /// [`RequestHeader::request_id()`].
+    pub fn request_id(&self) -> u64 {
+        self.request_id
+    }
+
+    /// Returns how many elements are stored inside the response's payload.
+    pub fn number_of_elements(&self) -> u64 {
+        self.number_of_elements
+    }
+
+    /// Returns true when the response was sent with
+    /// [`ActiveRequest::send_error()`](crate::active_request::ActiveRequest::send_error())
+    /// and its payload is the error type of the [`crate::service::Service`].
+    pub fn is_error(&self) -> bool {
+        self.is_error
+    }
+}
@@ -0,0 +1,324 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A language-neutral description of a [`Service`](crate::service::Service) that contains
+//! the payload layout, the QoS and the attributes. It can be exported into a machine-readable
+//! IDL, imported again and converted into C, C++ or Rust type definitions to enable
+//! contract-first development and interface reviews across teams.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::idl::{ServiceDescription, TargetLanguage};
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service_name = "My/Funk/ServiceName".try_into()?;
+//! let _service = node.service_builder(&service_name)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! if let Some(description) = ipc::Service::export_idl(
+//!     &service_name,
+//!     Config::global_config(),
+//!     MessagingPattern::PublishSubscribe,
+//! )? {
+//!     let idl = description.to_idl()?;
+//!
+//!     // the idl can be stored and shared, and later be imported again
+//!     let imported = ServiceDescription::from_idl(&idl)?;
+//!     println!("{}", imported.generate_type_definitions(TargetLanguage::Cxx));
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Write;
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::serialize::toml::Toml;
+use iceoryx2_cal::serialize::Serialize as _;
+use serde::{Deserialize, Serialize};
+
+use super::attribute::AttributeSet;
+use super::service_name::ServiceName;
+use super::static_config::message_type_details::{TypeDetail, TypeVariant};
+use super::static_config::messaging_pattern::MessagingPattern;
+use super::static_config::StaticConfig;
+
+/// The version of the IDL format that is produced by [`ServiceDescription::to_idl()`].
+/// [`ServiceDescription::from_idl()`] rejects descriptions with a different version.
+pub const IDL_FORMAT_VERSION: u32 = 1;
+
+/// Failures that can occur when a [`ServiceDescription`] is exported with
+/// [`ServiceDescription::to_idl()`] or imported with [`ServiceDescription::from_idl()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ServiceIdlError {
+    /// The [`ServiceDescription`] could not be serialized.
+    SerializationFailure,
+    /// The provided IDL is malformed or does not describe a [`ServiceDescription`].
+    DeserializationFailure,
+    /// The provided IDL was created with a different [`IDL_FORMAT_VERSION`].
+    UnsupportedFormatVersion,
+}
+
+impl core::fmt::Display for ServiceIdlError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ServiceIdlError::{:?}", self)
+    }
+}
+
+impl core::error::Error for ServiceIdlError {}
+
+/// The language for which [`ServiceDescription::generate_type_definitions()`] generates the
+/// type definitions.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum TargetLanguage {
+    /// C99 or later
+    C,
+    /// C++11 or later
+    Cxx,
+    /// Rust
+    Rust,
+}
+
+/// The language-neutral description of a [`Service`](crate::service::Service). It contains
+/// everything that is required to connect to the [`Service`](crate::service::Service) except
+/// the implementation specific details like the
+/// [`ServiceId`](crate::service::service_id::ServiceId).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServiceDescription {
+    format_version: u32,
+    service_name: ServiceName,
+    attributes: AttributeSet,
+    messaging_pattern: MessagingPattern,
+}
+
+impl From<&StaticConfig> for ServiceDescription {
+    fn from(value: &StaticConfig) -> Self {
+        Self {
+            format_version: IDL_FORMAT_VERSION,
+            service_name: value.name().clone(),
+            attributes: value.attributes().clone(),
+            messaging_pattern: value.messaging_pattern().clone(),
+        }
+    }
+}
+
+impl ServiceDescription {
+    /// Returns the [`ServiceName`] of the described [`Service`](crate::service::Service)
+    pub fn service_name(&self) -> &ServiceName {
+        &self.service_name
+    }
+
+    /// Returns the attributes of the described [`Service`](crate::service::Service)
+    pub fn attributes(&self) -> &AttributeSet {
+        &self.attributes
+    }
+
+    /// Returns the messaging pattern specific static configuration of the described
+    /// [`Service`](crate::service::Service) that contains the QoS and the type details.
+    pub fn messaging_pattern(&self) -> &MessagingPattern {
+        &self.messaging_pattern
+    }
+
+    /// Exports the [`ServiceDescription`] into the machine-readable IDL.
+    pub fn to_idl(&self) -> Result<String, ServiceIdlError> {
+        let msg = "Unable to export the service description";
+        let bytes = fail!(from self, when Toml::serialize(self),
+                with ServiceIdlError::SerializationFailure,
+                "{} since the description could not be serialized.", msg);
+
+        match String::from_utf8(bytes) {
+            Ok(idl) => Ok(idl),
+            Err(e) => {
+                fail!(from self, with ServiceIdlError::SerializationFailure,
+                    "{} since the serialized description is not valid UTF-8 ({:?}).", msg, e);
+            }
+        }
+    }
+
+    /// Imports a [`ServiceDescription`] from an IDL that was created with
+    /// [`ServiceDescription::to_idl()`].
+    pub fn from_idl(idl: &str) -> Result<Self, ServiceIdlError> {
+        let msg = "Unable to import the service description";
+        let origin = "ServiceDescription::from_idl()";
+        let description: Self = fail!(from origin, when Toml::deserialize(idl.as_bytes()),
+                with ServiceIdlError::DeserializationFailure,
+                "{} since the IDL could not be deserialized.", msg);
+
+        if description.format_version != IDL_FORMAT_VERSION {
+            fail!(from origin, with ServiceIdlError::UnsupportedFormatVersion,
+                "{} since the IDL has the format version {} but only the version {} is supported.",
+                msg, description.format_version, IDL_FORMAT_VERSION);
+        }
+
+        Ok(description)
+    }
+
+    /// Generates the type definitions of all user defined types of the described
+    /// [`Service`](crate::service::Service) in the provided [`TargetLanguage`].
+    /// Primitive types are mapped onto their counterpart, all other types are generated as
+    /// opaque types with the same size and alignment so that they are layout compatible.
+    pub fn generate_type_definitions(&self, language: TargetLanguage) -> String {
+        let mut code = String::new();
+        let _ = writeln!(
+            code,
+            "// Type definitions of the {} service \"{}\"",
+            self.messaging_pattern, self.service_name
+        );
+        let _ = writeln!(code, "// Generated from the iceoryx2 service description");
+
+        match language {
+            TargetLanguage::C => {
+                let _ = writeln!(
+                    code,
+                    "\n#include <stdbool.h>\n#include <stddef.h>\n#include <stdint.h>"
+                );
+            }
+            TargetLanguage::Cxx => {
+                let _ = writeln!(code, "\n#include <cstddef>\n#include <cstdint>");
+            }
+            TargetLanguage::Rust => (),
+        }
+
+        let mut generated_types: Vec<String> = vec![];
+        for (role, detail) in self.type_details() {
+            if detail.size == 0 {
+                continue;
+            }
+
+            let variant = match detail.variant {
+                TypeVariant::FixedSize => "",
+                TypeVariant::Dynamic => "slice of ",
+            };
+            let _ = writeln!(
+                code,
+                "\n// {}: {}{} (size: {}, alignment: {})",
+                role, variant, detail.type_name, detail.size, detail.alignment
+            );
+
+            if let Some(primitive) = primitive_type(&detail.type_name, language) {
+                let _ = writeln!(code, "// maps to the primitive type {}", primitive);
+                continue;
+            }
+
+            let name = type_identifier(&detail.type_name, role);
+            if generated_types.contains(&name) {
+                let _ = writeln!(code, "// uses the already defined type {}", name);
+                continue;
+            }
+
+            generate_opaque_type(&mut code, &name, detail, language);
+            generated_types.push(name);
+        }
+
+        code
+    }
+
+    fn type_details(&self) -> Vec<(&'static str, &TypeDetail)> {
+        match &self.messaging_pattern {
+            MessagingPattern::PublishSubscribe(c) => vec![
+                ("UserHeader", &c.message_type_details.user_header),
+                ("Payload", &c.message_type_details.payload),
+            ],
+            MessagingPattern::RequestResponse(c) => {
+                let mut details = vec![
+                    ("RequestHeader", &c.request_message_type_details.user_header),
+                    ("RequestPayload", &c.request_message_type_details.payload),
+                    (
+                        "ResponseHeader",
+                        &c.response_message_type_details.user_header,
+                    ),
+                    ("ResponsePayload", &c.response_message_type_details.payload),
+                ];
+                if let Some(error_details) = &c.error_message_type_details {
+                    details.push(("ErrorPayload", &error_details.payload));
+                }
+                details
+            }
+            MessagingPattern::Event(_) => vec![],
+        }
+    }
+}
+
+fn primitive_type(type_name: &str, language: TargetLanguage) -> Option<&'static str> {
+    const PRIMITIVES: [(&str, &str, &str); 13] = [
+        ("u8", "uint8_t", "std::uint8_t"),
+        ("u16", "uint16_t", "std::uint16_t"),
+        ("u32", "uint32_t", "std::uint32_t"),
+        ("u64", "uint64_t", "std::uint64_t"),
+        ("i8", "int8_t", "std::int8_t"),
+        ("i16", "int16_t", "std::int16_t"),
+        ("i32", "int32_t", "std::int32_t"),
+        ("i64", "int64_t", "std::int64_t"),
+        ("usize", "size_t", "std::size_t"),
+        ("isize", "ptrdiff_t", "std::ptrdiff_t"),
+        ("f32", "float", "float"),
+        ("f64", "double", "double"),
+        ("bool", "bool", "bool"),
+    ];
+
+    PRIMITIVES
+        .iter()
+        .find(|(rust, _, _)| *rust == type_name)
+        .map(|(rust, c, cxx)| match language {
+            TargetLanguage::C => *c,
+            TargetLanguage::Cxx => *cxx,
+            TargetLanguage::Rust => *rust,
+        })
+}
+
+fn type_identifier(type_name: &str, role: &str) -> String {
+    let without_generics = type_name.split('<').next().unwrap_or(type_name);
+    let last_segment = without_generics
+        .rsplit("::")
+        .next()
+        .unwrap_or(without_generics);
+
+    let identifier: String = last_segment
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    let identifier = identifier.trim_matches('_');
+
+    match identifier.chars().next() {
+        Some(c) if c.is_ascii_alphabetic() => identifier.to_string(),
+        _ => role.to_string(),
+    }
+}
+
+fn generate_opaque_type(
+    code: &mut String,
+    name: &str,
+    detail: &TypeDetail,
+    language: TargetLanguage,
+) {
+    let _ = match language {
+        TargetLanguage::C => writeln!(
+            code,
+            "typedef struct {{\n    _Alignas({}) uint8_t data[{}];\n}} {};",
+            detail.alignment, detail.size, name
+        ),
+        TargetLanguage::Cxx => writeln!(
+            code,
+            "struct alignas({}) {} {{\n    std::uint8_t data[{}];\n}};",
+            detail.alignment, name, detail.size
+        ),
+        TargetLanguage::Rust => writeln!(
+            code,
+            "#[repr(C, align({}))]\npub struct {} {{\n    pub data: [u8; {}],\n}}",
+            detail.alignment, name, detail.size
+        ),
+    };
+}
@@ -56,6 +56,7 @@ impl crate::service::Service for Service {
     type ResizableSharedMemory =
         resizable_shared_memory::dynamic::DynamicMemory<PoolAllocator, Self::SharedMemory>;
     type Connection = zero_copy_connection::posix_shared_memory::Connection;
+    type Broadcast = zero_copy_broadcast::posix_shared_memory::Broadcast;
     type Event = event::unix_datagram_socket::EventImpl;
     type Monitoring = monitoring::file_lock::FileLockMonitoring;
     type Reactor = reactor::posix_select::Reactor;
@@ -129,6 +129,13 @@ pub mod builder;
 /// The dynamic configuration of a [`Service`]
 pub mod dynamic_config;
 
+/// A low-latency channel for small, fixed-size control messages that is available in every
+/// [`Service`]
+pub mod control_channel;
+
+/// Key-value attributes of a [`Service`] that can be updated during its lifetime
+pub mod dynamic_attribute;
+
 /// Defines the sample headers for various
 /// [`MessagingPattern`]s
 pub mod header;
@@ -144,6 +151,9 @@ pub mod port_factory;
 /// Represents the name of a [`Service`]
 pub mod service_name;
 
+/// Represents the hierarchical partition a [`Service`] belongs to
+pub mod partition;
+
 /// Represents the unique id of a [`Service`]
 pub mod service_id;
 
@@ -158,6 +168,10 @@ pub mod static_config;
 /// Represents static features of a service that can be set when a [`Service`] is created.
 pub mod attribute;
 
+/// A language-neutral description of a [`Service`] that can be exported into a machine-readable
+/// IDL and converted into type definitions of other languages.
+pub mod idl;
+
 /// A configuration when communicating within a single process or single address space.
 pub mod local;
 
@@ -174,10 +188,13 @@ extern crate alloc;
 use alloc::sync::Arc;
 
 use crate::config;
-use crate::node::{NodeId, NodeListFailure, NodeState, SharedNode};
+use crate::node::evidence_log::{EvidenceEntity, StateTransition};
+use crate::node::{NodeCleanupFailure, NodeId, NodeListFailure, NodeState, SharedNode};
+use crate::port::details::persistent_history::remove_persistent_history;
 use crate::service::config_scheme::dynamic_config_storage_config;
 use crate::service::dynamic_config::DynamicConfig;
 use crate::service::static_config::*;
+use attribute::{AttributeQuery, AttributeSet};
 use config_scheme::service_tag_config;
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_elementary::CallbackProgression;
@@ -195,11 +212,13 @@ use iceoryx2_cal::resizable_shared_memory::ResizableSharedMemoryForPoolAllocator
 use iceoryx2_cal::serialize::Serialize;
 use iceoryx2_cal::shared_memory::SharedMemoryForPoolAllocator;
 use iceoryx2_cal::static_storage::*;
+use iceoryx2_cal::zero_copy_broadcast::ZeroCopyBroadcast;
 use iceoryx2_cal::zero_copy_connection::ZeroCopyConnection;
 use service_id::ServiceId;
 
 use self::dynamic_config::DeregisterNodeState;
 use self::messaging_pattern::MessagingPattern;
+use self::partition::Partition;
 use self::service_name::ServiceName;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -261,6 +280,41 @@ impl core::fmt::Display for ServiceListError {
 
 impl core::error::Error for ServiceListError {}
 
+/// Failure that can be reported by the partition-wide operations like
+/// [`Service::purge_partition()`] or [`Service::freeze_partition()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartitionOperationError {
+    /// The process has insufficient permissions to access all [`Service`]s of the
+    /// [`Partition`](crate::service::partition::Partition).
+    InsufficientPermissions,
+    /// A [`Service`] of the [`Partition`](crate::service::partition::Partition) was created
+    /// with a different iceoryx2 version.
+    VersionMismatch,
+    /// The process received an interrupt signal while the operation was performed.
+    Interrupt,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalError,
+}
+
+impl core::fmt::Display for PartitionOperationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "PartitionOperationError::{:?}", self)
+    }
+}
+
+impl core::error::Error for PartitionOperationError {}
+
+impl From<ServiceListError> for PartitionOperationError {
+    fn from(value: ServiceListError) -> Self {
+        match value {
+            ServiceListError::InsufficientPermissions => {
+                PartitionOperationError::InsufficientPermissions
+            }
+            ServiceListError::InternalError => PartitionOperationError::InternalError,
+        }
+    }
+}
+
 /// Represents all the [`Service`] information that one can acquire with [`Service::list()`]
 /// when the [`Service`] is accessible by the current process.
 #[derive(Debug)]
@@ -302,7 +356,25 @@ impl<S: Service> ServiceState<S> {
             static_storage,
         };
         trace!(from "Service::open()", "open service: {} ({:?})",
-            new_self.static_config.name(), new_self.static_config.service_id());
+            new_self.shared_node.record_evidence(
+            EvidenceEntity::Service,
+            StateTransition::Created,
+            format_args!(
+                "{} ({:?})",
+                new_self.static_config.name(),
+                new_self.static_config.service_id()
+            ),
+        );
+        new_self.static_config.name(), new_self.static_config.service_id());
+        new_self.shared_node.record_evidence(
+            EvidenceEntity::Service,
+            StateTransition::Created,
+            format_args!(
+                "{} ({:?})",
+                new_self.static_config.name(),
+                new_self.static_config.service_id()
+            ),
+        );
         new_self
     }
 }
@@ -311,6 +383,11 @@ impl<S: Service> Drop for ServiceState<S> {
     fn drop(&mut self) {
         let origin = "ServiceState::drop()";
         let id = self.static_config.service_id();
+        self.shared_node.record_evidence(
+            EvidenceEntity::Service,
+            StateTransition::Destroyed,
+            format_args!("{} ({:?})", self.static_config.name(), id),
+        );
         self.shared_node.registered_services().remove(id, |handle| {
             if let Err(e) = remove_service_tag::<S>(self.shared_node.id(), id, self.shared_node.config())
             {
@@ -324,6 +401,18 @@ impl<S: Service> Drop for ServiceState<S> {
                             self.static_config.name(), id);
                 }
                 DeregisterNodeState::NoMoreOwners => {
+                    if let Err(e) = unsafe {
+                        remove_attribute_index::<S>(self.shared_node.config(), &id.0.into())
+                    } {
+                        debug!(from origin, "The attribute index of the service {:?} could not be removed ({:?}).",
+                                id, e);
+                    }
+                    if let Err(e) = unsafe {
+                        remove_persistent_history::<S>(id, self.shared_node.config())
+                    } {
+                        debug!(from origin, "The persistent history of the service {:?} could not be removed ({:?}).",
+                                id, e);
+                    }
                     self.static_storage.acquire_ownership();
                     self.dynamic_storage.acquire_ownership();
                     trace!(from origin, "close and remove service: {} ({:?})",
@@ -342,6 +431,7 @@ pub(crate) mod internal {
     use crate::{
         node::{NodeBuilder, NodeId},
         port::{
+            client::{remove_client_from_all_connections, remove_data_segment_of_client},
             listener::remove_connection_of_listener,
             notifier::Notifier,
             port_identifiers::UniquePortId,
@@ -349,6 +439,8 @@ pub(crate) mod internal {
                 remove_data_segment_of_publisher, remove_publisher_from_all_connections,
                 remove_subscriber_from_all_connections,
             },
+            server::{remove_data_segment_of_server, remove_server_from_all_connections},
+            subscriber::remove_readiness_event_of_subscriber,
         },
         prelude::EventId,
     };
@@ -484,10 +576,43 @@ pub(crate) mod internal {
                             debug!(from origin, "Failed to remove the subscriber ({:?}) from all of its connections ({:?}).", id, e);
                             return PortCleanupAction::SkipPort;
                         }
+
+                        if let Err(e) =
+                            unsafe { remove_readiness_event_of_subscriber::<S>(id, config) }
+                        {
+                            debug!(from origin, "Failed to remove the subscribers ({:?}) readiness event ({:?}).", id, e);
+                            return PortCleanupAction::SkipPort;
+                        }
                     }
                     UniquePortId::Notifier(_) => {
                         number_of_dead_node_notifications += 1;
                     }
+                    UniquePortId::Client(ref id) => {
+                        if let Err(e) =
+                            unsafe { remove_client_from_all_connections::<S>(id, config) }
+                        {
+                            debug!(from origin, "Failed to remove the client ({:?}) from all of its connections ({:?}).", id, e);
+                            return PortCleanupAction::SkipPort;
+                        }
+
+                        if let Err(e) = unsafe { remove_data_segment_of_client::<S>(id, config) } {
+                            debug!(from origin, "Failed to remove the clients ({:?}) data segment ({:?}).", id, e);
+                            return PortCleanupAction::SkipPort;
+                        }
+                    }
+                    UniquePortId::Server(ref id) => {
+                        if let Err(e) =
+                            unsafe { remove_server_from_all_connections::<S>(id, config) }
+                        {
+                            debug!(from origin, "Failed to remove the server ({:?}) from all of its connections ({:?}).", id, e);
+                            return PortCleanupAction::SkipPort;
+                        }
+
+                        if let Err(e) = unsafe { remove_data_segment_of_server::<S>(id, config) } {
+                            debug!(from origin, "Failed to remove the servers ({:?}) data segment ({:?}).", id, e);
+                            return PortCleanupAction::SkipPort;
+                        }
+                    }
                     UniquePortId::Listener(ref id) => {
                         if let Err(e) = unsafe { remove_connection_of_listener::<S>(id, config) } {
                             debug!(from origin, "Failed to remove the listeners ({:?}) connection ({:?}).", id, e);
@@ -516,6 +641,18 @@ pub(crate) mod internal {
                 match unsafe { remove_static_service_config::<S>(config, &service_id.0.into()) } {
                     Ok(_) => {
                         debug!(from origin, "Remove unused service.");
+                        if let Err(e) =
+                            unsafe { remove_attribute_index::<S>(config, &service_id.0.into()) }
+                        {
+                            warn!(from origin, "Unable to remove the attribute index of unused service ({:?}).",
+                                e);
+                        }
+                        if let Err(e) =
+                            unsafe { remove_persistent_history::<S>(service_id, config) }
+                        {
+                            warn!(from origin, "Unable to remove the persistent history of unused service ({:?}).",
+                                e);
+                        }
                         dynamic_config.acquire_ownership()
                     }
                     Err(e) => {
@@ -561,6 +698,10 @@ pub trait Service: Debug + Sized + internal::ServiceInternal<Self> {
     /// The connection used to exchange pointers to the payload
     type Connection: ZeroCopyConnection;
 
+    /// The one-to-many connection used to exchange pointers to the payload when the
+    /// publish-subscribe service has broadcasting enabled
+    type Broadcast: ZeroCopyBroadcast;
+
     /// The mechanism used to signal events between endpoints.
     type Event: Event;
 
@@ -627,6 +768,41 @@ pub trait Service: Debug + Sized + internal::ServiceInternal<Self> {
         details::<Self>(config, &service_id.0.into())
     }
 
+    /// Exports the language-neutral [`ServiceDescription`](crate::service::idl::ServiceDescription)
+    /// of a [`Service`]. It contains the payload layout, the QoS and the attributes and can be
+    /// converted into a machine-readable IDL with
+    /// [`ServiceDescription::to_idl()`](crate::service::idl::ServiceDescription::to_idl()).
+    /// Returns [`None`] when the [`Service`] does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::config::Config;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let name = ServiceName::new("Some/Name")?;
+    /// let description =
+    ///     ipc::Service::export_idl(
+    ///                 &name,
+    ///                 Config::global_config(),
+    ///                 MessagingPattern::Event)?;
+    ///
+    /// if let Some(description) = description {
+    ///     println!("{}", description.to_idl()?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn export_idl(
+        service_name: &ServiceName,
+        config: &config::Config,
+        messaging_pattern: MessagingPattern,
+    ) -> Result<Option<idl::ServiceDescription>, ServiceDetailsError> {
+        Ok(Self::details(service_name, config, messaging_pattern)?
+            .map(|details| idl::ServiceDescription::from(&details.static_details)))
+    }
+
     /// Returns a list of all services created under a given [`config::Config`].
     ///
     /// # Example
@@ -667,6 +843,225 @@ pub trait Service: Debug + Sized + internal::ServiceInternal<Self> {
 
         Ok(())
     }
+
+    /// Returns a list of all services created under a given [`config::Config`] whose
+    /// attributes satisfy the [`AttributeQuery`]. The query is evaluated on a small attribute
+    /// index of every service so that the full static config is only loaded for matching
+    /// services.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::config::Config;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// ipc::Service::list_where(
+    ///     Config::global_config(),
+    ///     &AttributeQuery::key("sensor-type").equals("lidar"),
+    ///     |service| {
+    ///         println!("\n{:#?}", &service);
+    ///         CallbackProgression::Continue
+    ///     },
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn list_where<F: FnMut(ServiceDetails<Self>) -> CallbackProgression>(
+        config: &config::Config,
+        query: &AttributeQuery,
+        mut callback: F,
+    ) -> Result<(), ServiceListError> {
+        let msg = "Unable to list all services that satisfy the attribute query";
+        let origin = "Service::list_where()";
+        let static_storage_config = config_scheme::static_config_storage_config::<Self>(config);
+
+        let service_uuids = fail!(from origin,
+                when <Self::StaticStorage as NamedConceptMgmt>::list_cfg(&static_storage_config),
+                map NamedConceptListError::InsufficientPermissions => ServiceListError::InsufficientPermissions,
+                unmatched ServiceListError::InternalError,
+                "{} due to a failure while collecting all active services for config: {:?}", msg, config);
+
+        for uuid in &service_uuids {
+            if let Some(attributes) = attribute_index::<Self>(config, uuid) {
+                if !query.matches(&attributes) {
+                    continue;
+                }
+            }
+
+            if let Ok(Some(service_details)) = details::<Self>(config, uuid) {
+                if !query.matches(service_details.static_details.attributes()) {
+                    continue;
+                }
+
+                if callback(service_details) == CallbackProgression::Stop {
+                    break;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns a list of all services created under a given [`config::Config`] that belong to
+    /// the provided [`Partition`] or to one of its sub-partitions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::config::Config;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// ipc::Service::list_partition(
+    ///     &Partition::new("vehicle/front")?,
+    ///     Config::global_config(),
+    ///     |service| {
+    ///         println!("\n{:#?}", &service);
+    ///         CallbackProgression::Continue
+    ///     },
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn list_partition<F: FnMut(ServiceDetails<Self>) -> CallbackProgression>(
+        partition: &Partition,
+        config: &config::Config,
+        mut callback: F,
+    ) -> Result<(), ServiceListError> {
+        Self::list(config, |service| match service.static_details.partition() {
+            Some(p) if partition.contains(p) => callback(service),
+            _ => CallbackProgression::Continue,
+        })
+    }
+
+    /// Returns the number of services that belong to the provided [`Partition`] or to one of
+    /// its sub-partitions.
+    fn count_partition(
+        partition: &Partition,
+        config: &config::Config,
+    ) -> Result<usize, ServiceListError> {
+        let mut number_of_services = 0;
+        Self::list_partition(partition, config, |_| {
+            number_of_services += 1;
+            CallbackProgression::Continue
+        })?;
+
+        Ok(number_of_services)
+    }
+
+    /// Removes the stale resources of all dead [`Node`](crate::node::Node)s that are
+    /// registered at a service of the provided [`Partition`] or one of its sub-partitions, as
+    /// well as the leftovers of services whose dynamic configuration no longer exists.
+    /// Returns the number of services that were removed.
+    fn purge_partition(
+        partition: &Partition,
+        config: &config::Config,
+    ) -> Result<usize, PartitionOperationError> {
+        let msg = "Unable to purge partition";
+        let origin = format!("Service::purge_partition({})", partition);
+
+        let mut services = vec![];
+        Self::list_partition(partition, config, |service| {
+            services.push(service);
+            CallbackProgression::Continue
+        })?;
+
+        let mut number_of_removed_services = 0;
+        for service in services {
+            let uuid: FileName = service.static_details.service_id().0.into();
+            let dynamic_details = match service.dynamic_details {
+                Some(dynamic_details) => dynamic_details,
+                None => {
+                    if fail!(from origin, when unsafe { remove_static_service_config::<Self>(config, &uuid) },
+                            map NamedConceptRemoveError::InsufficientPermissions => PartitionOperationError::InsufficientPermissions,
+                            unmatched PartitionOperationError::InternalError,
+                            "{} since the static config of the service {} could not be removed.",
+                            msg, service.static_details.name())
+                    {
+                        if let Err(e) = unsafe { remove_attribute_index::<Self>(config, &uuid) } {
+                            warn!(from origin, "Unable to remove the attribute index of the service {} ({:?}).",
+                                service.static_details.name(), e);
+                        }
+                        number_of_removed_services += 1;
+                    }
+                    continue;
+                }
+            };
+
+            let mut has_removed_dead_nodes = false;
+            for node in dynamic_details.nodes {
+                if let NodeState::Dead(view) = node {
+                    has_removed_dead_nodes |= fail!(from origin, when view.remove_stale_resources(),
+                        map NodeCleanupFailure::Interrupt => PartitionOperationError::Interrupt;
+                            NodeCleanupFailure::InsufficientPermissions => PartitionOperationError::InsufficientPermissions;
+                            NodeCleanupFailure::VersionMismatch => PartitionOperationError::VersionMismatch,
+                        unmatched PartitionOperationError::InternalError,
+                        "{} since the stale resources of a dead node of the service {} could not be removed.",
+                        msg, service.static_details.name());
+                }
+            }
+
+            if has_removed_dead_nodes && matches!(details::<Self>(config, &uuid), Ok(None)) {
+                number_of_removed_services += 1;
+            }
+        }
+
+        Ok(number_of_removed_services)
+    }
+
+    /// Freezes all services of the provided [`Partition`] and its sub-partitions. A frozen
+    /// service does not accept new [`Node`](crate::node::Node)s, opening it fails with an
+    /// `IsFrozen` error. Nodes that already opened the service are not affected. Returns the
+    /// number of services that were not already frozen.
+    fn freeze_partition(
+        partition: &Partition,
+        config: &config::Config,
+    ) -> Result<usize, PartitionOperationError> {
+        set_partition_frozen::<Self>(partition, config, true)
+    }
+
+    /// Unfreezes all services of the provided [`Partition`] and its sub-partitions that were
+    /// frozen with [`Service::freeze_partition()`]. Returns the number of services that were
+    /// frozen.
+    fn unfreeze_partition(
+        partition: &Partition,
+        config: &config::Config,
+    ) -> Result<usize, PartitionOperationError> {
+        set_partition_frozen::<Self>(partition, config, false)
+    }
+}
+
+fn set_partition_frozen<S: Service>(
+    partition: &Partition,
+    config: &config::Config,
+    value: bool,
+) -> Result<usize, PartitionOperationError> {
+    let msg = "Unable to change the frozen state of the partition";
+    let origin = format!("Service::set_partition_frozen({}, {})", partition, value);
+
+    let mut service_ids = vec![];
+    S::list_partition(partition, config, |service| {
+        service_ids.push(service.static_details.service_id().clone());
+        CallbackProgression::Continue
+    })?;
+
+    let mut number_of_changed_services = 0;
+    for service_id in &service_ids {
+        let dynamic_config = fail!(from origin, when open_dynamic_config::<S>(config, service_id),
+            map ServiceDetailsError::VersionMismatch => PartitionOperationError::VersionMismatch,
+            unmatched PartitionOperationError::InternalError,
+            "{} since the dynamic config of the service {:?} could not be opened.",
+            msg, service_id);
+
+        if let Some(dynamic_config) = dynamic_config {
+            if dynamic_config.get().set_frozen(value) {
+                number_of_changed_services += 1;
+            }
+        }
+    }
+
+    Ok(number_of_changed_services)
 }
 
 pub(crate) unsafe fn remove_static_service_config<S: Service>(
@@ -685,7 +1080,41 @@ pub(crate) unsafe fn remove_static_service_config<S: Service>(
     }
 }
 
-fn details<S: Service>(
+pub(crate) unsafe fn remove_attribute_index<S: Service>(
+    config: &config::Config,
+    uuid: &FileName,
+) -> Result<bool, NamedConceptRemoveError> {
+    let msg = "Unable to remove attribute index";
+    let origin = "Service::remove_attribute_index()";
+    let attribute_index_config = config_scheme::attribute_index_config::<S>(config);
+
+    match <S::StaticStorage as NamedConceptMgmt>::remove_cfg(uuid, &attribute_index_config) {
+        Ok(v) => Ok(v),
+        Err(e) => {
+            fail!(from origin, with e, "{msg} due to ({:?}).", e);
+        }
+    }
+}
+
+// Returns [`None`] when the attribute index does not exist or is not readable, for instance
+// when the service was created by an older version that did not write it.
+fn attribute_index<S: Service>(config: &config::Config, uuid: &FileName) -> Option<AttributeSet> {
+    let attribute_index_config = config_scheme::attribute_index_config::<S>(config);
+
+    let reader = <<S::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<
+        S::StaticStorage,
+    >>::new(uuid)
+    .config(&attribute_index_config)
+    .has_ownership(false)
+    .open(Duration::ZERO)
+    .ok()?;
+
+    let mut content = vec![0u8; reader.len() as usize];
+    reader.read(content.as_mut_slice()).ok()?;
+    S::ConfigSerializer::deserialize::<AttributeSet>(&content).ok()
+}
+
+pub(crate) fn details<S: Service>(
     config: &config::Config,
     uuid: &FileName,
 ) -> Result<Option<ServiceDetails<S>>, ServiceDetailsError> {
@@ -759,7 +1188,7 @@ fn details<S: Service>(
     }))
 }
 
-fn open_dynamic_config<S: Service>(
+pub(crate) fn open_dynamic_config<S: Service>(
     config: &config::Config,
     service_id: &ServiceId,
 ) -> Result<Option<S::DynamicStorage>, ServiceDetailsError> {
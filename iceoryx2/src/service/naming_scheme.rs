@@ -10,7 +10,10 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use crate::port::port_identifiers::{UniqueListenerId, UniquePublisherId, UniqueSubscriberId};
+use crate::port::port_identifiers::{
+    UniqueClientId, UniqueListenerId, UniquePublisherId, UniqueServerId, UniqueSubscriberId,
+};
+use crate::service::service_id::ServiceId;
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_system_types::file_name::FileName;
@@ -34,6 +37,10 @@ pub(crate) fn connection_name(
     file
 }
 
+pub(crate) fn broadcast_name(publisher_id: UniquePublisherId) -> FileName {
+    FileName::new(publisher_id.0.value().to_string().as_bytes()).unwrap()
+}
+
 pub(crate) fn extract_publisher_id_from_connection(connection: &FileName) -> UniquePublisherId {
     let name = core::str::from_utf8(connection.as_bytes()).unwrap();
     let publisher_id = &name[..name.find('_').unwrap()];
@@ -50,6 +57,37 @@ pub(crate) fn extract_subscriber_id_from_connection(connection: &FileName) -> Un
     unsafe { core::mem::transmute::<u128, UniqueSubscriberId>(value) }
 }
 
+fn connection_name_from_raw_ids(sender_id: u128, receiver_id: u128) -> FileName {
+    let mut file = FileName::new(sender_id.to_string().as_bytes()).unwrap();
+    file.push(b'_').unwrap();
+    file.push_bytes(receiver_id.to_string().as_bytes()).unwrap();
+    file
+}
+
+pub(crate) fn request_connection_name(
+    client_id: UniqueClientId,
+    server_id: UniqueServerId,
+) -> FileName {
+    connection_name_from_raw_ids(client_id.value(), server_id.value())
+}
+
+pub(crate) fn response_connection_name(
+    server_id: UniqueServerId,
+    client_id: UniqueClientId,
+) -> FileName {
+    connection_name_from_raw_ids(server_id.value(), client_id.value())
+}
+
+pub(crate) fn extract_sender_id_from_connection(connection: &FileName) -> u128 {
+    let name = core::str::from_utf8(connection.as_bytes()).unwrap();
+    name[..name.find('_').unwrap()].parse::<u128>().unwrap()
+}
+
+pub(crate) fn extract_receiver_id_from_connection(connection: &FileName) -> u128 {
+    let name = core::str::from_utf8(connection.as_bytes()).unwrap();
+    name[name.find('_').unwrap() + 1..].parse::<u128>().unwrap()
+}
+
 pub(crate) fn data_segment_name(publisher_id: &UniquePublisherId) -> FileName {
     let msg = "The system does not support the required file name length for the publishers data segment.";
     let origin = "data_segment_name()";
@@ -58,3 +96,52 @@ pub(crate) fn data_segment_name(publisher_id: &UniquePublisherId) -> FileName {
                  when FileName::new(publisher_id.0.value().to_string().as_bytes()),
                  "{}", msg)
 }
+
+pub(crate) fn reclaim_event_name(publisher_id: &UniquePublisherId) -> FileName {
+    let msg = "The system does not support the required file name length for the publishers reclaim event.";
+    let origin = "reclaim_event_name()";
+
+    fatal_panic!(from origin,
+                 when FileName::new(publisher_id.0.value().to_string().as_bytes()),
+                 "{}", msg)
+}
+
+pub(crate) fn readiness_event_name(subscriber_id: &UniqueSubscriberId) -> FileName {
+    let msg = "The system does not support the required file name length for the subscribers readiness event.";
+    let origin = "readiness_event_name()";
+
+    fatal_panic!(from origin,
+                 when FileName::new(subscriber_id.0.value().to_string().as_bytes()),
+                 "{}", msg)
+}
+
+pub(crate) fn persistent_history_name(service_id: &ServiceId) -> FileName {
+    let msg = "The system does not support the required file name length for the persistent history of the service.";
+    let origin = "persistent_history_name()";
+
+    let mut file: FileName = service_id.0.into();
+    fatal_panic!(from origin,
+                 when file.push_bytes(b"_history"),
+                 "{}", msg);
+    file
+}
+
+pub(crate) fn client_data_segment_name(client_id: &UniqueClientId) -> FileName {
+    let msg =
+        "The system does not support the required file name length for the clients data segment.";
+    let origin = "client_data_segment_name()";
+
+    fatal_panic!(from origin,
+                 when FileName::new(client_id.value().to_string().as_bytes()),
+                 "{}", msg)
+}
+
+pub(crate) fn server_data_segment_name(server_id: &UniqueServerId) -> FileName {
+    let msg =
+        "The system does not support the required file name length for the servers data segment.";
+    let origin = "server_data_segment_name()";
+
+    fatal_panic!(from origin,
+                 when FileName::new(server_id.value().to_string().as_bytes()),
+                 "{}", msg)
+}
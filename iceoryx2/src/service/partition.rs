@@ -0,0 +1,144 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//!
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .partition(&Partition::new("vehicle/front")?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! // all services of the partition "vehicle" and its sub-partitions, like "vehicle/front"
+//! let number_of_services =
+//!     ipc::Service::count_partition(&Partition::new("vehicle")?, node.config())?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use iceoryx2_bb_container::semantic_string::SemanticStringError;
+use serde::{de::Visitor, Deserialize, Serialize};
+
+const SEPARATOR: char = '/';
+
+/// The hierarchical partition of a [`Service`](crate::service::Service). Its segments are
+/// separated by `/`, a partition contains all partitions that start with its segments, e.g.
+/// `vehicle` contains `vehicle/front` but not `vehicles`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Partition {
+    value: String,
+}
+
+impl Partition {
+    /// Creates a new [`Partition`]. The partition is not allowed to be empty and is not allowed
+    /// to contain empty segments, like a leading, trailing or double `/`.
+    pub fn new(name: &str) -> Result<Self, SemanticStringError> {
+        if name.is_empty() || name.split(SEPARATOR).any(|segment| segment.is_empty()) {
+            return Err(SemanticStringError::InvalidContent);
+        }
+
+        Ok(Self { value: name.into() })
+    }
+
+    /// Returns a str reference to the [`Partition`]
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns true when `other` is either the same [`Partition`] or one of its
+    /// sub-partitions.
+    pub fn contains(&self, other: &Partition) -> bool {
+        match other.value.strip_prefix(self.as_str()) {
+            Some(rest) => rest.is_empty() || rest.starts_with(SEPARATOR),
+            None => false,
+        }
+    }
+
+    /// Returns the parent [`Partition`] or [`None`] when it is a top-level [`Partition`].
+    pub fn parent(&self) -> Option<Partition> {
+        self.value.rsplit_once(SEPARATOR).map(|(parent, _)| Self {
+            value: parent.into(),
+        })
+    }
+}
+
+impl core::fmt::Display for Partition {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "{}", self.value)
+    }
+}
+
+impl TryInto<Partition> for &str {
+    type Error = SemanticStringError;
+
+    fn try_into(self) -> Result<Partition, Self::Error> {
+        Partition::new(self)
+    }
+}
+
+impl PartialEq<&str> for Partition {
+    fn eq(&self, other: &&str) -> bool {
+        *self.as_str() == **other
+    }
+}
+
+impl core::ops::Deref for Partition {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+struct PartitionVisitor;
+
+impl Visitor<'_> for PartitionVisitor {
+    type Value = Partition;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("a string containing the partition")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match Partition::new(v) {
+            Ok(v) => Ok(v),
+            Err(v) => Err(E::custom(format!("invalid partition provided {:?}.", v))),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Partition {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(PartitionVisitor)
+    }
+}
+
+impl Serialize for Partition {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
@@ -0,0 +1,173 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let req_res = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let client = req_res.client_builder().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::shm_allocator::AllocationStrategy;
+
+use crate::port::client::{Client, ClientCreateError};
+use crate::port::port_identifiers::UniqueServerId;
+use crate::port::ServerConnectedCallback;
+use crate::service;
+
+use super::request_response::PortFactory;
+
+#[derive(Debug)]
+pub(crate) struct LocalClientConfig {
+    pub(crate) initial_max_slice_len: usize,
+    pub(crate) allocation_strategy: AllocationStrategy,
+    pub(crate) server_connected_callback: Option<ServerConnectedCallback<'static>>,
+}
+
+/// Factory to create a new [`Client`] port/endpoint for
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+/// based communication.
+#[derive(Debug)]
+pub struct PortFactoryClient<
+    'factory,
+    Service: service::Service,
+    RequestPayload: Debug + ?Sized,
+    RequestHeader: Debug,
+    ResponsePayload: Debug + ?Sized,
+    ResponseHeader: Debug,
+> {
+    config: LocalClientConfig,
+    pub(crate) factory: &'factory PortFactory<
+        Service,
+        RequestPayload,
+        RequestHeader,
+        ResponsePayload,
+        ResponseHeader,
+    >,
+}
+
+impl<
+        'factory,
+        Service: service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    >
+    PortFactoryClient<
+        'factory,
+        Service,
+        RequestPayload,
+        RequestHeader,
+        ResponsePayload,
+        ResponseHeader,
+    >
+{
+    pub(crate) fn new(
+        factory: &'factory PortFactory<
+            Service,
+            RequestPayload,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+    ) -> Self {
+        Self {
+            config: LocalClientConfig {
+                initial_max_slice_len: 1,
+                allocation_strategy: AllocationStrategy::Static,
+                server_connected_callback: None,
+            },
+            factory,
+        }
+    }
+
+    /// Sets the [`ServerConnectedCallback`] of the [`Client`]. It is called with the
+    /// [`UniqueServerId`] of every [`Server`](crate::port::server::Server) the [`Client`]
+    /// establishes a connection to, when the [`Client`] is created, in
+    /// [`Client::prepare_connections()`] or whenever the connections are updated. Latency
+    /// critical applications can use it to prepare themselves for a new
+    /// [`Server`](crate::port::server::Server) before the first request is sent.
+    pub fn set_server_connected_callback<F: Fn(UniqueServerId) + 'static>(
+        mut self,
+        callback: Option<F>,
+    ) -> Self {
+        match callback {
+            Some(c) => {
+                self.config.server_connected_callback = Some(ServerConnectedCallback::new(c))
+            }
+            None => self.config.server_connected_callback = None,
+        }
+
+        self
+    }
+
+    /// Creates a new [`Client`] port or returns a [`ClientCreateError`] on failure.
+    pub fn create(
+        self,
+    ) -> Result<
+        Client<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>,
+        ClientCreateError,
+    > {
+        let origin = format!("{:?}", self);
+        let static_config = self
+            .factory
+            .service
+            .__internal_state()
+            .static_config
+            .request_response();
+
+        Ok(
+            fail!(from origin, when Client::new(&self.factory.service, static_config, self.config),
+                "Failed to create new Client port."),
+        )
+    }
+}
+
+impl<
+        Service: service::Service,
+        RequestPayload: Debug,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    >
+    PortFactoryClient<'_, Service, [RequestPayload], RequestHeader, ResponsePayload, ResponseHeader>
+{
+    /// Sets the maximum slice length that a user can allocate with
+    /// [`Client::loan_slice()`] or [`Client::loan_slice_uninit()`].
+    pub fn initial_max_slice_len(mut self, value: usize) -> Self {
+        self.config.initial_max_slice_len = value;
+        self
+    }
+
+    /// Defines the allocation strategy that is used when the provided
+    /// [`PortFactoryClient::initial_max_slice_len()`] is exhausted. This happens when the user
+    /// acquires a more than max slice len in [`Client::loan_slice()`] or
+    /// [`Client::loan_slice_uninit()`].
+    pub fn allocation_strategy(mut self, value: AllocationStrategy) -> Self {
+        self.config.allocation_strategy = value;
+        self
+    }
+}
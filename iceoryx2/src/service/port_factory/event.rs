@@ -39,6 +39,8 @@ use iceoryx2_cal::dynamic_storage::DynamicStorage;
 
 use crate::node::NodeListFailure;
 use crate::service::attribute::AttributeSet;
+use crate::service::control_channel::ControlChannel;
+use crate::service::dynamic_attribute::DynamicAttributes;
 use crate::service::service_id::ServiceId;
 use crate::service::{self, static_config};
 use crate::service::{dynamic_config, ServiceName};
@@ -98,6 +100,10 @@ impl<Service: service::Service> crate::service::port_factory::PortFactory for Po
             callback,
         )
     }
+
+    fn attributes_mut(&self) -> DynamicAttributes<Service> {
+        DynamicAttributes::new(self.service.__internal_state().clone())
+    }
 }
 
 impl<Service: service::Service> PortFactory<Service> {
@@ -105,6 +111,13 @@ impl<Service: service::Service> PortFactory<Service> {
         Self { service }
     }
 
+    /// Returns a new handle to the [`ControlChannel`] of the [`crate::service::Service`]. It
+    /// can be used to exchange small, fixed-size messages for lightweight coordination between
+    /// all participants without creating an additional [`crate::service::Service`].
+    pub fn control_channel(&self) -> ControlChannel<Service> {
+        ControlChannel::new(self.service.__internal_state().clone())
+    }
+
     /// Returns a [`PortFactoryNotifier`] to create a new [`crate::port::notifier::Notifier`] port
     ///
     /// # Example
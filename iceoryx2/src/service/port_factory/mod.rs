@@ -15,6 +15,7 @@ use iceoryx2_bb_elementary::CallbackProgression;
 use crate::config::Config;
 use crate::node::{NodeListFailure, NodeState};
 
+use super::dynamic_attribute::DynamicAttributes;
 use super::dynamic_config::DynamicConfig;
 use super::service_id::ServiceId;
 use super::{attribute::AttributeSet, service_name::ServiceName};
@@ -26,6 +27,9 @@ pub mod request_response;
 /// communication and to acquire static and dynamic service information
 pub mod event;
 
+/// Factory to create a [`Client`](crate::port::client::Client)
+pub mod client;
+
 /// Factory to create a [`Listener`](crate::port::listener::Listener)
 pub mod listener;
 
@@ -40,6 +44,9 @@ pub mod publish_subscribe;
 /// Factory to create a [`Publisher`](crate::port::publisher::Publisher)
 pub mod publisher;
 
+/// Factory to create a [`Server`](crate::port::server::Server)
+pub mod server;
+
 /// Factory to create a [`Subscriber`](crate::port::subscriber::Subscriber)
 pub mod subscriber;
 
@@ -82,6 +89,11 @@ pub trait PortFactory {
         &self,
         callback: F,
     ) -> Result<(), NodeListFailure>;
+
+    /// Returns a new handle to the [`DynamicAttributes`] of the [`crate::service::Service`].
+    /// In contrast to [`PortFactory::attributes()`] they can be updated during the lifetime of
+    /// the [`crate::service::Service`].
+    fn attributes_mut(&self) -> DynamicAttributes<Self::Service>;
 }
 
 pub(crate) fn nodes<
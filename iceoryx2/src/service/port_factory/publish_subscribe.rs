@@ -47,6 +47,8 @@ use iceoryx2_cal::dynamic_storage::DynamicStorage;
 
 use crate::node::NodeListFailure;
 use crate::service::attribute::AttributeSet;
+use crate::service::control_channel::ControlChannel;
+use crate::service::dynamic_attribute::DynamicAttributes;
 use crate::service::service_id::ServiceId;
 use crate::service::service_name::ServiceName;
 use crate::service::{self, dynamic_config, static_config};
@@ -119,6 +121,10 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             callback,
         )
     }
+
+    fn attributes_mut(&self) -> DynamicAttributes<Service> {
+        DynamicAttributes::new(self.service.__internal_state().clone())
+    }
 }
 
 impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
@@ -132,6 +138,13 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         }
     }
 
+    /// Returns a new handle to the [`ControlChannel`] of the [`crate::service::Service`]. It
+    /// can be used to exchange small, fixed-size messages for lightweight coordination between
+    /// all participants without creating an additional [`crate::service::Service`].
+    pub fn control_channel(&self) -> ControlChannel<Service> {
+        ControlChannel::new(self.service.__internal_state().clone())
+    }
+
     /// Returns a [`PortFactorySubscriber`] to create a new
     /// [`crate::port::subscriber::Subscriber`] port.
     ///
@@ -55,6 +55,7 @@
 //! ```
 
 use core::fmt::Debug;
+use core::time::Duration;
 
 use iceoryx2_bb_log::fail;
 use iceoryx2_cal::shm_allocator::AllocationStrategy;
@@ -66,9 +67,14 @@ use crate::{
         port_identifiers::{UniquePublisherId, UniqueSubscriberId},
         publisher::Publisher,
         publisher::PublisherCreateError,
-        DegrationAction, DegrationCallback,
+        serde_publisher::SerdePublisher,
+        BackpressureCallback, BackpressureEvent, DegrationAction, DegrationCallback,
+        DeliveryFilter,
+    },
+    service::{
+        self, dynamic_config::publish_subscribe::SubscriberDetails,
+        port_factory::subscriber::DeliveryQos,
     },
-    service,
 };
 
 /// Defines the strategy the [`Publisher`] shall pursue in
@@ -80,8 +86,21 @@ pub enum UnableToDeliverStrategy {
     /// Blocks until the [`crate::port::subscriber::Subscriber`] has consumed the
     /// [`crate::sample::Sample`] from the buffer and there is space again
     Block,
+    /// Blocks like [`UnableToDeliverStrategy::Block`] but at most for the provided timeout.
+    /// When the [`crate::port::subscriber::Subscriber`] did not consume a
+    /// [`crate::sample::Sample`] until then, the [`crate::sample::Sample`] is not delivered to
+    /// it. In the config file, it is written as `BlockWithTimeout(<value><unit>)` with one of
+    /// the units `ns`, `us`, `ms` or `s`, e.g. `BlockWithTimeout(250ms)`.
+    BlockWithTimeout(Duration),
     /// Do not deliver the [`crate::sample::Sample`].
     DiscardSample,
+    /// Removes the oldest [`crate::sample::Sample`] that the
+    /// [`crate::port::subscriber::Subscriber`] has not yet received from its buffer and
+    /// delivers the new [`crate::sample::Sample`] instead, like a service with safe overflow
+    /// would do for every [`crate::port::subscriber::Subscriber`]. When the service has
+    /// broadcasting enabled, it behaves like [`UnableToDeliverStrategy::DiscardSample`] since
+    /// all [`crate::port::subscriber::Subscriber`]s share one buffer.
+    DiscardOldest,
 }
 
 impl Serialize for UnableToDeliverStrategy {
@@ -89,17 +108,52 @@ impl Serialize for UnableToDeliverStrategy {
     where
         S: serde::Serializer,
     {
-        serializer.serialize_str(&std::format!("{:?}", self))
+        match self {
+            UnableToDeliverStrategy::BlockWithTimeout(timeout) => {
+                let nanos = timeout.as_nanos();
+                let value = match nanos {
+                    n if n % 1_000_000_000 == 0 => std::format!("{}s", n / 1_000_000_000),
+                    n if n % 1_000_000 == 0 => std::format!("{}ms", n / 1_000_000),
+                    n if n % 1_000 == 0 => std::format!("{}us", n / 1_000),
+                    n => std::format!("{}ns", n),
+                };
+                serializer.serialize_str(&std::format!("BlockWithTimeout({})", value))
+            }
+            _ => serializer.serialize_str(&std::format!("{:?}", self)),
+        }
     }
 }
 
+fn parse_timeout(value: &str) -> Option<Duration> {
+    let (number, factor) = if let Some(v) = value.strip_suffix("ns") {
+        (v, 1)
+    } else if let Some(v) = value.strip_suffix("us") {
+        (v, 1_000)
+    } else if let Some(v) = value.strip_suffix("ms") {
+        (v, 1_000_000)
+    } else if let Some(v) = value.strip_suffix('s') {
+        (v, 1_000_000_000)
+    } else {
+        return None;
+    };
+
+    number
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .and_then(|v| v.checked_mul(factor))
+        .map(Duration::from_nanos)
+}
+
 struct UnableToDeliverStrategyVisitor;
 
 impl Visitor<'_> for UnableToDeliverStrategyVisitor {
     type Value = UnableToDeliverStrategy;
 
     fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
-        formatter.write_str("a string containing either 'Block' or 'DiscardSample'")
+        formatter.write_str(
+            "a string containing either 'Block', 'BlockWithTimeout(<value><unit>)', 'DiscardSample' or 'DiscardOldest'",
+        )
     }
 
     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
@@ -109,10 +163,18 @@ impl Visitor<'_> for UnableToDeliverStrategyVisitor {
         match v {
             "Block" => Ok(UnableToDeliverStrategy::Block),
             "DiscardSample" => Ok(UnableToDeliverStrategy::DiscardSample),
-            v => Err(E::custom(format!(
-                "Invalid UnableToDeliverStrategy provided: \"{:?}\".",
-                v
-            ))),
+            "DiscardOldest" => Ok(UnableToDeliverStrategy::DiscardOldest),
+            v => match v
+                .strip_prefix("BlockWithTimeout(")
+                .and_then(|v| v.strip_suffix(')'))
+                .and_then(parse_timeout)
+            {
+                Some(timeout) => Ok(UnableToDeliverStrategy::BlockWithTimeout(timeout)),
+                None => Err(E::custom(format!(
+                    "Invalid UnableToDeliverStrategy provided: \"{:?}\".",
+                    v
+                ))),
+            },
         }
     }
 }
@@ -126,13 +188,62 @@ impl<'de> Deserialize<'de> for UnableToDeliverStrategy {
     }
 }
 
+/// Defines where the [`Publisher`] stores the samples of its history, see
+/// [`Builder::history_size()`](crate::service::builder::publish_subscribe::Builder::history_size()).
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub enum HistoryPersistence {
+    /// The history lives in the process memory of the [`Publisher`] and is lost when the
+    /// [`Publisher`] is dropped.
+    #[default]
+    Volatile,
+    /// Additionally to the process memory, the history is stored in a dedicated shared memory
+    /// of the [`Service`](crate::service::Service). A re-created [`Publisher`] with the same
+    /// setting restores the last samples from it and delivers them to late-joining
+    /// [`crate::port::subscriber::Subscriber`]s. The shared memory lives as long as the
+    /// [`Service`](crate::service::Service) exists and is shared by all [`Publisher`]s of the
+    /// [`Service`](crate::service::Service) that use this setting.
+    SharedMemory,
+}
+
 #[derive(Debug)]
 pub(crate) struct LocalPublisherConfig {
     pub(crate) max_loaned_samples: usize,
+    pub(crate) max_loaned_samples_limit: Option<usize>,
     pub(crate) unable_to_deliver_strategy: UnableToDeliverStrategy,
+    pub(crate) critical_unable_to_deliver_strategy: Option<UnableToDeliverStrategy>,
+    pub(crate) best_effort_unable_to_deliver_strategy: Option<UnableToDeliverStrategy>,
     pub(crate) degration_callback: Option<DegrationCallback<'static>>,
+    pub(crate) delivery_filter: Option<DeliveryFilter<'static>>,
+    pub(crate) backpressure_callback: Option<BackpressureCallback<'static>>,
+    pub(crate) high_watermark: Option<usize>,
+    pub(crate) low_watermark: Option<usize>,
+    pub(crate) pacing: Option<usize>,
     pub(crate) initial_max_slice_len: usize,
     pub(crate) allocation_strategy: AllocationStrategy,
+    pub(crate) initially_ready: bool,
+    pub(crate) history_persistence: HistoryPersistence,
+}
+
+impl LocalPublisherConfig {
+    /// The upper bound up to which the number of parallel loans can be raised at runtime.
+    pub(crate) fn max_loaned_samples_limit(&self) -> usize {
+        self.max_loaned_samples_limit
+            .unwrap_or(0)
+            .max(self.max_loaned_samples)
+    }
+
+    pub(crate) fn unable_to_deliver_strategy_for(
+        &self,
+        qos: DeliveryQos,
+    ) -> UnableToDeliverStrategy {
+        let strategy = match qos {
+            DeliveryQos::Regular => None,
+            DeliveryQos::Critical => self.critical_unable_to_deliver_strategy,
+            DeliveryQos::BestEffort => self.best_effort_unable_to_deliver_strategy,
+        };
+
+        strategy.unwrap_or(self.unable_to_deliver_strategy)
+    }
 }
 
 /// Factory to create a new [`Publisher`] port/endpoint for
@@ -156,8 +267,18 @@ impl<'factory, Service: service::Service, Payload: Debug + ?Sized, UserHeader: D
         Self {
             config: LocalPublisherConfig {
                 allocation_strategy: AllocationStrategy::Static,
+                critical_unable_to_deliver_strategy: None,
+                best_effort_unable_to_deliver_strategy: None,
                 degration_callback: None,
+                delivery_filter: None,
+                backpressure_callback: None,
+                high_watermark: None,
+                low_watermark: None,
+                pacing: None,
                 initial_max_slice_len: 1,
+                initially_ready: true,
+                history_persistence: HistoryPersistence::default(),
+                max_loaned_samples_limit: None,
                 max_loaned_samples: factory
                     .service
                     .__internal_state()
@@ -187,12 +308,64 @@ impl<'factory, Service: service::Service, Payload: Debug + ?Sized, UserHeader: D
         self
     }
 
+    /// Defines the upper bound up to which the number of parallel loans can be raised at
+    /// runtime with [`Publisher::set_max_loaned_samples()`]. The data segment of the
+    /// [`Publisher`] is sized for the limit. When it is not set or smaller than
+    /// [`PortFactoryPublisher::max_loaned_samples()`], the number of parallel loans cannot be
+    /// raised.
+    pub fn max_loaned_samples_limit(mut self, value: usize) -> Self {
+        self.config.max_loaned_samples_limit = Some(value);
+        self
+    }
+
+    /// Defines if the [`Publisher`] is ready when it is created. A [`Publisher`] that is not
+    /// ready marks all samples as not ready until [`Publisher::announce_ready()`] is called.
+    /// [`crate::port::subscriber::Subscriber`]s can ignore those samples or wait for a ready
+    /// [`Publisher`]. By default, the [`Publisher`] is ready.
+    pub fn initially_ready(mut self, value: bool) -> Self {
+        self.config.initially_ready = value;
+        self
+    }
+
+    /// Defines where the history of the [`Publisher`] is stored, see [`HistoryPersistence`].
+    /// By default, it is [`HistoryPersistence::Volatile`].
+    pub fn history_persistence(mut self, value: HistoryPersistence) -> Self {
+        self.config.history_persistence = value;
+        self
+    }
+
     /// Sets the [`UnableToDeliverStrategy`].
     pub fn unable_to_deliver_strategy(mut self, value: UnableToDeliverStrategy) -> Self {
         self.config.unable_to_deliver_strategy = value;
         self
     }
 
+    /// Sets the [`UnableToDeliverStrategy`] for all [`crate::port::subscriber::Subscriber`]s
+    /// with the provided [`DeliveryQos`] hint, e.g. blocking for
+    /// [`DeliveryQos::Critical`] and discarding for [`DeliveryQos::BestEffort`]
+    /// [`crate::port::subscriber::Subscriber`]s. When it is not set for a [`DeliveryQos`], the
+    /// strategy of [`PortFactoryPublisher::unable_to_deliver_strategy()`] is used. Setting it
+    /// for [`DeliveryQos::Regular`] is identical to
+    /// [`PortFactoryPublisher::unable_to_deliver_strategy()`].
+    ///
+    /// When the service has broadcasting enabled, all
+    /// [`crate::port::subscriber::Subscriber`]s share one buffer and only the regular
+    /// strategy is used.
+    pub fn unable_to_deliver_strategy_for(
+        mut self,
+        qos: DeliveryQos,
+        value: UnableToDeliverStrategy,
+    ) -> Self {
+        match qos {
+            DeliveryQos::Regular => self.config.unable_to_deliver_strategy = value,
+            DeliveryQos::Critical => self.config.critical_unable_to_deliver_strategy = Some(value),
+            DeliveryQos::BestEffort => {
+                self.config.best_effort_unable_to_deliver_strategy = Some(value)
+            }
+        }
+        self
+    }
+
     /// Sets the [`DegrationCallback`] of the [`Publisher`]. Whenever a connection to a
     /// [`crate::port::subscriber::Subscriber`] is corrupted or it seems to be dead, this callback
     /// is called and depending on the returned [`DegrationAction`] measures will be taken.
@@ -215,6 +388,68 @@ impl<'factory, Service: service::Service, Payload: Debug + ?Sized, UserHeader: D
         self
     }
 
+    /// Sets a filter that is evaluated once for every connected
+    /// [`crate::port::subscriber::Subscriber`]. Samples, including the history, are only
+    /// delivered to [`crate::port::subscriber::Subscriber`]s for which the filter returns
+    /// `true`. By default, samples are delivered to every
+    /// [`crate::port::subscriber::Subscriber`].
+    pub fn delivery_filter<F: Fn(SubscriberDetails) -> bool + 'static>(
+        mut self,
+        filter: F,
+    ) -> Self {
+        self.config.delivery_filter = Some(DeliveryFilter::new(filter));
+        self
+    }
+
+    /// Defines the number of samples in the buffer of a connected
+    /// [`crate::port::subscriber::Subscriber`] at which the [`Publisher`] reports a
+    /// [`BackpressureEvent::HighWatermarkReached`]. This allows to react before samples are
+    /// blocked, discarded or overflow. The fill level is checked whenever a sample is
+    /// delivered and when [`Publisher::update_connections()`] is called. By default, no
+    /// watermark is set.
+    pub fn high_watermark(mut self, value: usize) -> Self {
+        self.config.high_watermark = Some(value);
+        self
+    }
+
+    /// Defines the number of samples in the buffer of a connected
+    /// [`crate::port::subscriber::Subscriber`] at which the [`Publisher`] reports a
+    /// [`BackpressureEvent::LowWatermarkReached`] after the high watermark was reached. When
+    /// it is not set or not less than the [`PortFactoryPublisher::high_watermark()`], it is
+    /// one less than the high watermark.
+    pub fn low_watermark(mut self, value: usize) -> Self {
+        self.config.low_watermark = Some(value);
+        self
+    }
+
+    /// Sets the [`BackpressureCallback`] of the [`Publisher`]. It is called whenever the fill
+    /// level of the buffer of a connected [`crate::port::subscriber::Subscriber`] crosses the
+    /// [`PortFactoryPublisher::high_watermark()`] or the
+    /// [`PortFactoryPublisher::low_watermark()`]. Without a callback, reaching the high
+    /// watermark is logged as warning.
+    pub fn set_backpressure_callback<F: Fn(UniqueSubscriberId, BackpressureEvent) + 'static>(
+        mut self,
+        callback: Option<F>,
+    ) -> Self {
+        match callback {
+            Some(c) => self.config.backpressure_callback = Some(BackpressureCallback::new(c)),
+            None => self.config.backpressure_callback = None,
+        }
+
+        self
+    }
+
+    /// Limits the number of samples the [`Publisher`] delivers into the buffer of every
+    /// connected [`crate::port::subscriber::Subscriber`] to `max_samples_per_ms` per
+    /// millisecond. A burst of samples is spread out over time, the sending thread is
+    /// suspended until the next delivery is allowed, instead of overflowing small
+    /// [`crate::port::subscriber::Subscriber`] buffers. The history is delivered without
+    /// pacing. By default, no pacing is applied.
+    pub fn pacing(mut self, max_samples_per_ms: usize) -> Self {
+        self.config.pacing = Some(max_samples_per_ms);
+        self
+    }
+
     /// Creates a new [`Publisher`] or returns a [`PublisherCreateError`] on failure.
     pub fn create(self) -> Result<Publisher<Service, Payload, UserHeader>, PublisherCreateError> {
         let origin = format!("{:?}", self);
@@ -244,3 +479,14 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
         self
     }
 }
+
+impl<Service: service::Service> PortFactoryPublisher<'_, Service, [u8], ()> {
+    /// Creates a new [`SerdePublisher`] for a service created with
+    /// [`Builder::publish_subscribe_serde()`](crate::service::builder::Builder::publish_subscribe_serde())
+    /// or returns a [`PublisherCreateError`] on failure.
+    pub fn create_serde_publisher<Payload: Debug + Serialize>(
+        self,
+    ) -> Result<SerdePublisher<Service, Payload>, PublisherCreateError> {
+        Ok(SerdePublisher::new(self.create()?))
+    }
+}
@@ -41,6 +41,9 @@
 //! # }
 //! ```
 
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 
@@ -48,11 +51,12 @@ use crate::{
     node::NodeListFailure,
     prelude::AttributeSet,
     service::{
-        self, dynamic_config, service_id::ServiceId, service_name::ServiceName, static_config,
+        self, control_channel::ControlChannel, dynamic_attribute::DynamicAttributes,
+        dynamic_config, service_id::ServiceId, service_name::ServiceName, static_config,
     },
 };
 
-use super::nodes;
+use super::{client::PortFactoryClient, nodes, server::PortFactoryServer};
 
 /// The factory for
 /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse).
@@ -60,14 +64,51 @@ use super::nodes;
 /// [`crate::port::client::Client`]
 /// or [`crate::port::server::Server`] ports.
 #[derive(Debug)]
-pub struct PortFactory<Service: service::Service> {
+pub struct PortFactory<
+    Service: service::Service,
+    RequestPayload: Debug + ?Sized,
+    RequestHeader: Debug,
+    ResponsePayload: Debug + ?Sized,
+    ResponseHeader: Debug,
+> {
     pub(crate) service: Service,
+    _request_payload: PhantomData<RequestPayload>,
+    _request_header: PhantomData<RequestHeader>,
+    _response_payload: PhantomData<ResponsePayload>,
+    _response_header: PhantomData<ResponseHeader>,
+}
+
+unsafe impl<
+        Service: service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > Send
+    for PortFactory<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
 }
 
-unsafe impl<Service: service::Service> Send for PortFactory<Service> {}
-unsafe impl<Service: service::Service> Sync for PortFactory<Service> {}
+unsafe impl<
+        Service: service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > Sync
+    for PortFactory<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+}
 
-impl<Service: service::Service> crate::service::port_factory::PortFactory for PortFactory<Service> {
+impl<
+        Service: service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > crate::service::port_factory::PortFactory
+    for PortFactory<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
     type Service = Service;
     type StaticConfig = static_config::request_response::StaticConfig;
     type DynamicConfig = dynamic_config::request_response::DynamicConfig;
@@ -109,10 +150,86 @@ impl<Service: service::Service> crate::service::port_factory::PortFactory for Po
             callback,
         )
     }
+
+    fn attributes_mut(&self) -> DynamicAttributes<Service> {
+        DynamicAttributes::new(self.service.__internal_state().clone())
+    }
 }
 
-impl<Service: service::Service> PortFactory<Service> {
+impl<
+        Service: service::Service,
+        RequestPayload: Debug + ?Sized,
+        RequestHeader: Debug,
+        ResponsePayload: Debug + ?Sized,
+        ResponseHeader: Debug,
+    > PortFactory<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
     pub(crate) fn new(service: Service) -> Self {
-        Self { service }
+        Self {
+            service,
+            _request_payload: PhantomData,
+            _request_header: PhantomData,
+            _response_payload: PhantomData,
+            _response_header: PhantomData,
+        }
+    }
+
+    /// Returns a new handle to the [`ControlChannel`] of the [`crate::service::Service`]. It
+    /// can be used to exchange small, fixed-size messages for lightweight coordination between
+    /// all participants without creating an additional [`crate::service::Service`].
+    pub fn control_channel(&self) -> ControlChannel<Service> {
+        ControlChannel::new(self.service.__internal_state().clone())
+    }
+
+    /// Returns a [`PortFactoryClient`] to create a new
+    /// [`crate::port::client::Client`] port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let req_res = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .request_response::<u64, u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let client = req_res.client_builder().create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn client_builder(
+        &self,
+    ) -> PortFactoryClient<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+    {
+        PortFactoryClient::new(self)
+    }
+
+    /// Returns a [`PortFactoryServer`] to create a new
+    /// [`crate::port::server::Server`] port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let req_res = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .request_response::<u64, u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let server = req_res.server_builder().create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn server_builder(
+        &self,
+    ) -> PortFactoryServer<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+    {
+        PortFactoryServer::new(self)
     }
 }
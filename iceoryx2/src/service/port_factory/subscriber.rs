@@ -30,24 +30,117 @@
 //! ```
 
 use core::fmt::Debug;
+use core::time::Duration;
 
 use iceoryx2_bb_log::fail;
 
 use crate::{
+    deterministic_profile,
+    node::load_shedding::ShedPolicy,
     port::{
+        ack_subscriber::AckSubscriber,
         port_identifiers::{UniquePublisherId, UniqueSubscriberId},
+        serde_subscriber::SerdeSubscriber,
         subscriber::{Subscriber, SubscriberCreateError},
         DegrationAction, DegrationCallback,
     },
+    sample::Sample,
     service,
 };
 
 use super::publish_subscribe::PortFactory;
 
+/// Defines the order in which a [`Subscriber`] receives samples from multiple
+/// [`Publisher`](crate::port::publisher::Publisher)s with [`Subscriber::receive()`].
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub enum FairnessPolicy {
+    /// The connections are always processed in the same order. Samples of later
+    /// [`Publisher`](crate::port::publisher::Publisher)s are only received when all earlier
+    /// connections are empty.
+    #[default]
+    FixedOrder,
+    /// Every call to [`Subscriber::receive()`] continues with the connection that follows the
+    /// connection that delivered the previous sample.
+    RoundRobin,
+    /// The connections are weighted by their number of pending samples. The connection with
+    /// the largest backlog is served first.
+    Weighted,
+    /// The sample that was sent first is received first, independent of its
+    /// [`Publisher`](crate::port::publisher::Publisher). To compare the send timestamps the
+    /// [`Subscriber`] holds back up to one sample per connection which counts towards the
+    /// maximum number of borrowed samples. It requires a service with send timestamps, see
+    /// [`Builder::enable_send_timestamp()`](crate::service::builder::publish_subscribe::Builder::enable_send_timestamp()),
+    /// otherwise it behaves like [`FairnessPolicy::FixedOrder`].
+    OldestTimestampFirst,
+}
+
+/// A hint of the [`Subscriber`] that tells every connected
+/// [`Publisher`](crate::port::publisher::Publisher) how important the delivery is. The
+/// [`Publisher`](crate::port::publisher::Publisher) can define a dedicated
+/// [`UnableToDeliverStrategy`](crate::service::port_factory::publisher::UnableToDeliverStrategy)
+/// for every [`DeliveryQos`] with
+/// [`PortFactoryPublisher::unable_to_deliver_strategy_for()`](crate::service::port_factory::publisher::PortFactoryPublisher::unable_to_deliver_strategy_for()).
+#[repr(C)]
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub enum DeliveryQos {
+    /// The [`Publisher`](crate::port::publisher::Publisher) uses its regular
+    /// [`UnableToDeliverStrategy`](crate::service::port_factory::publisher::UnableToDeliverStrategy).
+    #[default]
+    Regular,
+    /// The [`Subscriber`] must not miss samples, e.g. a controller that acts on the data.
+    Critical,
+    /// The [`Subscriber`] tolerates missing samples, e.g. a monitoring or logging tool.
+    BestEffort,
+}
+
+/// Defines when a [`Subscriber`] expires automatically. An expired [`Subscriber`] disconnects
+/// from all [`Publisher`](crate::port::publisher::Publisher)s, releases its connection
+/// resources and every further [`Subscriber::receive()`] call fails with
+/// [`SubscriberReceiveError::Expired`](crate::port::subscriber::SubscriberReceiveError::Expired).
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum AutoExpire {
+    /// The [`Subscriber`] expires as soon as it has received the given number of samples.
+    AfterSamples(u64),
+    /// The [`Subscriber`] expires when the given duration has passed since its creation. The
+    /// expiration is detected whenever the [`Subscriber`] is used.
+    AfterDuration(Duration),
+}
+
 #[derive(Debug)]
 pub(crate) struct SubscriberConfig {
     pub(crate) buffer_size: Option<usize>,
     pub(crate) degration_callback: Option<DegrationCallback<'static>>,
+    pub(crate) ignore_unready_publishers: bool,
+    pub(crate) fairness_policy: FairnessPolicy,
+    pub(crate) auto_expire: Option<AutoExpire>,
+    pub(crate) receive_heap_copies: bool,
+    pub(crate) expired_connection_backlog: Option<usize>,
+    pub(crate) eager_segment_mapping: bool,
+    pub(crate) delivery_qos: DeliveryQos,
+    pub(crate) in_place_updates: bool,
+    pub(crate) shed_policy: ShedPolicy,
+}
+
+/// Decides if a received [`Sample`] is handed out to the user, see
+/// [`PortFactorySubscriber::filter()`].
+pub(crate) struct SampleFilter<Service: service::Service, PayloadType: Debug + ?Sized, UserHeader>(
+    Box<dyn Fn(&Sample<Service, PayloadType, UserHeader>) -> bool>,
+);
+
+impl<Service: service::Service, PayloadType: Debug + ?Sized, UserHeader>
+    SampleFilter<Service, PayloadType, UserHeader>
+{
+    pub(crate) fn call(&self, sample: &Sample<Service, PayloadType, UserHeader>) -> bool {
+        (self.0)(sample)
+    }
+}
+
+impl<Service: service::Service, PayloadType: Debug + ?Sized, UserHeader> Debug
+    for SampleFilter<Service, PayloadType, UserHeader>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "")
+    }
 }
 
 /// Factory to create a new [`Subscriber`] port/endpoint for
@@ -61,6 +154,8 @@ pub struct PortFactorySubscriber<
     UserHeader: Debug,
 > {
     config: SubscriberConfig,
+    filter: Option<SampleFilter<Service, PayloadType, UserHeader>>,
+    ack_timeout: Option<Duration>,
     pub(crate) factory: &'factory PortFactory<Service, PayloadType, UserHeader>,
 }
 
@@ -72,7 +167,18 @@ impl<'factory, Service: service::Service, PayloadType: Debug + ?Sized, UserHeade
             config: SubscriberConfig {
                 buffer_size: None,
                 degration_callback: None,
+                ignore_unready_publishers: false,
+                fairness_policy: FairnessPolicy::default(),
+                auto_expire: None,
+                receive_heap_copies: false,
+                expired_connection_backlog: None,
+                eager_segment_mapping: deterministic_profile::default_eager_segment_mapping(),
+                delivery_qos: DeliveryQos::default(),
+                in_place_updates: false,
+                shed_policy: ShedPolicy::default(),
             },
+            filter: None,
+            ack_timeout: None,
             factory,
         }
     }
@@ -83,6 +189,151 @@ impl<'factory, Service: service::Service, PayloadType: Debug + ?Sized, UserHeade
         self
     }
 
+    /// Defines if the [`Subscriber`] discards all samples that were sent by a
+    /// [`Publisher`](crate::port::publisher::Publisher) before it announced its readiness with
+    /// [`Publisher::announce_ready()`](crate::port::publisher::Publisher::announce_ready()).
+    pub fn ignore_samples_from_unready_publishers(mut self, value: bool) -> Self {
+        self.config.ignore_unready_publishers = value;
+        self
+    }
+
+    /// Defines the [`FairnessPolicy`] that decides from which
+    /// [`Publisher`](crate::port::publisher::Publisher) the next sample is received when
+    /// multiple [`Publisher`](crate::port::publisher::Publisher)s have delivered samples.
+    pub fn fairness_policy(mut self, value: FairnessPolicy) -> Self {
+        self.config.fairness_policy = value;
+        self
+    }
+
+    /// Defines when the [`Subscriber`] expires automatically, see [`AutoExpire`]. Short-lived
+    /// [`Subscriber`]s, like diagnostic ones, release their connection resources in the
+    /// [`Publisher`](crate::port::publisher::Publisher)s this way even when they are never
+    /// dropped.
+    pub fn auto_expire(mut self, value: AutoExpire) -> Self {
+        self.config.auto_expire = Some(value);
+        self
+    }
+
+    /// Defines if the [`Subscriber`] hands out every received [`Sample`] as an owned heap copy.
+    /// The [`Sample`] is copied on [`Subscriber::receive()`] and its shared memory is released
+    /// back to the [`Publisher`](crate::port::publisher::Publisher) immediately. Consumers that
+    /// hold samples for a long time do not occupy the
+    /// [`Publisher`](crate::port::publisher::Publisher)s data segment this way, at the cost of
+    /// one copy per [`Sample`].
+    pub fn receive_heap_copies(mut self, value: bool) -> Self {
+        self.config.receive_heap_copies = value;
+        self
+    }
+
+    /// Defines the maximum number of bytes of the owned backlog of the [`Subscriber`]. When a
+    /// [`Publisher`](crate::port::publisher::Publisher) disconnects or dies with undelivered
+    /// samples and the config entry
+    /// `defaults.publish-subscribe.subscriber-expired-connection-buffer` is exceeded, the
+    /// samples of the expired connection are copied into the backlog instead of being
+    /// discarded. The [`Subscriber`] hands them out as owned heap copies with
+    /// [`Subscriber::receive()`] before it receives from the remaining
+    /// [`Publisher`](crate::port::publisher::Publisher)s. Samples that would exceed the
+    /// backlog are discarded.
+    ///
+    /// By default, no backlog is used.
+    pub fn expired_connection_backlog(mut self, max_bytes: usize) -> Self {
+        self.config.expired_connection_backlog = Some(max_bytes);
+        self
+    }
+
+    /// Defines if the [`Subscriber`] maps all segments of the data segment of a
+    /// [`Publisher`](crate::port::publisher::Publisher) when it connects to it. Segments that
+    /// the [`Publisher`](crate::port::publisher::Publisher) adds later are announced and mapped
+    /// in [`UpdateConnections::update_connections()`](crate::port::update_connections::UpdateConnections::update_connections())
+    /// before the first [`Sample`] of the new segment is received. Without it, a segment is
+    /// mapped lazily when the first [`Sample`] of it is received, which adds latency to
+    /// [`Subscriber::receive()`].
+    ///
+    /// Services with enabled broadcasting do not announce new segments, their segments are
+    /// mapped only on connection. It is enabled by default when iceoryx2 is built with the
+    /// [`deterministic_profile`].
+    pub fn eager_segment_mapping(mut self, value: bool) -> Self {
+        self.config.eager_segment_mapping = value;
+        self
+    }
+
+    /// Defines the [`DeliveryQos`] hint of the [`Subscriber`]. Every connected
+    /// [`Publisher`](crate::port::publisher::Publisher) uses it to select the
+    /// [`UnableToDeliverStrategy`](crate::service::port_factory::publisher::UnableToDeliverStrategy)
+    /// for this [`Subscriber`]. By default, it is [`DeliveryQos::Regular`].
+    pub fn delivery_qos(mut self, value: DeliveryQos) -> Self {
+        self.config.delivery_qos = value;
+        self
+    }
+
+    /// Allows the [`Subscriber`] to update exclusive [`Sample`]s in place with
+    /// [`Sample::payload_mut()`] and to hand them back with [`Sample::hand_back()`]. The
+    /// [`Publisher`](crate::port::publisher::Publisher) delivers a handed back [`Sample`] as
+    /// new publication to all other [`Subscriber`]s without copying it, which enables
+    /// ping-pong ownership patterns between exactly two parties. It requires that the payload
+    /// is not mapped read-only, see
+    /// [`Service::read_only_payload_mapping`](crate::config::Service::read_only_payload_mapping).
+    /// By default, it is disabled.
+    pub fn enable_in_place_updates(mut self, value: bool) -> Self {
+        self.config.in_place_updates = value;
+        self
+    }
+
+    /// Defines the [`ShedPolicy`] of the [`Subscriber`] that decides which samples are discarded
+    /// while the [`Node`](crate::node::Node) sheds load, see
+    /// [`load_shedding`](crate::node::load_shedding). By default, it is
+    /// [`ShedPolicy::Disabled`].
+    pub fn shed_policy(mut self, value: ShedPolicy) -> Self {
+        self.config.shed_policy = match value {
+            ShedPolicy::KeepEveryNth(n) => ShedPolicy::KeepEveryNth(n.max(1)),
+            v => v,
+        };
+        self
+    }
+
+    /// Defines a predicate that decides if a received [`Sample`] is handed out by
+    /// [`Subscriber::receive()`]. Samples that do not match are released back to the
+    /// [`Publisher`](crate::port::publisher::Publisher) immediately and are not counted as
+    /// received samples, e.g. for [`AutoExpire::AfterSamples`].
+    ///
+    /// The predicate has only read access to the [`Sample`] that resides in shared memory and
+    /// cannot keep a reference to it beyond the call.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let pubsub = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// let subscriber = pubsub.subscriber_builder()
+    ///                     // only even numbers are received
+    ///                     .filter(|sample| **sample % 2 == 0)
+    ///                     .create()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn filter<F: Fn(&Sample<Service, PayloadType, UserHeader>) -> bool + 'static>(
+        mut self,
+        predicate: F,
+    ) -> Self {
+        self.filter = Some(SampleFilter(Box::new(predicate)));
+        self
+    }
+
+    /// Defines how long an [`AckSample`](crate::ack_sample::AckSample) that was received with
+    /// an [`AckSubscriber`] can stay unacknowledged before it is delivered again. Without a
+    /// timeout, samples are only delivered again when they are rejected or dropped. Applies
+    /// only to [`PortFactorySubscriber::create_ack_subscriber()`].
+    pub fn ack_timeout(mut self, value: Duration) -> Self {
+        self.ack_timeout = Some(value);
+        self
+    }
+
     /// Sets the [`DegrationCallback`] of the [`Subscriber`]. Whenever a connection to a
     /// [`crate::port::subscriber::Subscriber`] is corrupted or it seems to be dead, this callback
     /// is called and depending on the returned [`DegrationAction`] measures will be taken.
@@ -111,8 +362,29 @@ impl<'factory, Service: service::Service, PayloadType: Debug + ?Sized, UserHeade
     ) -> Result<Subscriber<Service, PayloadType, UserHeader>, SubscriberCreateError> {
         let origin = format!("{:?}", self);
         Ok(
-            fail!(from origin, when Subscriber::new(&self.factory.service, self.factory.service.__internal_state().static_config.publish_subscribe(), self.config),
+            fail!(from origin, when Subscriber::new(&self.factory.service, self.factory.service.__internal_state().static_config.publish_subscribe(), self.config, self.filter),
                 "Failed to create new Subscriber port."),
         )
     }
+
+    /// Creates a new [`AckSubscriber`] or returns a [`SubscriberCreateError`] on failure.
+    /// Every [`AckSample`](crate::ack_sample::AckSample) it receives must be acknowledged,
+    /// otherwise it is delivered again.
+    pub fn create_ack_subscriber(
+        self,
+    ) -> Result<AckSubscriber<Service, PayloadType, UserHeader>, SubscriberCreateError> {
+        let ack_timeout = self.ack_timeout;
+        Ok(AckSubscriber::new(self.create()?, ack_timeout))
+    }
+}
+
+impl<Service: service::Service> PortFactorySubscriber<'_, Service, [u8], ()> {
+    /// Creates a new [`SerdeSubscriber`] for a service created with
+    /// [`Builder::publish_subscribe_serde()`](crate::service::builder::Builder::publish_subscribe_serde())
+    /// or returns a [`SubscriberCreateError`] on failure.
+    pub fn create_serde_subscriber<Payload: Debug + serde::de::DeserializeOwned>(
+        self,
+    ) -> Result<SerdeSubscriber<Service, Payload>, SubscriberCreateError> {
+        Ok(SerdeSubscriber::new(self.create()?))
+    }
 }
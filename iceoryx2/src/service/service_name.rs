@@ -14,17 +14,216 @@
 //!
 //! ```
 //! use iceoryx2::prelude::*;
+//! use iceoryx2::service::service_name::ServiceNameBuilder;
 //!
 //! # fn main() -> Result<(), Box<dyn core::error::Error>> {
 //! let service_name = ServiceName::new("My/Funk/ServiceName")?;
 //!
+//! // validated at compile time
+//! let service_name = service_name!("radar/front/points");
+//!
+//! // validated segment-wise on construction
+//! let service_name = ServiceNameBuilder::new()
+//!     .segment("radar")
+//!     .segment("front")
+//!     .segment("points")
+//!     .create()?;
+//!
 //! # Ok(())
 //! # }
 //! ```
 
 use iceoryx2_bb_container::semantic_string::SemanticStringError;
+use iceoryx2_bb_log::fail;
 use serde::{de::Visitor, Deserialize, Serialize};
 
+/// The separator of the segments of a [`ServiceName`].
+pub const SEGMENT_SEPARATOR: char = '/';
+
+/// The maximum length of a single segment of a [`ServiceName`] that is validated with the
+/// [`ServiceNameBuilder`] or the [`service_name!`](crate::service_name) macro.
+pub const MAX_SEGMENT_LENGTH: usize = 64;
+
+/// The first segment of the [`ServiceName`]s of the services that iceoryx2 creates internally,
+/// like the discovery service.
+pub const RESERVED_PREFIX: &str = "iox2";
+
+/// Defines the failures that can occur when a [`ServiceName`] is validated with the
+/// [`ServiceNameBuilder`] or the [`service_name!`](crate::service_name) macro.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ServiceNameBuilderError {
+    /// The [`ServiceName`] does not contain a single segment.
+    NoSegments,
+    /// A segment is empty, for instance due to a leading, trailing or double `/`.
+    EmptySegment,
+    /// A segment contains a character that is neither an ASCII letter, an ASCII digit, `_`,
+    /// `-` nor `.`.
+    InvalidCharacter,
+    /// A segment is longer than [`MAX_SEGMENT_LENGTH`].
+    SegmentTooLong,
+    /// The first segment is the [`RESERVED_PREFIX`] of the internal services.
+    ReservedPrefix,
+}
+
+impl core::fmt::Display for ServiceNameBuilderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ServiceNameBuilderError::{:?}", self)
+    }
+}
+
+impl core::error::Error for ServiceNameBuilderError {}
+
+const fn validate_segment(segment: &[u8]) -> Result<(), ServiceNameBuilderError> {
+    if segment.is_empty() {
+        return Err(ServiceNameBuilderError::EmptySegment);
+    }
+
+    if MAX_SEGMENT_LENGTH < segment.len() {
+        return Err(ServiceNameBuilderError::SegmentTooLong);
+    }
+
+    let mut i = 0;
+    while i < segment.len() {
+        let c = segment[i];
+        if !(c.is_ascii_alphanumeric() || c == b'_' || c == b'-' || c == b'.') {
+            return Err(ServiceNameBuilderError::InvalidCharacter);
+        }
+        i += 1;
+    }
+
+    Ok(())
+}
+
+const fn is_reserved(segment: &[u8]) -> bool {
+    let prefix = RESERVED_PREFIX.as_bytes();
+    if segment.len() != prefix.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < segment.len() {
+        if segment[i] != prefix[i] {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+/// Validates every segment of the provided [`ServiceName`]. It is a `const fn` so that it can
+/// be used by the [`service_name!`](crate::service_name) macro to validate the name at compile
+/// time.
+pub const fn validate_service_name(name: &str) -> Result<(), ServiceNameBuilderError> {
+    let mut rest = name.as_bytes();
+    if rest.is_empty() {
+        return Err(ServiceNameBuilderError::NoSegments);
+    }
+
+    let mut is_first_segment = true;
+    loop {
+        let mut len = 0;
+        while len < rest.len() && rest[len] != SEGMENT_SEPARATOR as u8 {
+            len += 1;
+        }
+
+        let (segment, remainder) = rest.split_at(len);
+        if let Err(e) = validate_segment(segment) {
+            return Err(e);
+        }
+
+        if is_first_segment && is_reserved(segment) {
+            return Err(ServiceNameBuilderError::ReservedPrefix);
+        }
+        is_first_segment = false;
+
+        if remainder.is_empty() {
+            return Ok(());
+        }
+
+        // skip the separator, a trailing separator leads to an empty segment
+        let (_, remainder) = remainder.split_at(1);
+        if remainder.is_empty() {
+            return Err(ServiceNameBuilderError::EmptySegment);
+        }
+        rest = remainder;
+    }
+}
+
+/// Creates a [`ServiceName`] from a string literal that is validated at compile time with
+/// [`validate_service_name()`].
+///
+/// ```
+/// use iceoryx2::prelude::*;
+///
+/// let service_name = service_name!("radar/front/points");
+/// assert_eq!(service_name, "radar/front/points");
+/// ```
+///
+/// ```compile_fail
+/// use iceoryx2::prelude::*;
+///
+/// let service_name = service_name!("radar//points");
+/// ```
+#[macro_export]
+macro_rules! service_name {
+    ($name:literal) => {{
+        const _: () = assert!(
+            $crate::service::service_name::validate_service_name($name).is_ok(),
+            "invalid service name"
+        );
+        $crate::service::service_name::ServiceName::__from_validated($name)
+    }};
+}
+
+/// Creates a [`ServiceName`] out of segments that are validated on
+/// [`ServiceNameBuilder::create()`]. Every segment must consist of ASCII letters, ASCII
+/// digits, `_`, `-` or `.`, must not be longer than [`MAX_SEGMENT_LENGTH`] and the first
+/// segment must not be the [`RESERVED_PREFIX`].
+#[derive(Debug, Default, Clone)]
+pub struct ServiceNameBuilder {
+    segments: Vec<String>,
+}
+
+impl ServiceNameBuilder {
+    /// Creates a new [`ServiceNameBuilder`] without any segment.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a segment to the [`ServiceName`].
+    pub fn segment(mut self, value: &str) -> Self {
+        self.segments.push(value.into());
+        self
+    }
+
+    /// Validates all segments and creates the [`ServiceName`].
+    pub fn create(self) -> Result<ServiceName, ServiceNameBuilderError> {
+        let msg = "Unable to create ServiceName";
+        if self.segments.is_empty() {
+            fail!(from self, with ServiceNameBuilderError::NoSegments,
+                "{} since no segment was provided.", msg);
+        }
+
+        for (n, segment) in self.segments.iter().enumerate() {
+            if let Err(e) = validate_segment(segment.as_bytes()) {
+                fail!(from self, with e,
+                    "{} since the segment \"{}\" is invalid ({:?}).", msg, segment, e);
+            }
+
+            if n == 0 && is_reserved(segment.as_bytes()) {
+                fail!(from self, with ServiceNameBuilderError::ReservedPrefix,
+                    "{} since the first segment \"{}\" is reserved for internal services.",
+                    msg, segment);
+            }
+        }
+
+        Ok(ServiceName {
+            value: self.segments.join("/"),
+        })
+    }
+}
+
 /// The name of a [`Service`](crate::service::Service).
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct ServiceName {
@@ -41,6 +240,11 @@ impl ServiceName {
         Ok(Self { value: name.into() })
     }
 
+    #[doc(hidden)]
+    pub fn __from_validated(name: &str) -> Self {
+        Self { value: name.into() }
+    }
+
     /// Returns a str reference to the [`ServiceName`]
     pub fn as_str(&self) -> &str {
         &self.value
@@ -28,6 +28,8 @@
 //! println!("notifier created event:       {:?}", event.static_config().notifier_created_event());
 //! println!("notifier dropped event:       {:?}", event.static_config().notifier_dropped_event());
 //! println!("notifier dead event:          {:?}", event.static_config().notifier_dead_event());
+//! println!("max payload size:             {:?}", event.static_config().max_payload_size());
+//! println!("has notifier origin:          {:?}", event.static_config().has_notifier_origin());
 //!
 //! # Ok(())
 //! # }
@@ -54,9 +56,12 @@ pub struct StaticConfig {
     pub(crate) max_nodes: usize,
     pub(crate) event_id_max_value: usize,
     pub(crate) deadline: Option<Deadline>,
+    pub(crate) deadline_missed_event: Option<usize>,
     pub(crate) notifier_created_event: Option<usize>,
     pub(crate) notifier_dropped_event: Option<usize>,
     pub(crate) notifier_dead_event: Option<usize>,
+    pub(crate) max_payload_size: usize,
+    pub(crate) enable_notifier_origin: bool,
 }
 
 impl StaticConfig {
@@ -69,10 +74,13 @@ impl StaticConfig {
                 creation_time: Time::default(),
                 value: v,
             }),
+            deadline_missed_event: None,
             event_id_max_value: config.defaults.event.event_id_max_value,
             notifier_created_event: config.defaults.event.notifier_created_event,
             notifier_dropped_event: config.defaults.event.notifier_dropped_event,
             notifier_dead_event: config.defaults.event.notifier_dead_event,
+            max_payload_size: config.defaults.event.max_payload_size,
+            enable_notifier_origin: false,
         }
     }
 
@@ -85,6 +93,12 @@ impl StaticConfig {
         self.deadline.map(|v| v.value)
     }
 
+    /// Returns the [`EventId`] that every [`Listener`](crate::port::listener::Listener)
+    /// receives when the [`StaticConfig::deadline()`] was missed.
+    pub fn deadline_missed_event(&self) -> Option<EventId> {
+        self.deadline_missed_event.map(EventId::new)
+    }
+
     /// Returns the maximum supported amount of [`Node`](crate::node::Node)s that can open the
     /// [`Service`](crate::service::Service) in parallel.
     pub fn max_nodes(&self) -> usize {
@@ -120,4 +134,26 @@ impl StaticConfig {
     pub fn notifier_dead_event(&self) -> Option<EventId> {
         self.notifier_dead_event.map(EventId::new)
     }
+
+    /// Returns the maximum size in bytes of the payload that can be attached to a
+    /// notification.
+    pub fn max_payload_size(&self) -> usize {
+        self.max_payload_size
+    }
+
+    /// Returns true when every notification carries the origin of the
+    /// [`Notifier`](crate::port::notifier::Notifier) that sent it, see
+    /// [`Listener::try_wait_one_with_origin()`](crate::port::listener::Listener::try_wait_one_with_origin()).
+    pub fn has_notifier_origin(&self) -> bool {
+        self.enable_notifier_origin
+    }
+
+    // When the notifier origin is enabled, the index of the notifier in the dynamic config is
+    // encoded into the trigger id: trigger_id = event_id * stride + notifier_index
+    pub(crate) fn trigger_id_stride(&self) -> usize {
+        match self.enable_notifier_origin {
+            true => self.max_notifiers.max(1),
+            false => 1,
+        }
+    }
 }
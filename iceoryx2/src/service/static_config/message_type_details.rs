@@ -65,6 +65,17 @@ impl TypeDetail {
             alignment: core::mem::align_of::<T>(),
         }
     }
+
+    /// Creates the [`TypeDetail`] of a user header whose size is defined at runtime. It is
+    /// compatible only to other runtime-sized user headers with the same size.
+    pub(crate) fn new_runtime_sized_user_header(size: usize, alignment: usize) -> Self {
+        Self {
+            variant: TypeVariant::Dynamic,
+            type_name: core::any::type_name::<[u8]>().to_string(),
+            size,
+            alignment,
+        }
+    }
 }
 
 /// Contains all type information to the header and payload type.
@@ -77,6 +88,16 @@ pub struct MessageTypeDetails {
     pub user_header: TypeDetail,
     /// The [`TypeDetail`] of the payload of the message, the last part.
     pub payload: TypeDetail,
+    /// An optional descriptor of the payload type, like a hash or a serialized layout. Language
+    /// bindings that use a
+    /// [`CustomPayloadMarker`](crate::service::builder::publish_subscribe::CustomPayloadMarker)
+    /// can provide it so that two structurally different types with the same size and
+    /// alignment are detected as incompatible.
+    pub payload_type_descriptor: Option<String>,
+    /// The version of the user header when it is a
+    /// [`VersionedUserHeader`](iceoryx2_bb_elementary::versioned_user_header::VersionedUserHeader).
+    /// An older version of the same user header is compatible to a newer one.
+    pub user_header_version: Option<u16>,
 }
 
 impl MessageTypeDetails {
@@ -85,6 +106,8 @@ impl MessageTypeDetails {
             header: TypeDetail::__internal_new::<Header>(TypeVariant::FixedSize),
             user_header: TypeDetail::__internal_new::<UserHeader>(TypeVariant::FixedSize),
             payload: TypeDetail::__internal_new::<Payload>(payload_variant),
+            payload_type_descriptor: None,
+            user_header_version: None,
         }
     }
 
@@ -118,14 +141,35 @@ impl MessageTypeDetails {
 
     pub(crate) fn is_compatible_to(&self, rhs: &Self) -> bool {
         self.header == rhs.header
-            && self.user_header.type_name == rhs.user_header.type_name
-            && self.user_header.variant == rhs.user_header.variant
-            && self.user_header.size == rhs.user_header.size
-            && self.user_header.alignment <= rhs.user_header.alignment
+            && self.is_user_header_compatible_to(rhs)
             && self.payload.type_name == rhs.payload.type_name
             && self.payload.variant == rhs.payload.variant
             && self.payload.size == rhs.payload.size
             && self.payload.alignment <= rhs.payload.alignment
+            && self.is_payload_type_descriptor_compatible_to(rhs)
+    }
+
+    fn is_user_header_compatible_to(&self, rhs: &Self) -> bool {
+        let is_size_compatible = match (self.user_header_version, rhs.user_header_version) {
+            // an older definition covers only the beginning of a newer user header
+            (Some(lhs_version), Some(rhs_version)) => {
+                lhs_version <= rhs_version && self.user_header.size <= rhs.user_header.size
+            }
+            _ => self.user_header.size == rhs.user_header.size,
+        };
+
+        is_size_compatible
+            && self.user_header.type_name == rhs.user_header.type_name
+            && self.user_header.variant == rhs.user_header.variant
+            && self.user_header.alignment <= rhs.user_header.alignment
+    }
+
+    // the descriptor is optional, it can only be verified when both sides provide one
+    fn is_payload_type_descriptor_compatible_to(&self, rhs: &Self) -> bool {
+        match (&self.payload_type_descriptor, &rhs.payload_type_descriptor) {
+            (Some(lhs), Some(rhs)) => lhs == rhs,
+            _ => true,
+        }
     }
 }
 
@@ -168,6 +212,8 @@ mod tests {
                 size: 16,
                 alignment: ALIGNMENT,
             },
+            payload_type_descriptor: None,
+            user_header_version: None,
         };
         assert_that!(sut, eq expected);
 
@@ -191,6 +237,8 @@ mod tests {
                 size: 8,
                 alignment: ALIGNMENT,
             },
+            payload_type_descriptor: None,
+            user_header_version: None,
         };
         assert_that!(sut, eq expected);
     }
@@ -348,6 +396,8 @@ mod tests {
                 size: 8,
                 alignment: 2 * ALIGNMENT,
             },
+            payload_type_descriptor: None,
+            user_header_version: None,
         };
         // smaller to bigger is allowed.
         let sut = left.is_compatible_to(&right);
@@ -383,9 +433,45 @@ mod tests {
                 size: 8,
                 alignment: 2 * ALIGNMENT,
             },
+            payload_type_descriptor: None,
+            user_header_version: None,
         };
         // bigger to smaller is invalid.
         let sut = right.is_compatible_to(&left);
         assert_that!(sut, eq false);
     }
+
+    #[test]
+    fn test_is_compatible_to_verifies_payload_type_descriptor_when_both_are_set() {
+        let mut left = MessageTypeDetails::from::<i64, i64, i64>(TypeVariant::FixedSize);
+        let mut right = left.clone();
+
+        left.payload_type_descriptor = Some("struct A { int64_t a; }".to_string());
+        assert_that!(left.is_compatible_to(&right), eq true);
+        assert_that!(right.is_compatible_to(&left), eq true);
+
+        right.payload_type_descriptor = Some("struct B { double b; }".to_string());
+        assert_that!(left.is_compatible_to(&right), eq false);
+        assert_that!(right.is_compatible_to(&left), eq false);
+
+        right.payload_type_descriptor = left.payload_type_descriptor.clone();
+        assert_that!(left.is_compatible_to(&right), eq true);
+    }
+
+    #[test]
+    fn test_is_compatible_to_accepts_older_versioned_user_header() {
+        let mut older = MessageTypeDetails::from::<i64, i64, i64>(TypeVariant::FixedSize);
+        let mut newer = older.clone();
+
+        older.user_header_version = Some(1);
+        newer.user_header_version = Some(2);
+        newer.user_header.size = 16;
+
+        assert_that!(older.is_compatible_to(&newer), eq true);
+        assert_that!(newer.is_compatible_to(&older), eq false);
+
+        // non-versioned user headers must match exactly
+        older.user_header_version = None;
+        assert_that!(older.is_compatible_to(&newer), eq false);
+    }
 }
@@ -60,8 +60,15 @@ impl MessagingPattern {
     ) -> usize {
         match self {
             MessagingPattern::PublishSubscribe(v) => {
-                v.max_subscribers
-                    * (v.subscriber_max_buffer_size + v.subscriber_max_borrowed_samples)
+                // every priority lane can hold another buffer full of samples
+                let number_of_lanes = if v.enable_priority_lanes { 2 } else { 1 };
+                let mut borrowed_samples = v.max_subscribers * v.subscriber_max_borrowed_samples;
+                if let Some(max_total_borrowed_samples) = v.max_total_borrowed_samples {
+                    borrowed_samples = borrowed_samples.min(max_total_borrowed_samples);
+                }
+
+                v.max_subscribers * number_of_lanes * v.subscriber_max_buffer_size
+                    + borrowed_samples
                     + v.history_size
                     + publisher_max_loaned_samples
             }
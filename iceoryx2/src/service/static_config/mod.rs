@@ -28,6 +28,10 @@ pub mod request_response;
 
 pub mod messaging_pattern;
 
+/// Signing and verification of the static service configuration with the ed25519 keys
+/// defined in the [`Config`](crate::config::Config).
+pub mod signature;
+
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_cal::hash::Hash;
 use serde::{Deserialize, Serialize};
@@ -36,13 +40,16 @@ use crate::config;
 
 use self::messaging_pattern::MessagingPattern;
 
-use super::{attribute::AttributeSet, service_id::ServiceId, service_name::ServiceName};
+use super::{
+    attribute::AttributeSet, partition::Partition, service_id::ServiceId, service_name::ServiceName,
+};
 
 /// Defines a common set of static service configuration details every service shares.
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct StaticConfig {
     service_id: ServiceId,
     service_name: ServiceName,
+    pub(crate) partition: Option<Partition>,
     pub(crate) attributes: AttributeSet,
     pub(crate) messaging_pattern: MessagingPattern,
 }
@@ -60,6 +67,7 @@ impl StaticConfig {
                 crate::service::messaging_pattern::MessagingPattern::RequestResponse,
             ),
             service_name: service_name.clone(),
+            partition: None,
             messaging_pattern,
             attributes: AttributeSet::new(),
         }
@@ -76,6 +84,7 @@ impl StaticConfig {
                 crate::service::messaging_pattern::MessagingPattern::Event,
             ),
             service_name: service_name.clone(),
+            partition: None,
             messaging_pattern,
             attributes: AttributeSet::new(),
         }
@@ -93,6 +102,7 @@ impl StaticConfig {
                 crate::service::messaging_pattern::MessagingPattern::PublishSubscribe,
             ),
             service_name: service_name.clone(),
+            partition: None,
             messaging_pattern,
             attributes: AttributeSet::new(),
         }
@@ -113,6 +123,12 @@ impl StaticConfig {
         &self.service_name
     }
 
+    /// Returns the [`Partition`] of the [`crate::service::Service`] or [`None`] when it does
+    /// not belong to a [`Partition`]
+    pub fn partition(&self) -> Option<&Partition> {
+        self.partition.as_ref()
+    }
+
     /// Returns the [`MessagingPattern`] of the [`crate::service::Service`]
     pub fn messaging_pattern(&self) -> &MessagingPattern {
         &self.messaging_pattern
@@ -28,11 +28,17 @@
 //! println!("history size:                     {:?}", pubsub.static_config().history_size());
 //! println!("subscriber max borrowed samples:  {:?}", pubsub.static_config().subscriber_max_borrowed_samples());
 //! println!("safe overflow:                    {:?}", pubsub.static_config().has_safe_overflow());
+//! println!("payload checksum:                 {:?}", pubsub.static_config().has_payload_checksum());
+//! println!("global ordering:                  {:?}", pubsub.static_config().has_global_ordering());
+//! println!("broadcast:                        {:?}", pubsub.static_config().has_broadcast());
+//! println!("send timestamp:                   {:?}", pubsub.static_config().has_send_timestamp());
 //!
 //! # Ok(())
 //! # }
 //! ```
 
+use core::time::Duration;
+
 use super::message_type_details::MessageTypeDetails;
 use crate::config;
 use serde::{Deserialize, Serialize};
@@ -49,7 +55,16 @@ pub struct StaticConfig {
     pub(crate) history_size: usize,
     pub(crate) subscriber_max_buffer_size: usize,
     pub(crate) subscriber_max_borrowed_samples: usize,
+    pub(crate) max_total_borrowed_samples: Option<usize>,
     pub(crate) enable_safe_overflow: bool,
+    pub(crate) enable_payload_checksum: bool,
+    pub(crate) enable_global_ordering: bool,
+    pub(crate) enable_broadcast: bool,
+    pub(crate) enable_priority_lanes: bool,
+    pub(crate) enable_replay: bool,
+    pub(crate) enable_send_timestamp: bool,
+    pub(crate) inline_delivery_threshold: usize,
+    pub(crate) sample_lifetime: Option<Duration>,
     pub(crate) message_type_details: MessageTypeDetails,
 }
 
@@ -68,7 +83,16 @@ impl StaticConfig {
                 .defaults
                 .publish_subscribe
                 .subscriber_max_borrowed_samples,
+            max_total_borrowed_samples: None,
             enable_safe_overflow: config.defaults.publish_subscribe.enable_safe_overflow,
+            enable_payload_checksum: false,
+            enable_global_ordering: false,
+            enable_broadcast: false,
+            enable_priority_lanes: false,
+            enable_replay: false,
+            enable_send_timestamp: false,
+            inline_delivery_threshold: 0,
+            sample_lifetime: None,
             message_type_details: MessageTypeDetails::default(),
         }
     }
@@ -105,6 +129,13 @@ impl StaticConfig {
         self.subscriber_max_borrowed_samples
     }
 
+    /// Returns how many [`crate::sample::Sample`]s all [`crate::port::subscriber::Subscriber`]
+    /// ports together can borrow in parallel at most. If it returns [`None`] only the limit of
+    /// [`StaticConfig::subscriber_max_borrowed_samples()`] per subscriber applies.
+    pub fn max_total_borrowed_samples(&self) -> Option<usize> {
+        self.max_total_borrowed_samples
+    }
+
     /// Returns true if the [`crate::service::Service`] safely overflows, otherwise false. Safe
     /// overflow means that the [`crate::port::publisher::Publisher`] will recycle the oldest
     /// [`crate::sample::Sample`] from the [`crate::port::subscriber::Subscriber`] when its buffer
@@ -113,6 +144,81 @@ impl StaticConfig {
         self.enable_safe_overflow
     }
 
+    /// Returns true if every [`crate::sample::Sample`] carries a CRC-32C checksum of its
+    /// payload in the [`Header`](crate::service::header::publish_subscribe::Header) that is
+    /// verified by the [`crate::port::subscriber::Subscriber`] on receive.
+    pub fn has_payload_checksum(&self) -> bool {
+        self.enable_payload_checksum
+    }
+
+    /// Returns true if every [`crate::sample::Sample`] is stamped with a service-wide sequence
+    /// number in the [`Header`](crate::service::header::publish_subscribe::Header).
+    pub fn has_global_ordering(&self) -> bool {
+        self.enable_global_ordering
+    }
+
+    /// Returns true if every [`crate::port::publisher::Publisher`] submits a
+    /// [`crate::sample::Sample`] once into a single shared ring buffer from which all
+    /// [`crate::port::subscriber::Subscriber`]s read, instead of sending it over a dedicated
+    /// connection to every [`crate::port::subscriber::Subscriber`].
+    pub fn has_broadcast(&self) -> bool {
+        self.enable_broadcast
+    }
+
+    /// Returns true if every connection between a [`crate::port::publisher::Publisher`] and a
+    /// [`crate::port::subscriber::Subscriber`] has a high-priority lane that the
+    /// [`crate::port::subscriber::Subscriber`] drains first.
+    pub fn has_priority_lanes(&self) -> bool {
+        self.enable_priority_lanes
+    }
+
+    /// Returns true if captured [`crate::sample::Sample`]s can be re-injected with
+    /// [`crate::port::publisher::Publisher::publish_raw()`].
+    pub fn has_replay(&self) -> bool {
+        self.enable_replay
+    }
+
+    /// Returns true if every [`crate::sample::Sample`] is stamped with the monotonic point in
+    /// time when it was sent, see
+    /// [`Header::send_timestamp()`](crate::service::header::publish_subscribe::Header::send_timestamp()).
+    /// A [`StaticConfig::sample_lifetime()`] enables it implicitly.
+    pub fn has_send_timestamp(&self) -> bool {
+        self.enable_send_timestamp || self.sample_lifetime.is_some()
+    }
+
+    /// Returns the maximum payload size in bytes up to which a [`crate::sample::Sample`] is
+    /// copied directly into the connection of a [`crate::port::subscriber::Subscriber`]
+    /// instead of being delivered as reference into the data segment of the
+    /// [`crate::port::publisher::Publisher`]. If it returns 0, inline delivery is disabled.
+    pub fn inline_delivery_threshold(&self) -> usize {
+        self.inline_delivery_threshold
+    }
+
+    /// Returns the lifetime of a [`crate::sample::Sample`]. Samples that were sent longer ago
+    /// are stale and skipped by the [`crate::port::subscriber::Subscriber`]. If it returns
+    /// [`None`], samples never become stale.
+    pub fn sample_lifetime(&self) -> Option<Duration> {
+        self.sample_lifetime
+    }
+
+    // The inline slots of a connection are 8 byte aligned. Samples with a stricter alignment
+    // would have a different payload offset in the slot and are therefore never delivered
+    // inline.
+    pub(crate) fn inline_sample_size(&self) -> usize {
+        const INLINE_SAMPLE_ALIGNMENT: usize = 8;
+        let details = &self.message_type_details;
+        if self.inline_delivery_threshold == 0
+            || self.enable_broadcast
+            || details.header.alignment > INLINE_SAMPLE_ALIGNMENT
+            || details.user_header.alignment > INLINE_SAMPLE_ALIGNMENT
+            || details.payload.alignment > INLINE_SAMPLE_ALIGNMENT
+        {
+            return 0;
+        }
+
+        details.sample_layout(0).size() + self.inline_delivery_threshold
+    }
+
     /// Returns the type details of the [`crate::service::Service`].
     pub fn message_type_details(&self) -> &MessageTypeDetails {
         &self.message_type_details
@@ -23,6 +23,7 @@
 //!
 //! println!("request type details:      {:?}", req_res.static_config().request_message_type_details());
 //! println!("response type details:     {:?}", req_res.static_config().response_message_type_details());
+//! println!("error type details:        {:?}", req_res.static_config().error_message_type_details());
 //! println!("max active requests:       {:?}", req_res.static_config().max_active_requests());
 //! println!("max active responses:      {:?}", req_res.static_config().max_active_responses());
 //! println!("max borrowed responses:    {:?}", req_res.static_config().max_borrowed_responses());
@@ -34,16 +35,19 @@
 //! println!("max nodes:                 {:?}", req_res.static_config().max_nodes());
 //! println!("request safe overflow:     {:?}", req_res.static_config().has_safe_overflow_for_requests());
 //! println!("response safe overflow:    {:?}", req_res.static_config().has_safe_overflow_for_responses());
+//! println!("fire and forget requests:  {:?}", req_res.static_config().has_fire_and_forget_requests());
 //!
 //! # Ok(())
 //! # }
 //! ```
 
+use core::alloc::Layout;
+
 use serde::{Deserialize, Serialize};
 
 use crate::config;
 
-use super::message_type_details::MessageTypeDetails;
+use super::message_type_details::{MessageTypeDetails, TypeDetail, TypeVariant};
 
 /// The static configuration of an
 /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
@@ -62,8 +66,10 @@ pub struct StaticConfig {
     pub(crate) max_servers: usize,
     pub(crate) max_clients: usize,
     pub(crate) max_nodes: usize,
+    pub(crate) enable_fire_and_forget_requests: bool,
     pub(crate) request_message_type_details: MessageTypeDetails,
     pub(crate) response_message_type_details: MessageTypeDetails,
+    pub(crate) error_message_type_details: Option<MessageTypeDetails>,
 }
 
 impl StaticConfig {
@@ -86,8 +92,13 @@ impl StaticConfig {
             max_servers: config.defaults.request_response.max_servers,
             max_clients: config.defaults.request_response.max_clients,
             max_nodes: config.defaults.request_response.max_nodes,
+            enable_fire_and_forget_requests: config
+                .defaults
+                .request_response
+                .enable_fire_and_forget_requests,
             request_message_type_details: MessageTypeDetails::default(),
             response_message_type_details: MessageTypeDetails::default(),
+            error_message_type_details: None,
         }
     }
 
@@ -101,6 +112,42 @@ impl StaticConfig {
         &self.response_message_type_details
     }
 
+    /// Returns the error response type details of the [`crate::service::Service`] when it
+    /// was created with a dedicated error type, otherwise [`None`].
+    pub fn error_message_type_details(&self) -> Option<&MessageTypeDetails> {
+        self.error_message_type_details.as_ref()
+    }
+
+    pub(crate) fn has_error_type<E>(&self) -> bool {
+        match &self.error_message_type_details {
+            Some(details) => {
+                details.payload == TypeDetail::__internal_new::<E>(TypeVariant::FixedSize)
+            }
+            None => false,
+        }
+    }
+
+    /// The layout of a sample in the data segment of a [`crate::port::server::Server`]. It
+    /// must be able to store a response with `max_slice_len` elements as well as an error
+    /// response.
+    pub(crate) fn response_sample_layout(&self, max_slice_len: usize) -> Layout {
+        let response_layout = self
+            .response_message_type_details
+            .sample_layout(max_slice_len);
+        match &self.error_message_type_details {
+            Some(details) => {
+                let error_layout = details.sample_layout(1);
+                unsafe {
+                    Layout::from_size_align_unchecked(
+                        response_layout.size().max(error_layout.size()),
+                        response_layout.align().max(error_layout.align()),
+                    )
+                }
+            }
+            None => response_layout,
+        }
+    }
+
     /// Returns true if the request buffer of the [`crate::service::Service`] safely overflows,
     /// otherwise false. Safe overflow means that the [`crate::port::client::Client`] will
     /// recycle the oldest requests from the [`crate::port::server::Server`] when its buffer
@@ -117,6 +164,12 @@ impl StaticConfig {
         self.enable_safe_overflow_for_responses
     }
 
+    /// Returns true if [`crate::port::client::Client`]s can send fire and forget requests
+    /// that do not expect any response, otherwise false.
+    pub fn has_fire_and_forget_requests(&self) -> bool {
+        self.enable_fire_and_forget_requests
+    }
+
     /// Returns the maximum of active responses a [`crate::port::server::Server`] can hold in
     /// parallel.
     pub fn max_active_responses(&self) -> usize {
@@ -167,4 +220,34 @@ impl StaticConfig {
     pub fn max_nodes(&self) -> usize {
         self.max_nodes
     }
+
+    /// The number of request samples a [`crate::port::client::Client`] requires so that it
+    /// can hold all of its active requests and fill the request buffers of all
+    /// [`crate::port::server::Server`]s.
+    pub(crate) fn number_of_requests_per_client(&self) -> usize {
+        self.max_active_requests
+            + self.max_servers * (self.max_request_buffer_size + self.max_borrowed_requests)
+    }
+
+    /// All active requests of a [`crate::port::client::Client`] share one response connection
+    /// per [`crate::port::server::Server`], therefore the buffer must be able to hold the
+    /// responses of all of them.
+    pub(crate) fn response_buffer_size_per_client(&self) -> usize {
+        self.max_active_requests * self.max_response_buffer_size
+    }
+
+    /// Responses that are received for another active request are kept until that request
+    /// receives them and count as borrowed as well.
+    pub(crate) fn max_borrowed_responses_per_client(&self) -> usize {
+        self.max_active_requests * (self.max_response_buffer_size + self.max_borrowed_responses)
+    }
+
+    /// The number of response samples a [`crate::port::server::Server`] requires to serve
+    /// all [`crate::port::client::Client`]s.
+    pub(crate) fn number_of_responses_per_server(&self) -> usize {
+        self.max_active_responses
+            + self.max_clients
+                * (self.response_buffer_size_per_client()
+                    + self.max_borrowed_responses_per_client())
+    }
 }
@@ -0,0 +1,257 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! When a [`ServiceSigningKey`] is configured in
+//! [`Config::global.service.signing_key`](crate::config::Service::signing_key), every created
+//! service signs its static config with it. The id of the key and the signature are stored in
+//! the attributes of the service under [`SIGNATURE_KEY_ID_ATTRIBUTE`] and
+//! [`SIGNATURE_ATTRIBUTE`].
+//!
+//! When [`Config::global.service.verify_service_signatures`](crate::config::Service::verify_service_signatures)
+//! is enabled, a service can only be opened when its static config was signed with one of the
+//! [`TrustedKey`]s. Otherwise, the open call fails with a dedicated error, like
+//! [`PublishSubscribeOpenError::SignatureVerificationFailed`](crate::service::builder::publish_subscribe::PublishSubscribeOpenError::SignatureVerificationFailed).
+//!
+//! Signing and verification require the `signing` feature. Without it, the creation of a
+//! service fails when a signing key is configured and the opening of a service fails when
+//! signatures shall be verified.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::config::{ServiceSigningKey, TrustedKey};
+//!
+//! # #[cfg(not(feature = "signing"))]
+//! # fn main() {}
+//! # #[cfg(feature = "signing")]
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! const SECRET_KEY: &str = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+//! const PUBLIC_KEY: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+//!
+//! // the deployer signs the services it creates
+//! let mut deployer_config = Config::default();
+//! deployer_config.global.service.signing_key =
+//!     Some(ServiceSigningKey::new("deployer", SECRET_KEY));
+//! let deployer = NodeBuilder::new()
+//!     .config(&deployer_config)
+//!     .create::<ipc::Service>()?;
+//! let deployed_service = deployer.service_builder(&"My/Signed/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .create()?;
+//!
+//! // the subscriber opens only services that were created by the trusted deployer
+//! let mut subscriber_config = Config::default();
+//! subscriber_config.global.service.verify_service_signatures = true;
+//! subscriber_config.global.service.trusted_keys = vec![TrustedKey::new("deployer", PUBLIC_KEY)];
+//! let node = NodeBuilder::new()
+//!     .config(&subscriber_config)
+//!     .create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Signed/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open()?;
+//! # Ok(())
+//! # }
+//! ```
+
+#[cfg(feature = "signing")]
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+use iceoryx2_bb_log::fail;
+#[cfg(feature = "signing")]
+use iceoryx2_cal::serialize::Serialize;
+
+use super::StaticConfig;
+use crate::config::{ServiceSigningKey, TrustedKey};
+use crate::service;
+
+/// The attribute key under which the id of the key that signed the static config is stored.
+pub const SIGNATURE_KEY_ID_ATTRIBUTE: &str = "iox2.signature.key-id";
+
+/// The attribute key under which the hex encoded ed25519 signature of the static config is
+/// stored.
+pub const SIGNATURE_ATTRIBUTE: &str = "iox2.signature";
+
+#[cfg(feature = "signing")]
+const KEY_LENGTH: usize = 32;
+#[cfg(feature = "signing")]
+const SIGNATURE_LENGTH: usize = 64;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub(crate) enum SignatureError {
+    #[cfg(feature = "signing")]
+    MalformedKey,
+    #[cfg(feature = "signing")]
+    SerializationFailure,
+    #[cfg(feature = "signing")]
+    MissingSignature,
+    #[cfg(feature = "signing")]
+    UntrustedKey,
+    #[cfg(feature = "signing")]
+    InvalidSignature,
+    #[cfg(not(feature = "signing"))]
+    NotSupported,
+}
+
+#[cfg(feature = "signing")]
+fn decode_hex<const N: usize>(value: &str) -> Option<[u8; N]> {
+    let value = value.trim();
+    if value.len() != 2 * N || !value.is_ascii() {
+        return None;
+    }
+
+    let mut bytes = [0u8; N];
+    for (n, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&value[2 * n..2 * n + 2], 16).ok()?;
+    }
+
+    Some(bytes)
+}
+
+#[cfg(feature = "signing")]
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[cfg(feature = "signing")]
+// the signature covers the whole static config except the signature attributes themselves
+fn signed_content<S: service::Service>(config: &StaticConfig) -> Result<Vec<u8>, SignatureError> {
+    let mut unsigned_config = config.clone();
+    unsigned_config
+        .attributes
+        .remove(SIGNATURE_KEY_ID_ATTRIBUTE);
+    unsigned_config.attributes.remove(SIGNATURE_ATTRIBUTE);
+
+    match S::ConfigSerializer::serialize(&unsigned_config) {
+        Ok(content) => Ok(content),
+        Err(e) => {
+            fail!(from config, with SignatureError::SerializationFailure,
+                "Unable to acquire the signed content since the static config could not be serialized ({:?}).", e);
+        }
+    }
+}
+
+#[cfg(feature = "signing")]
+/// Signs the static config with the [`ServiceSigningKey`] and stores the key id and the
+/// signature in its attributes. Signature attributes that are already present are replaced.
+pub(crate) fn sign<S: service::Service>(
+    config: &mut StaticConfig,
+    key: &ServiceSigningKey,
+) -> Result<(), SignatureError> {
+    let msg = "Unable to sign the static config";
+    let secret_key = match decode_hex::<KEY_LENGTH>(&key.secret_key) {
+        Some(secret_key) => secret_key,
+        None => {
+            fail!(from config, with SignatureError::MalformedKey,
+                "{} since the secret key of the signing key \"{}\" is not a hex encoded {} byte ed25519 key.",
+                msg, key.key_id, KEY_LENGTH);
+        }
+    };
+
+    let content = signed_content::<S>(config)?;
+    let signature = SigningKey::from_bytes(&secret_key).sign(&content);
+
+    config.attributes.remove(SIGNATURE_KEY_ID_ATTRIBUTE);
+    config.attributes.remove(SIGNATURE_ATTRIBUTE);
+    config
+        .attributes
+        .add(SIGNATURE_KEY_ID_ATTRIBUTE, &key.key_id);
+    config
+        .attributes
+        .add(SIGNATURE_ATTRIBUTE, &encode_hex(&signature.to_bytes()));
+
+    Ok(())
+}
+
+#[cfg(feature = "signing")]
+/// Verifies that the static config was signed with one of the [`TrustedKey`]s.
+pub(crate) fn verify<S: service::Service>(
+    config: &StaticConfig,
+    trusted_keys: &[TrustedKey],
+) -> Result<(), SignatureError> {
+    let msg = "Unable to verify the signature of the static config";
+    let attributes = config.attributes();
+    if attributes.get_key_value_len(SIGNATURE_KEY_ID_ATTRIBUTE) != 1
+        || attributes.get_key_value_len(SIGNATURE_ATTRIBUTE) != 1
+    {
+        fail!(from config, with SignatureError::MissingSignature,
+            "{} since it does not contain exactly one signature.", msg);
+    }
+
+    // both attributes exist, see the check above
+    let key_id = attributes
+        .get_key_value_at(SIGNATURE_KEY_ID_ATTRIBUTE, 0)
+        .unwrap_or_default();
+    let signature = attributes
+        .get_key_value_at(SIGNATURE_ATTRIBUTE, 0)
+        .unwrap_or_default();
+
+    let trusted_key = match trusted_keys
+        .iter()
+        .find(|trusted_key| trusted_key.key_id == key_id)
+    {
+        Some(trusted_key) => trusted_key,
+        None => {
+            fail!(from config, with SignatureError::UntrustedKey,
+                "{} since it was signed with the untrusted key \"{}\".", msg, key_id);
+        }
+    };
+
+    let public_key = match decode_hex::<KEY_LENGTH>(&trusted_key.public_key)
+        .and_then(|public_key| VerifyingKey::from_bytes(&public_key).ok())
+    {
+        Some(public_key) => public_key,
+        None => {
+            fail!(from config, with SignatureError::MalformedKey,
+                "{} since the public key of the trusted key \"{}\" is not a valid hex encoded {} byte ed25519 key.",
+                msg, key_id, KEY_LENGTH);
+        }
+    };
+
+    let signature = match decode_hex::<SIGNATURE_LENGTH>(signature) {
+        Some(signature) => Signature::from_bytes(&signature),
+        None => {
+            fail!(from config, with SignatureError::InvalidSignature,
+                "{} since the signature is not a hex encoded {} byte ed25519 signature.",
+                msg, SIGNATURE_LENGTH);
+        }
+    };
+
+    let content = signed_content::<S>(config)?;
+    if let Err(e) = public_key.verify_strict(&content, &signature) {
+        fail!(from config, with SignatureError::InvalidSignature,
+            "{} since the signature does not match the static config ({:?}).", msg, e);
+    }
+
+    Ok(())
+}
+
+/// Fails since the static config cannot be signed without the `signing` feature.
+#[cfg(not(feature = "signing"))]
+pub(crate) fn sign<S: service::Service>(
+    config: &mut StaticConfig,
+    key: &ServiceSigningKey,
+) -> Result<(), SignatureError> {
+    fail!(from config, with SignatureError::NotSupported,
+        "Unable to sign the static config with the key \"{}\" since iceoryx2 was built without the signing feature.",
+        key.key_id);
+}
+
+/// Fails since the signature of the static config cannot be verified without the `signing`
+/// feature.
+#[cfg(not(feature = "signing"))]
+pub(crate) fn verify<S: service::Service>(
+    config: &StaticConfig,
+    _trusted_keys: &[TrustedKey],
+) -> Result<(), SignatureError> {
+    fail!(from config, with SignatureError::NotSupported,
+        "Unable to verify the signature of the static config since iceoryx2 was built without the signing feature.");
+}
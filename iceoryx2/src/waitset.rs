@@ -185,6 +185,44 @@
 //! # }
 //! ```
 //!
+//! ## Attachment Groups
+//!
+//! Attachments can be organized in [`WaitSetGroup`](crate::waitset::WaitSetGroup)s that are
+//! disabled and enabled as a whole. Groups can contain subgroups, disabling a group disables
+//! all of its subgroups as well.
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! # use core::time::Duration;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! # let event_1 = node.service_builder(&"MyEventName_1".try_into()?)
+//! #     .event()
+//! #     .open_or_create()?;
+//! # let event_2 = node.service_builder(&"MyEventName_2".try_into()?)
+//! #     .event()
+//! #     .open_or_create()?;
+//!
+//! let sensor_listener_1 = event_1.listener_builder().create()?;
+//! let sensor_listener_2 = event_2.listener_builder().create()?;
+//!
+//! let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+//! let guard_1 = waitset.attach_notification(&sensor_listener_1)?;
+//! let guard_2 = waitset.attach_deadline(&sensor_listener_2, Duration::from_millis(100))?;
+//!
+//! let sensors = waitset.create_group();
+//! sensors.add(&guard_1)?;
+//! sensors.add(&guard_2)?;
+//!
+//! // no sensor events and no missed deadlines are reported during the reconfiguration
+//! sensors.disable();
+//! // reconfigure the sensors
+//! sensors.enable();
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! ## Using [`WaitSet`](crate::waitset::WaitSet) Without [`Signal`](iceoryx2_bb_posix::signal::Signal) Handling
 //!
 //! This example demonstrates how the [`WaitSet`](crate::waitset::WaitSet) can be used when
@@ -214,13 +252,17 @@
 //! # }
 
 use core::{
-    cell::RefCell, fmt::Debug, hash::Hash, marker::PhantomData, sync::atomic::Ordering,
+    cell::{Cell, RefCell},
+    fmt::Debug,
+    hash::Hash,
+    marker::PhantomData,
+    sync::atomic::Ordering,
     time::Duration,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use iceoryx2_bb_elementary::CallbackProgression;
-use iceoryx2_bb_log::fail;
+use iceoryx2_bb_log::{debug, fail, warn};
 use iceoryx2_bb_posix::{
     deadline_queue::{DeadlineQueue, DeadlineQueueBuilder, DeadlineQueueGuard, DeadlineQueueIndex},
     file_descriptor::FileDescriptor,
@@ -230,8 +272,13 @@ use iceoryx2_bb_posix::{
 use iceoryx2_cal::reactor::*;
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
 
+use crate::node::load_shedding::{CpuLoadMeasurementError, LoadMonitor};
+use crate::node::Node;
 use crate::signal_handling_mode::SignalHandlingMode;
 
+extern crate alloc;
+use alloc::sync::Arc;
+
 /// States why the [`WaitSet::wait_and_process()`] method returned.
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum WaitSetRunResult {
@@ -255,6 +302,8 @@ pub enum WaitSetAttachmentError {
     AlreadyAttached,
     /// An internal error has occurred.
     InternalError,
+    /// The file descriptor is not a valid open file descriptor, see [`WaitSet::attach_fd()`].
+    InvalidFileDescriptor,
 }
 
 impl core::fmt::Display for WaitSetAttachmentError {
@@ -299,6 +348,36 @@ impl core::fmt::Display for WaitSetCreateError {
 
 impl core::error::Error for WaitSetCreateError {}
 
+/// Defines the failures that can occur when an attachment is added to a [`WaitSetGroup`] with
+/// [`WaitSetGroup::add()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WaitSetGroupError {
+    /// The attachment is attached to a different [`WaitSet`] than the [`WaitSetGroup`].
+    AttachmentOfDifferentWaitSet,
+    /// The attachment is already member of a [`WaitSetGroup`].
+    AlreadyGroupMember,
+}
+
+impl core::fmt::Display for WaitSetGroupError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "WaitSetGroupError::{:?}", self)
+    }
+}
+
+impl core::error::Error for WaitSetGroupError {}
+
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+enum AttachmentKey {
+    Reactor(i32),
+    DeadlineQueue(DeadlineQueueIndex),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct GroupDetails {
+    parent: Option<u64>,
+    is_enabled: bool,
+}
+
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, PartialOrd, Ord)]
 enum AttachmentIdType {
     Tick(u64, DeadlineQueueIndex),
@@ -341,6 +420,12 @@ impl<Service: crate::service::Service> WaitSetAttachmentId<Service> {
                     r.file_descriptor().native_handle()
                 })
             }
+            #[cfg(feature = "waitset_fd_attachments")]
+            GuardType::FileDescriptor(r, _) => {
+                WaitSetAttachmentId::notification(guard.waitset, unsafe {
+                    r.file_descriptor().native_handle()
+                })
+            }
         }
     }
 }
@@ -444,8 +529,29 @@ where
         DeadlineQueueGuard<'waitset>,
     ),
     Notification(<Service::Reactor as Reactor>::Guard<'waitset, 'attachment>),
+    // the reactor guard refers to the boxed file descriptor and must be dropped first
+    #[cfg(feature = "waitset_fd_attachments")]
+    FileDescriptor(
+        <Service::Reactor as Reactor>::Guard<'waitset, 'static>,
+        Box<ExternalFileDescriptor>,
+    ),
 }
 
+/// A file descriptor that is not owned by the [`WaitSet`], see [`WaitSet::attach_fd()`].
+#[cfg(feature = "waitset_fd_attachments")]
+#[derive(Debug)]
+struct ExternalFileDescriptor(FileDescriptor);
+
+#[cfg(feature = "waitset_fd_attachments")]
+impl iceoryx2_bb_posix::file_descriptor::FileDescriptorBased for ExternalFileDescriptor {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        &self.0
+    }
+}
+
+#[cfg(feature = "waitset_fd_attachments")]
+impl SynchronousMultiplexing for ExternalFileDescriptor {}
+
 /// Is returned when something is attached to the [`WaitSet`]. As soon as it goes out
 /// of scope, the attachment is detached.
 pub struct WaitSetGuard<'waitset, 'attachment, Service: crate::service::Service>
@@ -456,8 +562,24 @@ where
     guard_type: GuardType<'waitset, 'attachment, Service>,
 }
 
+impl<Service: crate::service::Service> WaitSetGuard<'_, '_, Service> {
+    fn attachment_key(&self) -> AttachmentKey {
+        match &self.guard_type {
+            GuardType::Tick(t) => AttachmentKey::DeadlineQueue(t.index()),
+            GuardType::Deadline(r, _) | GuardType::Notification(r) => {
+                AttachmentKey::Reactor(unsafe { r.file_descriptor().native_handle() })
+            }
+            #[cfg(feature = "waitset_fd_attachments")]
+            GuardType::FileDescriptor(r, _) => {
+                AttachmentKey::Reactor(unsafe { r.file_descriptor().native_handle() })
+            }
+        }
+    }
+}
+
 impl<Service: crate::service::Service> Drop for WaitSetGuard<'_, '_, Service> {
     fn drop(&mut self) {
+        self.waitset.remove_group_member(self.attachment_key());
         if let GuardType::Deadline(r, t) = &self.guard_type {
             self.waitset
                 .remove_deadline(unsafe { r.file_descriptor().native_handle() }, t.index())
@@ -504,6 +626,11 @@ impl WaitSetBuilder {
                 deadline_to_attachment: RefCell::new(HashMap::new()),
                 attachment_counter: IoxAtomicUsize::new(0),
                 signal_handling_mode: self.signal_handling_mode,
+                group_counter: Cell::new(0),
+                groups: RefCell::new(HashMap::new()),
+                group_members: RefCell::new(HashMap::new()),
+                disabled_attachments: RefCell::new(HashSet::new()),
+                load_monitors: RefCell::new(vec![]),
             }),
             Err(ReactorCreateError::UnknownError(e)) => {
                 fail!(from self, with WaitSetCreateError::InternalError,
@@ -532,6 +659,11 @@ pub struct WaitSet<Service: crate::service::Service> {
     deadline_to_attachment: RefCell<HashMap<DeadlineQueueIndex, i32>>,
     attachment_counter: IoxAtomicUsize,
     signal_handling_mode: SignalHandlingMode,
+    group_counter: Cell<u64>,
+    groups: RefCell<HashMap<u64, GroupDetails>>,
+    group_members: RefCell<HashMap<AttachmentKey, u64>>,
+    disabled_attachments: RefCell<HashSet<AttachmentKey>>,
+    load_monitors: RefCell<Vec<Arc<LoadMonitor>>>,
 }
 
 impl<Service: crate::service::Service> WaitSet<Service> {
@@ -580,11 +712,18 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         error_msg: &str,
     ) -> Result<WaitSetRunResult, WaitSetRunError> {
         let deadline_to_attachment = self.deadline_to_attachment.borrow();
+        let disabled_attachments = self.disabled_attachments.borrow();
         let mut result = WaitSetRunResult::AllEventsHandled;
         let call = |idx: DeadlineQueueIndex| -> CallbackProgression {
             let progression = if let Some(reactor_idx) = deadline_to_attachment.get(&idx) {
+                if disabled_attachments.contains(&AttachmentKey::Reactor(*reactor_idx)) {
+                    return CallbackProgression::Continue;
+                }
                 fn_call(WaitSetAttachmentId::deadline(self, *reactor_idx, idx))
             } else {
+                if disabled_attachments.contains(&AttachmentKey::DeadlineQueue(idx)) {
+                    return CallbackProgression::Continue;
+                }
                 fn_call(WaitSetAttachmentId::tick(self, idx))
             };
 
@@ -626,6 +765,15 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         };
 
         for fd in triggered_file_descriptors {
+            // a previous callback may have disabled the attachment
+            if self
+                .disabled_attachments
+                .borrow()
+                .contains(&AttachmentKey::Reactor(*fd))
+            {
+                continue;
+            }
+
             if let CallbackProgression::Stop = fn_call(WaitSetAttachmentId::notification(self, *fd))
             {
                 return Ok(WaitSetRunResult::StopRequest);
@@ -635,6 +783,102 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         Ok(WaitSetRunResult::AllEventsHandled)
     }
 
+    fn is_group_enabled(groups: &HashMap<u64, GroupDetails>, group_id: u64) -> bool {
+        let mut current = Some(group_id);
+        while let Some(id) = current {
+            match groups.get(&id) {
+                Some(details) if !details.is_enabled => return false,
+                Some(details) => current = details.parent,
+                None => return true,
+            }
+        }
+
+        true
+    }
+
+    fn disable_attachment(&self, key: AttachmentKey) {
+        if let AttachmentKey::Reactor(fd) = key {
+            if let Some(fd) = FileDescriptor::non_owning_new(fd) {
+                self.reactor.suspend(&fd);
+            }
+        }
+    }
+
+    fn enable_attachment(&self, key: AttachmentKey) {
+        // deadlines and intervals restart when the attachment is enabled, otherwise the
+        // time the attachment was disabled would be reported as missed deadline
+        let deadline_queue_idx = match key {
+            AttachmentKey::Reactor(fd) => {
+                if let Some(fd) = FileDescriptor::non_owning_new(fd) {
+                    self.reactor.resume(&fd);
+                }
+                self.attachment_to_deadline.borrow().get(&fd).copied()
+            }
+            AttachmentKey::DeadlineQueue(idx) => Some(idx),
+        };
+
+        if let Some(idx) = deadline_queue_idx {
+            if let Err(e) = self.deadline_queue.reset(idx) {
+                warn!(from self,
+                    "Unable to reset the deadline of the enabled attachment ({:?}). A stale missed deadline might be reported.", e);
+            }
+        }
+    }
+
+    fn update_attachment_states(&self) {
+        let groups = self.groups.borrow();
+        let group_members = self.group_members.borrow();
+        let mut disabled_attachments = self.disabled_attachments.borrow_mut();
+
+        let mut keys: Vec<AttachmentKey> = group_members.keys().copied().collect();
+        keys.extend(
+            disabled_attachments
+                .iter()
+                .filter(|key| !group_members.contains_key(key)),
+        );
+
+        for key in keys {
+            let should_be_enabled = match group_members.get(&key) {
+                Some(group_id) => Self::is_group_enabled(&groups, *group_id),
+                None => true,
+            };
+            let is_enabled = !disabled_attachments.contains(&key);
+
+            if should_be_enabled && !is_enabled {
+                disabled_attachments.remove(&key);
+                self.enable_attachment(key);
+            } else if !should_be_enabled && is_enabled {
+                disabled_attachments.insert(key);
+                self.disable_attachment(key);
+            }
+        }
+    }
+
+    fn remove_group_member(&self, key: AttachmentKey) {
+        self.group_members.borrow_mut().remove(&key);
+        self.disabled_attachments.borrow_mut().remove(&key);
+    }
+
+    fn create_group_with_parent(&self, parent: Option<u64>) -> WaitSetGroup<'_, Service> {
+        let id = self.group_counter.get();
+        self.group_counter.set(id + 1);
+        self.groups.borrow_mut().insert(
+            id,
+            GroupDetails {
+                parent,
+                is_enabled: true,
+            },
+        );
+
+        WaitSetGroup { waitset: self, id }
+    }
+
+    /// Creates a new top-level [`WaitSetGroup`]. Attachments can be added to the group with
+    /// [`WaitSetGroup::add()`] and are disabled and enabled together with the group.
+    pub fn create_group(&self) -> WaitSetGroup<'_, Service> {
+        self.create_group_with_parent(None)
+    }
+
     /// Attaches an object as notification to the [`WaitSet`]. Whenever an event is received on the
     /// object the [`WaitSet`] informs the user in [`WaitSet::wait_and_process()`] to handle the event.
     /// The object cannot be attached twice and the
@@ -682,6 +926,62 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         })
     }
 
+    /// Attaches an arbitrary POSIX file descriptor, like an external socket or timer, as
+    /// notification to the [`WaitSet`]. Whenever the file descriptor becomes readable the
+    /// [`WaitSet`] informs the user in [`WaitSet::wait_and_process()`] with a
+    /// [`WaitSetAttachmentId`], like it does for every other notification.
+    ///
+    /// The [`WaitSet`] does not take the ownership of the file descriptor. The user must keep it
+    /// open as long as the returned [`WaitSetGuard`] exists.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use iceoryx2::prelude::*;
+    /// use std::os::fd::AsRawFd;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let socket = std::net::UdpSocket::bind("127.0.0.1:34254")?;
+    ///
+    /// let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+    /// let guard = waitset.attach_fd(socket.as_raw_fd())?;
+    ///
+    /// waitset.wait_and_process(|attachment_id| {
+    ///     if attachment_id.has_event_from(&guard) {
+    ///         let mut buffer = [0u8; 128];
+    ///         let _ = socket.recv(&mut buffer);
+    ///     }
+    ///     CallbackProgression::Continue
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "waitset_fd_attachments")]
+    pub fn attach_fd(
+        &self,
+        raw_fd: i32,
+    ) -> Result<WaitSetGuard<'_, 'static, Service>, WaitSetAttachmentError> {
+        let msg = "Unable to attach file descriptor";
+        let fd = match FileDescriptor::non_owning_new(raw_fd) {
+            Some(fd) if fd.is_valid() => Box::new(ExternalFileDescriptor(fd)),
+            _ => {
+                fail!(from self, with WaitSetAttachmentError::InvalidFileDescriptor,
+                    "{msg} {raw_fd} since it is not a valid open file descriptor.");
+            }
+        };
+
+        // The file descriptor is stored on the heap and owned by the guard that drops the
+        // reactor guard first, therefore the reference stays valid as long as it is used.
+        let fd_ref: &'static ExternalFileDescriptor =
+            unsafe { &*(fd.as_ref() as *const ExternalFileDescriptor) };
+        let reactor_guard = self.attach_to_reactor(fd_ref)?;
+        self.attach()?;
+
+        Ok(WaitSetGuard {
+            waitset: self,
+            guard_type: GuardType::FileDescriptor(reactor_guard, fd),
+        })
+    }
+
     /// Attaches a tick event to the [`WaitSet`]. Whenever the timeout is reached the [`WaitSet`]
     /// informs the user in [`WaitSet::wait_and_process()`].
     pub fn attach_interval(
@@ -904,6 +1204,8 @@ impl<Service: crate::service::Service> WaitSet<Service> {
             self.reactor.timed_wait(collect_triggered_fds, next_timeout)
         };
 
+        self.measure_load();
+
         match reactor_wait_result {
             Ok(0) => self.handle_deadlines(&mut fn_call, msg),
             Ok(_) => self.handle_all_attachments(&triggered_file_descriptors, &mut fn_call, msg),
@@ -934,11 +1236,49 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         self.len() == 0
     }
 
+    /// Returns the number of attachments that are disabled, since they are member of a
+    /// disabled [`WaitSetGroup`].
+    pub fn number_of_disabled_attachments(&self) -> usize {
+        self.disabled_attachments.borrow().len()
+    }
+
     /// Returns the [`SignalHandlingMode`] with which the [`WaitSet`] was created.
     pub fn signal_handling_mode(&self) -> SignalHandlingMode {
         self.signal_handling_mode
     }
 
+    /// Measures the CPU load of the [`Node`] whenever the [`WaitSet`] wakes up, before the
+    /// attachments are processed, so that the low-priority
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s of the [`Node`] start or stop
+    /// shedding their samples automatically, see
+    /// [`load_shedding`](crate::node::load_shedding). Monitoring the same [`Node`] twice has
+    /// no effect.
+    pub fn monitor_load(&self, node: &Node<Service>) {
+        let load_monitor = node.load_monitor();
+        let mut load_monitors = self.load_monitors.borrow_mut();
+        if !load_monitors.iter().any(|m| Arc::ptr_eq(m, load_monitor)) {
+            load_monitors.push(load_monitor.clone());
+        }
+    }
+
+    fn measure_load(&self) {
+        // platforms without CPU usage measurement are not retried on every wake up
+        self.load_monitors
+            .borrow_mut()
+            .retain(|load_monitor| match load_monitor.measure() {
+                Ok(()) => true,
+                Err(CpuLoadMeasurementError::UnsupportedPlatform) => {
+                    debug!(from self,
+                        "Stop monitoring the load since the CPU load cannot be measured on this platform.");
+                    false
+                }
+                Err(e) => {
+                    debug!(from self, "Unable to monitor the load ({:?}).", e);
+                    true
+                }
+            });
+    }
+
     fn attach_to_reactor<'waitset, 'attachment, T: SynchronousMultiplexing + Debug>(
         &'waitset self,
         attachment: &'attachment T,
@@ -979,3 +1319,136 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         }
     }
 }
+
+/// A group of attachments of a [`WaitSet`], created with [`WaitSet::create_group()`] or
+/// [`WaitSetGroup::create_subgroup()`]. Disabling the group suppresses all events, missed
+/// deadlines and intervals of its members and of the members of all its subgroups until it is
+/// enabled again.
+///
+/// Triggers that occur while the group is disabled are never reported. When the group is
+/// enabled again, the deadlines and intervals of its members restart. Notifications that are
+/// still pending in the attachment, like unread events of a
+/// [`Listener`](crate::port::listener::Listener), are reported after the group was enabled.
+///
+/// When the group goes out of scope its members leave the group and its subgroups become
+/// subgroups of its parent.
+pub struct WaitSetGroup<'waitset, Service: crate::service::Service> {
+    waitset: &'waitset WaitSet<Service>,
+    id: u64,
+}
+
+impl<Service: crate::service::Service> Debug for WaitSetGroup<'_, Service> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "WaitSetGroup<{}> {{ id: {}, is_enabled: {} }}",
+            core::any::type_name::<Service>(),
+            self.id,
+            self.is_enabled()
+        )
+    }
+}
+
+impl<Service: crate::service::Service> Drop for WaitSetGroup<'_, Service> {
+    fn drop(&mut self) {
+        {
+            let mut groups = self.waitset.groups.borrow_mut();
+            let parent = groups.remove(&self.id).and_then(|details| details.parent);
+            for details in groups.values_mut() {
+                if details.parent == Some(self.id) {
+                    details.parent = parent;
+                }
+            }
+
+            self.waitset
+                .group_members
+                .borrow_mut()
+                .retain(|_, group_id| *group_id != self.id);
+        }
+
+        self.waitset.update_attachment_states();
+    }
+}
+
+impl<'waitset, Service: crate::service::Service> WaitSetGroup<'waitset, Service> {
+    /// Creates a new [`WaitSetGroup`] that is disabled whenever this group is disabled.
+    pub fn create_subgroup(&self) -> WaitSetGroup<'waitset, Service> {
+        self.waitset.create_group_with_parent(Some(self.id))
+    }
+
+    /// Adds the attachment corresponding to the [`WaitSetGuard`] to the group. An attachment
+    /// can be member of only one group.
+    pub fn add(&self, guard: &WaitSetGuard<Service>) -> Result<(), WaitSetGroupError> {
+        let msg = "Unable to add attachment to WaitSetGroup";
+        if !core::ptr::eq(self.waitset, guard.waitset) {
+            fail!(from self, with WaitSetGroupError::AttachmentOfDifferentWaitSet,
+                "{msg} since the attachment is attached to a different WaitSet.");
+        }
+
+        let key = guard.attachment_key();
+        if let Some(group_id) = self.waitset.group_members.borrow().get(&key) {
+            fail!(from self, with WaitSetGroupError::AlreadyGroupMember,
+                "{msg} since the attachment is already member of the group {}.", group_id);
+        }
+
+        self.waitset.group_members.borrow_mut().insert(key, self.id);
+        self.waitset.update_attachment_states();
+        Ok(())
+    }
+
+    /// Removes the attachment corresponding to the [`WaitSetGuard`] from the group. Returns
+    /// true when it was a member of the group, otherwise false.
+    pub fn remove(&self, guard: &WaitSetGuard<Service>) -> bool {
+        if !core::ptr::eq(self.waitset, guard.waitset) {
+            return false;
+        }
+
+        let key = guard.attachment_key();
+        let mut group_members = self.waitset.group_members.borrow_mut();
+        if group_members.get(&key) != Some(&self.id) {
+            return false;
+        }
+        group_members.remove(&key);
+        drop(group_members);
+
+        self.waitset.update_attachment_states();
+        true
+    }
+
+    /// Returns true if the attachment corresponding to the [`WaitSetGuard`] is a member of
+    /// this group.
+    pub fn contains(&self, guard: &WaitSetGuard<Service>) -> bool {
+        core::ptr::eq(self.waitset, guard.waitset)
+            && self
+                .waitset
+                .group_members
+                .borrow()
+                .get(&guard.attachment_key())
+                == Some(&self.id)
+    }
+
+    /// Disables the group and all its subgroups. Events of its members are not reported until
+    /// the group is enabled again.
+    pub fn disable(&self) {
+        self.set_enabled(false);
+    }
+
+    /// Enables the group. Its members are only reported when all parent groups are enabled
+    /// as well.
+    pub fn enable(&self) {
+        self.set_enabled(true);
+    }
+
+    /// Returns true if the group and all its parent groups are enabled, otherwise false.
+    pub fn is_enabled(&self) -> bool {
+        WaitSet::<Service>::is_group_enabled(&self.waitset.groups.borrow(), self.id)
+    }
+
+    fn set_enabled(&self, value: bool) {
+        if let Some(details) = self.waitset.groups.borrow_mut().get_mut(&self.id) {
+            details.is_enabled = value;
+        }
+
+        self.waitset.update_attachment_states();
+    }
+}
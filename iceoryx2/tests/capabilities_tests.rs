@@ -0,0 +1,38 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod capabilities {
+    use iceoryx2::port::event_payload::MAX_EVENT_PAYLOAD_SIZE;
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn capabilities_report_build_limits<Sut: Service>() {
+        let sut = iceoryx2::capabilities::<Sut>();
+
+        assert_that!(sut.max_number_of_segments(), ge 1);
+        assert_that!(sut.max_supported_alignment().is_power_of_two(), eq true);
+        assert_that!(sut.max_event_payload_size(), eq MAX_EVENT_PAYLOAD_SIZE);
+        assert_that!(sut.max_lock_free_atomic_width(), ge 8);
+        assert_that!(
+            sut.is_deterministic_profile_enabled(),
+            eq iceoryx2::deterministic_profile::IS_ENABLED
+        );
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -42,3 +42,269 @@ mod node_name {
         assert_that!(default_config, eq file_config);
     }
 }
+
+mod config_overlays {
+    use core::time::Duration;
+    use iceoryx2::config::ConfigCreationError;
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_container::semantic_string::SemanticString;
+    use iceoryx2_bb_posix::config::test_directory;
+    use iceoryx2_bb_posix::file::*;
+    use iceoryx2_bb_posix::testing::create_test_directory;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_name::FileName;
+    use iceoryx2_bb_system_types::file_path::FilePath;
+    use iceoryx2_bb_testing::assert_that;
+
+    struct TestFile {
+        path: FilePath,
+    }
+
+    impl TestFile {
+        fn new(contents: &str) -> Self {
+            create_test_directory();
+            let mut file = FileName::new(b"config_overlay_tests_").unwrap();
+            file.push_bytes(
+                UniqueSystemId::new()
+                    .unwrap()
+                    .value()
+                    .to_string()
+                    .as_bytes(),
+            )
+            .unwrap();
+            file.push_bytes(b".toml").unwrap();
+            let path = FilePath::from_path_and_file(&test_directory(), &file).unwrap();
+
+            let mut file = FileBuilder::new(&path)
+                .creation_mode(CreationMode::PurgeAndCreate)
+                .create()
+                .unwrap();
+            file.write(contents.as_bytes()).unwrap();
+
+            Self { path }
+        }
+    }
+
+    impl Drop for TestFile {
+        fn drop(&mut self) {
+            File::remove(&self.path).expect("failed to cleanup test file");
+        }
+    }
+
+    #[test]
+    fn from_files_without_files_returns_default_config() {
+        let (config, provenance) = Config::from_files_with_provenance(&[]).unwrap();
+
+        assert_that!(config, eq Config::default());
+        assert_that!(provenance.is_empty(), eq true);
+    }
+
+    #[test]
+    fn from_files_applies_partial_overlay_on_top_of_defaults() {
+        let overlay = TestFile::new(
+            r#"
+            [defaults.publish-subscribe]
+            max-publishers = 11
+            "#,
+        );
+
+        let config = Config::from_files(&[overlay.path]).unwrap();
+
+        let mut expected_config = Config::default();
+        expected_config.defaults.publish_subscribe.max_publishers = 11;
+        assert_that!(config, eq expected_config);
+    }
+
+    #[test]
+    fn from_files_later_files_override_earlier_files() {
+        let base = TestFile::new(
+            r#"
+            [defaults.publish-subscribe]
+            max-publishers = 11
+            max-subscribers = 12
+
+            [global.service.creation-timeout]
+            secs = 3
+            nanos = 0
+            "#,
+        );
+        let prod = TestFile::new(
+            r#"
+            [defaults.publish-subscribe]
+            max-subscribers = 13
+            "#,
+        );
+
+        let (config, provenance) =
+            Config::from_files_with_provenance(&[base.path, prod.path]).unwrap();
+
+        assert_that!(config.defaults.publish_subscribe.max_publishers, eq 11);
+        assert_that!(config.defaults.publish_subscribe.max_subscribers, eq 13);
+        assert_that!(config.global.service.creation_timeout, eq Duration::from_secs(3));
+
+        assert_that!(provenance.source_of("defaults.publish-subscribe.max-publishers"), eq Some(&base.path));
+        assert_that!(provenance.source_of("defaults.publish-subscribe.max-subscribers"), eq Some(&prod.path));
+        assert_that!(provenance.source_of("global.service.creation-timeout.secs"), eq Some(&base.path));
+        assert_that!(provenance.source_of("defaults.event.max-listeners"), eq None);
+        assert_that!(provenance.len(), eq 4);
+    }
+
+    #[test]
+    fn from_files_fails_when_one_file_does_not_exist() {
+        let base = TestFile::new(
+            r#"
+            [defaults.event]
+            max-listeners = 3
+            "#,
+        );
+        let mut non_existing = base.path;
+        non_existing.push_bytes(b".does_not_exist").unwrap();
+
+        let result = Config::from_files(&[base.path, non_existing]);
+
+        assert_that!(result.err(), eq Some(ConfigCreationError::ConfigFileDoesNotExist));
+    }
+
+    #[test]
+    fn from_files_fails_when_overlay_contains_invalid_value() {
+        let overlay = TestFile::new(
+            r#"
+            [defaults.event]
+            max-listeners = "many"
+            "#,
+        );
+
+        let result = Config::from_files(&[overlay.path]);
+
+        assert_that!(result.err(), eq Some(ConfigCreationError::UnableToDeserializeContents));
+    }
+}
+
+mod config_layers {
+    use iceoryx2::config::{
+        ConfigValidationError, Defaults, EnvironmentConfig, Event, PublishSubscribe, RequestResonse,
+    };
+    use iceoryx2::node::NodeCreationFailure;
+    use iceoryx2::port::event_payload::MAX_EVENT_PAYLOAD_SIZE;
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_system_types::file_name::FileName;
+    use iceoryx2_bb_system_types::path::Path;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn default_layers_are_valid() {
+        assert_that!(EnvironmentConfig::default().validate(), is_ok);
+        assert_that!(Defaults::default().validate(), is_ok);
+        assert_that!(Config::default().validate(), is_ok);
+    }
+
+    #[test]
+    fn config_from_layers_contains_both_layers() {
+        let environment = EnvironmentConfig::default()
+            .prefix(&FileName::new(b"layer_").unwrap())
+            .service_directory(&Path::new(b"layer_services").unwrap());
+        let mut defaults = Defaults::default();
+        defaults.publish_subscribe.max_publishers = 11;
+
+        let sut = Config::from_layers(&environment, &defaults);
+
+        assert_that!(sut.global.prefix, eq FileName::new(b"layer_").unwrap());
+        assert_that!(sut.global.service.directory, eq Path::new(b"layer_services").unwrap());
+        assert_that!(sut.defaults.publish_subscribe.max_publishers, eq 11);
+        assert_that!(sut.environment(), eq environment);
+        assert_that!(sut.defaults, eq defaults);
+    }
+
+    #[test]
+    fn environment_with_relative_root_path_is_invalid() {
+        let sut = EnvironmentConfig::default().root_path(&Path::new(b"relative").unwrap());
+
+        assert_that!(sut.validate().err(), eq Some(ConfigValidationError::RootPathIsNotAbsolute));
+    }
+
+    #[test]
+    fn environment_with_ambiguous_suffix_is_invalid() {
+        let sut = EnvironmentConfig::default()
+            .connection_suffix(&FileName::new(b".same").unwrap())
+            .broadcast_suffix(&FileName::new(b".same").unwrap());
+
+        assert_that!(sut.validate().err(), eq Some(ConfigValidationError::AmbiguousSuffix));
+    }
+
+    #[test]
+    fn defaults_with_zero_capacity_are_invalid() {
+        let mut sut = Defaults::default();
+        sut.event.max_listeners = 0;
+
+        assert_that!(sut.validate().err(), eq Some(ConfigValidationError::ZeroCapacity));
+    }
+
+    #[test]
+    fn node_creation_with_invalid_layer_fails() {
+        let mut defaults = Defaults::default();
+        defaults.request_response.max_servers = 0;
+
+        let sut = NodeBuilder::new()
+            .defaults(&defaults)
+            .create::<local::Service>();
+
+        assert_that!(sut.err(), eq Some(NodeCreationFailure::InvalidConfig));
+    }
+
+    #[test]
+    fn defaults_can_be_composed_with_typed_setters() {
+        let pubsub = PublishSubscribe::default()
+            .max_publishers(7)
+            .unwrap()
+            .enable_safe_overflow(false);
+        let event = Event::default().max_listeners(3).unwrap();
+        let rpc = RequestResonse::default().max_servers(2).unwrap();
+
+        let sut = Defaults::default()
+            .publish_subscribe(&pubsub)
+            .unwrap()
+            .event(&event)
+            .unwrap()
+            .request_response(&rpc)
+            .unwrap();
+
+        assert_that!(sut.publish_subscribe.max_publishers, eq 7);
+        assert_that!(sut.publish_subscribe.enable_safe_overflow, eq false);
+        assert_that!(sut.event.max_listeners, eq 3);
+        assert_that!(sut.request_response.max_servers, eq 2);
+        assert_that!(sut.validate(), is_ok);
+    }
+
+    #[test]
+    fn typed_setters_reject_zero_capacity() {
+        assert_that!(
+            PublishSubscribe::default().max_subscribers(0).err(),
+            eq Some(ConfigValidationError::ZeroCapacity)
+        );
+        assert_that!(
+            Event::default().max_notifiers(0).err(),
+            eq Some(ConfigValidationError::ZeroCapacity)
+        );
+        assert_that!(
+            RequestResonse::default().max_clients(0).err(),
+            eq Some(ConfigValidationError::ZeroCapacity)
+        );
+    }
+
+    #[test]
+    fn typed_setter_rejects_too_large_event_payload_size() {
+        let sut = Event::default().max_payload_size(MAX_EVENT_PAYLOAD_SIZE + 1);
+
+        assert_that!(sut.err(), eq Some(ConfigValidationError::EventPayloadSizeTooLarge));
+    }
+
+    #[test]
+    fn defaults_setter_rejects_invalid_section() {
+        let mut event = Event::default();
+        event.max_nodes = 0;
+
+        let sut = Defaults::default().event(&event);
+
+        assert_that!(sut.err(), eq Some(ConfigValidationError::ZeroCapacity));
+    }
+}
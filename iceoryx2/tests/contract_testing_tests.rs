@@ -0,0 +1,65 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::contract_testing::PublishSubscribeContract;
+
+#[derive(Debug, PartialEq)]
+#[repr(C)]
+struct ComplexPayload {
+    id: u64,
+    values: [u32; 8],
+    flag: bool,
+}
+
+fn complex_payload(n: u64) -> ComplexPayload {
+    ComplexPayload {
+        id: n,
+        values: [n as u32; 8],
+        flag: n % 2 == 0,
+    }
+}
+
+iceoryx2::publish_subscribe_contract_tests!(default_contract, u64, |n| n);
+
+iceoryx2::publish_subscribe_contract_tests!(
+    contract_with_history,
+    ComplexPayload,
+    complex_payload,
+    PublishSubscribeContract {
+        subscriber_max_buffer_size: 4,
+        history_size: 3,
+        ..Default::default()
+    }
+);
+
+iceoryx2::publish_subscribe_contract_tests!(
+    contract_with_history_larger_than_buffer,
+    u64,
+    |n| n * 3,
+    PublishSubscribeContract {
+        subscriber_max_buffer_size: 2,
+        history_size: 5,
+        ..Default::default()
+    }
+);
+
+iceoryx2::publish_subscribe_contract_tests!(
+    contract_without_safe_overflow,
+    ComplexPayload,
+    complex_payload,
+    PublishSubscribeContract {
+        subscriber_max_buffer_size: 3,
+        subscriber_max_borrowed_samples: 1,
+        history_size: 1,
+        enable_safe_overflow: false,
+    }
+);
@@ -0,0 +1,112 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod discovery {
+    use iceoryx2::discovery::*;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::Service;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "discovery_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn tracker_reports_added_and_removed_services<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let mut sut = Tracker::<S>::new(&config);
+
+        assert_that!(sut.sync().unwrap(), len 0);
+
+        let service_name = generate_name();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let service_id = service.service_id().clone();
+
+        let events = sut.sync().unwrap();
+        assert_that!(events, len 1);
+        match &events[0] {
+            DiscoveryEvent::Added(static_config) => {
+                assert_that!(*static_config.name(), eq service_name)
+            }
+            DiscoveryEvent::Removed(_) => assert_that!(true, eq false),
+        }
+        assert_that!(sut.get(&service_id), is_some);
+        assert_that!(sut.services().count(), eq 1);
+
+        // nothing changed
+        assert_that!(sut.sync().unwrap(), len 0);
+
+        drop(service);
+
+        let events = sut.sync().unwrap();
+        assert_that!(events, len 1);
+        match &events[0] {
+            DiscoveryEvent::Removed(static_config) => {
+                assert_that!(*static_config.name(), eq service_name)
+            }
+            DiscoveryEvent::Added(_) => assert_that!(true, eq false),
+        }
+        assert_that!(sut.get(&service_id), is_none);
+        assert_that!(sut.services().count(), eq 0);
+    }
+
+    #[test]
+    fn tracker_with_notifications_announces_changes<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let mut sut = Tracker::<S>::with_notifications(&node).unwrap();
+
+        let discovery_service = node
+            .service_builder(&DISCOVERY_SERVICE_NAME.try_into().unwrap())
+            .event()
+            .open()
+            .unwrap();
+        let listener = discovery_service.listener_builder().create().unwrap();
+
+        // the discovery service itself was added
+        assert_that!(sut.sync().unwrap(), len 1);
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(SERVICE_ADDED_EVENT));
+
+        let service = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        assert_that!(sut.sync().unwrap(), len 1);
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(SERVICE_ADDED_EVENT));
+
+        drop(service);
+        assert_that!(sut.sync().unwrap(), len 1);
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(SERVICE_REMOVED_EVENT));
+
+        assert_that!(sut.sync().unwrap(), len 0);
+        assert_that!(listener.try_wait_one().unwrap(), is_none);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -0,0 +1,148 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod introspection {
+    use iceoryx2::introspection::*;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::Service;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "introspection_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn nodes_are_listed_with_their_details<S: Service>() {
+        let config = generate_isolated_config();
+        let node_name = NodeName::new("the-inspected-one").unwrap();
+        let node = NodeBuilder::new()
+            .name(&node_name)
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+        let sut = Introspection::<S>::new(&config);
+
+        let nodes = sut.nodes().unwrap();
+        assert_that!(nodes, len 1);
+        assert_that!(nodes[0].id, eq * node.id());
+        assert_that!(nodes[0].liveness, eq NodeLiveness::Alive);
+        assert_that!(nodes[0].name, eq Some(node_name));
+
+        drop(node);
+        assert_that!(sut.nodes().unwrap(), len 0);
+    }
+
+    #[test]
+    fn publish_subscribe_ports_and_connections_are_listed<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service_name = generate_name();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let subscriber = service
+            .subscriber_builder()
+            .buffer_size(3)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = Introspection::<S>::new(&config);
+
+        let services = sut.services().unwrap();
+        assert_that!(services, len 1);
+        assert_that!(*services[0].static_config.name(), eq service_name);
+
+        let dynamic = services[0].dynamic.as_ref().unwrap();
+        assert_that!(dynamic.nodes, eq vec![*node.id()]);
+        assert_that!(dynamic.ports, len 2);
+        assert_that!(dynamic.number_of_borrowed_samples, eq 0);
+
+        let subscriber_info = dynamic
+            .ports
+            .iter()
+            .find(|p| p.kind == PortKind::Subscriber)
+            .unwrap();
+        assert_that!(subscriber_info.id, eq subscriber.id().value());
+        assert_that!(subscriber_info.node_id, eq * node.id());
+        assert_that!(subscriber_info.buffer_size, eq Some(3));
+
+        let publisher_info = dynamic
+            .ports
+            .iter()
+            .find(|p| p.kind == PortKind::Publisher)
+            .unwrap();
+        assert_that!(publisher_info.id, eq publisher.id().value());
+
+        assert_that!(dynamic.connections, eq vec![ConnectionInfo {
+            sender: publisher.id().value(),
+            receiver: subscriber.id().value(),
+        }]);
+
+        publisher.send_copy(123).unwrap();
+        let _sample = subscriber.receive().unwrap().unwrap();
+        let services = sut.services().unwrap();
+        assert_that!(services[0].dynamic.as_ref().unwrap().number_of_borrowed_samples, eq 1);
+    }
+
+    #[test]
+    fn event_and_request_response_ports_are_listed<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let event = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let _listener = event.listener_builder().create().unwrap();
+        let _notifier = event.notifier_builder().create().unwrap();
+        let request_response = node
+            .service_builder(&generate_name())
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+        let _server = request_response.server_builder().create().unwrap();
+        let sut = Introspection::<S>::new(&config);
+
+        let services = sut.services().unwrap();
+        assert_that!(services, len 2);
+
+        let mut kinds: Vec<_> = services
+            .iter()
+            .flat_map(|s| s.dynamic.as_ref().unwrap().ports.iter().map(|p| p.kind))
+            .collect();
+        kinds.sort_by_key(|k| *k as u8);
+        assert_that!(kinds, eq vec![PortKind::Notifier, PortKind::Listener, PortKind::Server]);
+
+        let event_ports = services
+            .iter()
+            .flat_map(|s| s.dynamic.as_ref().unwrap().ports.iter())
+            .filter(|p| p.kind != PortKind::Server);
+        for port in event_ports {
+            assert_that!(port.buffer_size, eq None);
+        }
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
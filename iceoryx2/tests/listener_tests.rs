@@ -15,7 +15,10 @@ mod listener {
     use std::collections::HashSet;
 
     use iceoryx2::testing::*;
-    use iceoryx2::{node::NodeBuilder, port::listener::ListenerCreateError, service::Service};
+    use iceoryx2::{
+        node::NodeBuilder, port::event_id::EventId, port::listener::ListenerCreateError,
+        service::Service,
+    };
     use iceoryx2_bb_testing::assert_that;
 
     #[test]
@@ -50,6 +53,43 @@ mod listener {
         }
     }
 
+    #[test]
+    fn wait_all_with_count_returns_every_event_id_once_with_count<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let listener = sut.listener_builder().create().unwrap();
+        let notifier_1 = sut.notifier_builder().create().unwrap();
+        let notifier_2 = sut.notifier_builder().create().unwrap();
+
+        for _ in 0..3 {
+            notifier_1
+                .notify_with_custom_event_id(EventId::new(4))
+                .unwrap();
+        }
+        notifier_2
+            .notify_with_custom_event_id(EventId::new(4))
+            .unwrap();
+        notifier_2
+            .notify_with_custom_event_id(EventId::new(7))
+            .unwrap();
+
+        let counts: Vec<(EventId, u64)> = listener.try_wait_all_with_count().unwrap().collect();
+        assert_that!(counts, len 2);
+        assert_that!(counts, contains(EventId::new(4), 4));
+        assert_that!(counts, contains(EventId::new(7), 1));
+
+        let counts: Vec<(EventId, u64)> = listener.try_wait_all_with_count().unwrap().collect();
+        assert_that!(counts, len 0);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
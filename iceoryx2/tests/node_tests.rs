@@ -17,13 +17,27 @@ mod node {
     use std::sync::Barrier;
 
     use iceoryx2::config::Config;
+    use iceoryx2::node::evidence_log::{self, *};
+    use iceoryx2::node::health::*;
+    use iceoryx2::node::namespace_reservation::conflicting_nodes;
+    use iceoryx2::node::resource_manifest::*;
     use iceoryx2::node::{
         NodeCleanupFailure, NodeCreationFailure, NodeId, NodeListFailure, NodeState, NodeView,
+        ServiceFilter,
     };
+    use iceoryx2::port::listener::ListenerCreateError;
+    use iceoryx2::port::notifier::NotifierCreateError;
+    use iceoryx2::port::publisher::{PublisherCreateError, PublisherLoanError};
+    use iceoryx2::port::update_connections::UpdateConnections;
     use iceoryx2::prelude::*;
     use iceoryx2::service::Service;
     use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::config::test_directory;
+    use iceoryx2_bb_posix::file::File;
     use iceoryx2_bb_posix::system_configuration::SystemInfo;
+    use iceoryx2_bb_posix::testing::create_test_directory;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_name::FileName;
     use iceoryx2_bb_system_types::path::*;
     use iceoryx2_bb_testing::watchdog::Watchdog;
     use iceoryx2_bb_testing::{assert_that, test_fail};
@@ -253,6 +267,8 @@ mod node {
             format!("{}", NodeCreationFailure::InsufficientPermissions), eq "NodeCreationFailure::InsufficientPermissions");
         assert_that!(
             format!("{}", NodeCreationFailure::InternalError), eq "NodeCreationFailure::InternalError");
+        assert_that!(
+            format!("{}", NodeCreationFailure::InvalidConfig), eq "NodeCreationFailure::InvalidConfig");
     }
 
     #[test]
@@ -395,6 +411,593 @@ mod node {
         assert_that!(node.signal_handling_mode(), eq SignalHandlingMode::HandleTerminationRequests);
     }
 
+    fn generate_service_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "node_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn by_default_node_has_no_resource_budget<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        assert_that!(node.memory_budget(), eq None);
+        assert_that!(node.max_ports(), eq None);
+        assert_that!(node.used_memory(), eq 0);
+        assert_that!(node.number_of_ports(), eq 0);
+    }
+
+    #[test]
+    fn node_port_creation_fails_when_max_ports_is_exceeded<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .max_ports(2)
+            .create::<S>()
+            .unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .event()
+            .create()
+            .unwrap();
+
+        let notifier = service.notifier_builder().create().unwrap();
+        let listener = service.listener_builder().create().unwrap();
+        assert_that!(node.number_of_ports(), eq 2);
+
+        let result = service.notifier_builder().create();
+        assert_that!(result.err(), eq Some(NotifierCreateError::ExceedsResourceBudgetOfNode));
+        let result = service.listener_builder().create();
+        assert_that!(result.err(), eq Some(ListenerCreateError::ExceedsResourceBudgetOfNode));
+
+        drop(notifier);
+        assert_that!(node.number_of_ports(), eq 1);
+        assert_that!(service.notifier_builder().create(), is_ok);
+        drop(listener);
+    }
+
+    #[test]
+    fn node_port_creation_fails_when_memory_budget_is_exceeded<S: Service>() {
+        const MEMORY_BUDGET: usize = 1024 * 1024;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .memory_budget(MEMORY_BUDGET)
+            .create::<S>()
+            .unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<[u8]>()
+            .create()
+            .unwrap();
+
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(1024)
+            .create()
+            .unwrap();
+        assert_that!(node.used_memory(), gt 0);
+        assert_that!(node.used_memory(), le MEMORY_BUDGET);
+
+        let result = service
+            .publisher_builder()
+            .initial_max_slice_len(MEMORY_BUDGET)
+            .create();
+        assert_that!(result.err(), eq Some(PublisherCreateError::ExceedsResourceBudgetOfNode));
+
+        drop(publisher);
+        assert_that!(node.used_memory(), eq 0);
+    }
+
+    #[test]
+    fn node_loan_fails_when_dynamic_data_segment_growth_exceeds_memory_budget<S: Service>() {
+        const MEMORY_BUDGET: usize = 1024 * 1024;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .memory_budget(MEMORY_BUDGET)
+            .create::<S>()
+            .unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<[u8]>()
+            .create()
+            .unwrap();
+
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(1024)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+        let used_memory = node.used_memory();
+        assert_that!(used_memory, gt 0);
+
+        let sample = publisher.loan_slice_uninit(1024);
+        assert_that!(sample, is_ok);
+        drop(sample);
+        assert_that!(node.used_memory(), eq used_memory);
+
+        let sample = publisher.loan_slice_uninit(MEMORY_BUDGET);
+        assert_that!(sample.err(), eq Some(PublisherLoanError::ExceedsResourceBudgetOfNode));
+        assert_that!(node.used_memory(), eq used_memory);
+
+        drop(publisher);
+        assert_that!(node.used_memory(), eq 0);
+    }
+
+    #[test]
+    fn prefault_and_lock_establishes_outstanding_connections<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let event = node
+            .service_builder(&generate_service_name())
+            .event()
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+        let notifier = event.notifier_builder().create().unwrap();
+        let _listener = event.listener_builder().create().unwrap();
+
+        assert_that!(node.is_locked(), eq false);
+        assert_that!(node.prefault_and_lock(), is_ok);
+        assert_that!(node.is_locked(), eq true);
+
+        assert_that!(publisher.send_copy(1234), eq Ok(1));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 1234);
+        assert_that!(notifier.notify(), eq Ok(1));
+    }
+
+    #[test]
+    fn locked_node_does_not_establish_new_connections<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let event = node
+            .service_builder(&generate_service_name())
+            .event()
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let notifier = event.notifier_builder().create().unwrap();
+
+        assert_that!(node.prefault_and_lock(), is_ok);
+
+        let subscriber = service.subscriber_builder().create().unwrap();
+        let _listener = event.listener_builder().create().unwrap();
+
+        assert_that!(publisher.send_copy(1234), eq Ok(0));
+        assert_that!(subscriber.receive().unwrap(), is_none);
+        assert_that!(notifier.notify(), eq Ok(0));
+    }
+
+    #[test]
+    fn prefault_and_lock_without_ports_works<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        assert_that!(node.prefault_and_lock(), is_ok);
+        assert_that!(node.is_locked(), eq true);
+    }
+
+    #[test]
+    fn shutdown_intent_is_not_requested_by_default<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        assert_that!(node.is_shutdown_requested(), eq false);
+        assert_that!(node.remaining_shutdown_grace_period(), is_none);
+    }
+
+    #[test]
+    fn broadcast_shutdown_intent_is_visible_on_all_ports<S: Service>() {
+        const GRACE_PERIOD: Duration = Duration::from_secs(60);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let event = node
+            .service_builder(&generate_service_name())
+            .event()
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+        let notifier = event.notifier_builder().create().unwrap();
+        let listener = event.listener_builder().create().unwrap();
+
+        assert_that!(publisher.is_shutdown_requested(), eq false);
+        assert_that!(subscriber.is_shutdown_requested(), eq false);
+        assert_that!(notifier.is_shutdown_requested(), eq false);
+        assert_that!(listener.is_shutdown_requested(), eq false);
+
+        node.broadcast_shutdown_intent(GRACE_PERIOD);
+
+        assert_that!(node.is_shutdown_requested(), eq true);
+        assert_that!(publisher.is_shutdown_requested(), eq true);
+        assert_that!(subscriber.is_shutdown_requested(), eq true);
+        assert_that!(notifier.is_shutdown_requested(), eq true);
+        assert_that!(listener.is_shutdown_requested(), eq true);
+
+        let remaining = node.remaining_shutdown_grace_period();
+        assert_that!(remaining, is_some);
+        assert_that!(remaining.unwrap(), le GRACE_PERIOD);
+    }
+
+    #[test]
+    fn broadcast_shutdown_intent_does_not_affect_other_nodes<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let other_node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        node.broadcast_shutdown_intent(Duration::from_secs(1));
+
+        assert_that!(other_node.is_shutdown_requested(), eq false);
+        assert_that!(other_node.remaining_shutdown_grace_period(), is_none);
+    }
+
+    #[test]
+    fn broadcast_shutdown_intent_twice_does_not_restart_grace_period<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        node.broadcast_shutdown_intent(Duration::ZERO);
+        node.broadcast_shutdown_intent(Duration::from_secs(60));
+
+        assert_that!(node.remaining_shutdown_grace_period(), eq Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn broadcast_shutdown_intent_wakes_up_listener_without_event_id<S: Service>() {
+        const TIMEOUT: Duration = Duration::from_secs(10);
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let event = node
+            .service_builder(&generate_service_name())
+            .event()
+            .create()
+            .unwrap();
+
+        let notifier = event.notifier_builder().create().unwrap();
+        let listener = event.listener_builder().create().unwrap();
+
+        node.broadcast_shutdown_intent(Duration::from_secs(1));
+
+        let start = std::time::Instant::now();
+        assert_that!(listener.timed_wait_one(TIMEOUT).unwrap(), is_none);
+        assert_that!(start.elapsed(), lt TIMEOUT);
+
+        node.broadcast_shutdown_intent(Duration::from_secs(1));
+        assert_that!(notifier.notify(), eq Ok(1));
+
+        let mut received_ids = vec![];
+        listener
+            .timed_wait_all(|id| received_ids.push(id), TIMEOUT)
+            .unwrap();
+        assert_that!(received_ids, len 1);
+        assert_that!(received_ids[0], eq EventId::default());
+    }
+
+    #[test]
+    fn health_monitor_detects_expired_and_refreshed_heartbeat<S: Service>() {
+        const TIMEOUT: Duration = Duration::from_millis(50);
+        let config = generate_isolated_config();
+        let supervisor = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .heartbeat_interval(Duration::from_millis(10))
+            .create::<S>()
+            .unwrap();
+        assert_that!(node.heartbeat_interval(), eq Some(Duration::from_millis(10)));
+        assert_that!(supervisor.heartbeat_interval(), is_none);
+
+        let mut sut = HealthMonitor::new(&supervisor, TIMEOUT).unwrap();
+        let listener = supervisor
+            .service_builder(&HEALTH_SERVICE_NAME.try_into().unwrap())
+            .event()
+            .open()
+            .unwrap()
+            .listener_builder()
+            .create()
+            .unwrap();
+
+        let events = sut.check().unwrap();
+        assert_that!(events, len 2);
+        assert_that!(events, contains NodeHealthEvent::NodeAlive(*node.id()));
+        assert_that!(events, contains NodeHealthEvent::NodeAlive(*supervisor.id()));
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(NODE_ALIVE_EVENT));
+
+        // the supervisor has no heartbeat and is therefore monitored only by its process state
+        std::thread::sleep(TIMEOUT * 2);
+        assert_that!(sut.check().unwrap(), eq vec![NodeHealthEvent::NodeDied(*node.id())]);
+        assert_that!(sut.is_alive(node.id()), eq false);
+        assert_that!(sut.is_alive(supervisor.id()), eq true);
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(NODE_DIED_EVENT));
+
+        node.wait(Duration::ZERO).unwrap();
+        assert_that!(sut.check().unwrap(), eq vec![NodeHealthEvent::NodeAlive(*node.id())]);
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(NODE_ALIVE_EVENT));
+
+        let node_id = *node.id();
+        drop(node);
+        assert_that!(sut.check().unwrap(), eq vec![NodeHealthEvent::NodeDied(node_id)]);
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(NODE_DIED_EVENT));
+        assert_that!(listener.try_wait_one().unwrap(), is_none);
+    }
+
+    #[test]
+    fn evidence_log_records_state_transitions<S: Service>() {
+        create_test_directory();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .evidence_log(&test_directory(), 64 * 1024)
+            .create::<S>()
+            .unwrap();
+        let log_path = node.evidence_log_path().unwrap().clone();
+
+        let service = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+        assert_that!(publisher.update_connections(), is_ok);
+
+        drop(subscriber);
+        drop(publisher);
+        drop(service);
+
+        let records = node.export_evidence_log().unwrap();
+        let transitions: Vec<_> = records.iter().map(|r| (r.entity, r.transition)).collect();
+        assert_that!(transitions, eq vec![
+            (EvidenceEntity::Service, StateTransition::Created),
+            (EvidenceEntity::Port, StateTransition::Created),
+            (EvidenceEntity::Connection, StateTransition::Connected),
+            (EvidenceEntity::Port, StateTransition::Created),
+            (EvidenceEntity::Connection, StateTransition::Connected),
+            (EvidenceEntity::Port, StateTransition::Destroyed),
+            (EvidenceEntity::Port, StateTransition::Destroyed),
+            (EvidenceEntity::Service, StateTransition::Destroyed),
+        ]);
+        for pair in records.windows(2) {
+            assert_that!(pair[0].timestamp, le pair[1].timestamp);
+        }
+        assert_that!(node.number_of_dropped_evidence_records(), eq 0);
+
+        // the evidence log outlives the node
+        drop(node);
+        assert_that!(evidence_log::export(&log_path).unwrap(), eq records);
+        File::remove(&log_path).unwrap();
+    }
+
+    #[test]
+    fn evidence_log_is_bounded_in_size<S: Service>() {
+        create_test_directory();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .evidence_log(&test_directory(), 1)
+            .create::<S>()
+            .unwrap();
+
+        let _service = node
+            .service_builder(&generate_service_name())
+            .event()
+            .create()
+            .unwrap();
+
+        assert_that!(node.export_evidence_log().unwrap(), len 0);
+        assert_that!(node.number_of_dropped_evidence_records(), eq 1);
+        File::remove(node.evidence_log_path().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn export_evidence_log_without_evidence_log_fails<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        assert_that!(node.evidence_log_path(), is_none);
+        assert_that!(node.export_evidence_log().err(), eq Some(EvidenceLogExportError::NotEnabled));
+    }
+
+    #[test]
+    fn resource_manifest_lists_resources_of_node_and_services<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let pubsub_name = generate_service_name();
+        let event_name = generate_service_name();
+
+        let node_manifest = node.resource_manifest().create();
+        assert_that!(node_manifest.resources().is_empty(), eq false);
+        assert_that!(node_manifest.resources().iter().any(|r| r.is_pattern()), eq false);
+
+        let sut = node
+            .resource_manifest()
+            .publish_subscribe(&pubsub_name)
+            .event(&event_name)
+            .create();
+
+        for resource in node_manifest.resources() {
+            assert_that!(sut.resources().contains(resource), eq true);
+        }
+
+        let has_resource = |kind: ResourceKind, is_pattern: bool| {
+            sut.resources()
+                .iter()
+                .any(|r| r.kind() == kind && r.is_pattern() == is_pattern)
+        };
+        assert_that!(has_resource(ResourceKind::SharedMemory, true), eq true);
+        assert_that!(has_resource(ResourceKind::SharedMemory, false), eq true);
+        assert_that!(has_resource(ResourceKind::Socket, true), eq true);
+        assert_that!(has_resource(ResourceKind::File, false), eq true);
+        assert_that!(has_resource(ResourceKind::Directory, false), eq true);
+        assert_that!(sut.resources().iter().any(|r| r.purpose() == "publisher reclaim event"), eq true);
+        assert_that!(sut.resources().iter().any(|r| r.purpose() == "subscriber readiness event"), eq true);
+
+        // adding a service twice does not list its resources twice
+        let sut_with_duplicate = node
+            .resource_manifest()
+            .publish_subscribe(&pubsub_name)
+            .publish_subscribe(&pubsub_name)
+            .event(&event_name)
+            .create();
+        assert_that!(sut_with_duplicate, eq sut);
+    }
+
+    #[test]
+    fn node_with_conflicting_namespace_cannot_be_created<S: Service>() {
+        let config = generate_isolated_config();
+        let mut conflicting_config = config.clone();
+        conflicting_config
+            .global
+            .service
+            .dynamic_config_storage_suffix = FileName::new(b".conflicting").unwrap();
+        let node_name = generate_node_name(0, "namespace");
+
+        let node = NodeBuilder::new()
+            .name(&node_name)
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+        let _compatible_node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = NodeBuilder::new().config(&conflicting_config).create::<S>();
+        assert_that!(sut.err(), eq Some(NodeCreationFailure::NamespaceConflict));
+
+        let conflicts = conflicting_nodes::<S>(&conflicting_config).unwrap();
+        assert_that!(conflicts, len 2);
+        assert_that!(conflicts.iter().any(|n| n.id() == node.id() && *n.name() == node_name), eq true);
+        assert_that!(conflicting_nodes::<S>(&config).unwrap(), len 0);
+    }
+
+    #[test]
+    fn node_with_different_prefix_and_config_does_not_conflict<S: Service>() {
+        let config = generate_isolated_config();
+        let mut other_config = generate_isolated_config();
+        other_config.global.service.dynamic_config_storage_suffix =
+            FileName::new(b".conflicting").unwrap();
+
+        let _node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = NodeBuilder::new().config(&other_config).create::<S>();
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn namespace_is_released_when_node_goes_out_of_scope<S: Service>() {
+        let config = generate_isolated_config();
+        let mut conflicting_config = config.clone();
+        conflicting_config.global.node.monitor_suffix = FileName::new(b".conflicting").unwrap();
+
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        assert_that!(NodeBuilder::new().config(&conflicting_config).create::<S>().err(),
+            eq Some(NodeCreationFailure::NamespaceConflict));
+
+        drop(node);
+        let sut = NodeBuilder::new().config(&conflicting_config).create::<S>();
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn close_services_closes_only_ports_of_the_matching_messaging_pattern<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let pubsub = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let event = node
+            .service_builder(&generate_service_name())
+            .event()
+            .create()
+            .unwrap();
+
+        let publisher = pubsub.publisher_builder().create().unwrap();
+        let subscriber = pubsub.subscriber_builder().create().unwrap();
+        let notifier = event.notifier_builder().create().unwrap();
+        let listener = event.listener_builder().create().unwrap();
+
+        let sut = node.close_services(&ServiceFilter::MessagingPattern(
+            MessagingPattern::PublishSubscribe,
+        ));
+        assert_that!(sut, eq Ok(2));
+
+        assert_that!(pubsub.dynamic_config().number_of_publishers(), eq 0);
+        assert_that!(pubsub.dynamic_config().number_of_subscribers(), eq 0);
+        assert_that!(publisher.send_copy(123), is_err);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+
+        assert_that!(event.dynamic_config().number_of_notifiers(), eq 1);
+        assert_that!(event.dynamic_config().number_of_listeners(), eq 1);
+        assert_that!(notifier.notify(), eq Ok(1));
+        assert_that!(listener.try_wait_one().unwrap(), is_some);
+
+        drop(publisher);
+        drop(subscriber);
+        assert_that!(pubsub.dynamic_config().number_of_publishers(), eq 0);
+
+        let publisher = pubsub.publisher_builder().create().unwrap();
+        let subscriber = pubsub.subscriber_builder().create().unwrap();
+        assert_that!(publisher.send_copy(456), eq Ok(1));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 456);
+    }
+
+    #[test]
+    fn close_services_by_name_keeps_ports_of_other_nodes_alive<S: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let other_node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let other_service = other_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        let subscriber = service.subscriber_builder().create().unwrap();
+        let publisher = other_service.publisher_builder().create().unwrap();
+        assert_that!(publisher.send_copy(1), eq Ok(1));
+
+        assert_that!(node.close_services(&ServiceFilter::Name(generate_service_name())), eq Ok(0));
+        assert_that!(node.close_services(&ServiceFilter::Name(service_name)), eq Ok(1));
+
+        assert_that!(service.dynamic_config().number_of_subscribers(), eq 0);
+        assert_that!(service.dynamic_config().number_of_publishers(), eq 1);
+        assert_that!(publisher.send_copy(2), eq Ok(0));
+
+        drop(subscriber);
+        let subscriber = service.subscriber_builder().create().unwrap();
+        assert_that!(publisher.send_copy(3), eq Ok(1));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 3);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
@@ -17,6 +17,7 @@ mod notifier {
     use iceoryx2::testing::*;
     use iceoryx2::{
         node::NodeBuilder,
+        port::event_id::EventId,
         port::notifier::{NotifierCreateError, NotifierNotifyError},
         service::Service,
     };
@@ -58,6 +59,43 @@ mod notifier {
         }
     }
 
+    #[test]
+    fn notify_batch_delivers_all_event_ids_at_once<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const EVENT_ID_MAX_VALUE: usize = 16;
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .event_id_max_value(EVENT_ID_MAX_VALUE)
+            .create()
+            .unwrap();
+
+        let listener = sut.listener_builder().create().unwrap();
+        let notifier = sut.notifier_builder().create().unwrap();
+
+        let event_ids = [EventId::new(1), EventId::new(5), EventId::new(16)];
+        assert_that!(notifier.notify_batch(&event_ids), eq Ok(1));
+        assert_that!(notifier.notify_batch(&[]), eq Ok(0));
+
+        let mut received_ids = HashSet::new();
+        listener
+            .try_wait_all(|id| {
+                received_ids.insert(id);
+            })
+            .unwrap();
+        assert_that!(received_ids, len event_ids.len());
+        for id in event_ids {
+            assert_that!(received_ids.contains(&id), eq true);
+        }
+
+        let result =
+            notifier.notify_batch(&[EventId::new(2), EventId::new(EVENT_ID_MAX_VALUE + 1)]);
+        assert_that!(result.err(), eq Some(NotifierNotifyError::EventIdOutOfBounds));
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
@@ -12,15 +12,20 @@
 
 #[generic_tests::define]
 mod publisher {
+    use core::cell::Cell;
     use core::time::Duration;
     use std::collections::HashSet;
+    use std::rc::Rc;
     use std::sync::Mutex;
     use std::time::Instant;
 
-    use iceoryx2::port::publisher::{PublisherCreateError, PublisherLoanError};
+    use iceoryx2::port::publisher::{
+        PublisherCreateError, PublisherLoanError, PublisherSetMaxLoanedSamplesError,
+        PublisherWaitForSubscribersError,
+    };
     use iceoryx2::prelude::*;
     use iceoryx2::service::builder::publish_subscribe::CustomPayloadMarker;
-    use iceoryx2::service::port_factory::publisher::UnableToDeliverStrategy;
+    use iceoryx2::service::port_factory::publisher::{HistoryPersistence, UnableToDeliverStrategy};
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
     use iceoryx2::service::{service_name::ServiceName, Service};
     use iceoryx2::testing::*;
@@ -75,6 +80,127 @@ mod publisher {
         Ok(())
     }
 
+    #[test]
+    fn publisher_max_loaned_samples_can_be_adjusted_within_limit<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .max_loaned_samples(1)
+            .max_loaned_samples_limit(3)
+            .create()?;
+        assert_that!(sut.max_loaned_samples(), eq 1);
+
+        let sample_1 = sut.loan()?;
+        assert_that!(sut.loan().err(), eq Some(PublisherLoanError::ExceedsMaxLoanedSamples));
+
+        assert_that!(sut.set_max_loaned_samples(4).err(), eq Some(PublisherSetMaxLoanedSamplesError::ExceedsLimit));
+        assert_that!(sut.set_max_loaned_samples(3), is_ok);
+        let sample_2 = sut.loan()?;
+        let sample_3 = sut.loan()?;
+        assert_that!(sut.loan().err(), eq Some(PublisherLoanError::ExceedsMaxLoanedSamples));
+
+        // lowering it below the number of loans keeps the loans valid
+        assert_that!(sut.set_max_loaned_samples(1), is_ok);
+        drop(sample_1);
+        drop(sample_2);
+        assert_that!(sut.loan().err(), eq Some(PublisherLoanError::ExceedsMaxLoanedSamples));
+        drop(sample_3);
+        assert_that!(sut.loan(), is_ok);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "deterministic_profile")]
+    #[test]
+    fn publisher_with_dynamic_allocation_strategy_violates_deterministic_profile<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create();
+        assert_that!(sut.err(), eq Some(PublisherCreateError::ViolatesDeterministicProfile));
+
+        let sut = service
+            .publisher_builder()
+            .allocation_strategy(AllocationStrategy::Static)
+            .create();
+        assert_that!(sut, is_ok);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "loan_diagnostics")]
+    #[test]
+    fn publisher_tracks_location_of_outstanding_loans<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(2).create()?;
+        assert_that!(sut.outstanding_loans(), len 0);
+
+        let sample_1 = sut.loan()?;
+        let sample_2 = sut.loan_uninit()?;
+        let loans = sut.outstanding_loans();
+        assert_that!(loans, len 2);
+        for loan in &loans {
+            assert_that!(loan.location().file(), eq file!());
+        }
+
+        drop(sample_1);
+        assert_that!(sut.outstanding_loans(), len 1);
+
+        assert_that!(sample_2.write_payload(5).send(), is_ok);
+        assert_that!(sut.outstanding_loans(), len 0);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "loan_diagnostics")]
+    #[test]
+    fn publisher_reports_age_of_outstanding_loans<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(1).create()?;
+        let _sample = sut.loan()?;
+        std::thread::sleep(TIMEOUT);
+
+        assert_that!(sut.loan().err(), eq Some(PublisherLoanError::ExceedsMaxLoanedSamples));
+        let loans = sut.outstanding_loans();
+        assert_that!(loans, len 1);
+        assert_that!(loans[0].age(), ge TIMEOUT);
+
+        #[cfg(feature = "loan_backtraces")]
+        assert_that!(loans[0].backtrace().status(), eq std::backtrace::BacktraceStatus::Captured);
+
+        Ok(())
+    }
+
     #[test]
     fn publisher_loan_initializes_sample_with_default<Sut: Service>() -> TestResult<()> {
         let service_name = generate_name()?;
@@ -164,6 +290,44 @@ mod publisher {
         Ok(())
     }
 
+    #[test]
+    fn publisher_send_truncated_delivers_only_written_prefix<Sut: Service>() -> TestResult<()> {
+        const NUMBER_OF_ELEMENTS: usize = 64;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .enable_payload_checksum(true)
+            .create()?;
+
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(NUMBER_OF_ELEMENTS)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        let mut sample = publisher.loan_slice_uninit(NUMBER_OF_ELEMENTS)?;
+        sample.truncate(3);
+        assert_that!(sample.payload(), len 3);
+        let sample = sample.write_from_fn(|n| n as u64 * 2);
+        sample.send()?;
+
+        let mut sample = publisher.loan_slice(NUMBER_OF_ELEMENTS)?;
+        sample.payload_mut()[0] = 42;
+        sample.send_truncated(1)?;
+
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(sample.header().number_of_elements(), eq 3);
+        assert_that!(sample.payload().to_vec(), eq vec![0, 2, 4]);
+
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(sample.payload().to_vec(), eq vec![42]);
+
+        Ok(())
+    }
+
     #[test]
     fn publisher_loan_unit_and_send_sample_works<Sut: Service>() -> TestResult<()> {
         let service_name = generate_name()?;
@@ -288,6 +452,206 @@ mod publisher {
         Ok(())
     }
 
+    #[test]
+    fn publisher_loan_with_timeout_returns_sample_when_memory_is_available<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(2).create()?;
+
+        let sample1 = sut.loan_with_timeout(TIMEOUT)?;
+        assert_that!(*sample1.payload(), eq 0);
+        let sample2 = sut.loan_uninit_with_timeout(TIMEOUT)?.write_payload(2);
+
+        assert_that!(sample1.send(), is_ok);
+        assert_that!(sample2.send(), is_ok);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_loan_with_timeout_works<Sut: Service>() -> TestResult<()> {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(1).create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        let mut sample = sut.loan_with_timeout(TIMEOUT)?;
+        *sample.payload_mut() = 73;
+        let result = sut.loan_with_timeout(TIMEOUT);
+        assert_that!(result.err(), eq Some(PublisherLoanError::ExceedsMaxLoanedSamples));
+
+        assert_that!(sample.send(), eq Ok(1));
+        let received = subscriber.receive()?;
+        assert_that!(received, is_some);
+        assert_that!(*received.unwrap(), eq 73);
+
+        assert_that!(sut.loan_with_timeout(TIMEOUT), is_ok);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_loan_with_timeout_times_out_when_max_loaned_samples_is_exceeded<Sut: Service>(
+    ) -> TestResult<()> {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(1).create()?;
+
+        let _sample = sut.loan_uninit()?;
+
+        let start = Instant::now();
+        let result = sut.loan_uninit_with_timeout(TIMEOUT);
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+        assert_that!(result.err(), eq Some(PublisherLoanError::ExceedsMaxLoanedSamples));
+
+        let start = Instant::now();
+        let result = sut.loan_with_timeout(TIMEOUT);
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+        assert_that!(result.err(), eq Some(PublisherLoanError::ExceedsMaxLoanedSamples));
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_loan_batch_and_send_batch_works<Sut: Service>() -> TestResult<()> {
+        const BATCH_SIZE: usize = 3;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BATCH_SIZE)
+            .max_subscribers(2)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .max_loaned_samples(BATCH_SIZE)
+            .create()?;
+        let subscriber_1 = service.subscriber_builder().create()?;
+        let subscriber_2 = service.subscriber_builder().create()?;
+
+        let samples = sut
+            .loan_uninit_batch(BATCH_SIZE)?
+            .into_iter()
+            .enumerate()
+            .map(|(n, sample)| sample.write_payload(n as u64))
+            .collect();
+        assert_that!(sut.send_batch(samples), eq Ok(2 * BATCH_SIZE));
+
+        for subscriber in [&subscriber_1, &subscriber_2] {
+            for n in 0..BATCH_SIZE {
+                let sample = subscriber.receive()?;
+                assert_that!(sample, is_some);
+                assert_that!(*sample.unwrap(), eq n as u64);
+            }
+        }
+
+        let mut samples = sut.loan_batch(BATCH_SIZE)?;
+        assert_that!(samples, len BATCH_SIZE);
+        assert_that!(*samples[0].payload(), eq 0);
+        *samples[1].payload_mut() = 1234;
+        assert_that!(sut.send_batch(samples), eq Ok(2 * BATCH_SIZE));
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_loan_batch_fails_when_it_exceeds_max_loaned_samples<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(3).create()?;
+
+        let _sample = sut.loan_uninit()?;
+        let result = sut.loan_uninit_batch(3);
+        assert_that!(result.err(), eq Some(PublisherLoanError::ExceedsMaxLoanedSamples));
+
+        let samples = sut.loan_uninit_batch(2)?;
+        assert_that!(samples, len 2);
+        drop(samples);
+
+        let samples = sut.loan_batch(2)?;
+        assert_that!(samples, len 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_send_empty_batch_works<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let _subscriber = service.subscriber_builder().create()?;
+
+        assert_that!(sut.send_batch(vec![]), eq Ok(0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_loan_slice_with_timeout_fails_instantly_when_slice_is_too_large<Sut: Service>(
+    ) -> TestResult<()> {
+        let _watchdog = Watchdog::new();
+        const MAX_SLICE_LEN: usize = 4;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .initial_max_slice_len(MAX_SLICE_LEN)
+            .create()?;
+
+        let sample = sut.loan_slice_with_timeout(MAX_SLICE_LEN, TIMEOUT)?;
+        assert_that!(sample.payload(), len MAX_SLICE_LEN);
+        drop(sample);
+
+        let result =
+            sut.loan_slice_uninit_with_timeout(MAX_SLICE_LEN + 1, Duration::from_secs(3600));
+        assert_that!(result.err(), eq Some(PublisherLoanError::ExceedsMaxLoanSize));
+
+        Ok(())
+    }
+
     #[test]
     fn publisher_block_when_unable_to_deliver_blocks<Sut: Service>() -> TestResult<()> {
         let _watchdog = Watchdog::new();
@@ -351,40 +715,397 @@ mod publisher {
     }
 
     #[test]
-    fn create_error_display_works<S: Service>() {
-        assert_that!(
-            format!("{}", PublisherCreateError::ExceedsMaxSupportedPublishers), eq "PublisherCreateError::ExceedsMaxSupportedPublishers");
-        assert_that!(
-            format!("{}", PublisherCreateError::UnableToCreateDataSegment), eq "PublisherCreateError::UnableToCreateDataSegment");
-    }
+    fn publisher_block_with_timeout_discards_sample_after_timeout<Sut: Service>() -> TestResult<()>
+    {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>()?;
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(false)
+            .create()?;
 
-    #[test]
-    fn loan_error_display_works<S: Service>() {
-        assert_that!(
-            format!("{}", PublisherLoanError::OutOfMemory), eq "PublisherLoanError::OutOfMemory");
-        assert_that!(
-            format!("{}", PublisherLoanError::ExceedsMaxLoanedSamples), eq "PublisherLoanError::ExceedsMaxLoanedSamples");
-        assert_that!(
-            format!("{}", PublisherLoanError::ExceedsMaxLoanSize), eq "PublisherLoanError::ExceedsMaxLoanSize");
-        assert_that!(
-            format!("{}", PublisherLoanError::InternalFailure), eq "PublisherLoanError::InternalFailure");
+        let sut = service
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::BlockWithTimeout(TIMEOUT))
+            .create()?;
+        assert_that!(sut.unable_to_deliver_strategy(), eq UnableToDeliverStrategy::BlockWithTimeout(TIMEOUT));
+
+        let subscriber = service.subscriber_builder().create()?;
+
+        assert_that!(sut.send_copy(8192)?, eq 1);
+        let now = Instant::now();
+        assert_that!(sut.send_copy(2)?, eq 0);
+        assert_that!(now.elapsed(), time_at_least TIMEOUT);
+
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(*sample, eq 8192);
+        assert_that!(subscriber.receive()?, is_none);
+
+        Ok(())
     }
 
     #[test]
-    fn id_is_unique<Sut: Service>() {
-        let service_name = generate_name().unwrap();
+    fn publisher_with_pacing_spreads_out_bursts<Sut: Service>() -> TestResult<()> {
+        const NUMBER_OF_SAMPLES: usize = 5;
+        let service_name = generate_name()?;
         let config = generate_isolated_config();
-        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        const MAX_PUBLISHERS: usize = 8;
-
-        let sut = node
+        let node = NodeBuilder::new().config(&config).create::<Sut>()?;
+        let service = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .max_publishers(MAX_PUBLISHERS)
-            .create()
-            .unwrap();
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+            .create()?;
 
-        let mut publishers = vec![];
+        let sut = service.publisher_builder().pacing(1).create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        let now = Instant::now();
+        for i in 0..NUMBER_OF_SAMPLES {
+            assert_that!(sut.send_copy(i as u64)?, eq 1);
+        }
+        assert_that!(now.elapsed(), time_at_least Duration::from_millis(NUMBER_OF_SAMPLES as u64 - 1));
+
+        for i in 0..NUMBER_OF_SAMPLES {
+            let sample = subscriber.receive()?.unwrap();
+            assert_that!(*sample, eq i as u64);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn wait_for_subscribers_returns_false_when_timeout_passed<Sut: Service>() -> TestResult<()> {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let _subscriber = service.subscriber_builder().create()?;
+
+        let now = Instant::now();
+        assert_that!(sut.wait_for_subscribers(2, TIMEOUT), eq Ok(false));
+        assert_that!(now.elapsed(), time_at_least TIMEOUT);
+
+        Ok(())
+    }
+
+    #[test]
+    fn wait_for_subscribers_returns_true_and_connects_when_enough_subscribers_are_present<
+        Sut: Service,
+    >() -> TestResult<()> {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        assert_that!(sut.wait_for_subscribers(0, TIMEOUT), eq Ok(true));
+        assert_that!(sut.number_of_subscribers(), eq 0);
+
+        let subscriber_1 = service.subscriber_builder().create()?;
+        let subscriber_2 = service.subscriber_builder().create()?;
+
+        assert_that!(sut.wait_for_subscribers(2, Duration::MAX), eq Ok(true));
+        assert_that!(sut.number_of_subscribers(), eq 2);
+        assert_that!(sut.send_copy(123), eq Ok(2));
+        assert_that!(*subscriber_1.receive()?.unwrap(), eq 123);
+        assert_that!(*subscriber_2.receive()?.unwrap(), eq 123);
+
+        Ok(())
+    }
+
+    #[test]
+    fn wait_for_subscribers_blocks_until_subscriber_is_created<Sut: Service>() -> TestResult<()> {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = Mutex::new(NodeBuilder::new().config(&config).create::<Sut>().unwrap());
+        let service = node
+            .lock()
+            .unwrap()
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+
+        let handle = BarrierHandle::new();
+        let barrier = BarrierBuilder::new(2).create(&handle).unwrap();
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let service = node
+                    .lock()
+                    .unwrap()
+                    .service_builder(&service_name)
+                    .publish_subscribe::<u64>()
+                    .open()
+                    .unwrap();
+
+                barrier.wait();
+                std::thread::sleep(TIMEOUT);
+                let subscriber = service.subscriber_builder().create().unwrap();
+                barrier.wait();
+                drop(subscriber);
+            });
+
+            barrier.wait();
+            let now = Instant::now();
+            assert_that!(sut.wait_for_subscribers(1, Duration::MAX), eq Ok(true));
+            assert_that!(now.elapsed(), time_at_least TIMEOUT);
+            barrier.wait();
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn persistent_history_is_restored_by_recreated_publisher<Sut: Service>() -> TestResult<()> {
+        const HISTORY_SIZE: usize = 3;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(HISTORY_SIZE)
+            .subscriber_max_buffer_size(HISTORY_SIZE)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .history_persistence(HistoryPersistence::SharedMemory)
+            .create()?;
+        for i in 0..5 {
+            sut.send_copy(i)?;
+        }
+        drop(sut);
+
+        let sut = service
+            .publisher_builder()
+            .history_persistence(HistoryPersistence::SharedMemory)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+        sut.update_connections()?;
+
+        for i in 2..5 {
+            let sample = subscriber.receive()?;
+            assert_that!(sample, is_some);
+            assert_that!(*sample.unwrap(), eq i);
+        }
+        assert_that!(subscriber.receive()?, is_none);
+
+        Ok(())
+    }
+
+    #[test]
+    fn volatile_history_is_lost_when_publisher_is_dropped<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(2)
+            .subscriber_max_buffer_size(2)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        sut.send_copy(123)?;
+        drop(sut);
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+        sut.update_connections()?;
+
+        assert_that!(subscriber.receive()?, is_none);
+
+        Ok(())
+    }
+
+    #[test]
+    fn persistent_history_is_removed_with_the_service<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(1)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .history_persistence(HistoryPersistence::SharedMemory)
+            .create()?;
+        sut.send_copy(42)?;
+        drop(sut);
+        drop(service);
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(1)
+            .create()?;
+        let sut = service
+            .publisher_builder()
+            .history_persistence(HistoryPersistence::SharedMemory)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+        sut.update_connections()?;
+
+        assert_that!(subscriber.receive()?, is_none);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fully_released_callback_is_called_when_all_subscribers_returned_the_sample<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(0)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber_1 = service.subscriber_builder().create()?;
+        let subscriber_2 = service.subscriber_builder().create()?;
+
+        let is_released = Rc::new(Cell::new(false));
+        let is_released_clone = is_released.clone();
+        let mut sample = sut.loan()?;
+        *sample.payload_mut() = 981;
+        sample.on_fully_released(move || is_released_clone.set(true));
+        sample.send()?;
+
+        let received_1 = subscriber_1.receive()?.unwrap();
+        let received_2 = subscriber_2.receive()?.unwrap();
+
+        drop(received_1);
+        drop(sut.loan()?);
+        assert_that!(is_released.get(), eq false);
+
+        drop(received_2);
+        drop(sut.loan()?);
+        assert_that!(is_released.get(), eq true);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fully_released_callback_is_called_when_sample_is_dropped_without_send<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+
+        let is_released = Rc::new(Cell::new(false));
+        let is_released_clone = is_released.clone();
+        let mut sample = sut.loan()?;
+        sample.on_fully_released(move || is_released_clone.set(true));
+        assert_that!(is_released.get(), eq false);
+
+        drop(sample);
+        assert_that!(is_released.get(), eq true);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fully_released_callback_is_not_called_while_sample_is_in_history<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(1)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+
+        let is_released = Rc::new(Cell::new(false));
+        let is_released_clone = is_released.clone();
+        let mut sample = sut.loan()?;
+        sample.on_fully_released(move || is_released_clone.set(true));
+        sample.send()?;
+        assert_that!(is_released.get(), eq false);
+
+        sut.send_copy(2)?;
+        assert_that!(is_released.get(), eq true);
+
+        Ok(())
+    }
+
+    #[test]
+    fn wait_for_subscribers_error_display_works<S: Service>() {
+        assert_that!(
+            format!("{}", PublisherWaitForSubscribersError::InternalFailure), eq "PublisherWaitForSubscribersError::InternalFailure");
+    }
+
+    #[test]
+    fn create_error_display_works<S: Service>() {
+        assert_that!(
+            format!("{}", PublisherCreateError::ExceedsMaxSupportedPublishers), eq "PublisherCreateError::ExceedsMaxSupportedPublishers");
+        assert_that!(
+            format!("{}", PublisherCreateError::UnableToCreateDataSegment), eq "PublisherCreateError::UnableToCreateDataSegment");
+        assert_that!(
+            format!("{}", PublisherCreateError::UnableToCreatePersistentHistory), eq "PublisherCreateError::UnableToCreatePersistentHistory");
+    }
+
+    #[test]
+    fn loan_error_display_works<S: Service>() {
+        assert_that!(
+            format!("{}", PublisherLoanError::OutOfMemory), eq "PublisherLoanError::OutOfMemory");
+        assert_that!(
+            format!("{}", PublisherLoanError::ExceedsMaxLoanedSamples), eq "PublisherLoanError::ExceedsMaxLoanedSamples");
+        assert_that!(
+            format!("{}", PublisherLoanError::ExceedsMaxLoanSize), eq "PublisherLoanError::ExceedsMaxLoanSize");
+        assert_that!(
+            format!("{}", PublisherLoanError::InternalFailure), eq "PublisherLoanError::InternalFailure");
+        assert_that!(
+            format!("{}", PublisherLoanError::ExceedsResourceBudgetOfNode), eq "PublisherLoanError::ExceedsResourceBudgetOfNode");
+    }
+
+    #[test]
+    fn id_is_unique<Sut: Service>() {
+        let service_name = generate_name().unwrap();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const MAX_PUBLISHERS: usize = 8;
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(MAX_PUBLISHERS)
+            .create()
+            .unwrap();
+
+        let mut publishers = vec![];
         let mut publisher_id_set = HashSet::new();
 
         for _ in 0..MAX_PUBLISHERS {
@@ -467,6 +1188,152 @@ mod publisher {
         let _sample = unsafe { sut.loan_custom_payload(2) };
     }
 
+    #[test]
+    fn paused_publisher_buffers_samples_until_resumed<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(2).create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        sut.pause();
+        assert_that!(sut.is_paused(), eq true);
+        assert_that!(sut.send_copy(12)?, eq 0);
+        assert_that!(sut.send_copy(34)?, eq 0);
+        assert_that!(subscriber.receive()?, is_none);
+
+        assert_that!(sut.resume()?, eq 2);
+        assert_that!(sut.is_paused(), eq false);
+        assert_that!(*subscriber.receive()?.unwrap(), eq 12);
+        assert_that!(*subscriber.receive()?.unwrap(), eq 34);
+        assert_that!(subscriber.receive()?, is_none);
+
+        Ok(())
+    }
+
+    #[test]
+    fn paused_publisher_rejects_loans_when_buffer_is_full<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(2).create()?;
+
+        sut.pause();
+        sut.send_copy(1)?;
+        sut.send_copy(2)?;
+
+        let sample = sut.loan();
+        assert_that!(sample.err(), eq Some(PublisherLoanError::ExceedsMaxLoanedSamples));
+
+        sut.resume()?;
+        assert_that!(sut.loan(), is_ok);
+
+        Ok(())
+    }
+
+    #[test]
+    fn resume_on_not_paused_publisher_has_no_effect<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let _subscriber = service.subscriber_builder().create()?;
+
+        assert_that!(sut.is_paused(), eq false);
+        assert_that!(sut.resume()?, eq 0);
+        assert_that!(sut.send_copy(1)?, eq 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn data_segment_info_reports_used_and_free_buckets<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(2).create()?;
+
+        let info = sut.data_segment_info();
+        assert_that!(info.is_dynamic(), eq false);
+        assert_that!(info.number_of_segments(), eq 1);
+        assert_that!(info.number_of_used_buckets(), eq 0);
+        let number_of_buckets = info.number_of_free_buckets();
+        assert_that!(number_of_buckets, ge 2);
+        assert_that!(info.segments()[0].bucket_size(), ge core::mem::size_of::<u64>());
+        assert_that!(info.total_bytes(), eq number_of_buckets * info.segments()[0].bucket_size());
+
+        let sample_1 = sut.loan_uninit()?;
+        let sample_2 = sut.loan_uninit()?;
+
+        let info = sut.data_segment_info();
+        assert_that!(info.number_of_used_buckets(), eq 2);
+        assert_that!(info.number_of_free_buckets(), eq number_of_buckets - 2);
+
+        drop(sample_1);
+        drop(sample_2);
+
+        let info = sut.data_segment_info();
+        assert_that!(info.number_of_used_buckets(), eq 0);
+        assert_that!(info.number_of_free_buckets(), eq number_of_buckets);
+
+        Ok(())
+    }
+
+    #[cfg(not(feature = "deterministic_profile"))]
+    #[test]
+    fn data_segment_info_reports_dynamically_allocated_segments<Sut: Service>() -> TestResult<()> {
+        const LARGE_SLICE_LEN: usize = 1024;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .initial_max_slice_len(1)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()?;
+
+        let _small_sample = sut.loan_slice(1)?;
+        let info = sut.data_segment_info();
+        assert_that!(info.is_dynamic(), eq true);
+        assert_that!(info.number_of_segments(), eq 1);
+        assert_that!(info.number_of_used_buckets(), eq 1);
+        assert_that!(info.segments()[0].bucket_size(), lt LARGE_SLICE_LEN);
+
+        let _large_sample = sut.loan_slice(LARGE_SLICE_LEN)?;
+        let info = sut.data_segment_info();
+        assert_that!(info.number_of_segments(), eq 2);
+        assert_that!(info.number_of_used_buckets(), eq 2);
+        assert_that!(info.segments().iter().any(|s| s.bucket_size() >= LARGE_SLICE_LEN), eq true);
+
+        Ok(())
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
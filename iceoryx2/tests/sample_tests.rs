@@ -47,6 +47,7 @@ mod sample {
                 .publish_subscribe::<u64>()
                 .max_publishers(2)
                 .max_subscribers(1)
+                .enable_send_timestamp(true)
                 .create()
                 .unwrap();
 
@@ -81,6 +82,41 @@ mod sample {
         assert_that!(sample.origin(), eq test_context.publisher_2.id());
     }
 
+    #[test]
+    fn latency_covers_the_time_between_send_and_receive<Sut: Service>() {
+        const DELAY: core::time::Duration = core::time::Duration::from_millis(10);
+        let config = generate_isolated_config();
+        let test_context = TestContext::<Sut>::new(&config);
+
+        assert_that!(test_context.publisher_1.send_copy(123), eq Ok(1));
+        std::thread::sleep(DELAY);
+        let sample = test_context.subscriber.receive().unwrap().unwrap();
+
+        assert_that!(sample.header().send_timestamp(), is_some);
+        assert_that!(sample.latency().unwrap(), ge DELAY);
+    }
+
+    #[test]
+    fn latency_is_not_available_without_send_timestamp<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        assert_that!(service.static_config().has_send_timestamp(), eq false);
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher.send_copy(123), eq Ok(1));
+        let sample = subscriber.receive().unwrap().unwrap();
+
+        assert_that!(sample.header().send_timestamp(), is_none);
+        assert_that!(sample.latency(), is_none);
+    }
+
     #[test]
     fn sample_of_dropped_service_does_not_block_new_service_creation<Sut: Service>() {
         let config = generate_isolated_config();
@@ -0,0 +1,185 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_control_channel {
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::control_channel::{
+        ControlChannelSendError, ControlMessage, CONTROL_CHANNEL_CAPACITY,
+        CONTROL_MESSAGE_RESERVED_ID_START,
+    };
+    use iceoryx2::service::Service;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Mutex;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_control_channel_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn sent_message_is_received_by_all_channels<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        let sender = sut.control_channel();
+        let receiver_1 = sut.control_channel();
+        let receiver_2 = sut2.control_channel();
+
+        assert_that!(receiver_1.has_messages(), eq false);
+        assert_that!(sender.send(ControlMessage::new(12, 34)), is_ok);
+        assert_that!(receiver_1.has_messages(), eq true);
+
+        assert_that!(receiver_1.receive(), eq Some(ControlMessage::new(12, 34)));
+        assert_that!(receiver_1.receive(), eq None);
+        assert_that!(receiver_2.receive(), eq Some(ControlMessage::new(12, 34)));
+        assert_that!(receiver_2.receive(), eq None);
+    }
+
+    #[test]
+    fn channel_does_not_receive_messages_sent_before_its_creation<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+
+        let sender = sut.control_channel();
+        assert_that!(sender.send(ControlMessage::new(1, 2)), is_ok);
+
+        let receiver = sut.control_channel();
+        assert_that!(receiver.receive(), eq None);
+    }
+
+    #[test]
+    fn sending_reserved_message_id_fails<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+
+        let sender = sut.control_channel();
+        let receiver = sut.control_channel();
+        let result = sender.send(ControlMessage::new(CONTROL_MESSAGE_RESERVED_ID_START, 0));
+        assert_that!(result, eq Err(ControlChannelSendError::ReservedMessageId));
+        assert_that!(receiver.receive(), eq None);
+    }
+
+    #[test]
+    fn slow_receiver_receives_the_latest_messages<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sender = sut.control_channel();
+        let receiver = sut.control_channel();
+        const NUMBER_OF_MESSAGES: usize = CONTROL_CHANNEL_CAPACITY * 3 + 5;
+
+        for n in 0..NUMBER_OF_MESSAGES {
+            assert_that!(sender.send(ControlMessage::new(1, n as u64)), is_ok);
+        }
+
+        for n in NUMBER_OF_MESSAGES - CONTROL_CHANNEL_CAPACITY..NUMBER_OF_MESSAGES {
+            assert_that!(receiver.receive(), eq Some(ControlMessage::new(1, n as u64)));
+        }
+        assert_that!(receiver.receive(), eq None);
+    }
+
+    #[test]
+    fn concurrent_senders_never_deliver_torn_messages<Sut: Service>() {
+        const NUMBER_OF_SENDERS: u32 = 2;
+        const NUMBER_OF_MESSAGES: u64 = 100000;
+
+        let config = generate_isolated_config();
+        let node = Mutex::new(NodeBuilder::new().config(&config).create::<Sut>().unwrap());
+        let service_name = generate_name();
+        let sut = node
+            .lock()
+            .unwrap()
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let receiver = sut.control_channel();
+        let number_of_finished_senders = AtomicU32::new(0);
+
+        std::thread::scope(|s| {
+            for id in 0..NUMBER_OF_SENDERS {
+                let node = &node;
+                let service_name = &service_name;
+                let number_of_finished_senders = &number_of_finished_senders;
+                s.spawn(move || {
+                    let service = node
+                        .lock()
+                        .unwrap()
+                        .service_builder(service_name)
+                        .publish_subscribe::<u64>()
+                        .open()
+                        .unwrap();
+                    let sender = service.control_channel();
+
+                    // the value carries the id, a torn message has a mismatching id
+                    for n in 0..NUMBER_OF_MESSAGES {
+                        sender
+                            .send(ControlMessage::new(id, ((id as u64) << 32) | n))
+                            .unwrap();
+                    }
+                    number_of_finished_senders.fetch_add(1, Ordering::Relaxed);
+                });
+            }
+
+            let mut number_of_received_messages = 0;
+            while number_of_finished_senders.load(Ordering::Relaxed) < NUMBER_OF_SENDERS
+                || receiver.has_messages()
+            {
+                while let Some(message) = receiver.receive() {
+                    assert_that!(message.value() >> 32, eq message.id() as u64);
+                    number_of_received_messages += 1;
+                }
+            }
+
+            assert_that!(number_of_received_messages, gt 0);
+        });
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
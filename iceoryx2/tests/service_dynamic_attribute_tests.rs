@@ -0,0 +1,168 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_dynamic_attribute {
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::dynamic_attribute::{
+        DynamicAttributeSetError, DYNAMIC_ATTRIBUTE_CAPACITY, DYNAMIC_ATTRIBUTE_KEY_LENGTH,
+        DYNAMIC_ATTRIBUTE_VALUE_LENGTH,
+    };
+    use iceoryx2::service::Service;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_dynamic_attribute_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn set_attribute_is_visible_in_all_handles<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        let writer = sut.attributes_mut();
+        let reader = sut2.attributes_mut();
+
+        assert_that!(reader.get("owner"), eq None);
+        assert_that!(writer.set("owner", "hypnotoad"), is_ok);
+        assert_that!(reader.get("owner"), eq Some("hypnotoad".to_string()));
+
+        let attributes = reader.list();
+        assert_that!(attributes, len 1);
+        assert_that!(attributes[0].key(), eq "owner");
+        assert_that!(attributes[0].value(), eq "hypnotoad");
+    }
+
+    #[test]
+    fn set_attribute_replaces_value_of_existing_key<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+
+        let attributes = sut.attributes_mut();
+        assert_that!(attributes.set("state", "starting"), is_ok);
+        assert_that!(attributes.set("state", "running"), is_ok);
+
+        assert_that!(attributes.get("state"), eq Some("running".to_string()));
+        assert_that!(attributes.list(), len 1);
+    }
+
+    #[test]
+    fn removed_attribute_is_no_longer_available<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let attributes = sut.attributes_mut();
+        assert_that!(attributes.set("active-configuration", "fast"), is_ok);
+
+        assert_that!(attributes.remove("active-configuration"), eq true);
+        assert_that!(attributes.remove("active-configuration"), eq false);
+        assert_that!(attributes.get("active-configuration"), eq None);
+        assert_that!(attributes.list(), len 0);
+    }
+
+    #[test]
+    fn change_is_detected_by_other_handles<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let writer = sut.attributes_mut();
+        let reader = sut.attributes_mut();
+        assert_that!(reader.has_changed(), eq false);
+
+        assert_that!(writer.set("state", "running"), is_ok);
+        assert_that!(reader.has_changed(), eq true);
+
+        reader.list();
+        assert_that!(reader.has_changed(), eq false);
+
+        writer.remove("state");
+        assert_that!(reader.has_changed(), eq true);
+    }
+
+    #[test]
+    fn set_attribute_with_invalid_key_or_value_fails<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let attributes = sut.attributes_mut();
+        let long_key = "k".repeat(DYNAMIC_ATTRIBUTE_KEY_LENGTH + 1);
+        let long_value = "v".repeat(DYNAMIC_ATTRIBUTE_VALUE_LENGTH + 1);
+
+        assert_that!(attributes.set("", "value"), eq Err(DynamicAttributeSetError::EmptyKey));
+        assert_that!(attributes.set(&long_key, "value"), eq Err(DynamicAttributeSetError::KeyTooLong));
+        assert_that!(attributes.set("key", &long_value), eq Err(DynamicAttributeSetError::ValueTooLong));
+        assert_that!(attributes.list(), len 0);
+    }
+
+    #[test]
+    fn set_more_than_capacity_attributes_fails<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let attributes = sut.attributes_mut();
+        for n in 0..DYNAMIC_ATTRIBUTE_CAPACITY {
+            assert_that!(attributes.set(&format!("key_{n}"), "value"), is_ok);
+        }
+
+        assert_that!(attributes.set("another_key", "value"), eq Err(DynamicAttributeSetError::ExceedsMaxNumberOfAttributes));
+        // replacing an existing value is still possible
+        assert_that!(attributes.set("key_0", "another value"), is_ok);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -18,10 +18,13 @@ mod service_event {
     use std::sync::Barrier;
     use std::time::Instant;
 
+    use iceoryx2::port::event_payload::MAX_EVENT_PAYLOAD_SIZE;
     use iceoryx2::port::listener::{Listener, ListenerCreateError};
     use iceoryx2::port::notifier::{NotifierCreateError, NotifierNotifyError};
     use iceoryx2::prelude::*;
-    use iceoryx2::service::builder::event::{EventCreateError, EventOpenError};
+    use iceoryx2::service::builder::event::{
+        EventCreateError, EventOpenError, EventOpenOrCreateError,
+    };
     use iceoryx2::testing::*;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
@@ -37,6 +40,41 @@ mod service_event {
         .unwrap()
     }
 
+    #[test]
+    fn open_or_create_with_exceeded_timeout_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .timeout(Duration::ZERO)
+            .event()
+            .open_or_create();
+
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq EventOpenOrCreateError::TimedOut);
+    }
+
+    #[test]
+    fn open_or_create_with_timeout_creates_and_opens_service<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .timeout(Duration::from_secs(5))
+            .event()
+            .open_or_create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .timeout(Duration::from_secs(5))
+            .event()
+            .open_or_create();
+        assert_that!(sut2, is_ok);
+    }
+
     #[test]
     fn creating_non_existing_service_works<Sut: Service>() {
         let service_name = generate_name();
@@ -724,6 +762,190 @@ mod service_event {
         assert_that!(result.err().unwrap(), eq NotifierNotifyError::EventIdOutOfBounds);
     }
 
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_notifier_origin_requirement<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .event()
+            .enable_notifier_origin(true)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .enable_notifier_origin(false)
+            .open();
+        assert_that!(sut2.err(), eq Some(EventOpenError::IncompatibleNotifierOriginSetting));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .enable_notifier_origin(true)
+            .open();
+        assert_that!(sut2, is_ok);
+        assert_that!(sut2.unwrap().static_config().has_notifier_origin(), eq true);
+    }
+
+    #[test]
+    fn listener_receives_origin_of_notification<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_notifiers(3)
+            .enable_notifier_origin(true)
+            .create()
+            .unwrap();
+
+        let listener = sut.listener_builder().create().unwrap();
+        let notifier_a = sut.notifier_builder().create().unwrap();
+        let notifier_b = sut.notifier_builder().create().unwrap();
+
+        assert_that!(notifier_a.notify_with_custom_event_id(EventId::new(3)), eq Ok(1));
+        assert_that!(notifier_b.notify_with_custom_event_id(EventId::new(5)), eq Ok(1));
+
+        let mut received = vec![];
+        while let Some((event_id, origin)) = listener.try_wait_one_with_origin().unwrap() {
+            let origin = origin.unwrap();
+            assert_that!(*origin.node_id(), eq * node.id());
+            received.push((event_id, origin.notifier_id()));
+        }
+
+        assert_that!(received, len 2);
+        assert_that!(received, contains(EventId::new(3), notifier_a.id()));
+        assert_that!(received, contains(EventId::new(5), notifier_b.id()));
+
+        assert_that!(notifier_b.notify_with_custom_event_id(EventId::new(7)), eq Ok(1));
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(EventId::new(7)));
+    }
+
+    #[test]
+    fn listener_receives_no_origin_when_it_is_disabled<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let listener = sut.listener_builder().create().unwrap();
+        let notifier = sut.notifier_builder().create().unwrap();
+
+        assert_that!(notifier.notify_with_custom_event_id(EventId::new(4)), eq Ok(1));
+        assert_that!(listener.try_wait_one_with_origin().unwrap(), eq Some((EventId::new(4), None)));
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_max_payload_size_requirements<Sut: Service>() {
+        let service_name = generate_name();
+        const MAX_PAYLOAD_SIZE: usize = 12;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_payload_size(MAX_PAYLOAD_SIZE)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .max_payload_size(MAX_PAYLOAD_SIZE + 1)
+            .open();
+        assert_that!(sut2.err(), eq Some(EventOpenError::DoesNotSupportRequestedMaxPayloadSize));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .max_payload_size(MAX_PAYLOAD_SIZE - 1)
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn max_payload_size_is_adjusted_to_largest_supported_value<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_payload_size(MAX_EVENT_PAYLOAD_SIZE + 1)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_payload_size(), eq MAX_EVENT_PAYLOAD_SIZE);
+    }
+
+    #[test]
+    fn notification_payload_can_be_read_by_listener<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_payload_size(8)
+            .create()
+            .unwrap();
+
+        let listener = sut.listener_builder().create().unwrap();
+        let notifier = sut.notifier_builder().create().unwrap();
+        assert_that!(listener.payload(), is_none);
+
+        assert_that!(notifier.notify_with_payload(&[1, 2, 3]).unwrap(), eq 1);
+        assert_that!(listener.try_wait_one().unwrap(), is_some);
+        assert_that!(listener.payload().unwrap().as_bytes(), eq [1u8, 2, 3]);
+
+        assert_that!(
+            notifier
+                .notify_with_custom_event_id_and_payload(EventId::new(7), &[9; 8])
+                .unwrap(), eq 1);
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(EventId::new(7)));
+        assert_that!(listener.payload().unwrap().as_bytes(), eq [9u8; 8]);
+
+        assert_that!(notifier.notify().unwrap(), eq 1);
+        assert_that!(listener.payload().unwrap().as_bytes(), eq [9u8; 8]);
+        assert_that!(sut.dynamic_config().latest_payload(), eq listener.payload());
+    }
+
+    #[test]
+    fn notification_payload_larger_than_max_payload_size_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_payload_size(4)
+            .create()
+            .unwrap();
+
+        let listener = sut.listener_builder().create().unwrap();
+        let notifier = sut.notifier_builder().create().unwrap();
+
+        let result = notifier.notify_with_payload(&[0; 5]);
+        assert_that!(result.err(), eq Some(NotifierNotifyError::ExceedsMaxPayloadSize));
+        assert_that!(listener.try_wait_one().unwrap(), is_none);
+        assert_that!(listener.payload(), is_none);
+    }
+
     #[test]
     fn concurrent_reconnecting_notifier_can_trigger_waiting_listener<Sut: Service>() {
         let _watch_dog = Watchdog::new_with_timeout(Duration::from_secs(120));
@@ -1402,6 +1624,117 @@ mod service_event {
         assert_that!(listener.try_wait_one().unwrap(), is_some);
     }
 
+    #[test]
+    fn deadline_missed_event_can_be_set<S: Service>() {
+        const DEADLINE: Duration = Duration::from_secs(556);
+        const MISSED_EVENT: EventId = EventId::new(12);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut_create = node
+            .service_builder(&service_name)
+            .event()
+            .deadline(DEADLINE)
+            .deadline_missed_event(MISSED_EVENT)
+            .create()
+            .unwrap();
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .event()
+            .deadline_missed_event(MISSED_EVENT)
+            .open();
+        assert_that!(sut_open, is_ok);
+        let sut_open = sut_open.unwrap();
+
+        assert_that!(sut_create.static_config().deadline_missed_event(), eq Some(MISSED_EVENT));
+        assert_that!(sut_open.static_config().deadline_missed_event(), eq Some(MISSED_EVENT));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .event()
+            .deadline_missed_event(EventId::new(13))
+            .open();
+        assert_that!(sut_open.err(), eq Some(EventOpenError::IncompatibleDeadline));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .event()
+            .disable_deadline_missed_event()
+            .open();
+        assert_that!(sut_open.err(), eq Some(EventOpenError::IncompatibleDeadline));
+    }
+
+    #[test]
+    fn listener_receives_deadline_missed_event_once_per_missed_deadline<S: Service>() {
+        const DEADLINE: Duration = Duration::from_millis(10);
+        const MISSED_EVENT: EventId = EventId::new(7);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .deadline(DEADLINE)
+            .deadline_missed_event(MISSED_EVENT)
+            .create()
+            .unwrap();
+
+        let listener_1 = sut.listener_builder().create().unwrap();
+        let listener_2 = sut.listener_builder().create().unwrap();
+        let notifier = sut.notifier_builder().create().unwrap();
+
+        assert_that!(notifier.notify(), is_ok);
+        assert_that!(listener_1.try_wait_one().unwrap(), eq Some(EventId::new(0)));
+        assert_that!(listener_2.try_wait_one().unwrap(), eq Some(EventId::new(0)));
+        assert_that!(listener_1.missed_deadlines(), eq 0);
+
+        assert_that!(listener_1.timed_wait_one(DEADLINE * 10).unwrap(), eq Some(MISSED_EVENT));
+        assert_that!(listener_1.try_wait_one().unwrap(), eq None);
+        assert_that!(listener_2.try_wait_one().unwrap(), eq Some(MISSED_EVENT));
+        assert_that!(listener_2.try_wait_one().unwrap(), eq None);
+        assert_that!(listener_1.missed_deadlines(), eq 1);
+        assert_that!(listener_2.missed_deadlines(), eq 1);
+
+        // the late notification does not report the already detected miss again
+        assert_that!(notifier.notify().err(), eq Some(NotifierNotifyError::MissedDeadline));
+        assert_that!(listener_1.try_wait_one().unwrap(), eq Some(EventId::new(0)));
+        assert_that!(listener_1.try_wait_one().unwrap(), eq None);
+        assert_that!(sut.dynamic_config().number_of_missed_deadlines(), eq 1);
+    }
+
+    #[test]
+    fn late_notifier_reports_deadline_missed_event_to_listeners<S: Service>() {
+        const DEADLINE: Duration = Duration::from_millis(10);
+        const MISSED_EVENT: EventId = EventId::new(7);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .deadline(DEADLINE)
+            .deadline_missed_event(MISSED_EVENT)
+            .create()
+            .unwrap();
+
+        let listener = sut.listener_builder().create().unwrap();
+        let notifier = sut.notifier_builder().create().unwrap();
+
+        std::thread::sleep(DEADLINE * 2);
+        assert_that!(notifier.notify().err(), eq Some(NotifierNotifyError::MissedDeadline));
+
+        let mut received = vec![];
+        listener.try_wait_all(|id| received.push(id)).unwrap();
+        assert_that!(received, len 2);
+        assert_that!(received, contains MISSED_EVENT);
+        assert_that!(received, contains EventId::new(0));
+        assert_that!(listener.missed_deadlines(), eq 1);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
@@ -0,0 +1,167 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_idl {
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::idl::{ServiceDescription, ServiceIdlError, TargetLanguage};
+    use iceoryx2::service::static_config::messaging_pattern::MessagingPattern as StaticMessagingPattern;
+    use iceoryx2::service::Service;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[derive(Debug, Default)]
+    #[repr(C)]
+    struct SensorData {
+        _timestamp: u64,
+        _value: u32,
+    }
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_idl_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn export_idl_of_non_existing_service_returns_none<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+
+        let sut = Sut::export_idl(&service_name, &config, MessagingPattern::PublishSubscribe);
+
+        assert_that!(sut, is_ok);
+        assert_that!(sut.unwrap(), is_none);
+    }
+
+    #[test]
+    fn exported_description_contains_qos_and_attributes<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<SensorData>()
+            .max_publishers(3)
+            .history_size(5)
+            .create_with_attributes(&AttributeSpecifier::new().define("owner", "team-a"))
+            .unwrap();
+
+        let sut = Sut::export_idl(&service_name, &config, MessagingPattern::PublishSubscribe)
+            .unwrap()
+            .unwrap();
+
+        assert_that!(*sut.service_name(), eq service_name);
+        assert_that!(sut.attributes(), eq service.attributes());
+        match sut.messaging_pattern() {
+            StaticMessagingPattern::PublishSubscribe(static_config) => {
+                assert_that!(static_config, eq service.static_config());
+            }
+            _ => panic!("the description has the wrong messaging pattern"),
+        }
+    }
+
+    #[test]
+    fn imported_description_equals_exported_description<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let _service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<SensorData>()
+            .create_with_attributes(&AttributeSpecifier::new().define("version", "2"))
+            .unwrap();
+
+        let sut = Sut::export_idl(&service_name, &config, MessagingPattern::PublishSubscribe)
+            .unwrap()
+            .unwrap();
+
+        let idl = sut.to_idl().unwrap();
+        let imported = ServiceDescription::from_idl(&idl).unwrap();
+
+        assert_that!(imported, eq sut);
+    }
+
+    #[test]
+    fn importing_malformed_idl_fails<Sut: Service>() {
+        let sut = ServiceDescription::from_idl("this is not an idl");
+
+        assert_that!(sut.err(), eq Some(ServiceIdlError::DeserializationFailure));
+    }
+
+    #[test]
+    fn importing_idl_with_different_format_version_fails<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let _service = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let idl = Sut::export_idl(&service_name, &config, MessagingPattern::Event)
+            .unwrap()
+            .unwrap()
+            .to_idl()
+            .unwrap()
+            .replace("format_version = 1", "format_version = 9999");
+
+        let sut = ServiceDescription::from_idl(&idl);
+
+        assert_that!(sut.err(), eq Some(ServiceIdlError::UnsupportedFormatVersion));
+    }
+
+    #[test]
+    fn generated_type_definitions_are_layout_compatible<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let _service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<SensorData>()
+            .user_header::<u64>()
+            .create()
+            .unwrap();
+
+        let sut = Sut::export_idl(&service_name, &config, MessagingPattern::PublishSubscribe)
+            .unwrap()
+            .unwrap();
+
+        let size = core::mem::size_of::<SensorData>();
+        let alignment = core::mem::align_of::<SensorData>();
+
+        let c = sut.generate_type_definitions(TargetLanguage::C);
+        assert_that!(c.contains("uint64_t"), eq true);
+        assert_that!(c.contains(&format!("_Alignas({}) uint8_t data[{}];", alignment, size)), eq true);
+        assert_that!(c.contains("} SensorData;"), eq true);
+
+        let cxx = sut.generate_type_definitions(TargetLanguage::Cxx);
+        assert_that!(cxx.contains("std::uint64_t"), eq true);
+        assert_that!(cxx.contains(&format!("struct alignas({}) SensorData {{", alignment)), eq true);
+        assert_that!(cxx.contains(&format!("std::uint8_t data[{}];", size)), eq true);
+
+        let rust = sut.generate_type_definitions(TargetLanguage::Rust);
+        assert_that!(rust.contains(&format!("#[repr(C, align({}))]", alignment)), eq true);
+        assert_that!(rust.contains("pub struct SensorData {"), eq true);
+        assert_that!(rust.contains(&format!("pub data: [u8; {}],", size)), eq true);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
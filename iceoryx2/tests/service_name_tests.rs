@@ -0,0 +1,65 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod service_name {
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::service_name::{
+        validate_service_name, ServiceNameBuilder, ServiceNameBuilderError, MAX_SEGMENT_LENGTH,
+    };
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn macro_creates_service_name() {
+        let sut = service_name!("radar/front/points");
+
+        assert_that!(sut, eq "radar/front/points");
+    }
+
+    #[test]
+    fn builder_joins_segments() {
+        let sut = ServiceNameBuilder::new()
+            .segment("radar")
+            .segment("front")
+            .segment("points")
+            .create()
+            .unwrap();
+
+        assert_that!(sut, eq "radar/front/points");
+    }
+
+    #[test]
+    fn builder_rejects_invalid_segments() {
+        assert_that!(ServiceNameBuilder::new().create().err(), eq Some(ServiceNameBuilderError::NoSegments));
+        assert_that!(ServiceNameBuilder::new().segment("radar").segment("").create().err(), eq Some(ServiceNameBuilderError::EmptySegment));
+        assert_that!(ServiceNameBuilder::new().segment("radar").segment("fr/ont").create().err(), eq Some(ServiceNameBuilderError::InvalidCharacter));
+        assert_that!(ServiceNameBuilder::new().segment("ra dar").create().err(), eq Some(ServiceNameBuilderError::InvalidCharacter));
+        assert_that!(ServiceNameBuilder::new().segment(&"a".repeat(MAX_SEGMENT_LENGTH + 1)).create().err(), eq Some(ServiceNameBuilderError::SegmentTooLong));
+        assert_that!(ServiceNameBuilder::new().segment("iox2").segment("radar").create().err(), eq Some(ServiceNameBuilderError::ReservedPrefix));
+        assert_that!(
+            ServiceNameBuilder::new()
+                .segment("radar")
+                .segment("iox2")
+                .create(),
+            is_ok
+        );
+    }
+
+    #[test]
+    fn validation_detects_empty_segments() {
+        assert_that!(validate_service_name(""), eq Err(ServiceNameBuilderError::NoSegments));
+        assert_that!(validate_service_name("/radar"), eq Err(ServiceNameBuilderError::EmptySegment));
+        assert_that!(validate_service_name("radar/"), eq Err(ServiceNameBuilderError::EmptySegment));
+        assert_that!(validate_service_name("radar//points"), eq Err(ServiceNameBuilderError::EmptySegment));
+        assert_that!(validate_service_name("iox2/node/health"), eq Err(ServiceNameBuilderError::ReservedPrefix));
+        assert_that!(validate_service_name("radar-1/front_2/points.3"), eq Ok(()));
+    }
+}
@@ -0,0 +1,203 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod partition {
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn partition_with_empty_segments_is_invalid() {
+        assert_that!(Partition::new(""), is_err);
+        assert_that!(Partition::new("/vehicle"), is_err);
+        assert_that!(Partition::new("vehicle/"), is_err);
+        assert_that!(Partition::new("vehicle//front"), is_err);
+        assert_that!(Partition::new("vehicle/front"), is_ok);
+    }
+
+    #[test]
+    fn partition_contains_itself_and_its_sub_partitions() {
+        let sut = Partition::new("vehicle").unwrap();
+
+        assert_that!(sut.contains(&Partition::new("vehicle").unwrap()), eq true);
+        assert_that!(sut.contains(&Partition::new("vehicle/front").unwrap()), eq true);
+        assert_that!(sut.contains(&Partition::new("vehicle/front/left").unwrap()), eq true);
+        assert_that!(sut.contains(&Partition::new("vehicles").unwrap()), eq false);
+        assert_that!(sut.contains(&Partition::new("truck/vehicle").unwrap()), eq false);
+        assert_that!(Partition::new("vehicle/front").unwrap().contains(&sut), eq false);
+    }
+
+    #[test]
+    fn partition_parent_works() {
+        let sut = Partition::new("vehicle/front/left").unwrap();
+
+        assert_that!(sut.parent(), eq Some(Partition::new("vehicle/front").unwrap()));
+        assert_that!(Partition::new("vehicle").unwrap().parent(), eq None);
+    }
+}
+
+#[generic_tests::define]
+mod service_partition {
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::event::EventOpenError;
+    use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_partition_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn partition_is_stored_in_static_config<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let partition = Partition::new("vehicle/front").unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .partition(&partition)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        assert_that!(sut.static_config().partition(), eq Some(&partition));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        assert_that!(sut2.static_config().partition(), eq Some(&partition));
+
+        let sut3 = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        assert_that!(sut3.static_config().partition(), eq None);
+    }
+
+    #[test]
+    fn list_and_count_partition_include_sub_partitions<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let mut services = vec![];
+        for partition in ["vehicle", "vehicle/front", "vehicle/rear", "vehicles"] {
+            services.push(
+                node.service_builder(&generate_name())
+                    .partition(&Partition::new(partition).unwrap())
+                    .event()
+                    .create()
+                    .unwrap(),
+            );
+        }
+        let _service_without_partition = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+
+        let mut listed_services = vec![];
+        let result = Sut::list_partition(&Partition::new("vehicle").unwrap(), &config, |s| {
+            listed_services.push(s.static_details.service_id().clone());
+            CallbackProgression::Continue
+        });
+        assert_that!(result, is_ok);
+        assert_that!(listed_services, len 3);
+        for service in &services[0..3] {
+            assert_that!(listed_services, contains service.service_id().clone());
+        }
+
+        assert_that!(Sut::count_partition(&Partition::new("vehicle/front").unwrap(), &config), eq Ok(1));
+        assert_that!(Sut::count_partition(&Partition::new("truck").unwrap(), &config), eq Ok(0));
+    }
+
+    #[test]
+    fn frozen_service_does_not_accept_new_nodes<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let node2 = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let partition = Partition::new("vehicle/front").unwrap();
+        let pubsub_name = generate_name();
+        let event_name = generate_name();
+
+        let _pubsub = node
+            .service_builder(&pubsub_name)
+            .partition(&partition)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let _event = node
+            .service_builder(&event_name)
+            .partition(&partition)
+            .event()
+            .create()
+            .unwrap();
+
+        let parent = Partition::new("vehicle").unwrap();
+        assert_that!(Sut::freeze_partition(&parent, &config), eq Ok(2));
+        assert_that!(Sut::freeze_partition(&parent, &config), eq Ok(0));
+
+        let result = node2
+            .service_builder(&pubsub_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(result.err(), eq Some(PublishSubscribeOpenError::IsFrozen));
+
+        let result = node2.service_builder(&event_name).event().open();
+        assert_that!(result.err(), eq Some(EventOpenError::IsFrozen));
+
+        // nodes that are already registered are not affected
+        let result = node
+            .service_builder(&pubsub_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(result, is_ok);
+
+        assert_that!(Sut::unfreeze_partition(&parent, &config), eq Ok(2));
+
+        let result = node2
+            .service_builder(&pubsub_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(result, is_ok);
+    }
+
+    #[test]
+    fn purge_partition_does_not_remove_services_with_alive_nodes<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let partition = Partition::new("vehicle").unwrap();
+
+        let _sut = node
+            .service_builder(&generate_name())
+            .partition(&partition)
+            .event()
+            .create()
+            .unwrap();
+
+        assert_that!(Sut::purge_partition(&partition, &config), eq Ok(0));
+        assert_that!(Sut::count_partition(&partition, &config), eq Ok(1));
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -0,0 +1,115 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_publish_subscribe_serde {
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    #[cfg(not(feature = "deterministic_profile"))]
+    use iceoryx2_cal::shm_allocator::AllocationStrategy;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    struct Message {
+        text: String,
+        values: Vec<u64>,
+    }
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_publish_subscribe_serde_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[cfg(not(feature = "deterministic_profile"))]
+    #[test]
+    fn payload_with_heap_indirections_can_be_sent_and_received<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe_serde::<Message>()
+            .create()
+            .unwrap();
+
+        let publisher = service
+            .publisher_builder()
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create_serde_publisher::<Message>()
+            .unwrap();
+        let subscriber = service
+            .subscriber_builder()
+            .create_serde_subscriber::<Message>()
+            .unwrap();
+
+        let messages = [
+            Message {
+                text: "hypnotoad".to_string(),
+                values: vec![],
+            },
+            Message {
+                text: "all glory to the hypnotoad".repeat(16),
+                values: (0..256).collect(),
+            },
+        ];
+
+        for message in &messages {
+            assert_that!(publisher.send(message), eq Ok(1));
+        }
+
+        for message in &messages {
+            assert_that!(subscriber.receive().unwrap(), eq Some(message.clone()));
+        }
+        assert_that!(subscriber.receive().unwrap(), eq None);
+    }
+
+    #[test]
+    fn serialized_service_is_incompatible_to_other_payload_types<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let _service = node
+            .service_builder(&service_name)
+            .publish_subscribe_serde::<Message>()
+            .create()
+            .unwrap();
+
+        let result = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .open();
+        assert_that!(result.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+
+        let result = node
+            .service_builder(&service_name)
+            .publish_subscribe_serde::<String>()
+            .open();
+        assert_that!(result.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+
+        let result = node
+            .service_builder(&service_name)
+            .publish_subscribe_serde::<Message>()
+            .open();
+        assert_that!(result, is_ok);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
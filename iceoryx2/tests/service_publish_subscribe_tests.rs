@@ -13,19 +13,31 @@
 #[generic_tests::define]
 mod service_publish_subscribe {
     use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use core::time::Duration;
     use std::sync::{Barrier, Mutex};
     use std::thread;
 
     use iceoryx2::config::Config;
-    use iceoryx2::port::publisher::{PublisherCreateError, PublisherLoanError};
-    use iceoryx2::port::subscriber::SubscriberCreateError;
+    #[cfg(feature = "signing")]
+    use iceoryx2::config::{ServiceSigningKey, TrustedKey};
+    use iceoryx2::port::publisher::{
+        Priority, PublisherCreateError, PublisherLoanError, PublisherSendError,
+    };
+    use iceoryx2::port::subscriber::{SubscriberCreateError, SubscriberReceiveError};
     use iceoryx2::port::update_connections::UpdateConnections;
-    use iceoryx2::prelude::{AllocationStrategy, *};
+    use iceoryx2::port::BackpressureEvent;
+    #[cfg(not(feature = "deterministic_profile"))]
+    use iceoryx2::prelude::AllocationStrategy;
+    use iceoryx2::prelude::*;
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeCreateError;
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
+    use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenOrCreateError;
     use iceoryx2::service::builder::publish_subscribe::{CustomHeaderMarker, CustomPayloadMarker};
+    use iceoryx2::service::dynamic_config::publish_subscribe::SubscriberDetails;
+    use iceoryx2::service::header::publish_subscribe::Header;
     use iceoryx2::service::messaging_pattern::MessagingPattern;
     use iceoryx2::service::port_factory::publisher::UnableToDeliverStrategy;
+    use iceoryx2::service::port_factory::subscriber::{AutoExpire, DeliveryQos, FairnessPolicy};
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
     use iceoryx2::service::{Service, ServiceDetails};
     use iceoryx2::testing::*;
@@ -41,6 +53,15 @@ mod service_publish_subscribe {
         value: [u64; 1024],
     }
 
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy, UserHeader)]
+    #[user_header(version = 2)]
+    struct SomeVersionedUserHeader {
+        timestamp: u64,
+        #[user_header(default = 42)]
+        frame_id: u32,
+    }
+
     fn generate_name() -> ServiceName {
         ServiceName::new(&format!(
             "service_tests_{}",
@@ -419,6 +440,105 @@ mod service_publish_subscribe {
         );
     }
 
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_payload_checksum_requirement<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_payload_checksum(true)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_payload_checksum(false)
+            .open();
+
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PublishSubscribeOpenError::IncompatiblePayloadChecksumSetting
+        );
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+
+        assert_that!(sut3, is_ok);
+        assert_that!(sut3.unwrap().static_config().has_payload_checksum(), eq true);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_global_ordering_requirement<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_global_ordering(true)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_global_ordering(false)
+            .open();
+
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PublishSubscribeOpenError::IncompatibleGlobalOrderingSetting
+        );
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+
+        assert_that!(sut3, is_ok);
+        assert_that!(sut3.unwrap().static_config().has_global_ordering(), eq true);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_broadcast_requirement<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_broadcast(true)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_broadcast(false)
+            .open();
+
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PublishSubscribeOpenError::IncompatibleBroadcastSetting
+        );
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+
+        assert_that!(sut3, is_ok);
+        assert_that!(sut3.unwrap().static_config().has_broadcast(), eq true);
+    }
+
     #[test]
     fn open_fails_when_service_does_not_satisfy_history_requirement<Sut: Service>() {
         let service_name = generate_name();
@@ -607,6 +727,159 @@ mod service_publish_subscribe {
         assert_that!(sut3.err().unwrap(), eq PublishSubscribeOpenError::DoesNotExist);
     }
 
+    #[cfg(feature = "signing")]
+    const SECRET_KEY: &str = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+    #[cfg(feature = "signing")]
+    const PUBLIC_KEY: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+    #[cfg(feature = "signing")]
+    const OTHER_SECRET_KEY: &str =
+        "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb";
+
+    #[cfg(feature = "signing")]
+    fn verifying_config(config: &Config) -> Config {
+        let mut config = config.clone();
+        config.global.service.verify_service_signatures = true;
+        config.global.service.trusted_keys = vec![TrustedKey::new("creator", PUBLIC_KEY)];
+        config
+    }
+
+    #[cfg(feature = "signing")]
+    #[test]
+    fn open_succeeds_when_service_is_signed_with_trusted_key<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let mut creator_config = config.clone();
+        creator_config.global.service.signing_key =
+            Some(ServiceSigningKey::new("creator", SECRET_KEY));
+        let creator = NodeBuilder::new()
+            .config(&creator_config)
+            .create::<Sut>()
+            .unwrap();
+        let node = NodeBuilder::new()
+            .config(&verifying_config(&config))
+            .create::<Sut>()
+            .unwrap();
+
+        let _sut = creator
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut, is_ok);
+    }
+
+    #[cfg(feature = "signing")]
+    #[test]
+    fn open_fails_when_service_is_not_signed_and_signatures_are_verified<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let creator = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let node = NodeBuilder::new()
+            .config(&verifying_config(&config))
+            .create::<Sut>()
+            .unwrap();
+
+        let _sut = creator
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::SignatureVerificationFailed);
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create();
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(
+                PublishSubscribeOpenError::SignatureVerificationFailed));
+    }
+
+    #[cfg(feature = "signing")]
+    #[test]
+    fn open_fails_when_service_is_signed_with_untrusted_key<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let mut creator_config = config.clone();
+        creator_config.global.service.signing_key =
+            Some(ServiceSigningKey::new("creator", OTHER_SECRET_KEY));
+        let creator = NodeBuilder::new()
+            .config(&creator_config)
+            .create::<Sut>()
+            .unwrap();
+        let node = NodeBuilder::new()
+            .config(&verifying_config(&config))
+            .create::<Sut>()
+            .unwrap();
+
+        let _sut = creator
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::SignatureVerificationFailed);
+    }
+
+    #[cfg(not(feature = "signing"))]
+    #[test]
+    fn signing_and_verification_fail_without_signing_feature<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let mut creator_config = config.clone();
+        creator_config.global.service.signing_key = Some(iceoryx2::config::ServiceSigningKey::new(
+            "creator",
+            "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+        ));
+        let signing_creator = NodeBuilder::new()
+            .config(&creator_config)
+            .create::<Sut>()
+            .unwrap();
+        let creator = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let mut verifying_config = config.clone();
+        verifying_config.global.service.verify_service_signatures = true;
+        let node = NodeBuilder::new()
+            .config(&verifying_config)
+            .create::<Sut>()
+            .unwrap();
+
+        let sut = signing_creator
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeCreateError::InternalFailure);
+
+        let _service = creator
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::SignatureVerificationFailed);
+    }
+
     #[test]
     fn open_or_create_creates_service_if_it_does_not_exist<Sut: Service>() {
         let service_name = generate_name();
@@ -639,6 +912,41 @@ mod service_publish_subscribe {
         assert_that!(sut, is_ok);
     }
 
+    #[test]
+    fn open_or_create_with_exceeded_timeout_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .timeout(Duration::ZERO)
+            .publish_subscribe::<u64>()
+            .open_or_create();
+
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenOrCreateError::TimedOut);
+    }
+
+    #[test]
+    fn open_or_create_with_timeout_creates_and_opens_service<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .timeout(Duration::from_secs(5))
+            .publish_subscribe::<u64>()
+            .open_or_create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .timeout(Duration::from_secs(5))
+            .publish_subscribe::<u64>()
+            .open_or_create();
+        assert_that!(sut2, is_ok);
+    }
+
     #[test]
     fn max_publishers_and_subscribers_is_set_to_config_default<Sut: Service>() {
         let service_name = generate_name();
@@ -1516,68 +1824,287 @@ mod service_publish_subscribe {
     }
 
     #[test]
-    fn publish_non_overflow_with_greater_history_than_buffer_fails<Sut: Service>() {
+    fn publisher_uses_unable_to_deliver_strategy_of_subscriber_delivery_qos<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<usize>()
-            .enable_safe_overflow(false)
-            .history_size(12)
-            .subscriber_max_buffer_size(11)
-            .create();
-
-        assert_that!(sut, is_err);
-        assert_that!(
-            sut.err().unwrap(), eq
-            PublishSubscribeCreateError::SubscriberBufferMustBeLargerThanHistorySize
-        );
-    }
-
-    #[test]
-    fn publish_history_is_delivered_on_subscription<Sut: Service>() {
         const BUFFER_SIZE: usize = 2;
-        let service_name = generate_name();
-        let config = generate_isolated_config();
-        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<usize>()
-            .history_size(3)
+            .enable_safe_overflow(false)
             .subscriber_max_buffer_size(BUFFER_SIZE)
             .create()
             .unwrap();
 
-        let sut_publisher = sut.publisher_builder().create().unwrap();
-        assert_that!(sut_publisher.send_copy(29), is_ok);
-        assert_that!(sut_publisher.send_copy(32), is_ok);
-        assert_that!(sut_publisher.send_copy(35), is_ok);
+        let publisher = sut
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .unable_to_deliver_strategy_for(
+                DeliveryQos::Critical,
+                UnableToDeliverStrategy::DiscardOldest,
+            )
+            .create()
+            .unwrap();
+        let regular_subscriber = sut.subscriber_builder().create().unwrap();
+        let critical_subscriber = sut
+            .subscriber_builder()
+            .delivery_qos(DeliveryQos::Critical)
+            .create()
+            .unwrap();
 
-        let sut_subscriber = sut.subscriber_builder().create().unwrap();
-        assert_that!(sut_publisher.update_connections(), is_ok);
+        assert_that!(critical_subscriber.delivery_qos(), eq DeliveryQos::Critical);
+        assert_that!(publisher.unable_to_deliver_strategy_for(DeliveryQos::Critical), eq UnableToDeliverStrategy::DiscardOldest);
+        assert_that!(publisher.unable_to_deliver_strategy_for(DeliveryQos::BestEffort), eq UnableToDeliverStrategy::DiscardSample);
+
+        for i in 0..2 * BUFFER_SIZE {
+            assert_that!(publisher.send_copy(i), is_ok);
+        }
 
         for i in 0..BUFFER_SIZE {
-            let data = sut_subscriber.receive().unwrap();
-            assert_that!(data, is_some);
-            assert_that!(*data.unwrap(), eq 29 + (i + 1) * 3 )
+            assert_that!(*regular_subscriber.receive().unwrap().unwrap(), eq i);
+            assert_that!(*critical_subscriber.receive().unwrap().unwrap(), eq BUFFER_SIZE + i);
         }
     }
 
     #[test]
-    fn publish_history_of_zero_works<Sut: Service>() {
-        const BUFFER_SIZE: usize = 2;
+    fn exclusive_sample_can_be_updated_in_place_and_handed_back<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
         let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<usize>()
-            .history_size(0)
-            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let subscriber_b = sut
+            .subscriber_builder()
+            .enable_in_place_updates(true)
+            .create()
+            .unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(publisher.send_copy(1), eq Ok(1));
+
+        let subscriber_a = sut
+            .subscriber_builder()
+            .enable_in_place_updates(true)
+            .create()
+            .unwrap();
+        assert_that!(publisher.update_connections(), is_ok);
+
+        let mut sample = subscriber_b.receive().unwrap().unwrap();
+        assert_that!(sample.header().is_exclusive(), eq true);
+        *sample.payload_mut().unwrap() = 2;
+        assert_that!(sample.hand_back(), is_ok);
+        publisher.reclaim_returned_samples();
+
+        let mut sample = subscriber_a.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 2);
+        assert_that!(sample.is_updatable_in_place(), eq true);
+        *sample.payload_mut().unwrap() = 3;
+        assert_that!(sample.hand_back(), is_ok);
+        publisher.reclaim_returned_samples();
+
+        assert_that!(*subscriber_b.receive().unwrap().unwrap(), eq 3);
+        assert_that!(subscriber_a.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn sample_delivered_to_multiple_subscribers_cannot_be_updated_in_place<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let subscriber = sut
+            .subscriber_builder()
+            .enable_in_place_updates(true)
+            .create()
+            .unwrap();
+        let _other_subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(publisher.send_copy(1), eq Ok(2));
+
+        let mut sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.header().is_exclusive(), eq false);
+        assert_that!(sample.payload_mut(), is_none);
+        assert_that!(sample.hand_back(), is_err);
+    }
+
+    #[test]
+    fn in_place_updates_with_read_only_payload_mapping_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let mut config = generate_isolated_config();
+        config.global.service.read_only_payload_mapping = true;
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let subscriber = sut
+            .subscriber_builder()
+            .enable_in_place_updates(true)
+            .create();
+        assert_that!(subscriber.err(), eq Some(SubscriberCreateError::InPlaceUpdatesRequireWritablePayload));
+    }
+
+    #[test]
+    fn publish_discards_oldest_sample_when_strategy_is_discard_oldest<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const BUFFER_SIZE: usize = 5;
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .enable_safe_overflow(false)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardOldest)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        for i in 0..BUFFER_SIZE {
+            assert_that!(publisher.send_copy(i), eq Ok(1));
+        }
+
+        for i in 0..BUFFER_SIZE {
+            assert_that!(publisher.send_copy(2 * i + 25), eq Ok(1));
+        }
+
+        for i in 0..BUFFER_SIZE {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq 2 * i + 25);
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn publish_non_overflow_with_greater_history_than_buffer_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .enable_safe_overflow(false)
+            .history_size(12)
+            .subscriber_max_buffer_size(11)
+            .create();
+
+        assert_that!(sut, is_err);
+        assert_that!(
+            sut.err().unwrap(), eq
+            PublishSubscribeCreateError::SubscriberBufferMustBeLargerThanHistorySize
+        );
+    }
+
+    #[test]
+    fn publish_history_is_delivered_on_subscription<Sut: Service>() {
+        const BUFFER_SIZE: usize = 2;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .history_size(3)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let sut_publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(sut_publisher.send_copy(29), is_ok);
+        assert_that!(sut_publisher.send_copy(32), is_ok);
+        assert_that!(sut_publisher.send_copy(35), is_ok);
+
+        let sut_subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(sut_publisher.update_connections(), is_ok);
+
+        for i in 0..BUFFER_SIZE {
+            let data = sut_subscriber.receive().unwrap();
+            assert_that!(data, is_some);
+            assert_that!(*data.unwrap(), eq 29 + (i + 1) * 3 )
+        }
+    }
+
+    #[test]
+    fn publish_history_is_redelivered_on_request<Sut: Service>() {
+        const BUFFER_SIZE: usize = 2;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .history_size(3)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let sut_publisher = sut.publisher_builder().create().unwrap();
+        let sut_subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(sut_publisher.update_connections(), is_ok);
+
+        assert_that!(sut_publisher.send_copy(29), is_ok);
+        assert_that!(sut_publisher.send_copy(32), is_ok);
+        assert_that!(sut_publisher.send_copy(35), is_ok);
+
+        for _ in 0..BUFFER_SIZE {
+            assert_that!(sut_subscriber.receive().unwrap(), is_some);
+        }
+
+        // nothing is redelivered without a request
+        assert_that!(sut_publisher.update_connections(), is_ok);
+        assert_that!(sut_subscriber.receive().unwrap(), is_none);
+
+        assert_that!(sut_subscriber.request_history(), is_ok);
+        assert_that!(sut_publisher.update_connections(), is_ok);
+
+        for i in 0..BUFFER_SIZE {
+            let data = sut_subscriber.receive().unwrap();
+            assert_that!(data, is_some);
+            assert_that!(*data.unwrap(), eq 29 + (i + 1) * 3 )
+        }
+        assert_that!(sut_subscriber.receive().unwrap(), is_none);
+
+        // the request is consumed with the redelivery
+        assert_that!(sut_publisher.update_connections(), is_ok);
+        assert_that!(sut_subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn publish_history_of_zero_works<Sut: Service>() {
+        const BUFFER_SIZE: usize = 2;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .history_size(0)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
             .create()
             .unwrap();
 
@@ -2581,6 +3108,40 @@ mod service_publish_subscribe {
         }
     }
 
+    #[test]
+    fn simple_communication_with_versioned_user_header_works<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .versioned_user_header::<SomeVersionedUserHeader>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .versioned_user_header::<SomeVersionedUserHeader>()
+            .open()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut2.publisher_builder().create().unwrap();
+        let mut sample = publisher.loan().unwrap();
+        sample.user_header_mut().timestamp = 9912;
+        sample.user_header_mut().frame_id = 7;
+        *sample.payload_mut() = 1;
+        sample.send().unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        let user_header = sample.user_header_with_defaults();
+        assert_that!(user_header.timestamp, eq 9912);
+        assert_that!(user_header.frame_id, eq 7);
+    }
+
     #[test]
     fn same_payload_type_but_different_user_header_does_not_connect<Sut: Service>() {
         let service_name = generate_name();
@@ -2635,6 +3196,49 @@ mod service_publish_subscribe {
         assert_that!(sut3.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
     }
 
+    #[test]
+    fn custom_payload_type_with_different_type_descriptor_does_not_connect<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let type_details = TypeDetail::__internal_new::<u64>(TypeVariant::FixedSize);
+
+        let _sut = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .__internal_set_payload_type_details(&type_details)
+                .__internal_set_payload_type_descriptor("struct Fuu { uint64_t a; }")
+                .create()
+                .unwrap()
+        };
+
+        let sut2 = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .__internal_set_payload_type_details(&type_details)
+                .__internal_set_payload_type_descriptor("struct Fuu { int64_t a; }")
+                .open()
+        };
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+
+        let sut3 = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .__internal_set_payload_type_details(&type_details)
+                .__internal_set_payload_type_descriptor("struct Fuu { uint64_t a; }")
+                .open()
+        };
+        assert_that!(sut3, is_ok);
+
+        // without descriptor only the type details are verified
+        let sut4 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut4, is_ok);
+    }
+
     #[test]
     fn create_with_custom_user_header_type_works<Sut: Service>() {
         let service_name = generate_name();
@@ -2691,27 +3295,101 @@ mod service_publish_subscribe {
     }
 
     #[test]
-    fn open_with_custom_payload_type_works<Sut: Service>() {
+    fn runtime_sized_user_header_is_delivered<Sut: Service>() {
+        const HEADER_SIZE: usize = 37;
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        let _sut = node
+        let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<u128>()
+            .publish_subscribe::<u64>()
+            .user_header::<CustomHeaderMarker>()
+            .user_header_size(HEADER_SIZE)
+            .user_header_alignment(Alignment::new(8).unwrap())
             .create()
             .unwrap();
 
-        let sut2 = unsafe {
-            node.service_builder(&service_name)
-                .publish_subscribe::<[CustomPayloadMarker]>()
-                .__internal_set_payload_type_details(&TypeDetail::__internal_new::<u128>(
-                    TypeVariant::FixedSize,
-                ))
-                .open()
-        };
-
-        assert_that!(sut2, is_ok);
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let mut sample = publisher.loan().unwrap();
+        assert_that!(sample.user_header_bytes_mut(), len HEADER_SIZE);
+        assert_that!((sample.user_header_bytes().as_ptr() as usize) % 8, eq 0);
+        for (i, byte) in sample.user_header_bytes_mut().iter_mut().enumerate() {
+            *byte = (3 * i + 1) as u8;
+        }
+        *sample.payload_mut() = 8127;
+        sample.send().unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.header().user_header_size(), eq HEADER_SIZE as u64);
+        assert_that!(*sample, eq 8127);
+        for (i, byte) in sample.user_header_bytes().iter().enumerate() {
+            assert_that!(*byte, eq(3 * i + 1) as u8);
+        }
+    }
+
+    #[test]
+    fn open_with_different_runtime_sized_user_header_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .user_header::<CustomHeaderMarker>()
+            .user_header_size(128)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .user_header::<CustomHeaderMarker>()
+            .user_header_size(64)
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .user_header::<[u8; 128]>()
+            .open();
+        assert_that!(sut3.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+
+        let sut4 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .user_header::<CustomHeaderMarker>()
+            .user_header_size(128)
+            .open();
+        assert_that!(sut4, is_ok);
+    }
+
+    #[test]
+    fn open_with_custom_payload_type_works<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u128>()
+            .create()
+            .unwrap();
+
+        let sut2 = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .__internal_set_payload_type_details(&TypeDetail::__internal_new::<u128>(
+                    TypeVariant::FixedSize,
+                ))
+                .open()
+        };
+
+        assert_that!(sut2, is_ok);
 
         let sut3 = unsafe {
             node.service_builder(&service_name)
@@ -2976,6 +3654,180 @@ mod service_publish_subscribe {
         assert_that!(sample.header().number_of_elements(), eq NUMBER_OF_ELEMENTS as u64);
     }
 
+    #[test]
+    fn published_raw_sample_is_received_as_captured<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_global_ordering(true)
+            .enable_replay(true)
+            .create()
+            .unwrap();
+        let sut_raw = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .__internal_set_payload_type_details(&TypeDetail::__internal_new::<u64>(
+                    TypeVariant::FixedSize,
+                ))
+                .enable_replay(true)
+                .open()
+                .unwrap()
+        };
+        assert_that!(sut.static_config().has_replay(), eq true);
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let raw_publisher = sut_raw.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(8127).unwrap();
+        publisher.send_copy(0).unwrap();
+        let sample = subscriber.receive().unwrap().unwrap();
+        let captured_header = *sample.header();
+        let payload_bytes = sample.payload().to_ne_bytes();
+        drop(sample);
+        assert_that!(subscriber.receive().unwrap(), is_some);
+
+        let header_bytes = unsafe {
+            core::slice::from_raw_parts(
+                (&captured_header as *const Header).cast::<u8>(),
+                core::mem::size_of::<Header>(),
+            )
+        };
+        let result = unsafe { raw_publisher.publish_raw(header_bytes, &[], &payload_bytes) };
+        assert_that!(result, eq Ok(1));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 8127);
+        assert_that!(sample.origin(), eq publisher.id());
+        assert_that!(sample.header().global_sequence_number(), eq captured_header.global_sequence_number());
+    }
+
+    #[test]
+    fn replayed_exclusive_sample_is_not_exclusive_for_multiple_subscribers<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_subscribers(2)
+            .enable_replay(true)
+            .create()
+            .unwrap();
+        let sut_raw = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .__internal_set_payload_type_details(&TypeDetail::__internal_new::<u64>(
+                    TypeVariant::FixedSize,
+                ))
+                .enable_replay(true)
+                .open()
+                .unwrap()
+        };
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber_1 = sut
+            .subscriber_builder()
+            .enable_in_place_updates(true)
+            .create()
+            .unwrap();
+
+        // the captured sample was delivered exclusively to a single subscriber
+        publisher.send_copy(8127).unwrap();
+        let sample = subscriber_1.receive().unwrap().unwrap();
+        assert_that!(sample.is_updatable_in_place(), eq true);
+        let captured_header = *sample.header();
+        let payload_bytes = sample.payload().to_ne_bytes();
+        drop(sample);
+
+        let subscriber_2 = sut
+            .subscriber_builder()
+            .enable_in_place_updates(true)
+            .create()
+            .unwrap();
+        let raw_publisher = sut_raw.publisher_builder().create().unwrap();
+
+        let header_bytes = unsafe {
+            core::slice::from_raw_parts(
+                (&captured_header as *const Header).cast::<u8>(),
+                core::mem::size_of::<Header>(),
+            )
+        };
+        let result = unsafe { raw_publisher.publish_raw(header_bytes, &[], &payload_bytes) };
+        assert_that!(result, eq Ok(2));
+
+        for subscriber in [&subscriber_1, &subscriber_2] {
+            let mut sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq 8127);
+            assert_that!(sample.origin(), eq publisher.id());
+            assert_that!(sample.is_updatable_in_place(), eq false);
+            assert_that!(sample.payload_mut(), is_none);
+            assert_that!(sample.hand_back(), is_err);
+        }
+    }
+
+    #[test]
+    fn publish_raw_fails_without_replay_or_with_invalid_sizes<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let create_service = |enable_replay: bool| unsafe {
+            node.service_builder(&generate_name())
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .__internal_set_payload_type_details(&TypeDetail::__internal_new::<u64>(
+                    TypeVariant::FixedSize,
+                ))
+                .enable_replay(enable_replay)
+                .create()
+                .unwrap()
+        };
+
+        let sut = create_service(false);
+        let publisher = sut.publisher_builder().create().unwrap();
+        let header_bytes = [0u8; core::mem::size_of::<Header>()];
+        let payload_bytes = [0u8; core::mem::size_of::<u64>()];
+
+        let result = unsafe { publisher.publish_raw(&header_bytes, &[], &payload_bytes) };
+        assert_that!(result, eq Err(PublisherSendError::ReplayNotEnabled));
+
+        let sut = create_service(true);
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        let result = unsafe { publisher.publish_raw(&header_bytes[1..], &[], &payload_bytes) };
+        assert_that!(result, eq Err(PublisherSendError::InvalidRawSample));
+
+        // the zeroed header defines zero elements, therefore the payload is too large
+        let result = unsafe { publisher.publish_raw(&header_bytes, &[], &payload_bytes) };
+        assert_that!(result, eq Err(PublisherSendError::InvalidRawSample));
+    }
+
+    #[test]
+    fn open_with_different_replay_setting_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_replay(true)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_replay(false)
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleReplaySetting));
+    }
+
+    #[cfg(not(feature = "deterministic_profile"))]
     #[test]
     fn send_increasing_samples_with_static_allocation_strategy_fails<Sut: Service>() {
         const SLICE_SIZE: usize = 1024;
@@ -3007,6 +3859,7 @@ mod service_publish_subscribe {
         assert_that!(sample.err(), eq Some(PublisherLoanError::ExceedsMaxLoanSize));
     }
 
+    #[cfg(not(feature = "deterministic_profile"))]
     fn send_and_receives_increasing_samples_works<Sut: Service>(
         allocation_strategy: AllocationStrategy,
     ) {
@@ -3053,11 +3906,13 @@ mod service_publish_subscribe {
         }
     }
 
+    #[cfg(not(feature = "deterministic_profile"))]
     #[test]
     fn send_and_receives_increasing_samples_works_for_best_fit_allocation_strategy<Sut: Service>() {
         send_and_receives_increasing_samples_works::<Sut>(AllocationStrategy::BestFit);
     }
 
+    #[cfg(not(feature = "deterministic_profile"))]
     #[test]
     fn send_and_receives_increasing_samples_works_for_power_of_two_allocation_strategy<
         Sut: Service,
@@ -3065,6 +3920,7 @@ mod service_publish_subscribe {
         send_and_receives_increasing_samples_works::<Sut>(AllocationStrategy::PowerOfTwo);
     }
 
+    #[cfg(not(feature = "deterministic_profile"))]
     fn send_and_receives_increasing_samples_with_overflow_works<Sut: Service>(
         allocation_strategy: AllocationStrategy,
     ) {
@@ -3116,6 +3972,7 @@ mod service_publish_subscribe {
         }
     }
 
+    #[cfg(not(feature = "deterministic_profile"))]
     #[test]
     fn send_and_receives_increasing_samples_with_overflow_for_best_fit_allocation_strategy<
         Sut: Service,
@@ -3125,6 +3982,7 @@ mod service_publish_subscribe {
         );
     }
 
+    #[cfg(not(feature = "deterministic_profile"))]
     #[test]
     fn send_and_receives_increasing_samples_with_overflow_for_power_of_two_allocation_strategy<
         Sut: Service,
@@ -3134,6 +3992,7 @@ mod service_publish_subscribe {
         );
     }
 
+    #[cfg(not(feature = "deterministic_profile"))]
     fn deliver_history_with_increasing_samples_works<Sut: Service>(
         allocation_strategy: AllocationStrategy,
     ) {
@@ -3186,6 +4045,7 @@ mod service_publish_subscribe {
         }
     }
 
+    #[cfg(not(feature = "deterministic_profile"))]
     #[test]
     fn deliver_history_with_increasing_samples_works_for_best_fit_allocation_strategy<
         Sut: Service,
@@ -3193,6 +4053,7 @@ mod service_publish_subscribe {
         deliver_history_with_increasing_samples_works::<Sut>(AllocationStrategy::BestFit);
     }
 
+    #[cfg(not(feature = "deterministic_profile"))]
     #[test]
     fn deliver_history_with_increasing_samples_works_for_power_of_two_allocation_strategy<
         Sut: Service,
@@ -3282,6 +4143,1400 @@ mod service_publish_subscribe {
         });
     }
 
+    #[test]
+    fn samples_carry_payload_checksum_when_enabled<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .enable_payload_checksum(true)
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(16)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        for len in [1, 7, 16] {
+            let sample = publisher
+                .loan_slice_uninit(len)
+                .unwrap()
+                .write_from_fn(|i| i as u64 * 13);
+            sample.send().unwrap();
+
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.payload(), len len);
+            assert_that!(sample.header().payload_checksum(), is_some);
+        }
+    }
+
+    #[test]
+    fn samples_carry_no_payload_checksum_when_disabled<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(1234).unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 1234);
+        assert_that!(sample.header().payload_checksum(), is_none);
+    }
+
+    #[test]
+    fn send_without_payload_checksum_skips_checksum<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_payload_checksum(true)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let sample = publisher.loan_uninit().unwrap().write_payload(5678);
+        sample.send_without_payload_checksum().unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 5678);
+        assert_that!(sample.header().payload_checksum(), is_none);
+    }
+
+    #[test]
+    fn samples_carry_no_global_sequence_number_when_disabled<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(1234).unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.header().global_sequence_number(), is_none);
+    }
+
+    #[test]
+    fn global_sequence_number_restores_send_order_of_multiple_publishers<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 8;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .enable_global_ordering(true)
+            .create()
+            .unwrap();
+
+        let publisher_1 = sut.publisher_builder().create().unwrap();
+        let publisher_2 = sut.publisher_builder().create().unwrap();
+        let subscriber = sut
+            .subscriber_builder()
+            .buffer_size(NUMBER_OF_SAMPLES as usize)
+            .create()
+            .unwrap();
+
+        for i in 0..NUMBER_OF_SAMPLES {
+            match i % 2 {
+                0 => publisher_1.send_copy(i).unwrap(),
+                _ => publisher_2.send_copy(i).unwrap(),
+            };
+        }
+
+        let mut received = vec![];
+        while let Some(sample) = subscriber.receive().unwrap() {
+            let sequence_number = sample.header().global_sequence_number();
+            assert_that!(sequence_number, is_some);
+            received.push((sequence_number.unwrap(), *sample));
+        }
+        assert_that!(received, len NUMBER_OF_SAMPLES as usize);
+
+        received.sort_by_key(|(sequence_number, _)| *sequence_number);
+        for (i, (_, payload)) in received.iter().enumerate() {
+            assert_that!(*payload, eq i as u64);
+        }
+    }
+
+    #[test]
+    fn broadcast_delivers_every_sample_to_all_subscribers<Sut: Service>() {
+        const NUMBER_OF_SUBSCRIBERS: usize = 3;
+        const NUMBER_OF_SAMPLES: u64 = 64;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_subscribers(NUMBER_OF_SUBSCRIBERS)
+            .subscriber_max_buffer_size(2)
+            .enable_broadcast(true)
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .max_loaned_samples(1)
+            .create()
+            .unwrap();
+        let subscribers: Vec<_> = (0..NUMBER_OF_SUBSCRIBERS)
+            .map(|_| sut.subscriber_builder().create().unwrap())
+            .collect();
+
+        // the samples must be returned to the publisher, otherwise the loan fails eventually
+        for i in 0..NUMBER_OF_SAMPLES {
+            assert_that!(publisher.send_copy(i), eq Ok(NUMBER_OF_SUBSCRIBERS));
+
+            for subscriber in &subscribers {
+                let sample = subscriber.receive().unwrap().unwrap();
+                assert_that!(*sample, eq i);
+                assert_that!(subscriber.receive().unwrap(), is_none);
+            }
+        }
+    }
+
+    #[test]
+    fn broadcast_delivers_history_to_new_subscriber<Sut: Service>() {
+        const HISTORY_SIZE: usize = 2;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(HISTORY_SIZE)
+            .subscriber_max_buffer_size(HISTORY_SIZE + 1)
+            .enable_broadcast(true)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        for i in 0..5 {
+            publisher.send_copy(i).unwrap();
+        }
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 3);
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 4);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_priority_lanes_requirement<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_priority_lanes(true)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_priority_lanes(false)
+            .open();
+        assert_that!(
+            sut2.err(), eq
+            Some(PublishSubscribeOpenError::IncompatiblePriorityLanesSetting)
+        );
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut3.unwrap().static_config().has_priority_lanes(), eq true);
+    }
+
+    #[test]
+    fn high_priority_samples_are_received_first<Sut: Service>() {
+        const BUFFER_SIZE: usize = 3;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .enable_priority_lanes(true)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        // the high-priority lane does not reduce the capacity of the regular buffer
+        for i in 0..BUFFER_SIZE as u64 {
+            assert_that!(publisher.send_copy(i), eq Ok(1));
+            let sample = publisher.loan_uninit().unwrap().write_payload(100 + i);
+            assert_that!(sample.send_with_priority(Priority::High), eq Ok(1));
+        }
+
+        for i in 0..BUFFER_SIZE as u64 {
+            assert_that!(*subscriber.receive().unwrap().unwrap(), eq 100 + i);
+        }
+        for i in 0..BUFFER_SIZE as u64 {
+            assert_that!(*subscriber.receive().unwrap().unwrap(), eq i);
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn samples_below_inline_delivery_threshold_do_not_occupy_the_data_segment<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(0)
+            .inline_delivery_threshold(64)
+            .create()
+            .unwrap();
+        assert_that!(sut.static_config().inline_delivery_threshold(), eq 64);
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher.send_copy(1234), eq Ok(1));
+        assert_that!(publisher.send_copy(5678), eq Ok(1));
+        assert_that!(publisher.data_segment_info().number_of_used_buckets(), eq 0);
+
+        let sample_1 = subscriber.receive().unwrap().unwrap();
+        let sample_2 = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample_1, eq 1234);
+        assert_that!(*sample_2, eq 5678);
+        assert_that!(sample_1.origin(), eq publisher.id());
+        drop(sample_1);
+        drop(sample_2);
+
+        // the inline slots are recycled when the samples were returned
+        for i in 0..32 {
+            assert_that!(publisher.send_copy(i), eq Ok(1));
+            assert_that!(*subscriber.receive().unwrap().unwrap(), eq i);
+        }
+    }
+
+    #[test]
+    fn samples_above_inline_delivery_threshold_are_delivered_by_reference<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8; 128]>()
+            .history_size(0)
+            .inline_delivery_threshold(64)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher.send_copy([7; 128]), eq Ok(1));
+        assert_that!(publisher.data_segment_info().number_of_used_buckets(), eq 1);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq [7; 128]);
+        drop(sample);
+
+        assert_that!(publisher.send_copy([8; 128]), eq Ok(1));
+        assert_that!(publisher.data_segment_info().number_of_used_buckets(), eq 1);
+    }
+
+    #[test]
+    fn samples_older_than_the_sample_lifetime_are_skipped<Sut: Service>() {
+        const LIFETIME: Duration = Duration::from_millis(50);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .sample_lifetime(LIFETIME)
+            .create()
+            .unwrap();
+        assert_that!(sut.static_config().sample_lifetime(), eq Some(LIFETIME));
+        assert_that!(sut.static_config().has_send_timestamp(), eq true);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        assert_that!(sut2.static_config().sample_lifetime(), eq Some(LIFETIME));
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher.send_copy(1), eq Ok(1));
+        assert_that!(publisher.send_copy(2), eq Ok(1));
+        std::thread::sleep(LIFETIME * 2);
+        assert_that!(publisher.send_copy(3), eq Ok(1));
+
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 3);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn receive_detects_payload_modified_after_send<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_payload_checksum(true)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let mut sample = publisher.loan_uninit().unwrap().write_payload(91);
+        let payload_ptr = sample.payload_mut() as *mut u64;
+        sample.send().unwrap();
+        // simulates a rogue writer that modifies the payload after it was sent
+        unsafe { payload_ptr.write_volatile(92) };
+        publisher.send_copy(93).unwrap();
+
+        let result = subscriber.receive();
+        assert_that!(result.err(), eq Some(SubscriberReceiveError::PayloadChecksumMismatch));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 93);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn publishers_are_ready_by_default<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(subscriber.has_ready_publisher(), eq false);
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(publisher.is_ready(), eq true);
+        assert_that!(subscriber.has_ready_publisher(), eq true);
+        assert_that!(sut.dynamic_config().number_of_ready_publishers(), eq 1);
+
+        publisher.send_copy(7).unwrap();
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.header().is_publisher_ready(), eq true);
+
+        drop(publisher);
+        assert_that!(subscriber.has_ready_publisher(), eq false);
+        assert_that!(sut.dynamic_config().number_of_ready_publishers(), eq 0);
+    }
+
+    #[test]
+    fn publisher_becomes_ready_after_announcement<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut
+            .publisher_builder()
+            .initially_ready(false)
+            .create()
+            .unwrap();
+
+        assert_that!(publisher.is_ready(), eq false);
+        assert_that!(subscriber.has_ready_publisher(), eq false);
+        assert_that!(
+            subscriber.wait_for_readiness(Duration::from_millis(1)),
+            eq Ok(false)
+        );
+
+        publisher.send_copy(1).unwrap();
+        publisher.announce_ready();
+        publisher.announce_ready();
+        publisher.send_copy(2).unwrap();
+
+        assert_that!(publisher.is_ready(), eq true);
+        assert_that!(sut.dynamic_config().number_of_ready_publishers(), eq 1);
+        assert_that!(
+            subscriber.wait_for_readiness(Duration::from_millis(1)),
+            eq Ok(true)
+        );
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 1);
+        assert_that!(sample.header().is_publisher_ready(), eq false);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 2);
+        assert_that!(sample.header().is_publisher_ready(), eq true);
+    }
+
+    #[test]
+    fn subscriber_can_ignore_samples_from_unready_publishers<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(5)
+            .create()
+            .unwrap();
+
+        let subscriber = sut
+            .subscriber_builder()
+            .ignore_samples_from_unready_publishers(true)
+            .create()
+            .unwrap();
+        let publisher = sut
+            .publisher_builder()
+            .initially_ready(false)
+            .create()
+            .unwrap();
+
+        for n in 0..3 {
+            publisher.send_copy(n).unwrap();
+        }
+        publisher.announce_ready();
+        publisher.send_copy(3).unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 3);
+        drop(sample);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+
+        // the discarded samples are returned to the publisher
+        for n in 4..9 {
+            publisher.send_copy(n).unwrap();
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq n);
+        }
+    }
+
+    #[test]
+    fn wait_for_readiness_returns_when_publisher_announces_readiness<Sut: Service>() {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let barrier = Barrier::new(2);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let publisher = sut
+                    .publisher_builder()
+                    .initially_ready(false)
+                    .create()
+                    .unwrap();
+                barrier.wait();
+                std::thread::sleep(Duration::from_millis(10));
+                publisher.announce_ready();
+                barrier.wait();
+            });
+
+            barrier.wait();
+            assert_that!(
+                subscriber.wait_for_readiness(Duration::from_secs(10)),
+                eq Ok(true)
+            );
+            barrier.wait();
+        });
+    }
+
+    #[test]
+    fn connection_counters_track_overflowed_samples<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_safe_overflow(true)
+            .subscriber_max_buffer_size(1)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        for n in 0..4 {
+            assert_that!(publisher.send_copy(n), is_ok);
+        }
+
+        let publisher_counters = publisher.connection_counters();
+        assert_that!(publisher_counters, len 1);
+        assert_that!(publisher_counters[0].0, eq subscriber.id());
+        assert_that!(publisher_counters[0].1.overflowed_samples, eq 3);
+        assert_that!(publisher_counters[0].1.rejected_sends, eq 0);
+
+        assert_that!(subscriber.update_connections(), is_ok);
+        let subscriber_counters = subscriber.connection_counters();
+        assert_that!(subscriber_counters, len 1);
+        assert_that!(subscriber_counters[0].0, eq publisher.id());
+        assert_that!(subscriber_counters[0].1, eq publisher_counters[0].1);
+    }
+
+    #[test]
+    fn connection_counters_track_rejected_sends<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_safe_overflow(false)
+            .subscriber_max_buffer_size(1)
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        for n in 0..3 {
+            assert_that!(publisher.send_copy(n), is_ok);
+        }
+
+        let publisher_counters = publisher.connection_counters();
+        assert_that!(publisher_counters, len 1);
+        assert_that!(publisher_counters[0].1.rejected_sends, eq 2);
+        assert_that!(publisher_counters[0].1.overflowed_samples, eq 0);
+        assert_that!(subscriber.update_connections(), is_ok);
+        assert_that!(subscriber.connection_counters()[0].1, eq publisher_counters[0].1);
+    }
+
+    #[test]
+    fn publisher_reports_watermark_crossings_of_subscriber_buffer<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let events = std::sync::Arc::new(Mutex::new(vec![]));
+        let callback_events = events.clone();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut
+            .publisher_builder()
+            .high_watermark(3)
+            .low_watermark(1)
+            .set_backpressure_callback(Some(move |subscriber_id, event| {
+                callback_events.lock().unwrap().push((subscriber_id, event));
+            }))
+            .create()
+            .unwrap();
+
+        for n in 0..2 {
+            assert_that!(publisher.send_copy(n), is_ok);
+        }
+        assert_that!(*events.lock().unwrap(), len 0);
+
+        assert_that!(publisher.send_copy(2), is_ok);
+        assert_that!(*events.lock().unwrap(), eq vec![(subscriber.id(), BackpressureEvent::HighWatermarkReached)]);
+
+        for _ in 0..2 {
+            assert_that!(subscriber.receive().unwrap(), is_some);
+        }
+        assert_that!(publisher.update_connections(), is_ok);
+        assert_that!(*events.lock().unwrap(), eq vec![
+            (subscriber.id(), BackpressureEvent::HighWatermarkReached),
+            (subscriber.id(), BackpressureEvent::LowWatermarkReached)
+        ]);
+    }
+
+    #[cfg(feature = "sample_counters")]
+    #[test]
+    fn connection_counters_track_sent_and_reclaimed_samples<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        for n in 0..3 {
+            assert_that!(publisher.send_copy(n), is_ok);
+        }
+
+        let publisher_counters = publisher.connection_counters();
+        assert_that!(publisher_counters, len 1);
+        assert_that!(publisher_counters[0].1.sent_samples, eq 3);
+        assert_that!(publisher_counters[0].1.reclaimed_samples, eq 0);
+
+        for _ in 0..3 {
+            assert_that!(subscriber.receive().unwrap(), is_some);
+        }
+        assert_that!(publisher.send_copy(3), is_ok);
+
+        let publisher_counters = publisher.connection_counters();
+        assert_that!(publisher_counters[0].1.sent_samples, eq 4);
+        assert_that!(publisher_counters[0].1.reclaimed_samples, eq 3);
+        assert_that!(subscriber.connection_counters()[0].1, eq publisher_counters[0].1);
+    }
+
+    #[test]
+    fn connection_counters_are_empty_without_connections<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        assert_that!(publisher.connection_counters(), len 0);
+    }
+
+    #[test]
+    fn delivery_filter_delivers_only_to_matching_subscribers<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .max_subscribers(2)
+            .create()
+            .unwrap();
+
+        let matching_subscriber = sut.subscriber_builder().create().unwrap();
+        let other_subscriber = sut.subscriber_builder().create().unwrap();
+        let matching_id = matching_subscriber.id();
+
+        let publisher = sut
+            .publisher_builder()
+            .delivery_filter(move |details: SubscriberDetails| details.subscriber_id == matching_id)
+            .create()
+            .unwrap();
+
+        assert_that!(publisher.send_copy(8129), eq Ok(1));
+
+        let sample = matching_subscriber.receive().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 8129);
+        assert_that!(other_subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn delivery_filter_is_applied_to_history<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(2)
+            .history_size(1)
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .delivery_filter(|details: SubscriberDetails| details.buffer_size == 2)
+            .create()
+            .unwrap();
+        assert_that!(publisher.send_copy(42), eq Ok(0));
+
+        let matching_subscriber = sut.subscriber_builder().buffer_size(2).create().unwrap();
+        let other_subscriber = sut.subscriber_builder().buffer_size(1).create().unwrap();
+        assert_that!(publisher.update_connections(), is_ok);
+
+        let sample = matching_subscriber.receive().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 42);
+        assert_that!(other_subscriber.receive().unwrap(), is_none);
+
+        assert_that!(publisher.send_copy(43), eq Ok(1));
+        assert_that!(other_subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn subscriber_expires_after_the_defined_number_of_samples<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 3;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(8)
+            .history_size(0)
+            .create()
+            .unwrap();
+
+        let subscriber = sut
+            .subscriber_builder()
+            .auto_expire(AutoExpire::AfterSamples(NUMBER_OF_SAMPLES))
+            .create()
+            .unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        for n in 0..NUMBER_OF_SAMPLES + 2 {
+            assert_that!(publisher.send_copy(n), eq Ok(1));
+        }
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            assert_that!(subscriber.is_expired(), eq false);
+            let sample = subscriber.receive().unwrap();
+            assert_that!(sample, is_some);
+            assert_that!(*sample.unwrap(), eq n);
+        }
+
+        assert_that!(subscriber.is_expired(), eq true);
+        assert_that!(subscriber.has_samples(), eq Ok(false));
+        assert_that!(subscriber.receive().err(), eq Some(SubscriberReceiveError::Expired));
+
+        assert_that!(publisher.update_connections(), is_ok);
+        assert_that!(publisher.send_copy(123), eq Ok(0));
+        assert_that!(sut.dynamic_config().number_of_subscribers(), eq 0);
+    }
+
+    #[test]
+    fn subscriber_expires_after_the_defined_duration<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_millis(25);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let subscriber = sut
+            .subscriber_builder()
+            .auto_expire(AutoExpire::AfterDuration(TIMEOUT))
+            .create()
+            .unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        assert_that!(publisher.send_copy(1), eq Ok(1));
+        assert_that!(subscriber.is_expired(), eq false);
+        assert_that!(subscriber.receive().unwrap(), is_some);
+
+        std::thread::sleep(TIMEOUT);
+
+        assert_that!(subscriber.is_expired(), eq true);
+        assert_that!(subscriber.receive().err(), eq Some(SubscriberReceiveError::Expired));
+        assert_that!(sut.dynamic_config().number_of_subscribers(), eq 0);
+    }
+
+    #[test]
+    fn subscriber_without_auto_expire_does_not_expire<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(8)
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        for n in 0..8 {
+            assert_that!(publisher.send_copy(n), eq Ok(1));
+            assert_that!(subscriber.receive().unwrap(), is_some);
+        }
+
+        assert_that!(subscriber.is_expired(), eq false);
+        assert_that!(sut.dynamic_config().number_of_subscribers(), eq 1);
+    }
+
+    #[test]
+    fn subscriber_with_filter_receives_only_matching_samples<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(8)
+            .history_size(0)
+            .create()
+            .unwrap();
+
+        let subscriber = sut
+            .subscriber_builder()
+            .filter(|sample| **sample % 2 == 0)
+            .create()
+            .unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        for n in 0..8 {
+            assert_that!(publisher.send_copy(n), eq Ok(1));
+        }
+
+        for n in (0..8).step_by(2) {
+            let sample = subscriber.receive().unwrap();
+            assert_that!(sample, is_some);
+            assert_that!(*sample.unwrap(), eq n);
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn subscriber_with_filter_releases_discarded_samples_immediately<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .subscriber_max_borrowed_samples(1)
+            .history_size(0)
+            .create()
+            .unwrap();
+
+        let subscriber = sut
+            .subscriber_builder()
+            .filter(|sample| **sample == 3)
+            .create()
+            .unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        for n in 0..4 {
+            assert_that!(publisher.send_copy(n), eq Ok(1));
+        }
+
+        // the discarded samples do not count towards the borrowed samples
+        let sample = subscriber.receive().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 3);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn filtered_samples_do_not_count_towards_auto_expire<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(8)
+            .history_size(0)
+            .create()
+            .unwrap();
+
+        let subscriber = sut
+            .subscriber_builder()
+            .filter(|sample| **sample == 5)
+            .auto_expire(AutoExpire::AfterSamples(1))
+            .create()
+            .unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        for n in 0..4 {
+            assert_that!(publisher.send_copy(n), eq Ok(1));
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+        assert_that!(subscriber.is_expired(), eq false);
+
+        assert_that!(publisher.send_copy(5), eq Ok(1));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 5);
+        assert_that!(subscriber.is_expired(), eq true);
+    }
+
+    #[test]
+    fn subscriber_samples_are_no_heap_copies_by_default<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        assert_that!(publisher.send_copy(42), eq Ok(1));
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 42);
+        assert_that!(sample.is_heap_copy(), eq false);
+    }
+
+    #[cfg(not(feature = "deterministic_profile"))]
+    #[test]
+    fn subscriber_with_heap_copies_can_hold_more_samples_than_it_can_borrow<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 4;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .subscriber_max_borrowed_samples(1)
+            .history_size(0)
+            .create()
+            .unwrap();
+
+        let subscriber = sut
+            .subscriber_builder()
+            .receive_heap_copies(true)
+            .create()
+            .unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            assert_that!(publisher.send_copy(n), eq Ok(1));
+        }
+
+        let mut samples = vec![];
+        while let Some(sample) = subscriber.receive().unwrap() {
+            assert_that!(sample.is_heap_copy(), eq true);
+            assert_that!(sample.origin(), eq publisher.id());
+            samples.push(sample);
+        }
+
+        assert_that!(samples, len NUMBER_OF_SAMPLES as usize);
+        for (n, sample) in samples.iter().enumerate() {
+            assert_that!(**sample, eq n as u64);
+        }
+    }
+
+    #[test]
+    fn subscribers_cannot_borrow_more_than_max_total_borrowed_samples<Sut: Service>() {
+        const MAX_TOTAL_BORROWED_SAMPLES: usize = 3;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(MAX_TOTAL_BORROWED_SAMPLES)
+            .subscriber_max_borrowed_samples(MAX_TOTAL_BORROWED_SAMPLES)
+            .max_total_borrowed_samples(MAX_TOTAL_BORROWED_SAMPLES)
+            .history_size(0)
+            .create()
+            .unwrap();
+
+        let subscriber_1 = sut.subscriber_builder().create().unwrap();
+        let subscriber_2 = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        for n in 0..MAX_TOTAL_BORROWED_SAMPLES {
+            assert_that!(publisher.send_copy(n as u64), eq Ok(2));
+        }
+
+        let mut samples = vec![];
+        for _ in 0..2 {
+            samples.push(subscriber_1.receive().unwrap().unwrap());
+        }
+        samples.push(subscriber_2.receive().unwrap().unwrap());
+        assert_that!(sut.dynamic_config().number_of_borrowed_samples(), eq MAX_TOTAL_BORROWED_SAMPLES);
+
+        let result = subscriber_2.receive();
+        assert_that!(result.err(), eq Some(SubscriberReceiveError::ExceedsMaxTotalBorrowedSamples));
+
+        samples.pop();
+        let sample = subscriber_2.receive().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 1);
+
+        drop(samples);
+        assert_that!(sut.dynamic_config().number_of_borrowed_samples(), eq 0);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_max_total_borrowed_samples_requirement<
+        Sut: Service,
+    >() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_total_borrowed_samples(4)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_total_borrowed_samples(5)
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::DoesNotSupportRequestedMinSubscriberBorrowedSamples));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_total_borrowed_samples(4)
+            .open();
+        assert_that!(sut2, is_ok);
+        assert_that!(sut2.unwrap().static_config().max_total_borrowed_samples(), eq Some(4));
+    }
+
+    #[cfg(not(feature = "deterministic_profile"))]
+    #[test]
+    fn subscriber_with_heap_copies_preserves_user_header_and_slice_payload<Sut: Service>() {
+        const SLICE_LEN: usize = 13;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u64]>()
+            .user_header::<u64>()
+            .create()
+            .unwrap();
+
+        let subscriber = sut
+            .subscriber_builder()
+            .receive_heap_copies(true)
+            .create()
+            .unwrap();
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(SLICE_LEN)
+            .create()
+            .unwrap();
+
+        let mut sample = publisher.loan_slice_uninit(SLICE_LEN).unwrap();
+        *sample.user_header_mut() = 8192;
+        let sample = sample.write_from_fn(|i| i as u64 * 3);
+        sample.send().unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.is_heap_copy(), eq true);
+        assert_that!(*sample.user_header(), eq 8192);
+        assert_that!(sample.payload(), len SLICE_LEN);
+        for (i, value) in sample.payload().iter().enumerate() {
+            assert_that!(*value, eq i as u64 * 3);
+        }
+    }
+
+    #[cfg(not(feature = "deterministic_profile"))]
+    #[test]
+    fn subscriber_with_eager_segment_mapping_receives_samples_from_new_segments<Sut: Service>() {
+        const SLICE_LENS: [usize; 4] = [1, 64, 512, 4096];
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(1)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+        // the first segment is mapped on connection
+        let _warm_up = publisher.loan_slice(1).unwrap();
+        let subscriber = sut
+            .subscriber_builder()
+            .eager_segment_mapping(true)
+            .create()
+            .unwrap();
+
+        for slice_len in SLICE_LENS {
+            let sample = publisher.loan_slice_uninit(slice_len).unwrap();
+            // the new segment was announced and is mapped before the sample arrives
+            assert_that!(subscriber.update_connections(), is_ok);
+
+            let sample = sample.write_from_fn(|i| (i * slice_len) as u64);
+            sample.send().unwrap();
+
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.payload(), len slice_len);
+            for (i, value) in sample.payload().iter().enumerate() {
+                let expected_value = (i * slice_len) as u64;
+                assert_that!(*value, eq expected_value);
+            }
+        }
+    }
+
+    #[test]
+    fn fixed_order_fairness_policy_drains_first_connection_first<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .subscriber_max_buffer_size(4)
+            .subscriber_max_borrowed_samples(3)
+            .create()
+            .unwrap();
+
+        let subscriber = sut
+            .subscriber_builder()
+            .fairness_policy(FairnessPolicy::FixedOrder)
+            .create()
+            .unwrap();
+        let publisher_a = sut.publisher_builder().create().unwrap();
+        let publisher_b = sut.publisher_builder().create().unwrap();
+
+        for n in 0..2 {
+            publisher_a.send_copy(n).unwrap();
+            publisher_b.send_copy(10 + n).unwrap();
+        }
+
+        let first = subscriber
+            .receive()
+            .unwrap()
+            .unwrap()
+            .header()
+            .publisher_id();
+        let second = subscriber
+            .receive()
+            .unwrap()
+            .unwrap()
+            .header()
+            .publisher_id();
+        assert_that!(first, eq second);
+    }
+
+    #[test]
+    fn round_robin_fairness_policy_alternates_between_publishers<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .subscriber_max_buffer_size(4)
+            .subscriber_max_borrowed_samples(3)
+            .create()
+            .unwrap();
+
+        let subscriber = sut
+            .subscriber_builder()
+            .fairness_policy(FairnessPolicy::RoundRobin)
+            .create()
+            .unwrap();
+        let publisher_a = sut.publisher_builder().create().unwrap();
+        let publisher_b = sut.publisher_builder().create().unwrap();
+
+        for n in 0..3 {
+            publisher_a.send_copy(n).unwrap();
+            publisher_b.send_copy(10 + n).unwrap();
+        }
+
+        let mut previous_origin = None;
+        for _ in 0..6 {
+            let origin = subscriber
+                .receive()
+                .unwrap()
+                .unwrap()
+                .header()
+                .publisher_id();
+            assert_that!(Some(origin), ne previous_origin);
+            previous_origin = Some(origin);
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn weighted_fairness_policy_prefers_largest_backlog<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .subscriber_max_buffer_size(4)
+            .subscriber_max_borrowed_samples(3)
+            .create()
+            .unwrap();
+
+        let subscriber = sut
+            .subscriber_builder()
+            .fairness_policy(FairnessPolicy::Weighted)
+            .create()
+            .unwrap();
+        let publisher_a = sut.publisher_builder().create().unwrap();
+        let publisher_b = sut.publisher_builder().create().unwrap();
+
+        publisher_a.send_copy(1).unwrap();
+        for n in 0..3 {
+            publisher_b.send_copy(10 + n).unwrap();
+        }
+
+        for n in 0..2 {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.header().publisher_id(), eq publisher_b.id());
+            assert_that!(*sample, eq 10 + n);
+        }
+    }
+
+    #[test]
+    fn oldest_timestamp_first_fairness_policy_receives_in_send_order<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .subscriber_max_buffer_size(4)
+            .subscriber_max_borrowed_samples(3)
+            .enable_send_timestamp(true)
+            .create()
+            .unwrap();
+
+        let subscriber = sut
+            .subscriber_builder()
+            .fairness_policy(FairnessPolicy::OldestTimestampFirst)
+            .create()
+            .unwrap();
+        let publisher_a = sut.publisher_builder().create().unwrap();
+        let publisher_b = sut.publisher_builder().create().unwrap();
+
+        for n in 0..2 {
+            publisher_b.send_copy(2 * n).unwrap();
+            publisher_a.send_copy(2 * n + 1).unwrap();
+        }
+
+        for n in 0..4 {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq n);
+        }
+        assert_that!(subscriber.has_samples().unwrap(), eq false);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn forwarded_sample_is_received_by_subscribers_of_other_service<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let source = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .user_header::<u64>()
+            .create()
+            .unwrap();
+        let destination = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .user_header::<u64>()
+            .create()
+            .unwrap();
+
+        let source_publisher = source.publisher_builder().create().unwrap();
+        let source_subscriber = source.subscriber_builder().create().unwrap();
+        let publisher = destination.publisher_builder().create().unwrap();
+        let subscriber = destination.subscriber_builder().create().unwrap();
+
+        let mut sample = source_publisher.loan().unwrap();
+        *sample.user_header_mut() = 871;
+        *sample.payload_mut() = 9123;
+        sample.send().unwrap();
+
+        let sample = source_subscriber.receive().unwrap().unwrap();
+        assert_that!(publisher.forward(sample), eq Ok(1));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 9123);
+        assert_that!(*sample.user_header(), eq 871);
+        assert_that!(sample.origin(), eq publisher.id());
+
+        // the forwarded sample was released by the source subscriber
+        assert_that!(source.dynamic_config().number_of_borrowed_samples(), eq 0);
+    }
+
+    #[test]
+    fn forwarded_slice_sample_is_received_by_subscribers_of_other_service<Sut: Service>() {
+        const SLICE_LEN: usize = 6;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let source = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+        let destination = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+
+        let source_publisher = source
+            .publisher_builder()
+            .initial_max_slice_len(SLICE_LEN)
+            .create()
+            .unwrap();
+        let source_subscriber = source.subscriber_builder().create().unwrap();
+        let publisher = destination
+            .publisher_builder()
+            .initial_max_slice_len(SLICE_LEN)
+            .create()
+            .unwrap();
+        let subscriber = destination.subscriber_builder().create().unwrap();
+
+        let sample = source_publisher.loan_slice_uninit(SLICE_LEN).unwrap();
+        sample.write_from_fn(|n| n as u64 * 3).send().unwrap();
+
+        let sample = source_subscriber.receive().unwrap().unwrap();
+        assert_that!(publisher.forward(sample), eq Ok(1));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.payload(), len SLICE_LEN);
+        for (n, element) in sample.payload().iter().enumerate() {
+            assert_that!(*element, eq n as u64 * 3);
+        }
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
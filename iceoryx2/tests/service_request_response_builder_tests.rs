@@ -270,6 +270,92 @@ mod service_request_response {
         assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleResponseType));
     }
 
+    #[test]
+    fn opening_service_with_matching_error_type_works<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut_create = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .error_type::<i32>()
+            .create();
+
+        assert_that!(sut_create, is_ok);
+        let sut_create = sut_create.unwrap();
+        let error_details = sut_create.static_config().error_message_type_details();
+        assert_that!(error_details, is_some);
+        assert_that!(error_details.unwrap().payload.size, eq core::mem::size_of::<i32>());
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .error_type::<i32>()
+            .open();
+
+        assert_that!(sut_open, is_ok);
+    }
+
+    #[test]
+    fn opening_service_with_mismatching_error_type_fails<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut_create = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .error_type::<i32>()
+            .create();
+
+        assert_that!(sut_create, is_ok);
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .error_type::<u32>()
+            .open();
+
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleErrorType));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .open();
+
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleErrorType));
+    }
+
+    #[test]
+    fn opening_service_without_error_type_with_error_type_fails<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut_create = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create();
+
+        assert_that!(sut_create, is_ok);
+        assert_that!(
+            sut_create
+                .unwrap()
+                .static_config()
+                .error_message_type_details(),
+            is_none
+        );
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .error_type::<i32>()
+            .open();
+
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleErrorType));
+    }
+
     #[test]
     fn opening_service_with_incompatible_response_type_alignment_fails<Sut: Service>() {
         let service_name = generate_service_name();
@@ -471,6 +557,27 @@ mod service_request_response {
         assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleOverflowBehaviorForResponses));
     }
 
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_fire_and_forget_requirement<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut_create = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_fire_and_forget_requests(true)
+            .create();
+        assert_that!(sut_create, is_ok);
+        assert_that!(sut_create.unwrap().static_config().has_fire_and_forget_requests(), eq true);
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_fire_and_forget_requests(false)
+            .open();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleFireAndForgetRequests));
+    }
+
     #[test]
     fn open_verifies_max_active_responses_correctly<Sut: Service>() {
         let service_name = generate_service_name();
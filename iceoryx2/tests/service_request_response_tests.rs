@@ -0,0 +1,1044 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_request_response {
+    use core::cell::RefCell;
+    use core::sync::atomic::{AtomicBool, Ordering};
+    use core::time::Duration;
+    use std::rc::Rc;
+    use std::sync::{Barrier, Mutex};
+    use std::thread;
+
+    use iceoryx2::node::NodeBuilder;
+    use iceoryx2::port::client::{CallError, RequestSendError, ResponseReceiveError};
+    use iceoryx2::port::server::ResponseSendError;
+    use iceoryx2::port::LoanError;
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn request_is_received_and_answered<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let server = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let pending_response = client.send_copy(1234).unwrap();
+        assert_that!(pending_response.number_of_server_connections(), eq 1);
+
+        let active_request = server.receive().unwrap().unwrap();
+        assert_that!(*active_request, eq 1234);
+        assert_that!(active_request.origin(), eq client.id());
+        assert_that!(active_request.header().request_id(), eq pending_response.request_id());
+        assert_that!(active_request.is_connected(), eq true);
+
+        active_request.send_copy(5678).unwrap();
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(*response, eq 5678);
+        assert_that!(response.origin(), eq server.id());
+        assert_that!(response.header().request_id(), eq pending_response.request_id());
+        assert_that!(pending_response.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn server_can_send_a_stream_of_responses<Sut: Service>() {
+        const NUMBER_OF_RESPONSES: u64 = 4;
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_response_buffer_size(NUMBER_OF_RESPONSES as usize)
+            .create()
+            .unwrap();
+
+        let server = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let mut request = client.loan().unwrap();
+        *request.payload_mut() = 3;
+        let pending_response = request.send().unwrap();
+
+        let active_request = server.receive().unwrap().unwrap();
+        for n in 0..NUMBER_OF_RESPONSES {
+            let response = active_request.loan_uninit().unwrap();
+            response.write_payload(*active_request * n).send().unwrap();
+        }
+
+        for n in 0..NUMBER_OF_RESPONSES {
+            let response = pending_response.receive().unwrap().unwrap();
+            assert_that!(*response, eq 3 * n);
+        }
+        assert_that!(pending_response.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn sending_more_than_max_active_requests_fails<Sut: Service>() {
+        const MAX_ACTIVE_REQUESTS: usize = 3;
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests(MAX_ACTIVE_REQUESTS)
+            .create()
+            .unwrap();
+
+        let _server = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let mut pending_responses = vec![];
+        for n in 0..MAX_ACTIVE_REQUESTS {
+            pending_responses.push(client.send_copy(n as u64).unwrap());
+        }
+        assert_that!(client.number_of_active_requests(), eq MAX_ACTIVE_REQUESTS);
+
+        let result = client.send_copy(0);
+        assert_that!(result.err(), eq Some(RequestSendError::ExceedsMaxActiveRequests));
+
+        pending_responses.pop();
+        assert_that!(client.number_of_active_requests(), eq MAX_ACTIVE_REQUESTS - 1);
+        assert_that!(client.send_copy(0), is_ok);
+    }
+
+    #[test]
+    fn fire_and_forget_requests_do_not_occupy_active_requests<Sut: Service>() {
+        const MAX_ACTIVE_REQUESTS: usize = 1;
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests(MAX_ACTIVE_REQUESTS)
+            .max_request_buffer_size(4)
+            .enable_fire_and_forget_requests(true)
+            .create()
+            .unwrap();
+
+        let server = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let _pending_response = client.send_copy(1).unwrap();
+        assert_that!(client.send_copy_and_forget(2).unwrap(), eq 1);
+        assert_that!(client.send_copy_and_forget(3).unwrap(), eq 1);
+        assert_that!(client.number_of_active_requests(), eq MAX_ACTIVE_REQUESTS);
+
+        let active_request = server.receive().unwrap().unwrap();
+        assert_that!(*active_request, eq 1);
+        assert_that!(active_request.is_fire_and_forget(), eq false);
+
+        for n in 2..4 {
+            let active_request = server.receive().unwrap().unwrap();
+            assert_that!(*active_request, eq n);
+            assert_that!(active_request.header().is_fire_and_forget(), eq true);
+        }
+    }
+
+    #[test]
+    fn fire_and_forget_request_fails_when_not_enabled<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_fire_and_forget_requests(false)
+            .create()
+            .unwrap();
+
+        let _server = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let result = client.send_copy_and_forget(1);
+        assert_that!(result.err(), eq Some(RequestSendError::FireAndForgetRequestsNotEnabled));
+    }
+
+    #[test]
+    fn loaning_more_than_max_active_requests_fails<Sut: Service>() {
+        const MAX_ACTIVE_REQUESTS: usize = 2;
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests(MAX_ACTIVE_REQUESTS)
+            .create()
+            .unwrap();
+
+        let client = service.client_builder().create().unwrap();
+
+        let mut requests = vec![];
+        for _ in 0..MAX_ACTIVE_REQUESTS {
+            requests.push(client.loan_uninit().unwrap());
+        }
+
+        assert_that!(client.loan_uninit().err(), eq Some(LoanError::ExceedsMaxLoans));
+
+        requests.pop();
+        assert_that!(client.loan_uninit(), is_ok);
+    }
+
+    #[cfg(feature = "loan_diagnostics")]
+    #[test]
+    fn client_tracks_location_of_outstanding_loans<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests(2)
+            .create()
+            .unwrap();
+
+        let client = service.client_builder().create().unwrap();
+        assert_that!(client.outstanding_loans(), len 0);
+
+        let request_1 = client.loan().unwrap();
+        let request_2 = client.loan_uninit().unwrap();
+        let loans = client.outstanding_loans();
+        assert_that!(loans, len 2);
+        for loan in &loans {
+            assert_that!(loan.location().file(), eq file!());
+        }
+
+        drop(request_1);
+        assert_that!(client.outstanding_loans(), len 1);
+
+        let _pending_response = request_2.write_payload(5).send().unwrap();
+        assert_that!(client.outstanding_loans(), len 0);
+    }
+
+    #[cfg(feature = "loan_diagnostics")]
+    #[test]
+    fn server_tracks_location_of_outstanding_loans<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let server = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+        assert_that!(server.outstanding_loans(), len 0);
+
+        let _pending_response = client.send_copy(1234).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+
+        let response_1 = active_request.loan().unwrap();
+        let response_2 = active_request.loan_uninit().unwrap();
+        let loans = server.outstanding_loans();
+        assert_that!(loans, len 2);
+        for loan in &loans {
+            assert_that!(loan.location().file(), eq file!());
+        }
+
+        drop(response_1);
+        assert_that!(server.outstanding_loans(), len 1);
+
+        assert_that!(response_2.write_payload(5).send(), is_ok);
+        assert_that!(server.outstanding_loans(), len 0);
+    }
+
+    #[test]
+    fn responses_are_delivered_to_the_corresponding_pending_response<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests(2)
+            .create()
+            .unwrap();
+
+        let server = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let pending_response_1 = client.send_copy(10).unwrap();
+        let pending_response_2 = client.send_copy(20).unwrap();
+
+        let active_request_1 = server.receive().unwrap().unwrap();
+        let active_request_2 = server.receive().unwrap().unwrap();
+        assert_that!(*active_request_1, eq 10);
+        assert_that!(*active_request_2, eq 20);
+
+        active_request_2.send_copy(21).unwrap();
+        active_request_1.send_copy(11).unwrap();
+
+        let response = pending_response_1.receive().unwrap().unwrap();
+        assert_that!(*response, eq 11);
+        assert_that!(pending_response_1.receive().unwrap(), is_none);
+
+        let response = pending_response_2.receive().unwrap().unwrap();
+        assert_that!(*response, eq 21);
+        assert_that!(pending_response_2.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn responses_of_dropped_pending_response_are_discarded<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests(2)
+            .create()
+            .unwrap();
+
+        let server = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let pending_response_1 = client.send_copy(10).unwrap();
+        let pending_response_2 = client.send_copy(20).unwrap();
+
+        let active_request_1 = server.receive().unwrap().unwrap();
+        let active_request_2 = server.receive().unwrap().unwrap();
+
+        drop(pending_response_1);
+        assert_that!(client.number_of_active_requests(), eq 1);
+
+        active_request_1.send_copy(11).unwrap();
+        active_request_2.send_copy(21).unwrap();
+
+        let response = pending_response_2.receive().unwrap().unwrap();
+        assert_that!(*response, eq 21);
+        assert_that!(pending_response_2.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn request_without_response_expires_after_timeout<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_millis(25);
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests(1)
+            .create()
+            .unwrap();
+
+        let server = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let mut request = client.loan().unwrap();
+        *request.payload_mut() = 42;
+        let pending_response = request.send_with_timeout(TIMEOUT).unwrap();
+        assert_that!(pending_response.has_timed_out(), eq false);
+        assert_that!(client.send_copy(0).err(), eq Some(RequestSendError::ExceedsMaxActiveRequests));
+
+        std::thread::sleep(TIMEOUT * 2);
+
+        assert_that!(pending_response.has_timed_out(), eq true);
+        assert_that!(pending_response.receive().err(), eq Some(ResponseReceiveError::Timeout));
+        assert_that!(client.number_of_active_requests(), eq 0);
+        assert_that!(service.dynamic_config().number_of_expired_requests(), eq 1);
+
+        let active_request = server.receive().unwrap().unwrap();
+        assert_that!(active_request.send_copy(43), is_ok);
+        assert_that!(pending_response.receive().err(), eq Some(ResponseReceiveError::Timeout));
+
+        assert_that!(client.send_copy(0), is_ok);
+    }
+
+    #[test]
+    fn request_with_response_before_timeout_does_not_expire<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_millis(25);
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let server = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let pending_response = client.loan().unwrap().send_with_timeout(TIMEOUT).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        active_request.send_copy(1).unwrap();
+
+        let response = pending_response.receive().unwrap();
+        assert_that!(response, is_some);
+        drop(response);
+
+        std::thread::sleep(TIMEOUT * 2);
+        active_request.send_copy(2).unwrap();
+
+        assert_that!(pending_response.has_timed_out(), eq false);
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(*response, eq 2);
+        assert_that!(service.dynamic_config().number_of_expired_requests(), eq 0);
+    }
+
+    #[test]
+    fn request_without_server_reaches_no_one<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let client = service.client_builder().create().unwrap();
+
+        let pending_response = client.send_copy(1).unwrap();
+        assert_that!(pending_response.number_of_server_connections(), eq 0);
+        assert_that!(pending_response.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn request_is_delivered_to_all_servers<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_servers(2)
+            .create()
+            .unwrap();
+
+        let client = service.client_builder().create().unwrap();
+        let server_1 = service.server_builder().create().unwrap();
+        let server_2 = service.server_builder().create().unwrap();
+
+        let pending_response = client.send_copy(7).unwrap();
+        assert_that!(pending_response.number_of_server_connections(), eq 2);
+
+        for server in [&server_1, &server_2] {
+            assert_that!(server.has_requests().unwrap(), eq true);
+            let active_request = server.receive().unwrap().unwrap();
+            active_request.send_copy(*active_request + 1).unwrap();
+        }
+
+        for _ in 0..2 {
+            let response = pending_response.receive().unwrap().unwrap();
+            assert_that!(*response, eq 8);
+        }
+        assert_that!(pending_response.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn client_prepares_connections_to_servers_that_appear_later<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let connected_servers = Rc::new(RefCell::new(vec![]));
+        let callback_servers = connected_servers.clone();
+        let client = service
+            .client_builder()
+            .set_server_connected_callback(Some(move |server_id| {
+                callback_servers.borrow_mut().push(server_id)
+            }))
+            .create()
+            .unwrap();
+        assert_that!(client.prepare_connections(), eq Ok(0));
+        assert_that!(*connected_servers.borrow(), len 0);
+
+        let server = service.server_builder().create().unwrap();
+
+        assert_that!(client.prepare_connections(), eq Ok(1));
+        assert_that!(*connected_servers.borrow(), eq vec![server.id()]);
+
+        assert_that!(client.prepare_connections(), eq Ok(1));
+        assert_that!(*connected_servers.borrow(), len 1);
+
+        let pending_response = client.send_copy(12).unwrap();
+        assert_that!(pending_response.number_of_server_connections(), eq 1);
+        assert_that!(*connected_servers.borrow(), len 1);
+
+        let active_request = server.receive().unwrap().unwrap();
+        assert_that!(*active_request, eq 12);
+        active_request.send_copy(13).unwrap();
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(*response, eq 13);
+    }
+
+    #[test]
+    fn server_sends_error_response_and_client_receives_it<Sut: Service>() {
+        type ErrorType = [u64; 8];
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .error_type::<ErrorType>()
+            .create()
+            .unwrap();
+
+        let client = service.client_builder().create().unwrap();
+        let server = service.server_builder().create().unwrap();
+
+        let pending_response = client.send_copy(12).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        active_request.send_copy(13).unwrap();
+        active_request.send_error([7u64; 8]).unwrap();
+
+        let response = pending_response.receive_result::<ErrorType>().unwrap();
+        assert_that!(response, is_some);
+        let response = response.unwrap();
+        assert_that!(response, is_ok);
+        let response = response.unwrap();
+        assert_that!(*response, eq 13);
+        assert_that!(response.header().is_error(), eq false);
+
+        let error_response = pending_response.receive_result::<ErrorType>().unwrap();
+        assert_that!(error_response, is_some);
+        let error_response = error_response.unwrap();
+        assert_that!(error_response, is_err);
+        let error_response = error_response.err().unwrap();
+        assert_that!(*error_response, eq [7u64; 8]);
+        assert_that!(error_response.header().is_error(), eq true);
+        assert_that!(error_response.header().request_id(), eq pending_response.request_id());
+        assert_that!(error_response.origin(), eq server.id());
+
+        assert_that!(
+            pending_response.receive_result::<ErrorType>().unwrap(),
+            is_none
+        );
+    }
+
+    #[test]
+    fn receiving_error_response_without_error_type_fails<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .error_type::<i32>()
+            .create()
+            .unwrap();
+
+        let client = service.client_builder().create().unwrap();
+        let server = service.server_builder().create().unwrap();
+
+        let pending_response = client.send_copy(12).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        active_request.send_error(42i32).unwrap();
+
+        assert_that!(pending_response.receive().err(), eq Some(ResponseReceiveError::ReceivedErrorResponse));
+        assert_that!(pending_response.receive_result::<u32>().err(), eq Some(ResponseReceiveError::IncompatibleErrorType));
+
+        let error_response = pending_response.receive_result::<i32>().unwrap().unwrap();
+        assert_that!(error_response, is_err);
+        assert_that!(*error_response.err().unwrap(), eq 42);
+    }
+
+    #[test]
+    fn sending_error_response_with_incompatible_error_type_fails<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service_with_error_type = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .error_type::<i32>()
+            .create()
+            .unwrap();
+        let service_without_error_type = node
+            .service_builder(&generate_service_name())
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let client = service_with_error_type.client_builder().create().unwrap();
+        let server = service_with_error_type.server_builder().create().unwrap();
+        let _pending_response = client.send_copy(12).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        assert_that!(active_request.send_error(5u32).err(), eq Some(ResponseSendError::IncompatibleErrorType));
+
+        let client = service_without_error_type
+            .client_builder()
+            .create()
+            .unwrap();
+        let server = service_without_error_type
+            .server_builder()
+            .create()
+            .unwrap();
+        let pending_response = client.send_copy(12).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        assert_that!(active_request.send_error(5i32).err(), eq Some(ResponseSendError::IncompatibleErrorType));
+        assert_that!(pending_response.receive_result::<i32>().err(), eq Some(ResponseReceiveError::IncompatibleErrorType));
+    }
+
+    #[test]
+    fn dynamic_config_tracks_number_of_clients_and_servers<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_clients(2)
+            .max_servers(2)
+            .create()
+            .unwrap();
+
+        let client_1 = service.client_builder().create().unwrap();
+        let client_2 = service.client_builder().create().unwrap();
+        let server = service.server_builder().create().unwrap();
+
+        assert_that!(service.dynamic_config().number_of_clients(), eq 2);
+        assert_that!(service.dynamic_config().number_of_servers(), eq 1);
+
+        assert_that!(service.client_builder().create(), is_err);
+
+        drop(client_1);
+        drop(server);
+        assert_that!(service.dynamic_config().number_of_clients(), eq 1);
+        assert_that!(service.dynamic_config().number_of_servers(), eq 0);
+
+        drop(client_2);
+        assert_that!(service.dynamic_config().number_of_clients(), eq 0);
+    }
+
+    #[test]
+    fn slice_requests_and_responses_are_delivered<Sut: Service>() {
+        const MAX_SLICE_LEN: usize = 16;
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<[u8], [u8]>()
+            .create()
+            .unwrap();
+
+        let server = service
+            .server_builder()
+            .initial_max_slice_len(MAX_SLICE_LEN)
+            .create()
+            .unwrap();
+        let client = service
+            .client_builder()
+            .initial_max_slice_len(MAX_SLICE_LEN)
+            .create()
+            .unwrap();
+
+        let request = client.loan_slice_uninit(5).unwrap();
+        let pending_response = request.write_from_fn(|n| n as u8 * 2).send().unwrap();
+
+        let active_request = server.receive().unwrap().unwrap();
+        assert_that!(active_request.header().number_of_elements(), eq 5);
+        assert_that!(*active_request, eq [0u8, 2, 4, 6, 8]);
+
+        let response = active_request.loan_slice_uninit(3).unwrap();
+        response.write_from_slice(&[7, 8, 9]).send().unwrap();
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(response.header().number_of_elements(), eq 3);
+        assert_that!(*response, eq [7u8, 8, 9]);
+    }
+
+    #[test]
+    fn sliced_request_with_fixed_size_response_works<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<[u64], u64>()
+            .create()
+            .unwrap();
+
+        let server = service.server_builder().create().unwrap();
+        let client = service
+            .client_builder()
+            .initial_max_slice_len(4)
+            .create()
+            .unwrap();
+
+        let mut request = client.loan_slice(4).unwrap();
+        request.payload_mut().copy_from_slice(&[1, 2, 3, 4]);
+        let pending_response = request.send().unwrap();
+
+        let active_request = server.receive().unwrap().unwrap();
+        active_request
+            .send_copy(active_request.iter().sum())
+            .unwrap();
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(*response, eq 10);
+    }
+
+    #[test]
+    fn loaning_slice_larger_than_max_slice_len_with_static_allocation_fails<Sut: Service>() {
+        const MAX_SLICE_LEN: usize = 8;
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<[u8], [u8]>()
+            .create()
+            .unwrap();
+
+        let server = service
+            .server_builder()
+            .initial_max_slice_len(MAX_SLICE_LEN)
+            .create()
+            .unwrap();
+        let client = service
+            .client_builder()
+            .initial_max_slice_len(MAX_SLICE_LEN)
+            .create()
+            .unwrap();
+
+        assert_that!(client.loan_slice(MAX_SLICE_LEN + 1).err(), eq Some(LoanError::ExceedsMaxLoanSize));
+
+        let _pending_response = client.loan_slice(MAX_SLICE_LEN).unwrap().send().unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        assert_that!(active_request.loan_slice(MAX_SLICE_LEN + 1).err(), eq Some(LoanError::ExceedsMaxLoanSize));
+        assert_that!(active_request.loan_slice(MAX_SLICE_LEN), is_ok);
+    }
+
+    #[cfg(not(feature = "deterministic_profile"))]
+    #[test]
+    fn dynamic_allocation_strategy_allows_slices_larger_than_max_slice_len<Sut: Service>() {
+        const INITIAL_SLICE_LEN: usize = 4;
+        const LARGE_SLICE_LEN: usize = 1024;
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<[u8], [u8]>()
+            .create()
+            .unwrap();
+
+        let server = service
+            .server_builder()
+            .initial_max_slice_len(INITIAL_SLICE_LEN)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+        let client = service
+            .client_builder()
+            .initial_max_slice_len(INITIAL_SLICE_LEN)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+
+        let request = client.loan_slice_uninit(LARGE_SLICE_LEN).unwrap();
+        let pending_response = request.write_from_fn(|n| (n % 255) as u8).send().unwrap();
+
+        let active_request = server.receive().unwrap().unwrap();
+        assert_that!(active_request.len(), eq LARGE_SLICE_LEN);
+        for (n, element) in active_request.iter().enumerate() {
+            assert_that!(*element, eq(n % 255) as u8);
+        }
+
+        let response = active_request.loan_slice_uninit(LARGE_SLICE_LEN).unwrap();
+        response.write_from_fn(|n| (n % 127) as u8).send().unwrap();
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(response.len(), eq LARGE_SLICE_LEN);
+        for (n, element) in response.iter().enumerate() {
+            assert_that!(*element, eq(n % 127) as u8);
+        }
+    }
+
+    #[test]
+    fn call_without_server_fails<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let client = service.client_builder().create().unwrap();
+
+        let result = client.call(1, Duration::from_millis(10));
+        assert_that!(result.err(), eq Some(CallError::NoConnectedServer));
+        assert_that!(client.number_of_active_requests(), eq 0);
+    }
+
+    #[test]
+    fn call_without_response_times_out<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_millis(25);
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let _server = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let start = std::time::Instant::now();
+        let result = client.call(1, TIMEOUT);
+        assert_that!(result.err(), eq Some(CallError::Timeout));
+        assert_that!(start.elapsed(), ge TIMEOUT);
+        assert_that!(client.number_of_active_requests(), eq 0);
+    }
+
+    #[test]
+    fn call_returns_response_of_server<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = Mutex::new(NodeBuilder::new().config(&config).create::<Sut>().unwrap());
+        let server_created = Barrier::new(2);
+        let keep_running = AtomicBool::new(true);
+
+        thread::scope(|s| {
+            s.spawn(|| {
+                let service = node
+                    .lock()
+                    .unwrap()
+                    .service_builder(&service_name)
+                    .request_response::<u64, u64>()
+                    .open_or_create()
+                    .unwrap();
+                let server = service.server_builder().create().unwrap();
+                server_created.wait();
+
+                while keep_running.load(Ordering::Relaxed) {
+                    if let Some(active_request) = server.receive().unwrap() {
+                        active_request.send_copy(*active_request * 2).unwrap();
+                    }
+                }
+            });
+
+            server_created.wait();
+            let service = node
+                .lock()
+                .unwrap()
+                .service_builder(&service_name)
+                .request_response::<u64, u64>()
+                .open_or_create()
+                .unwrap();
+            let client = service.client_builder().create().unwrap();
+
+            for n in 0..4 {
+                let response = client.call(n, Duration::from_secs(10)).unwrap();
+                assert_that!(*response, eq n * 2);
+            }
+            assert_that!(client.number_of_active_requests(), eq 0);
+
+            keep_running.store(false, Ordering::Relaxed);
+        });
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn client_sends_and_receives_asynchronously<Sut: Service>()
+    where
+        <Sut::Event as iceoryx2_cal::event::Event>::Listener:
+            iceoryx2_bb_posix::file_descriptor::FileDescriptorBased,
+    {
+        use iceoryx2::port::async_listener::AsyncListener;
+
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+        let event = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_io()
+            .build()
+            .unwrap();
+
+        let response = runtime.block_on(async {
+            let listener = AsyncListener::new(event.listener_builder().create().unwrap()).unwrap();
+            let barrier = std::sync::Arc::new(Barrier::new(2));
+
+            let server_thread = {
+                let service_name = service_name.clone();
+                let config = config.clone();
+                let barrier = barrier.clone();
+                thread::spawn(move || {
+                    let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+                    let service = node
+                        .service_builder(&service_name)
+                        .request_response::<u64, u64>()
+                        .open()
+                        .unwrap();
+                    let event = node.service_builder(&service_name).event().open().unwrap();
+                    let notifier = event.notifier_builder().create().unwrap();
+
+                    thread::sleep(Duration::from_millis(50));
+                    let server = service.server_builder().create().unwrap();
+                    notifier.notify().unwrap();
+
+                    loop {
+                        if let Some(active_request) = server.receive().unwrap() {
+                            active_request.send_copy(*active_request + 1).unwrap();
+                            notifier.notify().unwrap();
+                            break;
+                        }
+                        thread::sleep(Duration::from_millis(1));
+                    }
+
+                    barrier.wait();
+                })
+            };
+
+            let pending_response = client.send_copy_async(12, &listener).await.unwrap();
+            assert_that!(pending_response.number_of_server_connections(), eq 1);
+            let response = *pending_response.receive_async(&listener).await.unwrap();
+
+            barrier.wait();
+            server_thread.join().unwrap();
+            response
+        });
+
+        assert_that!(response, eq 13);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn server_receives_asynchronously<Sut: Service>()
+    where
+        <Sut::Event as iceoryx2_cal::event::Event>::Listener:
+            iceoryx2_bb_posix::file_descriptor::FileDescriptorBased,
+    {
+        use iceoryx2::port::async_listener::AsyncListener;
+
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+        let event = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let server = service.server_builder().create().unwrap();
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_io()
+            .build()
+            .unwrap();
+
+        let request = runtime.block_on(async {
+            let listener = AsyncListener::new(event.listener_builder().create().unwrap()).unwrap();
+            let barrier = std::sync::Arc::new(Barrier::new(2));
+
+            let client_thread = {
+                let service_name = service_name.clone();
+                let config = config.clone();
+                let barrier = barrier.clone();
+                thread::spawn(move || {
+                    let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+                    let service = node
+                        .service_builder(&service_name)
+                        .request_response::<u64, u64>()
+                        .open()
+                        .unwrap();
+                    let event = node.service_builder(&service_name).event().open().unwrap();
+                    let client = service.client_builder().create().unwrap();
+                    let notifier = event.notifier_builder().create().unwrap();
+
+                    thread::sleep(Duration::from_millis(50));
+                    let _pending_response = client.send_copy(7812).unwrap();
+                    notifier.notify().unwrap();
+
+                    barrier.wait();
+                })
+            };
+
+            let request = *server.receive_async(&listener).await.unwrap();
+
+            barrier.wait();
+            client_thread.join().unwrap();
+            request
+        });
+
+        assert_that!(request, eq 7812);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -677,6 +677,123 @@ mod service {
         assert_that!(service_counter, eq 1);
     }
 
+    #[test]
+    fn list_where_returns_only_services_that_satisfy_the_query<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        const NUMBER_OF_SERVICES: usize = 8;
+        let test = Factory::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let mut services = vec![];
+        let mut lidar_service_ids = vec![];
+        for i in 0..NUMBER_OF_SERVICES {
+            let service_name = generate_name();
+            let sensor_type = if i % 2 == 0 { "lidar" } else { "radar" };
+            let sut = test
+                .create(
+                    &node,
+                    &service_name,
+                    &AttributeSpecifier::new().define("sensor-type", sensor_type),
+                )
+                .unwrap();
+
+            if i % 2 == 0 {
+                lidar_service_ids.push(sut.service_id().clone());
+            }
+            services.push(sut);
+        }
+
+        let mut listed_services = vec![];
+        let result = Sut::list_where(
+            &config,
+            &AttributeQuery::key("sensor-type").equals("lidar"),
+            |service| {
+                listed_services.push(service.static_details.service_id().clone());
+                CallbackProgression::Continue
+            },
+        );
+        assert_that!(result, is_ok);
+        assert_that!(listed_services, len lidar_service_ids.len());
+
+        for s in listed_services {
+            assert_that!(lidar_service_ids, contains s);
+        }
+    }
+
+    #[test]
+    fn list_where_with_key_query_returns_services_that_define_the_key<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut_with_key = test
+            .create(
+                &node,
+                &generate_name(),
+                &AttributeSpecifier::new()
+                    .define("calibration", "factory")
+                    .define("sensor-type", "lidar"),
+            )
+            .unwrap();
+        let _sut_without_key = test
+            .create(
+                &node,
+                &generate_name(),
+                &AttributeSpecifier::new().define("sensor-type", "lidar"),
+            )
+            .unwrap();
+
+        let mut listed_services = vec![];
+        let result = Sut::list_where(
+            &config,
+            &AttributeQuery::key("calibration")
+                .and(AttributeQuery::key("sensor-type").equals("lidar")),
+            |service| {
+                listed_services.push(service.static_details.service_id().clone());
+                CallbackProgression::Continue
+            },
+        );
+        assert_that!(result, is_ok);
+        assert_that!(listed_services, len 1);
+        assert_that!(&listed_services[0], eq sut_with_key.service_id());
+    }
+
+    #[test]
+    fn list_where_lists_nothing_when_no_service_satisfies_the_query<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = test
+            .create(
+                &node,
+                &generate_name(),
+                &AttributeSpecifier::new().define("sensor-type", "radar"),
+            )
+            .unwrap();
+
+        let mut service_counter = 0;
+        let result = Sut::list_where(
+            &config,
+            &AttributeQuery::key("sensor-type").equals("lidar"),
+            |_| {
+                service_counter += 1;
+                CallbackProgression::Continue
+            },
+        );
+        assert_that!(result, is_ok);
+        assert_that!(service_counter, eq 0);
+    }
+
     #[test]
     fn concurrent_service_creation_and_listing_works<Sut: Service, Factory: SutFactory<Sut>>() {
         let _watch_dog = Watchdog::new_with_timeout(Duration::from_secs(120));
@@ -0,0 +1,209 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod subscriber_load_shedding {
+    use iceoryx2::node::load_shedding::{CpuLoadMeasurementError, ShedPolicy};
+    use iceoryx2::port::publisher::Publisher;
+    use iceoryx2::port::subscriber::Subscriber;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::service_name::ServiceName;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    const BUFFER_SIZE: usize = 16;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "subscriber_load_shedding_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    struct TestFixture<S: Service> {
+        node: Node<S>,
+        publisher: Publisher<S, u64, ()>,
+        subscriber: Subscriber<S, u64, ()>,
+    }
+
+    impl<S: Service> TestFixture<S> {
+        fn new(shed_policy: ShedPolicy) -> Self {
+            let config = generate_isolated_config();
+            let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+            let service = node
+                .service_builder(&generate_name())
+                .publish_subscribe::<u64>()
+                .subscriber_max_buffer_size(BUFFER_SIZE)
+                .create()
+                .unwrap();
+            let publisher = service.publisher_builder().create().unwrap();
+            let subscriber = service
+                .subscriber_builder()
+                .buffer_size(BUFFER_SIZE)
+                .shed_policy(shed_policy)
+                .create()
+                .unwrap();
+
+            Self {
+                node,
+                publisher,
+                subscriber,
+            }
+        }
+
+        fn send_and_receive(&self, values: core::ops::Range<u64>) -> Vec<u64> {
+            for value in values {
+                self.publisher.send_copy(value).unwrap();
+            }
+
+            let mut received = vec![];
+            while let Some(sample) = self.subscriber.receive().unwrap() {
+                received.push(*sample);
+            }
+            received
+        }
+    }
+
+    #[test]
+    fn cpu_load_measurement_error_display_works<S: Service>() {
+        assert_that!(
+            format!("{}", CpuLoadMeasurementError::UnsupportedPlatform), eq "CpuLoadMeasurementError::UnsupportedPlatform");
+    }
+
+    #[test]
+    fn node_does_not_shed_load_before_the_load_is_acquired<S: Service>() {
+        let config = generate_isolated_config();
+        let sut = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        assert_that!(sut.cpu_load(), eq None);
+        assert_that!(sut.is_shedding_load(), eq false);
+    }
+
+    #[test]
+    fn node_sheds_load_with_hysteresis<S: Service>() {
+        let mut config = generate_isolated_config();
+        config.global.node.load_shedding.shedding_threshold = 80;
+        config.global.node.load_shedding.recovery_threshold = 50;
+        let sut = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        sut.report_cpu_load(80);
+        assert_that!(sut.cpu_load(), eq Some(80));
+        assert_that!(sut.is_shedding_load(), eq false);
+
+        sut.report_cpu_load(81);
+        assert_that!(sut.is_shedding_load(), eq true);
+
+        sut.report_cpu_load(50);
+        assert_that!(sut.is_shedding_load(), eq true);
+
+        sut.report_cpu_load(49);
+        assert_that!(sut.cpu_load(), eq Some(49));
+        assert_that!(sut.is_shedding_load(), eq false);
+    }
+
+    #[test]
+    fn subscriber_without_shed_policy_receives_all_samples_while_shedding<S: Service>() {
+        let sut = TestFixture::<S>::new(ShedPolicy::Disabled);
+
+        sut.node.report_cpu_load(100);
+
+        assert_that!(sut.send_and_receive(0..4), eq vec![0, 1, 2, 3]);
+        assert_that!(sut.subscriber.number_of_shed_samples(), eq 0);
+    }
+
+    #[test]
+    fn paused_subscriber_discards_samples_while_shedding<S: Service>() {
+        let sut = TestFixture::<S>::new(ShedPolicy::Pause);
+
+        assert_that!(sut.send_and_receive(0..2), eq vec![0, 1]);
+
+        sut.node.report_cpu_load(100);
+        assert_that!(sut.send_and_receive(2..6), len 0);
+        assert_that!(sut.subscriber.number_of_shed_samples(), eq 4);
+        // the shed samples were removed from the buffer and do not block the publisher
+        assert_that!(sut.subscriber.has_samples().unwrap(), eq false);
+
+        sut.node.report_cpu_load(0);
+        assert_that!(sut.send_and_receive(6..8), eq vec![6, 7]);
+        assert_that!(sut.subscriber.number_of_shed_samples(), eq 4);
+    }
+
+    #[test]
+    fn downsampling_subscriber_keeps_every_nth_sample_while_shedding<S: Service>() {
+        let sut = TestFixture::<S>::new(ShedPolicy::KeepEveryNth(3));
+
+        sut.node.report_cpu_load(100);
+        assert_that!(sut.send_and_receive(0..10), eq vec![2, 5, 8]);
+        assert_that!(sut.subscriber.number_of_shed_samples(), eq 7);
+
+        sut.node.report_cpu_load(0);
+        assert_that!(sut.send_and_receive(10..13), eq vec![10, 11, 12]);
+
+        // the downsampling starts over when the shedding starts again
+        sut.node.report_cpu_load(100);
+        assert_that!(sut.send_and_receive(13..16), eq vec![15]);
+    }
+
+    #[test]
+    fn keep_every_nth_sample_is_at_least_one<S: Service>() {
+        let sut = TestFixture::<S>::new(ShedPolicy::KeepEveryNth(0));
+
+        assert_that!(sut.subscriber.shed_policy(), eq ShedPolicy::KeepEveryNth(1));
+
+        sut.node.report_cpu_load(100);
+        assert_that!(sut.send_and_receive(0..3), eq vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn node_measures_cpu_load<S: Service>() {
+        let mut config = generate_isolated_config();
+        config.global.node.load_shedding.measurement_interval = core::time::Duration::ZERO;
+        let sut = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        // the first measurement acquires only the reference point
+        assert_that!(sut.measure_cpu_load(), is_ok);
+        std::thread::sleep(core::time::Duration::from_millis(10));
+        assert_that!(sut.measure_cpu_load(), is_ok);
+
+        assert_that!(sut.cpu_load(), is_some);
+    }
+
+    #[test]
+    fn waitset_measures_cpu_load_of_monitored_node<S: Service>() {
+        let mut config = generate_isolated_config();
+        config.global.node.load_shedding.measurement_interval = core::time::Duration::ZERO;
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let waitset = WaitSetBuilder::new().create::<S>().unwrap();
+        let _guard = waitset
+            .attach_interval(core::time::Duration::from_millis(1))
+            .unwrap();
+
+        waitset.monitor_load(&node);
+        waitset.monitor_load(&node);
+
+        for _ in 0..2 {
+            waitset
+                .wait_and_process_once(|_| CallbackProgression::Continue)
+                .unwrap();
+        }
+
+        assert_that!(node.cpu_load(), is_some);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -12,12 +12,14 @@
 
 #[generic_tests::define]
 mod subscriber {
+    use core::time::Duration;
     use iceoryx2::service::builder::publish_subscribe::CustomPayloadMarker;
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
     use std::collections::HashSet;
 
     use iceoryx2::{
         node::NodeBuilder,
+        port::ack_subscriber::AckError,
         port::subscriber::{SubscriberCreateError, SubscriberReceiveError},
         service::{service_name::ServiceName, Service},
         testing::*,
@@ -47,6 +49,34 @@ mod subscriber {
             format!("{}", SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService), eq "SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService");
     }
 
+    #[cfg(feature = "deterministic_profile")]
+    #[test]
+    fn subscriber_with_lazy_mapping_or_heap_copies_violates_deterministic_profile<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut = service
+            .subscriber_builder()
+            .eager_segment_mapping(false)
+            .create();
+        assert_that!(sut.err(), eq Some(SubscriberCreateError::ViolatesDeterministicProfile));
+
+        let sut = service
+            .subscriber_builder()
+            .receive_heap_copies(true)
+            .create();
+        assert_that!(sut.err(), eq Some(SubscriberCreateError::ViolatesDeterministicProfile));
+
+        let sut = service.subscriber_builder().create();
+        assert_that!(sut, is_ok);
+    }
+
     #[test]
     fn id_is_unique<Sut: Service>() {
         let service_name = generate_name();
@@ -71,6 +101,327 @@ mod subscriber {
         }
     }
 
+    #[test]
+    fn paused_subscriber_does_not_receive_samples<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(2)
+            .enable_safe_overflow(true)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        sut.pause();
+        assert_that!(sut.is_paused(), eq true);
+
+        for n in 0..3 {
+            publisher.send_copy(n).unwrap();
+        }
+        assert_that!(sut.has_samples().unwrap(), eq false);
+        assert_that!(sut.receive().unwrap(), is_none);
+
+        sut.resume();
+        assert_that!(sut.is_paused(), eq false);
+
+        // the oldest sample was overridden by safe overflow while paused
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 1);
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 2);
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn ack_subscriber_releases_acknowledged_samples<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service
+            .subscriber_builder()
+            .create_ack_subscriber()
+            .unwrap();
+
+        publisher.send_copy(1234).unwrap();
+
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 1234);
+        assert_that!(sample.number_of_deliveries(), eq 1);
+        assert_that!(sut.number_of_unacknowledged_samples(), eq 1);
+
+        assert_that!(sample.ack(), is_ok);
+        assert_that!(sut.number_of_unacknowledged_samples(), eq 0);
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn ack_subscriber_redelivers_rejected_and_dropped_samples<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service
+            .subscriber_builder()
+            .create_ack_subscriber()
+            .unwrap();
+
+        publisher.send_copy(42).unwrap();
+
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(sample.nack(), is_ok);
+
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 42);
+        assert_that!(sample.number_of_deliveries(), eq 2);
+        drop(sample);
+
+        assert_that!(sut.has_samples().unwrap(), eq true);
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 42);
+        assert_that!(sample.number_of_deliveries(), eq 3);
+        assert_that!(sample.ack(), is_ok);
+
+        assert_that!(sut.has_samples().unwrap(), eq false);
+    }
+
+    #[test]
+    fn ack_subscriber_redelivers_samples_after_ack_timeout<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_millis(50);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service
+            .subscriber_builder()
+            .ack_timeout(TIMEOUT)
+            .create_ack_subscriber()
+            .unwrap();
+        assert_that!(sut.ack_timeout(), eq Some(TIMEOUT));
+
+        publisher.send_copy(7).unwrap();
+
+        let expired_sample = sut.receive().unwrap().unwrap();
+        std::thread::sleep(TIMEOUT * 2);
+
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 7);
+        assert_that!(sample.number_of_deliveries(), eq 2);
+
+        assert_that!(expired_sample.ack(), eq Err(AckError::DeliveryExpired));
+        assert_that!(sample.ack(), is_ok);
+        assert_that!(sut.number_of_unacknowledged_samples(), eq 0);
+    }
+
+    #[test]
+    fn ack_subscriber_group_members_share_samples<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut_1 = service
+            .subscriber_builder()
+            .create_ack_subscriber()
+            .unwrap();
+        let sut_2 = sut_1.create_group_member();
+        assert_that!(sut_1.number_of_group_members(), eq 2);
+        assert_that!(sut_2.number_of_group_members(), eq 2);
+
+        publisher.send_copy(1).unwrap();
+        publisher.send_copy(2).unwrap();
+
+        let sample_1 = sut_1.receive().unwrap().unwrap();
+        let sample_2 = sut_2.receive().unwrap().unwrap();
+        assert_that!(*sample_1, eq 1);
+        assert_that!(*sample_2, eq 2);
+        assert_that!(sut_1.receive().unwrap(), is_none);
+        assert_that!(sut_2.receive().unwrap(), is_none);
+
+        assert_that!(sample_1.ack(), is_ok);
+        assert_that!(sample_2.ack(), is_ok);
+        assert_that!(sut_1.number_of_unacknowledged_samples(), eq 0);
+
+        drop(sut_2);
+        assert_that!(sut_1.number_of_group_members(), eq 1);
+    }
+
+    #[test]
+    fn ack_subscriber_redelivers_rejected_samples_to_another_group_member<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut_1 = service
+            .subscriber_builder()
+            .create_ack_subscriber()
+            .unwrap();
+        let sut_2 = sut_1.create_group_member();
+
+        publisher.send_copy(42).unwrap();
+
+        let sample = sut_1.receive().unwrap().unwrap();
+        assert_that!(sample.nack(), is_ok);
+
+        assert_that!(sut_1.has_samples().unwrap(), eq false);
+        assert_that!(sut_1.receive().unwrap(), is_none);
+        assert_that!(sut_2.has_samples().unwrap(), eq true);
+
+        let sample = sut_2.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 42);
+        assert_that!(sample.number_of_deliveries(), eq 2);
+        drop(sample);
+
+        assert_that!(sut_2.receive().unwrap(), is_none);
+        let sample = sut_1.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 42);
+        assert_that!(sample.number_of_deliveries(), eq 3);
+        assert_that!(sample.nack(), is_ok);
+
+        // without another group member the sample is delivered to the same member again
+        drop(sut_2);
+        let sample = sut_1.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 42);
+        assert_that!(sample.number_of_deliveries(), eq 4);
+        assert_that!(sample.ack(), is_ok);
+    }
+
+    #[test]
+    fn ack_subscriber_redelivers_expired_samples_to_another_group_member<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_millis(50);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut_1 = service
+            .subscriber_builder()
+            .ack_timeout(TIMEOUT)
+            .create_ack_subscriber()
+            .unwrap();
+        let sut_2 = sut_1.create_group_member();
+        assert_that!(sut_2.ack_timeout(), eq Some(TIMEOUT));
+
+        publisher.send_copy(7).unwrap();
+
+        let expired_sample = sut_1.receive().unwrap().unwrap();
+        std::thread::sleep(TIMEOUT * 2);
+
+        assert_that!(sut_1.receive().unwrap(), is_none);
+        let sample = sut_2.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 7);
+        assert_that!(sample.number_of_deliveries(), eq 2);
+
+        assert_that!(expired_sample.ack(), eq Err(AckError::DeliveryExpired));
+        assert_that!(sample.ack(), is_ok);
+        assert_that!(sut_1.number_of_unacknowledged_samples(), eq 0);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn receive_async_waits_until_sample_is_notified<Sut: Service>()
+    where
+        <Sut::Event as iceoryx2_cal::event::Event>::Listener:
+            iceoryx2_bb_posix::file_descriptor::FileDescriptorBased,
+    {
+        use iceoryx2::port::async_listener::AsyncListener;
+
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let pubsub = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let event = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let sut = pubsub.subscriber_builder().create().unwrap();
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_io()
+            .build()
+            .unwrap();
+
+        let sample = runtime.block_on(async {
+            let listener = AsyncListener::new(event.listener_builder().create().unwrap()).unwrap();
+
+            let sender_thread = {
+                let service_name = service_name.clone();
+                let config = config.clone();
+                std::thread::spawn(move || {
+                    let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+                    let pubsub = node
+                        .service_builder(&service_name)
+                        .publish_subscribe::<u64>()
+                        .open()
+                        .unwrap();
+                    let event = node.service_builder(&service_name).event().open().unwrap();
+                    let publisher = pubsub.publisher_builder().create().unwrap();
+                    let notifier = event.notifier_builder().create().unwrap();
+
+                    std::thread::sleep(Duration::from_millis(50));
+                    publisher.send_copy(8912).unwrap();
+                    notifier.notify().unwrap();
+                })
+            };
+
+            let sample = *sut.receive_async(&listener).await.unwrap();
+            sender_thread.join().unwrap();
+            sample
+        });
+
+        assert_that!(sample, eq 8912);
+    }
+
     #[test]
     #[should_panic]
     #[cfg(debug_assertions)]
@@ -98,6 +449,119 @@ mod subscriber {
         let _sample = sut.receive();
     }
 
+    #[test]
+    fn receive_latest_returns_newest_sample_and_releases_older_ones<Sut: Service>() {
+        const BUFFER_SIZE: usize = 5;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .subscriber_max_borrowed_samples(1)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service
+            .subscriber_builder()
+            .buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.receive_latest().unwrap(), is_none);
+
+        for n in 0..BUFFER_SIZE as u64 {
+            assert_that!(publisher.send_copy(n), eq Ok(1));
+        }
+
+        let sample = sut.receive_latest().unwrap().unwrap();
+        assert_that!(*sample, eq BUFFER_SIZE as u64 - 1);
+        drop(sample);
+        assert_that!(sut.receive().unwrap(), is_none);
+
+        // the older samples were returned to the publisher
+        for n in 0..BUFFER_SIZE as u64 {
+            assert_that!(publisher.send_copy(n), eq Ok(1));
+        }
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 0);
+    }
+
+    #[test]
+    fn receive_latest_returns_newest_sample_of_all_publishers<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .subscriber_max_buffer_size(4)
+            .enable_send_timestamp(true)
+            .create()
+            .unwrap();
+
+        let publisher_1 = service.publisher_builder().create().unwrap();
+        let publisher_2 = service.publisher_builder().create().unwrap();
+        let sut = service
+            .subscriber_builder()
+            .buffer_size(4)
+            .create()
+            .unwrap();
+
+        assert_that!(publisher_2.send_copy(1), eq Ok(1));
+        assert_that!(publisher_2.send_copy(2), eq Ok(1));
+        assert_that!(publisher_1.send_copy(3), eq Ok(1));
+        assert_that!(publisher_2.send_copy(4), eq Ok(1));
+        assert_that!(publisher_1.send_copy(5), eq Ok(1));
+
+        assert_that!(*sut.receive_latest().unwrap().unwrap(), eq 5);
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn expired_connection_backlog_keeps_samples_of_disconnected_publishers<Sut: Service>() {
+        let service_name = generate_name();
+        let mut config = generate_isolated_config();
+        config
+            .defaults
+            .publish_subscribe
+            .subscriber_expired_connection_buffer = 1;
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .subscriber_max_buffer_size(2)
+            .create()
+            .unwrap();
+
+        let sut = service
+            .subscriber_builder()
+            .expired_connection_backlog(1024 * 1024)
+            .create()
+            .unwrap();
+
+        let publisher_1 = service.publisher_builder().create().unwrap();
+        let publisher_2 = service.publisher_builder().create().unwrap();
+        for n in 0..2 {
+            publisher_1.send_copy(n).unwrap();
+            publisher_2.send_copy(n + 2).unwrap();
+        }
+        drop(publisher_1);
+        drop(publisher_2);
+
+        let mut received = vec![];
+        while let Some(sample) = sut.receive().unwrap() {
+            received.push(*sample);
+        }
+        received.sort();
+
+        assert_that!(received, eq vec![0, 1, 2, 3]);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
@@ -19,7 +19,7 @@ mod waitset {
     use iceoryx2::port::notifier::Notifier;
     use iceoryx2::prelude::{WaitSetBuilder, *};
     use iceoryx2::testing::*;
-    use iceoryx2::waitset::{WaitSetAttachmentError, WaitSetRunError};
+    use iceoryx2::waitset::{WaitSetAttachmentError, WaitSetGroupError, WaitSetRunError};
     use iceoryx2_bb_posix::config::test_directory;
     use iceoryx2_bb_posix::directory::Directory;
     use iceoryx2_bb_posix::file::Permission;
@@ -576,6 +576,193 @@ mod waitset {
         assert_that!(now.elapsed(), time_at_least TIMEOUT / 2);
     }
 
+    #[cfg(feature = "waitset_fd_attachments")]
+    #[test]
+    fn attached_file_descriptor_is_reported<S: Service>() {
+        use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let (receiver, sender) = create_socket();
+
+        let guard = sut
+            .attach_fd(unsafe { receiver.file_descriptor().native_handle() })
+            .unwrap();
+        assert_that!(sut.len(), eq 1);
+
+        sender.try_send(b"bla").unwrap();
+
+        let mut fd_triggered = false;
+        sut.wait_and_process_once(|attachment_id| {
+            if attachment_id.has_event_from(&guard) {
+                fd_triggered = true;
+            } else {
+                test_fail!("only attachments shall trigger");
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(fd_triggered, eq true);
+
+        drop(guard);
+        assert_that!(sut.is_empty(), eq true);
+    }
+
+    #[cfg(feature = "waitset_fd_attachments")]
+    #[test]
+    fn attaching_invalid_file_descriptor_fails<S: Service>() {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        assert_that!(sut.attach_fd(-1).err(), eq Some(WaitSetAttachmentError::InvalidFileDescriptor));
+        assert_that!(sut.is_empty(), eq true);
+    }
+
+    #[test]
+    fn disabled_group_does_not_report_notifications<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener_1, notifier_1) = create_event::<S>(&node);
+        let (listener_2, notifier_2) = create_event::<S>(&node);
+
+        let listener_1_guard = sut.attach_notification(&listener_1).unwrap();
+        let listener_2_guard = sut.attach_notification(&listener_2).unwrap();
+
+        let group = sut.create_group();
+        let subgroup = group.create_subgroup();
+        group.add(&listener_1_guard).unwrap();
+        subgroup.add(&listener_2_guard).unwrap();
+
+        group.disable();
+        assert_that!(group.is_enabled(), eq false);
+        assert_that!(subgroup.is_enabled(), eq false);
+        assert_that!(sut.number_of_disabled_attachments(), eq 2);
+
+        notifier_1.notify().unwrap();
+        notifier_2.notify().unwrap();
+
+        let mut number_of_triggers = 0;
+        sut.wait_and_process_once_with_timeout(
+            |_| {
+                number_of_triggers += 1;
+                CallbackProgression::Continue
+            },
+            TIMEOUT,
+        )
+        .unwrap();
+        assert_that!(number_of_triggers, eq 0);
+
+        group.enable();
+        assert_that!(subgroup.is_enabled(), eq true);
+        assert_that!(sut.number_of_disabled_attachments(), eq 0);
+
+        let mut listener_1_triggered = false;
+        let mut listener_2_triggered = false;
+        sut.wait_and_process_once_with_timeout(
+            |attachment_id| {
+                if attachment_id.has_event_from(&listener_1_guard) {
+                    listener_1_triggered = true;
+                } else if attachment_id.has_event_from(&listener_2_guard) {
+                    listener_2_triggered = true;
+                } else {
+                    test_fail!("only attachments shall trigger");
+                }
+                CallbackProgression::Continue
+            },
+            TIMEOUT,
+        )
+        .unwrap();
+
+        assert_that!(listener_1_triggered, eq true);
+        assert_that!(listener_2_triggered, eq true);
+    }
+
+    #[test]
+    fn enabled_group_does_not_report_stale_missed_deadlines<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener, _notifier) = create_event::<S>(&node);
+        let deadline_guard = sut.attach_deadline(&listener, TIMEOUT).unwrap();
+        let interval_guard = sut.attach_interval(TIMEOUT).unwrap();
+
+        let group = sut.create_group();
+        group.add(&deadline_guard).unwrap();
+        group.add(&interval_guard).unwrap();
+        group.disable();
+
+        let mut number_of_triggers = 0;
+        sut.wait_and_process_once_with_timeout(
+            |_| {
+                number_of_triggers += 1;
+                CallbackProgression::Continue
+            },
+            TIMEOUT + TIMEOUT / 2,
+        )
+        .unwrap();
+        assert_that!(number_of_triggers, eq 0);
+
+        group.enable();
+
+        sut.wait_and_process_once_with_timeout(
+            |_| {
+                number_of_triggers += 1;
+                CallbackProgression::Continue
+            },
+            TIMEOUT / 2,
+        )
+        .unwrap();
+        assert_that!(number_of_triggers, eq 0);
+    }
+
+    #[test]
+    fn attachment_can_be_member_of_only_one_group<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let other_waitset = WaitSetBuilder::new().create::<S>().unwrap();
+        let interval_guard = sut.attach_interval(TIMEOUT).unwrap();
+
+        let group_1 = sut.create_group();
+        let group_2 = sut.create_group();
+        let other_group = other_waitset.create_group();
+
+        assert_that!(group_1.add(&interval_guard), is_ok);
+        assert_that!(group_1.contains(&interval_guard), eq true);
+        assert_that!(group_2.add(&interval_guard).err(), eq Some(WaitSetGroupError::AlreadyGroupMember));
+        assert_that!(other_group.add(&interval_guard).err(), eq Some(WaitSetGroupError::AttachmentOfDifferentWaitSet));
+
+        assert_that!(group_2.remove(&interval_guard), eq false);
+        assert_that!(group_1.remove(&interval_guard), eq true);
+        assert_that!(group_2.add(&interval_guard), is_ok);
+    }
+
+    #[test]
+    fn dropping_disabled_group_enables_its_members<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let interval_guard = sut.attach_interval(TIMEOUT).unwrap();
+
+        let group = sut.create_group();
+        group.add(&interval_guard).unwrap();
+        group.disable();
+        assert_that!(sut.number_of_disabled_attachments(), eq 1);
+
+        drop(group);
+        assert_that!(sut.number_of_disabled_attachments(), eq 0);
+    }
+
     #[test]
     fn signal_handling_mechanism_can_be_configured<S: Service>() {
         let sut_1 = WaitSetBuilder::new()